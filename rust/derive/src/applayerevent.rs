@@ -23,6 +23,7 @@ use syn::{self, parse_macro_input, DeriveInput};
 pub fn derive_app_layer_event(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
+    let ffi_prefix = parse_ffi_prefix(&input.attrs);
 
     let mut fields = Vec::new();
     let mut event_ids = Vec::new();
@@ -105,6 +106,41 @@ pub fn derive_app_layer_event(input: TokenStream) -> TokenStream {
         }
     };
 
+    let ffi = if let Some(prefix) = ffi_prefix {
+        let span = prefix.span();
+        let info_fn = syn::Ident::new(&format!("{}_get_event_info", prefix.value()), span);
+        let info_by_id_fn =
+            syn::Ident::new(&format!("{}_get_event_info_by_id", prefix.value()), span);
+        quote! {
+            #[no_mangle]
+            pub unsafe extern "C" fn #info_fn(
+                event_name: *const std::os::raw::c_char,
+                event_id: *mut u8,
+                event_type: *mut #crate_id::core::AppLayerEventType,
+            ) -> std::os::raw::c_int {
+                <#name as #crate_id::applayer::AppLayerEvent>::get_event_info(
+                    event_name, event_id, event_type)
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn #info_by_id_fn(
+                event_id: u8,
+                event_name: *mut *const std::os::raw::c_char,
+                event_type: *mut #crate_id::core::AppLayerEventType,
+            ) -> std::os::raw::c_int {
+                <#name as #crate_id::applayer::AppLayerEvent>::get_event_info_by_id(
+                    event_id, event_name, event_type)
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let expanded = quote! {
+        #expanded
+        #ffi
+    };
+
     proc_macro::TokenStream::from(expanded)
 }
 
@@ -150,6 +186,30 @@ fn parse_name(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
     None
 }
 
+/// Parse the prefix of the exported event info functions from the
+/// "ffi_prefix" attribute of the enum.
+///
+/// For example:
+/// ```ignore
+/// #[derive(AppLayerEvent)]
+/// #[ffi_prefix("rs_smb_state")]
+/// pub enum SMBEvent {
+///     InternalError,
+/// }
+/// ```
+/// exports `rs_smb_state_get_event_info` and
+/// `rs_smb_state_get_event_info_by_id`.
+fn parse_ffi_prefix(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    for attr in attrs {
+        if attr.path.is_ident("ffi_prefix") {
+            if let Ok(val) = attr.parse_args::<syn::LitStr>() {
+                return Some(val);
+            }
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
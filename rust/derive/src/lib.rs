@@ -42,7 +42,16 @@ mod stringenum;
 /// The enum variants must follow the naming convention of OneTwoThree
 /// for proper conversion to the name used in rules (one_tow_three) or
 /// optionally add a name attribute.
-#[proc_macro_derive(AppLayerEvent, attributes(name))]
+///
+/// The `get_event_info` and `get_event_info_by_id` functions of the trait
+/// can be used directly in `RustParser`. With the `ffi_prefix` attribute on
+/// the enum, they are also exported as `<prefix>_get_event_info` and
+/// `<prefix>_get_event_info_by_id`:
+///
+/// #[derive(AppLayerEvent)]
+/// #[ffi_prefix("rs_dns")]
+/// enum { ... }
+#[proc_macro_derive(AppLayerEvent, attributes(name, ffi_prefix))]
 pub fn derive_app_layer_event(input: TokenStream) -> TokenStream {
     applayerevent::derive_app_layer_event(input)
 }
@@ -0,0 +1,222 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Per flow summary of the app-layer events raised by the parsers.
+//!
+//! The app-layer parser adds the events of each transaction when it is
+//! freed and the app-layer events of each packet of the flow. At flow end
+//! the anomaly summary logger merges these with the events of the remaining
+//! transactions and logs a single record with the count per event, instead
+//! of one anomaly record per event.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::core::AppProto;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+
+#[derive(Debug)]
+struct AnomalyCount {
+    alproto: AppProto,
+    app_proto: String,
+    code: u8,
+    /// Event name, None if the parser does not know the event code.
+    event: Option<String>,
+    count: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct AnomalySummary {
+    events: Vec<AnomalyCount>,
+}
+
+impl AnomalySummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count event `code` of `alproto`. The names are only used the first
+    /// time the event is seen.
+    pub fn add(&mut self, alproto: AppProto, app_proto: &str, code: u8, event: Option<&str>) {
+        if let Some(entry) = self
+            .events
+            .iter_mut()
+            .find(|e| e.alproto == alproto && e.code == code)
+        {
+            entry.count += 1;
+            return;
+        }
+        self.events.push(AnomalyCount {
+            alproto,
+            app_proto: app_proto.to_string(),
+            code,
+            event: event.map(|e| e.to_string()),
+            count: 1,
+        });
+    }
+
+    /// Add the counts of `other`.
+    pub fn merge(&mut self, other: &AnomalySummary) {
+        for e in &other.events {
+            if let Some(entry) = self
+                .events
+                .iter_mut()
+                .find(|s| s.alproto == e.alproto && s.code == e.code)
+            {
+                entry.count += e.count;
+                continue;
+            }
+            self.events.push(AnomalyCount {
+                alproto: e.alproto,
+                app_proto: e.app_proto.clone(),
+                code: e.code,
+                event: e.event.clone(),
+                count: e.count,
+            });
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Total number of events.
+    pub fn total(&self) -> u64 {
+        self.events.iter().map(|e| e.count).sum()
+    }
+
+    pub fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        js.open_object("anomaly_summary")?;
+        js.set_uint("total", self.total())?;
+        js.open_array("events")?;
+        for entry in &self.events {
+            js.start_object()?;
+            js.set_string("app_proto", &entry.app_proto)?;
+            if let Some(event) = &entry.event {
+                js.set_string("event", event)?;
+            } else {
+                js.set_uint("code", entry.code as u64)?;
+            }
+            js.set_uint("count", entry.count)?;
+            js.close()?;
+        }
+        js.close()?;
+        js.close()?;
+        Ok(())
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn SCAnomalySummaryNew() -> *mut AnomalySummary {
+    Box::into_raw(Box::new(AnomalySummary::new()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCAnomalySummaryFree(summary: *mut AnomalySummary) {
+    if !summary.is_null() {
+        std::mem::drop(Box::from_raw(summary));
+    }
+}
+
+/// Count an event. `event_name` may be NULL for an unknown event code.
+#[no_mangle]
+pub unsafe extern "C" fn SCAnomalySummaryAdd(
+    summary: &mut AnomalySummary, alproto: AppProto, alproto_name: *const c_char, code: u8,
+    event_name: *const c_char,
+) {
+    let app_proto = if alproto_name.is_null() {
+        std::borrow::Cow::Borrowed("unknown")
+    } else {
+        CStr::from_ptr(alproto_name).to_string_lossy()
+    };
+    let event = if event_name.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(event_name).to_string_lossy())
+    };
+    summary.add(alproto, &app_proto, code, event.as_deref());
+}
+
+#[no_mangle]
+pub extern "C" fn SCAnomalySummaryMerge(summary: &mut AnomalySummary, other: &AnomalySummary) {
+    summary.merge(other);
+}
+
+#[no_mangle]
+pub extern "C" fn SCAnomalySummaryIsEmpty(summary: &AnomalySummary) -> bool {
+    summary.is_empty()
+}
+
+#[no_mangle]
+pub extern "C" fn SCAnomalySummaryLogJson(summary: &AnomalySummary, js: &mut JsonBuilder) -> bool {
+    summary.log(js).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonbuilder::{jb_len, jb_ptr};
+
+    #[test]
+    fn test_anomaly_summary_counts() {
+        let mut summary = AnomalySummary::new();
+        assert!(summary.is_empty());
+        for _ in 0..1000 {
+            summary.add(1, "dns", 2, Some("malformed_data"));
+        }
+        summary.add(2, "smb", 2, Some("record_overflow"));
+        summary.add(1, "dns", 7, None);
+        assert!(!summary.is_empty());
+        assert_eq!(summary.events.len(), 3);
+        assert_eq!(summary.events[0].count, 1000);
+        assert_eq!(summary.total(), 1002);
+
+        let mut js = JsonBuilder::try_new_object().unwrap();
+        summary.log(&mut js).unwrap();
+        js.close().unwrap();
+        let buf = unsafe { std::slice::from_raw_parts(jb_ptr(&mut js), jb_len(&js)) };
+        assert_eq!(
+            std::str::from_utf8(buf).unwrap(),
+            r#"{"anomaly_summary":{"total":1002,"events":[{"app_proto":"dns","event":"malformed_data","count":1000},{"app_proto":"smb","event":"record_overflow","count":1},{"app_proto":"dns","code":7,"count":1}]}}"#
+        );
+    }
+
+    #[test]
+    fn test_anomaly_summary_merge() {
+        let mut flow = AnomalySummary::new();
+        flow.add(1, "dns", 2, Some("malformed_data"));
+        flow.add(
+            0,
+            "applayer",
+            2,
+            Some("APPLAYER_WRONG_DIRECTION_FIRST_DATA"),
+        );
+
+        let mut summary = AnomalySummary::new();
+        summary.merge(&flow);
+        summary.add(1, "dns", 2, Some("malformed_data"));
+        summary.add(1, "dns", 3, Some("not_a_request"));
+        assert_eq!(summary.events.len(), 3);
+        assert_eq!(summary.events[0].count, 2);
+        assert_eq!(summary.total(), 4);
+
+        // merging does not change the summary that is merged
+        assert_eq!(flow.total(), 2);
+        summary.merge(&flow);
+        assert_eq!(summary.total(), 6);
+    }
+}
@@ -24,6 +24,11 @@ use std::os::raw::{c_void,c_char,c_int};
 use crate::core::SC;
 use std::ffi::CStr;
 use crate::core::StreamingBufferConfig;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::conf::{conf_get, get_memval};
+use crate::applayer_bytes::app_layer_bytes_register_parser;
 
 // Make the AppLayerEvent derive macro available to users importing
 // AppLayerEvent from this module.
@@ -51,6 +56,17 @@ impl StreamSlice {
         }
     }
 
+    /// Create a StreamSlice for a gap of `size` bytes. Useful in unit tests.
+    #[cfg(test)]
+    pub fn from_gap(size: u32, flags: u8, offset: u64) -> Self {
+        Self {
+            input: std::ptr::null(),
+            input_len: size,
+            flags: flags | core::STREAM_GAP,
+            offset,
+        }
+    }
+
     pub fn is_gap(&self) -> bool {
         self.input.is_null() && self.input_len > 0
     }
@@ -77,6 +93,142 @@ impl StreamSlice {
     }
 }
 
+/// Input of a parse function, borrowed from the StreamSlice passed by
+/// the C side for the duration of the call.
+///
+/// Unlike the StreamSlice, the data of a gap is always empty instead of
+/// a null pointer with a length, and sub-slicing is bounds checked.
+#[derive(Clone, Copy, Debug)]
+pub struct BorrowedInput<'a> {
+    data: &'a [u8],
+    /// size of the gap, 0 for data
+    gap_size: u32,
+    /// STREAM_* flags
+    flags: u8,
+}
+
+impl<'a> BorrowedInput<'a> {
+    pub fn new(stream_slice: &'a StreamSlice) -> Self {
+        if stream_slice.is_gap() {
+            return Self {
+                data: &[],
+                gap_size: stream_slice.gap_size(),
+                flags: stream_slice.flags(),
+            };
+        }
+        Self {
+            data: stream_slice.as_slice(),
+            gap_size: 0,
+            flags: stream_slice.flags(),
+        }
+    }
+
+    /// Create a BorrowedInput from a Rust slice. Useful in unit tests.
+    #[cfg(test)]
+    pub fn from_slice(data: &'a [u8], flags: u8) -> Self {
+        Self {
+            data,
+            gap_size: 0,
+            flags,
+        }
+    }
+
+    pub fn is_gap(&self) -> bool {
+        self.gap_size > 0
+    }
+    pub fn gap_size(&self) -> u32 {
+        self.gap_size
+    }
+    /// The data, empty for a gap.
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.data
+    }
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+    pub fn direction(&self) -> Direction {
+        self.flags.into()
+    }
+    /// Get a sub-slice of the data, `None` if out of bounds.
+    pub fn get<R>(&self, range: R) -> Option<&'a [u8]>
+    where
+        R: std::slice::SliceIndex<[u8], Output = [u8]>,
+    {
+        self.data.get(range)
+    }
+    /// The input without its first `n` bytes, `None` if it is shorter.
+    pub fn advance(&self, n: usize) -> Option<Self> {
+        let data = self.data.get(n..)?;
+        Some(Self { data, ..*self })
+    }
+    /// Number of bytes consumed given the remainder of the data, as
+    /// needed by `AppLayerResult::incomplete`.
+    pub fn consumed(&self, rem: &[u8]) -> u32 {
+        self.data.len().saturating_sub(rem.len()) as u32
+    }
+}
+
+impl<'a> From<&'a StreamSlice> for BorrowedInput<'a> {
+    fn from(stream_slice: &'a StreamSlice) -> Self {
+        Self::new(stream_slice)
+    }
+}
+
+/// Gaps in a direction of a stream, for parsers accepting gaps that
+/// look for the start of a record after one.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GapStats {
+    /// Gaps after which the parser found a record again.
+    pub recovered: u32,
+    /// Gaps after which the parser gave up on the direction.
+    pub unrecoverable: u32,
+    /// Data skipped looking for a record since the last gap.
+    skipped: u64,
+    /// A gap was seen and no record was found yet.
+    pending: bool,
+}
+
+impl GapStats {
+    /// Account for a gap. Gaps seen while still looking for a record
+    /// after a previous one are counted once.
+    pub fn gap(&mut self) {
+        self.pending = true;
+        self.skipped = 0;
+    }
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+    /// Account for data skipped looking for a record, returns the
+    /// total skipped since the gap.
+    pub fn skip(&mut self, len: u32) -> u64 {
+        self.skipped = self.skipped.saturating_add(len as u64);
+        self.skipped
+    }
+    /// A record was found after the gap.
+    pub fn resync(&mut self) {
+        if self.pending {
+            self.pending = false;
+            self.recovered = self.recovered.saturating_add(1);
+        }
+    }
+    /// No record could be found after the gap.
+    pub fn give_up(&mut self) {
+        if self.pending {
+            self.pending = false;
+            self.unrecoverable = self.unrecoverable.saturating_add(1);
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.recovered == 0 && self.unrecoverable == 0
+    }
+}
+
 #[repr(C)]
 #[derive(Default, Debug,PartialEq, Eq)]
 pub struct AppLayerTxConfig {
@@ -92,6 +244,60 @@ impl AppLayerTxConfig {
     }
 }
 
+/// Events of a transaction. Wraps the C AppLayerDecoderEvents pointer,
+/// which is allocated when the first event is set and freed on drop.
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct EventList(*mut core::AppLayerDecoderEvents);
+
+impl Default for EventList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for EventList {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            core::sc_app_layer_decoder_events_free_events(&mut self.0);
+        }
+    }
+}
+
+impl EventList {
+    pub const fn new() -> Self {
+        Self(std::ptr::null_mut())
+    }
+
+    pub fn set_event(&mut self, event: u8) {
+        core::sc_app_layer_decoder_events_set_event_raw(&mut self.0, event);
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        match unsafe { self.0.as_ref() } {
+            Some(events) => events.as_slice(),
+            None => &[],
+        }
+    }
+
+    /// Number of events set, an event can be set more than once.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    pub fn is_set(&self, event: u8) -> bool {
+        self.as_slice().contains(&event)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.as_slice().iter().copied()
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq)]
 pub struct AppLayerTxData {
@@ -122,7 +328,7 @@ pub struct AppLayerTxData {
     detect_flags_tc: u64,
 
     de_state: *mut DetectEngineState,
-    pub events: *mut core::AppLayerDecoderEvents,
+    pub events: EventList,
 }
 
 impl Default for AppLayerTxData {
@@ -136,9 +342,6 @@ impl Drop for AppLayerTxData {
         if !self.de_state.is_null() {
             core::sc_detect_engine_state_free(self.de_state);
         }
-        if !self.events.is_null() {
-            core::sc_app_layer_decoder_events_free_events(&mut self.events);
-        }
     }
 }
 
@@ -158,7 +361,7 @@ impl AppLayerTxData {
             detect_flags_ts: 0,
             detect_flags_tc: 0,
             de_state: std::ptr::null_mut(),
-            events: std::ptr::null_mut(),
+            events: EventList::new(),
         }
     }
 
@@ -181,7 +384,7 @@ impl AppLayerTxData {
             detect_flags_ts,
             detect_flags_tc,
             de_state: std::ptr::null_mut(),
-            events: std::ptr::null_mut(),
+            events: EventList::new(),
         }
     }
 
@@ -194,7 +397,7 @@ impl AppLayerTxData {
     }
 
     pub fn set_event(&mut self, event: u8) {
-        core::sc_app_layer_decoder_events_set_event_raw(&mut self.events, event);
+        self.events.set_event(event);
     }
 
     pub fn update_file_flags(&mut self, state_flags: u16) {
@@ -246,12 +449,17 @@ macro_rules!export_tx_data_get {
 #[derive(Default,Debug,PartialEq, Eq,Copy,Clone)]
 pub struct AppLayerStateData {
     pub file_flags: u16,
+    /// Times the transaction limit was reached since the last call to the
+    /// parser, counted by the engine in the
+    /// `app_layer.error.<proto>.tx_limit_reached` stats.
+    pub tx_limit_reached: u16,
 }
 
 impl AppLayerStateData {
     pub fn new() -> Self {
         Self {
             file_flags: 0,
+            tx_limit_reached: 0,
         }
     }
 }
@@ -269,6 +477,21 @@ macro_rules!export_state_data_get {
     }
 }
 
+/// Reason of a parser error, counted per protocol in the
+/// `app_layer.error` stats.
+#[repr(i32)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AppLayerError {
+    /// Invalid or unsupported data.
+    Parser = -1,
+    /// Data lost in a gap the parser can't recover from.
+    Gap = -2,
+    /// A memcap or limit of the parser was reached.
+    Memcap = -3,
+    /// Inconsistent state of the parser, i.e. a bug.
+    Internal = -4,
+}
+
 #[repr(C)]
 #[derive(Default,Debug,PartialEq, Eq,Copy,Clone)]
 pub struct AppLayerResult {
@@ -285,8 +508,12 @@ impl AppLayerResult {
     /// parser has hit an unrecoverable error. Returning this to the API
     /// leads to no further calls to the parser.
     pub fn err() -> Self {
+        Self::error(AppLayerError::Parser)
+    }
+    /// parser has hit an unrecoverable error for `reason`, see err()
+    pub fn error(reason: AppLayerError) -> Self {
         return Self {
-            status: -1,
+            status: reason as i32,
             ..Default::default()
         };
     }
@@ -396,6 +623,7 @@ pub struct RustParser {
     // the implementation to make sure the config is applied correctly.
     pub apply_tx_config: Option<ApplyTxConfigFn>,
 
+    /// Parser options, a combination of the `APP_LAYER_PARSER_OPT_*` flags
     pub flags: u32,
 
     pub get_frame_id_by_name: Option<GetFrameIdByName>,
@@ -474,6 +702,137 @@ pub unsafe fn AppLayerRegisterParser(parser: *const RustParser, alproto: AppProt
     (SC.unwrap().AppLayerRegisterParser)(parser, alproto)
 }
 
+/// Error of the registration of a parser with the app-layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegistrationError {
+    /// AppLayerRegisterParser failed with this return value.
+    ParserRegistration(c_int),
+}
+
+impl std::fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistrationError::ParserRegistration(ret) => {
+                write!(f, "parser registration failed with {}", ret)
+            }
+        }
+    }
+}
+
+/// Outcome of the registration of a parser.
+#[derive(Debug, PartialEq, Eq)]
+struct ParserRegistration {
+    name: String,
+    ipproto: u8,
+    result: Result<(), RegistrationError>,
+}
+
+/// Outcome of the registration of the parsers, for the unix socket
+/// status command.
+#[derive(Debug, Default)]
+struct ParserRegistrations(Vec<ParserRegistration>);
+
+impl ParserRegistrations {
+    /// Record and log the outcome of the registration of a parser from
+    /// the return value of AppLayerRegisterParser.
+    fn record(&mut self, name: &str, ipproto: u8, ret: c_int) -> Result<(), RegistrationError> {
+        let result = if ret == 0 {
+            Ok(())
+        } else {
+            Err(RegistrationError::ParserRegistration(ret))
+        };
+        if let Err(ref err) = result {
+            SCLogError!("{}/{}: {}", name, ipproto_str(ipproto), err);
+        }
+        self.0.push(ParserRegistration {
+            name: name.to_string(),
+            ipproto,
+            result,
+        });
+        result
+    }
+
+    fn to_json(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        for registration in &self.0 {
+            js.start_object()?;
+            js.set_string("name", &registration.name)?;
+            js.set_string("ipproto", ipproto_str(registration.ipproto))?;
+            match registration.result {
+                Ok(()) => {
+                    js.set_string("status", "registered")?;
+                }
+                Err(RegistrationError::ParserRegistration(ret)) => {
+                    js.set_string("status", "failed")?;
+                    js.set_int("error", ret as i64)?;
+                }
+            }
+            js.close()?;
+        }
+        Ok(())
+    }
+}
+
+static PARSER_REGISTRATIONS: Mutex<ParserRegistrations> =
+    Mutex::new(ParserRegistrations(Vec::new()));
+
+fn ipproto_str(ipproto: u8) -> &'static str {
+    match ipproto {
+        core::IPPROTO_TCP => "tcp",
+        core::IPPROTO_UDP => "udp",
+        _ => "unknown",
+    }
+}
+
+/// Stream depth of a parser with the `APP_LAYER_PARSER_OPT_UNLIMITED_STREAM_DEPTH`
+/// option: unlimited, unless `value`, the `stream-depth` setting of the parser,
+/// is set to a valid size.
+fn unlimited_stream_depth(name: &str, value: Option<&str>) -> u32 {
+    match value.map(get_memval) {
+        None => 0,
+        Some(Ok(depth)) => depth.min(u32::MAX as u64) as u32,
+        Some(Err(_)) => {
+            SCLogError!("{}: invalid stream-depth value, using unlimited", name);
+            0
+        }
+    }
+}
+
+/// Register a parser with the app-layer.
+///
+/// A failure is logged and, as the outcome of every registration, reported
+/// by the unix socket status command. Callers that can go on without the
+/// parser can ignore the error.
+pub unsafe fn applayer_register_parser(
+    parser: &RustParser, alproto: AppProto,
+) -> Result<(), RegistrationError> {
+    let ret = AppLayerRegisterParser(parser, alproto);
+    let name = CStr::from_ptr(parser.name).to_string_lossy();
+    if ret == 0 {
+        app_layer_bytes_register_parser(&name, parser.ipproto, alproto);
+    }
+    if ret == 0 && parser.flags & APP_LAYER_PARSER_OPT_UNLIMITED_STREAM_DEPTH != 0 {
+        let key = format!("app-layer.protocols.{}.stream-depth", name);
+        let depth = unlimited_stream_depth(&name, conf_get(&key));
+        AppLayerParserSetStreamDepth(parser.ipproto, alproto, depth);
+    }
+    PARSER_REGISTRATIONS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .record(&name, parser.ipproto, ret)
+}
+
+/// Append the outcome of the registration of the parsers to the JSON
+/// array `js`, as objects with the "name", "ipproto" and "status" of the
+/// parser.
+#[no_mangle]
+pub extern "C" fn SCAppLayerParserRegistrationsToJson(js: &mut JsonBuilder) -> bool {
+    PARSER_REGISTRATIONS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .to_json(js)
+        .is_ok()
+}
+
 // Defined in app-layer-detect-proto.h
 /// cbindgen:ignore
 extern {
@@ -497,9 +856,93 @@ extern {
                                                      pp_min_depth: u16, pp_max_depth: u16) -> c_int;
     pub fn AppLayerProtoDetectConfProtoDetectionEnabled(ipproto: *const c_char, proto: *const c_char) -> c_int;
     pub fn AppLayerProtoDetectConfProtoDetectionEnabledDefault(ipproto: *const c_char, proto: *const c_char, default: bool) -> c_int;
+    pub fn AppLayerRequestProtocolChange(flow: *const Flow, dp: u16, expect_proto: AppProto) -> bool;
     pub fn AppLayerRequestProtocolTLSUpgrade(flow: *const Flow) -> bool;
 }
 
+/// Request the app-layer to wrap up the current protocol of the flow and
+/// to rerun protocol detection on the next data, as if it was sent to
+/// port `dp`. If the detected protocol is not `expect_proto`, an
+/// `applayer_unexpected_protocol` event is set, `ALPROTO_UNKNOWN` accepts
+/// any protocol.
+///
+/// The state of the current parser is freed once the new protocol is
+/// detected, so the parser should stop parsing after a successful
+/// request. Returns false if a change is already in progress or if there
+/// is no flow, as in unit tests.
+pub fn applayer_request_protocol_change(
+    flow: *const Flow, dp: u16, expect_proto: AppProto,
+) -> bool {
+    if flow.is_null() {
+        return false;
+    }
+    unsafe { AppLayerRequestProtocolChange(flow, dp, expect_proto) }
+}
+
+/// Request the app-layer to hand the flow over to TLS, e.g. after a
+/// STARTTLS command was accepted. If the next data is not TLS, an
+/// `applayer_no_tls_after_starttls` event is set.
+///
+/// See [`applayer_request_protocol_change`].
+pub fn applayer_request_tls_upgrade(flow: *const Flow) -> bool {
+    if flow.is_null() {
+        return false;
+    }
+    unsafe { AppLayerRequestProtocolTLSUpgrade(flow) }
+}
+
+/// Register the probing parsers of a protocol on the ports of its
+/// `app-layer.protocols.<name>.detection-ports` configuration, falling
+/// back to `default_port` if there is none. Without a default port,
+/// the probing parsers are only registered if ports are configured.
+///
+/// This is for parsers registering probing parsers besides the ones of
+/// their `RustParser`, whose `default_port` is handled the same way.
+/// Returns true if the ports came from the configuration.
+pub unsafe fn applayer_register_probing_ports(
+    ipproto: u8, alproto_name: *const c_char, alproto: AppProto, min_depth: u16, max_depth: u16,
+    probe_ts: ProbeFn, probe_tc: ProbeFn, default_port: Option<&str>,
+) -> bool {
+    let ipproto_name = if ipproto == core::IPPROTO_TCP {
+        "tcp"
+    } else {
+        "udp"
+    };
+    let ipproto_name = std::ffi::CString::new(ipproto_name).unwrap();
+    if AppLayerProtoDetectPPParseConfPorts(
+        ipproto_name.as_ptr(),
+        ipproto,
+        alproto_name,
+        alproto,
+        min_depth,
+        max_depth,
+        probe_ts,
+        probe_tc,
+    ) != 0
+    {
+        return true;
+    }
+    if let Some(port) = default_port {
+        SCLogDebug!(
+            "No {:?} detection ports configured, using {}",
+            CStr::from_ptr(alproto_name),
+            port
+        );
+        let port = std::ffi::CString::new(port).unwrap();
+        AppLayerProtoDetectPPRegister(
+            ipproto,
+            port.as_ptr(),
+            alproto,
+            min_depth,
+            max_depth,
+            Direction::ToServer.into(),
+            probe_ts,
+            probe_tc,
+        );
+    }
+    false
+}
+
 // Defined in app-layer-parser.h
 pub const APP_LAYER_PARSER_NO_INSPECTION : u16 = BIT_U16!(1);
 pub const APP_LAYER_PARSER_NO_REASSEMBLY : u16 = BIT_U16!(2);
@@ -509,6 +952,12 @@ pub const APP_LAYER_PARSER_EOF_TS : u16 = BIT_U16!(5);
 pub const APP_LAYER_PARSER_EOF_TC : u16 = BIT_U16!(6);
 
 pub const APP_LAYER_PARSER_OPT_ACCEPT_GAPS: u32 = BIT_U32!(0);
+/// The parser needs the full stream: no stream depth is applied unless
+/// `app-layer.protocols.<name>.stream-depth` is set in the configuration.
+pub const APP_LAYER_PARSER_OPT_UNLIMITED_STREAM_DEPTH: u32 = BIT_U32!(1);
+/// The parser is a classifier that only needs the first data in each
+/// direction. Once it has seen both, the app-layer is done with the flow.
+pub const APP_LAYER_PARSER_OPT_FIRST_DATA_ONLY: u32 = BIT_U32!(2);
 
 pub const APP_LAYER_TX_SKIP_INSPECT_FLAG: u64 = BIT_U64!(62);
 
@@ -653,6 +1102,137 @@ pub trait Transaction {
     fn id(&self) -> u64;
 }
 
+/// Transaction limit trait.
+///
+/// Implemented by the transactions of parsers enforcing a `max-tx` limit
+/// with `applayer_enforce_tx_limit`.
+pub trait TxLimit {
+    /// Return true if the transaction is complete in both directions.
+    fn is_complete(&self) -> bool;
+
+    /// Mark the transaction as complete in both directions.
+    fn force_complete(&mut self);
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData;
+}
+
+/// Enforce the limit of live transactions of a state, before `new_tx` is
+/// added to its `transactions`.
+///
+/// If the state holds more than `max_tx` transactions, the oldest
+/// incomplete one is forced to completion, so that it can be logged and
+/// freed, and `event`, the protocol's `TooManyTransactions` event, is set
+/// on it. If all of them are complete already the event is set on `new_tx`
+/// instead. The limit being reached is counted in `state_data`, for the
+/// `app_layer.error.<proto>.tx_limit_reached` stats. Returns true if the
+/// limit was exceeded.
+///
+/// `index_completed` is the index of the first transaction that may still
+/// be incomplete. It is advanced past the transactions visited, and is
+/// expected to be reset by the caller when transactions are freed.
+pub fn applayer_enforce_tx_limit<'a, Tx, I>(
+    transactions: I, state_data: &mut AppLayerStateData, max_tx: usize,
+    index_completed: &mut usize, new_tx: &mut Tx, event: u8,
+) -> bool
+where
+    Tx: TxLimit + 'a,
+    I: ExactSizeIterator<Item = &'a mut Tx>,
+{
+    if transactions.len() <= max_tx {
+        return false;
+    }
+    state_data.tx_limit_reached = state_data.tx_limit_reached.saturating_add(1);
+    for tx_old in transactions.skip(*index_completed) {
+        *index_completed += 1;
+        if !tx_old.is_complete() {
+            tx_old.force_complete();
+            tx_old.tx_data_mut().set_event(event);
+            return true;
+        }
+    }
+    new_tx.tx_data_mut().set_event(event);
+    true
+}
+
+/// Transaction and buffer limits of an app-layer protocol.
+///
+/// They are set from the `max-tx`, `max-buffer-per-tx` and
+/// `max-state-memory` options of the section of the protocol in
+/// `app-layer.protocols`. The buffer and memory limits are in bytes, 0
+/// being no limit, the default.
+pub struct AppLayerLimits {
+    max_tx: AtomicUsize,
+    max_buffer_per_tx: AtomicUsize,
+    max_total_state_mem: AtomicUsize,
+}
+
+impl AppLayerLimits {
+    pub const fn new(max_tx: usize) -> Self {
+        Self {
+            max_tx: AtomicUsize::new(max_tx),
+            max_buffer_per_tx: AtomicUsize::new(0),
+            max_total_state_mem: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the limits from the configuration of the protocol, `proto`
+    /// being its name in `app-layer.protocols`.
+    pub fn configure(&self, proto: &str) {
+        if let Some(val) = conf_get(&format!("app-layer.protocols.{}.max-tx", proto)) {
+            if let Ok(v) = val.parse::<usize>() {
+                self.max_tx.store(v, Ordering::Relaxed);
+            } else {
+                SCLogError!("Invalid value for {}.max-tx", proto);
+            }
+        }
+        let memvals = [
+            ("max-buffer-per-tx", &self.max_buffer_per_tx),
+            ("max-state-memory", &self.max_total_state_mem),
+        ];
+        for (name, limit) in memvals {
+            if let Some(val) = conf_get(&format!("app-layer.protocols.{}.{}", proto, name)) {
+                if let Ok(v) = get_memval(val) {
+                    limit.store(v as usize, Ordering::Relaxed);
+                } else {
+                    SCLogError!("Invalid value for {}.{}", proto, name);
+                }
+            }
+        }
+    }
+
+    pub fn max_tx(&self) -> usize {
+        self.max_tx.load(Ordering::Relaxed)
+    }
+
+    pub fn max_buffer_per_tx(&self) -> usize {
+        self.max_buffer_per_tx.load(Ordering::Relaxed)
+    }
+
+    pub fn max_total_state_mem(&self) -> usize {
+        self.max_total_state_mem.load(Ordering::Relaxed)
+    }
+
+    /// Append `data` to a buffer of a transaction, within the limits.
+    ///
+    /// `state_mem` is the memory used by the buffers of all the
+    /// transactions of the state, and is updated with the bytes appended.
+    /// Returns false if `data` was truncated.
+    pub fn append_buffer(&self, buffer: &mut Vec<u8>, data: &[u8], state_mem: &mut usize) -> bool {
+        let mut len = data.len();
+        let max_buffer = self.max_buffer_per_tx();
+        if max_buffer > 0 {
+            len = std::cmp::min(len, max_buffer.saturating_sub(buffer.len()));
+        }
+        let max_mem = self.max_total_state_mem();
+        if max_mem > 0 {
+            len = std::cmp::min(len, max_mem.saturating_sub(*state_mem));
+        }
+        buffer.extend_from_slice(&data[..len]);
+        *state_mem += len;
+        len == data.len()
+    }
+}
+
 pub trait State<Tx: Transaction> {
     /// Return the number of transactions in the state's transaction collection.
     fn get_transaction_count(&self) -> usize;
@@ -736,3 +1316,156 @@ pub trait AppLayerFrameType {
         Self::from_u8(id).map(|s| s.to_cstring()).unwrap_or_else(std::ptr::null)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonbuilder::{jb_len, jb_ptr};
+
+    #[test]
+    fn test_unlimited_stream_depth() {
+        assert_eq!(unlimited_stream_depth("smb", None), 0);
+        // the configuration overrides the unlimited depth
+        assert_eq!(unlimited_stream_depth("smb", Some("1mb")), 1024 * 1024);
+        assert_eq!(unlimited_stream_depth("smb", Some("0")), 0);
+        assert_eq!(unlimited_stream_depth("smb", Some("lots")), 0);
+    }
+
+    #[test]
+    fn test_event_list() {
+        let list = EventList::new();
+        assert!(list.is_empty());
+        assert!(!list.is_set(0));
+
+        let mut buf = [3, 1, 3];
+        let mut events = core::AppLayerDecoderEvents::from_slice(&mut buf);
+        let mut list = EventList(&mut events);
+        assert_eq!(list.len(), 3);
+        assert!(list.is_set(1));
+        assert!(!list.is_set(2));
+        assert_eq!(list.iter().collect::<Vec<u8>>(), vec![3, 1, 3]);
+        // not allocated by the C side
+        list.0 = std::ptr::null_mut();
+    }
+
+    #[test]
+    fn test_borrowed_input() {
+        let buf = [1, 2, 3, 4];
+        let ss = StreamSlice::from_slice(&buf, core::STREAM_TOCLIENT, 0);
+        let input = BorrowedInput::from(&ss);
+        assert!(!input.is_gap());
+        assert_eq!(input.direction(), Direction::ToClient);
+        assert_eq!(input.len(), 4);
+        assert_eq!(input.get(1..3), Some(&buf[1..3]));
+        assert_eq!(input.get(3..5), None);
+        let rem = input.advance(3).unwrap();
+        assert_eq!(rem.as_slice(), &[4]);
+        assert_eq!(rem.flags(), core::STREAM_TOCLIENT);
+        assert_eq!(input.consumed(rem.as_slice()), 3);
+        assert!(input.advance(5).is_none());
+
+        let gap = StreamSlice {
+            input: std::ptr::null(),
+            input_len: 1000,
+            flags: core::STREAM_TOSERVER | core::STREAM_GAP,
+            offset: 0,
+        };
+        let input = BorrowedInput::from(&gap);
+        assert!(input.is_gap());
+        assert_eq!(input.gap_size(), 1000);
+        assert!(input.is_empty());
+        assert_eq!(input.get(0..1), None);
+    }
+
+    #[test]
+    fn test_request_protocol_change_no_flow() {
+        assert!(!applayer_request_protocol_change(
+            std::ptr::null(),
+            443,
+            core::ALPROTO_UNKNOWN
+        ));
+        assert!(!applayer_request_tls_upgrade(std::ptr::null()));
+    }
+
+    #[test]
+    fn test_result_error() {
+        // the status values are shared with the C parsers
+        assert_eq!(AppLayerResult::err().status, -1);
+        assert_eq!(AppLayerResult::error(AppLayerError::Gap).status, -2);
+        assert_eq!(AppLayerResult::error(AppLayerError::Memcap).status, -3);
+        assert_eq!(AppLayerResult::error(AppLayerError::Internal).status, -4);
+        assert!(!AppLayerResult::error(AppLayerError::Gap).is_incomplete());
+    }
+
+    #[test]
+    fn test_parser_registrations() {
+        let mut registrations = ParserRegistrations::default();
+        assert_eq!(registrations.record("dns", core::IPPROTO_UDP, 0), Ok(()));
+        assert_eq!(
+            registrations.record("dns", core::IPPROTO_TCP, -1),
+            Err(RegistrationError::ParserRegistration(-1))
+        );
+        assert_eq!(registrations.0.len(), 2);
+        assert_eq!(
+            RegistrationError::ParserRegistration(-1).to_string(),
+            "parser registration failed with -1"
+        );
+
+        let mut js = JsonBuilder::try_new_array().unwrap();
+        registrations.to_json(&mut js).unwrap();
+        js.close().unwrap();
+        let buf = unsafe { std::slice::from_raw_parts(jb_ptr(&mut js), jb_len(&js)) };
+        assert_eq!(
+            std::str::from_utf8(buf).unwrap(),
+            r#"[{"name":"dns","ipproto":"udp","status":"registered"},{"name":"dns","ipproto":"tcp","status":"failed","error":-1}]"#
+        );
+    }
+
+    #[test]
+    fn test_gap_stats() {
+        let mut stats = GapStats::default();
+        assert!(stats.is_empty());
+        stats.resync();
+        assert!(stats.is_empty());
+
+        stats.gap();
+        stats.gap();
+        assert!(stats.is_pending());
+        assert_eq!(stats.skip(10), 10);
+        assert_eq!(stats.skip(5), 15);
+        stats.resync();
+        assert!(!stats.is_pending());
+        assert_eq!(stats.recovered, 1);
+
+        stats.gap();
+        assert_eq!(stats.skip(1), 1);
+        stats.give_up();
+        stats.give_up();
+        assert_eq!(stats.unrecoverable, 1);
+        assert_eq!(stats.recovered, 1);
+    }
+
+    #[test]
+    fn test_limits_append_buffer() {
+        let limits = AppLayerLimits::new(1024);
+        let mut state_mem = 0;
+        let mut buf1 = Vec::new();
+        assert!(limits.append_buffer(&mut buf1, &[0; 100], &mut state_mem));
+        assert_eq!(state_mem, 100);
+
+        limits.max_buffer_per_tx.store(64, Ordering::Relaxed);
+        limits.max_total_state_mem.store(150, Ordering::Relaxed);
+        let mut buf2 = vec![0; 60];
+        assert!(!limits.append_buffer(&mut buf2, &[1; 8], &mut state_mem));
+        assert_eq!(buf2.len(), 64);
+        assert_eq!(state_mem, 104);
+
+        let mut buf3 = Vec::new();
+        assert!(!limits.append_buffer(&mut buf3, &[2; 50], &mut state_mem));
+        assert_eq!(buf3.len(), 46);
+        assert_eq!(state_mem, 150);
+        assert!(!limits.append_buffer(&mut buf3, &[2; 1], &mut state_mem));
+        assert!(limits.append_buffer(&mut buf3, &[], &mut state_mem));
+        assert_eq!(state_mem, 150);
+    }
+}
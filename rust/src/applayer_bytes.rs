@@ -0,0 +1,487 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Counters of the bytes passed to the Rust app-layer parsers.
+//!
+//! Enabled with `stats.app-layer-bytes`, the bytes passed to each
+//! registered Rust parser are counted per direction in the
+//! `app_layer.bytes.<proto>.toserver` and `app_layer.bytes.<proto>.toclient`
+//! stats counters. Like for the `app_layer.flow` counters, a protocol with a
+//! parser for both TCP and UDP gets a `_tcp` or `_udp` suffix.
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::conf::conf_get_bool;
+use crate::core::{AppProto, IPPROTO_TCP, IPPROTO_UDP, STREAM_TOSERVER};
+
+/// cbindgen:ignore
+extern "C" {
+    fn StatsRegisterCounter(name: *const c_char, tv: *mut c_void) -> u16;
+    fn StatsAddUI64(tv: *mut c_void, id: u16, x: u64);
+}
+
+static APP_LAYER_BYTES_ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref PARSER_BYTES: RwLock<ParserBytesCounters> =
+        RwLock::new(ParserBytesCounters::default());
+}
+
+#[derive(Debug)]
+struct ParserBytes {
+    name: String,
+    ipproto: u8,
+    alproto: AppProto,
+    /// Counter names, to server first. The stats API keeps a pointer to
+    /// them, so they live as long as the counters do.
+    counter_names: [CString; 2],
+    /// Counter ids, to server first. They are the same for all threads.
+    ids: [u16; 2],
+}
+
+#[derive(Debug, Default)]
+struct ParserBytesCounters {
+    parsers: Vec<ParserBytes>,
+}
+
+impl ParserBytesCounters {
+    fn register(&mut self, name: &str, ipproto: u8, alproto: AppProto) {
+        if self.find(ipproto, alproto).is_some() {
+            return;
+        }
+        self.parsers.push(ParserBytes {
+            name: name.to_string(),
+            ipproto,
+            alproto,
+            counter_names: Default::default(),
+            ids: [0, 0],
+        });
+    }
+
+    fn find(&self, ipproto: u8, alproto: AppProto) -> Option<&ParserBytes> {
+        self.parsers
+            .iter()
+            .find(|p| p.ipproto == ipproto && p.alproto == alproto)
+    }
+
+    /// Set the counter names, once all the parsers are registered.
+    fn setup(&mut self) {
+        let names: Vec<[CString; 2]> = self
+            .parsers
+            .iter()
+            .map(|p| {
+                let both = self
+                    .parsers
+                    .iter()
+                    .any(|o| o.alproto == p.alproto && o.ipproto != p.ipproto);
+                let proto = match (both, p.ipproto) {
+                    (true, IPPROTO_TCP) => format!("{}_tcp", p.name),
+                    (true, IPPROTO_UDP) => format!("{}_udp", p.name),
+                    _ => p.name.clone(),
+                };
+                ["toserver", "toclient"]
+                    .map(|dir| format!("app_layer.bytes.{}.{}", proto, dir))
+                    .map(|name| CString::new(name).unwrap_or_default())
+            })
+            .collect();
+        for (p, names) in self.parsers.iter_mut().zip(names) {
+            p.counter_names = names;
+        }
+    }
+}
+
+/// Record the registration of a Rust parser, to count the bytes passed
+/// to it.
+pub(crate) fn app_layer_bytes_register_parser(name: &str, ipproto: u8, alproto: AppProto) {
+    PARSER_BYTES
+        .write()
+        .unwrap_or_else(|err| err.into_inner())
+        .register(name, ipproto, alproto);
+}
+
+/// Set up the counters of the registered parsers if `stats.app-layer-bytes`
+/// is enabled. Called once the parsers are registered.
+#[no_mangle]
+pub extern "C" fn SCAppLayerBytesSetupCounters() {
+    if !conf_get_bool("stats.app-layer-bytes") {
+        return;
+    }
+    PARSER_BYTES
+        .write()
+        .unwrap_or_else(|err| err.into_inner())
+        .setup();
+    APP_LAYER_BYTES_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Register the counters for the thread `tv`.
+#[no_mangle]
+pub unsafe extern "C" fn SCAppLayerBytesRegisterThreadCounters(tv: *mut c_void) {
+    if !APP_LAYER_BYTES_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut counters = PARSER_BYTES.write().unwrap_or_else(|err| err.into_inner());
+    for p in counters.parsers.iter_mut() {
+        for (id, name) in p.ids.iter_mut().zip(p.counter_names.iter()) {
+            *id = StatsRegisterCounter(name.as_ptr(), tv);
+        }
+    }
+}
+
+/// Count `bytes` passed to the parser of `alproto` over `ipproto`, in the
+/// direction of the `STREAM_*` `flags`.
+#[no_mangle]
+pub unsafe extern "C" fn SCAppLayerBytesAdd(
+    tv: *mut c_void, ipproto: u8, alproto: AppProto, flags: u8, bytes: u32,
+) {
+    if tv.is_null() || bytes == 0 || !APP_LAYER_BYTES_ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    let counters = PARSER_BYTES.read().unwrap_or_else(|err| err.into_inner());
+    if let Some(p) = counters.find(ipproto, alproto) {
+        let id = p.ids[if flags & STREAM_TOSERVER != 0 { 0 } else { 1 }];
+        if id > 0 {
+            StatsAddUI64(tv, id, bytes as u64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonbuilder::{jb_len, jb_ptr, JsonBuilder};
+    use std::collections::BTreeMap;
+
+    /// The Rust parsers, as registered by the engine.
+    const RUST_PARSERS: &[(&str, u8)] = &[
+        ("bittorrent-dht", IPPROTO_UDP),
+        ("dcerpc", IPPROTO_TCP),
+        ("dcerpc", IPPROTO_UDP),
+        ("dhcp", IPPROTO_UDP),
+        ("dhcpv6", IPPROTO_UDP),
+        ("dns", IPPROTO_TCP),
+        ("dns", IPPROTO_UDP),
+        ("enip", IPPROTO_TCP),
+        ("enip", IPPROTO_UDP),
+        ("ftp-data", IPPROTO_TCP),
+        ("http2", IPPROTO_TCP),
+        ("ike", IPPROTO_UDP),
+        ("imap", IPPROTO_TCP),
+        ("krb5", IPPROTO_TCP),
+        ("krb5", IPPROTO_UDP),
+        ("ldap", IPPROTO_TCP),
+        ("ldap", IPPROTO_UDP),
+        ("modbus", IPPROTO_TCP),
+        ("mqtt", IPPROTO_TCP),
+        ("nfs", IPPROTO_TCP),
+        ("nfs", IPPROTO_UDP),
+        ("ntp", IPPROTO_UDP),
+        ("openvpn", IPPROTO_TCP),
+        ("openvpn", IPPROTO_UDP),
+        ("pgsql", IPPROTO_TCP),
+        ("pop3", IPPROTO_TCP),
+        ("quic", IPPROTO_UDP),
+        ("rdp", IPPROTO_TCP),
+        ("rfb", IPPROTO_TCP),
+        ("rsync", IPPROTO_TCP),
+        ("rtp", IPPROTO_UDP),
+        ("sip", IPPROTO_TCP),
+        ("sip", IPPROTO_UDP),
+        ("smb", IPPROTO_TCP),
+        ("snmp", IPPROTO_UDP),
+        ("ssh", IPPROTO_TCP),
+        ("stun", IPPROTO_UDP),
+        ("syslog", IPPROTO_TCP),
+        ("syslog", IPPROTO_UDP),
+        ("telnet", IPPROTO_TCP),
+        ("websocket", IPPROTO_TCP),
+        ("wireguard", IPPROTO_UDP),
+    ];
+
+    #[derive(Debug)]
+    enum Json {
+        Null,
+        Bool(bool),
+        Number,
+        String(String),
+        Array(Vec<Json>),
+        Object(BTreeMap<String, Json>),
+    }
+
+    /// Minimal JSON reader, enough to read the schema and the records.
+    struct Reader<'a> {
+        input: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Reader<'a> {
+        fn parse(input: &'a str) -> Json {
+            let mut reader = Reader {
+                input: input.as_bytes(),
+                pos: 0,
+            };
+            let value = reader.value();
+            reader.ws();
+            assert_eq!(reader.pos, reader.input.len(), "trailing data");
+            value
+        }
+
+        fn ws(&mut self) {
+            while self.pos < self.input.len() && self.input[self.pos].is_ascii_whitespace() {
+                self.pos += 1;
+            }
+        }
+
+        fn next(&mut self) -> u8 {
+            self.ws();
+            let c = self.input[self.pos];
+            self.pos += 1;
+            c
+        }
+
+        fn value(&mut self) -> Json {
+            match self.next() {
+                b'{' => {
+                    let mut map = BTreeMap::new();
+                    if self.next() == b'}' {
+                        return Json::Object(map);
+                    }
+                    self.pos -= 1;
+                    loop {
+                        assert_eq!(self.next(), b'"');
+                        let key = self.string();
+                        assert_eq!(self.next(), b':');
+                        map.insert(key, self.value());
+                        match self.next() {
+                            b',' => continue,
+                            b'}' => return Json::Object(map),
+                            c => panic!("unexpected {} at {}", c as char, self.pos),
+                        }
+                    }
+                }
+                b'[' => {
+                    let mut items = Vec::new();
+                    if self.next() == b']' {
+                        return Json::Array(items);
+                    }
+                    self.pos -= 1;
+                    loop {
+                        items.push(self.value());
+                        match self.next() {
+                            b',' => continue,
+                            b']' => return Json::Array(items),
+                            c => panic!("unexpected {} at {}", c as char, self.pos),
+                        }
+                    }
+                }
+                b'"' => Json::String(self.string()),
+                b't' | b'f' | b'n' => {
+                    let start = self.pos - 1;
+                    while self.pos < self.input.len() && self.input[self.pos].is_ascii_alphabetic()
+                    {
+                        self.pos += 1;
+                    }
+                    match &self.input[start..self.pos] {
+                        b"true" => Json::Bool(true),
+                        b"false" => Json::Bool(false),
+                        b"null" => Json::Null,
+                        _ => panic!("invalid literal at {}", start),
+                    }
+                }
+                _ => {
+                    while self.pos < self.input.len()
+                        && matches!(
+                            self.input[self.pos],
+                            b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E'
+                        )
+                    {
+                        self.pos += 1;
+                    }
+                    Json::Number
+                }
+            }
+        }
+
+        fn string(&mut self) -> String {
+            let mut s = Vec::new();
+            loop {
+                let c = self.input[self.pos];
+                self.pos += 1;
+                match c {
+                    b'"' => return String::from_utf8(s).unwrap(),
+                    b'\\' => {
+                        let e = self.input[self.pos];
+                        self.pos += 1;
+                        match e {
+                            b'u' => {
+                                let hex = std::str::from_utf8(&self.input[self.pos..self.pos + 4]);
+                                let c = u32::from_str_radix(hex.unwrap(), 16).unwrap();
+                                self.pos += 4;
+                                let c = char::from_u32(c).unwrap_or('\u{fffd}');
+                                s.extend_from_slice(c.to_string().as_bytes());
+                            }
+                            b'n' => s.push(b'\n'),
+                            b't' => s.push(b'\t'),
+                            _ => s.push(e),
+                        }
+                    }
+                    _ => s.push(c),
+                }
+            }
+        }
+    }
+
+    fn get<'a>(value: &'a Json, key: &str) -> Option<&'a Json> {
+        match value {
+            Json::Object(map) => map.get(key),
+            _ => None,
+        }
+    }
+
+    /// Check `value` against `schema`, for the keywords used by the
+    /// schema of the stats records.
+    fn validate(root: &Json, schema: &Json, value: &Json, path: &str) -> Result<(), String> {
+        if let Some(Json::String(r)) = get(schema, "$ref") {
+            let name = r.strip_prefix("#/$defs/").unwrap();
+            let def = get(get(root, "$defs").unwrap(), name).unwrap();
+            return validate(root, def, value, path);
+        }
+        if let Some(Json::String(t)) = get(schema, "type") {
+            let ok = matches!(
+                (t.as_str(), value),
+                ("object", Json::Object(_))
+                    | ("integer" | "number", Json::Number)
+                    | ("string", Json::String(_))
+                    | ("array", Json::Array(_))
+                    | ("boolean", Json::Bool(_))
+            );
+            if !ok {
+                return Err(format!("{}: not of type {}", path, t));
+            }
+        }
+        if let (Json::Array(items), Some(s)) = (value, get(schema, "items")) {
+            for (i, v) in items.iter().enumerate() {
+                validate(root, s, v, &format!("{}[{}]", path, i))?;
+            }
+        }
+        if let Json::Object(map) = value {
+            let props = get(schema, "properties");
+            for (key, v) in map {
+                let path = format!("{}.{}", path, key);
+                match props.and_then(|p| get(p, key)) {
+                    Some(s) => validate(root, s, v, &path)?,
+                    None => {
+                        if let Some(Json::Bool(false)) = get(schema, "additionalProperties") {
+                            return Err(format!("{}: not in the schema", path));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the stats record for the counters like the stats logger does,
+    /// splitting the counter names on the dots.
+    fn stats_record(counters: &ParserBytesCounters) -> String {
+        let mut tree: BTreeMap<String, BTreeMap<String, u64>> = BTreeMap::new();
+        for p in &counters.parsers {
+            for name in &p.counter_names {
+                let name = name.to_str().unwrap();
+                let parts: Vec<&str> = name.split('.').collect();
+                assert_eq!(&parts[..2], &["app_layer", "bytes"]);
+                tree.entry(parts[2].to_string())
+                    .or_default()
+                    .insert(parts[3].to_string(), 1500);
+            }
+        }
+        let mut js = JsonBuilder::try_new_object().unwrap();
+        js.set_string("event_type", "stats").unwrap();
+        js.open_object("stats").unwrap();
+        js.open_object("app_layer").unwrap();
+        js.open_object("bytes").unwrap();
+        for (proto, dirs) in &tree {
+            js.open_object(proto).unwrap();
+            for (dir, val) in dirs {
+                js.set_uint(dir, *val).unwrap();
+            }
+            js.close().unwrap();
+        }
+        js.close().unwrap();
+        js.close().unwrap();
+        js.close().unwrap();
+        js.close().unwrap();
+        let buf = unsafe { std::slice::from_raw_parts(jb_ptr(&mut js), jb_len(&js)) };
+        std::str::from_utf8(buf).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_app_layer_bytes_names() {
+        let mut counters = ParserBytesCounters::default();
+        counters.register("dns", IPPROTO_TCP, 1);
+        counters.register("smb", IPPROTO_TCP, 2);
+        counters.register("dns", IPPROTO_UDP, 1);
+        counters.register("dns", IPPROTO_UDP, 1);
+        counters.setup();
+        assert_eq!(counters.parsers.len(), 3);
+        let names: Vec<&str> = counters
+            .parsers
+            .iter()
+            .flat_map(|p| p.counter_names.iter().map(|n| n.to_str().unwrap()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "app_layer.bytes.dns_tcp.toserver",
+                "app_layer.bytes.dns_tcp.toclient",
+                "app_layer.bytes.smb.toserver",
+                "app_layer.bytes.smb.toclient",
+                "app_layer.bytes.dns_udp.toserver",
+                "app_layer.bytes.dns_udp.toclient",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_app_layer_bytes_schema() {
+        let mut counters = ParserBytesCounters::default();
+        let mut alproto = 0;
+        for (i, (name, ipproto)) in RUST_PARSERS.iter().enumerate() {
+            if i == 0 || RUST_PARSERS[i - 1].0 != *name {
+                alproto += 1;
+            }
+            counters.register(name, *ipproto, alproto);
+        }
+        counters.setup();
+
+        let schema = Reader::parse(include_str!("../../etc/schema.json"));
+        let record = stats_record(&counters);
+        let value = Reader::parse(&record);
+        assert_eq!(validate(&schema, &schema, &value, ""), Ok(()));
+
+        // the schema does not accept counters it does not describe
+        let value = Reader::parse(r#"{"stats":{"app_layer":{"bytes":{"nope":{"toserver":1}}}}}"#);
+        assert!(validate(&schema, &schema, &value, "").is_err());
+        let value =
+            Reader::parse(r#"{"stats":{"app_layer":{"bytes":{"dns_udp":{"toserver":"1"}}}}}"#);
+        assert!(validate(&schema, &schema, &value, "").is_err());
+    }
+}
@@ -155,7 +155,7 @@ impl TemplateState {
                     }
                     self.transactions.push_back(tx);
                     if self.transactions.len() >= unsafe {TEMPLATE_MAX_TX} {
-                        return AppLayerResult::err();
+                        return AppLayerResult::error(AppLayerError::Memcap);
                     }
                 }
                 Err(nom::Err::Incomplete(_)) => {
@@ -402,7 +402,7 @@ pub unsafe extern "C" fn rs_template_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_TEMPLATE = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.template.max-tx") {
             if let Ok(v) = val.parse::<usize>() {
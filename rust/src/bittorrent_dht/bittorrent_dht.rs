@@ -17,19 +17,32 @@
 
 use crate::applayer::{self, *};
 use crate::bittorrent_dht::parser::{
-    parse_bittorrent_dht_packet, BitTorrentDHTError, BitTorrentDHTRequest, BitTorrentDHTResponse,
+    bittorrent_dht_probe, parse_bittorrent_dht_packet, BitTorrentDHTError, BitTorrentDHTRequest,
+    BitTorrentDHTResponse,
 };
-use crate::core::{AppProto, Flow, ALPROTO_UNKNOWN, IPPROTO_UDP, Direction};
+use crate::common::to_hex;
+use crate::core::{
+    AppProto, Direction, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, DIR_BOTH, IPPROTO_UDP,
+};
+use crate::datasets::{DatasetConfig, DatasetType};
 use std::ffi::CString;
 use std::os::raw::c_char;
 
-const BITTORRENT_DHT_PAYLOAD_PREFIX: &[u8] = b"d1:ad2:id20:\0";
+/// Start of a bencoded dictionary with a single character first key,
+/// which is "a", "e" or "r" as keys are sorted.
+const BITTORRENT_DHT_PAYLOAD_PREFIX: &[u8] = b"d1:\0";
+/// Start of a response with the BEP 42 "ip" key.
+const BITTORRENT_DHT_PAYLOAD_PREFIX_IP: &[u8] = b"d2:ip\0";
 
 static mut ALPROTO_BITTORRENT_DHT: AppProto = ALPROTO_UNKNOWN;
+static BITTORRENT_DHT_LIMITS: AppLayerLimits = AppLayerLimits::new(1024);
+/// Dataset to add the info_hash of get_peers and announce_peer queries to.
+static INFO_HASH_DATASET: DatasetConfig = DatasetConfig::new();
 
 #[derive(AppLayerEvent, Debug, PartialEq, Eq)]
 pub enum BitTorrentDHTEvent {
     MalformedPacket,
+    TooManyTransactions,
 }
 
 #[derive(Default)]
@@ -40,7 +53,14 @@ pub struct BitTorrentDHTTransaction {
     pub response: Option<BitTorrentDHTResponse>,
     pub error: Option<BitTorrentDHTError>,
     pub transaction_id: Vec<u8>,
+    /// Client version of the query, or of the reply without a query.
     pub client_version: Option<Vec<u8>>,
+    /// Client version of the reply to the query.
+    pub reply_client_version: Option<Vec<u8>>,
+    /// Direction of the query, a reply comes from the other direction.
+    query_direction: Option<Direction>,
+    /// The query got a reply, or the tx does not wait for one.
+    complete: bool,
 
     tx_data: AppLayerTxData,
 }
@@ -55,7 +75,21 @@ impl BitTorrentDHTTransaction {
 
     /// Set an event on the transaction
     pub fn set_event(&mut self, event: BitTorrentDHTEvent) {
-        self.tx_data.set_event(event as u8);
+        self.tx_data.events.set_event(event as u8);
+    }
+}
+
+impl TxLimit for BitTorrentDHTTransaction {
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn force_complete(&mut self) {
+        self.complete = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
     }
 }
 
@@ -63,6 +97,7 @@ impl BitTorrentDHTTransaction {
 pub struct BitTorrentDHTState {
     tx_id: u64,
     transactions: Vec<BitTorrentDHTTransaction>,
+    tx_index_completed: usize,
     state_data: AppLayerStateData,
 }
 
@@ -74,17 +109,23 @@ impl BitTorrentDHTState {
     // Free a transaction by ID.
     fn free_tx(&mut self, tx_id: u64) {
         self.transactions.retain(|tx| tx.tx_id != tx_id + 1);
+        self.tx_index_completed = 0;
     }
 
     pub fn get_tx(&mut self, tx_id: u64) -> Option<&BitTorrentDHTTransaction> {
         self.transactions.iter().find(|&tx| tx.tx_id == tx_id + 1)
     }
 
-    fn new_tx(&mut self, direction: Direction) -> BitTorrentDHTTransaction {
-        let mut tx = BitTorrentDHTTransaction::new(direction);
-        self.tx_id += 1;
-        tx.tx_id = self.tx_id;
-        return tx;
+    /// Find the unanswered query of the other direction a reply with
+    /// `transaction_id` is for.
+    fn find_query(
+        &mut self, transaction_id: &[u8], direction: Direction,
+    ) -> Option<&mut BitTorrentDHTTransaction> {
+        self.transactions.iter_mut().find(|tx| {
+            !tx.complete
+                && matches!(tx.query_direction, Some(d) if d != direction)
+                && tx.transaction_id == transaction_id
+        })
     }
 
     fn is_dht(input: &[u8]) -> bool {
@@ -98,19 +139,53 @@ impl BitTorrentDHTState {
         }
     }
 
-    pub fn parse(&mut self, input: &[u8], _direction: crate::core::Direction) -> bool {
+    pub fn parse(&mut self, input: BorrowedInput) -> bool {
+        let direction = input.direction();
+        let input = input.as_slice();
         if !Self::is_dht(input) {
             return true;
         }
-        let mut tx = self.new_tx(_direction);
+        let mut tx = BitTorrentDHTTransaction::new(direction);
         let mut status = true;
 
         if let Err(_e) = parse_bittorrent_dht_packet(input, &mut tx) {
             status = false;
             tx.set_event(BitTorrentDHTEvent::MalformedPacket);
             SCLogDebug!("BitTorrent DHT Parsing Error: {}", _e);
+            tx.complete = true;
+        } else if tx.request.is_some() {
+            // the reply is added to the tx of the query
+            tx.tx_data = AppLayerTxData::new();
+            tx.query_direction = Some(direction);
+        } else if let Some(query) = self.find_query(&tx.transaction_id, direction) {
+            query.response = tx.response.take();
+            query.error = tx.error.take();
+            query.reply_client_version = tx.client_version.take();
+            query.complete = true;
+            return status;
+        } else {
+            // The reply without a query is logged on its own.
+            tx.complete = true;
+        }
+        self.tx_id += 1;
+        tx.tx_id = self.tx_id;
+
+        if let Some(info_hash) = tx.request.as_ref().and_then(|r| r.info_hash.as_ref()) {
+            if let Some(set) = INFO_HASH_DATASET.find(&[DatasetType::String]) {
+                set.add(to_hex(info_hash).as_bytes());
+            }
         }
 
+        if applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            BITTORRENT_DHT_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            BitTorrentDHTEvent::TooManyTransactions as u8,
+        ) {
+            SCLogDebug!("BitTorrent DHT: too many transactions");
+        }
         self.transactions.push(tx);
 
         return status;
@@ -168,9 +243,7 @@ pub unsafe extern "C" fn rs_bittorrent_dht_parse_ts(
     _flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice, _data: *const std::os::raw::c_void,
 ) -> AppLayerResult {
-    return rs_bittorrent_dht_parse(
-        _flow, state, _pstate, stream_slice,
-        _data, crate::core::Direction::ToServer);
+    return rs_bittorrent_dht_parse(_flow, state, _pstate, stream_slice, _data);
 }
 
 #[no_mangle]
@@ -178,20 +251,16 @@ pub unsafe extern "C" fn rs_bittorrent_dht_parse_tc(
     _flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice, _data: *const std::os::raw::c_void,
 ) -> AppLayerResult {
-    return rs_bittorrent_dht_parse(
-        _flow, state, _pstate, stream_slice,
-        _data, crate::core::Direction::ToClient);
+    return rs_bittorrent_dht_parse(_flow, state, _pstate, stream_slice, _data);
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_bittorrent_dht_parse(
     _flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice, _data: *const std::os::raw::c_void,
-    direction: crate::core::Direction,
 ) -> AppLayerResult {
     let state = cast_pointer!(state, BitTorrentDHTState);
-    let buf = stream_slice.as_slice();
-    state.parse(buf, direction).into()
+    state.parse(BorrowedInput::from(&stream_slice)).into()
 }
 
 #[no_mangle]
@@ -222,10 +291,7 @@ pub unsafe extern "C" fn rs_bittorrent_dht_tx_get_alstate_progress(
     tx: *mut std::os::raw::c_void, _direction: u8,
 ) -> std::os::raw::c_int {
     let tx = cast_pointer!(tx, BitTorrentDHTTransaction);
-
-    // Transaction is done if we have a request, response, or error since
-    // a new transaction is created for each received packet
-    if tx.request.is_some() || tx.response.is_some() || tx.error.is_some() {
+    if tx.complete {
         return 1;
     }
     return 0;
@@ -249,6 +315,32 @@ pub unsafe extern "C" fn rs_bittorrent_dht_state_get_tx_iterator(
     }
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn rs_bittorrent_dht_probing_parser(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() || input_len == 0 {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    let is_query = match bittorrent_dht_probe(slice) {
+        Some(b'q') => true,
+        Some(_) => false,
+        None => {
+            return ALPROTO_FAILED;
+        }
+    };
+    let dir: Direction = (direction & DIR_BOTH).into();
+    if is_query {
+        if dir != Direction::ToServer {
+            *rdir = Direction::ToServer.into();
+        }
+    } else if dir != Direction::ToClient {
+        *rdir = Direction::ToClient.into();
+    }
+    return ALPROTO_BITTORRENT_DHT;
+}
+
 // Parser name as a C style string.
 const PARSER_NAME: &[u8] = b"bittorrent-dht\0";
 
@@ -281,7 +373,7 @@ pub unsafe extern "C" fn rs_bittorrent_dht_udp_register_parser() {
         get_tx_data: rs_bittorrent_dht_get_tx_data,
         get_state_data: rs_bittorrent_dht_get_state_data,
         apply_tx_config: None,
-        flags: 0,
+        flags: APP_LAYER_PARSER_OPT_FIRST_DATA_ONLY,
         get_frame_id_by_name: None,
         get_frame_name_by_id: None,
     };
@@ -292,35 +384,84 @@ pub unsafe extern "C" fn rs_bittorrent_dht_udp_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_BITTORRENT_DHT = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
 
-        if AppLayerProtoDetectPMRegisterPatternCS(
-            IPPROTO_UDP,
-            ALPROTO_BITTORRENT_DHT,
-            BITTORRENT_DHT_PAYLOAD_PREFIX.as_ptr() as *const c_char,
-            BITTORRENT_DHT_PAYLOAD_PREFIX.len() as u16 - 1,
-            0,
-            crate::core::Direction::ToServer.into(),
-        ) < 0
-        {
-            SCLogDebug!("Failed to register protocol detection pattern for direction TOSERVER");
-        };
-        if AppLayerProtoDetectPMRegisterPatternCS(
+        for prefix in [
+            BITTORRENT_DHT_PAYLOAD_PREFIX,
+            BITTORRENT_DHT_PAYLOAD_PREFIX_IP,
+        ] {
+            for direction in [Direction::ToServer, Direction::ToClient] {
+                if AppLayerProtoDetectPMRegisterPatternCSwPP(
+                    IPPROTO_UDP,
+                    ALPROTO_BITTORRENT_DHT,
+                    prefix.as_ptr() as *const c_char,
+                    prefix.len() as u16 - 1,
+                    0,
+                    direction.into(),
+                    rs_bittorrent_dht_probing_parser,
+                    0,
+                    0,
+                ) < 0
+                {
+                    SCLogDebug!("Failed to register protocol detection pattern");
+                }
+            }
+        }
+        // Detection ports are optional, the patterns work on any port.
+        applayer_register_probing_ports(
             IPPROTO_UDP,
+            parser.name,
             ALPROTO_BITTORRENT_DHT,
-            BITTORRENT_DHT_PAYLOAD_PREFIX.as_ptr() as *const c_char,
-            BITTORRENT_DHT_PAYLOAD_PREFIX.len() as u16 - 1,
             0,
-            crate::core::Direction::ToClient.into(),
-        ) < 0
-        {
-            SCLogDebug!("Failed to register protocol detection pattern for direction TOCLIENT");
-        }
+            16,
+            rs_bittorrent_dht_probing_parser,
+            rs_bittorrent_dht_probing_parser,
+            None,
+        );
         AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_BITTORRENT_DHT);
 
+        BITTORRENT_DHT_LIMITS.configure("bittorrent-dht");
+        INFO_HASH_DATASET.configure("app-layer.protocols.bittorrent-dht.datasets.info-hash");
+
         SCLogDebug!("Parser registered for bittorrent-dht.");
     } else {
         SCLogDebug!("Protocol detector and parser disabled for bittorrent-dht.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{STREAM_TOCLIENT, STREAM_TOSERVER};
+
+    #[test]
+    fn test_bittorrent_dht_pair_query_reply() {
+        let mut state = BitTorrentDHTState::new();
+        let query = b"d1:ad2:id20:abcdefghij0123456789e1:q4:ping1:t2:aa1:y1:qe";
+        let reply = b"d1:rd2:id20:mnopqrstuvwxyz123456e1:t2:aa1:y1:re";
+        let error = b"d1:eli201e23:A Generic Error Ocurrede1:t2:bb1:y1:ee";
+
+        assert!(state.parse(BorrowedInput::from_slice(query, STREAM_TOSERVER)));
+        assert_eq!(state.transactions.len(), 1);
+        assert!(!state.transactions[0].complete);
+
+        // a reply of the same direction is not for the query
+        assert!(state.parse(BorrowedInput::from_slice(reply, STREAM_TOSERVER)));
+        assert_eq!(state.transactions.len(), 2);
+        assert!(state.transactions[1].complete);
+
+        assert!(state.parse(BorrowedInput::from_slice(reply, STREAM_TOCLIENT)));
+        assert_eq!(state.transactions.len(), 2);
+        let tx = &state.transactions[0];
+        assert!(tx.complete);
+        assert!(tx.request.is_some());
+        assert!(tx.response.is_some());
+
+        // an error without a query
+        assert!(state.parse(BorrowedInput::from_slice(error, STREAM_TOCLIENT)));
+        assert_eq!(state.transactions.len(), 3);
+        assert_eq!(state.tx_id, 3);
+        assert!(state.transactions[2].error.is_some());
+    }
+}
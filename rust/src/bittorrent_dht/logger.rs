@@ -53,6 +53,9 @@ fn log_bittorrent_dht(
     if let Some(client_version) = &tx.client_version {
         js.set_hex("client_version", client_version)?;
     }
+    if let Some(client_version) = &tx.reply_client_version {
+        js.set_hex("reply_client_version", client_version)?;
+    }
     if let Some(request_type) = &tx.request_type {
         js.set_string("request_type", request_type)?;
     }
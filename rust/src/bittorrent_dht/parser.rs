@@ -443,6 +443,22 @@ pub fn parse_bittorrent_dht_packet(
     Ok(())
 }
 
+/// Lightweight check for a DHT message: a bencoded top level dictionary
+/// with a "y" key of "q", "r" or "e". Returns the message type.
+pub fn bittorrent_dht_probe(bytes: &[u8]) -> Option<u8> {
+    let mut decoder = Decoder::new(bytes).with_max_depth(3);
+    let mut dict_dec = decoder.next_object().ok()??.try_into_dictionary().ok()?;
+    while let Ok(Some(pair)) = dict_dec.next_pair() {
+        if let (b"y", value) = pair {
+            return match value.try_into_bytes() {
+                Ok(&[t @ (b'q' | b'r' | b'e')]) => Some(t),
+                _ => None,
+            };
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -660,4 +676,16 @@ mod tests {
         let (rem, _node) = parse_node(bytes).unwrap();
         assert_eq!(rem, b"bb");
     }
+
+    #[test_case(b"d1:ad2:id20:abcdefghij0123456789e1:q4:ping1:t2:aa1:y1:qe", Some(b'q') ; "test probe request")]
+    #[test_case(b"d1:rd2:id20:mnopqrstuvwxyz123456e1:t2:aa1:y1:re", Some(b'r') ; "test probe response")]
+    #[test_case(b"d2:ip6:\x01\x02\x03\x04\x05\x061:rd2:id20:mnopqrstuvwxyz123456e1:t2:aa1:y1:re", Some(b'r') ; "test probe response with ip")]
+    #[test_case(b"d1:eli201e23:A Generic Error Ocurrede1:t2:aa1:y1:ee", Some(b'e') ; "test probe error")]
+    #[test_case(b"d1:rd2:id20:mnopqrstuvwxyz123456e1:t2:aa1:y1:xe", None ; "test probe bad type")]
+    #[test_case(b"d1:rd2:id20:mnopqrstuvwxyz123456e1:t2:aae", None ; "test probe no type")]
+    #[test_case(b"d1:rd2:id20:mnopqrst", None ; "test probe truncated")]
+    #[test_case(b"l1:y1:qe", None ; "test probe not a dict")]
+    fn test_bittorrent_dht_probe(encoded: &[u8], expected: Option<u8>) {
+        assert_eq!(bittorrent_dht_probe(encoded), expected);
+    }
 }
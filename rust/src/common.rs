@@ -17,8 +17,10 @@
 
 //! Utility library module for commonly used strings, hexadecimals and other elements.
 
+use self::nom7::take_until_and_consume;
 use super::build_slice;
 use crate::jsonbuilder::HEX;
+use ::nom7::IResult;
 use std::ffi::CString;
 use std::os::raw::c_char;
 
@@ -169,3 +171,69 @@ pub unsafe extern "C" fn rs_to_hex_sep(
     // overwrites last separator with final null char
     oslice[3 * islice.len() - 1] = 0;
 }
+
+/// Read a single newline terminated line, without the line terminator.
+pub fn parse_line(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (i, line) = take_until_and_consume(b"\n")(i)?;
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    Ok((i, line))
+}
+
+/// Split the first space separated word of `i`.
+pub fn split_word(i: &[u8]) -> (&[u8], &[u8]) {
+    match i.iter().position(|&c| c == b' ') {
+        Some(p) => (&i[..p], &i[p + 1..]),
+        None => (i, &[]),
+    }
+}
+
+/// Return true for loopback, private and link local addresses.
+pub fn is_local_address(addr: &std::net::IpAddr) -> bool {
+    match addr {
+        std::net::IpAddr::V4(addr) => {
+            addr.is_loopback() || addr.is_private() || addr.is_link_local()
+        }
+        std::net::IpAddr::V6(addr) => {
+            let segment = addr.segments()[0];
+            addr.is_loopback() || (segment & 0xfe00) == 0xfc00 || (segment & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let (rem, line) = parse_line(b"USER alice\r\nPASS").unwrap();
+        assert_eq!(rem, b"PASS");
+        assert_eq!(line, b"USER alice");
+        let (rem, line) = parse_line(b"@RSYNCD: OK\n").unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(line, b"@RSYNCD: OK");
+        assert!(matches!(
+            parse_line(b"a001 LOG"),
+            Err(::nom7::Err::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_split_word() {
+        assert_eq!(
+            split_word(b"a001 LOGIN alice"),
+            (&b"a001"[..], &b"LOGIN alice"[..])
+        );
+        assert_eq!(split_word(b"NOOP"), (&b"NOOP"[..], &b""[..]));
+    }
+
+    #[test]
+    fn test_is_local_address() {
+        assert!(is_local_address(&"192.168.1.10".parse().unwrap()));
+        assert!(is_local_address(&"127.0.0.1".parse().unwrap()));
+        assert!(is_local_address(&"fd00::1".parse().unwrap()));
+        assert!(is_local_address(&"fe80::1".parse().unwrap()));
+        assert!(!is_local_address(&"8.8.8.8".parse().unwrap()));
+        assert!(!is_local_address(&"2001:db8::1".parse().unwrap()));
+    }
+}
@@ -20,10 +20,40 @@
 use std;
 use crate::filecontainer::*;
 use crate::debug_validate_fail;
+use crate::common::is_local_address;
 
 /// Opaque C types.
 pub enum DetectEngineState {}
-pub enum AppLayerDecoderEvents {}
+
+/// C AppLayerDecoderEvents, owned by the C side, see applayer::EventList.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AppLayerDecoderEvents {
+    events: *mut u8,
+    cnt: u8,
+    events_buffer_size: u8,
+    event_last_logged: u8,
+}
+
+impl AppLayerDecoderEvents {
+    /// Create events from a Rust slice. Useful in unit tests.
+    #[cfg(test)]
+    pub fn from_slice(events: &mut [u8]) -> Self {
+        Self {
+            events: events.as_mut_ptr(),
+            cnt: events.len() as u8,
+            events_buffer_size: events.len() as u8,
+            event_last_logged: 0,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        if self.events.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.events, self.cnt as usize) }
+    }
+}
 
 #[repr(C)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -311,8 +341,12 @@ pub enum Flow {}
 extern {
     pub fn FlowGetLastTimeAsParts(flow: &Flow, secs: *mut u64, usecs: *mut u64);
     pub fn FlowGetFlags(flow: &Flow) -> u32;
+    pub fn FlowGetFlowId(flow: &Flow) -> i64;
     pub fn FlowGetSourcePort(flow: &Flow) -> u16;
     pub fn FlowGetDestinationPort(flow: &Flow) -> u16;
+    pub fn FlowHasAddress(flow: &Flow, addr: *const u8, addr_len: u8) -> bool;
+    pub fn FlowSetParentId(flow: &Flow, id: i64);
+    pub fn FlowGetAddresses(flow: &Flow, src: *mut u8, dst: *mut u8) -> u8;
 }
 
 /// Rust implementation of Flow.
@@ -334,10 +368,54 @@ impl Flow {
         unsafe { FlowGetFlags(self) }
     }
 
+    /// Return the flow id, as logged in the EVE records.
+    pub fn get_id(&self) -> i64 {
+        unsafe { FlowGetFlowId(self) }
+    }
+
+    /// Set the id of the flow this one was expected by.
+    pub fn set_parent_id(&self, id: i64) {
+        unsafe { FlowSetParentId(self, id) }
+    }
+
     /// Return flow ports
     pub fn get_ports(&self) -> (u16, u16) {
         unsafe { (FlowGetSourcePort(self), FlowGetDestinationPort(self)) }
     }
+
+    /// Return true if the address is the source or destination of the flow.
+    pub fn has_address(&self, addr: &std::net::IpAddr) -> bool {
+        match addr {
+            std::net::IpAddr::V4(addr) => unsafe {
+                FlowHasAddress(self, addr.octets().as_ptr(), 4)
+            },
+            std::net::IpAddr::V6(addr) => unsafe {
+                FlowHasAddress(self, addr.octets().as_ptr(), 16)
+            },
+        }
+    }
+
+    /// Return the source and destination addresses of the flow.
+    pub fn get_addresses(&self) -> Option<(std::net::IpAddr, std::net::IpAddr)> {
+        let mut src = [0u8; 16];
+        let mut dst = [0u8; 16];
+        match unsafe { FlowGetAddresses(self, src.as_mut_ptr(), dst.as_mut_ptr()) } {
+            4 => {
+                let src: [u8; 4] = src[..4].try_into().unwrap();
+                let dst: [u8; 4] = dst[..4].try_into().unwrap();
+                Some((src.into(), dst.into()))
+            }
+            16 => Some((src.into(), dst.into())),
+            _ => None,
+        }
+    }
+
+    /// Return true if both addresses of the flow are loopback, private or
+    /// link local addresses, None if the addresses are not known.
+    pub fn is_local(&self) -> Option<bool> {
+        let (src, dst) = self.get_addresses()?;
+        Some(is_local_address(&src) && is_local_address(&dst))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,334 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Rust bindings to the datasets API.
+//!
+//! Allows parsers to add the values they observe to the datasets defined
+//! in the configuration, or to look them up, for correlation across
+//! flows.
+//!
+//! As the datasets module is a binding to a Suricata C module it is not
+//! available to Rust unit tests. Instead when running Rust unit tests an
+//! in memory "mock" version is provided, where a set is created the first
+//! time it is looked up.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_int, c_void};
+use std::sync::{Arc, RwLock};
+
+use crate::conf::conf_get;
+
+/// Dataset types supported by the bindings, values as in `enum DatasetTypes`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatasetType {
+    String = 1,
+    Md5 = 2,
+    Sha256 = 3,
+}
+
+impl DatasetType {
+    /// Length of the values of the type, None if not fixed.
+    fn value_len(&self) -> Option<usize> {
+        match self {
+            DatasetType::String => None,
+            DatasetType::Md5 => Some(16),
+            DatasetType::Sha256 => Some(32),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod mock {
+    use std::collections::HashSet;
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int, c_void};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref SETS: Mutex<Vec<(String, c_int, HashSet<Vec<u8>>)>> = Mutex::new(Vec::new());
+    }
+
+    pub static GENERATION: AtomicU32 = AtomicU32::new(0);
+
+    /// Find a set, creating it if needed. The handle of a set is its
+    /// index plus one.
+    pub unsafe fn DatasetFindRef(name: *const c_char, dtype: c_int) -> *mut c_void {
+        let name = CStr::from_ptr(name).to_string_lossy().to_string();
+        let mut sets = SETS.lock().unwrap();
+        let index = match sets.iter().position(|(n, _, _)| *n == name) {
+            Some(index) => {
+                if sets[index].1 != dtype {
+                    return std::ptr::null_mut();
+                }
+                index
+            }
+            None => {
+                sets.push((name, dtype, HashSet::new()));
+                sets.len() - 1
+            }
+        };
+        (index + 1) as *mut c_void
+    }
+
+    pub unsafe fn DatasetRelease(_set: *mut c_void) {}
+
+    pub unsafe fn DatasetsGeneration() -> u32 {
+        GENERATION.load(Ordering::Relaxed)
+    }
+
+    pub unsafe fn DatasetAdd(set: *mut c_void, data: *const u8, data_len: u32) -> c_int {
+        let data = std::slice::from_raw_parts(data, data_len as usize);
+        let mut sets = SETS.lock().unwrap();
+        sets[set as usize - 1].2.insert(data.to_vec()) as c_int
+    }
+
+    pub unsafe fn DatasetLookup(set: *mut c_void, data: *const u8, data_len: u32) -> c_int {
+        let data = std::slice::from_raw_parts(data, data_len as usize);
+        let sets = SETS.lock().unwrap();
+        sets[set as usize - 1].2.contains(data) as c_int
+    }
+}
+
+#[cfg(not(test))]
+mod real {
+    use std::os::raw::{c_char, c_int, c_void};
+
+    extern "C" {
+        pub fn DatasetFindRef(name: *const c_char, dtype: c_int) -> *mut c_void;
+        pub fn DatasetRelease(set: *mut c_void);
+        pub fn DatasetsGeneration() -> u32;
+        pub fn DatasetAdd(set: *mut c_void, data: *const u8, data_len: u32) -> c_int;
+        pub fn DatasetLookup(set: *mut c_void, data: *const u8, data_len: u32) -> c_int;
+    }
+}
+
+#[cfg(test)]
+use mock::*;
+
+#[cfg(not(test))]
+use real::*;
+
+/// A dataset loaded by the engine.
+///
+/// A reference to the set is held while the `Dataset` is alive, so the set
+/// is not freed by a rule reload replacing it.
+pub struct Dataset {
+    set: *mut c_void,
+    dtype: DatasetType,
+}
+
+// The sets are safe to use from any thread.
+unsafe impl Send for Dataset {}
+unsafe impl Sync for Dataset {}
+
+impl Drop for Dataset {
+    fn drop(&mut self) {
+        unsafe { DatasetRelease(self.set) };
+    }
+}
+
+impl Dataset {
+    /// Find the dataset `name` of type `dtype`.
+    pub fn find(name: &str, dtype: DatasetType) -> Option<Dataset> {
+        let name = CString::new(name).ok()?;
+        Self::find_cstr(&name, dtype)
+    }
+
+    fn find_cstr(name: &CStr, dtype: DatasetType) -> Option<Dataset> {
+        let set = unsafe { DatasetFindRef(name.as_ptr(), dtype as c_int) };
+        if set.is_null() {
+            return None;
+        }
+        Some(Dataset { set, dtype })
+    }
+
+    pub fn dataset_type(&self) -> DatasetType {
+        self.dtype
+    }
+
+    fn is_valid(&self, value: &[u8]) -> bool {
+        self.dtype
+            .value_len()
+            .map_or(true, |len| len == value.len())
+    }
+
+    /// Add `value` to the set. Returns true if it was not in the set
+    /// yet.
+    pub fn add(&self, value: &[u8]) -> bool {
+        if !self.is_valid(value) {
+            return false;
+        }
+        unsafe { DatasetAdd(self.set, value.as_ptr(), value.len() as u32) == 1 }
+    }
+
+    /// Add a hash given as a hex string, as it is logged. Sets of a hash
+    /// type store the decoded hash, string sets the hex string itself.
+    pub fn add_hex(&self, value: &[u8]) -> bool {
+        if self.dtype == DatasetType::String {
+            return self.add(value);
+        }
+        match hex::decode(value) {
+            Ok(decoded) => self.add(&decoded),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns true if `value` is in the set.
+    pub fn lookup(&self, value: &[u8]) -> bool {
+        if !self.is_valid(value) {
+            return false;
+        }
+        unsafe { DatasetLookup(self.set, value.as_ptr(), value.len() as u32) == 1 }
+    }
+}
+
+/// A set found by a [`DatasetConfig`], and the generation of the sets it
+/// was found in.
+struct DatasetCache {
+    generation: u32,
+    set: Option<Arc<Dataset>>,
+}
+
+/// A dataset a parser is configured to use.
+///
+/// The datasets are set up after the parsers are registered and are
+/// replaced on rule reloads, so the set is looked up by name the first
+/// time it is used, and again once the sets of a reload are in place.
+pub struct DatasetConfig {
+    name: RwLock<Option<CString>>,
+    cache: RwLock<Option<DatasetCache>>,
+}
+
+impl DatasetConfig {
+    pub const fn new() -> Self {
+        Self {
+            name: RwLock::new(None),
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Use the dataset named by the configuration value `key`, if set.
+    pub fn configure(&self, key: &str) {
+        if let Some(name) = conf_get(key) {
+            self.set_name(name);
+        }
+    }
+
+    pub(crate) fn set_name(&self, name: &str) {
+        match (CString::new(name), self.name.write()) {
+            (Ok(name), Ok(mut current)) => {
+                *current = Some(name);
+                if let Ok(mut cache) = self.cache.write() {
+                    *cache = None;
+                }
+            }
+            _ => {
+                SCLogError!("Invalid dataset name {}", name);
+            }
+        }
+    }
+
+    /// Find the configured dataset, trying the `types` in order.
+    pub fn find(&self, types: &[DatasetType]) -> Option<Arc<Dataset>> {
+        let generation = unsafe { DatasetsGeneration() };
+        if let Ok(cache) = self.cache.read() {
+            if let Some(cache) = cache.as_ref() {
+                if cache.generation == generation {
+                    return cache.set.clone();
+                }
+            }
+        }
+        let set = self.find_uncached(types).map(Arc::new);
+        if let Ok(mut cache) = self.cache.write() {
+            *cache = Some(DatasetCache {
+                generation,
+                set: set.clone(),
+            });
+        }
+        set
+    }
+
+    fn find_uncached(&self, types: &[DatasetType]) -> Option<Dataset> {
+        let name = self.name.read().ok()?;
+        let name = name.as_ref()?;
+        let set = types
+            .iter()
+            .find_map(|dtype| Dataset::find_cstr(name, *dtype));
+        if set.is_none() {
+            SCLogDebug!("dataset {:?} not found", name);
+        }
+        set
+    }
+}
+
+impl Default for DatasetConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dataset_add_lookup() {
+        let set = Dataset::find("test-string", DatasetType::String).unwrap();
+        assert!(!set.lookup(b"value"));
+        assert!(set.add(b"value"));
+        assert!(!set.add(b"value"));
+        assert!(set.lookup(b"value"));
+        assert!(Dataset::find("test-string", DatasetType::Md5).is_none());
+    }
+
+    #[test]
+    fn test_dataset_add_hex() {
+        let hash = b"ec7378c1a92f5a8dde7e8b7a1ddf33d1";
+        let set = Dataset::find("test-md5", DatasetType::Md5).unwrap();
+        assert!(set.add_hex(hash));
+        assert!(set.lookup(&hex::decode(hash).unwrap()));
+        // Wrong length or not hex.
+        assert!(!set.add_hex(b"ec7378c1"));
+        assert!(!set.add_hex(b"not hex"));
+
+        let set = Dataset::find("test-hex-string", DatasetType::String).unwrap();
+        assert!(set.add_hex(hash));
+        assert!(set.lookup(hash));
+    }
+
+    #[test]
+    fn test_dataset_config() {
+        let config = DatasetConfig::new();
+        assert!(config.find(&[DatasetType::String]).is_none());
+        config.set_name("test-config");
+        let set = config
+            .find(&[DatasetType::Md5, DatasetType::String])
+            .unwrap();
+        assert_eq!(set.dataset_type(), DatasetType::Md5);
+
+        // The set is only looked up again after a reload.
+        let again = config.find(&[DatasetType::Md5]).unwrap();
+        assert!(Arc::ptr_eq(&set, &again));
+        mock::GENERATION.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let reloaded = config.find(&[DatasetType::Md5]).unwrap();
+        assert!(!Arc::ptr_eq(&set, &reloaded));
+    }
+}
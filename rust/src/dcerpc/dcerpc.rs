@@ -18,6 +18,8 @@
 use crate::applayer::{self, *};
 use crate::core::{self, *};
 use crate::dcerpc::parser;
+use crate::dcerpc::ndr::NdrReader;
+use crate::dcerpc::svcctl::{svcctl_decode_request, SvcctlCreateService, SVCCTL_UUID};
 use nom7::error::{Error, ErrorKind};
 use nom7::number::Endianness;
 use nom7::{Err, IResult, Needed};
@@ -25,13 +27,15 @@ use std;
 use std::cmp;
 use std::ffi::CString;
 use std::collections::VecDeque;
-use crate::conf::conf_get;
 
 // Constant DCERPC UDP Header length
 pub const DCERPC_HDR_LEN: u16 = 16;
 // FIRST flag set on the packet
 pub const DCERPC_UUID_ENTRY_FLAG_FF: u16 = 0x0001;
 
+// Upper bound on the presentation contexts tracked per connection.
+const DCERPC_MAX_CONTEXTS: usize = 256;
+
 // Flag bits in connection-oriented PDU header
 
 // Value to indicate first fragment
@@ -111,7 +115,21 @@ pub const DCERPC_TYPE_ORPHANED: u8 = 19;
 pub const DCERPC_TYPE_RTS: u8 = 20;
 pub const DCERPC_TYPE_UNKNOWN: u8 = 99;
 
-pub(super) static mut DCERPC_MAX_TX: usize = 1024;
+pub(super) static DCERPC_LIMITS: AppLayerLimits = AppLayerLimits::new(1024);
+
+// Fault status of a call to an opnum the interface does not have
+pub const DCERPC_FAULT_OP_RNG_ERROR: u32 = 0x1c010002;
+// Faults for out of range opnums in a flow above which the event is set
+const DCERPC_OP_RNG_FAULTS_THRESHOLD: u32 = 10;
+
+#[derive(AppLayerEvent)]
+pub enum DCERPCEvent {
+    TooManyTransactions,
+    StubDataLimitReached,
+    /// Repeated faults for out of range opnums, like when probing the
+    /// opnums of an interface.
+    RepeatedOpRangeFaults,
+}
 
 pub static mut ALPROTO_DCERPC: AppProto = ALPROTO_UNKNOWN;
 
@@ -146,6 +164,77 @@ pub fn dcerpc_type_string(t: u8) -> String {
     .to_string()
 }
 
+/// Name of a fault status, the NCA status codes of the DCE 1.1 RPC
+/// specification and the Windows error codes of MS-RPCE.
+pub fn dcerpc_fault_status_string(status: u32) -> Option<&'static str> {
+    let name = match status {
+        0x00000001 => "nca_s_fault_other",
+        0x00000005 => "nca_s_fault_access_denied",
+        0x000006d8 => "nca_s_fault_cant_perform",
+        0x000006f7 => "nca_s_fault_ndr",
+        0x00000721 => "nca_s_fault_sec_pkg_error",
+        0x1c000001 => "nca_s_fault_int_div_by_zero",
+        0x1c000002 => "nca_s_fault_addr_error",
+        0x1c000003 => "nca_s_fault_fp_div_zero",
+        0x1c000004 => "nca_s_fault_fp_underflow",
+        0x1c000005 => "nca_s_fault_fp_overflow",
+        0x1c000006 => "nca_s_fault_invalid_tag",
+        0x1c000007 => "nca_s_fault_invalid_bound",
+        0x1c000008 => "nca_rpc_version_mismatch",
+        0x1c000009 => "nca_unspec_reject",
+        0x1c00000a => "nca_s_bad_actid",
+        0x1c00000b => "nca_who_are_you_failed",
+        0x1c00000c => "nca_manager_not_entered",
+        0x1c00000d => "nca_s_fault_cancel",
+        0x1c00000e => "nca_s_fault_ill_inst",
+        0x1c00000f => "nca_s_fault_fp_error",
+        0x1c000010 => "nca_s_fault_int_overflow",
+        0x1c000012 => "nca_s_fault_unspec",
+        0x1c000013 => "nca_s_fault_remote_comm_failure",
+        0x1c000014 => "nca_s_fault_pipe_empty",
+        0x1c000015 => "nca_s_fault_pipe_closed",
+        0x1c000016 => "nca_s_fault_pipe_order",
+        0x1c000017 => "nca_s_fault_pipe_discipline",
+        0x1c000018 => "nca_s_fault_pipe_comm_error",
+        0x1c000019 => "nca_s_fault_pipe_memory",
+        0x1c00001a => "nca_s_fault_context_mismatch",
+        0x1c00001b => "nca_s_fault_remote_no_memory",
+        0x1c00001c => "nca_invalid_pres_context_id",
+        0x1c00001d => "nca_unsupported_authn_level",
+        0x1c00001f => "nca_invalid_checksum",
+        0x1c000020 => "nca_invalid_crc",
+        0x1c000021 => "nca_s_fault_user_defined",
+        0x1c000022 => "nca_s_fault_tx_open_failed",
+        0x1c000023 => "nca_s_fault_codeset_conv_error",
+        0x1c000024 => "nca_s_fault_object_not_found",
+        0x1c000025 => "nca_s_fault_no_client_stub",
+        0x1c010001 => "nca_comm_failure",
+        DCERPC_FAULT_OP_RNG_ERROR => "nca_op_rng_error",
+        0x1c010003 => "nca_unk_if",
+        0x1c010006 => "nca_wrong_boot_time",
+        0x1c010009 => "nca_s_you_crashed",
+        0x1c01000b => "nca_proto_error",
+        0x1c010013 => "nca_out_args_too_big",
+        0x1c010014 => "nca_server_too_busy",
+        0x1c010015 => "nca_fault_string_too_long",
+        0x1c010017 => "nca_unsupported_type",
+        _ => {
+            return None;
+        }
+    };
+    Some(name)
+}
+
+/// Count a fault of the flow, returns true when the faults for out of
+/// range opnums reach the threshold of the event.
+pub(super) fn dcerpc_count_fault(op_rng_faults: &mut u32, status: u32) -> bool {
+    if status != DCERPC_FAULT_OP_RNG_ERROR {
+        return false;
+    }
+    *op_rng_faults = op_rng_faults.saturating_add(1);
+    *op_rng_faults == DCERPC_OP_RNG_FAULTS_THRESHOLD
+}
+
 pub fn get_resp_type_for_req(t: u8) -> u8 {
     match t {
         DCERPC_TYPE_REQUEST => DCERPC_TYPE_RESPONSE,
@@ -157,13 +246,31 @@ pub fn get_resp_type_for_req(t: u8) -> u8 {
 
 pub fn get_req_type_for_resp(t: u8) -> u8 {
     match t {
-        DCERPC_TYPE_RESPONSE => DCERPC_TYPE_REQUEST,
+        DCERPC_TYPE_RESPONSE | DCERPC_TYPE_FAULT => DCERPC_TYPE_REQUEST,
         DCERPC_TYPE_BINDACK => DCERPC_TYPE_BIND,
         DCERPC_TYPE_ALTER_CONTEXT_RESP => DCERPC_TYPE_ALTER_CONTEXT,
         _ => DCERPC_TYPE_UNKNOWN,
     }
 }
 
+/// Request parameters decoded from the stub data of known interfaces.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DCERPCStubRequest {
+    SvcctlCreateService(SvcctlCreateService),
+}
+
+/// Decode the request stub of a call to interface `uuid`, if the
+/// interface and opnum are supported.
+pub fn dcerpc_decode_request_stub(
+    uuid: &[u8], opnum: u16, stub: &[u8], drep_0: u8,
+) -> Option<DCERPCStubRequest> {
+    let mut ndr = NdrReader::from_drep(stub, drep_0);
+    if uuid == SVCCTL_UUID {
+        return svcctl_decode_request(opnum, &mut ndr).map(DCERPCStubRequest::SvcctlCreateService);
+    }
+    None
+}
+
 #[derive(Default, Debug)]
 pub struct DCERPCTransaction {
     pub id: u64, // internal transaction ID
@@ -178,6 +285,9 @@ pub struct DCERPCTransaction {
     pub stub_data_buffer_tc: Vec<u8>,
     pub stub_data_buffer_reset_ts: bool,
     pub stub_data_buffer_reset_tc: bool,
+    /// Stub data was truncated by the buffer limits.
+    pub stub_data_truncated: bool,
+    pub stub_request: Option<DCERPCStubRequest>,
     pub req_done: bool,
     pub resp_done: bool,
     pub req_lost: bool,
@@ -185,7 +295,17 @@ pub struct DCERPCTransaction {
     pub req_cmd: u8,
     pub resp_cmd: u8,
     pub activityuuid: Vec<u8>,
+    /// Object UUID of the request, empty if the request has none.
+    pub objectuuid: Vec<u8>,
     pub seqnum: u32,
+    /// UDP request is a `maybe` call, the client expects no response.
+    pub maybe: bool,
+    /// UDP request is idempotent, it may be executed more than once.
+    pub idempotent: bool,
+    /// UDP request is broadcast to all the servers of the network.
+    pub broadcast: bool,
+    /// Status of the FAULT the call was answered with.
+    pub fault_status: Option<u32>,
     pub tx_data: AppLayerTxData,
 }
 
@@ -196,6 +316,21 @@ impl Transaction for DCERPCTransaction {
     }
 }
 
+impl TxLimit for DCERPCTransaction {
+    fn is_complete(&self) -> bool {
+        self.req_done && self.resp_done
+    }
+
+    fn force_complete(&mut self) {
+        self.req_done = true;
+        self.resp_done = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
 impl DCERPCTransaction {
     pub fn new() -> Self {
         return Self {
@@ -204,6 +339,7 @@ impl DCERPCTransaction {
             req_cmd: DCERPC_TYPE_REQUEST,
             resp_cmd: DCERPC_TYPE_RESPONSE,
             activityuuid: Vec::new(),
+            objectuuid: Vec::new(),
             tx_data: AppLayerTxData::new(),
             ..Default::default()
         }
@@ -213,6 +349,38 @@ impl DCERPCTransaction {
         self.ctxid
     }
 
+    /// Complete the call with a FAULT, setting the event if `event`.
+    pub fn set_fault(&mut self, status: u32, event: bool) {
+        self.resp_cmd = DCERPC_TYPE_FAULT;
+        self.fault_status = Some(status);
+        self.resp_done = true;
+        if event {
+            self.tx_data
+                .set_event(DCERPCEvent::RepeatedOpRangeFaults as u8);
+        }
+    }
+
+    /// Get the number of bytes of the stub data buffers.
+    pub fn stub_data_len(&self) -> usize {
+        self.stub_data_buffer_ts.len() + self.stub_data_buffer_tc.len()
+    }
+
+    /// Append stub data to the buffer of a direction, within the limits
+    /// of `DCERPC_LIMITS`. `stub_data_mem` is the size of the stub data
+    /// of the state.
+    pub fn append_stub_data(&mut self, data: &[u8], dir: Direction, stub_data_mem: &mut usize) {
+        let buffer = match dir {
+            Direction::ToServer => &mut self.stub_data_buffer_ts,
+            Direction::ToClient => &mut self.stub_data_buffer_tc,
+        };
+        let complete = DCERPC_LIMITS.append_buffer(buffer, data, stub_data_mem);
+        if !complete && !self.stub_data_truncated {
+            self.stub_data_truncated = true;
+            let event = DCERPCEvent::StubDataLimitReached as u8;
+            self.tx_data.set_event(event);
+        }
+    }
+
     pub fn get_first_req_seen(&self) -> u8 {
         self.first_request_seen
     }
@@ -224,6 +392,15 @@ impl DCERPCTransaction {
     pub fn get_endianness(&self) -> u8 {
         self.endianness
     }
+
+    /// NDR reader over the reassembled stub data of one direction.
+    pub fn ndr_stub(&self, direction: Direction) -> NdrReader {
+        let stub = match direction {
+            Direction::ToServer => &self.stub_data_buffer_ts,
+            Direction::ToClient => &self.stub_data_buffer_tc,
+        };
+        NdrReader::from_drep(stub, self.endianness)
+    }
 }
 
 #[derive(Debug)]
@@ -231,6 +408,22 @@ pub struct DCERPCRequest {
     pub ctxid: u16,
     pub opnum: u16,
     pub first_request_seen: u8,
+    pub object_uuid: Option<Vec<u8>>,
+}
+
+#[derive(Debug)]
+pub struct DCERPCResponse {
+    pub alloc_hint: u32,
+    pub ctxid: u16,
+    pub cancel_count: u8,
+}
+
+#[derive(Debug)]
+pub struct DCERPCFault {
+    pub alloc_hint: u32,
+    pub ctxid: u16,
+    pub cancel_count: u8,
+    pub status: u32,
 }
 
 #[derive(Default, Debug, Clone)]
@@ -307,8 +500,13 @@ pub struct DCERPCState {
     pub header: Option<DCERPCHdr>,
     pub bind: Option<DCERPCBind>,
     pub bindack: Option<DCERPCBindAck>,
+    /// Presentation contexts accepted over the lifetime of the
+    /// connection, one entry per context id.
+    pub contexts: Vec<DCERPCUuidEntry>,
     pub transactions: VecDeque<DCERPCTransaction>,
     tx_index_completed: usize,
+    /// Bytes of stub data of the transactions.
+    stub_data_mem: usize,
     pub buffer_ts: Vec<u8>,
     pub buffer_tc: Vec<u8>,
     pub pad: u8,
@@ -322,6 +520,8 @@ pub struct DCERPCState {
     pub tc_gap: bool,
     pub ts_ssn_gap: bool,
     pub tc_ssn_gap: bool,
+    /// Faults for out of range opnums.
+    op_rng_faults: u32,
     pub flow: Option<*const core::Flow>,
     state_data: AppLayerStateData,
 }
@@ -345,6 +545,24 @@ impl DCERPCState {
         }
     }
 
+    /// Get the interface currently bound to a presentation context.
+    pub fn get_context(&self, ctxid: u16) -> Option<&DCERPCUuidEntry> {
+        self.contexts.iter().find(|uuid| uuid.ctxid == ctxid)
+    }
+
+    /// Add or replace an accepted presentation context. A bind or
+    /// alter_context only changes the contexts it names, so earlier
+    /// contexts remain usable by later requests.
+    fn set_context(&mut self, uuid: &DCERPCUuidEntry) {
+        if let Some(ctx) = self.contexts.iter_mut().find(|ctx| ctx.ctxid == uuid.ctxid) {
+            *ctx = uuid.clone();
+        } else if self.contexts.len() < DCERPC_MAX_CONTEXTS {
+            self.contexts.push(uuid.clone());
+        } else {
+            SCLogDebug!("DCERPC context table full, ignoring ctxid {}", uuid.ctxid);
+        }
+    }
+
     fn create_tx(&mut self, call_id: u32) -> DCERPCTransaction {
         let mut tx = DCERPCTransaction::new();
         let endianness = self.get_hdr_drep_0() & 0x10;
@@ -352,18 +570,14 @@ impl DCERPCState {
         tx.call_id = call_id;
         tx.endianness = endianness;
         self.tx_id += 1;
-        if self.transactions.len() > unsafe { DCERPC_MAX_TX } {
-            let mut index = self.tx_index_completed;
-            for tx_old in &mut self.transactions.range_mut(self.tx_index_completed..) {
-                index += 1;
-                if !tx_old.req_done || !tx_old.resp_done {
-                    tx_old.req_done = true;
-                    tx_old.resp_done = true;
-                    break;
-                }
-            }
-            self.tx_index_completed = index;
-        }
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            DCERPC_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            DCERPCEvent::TooManyTransactions as u8,
+        );
         tx
     }
 
@@ -385,7 +599,9 @@ impl DCERPCState {
             SCLogDebug!("freeing TX with ID {} TX.ID {} at index {} left: {} max id: {}",
                             tx_id, tx_id+1, index, self.transactions.len(), self.tx_id);
             self.tx_index_completed = 0;
-            self.transactions.remove(index);
+            if let Some(tx) = self.transactions.remove(index) {
+                self.stub_data_mem -= tx.stub_data_len();
+            }
         }
     }
 
@@ -495,46 +711,60 @@ impl DCERPCState {
         None
     }
 
-    /// Find the transaction as per call ID defined in header. If the tx is not
-    /// found, create one.
+    /// Find the open transaction of a call. Calls are identified by their
+    /// call ID and, for requests and responses, the presentation context
+    /// ID, so that pipelined calls are never mixed up. A transaction stays
+    /// open in a direction until the last fragment in that direction was
+    /// seen. If several match, the oldest one is returned.
     ///
     /// Arguments:
     /// * `call_id`:
     ///             type: unsigned 32 bit integer
     ///      description: call_id param derived from TCP Header
+    /// * `ctxid`:
+    ///             type: optional unsigned 16 bit integer
+    ///      description: context id of the request or response PDU, None
+    ///                   for PDUs without one like BIND and BINDACK
     /// * `dir`:
     ///         type: enum Direction
     ///   description: direction of the flow
     ///
     /// Return value:
     /// Option mutable reference to DCERPCTransaction
-    pub fn get_tx_by_call_id(&mut self, call_id: u32, dir: Direction) -> Option<&mut DCERPCTransaction> {
+    pub fn get_tx_by_call_id(
+        &mut self, call_id: u32, ctxid: Option<u16>, dir: Direction,
+    ) -> Option<&mut DCERPCTransaction> {
         let cmd = self.get_hdr_type().unwrap_or(0);
         for tx in &mut self.transactions {
-            let found = tx.call_id == call_id;
-            if found {
-                match dir {
-                    Direction::ToServer => {
-                        if tx.req_done || tx.req_lost {
-                            continue;
-                        }
-                        let resp_cmd = get_resp_type_for_req(cmd);
-                        if resp_cmd != tx.resp_cmd {
-                            continue;
-                        }
+            if tx.call_id != call_id {
+                continue;
+            }
+            if let Some(ctxid) = ctxid {
+                if tx.ctxid != ctxid {
+                    continue;
+                }
+            }
+            match dir {
+                Direction::ToServer => {
+                    if tx.req_done || tx.req_lost {
+                        continue;
                     }
-                    Direction::ToClient => {
-                        if tx.resp_done || tx.resp_lost {
-                            continue;
-                        }
-                        let req_cmd = get_req_type_for_resp(cmd);
-                        if req_cmd != tx.req_cmd {
-                            continue;
-                        }
+                    let resp_cmd = get_resp_type_for_req(cmd);
+                    if resp_cmd != tx.resp_cmd {
+                        continue;
+                    }
+                }
+                Direction::ToClient => {
+                    if tx.resp_done || tx.resp_lost {
+                        continue;
+                    }
+                    let req_cmd = get_req_type_for_resp(cmd);
+                    if req_cmd != tx.req_cmd {
+                        continue;
                     }
                 }
-                return Some(tx);
             }
+            return Some(tx);
         }
         None
     }
@@ -737,6 +967,9 @@ impl DCERPCState {
                             }
                         }
                     }
+                    for uuid in back.accepted_uuid_list.iter() {
+                        self.set_context(uuid);
+                    }
                     self.bindack = Some(back);
                 }
                 (input.len() - leftover_bytes.len()) as i32
@@ -754,14 +987,65 @@ impl DCERPCState {
         }
     }
 
-    pub fn handle_stub_data(&mut self, input: &[u8], input_len: usize, dir: Direction) -> u16 {
+    pub fn process_fault_pdu(&mut self, input: &[u8]) -> i32 {
+        let endianness = self.get_endianness();
+        match parser::parse_dcerpc_fault(input, endianness) {
+            Ok((leftover_bytes, fault)) => {
+                SCLogDebug!("DCERPC FAULT status {:x}", fault.status);
+                let call_id = self.get_hdr_call_id().unwrap_or(0);
+                let event = dcerpc_count_fault(&mut self.op_rng_faults, fault.status);
+                // the context of the fault may not be the one of the request,
+                // like for an unknown interface
+                let mut ctxid = Some(fault.ctxid);
+                if self
+                    .get_tx_by_call_id(call_id, ctxid, Direction::ToClient)
+                    .is_none()
+                {
+                    ctxid = None;
+                }
+                let tx =
+                    if let Some(tx) = self.get_tx_by_call_id(call_id, ctxid, Direction::ToClient) {
+                        tx
+                    } else {
+                        let mut tx = self.create_tx(call_id);
+                        tx.ctxid = fault.ctxid;
+                        self.transactions.push_back(tx);
+                        self.transactions.back_mut().unwrap()
+                    };
+                tx.set_fault(fault.status, event);
+                tx.frag_cnt_tc = 1;
+                if let Some(flow) = self.flow {
+                    sc_app_layer_parser_trigger_raw_stream_reassembly(
+                        flow,
+                        Direction::ToClient as i32,
+                    );
+                }
+                (input.len() - leftover_bytes.len()) as i32
+            }
+            Err(Err::Incomplete(_)) => {
+                // Insufficient data.
+                SCLogDebug!("Insufficient data while parsing DCERPC FAULT");
+                -1
+            }
+            Err(_) => {
+                // Error, probably malformed data.
+                SCLogDebug!("An error occurred while parsing DCERPC FAULT");
+                -1
+            }
+        }
+    }
+
+    pub fn handle_stub_data(
+        &mut self, input: &[u8], input_len: usize, ctxid: Option<u16>, dir: Direction,
+    ) -> u16 {
         let retval;
         let hdrpfcflags = self.get_hdr_pfcflags().unwrap_or(0);
         let padleft = self.padleft;
         let call_id = self.get_hdr_call_id().unwrap_or(0);
         let hdrtype = self.get_hdr_type();
+        let mut stub_data_mem = self.stub_data_mem;
         let tx;
-        if let Some(transaction) = self.get_tx_by_call_id(call_id, dir) {
+        if let Some(transaction) = self.get_tx_by_call_id(call_id, ctxid, dir) {
             tx = transaction;
         } else {
             SCLogDebug!("No transaction found matching the call ID: {:?}", call_id);
@@ -777,14 +1061,10 @@ impl DCERPCState {
                         input_len,
                         hdrpfcflags,
                         padleft,
-                        &mut tx.stub_data_buffer_ts,
-                        &mut tx.stub_data_buffer_reset_ts,
+                        tx,
+                        Direction::ToServer,
+                        &mut stub_data_mem,
                     );
-                    tx.req_done = true;
-                    tx.frag_cnt_ts = 1;
-                    if let Some(flow) = self.flow {
-                        sc_app_layer_parser_trigger_raw_stream_reassembly(flow, Direction::ToServer as i32);
-                    }
                 }
                 DCERPC_TYPE_RESPONSE => {
                     retval = evaluate_stub_params(
@@ -792,14 +1072,10 @@ impl DCERPCState {
                         input_len,
                         hdrpfcflags,
                         padleft,
-                        &mut tx.stub_data_buffer_tc,
-                        &mut tx.stub_data_buffer_reset_tc,
+                        tx,
+                        Direction::ToClient,
+                        &mut stub_data_mem,
                     );
-                    tx.resp_done = true;
-                    tx.frag_cnt_tc = 1;
-                    if let Some(flow) = self.flow {
-                        sc_app_layer_parser_trigger_raw_stream_reassembly(flow, Direction::ToClient as i32);
-                    }
                 }
                 _ => {
                     SCLogDebug!("Unrecognized packet type");
@@ -810,6 +1086,7 @@ impl DCERPCState {
                 return 0;
             }
         }
+        self.stub_data_mem = stub_data_mem;
         // Update the remaining fragment length
         self.padleft -= retval;
 
@@ -825,6 +1102,9 @@ impl DCERPCState {
     /// * `bytes_consumed`:
     ///           type: 16 bit unsigned integer.
     ///    description: bytes consumed *after* parsing header.
+    /// * `ctxid`:
+    ///           type: optional 16 bit unsigned integer.
+    ///    description: context id of the PDU the stub belongs to.
     /// * `dir`:
     ///           type: enum Direction.
     ///    description: direction whose stub is supposed to be handled.
@@ -832,7 +1112,9 @@ impl DCERPCState {
     /// Return value:
     /// * Success: Number of bytes successfully parsed.
     /// * Failure: -1 in case fragment length defined by header mismatches the data.
-    pub fn handle_common_stub(&mut self, input: &[u8], bytes_consumed: usize, dir: Direction) -> i32 {
+    pub fn handle_common_stub(
+        &mut self, input: &[u8], bytes_consumed: usize, ctxid: Option<u16>, dir: Direction,
+    ) -> i32 {
         let fraglen = self.get_hdr_fraglen().unwrap_or(0);
         if (fraglen as usize) < bytes_consumed + (DCERPC_HDR_LEN as usize) {
             return -1;
@@ -842,7 +1124,7 @@ impl DCERPCState {
         let mut input_left = input.len() - bytes_consumed;
         let mut parsed = bytes_consumed as i32;
         while input_left > 0 && parsed < fraglen as i32 {
-            let retval = self.handle_stub_data(&input[parsed as usize..], input_left, dir);
+            let retval = self.handle_stub_data(&input[parsed as usize..], input_left, ctxid, dir);
             if retval > 0 && retval as usize <= input_left {
                 parsed += retval as i32;
                 input_left -= <u16 as std::convert::Into<usize>>::into(retval);
@@ -859,22 +1141,75 @@ impl DCERPCState {
                 input_left = 0;
             }
         }
+        self.handle_fragment_done(ctxid, dir);
         parsed
     }
 
+    /// Account for a request or response fragment of the current call. The
+    /// call is only done in a direction once its last fragment was seen, so
+    /// that later fragments still find the same transaction.
+    fn handle_fragment_done(&mut self, ctxid: Option<u16>, dir: Direction) {
+        let call_id = self.get_hdr_call_id().unwrap_or(0);
+        let last_frag = self.get_hdr_pfcflags().unwrap_or(0) & PFC_LAST_FRAG != 0;
+        let flow = self.flow;
+        if let Some(tx) = self.get_tx_by_call_id(call_id, ctxid, dir) {
+            match dir {
+                Direction::ToServer => {
+                    tx.frag_cnt_ts = tx.frag_cnt_ts.saturating_add(1);
+                    tx.req_done = last_frag;
+                }
+                Direction::ToClient => {
+                    tx.frag_cnt_tc = tx.frag_cnt_tc.saturating_add(1);
+                    tx.resp_done = last_frag;
+                }
+            }
+            if last_frag {
+                if let Some(flow) = flow {
+                    sc_app_layer_parser_trigger_raw_stream_reassembly(flow, dir as i32);
+                }
+            }
+        } else {
+            SCLogDebug!("No transaction found matching the call ID: {:?}", call_id);
+        }
+    }
+
+    /// Decode the complete request stub of the call, based on the
+    /// interface bound to its context id.
+    fn decode_request_stub(&mut self, call_id: u32, ctxid: u16) {
+        let uuids = &self.contexts;
+        let tx = self.transactions.iter_mut().rev().find(|tx| {
+            tx.call_id == call_id && tx.ctxid == ctxid && tx.req_cmd == DCERPC_TYPE_REQUEST
+        });
+        if let Some(tx) = tx {
+            if let Some(uuid) = uuids.iter().find(|uuid| uuid.ctxid == tx.ctxid) {
+                tx.stub_request = dcerpc_decode_request_stub(
+                    &uuid.uuid,
+                    tx.opnum,
+                    &tx.stub_data_buffer_ts,
+                    tx.endianness,
+                );
+            }
+        }
+    }
+
     pub fn process_request_pdu(&mut self, input: &[u8]) -> i32 {
         let endianness = self.get_endianness();
-        match parser::parse_dcerpc_request(input, endianness) {
-            Ok((leftover_input, request)) => {
+        let object = self.get_hdr_pfcflags().unwrap_or(0) & PFC_OBJECT_UUID != 0;
+        match parser::parse_dcerpc_request(input, endianness, object) {
+            Ok((leftover_input, mut request)) => {
                 let call_id = self.get_hdr_call_id().unwrap_or(0);
                 let hdr_type = self.get_hdr_type().unwrap_or(0);
-                let mut transaction = self.get_tx_by_call_id(call_id, Direction::ToServer);
+                let mut transaction =
+                    self.get_tx_by_call_id(call_id, Some(request.ctxid), Direction::ToServer);
                 match transaction {
                     Some(ref mut tx) => {
                         tx.req_cmd = hdr_type;
                         tx.ctxid = request.ctxid;
                         tx.opnum = request.opnum;
                         tx.first_request_seen = request.first_request_seen;
+                        if let Some(uuid) = request.object_uuid.take() {
+                            tx.objectuuid = uuid;
+                        }
                     }
                     None => {
                         let mut tx = self.create_tx(call_id);
@@ -882,14 +1217,21 @@ impl DCERPCState {
                         tx.ctxid = request.ctxid;
                         tx.opnum = request.opnum;
                         tx.first_request_seen = request.first_request_seen;
+                        if let Some(uuid) = request.object_uuid.take() {
+                            tx.objectuuid = uuid;
+                        }
                         self.transactions.push_back(tx);
                     }
                 }
                 let parsed = self.handle_common_stub(
                     input,
                     input.len() - leftover_input.len(),
+                    Some(request.ctxid),
                     Direction::ToServer,
                 );
+                if parsed >= 0 && self.get_hdr_pfcflags().unwrap_or(0) & PFC_LAST_FRAG != 0 {
+                    self.decode_request_stub(call_id, request.ctxid);
+                }
                 parsed
             }
             Err(Err::Incomplete(_)) => {
@@ -1019,7 +1361,9 @@ impl DCERPCState {
                     if retval == -1 {
                         return AppLayerResult::err();
                     }
-                    let tx = if let Some(tx) = self.get_tx_by_call_id(current_call_id, Direction::ToClient) {
+                    let tx = if let Some(tx) =
+                        self.get_tx_by_call_id(current_call_id, None, Direction::ToClient)
+                    {
                         tx.resp_cmd = x;
                         tx
                     } else {
@@ -1043,7 +1387,17 @@ impl DCERPCState {
                     // the corresponding request also comes through
                 }
                 DCERPC_TYPE_RESPONSE => {
-                    let transaction = self.get_tx_by_call_id(current_call_id, Direction::ToClient);
+                    let endianness = self.get_endianness();
+                    let pdu = &buffer[parsed as usize..];
+                    let ctxid = match parser::parse_dcerpc_response(pdu, endianness) {
+                        Ok((_, response)) => response.ctxid,
+                        Err(_) => {
+                            SCLogDebug!("An error occurred while parsing DCERPC RESPONSE");
+                            return AppLayerResult::err();
+                        }
+                    };
+                    let transaction =
+                        self.get_tx_by_call_id(current_call_id, Some(ctxid), Direction::ToClient);
                     match transaction {
                         Some(tx) => {
                             tx.resp_cmd = x;
@@ -1051,18 +1405,26 @@ impl DCERPCState {
                         None => {
                             let mut tx = self.create_tx(current_call_id);
                             tx.resp_cmd = x;
+                            tx.ctxid = ctxid;
                             self.transactions.push_back(tx);
                         }
                     };
                     retval = self.handle_common_stub(
                         &buffer[parsed as usize..],
                         0,
+                        Some(ctxid),
                         Direction::ToClient,
                     );
                     if retval < 0 {
                         return AppLayerResult::err();
                     }
                 }
+                DCERPC_TYPE_FAULT if direction == Direction::ToClient => {
+                    retval = self.process_fault_pdu(&buffer[parsed as usize..]);
+                    if retval < 0 {
+                        return AppLayerResult::err();
+                    }
+                }
                 _ => {
                     SCLogDebug!("Unrecognized packet type: {:?}", x);
                     self.clean_buffer(direction);
@@ -1087,8 +1449,8 @@ impl DCERPCState {
 }
 
 fn evaluate_stub_params(
-    input: &[u8], input_len: usize, hdrflags: u8, lenleft: u16,
-    stub_data_buffer: &mut Vec<u8>,stub_data_buffer_reset: &mut bool,
+    input: &[u8], input_len: usize, hdrflags: u8, lenleft: u16, tx: &mut DCERPCTransaction,
+    dir: Direction, stub_data_mem: &mut usize,
 ) -> u16 {
     
     let fragtype = hdrflags & (PFC_FIRST_FRAG | PFC_LAST_FRAG);
@@ -1098,11 +1460,14 @@ fn evaluate_stub_params(
         return 0;
     }
     if stub_len == lenleft && (fragtype == 0 || (fragtype & PFC_LAST_FRAG > 0)) {
-        *stub_data_buffer_reset = true;
+        match dir {
+            Direction::ToServer => tx.stub_data_buffer_reset_ts = true,
+            Direction::ToClient => tx.stub_data_buffer_reset_tc = true,
+        }
     }
 
     let input_slice = &input[..stub_len as usize];
-    stub_data_buffer.extend_from_slice(input_slice);
+    tx.append_stub_data(input_slice, dir, stub_data_mem);
 
     stub_len
 }
@@ -1145,7 +1510,7 @@ pub unsafe extern "C" fn rs_dcerpc_parse_request(
         state.flow = Some(flow);
         return state.handle_input_data(stream_slice.as_slice(), Direction::ToServer);
     }
-    AppLayerResult::err()
+    AppLayerResult::error(AppLayerError::Gap)
 }
 
 #[no_mangle]
@@ -1168,7 +1533,7 @@ pub unsafe extern "C" fn rs_dcerpc_parse_response(
         state.flow = Some(flow);
         return state.handle_input_data(stream_slice.as_slice(), Direction::ToClient);
     }
-    AppLayerResult::err()
+    AppLayerResult::error(AppLayerError::Gap)
 }
 
 #[no_mangle]
@@ -1333,8 +1698,8 @@ pub unsafe extern "C" fn rs_dcerpc_register_parser() {
         tx_comp_st_ts: 1,
         tx_comp_st_tc: 1,
         tx_get_progress: rs_dcerpc_get_alstate_progress,
-        get_eventinfo: None,
-        get_eventinfo_byid : None,
+        get_eventinfo: Some(DCERPCEvent::get_event_info),
+        get_eventinfo_byid : Some(DCERPCEvent::get_event_info_by_id),
         localstorage_new: None,
         localstorage_free: None,
         get_tx_files: None,
@@ -1364,15 +1729,9 @@ pub unsafe extern "C" fn rs_dcerpc_register_parser() {
             parser.name,
         ) != 0
         {
-            let _ = AppLayerRegisterParser(&parser, alproto);
-        }
-        if let Some(val) = conf_get("app-layer.protocols.dcerpc.max-tx") {
-            if let Ok(v) = val.parse::<usize>() {
-                DCERPC_MAX_TX = v;
-            } else {
-                SCLogError!("Invalid value for smb.max-tx");
-            }
+            let _ = applayer_register_parser(&parser, alproto);
         }
+        DCERPC_LIMITS.configure("dcerpc");
         SCLogDebug!("Rust DCERPC parser registered.");
     } else {
         SCLogDebug!("Protocol detector and parser disabled for DCERPC.");
@@ -1381,10 +1740,11 @@ pub unsafe extern "C" fn rs_dcerpc_register_parser() {
 
 #[cfg(test)]
 mod tests {
-    use crate::applayer::AppLayerResult;
+    use crate::applayer::{applayer_enforce_tx_limit, AppLayerResult};
     use crate::core::*;
-    use crate::dcerpc::dcerpc::DCERPCState;
+    use crate::dcerpc::dcerpc::{DCERPCEvent, DCERPCState, DCERPCTransaction};
     use std::cmp;
+    use std::collections::VecDeque;
 
     #[test]
     fn test_process_header() {
@@ -1822,6 +2182,11 @@ mod tests {
         assert_eq!(1, tx.first_request_seen);
         assert_eq!(1000, tx.stub_data_buffer_ts.len());
         assert!(tx.stub_data_buffer_reset_ts);
+        assert!(!tx.stub_data_truncated);
+        assert_eq!(1000, dcerpc_state.stub_data_mem);
+        let tx_id = tx.id;
+        dcerpc_state.free_tx(tx_id);
+        assert_eq!(0, dcerpc_state.stub_data_mem);
     }
 
     #[test]
@@ -2425,6 +2790,11 @@ mod tests {
             assert_eq!(11, back.accepted_uuid_list[0].ctxid);
             assert_eq!(expected_uuid3, back.accepted_uuid_list[0].uuid);
         }
+        // earlier binds are still tracked in the context table
+        assert_eq!(3, dcerpc_state.contexts.len());
+        assert_eq!(expected_uuid1, dcerpc_state.get_context(12).unwrap().uuid);
+        assert_eq!(expected_uuid2, dcerpc_state.get_context(15).unwrap().uuid);
+        assert_eq!(expected_uuid3, dcerpc_state.get_context(11).unwrap().uuid);
     }
 
     #[test]
@@ -2494,6 +2864,10 @@ mod tests {
             assert_eq!(1, back.accepted_uuid_list[0].ctxid);
             assert_eq!(expected_uuid2, back.accepted_uuid_list[0].uuid);
         }
+        // the alter_context adds ctxid 1 without dropping ctxid 0
+        assert_eq!(2, dcerpc_state.contexts.len());
+        assert_eq!(expected_uuid1, dcerpc_state.get_context(0).unwrap().uuid);
+        assert_eq!(expected_uuid2, dcerpc_state.get_context(1).unwrap().uuid);
     }
 
     #[test]
@@ -2520,4 +2894,246 @@ mod tests {
         assert_eq!(0, tx.ctxid);
         assert_eq!(14, tx.stub_data_buffer_ts.len());
     }
+
+    fn dcerpc_pdu(hdrtype: u8, pfc_flags: u8, call_id: u32, body: &[u8]) -> Vec<u8> {
+        let frag_length = (DCERPC_HDR_LEN as usize + body.len()) as u16;
+        let mut pdu = vec![0x05, 0x00, hdrtype, pfc_flags, 0x10, 0x00, 0x00, 0x00];
+        pdu.extend_from_slice(&frag_length.to_le_bytes());
+        pdu.extend_from_slice(&[0x00, 0x00]);
+        pdu.extend_from_slice(&call_id.to_le_bytes());
+        pdu.extend_from_slice(body);
+        pdu
+    }
+
+    fn dcerpc_request(pfc_flags: u8, call_id: u32, ctxid: u16, opnum: u16, stub: &[u8]) -> Vec<u8> {
+        let mut body = (stub.len() as u32).to_le_bytes().to_vec();
+        body.extend_from_slice(&ctxid.to_le_bytes());
+        body.extend_from_slice(&opnum.to_le_bytes());
+        body.extend_from_slice(stub);
+        dcerpc_pdu(DCERPC_TYPE_REQUEST, pfc_flags, call_id, &body)
+    }
+
+    fn dcerpc_response(pfc_flags: u8, call_id: u32, ctxid: u16, stub: &[u8]) -> Vec<u8> {
+        let mut body = (stub.len() as u32).to_le_bytes().to_vec();
+        body.extend_from_slice(&ctxid.to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00]);
+        body.extend_from_slice(stub);
+        dcerpc_pdu(DCERPC_TYPE_RESPONSE, pfc_flags, call_id, &body)
+    }
+
+    fn dcerpc_fault(call_id: u32, ctxid: u16, status: u32) -> Vec<u8> {
+        let mut body = 0_u32.to_le_bytes().to_vec();
+        body.extend_from_slice(&ctxid.to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00]);
+        body.extend_from_slice(&status.to_le_bytes());
+        body.extend_from_slice(&[0x00; 4]);
+        dcerpc_pdu(
+            DCERPC_TYPE_FAULT,
+            PFC_FIRST_FRAG | PFC_LAST_FRAG,
+            call_id,
+            &body,
+        )
+    }
+
+    #[test]
+    pub fn test_parse_dcerpc_fault() {
+        let mut dcerpc_state = DCERPCState::new();
+        let single = PFC_FIRST_FRAG | PFC_LAST_FRAG;
+
+        for call_id in 1..=DCERPC_OP_RNG_FAULTS_THRESHOLD {
+            let pdu = dcerpc_request(single, call_id, 1, call_id as u16 + 100, &[0x01]);
+            assert_eq!(
+                AppLayerResult::ok(),
+                dcerpc_state.handle_input_data(&pdu, Direction::ToServer)
+            );
+            // the fault may name another context than the request
+            let pdu = dcerpc_fault(call_id, 0, DCERPC_FAULT_OP_RNG_ERROR);
+            assert_eq!(
+                AppLayerResult::ok(),
+                dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+            );
+        }
+        assert_eq!(
+            DCERPC_OP_RNG_FAULTS_THRESHOLD as usize,
+            dcerpc_state.transactions.len()
+        );
+        let tx = &dcerpc_state.transactions[0];
+        assert!(tx.req_done && tx.resp_done);
+        assert_eq!(DCERPC_TYPE_FAULT, tx.resp_cmd);
+        assert_eq!(Some(DCERPC_FAULT_OP_RNG_ERROR), tx.fault_status);
+        assert_eq!(DCERPC_OP_RNG_FAULTS_THRESHOLD, dcerpc_state.op_rng_faults);
+
+        // the event is set once, when the threshold is reached
+        let mut count = DCERPC_OP_RNG_FAULTS_THRESHOLD - 1;
+        assert!(dcerpc_count_fault(&mut count, DCERPC_FAULT_OP_RNG_ERROR));
+        assert!(!dcerpc_count_fault(&mut count, DCERPC_FAULT_OP_RNG_ERROR));
+        // other faults are not counted
+        let mut count = 0;
+        assert!(!dcerpc_count_fault(&mut count, 0x00000005));
+        assert_eq!(0, count);
+        assert_eq!(
+            Some("nca_op_rng_error"),
+            dcerpc_fault_status_string(DCERPC_FAULT_OP_RNG_ERROR)
+        );
+        assert_eq!(
+            Some("nca_s_fault_access_denied"),
+            dcerpc_fault_status_string(5)
+        );
+        assert_eq!(None, dcerpc_fault_status_string(0x12345678));
+    }
+
+    #[test]
+    pub fn test_parse_dcerpc_pipelined_fragments() {
+        let mut dcerpc_state = DCERPCState::new();
+        let first = PFC_FIRST_FRAG;
+        let last = PFC_LAST_FRAG;
+        let single = PFC_FIRST_FRAG | PFC_LAST_FRAG;
+
+        // Call 1 is fragmented around call 2.
+        for pdu in [
+            dcerpc_request(first, 1, 0, 2, &[0x01, 0x02, 0x03, 0x04]),
+            dcerpc_request(single, 2, 0, 5, &[0x09, 0x09, 0x09, 0x09]),
+            dcerpc_request(last, 1, 0, 2, &[0x05, 0x06, 0x07, 0x08]),
+        ] {
+            assert_eq!(
+                AppLayerResult::ok(),
+                dcerpc_state.handle_input_data(&pdu, Direction::ToServer)
+            );
+        }
+        assert_eq!(2, dcerpc_state.transactions.len());
+        let tx = &dcerpc_state.transactions[0];
+        assert_eq!(1, tx.call_id);
+        assert_eq!(2, tx.opnum);
+        assert_eq!(2, tx.frag_cnt_ts);
+        assert!(tx.req_done);
+        let expected_stub: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        assert_eq!(expected_stub, tx.stub_data_buffer_ts.as_slice());
+        let tx = &dcerpc_state.transactions[1];
+        assert_eq!(2, tx.call_id);
+        assert_eq!(5, tx.opnum);
+        assert_eq!(1, tx.frag_cnt_ts);
+        assert!(tx.req_done);
+
+        // Responses come back in a different order, call 1 fragmented.
+        let pdu = dcerpc_response(single, 2, 0, &[0xaa]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        assert!(dcerpc_state.transactions[1].resp_done);
+        assert!(!dcerpc_state.transactions[0].resp_done);
+        let pdu = dcerpc_response(first, 1, 0, &[0xbb]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        assert!(!dcerpc_state.transactions[0].resp_done);
+        let pdu = dcerpc_response(last, 1, 0, &[0xcc]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        assert_eq!(2, dcerpc_state.transactions.len());
+        let tx = &dcerpc_state.transactions[0];
+        assert!(tx.resp_done);
+        assert_eq!(2, tx.frag_cnt_tc);
+        let tx = &dcerpc_state.transactions[1];
+        assert_eq!(1, tx.frag_cnt_tc);
+    }
+
+    #[test]
+    pub fn test_parse_dcerpc_call_id_per_context() {
+        let mut dcerpc_state = DCERPCState::new();
+        let single = PFC_FIRST_FRAG | PFC_LAST_FRAG;
+
+        // The same call id used on two presentation contexts.
+        for pdu in [
+            dcerpc_request(single, 7, 0, 1, &[0x01]),
+            dcerpc_request(single, 7, 1, 2, &[0x02]),
+        ] {
+            assert_eq!(
+                AppLayerResult::ok(),
+                dcerpc_state.handle_input_data(&pdu, Direction::ToServer)
+            );
+        }
+        let pdu = dcerpc_response(single, 7, 1, &[0x22]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        assert_eq!(2, dcerpc_state.transactions.len());
+        assert!(!dcerpc_state.transactions[0].resp_done);
+        assert!(dcerpc_state.transactions[1].resp_done);
+        let pdu = dcerpc_response(single, 7, 0, &[0x11]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        assert!(dcerpc_state.transactions[0].resp_done);
+
+        // A response seen before its request is completed by the request
+        // on the same context only.
+        let pdu = dcerpc_response(single, 9, 3, &[0x33]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToClient)
+        );
+        let pdu = dcerpc_request(single, 9, 2, 4, &[0x04]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToServer)
+        );
+        let pdu = dcerpc_request(single, 9, 3, 4, &[0x03]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpc_state.handle_input_data(&pdu, Direction::ToServer)
+        );
+        assert_eq!(4, dcerpc_state.transactions.len());
+        let tx = &dcerpc_state.transactions[2];
+        assert_eq!(3, tx.ctxid);
+        assert!(tx.req_done && tx.resp_done);
+        let tx = &dcerpc_state.transactions[3];
+        assert_eq!(2, tx.ctxid);
+        assert!(tx.req_done && !tx.resp_done);
+    }
+
+    fn enforce_tx_limit(
+        txs: &mut VecDeque<DCERPCTransaction>, state_data: &mut AppLayerStateData, max_tx: usize,
+        index: &mut usize, tx: &mut DCERPCTransaction,
+    ) -> bool {
+        let event = DCERPCEvent::TooManyTransactions as u8;
+        applayer_enforce_tx_limit(txs.iter_mut(), state_data, max_tx, index, tx, event)
+    }
+
+    #[test]
+    fn test_dcerpc_tx_limit() {
+        let mut txs: VecDeque<DCERPCTransaction> = VecDeque::new();
+        for _ in 0..3 {
+            txs.push_back(DCERPCTransaction::new());
+        }
+        txs[0].req_done = true;
+        txs[0].resp_done = true;
+        let mut index = 0;
+        let mut tx = DCERPCTransaction::new();
+        let mut sd = AppLayerStateData::new();
+
+        assert!(!enforce_tx_limit(&mut txs, &mut sd, 3, &mut index, &mut tx));
+        assert_eq!(index, 0);
+
+        // The first incomplete transaction is closed.
+        assert!(enforce_tx_limit(&mut txs, &mut sd, 2, &mut index, &mut tx));
+        assert_eq!(index, 2);
+        assert!(txs[1].req_done && txs[1].resp_done);
+        assert!(!txs[2].req_done);
+
+        assert!(enforce_tx_limit(&mut txs, &mut sd, 2, &mut index, &mut tx));
+        assert_eq!(index, 3);
+        assert!(txs[2].req_done && txs[2].resp_done);
+
+        // Nothing left to close.
+        assert!(enforce_tx_limit(&mut txs, &mut sd, 2, &mut index, &mut tx));
+        assert_eq!(index, 3);
+        assert!(!tx.req_done);
+        assert_eq!(sd.tx_limit_reached, 3);
+    }
 }
@@ -18,9 +18,12 @@
 use crate::applayer::{self, *};
 use crate::core::{self, Direction, DIR_BOTH};
 use crate::dcerpc::dcerpc::{
-    DCERPCTransaction, DCERPC_MAX_TX, DCERPC_TYPE_REQUEST, DCERPC_TYPE_RESPONSE, PFCL1_FRAG, PFCL1_LASTFRAG,
-    rs_dcerpc_get_alstate_progress, ALPROTO_DCERPC, PARSER_NAME,
+    dcerpc_count_fault, DCERPCEvent, DCERPCTransaction, DCERPC_LIMITS, DCERPC_TYPE_CANCEL_ACK,
+    DCERPC_TYPE_FAULT, DCERPC_TYPE_REQUEST, DCERPC_TYPE_RESPONSE, PFCL1_BROADCAST, PFCL1_FRAG,
+    PFCL1_IDEMPOTENT, PFCL1_LASTFRAG, PFCL1_MAYBE, rs_dcerpc_get_alstate_progress,
+    ALPROTO_DCERPC, PARSER_NAME,
 };
+use nom7::number::Endianness;
 use nom7::Err;
 use std;
 use std::ffi::CString;
@@ -59,6 +62,10 @@ pub struct DCERPCUDPState {
     pub tx_id: u64,
     pub transactions: VecDeque<DCERPCTransaction>,
     tx_index_completed: usize,
+    /// Bytes of stub data of the transactions.
+    stub_data_mem: usize,
+    /// Faults for out of range opnums.
+    op_rng_faults: u32,
 }
 
 impl State<DCERPCTransaction> for DCERPCUDPState {
@@ -81,20 +88,20 @@ impl DCERPCUDPState {
         tx.id = self.tx_id;
         tx.endianness = hdr.drep[0] & 0x10;
         tx.activityuuid = hdr.activityuuid.to_vec();
+        // the field is always present, a nil UUID means no object
+        if hdr.objectuuid.len() == 16 && hdr.objectuuid.iter().any(|&b| b != 0) {
+            tx.objectuuid = hdr.objectuuid.to_vec();
+        }
         tx.seqnum = hdr.seqnum;
         self.tx_id += 1;
-        if self.transactions.len() > unsafe { DCERPC_MAX_TX } {
-            let mut index = self.tx_index_completed;
-            for tx_old in &mut self.transactions.range_mut(self.tx_index_completed..) {
-                index += 1;
-                if !tx_old.req_done || !tx_old.resp_done {
-                    tx_old.req_done = true;
-                    tx_old.resp_done = true;
-                    break;
-                }
-            }
-            self.tx_index_completed = index;
-        }
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            DCERPC_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            DCERPCEvent::TooManyTransactions as u8,
+        );
         tx
     }
 
@@ -116,7 +123,9 @@ impl DCERPCUDPState {
             SCLogDebug!("freeing TX with ID {} TX.ID {} at index {} left: {} max id: {}",
                             tx_id, tx_id+1, index, self.transactions.len(), self.tx_id);
             self.tx_index_completed = 0;
-            self.transactions.remove(index);
+            if let Some(tx) = self.transactions.remove(index) {
+                self.stub_data_mem -= tx.stub_data_len();
+            }
         }
     }
 
@@ -145,16 +154,49 @@ impl DCERPCUDPState {
             tx.seqnum == hdr.seqnum
                 && tx.activityuuid == hdr.activityuuid
                 && ((hdr.pkt_type == DCERPC_TYPE_REQUEST && !tx.req_done)
-                    || (hdr.pkt_type == DCERPC_TYPE_RESPONSE && !tx.resp_done))
+                    || ((hdr.pkt_type == DCERPC_TYPE_RESPONSE
+                        || hdr.pkt_type == DCERPC_TYPE_FAULT)
+                        && !tx.resp_done))
         });
     }
 
+    /// Complete the call of a FAULT with its status.
+    fn handle_fault(&mut self, hdr: &DCERPCHdrUdp, input: &[u8]) -> bool {
+        let endianness = if hdr.drep[0] & 0x10 == 0 {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        };
+        let status = match parser::parse_dcerpc_udp_fault(input, endianness) {
+            Ok((_, status)) => status,
+            Err(_) => {
+                SCLogDebug!("Invalid DCERPC UDP FAULT");
+                return false;
+            }
+        };
+        let event = dcerpc_count_fault(&mut self.op_rng_faults, status);
+        let tx = if let Some(tx) = self.find_incomplete_tx(hdr) {
+            tx
+        } else {
+            let tx = self.create_tx(hdr);
+            self.transactions.push_back(tx);
+            self.transactions.back_mut().unwrap()
+        };
+        tx.set_fault(status, event);
+        tx.frag_cnt_tc += 1;
+        return true;
+    }
+
     pub fn handle_fragment_data(&mut self, hdr: &DCERPCHdrUdp, input: &[u8]) -> bool {
+        if hdr.pkt_type == DCERPC_TYPE_FAULT {
+            return self.handle_fault(hdr, input);
+        }
         if hdr.pkt_type != DCERPC_TYPE_REQUEST && hdr.pkt_type != DCERPC_TYPE_RESPONSE {
             SCLogDebug!("Unrecognized packet type");
             return false;
         }
 
+        let mut stub_data_mem = self.stub_data_mem;
         let mut otx = self.find_incomplete_tx(hdr);
         if otx.is_none() {
             let ntx = self.create_tx(hdr);
@@ -168,25 +210,31 @@ impl DCERPCUDPState {
 
             match hdr.pkt_type {
                 DCERPC_TYPE_REQUEST => {
-                    tx.stub_data_buffer_ts.extend_from_slice(input);
+                    tx.opnum = hdr.opnum;
+                    tx.first_request_seen = 1;
+                    tx.maybe = hdr.flags1 & PFCL1_MAYBE != 0;
+                    tx.idempotent = hdr.flags1 & PFCL1_IDEMPOTENT != 0;
+                    tx.broadcast = hdr.flags1 & PFCL1_BROADCAST != 0;
+                    tx.append_stub_data(input, Direction::ToServer, &mut stub_data_mem);
                     tx.frag_cnt_ts += 1;
                     if done {
                         tx.req_done = true;
                     }
-                    return true;
                 }
                 DCERPC_TYPE_RESPONSE => {
-                    tx.stub_data_buffer_tc.extend_from_slice(input);
+                    tx.append_stub_data(input, Direction::ToClient, &mut stub_data_mem);
                     tx.frag_cnt_tc += 1;
                     if done {
                         tx.resp_done = true;
                     }
-                    return true;
                 }
                 _ => {
                     // unreachable
+                    return false;
                 }
             }
+            self.stub_data_mem = stub_data_mem;
+            return true;
         }
         return false; // unreachable
     }
@@ -234,10 +282,11 @@ pub unsafe extern "C" fn rs_dcerpc_udp_parse(
     _data: *const std::os::raw::c_void,
 ) -> AppLayerResult {
     let state = cast_pointer!(state, DCERPCUDPState);
-    if !stream_slice.is_gap() {
-        return state.handle_input_data(stream_slice.as_slice());
+    let input = BorrowedInput::from(&stream_slice);
+    if input.is_gap() {
+        return AppLayerResult::error(AppLayerError::Gap);
     }
-    AppLayerResult::err()
+    state.handle_input_data(input.as_slice())
 }
 
 #[no_mangle]
@@ -291,15 +340,68 @@ pub unsafe extern "C" fn rs_dcerpc_udp_get_tx_cnt(vtx: *mut std::os::raw::c_void
     dce_state.tx_id
 }
 
+/// Get the activity UUID of a tx. It is the same for all the calls of a
+/// client activity, even when they are sent from other ports.
+#[no_mangle]
+pub unsafe extern "C" fn rs_dcerpc_udp_tx_get_activityuuid(
+    tx: &DCERPCTransaction, buf: *mut *const u8, len: *mut u32,
+) -> bool {
+    if tx.activityuuid.len() != 16 {
+        return false;
+    }
+    *buf = tx.activityuuid.as_ptr();
+    *len = tx.activityuuid.len() as u32;
+    true
+}
+
+/// Consistency checks of the header fields. The probing patterns are short
+/// so random UDP payloads regularly pass the version and drep checks.
+fn probe_header(hdr: &DCERPCHdrUdp, body_len: usize) -> bool {
+    // Connectionless PDU types only.
+    if hdr.pkt_type > DCERPC_TYPE_CANCEL_ACK {
+        return false;
+    }
+    // The fragment has to fit in the datagram; an auth verifier may follow.
+    if hdr.fraglen as usize > body_len {
+        return false;
+    }
+    // Broadcast calls are always idempotent.
+    if hdr.flags1 & PFCL1_BROADCAST != 0 && hdr.flags1 & PFCL1_IDEMPOTENT == 0 {
+        return false;
+    }
+    if hdr.interfaceuuid.iter().all(|&b| b == 0) {
+        return false;
+    }
+    if hdr.pkt_type == DCERPC_TYPE_REQUEST || hdr.pkt_type == DCERPC_TYPE_RESPONSE {
+        // The serial number is incremented for each fragment sent,
+        // retransmissions included, so it can't be below the fragment number.
+        let serial = (hdr.serial_hi as u16) << 8 | hdr.serial_lo as u16;
+        if serial < hdr.fragnum {
+            return false;
+        }
+        if hdr.flags1 & PFCL1_FRAG == 0 && hdr.fragnum != 0 {
+            return false;
+        }
+    }
+    // The client may not know the server boot time yet, the server does.
+    if (hdr.pkt_type == DCERPC_TYPE_RESPONSE || hdr.pkt_type == DCERPC_TYPE_FAULT)
+        && hdr.server_boot == 0
+    {
+        return false;
+    }
+    true
+}
+
 /// Probe input to see if it looks like DCERPC.
 fn probe(input: &[u8]) -> (bool, bool) {
     match parser::parse_dcerpc_udp_header(input) {
-        Ok((_, hdr)) => {
+        Ok((body, hdr)) => {
             let is_request = hdr.pkt_type == 0x00;
             let is_dcerpc = hdr.rpc_vers == 0x04 &&
                 (hdr.flags2 & 0xfc == 0) &&
                 (hdr.drep[0] & 0xee == 0) &&
-                (hdr.drep[1] <= 3);
+                (hdr.drep[1] <= 3) &&
+                probe_header(&hdr, body.len());
             return (is_dcerpc, is_request);
         },
         Err(_) => (false, false),
@@ -364,8 +466,8 @@ pub unsafe extern "C" fn rs_dcerpc_udp_register_parser() {
         tx_comp_st_ts: 1,
         tx_comp_st_tc: 1,
         tx_get_progress: rs_dcerpc_get_alstate_progress,
-        get_eventinfo: None,
-        get_eventinfo_byid: None,
+        get_eventinfo: Some(DCERPCEvent::get_event_info),
+        get_eventinfo_byid: Some(DCERPCEvent::get_event_info_by_id),
         localstorage_new: None,
         localstorage_free: None,
         get_tx_files: None,
@@ -386,7 +488,7 @@ pub unsafe extern "C" fn rs_dcerpc_udp_register_parser() {
             return;
         }
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
     } else {
         SCLogDebug!("Protocol detecter and parser disabled for DCERPC/UDP.");
@@ -397,9 +499,123 @@ pub unsafe extern "C" fn rs_dcerpc_udp_register_parser() {
 #[cfg(test)]
 mod tests {
     use crate::applayer::AppLayerResult;
-    use crate::dcerpc::dcerpc_udp::DCERPCUDPState;
+    use crate::dcerpc::dcerpc::{
+        DCERPC_TYPE_FAULT, PFCL1_BROADCAST, PFCL1_FRAG, PFCL1_IDEMPOTENT, PFCL1_MAYBE,
+    };
+    use crate::dcerpc::dcerpc_udp::{probe, rs_dcerpc_udp_tx_get_activityuuid, DCERPCUDPState};
     use crate::dcerpc::parser;
 
+    /// Little endian header followed by a body of `fraglen` bytes.
+    fn udp_pdu(pkt_type: u8, flags1: u8, fraglen: u16, fragnum: u16, serial: u16) -> Vec<u8> {
+        let mut pdu = vec![0x04, pkt_type, flags1, 0x00, 0x10, 0x00, 0x00, (serial >> 8) as u8];
+        pdu.extend_from_slice(&[0; 16]);
+        pdu.extend_from_slice(&[
+            0xb8, 0x4a, 0x9f, 0x4d, 0x1c, 0x7d, 0xcf, 0x11, 0x86, 0x1e, 0x00, 0x20, 0xaf, 0x6e,
+            0x7c, 0x57,
+        ]);
+        pdu.extend_from_slice(&[0x11; 16]);
+        pdu.extend_from_slice(&0x5f3a_0c21_u32.to_le_bytes());
+        pdu.extend_from_slice(&[0; 12]);
+        pdu.extend_from_slice(&[0xff, 0xff]);
+        pdu.extend_from_slice(&fraglen.to_le_bytes());
+        pdu.extend_from_slice(&fragnum.to_le_bytes());
+        pdu.extend_from_slice(&[0x00, serial as u8]);
+        pdu.extend(std::iter::repeat(0x90).take(fraglen as usize));
+        pdu
+    }
+
+    #[test]
+    fn test_probe_udp() {
+        assert_eq!(probe(&udp_pdu(0, 0, 16, 0, 0)), (true, true));
+        assert_eq!(probe(&udp_pdu(2, 0, 16, 0, 0)), (true, false));
+        assert_eq!(
+            probe(&udp_pdu(0, PFCL1_BROADCAST | PFCL1_IDEMPOTENT, 0, 0, 0)),
+            (true, true)
+        );
+        assert_eq!(probe(&udp_pdu(0, PFCL1_FRAG, 16, 2, 3)), (true, true));
+
+        // Connection oriented PDU type.
+        assert!(!probe(&udp_pdu(11, 0, 16, 0, 0)).0);
+        // Broadcast but not idempotent.
+        assert!(!probe(&udp_pdu(0, PFCL1_BROADCAST, 16, 0, 0)).0);
+        // Fragment longer than the datagram.
+        let mut pdu = udp_pdu(0, 0, 16, 0, 0);
+        pdu.truncate(90);
+        assert!(!probe(&pdu).0);
+        // Serial number lower than the fragment number.
+        assert!(!probe(&udp_pdu(0, PFCL1_FRAG, 16, 2, 1)).0);
+        // Fragment number of an unfragmented PDU.
+        assert!(!probe(&udp_pdu(0, 0, 16, 1, 1)).0);
+
+        // All zero interface uuid.
+        let mut pdu = udp_pdu(0, 0, 16, 0, 0);
+        pdu[24..40].fill(0);
+        assert!(!probe(&pdu).0);
+
+        // Response without the server boot time.
+        let mut pdu = udp_pdu(2, 0, 16, 0, 0);
+        pdu[56..60].fill(0);
+        assert!(!probe(&pdu).0);
+        // A request may not know it yet.
+        let mut pdu = udp_pdu(0, 0, 16, 0, 0);
+        pdu[56..60].fill(0);
+        assert!(probe(&pdu).0);
+    }
+
+    #[test]
+    fn test_tx_get_activityuuid() {
+        let mut dcerpcudp_state = DCERPCUDPState::new();
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpcudp_state.handle_input_data(&udp_pdu(0, 0, 16, 0, 0))
+        );
+        let mut buf: *const u8 = std::ptr::null();
+        let mut len = 0;
+        let tx = &mut dcerpcudp_state.transactions[0];
+        assert!(unsafe { rs_dcerpc_udp_tx_get_activityuuid(tx, &mut buf, &mut len) });
+        let uuid = unsafe { std::slice::from_raw_parts(buf, len as usize) };
+        assert_eq!(uuid, &[0x11; 16]);
+
+        tx.activityuuid = vec![0];
+        assert!(!unsafe { rs_dcerpc_udp_tx_get_activityuuid(tx, &mut buf, &mut len) });
+    }
+
+    #[test]
+    fn test_fault() {
+        let mut dcerpcudp_state = DCERPCUDPState::new();
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpcudp_state.handle_input_data(&udp_pdu(0, 0, 16, 0, 0))
+        );
+        let mut pdu = udp_pdu(DCERPC_TYPE_FAULT, 0, 4, 0, 0);
+        let len = pdu.len();
+        pdu[len - 4..].copy_from_slice(&0x1c010003_u32.to_le_bytes());
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpcudp_state.handle_input_data(&pdu)
+        );
+        assert_eq!(1, dcerpcudp_state.transactions.len());
+        let tx = &dcerpcudp_state.transactions[0];
+        assert!(tx.resp_done);
+        assert_eq!(DCERPC_TYPE_FAULT, tx.resp_cmd);
+        assert_eq!(Some(0x1c010003), tx.fault_status);
+        assert_eq!(0, dcerpcudp_state.op_rng_faults);
+    }
+
+    #[test]
+    fn test_request_flags() {
+        let mut dcerpcudp_state = DCERPCUDPState::new();
+        let pdu = udp_pdu(0, PFCL1_MAYBE | PFCL1_IDEMPOTENT, 16, 0, 0);
+        assert_eq!(
+            AppLayerResult::ok(),
+            dcerpcudp_state.handle_input_data(&pdu)
+        );
+        let tx = &dcerpcudp_state.transactions[0];
+        assert!(tx.maybe);
+        assert!(tx.idempotent);
+        assert!(!tx.broadcast);
+    }
+
     #[test]
     fn test_process_header_udp_incomplete_hdr() {
         let request: &[u8] = &[
@@ -16,15 +16,30 @@
  */
 
 use super::dcerpc::{
-    DCERPCState, DCERPCTransaction, DCERPC_TYPE_REQUEST, DCERPC_TYPE_RESPONSE,
-    DCERPC_UUID_ENTRY_FLAG_FF,
+    DCERPCState, DCERPCTransaction, ALPROTO_DCERPC, DCERPC_TYPE_REQUEST, DCERPC_TYPE_RESPONSE,
+    DCERPC_UUID_ENTRY_FLAG_FF, PFCL1_BROADCAST, PFCL1_IDEMPOTENT, PFCL1_MAYBE,
 };
-use crate::detect::uint::{detect_match_uint, detect_parse_uint, DetectUintData};
+use crate::detect::uint::{
+    detect_match_uint, detect_parse_uint, detect_parse_uint_inclusive, rs_detect_u8_free,
+    DetectUintData, DetectUintMode,
+};
+use crate::detect::{
+    DetectHelperBufferRegister, DetectHelperKeywordRegister, DetectSignatureSetAppProto,
+    SCSigTableElmt, SigMatchAppendSMToList,
+};
+use nom7::branch::alt;
+use nom7::bytes::complete::{is_a, tag};
+use nom7::combinator::{opt, value};
+use nom7::multi::many1;
+use nom7::IResult;
 use std::ffi::CStr;
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 use uuid::Uuid;
 
-pub const DETECT_DCE_OPNUM_RANGE_UNINITIALIZED: u32 = 100000;
+static mut G_DCERPC_OBJECT_UUID_KW_ID: c_int = 0;
+static mut G_DCERPC_OBJECT_UUID_BUFFER_ID: c_int = 0;
+static mut G_DCERPC_FLAGS_KW_ID: c_int = 0;
+static mut G_DCERPC_FLAGS_BUFFER_ID: c_int = 0;
 
 #[derive(Debug)]
 pub struct DCEIfaceData {
@@ -33,74 +48,59 @@ pub struct DCEIfaceData {
     pub any_frag: u8,
 }
 
+/// List of opnums, ranges or comparisons. The opnum matches if any of
+/// them matches.
 #[derive(Debug)]
-pub struct DCEOpnumRange {
-    pub range1: u32,
-    pub range2: u32,
-}
-
-impl Default for DCEOpnumRange {
-    fn default() -> Self {
-        Self::new()
-    }
+pub struct DCEOpnumData {
+    pub data: Vec<DetectUintData<u16>>,
 }
 
-impl DCEOpnumRange {
-    pub fn new() -> Self {
-        Self {
-            range1: DETECT_DCE_OPNUM_RANGE_UNINITIALIZED,
-            range2: DETECT_DCE_OPNUM_RANGE_UNINITIALIZED,
-        }
+impl DCEOpnumData {
+    pub fn is_match(&self, opnum: u16) -> bool {
+        self.data.iter().any(|du16| detect_match_uint(du16, opnum))
     }
 }
 
-#[derive(Debug)]
-pub struct DCEOpnumData {
-    pub data: Vec<DCEOpnumRange>,
-}
-
 fn match_backuuid(
     tx: &mut DCERPCTransaction, state: &mut DCERPCState, if_data: &mut DCEIfaceData,
 ) -> u8 {
     let mut ret = 0;
-    if let Some(ref bindack) = state.bindack {
-        for uuidentry in bindack.accepted_uuid_list.iter() {
-            ret = 1;
-            // if any_frag is not enabled, we need to match only against the first fragment
-            if if_data.any_frag == 0 && (uuidentry.flags & DCERPC_UUID_ENTRY_FLAG_FF == 0) {
-                SCLogDebug!("any frag not enabled");
-                continue;
-            }
-            // if the uuid has been rejected(uuidentry->result == 1), we skip to the next uuid
-            if uuidentry.result != 0 {
-                SCLogDebug!("Skipping to next UUID");
-                continue;
-            }
+    for uuidentry in state.contexts.iter() {
+        ret = 1;
+        // if any_frag is not enabled, we need to match only against the first fragment
+        if if_data.any_frag == 0 && (uuidentry.flags & DCERPC_UUID_ENTRY_FLAG_FF == 0) {
+            SCLogDebug!("any frag not enabled");
+            continue;
+        }
+        // if the uuid has been rejected(uuidentry->result == 1), we skip to the next uuid
+        if uuidentry.result != 0 {
+            SCLogDebug!("Skipping to next UUID");
+            continue;
+        }
 
-            for i in 0..16 {
-                if if_data.if_uuid[i] != uuidentry.uuid[i] {
-                    SCLogDebug!("Iface UUID and BINDACK Accepted UUID does not match");
-                    ret = 0;
-                    break;
-                }
-            }
-            let ctxid = tx.get_req_ctxid();
-            ret &= (uuidentry.ctxid == ctxid) as u8;
-            if ret == 0 {
-                SCLogDebug!("CTX IDs/UUIDs do not match");
-                continue;
+        for i in 0..16 {
+            if if_data.if_uuid[i] != uuidentry.uuid[i] {
+                SCLogDebug!("Iface UUID and BINDACK Accepted UUID does not match");
+                ret = 0;
+                break;
             }
+        }
+        let ctxid = tx.get_req_ctxid();
+        ret &= (uuidentry.ctxid == ctxid) as u8;
+        if ret == 0 {
+            SCLogDebug!("CTX IDs/UUIDs do not match");
+            continue;
+        }
 
-            if let Some(x) = &if_data.du16 {
-                if !detect_match_uint(x, uuidentry.version) {
-                    SCLogDebug!("Interface version did not match");
-                    ret &= 0;
-                }
+        if let Some(x) = &if_data.du16 {
+            if !detect_match_uint(x, uuidentry.version) {
+                SCLogDebug!("Interface version did not match");
+                ret &= 0;
             }
+        }
 
-            if ret == 1 {
-                return 1;
-            }
+        if ret == 1 {
+            return 1;
         }
     }
 
@@ -157,49 +157,40 @@ fn parse_iface_data(arg: &str) -> Result<DCEIfaceData, ()> {
     })
 }
 
-fn convert_str_to_u32(arg: &str) -> Result<u32, ()> {
-    match arg.parse::<u32>() {
-        Ok(res) => Ok(res),
-        _ => Err(()),
+/// Parse one opnum, `12`, `>100` or the inclusive range `2-5`.
+fn parse_opnum_value(arg: &str) -> Result<DetectUintData<u16>, ()> {
+    if let Ok((_, du16)) = detect_parse_uint_inclusive::<u16>(arg) {
+        return Ok(du16);
     }
+    // Ranges that start at 0, end at 65535 or hold a single opnum can
+    // not be stored as an exclusive range.
+    let (min, max) = arg.split_once('-').ok_or(())?;
+    let min = min.trim().parse::<u16>().map_err(|_| ())?;
+    let max = max.trim().parse::<u16>().map_err(|_| ())?;
+    let (mode, arg1) = if max < min {
+        return Err(());
+    } else if min == max {
+        (DetectUintMode::DetectUintModeEqual, min)
+    } else if min == 0 {
+        (DetectUintMode::DetectUintModeLte, max)
+    } else if max == u16::MAX {
+        (DetectUintMode::DetectUintModeGte, min)
+    } else {
+        return Err(());
+    };
+    Ok(DetectUintData {
+        arg1,
+        arg2: 0,
+        mode,
+    })
 }
 
 fn parse_opnum_data(arg: &str) -> Result<DCEOpnumData, ()> {
-    let split_args: Vec<&str> = arg.split(',').collect();
-    let mut dce_opnum_data: Vec<DCEOpnumRange> = Vec::new();
-    for range in split_args.iter() {
-        let mut opnum_range = DCEOpnumRange::new();
-        let split_range: Vec<&str> = range.split('-').collect();
-        let split_len = split_range.len();
-
-        if (split_len > 0 && convert_str_to_u32(split_range[0]).is_err())
-            || (split_len > 1 && convert_str_to_u32(split_range[1]).is_err())
-        {
-            return Err(());
-        }
-        match split_len {
-            1 => {
-                opnum_range.range1 = convert_str_to_u32(split_range[0]).unwrap();
-            }
-            2 => {
-                let range1 = convert_str_to_u32(split_range[0]).unwrap();
-                let range2 = convert_str_to_u32(split_range[1]).unwrap();
-                if range2 < range1 {
-                    return Err(());
-                }
-                opnum_range.range1 = range1;
-                opnum_range.range2 = range2;
-            }
-            _ => {
-                return Err(());
-            }
-        }
-        dce_opnum_data.push(opnum_range);
-    }
-
-    Ok(DCEOpnumData {
-        data: dce_opnum_data,
-    })
+    let data = arg
+        .split(',')
+        .map(parse_opnum_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(DCEOpnumData { data })
 }
 
 #[no_mangle]
@@ -254,18 +245,7 @@ pub unsafe extern "C" fn rs_dcerpc_opnum_match(
     if first_req_seen == 0 {
         return 0;
     }
-    let opnum = tx.get_req_opnum();
-    for range in opnum_data.data.iter() {
-        if range.range2 == DETECT_DCE_OPNUM_RANGE_UNINITIALIZED {
-            if range.range1 == opnum as u32 {
-                return 1;
-            }
-        } else if range.range1 <= opnum as u32 && range.range2 >= opnum as u32 {
-            return 1;
-        }
-    }
-
-    0
+    return opnum_data.is_match(tx.get_req_opnum()) as u8;
 }
 
 #[no_mangle]
@@ -293,10 +273,211 @@ pub unsafe extern "C" fn rs_dcerpc_opnum_free(ptr: *mut c_void) {
     }
 }
 
+fn parse_object_uuid(arg: &str) -> Option<Vec<u8>> {
+    Uuid::parse_str(arg.trim())
+        .ok()
+        .map(|uuid| uuid.as_bytes().to_vec())
+}
+
+unsafe extern "C" fn dcerpc_object_uuid_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DCERPC) != 0 {
+        return -1;
+    }
+    let arg = match CStr::from_ptr(raw).to_str() {
+        Ok(arg) => arg,
+        _ => {
+            return -1;
+        }
+    };
+    let ctx = match parse_object_uuid(arg) {
+        Some(uuid) => Box::into_raw(Box::new(uuid)) as *mut c_void,
+        None => {
+            SCLogError!("invalid uuid for dcerpc.object_uuid: {}", arg);
+            return -1;
+        }
+    };
+    if SigMatchAppendSMToList(
+        de,
+        s,
+        G_DCERPC_OBJECT_UUID_KW_ID,
+        ctx,
+        G_DCERPC_OBJECT_UUID_BUFFER_ID,
+    )
+    .is_null()
+    {
+        dcerpc_object_uuid_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn dcerpc_object_uuid_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, DCERPCTransaction);
+    let uuid = cast_pointer!(ctx, Vec<u8>);
+    return (!tx.objectuuid.is_empty() && tx.objectuuid == *uuid) as c_int;
+}
+
+unsafe extern "C" fn dcerpc_object_uuid_free(_de: *mut c_void, ctx: *mut c_void) {
+    std::mem::drop(Box::from_raw(ctx as *mut Vec<u8>));
+}
+
+struct DCEParsedFlagItem {
+    neg: bool,
+    value: u8,
+}
+
+fn parse_flag_list_item(s: &str) -> IResult<&str, DCEParsedFlagItem> {
+    let (s, _) = opt(is_a(" "))(s)?;
+    let (s, neg) = opt(tag("!"))(s)?;
+    let neg = neg.is_some();
+    let (s, value) = alt((
+        value(PFCL1_MAYBE, tag("maybe")),
+        value(PFCL1_IDEMPOTENT, tag("idempotent")),
+        value(PFCL1_BROADCAST, tag("broadcast")),
+    ))(s)?;
+    let (s, _) = opt(is_a(" ,"))(s)?;
+    Ok((s, DCEParsedFlagItem { neg, value }))
+}
+
+/// Parse the `dcerpc.flags` value: a number or a list of flag names,
+/// each one optionally negated.
+fn parse_flags(s: &str) -> Option<DetectUintData<u8>> {
+    // try first numerical value
+    if let Ok((_, ctx)) = detect_parse_uint::<u8>(s) {
+        return Some(ctx);
+    }
+    // otherwise, try strings for bitmask
+    if let Ok((rem, l)) = many1(parse_flag_list_item)(s) {
+        if !rem.is_empty() {
+            SCLogWarning!("junk at the end of dcerpc.flags");
+            return None;
+        }
+        let mut arg1 = 0;
+        let mut arg2 = 0;
+        for elem in l.iter() {
+            if elem.value & arg1 != 0 {
+                SCLogWarning!("Repeated bitflag for dcerpc.flags");
+                return None;
+            }
+            arg1 |= elem.value;
+            if !elem.neg {
+                arg2 |= elem.value;
+            }
+        }
+        let ctx = DetectUintData::<u8> {
+            arg1,
+            arg2,
+            mode: DetectUintMode::DetectUintModeBitmask,
+        };
+        return Some(ctx);
+    }
+    return None;
+}
+
+unsafe extern "C" fn dcerpc_flags_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DCERPC) != 0 {
+        return -1;
+    }
+    let arg = match CStr::from_ptr(raw).to_str() {
+        Ok(arg) => arg,
+        _ => {
+            return -1;
+        }
+    };
+    let ctx = match parse_flags(arg) {
+        Some(ctx) => Box::into_raw(Box::new(ctx)) as *mut c_void,
+        None => {
+            SCLogError!("invalid value for dcerpc.flags: {}", arg);
+            return -1;
+        }
+    };
+    if SigMatchAppendSMToList(de, s, G_DCERPC_FLAGS_KW_ID, ctx, G_DCERPC_FLAGS_BUFFER_ID).is_null()
+    {
+        dcerpc_flags_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+/// Match the flags of a request over UDP: the requests over TCP have
+/// none of them.
+fn dcerpc_tx_has_flags(tx: &DCERPCTransaction, ctx: &DetectUintData<u8>) -> bool {
+    if tx.activityuuid.is_empty() || tx.first_request_seen == 0 {
+        return false;
+    }
+    let mut v = 0;
+    if tx.maybe {
+        v |= PFCL1_MAYBE;
+    }
+    if tx.idempotent {
+        v |= PFCL1_IDEMPOTENT;
+    }
+    if tx.broadcast {
+        v |= PFCL1_BROADCAST;
+    }
+    detect_match_uint(ctx, v)
+}
+
+unsafe extern "C" fn dcerpc_flags_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, DCERPCTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u8>);
+    return dcerpc_tx_has_flags(tx, ctx) as c_int;
+}
+
+unsafe extern "C" fn dcerpc_flags_free(_de: *mut c_void, ctx: *mut c_void) {
+    let ctx = cast_pointer!(ctx, DetectUintData<u8>);
+    rs_detect_u8_free(ctx);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectDcerpcRegister() {
+    let kw = SCSigTableElmt {
+        name: b"dcerpc.object_uuid\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the object UUID of a DCERPC request\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dcerpc-keywords.html#dcerpc-object-uuid\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(dcerpc_object_uuid_match),
+        Setup: dcerpc_object_uuid_setup,
+        Free: Some(dcerpc_object_uuid_free),
+        flags: 0,
+    };
+    G_DCERPC_OBJECT_UUID_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DCERPC_OBJECT_UUID_BUFFER_ID = DetectHelperBufferRegister(
+        b"dcerpc.object_uuid\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DCERPC,
+        false,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dcerpc.flags\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the flags of a DCERPC request over UDP\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dcerpc-keywords.html#dcerpc-flags\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(dcerpc_flags_match),
+        Setup: dcerpc_flags_setup,
+        Free: Some(dcerpc_flags_free),
+        flags: 0,
+    };
+    G_DCERPC_FLAGS_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DCERPC_FLAGS_BUFFER_ID = DetectHelperBufferRegister(
+        b"dcerpc.flags\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DCERPC,
+        false,
+        true,
+    );
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::detect::uint::DetectUintMode;
 
     fn extract_op_version(i: &str) -> Result<(DetectUintMode, u16), ()> {
         match detect_parse_uint(i) {
@@ -434,38 +615,36 @@ mod test {
         let arg = "12";
         let opnum_data = parse_opnum_data(arg).unwrap();
         assert_eq!(1, opnum_data.data.len());
-        assert_eq!(12, opnum_data.data[0].range1);
-        assert_eq!(
-            DETECT_DCE_OPNUM_RANGE_UNINITIALIZED,
-            opnum_data.data[0].range2
-        );
+        assert!(opnum_data.is_match(12));
+        assert!(!opnum_data.is_match(13));
 
         let arg = "12,24";
         let opnum_data = parse_opnum_data(arg).unwrap();
         assert_eq!(2, opnum_data.data.len());
-        assert_eq!(12, opnum_data.data[0].range1);
-        assert_eq!(24, opnum_data.data[1].range1);
+        assert!(opnum_data.is_match(12));
+        assert!(opnum_data.is_match(24));
+        assert!(!opnum_data.is_match(18));
 
         let arg = "12,12-24";
         let opnum_data = parse_opnum_data(arg).unwrap();
         assert_eq!(2, opnum_data.data.len());
-        assert_eq!(12, opnum_data.data[0].range1);
-        assert_eq!(12, opnum_data.data[1].range1);
-        assert_eq!(24, opnum_data.data[1].range2);
+        assert!(opnum_data.is_match(12));
+        assert!(opnum_data.is_match(24));
+        assert!(!opnum_data.is_match(25));
 
         let arg = "12-14,12,121,62-78";
         let opnum_data = parse_opnum_data(arg).unwrap();
         assert_eq!(4, opnum_data.data.len());
-        assert_eq!(12, opnum_data.data[0].range1);
-        assert_eq!(14, opnum_data.data[0].range2);
-        assert_eq!(121, opnum_data.data[2].range1);
-        assert_eq!(78, opnum_data.data[3].range2);
+        assert!(opnum_data.is_match(14));
+        assert!(opnum_data.is_match(121));
+        assert!(opnum_data.is_match(78));
+        assert!(!opnum_data.is_match(79));
 
         let arg = "12,26,62,61,6513-6666";
         let opnum_data = parse_opnum_data(arg).unwrap();
         assert_eq!(5, opnum_data.data.len());
-        assert_eq!(61, opnum_data.data[3].range1);
-        assert_eq!(6513, opnum_data.data[4].range1);
+        assert!(opnum_data.is_match(61));
+        assert!(opnum_data.is_match(6513));
 
         let arg = "12,26,62,61,6513--";
         let opnum_data = parse_opnum_data(arg);
@@ -475,4 +654,80 @@ mod test {
         let opnum_data = parse_opnum_data(arg);
         assert!(opnum_data.is_err());
     }
+
+    #[test]
+    fn test_parse_opnum_data_ranges() {
+        let opnum_data = parse_opnum_data("2-5, 12").unwrap();
+        assert!(!opnum_data.is_match(1));
+        assert!(opnum_data.is_match(2));
+        assert!(opnum_data.is_match(5));
+        assert!(!opnum_data.is_match(6));
+        assert!(opnum_data.is_match(12));
+
+        // ranges the shared parser can not store as an exclusive range
+        let opnum_data = parse_opnum_data("0-3,7-7,65000-65535").unwrap();
+        assert!(opnum_data.is_match(0));
+        assert!(opnum_data.is_match(3));
+        assert!(!opnum_data.is_match(4));
+        assert!(opnum_data.is_match(7));
+        assert!(!opnum_data.is_match(8));
+        assert!(opnum_data.is_match(65535));
+
+        let opnum_data = parse_opnum_data(">100").unwrap();
+        assert!(!opnum_data.is_match(100));
+        assert!(opnum_data.is_match(101));
+
+        assert!(parse_opnum_data("70000").is_err());
+        assert!(parse_opnum_data("").is_err());
+        assert!(parse_opnum_data("2,").is_err());
+    }
+
+    #[test]
+    fn test_parse_object_uuid() {
+        let uuid = parse_object_uuid(" 12345678-1234-1234-1234-123456789ABC").unwrap();
+        assert_eq!(
+            uuid,
+            vec![
+                0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x12, 0x34, 0x12, 0x34, 0x12, 0x34, 0x56, 0x78,
+                0x9a, 0xbc,
+            ]
+        );
+        assert!(parse_object_uuid("12345678-1234-1234-1234").is_none());
+        assert!(parse_object_uuid("").is_none());
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        let ctx = parse_flags("maybe, !idempotent").unwrap();
+        assert_eq!(ctx.arg1, PFCL1_MAYBE | PFCL1_IDEMPOTENT);
+        assert_eq!(ctx.arg2, PFCL1_MAYBE);
+        assert_eq!(ctx.mode, DetectUintMode::DetectUintModeBitmask);
+
+        let ctx = parse_flags("0x40").unwrap();
+        assert_eq!(ctx.arg1, PFCL1_BROADCAST);
+        assert_eq!(ctx.mode, DetectUintMode::DetectUintModeEqual);
+
+        assert!(parse_flags("maybe,!maybe").is_none());
+        assert!(parse_flags("maybe,nofack").is_none());
+        assert!(parse_flags("").is_none());
+    }
+
+    #[test]
+    fn test_dcerpc_tx_has_flags() {
+        let mut tx = DCERPCTransaction::new();
+        tx.first_request_seen = 1;
+        tx.maybe = true;
+        let ctx = parse_flags("maybe,!idempotent").unwrap();
+        // request over TCP
+        assert!(!dcerpc_tx_has_flags(&tx, &ctx));
+
+        tx.activityuuid = vec![0; 16];
+        assert!(dcerpc_tx_has_flags(&tx, &ctx));
+        tx.idempotent = true;
+        assert!(!dcerpc_tx_has_flags(&tx, &ctx));
+        let ctx = parse_flags("idempotent").unwrap();
+        assert!(dcerpc_tx_has_flags(&tx, &ctx));
+        let ctx = parse_flags("broadcast").unwrap();
+        assert!(!dcerpc_tx_has_flags(&tx, &ctx));
+    }
 }
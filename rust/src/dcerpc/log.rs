@@ -18,8 +18,37 @@ use uuid::Uuid;
 
 use crate::dcerpc::dcerpc::*;
 use crate::dcerpc::dcerpc_udp::*;
+use crate::dcerpc::svcctl::svcctl_log_create_service;
 use crate::jsonbuilder::{JsonBuilder, JsonError};
 
+/// Log the request parameters decoded from the stub data.
+pub fn log_dcerpc_stub_request(
+    jsb: &mut JsonBuilder, req: &DCERPCStubRequest,
+) -> Result<(), JsonError> {
+    match req {
+        DCERPCStubRequest::SvcctlCreateService(svc) => svcctl_log_create_service(jsb, svc),
+    }
+}
+
+fn log_dcerpc_object_uuid(jsb: &mut JsonBuilder, tx: &DCERPCTransaction) -> Result<(), JsonError> {
+    if let Ok(objectuuid) = Uuid::from_slice(tx.objectuuid.as_slice()) {
+        jsb.set_string("object_uuid", &objectuuid.to_hyphenated().to_string())?;
+    }
+    Ok(())
+}
+
+fn log_dcerpc_fault(jsb: &mut JsonBuilder, tx: &DCERPCTransaction) -> Result<(), JsonError> {
+    if let Some(status) = tx.fault_status {
+        jsb.open_object("fault")?;
+        jsb.set_string("status", &format!("0x{:08x}", status))?;
+        if let Some(name) = dcerpc_fault_status_string(status) {
+            jsb.set_string("name", name)?;
+        }
+        jsb.close()?;
+    }
+    Ok(())
+}
+
 fn log_dcerpc_header_tcp(
     jsb: &mut JsonBuilder, state: &DCERPCState, tx: &DCERPCTransaction,
 ) -> Result<(), JsonError> {
@@ -31,6 +60,10 @@ fn log_dcerpc_header_tcp(
                 jsb.set_uint("opnum", tx.opnum as u64)?;
                 jsb.set_uint("frag_cnt", tx.frag_cnt_ts as u64)?;
                 jsb.set_uint("stub_data_size", tx.stub_data_buffer_ts.len() as u64)?;
+                log_dcerpc_object_uuid(jsb, tx)?;
+                if let Some(req) = &tx.stub_request {
+                    log_dcerpc_stub_request(jsb, req)?;
+                }
                 jsb.close()?;
             }
             DCERPC_TYPE_BIND => if let Some(bind) = &state.bind {
@@ -55,7 +88,6 @@ fn log_dcerpc_header_tcp(
 
     if tx.resp_done && !tx.resp_lost {
         jsb.set_string("response", &dcerpc_type_string(tx.resp_cmd))?;
-        #[allow(clippy::single_match)]
         match tx.resp_cmd {
             DCERPC_TYPE_RESPONSE => {
                 jsb.open_object("res")?;
@@ -63,6 +95,9 @@ fn log_dcerpc_header_tcp(
                 jsb.set_uint("stub_data_size", tx.stub_data_buffer_tc.len() as u64)?;
                 jsb.close()?;
             }
+            DCERPC_TYPE_FAULT => {
+                log_dcerpc_fault(jsb, tx)?;
+            }
             _ => {} // replicating behavior from smb
         }
     } else {
@@ -90,6 +125,10 @@ fn log_dcerpc_header_udp(
                 jsb.set_uint("opnum", tx.opnum as u64)?;
                 jsb.set_uint("frag_cnt", tx.frag_cnt_ts as u64)?;
                 jsb.set_uint("stub_data_size", tx.stub_data_buffer_ts.len() as u64)?;
+                log_dcerpc_object_uuid(jsb, tx)?;
+                jsb.set_bool("maybe", tx.maybe)?;
+                jsb.set_bool("idempotent", tx.idempotent)?;
+                jsb.set_bool("broadcast", tx.broadcast)?;
                 jsb.close()?;
             }
             _ => {}
@@ -100,7 +139,6 @@ fn log_dcerpc_header_udp(
 
     if tx.resp_done && !tx.resp_lost {
         jsb.set_string("response", &dcerpc_type_string(tx.resp_cmd))?;
-        #[allow(clippy::single_match)]
         match tx.resp_cmd {
             DCERPC_TYPE_RESPONSE => {
                 jsb.open_object("res")?;
@@ -108,14 +146,17 @@ fn log_dcerpc_header_udp(
                 jsb.set_uint("stub_data_size", tx.stub_data_buffer_tc.len() as u64)?;
                 jsb.close()?;
             }
+            DCERPC_TYPE_FAULT => {
+                log_dcerpc_fault(jsb, tx)?;
+            }
             _ => {} // replicating behavior from smb
         }
     } else {
         jsb.set_string("response", "UNREPLIED")?;
     }
-    let activityuuid = Uuid::from_slice(tx.activityuuid.as_slice());
-    let activityuuid = activityuuid.map(|uuid| uuid.to_hyphenated().to_string()).unwrap();
-    jsb.set_string("activityuuid", &activityuuid)?;
+    if let Ok(activityuuid) = Uuid::from_slice(tx.activityuuid.as_slice()) {
+        jsb.set_string("activityuuid", &activityuuid.to_hyphenated().to_string())?;
+    }
     jsb.set_uint("seqnum", tx.seqnum as u64)?;
     jsb.set_string("rpc_version", "4.0")?;
     return Ok(());
@@ -0,0 +1,313 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! NDR (Network Data Representation) decoding helpers for DCE/RPC stub
+//! data.
+//!
+//! NDR aligns primitive types on their natural size relative to the start
+//! of the stub data, so decoding uses a cursor over the complete stub
+//! buffer of a transaction rather than nom parsers over sub-slices. The
+//! integer byte order is taken from the data representation (drep) of the
+//! PDU header.
+//!
+//! Interface specific stub decoders are expected to be built on top of
+//! this, e.g.:
+//!
+//! ```ignore
+//! let mut ndr = tx.ndr_stub(Direction::ToServer);
+//! let server = ndr.unique_wide_string()?;
+//! let access_mask = ndr.u32()?;
+//! ```
+
+use nom7::number::Endianness;
+
+/// Drep flag for little endian integers in the first drep byte.
+pub const NDR_DREP_LITTLE_ENDIAN: u8 = 0x10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NdrError {
+    /// Not enough stub data left.
+    Incomplete,
+    /// A count, offset or length is inconsistent with the data.
+    Invalid,
+}
+
+pub type NdrResult<T> = Result<T, NdrError>;
+
+/// Cursor over NDR encoded stub data.
+#[derive(Debug, Clone)]
+pub struct NdrReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    endianness: Endianness,
+}
+
+impl<'a> NdrReader<'a> {
+    pub fn new(data: &'a [u8], endianness: Endianness) -> Self {
+        Self {
+            data,
+            offset: 0,
+            endianness,
+        }
+    }
+
+    /// Create a reader using the integer representation from the first
+    /// drep byte, as stored in `DCERPCTransaction::endianness`.
+    pub fn from_drep(data: &'a [u8], drep_0: u8) -> Self {
+        let endianness = if drep_0 & NDR_DREP_LITTLE_ENDIAN != 0 {
+            Endianness::Little
+        } else {
+            Endianness::Big
+        };
+        Self::new(data, endianness)
+    }
+
+    /// Offset from the start of the stub data.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of bytes left to decode.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    /// Skip padding up to the next multiple of `align`.
+    pub fn align(&mut self, align: usize) -> NdrResult<()> {
+        let pad = (align - self.offset % align) % align;
+        self.take(pad)?;
+        Ok(())
+    }
+
+    /// Take `len` unaligned bytes.
+    pub fn take(&mut self, len: usize) -> NdrResult<&'a [u8]> {
+        if len > self.remaining() {
+            return Err(NdrError::Incomplete);
+        }
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> NdrResult<[u8; N]> {
+        self.align(N)?;
+        let mut buf = [0; N];
+        buf.copy_from_slice(self.take(N)?);
+        Ok(buf)
+    }
+
+    pub fn u8(&mut self) -> NdrResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> NdrResult<u16> {
+        let buf = self.take_array::<2>()?;
+        Ok(match self.endianness {
+            Endianness::Big => u16::from_be_bytes(buf),
+            _ => u16::from_le_bytes(buf),
+        })
+    }
+
+    pub fn u32(&mut self) -> NdrResult<u32> {
+        let buf = self.take_array::<4>()?;
+        Ok(match self.endianness {
+            Endianness::Big => u32::from_be_bytes(buf),
+            _ => u32::from_le_bytes(buf),
+        })
+    }
+
+    /// NDR `hyper`.
+    pub fn u64(&mut self) -> NdrResult<u64> {
+        let buf = self.take_array::<8>()?;
+        Ok(match self.endianness {
+            Endianness::Big => u64::from_be_bytes(buf),
+            _ => u64::from_le_bytes(buf),
+        })
+    }
+
+    /// Referent id of a unique (or full) pointer. Returns None for a
+    /// NULL pointer, in which case no referent follows.
+    pub fn unique_ptr(&mut self) -> NdrResult<Option<u32>> {
+        let referent = self.u32()?;
+        Ok(if referent == 0 { None } else { Some(referent) })
+    }
+
+    /// Maximum count of a conformant array. The count is checked
+    /// against the remaining data, assuming elements of at least
+    /// `elem_size` bytes. An `elem_size` of 0 is taken as 1, as every
+    /// element takes some data.
+    pub fn conformance(&mut self, elem_size: usize) -> NdrResult<u32> {
+        let max_count = self.u32()?;
+        if (max_count as usize).saturating_mul(elem_size.max(1)) > self.remaining() {
+            return Err(NdrError::Invalid);
+        }
+        Ok(max_count)
+    }
+
+    /// Offset and actual count of a varying array.
+    pub fn variance(&mut self) -> NdrResult<(u32, u32)> {
+        let offset = self.u32()?;
+        let actual_count = self.u32()?;
+        Ok((offset, actual_count))
+    }
+
+    /// Conformant array of elements decoded by `elem`. `elem_size` is
+    /// the minimum encoded size of an element.
+    pub fn conformant_array<T, F>(&mut self, elem_size: usize, mut elem: F) -> NdrResult<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> NdrResult<T>,
+    {
+        let count = self.conformance(elem_size)?;
+        let mut items = Vec::with_capacity((count as usize).min(self.remaining()));
+        for _ in 0..count {
+            items.push(elem(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Conformant varying array of bytes.
+    pub fn conformant_varying_bytes(&mut self) -> NdrResult<&'a [u8]> {
+        let max_count = self.u32()?;
+        let (offset, actual_count) = self.variance()?;
+        if offset.saturating_add(actual_count) > max_count {
+            return Err(NdrError::Invalid);
+        }
+        self.take(actual_count as usize)
+    }
+
+    /// Conformant array of bytes.
+    pub fn conformant_bytes(&mut self) -> NdrResult<&'a [u8]> {
+        let max_count = self.conformance(1)?;
+        self.take(max_count as usize)
+    }
+
+    /// Conformant varying string of 8-bit characters, as used for
+    /// `[string] char *`. A trailing NUL is removed.
+    pub fn string(&mut self) -> NdrResult<String> {
+        let mut chars = self.conformant_varying_bytes()?;
+        if let Some((&0, head)) = chars.split_last() {
+            chars = head;
+        }
+        Ok(String::from_utf8_lossy(chars).to_string())
+    }
+
+    /// Unique pointer to an 8-bit character string.
+    pub fn unique_string(&mut self) -> NdrResult<Option<String>> {
+        if self.unique_ptr()?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(self.string()?))
+    }
+
+    /// Conformant varying string of UTF-16 characters, as used for
+    /// `[string] wchar_t *`. A trailing NUL is removed.
+    pub fn wide_string(&mut self) -> NdrResult<String> {
+        let max_count = self.u32()?;
+        let (offset, actual_count) = self.variance()?;
+        if offset.saturating_add(actual_count) > max_count
+            || (actual_count as usize).saturating_mul(2) > self.remaining()
+        {
+            return Err(NdrError::Invalid);
+        }
+        let mut chars = Vec::with_capacity(actual_count as usize);
+        for _ in 0..actual_count {
+            chars.push(self.u16()?);
+        }
+        if chars.last() == Some(&0) {
+            chars.pop();
+        }
+        Ok(String::from_utf16_lossy(&chars))
+    }
+
+    /// Unique pointer to a wide string, with the string following the
+    /// pointer directly as it does for top level parameters.
+    pub fn unique_wide_string(&mut self) -> NdrResult<Option<String>> {
+        if self.unique_ptr()?.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(self.wide_string()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ndr_aligned_integers() {
+        #[rustfmt::skip]
+        let buf: &[u8] = &[
+            0x01, 0x00, 0x02, 0x01, 0x04, 0x03, 0x02, 0x01, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02,
+            0x01,
+        ];
+        let mut ndr = NdrReader::from_drep(buf, 0x10);
+        assert_eq!(ndr.u8(), Ok(1));
+        assert_eq!(ndr.u16(), Ok(0x0102));
+        assert_eq!(ndr.u32(), Ok(0x01020304));
+        assert_eq!(ndr.u64(), Ok(0x0102030405060708));
+        assert_eq!(ndr.remaining(), 0);
+        assert_eq!(ndr.u8(), Err(NdrError::Incomplete));
+
+        let mut ndr = NdrReader::from_drep(&buf[2..], 0x00);
+        assert_eq!(ndr.u16(), Ok(0x0201));
+        assert_eq!(ndr.u32(), Ok(0x02010807));
+    }
+
+    #[test]
+    fn test_ndr_unique_wide_string() {
+        #[rustfmt::skip]
+        let buf: &[u8] = &[
+            // referent id
+            0x00, 0x00, 0x02, 0x00,
+            // max count, offset, actual count
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+            // "AB\0"
+            0x41, 0x00, 0x42, 0x00, 0x00, 0x00,
+            // padding, then a NULL pointer
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut ndr = NdrReader::from_drep(buf, 0x10);
+        assert_eq!(ndr.unique_wide_string(), Ok(Some("AB".to_string())));
+        assert_eq!(ndr.offset(), 22);
+        assert_eq!(ndr.unique_wide_string(), Ok(None));
+        assert_eq!(ndr.offset(), 28);
+    }
+
+    #[test]
+    fn test_ndr_conformant_array() {
+        let buf: &[u8] = &[
+            0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        ];
+        let mut ndr = NdrReader::from_drep(buf, 0x10);
+        assert_eq!(ndr.conformant_array(4, |ndr| ndr.u32()), Ok(vec![10, 11]));
+
+        // Count larger than the data left.
+        let buf: &[u8] = &[0xff, 0xff, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00];
+        let mut ndr = NdrReader::from_drep(buf, 0x10);
+        assert_eq!(
+            ndr.conformant_array(4, |ndr| ndr.u32()),
+            Err(NdrError::Invalid)
+        );
+
+        // A zero element size still bounds the count.
+        let mut ndr = NdrReader::from_drep(buf, 0x10);
+        assert_eq!(
+            ndr.conformant_array(0, |ndr| ndr.u32()),
+            Err(NdrError::Invalid)
+        );
+    }
+}
@@ -15,7 +15,8 @@
  * 02110-1301, USA.
  */
 use crate::dcerpc::dcerpc::{
-    BindCtxItem, DCERPCBind, DCERPCBindAck, DCERPCBindAckResult, DCERPCHdr, DCERPCRequest, Uuid,
+    BindCtxItem, DCERPCBind, DCERPCBindAck, DCERPCBindAckResult, DCERPCFault, DCERPCHdr,
+    DCERPCRequest, DCERPCResponse, Uuid,
 };
 use crate::dcerpc::dcerpc_udp::DCERPCHdrUdp;
 use nom7::bytes::streaming::take;
@@ -228,18 +229,62 @@ pub fn parse_dcerpc_header(i: &[u8]) -> IResult<&[u8], DCERPCHdr> {
     Ok((i, header))
 }
 
-pub fn parse_dcerpc_request(i: &[u8], endianness: Endianness) -> IResult<&[u8], DCERPCRequest> {
+/// Parse the request PDU body. The object UUID follows the opnum if the
+/// PFC_OBJECT_UUID flag is set in the header.
+pub fn parse_dcerpc_request(
+    i: &[u8], endianness: Endianness, object: bool,
+) -> IResult<&[u8], DCERPCRequest> {
     let (i, _pad) = take(4_usize)(i)?;
     let (i, ctxid) = u16(endianness)(i)?;
     let (i, opnum) = u16(endianness)(i)?;
+    let (i, object_uuid) = cond(object, parse_uuid)(i)?;
+    let object_uuid = object_uuid.map(|uuid| match endianness {
+        Endianness::Little => assemble_uuid(uuid),
+        _ => uuid_to_vec(uuid),
+    });
     let req = DCERPCRequest {
         ctxid,
         opnum,
         first_request_seen: 1,
+        object_uuid,
     };
     Ok((i, req))
 }
 
+pub fn parse_dcerpc_response(i: &[u8], endianness: Endianness) -> IResult<&[u8], DCERPCResponse> {
+    let (i, alloc_hint) = u32(endianness)(i)?;
+    let (i, ctxid) = u16(endianness)(i)?;
+    let (i, cancel_count) = le_u8(i)?;
+    let (i, _reserved) = le_u8(i)?;
+    let resp = DCERPCResponse {
+        alloc_hint,
+        ctxid,
+        cancel_count,
+    };
+    Ok((i, resp))
+}
+
+pub fn parse_dcerpc_fault(i: &[u8], endianness: Endianness) -> IResult<&[u8], DCERPCFault> {
+    let (i, alloc_hint) = u32(endianness)(i)?;
+    let (i, ctxid) = u16(endianness)(i)?;
+    let (i, cancel_count) = le_u8(i)?;
+    let (i, _reserved) = le_u8(i)?;
+    let (i, status) = u32(endianness)(i)?;
+    let (i, _reserved2) = u32(endianness)(i)?;
+    let fault = DCERPCFault {
+        alloc_hint,
+        ctxid,
+        cancel_count,
+        status,
+    };
+    Ok((i, fault))
+}
+
+/// Parse the status of a connectionless FAULT or REJECT body.
+pub fn parse_dcerpc_udp_fault(i: &[u8], endianness: Endianness) -> IResult<&[u8], u32> {
+    u32(endianness)(i)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,6 +366,66 @@ mod tests {
         assert_eq!(1024, header.frag_length);
     }
 
+    #[test]
+    fn test_parse_dcerpc_request_object_uuid() {
+        let request: &[u8] = &[
+            0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x09, 0x00, // alloc hint, ctxid, opnum
+            0x78, 0x56, 0x34, 0x12, 0x34, 0x12, 0x34, 0x12, 0x12, 0x34, 0x12, 0x34, 0x56, 0x78,
+            0x9a, 0xbc, // object uuid
+            0xaa,
+        ];
+        let (remainder, req) = parse_dcerpc_request(request, Endianness::Little, true).unwrap();
+        assert_eq!(1, req.ctxid);
+        assert_eq!(9, req.opnum);
+        assert_eq!(
+            Some(vec![
+                0x12, 0x34, 0x56, 0x78, 0x12, 0x34, 0x12, 0x34, 0x12, 0x34, 0x12, 0x34, 0x56, 0x78,
+                0x9a, 0xbc,
+            ]),
+            req.object_uuid
+        );
+        assert_eq!(&[0xaa_u8], remainder);
+
+        // without the object flag the uuid bytes are stub data
+        let (remainder, req) = parse_dcerpc_request(request, Endianness::Little, false).unwrap();
+        assert_eq!(None, req.object_uuid);
+        assert_eq!(17, remainder.len());
+    }
+
+    #[test]
+    fn test_parse_dcerpc_response() {
+        let response: &[u8] = &[0x10, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0xaa];
+        let (remainder, resp) = parse_dcerpc_response(response, Endianness::Little).unwrap();
+        assert_eq!(16, resp.alloc_hint);
+        assert_eq!(3, resp.ctxid);
+        assert_eq!(0, resp.cancel_count);
+        assert_eq!(&[0xaa_u8], remainder);
+
+        let response: &[u8] = &[0x00, 0x00, 0x00, 0x10, 0x00, 0x03, 0x00, 0x00];
+        let (_, resp) = parse_dcerpc_response(response, Endianness::Big).unwrap();
+        assert_eq!(16, resp.alloc_hint);
+        assert_eq!(3, resp.ctxid);
+    }
+
+    #[test]
+    fn test_parse_dcerpc_fault() {
+        let fault: &[u8] = &[
+            0x20, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x1c, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let (remainder, fault) = parse_dcerpc_fault(fault, Endianness::Little).unwrap();
+        assert_eq!(32, fault.alloc_hint);
+        assert_eq!(1, fault.ctxid);
+        assert_eq!(0x1c010002, fault.status);
+        assert!(remainder.is_empty());
+        // the second reserved field is missing
+        assert!(parse_dcerpc_fault(&[0; 12], Endianness::Big).is_err());
+
+        let (_, status) =
+            parse_dcerpc_udp_fault(&[0x1c, 0x01, 0x00, 0x03], Endianness::Big).unwrap();
+        assert_eq!(0x1c010003, status);
+    }
+
     #[test]
     fn test_parse_dcerpc_bind() {
         let dcerpcbind: &[u8] = &[
@@ -0,0 +1,185 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! SVCCTL (MS-SCMR, Service Control Manager Remote Protocol) stub
+//! decoding.
+
+use crate::dcerpc::ndr::{NdrReader, NdrResult};
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+
+/// SVCCTL interface: 367abb81-9844-35f1-ad32-98f038001003
+pub const SVCCTL_UUID: [u8; 16] = [
+    0x36, 0x7a, 0xbb, 0x81, 0x98, 0x44, 0x35, 0xf1, 0xad, 0x32, 0x98, 0xf0, 0x38, 0x00, 0x10, 0x03,
+];
+
+pub const SVCCTL_OPNUM_CREATE_SERVICE_W: u16 = 12;
+pub const SVCCTL_OPNUM_CREATE_SERVICE_A: u16 = 24;
+
+/// Size of an RPC context handle.
+const SVCCTL_HANDLE_SIZE: usize = 20;
+
+/// Parameters of a RCreateServiceW/RCreateServiceA request.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SvcctlCreateService {
+    pub service_name: String,
+    pub display_name: Option<String>,
+    pub service_type: u32,
+    pub start_type: u32,
+    pub binary_path: String,
+    pub start_name: Option<String>,
+}
+
+fn svcctl_string(ndr: &mut NdrReader, wide: bool) -> NdrResult<String> {
+    if wide {
+        ndr.wide_string()
+    } else {
+        ndr.string()
+    }
+}
+
+fn svcctl_unique_string(ndr: &mut NdrReader, wide: bool) -> NdrResult<Option<String>> {
+    if wide {
+        ndr.unique_wide_string()
+    } else {
+        ndr.unique_string()
+    }
+}
+
+fn parse_create_service_start_name(ndr: &mut NdrReader, wide: bool) -> NdrResult<Option<String>> {
+    let _load_order_group = svcctl_unique_string(ndr, wide)?;
+    if ndr.unique_ptr()?.is_some() {
+        let _tag_id = ndr.u32()?;
+    }
+    if ndr.unique_ptr()?.is_some() {
+        let _dependencies = ndr.conformant_bytes()?;
+    }
+    let _depend_size = ndr.u32()?;
+    svcctl_unique_string(ndr, wide)
+}
+
+/// Decode the request stub of RCreateServiceW (`wide`) or
+/// RCreateServiceA.
+pub fn parse_create_service(ndr: &mut NdrReader, wide: bool) -> NdrResult<SvcctlCreateService> {
+    ndr.take(SVCCTL_HANDLE_SIZE)?;
+    let service_name = svcctl_string(ndr, wide)?;
+    let display_name = svcctl_unique_string(ndr, wide)?;
+    let _desired_access = ndr.u32()?;
+    let service_type = ndr.u32()?;
+    let start_type = ndr.u32()?;
+    let _error_control = ndr.u32()?;
+    let binary_path = svcctl_string(ndr, wide)?;
+    let mut svc = SvcctlCreateService {
+        service_name,
+        display_name,
+        service_type,
+        start_type,
+        binary_path,
+        start_name: None,
+    };
+
+    // The account the service runs as is further down the stub; keep
+    // what has been decoded so far if the rest is truncated.
+    if let Ok(start_name) = parse_create_service_start_name(ndr, wide) {
+        svc.start_name = start_name;
+    }
+    Ok(svc)
+}
+
+/// Decode a SVCCTL request stub. Returns None for opnums that are not
+/// decoded or a stub that could not be decoded.
+pub fn svcctl_decode_request(opnum: u16, ndr: &mut NdrReader) -> Option<SvcctlCreateService> {
+    let wide = match opnum {
+        SVCCTL_OPNUM_CREATE_SERVICE_W => true,
+        SVCCTL_OPNUM_CREATE_SERVICE_A => false,
+        _ => {
+            return None;
+        }
+    };
+    parse_create_service(ndr, wide).ok()
+}
+
+pub fn svcctl_log_create_service(
+    jsb: &mut JsonBuilder, svc: &SvcctlCreateService,
+) -> Result<(), JsonError> {
+    jsb.open_object("svcctl")?;
+    jsb.set_string("operation", "create_service")?;
+    jsb.set_string("service_name", &svc.service_name)?;
+    if let Some(display_name) = &svc.display_name {
+        jsb.set_string("display_name", display_name)?;
+    }
+    jsb.set_string("binary_path", &svc.binary_path)?;
+    jsb.set_uint("service_type", svc.service_type as u64)?;
+    jsb.set_uint("start_type", svc.start_type as u64)?;
+    if let Some(start_name) = &svc.start_name {
+        jsb.set_string("start_name", start_name)?;
+    }
+    jsb.close()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wide(s: &str) -> Vec<u8> {
+        let chars: Vec<u16> = s.encode_utf16().chain(std::iter::once(0)).collect();
+        let mut buf = Vec::new();
+        for len in [chars.len() as u32, 0, chars.len() as u32] {
+            buf.extend_from_slice(&len.to_le_bytes());
+        }
+        for c in chars {
+            buf.extend_from_slice(&c.to_le_bytes());
+        }
+        while buf.len() % 4 != 0 {
+            buf.push(0);
+        }
+        buf
+    }
+
+    #[test]
+    fn test_svcctl_create_service_w() {
+        let mut stub = vec![0; SVCCTL_HANDLE_SIZE];
+        stub.extend(wide("PSEXESVC"));
+        stub.extend_from_slice(&[0x00, 0x00, 0x02, 0x00]);
+        stub.extend(wide("PSEXESVC"));
+        for value in [0x000f01ff_u32, 0x10, 3, 0] {
+            stub.extend_from_slice(&value.to_le_bytes());
+        }
+        stub.extend(wide("%SystemRoot%\\PSEXESVC.exe"));
+        // load order group, tag id, dependencies: NULL
+        stub.extend_from_slice(&[0; 12]);
+        // depend size, then a NULL start name
+        stub.extend_from_slice(&[0; 8]);
+
+        let mut ndr = NdrReader::from_drep(&stub, 0x10);
+        let svc = svcctl_decode_request(SVCCTL_OPNUM_CREATE_SERVICE_W, &mut ndr).unwrap();
+        assert_eq!(svc.service_name, "PSEXESVC");
+        assert_eq!(svc.display_name.as_deref(), Some("PSEXESVC"));
+        assert_eq!(svc.service_type, 0x10);
+        assert_eq!(svc.start_type, 3);
+        assert_eq!(svc.binary_path, "%SystemRoot%\\PSEXESVC.exe");
+        assert_eq!(svc.start_name, None);
+
+        // Truncated after the binary path.
+        let mut ndr = NdrReader::from_drep(&stub[..stub.len() - 20], 0x10);
+        let svc = svcctl_decode_request(SVCCTL_OPNUM_CREATE_SERVICE_W, &mut ndr).unwrap();
+        assert_eq!(svc.binary_path, "%SystemRoot%\\PSEXESVC.exe");
+
+        let mut ndr = NdrReader::from_drep(&stub, 0x10);
+        assert_eq!(svcctl_decode_request(15, &mut ndr), None);
+    }
+}
@@ -387,6 +387,20 @@ pub unsafe extern "C" fn rs_detect_u64_parse(
     return std::ptr::null_mut();
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn rs_detect_u64_parse_inclusive(
+    ustr: *const std::os::raw::c_char,
+) -> *mut DetectUintData<u64> {
+    let ft_name: &CStr = CStr::from_ptr(ustr); //unsafe
+    if let Ok(s) = ft_name.to_str() {
+        if let Ok((_, ctx)) = detect_parse_uint_inclusive::<u64>(s) {
+            let boxed = Box::new(ctx);
+            return Box::into_raw(boxed) as *mut _;
+        }
+    }
+    return std::ptr::null_mut();
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_detect_u64_match(
     arg: u64, ctx: &DetectUintData<u64>,
@@ -582,6 +596,45 @@ mod tests {
         assert_eq!(val.arg1, 3 * 1024 * 1024);
     }
 
+    #[test]
+    fn test_parse_uint_u64() {
+        let (_, val) = detect_parse_uint::<u64>("5000000000-6gb").unwrap();
+        assert_eq!(val.mode, DetectUintMode::DetectUintModeRange);
+        assert_eq!(val.arg1, 5_000_000_000);
+        assert_eq!(val.arg2, 6 * 1024 * 1024 * 1024);
+        assert!(!detect_match_uint(&val, 5_000_000_000));
+        assert!(detect_match_uint(&val, 5_000_000_001));
+        assert!(!detect_match_uint(&val, u32::MAX as u64));
+
+        let (_, val) = detect_parse_uint::<u64>("!5000000000-6000000000").unwrap();
+        assert_eq!(val.mode, DetectUintMode::DetectUintModeNegRg);
+        assert!(detect_match_uint(&val, 1_000));
+        assert!(!detect_match_uint(&val, 5_500_000_000));
+        assert!(detect_match_uint(&val, 7_000_000_000));
+
+        let (_, val) = detect_parse_uint::<u64>("!=0x100000000").unwrap();
+        assert_eq!(val.mode, DetectUintMode::DetectUintModeNe);
+        assert!(detect_match_uint(&val, 0));
+        assert!(!detect_match_uint(&val, 1 << 32));
+
+        let (_, val) = detect_parse_uint::<u64>(">4294967295").unwrap();
+        assert!(detect_match_uint(&val, 1 << 32));
+        assert!(!detect_match_uint(&val, u32::MAX as u64));
+        assert!(detect_parse_uint::<u32>(">4294967296").is_err());
+        assert!(detect_parse_uint::<u32>("5gb").is_err());
+
+        let (_, val) = detect_parse_uint::<u64>("18446744073709551615").unwrap();
+        assert_eq!(val.arg1, u64::MAX);
+        assert!(detect_parse_uint::<u64>(">18446744073709551615").is_err());
+        assert!(detect_parse_uint::<u64>("18446744073709551616").is_err());
+        assert!(detect_parse_uint::<u64>("17179869184gb").is_err());
+
+        let (_, val) = detect_parse_uint_inclusive::<u64>("5000000000-6000000000").unwrap();
+        assert!(detect_match_uint(&val, 5_000_000_000));
+        assert!(detect_match_uint(&val, 6_000_000_000));
+        assert!(!detect_match_uint(&val, 6_000_000_001));
+    }
+
     #[test]
     fn test_parse_uint_like_mqtt_protocol_version() {
         let (_, val) = detect_parse_uint::<u8>("3").unwrap();
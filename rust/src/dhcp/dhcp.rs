@@ -308,7 +308,7 @@ pub unsafe extern "C" fn rs_dhcp_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_DHCP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
     } else {
         SCLogDebug!("Protocol detector and parser disabled for DHCP.");
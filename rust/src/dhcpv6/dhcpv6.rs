@@ -0,0 +1,413 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, Dhcpv6Message, Dhcpv6MessageType};
+use super::servers::{dhcpv6_track_server, register_rogue_server_detection};
+use crate::applayer::{self, *};
+use crate::core::{
+    AppProto, Direction, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, DIR_BOTH, IPPROTO_UDP,
+};
+use crate::detect::EnumString;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(super) static mut ALPROTO_DHCPV6: AppProto = ALPROTO_UNKNOWN;
+
+#[derive(AppLayerEvent)]
+pub enum Dhcpv6Event {
+    /// Not a valid DHCPv6 message.
+    MalformedMessage,
+    /// Option with a value that could not be parsed.
+    MalformedOptions,
+    /// Client or server identifier of an invalid length.
+    InvalidDuid,
+    /// Advertise or Reply without a server identifier.
+    MissingServerId,
+    /// Answer of a server while too many others answered on the flow
+    /// within the window.
+    MultipleServers,
+}
+
+/// Each message is its own transaction, as for DHCP.
+#[derive(Debug, Default)]
+pub struct Dhcpv6Transaction {
+    tx_id: u64,
+    /// None if the message could not be parsed.
+    pub message: Option<Dhcpv6Message>,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for Dhcpv6Transaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+#[derive(Default)]
+pub struct Dhcpv6State {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<Dhcpv6Transaction>,
+}
+
+impl State<Dhcpv6Transaction> for Dhcpv6State {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&Dhcpv6Transaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl Dhcpv6State {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&Dhcpv6Transaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self, direction: Direction) -> Dhcpv6Transaction {
+        self.tx_id += 1;
+        Dhcpv6Transaction {
+            tx_id: self.tx_id,
+            tx_data: AppLayerTxData::for_direction(direction),
+            ..Default::default()
+        }
+    }
+
+    /// Set the events of a parsed message and track the server of an
+    /// answer.
+    fn handle_message(&mut self, tx: &mut Dhcpv6Transaction, message: &Dhcpv6Message, ts: u64) {
+        if message.malformed_options {
+            tx.tx_data.set_event(Dhcpv6Event::MalformedOptions as u8);
+        }
+        if message.invalid_duid {
+            tx.tx_data.set_event(Dhcpv6Event::InvalidDuid as u8);
+        }
+        if !matches!(
+            Dhcpv6MessageType::from_u(message.msg_type),
+            Some(Dhcpv6MessageType::Advertise) | Some(Dhcpv6MessageType::Reply)
+        ) {
+            return;
+        }
+        match (&message.server_id, &message.client_id) {
+            (Some(server_id), Some(client_id)) => {
+                if dhcpv6_track_server(client_id, server_id, ts) {
+                    SCLogDebug!("DHCPv6 answer from an additional server");
+                    tx.tx_data.set_event(Dhcpv6Event::MultipleServers as u8);
+                }
+            }
+            (Some(_), None) => {}
+            (None, _) => {
+                tx.tx_data.set_event(Dhcpv6Event::MissingServerId as u8);
+            }
+        }
+    }
+
+    fn parse(&mut self, input: &[u8], direction: Direction, ts: u64) -> AppLayerResult {
+        if input.is_empty() {
+            return AppLayerResult::ok();
+        }
+        let mut tx = self.new_tx(direction);
+        match parser::parse_message(input) {
+            Ok((_, message)) => {
+                self.handle_message(&mut tx, &message, ts);
+                tx.message = Some(message);
+            }
+            Err(_) => {
+                tx.tx_data.set_event(Dhcpv6Event::MalformedMessage as u8);
+            }
+        }
+        self.transactions.push_back(tx);
+        AppLayerResult::ok()
+    }
+}
+
+/// Probe for a message of a known type filling the datagram. Messages of
+/// servers and relay replies go to the client.
+fn probe(input: &[u8]) -> Option<Direction> {
+    let msg_type = Dhcpv6MessageType::from_u(*input.first()?)?;
+    let (_, msg) = parser::parse_message(input).ok()?;
+    Dhcpv6MessageType::from_u(msg.msg_type)?;
+    if msg_type.is_to_client() {
+        Some(Direction::ToClient)
+    } else {
+        Some(Direction::ToServer)
+    }
+}
+
+unsafe fn dhcpv6_flow_time(flow: *const Flow) -> u64 {
+    let flow = cast_pointer!(flow, Flow);
+    flow.get_last_time().as_secs()
+}
+
+// C exports.
+
+unsafe extern "C" fn rs_dhcpv6_probing_parser(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    match probe(slice) {
+        Some(dir) => {
+            if (direction & DIR_BOTH) != dir.into() {
+                *rdir = dir as u8;
+            }
+            ALPROTO_DHCPV6
+        }
+        None => ALPROTO_FAILED,
+    }
+}
+
+extern "C" fn rs_dhcpv6_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = Dhcpv6State::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_dhcpv6_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut Dhcpv6State));
+}
+
+unsafe extern "C" fn rs_dhcpv6_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, Dhcpv6State);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_dhcpv6_parse_request(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, Dhcpv6State);
+    let ts = dhcpv6_flow_time(flow);
+    state.parse(stream_slice.as_slice(), Direction::ToServer, ts)
+}
+
+unsafe extern "C" fn rs_dhcpv6_parse_response(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, Dhcpv6State);
+    let ts = dhcpv6_flow_time(flow);
+    state.parse(stream_slice.as_slice(), Direction::ToClient, ts)
+}
+
+unsafe extern "C" fn rs_dhcpv6_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, Dhcpv6State);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_dhcpv6_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, Dhcpv6State);
+    return state.tx_id;
+}
+
+extern "C" fn rs_dhcpv6_tx_get_alstate_progress(_tx: *mut c_void, _direction: u8) -> c_int {
+    // Each message is a complete transaction.
+    return 1;
+}
+
+export_tx_data_get!(rs_dhcpv6_get_tx_data, Dhcpv6Transaction);
+export_state_data_get!(rs_dhcpv6_get_state_data, Dhcpv6State);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"dhcpv6\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterDhcpv6Parser() {
+    let default_port = CString::new("[546,547]").unwrap();
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: Some(rs_dhcpv6_probing_parser),
+        probe_tc: Some(rs_dhcpv6_probing_parser),
+        min_depth: 0,
+        max_depth: parser::DHCPV6_HEADER_LEN as u16,
+        state_new: rs_dhcpv6_state_new,
+        state_free: rs_dhcpv6_state_free,
+        tx_free: rs_dhcpv6_state_tx_free,
+        parse_ts: rs_dhcpv6_parse_request,
+        parse_tc: rs_dhcpv6_parse_response,
+        get_tx_count: rs_dhcpv6_state_get_tx_count,
+        get_tx: rs_dhcpv6_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_dhcpv6_tx_get_alstate_progress,
+        get_eventinfo: Some(Dhcpv6Event::get_event_info),
+        get_eventinfo_byid: Some(Dhcpv6Event::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<Dhcpv6State, Dhcpv6Transaction>),
+        get_tx_data: rs_dhcpv6_get_tx_data,
+        get_state_data: rs_dhcpv6_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: None,
+        get_frame_name_by_id: None,
+    };
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_DHCPV6 = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        register_rogue_server_detection();
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_DHCPV6);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for DHCPv6.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn option(code: u16, data: &[u8]) -> Vec<u8> {
+        let mut buf = code.to_be_bytes().to_vec();
+        buf.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    fn message(msg_type: Dhcpv6MessageType, server: Option<u8>) -> Vec<u8> {
+        client_message(0x95, msg_type, server)
+    }
+
+    fn client_message(client: u8, msg_type: Dhcpv6MessageType, server: Option<u8>) -> Vec<u8> {
+        let mut buf = vec![msg_type as u8, 0x12, 0x34, 0x56];
+        buf.extend(option(
+            parser::DHCPV6_OPT_CLIENTID,
+            &[0, 3, 0, 1, 0x08, 0x00, 0x27, 0xfe, 0x8f, client],
+        ));
+        if let Some(id) = server {
+            buf.extend(option(
+                parser::DHCPV6_OPT_SERVERID,
+                &[0, 3, 0, 1, 0x52, 0x54, 0x00, 0x00, 0x00, id],
+            ));
+        }
+        buf
+    }
+
+    #[test]
+    fn test_dhcpv6_probe() {
+        let solicit = message(Dhcpv6MessageType::Solicit, None);
+        assert_eq!(probe(&solicit), Some(Direction::ToServer));
+        let advertise = message(Dhcpv6MessageType::Advertise, Some(1));
+        assert_eq!(probe(&advertise), Some(Direction::ToClient));
+        // Unknown message type.
+        let mut buf = solicit.clone();
+        buf[0] = 0;
+        assert_eq!(probe(&buf), None);
+        // Options not filling the datagram.
+        let mut buf = solicit;
+        buf.push(0);
+        assert_eq!(probe(&buf), None);
+        assert_eq!(probe(&[]), None);
+    }
+
+    #[test]
+    fn test_dhcpv6_parse() {
+        let mut state = Dhcpv6State::new();
+        let solicit = message(Dhcpv6MessageType::Solicit, None);
+        state.parse(&solicit, Direction::ToServer, 100);
+        state.parse(
+            &message(Dhcpv6MessageType::Advertise, Some(1)),
+            Direction::ToClient,
+            100,
+        );
+        state.parse(
+            &message(Dhcpv6MessageType::Advertise, Some(2)),
+            Direction::ToClient,
+            101,
+        );
+        state.parse(
+            &message(Dhcpv6MessageType::Reply, None),
+            Direction::ToClient,
+            102,
+        );
+        state.parse(&[0x07, 0x00], Direction::ToClient, 102);
+
+        assert_eq!(state.transactions.len(), 5);
+        let tx = &state.transactions[1];
+        let server_id = tx.message.as_ref().unwrap().server_id.as_ref().unwrap();
+        assert_eq!(server_id.type_str(), Some("ll"));
+        assert!(state.transactions[3].message.is_some());
+        // Malformed messages are kept for their event.
+        assert!(state.transactions[4].message.is_none());
+
+        state.free_tx(0);
+        assert_eq!(state.transactions.len(), 4);
+        assert!(state.get_tx(0).is_none());
+        assert_eq!(state.get_tx(4).unwrap().id(), 5);
+    }
+
+    #[test]
+    fn test_dhcpv6_servers_separate_flows() {
+        // each server answers the client on its own flow
+        let mut flow1 = Dhcpv6State::new();
+        flow1.parse(
+            &client_message(0x01, Dhcpv6MessageType::Advertise, Some(1)),
+            Direction::ToClient,
+            100,
+        );
+        let mut flow2 = Dhcpv6State::new();
+        flow2.parse(
+            &client_message(0x01, Dhcpv6MessageType::Advertise, Some(2)),
+            Direction::ToClient,
+            101,
+        );
+        // both servers are known for the client, whatever the flow
+        let client = parser::Dhcpv6Duid {
+            data: vec![0, 3, 0, 1, 0x08, 0x00, 0x27, 0xfe, 0x8f, 0x01],
+        };
+        let server = |id| parser::Dhcpv6Duid {
+            data: vec![0, 3, 0, 1, 0x52, 0x54, 0x00, 0x00, 0x00, id],
+        };
+        assert!(!dhcpv6_track_server(&client, &server(1), 102));
+        assert!(!dhcpv6_track_server(&client, &server(2), 102));
+        assert!(dhcpv6_track_server(&client, &server(3), 102));
+    }
+}
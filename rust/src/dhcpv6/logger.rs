@@ -0,0 +1,174 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::dhcpv6::Dhcpv6Transaction;
+use super::parser::{
+    Dhcpv6Duid, Dhcpv6Ia, Dhcpv6Message, Dhcpv6MessageType, Dhcpv6Status, Dhcpv6StatusCode,
+};
+use crate::detect::EnumString;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+/// Ethernet hardware type.
+const HW_TYPE_ETHERNET: u16 = 1;
+
+fn log_duid(name: &str, duid: &Dhcpv6Duid, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object(name)?;
+    js.set_hex("duid", &duid.data)?;
+    if let Some(duid_type) = duid.type_str() {
+        js.set_string("type", duid_type)?;
+    }
+    if let Some((hw_type, addr)) = duid.link_layer_address() {
+        if hw_type == HW_TYPE_ETHERNET && addr.len() == 6 {
+            let mac: Vec<String> = addr.iter().map(|b| format!("{:02x}", b)).collect();
+            js.set_string("mac", &mac.join(":"))?;
+        } else {
+            js.set_hex("link_layer_address", addr)?;
+        }
+    }
+    if let Some(en) = duid.enterprise_number() {
+        js.set_uint("enterprise_number", en as u64)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+fn log_status(status: &Dhcpv6Status, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("status")?;
+    js.set_uint("code", status.code as u64)?;
+    if let Some(code) = Dhcpv6StatusCode::from_u(status.code) {
+        js.set_string("name", code.to_str())?;
+    }
+    if !status.message.is_empty() {
+        js.set_string_from_bytes("message", &status.message)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+fn log_ias(name: &str, ias: &[Dhcpv6Ia], js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_array(name)?;
+    for ia in ias {
+        js.start_object()?;
+        js.set_uint("iaid", ia.iaid as u64)?;
+        js.set_uint("t1", ia.t1 as u64)?;
+        js.set_uint("t2", ia.t2 as u64)?;
+        if !ia.addresses.is_empty() {
+            js.open_array("addresses")?;
+            for addr in &ia.addresses {
+                js.start_object()?;
+                js.set_string("address", &addr.address.to_string())?;
+                js.set_uint("preferred_lifetime", addr.preferred_lifetime as u64)?;
+                js.set_uint("valid_lifetime", addr.valid_lifetime as u64)?;
+                js.close()?;
+            }
+            js.close()?;
+        }
+        if !ia.prefixes.is_empty() {
+            js.open_array("prefixes")?;
+            for prefix in &ia.prefixes {
+                js.start_object()?;
+                js.set_string(
+                    "prefix",
+                    &format!("{}/{}", prefix.prefix, prefix.prefix_len),
+                )?;
+                js.set_uint("preferred_lifetime", prefix.preferred_lifetime as u64)?;
+                js.set_uint("valid_lifetime", prefix.valid_lifetime as u64)?;
+                js.close()?;
+            }
+            js.close()?;
+        }
+        if let Some(ref status) = ia.status {
+            log_status(status, js)?;
+        }
+        js.close()?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+fn log_message_type(name: &str, msg_type: u8, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    if let Some(t) = Dhcpv6MessageType::from_u(msg_type) {
+        js.set_string(name, t.to_str())?;
+    } else {
+        js.set_uint(name, msg_type as u64)?;
+    }
+    Ok(())
+}
+
+fn log_dhcpv6(msg: &Dhcpv6Message, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("dhcpv6")?;
+    log_message_type("type", msg.msg_type, js)?;
+    js.set_uint("transaction_id", msg.transaction_id as u64)?;
+    if !msg.relays.is_empty() {
+        js.open_array("relays")?;
+        for relay in &msg.relays {
+            js.start_object()?;
+            log_message_type("type", relay.msg_type, js)?;
+            js.set_uint("hop_count", relay.hop_count as u64)?;
+            js.set_string("link_address", &relay.link_address.to_string())?;
+            js.set_string("peer_address", &relay.peer_address.to_string())?;
+            js.close()?;
+        }
+        js.close()?;
+    }
+    if let Some(ref client_id) = msg.client_id {
+        log_duid("client_id", client_id, js)?;
+    }
+    if let Some(ref server_id) = msg.server_id {
+        log_duid("server_id", server_id, js)?;
+    }
+    if !msg.ia_na.is_empty() {
+        log_ias("ia_na", &msg.ia_na, js)?;
+    }
+    if !msg.ia_pd.is_empty() {
+        log_ias("ia_pd", &msg.ia_pd, js)?;
+    }
+    if let Some(ref status) = msg.status {
+        log_status(status, js)?;
+    }
+    if let Some(preference) = msg.preference {
+        js.set_uint("preference", preference as u64)?;
+    }
+    if let Some(elapsed_time) = msg.elapsed_time {
+        js.set_uint("elapsed_time", elapsed_time as u64)?;
+    }
+    if !msg.requested_options.is_empty() {
+        js.open_array("requested_options")?;
+        for code in &msg.requested_options {
+            js.append_uint(*code as u64)?;
+        }
+        js.close()?;
+    }
+    if msg.rapid_commit {
+        js.set_bool("rapid_commit", true)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDhcpv6LoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, Dhcpv6Transaction);
+    match tx.message {
+        Some(ref msg) => log_dhcpv6(msg, js).is_ok(),
+        // only the events of a malformed message are of interest
+        None => false,
+    }
+}
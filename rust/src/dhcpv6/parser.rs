@@ -0,0 +1,564 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! DHCPv6 (RFC 8415) message parsing.
+
+use nom7::bytes::complete::take;
+use nom7::combinator::{all_consuming, rest};
+use nom7::error::{make_error, ErrorKind};
+use nom7::multi::many0;
+use nom7::number::complete::{be_u16, be_u24, be_u32, be_u8};
+use nom7::{Err, IResult};
+use std::net::Ipv6Addr;
+use suricata_derive::{EnumStringU16, EnumStringU8};
+
+/// Length of the header of a client or server message.
+pub const DHCPV6_HEADER_LEN: usize = 4;
+/// Length of the header of a relay message.
+pub const DHCPV6_RELAY_HEADER_LEN: usize = 34;
+
+/// Relay agents drop messages relayed this many times.
+const DHCPV6_HOP_COUNT_LIMIT: usize = 8;
+
+/// A DUID is a 2 bytes type followed by up to 128 bytes.
+const DHCPV6_DUID_MIN_LEN: usize = 3;
+const DHCPV6_DUID_MAX_LEN: usize = 130;
+
+pub const DHCPV6_OPT_CLIENTID: u16 = 1;
+pub const DHCPV6_OPT_SERVERID: u16 = 2;
+pub const DHCPV6_OPT_IA_NA: u16 = 3;
+pub const DHCPV6_OPT_IAADDR: u16 = 5;
+pub const DHCPV6_OPT_ORO: u16 = 6;
+pub const DHCPV6_OPT_PREFERENCE: u16 = 7;
+pub const DHCPV6_OPT_ELAPSED_TIME: u16 = 8;
+pub const DHCPV6_OPT_RELAY_MSG: u16 = 9;
+pub const DHCPV6_OPT_STATUS_CODE: u16 = 13;
+pub const DHCPV6_OPT_RAPID_COMMIT: u16 = 14;
+pub const DHCPV6_OPT_IA_PD: u16 = 25;
+pub const DHCPV6_OPT_IAPREFIX: u16 = 26;
+
+pub const DHCPV6_DUID_LLT: u16 = 1;
+pub const DHCPV6_DUID_EN: u16 = 2;
+pub const DHCPV6_DUID_LL: u16 = 3;
+pub const DHCPV6_DUID_UUID: u16 = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumStringU8)]
+#[repr(u8)]
+pub enum Dhcpv6MessageType {
+    Solicit = 1,
+    Advertise = 2,
+    Request = 3,
+    Confirm = 4,
+    Renew = 5,
+    Rebind = 6,
+    Reply = 7,
+    Release = 8,
+    Decline = 9,
+    Reconfigure = 10,
+    InformationRequest = 11,
+    RelayForw = 12,
+    RelayRepl = 13,
+}
+
+impl Dhcpv6MessageType {
+    /// Whether the message is sent by a server or a relay agent towards
+    /// the client.
+    pub fn is_to_client(&self) -> bool {
+        matches!(
+            self,
+            Dhcpv6MessageType::Advertise
+                | Dhcpv6MessageType::Reply
+                | Dhcpv6MessageType::Reconfigure
+                | Dhcpv6MessageType::RelayRepl
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumStringU16)]
+#[repr(u16)]
+pub enum Dhcpv6StatusCode {
+    Success = 0,
+    UnspecFail = 1,
+    NoAddrsAvail = 2,
+    NoBinding = 3,
+    NotOnLink = 4,
+    UseMulticast = 5,
+    NoPrefixAvail = 6,
+}
+
+/// A DHCP Unique Identifier, with its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6Duid {
+    pub data: Vec<u8>,
+}
+
+impl Dhcpv6Duid {
+    pub fn duid_type(&self) -> u16 {
+        match self.data.get(..2) {
+            Some(t) => u16::from_be_bytes([t[0], t[1]]),
+            None => 0,
+        }
+    }
+
+    pub fn type_str(&self) -> Option<&'static str> {
+        match self.duid_type() {
+            DHCPV6_DUID_LLT => Some("llt"),
+            DHCPV6_DUID_EN => Some("en"),
+            DHCPV6_DUID_LL => Some("ll"),
+            DHCPV6_DUID_UUID => Some("uuid"),
+            _ => None,
+        }
+    }
+
+    /// The hardware type and link-layer address of a DUID-LLT or DUID-LL.
+    pub fn link_layer_address(&self) -> Option<(u16, &[u8])> {
+        let offset = match self.duid_type() {
+            DHCPV6_DUID_LLT => 8,
+            DHCPV6_DUID_LL => 4,
+            _ => return None,
+        };
+        let hw_type = self.data.get(2..4)?;
+        let addr = self.data.get(offset..).filter(|a| !a.is_empty())?;
+        Some((u16::from_be_bytes([hw_type[0], hw_type[1]]), addr))
+    }
+
+    /// The enterprise number of a DUID-EN.
+    pub fn enterprise_number(&self) -> Option<u32> {
+        if self.duid_type() != DHCPV6_DUID_EN {
+            return None;
+        }
+        let en = self.data.get(2..6)?;
+        Some(u32::from_be_bytes([en[0], en[1], en[2], en[3]]))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6Status {
+    pub code: u16,
+    pub message: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6IaAddress {
+    pub address: Ipv6Addr,
+    pub preferred_lifetime: u32,
+    pub valid_lifetime: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6IaPrefix {
+    pub preferred_lifetime: u32,
+    pub valid_lifetime: u32,
+    pub prefix_len: u8,
+    pub prefix: Ipv6Addr,
+}
+
+/// An identity association for non-temporary addresses (IA_NA) or for
+/// prefix delegation (IA_PD). The addresses and prefixes of a client
+/// message are the ones it would like to get.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dhcpv6Ia {
+    pub iaid: u32,
+    pub t1: u32,
+    pub t2: u32,
+    pub addresses: Vec<Dhcpv6IaAddress>,
+    pub prefixes: Vec<Dhcpv6IaPrefix>,
+    pub status: Option<Dhcpv6Status>,
+}
+
+/// The header of a relay agent message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6Relay {
+    pub msg_type: u8,
+    pub hop_count: u8,
+    pub link_address: Ipv6Addr,
+    pub peer_address: Ipv6Addr,
+}
+
+/// A client or server message. A relayed message is the one in the
+/// innermost relay message option, with the headers of the relay
+/// messages it was carried in.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Dhcpv6Message {
+    pub msg_type: u8,
+    pub transaction_id: u32,
+    /// Relay message headers, outermost first.
+    pub relays: Vec<Dhcpv6Relay>,
+    pub client_id: Option<Dhcpv6Duid>,
+    pub server_id: Option<Dhcpv6Duid>,
+    pub ia_na: Vec<Dhcpv6Ia>,
+    pub ia_pd: Vec<Dhcpv6Ia>,
+    pub status: Option<Dhcpv6Status>,
+    pub preference: Option<u8>,
+    pub elapsed_time: Option<u16>,
+    pub requested_options: Vec<u16>,
+    pub rapid_commit: bool,
+    /// An option with a value that could not be parsed.
+    pub malformed_options: bool,
+    /// A client or server identifier of an invalid length.
+    pub invalid_duid: bool,
+}
+
+struct Dhcpv6Option<'a> {
+    code: u16,
+    data: &'a [u8],
+}
+
+fn parse_option(i: &[u8]) -> IResult<&[u8], Dhcpv6Option> {
+    let (i, code) = be_u16(i)?;
+    let (i, len) = be_u16(i)?;
+    let (i, data) = take(len as usize)(i)?;
+    Ok((i, Dhcpv6Option { code, data }))
+}
+
+/// Parse the options of a message or of an option, which must fill the
+/// input.
+fn parse_options(i: &[u8]) -> IResult<&[u8], Vec<Dhcpv6Option>> {
+    all_consuming(many0(parse_option))(i)
+}
+
+fn parse_ipv6(i: &[u8]) -> IResult<&[u8], Ipv6Addr> {
+    let (i, addr) = take(16_usize)(i)?;
+    let mut octets = [0u8; 16];
+    octets.copy_from_slice(addr);
+    Ok((i, Ipv6Addr::from(octets)))
+}
+
+fn parse_status(i: &[u8]) -> IResult<&[u8], Dhcpv6Status> {
+    let (i, code) = be_u16(i)?;
+    let (i, message) = rest(i)?;
+    Ok((
+        i,
+        Dhcpv6Status {
+            code,
+            message: message.to_vec(),
+        },
+    ))
+}
+
+fn parse_iaaddr(i: &[u8]) -> IResult<&[u8], (Dhcpv6IaAddress, &[u8])> {
+    let (i, address) = parse_ipv6(i)?;
+    let (i, preferred_lifetime) = be_u32(i)?;
+    let (i, valid_lifetime) = be_u32(i)?;
+    let addr = Dhcpv6IaAddress {
+        address,
+        preferred_lifetime,
+        valid_lifetime,
+    };
+    Ok((&[], (addr, i)))
+}
+
+fn parse_iaprefix(i: &[u8]) -> IResult<&[u8], (Dhcpv6IaPrefix, &[u8])> {
+    let (i, preferred_lifetime) = be_u32(i)?;
+    let (i, valid_lifetime) = be_u32(i)?;
+    let (i, prefix_len) = be_u8(i)?;
+    let (i, prefix) = parse_ipv6(i)?;
+    let prefix = Dhcpv6IaPrefix {
+        preferred_lifetime,
+        valid_lifetime,
+        prefix_len,
+        prefix,
+    };
+    Ok((&[], (prefix, i)))
+}
+
+/// Check the options of an address or prefix, which are not kept.
+fn sub_options_valid(i: &[u8]) -> bool {
+    match parse_options(i) {
+        Ok((_, options)) => options
+            .iter()
+            .all(|opt| opt.code != DHCPV6_OPT_STATUS_CODE || parse_status(opt.data).is_ok()),
+        Err(_) => false,
+    }
+}
+
+/// Parse an IA_NA or IA_PD option. Returns the identity association and
+/// whether one of its options could not be parsed.
+fn parse_ia(i: &[u8]) -> IResult<&[u8], (Dhcpv6Ia, bool)> {
+    let (i, iaid) = be_u32(i)?;
+    let (i, t1) = be_u32(i)?;
+    let (i, t2) = be_u32(i)?;
+    let (i, options) = parse_options(i)?;
+    let mut ia = Dhcpv6Ia {
+        iaid,
+        t1,
+        t2,
+        ..Default::default()
+    };
+    let mut malformed = false;
+    for opt in options {
+        match opt.code {
+            DHCPV6_OPT_IAADDR => match parse_iaaddr(opt.data) {
+                Ok((_, (addr, sub))) => {
+                    ia.addresses.push(addr);
+                    malformed |= !sub_options_valid(sub);
+                }
+                Err(_) => malformed = true,
+            },
+            DHCPV6_OPT_IAPREFIX => match parse_iaprefix(opt.data) {
+                Ok((_, (prefix, sub))) => {
+                    ia.prefixes.push(prefix);
+                    malformed |= !sub_options_valid(sub);
+                }
+                Err(_) => malformed = true,
+            },
+            DHCPV6_OPT_STATUS_CODE => match parse_status(opt.data) {
+                Ok((_, status)) => ia.status = Some(status),
+                Err(_) => malformed = true,
+            },
+            _ => {}
+        }
+    }
+    Ok((i, (ia, malformed)))
+}
+
+fn parse_duid(data: &[u8], message: &mut Dhcpv6Message) -> Dhcpv6Duid {
+    if !(DHCPV6_DUID_MIN_LEN..=DHCPV6_DUID_MAX_LEN).contains(&data.len()) {
+        message.invalid_duid = true;
+    }
+    Dhcpv6Duid {
+        data: data.to_vec(),
+    }
+}
+
+/// Handle an option of a client or server message, failing if its value
+/// could not be parsed.
+fn handle_option<'a>(opt: &Dhcpv6Option<'a>, message: &mut Dhcpv6Message) -> IResult<&'a [u8], ()> {
+    match opt.code {
+        DHCPV6_OPT_CLIENTID => {
+            message.client_id = Some(parse_duid(opt.data, message));
+        }
+        DHCPV6_OPT_SERVERID => {
+            message.server_id = Some(parse_duid(opt.data, message));
+        }
+        DHCPV6_OPT_IA_NA | DHCPV6_OPT_IA_PD => {
+            let (_, (ia, malformed)) = parse_ia(opt.data)?;
+            message.malformed_options |= malformed;
+            if opt.code == DHCPV6_OPT_IA_NA {
+                message.ia_na.push(ia);
+            } else {
+                message.ia_pd.push(ia);
+            }
+        }
+        DHCPV6_OPT_STATUS_CODE => {
+            let (_, status) = parse_status(opt.data)?;
+            message.status = Some(status);
+        }
+        DHCPV6_OPT_PREFERENCE => {
+            let (_, preference) = all_consuming(be_u8)(opt.data)?;
+            message.preference = Some(preference);
+        }
+        DHCPV6_OPT_ELAPSED_TIME => {
+            let (_, elapsed) = all_consuming(be_u16)(opt.data)?;
+            message.elapsed_time = Some(elapsed);
+        }
+        DHCPV6_OPT_ORO => {
+            let (_, codes) = all_consuming(many0(be_u16))(opt.data)?;
+            message.requested_options = codes;
+        }
+        DHCPV6_OPT_RAPID_COMMIT => {
+            message.rapid_commit = true;
+        }
+        _ => {}
+    }
+    Ok((&[], ()))
+}
+
+fn parse_relay_header(i: &[u8]) -> IResult<&[u8], Dhcpv6Relay> {
+    let (i, msg_type) = be_u8(i)?;
+    let (i, hop_count) = be_u8(i)?;
+    let (i, link_address) = parse_ipv6(i)?;
+    let (i, peer_address) = parse_ipv6(i)?;
+    Ok((
+        i,
+        Dhcpv6Relay {
+            msg_type,
+            hop_count,
+            link_address,
+            peer_address,
+        },
+    ))
+}
+
+fn is_relay(msg_type: u8) -> bool {
+    msg_type == Dhcpv6MessageType::RelayForw as u8 || msg_type == Dhcpv6MessageType::RelayRepl as u8
+}
+
+/// Parse a message as carried by a single UDP datagram.
+pub fn parse_message(i: &[u8]) -> IResult<&[u8], Dhcpv6Message> {
+    let mut message = Dhcpv6Message::default();
+    let mut input = i;
+    // unwrap the relay messages
+    while matches!(input.first(), Some(&t) if is_relay(t)) {
+        if message.relays.len() >= DHCPV6_HOP_COUNT_LIMIT {
+            return Err(Err::Error(make_error(input, ErrorKind::TooLarge)));
+        }
+        let (rem, relay) = parse_relay_header(input)?;
+        let (_, options) = parse_options(rem)?;
+        let relay_msg = options
+            .iter()
+            .find(|opt| opt.code == DHCPV6_OPT_RELAY_MSG)
+            .ok_or_else(|| Err::Error(make_error(rem, ErrorKind::Verify)))?;
+        message.relays.push(relay);
+        input = relay_msg.data;
+    }
+    let (rem, msg_type) = be_u8(input)?;
+    let (rem, transaction_id) = be_u24(rem)?;
+    let (_, options) = parse_options(rem)?;
+    message.msg_type = msg_type;
+    message.transaction_id = transaction_id;
+    for opt in &options {
+        if handle_option(opt, &mut message).is_err() {
+            message.malformed_options = true;
+        }
+    }
+    Ok((&[], message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Solicit with a DUID-LLT, an IA_NA and an IA_PD with a /56 hint.
+    const SOLICIT: &[u8] = &[
+        0x01, 0x10, 0x08, 0x74, 0x00, 0x01, 0x00, 0x0e, 0x00, 0x01, 0x00, 0x01, 0x1c, 0x39, 0xcf,
+        0x88, 0x08, 0x00, 0x27, 0xfe, 0x8f, 0x95, 0x00, 0x06, 0x00, 0x04, 0x00, 0x17, 0x00, 0x18,
+        0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x0c, 0x27, 0xfe, 0x8f, 0x95, 0x00,
+        0x00, 0x0e, 0x10, 0x00, 0x00, 0x15, 0x18, 0x00, 0x19, 0x00, 0x29, 0x27, 0xfe, 0x8f, 0x95,
+        0x00, 0x00, 0x0e, 0x10, 0x00, 0x00, 0x15, 0x18, 0x00, 0x1a, 0x00, 0x19, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    fn option(code: u16, data: &[u8]) -> Vec<u8> {
+        let mut buf = code.to_be_bytes().to_vec();
+        buf.extend_from_slice(&(data.len() as u16).to_be_bytes());
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    #[test]
+    fn test_parse_solicit() {
+        let (rem, msg) = parse_message(SOLICIT).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(msg.msg_type, Dhcpv6MessageType::Solicit as u8);
+        assert_eq!(msg.transaction_id, 0x100874);
+        assert!(msg.relays.is_empty());
+        let client_id = msg.client_id.as_ref().unwrap();
+        assert_eq!(client_id.duid_type(), DHCPV6_DUID_LLT);
+        assert_eq!(client_id.type_str(), Some("llt"));
+        assert_eq!(
+            client_id.link_layer_address(),
+            Some((1, &[0x08, 0x00, 0x27, 0xfe, 0x8f, 0x95][..]))
+        );
+        assert!(msg.server_id.is_none());
+        assert_eq!(msg.requested_options, vec![23, 24]);
+        assert_eq!(msg.elapsed_time, Some(0));
+        assert_eq!(msg.ia_na.len(), 1);
+        assert_eq!(msg.ia_na[0].iaid, 0x27fe8f95);
+        assert_eq!(msg.ia_na[0].t1, 3600);
+        assert_eq!(msg.ia_pd.len(), 1);
+        assert_eq!(
+            msg.ia_pd[0].prefixes,
+            vec![Dhcpv6IaPrefix {
+                preferred_lifetime: 0,
+                valid_lifetime: 0,
+                prefix_len: 56,
+                prefix: Ipv6Addr::UNSPECIFIED,
+            }]
+        );
+        assert!(!msg.malformed_options);
+        assert!(!msg.invalid_duid);
+    }
+
+    #[test]
+    fn test_parse_reply_status() {
+        let mut ia = vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+        ia.extend(option(DHCPV6_OPT_STATUS_CODE, b"\x00\x06none"));
+        let mut buf = vec![0x07, 0x00, 0x00, 0x01];
+        buf.extend(option(DHCPV6_OPT_SERVERID, &[0, 2, 0, 0, 0, 9, 1, 2]));
+        buf.extend(option(DHCPV6_OPT_IA_PD, &ia));
+        buf.extend(option(DHCPV6_OPT_PREFERENCE, &[255]));
+        let (_, msg) = parse_message(&buf).unwrap();
+        let server_id = msg.server_id.as_ref().unwrap();
+        assert_eq!(server_id.type_str(), Some("en"));
+        assert_eq!(server_id.enterprise_number(), Some(9));
+        assert_eq!(server_id.link_layer_address(), None);
+        let status = msg.ia_pd[0].status.as_ref().unwrap();
+        assert_eq!(status.code, Dhcpv6StatusCode::NoPrefixAvail as u16);
+        assert_eq!(status.message, b"none");
+        assert_eq!(msg.preference, Some(255));
+
+        // Preference of the wrong length.
+        let len = buf.len();
+        buf[len - 2] = 2;
+        buf.push(0);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert!(msg.malformed_options);
+        assert_eq!(msg.preference, None);
+
+        // Option overflowing the message.
+        buf[len - 2] = 3;
+        assert!(parse_message(&buf).is_err());
+    }
+
+    #[test]
+    fn test_parse_relay() {
+        let mut relay = vec![0x0c, 0x00];
+        relay.extend_from_slice(&[0x20, 0x01, 0x0d, 0xb8]);
+        relay.extend_from_slice(&[0; 12]);
+        relay.extend_from_slice(&[0xfe, 0x80]);
+        relay.extend_from_slice(&[0; 13]);
+        relay.push(1);
+        relay.extend(option(DHCPV6_OPT_RELAY_MSG, SOLICIT));
+        let (_, msg) = parse_message(&relay).unwrap();
+        assert_eq!(msg.relays.len(), 1);
+        assert_eq!(msg.relays[0].msg_type, Dhcpv6MessageType::RelayForw as u8);
+        assert_eq!(
+            msg.relays[0].link_address,
+            "2001:db8::".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(
+            msg.relays[0].peer_address,
+            "fe80::1".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(msg.msg_type, Dhcpv6MessageType::Solicit as u8);
+        assert_eq!(msg.ia_pd.len(), 1);
+
+        // A relay message without the relayed message.
+        let buf = &relay[..DHCPV6_RELAY_HEADER_LEN];
+        assert!(parse_message(buf).is_err());
+
+        // Relayed more than the hop count limit.
+        let mut buf = relay;
+        for _ in 0..DHCPV6_HOP_COUNT_LIMIT {
+            let mut outer = buf[..DHCPV6_RELAY_HEADER_LEN].to_vec();
+            outer.extend(option(DHCPV6_OPT_RELAY_MSG, &buf));
+            buf = outer;
+        }
+        assert!(parse_message(&buf).is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_duid() {
+        let mut buf = vec![0x02, 0x00, 0x00, 0x01];
+        buf.extend(option(DHCPV6_OPT_SERVERID, &[0, 3]));
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert!(msg.invalid_duid);
+        assert_eq!(msg.server_id.unwrap().link_layer_address(), None);
+    }
+}
@@ -0,0 +1,266 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Tracking of the servers answering the DHCPv6 clients.
+//!
+//! A rogue DHCPv6 server on the link answers the same clients as the
+//! legitimate one, so more server DUIDs than expected show up in the
+//! Advertise and Reply messages. Each server answers on its own flow, so
+//! the servers are kept per client DUID in a table shared by all flows,
+//! with the time they were last seen. An event is raised when a server
+//! answers a client while `max-servers` others were already seen within
+//! `window` seconds.
+
+use super::parser::Dhcpv6Duid;
+use crate::conf::conf_get;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Number of servers tracked per client.
+const DHCPV6_MAX_TRACKED_SERVERS: usize = 64;
+
+/// Number of clients tracked.
+const DHCPV6_MAX_TRACKED_CLIENTS: usize = 4096;
+
+lazy_static! {
+    static ref DHCPV6_CLIENT_SERVERS: Mutex<Dhcpv6ClientServers> =
+        Mutex::new(Dhcpv6ClientServers::default());
+}
+
+#[derive(Debug)]
+struct Dhcpv6RogueServerConfig {
+    /// Number of distinct servers allowed within the window.
+    max_servers: usize,
+    /// Window in seconds.
+    window: u64,
+}
+
+static mut DHCPV6_ROGUE_SERVER_CONFIG: Dhcpv6RogueServerConfig = Dhcpv6RogueServerConfig {
+    max_servers: 1,
+    window: 60,
+};
+
+fn dhcpv6_rogue_server_conf_u64(name: &str, value: &mut u64) {
+    let key = format!("app-layer.protocols.dhcpv6.rogue-server.{}", name);
+    if let Some(val) = conf_get(&key) {
+        if let Ok(v) = val.parse::<u64>() {
+            *value = v;
+        } else {
+            SCLogError!("Invalid value for dhcpv6.rogue-server.{}", name);
+        }
+    }
+}
+
+pub(super) unsafe fn register_rogue_server_detection() {
+    let mut max_servers = DHCPV6_ROGUE_SERVER_CONFIG.max_servers as u64;
+    dhcpv6_rogue_server_conf_u64("max-servers", &mut max_servers);
+    if max_servers == 0 {
+        SCLogError!("Invalid value for dhcpv6.rogue-server.max-servers, using 1");
+        max_servers = 1;
+    }
+    DHCPV6_ROGUE_SERVER_CONFIG.max_servers = max_servers as usize;
+    dhcpv6_rogue_server_conf_u64("window", &mut DHCPV6_ROGUE_SERVER_CONFIG.window);
+}
+
+#[derive(Debug)]
+struct Dhcpv6ServerEntry {
+    duid: Dhcpv6Duid,
+    last_seen: u64,
+}
+
+/// Record an answer of the server `server` to the client `client` at
+/// `ts`, whatever the flow it was seen on. Returns true if it is a server
+/// not seen within the window and too many others were.
+pub(super) fn dhcpv6_track_server(client: &Dhcpv6Duid, server: &Dhcpv6Duid, ts: u64) -> bool {
+    match DHCPV6_CLIENT_SERVERS.lock() {
+        Ok(mut clients) => {
+            clients.update_with_config(client, server, ts, unsafe { &DHCPV6_ROGUE_SERVER_CONFIG })
+        }
+        Err(_) => false,
+    }
+}
+
+/// The servers that answered each client.
+#[derive(Debug, Default)]
+struct Dhcpv6ClientServers {
+    clients: HashMap<Vec<u8>, Dhcpv6ServerTable>,
+}
+
+impl Dhcpv6ClientServers {
+    fn update_with_config(
+        &mut self, client: &Dhcpv6Duid, server: &Dhcpv6Duid, ts: u64,
+        config: &Dhcpv6RogueServerConfig,
+    ) -> bool {
+        if !self.clients.contains_key(&client.data)
+            && self.clients.len() >= DHCPV6_MAX_TRACKED_CLIENTS
+        {
+            self.expire(ts, config);
+        }
+        self.clients
+            .entry(client.data.clone())
+            .or_default()
+            .update_with_config(server, ts, config)
+    }
+
+    /// Drop the clients without answers within the window, or the client
+    /// answered the longest time ago if all had some.
+    fn expire(&mut self, ts: u64, config: &Dhcpv6RogueServerConfig) {
+        self.clients
+            .retain(|_, table| ts.saturating_sub(table.last_seen()) < config.window);
+        if self.clients.len() < DHCPV6_MAX_TRACKED_CLIENTS {
+            return;
+        }
+        let oldest = self
+            .clients
+            .iter()
+            .min_by_key(|(_, table)| table.last_seen())
+            .map(|(client, _)| client.clone());
+        if let Some(client) = oldest {
+            self.clients.remove(&client);
+        }
+    }
+}
+
+/// The servers that answered a client.
+#[derive(Debug, Default)]
+struct Dhcpv6ServerTable {
+    entries: Vec<Dhcpv6ServerEntry>,
+}
+
+impl Dhcpv6ServerTable {
+    /// Time the last answer was seen.
+    fn last_seen(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| entry.last_seen)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn update_with_config(
+        &mut self, duid: &Dhcpv6Duid, ts: u64, config: &Dhcpv6RogueServerConfig,
+    ) -> bool {
+        self.entries
+            .retain(|entry| ts.saturating_sub(entry.last_seen) < config.window);
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.duid == *duid) {
+            entry.last_seen = ts;
+            return false;
+        }
+        let rogue = self.entries.len() >= config.max_servers;
+        if self.entries.len() >= DHCPV6_MAX_TRACKED_SERVERS {
+            self.evict_oldest();
+        }
+        self.entries.push(Dhcpv6ServerEntry {
+            duid: duid.clone(),
+            last_seen: ts,
+        });
+        rogue
+    }
+
+    /// Drop the server that was not seen for the longest time.
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, entry)| entry.last_seen)
+            .map(|(index, _)| index);
+        if let Some(index) = oldest {
+            self.entries.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn duid(id: u8) -> Dhcpv6Duid {
+        Dhcpv6Duid {
+            data: vec![0, 3, 0, 1, 0, 0, 0, 0, 0, id],
+        }
+    }
+
+    #[test]
+    fn test_dhcpv6_rogue_server() {
+        let config = Dhcpv6RogueServerConfig {
+            max_servers: 1,
+            window: 10,
+        };
+        let mut table = Dhcpv6ServerTable::default();
+        assert!(!table.update_with_config(&duid(1), 100, &config));
+        assert!(!table.update_with_config(&duid(1), 101, &config));
+        // a second server within the window, once
+        assert!(table.update_with_config(&duid(2), 102, &config));
+        assert!(!table.update_with_config(&duid(2), 103, &config));
+        assert!(!table.update_with_config(&duid(1), 104, &config));
+        // a third one
+        assert!(table.update_with_config(&duid(3), 105, &config));
+        assert_eq!(table.entries.len(), 3);
+        // all left the window
+        assert!(!table.update_with_config(&duid(2), 116, &config));
+        assert_eq!(table.entries.len(), 1);
+        assert!(table.update_with_config(&duid(1), 117, &config));
+    }
+
+    #[test]
+    fn test_dhcpv6_rogue_server_evict() {
+        let config = Dhcpv6RogueServerConfig {
+            max_servers: 255,
+            window: 1000,
+        };
+        let mut table = Dhcpv6ServerTable::default();
+        for id in 0..DHCPV6_MAX_TRACKED_SERVERS as u8 {
+            assert!(!table.update_with_config(&duid(id), 100 + id as u64, &config));
+        }
+        assert!(!table.update_with_config(&duid(0), 200, &config));
+        assert!(!table.update_with_config(&duid(255), 201, &config));
+        assert_eq!(table.entries.len(), DHCPV6_MAX_TRACKED_SERVERS);
+        // 1 was the least recently seen
+        assert!(table.entries.iter().all(|entry| entry.duid != duid(1)));
+        assert!(table.entries.iter().any(|entry| entry.duid == duid(0)));
+    }
+
+    #[test]
+    fn test_dhcpv6_rogue_server_per_client() {
+        let config = Dhcpv6RogueServerConfig {
+            max_servers: 1,
+            window: 10,
+        };
+        let mut clients = Dhcpv6ClientServers::default();
+        assert!(!clients.update_with_config(&duid(100), &duid(1), 100, &config));
+        // another client with its own server
+        assert!(!clients.update_with_config(&duid(101), &duid(2), 101, &config));
+        // a second server for the first client
+        assert!(clients.update_with_config(&duid(100), &duid(2), 102, &config));
+        assert_eq!(clients.clients.len(), 2);
+
+        // full, the clients without recent answers make room
+        for id in 0..DHCPV6_MAX_TRACKED_CLIENTS as u32 - 2 {
+            let client = Dhcpv6Duid {
+                data: id.to_be_bytes().to_vec(),
+            };
+            clients.update_with_config(&client, &duid(1), 103, &config);
+        }
+        assert_eq!(clients.clients.len(), DHCPV6_MAX_TRACKED_CLIENTS);
+        assert!(!clients.update_with_config(&duid(102), &duid(1), 112, &config));
+        assert!(!clients.clients.contains_key(&duid(100).data));
+        assert!(!clients.clients.contains_key(&duid(101).data));
+        assert!(clients.clients.contains_key(&duid(102).data));
+    }
+}
@@ -15,9 +15,38 @@
  * 02110-1301, USA.
  */
 
-use super::dns::DNSTransaction;
+use super::dns::{DNSAnswerEntry, DNSHeader, DNSMessage, DNSRData, DNSTransaction, ALPROTO_DNS};
+use super::log::dns_rrtype_from_string;
 use crate::core::Direction;
-use crate::detect::uint::{detect_match_uint, DetectUintData};
+use crate::detect::uint::{
+    detect_match_uint, detect_parse_uint, rs_detect_u16_free, rs_detect_u16_match,
+    rs_detect_u16_parse, rs_detect_u32_free, rs_detect_u32_match, rs_detect_u32_parse,
+    DetectUintData, DetectUintMode,
+};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferRegister, DetectHelperGetMultiData,
+    DetectHelperKeywordRegister, DetectHelperMultiBufferMpmRegister, DetectSignatureSetAppProto,
+    SCSigTableElmt, SigMatchAppendSMToList, SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::ffi::CStr;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+static mut G_DNS_QUERIES_COUNT_KW_ID: c_int = 0;
+static mut G_DNS_QUERIES_COUNT_BUFFER_ID: c_int = 0;
+static mut G_DNS_ANSWERS_COUNT_KW_ID: c_int = 0;
+static mut G_DNS_ANSWERS_COUNT_BUFFER_ID: c_int = 0;
+static mut G_DNS_AUTHORITIES_COUNT_KW_ID: c_int = 0;
+static mut G_DNS_AUTHORITIES_COUNT_BUFFER_ID: c_int = 0;
+static mut G_DNS_ADDITIONALS_COUNT_KW_ID: c_int = 0;
+static mut G_DNS_ADDITIONALS_COUNT_BUFFER_ID: c_int = 0;
+static mut G_DNS_QUERY_LENGTH_KW_ID: c_int = 0;
+static mut G_DNS_QUERY_LENGTH_BUFFER_ID: c_int = 0;
+static mut G_DNS_RESPONSE_SIZE_KW_ID: c_int = 0;
+static mut G_DNS_RESPONSE_SIZE_BUFFER_ID: c_int = 0;
+static mut G_DNS_COOKIE_ABSENT_KW_ID: c_int = 0;
+static mut G_DNS_COOKIE_ABSENT_BUFFER_ID: c_int = 0;
+static mut G_DNS_ANSWERS_IP_BUFFER_ID: c_int = 0;
 
 /// Perform the DNS opcode match.
 ///
@@ -77,36 +106,588 @@ pub extern "C" fn SCDnsDetectRcodeMatch(
     return 0;
 }
 
+/// Section of the message inspected by `dns.rrtype`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DnsRrtypeSection {
+    Query,
+    Answer,
+    Authority,
+}
+
+/// The `dns.rrtype` keyword: a list of rrtypes, and the section they are
+/// looked for in.
+#[derive(Debug, Default, PartialEq)]
+pub struct DetectDnsRrtype {
+    /// Bitmap of the listed rrtypes below 256.
+    low: [u64; 4],
+    /// Listed rrtypes from 256.
+    high: Vec<u16>,
+    /// Integer expressions other than a single value, like a range.
+    uints: Vec<DetectUintData<u16>>,
+    /// By default the query section of requests, and the answer section
+    /// of responses.
+    section: Option<DnsRrtypeSection>,
+}
+
+impl DetectDnsRrtype {
+    fn insert(&mut self, rrtype: u16) {
+        if rrtype < 256 {
+            self.low[(rrtype / 64) as usize] |= 1 << (rrtype % 64);
+        } else if !self.high.contains(&rrtype) {
+            self.high.push(rrtype);
+        }
+    }
+
+    fn matches(&self, rrtype: u16) -> bool {
+        if rrtype < 256 {
+            if self.low[(rrtype / 64) as usize] & (1 << (rrtype % 64)) != 0 {
+                return true;
+            }
+        } else if self.high.contains(&rrtype) {
+            return true;
+        }
+        self.uints.iter().any(|u| detect_match_uint(u, rrtype))
+    }
+}
+
+/// Parse the comma separated list of `dns.rrtype`. Items are rrtype names
+/// like `TXT`, unsigned 16-bit integer expressions, or one of the `query`,
+/// `answer` and `authority` section modifiers.
+fn dns_parse_rrtype(s: &str) -> Option<DetectDnsRrtype> {
+    let mut ctx = DetectDnsRrtype::default();
+    let mut types = false;
+    for item in s.split(',').map(|item| item.trim()) {
+        let section = if item.eq_ignore_ascii_case("query") {
+            Some(DnsRrtypeSection::Query)
+        } else if item.eq_ignore_ascii_case("answer") {
+            Some(DnsRrtypeSection::Answer)
+        } else if item.eq_ignore_ascii_case("authority") {
+            Some(DnsRrtypeSection::Authority)
+        } else {
+            None
+        };
+        if section.is_some() {
+            if ctx.section.is_some() {
+                SCLogError!("dns.rrtype: more than one section in {}", s);
+                return None;
+            }
+            ctx.section = section;
+            continue;
+        }
+        types = true;
+        if let Some(rrtype) = dns_rrtype_from_string(item) {
+            ctx.insert(rrtype);
+            continue;
+        }
+        match detect_parse_uint::<u16>(item) {
+            Ok((_, u)) if u.mode == DetectUintMode::DetectUintModeEqual => {
+                ctx.insert(u.arg1);
+            }
+            Ok((_, u)) => {
+                ctx.uints.push(u);
+            }
+            Err(_) => {
+                SCLogError!("dns.rrtype: invalid rrtype {}", item);
+                return None;
+            }
+        }
+    }
+    if !types {
+        return None;
+    }
+    Some(ctx)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDnsDetectRrtypeParse(
+    ustr: *const std::os::raw::c_char,
+) -> *mut DetectDnsRrtype {
+    let ft_name: &CStr = CStr::from_ptr(ustr); //unsafe
+    if let Ok(s) = ft_name.to_str() {
+        if let Some(ctx) = dns_parse_rrtype(s) {
+            let boxed = Box::new(ctx);
+            return Box::into_raw(boxed) as *mut _;
+        }
+    }
+    return std::ptr::null_mut();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDnsDetectRrtypeFree(ctx: &mut DetectDnsRrtype) {
+    // Just unbox...
+    std::mem::drop(Box::from_raw(ctx));
+}
+
+fn dns_match_rrtype_records(records: &[DNSAnswerEntry], detect: &DetectDnsRrtype) -> bool {
+    records.iter().any(|record| detect.matches(record.rrtype))
+}
+
 /// Perform the DNS rrtype match.
 /// 1 will be returned on match, otherwise 0 will be returned.
 #[no_mangle]
 pub extern "C" fn SCDnsDetectRrtypeMatch(
-    tx: &mut DNSTransaction, detect: &mut DetectUintData<u16>, flags: u8,
+    tx: &mut DNSTransaction, detect: &mut DetectDnsRrtype, flags: u8,
 ) -> u16 {
-    if flags & Direction::ToServer as u8 != 0 {
-        if let Some(request) = &tx.request {
-            for i in 0..request.queries.len() {
-                if detect_match_uint(detect, request.queries[i].rrtype) {
-                    return 1;
-                }
-            }
+    let message = match dns_tx_get_message(tx, flags) {
+        Some(message) => message,
+        None => {
+            return 0;
         }
-    } else if flags & Direction::ToClient as u8 != 0 {
-        if let Some(response) = &tx.response {
-            for i in 0..response.answers.len() {
-                if detect_match_uint(detect, response.answers[i].rrtype) {
-                    return 1;
-                }
-            }
+    };
+    let section = detect
+        .section
+        .unwrap_or(if flags & Direction::ToServer as u8 != 0 {
+            DnsRrtypeSection::Query
+        } else {
+            DnsRrtypeSection::Answer
+        });
+    let matched = match section {
+        DnsRrtypeSection::Query => message
+            .queries
+            .iter()
+            .any(|query| detect.matches(query.rrtype)),
+        DnsRrtypeSection::Answer => dns_match_rrtype_records(&message.answers, detect),
+        DnsRrtypeSection::Authority => dns_match_rrtype_records(&message.authorities, detect),
+    };
+    if matched {
+        return 1;
+    }
+    return 0;
+}
+
+/// Request or response, depending on the direction.
+fn dns_tx_get_message(tx: &DNSTransaction, flags: u8) -> Option<&DNSMessage> {
+    let message = if flags & Direction::ToServer as u8 != 0 {
+        &tx.request
+    } else {
+        &tx.response
+    };
+    message.as_ref()
+}
+
+/// Header of the request or response, depending on the direction.
+fn dns_tx_get_header(tx: &DNSTransaction, flags: u8) -> Option<&DNSHeader> {
+    dns_tx_get_message(tx, flags).map(|m| &m.header)
+}
+
+/// Get the address of the A or AAAA answer at `idx`, other answers are
+/// skipped. The address is in network byte order, 4 bytes for IPv4 and
+/// 16 bytes for IPv6, as expected by the ipv4 and ip dataset types.
+fn dns_message_get_answer_ip(message: &DNSMessage, idx: u32) -> Option<&[u8]> {
+    message
+        .answers
+        .iter()
+        .filter_map(|answer| match &answer.data {
+            DNSRData::A(addr) if addr.len() == 4 => Some(addr.as_slice()),
+            DNSRData::AAAA(addr) if addr.len() == 16 => Some(addr.as_slice()),
+            _ => None,
+        })
+        .nth(idx as usize)
+}
+
+/// Check if the name of any query of the message matches the length.
+fn dns_match_query_length(message: &DNSMessage, ctx: &DetectUintData<u16>) -> bool {
+    message.queries.iter().any(|query| {
+        let len = u16::try_from(query.name.len()).unwrap_or(u16::MAX);
+        detect_match_uint(ctx, len)
+    })
+}
+
+unsafe fn dns_count_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char, kw_id: c_int, buffer_id: c_int,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DNS) != 0 {
+        return -1;
+    }
+    let ctx = rs_detect_u16_parse(raw) as *mut c_void;
+    if ctx.is_null() {
+        return -1;
+    }
+    if SigMatchAppendSMToList(de, s, kw_id, ctx, buffer_id).is_null() {
+        dns_count_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+unsafe fn dns_count_match(
+    flags: u8, tx: *mut c_void, ctx: *const c_void, count: fn(&DNSHeader) -> u16,
+) -> c_int {
+    let tx = cast_pointer!(tx, DNSTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    if let Some(header) = dns_tx_get_header(tx, flags) {
+        return rs_detect_u16_match(count(header), ctx);
+    }
+    return 0;
+}
+
+unsafe extern "C" fn dns_count_free(_de: *mut c_void, ctx: *mut c_void) {
+    // Just unbox...
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    rs_detect_u16_free(ctx);
+}
+
+unsafe extern "C" fn queries_count_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    dns_count_setup(
+        de,
+        s,
+        raw,
+        G_DNS_QUERIES_COUNT_KW_ID,
+        G_DNS_QUERIES_COUNT_BUFFER_ID,
+    )
+}
+
+unsafe extern "C" fn queries_count_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    dns_count_match(flags, tx, ctx, |header| header.questions)
+}
+
+unsafe extern "C" fn answers_count_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    dns_count_setup(
+        de,
+        s,
+        raw,
+        G_DNS_ANSWERS_COUNT_KW_ID,
+        G_DNS_ANSWERS_COUNT_BUFFER_ID,
+    )
+}
+
+unsafe extern "C" fn answers_count_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    dns_count_match(flags, tx, ctx, |header| header.answer_rr)
+}
+
+unsafe extern "C" fn authorities_count_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    dns_count_setup(
+        de,
+        s,
+        raw,
+        G_DNS_AUTHORITIES_COUNT_KW_ID,
+        G_DNS_AUTHORITIES_COUNT_BUFFER_ID,
+    )
+}
+
+unsafe extern "C" fn authorities_count_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    dns_count_match(flags, tx, ctx, |header| header.authority_rr)
+}
+
+unsafe extern "C" fn additionals_count_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    dns_count_setup(
+        de,
+        s,
+        raw,
+        G_DNS_ADDITIONALS_COUNT_KW_ID,
+        G_DNS_ADDITIONALS_COUNT_BUFFER_ID,
+    )
+}
+
+unsafe extern "C" fn additionals_count_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    dns_count_match(flags, tx, ctx, |header| header.additional_rr)
+}
+
+unsafe extern "C" fn query_length_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    dns_count_setup(
+        de,
+        s,
+        raw,
+        G_DNS_QUERY_LENGTH_KW_ID,
+        G_DNS_QUERY_LENGTH_BUFFER_ID,
+    )
+}
+
+unsafe extern "C" fn query_length_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, DNSTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    if let Some(message) = dns_tx_get_message(tx, flags) {
+        if dns_match_query_length(message, ctx) {
+            return 1;
         }
     }
     return 0;
 }
 
+unsafe extern "C" fn response_size_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DNS) != 0 {
+        return -1;
+    }
+    let ctx = rs_detect_u32_parse(raw) as *mut c_void;
+    if ctx.is_null() {
+        return -1;
+    }
+    if SigMatchAppendSMToList(
+        de,
+        s,
+        G_DNS_RESPONSE_SIZE_KW_ID,
+        ctx,
+        G_DNS_RESPONSE_SIZE_BUFFER_ID,
+    )
+    .is_null()
+    {
+        response_size_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn response_size_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, DNSTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u32>);
+    if let Some(response) = &tx.response {
+        return rs_detect_u32_match(response.size, ctx);
+    }
+    return 0;
+}
+
+unsafe extern "C" fn response_size_free(_de: *mut c_void, ctx: *mut c_void) {
+    // Just unbox...
+    let ctx = cast_pointer!(ctx, DetectUintData<u32>);
+    rs_detect_u32_free(ctx);
+}
+
+unsafe extern "C" fn cookie_absent_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DNS) != 0 {
+        return -1;
+    }
+    if SigMatchAppendSMToList(
+        de,
+        s,
+        G_DNS_COOKIE_ABSENT_KW_ID,
+        std::ptr::null_mut(),
+        G_DNS_COOKIE_ABSENT_BUFFER_ID,
+    )
+    .is_null()
+    {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn cookie_absent_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, _ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, DNSTransaction);
+    return (tx.response.is_some() && tx.cookie.is_none()) as c_int;
+}
+
+unsafe extern "C" fn answers_ip_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_DNS) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_DNS_ANSWERS_IP_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn answers_ip_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        answers_ip_get_data,
+    );
+}
+
+unsafe extern "C" fn answers_ip_get_data(
+    tx: *const c_void, flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, DNSTransaction);
+    if let Some(message) = dns_tx_get_message(tx, flow_flags) {
+        if let Some(addr) = dns_message_get_answer_ip(message, local_id) {
+            *buffer = addr.as_ptr();
+            *buffer_len = addr.len() as u32;
+            return true;
+        }
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectDnsRegister() {
+    let kw = SCSigTableElmt {
+        name: b"dns.queries.count\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the number of DNS queries in the header\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-queries-count\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(queries_count_match),
+        Setup: queries_count_setup,
+        Free: Some(dns_count_free),
+        flags: 0,
+    };
+    G_DNS_QUERIES_COUNT_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_QUERIES_COUNT_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.queries.count\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.answers.count\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the number of DNS answers in the header\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-answers-count\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(answers_count_match),
+        Setup: answers_count_setup,
+        Free: Some(dns_count_free),
+        flags: 0,
+    };
+    G_DNS_ANSWERS_COUNT_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_ANSWERS_COUNT_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.answers.count\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.authorities.count\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the number of DNS authority records in the header\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-authorities-count\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(authorities_count_match),
+        Setup: authorities_count_setup,
+        Free: Some(dns_count_free),
+        flags: 0,
+    };
+    G_DNS_AUTHORITIES_COUNT_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_AUTHORITIES_COUNT_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.authorities.count\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.additionals.count\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the number of DNS additional records in the header\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-additionals-count\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(additionals_count_match),
+        Setup: additionals_count_setup,
+        Free: Some(dns_count_free),
+        flags: 0,
+    };
+    G_DNS_ADDITIONALS_COUNT_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_ADDITIONALS_COUNT_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.additionals.count\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.query.length\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the length of the DNS query names\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-query-length\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(query_length_match),
+        Setup: query_length_setup,
+        Free: Some(dns_count_free),
+        flags: 0,
+    };
+    G_DNS_QUERY_LENGTH_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_QUERY_LENGTH_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.query.length\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.response.size\0".as_ptr() as *const libc::c_char,
+        desc: b"match on the size of the DNS response message\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-response-size\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(response_size_match),
+        Setup: response_size_setup,
+        Free: Some(response_size_free),
+        flags: 0,
+    };
+    G_DNS_RESPONSE_SIZE_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_RESPONSE_SIZE_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.response.size\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        false,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.cookie_absent\0".as_ptr() as *const libc::c_char,
+        desc: b"match on DNS responses without a DNS cookie\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-cookie-absent\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(cookie_absent_match),
+        Setup: cookie_absent_setup,
+        Free: None,
+        flags: SIGMATCH_NOOPT,
+    };
+    G_DNS_COOKIE_ABSENT_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_DNS_COOKIE_ABSENT_BUFFER_ID = DetectHelperBufferRegister(
+        b"dns.cookie_absent\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        false,
+    );
+    let kw = SCSigTableElmt {
+        name: b"dns.answers.ip\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the addresses of the DNS A and AAAA answers\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/dns-keywords.html#dns-answers-ip\0".as_ptr() as *const libc::c_char,
+        Setup: answers_ip_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_dns_answers_ip_kw_id = DetectHelperKeywordRegister(&kw);
+    // answers are only expected in responses, but like dns.answer.name,
+    // the buffer is also available for requests
+    G_DNS_ANSWERS_IP_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"dns.answers.ip\0".as_ptr() as *const libc::c_char,
+        b"dns answer address\0".as_ptr() as *const libc::c_char,
+        ALPROTO_DNS,
+        true,
+        true,
+        answers_ip_get,
+    );
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::detect::uint::{detect_parse_uint, DetectUintMode};
+    use crate::dns::dns::{DNSAnswerEntry, DNSQueryEntry};
 
     #[test]
     fn parse_opcode_good() {
@@ -336,4 +917,206 @@ mod test {
             4u16,
         ));
     }
+
+    #[test]
+    fn test_parse_rrtype_list() {
+        let ctx = dns_parse_rrtype("16").unwrap();
+        assert!(ctx.matches(16));
+        assert!(!ctx.matches(1));
+        assert_eq!(ctx.section, None);
+
+        let ctx = dns_parse_rrtype("ANY, txt,NULL,300").unwrap();
+        for rrtype in [255, 16, 10, 300] {
+            assert!(ctx.matches(rrtype));
+        }
+        assert!(!ctx.matches(1));
+        assert!(!ctx.matches(256));
+
+        let ctx = dns_parse_rrtype("authority,NS,SOA").unwrap();
+        assert_eq!(ctx.section, Some(DnsRrtypeSection::Authority));
+        assert!(ctx.matches(2));
+        assert!(ctx.matches(6));
+        assert!(!ctx.matches(1));
+
+        let ctx = dns_parse_rrtype("A,60-70").unwrap();
+        assert!(ctx.matches(1));
+        assert!(ctx.matches(64));
+        assert!(!ctx.matches(70));
+
+        assert!(dns_parse_rrtype("").is_none());
+        assert!(dns_parse_rrtype("answer").is_none());
+        assert!(dns_parse_rrtype("query,answer,A").is_none());
+        assert!(dns_parse_rrtype("A,NOTATYPE").is_none());
+        assert!(dns_parse_rrtype("A,,MX").is_none());
+        assert!(dns_parse_rrtype("70000").is_none());
+    }
+
+    #[test]
+    fn test_match_rrtype_section() {
+        let answer = |rrtype| DNSAnswerEntry {
+            name: b"example.com".to_vec(),
+            rrtype,
+            rrclass: 1,
+            ttl: 3600,
+            data: DNSRData::Unknown(Vec::new()),
+        };
+        let message = |queries, answers, authorities| DNSMessage {
+            header: DNSHeader {
+                tx_id: 1,
+                flags: 0,
+                questions: 1,
+                answer_rr: 0,
+                authority_rr: 0,
+                additional_rr: 0,
+            },
+            queries,
+            answers,
+            authorities,
+            additionals: Vec::new(),
+            size: 12,
+        };
+        let query = DNSQueryEntry {
+            name: b"example.com".to_vec(),
+            rrtype: 255,
+            rrclass: 1,
+        };
+        let mut tx = DNSTransaction {
+            request: Some(message(vec![query], Vec::new(), Vec::new())),
+            response: Some(message(Vec::new(), vec![answer(16)], vec![answer(6)])),
+            ..Default::default()
+        };
+        let to_server = Direction::ToServer as u8;
+        let to_client = Direction::ToClient as u8;
+
+        let mut ctx = dns_parse_rrtype("ANY,TXT").unwrap();
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_server), 1);
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_client), 1);
+
+        let mut ctx = dns_parse_rrtype("SOA").unwrap();
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_client), 0);
+        let mut ctx = dns_parse_rrtype("authority,SOA").unwrap();
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_client), 1);
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_server), 0);
+
+        let mut ctx = dns_parse_rrtype("query,TXT").unwrap();
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_server), 0);
+        let mut ctx = dns_parse_rrtype("answer,TXT").unwrap();
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_server), 0);
+        assert_eq!(SCDnsDetectRrtypeMatch(&mut tx, &mut ctx, to_client), 1);
+    }
+
+    #[test]
+    fn test_dns_tx_get_header() {
+        let message = |answer_rr| DNSMessage {
+            header: DNSHeader {
+                tx_id: 1,
+                flags: 0,
+                questions: 1,
+                answer_rr,
+                authority_rr: 0,
+                additional_rr: 0,
+            },
+            queries: Vec::new(),
+            answers: Vec::new(),
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+            size: 12,
+        };
+        let mut tx = DNSTransaction {
+            request: Some(message(0)),
+            ..Default::default()
+        };
+        let to_server = Direction::ToServer as u8;
+        let to_client = Direction::ToClient as u8;
+        assert_eq!(
+            dns_tx_get_header(&tx, to_server).map(|h| h.answer_rr),
+            Some(0)
+        );
+        assert!(dns_tx_get_header(&tx, to_client).is_none());
+
+        tx.response = Some(message(1000));
+        assert_eq!(
+            dns_tx_get_header(&tx, to_client).map(|h| h.answer_rr),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_dns_match_query_length() {
+        let query = |name: &[u8]| DNSQueryEntry {
+            name: name.to_vec(),
+            rrtype: 16,
+            rrclass: 1,
+        };
+        let message = DNSMessage {
+            header: DNSHeader {
+                tx_id: 1,
+                flags: 0,
+                questions: 2,
+                answer_rr: 0,
+                authority_rr: 0,
+                additional_rr: 0,
+            },
+            queries: vec![
+                query(b"www.example.com"),
+                query(b"aGVsbG8gd29ybGQgdGhpcyBpcyBhIHR1bm5lbA.t.example.com"),
+            ],
+            answers: Vec::new(),
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+            size: 112,
+        };
+        let ctx = detect_parse_uint::<u16>(">40").unwrap().1;
+        assert!(dns_match_query_length(&message, &ctx));
+        let ctx = detect_parse_uint::<u16>("15").unwrap().1;
+        assert!(dns_match_query_length(&message, &ctx));
+        let ctx = detect_parse_uint::<u16>("<10").unwrap().1;
+        assert!(!dns_match_query_length(&message, &ctx));
+    }
+
+    #[test]
+    fn test_dns_message_get_answer_ip() {
+        let answer = |rrtype, data| DNSAnswerEntry {
+            name: b"www.example.com".to_vec(),
+            rrtype,
+            rrclass: 1,
+            ttl: 3600,
+            data,
+        };
+        let message = DNSMessage {
+            header: DNSHeader {
+                tx_id: 1,
+                flags: 0x8180,
+                questions: 1,
+                answer_rr: 4,
+                authority_rr: 0,
+                additional_rr: 0,
+            },
+            queries: Vec::new(),
+            answers: vec![
+                answer(5, DNSRData::CNAME(b"example.com".to_vec())),
+                answer(1, DNSRData::A(vec![192, 0, 2, 1])),
+                answer(
+                    28,
+                    DNSRData::AAAA(vec![
+                        0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+                    ]),
+                ),
+                answer(1, DNSRData::A(vec![192, 0, 2])),
+            ],
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+            size: 120,
+        };
+        assert_eq!(
+            dns_message_get_answer_ip(&message, 0),
+            Some(&[192, 0, 2, 1][..])
+        );
+        assert_eq!(
+            dns_message_get_answer_ip(&message, 1).map(|a| a.len()),
+            Some(16)
+        );
+        // truncated address is skipped
+        assert_eq!(dns_message_get_answer_ip(&message, 2), None);
+    }
 }
@@ -21,8 +21,17 @@ use std::collections::VecDeque;
 use std::ffi::CString;
 
 use crate::applayer::*;
+use crate::conf::conf_get;
 use crate::core::{self, *};
 use crate::dns::parser;
+use crate::dns::query_stats::{dns_query_stats_is_tunnel, register_tunnel_detection, DNSQueryStats};
+use crate::dns::retry::{
+    dns_expect_tcp_retry, dns_get_tcp_retry, register_tcp_retry_expectation, DNSRetry,
+    DNS_FLAG_TRUNCATION,
+};
+use crate::dns::validate::{
+    dns_validate_response, register_response_validation, DNSPendingRequests,
+};
 use crate::frames::Frame;
 
 use nom7::number::streaming::be_u16;
@@ -85,6 +94,8 @@ pub const DNS_RECORD_TYPE_HTTPS: u16 = 65;
 pub const DNS_RECORD_TYPE_SPF: u16 = 99; // Obsolete
 pub const DNS_RECORD_TYPE_TKEY: u16 = 249;
 pub const DNS_RECORD_TYPE_TSIG: u16 = 250;
+pub const DNS_RECORD_TYPE_IXFR: u16 = 251;
+pub const DNS_RECORD_TYPE_AXFR: u16 = 252;
 pub const DNS_RECORD_TYPE_MAILA: u16 = 254; // Obsolete
 pub const DNS_RECORD_TYPE_ANY: u16 = 255;
 pub const DNS_RECORD_TYPE_URI: u16 = 256;
@@ -112,7 +123,15 @@ pub const DNS_RCODE_BADNAME: u16 = 20;
 pub const DNS_RCODE_BADALG: u16 = 21;
 pub const DNS_RCODE_BADTRUNC: u16 = 22;
 
-static mut ALPROTO_DNS: AppProto = ALPROTO_UNKNOWN;
+/// EDNS option code of the DNS cookie (RFC 7873).
+pub const DNS_EDNS_OPTION_COOKIE: u16 = 10;
+const DNS_COOKIE_CLIENT_LEN: usize = 8;
+const DNS_COOKIE_SERVER_MIN_LEN: usize = 8;
+const DNS_COOKIE_SERVER_MAX_LEN: usize = 32;
+
+pub(super) static mut ALPROTO_DNS: AppProto = ALPROTO_UNKNOWN;
+
+static mut DNS_MAX_TX: usize = 1024;
 
 #[derive(AppLayerFrameType)]
 enum DnsFrameType {
@@ -120,6 +139,12 @@ enum DnsFrameType {
     /// this is the DNS payload not including the leading length field allowing
     /// this frame to be used for UDP and TCP DNS.
     Pdu,
+    /// The 12 byte DNS header.
+    Hdr,
+    /// A query of the question section.
+    Query,
+    /// A resource record of the answer section.
+    Answer,
 }
 
 #[derive(Debug, PartialEq, Eq, AppLayerEvent)]
@@ -129,6 +154,25 @@ pub enum DNSEvent {
     NotResponse,
     ZFlagSet,
     InvalidOpcode,
+    /// An AXFR or IXFR zone transfer completed successfully.
+    ZoneTransferCompleted,
+    TooManyTransactions,
+    /// A response was seen on a flow without any request, for example
+    /// due to asymmetric routing.
+    MissingRequest,
+    /// The query types and name lengths of the flow look like DNS
+    /// tunneling.
+    TunnelingSuspected,
+    /// The COOKIE EDNS option has an invalid length.
+    MalformedCookie,
+    /// A response does not answer any unanswered request of the flow
+    /// with its ID and question.
+    ResponseIdMismatch,
+    /// A response has answers for names other than the question name
+    /// and the names of its CNAME chain.
+    UnsolicitedAnswer,
+    /// The CNAME chain of a response is longer than `max-cname-chain`.
+    CnameChainTooLong,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -229,6 +273,34 @@ pub struct DNSAnswerEntry {
     pub data: DNSRData,
 }
 
+/// DNS cookie of the COOKIE EDNS option (RFC 7873).
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DNSCookie {
+    /// Length of the client cookie, 8 unless malformed.
+    pub client_len: u16,
+    /// Length of the server cookie, 0 if the option only has a client
+    /// cookie.
+    pub server_len: u16,
+    /// The option length does not fit a client cookie optionally
+    /// followed by a server cookie of 8 to 32 bytes.
+    pub malformed: bool,
+}
+
+impl DNSCookie {
+    fn new(data: &[u8]) -> Self {
+        let client_len = std::cmp::min(data.len(), DNS_COOKIE_CLIENT_LEN);
+        let server_len = data.len() - client_len;
+        Self {
+            client_len: client_len as u16,
+            server_len: server_len as u16,
+            malformed: client_len < DNS_COOKIE_CLIENT_LEN
+                || (server_len != 0
+                    && !(DNS_COOKIE_SERVER_MIN_LEN..=DNS_COOKIE_SERVER_MAX_LEN)
+                        .contains(&server_len)),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DNSMessage {
     pub header: DNSHeader,
@@ -236,6 +308,101 @@ pub struct DNSMessage {
     pub answers: Vec<DNSAnswerEntry>,
     pub authorities: Vec<DNSAnswerEntry>,
     pub additionals: Vec<DNSAnswerEntry>,
+    /// Size of the message in bytes, without the TCP length prefix.
+    pub size: u32,
+}
+
+impl DNSMessage {
+    /// Get the DNS cookie of the first COOKIE option of the OPT record.
+    pub fn cookie(&self) -> Option<DNSCookie> {
+        for additional in &self.additionals {
+            if let DNSRData::OPT(options) = &additional.data {
+                if let Some(opt) = options.iter().find(|o| o.code == DNS_EDNS_OPTION_COOKIE) {
+                    return Some(DNSCookie::new(&opt.data));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// An AXFR or IXFR zone transfer over TCP.
+///
+/// The response to a zone transfer request may span many DNS messages
+/// that all carry the ID of the request. Only the first message is kept
+/// on the transaction, the following messages are only counted.
+#[derive(Debug, Default)]
+pub struct DNSZoneTransfer {
+    /// Query type of the request, AXFR or IXFR.
+    pub rrtype: u16,
+    /// Number of DNS messages in the response.
+    pub messages: u32,
+    /// Number of records in the answer sections of the response.
+    pub records: u64,
+    /// Number of bytes in the response, not including the TCP length
+    /// prefix of each message.
+    pub bytes: u64,
+    /// Set once the closing SOA record has been seen or the server
+    /// returned an error.
+    pub complete: bool,
+    /// Set if the transfer was closed by an SOA record matching the
+    /// opening one, or if an IXFR response is a single SOA record.
+    pub success: bool,
+    /// Serial of the opening SOA record.
+    serial: Option<u32>,
+}
+
+impl DNSZoneTransfer {
+    fn new(rrtype: u16) -> Self {
+        Self {
+            rrtype,
+            ..Default::default()
+        }
+    }
+
+    /// Account for one response message of the transfer.
+    fn update(&mut self, response: &DNSMessage, len: usize) {
+        self.messages += 1;
+        self.bytes += len as u64;
+        if response.header.flags & 0x000f != DNS_RCODE_NOERROR {
+            self.complete = true;
+            return;
+        }
+        for answer in &response.answers {
+            self.records += 1;
+            if let DNSRData::SOA(soa) = &answer.data {
+                if self.serial.is_none() {
+                    self.serial = Some(soa.serial);
+                }
+            }
+        }
+        // An IXFR response that is only the current SOA record tells the
+        // client its zone is up to date (RFC 1995 section 4).
+        if self.rrtype == DNS_RECORD_TYPE_IXFR
+            && self.messages == 1
+            && response.answers.len() == 1
+            && self.serial.is_some()
+        {
+            self.complete = true;
+            self.success = true;
+            return;
+        }
+        // The transfer ends with a repeat of the opening SOA record. An IXFR
+        // also carries the new SOA within its last difference sequence, so
+        // only look at the last record of the message.
+        if self.records > 1 {
+            if let Some(DNSAnswerEntry {
+                data: DNSRData::SOA(soa),
+                ..
+            }) = response.answers.last()
+            {
+                if self.serial == Some(soa.serial) {
+                    self.complete = true;
+                    self.success = true;
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -243,6 +410,16 @@ pub struct DNSTransaction {
     pub id: u64,
     pub request: Option<DNSMessage>,
     pub response: Option<DNSMessage>,
+    pub zone_transfer: Option<DNSZoneTransfer>,
+    /// Set on responses seen before any request on the flow.
+    pub response_only: bool,
+    /// DNS cookie of the request or response, if any.
+    pub cookie: Option<DNSCookie>,
+    /// Set on UDP responses with the TC flag, the client is expected to
+    /// retry over TCP.
+    pub truncated: bool,
+    /// UDP query this TCP transaction is the retry of, if any.
+    pub retry_of: Option<DNSRetry>,
     pub tx_data: AppLayerTxData,
 }
 
@@ -252,6 +429,22 @@ impl Transaction for DNSTransaction {
     }
 }
 
+impl TxLimit for DNSTransaction {
+    fn is_complete(&self) -> bool {
+        self.zone_transfer.as_ref().map_or(true, |xfr| xfr.complete)
+    }
+
+    fn force_complete(&mut self) {
+        if let Some(xfr) = &mut self.zone_transfer {
+            xfr.complete = true;
+        }
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
 impl DNSTransaction {
     fn new(direction: Direction) -> Self {
         Self {
@@ -286,6 +479,15 @@ impl DNSTransaction {
     pub fn set_event(&mut self, event: DNSEvent) {
         self.tx_data.set_event(event as u8);
     }
+
+    /// Keep the DNS cookie of the message, setting an event if it is
+    /// malformed.
+    fn set_cookie(&mut self, message: &DNSMessage) {
+        self.cookie = message.cookie();
+        if matches!(&self.cookie, Some(cookie) if cookie.malformed) {
+            self.set_event(DNSEvent::MalformedCookie);
+        }
+    }
 }
 
 struct ConfigTracker {
@@ -327,9 +529,29 @@ pub struct DNSState {
 
     // Transactions.
     transactions: VecDeque<DNSTransaction>,
+    tx_index_completed: usize,
 
     config: Option<ConfigTracker>,
 
+    // DNS message ID and query type of the last AXFR/IXFR request seen
+    // on TCP that has not been answered yet.
+    xfr_request: Option<(u16, u16)>,
+
+    // Set once a request has been seen, responses seen before are
+    // flagged as response only.
+    request_seen: bool,
+
+    // Query counters of the flow, and whether the tunneling event has
+    // been raised already.
+    query_stats: DNSQueryStats,
+    tunnel_event_set: bool,
+
+    // Requests not answered yet, to validate the responses.
+    pending_requests: DNSPendingRequests,
+
+    // UDP query of which this TCP flow is the retry, if any.
+    retry: Option<DNSRetry>,
+
     gap: bool,
 }
 
@@ -378,6 +600,7 @@ pub(crate) fn dns_parse_request(input: &[u8]) -> Result<DNSTransaction, DNSParse
             let opcode = ((request.header.flags >> 11) & 0xf) as u8;
 
             let mut tx = DNSTransaction::new(Direction::ToServer);
+            tx.set_cookie(&request);
             tx.request = Some(request);
 
             if z_flag {
@@ -418,6 +641,7 @@ pub(crate) fn dns_parse_response(input: &[u8]) -> Result<DNSTransaction, DNSPars
             let flags = response.header.flags;
 
             let mut tx = DNSTransaction::new(Direction::ToClient);
+            tx.set_cookie(&response);
             tx.response = Some(response);
 
             if flags & 0x8000 == 0 {
@@ -448,6 +672,43 @@ pub(crate) fn dns_parse_response(input: &[u8]) -> Result<DNSTransaction, DNSPars
     }
 }
 
+/// Create the header, query and answer frames of a parsed message.
+fn dns_record_frames_new(
+    flow: *const core::Flow, stream_slice: &StreamSlice, message: &[u8], header: &DNSHeader,
+    tx_id: u64,
+) {
+    let _hdr = Frame::new(
+        flow,
+        stream_slice,
+        message,
+        DNS_HEADER_SIZE as i64,
+        DnsFrameType::Hdr as u8,
+        Some(tx_id),
+    );
+    if let Ok((_, (queries, answers))) = parser::dns_parse_record_spans(message, header) {
+        for (offset, len) in queries {
+            let _query = Frame::new(
+                flow,
+                stream_slice,
+                &message[offset..],
+                len as i64,
+                DnsFrameType::Query as u8,
+                Some(tx_id),
+            );
+        }
+        for (offset, len) in answers {
+            let _answer = Frame::new(
+                flow,
+                stream_slice,
+                &message[offset..],
+                len as i64,
+                DnsFrameType::Answer as u8,
+                Some(tx_id),
+            );
+        }
+    }
+}
+
 impl DNSState {
     fn new() -> Self {
         Default::default()
@@ -466,6 +727,7 @@ impl DNSState {
             }
         }
         if found {
+            self.tx_index_completed = 0;
             self.transactions.remove(index);
         }
     }
@@ -485,15 +747,66 @@ impl DNSState {
         tx.tx_data.set_event(event as u8);
     }
 
-    fn parse_request(&mut self, input: &[u8], is_tcp: bool, frame: Option<Frame>, flow: *const core::Flow,) -> bool {
+    /// Add a new transaction, enforcing the transaction limit.
+    fn push_tx(&mut self, mut tx: DNSTransaction) {
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            unsafe { DNS_MAX_TX },
+            &mut self.tx_index_completed,
+            &mut tx,
+            DNSEvent::TooManyTransactions as u8,
+        );
+        self.transactions.push_back(tx);
+    }
+
+    fn parse_request(
+        &mut self, input: &[u8], is_tcp: bool, stream_slice: Option<&StreamSlice>,
+        flow: *const core::Flow,
+    ) -> bool {
+        let frame = stream_slice.and_then(|stream_slice| {
+            Frame::new(
+                flow,
+                stream_slice,
+                input,
+                input.len() as i64,
+                DnsFrameType::Pdu as u8,
+                None,
+            )
+        });
         match dns_parse_request(input) {
             Ok(mut tx) => {
                 self.tx_id += 1;
                 tx.id = self.tx_id;
+                self.request_seen = true;
+                if let Some(request) = &tx.request {
+                    self.query_stats.update(&request.queries);
+                    self.pending_requests
+                        .add(request.header.tx_id, &request.queries);
+                }
+                if !self.tunnel_event_set && dns_query_stats_is_tunnel(&self.query_stats) {
+                    tx.set_event(DNSEvent::TunnelingSuspected);
+                    self.tunnel_event_set = true;
+                }
                 if let Some(frame) = frame {
                     frame.set_tx(flow, tx.id);
                 }
-                self.transactions.push_back(tx);
+                if let (Some(stream_slice), Some(request)) = (stream_slice, &tx.request) {
+                    dns_record_frames_new(flow, stream_slice, input, &request.header, tx.id);
+                }
+                if is_tcp {
+                    self.tag_retry(flow, &mut tx);
+                    if let Some(request) = &tx.request {
+                        if let Some(query) = request.queries.first() {
+                            if query.rrtype == DNS_RECORD_TYPE_AXFR
+                                || query.rrtype == DNS_RECORD_TYPE_IXFR
+                            {
+                                self.xfr_request = Some((request.header.tx_id, query.rrtype));
+                            }
+                        }
+                    }
+                }
+                self.push_tx(tx);
                 return true;
             }
             Err(e) => match e {
@@ -517,36 +830,60 @@ impl DNSState {
     }
 
     fn parse_request_udp(&mut self, flow: *const core::Flow, stream_slice: StreamSlice) -> bool {
-        let input = stream_slice.as_slice();
-        let frame = Frame::new(
-            flow,
-            &stream_slice,
-            input,
-            input.len() as i64,
-            DnsFrameType::Pdu as u8,
-            None,
-        );
-        self.parse_request(input, false, frame, flow)
+        self.parse_request(stream_slice.as_slice(), false, Some(&stream_slice), flow)
     }
 
     fn parse_response_udp(&mut self, flow: *const core::Flow, stream_slice: StreamSlice) -> bool {
-        let input = stream_slice.as_slice();
-        let frame = Frame::new(
-            flow,
-            &stream_slice,
-            input,
-            input.len() as i64,
-            DnsFrameType::Pdu as u8,
-            None,
-        );
-        self.parse_response(input, false, frame, flow)
+        self.parse_response(stream_slice.as_slice(), false, Some(&stream_slice), flow)
     }
 
-    fn parse_response(&mut self, input: &[u8], is_tcp: bool, frame: Option<Frame>, flow: *const core::Flow) -> bool {
+    fn parse_response(
+        &mut self, input: &[u8], is_tcp: bool, stream_slice: Option<&StreamSlice>,
+        flow: *const core::Flow,
+    ) -> bool {
+        let frame = stream_slice.and_then(|stream_slice| {
+            Frame::new(
+                flow,
+                stream_slice,
+                input,
+                input.len() as i64,
+                DnsFrameType::Pdu as u8,
+                None,
+            )
+        });
         match dns_parse_response(input) {
             Ok(mut tx) => {
+                if is_tcp {
+                    if let Some(id) = self.zone_transfer_continue(&tx, input.len()) {
+                        if let Some(frame) = frame {
+                            frame.set_tx(flow, id);
+                        }
+                        if let (Some(stream_slice), Some(response)) = (stream_slice, &tx.response) {
+                            dns_record_frames_new(flow, stream_slice, input, &response.header, id);
+                        }
+                        return true;
+                    }
+                }
                 self.tx_id += 1;
                 tx.id = self.tx_id;
+                if !self.request_seen {
+                    SCLogDebug!("DNS response without a request");
+                    tx.response_only = true;
+                    tx.set_event(DNSEvent::MissingRequest);
+                }
+                if let Some(response) = &tx.response {
+                    let mut events = dns_validate_response(response);
+                    if self.request_seen
+                        && !self
+                            .pending_requests
+                            .answer(response.header.tx_id, &response.queries)
+                    {
+                        events.push(DNSEvent::ResponseIdMismatch);
+                    }
+                    for event in events {
+                        tx.set_event(event);
+                    }
+                }
                 if let Some(ref mut config) = &mut self.config {
                     if let Some(response) = &tx.response {
                         if let Some(config) = config.remove(&response.header.tx_id) {
@@ -554,10 +891,24 @@ impl DNSState {
                         }
                     }
                 }
+                if is_tcp {
+                    self.tag_retry(flow, &mut tx);
+                    self.zone_transfer_start(&mut tx, input.len());
+                } else if let Some(response) = &tx.response {
+                    if response.header.flags & DNS_FLAG_TRUNCATION != 0 {
+                        tx.truncated = true;
+                        if self.request_seen {
+                            dns_expect_tcp_retry(flow, response);
+                        }
+                    }
+                }
                 if let Some(frame) = frame {
                     frame.set_tx(flow, tx.id);
                 }
-                self.transactions.push_back(tx);
+                if let (Some(stream_slice), Some(response)) = (stream_slice, &tx.response) {
+                    dns_record_frames_new(flow, stream_slice, input, &response.header, tx.id);
+                }
+                self.push_tx(tx);
                 return true;
             }
             Err(e) => match e {
@@ -572,6 +923,59 @@ impl DNSState {
         }
     }
 
+    /// Tag the transaction of a TCP flow as the retry of a truncated UDP
+    /// query if it has the same question.
+    fn tag_retry(&mut self, flow: *const core::Flow, tx: &mut DNSTransaction) {
+        if tx.id == 1 {
+            self.retry = dns_get_tcp_retry(flow);
+        }
+        if let Some(retry) = &self.retry {
+            let message = tx.request.as_ref().or(tx.response.as_ref());
+            if message.map_or(false, |message| retry.matches(message)) {
+                tx.retry_of = Some(retry.clone());
+            }
+        }
+    }
+
+    /// Start tracking a zone transfer if the response answers the last
+    /// AXFR/IXFR request.
+    fn zone_transfer_start(&mut self, tx: &mut DNSTransaction, len: usize) {
+        let xfr = match (&tx.response, self.xfr_request) {
+            (Some(response), Some((id, rrtype))) if response.header.tx_id == id => {
+                let mut xfr = DNSZoneTransfer::new(rrtype);
+                xfr.update(response, len);
+                xfr
+            }
+            _ => {
+                return;
+            }
+        };
+        self.xfr_request = None;
+        if xfr.success {
+            tx.set_event(DNSEvent::ZoneTransferCompleted);
+        }
+        tx.zone_transfer = Some(xfr);
+    }
+
+    /// Account for a response message continuing an open zone transfer.
+    ///
+    /// Returns the internal ID of the transaction tracking the transfer,
+    /// or None if the message is not part of one.
+    fn zone_transfer_continue(&mut self, tx: &DNSTransaction, len: usize) -> Option<u64> {
+        let response = tx.response.as_ref()?;
+        let xfr_tx = self.transactions.iter_mut().rev().find(|xfr_tx| {
+            xfr_tx.tx_id() == response.header.tx_id
+                && matches!(&xfr_tx.zone_transfer, Some(xfr) if !xfr.complete)
+        })?;
+        if let Some(xfr) = &mut xfr_tx.zone_transfer {
+            xfr.update(response, len);
+            if xfr.success {
+                xfr_tx.set_event(DNSEvent::ZoneTransferCompleted);
+            }
+        }
+        Some(xfr_tx.id)
+    }
+
     /// TCP variation of response request parser to handle the length
     /// prefix.
     ///
@@ -607,15 +1011,7 @@ impl DNSState {
             if size > 0 && cur_i.len() >= size + 2 {
                 let msg = &cur_i[2..(size + 2)];
                 sc_app_layer_parser_trigger_raw_stream_reassembly(flow, Direction::ToServer as i32);
-                let frame = Frame::new(
-                    flow,
-                    &stream_slice,
-                    msg,
-                    msg.len() as i64,
-                    DnsFrameType::Pdu as u8,
-                    None,
-                );
-                if self.parse_request(msg, true, frame, flow) {
+                if self.parse_request(msg, true, Some(&stream_slice), flow) {
                     cur_i = &cur_i[(size + 2)..];
                     consumed += size + 2;
                 } else {
@@ -671,15 +1067,7 @@ impl DNSState {
             if size > 0 && cur_i.len() >= size + 2 {
                 let msg = &cur_i[2..(size + 2)];
                 sc_app_layer_parser_trigger_raw_stream_reassembly(flow, Direction::ToClient as i32);
-                let frame = Frame::new(
-                    flow,
-                    &stream_slice,
-                    msg,
-                    msg.len() as i64,
-                    DnsFrameType::Pdu as u8,
-                    None,
-                );
-                if self.parse_response(msg, true, frame, flow) {
+                if self.parse_response(msg, true, Some(&stream_slice), flow) {
                     cur_i = &cur_i[(size + 2)..];
                     consumed += size + 2;
                 } else {
@@ -848,12 +1236,19 @@ unsafe extern "C" fn parse_response_tcp(
     AppLayerResult::ok()
 }
 
-extern "C" fn tx_get_alstate_progress(
-    _tx: *mut std::os::raw::c_void, _direction: u8,
+unsafe extern "C" fn tx_get_alstate_progress(
+    tx: *mut std::os::raw::c_void, _direction: u8,
 ) -> std::os::raw::c_int {
+    let tx = cast_pointer!(tx, DNSTransaction);
     // This is a stateless parser, just the existence of a transaction
-    // means its complete.
+    // means its complete. The exception is a zone transfer, which is
+    // complete once its last message has been seen.
     SCLogDebug!("rs_dns_tx_get_alstate_progress");
+    if let Some(xfr) = &tx.zone_transfer {
+        if !xfr.complete {
+            return 0;
+        }
+    }
     return 1;
 }
 
@@ -1011,6 +1406,45 @@ unsafe extern "C" fn apply_tx_config(
     }
 }
 
+/// Also probe UDP traffic coming from the DNS port, so that flows of which
+/// only the responses are seen are detected as well. Skipped if source
+/// ports are configured for detection.
+unsafe fn register_udp_response_probe(alproto: AppProto) {
+    if conf_get("app-layer.protocols.dns.udp.detection-ports.sp").is_some() {
+        return;
+    }
+    let port = CString::new("[53]").unwrap();
+    AppLayerProtoDetectPPRegister(
+        IPPROTO_UDP,
+        port.as_ptr(),
+        alproto,
+        0,
+        std::mem::size_of::<DNSHeader>() as u16,
+        Direction::ToClient.into(),
+        probe_udp,
+        probe_udp,
+    );
+}
+
+/// Get the query counters of the flow.
+#[no_mangle]
+pub unsafe extern "C" fn SCDnsStateGetQueryStats(
+    state: *const std::os::raw::c_void, stats: &mut DNSQueryStats,
+) {
+    let state = cast_pointer!(state, DNSState);
+    *stats = state.query_stats;
+}
+
+unsafe fn register_max_tx() {
+    if let Some(val) = conf_get("app-layer.protocols.dns.max-tx") {
+        if let Ok(v) = val.parse::<usize>() {
+            DNS_MAX_TX = v;
+        } else {
+            SCLogError!("Invalid value for dns.max-tx");
+        }
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn SCRegisterDnsUdpParser() {
     let default_port = std::ffi::CString::new("[53]").unwrap();
@@ -1050,9 +1484,13 @@ pub unsafe extern "C" fn SCRegisterDnsUdpParser() {
     if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_DNS = alproto;
+        register_udp_response_probe(alproto);
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
+        register_max_tx();
+        register_tunnel_detection();
+        register_response_validation();
     }
 }
 
@@ -1096,8 +1534,12 @@ pub unsafe extern "C" fn SCRegisterDnsTcpParser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_DNS = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
+        register_max_tx();
+        register_tunnel_detection();
+        register_response_validation();
+        register_tcp_retry_expectation();
     }
 }
 
@@ -1392,6 +1834,65 @@ mod tests {
     }
 
     // Port of the C RustDNSTCPParserTestMultiRecord unit test.
+    #[test]
+    fn test_dns_udp_response_only() {
+        #[rustfmt::skip]
+        let request: &[u8] = &[
+            0x12, 0x34, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x61, 0x03, 0x63,
+            0x6f, 0x6d, 0x00, 0x00, 0x01, 0x00, 0x01,
+        ];
+        #[rustfmt::skip]
+        let response: &[u8] = &[
+            0x12, 0x34, 0x81, 0x80, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x61, 0x03, 0x63,
+            0x6f, 0x6d, 0x00, 0x00, 0x01, 0x00, 0x01,
+        ];
+        assert_eq!(probe(response, response.len()), (true, false, false));
+
+        // Responses are parsed even if no request was seen.
+        let mut state = DNSState::new();
+        assert!(state.parse_response(response, false, None, std::ptr::null()));
+        assert_eq!(state.transactions.len(), 1);
+        assert!(state.transactions[0].response_only);
+        assert!(state.transactions[0].response.is_some());
+
+        assert!(state.parse_request(request, false, None, std::ptr::null()));
+        assert!(state.parse_response(response, false, None, std::ptr::null()));
+        assert_eq!(state.transactions.len(), 3);
+        assert!(!state.transactions[2].response_only);
+    }
+
+    #[test]
+    fn test_dns_cookie() {
+        #[rustfmt::skip]
+        let request: &[u8] = &[
+            0x12, 0x34, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01, 0x01, 0x61, 0x03, 0x63,
+            0x6f, 0x6d, 0x00, 0x00, 0x01, 0x00, 0x01,
+            // OPT record with a COOKIE option holding a client cookie
+            0x00, 0x00, 0x29, 0x04, 0xd0, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x0c, 0x00, 0x0a, 0x00, 0x08, 0x01,
+            0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+        ];
+        let tx = dns_parse_request(request).unwrap();
+        assert_eq!(
+            tx.cookie,
+            Some(DNSCookie {
+                client_len: 8,
+                server_len: 0,
+                malformed: false,
+            })
+        );
+
+        assert!(!DNSCookie::new(&[0; 24]).malformed);
+        assert!(!DNSCookie::new(&[0; 40]).malformed);
+        assert!(DNSCookie::new(&[0; 7]).malformed);
+        assert!(DNSCookie::new(&[0; 12]).malformed);
+        assert!(DNSCookie::new(&[0; 41]).malformed);
+        assert_eq!(DNSCookie::new(&[0; 4]).server_len, 0);
+    }
+
     #[test]
     fn test_dns_tcp_parser_multi_record() {
         #[rustfmt::skip]
@@ -1538,6 +2039,191 @@ mod tests {
         );
     }
 
+    fn xfr_name(name: &[&str]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for label in name {
+            buf.push(label.len() as u8);
+            buf.extend_from_slice(label.as_bytes());
+        }
+        buf.push(0);
+        buf
+    }
+
+    fn xfr_record(rrtype: u16, rdata: &[u8]) -> Vec<u8> {
+        let mut buf = xfr_name(&["example", "com"]);
+        buf.extend_from_slice(&rrtype.to_be_bytes());
+        buf.extend_from_slice(&[0x00, 0x01, 0x00, 0x00, 0x0e, 0x10]);
+        buf.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        buf.extend_from_slice(rdata);
+        buf
+    }
+
+    fn xfr_soa(serial: u32) -> Vec<u8> {
+        let mut rdata = xfr_name(&["ns", "example", "com"]);
+        rdata.extend(xfr_name(&["hostmaster", "example", "com"]));
+        rdata.extend_from_slice(&serial.to_be_bytes());
+        for value in [3600_u32, 600, 86400, 300] {
+            rdata.extend_from_slice(&value.to_be_bytes());
+        }
+        xfr_record(DNS_RECORD_TYPE_SOA, &rdata)
+    }
+
+    /// Build a length prefixed zone transfer message for ID 0x1234.
+    fn xfr_message(flags: u16, rrtype: u16, question: bool, records: &[Vec<u8>]) -> Vec<u8> {
+        let mut msg = vec![0x12, 0x34];
+        msg.extend_from_slice(&flags.to_be_bytes());
+        msg.extend_from_slice(&(question as u16).to_be_bytes());
+        msg.extend_from_slice(&(records.len() as u16).to_be_bytes());
+        msg.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+        if question {
+            msg.extend(xfr_name(&["example", "com"]));
+            msg.extend_from_slice(&rrtype.to_be_bytes());
+            msg.extend_from_slice(&[0x00, 0x01]);
+        }
+        for record in records {
+            msg.extend_from_slice(record);
+        }
+        let mut buf = (msg.len() as u16).to_be_bytes().to_vec();
+        buf.extend(msg);
+        buf
+    }
+
+    #[test]
+    fn test_dns_tcp_zone_transfer() {
+        let flow = std::ptr::null();
+        let mut state = DNSState::new();
+
+        let request = xfr_message(0x0000, DNS_RECORD_TYPE_AXFR, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_request_tcp(flow, StreamSlice::from_slice(&request, STREAM_TOSERVER, 0))
+        );
+        assert_eq!(state.xfr_request, Some((0x1234, DNS_RECORD_TYPE_AXFR)));
+
+        let a = xfr_record(DNS_RECORD_TYPE_A, &[192, 0, 2, 1]);
+        let first = xfr_message(
+            0x8400,
+            DNS_RECORD_TYPE_AXFR,
+            true,
+            &[xfr_soa(2024010101), a.clone()],
+        );
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_response_tcp(flow, StreamSlice::from_slice(&first, STREAM_TOCLIENT, 0))
+        );
+        assert_eq!(state.transactions.len(), 2);
+        let xfr = state.transactions[1].zone_transfer.as_ref().unwrap();
+        assert_eq!(xfr.records, 2);
+        assert!(!xfr.complete);
+        let tx = &mut state.transactions[1] as *mut _ as *mut std::os::raw::c_void;
+        assert_eq!(unsafe { tx_get_alstate_progress(tx, STREAM_TOCLIENT) }, 0);
+
+        let last = xfr_message(
+            0x8400,
+            DNS_RECORD_TYPE_AXFR,
+            false,
+            &[a.clone(), a, xfr_soa(2024010101)],
+        );
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_response_tcp(flow, StreamSlice::from_slice(&last, STREAM_TOCLIENT, 0))
+        );
+        assert_eq!(state.transactions.len(), 2);
+        assert_eq!(state.tx_id, 2);
+        let xfr = state.transactions[1].zone_transfer.as_ref().unwrap();
+        assert_eq!(xfr.messages, 2);
+        assert_eq!(xfr.records, 5);
+        assert_eq!(xfr.bytes, (first.len() + last.len() - 4) as u64);
+        assert!(xfr.complete);
+        assert!(xfr.success);
+        let tx = &mut state.transactions[1] as *mut _ as *mut std::os::raw::c_void;
+        assert_eq!(unsafe { tx_get_alstate_progress(tx, STREAM_TOCLIENT) }, 1);
+    }
+
+    #[test]
+    fn test_dns_tcp_zone_transfer_refused() {
+        let flow = std::ptr::null();
+        let mut state = DNSState::new();
+
+        let request = xfr_message(0x0000, DNS_RECORD_TYPE_IXFR, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_request_tcp(flow, StreamSlice::from_slice(&request, STREAM_TOSERVER, 0))
+        );
+        let response = xfr_message(0x8405, DNS_RECORD_TYPE_IXFR, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_response_tcp(flow, StreamSlice::from_slice(&response, STREAM_TOCLIENT, 0))
+        );
+        let xfr = state.transactions[1].zone_transfer.as_ref().unwrap();
+        assert_eq!(xfr.rrtype, DNS_RECORD_TYPE_IXFR);
+        assert!(xfr.complete);
+        assert!(!xfr.success);
+        assert!(state.xfr_request.is_none());
+    }
+
+    #[test]
+    fn test_dns_tcp_zone_transfer_up_to_date() {
+        let flow = std::ptr::null();
+        let mut state = DNSState::new();
+
+        let request = xfr_message(0x0000, DNS_RECORD_TYPE_IXFR, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_request_tcp(flow, StreamSlice::from_slice(&request, STREAM_TOSERVER, 0))
+        );
+        let response = xfr_message(0x8400, DNS_RECORD_TYPE_IXFR, true, &[xfr_soa(2024010101)]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_response_tcp(flow, StreamSlice::from_slice(&response, STREAM_TOCLIENT, 0))
+        );
+        let xfr = state.transactions[1].zone_transfer.as_ref().unwrap();
+        assert_eq!(xfr.records, 1);
+        assert!(xfr.complete);
+        assert!(xfr.success);
+        let tx = &mut state.transactions[1] as *mut _ as *mut std::os::raw::c_void;
+        assert_eq!(unsafe { tx_get_alstate_progress(tx, STREAM_TOCLIENT) }, 1);
+    }
+
+    #[test]
+    fn test_dns_tcp_retry() {
+        let flow = std::ptr::null();
+        let mut udp_state = DNSState::new();
+        let request = xfr_message(0x0100, DNS_RECORD_TYPE_TXT, true, &[]);
+        assert!(udp_state.parse_request(&request[2..], false, None, flow));
+        let response = xfr_message(0x8380, DNS_RECORD_TYPE_TXT, true, &[]);
+        assert!(udp_state.parse_response(&response[2..], false, None, flow));
+        assert!(!udp_state.transactions[0].truncated);
+        assert!(udp_state.transactions[1].truncated);
+
+        let mut state = DNSState::new();
+        let other = xfr_message(0x0100, DNS_RECORD_TYPE_A, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_request_tcp(flow, StreamSlice::from_slice(&other, STREAM_TOSERVER, 0))
+        );
+        // As if the flow was expected by the truncated response.
+        let truncated = udp_state.transactions[1].response.as_ref().unwrap();
+        state.retry = DNSRetry::new(7, truncated);
+
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_request_tcp(flow, StreamSlice::from_slice(&request, STREAM_TOSERVER, 0))
+        );
+        let response = xfr_message(0x8180, DNS_RECORD_TYPE_TXT, true, &[]);
+        assert_eq!(
+            AppLayerResult::ok(),
+            state.parse_response_tcp(flow, StreamSlice::from_slice(&response, STREAM_TOCLIENT, 0))
+        );
+        assert!(state.transactions[0].retry_of.is_none());
+        for tx in state.transactions.range(1..) {
+            let retry = tx.retry_of.as_ref().unwrap();
+            assert_eq!(retry.flow_id, 7);
+            assert_eq!(retry.id, 0x1234);
+            assert!(!tx.truncated);
+        }
+    }
+
     #[test]
     fn test_dns_event_from_id() {
         assert_eq!(DNSEvent::from_id(0), Some(DNSEvent::MalformedData));
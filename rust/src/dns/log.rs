@@ -292,6 +292,8 @@ pub fn dns_rrtype_string(rrtype: u16) -> String {
         DNS_RECORD_TYPE_WKS => "WKS",
         DNS_RECORD_TYPE_TKEY => "TKEY",
         DNS_RECORD_TYPE_TSIG => "TSIG",
+        DNS_RECORD_TYPE_IXFR => "IXFR",
+        DNS_RECORD_TYPE_AXFR => "AXFR",
         DNS_RECORD_TYPE_ANY => "ANY",
         DNS_RECORD_TYPE_RRSIG => "RRSIG",
         DNS_RECORD_TYPE_NSEC => "NSEC",
@@ -345,6 +347,16 @@ pub fn dns_rrtype_string(rrtype: u16) -> String {
     .to_string()
 }
 
+/// Return the rrtype named `name` by `dns_rrtype_string`, ignoring case.
+pub fn dns_rrtype_from_string(name: &str) -> Option<u16> {
+    if !name.bytes().all(|c| c.is_ascii_alphanumeric()) || name.bytes().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    // the named rrtypes are all below 257
+    (0..=256).find(|&rrtype| dns_rrtype_string(rrtype).eq_ignore_ascii_case(name))
+}
+
 pub fn dns_rcode_string(flags: u16) -> String {
     match flags & 0x000f {
         DNS_RCODE_NOERROR => "NOERROR",
@@ -834,6 +846,24 @@ fn log_json(tx: &mut DNSTransaction, flags: u64, jb: &mut JsonBuilder) -> Result
     let opcode = ((header.flags >> 11) & 0xf) as u8;
     jb.set_uint("opcode", opcode as u64)?;
     jb.set_string("rcode", &dns_rcode_string(header.flags))?;
+    if tx.response_only {
+        jb.set_bool("response_only", true)?;
+    }
+    if let Some(retry) = &tx.retry_of {
+        jb.open_object("retry_of")?;
+        jb.set_int("flow_id", retry.flow_id)?;
+        jb.set_uint("id", retry.id as u64)?;
+        jb.close()?;
+    }
+    if let Some(cookie) = &tx.cookie {
+        jb.open_object("cookie")?;
+        jb.set_uint("client_len", cookie.client_len as u64)?;
+        jb.set_uint("server_len", cookie.server_len as u64)?;
+        if cookie.malformed {
+            jb.set_bool("malformed", true)?;
+        }
+        jb.close()?;
+    }
 
     if !message.queries.is_empty() {
         jb.open_array("queries")?;
@@ -852,6 +882,17 @@ fn log_json(tx: &mut DNSTransaction, flags: u64, jb: &mut JsonBuilder) -> Result
         dns_log_json_answers(jb, message, flags)?;
     }
 
+    if let Some(xfr) = &tx.zone_transfer {
+        jb.open_object("zone_transfer")?;
+        jb.set_string("type", &dns_rrtype_string(xfr.rrtype))?;
+        jb.set_uint("messages", xfr.messages as u64)?;
+        jb.set_uint("records", xfr.records)?;
+        jb.set_uint("bytes", xfr.bytes)?;
+        jb.set_bool("complete", xfr.complete)?;
+        jb.set_bool("success", xfr.success)?;
+        jb.close()?;
+    }
+
     if !message.authorities.is_empty() {
         jb.open_array("authorities")?;
         for auth in &message.authorities {
@@ -21,4 +21,7 @@ pub mod detect;
 pub mod dns;
 pub mod log;
 pub mod parser;
+pub mod query_stats;
+pub mod retry;
+pub mod validate;
 pub mod lua;
@@ -18,6 +18,7 @@
 //! Nom parsers for DNS.
 
 use crate::dns::dns::*;
+use nom7::bytes::streaming::take;
 use nom7::combinator::{complete, rest};
 use nom7::error::ErrorKind;
 use nom7::multi::{count, length_data, many_m_n};
@@ -371,10 +372,39 @@ pub fn dns_parse_body<'a>(
             answers,
             authorities,
             additionals,
+            size: message.len() as u32,
         },
     ))
 }
 
+/// Offset and length of each query and of each record of the answer
+/// section of a message, for the frames. The record counts come from the
+/// header, so the preallocation is capped by the data left.
+#[allow(clippy::type_complexity)]
+pub fn dns_parse_record_spans<'a>(
+    message: &'a [u8], header: &DNSHeader,
+) -> IResult<&'a [u8], (Vec<(usize, usize)>, Vec<(usize, usize)>)> {
+    let (mut i, _) = dns_parse_header(message)?;
+    let mut queries = Vec::with_capacity((header.questions as usize).min(i.len()));
+    for _ in 0..header.questions {
+        let offset = message.len() - i.len();
+        let (rem, _) = dns_parse_query(i, message)?;
+        queries.push((offset, i.len() - rem.len()));
+        i = rem;
+    }
+    let mut answers = Vec::with_capacity((header.answer_rr as usize).min(i.len()));
+    for _ in 0..header.answer_rr {
+        let offset = message.len() - i.len();
+        let (rem, _) = dns_parse_name(i, message)?;
+        // type, class and ttl
+        let (rem, _) = take(8_usize)(rem)?;
+        let (rem, _) = length_data(be_u16)(rem)?;
+        answers.push((offset, i.len() - rem.len()));
+        i = rem;
+    }
+    Ok((i, (queries, answers)))
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -692,7 +722,18 @@ mod tests {
                 ttl: 244,
                 data: DNSRData::A([192, 0, 78, 25].to_vec()),
             }
-        )
+        );
+
+        let (_, (queries, answers)) = dns_parse_record_spans(pkt, &response.header).unwrap();
+        assert_eq!(queries, vec![(12, 26)]);
+        assert_eq!(answers, vec![(38, 30), (68, 16), (84, 16)]);
+
+        // Counts beyond the records in the message.
+        let header = DNSHeader {
+            answer_rr: u16::MAX,
+            ..response.header
+        };
+        assert!(dns_parse_record_spans(pkt, &header).is_err());
     }
 
     #[test]
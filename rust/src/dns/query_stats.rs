@@ -0,0 +1,177 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Per flow query statistics, used to spot DNS tunneling.
+//!
+//! Tunnels carry their data in long query names and mostly use record
+//! types able to return arbitrary data in the answers, like TXT and
+//! NULL. Once enough queries are seen on a flow, a high ratio of such
+//! queries combined with long names raises an event.
+
+use crate::conf::conf_get;
+use crate::dns::dns::{
+    DNSQueryEntry, DNS_RECORD_TYPE_CNAME, DNS_RECORD_TYPE_NULL, DNS_RECORD_TYPE_TXT,
+};
+
+/// Query counters of a flow.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DNSQueryStats {
+    /// Number of queries, a request can have several.
+    pub queries: u64,
+    pub txt: u64,
+    pub null: u64,
+    pub cname: u64,
+    /// Sum of the query name lengths.
+    pub name_len_total: u64,
+}
+
+impl DNSQueryStats {
+    /// Account for the queries of a request.
+    pub fn update(&mut self, queries: &[DNSQueryEntry]) {
+        for query in queries {
+            self.queries += 1;
+            self.name_len_total += query.name.len() as u64;
+            match query.rrtype {
+                DNS_RECORD_TYPE_TXT => self.txt += 1,
+                DNS_RECORD_TYPE_NULL => self.null += 1,
+                DNS_RECORD_TYPE_CNAME => self.cname += 1,
+                _ => {}
+            }
+        }
+    }
+
+    pub fn avg_name_len(&self) -> u64 {
+        if self.queries == 0 {
+            return 0;
+        }
+        self.name_len_total / self.queries
+    }
+
+    /// Check the counters against the tunneling thresholds.
+    fn is_tunnel(&self, config: &DNSTunnelConfig) -> bool {
+        config.enabled
+            && self.queries >= config.min_queries
+            && (self.txt + self.null) * 100 >= self.queries * config.txt_null_ratio
+            && self.avg_name_len() >= config.min_avg_name_len
+    }
+}
+
+#[derive(Debug)]
+struct DNSTunnelConfig {
+    enabled: bool,
+    /// Number of queries before the flow is considered.
+    min_queries: u64,
+    /// Percentage of TXT and NULL queries.
+    txt_null_ratio: u64,
+    min_avg_name_len: u64,
+}
+
+static mut DNS_TUNNEL_CONFIG: DNSTunnelConfig = DNSTunnelConfig {
+    enabled: true,
+    min_queries: 20,
+    txt_null_ratio: 50,
+    min_avg_name_len: 40,
+};
+
+fn dns_tunnel_conf_u64(name: &str, value: &mut u64) {
+    let key = format!("app-layer.protocols.dns.tunnel-detection.{}", name);
+    if let Some(val) = conf_get(&key) {
+        if let Ok(v) = val.parse::<u64>() {
+            *value = v;
+        } else {
+            SCLogError!("Invalid value for dns.tunnel-detection.{}", name);
+        }
+    }
+}
+
+pub(super) unsafe fn register_tunnel_detection() {
+    if let Some(val) = conf_get("app-layer.protocols.dns.tunnel-detection.enabled") {
+        DNS_TUNNEL_CONFIG.enabled = matches!(val, "1" | "yes" | "true" | "on");
+    }
+    dns_tunnel_conf_u64("min-queries", &mut DNS_TUNNEL_CONFIG.min_queries);
+    dns_tunnel_conf_u64("txt-null-ratio", &mut DNS_TUNNEL_CONFIG.txt_null_ratio);
+    dns_tunnel_conf_u64("min-avg-name-len", &mut DNS_TUNNEL_CONFIG.min_avg_name_len);
+    if DNS_TUNNEL_CONFIG.txt_null_ratio > 100 {
+        SCLogError!("Invalid value for dns.tunnel-detection.txt-null-ratio, using 100");
+        DNS_TUNNEL_CONFIG.txt_null_ratio = 100;
+    }
+}
+
+/// Check if the counters of a flow indicate DNS tunneling.
+pub(super) fn dns_query_stats_is_tunnel(stats: &DNSQueryStats) -> bool {
+    stats.is_tunnel(unsafe { &DNS_TUNNEL_CONFIG })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(name: &[u8], rrtype: u16) -> Vec<DNSQueryEntry> {
+        vec![DNSQueryEntry {
+            name: name.to_vec(),
+            rrtype,
+            rrclass: 1,
+        }]
+    }
+
+    #[test]
+    fn test_dns_query_stats_tunnel() {
+        let config = DNSTunnelConfig {
+            enabled: true,
+            min_queries: 4,
+            txt_null_ratio: 50,
+            min_avg_name_len: 40,
+        };
+        let long_name = b"aGVsbG8gd29ybGQgdGhpcyBpcyBhIHR1bm5lbA.x1.t.example.com";
+
+        let mut stats = DNSQueryStats::default();
+        assert_eq!(stats.avg_name_len(), 0);
+        stats.update(&request(long_name, DNS_RECORD_TYPE_TXT));
+        stats.update(&request(long_name, DNS_RECORD_TYPE_NULL));
+        stats.update(&request(long_name, DNS_RECORD_TYPE_CNAME));
+        assert_eq!(stats.txt, 1);
+        assert_eq!(stats.null, 1);
+        assert_eq!(stats.cname, 1);
+        assert_eq!(stats.avg_name_len(), long_name.len() as u64);
+        // not enough queries yet
+        assert!(!stats.is_tunnel(&config));
+        stats.update(&request(long_name, DNS_RECORD_TYPE_TXT));
+        assert!(stats.is_tunnel(&config));
+
+        let disabled = DNSTunnelConfig {
+            enabled: false,
+            ..config
+        };
+        assert!(!stats.is_tunnel(&disabled));
+
+        // short names
+        let mut stats = DNSQueryStats::default();
+        for _ in 0..4 {
+            stats.update(&request(b"www.example.com", DNS_RECORD_TYPE_TXT));
+        }
+        assert!(!stats.is_tunnel(&config));
+
+        // mostly regular queries
+        let mut stats = DNSQueryStats::default();
+        stats.update(&request(long_name, DNS_RECORD_TYPE_TXT));
+        for _ in 0..3 {
+            stats.update(&request(long_name, 1));
+        }
+        assert!(!stats.is_tunnel(&config));
+    }
+}
@@ -0,0 +1,124 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Correlation of the TCP retries of truncated UDP responses.
+//!
+//! A client getting a UDP response with the TC flag set retries the query
+//! over TCP, to the same server and port. The truncated response registers
+//! an expectation of this TCP flow with the UDP flow id and the question,
+//! so that the transactions of the TCP flow asking the same question are
+//! tagged as a retry of the UDP query.
+
+use crate::core::{Flow, ALPROTO_UNKNOWN, IPPROTO_TCP, STREAM_TOSERVER};
+use crate::dns::dns::{DNSMessage, ALPROTO_DNS};
+use crate::expectation::{expectation_create, expectation_get_data, expectation_register_proto};
+
+/// TC flag of the header, the message was truncated.
+pub const DNS_FLAG_TRUNCATION: u16 = 0x0200;
+
+/// UDP query of which a TCP flow is the retry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DNSRetry {
+    /// Flow id of the UDP flow.
+    pub flow_id: i64,
+    /// Message ID of the truncated response.
+    pub id: u16,
+    name: Vec<u8>,
+    rrtype: u16,
+}
+
+impl DNSRetry {
+    pub(super) fn new(flow_id: i64, response: &DNSMessage) -> Option<Self> {
+        let query = response.queries.first()?;
+        Some(Self {
+            flow_id,
+            id: response.header.tx_id,
+            name: query.name.clone(),
+            rrtype: query.rrtype,
+        })
+    }
+
+    /// Check if a message asks the question of the truncated response.
+    pub fn matches(&self, message: &DNSMessage) -> bool {
+        message.queries.first().map_or(false, |query| {
+            query.rrtype == self.rrtype && query.name.eq_ignore_ascii_case(&self.name)
+        })
+    }
+}
+
+/// Expect the TCP retry of a truncated response of the UDP `flow`, from
+/// the client to the server port of the flow.
+pub(super) fn dns_expect_tcp_retry(flow: *const Flow, response: &DNSMessage) {
+    let alproto = unsafe { ALPROTO_DNS };
+    if alproto == ALPROTO_UNKNOWN || flow.is_null() {
+        return;
+    }
+    let flow = unsafe { &*flow };
+    let retry = match DNSRetry::new(flow.get_id(), response) {
+        Some(retry) => retry,
+        None => return,
+    };
+    let (_, dp) = flow.get_ports();
+    if !expectation_create(flow, STREAM_TOSERVER, 0, dp, alproto, retry) {
+        SCLogDebug!("no expectation created for the TCP retry to port {}", dp);
+    }
+}
+
+/// Get the UDP query of which the TCP `flow` is the retry, if any.
+pub(super) fn dns_get_tcp_retry(flow: *const Flow) -> Option<DNSRetry> {
+    if flow.is_null() {
+        return None;
+    }
+    unsafe { expectation_get_data::<DNSRetry>(flow) }.cloned()
+}
+
+pub(super) fn register_tcp_retry_expectation() {
+    expectation_register_proto(IPPROTO_TCP, unsafe { ALPROTO_DNS });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dns::dns::dns_parse_response;
+
+    #[test]
+    fn test_dns_retry_matches() {
+        // Truncated response to a TXT query for example.com.
+        #[rustfmt::skip]
+        let buf: &[u8] = &[
+            0x12, 0x34, 0x83, 0x80, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x07, 0x65, 0x78, 0x61,
+            0x6d, 0x70, 0x6c, 0x65, 0x03, 0x63, 0x6f, 0x6d,
+            0x00, 0x00, 0x10, 0x00, 0x01,
+        ];
+        let tx = dns_parse_response(buf).unwrap();
+        let mut response = tx.response.unwrap();
+        assert_ne!(response.header.flags & DNS_FLAG_TRUNCATION, 0);
+        let retry = DNSRetry::new(7, &response).unwrap();
+        assert_eq!(retry.flow_id, 7);
+        assert_eq!(retry.id, 0x1234);
+        assert!(retry.matches(&response));
+
+        response.queries[0].name = b"EXAMPLE.com".to_vec();
+        assert!(retry.matches(&response));
+        response.queries[0].rrtype = 1;
+        assert!(!retry.matches(&response));
+        response.queries.clear();
+        assert!(!retry.matches(&response));
+        assert!(DNSRetry::new(7, &response).is_none());
+    }
+}
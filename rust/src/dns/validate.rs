@@ -0,0 +1,266 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Validation of responses against the requests of the flow.
+//!
+//! Cache poisoning attempts race the real server with forged responses,
+//! guessing the message ID of an outstanding request, and may slip in
+//! records for names that were not asked for. A response is expected to
+//! answer an unanswered request of the flow with the same ID and question,
+//! and its answer records to be owned by the question name or by a name
+//! of the CNAME chain starting at it.
+
+use crate::conf::conf_get;
+use crate::dns::dns::{
+    DNSAnswerEntry, DNSEvent, DNSMessage, DNSQueryEntry, DNSRData, DNS_RECORD_TYPE_AXFR,
+    DNS_RECORD_TYPE_CNAME, DNS_RECORD_TYPE_DNAME, DNS_RECORD_TYPE_IXFR,
+};
+use std::collections::VecDeque;
+
+/// Max number of unanswered requests tracked per flow.
+const DNS_PENDING_REQUESTS_MAX: usize = 500;
+
+/// Max length of a CNAME chain, 0 to not check the length.
+static mut DNS_MAX_CNAME_CHAIN: usize = 10;
+
+/// Unanswered requests of a flow, as message ID and question name.
+#[derive(Debug, Default)]
+pub(super) struct DNSPendingRequests {
+    queue: VecDeque<(u16, Vec<u8>)>,
+}
+
+impl DNSPendingRequests {
+    pub(super) fn add(&mut self, id: u16, queries: &[DNSQueryEntry]) {
+        // If at size limit, forget the oldest request.
+        if self.queue.len() >= DNS_PENDING_REQUESTS_MAX {
+            self.queue.pop_front();
+        }
+        let name = queries.first().map(|q| q.name.clone()).unwrap_or_default();
+        self.queue.push_back((id, name));
+    }
+
+    /// Remove the request a response answers.
+    ///
+    /// Returns false if no request has the ID and question name of the
+    /// response. Responses without question, like some error responses,
+    /// only need to match the ID.
+    pub(super) fn answer(&mut self, id: u16, queries: &[DNSQueryEntry]) -> bool {
+        let name = queries.first().map(|q| q.name.as_slice());
+        let idx = self.queue.iter().position(|(req_id, req_name)| {
+            *req_id == id && name.map_or(true, |name| name.eq_ignore_ascii_case(req_name))
+        });
+        if let Some(idx) = idx {
+            self.queue.remove(idx);
+            return true;
+        }
+        false
+    }
+}
+
+/// Get the target of the CNAME record owned by `name`.
+fn dns_cname_target<'a>(answers: &'a [DNSAnswerEntry], name: &[u8]) -> Option<&'a [u8]> {
+    answers.iter().find_map(|answer| match &answer.data {
+        DNSRData::CNAME(target)
+            if answer.rrtype == DNS_RECORD_TYPE_CNAME && answer.name.eq_ignore_ascii_case(name) =>
+        {
+            Some(target.as_slice())
+        }
+        _ => None,
+    })
+}
+
+/// Check the answer section of a response against its question.
+///
+/// Answers are only checked for responses to a single question. Zone
+/// transfers are not checked, and neither are DNAME records, which are
+/// owned by a parent of the names they redirect.
+fn dns_validate_answers(message: &DNSMessage, max_cname_chain: usize) -> Vec<DNSEvent> {
+    let mut events = Vec::new();
+    let query = match message.queries.as_slice() {
+        [query] => query,
+        _ => return events,
+    };
+    if query.rrtype == DNS_RECORD_TYPE_AXFR || query.rrtype == DNS_RECORD_TYPE_IXFR {
+        return events;
+    }
+
+    // Follow the CNAME chain from the question name, the records may be
+    // in any order. Every link adds a new name, so a loop ends the chain.
+    let mut names: Vec<&[u8]> = vec![&query.name];
+    let mut name: &[u8] = &query.name;
+    while let Some(target) = dns_cname_target(&message.answers, name) {
+        if names.iter().any(|n| n.eq_ignore_ascii_case(target)) {
+            break;
+        }
+        names.push(target);
+        name = target;
+        if max_cname_chain > 0 && names.len() - 1 > max_cname_chain {
+            // The rest of the chain is not followed, so the owners of
+            // the remaining answers are unknown.
+            events.push(DNSEvent::CnameChainTooLong);
+            return events;
+        }
+    }
+
+    let unsolicited = message.answers.iter().any(|answer| {
+        answer.rrtype != DNS_RECORD_TYPE_DNAME
+            && !names
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&answer.name))
+    });
+    if unsolicited {
+        events.push(DNSEvent::UnsolicitedAnswer);
+    }
+    events
+}
+
+/// Check the answer section of a response against its question, using
+/// the configured max CNAME chain length.
+pub(super) fn dns_validate_response(message: &DNSMessage) -> Vec<DNSEvent> {
+    dns_validate_answers(message, unsafe { DNS_MAX_CNAME_CHAIN })
+}
+
+pub(super) unsafe fn register_response_validation() {
+    if let Some(val) = conf_get("app-layer.protocols.dns.max-cname-chain") {
+        if let Ok(v) = val.parse::<usize>() {
+            DNS_MAX_CNAME_CHAIN = v;
+        } else {
+            SCLogError!("Invalid value for dns.max-cname-chain");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dns::dns::DNSHeader;
+
+    fn query(name: &[u8], rrtype: u16) -> Vec<DNSQueryEntry> {
+        vec![DNSQueryEntry {
+            name: name.to_vec(),
+            rrtype,
+            rrclass: 1,
+        }]
+    }
+
+    fn answer(name: &[u8], data: DNSRData) -> DNSAnswerEntry {
+        let rrtype = match data {
+            DNSRData::CNAME(_) => DNS_RECORD_TYPE_CNAME,
+            _ => 1,
+        };
+        DNSAnswerEntry {
+            name: name.to_vec(),
+            rrtype,
+            rrclass: 1,
+            ttl: 300,
+            data,
+        }
+    }
+
+    fn response(name: &[u8], answers: Vec<DNSAnswerEntry>) -> DNSMessage {
+        DNSMessage {
+            header: DNSHeader {
+                tx_id: 1,
+                flags: 0x8180,
+                questions: 1,
+                answer_rr: answers.len() as u16,
+                authority_rr: 0,
+                additional_rr: 0,
+            },
+            queries: query(name, 1),
+            answers,
+            authorities: Vec::new(),
+            additionals: Vec::new(),
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_dns_pending_requests() {
+        let mut pending = DNSPendingRequests::default();
+        pending.add(1, &query(b"www.example.com", 1));
+        pending.add(2, &query(b"mail.example.com", 1));
+        // retransmission of the same request
+        pending.add(2, &query(b"mail.example.com", 1));
+
+        assert!(!pending.answer(3, &query(b"www.example.com", 1)));
+        assert!(!pending.answer(1, &query(b"mail.example.com", 1)));
+        assert!(pending.answer(1, &query(b"WWW.example.com", 1)));
+        assert!(!pending.answer(1, &query(b"www.example.com", 1)));
+        assert!(pending.answer(2, &[]));
+        assert!(pending.answer(2, &query(b"mail.example.com", 1)));
+        assert!(!pending.answer(2, &query(b"mail.example.com", 1)));
+
+        for id in 0..=DNS_PENDING_REQUESTS_MAX as u16 {
+            pending.add(id, &query(b"www.example.com", 1));
+        }
+        assert_eq!(pending.queue.len(), DNS_PENDING_REQUESTS_MAX);
+        assert!(!pending.answer(0, &query(b"www.example.com", 1)));
+    }
+
+    #[test]
+    fn test_dns_validate_answers() {
+        let a = || DNSRData::A(vec![192, 0, 2, 1]);
+        let cname = |name: &[u8]| DNSRData::CNAME(name.to_vec());
+
+        let message = response(b"www.example.com", vec![answer(b"www.example.com", a())]);
+        assert!(dns_validate_answers(&message, 10).is_empty());
+
+        // chain in any order, with names in another case
+        let message = response(
+            b"www.example.com",
+            vec![
+                answer(b"cdn.example.net", a()),
+                answer(b"WWW.example.com", cname(b"edge.example.org")),
+                answer(b"edge.example.org", cname(b"cdn.example.net")),
+            ],
+        );
+        assert!(dns_validate_answers(&message, 10).is_empty());
+        assert_eq!(
+            dns_validate_answers(&message, 1),
+            vec![DNSEvent::CnameChainTooLong]
+        );
+        assert!(dns_validate_answers(&message, 0).is_empty());
+
+        let message = response(
+            b"www.example.com",
+            vec![
+                answer(b"www.example.com", a()),
+                answer(b"ns.bank.example", a()),
+            ],
+        );
+        assert_eq!(
+            dns_validate_answers(&message, 10),
+            vec![DNSEvent::UnsolicitedAnswer]
+        );
+
+        // CNAME loop
+        let message = response(
+            b"a.example.com",
+            vec![
+                answer(b"a.example.com", cname(b"b.example.com")),
+                answer(b"b.example.com", cname(b"a.example.com")),
+            ],
+        );
+        assert!(dns_validate_answers(&message, 10).is_empty());
+
+        // zone transfers are not checked
+        let mut message = response(b"example.com", vec![answer(b"www.example.com", a())]);
+        message.queries = query(b"example.com", DNS_RECORD_TYPE_AXFR);
+        assert!(dns_validate_answers(&message, 10).is_empty());
+    }
+}
@@ -629,7 +629,7 @@ pub unsafe extern "C" fn SCEnipRegisterParsers() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_ENIP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust enip parser registered for UDP.");
         AppLayerParserRegisterParserAcceptableDataDirection(
@@ -654,7 +654,7 @@ pub unsafe extern "C" fn SCEnipRegisterParsers() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_ENIP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust enip parser registered for TCP.");
         AppLayerParserRegisterParserAcceptableDataDirection(
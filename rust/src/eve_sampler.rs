@@ -0,0 +1,169 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Sampling of the transaction records of an EVE event type.
+//!
+//! A sampler is configured with the `sampling` section of an event type,
+//! and lets the transaction logger log only 1 in `rate` transactions, and
+//! only the first `max-per-flow` transactions of a flow. The transactions
+//! that are not logged are counted in the `eve.<type>.suppressed` per
+//! thread counter.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::conf::ConfNode;
+
+#[derive(Debug)]
+pub struct EveSampler {
+    /// Log 1 in `rate` transactions, 1 to log all of them.
+    rate: u64,
+    /// Log the first `max_per_flow` transactions of a flow, 0 for no limit.
+    max_per_flow: u64,
+    /// Transactions seen within the per flow limit, over all threads.
+    seen: AtomicU64,
+    /// Name of the counter of the suppressed records.
+    counter: CString,
+}
+
+impl EveSampler {
+    pub fn new(rate: u64, max_per_flow: u64, event_type: &str) -> Self {
+        Self {
+            rate: rate.max(1),
+            max_per_flow,
+            seen: AtomicU64::new(0),
+            counter: CString::new(format!("eve.{}.suppressed", event_type)).unwrap_or_default(),
+        }
+    }
+
+    /// Return true if the transaction `tx_id` of a flow is to be logged.
+    /// The transaction ids of a flow start at 0, so a transaction is past
+    /// the per flow limit if its id is.
+    pub fn sample(&self, tx_id: u64) -> bool {
+        if self.max_per_flow > 0 && tx_id >= self.max_per_flow {
+            return false;
+        }
+        if self.rate > 1 {
+            return self.seen.fetch_add(1, Ordering::Relaxed) % self.rate == 0;
+        }
+        true
+    }
+}
+
+fn parse_limit(value: Option<&str>, name: &str) -> Result<u64, String> {
+    match value {
+        None => Ok(0),
+        Some(v) => match v.parse::<u64>() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(format!("invalid {} value \"{}\"", name, v)),
+        },
+    }
+}
+
+/// Parse the `rate` and `max-per-flow` settings of a `sampling` section.
+fn parse_sampling(rate: Option<&str>, max_per_flow: Option<&str>) -> Result<(u64, u64), String> {
+    let rate = parse_limit(rate, "rate")?;
+    let max_per_flow = parse_limit(max_per_flow, "max-per-flow")?;
+    if rate == 0 && max_per_flow == 0 {
+        return Err("neither rate nor max-per-flow is set".to_string());
+    }
+    Ok((rate, max_per_flow))
+}
+
+/// Create a sampler from the `sampling` configuration node of the EVE
+/// event type `event_type`. Returns NULL if the configuration is invalid.
+#[no_mangle]
+pub unsafe extern "C" fn SCEveSamplerNew(
+    conf: *const c_void, event_type: *const c_char,
+) -> *mut EveSampler {
+    if conf.is_null() || event_type.is_null() {
+        return std::ptr::null_mut();
+    }
+    let event_type = CStr::from_ptr(event_type).to_string_lossy();
+    let conf = ConfNode::wrap(conf);
+    match parse_sampling(
+        conf.get_child_value("rate"),
+        conf.get_child_value("max-per-flow"),
+    ) {
+        Ok((rate, max_per_flow)) => {
+            SCLogConfig!(
+                "eve-log type {}: sampling rate {}, max-per-flow {}",
+                event_type,
+                rate,
+                max_per_flow
+            );
+            Box::into_raw(Box::new(EveSampler::new(rate, max_per_flow, &event_type)))
+        }
+        Err(err) => {
+            SCLogError!("eve-log type {} sampling: {}", event_type, err);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCEveSamplerFree(sampler: *mut EveSampler) {
+    if !sampler.is_null() {
+        std::mem::drop(Box::from_raw(sampler));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn SCEveSamplerSample(sampler: &EveSampler, tx_id: u64) -> bool {
+    sampler.sample(tx_id)
+}
+
+/// Name of the per thread counter of the suppressed records. Valid as long
+/// as the sampler is.
+#[no_mangle]
+pub extern "C" fn SCEveSamplerCounterName(sampler: &EveSampler) -> *const c_char {
+    sampler.counter.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sampling() {
+        assert_eq!(parse_sampling(Some("10"), None), Ok((10, 0)));
+        assert_eq!(parse_sampling(None, Some("100")), Ok((0, 100)));
+        assert_eq!(parse_sampling(Some("1"), Some("5")), Ok((1, 5)));
+        assert!(parse_sampling(None, None).is_err());
+        assert!(parse_sampling(Some("0"), None).is_err());
+        assert!(parse_sampling(Some("ten"), None).is_err());
+        assert!(parse_sampling(Some("10"), Some("-1")).is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        let sampler = EveSampler::new(10, 0, "dns");
+        assert_eq!(sampler.counter.to_str(), Ok("eve.dns.suppressed"));
+        let logged = (0..1000).filter(|_| sampler.sample(0)).count();
+        assert_eq!(logged, 100);
+
+        let sampler = EveSampler::new(0, 3, "bittorrent-dht");
+        let logged: Vec<u64> = (0..10).filter(|&tx_id| sampler.sample(tx_id)).collect();
+        assert_eq!(logged, vec![0, 1, 2]);
+
+        // only the transactions within the per flow limit count for the rate
+        let sampler = EveSampler::new(2, 4, "dns");
+        let logged: Vec<u64> = (0..10).filter(|&tx_id| sampler.sample(tx_id)).collect();
+        assert_eq!(logged, vec![0, 2]);
+    }
+}
@@ -0,0 +1,98 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Bindings to the app-layer expectations.
+//!
+//! An expectation announces a flow between the addresses of an existing
+//! flow, like the data connection of an FTP transfer, so that the new flow
+//! gets its app-layer protocol without protocol detection. The data of the
+//! expectation is moved to the expected flow once it is seen, and freed
+//! with it.
+
+use crate::core::{AppProto, Flow};
+use std::os::raw::{c_int, c_void};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct FlowStorageId {
+    id: c_int,
+}
+
+// Defined in app-layer-expectation.h, app-layer-detect-proto.h and
+// flow-storage.h
+/// cbindgen:ignore
+extern "C" {
+    fn AppLayerExpectationCreate(
+        f: *const Flow, direction: c_int, src: u16, dst: u16, alproto: AppProto, data: *mut c_void,
+    ) -> c_int;
+    fn AppLayerExpectationGetFlowId() -> FlowStorageId;
+    fn AppLayerRegisterExpectationProto(proto: u8, alproto: AppProto);
+    fn FlowGetStorageById(f: *const Flow, id: FlowStorageId) -> *mut c_void;
+}
+
+/// Data of an expectation. The C side expects it to start with the
+/// function to free it.
+#[repr(C)]
+struct ExpectationData<T> {
+    free: unsafe extern "C" fn(*mut c_void),
+    data: T,
+}
+
+unsafe extern "C" fn expectation_data_free<T>(data: *mut c_void) {
+    std::mem::drop(Box::from_raw(data as *mut ExpectationData<T>));
+}
+
+/// Let the flows of `ipproto` get `alproto` from expectations.
+pub fn expectation_register_proto(ipproto: u8, alproto: AppProto) {
+    unsafe {
+        AppLayerRegisterExpectationProto(ipproto, alproto);
+    }
+}
+
+/// Expect a flow between the addresses of `flow`, from port `sp` to port
+/// `dp`, 0 matching any port. `direction` is the STREAM_TOSERVER and
+/// STREAM_TOCLIENT flags of the packets that can start it.
+///
+/// The expected flow gets `alproto` and `data`. Returns false if the
+/// expectation could not be created.
+pub fn expectation_create<T>(
+    flow: *const Flow, direction: u8, sp: u16, dp: u16, alproto: AppProto, data: T,
+) -> bool {
+    let data = Box::into_raw(Box::new(ExpectationData {
+        free: expectation_data_free::<T>,
+        data,
+    })) as *mut c_void;
+    unsafe {
+        if AppLayerExpectationCreate(flow, direction as c_int, sp, dp, alproto, data) != 0 {
+            expectation_data_free::<T>(data);
+            return false;
+        }
+    }
+    true
+}
+
+/// Get the data of the expectation that `flow` was expected by.
+///
+/// # Safety
+///
+/// `T` must be the type of the data of the expectations for the app-layer
+/// protocol of the flow.
+pub unsafe fn expectation_get_data<'a, T>(flow: *const Flow) -> Option<&'a T> {
+    let data =
+        FlowGetStorageById(flow, AppLayerExpectationGetFlowId()) as *const ExpectationData<T>;
+    data.as_ref().map(|data| &data.data)
+}
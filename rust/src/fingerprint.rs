@@ -0,0 +1,161 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Per flow collection of the client and server fingerprints computed
+//! by the parsers.
+//!
+//! The fingerprint logger collects the JA3/JA3S/JA4 hashes of TLS, the
+//! hassh of SSH and the RDP fingerprint of a flow at flow end, and logs
+//! them in a single record.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+
+#[derive(Debug)]
+struct Fingerprint {
+    /// Fingerprint type, used as the key in the record.
+    kind: String,
+    hash: Vec<u8>,
+    /// String the hash was computed from, empty if not available.
+    string: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+pub struct FlowFingerprints {
+    fingerprints: Vec<Fingerprint>,
+}
+
+impl FlowFingerprints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a fingerprint, replacing a previous one of the same type.
+    /// Nothing is added if both the hash and the string are empty.
+    pub fn add(&mut self, kind: &str, hash: &[u8], string: &[u8]) {
+        if hash.is_empty() && string.is_empty() {
+            return;
+        }
+        let fingerprint = Fingerprint {
+            kind: kind.to_string(),
+            hash: hash.to_vec(),
+            string: string.to_vec(),
+        };
+        if let Some(entry) = self.fingerprints.iter_mut().find(|f| f.kind == kind) {
+            *entry = fingerprint;
+        } else {
+            self.fingerprints.push(fingerprint);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fingerprints.is_empty()
+    }
+
+    pub fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        js.open_object("fingerprint")?;
+        for fingerprint in &self.fingerprints {
+            js.open_object(&fingerprint.kind)?;
+            if !fingerprint.hash.is_empty() {
+                js.set_string_from_bytes("hash", &fingerprint.hash)?;
+            }
+            if !fingerprint.string.is_empty() {
+                js.set_string_from_bytes("string", &fingerprint.string)?;
+            }
+            js.close()?;
+        }
+        js.close()?;
+        Ok(())
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn SCFingerprintsNew() -> *mut FlowFingerprints {
+    Box::into_raw(Box::new(FlowFingerprints::new()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCFingerprintsFree(fingerprints: *mut FlowFingerprints) {
+    if !fingerprints.is_null() {
+        std::mem::drop(Box::from_raw(fingerprints));
+    }
+}
+
+/// Add a fingerprint computed in C. `hash` and `string` may be NULL.
+#[no_mangle]
+pub unsafe extern "C" fn SCFingerprintsAdd(
+    fingerprints: &mut FlowFingerprints, kind: *const c_char, hash: *const c_char,
+    string: *const c_char,
+) {
+    if kind.is_null() {
+        return;
+    }
+    let kind = CStr::from_ptr(kind).to_string_lossy();
+    let hash: &[u8] = if hash.is_null() {
+        &[]
+    } else {
+        CStr::from_ptr(hash).to_bytes()
+    };
+    let string: &[u8] = if string.is_null() {
+        &[]
+    } else {
+        CStr::from_ptr(string).to_bytes()
+    };
+    fingerprints.add(&kind, hash, string);
+}
+
+#[no_mangle]
+pub extern "C" fn SCFingerprintsIsEmpty(fingerprints: &FlowFingerprints) -> bool {
+    fingerprints.is_empty()
+}
+
+#[no_mangle]
+pub extern "C" fn SCFingerprintsLogJson(
+    fingerprints: &FlowFingerprints, js: &mut JsonBuilder,
+) -> bool {
+    fingerprints.log(js).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonbuilder::{jb_len, jb_ptr};
+
+    #[test]
+    fn test_flow_fingerprints_log() {
+        let mut fingerprints = FlowFingerprints::new();
+        assert!(fingerprints.is_empty());
+        fingerprints.add("ja3", b"", b"");
+        assert!(fingerprints.is_empty());
+
+        fingerprints.add("ja3", b"0123", b"771,4865");
+        fingerprints.add("ja4", b"t13d1516h2_8daaf6152771_b186095e22b6", b"");
+        fingerprints.add("ja3", b"4567", b"771,4866");
+        assert_eq!(fingerprints.fingerprints.len(), 2);
+
+        let mut js = JsonBuilder::try_new_object().unwrap();
+        fingerprints.log(&mut js).unwrap();
+        js.close().unwrap();
+        let buf = unsafe { std::slice::from_raw_parts(jb_ptr(&mut js), jb_len(&js)) };
+        assert_eq!(
+            std::str::from_utf8(buf).unwrap(),
+            r#"{"fingerprint":{"ja3":{"hash":"4567","string":"771,4866"},"ja4":{"hash":"t13d1516h2_8daaf6152771_b186095e22b6"}}}"#
+        );
+    }
+}
@@ -0,0 +1,410 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! FTP data channel.
+//!
+//! There is no protocol detection for ftp-data: the flows only get the
+//! protocol from the expectations registered by the FTP parser on RETR
+//! and STOR commands, with the name of the file, its size if the client
+//! asked for it with SIZE, and the direction it is sent in. A flow is a
+//! single transaction holding the file, opened with the first data in
+//! the direction of the transfer and closed at the end of the stream.
+
+use crate::applayer::*;
+use crate::core::*;
+use crate::expectation::{expectation_create, expectation_get_data, expectation_register_proto};
+use crate::filecontainer::*;
+use std;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+static mut ALPROTO_FTPDATA: AppProto = ALPROTO_UNKNOWN;
+
+static mut SURICATA_FTPDATA_FILE_CONFIG: Option<&'static SuricataFileContext> = None;
+
+/// Data of the expectations of ftp-data flows.
+#[derive(Debug, Clone, Default)]
+pub struct FtpDataExpectation {
+    /// Flow id of the control channel.
+    pub flow_id: i64,
+    /// The FtpRequestCommand of the control channel, RETR or STOR.
+    pub command: u8,
+    /// STREAM_TOSERVER or STREAM_TOCLIENT, the direction of the file.
+    pub direction: u8,
+    pub file_name: Vec<u8>,
+    /// Size of the file replied to a SIZE command, if any.
+    pub file_size: Option<u64>,
+}
+
+#[derive(AppLayerEvent)]
+pub enum FtpDataEvent {
+    /// Data sent in the direction opposite to the file.
+    DataWrongDirection,
+}
+
+#[derive(Debug, Default)]
+pub struct FtpDataTransaction {
+    /// The expectation of the flow, set with the first data.
+    pub expectation: Option<FtpDataExpectation>,
+    pub files: FileContainer,
+    file_open: bool,
+    /// The file is complete, or will never be.
+    pub done: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Drop for FtpDataTransaction {
+    fn drop(&mut self) {
+        if let Some(sfcm) = unsafe { SURICATA_FTPDATA_FILE_CONFIG } {
+            self.files.free(sfcm);
+        }
+    }
+}
+
+impl FtpDataTransaction {
+    fn direction(&self) -> u8 {
+        self.expectation.as_ref().map_or(0, |e| e.direction)
+    }
+
+    /// Progress of the transaction in `direction`, the opposite one having
+    /// nothing to wait for.
+    fn progress(&self, direction: u8) -> c_int {
+        if self.done || direction & self.direction() == 0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct FtpDataState {
+    state_data: AppLayerStateData,
+    tx: FtpDataTransaction,
+}
+
+impl FtpDataState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Handle data of the flow in `direction`, the transfer being described
+    /// by `expectation` if it is not already known. Returns false if the
+    /// file could not be handled.
+    fn parse(
+        &mut self, input: &[u8], direction: u8, eof: bool,
+        expectation: impl FnOnce() -> Option<FtpDataExpectation>,
+    ) -> bool {
+        let tx = &mut self.tx;
+        if tx.done {
+            return true;
+        }
+        if tx.expectation.is_none() {
+            if input.is_empty() {
+                return true;
+            }
+            match expectation() {
+                Some(e) => {
+                    tx.tx_data.file_tx = e.direction;
+                    tx.expectation = Some(e);
+                }
+                None => {
+                    return false;
+                }
+            }
+        }
+        if direction & tx.direction() == 0 {
+            if !input.is_empty() {
+                SCLogDebug!("{} bytes of data in the wrong direction", input.len());
+                tx.tx_data.set_event(FtpDataEvent::DataWrongDirection as u8);
+            }
+            return true;
+        }
+        tx.tx_data.update_file_flags(self.state_data.file_flags);
+        let sfcm = match unsafe { SURICATA_FTPDATA_FILE_CONFIG } {
+            Some(sfcm) => sfcm,
+            None => {
+                return false;
+            }
+        };
+        let flags = unsafe { FileFlowFlagsToFlags(tx.tx_data.file_flags, direction) };
+        if !tx.file_open && !input.is_empty() {
+            // one file per flow, with the fixed track id 0
+            let name = tx
+                .expectation
+                .as_ref()
+                .map(|e| e.file_name.clone())
+                .unwrap_or_default();
+            if tx.files.file_open(sfcm, 0, &name, flags) != 0 {
+                SCLogDebug!("could not open the file");
+                tx.done = true;
+                return false;
+            }
+            tx.file_open = true;
+            tx.tx_data.init_files_opened();
+        }
+        let mut ok = true;
+        if !input.is_empty() {
+            let r = tx.files.file_append(sfcm, &0, input, false);
+            // -2 is a file not extracted anymore
+            if r < 0 && r != -2 {
+                SCLogDebug!("could not append the data: {}", r);
+                ok = false;
+            }
+        }
+        if eof {
+            if tx.file_open && tx.files.file_close(sfcm, &0, flags) < 0 {
+                ok = false;
+            }
+            tx.done = true;
+        }
+        ok
+    }
+}
+
+/// Expect the data connection of a transfer of the FTP control channel
+/// `flow`, to the port `dp`. `command` is the FtpRequestCommand, RETR or
+/// STOR, and `direction` the one of the file on the data connection.
+/// `file_size` is the size replied to a SIZE command for the file, if
+/// `has_file_size` is set.
+///
+/// # Safety
+///
+/// `file_name` must point to `file_name_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn SCFtpDataExpectationCreate(
+    flow: *const Flow, direction: u8, dp: u16, command: u8, file_name: *const u8,
+    file_name_len: u32, has_file_size: bool, file_size: u64,
+) -> bool {
+    let alproto = ALPROTO_FTPDATA;
+    if alproto == ALPROTO_UNKNOWN || flow.is_null() {
+        return false;
+    }
+    let data = FtpDataExpectation {
+        flow_id: (*flow).get_id(),
+        command,
+        direction,
+        file_name: build_slice!(file_name, file_name_len as usize).to_vec(),
+        file_size: if has_file_size { Some(file_size) } else { None },
+    };
+    expectation_create(flow, direction, 0, dp, alproto, data)
+}
+
+/// Get the FtpRequestCommand of the control channel, or 0 if the transfer
+/// did not start.
+#[no_mangle]
+pub unsafe extern "C" fn SCFtpDataTxGetCommand(tx: *const c_void) -> u8 {
+    let tx = cast_pointer!(tx, FtpDataTransaction);
+    tx.expectation.as_ref().map_or(0, |e| e.command)
+}
+
+/// Get the name of the file, if the transfer started.
+#[no_mangle]
+pub unsafe extern "C" fn SCFtpDataTxGetFileName(
+    tx: *const c_void, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, FtpDataTransaction);
+    if let Some(e) = &tx.expectation {
+        *buffer = e.file_name.as_ptr();
+        *buffer_len = e.file_name.len() as u32;
+        return true;
+    }
+    false
+}
+
+/// Get the size of the file replied to a SIZE command, if any.
+#[no_mangle]
+pub unsafe extern "C" fn SCFtpDataTxGetFileSize(tx: *const c_void, size: *mut u64) -> bool {
+    let tx = cast_pointer!(tx, FtpDataTransaction);
+    if let Some(file_size) = tx.expectation.as_ref().and_then(|e| e.file_size) {
+        *size = file_size;
+        return true;
+    }
+    false
+}
+
+unsafe fn ftpdata_parse(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    direction: u8,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, FtpDataState);
+    let eof_flag = if direction == STREAM_TOSERVER {
+        APP_LAYER_PARSER_EOF_TS
+    } else {
+        APP_LAYER_PARSER_EOF_TC
+    };
+    let eof = AppLayerParserStateIssetFlag(pstate, eof_flag) > 0;
+    let expectation = || {
+        let e = expectation_get_data::<FtpDataExpectation>(flow)?;
+        (*flow).set_parent_id(e.flow_id);
+        Some(e.clone())
+    };
+    if state.parse(stream_slice.as_slice(), direction, eof, expectation) {
+        AppLayerResult::ok()
+    } else {
+        AppLayerResult::err()
+    }
+}
+
+// C exports.
+
+extern "C" fn ftpdata_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = FtpDataState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn ftpdata_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut FtpDataState));
+}
+
+extern "C" fn ftpdata_state_tx_free(_state: *mut c_void, _tx_id: u64) {
+    // the single transaction is freed with the state
+}
+
+unsafe extern "C" fn ftpdata_parse_ts(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    ftpdata_parse(flow, state, pstate, stream_slice, STREAM_TOSERVER)
+}
+
+unsafe extern "C" fn ftpdata_parse_tc(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    ftpdata_parse(flow, state, pstate, stream_slice, STREAM_TOCLIENT)
+}
+
+unsafe extern "C" fn ftpdata_state_get_tx(state: *mut c_void, _tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, FtpDataState);
+    return &mut state.tx as *mut _ as *mut c_void;
+}
+
+extern "C" fn ftpdata_state_get_tx_count(_state: *mut c_void) -> u64 {
+    // ftp-data is single tx
+    return 1;
+}
+
+unsafe extern "C" fn ftpdata_tx_get_alstate_progress(tx: *mut c_void, direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, FtpDataTransaction);
+    tx.progress(direction)
+}
+
+unsafe extern "C" fn ftpdata_get_tx_files(tx: *mut c_void, direction: u8) -> AppLayerGetFileState {
+    let tx = cast_pointer!(tx, FtpDataTransaction);
+    if let Some(sfcm) = SURICATA_FTPDATA_FILE_CONFIG {
+        if direction & tx.direction() != 0 {
+            return AppLayerGetFileState {
+                fc: &mut tx.files,
+                cfg: sfcm.files_sbcfg,
+            };
+        }
+    }
+    AppLayerGetFileState::err()
+}
+
+export_tx_data_get!(ftpdata_get_tx_data, FtpDataTransaction);
+export_state_data_get!(ftpdata_get_state_data, FtpDataState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"ftp-data\0";
+
+/// Register the ftp-data parser, with the file configuration of the FTP
+/// parser.
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterFtpDataParser(context: &'static SuricataFileContext) {
+    SURICATA_FTPDATA_FILE_CONFIG = Some(context);
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        // only set by the expectations of the FTP parser
+        default_port: std::ptr::null(),
+        ipproto: IPPROTO_TCP,
+        probe_ts: None,
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 0,
+        state_new: ftpdata_state_new,
+        state_free: ftpdata_state_free,
+        tx_free: ftpdata_state_tx_free,
+        parse_ts: ftpdata_parse_ts,
+        parse_tc: ftpdata_parse_tc,
+        get_tx_count: ftpdata_state_get_tx_count,
+        get_tx: ftpdata_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: ftpdata_tx_get_alstate_progress,
+        get_eventinfo: Some(FtpDataEvent::get_event_info),
+        get_eventinfo_byid: Some(FtpDataEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: Some(ftpdata_get_tx_files),
+        get_tx_iterator: None,
+        get_tx_data: ftpdata_get_tx_data,
+        get_state_data: ftpdata_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: None,
+        get_frame_name_by_id: None,
+    };
+
+    let ip_proto_str = CString::new("tcp").unwrap();
+    let ftp_name = CString::new("ftp").unwrap();
+    // enabled along with the ftp parser
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), ftp_name.as_ptr()) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_FTPDATA = alproto;
+        expectation_register_proto(IPPROTO_TCP, alproto);
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), ftp_name.as_ptr()) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_TCP, alproto);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for ftp-data.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn retr_expectation() -> FtpDataExpectation {
+        FtpDataExpectation {
+            flow_id: 1,
+            command: 1,
+            direction: STREAM_TOCLIENT,
+            file_name: b"file.txt".to_vec(),
+            file_size: Some(4),
+        }
+    }
+
+    #[test]
+    fn test_ftpdata_wrong_direction() {
+        let mut state = FtpDataState::new();
+        // nothing to do until data is seen
+        assert!(state.parse(&[], STREAM_TOSERVER, false, || None));
+        assert!(state.tx.expectation.is_none());
+        // data without an expectation
+        assert!(!state.parse(b"data", STREAM_TOCLIENT, false, || None));
+
+        assert!(state.parse(b"data", STREAM_TOSERVER, false, || Some(retr_expectation())));
+        assert_eq!(state.tx.tx_data.file_tx, STREAM_TOCLIENT);
+        assert!(!state.tx.file_open);
+        assert_eq!(state.tx.progress(STREAM_TOSERVER), 1);
+        assert_eq!(state.tx.progress(STREAM_TOCLIENT), 0);
+    }
+}
@@ -26,6 +26,7 @@ use std;
 use std::str;
 use std::str::FromStr;
 
+pub mod data;
 pub mod event;
 
 // We transform an integer string into a i64, ignoring surrounding whitespaces
@@ -178,6 +179,36 @@ pub unsafe extern "C" fn rs_ftp_epsv_response(input: *const u8, len: u32) -> u16
     return 0;
 }
 
+// 213 1234
+pub fn ftp_size_response(i: &[u8]) -> IResult<&[u8], u64> {
+    let (i, _) = tag("213 ")(i)?;
+    let (i, size) = map_res(map_res(digit1, str::from_utf8), u64::from_str)(i)?;
+    let (i, _) = multispace0(i)?;
+    Ok((i, size))
+}
+
+/// Get the file size of the reply to a SIZE command.
+///
+/// # Safety
+///
+/// `input` must point to `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn SCFtpParseSizeResponse(
+    input: *const u8, len: u32, size: *mut u64,
+) -> bool {
+    let buf = build_slice!(input, len as usize);
+    match ftp_size_response(buf) {
+        Ok((_, file_size)) => {
+            *size = file_size;
+            return true;
+        }
+        Err(_) => {
+            SCLogDebug!("size error on '{:?}'", String::from_utf8_lossy(buf));
+        }
+    }
+    return false;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -206,6 +237,14 @@ mod test {
         assert_eq!(port, Ok((&b""[..], 41813)));
     }
 
+    #[test]
+    fn test_size_response() {
+        let size = ftp_size_response("213 1048576".as_bytes());
+        assert_eq!(size, Ok((&b""[..], 1048576)));
+        assert!(ftp_size_response("550 No such file".as_bytes()).is_err());
+        assert!(ftp_size_response("213 99999999999999999999".as_bytes()).is_err());
+    }
+
     #[test]
     fn test_active_port_valid() {
         let port = ftp_active_port("PORT 192,168,0,13,234,10".as_bytes());
@@ -0,0 +1,191 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! ERSPAN Type II and III header decoding.
+//!
+//! Type I has no header and is decoded as Ethernet directly by the caller.
+
+use nom7::number::complete::{be_u16, be_u32};
+use nom7::IResult;
+
+/// Version field of an ERSPAN Type II header.
+pub const ERSPAN_VERSION_II: u8 = 1;
+/// Version field of an ERSPAN Type III header.
+pub const ERSPAN_VERSION_III: u8 = 2;
+
+const ERSPAN_II_HDR_LEN: usize = 8;
+const ERSPAN_III_HDR_LEN: usize = 12;
+const ERSPAN_III_PLATFORM_HDR_LEN: usize = 8;
+
+const ERSPAN_III_FRAME_TYPE_ETHERNET: u8 = 0;
+
+/// Decoder events of the ERSPAN header, mapped to the C decoder events.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErspanEvent {
+    ErspanEventNone = 0,
+    ErspanEventHeaderTooSmall,
+    ErspanEventUnsupportedVersion,
+    ErspanEventUnsupportedFrameType,
+}
+
+/// The fields of an ERSPAN Type II or III header.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ErspanHeader {
+    pub version: u8,
+    pub vlan: u16,
+    pub session_id: u16,
+    /// Length of the ERSPAN headers, the offset of the mirrored frame.
+    pub header_len: u32,
+}
+
+/// Version, vlan and session id, common to Type II and III.
+fn parse_erspan_common(i: &[u8]) -> IResult<&[u8], (u8, u16, u16)> {
+    let (i, ver_vlan) = be_u16(i)?;
+    let (i, flags_session) = be_u16(i)?;
+    Ok((
+        i,
+        (
+            (ver_vlan >> 12) as u8,
+            ver_vlan & 0x0fff,
+            flags_session & 0x03ff,
+        ),
+    ))
+}
+
+/// Frame type and presence of the optional platform specific subheader
+/// of a Type III header.
+fn parse_erspan_iii(i: &[u8]) -> IResult<&[u8], (u8, bool)> {
+    let (i, _timestamp) = be_u32(i)?;
+    let (i, _sgt) = be_u16(i)?;
+    let (i, flags) = be_u16(i)?;
+    Ok((i, (((flags >> 10) & 0x1f) as u8, flags & 0x01 != 0)))
+}
+
+pub fn decode_erspan(input: &[u8]) -> Result<ErspanHeader, ErspanEvent> {
+    if input.len() < ERSPAN_II_HDR_LEN {
+        return Err(ErspanEvent::ErspanEventHeaderTooSmall);
+    }
+    let (rem, (version, vlan, session_id)) =
+        parse_erspan_common(input).map_err(|_| ErspanEvent::ErspanEventHeaderTooSmall)?;
+    let header_len = match version {
+        ERSPAN_VERSION_II => ERSPAN_II_HDR_LEN,
+        ERSPAN_VERSION_III => {
+            let (_, (frame_type, platform)) =
+                parse_erspan_iii(rem).map_err(|_| ErspanEvent::ErspanEventHeaderTooSmall)?;
+            if frame_type != ERSPAN_III_FRAME_TYPE_ETHERNET {
+                return Err(ErspanEvent::ErspanEventUnsupportedFrameType);
+            }
+            if platform {
+                ERSPAN_III_HDR_LEN + ERSPAN_III_PLATFORM_HDR_LEN
+            } else {
+                ERSPAN_III_HDR_LEN
+            }
+        }
+        _ => {
+            return Err(ErspanEvent::ErspanEventUnsupportedVersion);
+        }
+    };
+    if input.len() < header_len {
+        return Err(ErspanEvent::ErspanEventHeaderTooSmall);
+    }
+    Ok(ErspanHeader {
+        version,
+        vlan,
+        session_id,
+        header_len: header_len as u32,
+    })
+}
+
+/// Decode the ERSPAN header at `input` into `hdr`.
+///
+/// Returns ErspanEventNone if the mirrored Ethernet frame follows the
+/// header, the event to set on the packet otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn SCErspanDecode(
+    input: *const u8, len: u32, hdr: &mut ErspanHeader,
+) -> ErspanEvent {
+    let input = build_slice!(input, len as usize);
+    match decode_erspan(input) {
+        Ok(h) => {
+            *hdr = h;
+            ErspanEvent::ErspanEventNone
+        }
+        Err(e) => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erspan_ii() {
+        let buf: &[u8] = &[
+            0x10, 0x64, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, // header
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, // ethernet
+        ];
+        let hdr = decode_erspan(buf).unwrap();
+        assert_eq!(hdr.version, ERSPAN_VERSION_II);
+        assert_eq!(hdr.vlan, 100);
+        assert_eq!(hdr.session_id, 42);
+        assert_eq!(hdr.header_len, 8);
+
+        assert_eq!(
+            decode_erspan(&buf[..7]),
+            Err(ErspanEvent::ErspanEventHeaderTooSmall)
+        );
+        let buf: &[u8] = &[0x30, 0x64, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(
+            decode_erspan(buf),
+            Err(ErspanEvent::ErspanEventUnsupportedVersion)
+        );
+    }
+
+    #[test]
+    fn test_erspan_iii() {
+        let buf: &[u8] = &[
+            0x20, 0x00, 0x03, 0xff, // version 2, session 1023
+            0x00, 0x00, 0x00, 0x01, // timestamp
+            0x00, 0x00, 0x00, 0x00, // sgt, ethernet frame
+        ];
+        let hdr = decode_erspan(buf).unwrap();
+        assert_eq!(hdr.version, ERSPAN_VERSION_III);
+        assert_eq!(hdr.vlan, 0);
+        assert_eq!(hdr.session_id, 1023);
+        assert_eq!(hdr.header_len, 12);
+
+        // platform specific subheader announced but missing
+        let buf: &[u8] = &[
+            0x20, 0x00, 0x03, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        ];
+        assert_eq!(
+            decode_erspan(buf),
+            Err(ErspanEvent::ErspanEventHeaderTooSmall)
+        );
+
+        // ip frame type
+        let buf: &[u8] = &[
+            0x20, 0x00, 0x03, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x08, 0x00,
+        ];
+        assert_eq!(
+            decode_erspan(buf),
+            Err(ErspanEvent::ErspanEventUnsupportedFrameType)
+        );
+    }
+}
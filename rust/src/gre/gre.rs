@@ -0,0 +1,426 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! GRE header decoding, version 0 from RFC 1701 and version 1, the
+//! enhanced GRE header of PPTP, from RFC 2637.
+//!
+//! The header is validated and the protocol of the payload is returned,
+//! the tunneled packet itself is set up and decoded by the C decoder.
+
+use super::erspan::decode_erspan;
+use nom7::number::complete::{be_u16, be_u8};
+use nom7::IResult;
+
+const GRE_VERSION_0: u8 = 0;
+const GRE_VERSION_1: u8 = 1;
+
+const GRE_HDR_LEN: usize = 4;
+const GRE_CHKSUM_LEN: usize = 2;
+const GRE_OFFSET_LEN: usize = 2;
+const GRE_KEY_LEN: usize = 4;
+const GRE_SEQ_LEN: usize = 4;
+const GRE_SRE_HDR_LEN: usize = 4;
+const GREV1_ACK_LEN: usize = 4;
+
+const GRE_FLAG_CHKSUM: u8 = 0x80;
+const GRE_FLAG_ROUTE: u8 = 0x40;
+const GRE_FLAG_KEY: u8 = 0x20;
+const GRE_FLAG_SEQ: u8 = 0x10;
+const GRE_FLAG_SSR: u8 = 0x08;
+const GRE_FLAG_RECUR: u8 = 0x07;
+const GREV1_FLAG_ACK: u8 = 0x80;
+const GREV1_FLAG_FLAGS: u8 = 0x78;
+
+const GRE_PROTO_IPV4: u16 = 0x0800;
+const GRE_PROTO_ARP: u16 = 0x0806;
+const GRE_PROTO_BRIDGE: u16 = 0x6558;
+const GRE_PROTO_VLAN: u16 = 0x8100;
+const GRE_PROTO_IPV6: u16 = 0x86dd;
+const GRE_PROTO_PPP: u16 = 0x880b;
+const GRE_PROTO_ERSPAN: u16 = 0x88be;
+const GRE_PROTO_ERSPAN_III: u16 = 0x22eb;
+
+/// Decoder events of the GRE header, mapped to the C decoder events.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GreEvent {
+    GreEventNone = 0,
+    GreEventPktTooSmall,
+    GreEventWrongVersion,
+    GreEventVersion0Recur,
+    GreEventVersion0Flags,
+    GreEventVersion0HdrTooBig,
+    GreEventVersion0MalformedSreHdr,
+    GreEventVersion1Chksum,
+    GreEventVersion1Route,
+    GreEventVersion1Ssr,
+    GreEventVersion1Recur,
+    GreEventVersion1Flags,
+    GreEventVersion1WrongProtocol,
+    GreEventVersion1NoKey,
+    GreEventVersion1HdrTooBig,
+}
+
+/// Protocol of the GRE payload, to be decoded as a tunneled packet.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GreTunnelProto {
+    /// Unsupported protocol or no payload: nothing to decode.
+    #[default]
+    GreTunnelNone = 0,
+    GreTunnelIpv4,
+    GreTunnelIpv6,
+    GreTunnelPpp,
+    GreTunnelVlan,
+    GreTunnelEthernet,
+    GreTunnelArp,
+    GreTunnelErspanI,
+    /// ERSPAN Type II or III, the version is in the ERSPAN header.
+    GreTunnelErspan,
+}
+
+/// The fields of a valid GRE header.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GreHeader {
+    pub version: u8,
+    pub proto: u16,
+    /// Length of the GRE header, the offset of the payload.
+    pub header_len: u32,
+    pub key_set: bool,
+    pub key: u32,
+    pub seq_set: bool,
+    pub seq: u32,
+    /// Session id of the ERSPAN Type II or III header of the payload.
+    pub erspan_session_id_set: bool,
+    pub erspan_session_id: u16,
+    pub tunnel: GreTunnelProto,
+}
+
+fn parse_gre_base(i: &[u8]) -> IResult<&[u8], (u8, u8, u16)> {
+    let (i, flags) = be_u8(i)?;
+    let (i, version) = be_u8(i)?;
+    let (i, proto) = be_u16(i)?;
+    Ok((i, (flags, version, proto)))
+}
+
+/// Length of the source route entries starting at `offset`, the last
+/// entry has an address family and a length of 0.
+fn gre_sre_len(input: &[u8], offset: usize) -> Option<usize> {
+    let mut len = 0;
+    loop {
+        let start = offset + len;
+        let sre = input.get(start..start + GRE_SRE_HDR_LEN)?;
+        len += GRE_SRE_HDR_LEN;
+        let af = u16::from_be_bytes([sre[0], sre[1]]);
+        let sre_length = sre[3] as usize;
+        if af == 0 && sre_length == 0 {
+            return Some(len);
+        }
+        len += sre_length;
+        if offset + len > input.len() {
+            return None;
+        }
+    }
+}
+
+fn decode_gre_v0(input: &[u8], flags: u8, version: u8) -> Result<GreHeader, GreEvent> {
+    // GRE version 0 doesn't support the fields below RFC 1701
+    if flags & GRE_FLAG_RECUR != 0 {
+        return Err(GreEvent::GreEventVersion0Recur);
+    }
+    if version & GREV1_FLAG_FLAGS != 0 {
+        return Err(GreEvent::GreEventVersion0Flags);
+    }
+
+    let mut hdr = GreHeader::default();
+    let mut header_len = GRE_HDR_LEN;
+    if flags & (GRE_FLAG_CHKSUM | GRE_FLAG_ROUTE) != 0 {
+        header_len += GRE_CHKSUM_LEN + GRE_OFFSET_LEN;
+    }
+    let key_offset = header_len;
+    if flags & GRE_FLAG_KEY != 0 {
+        header_len += GRE_KEY_LEN;
+    }
+    let seq_offset = header_len;
+    if flags & GRE_FLAG_SEQ != 0 {
+        header_len += GRE_SEQ_LEN;
+    }
+    if header_len > input.len() {
+        return Err(GreEvent::GreEventVersion0HdrTooBig);
+    }
+    if flags & GRE_FLAG_KEY != 0 {
+        hdr.key_set = true;
+        hdr.key = read_u32(input, key_offset);
+    }
+    if flags & GRE_FLAG_SEQ != 0 {
+        hdr.seq_set = true;
+        hdr.seq = read_u32(input, seq_offset);
+    }
+    if flags & GRE_FLAG_ROUTE != 0 {
+        header_len +=
+            gre_sre_len(input, header_len).ok_or(GreEvent::GreEventVersion0MalformedSreHdr)?;
+    }
+    hdr.header_len = header_len as u32;
+    Ok(hdr)
+}
+
+fn decode_gre_v1(input: &[u8], flags: u8, version: u8, proto: u16) -> Result<GreHeader, GreEvent> {
+    // GRE version 1 doesn't support the fields below RFC 1701
+    if flags & GRE_FLAG_CHKSUM != 0 {
+        return Err(GreEvent::GreEventVersion1Chksum);
+    }
+    if flags & GRE_FLAG_ROUTE != 0 {
+        return Err(GreEvent::GreEventVersion1Route);
+    }
+    if flags & GRE_FLAG_SSR != 0 {
+        return Err(GreEvent::GreEventVersion1Ssr);
+    }
+    if flags & GRE_FLAG_RECUR != 0 {
+        return Err(GreEvent::GreEventVersion1Recur);
+    }
+    if version & GREV1_FLAG_FLAGS != 0 {
+        return Err(GreEvent::GreEventVersion1Flags);
+    }
+    if proto != GRE_PROTO_PPP {
+        return Err(GreEvent::GreEventVersion1WrongProtocol);
+    }
+    if flags & GRE_FLAG_KEY == 0 {
+        return Err(GreEvent::GreEventVersion1NoKey);
+    }
+
+    let mut header_len = GRE_HDR_LEN + GRE_KEY_LEN;
+    if flags & GRE_FLAG_SEQ != 0 {
+        header_len += GRE_SEQ_LEN;
+    }
+    if version & GREV1_FLAG_ACK != 0 {
+        header_len += GREV1_ACK_LEN;
+    }
+    if header_len > input.len() {
+        return Err(GreEvent::GreEventVersion1HdrTooBig);
+    }
+
+    // the key holds the payload length and the call id
+    let mut hdr = GreHeader {
+        key_set: true,
+        key: read_u32(input, GRE_HDR_LEN),
+        header_len: header_len as u32,
+        ..Default::default()
+    };
+    if flags & GRE_FLAG_SEQ != 0 {
+        hdr.seq_set = true;
+        hdr.seq = read_u32(input, GRE_HDR_LEN + GRE_KEY_LEN);
+    }
+    Ok(hdr)
+}
+
+/// Reads a u32 the caller made sure is within `input`.
+fn read_u32(input: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        input[offset],
+        input[offset + 1],
+        input[offset + 2],
+        input[offset + 3],
+    ])
+}
+
+pub fn decode_gre(input: &[u8]) -> Result<GreHeader, GreEvent> {
+    let (_, (flags, version, proto)) =
+        parse_gre_base(input).map_err(|_| GreEvent::GreEventPktTooSmall)?;
+    let mut hdr = match version & 0x07 {
+        GRE_VERSION_0 => decode_gre_v0(input, flags, version)?,
+        GRE_VERSION_1 => decode_gre_v1(input, flags, version, proto)?,
+        _ => {
+            return Err(GreEvent::GreEventWrongVersion);
+        }
+    };
+    hdr.version = version & 0x07;
+    hdr.proto = proto;
+    hdr.tunnel = match proto {
+        GRE_PROTO_IPV4 => GreTunnelProto::GreTunnelIpv4,
+        GRE_PROTO_IPV6 => GreTunnelProto::GreTunnelIpv6,
+        // PPTP packets without payload only carry acknowledgements
+        GRE_PROTO_PPP if hdr.version == GRE_VERSION_1 && hdr.key >> 16 == 0 => {
+            GreTunnelProto::GreTunnelNone
+        }
+        GRE_PROTO_PPP => GreTunnelProto::GreTunnelPpp,
+        GRE_PROTO_VLAN => GreTunnelProto::GreTunnelVlan,
+        GRE_PROTO_BRIDGE => GreTunnelProto::GreTunnelEthernet,
+        GRE_PROTO_ARP => GreTunnelProto::GreTunnelArp,
+        // Type I has no sequence number and no header, Type II has both
+        GRE_PROTO_ERSPAN if flags & GRE_FLAG_SEQ == 0 => GreTunnelProto::GreTunnelErspanI,
+        GRE_PROTO_ERSPAN | GRE_PROTO_ERSPAN_III => GreTunnelProto::GreTunnelErspan,
+        _ => GreTunnelProto::GreTunnelNone,
+    };
+    if hdr.tunnel == GreTunnelProto::GreTunnelErspan {
+        // a malformed ERSPAN header is reported when decoding the payload
+        if let Ok(erspan) = decode_erspan(&input[hdr.header_len as usize..]) {
+            hdr.erspan_session_id_set = true;
+            hdr.erspan_session_id = erspan.session_id;
+        }
+    }
+    Ok(hdr)
+}
+
+/// Decode the GRE header at `input` into `hdr`.
+///
+/// Returns GreEventNone if the header is valid, the event to set on the
+/// packet otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn SCGreDecode(input: *const u8, len: u32, hdr: &mut GreHeader) -> GreEvent {
+    let input = build_slice!(input, len as usize);
+    match decode_gre(input) {
+        Ok(h) => {
+            *hdr = h;
+            GreEvent::GreEventNone
+        }
+        Err(e) => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gre_too_small() {
+        let buf: &[u8] = &[0x00, 0x6e, 0x62];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventPktTooSmall));
+    }
+
+    #[test]
+    fn test_gre_wrong_version() {
+        let buf: &[u8] = &[
+            0x00, 0x6e, 0x62, 0xac, 0x40, 0x00, 0x40, 0x2f, 0xc2, 0xc7, 0x0a, 0x00,
+        ];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventWrongVersion));
+    }
+
+    #[test]
+    fn test_gre_v1_pptp() {
+        // key, sequence and acknowledgement, call id 0x0218
+        let buf: &[u8] = &[
+            0x30, 0x81, 0x88, 0x0b, 0x00, 0x4e, 0x02, 0x18, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x02, 0xff, 0x03, 0x00, 0x21,
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.version, 1);
+        assert_eq!(hdr.header_len, 16);
+        assert!(hdr.key_set);
+        assert_eq!(hdr.key, 0x004e0218);
+        assert!(hdr.seq_set);
+        assert_eq!(hdr.seq, 1);
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelPpp);
+
+        // acknowledgement only
+        let buf: &[u8] = &[
+            0x20, 0x81, 0x88, 0x0b, 0x00, 0x00, 0x02, 0x18, 0x00, 0x00, 0x00, 0x02,
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelNone);
+
+        assert_eq!(
+            decode_gre(&buf[..10]),
+            Err(GreEvent::GreEventVersion1HdrTooBig)
+        );
+        let buf: &[u8] = &[0x00, 0x01, 0x88, 0x0b, 0x00, 0x00, 0x02, 0x18];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventVersion1NoKey));
+        let buf: &[u8] = &[0x20, 0x01, 0x08, 0x00, 0x00, 0x00, 0x02, 0x18];
+        assert_eq!(
+            decode_gre(buf),
+            Err(GreEvent::GreEventVersion1WrongProtocol)
+        );
+        let buf: &[u8] = &[0xa0, 0x01, 0x88, 0x0b, 0x00, 0x00, 0x02, 0x18];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventVersion1Chksum));
+    }
+
+    #[test]
+    fn test_gre_v0_key() {
+        // checksum and key
+        let buf: &[u8] = &[
+            0xa0, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xd2, 0x45,
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.version, 0);
+        assert_eq!(hdr.header_len, 12);
+        assert!(hdr.key_set);
+        assert_eq!(hdr.key, 1234);
+        assert!(!hdr.seq_set);
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelIpv4);
+
+        assert_eq!(
+            decode_gre(&buf[..10]),
+            Err(GreEvent::GreEventVersion0HdrTooBig)
+        );
+        let buf: &[u8] = &[0x01, 0x00, 0x08, 0x00];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventVersion0Recur));
+        let buf: &[u8] = &[0x00, 0x08, 0x08, 0x00];
+        assert_eq!(decode_gre(buf), Err(GreEvent::GreEventVersion0Flags));
+    }
+
+    #[test]
+    fn test_gre_v0_sre() {
+        let buf: &[u8] = &[
+            0x40, 0x00, 0x86, 0xdd, 0x00, 0x00, 0x00, 0x00, // routing, offset
+            0x08, 0x00, 0x00, 0x04, 0x0a, 0x00, 0x00, 0x01, // sre
+            0x00, 0x00, 0x00, 0x00, // last sre
+            0x60,
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.header_len, 20);
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelIpv6);
+
+        assert_eq!(
+            decode_gre(&buf[..19]),
+            Err(GreEvent::GreEventVersion0MalformedSreHdr)
+        );
+        assert_eq!(
+            decode_gre(&buf[..14]),
+            Err(GreEvent::GreEventVersion0MalformedSreHdr)
+        );
+    }
+
+    #[test]
+    fn test_gre_erspan() {
+        // no sequence number: Type I
+        let buf: &[u8] = &[0x00, 0x00, 0x88, 0xbe, 0x00, 0x01, 0x02, 0x03];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelErspanI);
+        assert!(!hdr.erspan_session_id_set);
+
+        // Type II
+        let buf: &[u8] = &[
+            0x10, 0x00, 0x88, 0xbe, 0x00, 0x00, 0x00, 0x07, // gre
+            0x10, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, // erspan
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.header_len, 8);
+        assert_eq!(hdr.seq, 7);
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelErspan);
+        assert!(hdr.erspan_session_id_set);
+        assert_eq!(hdr.erspan_session_id, 42);
+
+        // Type III
+        let buf: &[u8] = &[
+            0x10, 0x00, 0x22, 0xeb, 0x00, 0x00, 0x00, 0x07, // gre
+            0x20, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // erspan
+        ];
+        let hdr = decode_gre(buf).unwrap();
+        assert_eq!(hdr.tunnel, GreTunnelProto::GreTunnelErspan);
+        assert_eq!(hdr.erspan_session_id, 43);
+    }
+}
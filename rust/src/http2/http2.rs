@@ -1560,7 +1560,7 @@ pub unsafe extern "C" fn rs_http2_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_HTTP2 = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.http2.max-streams") {
             if let Ok(v) = val.parse::<usize>() {
@@ -1597,7 +1597,7 @@ pub unsafe extern "C" fn rs_http2_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_DOH2 = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         } else {
             SCLogWarning!("DOH2 is not meant to be detection-only.");
         }
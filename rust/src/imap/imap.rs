@@ -0,0 +1,713 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, ImapResponse, ImapStatus};
+use crate::applayer::{self, *};
+use crate::common::parse_line;
+use crate::core::{AppProto, Direction, Flow, ALPROTO_UNKNOWN, IPPROTO_TCP};
+use crate::frames::Frame;
+use nom7 as nom;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(super) static mut ALPROTO_IMAP: AppProto = ALPROTO_UNKNOWN;
+
+pub(super) static IMAP_LIMITS: AppLayerLimits = AppLayerLimits::new(256);
+
+/// RFC 7162 recommends clients to limit their command lines to 8192
+/// octets, responses longer than this are not parsed either.
+const IMAP_MAX_LINE_LEN: usize = 8192;
+
+/// Maximum number of capabilities kept per transaction.
+const IMAP_MAX_CAPABILITIES: usize = 64;
+
+#[derive(AppLayerFrameType)]
+pub enum ImapFrameType {
+    Line,
+    Literal,
+}
+
+#[derive(AppLayerEvent)]
+pub enum ImapEvent {
+    /// Client line without a command after the tag.
+    InvalidCommand,
+    /// Server line that is neither a continuation request, untagged
+    /// data nor a tagged status response.
+    InvalidResponse,
+    /// Tagged response not matching any pending command.
+    UnexpectedTag,
+    /// Line exceeding `IMAP_MAX_LINE_LEN`.
+    LineTooLong,
+    /// Credentials sent in clear text by LOGIN or an AUTHENTICATE PLAIN
+    /// or LOGIN exchange, on a flow not limited to local networks.
+    PlaintextAuth,
+    TooManyTransactions,
+}
+
+/// A command and its responses. The server greeting gets a transaction
+/// of its own, without a command.
+#[derive(Debug, Default)]
+pub struct ImapTransaction {
+    tx_id: u64,
+    pub tag: Option<Vec<u8>>,
+    /// Command name in upper case, like `UID FETCH`.
+    pub command: Option<Vec<u8>>,
+    /// User name of a LOGIN command, unless sent as a literal.
+    pub user: Option<Vec<u8>>,
+    /// SASL mechanism of an AUTHENTICATE command.
+    pub auth_mechanism: Option<Vec<u8>>,
+    /// Mailbox of a SELECT or EXAMINE command.
+    pub mailbox: Option<Vec<u8>>,
+    pub greeting: Option<Vec<u8>>,
+    pub capabilities: Vec<Vec<u8>>,
+    /// Number of untagged FETCH responses.
+    pub fetch_messages: u64,
+    /// Size of the literals of the FETCH responses, the message data.
+    pub fetch_bytes: u64,
+    pub status: Option<ImapStatus>,
+    pub status_text: Option<Vec<u8>>,
+    complete: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for ImapTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+impl TxLimit for ImapTransaction {
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn force_complete(&mut self) {
+        self.complete = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
+impl ImapTransaction {
+    fn add_capabilities(&mut self, capabilities: Vec<&[u8]>) {
+        for c in capabilities {
+            if self.capabilities.len() >= IMAP_MAX_CAPABILITIES {
+                break;
+            }
+            self.capabilities.push(c.to_vec());
+        }
+    }
+}
+
+/// Parsing state of a direction.
+#[derive(Debug, Default)]
+struct ImapDirState {
+    /// Bytes of the current literal not seen yet.
+    literal_left: u64,
+    /// The next line continues the line of the literal.
+    continuation: bool,
+    /// Discarding a line exceeding `IMAP_MAX_LINE_LEN`.
+    skip_line: bool,
+}
+
+#[derive(Default)]
+pub struct ImapState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<ImapTransaction>,
+    tx_index_completed: usize,
+    ts: ImapDirState,
+    tc: ImapDirState,
+    greeting_seen: bool,
+    /// Client lines are SASL responses of an AUTHENTICATE command.
+    sasl: bool,
+    /// The literals of the current response are FETCH data.
+    fetch: bool,
+    /// Whether the client and server are both on local networks, None
+    /// until known.
+    local: Option<bool>,
+    /// STARTTLS accepted, the rest of the session is TLS.
+    tls: bool,
+}
+
+impl State<ImapTransaction> for ImapState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&ImapTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl ImapState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+            self.tx_index_completed = 0;
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&ImapTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self) -> &mut ImapTransaction {
+        self.tx_id += 1;
+        let mut tx = ImapTransaction {
+            tx_id: self.tx_id,
+            ..Default::default()
+        };
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            IMAP_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            ImapEvent::TooManyTransactions as u8,
+        );
+        self.transactions.push_back(tx);
+        // just pushed
+        self.transactions.back_mut().unwrap()
+    }
+
+    /// Return the oldest command waiting for its tagged response.
+    fn pending_tx(&mut self) -> Option<&mut ImapTransaction> {
+        self.transactions.iter_mut().find(|tx| !tx.complete)
+    }
+
+    /// Set an event on the pending command, or on a transaction of its
+    /// own if there is none.
+    fn set_event(&mut self, event: ImapEvent) {
+        let tx = match self.pending_tx() {
+            Some(tx) => tx,
+            None => {
+                let tx = self.new_tx();
+                tx.complete = true;
+                tx
+            }
+        };
+        tx.tx_data.set_event(event as u8);
+    }
+
+    fn dir_mut(&mut self, direction: Direction) -> &mut ImapDirState {
+        if direction == Direction::ToServer {
+            &mut self.ts
+        } else {
+            &mut self.tc
+        }
+    }
+
+    fn handle_request_line(&mut self, line: &[u8], continuation: bool) {
+        if continuation {
+            // rest of a command after a literal
+            return;
+        }
+        if self.sasl || line.is_empty() || line.eq_ignore_ascii_case(b"DONE") {
+            // SASL response, or the end of an IDLE command
+            return;
+        }
+        let request = match parser::parse_request(line) {
+            Some(request) => request,
+            None => {
+                self.set_event(ImapEvent::InvalidCommand);
+                return;
+            }
+        };
+        let mut command = request.command.to_ascii_uppercase();
+        let mut args = request.args;
+        if command == b"UID" {
+            let (name, rest) = match args.iter().position(|&c| c == b' ') {
+                Some(p) => (&args[..p], &args[p + 1..]),
+                None => (args, &args[args.len()..]),
+            };
+            command.push(b' ');
+            command.extend_from_slice(&name.to_ascii_uppercase());
+            args = rest;
+        }
+        let local = self.local;
+        let mut sasl = false;
+        let tx = self.new_tx();
+        tx.tag = Some(request.tag.to_vec());
+        let plaintext_auth = match command.as_slice() {
+            b"LOGIN" => {
+                tx.user = parser::first_astring(args);
+                true
+            }
+            b"AUTHENTICATE" => {
+                let mechanism = args
+                    .split(|&c| c == b' ')
+                    .next()
+                    .unwrap_or(args)
+                    .to_ascii_uppercase();
+                let plaintext = mechanism == b"PLAIN" || mechanism == b"LOGIN";
+                tx.auth_mechanism = Some(mechanism);
+                sasl = true;
+                plaintext
+            }
+            b"SELECT" | b"EXAMINE" => {
+                tx.mailbox = parser::first_astring(args);
+                false
+            }
+            _ => false,
+        };
+        if plaintext_auth && local == Some(false) {
+            tx.tx_data.set_event(ImapEvent::PlaintextAuth as u8);
+        }
+        tx.command = Some(command);
+        self.sasl = sasl;
+    }
+
+    fn handle_response_line(&mut self, flow: *const Flow, line: &[u8], continuation: bool) {
+        if continuation {
+            // rest of a response after a literal
+            return;
+        }
+        self.fetch = false;
+        let greeting = !self.greeting_seen;
+        self.greeting_seen = true;
+        match parser::parse_response(line) {
+            Some(ImapResponse::Continuation(_)) => {}
+            Some(ImapResponse::Untagged(data)) => {
+                if greeting {
+                    let tx = self.new_tx();
+                    tx.greeting = Some(data.to_vec());
+                    if let Some(c) = parser::capabilities(data) {
+                        tx.add_capabilities(c);
+                    }
+                    tx.complete = true;
+                    return;
+                }
+                let fetch = parser::is_fetch(data);
+                if let Some(tx) = self.pending_tx() {
+                    if let Some(c) = parser::capabilities(data) {
+                        tx.add_capabilities(c);
+                    }
+                    if fetch {
+                        tx.fetch_messages += 1;
+                    }
+                }
+                self.fetch = fetch;
+            }
+            Some(ImapResponse::Tagged { tag, status, text }) => {
+                let tx = match self
+                    .transactions
+                    .iter_mut()
+                    .find(|tx| !tx.complete && tx.tag.as_deref() == Some(tag))
+                {
+                    Some(tx) => tx,
+                    None => {
+                        self.set_event(ImapEvent::UnexpectedTag);
+                        return;
+                    }
+                };
+                tx.status = Some(status);
+                tx.status_text = Some(text.to_vec());
+                if let Some(c) = parser::capabilities(text) {
+                    tx.add_capabilities(c);
+                }
+                tx.complete = true;
+                match tx.command.as_deref() {
+                    Some(b"AUTHENTICATE") => {
+                        self.sasl = false;
+                    }
+                    Some(b"STARTTLS") if status == ImapStatus::Ok => {
+                        SCLogDebug!("IMAP STARTTLS accepted, switching to TLS");
+                        applayer_request_tls_upgrade(flow);
+                        self.tls = true;
+                    }
+                    _ => {}
+                }
+            }
+            None => {
+                self.set_event(ImapEvent::InvalidResponse);
+            }
+        }
+    }
+
+    fn handle_literal(&mut self, direction: Direction, size: u64) {
+        if direction == Direction::ToClient && self.fetch {
+            if let Some(tx) = self.pending_tx() {
+                tx.fetch_bytes = tx.fetch_bytes.saturating_add(size);
+            }
+        }
+        let dir = self.dir_mut(direction);
+        if size > 0 {
+            dir.literal_left = size;
+        } else {
+            dir.continuation = true;
+        }
+    }
+
+    fn parse(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        if self.local.is_none() && !flow.is_null() {
+            self.local = unsafe { (*flow).is_local() };
+        }
+        let input = stream_slice.as_slice();
+        let mut start = input;
+        while !start.is_empty() {
+            if self.tls {
+                return AppLayerResult::ok();
+            }
+            let dir = self.dir_mut(direction);
+            if dir.literal_left > 0 {
+                let len = std::cmp::min(dir.literal_left, start.len() as u64) as usize;
+                dir.literal_left -= len as u64;
+                if dir.literal_left == 0 {
+                    dir.continuation = true;
+                }
+                let _pdu = Frame::new(
+                    flow,
+                    stream_slice,
+                    start,
+                    len as i64,
+                    ImapFrameType::Literal as u8,
+                    None,
+                );
+                start = &start[len..];
+                continue;
+            }
+            if dir.skip_line {
+                match start.iter().position(|&c| c == b'\n') {
+                    Some(p) => {
+                        dir.skip_line = false;
+                        start = &start[p + 1..];
+                        continue;
+                    }
+                    None => {
+                        return AppLayerResult::ok();
+                    }
+                }
+            }
+            match parse_line(start) {
+                Ok((rem, line)) => {
+                    let _pdu = Frame::new(
+                        flow,
+                        stream_slice,
+                        start,
+                        (start.len() - rem.len()) as i64,
+                        ImapFrameType::Line as u8,
+                        None,
+                    );
+                    start = rem;
+                    let continuation = std::mem::take(&mut dir.continuation);
+                    let sasl = self.sasl;
+                    if direction == Direction::ToServer {
+                        self.handle_request_line(line, continuation);
+                    } else {
+                        self.handle_response_line(flow, line, continuation);
+                    }
+                    // SASL responses are base64, not followed by literals
+                    if !(direction == Direction::ToServer && sasl) {
+                        if let Some(size) = parser::literal_size(line) {
+                            self.handle_literal(direction, size);
+                        }
+                    }
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    if start.len() > IMAP_MAX_LINE_LEN {
+                        dir.skip_line = true;
+                        self.set_event(ImapEvent::LineTooLong);
+                        return AppLayerResult::ok();
+                    }
+                    let consumed = input.len() - start.len();
+                    let needed = start.len() + 1;
+                    return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                }
+                Err(_) => {
+                    return AppLayerResult::err();
+                }
+            }
+        }
+        // Input was fully consumed.
+        return AppLayerResult::ok();
+    }
+}
+
+// C exports.
+
+extern "C" fn imap_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = ImapState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn imap_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut ImapState));
+}
+
+unsafe extern "C" fn imap_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, ImapState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn imap_parse_request(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        return AppLayerResult::ok();
+    }
+    let state = cast_pointer!(state, ImapState);
+    state.parse(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn imap_parse_response(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+        return AppLayerResult::ok();
+    }
+    let state = cast_pointer!(state, ImapState);
+    state.parse(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn imap_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, ImapState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn imap_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, ImapState);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn imap_tx_get_alstate_progress(tx: *mut c_void, direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, ImapTransaction);
+    // the command is complete once seen, the responses once tagged
+    if tx.complete || Direction::from(direction) == Direction::ToServer {
+        return 1;
+    }
+    return 0;
+}
+
+export_tx_data_get!(imap_get_tx_data, ImapTransaction);
+export_state_data_get!(imap_get_state_data, ImapState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"imap\0";
+
+/// Register the IMAP parser. Protocol detection is done with the
+/// patterns registered by `RegisterIMAPParsers`.
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterImapParser() {
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: std::ptr::null(),
+        ipproto: IPPROTO_TCP,
+        probe_ts: None,
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 16,
+        state_new: imap_state_new,
+        state_free: imap_state_free,
+        tx_free: imap_state_tx_free,
+        parse_ts: imap_parse_request,
+        parse_tc: imap_parse_response,
+        get_tx_count: imap_state_get_tx_count,
+        get_tx: imap_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: imap_tx_get_alstate_progress,
+        get_eventinfo: Some(ImapEvent::get_event_info),
+        get_eventinfo_byid: Some(ImapEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<ImapState, ImapTransaction>),
+        get_tx_data: imap_get_tx_data,
+        get_state_data: imap_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(ImapFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(ImapFrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("tcp").unwrap();
+
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_IMAP = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+            AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_IMAP);
+        }
+        IMAP_LIMITS.configure("imap");
+        SCLogDebug!("Rust IMAP parser registered.");
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for IMAP.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(state: &mut ImapState, direction: Direction, buf: &[u8]) -> AppLayerResult {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse(std::ptr::null(), &ss, direction)
+    }
+
+    #[test]
+    fn test_imap_login_fetch() {
+        let mut state = ImapState::new();
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"* OK [CAPABILITY IMAP4rev1 STARTTLS] Dovecot ready.\r\n",
+        );
+        parse(
+            &mut state,
+            Direction::ToServer,
+            b"a1 LOGIN alice secret\r\n",
+        );
+        parse(&mut state, Direction::ToClient, b"a1 OK Logged in\r\n");
+        parse(&mut state, Direction::ToServer, b"a2 SELECT \"INBOX\"\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"* 2 EXISTS\r\na2 OK [READ-WRITE] Select completed\r\n",
+        );
+        parse(
+            &mut state,
+            Direction::ToServer,
+            b"a3 uid fetch 1:2 (BODY[])\r\n",
+        );
+        // the literal data looks like a tagged response, and is split
+        let r = parse(
+            &mut state,
+            Direction::ToClient,
+            b"* 1 FETCH (UID 1 BODY[] {12}\r\na3 OK done\r\n)\r\n* 2 FETCH (UID 2 BODY[] {4}\r\nab",
+        );
+        assert_eq!(r, AppLayerResult::ok());
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"cd)\r\na3 OK Fetch completed\r\n",
+        );
+
+        assert_eq!(state.transactions.len(), 4);
+        let tx = &state.transactions[0];
+        assert!(tx.command.is_none());
+        assert_eq!(tx.capabilities.len(), 2);
+        assert!(tx.complete);
+
+        let tx = &state.transactions[1];
+        assert_eq!(tx.command.as_deref(), Some(&b"LOGIN"[..]));
+        assert_eq!(tx.user.as_deref(), Some(&b"alice"[..]));
+        assert_eq!(tx.status, Some(ImapStatus::Ok));
+
+        let tx = &state.transactions[2];
+        assert_eq!(tx.mailbox.as_deref(), Some(&b"INBOX"[..]));
+
+        let tx = &state.transactions[3];
+        assert_eq!(tx.command.as_deref(), Some(&b"UID FETCH"[..]));
+        assert_eq!(tx.fetch_messages, 2);
+        assert_eq!(tx.fetch_bytes, 16);
+        assert_eq!(tx.status_text.as_deref(), Some(&b"Fetch completed"[..]));
+        assert!(tx.complete);
+    }
+
+    #[test]
+    fn test_imap_authenticate_literal() {
+        let mut state = ImapState::new();
+        parse(&mut state, Direction::ToClient, b"* OK ready\r\n");
+        parse(
+            &mut state,
+            Direction::ToServer,
+            b"a1 AUTHENTICATE plain\r\n",
+        );
+        parse(&mut state, Direction::ToClient, b"+ \r\n");
+        parse(&mut state, Direction::ToServer, b"AGFsaWNlAHNlY3JldA==\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"a1 NO Authentication failed\r\n",
+        );
+        assert!(!state.sasl);
+
+        // a literal with a line terminator, and its continuation
+        parse(&mut state, Direction::ToServer, b"a2 LOGIN {7}\r\n");
+        parse(&mut state, Direction::ToClient, b"+ go ahead\r\n");
+        parse(&mut state, Direction::ToServer, b"al\r\nice secret\r\n");
+        parse(&mut state, Direction::ToClient, b"a2 OK Logged in\r\n");
+
+        assert_eq!(state.transactions.len(), 3);
+        let tx = &state.transactions[1];
+        assert_eq!(tx.auth_mechanism.as_deref(), Some(&b"PLAIN"[..]));
+        assert_eq!(tx.status, Some(ImapStatus::No));
+        let tx = &state.transactions[2];
+        assert_eq!(tx.command.as_deref(), Some(&b"LOGIN"[..]));
+        assert!(tx.user.is_none());
+        assert_eq!(tx.status, Some(ImapStatus::Ok));
+    }
+
+    #[test]
+    fn test_imap_starttls() {
+        let mut state = ImapState::new();
+        parse(&mut state, Direction::ToClient, b"* OK ready\r\n");
+        parse(&mut state, Direction::ToServer, b"a1 STARTTLS\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"a1 OK Begin TLS negotiation now\r\n",
+        );
+        assert!(state.tls);
+        let r = parse(&mut state, Direction::ToServer, b"\x16\x03\x01\x02\x00\x01");
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), 2);
+    }
+
+    #[test]
+    fn test_imap_line_too_long() {
+        let mut state = ImapState::new();
+        let mut buf = b"a1 SEARCH ".to_vec();
+        buf.resize(IMAP_MAX_LINE_LEN + 1, b'1');
+        assert_eq!(
+            parse(&mut state, Direction::ToServer, &buf),
+            AppLayerResult::ok()
+        );
+        parse(&mut state, Direction::ToServer, b"1 2 3\r\na2 NOOP\r\n");
+        assert!(!state.ts.skip_line);
+        // the event is on a transaction of its own
+        assert_eq!(state.transactions.len(), 2);
+        assert_eq!(state.transactions[1].command.as_deref(), Some(&b"NOOP"[..]));
+    }
+}
@@ -0,0 +1,71 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::imap::ImapTransaction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_imap(tx: &ImapTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("imap")?;
+    if let Some(ref greeting) = tx.greeting {
+        js.set_string_from_bytes("greeting", greeting)?;
+    }
+    if let Some(ref tag) = tx.tag {
+        js.set_string_from_bytes("tag", tag)?;
+    }
+    if let Some(ref command) = tx.command {
+        js.set_string_from_bytes("command", command)?;
+    }
+    if let Some(ref user) = tx.user {
+        js.set_string_from_bytes("user", user)?;
+    }
+    if let Some(ref mechanism) = tx.auth_mechanism {
+        js.set_string_from_bytes("auth_mechanism", mechanism)?;
+    }
+    if let Some(ref mailbox) = tx.mailbox {
+        js.set_string_from_bytes("mailbox", mailbox)?;
+    }
+    if !tx.capabilities.is_empty() {
+        js.open_array("capabilities")?;
+        for c in &tx.capabilities {
+            js.append_string_from_bytes(c)?;
+        }
+        js.close()?;
+    }
+    if tx.fetch_messages > 0 {
+        js.open_object("fetch")?;
+        js.set_uint("messages", tx.fetch_messages)?;
+        js.set_uint("bytes", tx.fetch_bytes)?;
+        js.close()?;
+    }
+    if let Some(status) = tx.status {
+        js.set_string("status", status.as_str())?;
+    }
+    if let Some(ref text) = tx.status_text {
+        js.set_string_from_bytes("status_text", text)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCImapLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, ImapTransaction);
+    log_imap(tx, js).is_ok()
+}
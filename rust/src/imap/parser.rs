@@ -0,0 +1,284 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use crate::common::split_word;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ImapRequest<'a> {
+    pub tag: &'a [u8],
+    /// Command name, as sent.
+    pub command: &'a [u8],
+    /// Anything after the command name.
+    pub args: &'a [u8],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImapStatus {
+    Ok,
+    No,
+    Bad,
+}
+
+impl ImapStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImapStatus::Ok => "OK",
+            ImapStatus::No => "NO",
+            ImapStatus::Bad => "BAD",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ImapResponse<'a> {
+    /// `+ <text>`, the server waiting for more data from the client.
+    Continuation(&'a [u8]),
+    /// `* <data>`
+    Untagged(&'a [u8]),
+    /// `<tag> OK|NO|BAD <text>`, completing a command.
+    Tagged {
+        tag: &'a [u8],
+        status: ImapStatus,
+        text: &'a [u8],
+    },
+}
+
+/// Parse a client command line. Returns None if there is no command
+/// after the tag.
+pub fn parse_request(line: &[u8]) -> Option<ImapRequest> {
+    let (tag, rest) = split_word(line);
+    let (command, args) = split_word(rest);
+    if tag.is_empty() || command.is_empty() {
+        return None;
+    }
+    Some(ImapRequest { tag, command, args })
+}
+
+/// Parse a server response line. Returns None if it is neither a
+/// continuation request, untagged data nor a tagged status response.
+pub fn parse_response(line: &[u8]) -> Option<ImapResponse> {
+    if let Some(text) = line.strip_prefix(b"+") {
+        return Some(ImapResponse::Continuation(
+            text.strip_prefix(b" ").unwrap_or(text),
+        ));
+    }
+    if let Some(data) = line.strip_prefix(b"* ") {
+        return Some(ImapResponse::Untagged(data));
+    }
+    let (tag, rest) = split_word(line);
+    let (status, text) = split_word(rest);
+    let status = if status.eq_ignore_ascii_case(b"OK") {
+        ImapStatus::Ok
+    } else if status.eq_ignore_ascii_case(b"NO") {
+        ImapStatus::No
+    } else if status.eq_ignore_ascii_case(b"BAD") {
+        ImapStatus::Bad
+    } else {
+        return None;
+    };
+    if tag.is_empty() {
+        return None;
+    }
+    Some(ImapResponse::Tagged { tag, status, text })
+}
+
+/// Return the size of the literal announced at the end of a line, as
+/// `{N}`, or `{N+}` and `{N-}` for the non synchronizing literals.
+pub fn literal_size(line: &[u8]) -> Option<u64> {
+    let line = line.strip_suffix(b"}")?;
+    let start = line.iter().rposition(|&c| c == b'{')?;
+    let size = &line[start + 1..];
+    let size = size
+        .strip_suffix(b"+")
+        .or_else(|| size.strip_suffix(b"-"))
+        .unwrap_or(size);
+    if size.is_empty() || size.len() > 19 || !size.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    std::str::from_utf8(size).ok()?.parse().ok()
+}
+
+/// Parse the first argument of `args` if it is an atom or a quoted
+/// string. Returns None for a literal, whose data follows the line.
+pub fn first_astring(args: &[u8]) -> Option<Vec<u8>> {
+    if let Some(quoted) = args.strip_prefix(b"\"") {
+        let mut value = Vec::new();
+        let mut escaped = false;
+        for &c in quoted {
+            if escaped {
+                value.push(c);
+                escaped = false;
+            } else if c == b'\\' {
+                escaped = true;
+            } else if c == b'"' {
+                return Some(value);
+            } else {
+                value.push(c);
+            }
+        }
+        // unterminated string
+        return None;
+    }
+    let (atom, _) = split_word(args);
+    if atom.is_empty() || atom.starts_with(b"{") {
+        return None;
+    }
+    Some(atom.to_vec())
+}
+
+/// Return the capabilities of an untagged `CAPABILITY` response or of a
+/// `[CAPABILITY ...]` response code, like in the server greeting. The
+/// response code is looked for at the start of `data` or after its first
+/// word, the status of an untagged status response.
+pub fn capabilities(data: &[u8]) -> Option<Vec<&[u8]>> {
+    let list = if let Some(list) = strip_prefix_ignore_case(data, b"CAPABILITY ") {
+        list
+    } else {
+        let text = if data.starts_with(b"[") {
+            data
+        } else {
+            split_word(data).1
+        };
+        let code = text.strip_prefix(b"[")?;
+        let code = strip_prefix_ignore_case(code, b"CAPABILITY ")?;
+        let end = code.iter().position(|&c| c == b']')?;
+        &code[..end]
+    };
+    Some(
+        list.split(|&c| c == b' ')
+            .filter(|c| !c.is_empty())
+            .collect(),
+    )
+}
+
+/// Return true if the untagged data is a `<n> FETCH` response.
+pub fn is_fetch(data: &[u8]) -> bool {
+    let (num, rest) = split_word(data);
+    let (name, _) = split_word(rest);
+    !num.is_empty() && num.iter().all(|c| c.is_ascii_digit()) && name.eq_ignore_ascii_case(b"FETCH")
+}
+
+fn strip_prefix_ignore_case<'a>(i: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if i.len() >= prefix.len() && i[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&i[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::parse_line;
+
+    #[test]
+    fn test_parse_request() {
+        let (rem, line) = parse_line(b"a001 LOGIN alice secret\r\nrest").unwrap();
+        assert_eq!(rem, b"rest");
+        assert_eq!(
+            parse_request(line),
+            Some(ImapRequest {
+                tag: b"a001",
+                command: b"LOGIN",
+                args: b"alice secret",
+            })
+        );
+        assert_eq!(
+            parse_request(b"a002 NOOP"),
+            Some(ImapRequest {
+                tag: b"a002",
+                command: b"NOOP",
+                args: b"",
+            })
+        );
+        assert_eq!(parse_request(b"a003"), None);
+        assert!(matches!(
+            parse_line(b"a001 LOG"),
+            Err(nom7::Err::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(b"+ Ready for literal data"),
+            Some(ImapResponse::Continuation(b"Ready for literal data"))
+        );
+        assert_eq!(parse_response(b"+"), Some(ImapResponse::Continuation(b"")));
+        assert_eq!(
+            parse_response(b"* 18 EXISTS"),
+            Some(ImapResponse::Untagged(b"18 EXISTS"))
+        );
+        assert_eq!(
+            parse_response(b"a001 no [AUTHENTICATIONFAILED] Invalid credentials"),
+            Some(ImapResponse::Tagged {
+                tag: b"a001",
+                status: ImapStatus::No,
+                text: b"[AUTHENTICATIONFAILED] Invalid credentials",
+            })
+        );
+        assert_eq!(parse_response(b"a001 MAYBE"), None);
+    }
+
+    #[test]
+    fn test_literal_size() {
+        assert_eq!(literal_size(b"* 1 FETCH (BODY[] {342}"), Some(342));
+        assert_eq!(literal_size(b"a1 APPEND INBOX {1200+}"), Some(1200));
+        assert_eq!(literal_size(b"a1 LOGIN {0}"), Some(0));
+        assert_eq!(literal_size(b"a1 LOGIN {}"), None);
+        assert_eq!(literal_size(b"a1 LOGIN {x}"), None);
+        assert_eq!(literal_size(b"a1 LOGIN alice"), None);
+        assert_eq!(literal_size(b"a1 LOGIN {99999999999999999999}"), None);
+    }
+
+    #[test]
+    fn test_first_astring() {
+        assert_eq!(first_astring(b"alice secret"), Some(b"alice".to_vec()));
+        assert_eq!(
+            first_astring(b"\"al \\\"ice\\\"\" secret"),
+            Some(b"al \"ice\"".to_vec())
+        );
+        assert_eq!(first_astring(b"\"alice"), None);
+        assert_eq!(first_astring(b"{5}"), None);
+        assert_eq!(first_astring(b""), None);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        assert_eq!(
+            capabilities(b"CAPABILITY IMAP4rev1 STARTTLS AUTH=PLAIN"),
+            Some(vec![&b"IMAP4rev1"[..], b"STARTTLS", b"AUTH=PLAIN"])
+        );
+        assert_eq!(
+            capabilities(b"OK [CAPABILITY IMAP4rev1 LOGINDISABLED] Dovecot ready."),
+            Some(vec![&b"IMAP4rev1"[..], b"LOGINDISABLED"])
+        );
+        assert_eq!(
+            capabilities(b"[CAPABILITY IMAP4rev1 IDLE] Logged in"),
+            Some(vec![&b"IMAP4rev1"[..], b"IDLE"])
+        );
+        assert_eq!(capabilities(b"OK [ALERT] hello"), None);
+        assert_eq!(capabilities(b"OK Dovecot ready."), None);
+    }
+
+    #[test]
+    fn test_is_fetch() {
+        assert!(is_fetch(b"12 FETCH (FLAGS (\\Seen))"));
+        assert!(!is_fetch(b"12 EXISTS"));
+        assert!(!is_fetch(b"FETCH"));
+    }
+}
@@ -619,7 +619,7 @@ pub unsafe extern "C" fn rs_register_krb5_parser() {
         // store the allocated ID for the probe function
         ALPROTO_KRB5 = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_KRB5);
     } else {
@@ -637,7 +637,7 @@ pub unsafe extern "C" fn rs_register_krb5_parser() {
         // store the allocated ID for the probe function
         ALPROTO_KRB5 = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_KRB5);
     } else {
@@ -0,0 +1,187 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::ldap::{LdapTransaction, ALPROTO_LDAP};
+use super::types::{LdapMessage, ProtocolOp};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferMpmRegister, DetectHelperGetData,
+    DetectHelperKeywordRegister, DetectSignatureSetAppProto, SCSigTableElmt,
+    SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+static mut G_LDAP_DN_BUFFER_ID: c_int = 0;
+static mut G_LDAP_FILTER_BUFFER_ID: c_int = 0;
+
+/// Get the DN a request operates on: the name of a bind, the base of a
+/// search or the entry of the update operations.
+fn ldap_request_get_dn(request: &LdapMessage) -> Option<&str> {
+    let dn = match &request.protocol_op {
+        ProtocolOp::BindRequest(req) => &req.name,
+        ProtocolOp::SearchRequest(req) => &req.base_object,
+        ProtocolOp::ModifyRequest(req) => &req.object,
+        ProtocolOp::AddRequest(req) => &req.entry,
+        ProtocolOp::DelRequest(dn) => dn,
+        ProtocolOp::ModDnRequest(req) => &req.entry,
+        ProtocolOp::CompareRequest(req) => &req.entry,
+        _ => return None,
+    };
+    Some(&dn.0)
+}
+
+unsafe extern "C" fn ldap_dn_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_LDAP) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_LDAP_DN_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ldap_dn_get(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, LdapTransaction);
+    if let Some(dn) = tx.request.as_ref().and_then(ldap_request_get_dn) {
+        *buffer = dn.as_ptr();
+        *buffer_len = dn.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn ldap_dn_get_data(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(de, transforms, flow, flow_flags, tx, list_id, ldap_dn_get);
+}
+
+unsafe extern "C" fn ldap_filter_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_LDAP) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_LDAP_FILTER_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ldap_filter_get(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, LdapTransaction);
+    if let Some(filter) = &tx.search_filter {
+        *buffer = filter.as_ptr();
+        *buffer_len = filter.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn ldap_filter_get_data(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ldap_filter_get,
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectLdapRegister() {
+    let kw = SCSigTableElmt {
+        name: b"ldap.dn\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the DN of LDAP requests\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ldap-keywords.html#ldap-dn\0".as_ptr() as *const libc::c_char,
+        Setup: ldap_dn_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ldap_dn_kw_id = DetectHelperKeywordRegister(&kw);
+    G_LDAP_DN_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ldap.dn\0".as_ptr() as *const libc::c_char,
+        b"LDAP DN\0".as_ptr() as *const libc::c_char,
+        ALPROTO_LDAP,
+        false,
+        true,
+        ldap_dn_get_data,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ldap.filter\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the filter of LDAP search requests\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ldap-keywords.html#ldap-filter\0".as_ptr() as *const libc::c_char,
+        Setup: ldap_filter_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ldap_filter_kw_id = DetectHelperKeywordRegister(&kw);
+    G_LDAP_FILTER_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ldap.filter\0".as_ptr() as *const libc::c_char,
+        b"LDAP search filter\0".as_ptr() as *const libc::c_char,
+        ALPROTO_LDAP,
+        false,
+        true,
+        ldap_filter_get_data,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldap::types::{LdapDN, MessageID};
+
+    #[test]
+    fn test_ldap_request_get_dn() {
+        let message = |protocol_op| LdapMessage {
+            message_id: MessageID(1),
+            protocol_op,
+            controls: None,
+        };
+        let request = message(ProtocolOp::DelRequest(LdapDN(
+            "cn=user,dc=example,dc=com".to_string(),
+        )));
+        assert_eq!(
+            ldap_request_get_dn(&request),
+            Some("cn=user,dc=example,dc=com")
+        );
+        assert_eq!(
+            ldap_request_get_dn(&message(ProtocolOp::UnbindRequest)),
+            None
+        );
+    }
+}
@@ -18,6 +18,8 @@
 // written by Giuseppe Longo <giuseppe@glongo.it>
 
 use crate::ldap::types::LdapString;
+use std::fmt;
+use std::fmt::{Display, Formatter};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Filter {
@@ -117,6 +119,77 @@ impl<'a> From<ldap_parser::filter::Filter<'a>> for Filter {
     }
 }
 
+/// Write an assertion value, escaping the special characters and the
+/// non printable bytes (RFC 4515, section 3).
+fn write_assertion_value(f: &mut Formatter<'_>, value: &[u8]) -> fmt::Result {
+    for &b in value {
+        match b {
+            b'*' | b'(' | b')' | b'\\' | 0..=0x1f | 0x7f..=0xff => write!(f, "\\{:02x}", b)?,
+            _ => write!(f, "{}", b as char)?,
+        }
+    }
+    Ok(())
+}
+
+/// String representation of a filter (RFC 4515).
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::And(filters) | Filter::Or(filters) => {
+                let op = if let Filter::And(_) = self { '&' } else { '|' };
+                write!(f, "({}", op)?;
+                for filter in filters {
+                    write!(f, "{}", filter)?;
+                }
+                write!(f, ")")
+            }
+            Filter::Not(filter) => write!(f, "(!{})", filter),
+            Filter::EqualityMatch(ava) => ava.fmt_with_op(f, "="),
+            Filter::GreaterOrEqual(ava) => ava.fmt_with_op(f, ">="),
+            Filter::LessOrEqual(ava) => ava.fmt_with_op(f, "<="),
+            Filter::ApproxMatch(ava) => ava.fmt_with_op(f, "~="),
+            Filter::Present(attr) => write!(f, "({}=*)", attr.0),
+            Filter::Substrings(sub) => {
+                write!(f, "({}=", sub.filter_type.0)?;
+                let mut star = false;
+                for substring in &sub.substrings {
+                    match substring {
+                        Substring::Initial(val) => write_assertion_value(f, &val.0)?,
+                        Substring::Any(val) => {
+                            write!(f, "*")?;
+                            write_assertion_value(f, &val.0)?;
+                        }
+                        Substring::Final(val) => {
+                            write!(f, "*")?;
+                            write_assertion_value(f, &val.0)?;
+                            star = true;
+                        }
+                    }
+                }
+                if !star {
+                    write!(f, "*")?;
+                }
+                write!(f, ")")
+            }
+            Filter::ExtensibleMatch(mra) => {
+                write!(f, "(")?;
+                if let Some(rule_type) = &mra.rule_type {
+                    write!(f, "{}", rule_type.0)?;
+                }
+                if mra.dn_attributes == Some(true) {
+                    write!(f, ":dn")?;
+                }
+                if let Some(rule) = &mra.matching_rule {
+                    write!(f, ":{}", rule.0)?;
+                }
+                write!(f, ":=")?;
+                write_assertion_value(f, &mra.assertion_value.0)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PartialAttribute {
     pub attr_type: LdapString,
@@ -166,6 +239,14 @@ pub struct AttributeValueAssertion {
     pub attribute_desc: LdapString,
     pub assertion_value: Vec<u8>,
 }
+impl AttributeValueAssertion {
+    fn fmt_with_op(&self, f: &mut Formatter<'_>, op: &str) -> fmt::Result {
+        write!(f, "({}{}", self.attribute_desc.0, op)?;
+        write_assertion_value(f, &self.assertion_value)?;
+        write!(f, ")")
+    }
+}
+
 impl<'a> From<&ldap_parser::filter::AttributeValueAssertion<'a>> for AttributeValueAssertion {
     fn from(value: &ldap_parser::filter::AttributeValueAssertion) -> Self {
         let attribute_desc = LdapString(value.attribute_desc.0.to_string());
@@ -224,3 +305,56 @@ pub struct AssertionValue(pub Vec<u8>);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AttributeValue(pub Vec<u8>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ava(attr: &str, value: &[u8]) -> AttributeValueAssertion {
+        AttributeValueAssertion {
+            attribute_desc: LdapString(attr.to_string()),
+            assertion_value: value.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_ldap_filter_to_string() {
+        let filter = Filter::And(vec![
+            Filter::EqualityMatch(ava("objectClass", b"user")),
+            Filter::Not(Box::new(Filter::Present(LdapString("mail".to_string())))),
+            Filter::Or(vec![
+                Filter::GreaterOrEqual(ava("uidNumber", b"1000")),
+                Filter::ApproxMatch(ava("cn", b"a*(b)\\")),
+            ]),
+        ]);
+        assert_eq!(
+            filter.to_string(),
+            "(&(objectClass=user)(!(mail=*))(|(uidNumber>=1000)(cn~=a\\2a\\28b\\29\\5c)))"
+        );
+
+        let filter = Filter::Substrings(SubstringFilter {
+            filter_type: LdapString("cn".to_string()),
+            substrings: vec![
+                Substring::Initial(AssertionValue(b"adm".to_vec())),
+                Substring::Any(AssertionValue(b"in".to_vec())),
+            ],
+        });
+        assert_eq!(filter.to_string(), "(cn=adm*in*)");
+        let filter = Filter::Substrings(SubstringFilter {
+            filter_type: LdapString("cn".to_string()),
+            substrings: vec![Substring::Final(AssertionValue(b"svc".to_vec()))],
+        });
+        assert_eq!(filter.to_string(), "(cn=*svc)");
+
+        let filter = Filter::ExtensibleMatch(MatchingRuleAssertion {
+            matching_rule: Some(LdapString("1.2.840.113556.1.4.803".to_string())),
+            rule_type: Some(AttributeDescription("userAccountControl".to_string())),
+            assertion_value: AssertionValue(b"2".to_vec()),
+            dn_attributes: None,
+        });
+        assert_eq!(
+            filter.to_string(),
+            "(userAccountControl:1.2.840.113556.1.4.803:=2)"
+        );
+    }
+}
@@ -33,7 +33,11 @@ static LDAP_MAX_TX_DEFAULT: usize = 256;
 
 static mut LDAP_MAX_TX: usize = LDAP_MAX_TX_DEFAULT;
 
-static mut ALPROTO_LDAP: AppProto = ALPROTO_UNKNOWN;
+static LDAP_MAX_ROOTDSE_SEARCHES_DEFAULT: u32 = 16;
+
+static mut LDAP_MAX_ROOTDSE_SEARCHES: u32 = LDAP_MAX_ROOTDSE_SEARCHES_DEFAULT;
+
+pub(super) static mut ALPROTO_LDAP: AppProto = ALPROTO_UNKNOWN;
 
 #[derive(AppLayerFrameType)]
 pub enum LdapFrameType {
@@ -46,6 +50,8 @@ enum LdapEvent {
     InvalidData,
     RequestNotFound,
     IncompleteData,
+    AnonymousBind,
+    RootdseSearchFlood,
 }
 
 #[derive(Debug)]
@@ -53,6 +59,8 @@ pub struct LdapTransaction {
     pub tx_id: u64,
     pub request: Option<LdapMessage>,
     pub responses: VecDeque<LdapMessage>,
+    /// Filter of a search request, in its string representation.
+    pub search_filter: Option<String>,
     complete: bool,
 
     tx_data: AppLayerTxData,
@@ -70,6 +78,7 @@ impl LdapTransaction {
             tx_id: 0,
             request: None,
             responses: VecDeque::new(),
+            search_filter: None,
             complete: false,
             tx_data: AppLayerTxData::new(),
         }
@@ -92,6 +101,7 @@ pub struct LdapState {
     response_frame: Option<Frame>,
     request_gap: bool,
     response_gap: bool,
+    rootdse_searches: u32,
 }
 
 impl State<LdapTransaction> for LdapState {
@@ -115,6 +125,7 @@ impl LdapState {
             response_frame: None,
             request_gap: false,
             response_gap: false,
+            rootdse_searches: 0,
         }
     }
 
@@ -168,6 +179,29 @@ impl LdapState {
         }
     }
 
+    /// Set the search filter of the transaction and raise the events
+    /// about anonymous binds and rootDSE mass searches.
+    fn inspect_request(&mut self, tx: &mut LdapTransaction, request: &LdapMessage) {
+        match &request.protocol_op {
+            ProtocolOp::BindRequest(bind) => {
+                if ldap_bind_is_anonymous(bind) {
+                    tx.tx_data.set_event(LdapEvent::AnonymousBind as u8);
+                }
+            }
+            ProtocolOp::SearchRequest(search) => {
+                tx.search_filter = Some(search.filter.to_string());
+                if ldap_search_is_rootdse(search) {
+                    self.rootdse_searches = self.rootdse_searches.saturating_add(1);
+                    // only raised once per flow
+                    if self.rootdse_searches == unsafe { LDAP_MAX_ROOTDSE_SEARCHES } + 1 {
+                        tx.tx_data.set_event(LdapEvent::RootdseSearchFlood as u8);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn find_request(&mut self, message_id: MessageID) -> Option<&mut LdapTransaction> {
         self.transactions.iter_mut().find(|tx| {
             tx.request
@@ -217,6 +251,7 @@ impl LdapState {
                     let tx_id = tx.id();
                     let request = LdapMessage::from(msg);
                     tx.complete = tx_is_complete(&request.protocol_op, Direction::ToServer);
+                    self.inspect_request(&mut tx, &request);
                     tx.request = Some(request);
                     self.transactions.push_back(tx);
                     let consumed = start.len() - rem.len();
@@ -337,6 +372,7 @@ impl LdapState {
                 let mut tx = self.new_tx();
                 let request = LdapMessage::from(msg);
                 tx.complete = tx_is_complete(&request.protocol_op, Direction::ToServer);
+                self.inspect_request(&mut tx, &request);
                 tx.request = Some(request);
                 self.transactions.push_back(tx);
             }
@@ -444,6 +480,18 @@ impl LdapState {
     }
 }
 
+/// A simple bind without password is an anonymous bind, or an
+/// unauthenticated one if a name is given (RFC 4513, section 5.1).
+fn ldap_bind_is_anonymous(bind: &BindRequest) -> bool {
+    matches!(&bind.authentication, AuthenticationChoice::Simple(password) if password.is_empty())
+}
+
+/// A base search with an empty base DN reads the rootDSE, which tells
+/// about the naming contexts and the supported features of the server.
+fn ldap_search_is_rootdse(search: &SearchRequest) -> bool {
+    search.base_object.0.is_empty() && search.scope.0 == 0
+}
+
 fn tx_is_complete(op: &ProtocolOp, dir: Direction) -> bool {
     match dir {
         Direction::ToServer => match op {
@@ -655,7 +703,7 @@ pub unsafe extern "C" fn SCRegisterLdapTcpParser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_LDAP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.ldap.max-tx") {
             if let Ok(v) = val.parse::<usize>() {
@@ -666,6 +714,15 @@ pub unsafe extern "C" fn SCRegisterLdapTcpParser() {
                 SCLogError!("Invalid value for ldap.max-tx");
             }
         }
+        if let Some(val) = conf_get("app-layer.protocols.ldap.max-rootdse-searches") {
+            if let Ok(v) = val.parse::<u32>() {
+                if LDAP_MAX_ROOTDSE_SEARCHES == LDAP_MAX_ROOTDSE_SEARCHES_DEFAULT {
+                    LDAP_MAX_ROOTDSE_SEARCHES = v;
+                }
+            } else {
+                SCLogError!("Invalid value for ldap.max-rootdse-searches");
+            }
+        }
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_LDAP);
     } else {
         SCLogDebug!("Protocol detection and parser disabled for LDAP/TCP.");
@@ -712,7 +769,7 @@ pub unsafe extern "C" fn SCRegisterLdapUdpParser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_LDAP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.ldap.max-tx") {
             if let Ok(v) = val.parse::<usize>() {
@@ -723,8 +780,94 @@ pub unsafe extern "C" fn SCRegisterLdapUdpParser() {
                 SCLogError!("Invalid value for ldap.max-tx");
             }
         }
+        if let Some(val) = conf_get("app-layer.protocols.ldap.max-rootdse-searches") {
+            if let Ok(v) = val.parse::<u32>() {
+                if LDAP_MAX_ROOTDSE_SEARCHES == LDAP_MAX_ROOTDSE_SEARCHES_DEFAULT {
+                    LDAP_MAX_ROOTDSE_SEARCHES = v;
+                }
+            } else {
+                SCLogError!("Invalid value for ldap.max-rootdse-searches");
+            }
+        }
         AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_LDAP);
     } else {
         SCLogDebug!("Protocol detection and parser disabled for LDAP/UDP.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ldap::filters::Filter;
+
+    #[test]
+    fn test_ldap_bind_is_anonymous() {
+        let bind = |name: &str, authentication| BindRequest {
+            version: 3,
+            name: LdapDN(name.to_string()),
+            authentication,
+        };
+        assert!(ldap_bind_is_anonymous(&bind(
+            "",
+            AuthenticationChoice::Simple(Vec::new())
+        )));
+        // unauthenticated bind
+        assert!(ldap_bind_is_anonymous(&bind(
+            "cn=admin,dc=example,dc=com",
+            AuthenticationChoice::Simple(Vec::new())
+        )));
+        assert!(!ldap_bind_is_anonymous(&bind(
+            "cn=admin,dc=example,dc=com",
+            AuthenticationChoice::Simple(b"secret".to_vec())
+        )));
+        assert!(!ldap_bind_is_anonymous(&bind(
+            "",
+            AuthenticationChoice::Sasl(SaslCredentials {
+                mechanism: LdapString("GSSAPI".to_string()),
+                credentials: None,
+            })
+        )));
+    }
+
+    #[test]
+    fn test_ldap_search_is_rootdse() {
+        let search = |base: &str, scope| SearchRequest {
+            base_object: LdapDN(base.to_string()),
+            scope: SearchScope(scope),
+            deref_aliases: DerefAliases(0),
+            size_limit: 0,
+            time_limit: 0,
+            types_only: false,
+            filter: Filter::Present(LdapString("objectClass".to_string())),
+            attributes: Vec::new(),
+        };
+        assert!(ldap_search_is_rootdse(&search("", 0)));
+        assert!(!ldap_search_is_rootdse(&search("", 2)));
+        assert!(!ldap_search_is_rootdse(&search("dc=example,dc=com", 0)));
+    }
+
+    #[test]
+    fn test_ldap_inspect_request() {
+        let mut state = LdapState::new();
+        let request = LdapMessage {
+            message_id: MessageID(1),
+            protocol_op: ProtocolOp::SearchRequest(SearchRequest {
+                base_object: LdapDN(String::new()),
+                scope: SearchScope(0),
+                deref_aliases: DerefAliases(0),
+                size_limit: 0,
+                time_limit: 0,
+                types_only: false,
+                filter: Filter::Present(LdapString("objectClass".to_string())),
+                attributes: Vec::new(),
+            }),
+            controls: None,
+        };
+        for _ in 0..3 {
+            let mut tx = state.new_tx();
+            state.inspect_request(&mut tx, &request);
+            assert_eq!(tx.search_filter.as_deref(), Some("(objectClass=*)"));
+        }
+        assert_eq!(state.rootdse_searches, 3);
+    }
+}
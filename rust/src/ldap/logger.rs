@@ -99,6 +99,7 @@ fn log_search_request(msg: &SearchRequest, js: &mut JsonBuilder) -> Result<(), J
         js.set_string("value", &val.0.to_string())?;
         js.close()?;
     }
+    js.set_string("filter_string", &msg.filter.to_string())?;
     if !msg.attributes.is_empty() {
         js.open_array("attributes")?;
         for attr in &msg.attributes {
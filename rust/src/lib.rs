@@ -81,10 +81,15 @@ pub mod core;
 #[macro_use]
 pub mod common;
 pub mod conf;
+pub mod datasets;
 pub mod jsonbuilder;
 #[macro_use]
 pub mod applayer;
+pub mod applayer_bytes;
 pub mod frames;
+pub mod anomaly_summary;
+pub mod fingerprint;
+pub mod eve_sampler;
 pub mod filecontainer;
 pub mod filetracker;
 pub mod kerberos;
@@ -132,6 +137,17 @@ pub mod ffi;
 pub mod feature;
 pub mod sdp;
 pub mod ldap;
+pub mod imap;
+pub mod pop3;
+pub mod rsync;
+pub mod openvpn;
+pub mod wireguard;
+pub mod stun;
+pub mod syslog;
+pub mod dhcpv6;
+pub mod gre;
+pub mod rtp;
+pub mod expectation;
 
 #[allow(unused_imports)]
 pub use suricata_lua_sys;
@@ -416,7 +416,7 @@ pub unsafe extern "C" fn rs_modbus_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_MODBUS = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_MODBUS);
     }
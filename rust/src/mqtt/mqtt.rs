@@ -799,7 +799,7 @@ pub unsafe extern "C" fn SCMqttRegisterParser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_MQTT = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.mqtt.max-tx") {
             if let Ok(v) = val.parse::<usize>() {
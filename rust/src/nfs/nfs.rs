@@ -1957,10 +1957,11 @@ pub unsafe extern "C" fn rs_nfs_probe_udp_tc(_f: *const Flow,
 
 // Parser name as a C style string.
 const PARSER_NAME: &[u8] = b"nfs\0";
+/// Detection port if none is configured.
+const NFS_DEFAULT_PORT: &str = "[2049]";
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_nfs_register_parser() {
-    let default_port = CString::new("[2049]").unwrap();
     let parser = RustParser {
         name: PARSER_NAME.as_ptr() as *const std::os::raw::c_char,
         default_port: std::ptr::null(),
@@ -2003,31 +2004,20 @@ pub unsafe extern "C" fn rs_nfs_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_NFS = alproto;
 
-        let midstream = conf_get_bool("stream.midstream");
-        if midstream {
-            if AppLayerProtoDetectPPParseConfPorts(ip_proto_str.as_ptr(), IPPROTO_TCP,
-                    parser.name, ALPROTO_NFS, 0, NFS_MIN_FRAME_LEN,
-                    rs_nfs_probe_ms, rs_nfs_probe_ms) == 0 {
-                SCLogDebug!("No NFSTCP app-layer configuration, enabling NFSTCP detection TCP detection on port {:?}.",
-                            default_port);
-                /* register 'midstream' probing parsers if midstream is enabled. */
-                AppLayerProtoDetectPPRegister(IPPROTO_TCP,
-                    default_port.as_ptr(), ALPROTO_NFS, 0,
-                    NFS_MIN_FRAME_LEN, Direction::ToServer.into(),
-                    rs_nfs_probe_ms, rs_nfs_probe_ms);
-            }
+        /* register 'midstream' probing parsers if midstream is enabled. */
+        let probe: ProbeFn = if conf_get_bool("stream.midstream") {
+            rs_nfs_probe_ms
         } else {
-            AppLayerProtoDetectPPRegister(IPPROTO_TCP,
-                default_port.as_ptr(), ALPROTO_NFS, 0,
-                NFS_MIN_FRAME_LEN, Direction::ToServer.into(),
-                rs_nfs_probe, rs_nfs_probe);
-        }
+            rs_nfs_probe
+        };
+        applayer_register_probing_ports(IPPROTO_TCP, parser.name, ALPROTO_NFS, 0,
+                NFS_MIN_FRAME_LEN, probe, probe, Some(NFS_DEFAULT_PORT));
         if AppLayerParserConfParserEnabled(
             ip_proto_str.as_ptr(),
             parser.name,
         ) != 0
         {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust nfs parser registered.");
     } else {
@@ -2037,7 +2027,6 @@ pub unsafe extern "C" fn rs_nfs_register_parser() {
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_nfs_udp_register_parser() {
-    let default_port = CString::new("[2049]").unwrap();
     let parser = RustParser {
         name: PARSER_NAME.as_ptr() as *const std::os::raw::c_char,
         default_port: std::ptr::null(),
@@ -2080,22 +2069,15 @@ pub unsafe extern "C" fn rs_nfs_udp_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_NFS = alproto;
 
-        if AppLayerProtoDetectPPParseConfPorts(ip_proto_str.as_ptr(), IPPROTO_UDP,
-                parser.name, ALPROTO_NFS, 0, NFS_MIN_FRAME_LEN,
-                rs_nfs_probe_udp_ts, rs_nfs_probe_udp_tc) == 0 {
-            SCLogDebug!("No NFSUDP app-layer configuration, enabling NFSUDP detection UDP detection on port {:?}.",
-                        default_port);
-            AppLayerProtoDetectPPRegister(IPPROTO_UDP,
-                default_port.as_ptr(), ALPROTO_NFS, 0,
-                NFS_MIN_FRAME_LEN, Direction::ToServer.into(),
-                rs_nfs_probe_udp_ts, rs_nfs_probe_udp_tc);
-        }
+        applayer_register_probing_ports(IPPROTO_UDP, parser.name, ALPROTO_NFS, 0,
+                NFS_MIN_FRAME_LEN, rs_nfs_probe_udp_ts, rs_nfs_probe_udp_tc,
+                Some(NFS_DEFAULT_PORT));
         if AppLayerParserConfParserEnabled(
             ip_proto_str.as_ptr(),
             parser.name,
         ) != 0
         {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if let Some(val) = conf_get("app-layer.protocols.nfs.max-tx") {
             if let Ok(v) = val.parse::<usize>() {
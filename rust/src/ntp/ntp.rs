@@ -310,7 +310,7 @@ pub unsafe extern "C" fn rs_register_ntp_parser() {
         // store the allocated ID for the probe function
         ALPROTO_NTP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
     } else {
         SCLogDebug!("Protocol detector and parser disabled for NTP.");
@@ -0,0 +1,55 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::openvpn::OpenVpnTransaction;
+use super::parser::{self, OpenVpnWrapping};
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_openvpn(tx: &OpenVpnTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("openvpn")?;
+    if let Some(ref sid) = tx.client_session_id {
+        js.set_hex("client_session_id", sid)?;
+    }
+    if let Some(ref sid) = tx.server_session_id {
+        js.set_hex("server_session_id", sid)?;
+    }
+    if let Some(opcode) = tx.client_reset.and_then(parser::opcode_string) {
+        js.set_string("client_reset", opcode)?;
+    }
+    if let Some(opcode) = tx.server_reset.and_then(parser::opcode_string) {
+        js.set_string("server_reset", opcode)?;
+    }
+    if let Some(wrapping) = tx.wrapping {
+        js.set_string("wrapping", wrapping.as_str())?;
+        if let OpenVpnWrapping::TlsAuth(hmac_size) = wrapping {
+            js.set_uint("hmac_size", hmac_size as u64)?;
+        }
+    }
+    js.set_string("fingerprint", &tx.fingerprint())?;
+    js.set_bool("data", tx.data)?;
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCOpenVpnLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, OpenVpnTransaction);
+    log_openvpn(tx, js).is_ok()
+}
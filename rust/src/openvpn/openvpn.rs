@@ -0,0 +1,648 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, OpenVpnWrapping};
+use crate::applayer::{self, *};
+use crate::core::{
+    AppProto, Direction, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, DIR_BOTH, IPPROTO_TCP, IPPROTO_UDP,
+};
+use crate::frames::Frame;
+use nom7 as nom;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(super) static mut ALPROTO_OPENVPN: AppProto = ALPROTO_UNKNOWN;
+
+/// Number of packets making up the opcode sequence fingerprint.
+pub const OPENVPN_FINGERPRINT_LEN: usize = 8;
+
+#[derive(AppLayerFrameType)]
+pub enum OpenVpnFrameType {
+    /// A packet, not including the length prefix over TCP.
+    Pdu,
+}
+
+#[derive(AppLayerEvent)]
+pub enum OpenVpnEvent {
+    /// Packet too short or with an unknown opcode.
+    MalformedPacket,
+    /// Hard reset that is not a valid control packet.
+    InvalidHardReset,
+    /// Hard reset wrapped differently than the session.
+    WrappingMismatch,
+}
+
+#[derive(Debug, Default)]
+pub struct OpenVpnTransaction {
+    tx_id: u64,
+    pub client_session_id: Option<Vec<u8>>,
+    pub server_session_id: Option<Vec<u8>>,
+    /// Opcode of the first client hard reset.
+    pub client_reset: Option<u8>,
+    /// Opcode of the first server hard reset.
+    pub server_reset: Option<u8>,
+    pub wrapping: Option<OpenVpnWrapping>,
+    /// Opcodes of the first packets in both directions.
+    pub opcodes: Vec<(Direction, u8)>,
+    /// Data channel packets were seen.
+    pub data: bool,
+    complete: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for OpenVpnTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+impl OpenVpnTransaction {
+    /// The opcode sequence, e.g. "c7-s8-c5-c4".
+    pub fn fingerprint(&self) -> String {
+        self.opcodes
+            .iter()
+            .map(|(direction, opcode)| {
+                let d = if *direction == Direction::ToServer {
+                    'c'
+                } else {
+                    's'
+                };
+                format!("{}{}", d, opcode)
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[derive(Default)]
+pub struct OpenVpnState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<OpenVpnTransaction>,
+}
+
+impl State<OpenVpnTransaction> for OpenVpnState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&OpenVpnTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl OpenVpnState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&OpenVpnTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    /// Return the session transaction. Only the start of the session is
+    /// inspected, so there is a single transaction per flow.
+    fn get_current_tx(&mut self) -> &mut OpenVpnTransaction {
+        if self.transactions.is_empty() {
+            self.tx_id += 1;
+            let tx = OpenVpnTransaction {
+                tx_id: self.tx_id,
+                ..Default::default()
+            };
+            self.transactions.push_back(tx);
+        }
+        // checked above
+        self.transactions.back_mut().unwrap()
+    }
+
+    fn set_event(&mut self, event: OpenVpnEvent) {
+        self.get_current_tx().tx_data.set_event(event as u8);
+    }
+
+    /// Close the open transaction, if there is one.
+    fn finish(&mut self) {
+        if let Some(tx) = self.transactions.back_mut() {
+            tx.complete = true;
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.transactions.back().map_or(false, |tx| tx.complete)
+    }
+
+    fn handle_packet(&mut self, packet: &[u8], direction: Direction) {
+        let (rem, hdr) = match parser::parse_header(packet) {
+            Ok(r) => r,
+            Err(_) => {
+                self.set_event(OpenVpnEvent::MalformedPacket);
+                return;
+            }
+        };
+        if !parser::is_valid_opcode(hdr.opcode) {
+            self.set_event(OpenVpnEvent::MalformedPacket);
+            return;
+        }
+
+        let tx = self.get_current_tx();
+        // A client hard reset of another session restarts the handshake,
+        // e.g. after it failed: the session logged is over.
+        if direction == Direction::ToServer
+            && parser::is_hard_reset_client(hdr.opcode)
+            && tx.client_session_id.is_some()
+            && tx.client_session_id.as_deref() != hdr.session_id
+        {
+            tx.complete = true;
+            return;
+        }
+        if tx.opcodes.len() < OPENVPN_FINGERPRINT_LEN {
+            tx.opcodes.push((direction, hdr.opcode));
+        }
+        if parser::is_data_opcode(hdr.opcode) {
+            tx.data = true;
+            // Past the control channel setup.
+            tx.complete = true;
+            return;
+        }
+        if hdr.opcode == parser::P_CONTROL_SOFT_RESET_V1 {
+            // Renegotiation of the keys, the handshake is over.
+            tx.complete = true;
+            return;
+        }
+        if let Some(session_id) = hdr.session_id {
+            let sid = if direction == Direction::ToServer {
+                &mut tx.client_session_id
+            } else {
+                &mut tx.server_session_id
+            };
+            if sid.is_none() {
+                *sid = Some(session_id.to_vec());
+            }
+        }
+
+        let reset = if direction == Direction::ToServer {
+            parser::is_hard_reset_client(hdr.opcode)
+        } else {
+            parser::is_hard_reset_server(hdr.opcode)
+        };
+        if reset {
+            let reset_opcode = if direction == Direction::ToServer {
+                &mut tx.client_reset
+            } else {
+                &mut tx.server_reset
+            };
+            if reset_opcode.is_none() {
+                *reset_opcode = Some(hdr.opcode);
+            }
+            match (parser::detect_wrapping(hdr.opcode, rem), tx.wrapping) {
+                (None, _) => {
+                    tx.tx_data.set_event(OpenVpnEvent::InvalidHardReset as u8);
+                }
+                (Some(wrapping), None) => {
+                    tx.wrapping = Some(wrapping);
+                }
+                (Some(wrapping), Some(current)) => {
+                    // tls-crypt-v2 servers reply with a tls-crypt wrapped
+                    // reset.
+                    let v2 = current == OpenVpnWrapping::TlsCryptV2
+                        && wrapping == OpenVpnWrapping::TlsCrypt;
+                    if wrapping != current && !v2 {
+                        tx.tx_data.set_event(OpenVpnEvent::WrappingMismatch as u8);
+                    }
+                }
+            }
+        }
+        if tx.opcodes.len() >= OPENVPN_FINGERPRINT_LEN {
+            tx.complete = true;
+        }
+    }
+
+    fn parse_udp(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        if self.is_complete() {
+            return AppLayerResult::ok();
+        }
+        let input = stream_slice.as_slice();
+        let _pdu = Frame::new(
+            flow,
+            stream_slice,
+            input,
+            input.len() as i64,
+            OpenVpnFrameType::Pdu as u8,
+            None,
+        );
+        self.handle_packet(input, direction);
+        AppLayerResult::ok()
+    }
+
+    fn parse_tcp(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        let mut start = input;
+        while !start.is_empty() {
+            if self.is_complete() {
+                return AppLayerResult::ok();
+            }
+            match parser::parse_tcp_packet(start) {
+                Ok((rem, packet)) => {
+                    let _pdu = Frame::new(
+                        flow,
+                        stream_slice,
+                        packet,
+                        packet.len() as i64,
+                        OpenVpnFrameType::Pdu as u8,
+                        None,
+                    );
+                    start = rem;
+                    self.handle_packet(packet, direction);
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    let consumed = input.len() - start.len();
+                    let needed = match start.get(..2) {
+                        Some(len) => 2 + u16::from_be_bytes([len[0], len[1]]) as usize,
+                        None => 2,
+                    };
+                    return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                }
+                Err(_) => {
+                    return AppLayerResult::err();
+                }
+            }
+        }
+        // Input was fully consumed.
+        return AppLayerResult::ok();
+    }
+}
+
+/// Probe for a hard reset, in either direction. Returns the direction the
+/// packet was sent in.
+fn probe(packet: &[u8]) -> Option<Direction> {
+    let (rem, hdr) = parser::parse_header(packet).ok()?;
+    // A new session starts with key id 0.
+    if hdr.key_id != 0 {
+        return None;
+    }
+    let direction = if parser::is_hard_reset_client(hdr.opcode) {
+        Direction::ToServer
+    } else if parser::is_hard_reset_server(hdr.opcode) {
+        Direction::ToClient
+    } else {
+        return None;
+    };
+    parser::detect_wrapping(hdr.opcode, rem)?;
+    Some(direction)
+}
+
+unsafe fn probe_result(probed: Option<Direction>, direction: u8, rdir: *mut u8) -> AppProto {
+    match probed {
+        Some(dir) => {
+            if (direction & DIR_BOTH) != dir.into() {
+                *rdir = dir as u8;
+            }
+            ALPROTO_OPENVPN
+        }
+        None => ALPROTO_FAILED,
+    }
+}
+
+// C exports.
+
+unsafe extern "C" fn rs_openvpn_probing_parser_udp(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    probe_result(probe(slice), direction, rdir)
+}
+
+unsafe extern "C" fn rs_openvpn_probing_parser_tcp(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    match parser::parse_tcp_packet(slice) {
+        Ok((_, packet)) => probe_result(probe(packet), direction, rdir),
+        Err(nom::Err::Incomplete(_)) => ALPROTO_UNKNOWN,
+        Err(_) => ALPROTO_FAILED,
+    }
+}
+
+extern "C" fn rs_openvpn_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = OpenVpnState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_openvpn_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut OpenVpnState));
+}
+
+unsafe extern "C" fn rs_openvpn_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, OpenVpnState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_openvpn_parse_request_udp(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, OpenVpnState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse_udp(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_openvpn_parse_response_udp(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, OpenVpnState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse_udp(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_openvpn_parse_request_tcp(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, OpenVpnState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse_tcp(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_openvpn_parse_response_tcp(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, OpenVpnState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse_tcp(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_openvpn_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, OpenVpnState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_openvpn_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, OpenVpnState);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn rs_openvpn_tx_get_alstate_progress(tx: *mut c_void, _direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, OpenVpnTransaction);
+    if tx.complete {
+        return 1;
+    }
+    return 0;
+}
+
+export_tx_data_get!(rs_openvpn_get_tx_data, OpenVpnTransaction);
+export_state_data_get!(rs_openvpn_get_state_data, OpenVpnState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"openvpn\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterOpenVpnParser() {
+    let default_port = CString::new("[1194]").unwrap();
+    let mut parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: Some(rs_openvpn_probing_parser_udp),
+        probe_tc: Some(rs_openvpn_probing_parser_udp),
+        min_depth: 0,
+        max_depth: 16,
+        state_new: rs_openvpn_state_new,
+        state_free: rs_openvpn_state_free,
+        tx_free: rs_openvpn_state_tx_free,
+        parse_ts: rs_openvpn_parse_request_udp,
+        parse_tc: rs_openvpn_parse_response_udp,
+        get_tx_count: rs_openvpn_state_get_tx_count,
+        get_tx: rs_openvpn_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_openvpn_tx_get_alstate_progress,
+        get_eventinfo: Some(OpenVpnEvent::get_event_info),
+        get_eventinfo_byid: Some(OpenVpnEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<OpenVpnState, OpenVpnTransaction>),
+        get_tx_data: rs_openvpn_get_tx_data,
+        get_state_data: rs_openvpn_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(OpenVpnFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(OpenVpnFrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_OPENVPN = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_OPENVPN);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for OpenVPN/UDP.");
+    }
+
+    parser.ipproto = IPPROTO_TCP;
+    parser.probe_ts = Some(rs_openvpn_probing_parser_tcp);
+    parser.probe_tc = Some(rs_openvpn_probing_parser_tcp);
+    parser.parse_ts = rs_openvpn_parse_request_tcp;
+    parser.parse_tc = rs_openvpn_parse_response_tcp;
+    let ip_proto_str = CString::new("tcp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_OPENVPN = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_OPENVPN);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for OpenVPN/TCP.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CLIENT_RESET: &[u8] = &[
+        0x38, 0x8e, 0x3f, 0x72, 0x0c, 0x19, 0x2b, 0x5d, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    const SERVER_RESET: &[u8] = &[
+        0x40, 0x27, 0x9b, 0x3a, 0x10, 0x44, 0x5e, 0x01, 0x7c, 0x01, 0x00, 0x00, 0x00, 0x00, 0x8e,
+        0x3f, 0x72, 0x0c, 0x19, 0x2b, 0x5d, 0x61, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    fn parse_udp(state: &mut OpenVpnState, direction: Direction, buf: &[u8]) {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse_udp(std::ptr::null(), &ss, direction);
+    }
+
+    #[test]
+    fn test_openvpn_probe() {
+        assert_eq!(probe(CLIENT_RESET), Some(Direction::ToServer));
+        assert_eq!(probe(SERVER_RESET), Some(Direction::ToClient));
+        // key id 1
+        let mut buf = CLIENT_RESET.to_vec();
+        buf[0] |= 0x01;
+        assert_eq!(probe(&buf), None);
+        // P_CONTROL_V1
+        assert_eq!(probe(&[0x20; 14]), None);
+        assert_eq!(probe(&CLIENT_RESET[..5]), None);
+    }
+
+    #[test]
+    fn test_openvpn_session() {
+        let mut state = OpenVpnState::new();
+        parse_udp(&mut state, Direction::ToServer, CLIENT_RESET);
+        parse_udp(&mut state, Direction::ToClient, SERVER_RESET);
+        // P_ACK_V1 acking the server reset.
+        let mut ack = vec![0x28];
+        ack.extend_from_slice(&CLIENT_RESET[1..9]);
+        ack.extend_from_slice(&[0x01, 0x00, 0x00, 0x00, 0x00]);
+        ack.extend_from_slice(&SERVER_RESET[1..9]);
+        parse_udp(&mut state, Direction::ToServer, &ack);
+        parse_udp(
+            &mut state,
+            Direction::ToServer,
+            &[0x48, 0x00, 0x00, 0x01, 0xff],
+        );
+
+        assert_eq!(state.transactions.len(), 1);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.client_session_id.as_deref(), Some(&CLIENT_RESET[1..9]));
+        assert_eq!(tx.server_session_id.as_deref(), Some(&SERVER_RESET[1..9]));
+        assert_eq!(
+            tx.client_reset,
+            Some(parser::P_CONTROL_HARD_RESET_CLIENT_V2)
+        );
+        assert_eq!(
+            tx.server_reset,
+            Some(parser::P_CONTROL_HARD_RESET_SERVER_V2)
+        );
+        assert_eq!(tx.wrapping, Some(OpenVpnWrapping::None));
+        assert_eq!(tx.fingerprint(), "c7-s8-c5-c9");
+        assert!(tx.data);
+        assert!(tx.complete);
+
+        // Nothing is parsed once complete.
+        parse_udp(
+            &mut state,
+            Direction::ToServer,
+            &[0x48, 0x00, 0x00, 0x01, 0xff],
+        );
+        assert_eq!(state.transactions[0].opcodes.len(), 4);
+    }
+
+    #[test]
+    fn test_openvpn_handshake_only() {
+        let mut state = OpenVpnState::new();
+        parse_udp(&mut state, Direction::ToServer, CLIENT_RESET);
+        parse_udp(&mut state, Direction::ToClient, SERVER_RESET);
+        // retransmission of the client reset
+        parse_udp(&mut state, Direction::ToServer, CLIENT_RESET);
+        assert!(!state.transactions[0].complete);
+
+        // the handshake failed, the client retries with a new session
+        let mut retry = CLIENT_RESET.to_vec();
+        retry[1] ^= 0xff;
+        parse_udp(&mut state, Direction::ToServer, &retry);
+        assert_eq!(state.transactions.len(), 1);
+        let tx = &state.transactions[0];
+        assert!(tx.complete);
+        assert!(!tx.data);
+        assert_eq!(tx.fingerprint(), "c7-s8-c7");
+
+        // a soft reset ends the handshake
+        let mut state = OpenVpnState::new();
+        parse_udp(&mut state, Direction::ToServer, CLIENT_RESET);
+        parse_udp(&mut state, Direction::ToClient, SERVER_RESET);
+        let mut soft_reset = CLIENT_RESET.to_vec();
+        soft_reset[0] = (parser::P_CONTROL_SOFT_RESET_V1 << 3) | 1;
+        parse_udp(&mut state, Direction::ToServer, &soft_reset);
+        assert!(state.transactions[0].complete);
+        assert_eq!(state.transactions[0].fingerprint(), "c7-s8-c3");
+
+        // or the end of the flow
+        let mut state = OpenVpnState::new();
+        parse_udp(&mut state, Direction::ToServer, CLIENT_RESET);
+        assert!(!state.transactions[0].complete);
+        state.finish();
+        assert!(state.transactions[0].complete);
+        // which does not create a transaction
+        let mut state = OpenVpnState::new();
+        state.finish();
+        assert!(state.transactions.is_empty());
+    }
+
+    #[test]
+    fn test_openvpn_tcp() {
+        let mut state = OpenVpnState::new();
+        let mut buf = (CLIENT_RESET.len() as u16).to_be_bytes().to_vec();
+        buf.extend_from_slice(CLIENT_RESET);
+        let ss = StreamSlice::from_slice(&buf[..10], Direction::ToServer.into(), 0);
+        let r = state.parse_tcp(std::ptr::null(), &ss, Direction::ToServer);
+        assert_eq!(r, AppLayerResult::incomplete(0, 16));
+        let ss = StreamSlice::from_slice(&buf, Direction::ToServer.into(), 0);
+        let r = state.parse_tcp(std::ptr::null(), &ss, Direction::ToServer);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions[0].fingerprint(), "c7");
+    }
+}
@@ -0,0 +1,275 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! OpenVPN packet parsing.
+//!
+//! Only the packet header is parsed: the opcode, the session id and, for
+//! the control channel, how it is wrapped. The TLS handshake carried by
+//! the control channel and the data channel are not parsed.
+
+use nom7::bytes::streaming::take;
+use nom7::number::streaming::{be_u16, be_u32, be_u8};
+use nom7::IResult;
+
+pub const P_CONTROL_HARD_RESET_CLIENT_V1: u8 = 1;
+pub const P_CONTROL_HARD_RESET_SERVER_V1: u8 = 2;
+pub const P_CONTROL_SOFT_RESET_V1: u8 = 3;
+pub const P_CONTROL_V1: u8 = 4;
+pub const P_ACK_V1: u8 = 5;
+pub const P_DATA_V1: u8 = 6;
+pub const P_CONTROL_HARD_RESET_CLIENT_V2: u8 = 7;
+pub const P_CONTROL_HARD_RESET_SERVER_V2: u8 = 8;
+pub const P_DATA_V2: u8 = 9;
+pub const P_CONTROL_HARD_RESET_CLIENT_V3: u8 = 10;
+pub const P_CONTROL_WKC_V1: u8 = 11;
+
+pub const OPENVPN_SESSION_ID_LEN: usize = 8;
+
+/// Maximum number of acknowledged packet ids in a control packet.
+const OPENVPN_MAX_ACKS: u8 = 8;
+
+/// HMAC sizes of the tls-auth digests, most common first: SHA1, SHA256,
+/// SHA512, SHA384 and MD5.
+const OPENVPN_TLS_AUTH_HMAC_SIZES: [usize; 5] = [20, 32, 64, 48, 16];
+
+/// HMAC size of tls-crypt, always SHA256.
+const OPENVPN_TLS_CRYPT_HMAC_SIZE: usize = 32;
+
+/// Lowest net time, in seconds since the epoch, of a replay protection
+/// packet id. Used to tell the packet id apart from other fields.
+const OPENVPN_MIN_NET_TIME: u32 = 1_000_000_000;
+
+/// Wrapping of the control channel packets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenVpnWrapping {
+    None,
+    /// tls-auth, with the size of its HMAC.
+    TlsAuth(u8),
+    TlsCrypt,
+    TlsCryptV2,
+}
+
+impl OpenVpnWrapping {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpenVpnWrapping::None => "none",
+            OpenVpnWrapping::TlsAuth(_) => "tls-auth",
+            OpenVpnWrapping::TlsCrypt => "tls-crypt",
+            OpenVpnWrapping::TlsCryptV2 => "tls-crypt-v2",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct OpenVpnHeader<'a> {
+    pub opcode: u8,
+    pub key_id: u8,
+    /// Session id of the sender, None for data packets.
+    pub session_id: Option<&'a [u8]>,
+}
+
+pub fn is_data_opcode(opcode: u8) -> bool {
+    opcode == P_DATA_V1 || opcode == P_DATA_V2
+}
+
+pub fn is_hard_reset_client(opcode: u8) -> bool {
+    matches!(
+        opcode,
+        P_CONTROL_HARD_RESET_CLIENT_V1
+            | P_CONTROL_HARD_RESET_CLIENT_V2
+            | P_CONTROL_HARD_RESET_CLIENT_V3
+    )
+}
+
+pub fn is_hard_reset_server(opcode: u8) -> bool {
+    opcode == P_CONTROL_HARD_RESET_SERVER_V1 || opcode == P_CONTROL_HARD_RESET_SERVER_V2
+}
+
+pub fn is_valid_opcode(opcode: u8) -> bool {
+    (P_CONTROL_HARD_RESET_CLIENT_V1..=P_CONTROL_WKC_V1).contains(&opcode)
+}
+
+pub fn opcode_string(opcode: u8) -> Option<&'static str> {
+    let s = match opcode {
+        P_CONTROL_HARD_RESET_CLIENT_V1 => "P_CONTROL_HARD_RESET_CLIENT_V1",
+        P_CONTROL_HARD_RESET_SERVER_V1 => "P_CONTROL_HARD_RESET_SERVER_V1",
+        P_CONTROL_SOFT_RESET_V1 => "P_CONTROL_SOFT_RESET_V1",
+        P_CONTROL_V1 => "P_CONTROL_V1",
+        P_ACK_V1 => "P_ACK_V1",
+        P_DATA_V1 => "P_DATA_V1",
+        P_CONTROL_HARD_RESET_CLIENT_V2 => "P_CONTROL_HARD_RESET_CLIENT_V2",
+        P_CONTROL_HARD_RESET_SERVER_V2 => "P_CONTROL_HARD_RESET_SERVER_V2",
+        P_DATA_V2 => "P_DATA_V2",
+        P_CONTROL_HARD_RESET_CLIENT_V3 => "P_CONTROL_HARD_RESET_CLIENT_V3",
+        P_CONTROL_WKC_V1 => "P_CONTROL_WKC_V1",
+        _ => {
+            return None;
+        }
+    };
+    Some(s)
+}
+
+/// Parse the opcode, key id and, for control packets, session id of a
+/// packet.
+pub fn parse_header(i: &[u8]) -> IResult<&[u8], OpenVpnHeader> {
+    let (i, b) = be_u8(i)?;
+    let opcode = b >> 3;
+    let key_id = b & 0x07;
+    if is_data_opcode(opcode) {
+        return Ok((
+            i,
+            OpenVpnHeader {
+                opcode,
+                key_id,
+                session_id: None,
+            },
+        ));
+    }
+    let (i, session_id) = take(OPENVPN_SESSION_ID_LEN)(i)?;
+    Ok((
+        i,
+        OpenVpnHeader {
+            opcode,
+            key_id,
+            session_id: Some(session_id),
+        },
+    ))
+}
+
+/// Parse the length prefix of a packet over TCP, returning the packet.
+pub fn parse_tcp_packet(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (i, len) = be_u16(i)?;
+    take(len as usize)(i)
+}
+
+/// Returns true if `i` starts with a replay protection packet id: a
+/// small counter and a net time.
+fn is_packet_id(i: &[u8]) -> bool {
+    fn packet_id(i: &[u8]) -> IResult<&[u8], (u32, u32)> {
+        let (i, id) = be_u32(i)?;
+        let (i, time) = be_u32(i)?;
+        Ok((i, (id, time)))
+    }
+    match packet_id(i) {
+        Ok((_, (id, time))) => id > 0 && id < 0x0100_0000 && time >= OPENVPN_MIN_NET_TIME,
+        _ => false,
+    }
+}
+
+/// Returns true if `i` is the plain text part of a control packet: the
+/// acknowledgments and the message packet id.
+fn is_control_plain(opcode: u8, i: &[u8]) -> bool {
+    let acks = match i.first() {
+        Some(&acks) if acks <= OPENVPN_MAX_ACKS => acks as usize,
+        _ => {
+            return false;
+        }
+    };
+    let mut len = 1 + acks * 4;
+    if acks > 0 {
+        // remote session id
+        len += OPENVPN_SESSION_ID_LEN;
+    }
+    if opcode != P_ACK_V1 {
+        // message packet id
+        len += 4;
+    }
+    i.len() >= len
+}
+
+/// Detect how a control packet is wrapped from what follows its session
+/// id. Returns None if it is not a valid control packet.
+pub fn detect_wrapping(opcode: u8, i: &[u8]) -> Option<OpenVpnWrapping> {
+    if opcode == P_CONTROL_HARD_RESET_CLIENT_V3 || opcode == P_CONTROL_WKC_V1 {
+        // The client key is only used with tls-crypt-v2.
+        return Some(OpenVpnWrapping::TlsCryptV2);
+    }
+    for hmac_size in OPENVPN_TLS_AUTH_HMAC_SIZES {
+        // HMAC, then the packet id followed by the plain text part.
+        if i.len() > hmac_size + 8
+            && is_packet_id(&i[hmac_size..])
+            && is_control_plain(opcode, &i[hmac_size + 8..])
+        {
+            return Some(OpenVpnWrapping::TlsAuth(hmac_size as u8));
+        }
+    }
+    // Packet id then the HMAC, the rest is encrypted.
+    if i.len() > 8 + OPENVPN_TLS_CRYPT_HMAC_SIZE && is_packet_id(i) {
+        return Some(OpenVpnWrapping::TlsCrypt);
+    }
+    if is_control_plain(opcode, i) {
+        return Some(OpenVpnWrapping::None);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hard_reset_plain() {
+        let buf: &[u8] = &[
+            0x38, 0x8e, 0x3f, 0x72, 0x0c, 0x19, 0x2b, 0x5d, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let (rem, hdr) = parse_header(buf).unwrap();
+        assert_eq!(hdr.opcode, P_CONTROL_HARD_RESET_CLIENT_V2);
+        assert_eq!(hdr.key_id, 0);
+        assert_eq!(hdr.session_id.unwrap(), &buf[1..9]);
+        assert_eq!(
+            detect_wrapping(hdr.opcode, rem),
+            Some(OpenVpnWrapping::None)
+        );
+        // Too many acks.
+        assert_eq!(detect_wrapping(P_CONTROL_V1, &[0x09, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_detect_wrapping() {
+        let mut buf = vec![0xaa; 20];
+        // packet id 1, net time 2024-03-04
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x01, 0x65, 0xe5, 0x8a, 0x00]);
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            detect_wrapping(P_CONTROL_HARD_RESET_CLIENT_V2, &buf),
+            Some(OpenVpnWrapping::TlsAuth(20))
+        );
+
+        let mut buf = vec![0x00, 0x00, 0x00, 0x01, 0x65, 0xe5, 0x8a, 0x00];
+        buf.extend_from_slice(&[0xaa; 32]);
+        buf.extend_from_slice(&[0x55; 5]);
+        assert_eq!(
+            detect_wrapping(P_CONTROL_HARD_RESET_CLIENT_V2, &buf),
+            Some(OpenVpnWrapping::TlsCrypt)
+        );
+
+        assert_eq!(
+            detect_wrapping(P_CONTROL_HARD_RESET_CLIENT_V3, &buf),
+            Some(OpenVpnWrapping::TlsCryptV2)
+        );
+    }
+
+    #[test]
+    fn test_parse_data() {
+        let buf: &[u8] = &[0x48, 0x00, 0x00, 0x01, 0xde, 0xad];
+        let (rem, hdr) = parse_header(buf).unwrap();
+        assert_eq!(hdr.opcode, P_DATA_V2);
+        assert_eq!(hdr.key_id, 0);
+        assert_eq!(hdr.session_id, None);
+        assert_eq!(rem.len(), 5);
+    }
+}
@@ -412,9 +412,7 @@ impl PgsqlState {
         match response {
             PgsqlBEMessage::SSLResponse(parser::SSLResponseMessage::SSLAccepted) => {
                 SCLogDebug!("SSL Request accepted");
-                unsafe {
-                    AppLayerRequestProtocolTLSUpgrade(f);
-                }
+                applayer_request_tls_upgrade(f);
                 Some(PgsqlStateProgress::Finished)
             }
             PgsqlBEMessage::SSLResponse(parser::SSLResponseMessage::SSLRejected) => {
@@ -829,7 +827,7 @@ pub unsafe extern "C" fn SCRegisterPgsqlParser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_PGSQL = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust pgsql parser registered.");
         let retval = conf_get("app-layer.protocols.pgsql.stream-depth");
@@ -0,0 +1,68 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::pop3::Pop3Transaction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_pop3(tx: &Pop3Transaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("pop3")?;
+    if let Some(ref greeting) = tx.greeting {
+        js.set_string_from_bytes("greeting", greeting)?;
+    }
+    if let Some(ref command) = tx.command {
+        js.set_string_from_bytes("command", command)?;
+    }
+    if let Some(ref user) = tx.user {
+        js.set_string_from_bytes("user", user)?;
+    }
+    if let Some(ref mechanism) = tx.auth_mechanism {
+        js.set_string_from_bytes("auth_mechanism", mechanism)?;
+    }
+    if let Some(message) = tx.message {
+        js.set_uint("message", message)?;
+    }
+    if !tx.capabilities.is_empty() {
+        js.open_array("capabilities")?;
+        for c in &tx.capabilities {
+            js.append_string_from_bytes(c)?;
+        }
+        js.close()?;
+    }
+    if let Some(messages) = tx.messages {
+        js.set_uint("messages", messages)?;
+    }
+    if let Some(size) = tx.size {
+        js.set_uint("size", size)?;
+    }
+    if let Some(status) = tx.status {
+        js.set_string("status", status.as_str())?;
+    }
+    if let Some(ref text) = tx.status_text {
+        js.set_string_from_bytes("status_text", text)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCPop3LoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, Pop3Transaction);
+    log_pop3(tx, js).is_ok()
+}
@@ -0,0 +1,146 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use crate::common::split_word;
+
+/// Longest command keyword accepted. RFC 1939 keywords are 3 or 4
+/// characters, extensions may be longer.
+const POP3_MAX_KEYWORD_LEN: usize = 8;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pop3Request<'a> {
+    /// Command keyword, as sent.
+    pub command: &'a [u8],
+    /// Anything after the keyword.
+    pub args: &'a [u8],
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Pop3Response<'a> {
+    /// `+OK <text>`
+    Ok(&'a [u8]),
+    /// `-ERR <text>`
+    Err(&'a [u8]),
+    /// `+ <challenge>`, a SASL challenge of an AUTH exchange.
+    Continuation(&'a [u8]),
+}
+
+/// Parse a client command line. Returns None if it does not start with
+/// a keyword.
+pub fn parse_request(line: &[u8]) -> Option<Pop3Request> {
+    let (command, args) = split_word(line);
+    if command.is_empty()
+        || command.len() > POP3_MAX_KEYWORD_LEN
+        || !command.iter().all(|c| c.is_ascii_alphabetic())
+    {
+        return None;
+    }
+    Some(Pop3Request { command, args })
+}
+
+/// Parse a server status line.
+pub fn parse_response(line: &[u8]) -> Option<Pop3Response> {
+    let (status, text) = split_word(line);
+    if status.eq_ignore_ascii_case(b"+OK") {
+        Some(Pop3Response::Ok(text))
+    } else if status.eq_ignore_ascii_case(b"-ERR") {
+        Some(Pop3Response::Err(text))
+    } else if status == b"+" {
+        Some(Pop3Response::Continuation(text))
+    } else {
+        None
+    }
+}
+
+fn parse_u64(i: &[u8]) -> Option<u64> {
+    if i.is_empty() || !i.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    std::str::from_utf8(i).ok()?.parse().ok()
+}
+
+/// Parse a scan listing, the message number and size of a LIST response
+/// line, or the message count and mailbox size of a STAT response.
+pub fn parse_scan_listing(i: &[u8]) -> Option<(u64, u64)> {
+    let (num, rest) = split_word(i);
+    let (size, _) = split_word(rest);
+    Some((parse_u64(num)?, parse_u64(size)?))
+}
+
+/// Parse the message number argument of a command, like RETR.
+pub fn parse_message_number(args: &[u8]) -> Option<u64> {
+    parse_u64(split_word(args).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::parse_line;
+
+    #[test]
+    fn test_parse_request() {
+        let (rem, line) = parse_line(b"USER alice\r\nPASS").unwrap();
+        assert_eq!(rem, b"PASS");
+        assert_eq!(
+            parse_request(line),
+            Some(Pop3Request {
+                command: b"USER",
+                args: b"alice",
+            })
+        );
+        assert_eq!(
+            parse_request(b"stat"),
+            Some(Pop3Request {
+                command: b"stat",
+                args: b"",
+            })
+        );
+        assert_eq!(parse_request(b""), None);
+        assert_eq!(parse_request(b"\x16\x03\x01"), None);
+        assert_eq!(parse_request(b"XAVERYLONGCMD"), None);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(b"+OK POP3 server ready"),
+            Some(Pop3Response::Ok(b"POP3 server ready"))
+        );
+        assert_eq!(parse_response(b"+OK"), Some(Pop3Response::Ok(b"")));
+        assert_eq!(
+            parse_response(b"-ERR no such message"),
+            Some(Pop3Response::Err(b"no such message"))
+        );
+        assert_eq!(
+            parse_response(b"+ dXNlcm5hbWU6"),
+            Some(Pop3Response::Continuation(b"dXNlcm5hbWU6"))
+        );
+        assert_eq!(parse_response(b"+OKAY"), None);
+        assert_eq!(parse_response(b"hello"), None);
+    }
+
+    #[test]
+    fn test_parse_scan_listing() {
+        assert_eq!(parse_scan_listing(b"2 320"), Some((2, 320)));
+        assert_eq!(parse_scan_listing(b"2 320 octets"), Some((2, 320)));
+        assert_eq!(parse_scan_listing(b"2"), None);
+        assert_eq!(parse_scan_listing(b"two 320"), None);
+        assert_eq!(parse_message_number(b"12"), Some(12));
+        assert_eq!(parse_message_number(b"12 10"), Some(12));
+        assert_eq!(parse_message_number(b""), None);
+    }
+}
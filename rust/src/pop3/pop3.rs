@@ -0,0 +1,760 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, Pop3Response};
+use crate::applayer::{self, *};
+use crate::common::parse_line;
+use crate::conf::conf_get;
+use crate::core::{AppProto, Direction, Flow, ALPROTO_UNKNOWN, IPPROTO_TCP};
+use crate::frames::Frame;
+use nom7 as nom;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub(super) static mut ALPROTO_POP3: AppProto = ALPROTO_UNKNOWN;
+
+pub(super) static POP3_LIMITS: AppLayerLimits = AppLayerLimits::new(256);
+
+/// Messages retrieved in a session before the `mass_retr` event is
+/// raised, 0 to disable it.
+static POP3_MASS_RETR_THRESHOLD: AtomicU64 = AtomicU64::new(100);
+
+/// Commands and status lines are limited to 255 and 512 octets by
+/// RFC 1939, this leaves room for extensions. Lines of multi-line
+/// responses are not limited.
+const POP3_MAX_LINE_LEN: usize = 4096;
+
+/// Maximum number of capabilities kept per transaction.
+const POP3_MAX_CAPABILITIES: usize = 64;
+
+#[derive(AppLayerFrameType)]
+pub enum Pop3FrameType {
+    Line,
+    Data,
+}
+
+#[derive(AppLayerEvent)]
+pub enum Pop3Event {
+    /// Client line not starting with a command keyword.
+    InvalidCommand,
+    /// Server line that is neither `+OK`, `-ERR` nor a SASL challenge.
+    InvalidResponse,
+    /// Status line without a pending command.
+    UnexpectedResponse,
+    /// Line exceeding `POP3_MAX_LINE_LEN`.
+    LineTooLong,
+    /// Credentials sent in clear text by PASS or an AUTH PLAIN or LOGIN
+    /// exchange, on a flow not limited to local networks.
+    PlaintextAuth,
+    /// The number of messages retrieved reached the configured
+    /// threshold.
+    MassRetr,
+    TooManyTransactions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pop3Status {
+    Ok,
+    Err,
+}
+
+impl Pop3Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Pop3Status::Ok => "OK",
+            Pop3Status::Err => "ERR",
+        }
+    }
+}
+
+/// A command and its response. The server greeting gets a transaction
+/// of its own, without a command.
+#[derive(Debug, Default)]
+pub struct Pop3Transaction {
+    tx_id: u64,
+    /// Command keyword in upper case.
+    pub command: Option<Vec<u8>>,
+    /// User name of a USER or APOP command.
+    pub user: Option<Vec<u8>>,
+    /// SASL mechanism of an AUTH command.
+    pub auth_mechanism: Option<Vec<u8>>,
+    /// Message number argument, like the message of a RETR.
+    pub message: Option<u64>,
+    pub greeting: Option<Vec<u8>>,
+    pub capabilities: Vec<Vec<u8>>,
+    /// Messages counted by a STAT, or listed by LIST or UIDL.
+    pub messages: Option<u64>,
+    /// Size in octets: of the mailbox for STAT, the sum of the listed
+    /// sizes for LIST, or the data returned by RETR and TOP.
+    pub size: Option<u64>,
+    pub status: Option<Pop3Status>,
+    pub status_text: Option<Vec<u8>>,
+    complete: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for Pop3Transaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+impl TxLimit for Pop3Transaction {
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn force_complete(&mut self) {
+        self.complete = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
+impl Pop3Transaction {
+    /// Return true if a `+OK` to the command is followed by a multi-line
+    /// response.
+    fn is_multiline(&self) -> bool {
+        match self.command.as_deref() {
+            Some(b"RETR") | Some(b"TOP") | Some(b"CAPA") => true,
+            // without argument, for all the messages or mechanisms
+            Some(b"LIST") | Some(b"UIDL") | Some(b"AUTH") => {
+                self.message.is_none() && self.auth_mechanism.is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// Handle a line of a multi-line response, without its terminator.
+    fn handle_data_line(&mut self, line: &[u8]) {
+        match self.command.as_deref() {
+            Some(b"LIST") | Some(b"UIDL") => {
+                *self.messages.get_or_insert(0) += 1;
+                if self.command.as_deref() == Some(b"LIST") {
+                    if let Some((_, size)) = parser::parse_scan_listing(line) {
+                        let total = self.size.get_or_insert(0);
+                        *total = total.saturating_add(size);
+                    }
+                }
+            }
+            Some(b"CAPA") => {
+                if self.capabilities.len() < POP3_MAX_CAPABILITIES {
+                    self.capabilities.push(line.to_vec());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Pop3State {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<Pop3Transaction>,
+    tx_index_completed: usize,
+    /// Discarding a line exceeding `POP3_MAX_LINE_LEN`, per direction.
+    ts_skip_line: bool,
+    tc_skip_line: bool,
+    greeting_seen: bool,
+    /// Client lines are SASL responses of an AUTH command.
+    sasl: bool,
+    /// Receiving the data of a multi-line response.
+    multiline: bool,
+    /// The multi-line data is at the start of a line, where the
+    /// terminating `.` line can be.
+    line_start: bool,
+    /// Messages retrieved in the session.
+    retr_count: u64,
+    /// Whether the client and server are both on local networks, None
+    /// until known.
+    local: Option<bool>,
+    /// STLS accepted, the rest of the session is TLS.
+    tls: bool,
+}
+
+impl State<Pop3Transaction> for Pop3State {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&Pop3Transaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl Pop3State {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+            self.tx_index_completed = 0;
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&Pop3Transaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self) -> &mut Pop3Transaction {
+        self.tx_id += 1;
+        let mut tx = Pop3Transaction {
+            tx_id: self.tx_id,
+            ..Default::default()
+        };
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            POP3_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            Pop3Event::TooManyTransactions as u8,
+        );
+        self.transactions.push_back(tx);
+        // just pushed
+        self.transactions.back_mut().unwrap()
+    }
+
+    /// Return the oldest command waiting for its response. Commands are
+    /// answered in order, even when pipelined.
+    fn pending_tx(&mut self) -> Option<&mut Pop3Transaction> {
+        self.transactions.iter_mut().find(|tx| !tx.complete)
+    }
+
+    /// Set an event on the pending command, or on a transaction of its
+    /// own if there is none.
+    fn set_event(&mut self, event: Pop3Event) {
+        let tx = match self.pending_tx() {
+            Some(tx) => tx,
+            None => {
+                let tx = self.new_tx();
+                tx.complete = true;
+                tx
+            }
+        };
+        tx.tx_data.set_event(event as u8);
+    }
+
+    fn handle_request_line(&mut self, line: &[u8]) {
+        if self.sasl {
+            // SASL response, not logged
+            return;
+        }
+        let request = match parser::parse_request(line) {
+            Some(request) => request,
+            None => {
+                self.set_event(Pop3Event::InvalidCommand);
+                return;
+            }
+        };
+        let command = request.command.to_ascii_uppercase();
+        let args = request.args;
+        let local = self.local;
+        let mut sasl = false;
+        let tx = self.new_tx();
+        let plaintext_auth = match command.as_slice() {
+            b"USER" | b"APOP" => {
+                let user = args.split(|&c| c == b' ').next().unwrap_or(args);
+                tx.user = Some(user.to_vec());
+                false
+            }
+            b"PASS" => true,
+            b"AUTH" if !args.is_empty() => {
+                let mechanism = args
+                    .split(|&c| c == b' ')
+                    .next()
+                    .unwrap_or(args)
+                    .to_ascii_uppercase();
+                let plaintext = mechanism == b"PLAIN" || mechanism == b"LOGIN";
+                tx.auth_mechanism = Some(mechanism);
+                sasl = true;
+                plaintext
+            }
+            b"RETR" | b"TOP" | b"LIST" | b"UIDL" | b"DELE" => {
+                tx.message = parser::parse_message_number(args);
+                false
+            }
+            _ => false,
+        };
+        if plaintext_auth && local == Some(false) {
+            tx.tx_data.set_event(Pop3Event::PlaintextAuth as u8);
+        }
+        tx.command = Some(command);
+        self.sasl = sasl;
+    }
+
+    fn handle_response_line(&mut self, flow: *const Flow, line: &[u8]) {
+        let greeting = !self.greeting_seen;
+        self.greeting_seen = true;
+        let (status, text) = match parser::parse_response(line) {
+            Some(Pop3Response::Ok(text)) => (Pop3Status::Ok, text),
+            Some(Pop3Response::Err(text)) => (Pop3Status::Err, text),
+            Some(Pop3Response::Continuation(_)) => {
+                return;
+            }
+            None => {
+                self.set_event(Pop3Event::InvalidResponse);
+                return;
+            }
+        };
+        if greeting {
+            let tx = self.new_tx();
+            tx.greeting = Some(text.to_vec());
+            tx.status = Some(status);
+            tx.complete = true;
+            return;
+        }
+        let threshold = POP3_MASS_RETR_THRESHOLD.load(Ordering::Relaxed);
+        let tx = match self.transactions.iter_mut().find(|tx| !tx.complete) {
+            Some(tx) => tx,
+            None => {
+                self.set_event(Pop3Event::UnexpectedResponse);
+                return;
+            }
+        };
+        tx.status = Some(status);
+        tx.status_text = Some(text.to_vec());
+        let mut multiline = false;
+        if status == Pop3Status::Ok {
+            match tx.command.as_deref() {
+                Some(b"STAT") => {
+                    if let Some((messages, size)) = parser::parse_scan_listing(text) {
+                        tx.messages = Some(messages);
+                        tx.size = Some(size);
+                    }
+                }
+                Some(b"LIST") if tx.message.is_some() => {
+                    if let Some((_, size)) = parser::parse_scan_listing(text) {
+                        tx.size = Some(size);
+                    }
+                }
+                Some(b"RETR") => {
+                    self.retr_count += 1;
+                    if self.retr_count == threshold {
+                        tx.tx_data.set_event(Pop3Event::MassRetr as u8);
+                    }
+                }
+                Some(b"STLS") => {
+                    SCLogDebug!("POP3 STLS accepted, switching to TLS");
+                    applayer_request_tls_upgrade(flow);
+                    self.tls = true;
+                }
+                _ => {}
+            }
+            multiline = tx.is_multiline();
+        }
+        if tx.command.as_deref() == Some(b"AUTH") {
+            self.sasl = false;
+        }
+        if multiline {
+            if tx.command.as_deref() == Some(b"RETR") || tx.command.as_deref() == Some(b"TOP") {
+                tx.size = Some(0);
+            }
+            self.multiline = true;
+            self.line_start = true;
+        } else {
+            tx.complete = true;
+        }
+    }
+
+    /// Handle the data of a multi-line response, up to its end or the end
+    /// of `input`. Returns the number of bytes consumed, None if more
+    /// data is needed to know if the terminating line starts `input`.
+    fn handle_data(&mut self, input: &[u8]) -> Option<usize> {
+        if self.line_start {
+            // the terminating line, a single dot
+            for end in [&b".\r\n"[..], b".\n"] {
+                if input.starts_with(end) {
+                    self.multiline = false;
+                    if let Some(tx) = self.pending_tx() {
+                        tx.complete = true;
+                    }
+                    return Some(end.len());
+                }
+            }
+            if input.len() < 3 && b".\r\n".starts_with(input) {
+                return None;
+            }
+        }
+        let line_start = self.line_start;
+        let (len, line) = match input.iter().position(|&c| c == b'\n') {
+            Some(p) => {
+                self.line_start = true;
+                (p + 1, Some(&input[..p]))
+            }
+            None => {
+                self.line_start = false;
+                (input.len(), None)
+            }
+        };
+        if let Some(tx) = self.pending_tx() {
+            if let Some(ref mut size) = tx.size {
+                if tx.command.as_deref() != Some(b"LIST") {
+                    *size = size.saturating_add(len as u64);
+                }
+            }
+            if let Some(line) = line {
+                if line_start {
+                    tx.handle_data_line(line.strip_suffix(b"\r").unwrap_or(line));
+                }
+            }
+        }
+        Some(len)
+    }
+
+    fn parse(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        if self.local.is_none() && !flow.is_null() {
+            self.local = unsafe { (*flow).is_local() };
+        }
+        let input = stream_slice.as_slice();
+        let mut start = input;
+        while !start.is_empty() {
+            if self.tls {
+                return AppLayerResult::ok();
+            }
+            if direction == Direction::ToClient && self.multiline {
+                match self.handle_data(start) {
+                    Some(len) => {
+                        let _pdu = Frame::new(
+                            flow,
+                            stream_slice,
+                            start,
+                            len as i64,
+                            Pop3FrameType::Data as u8,
+                            None,
+                        );
+                        start = &start[len..];
+                        continue;
+                    }
+                    None => {
+                        let consumed = input.len() - start.len();
+                        let needed = start.len() + 1;
+                        return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                    }
+                }
+            }
+            let skip_line = if direction == Direction::ToServer {
+                &mut self.ts_skip_line
+            } else {
+                &mut self.tc_skip_line
+            };
+            if *skip_line {
+                match start.iter().position(|&c| c == b'\n') {
+                    Some(p) => {
+                        *skip_line = false;
+                        start = &start[p + 1..];
+                        continue;
+                    }
+                    None => {
+                        return AppLayerResult::ok();
+                    }
+                }
+            }
+            match parse_line(start) {
+                Ok((rem, line)) => {
+                    let _pdu = Frame::new(
+                        flow,
+                        stream_slice,
+                        start,
+                        (start.len() - rem.len()) as i64,
+                        Pop3FrameType::Line as u8,
+                        None,
+                    );
+                    start = rem;
+                    if direction == Direction::ToServer {
+                        self.handle_request_line(line);
+                    } else {
+                        self.handle_response_line(flow, line);
+                    }
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    if start.len() > POP3_MAX_LINE_LEN {
+                        *skip_line = true;
+                        self.set_event(Pop3Event::LineTooLong);
+                        return AppLayerResult::ok();
+                    }
+                    let consumed = input.len() - start.len();
+                    let needed = start.len() + 1;
+                    return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                }
+                Err(_) => {
+                    return AppLayerResult::err();
+                }
+            }
+        }
+        // Input was fully consumed.
+        return AppLayerResult::ok();
+    }
+}
+
+// C exports.
+
+extern "C" fn pop3_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = Pop3State::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn pop3_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut Pop3State));
+}
+
+unsafe extern "C" fn pop3_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, Pop3State);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn pop3_parse_request(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        return AppLayerResult::ok();
+    }
+    let state = cast_pointer!(state, Pop3State);
+    state.parse(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn pop3_parse_response(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+        return AppLayerResult::ok();
+    }
+    let state = cast_pointer!(state, Pop3State);
+    state.parse(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn pop3_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, Pop3State);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn pop3_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, Pop3State);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn pop3_tx_get_alstate_progress(tx: *mut c_void, direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, Pop3Transaction);
+    // the command is complete once seen, the response at its end
+    if tx.complete || Direction::from(direction) == Direction::ToServer {
+        return 1;
+    }
+    return 0;
+}
+
+export_tx_data_get!(pop3_get_tx_data, Pop3Transaction);
+export_state_data_get!(pop3_get_state_data, Pop3State);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"pop3\0";
+
+/// Register the POP3 parser. Protocol detection is done with the
+/// pattern registered in `AppLayerParserRegisterProtocolParsers`.
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterPop3Parser() {
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: std::ptr::null(),
+        ipproto: IPPROTO_TCP,
+        probe_ts: None,
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 16,
+        state_new: pop3_state_new,
+        state_free: pop3_state_free,
+        tx_free: pop3_state_tx_free,
+        parse_ts: pop3_parse_request,
+        parse_tc: pop3_parse_response,
+        get_tx_count: pop3_state_get_tx_count,
+        get_tx: pop3_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: pop3_tx_get_alstate_progress,
+        get_eventinfo: Some(Pop3Event::get_event_info),
+        get_eventinfo_byid: Some(Pop3Event::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<Pop3State, Pop3Transaction>),
+        get_tx_data: pop3_get_tx_data,
+        get_state_data: pop3_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(Pop3FrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(Pop3FrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("tcp").unwrap();
+
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_POP3 = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+            AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_POP3);
+        }
+        POP3_LIMITS.configure("pop3");
+        if let Some(val) = conf_get("app-layer.protocols.pop3.mass-retr-threshold") {
+            if let Ok(v) = val.parse::<u64>() {
+                POP3_MASS_RETR_THRESHOLD.store(v, Ordering::Relaxed);
+            } else {
+                SCLogError!("Invalid value for pop3.mass-retr-threshold");
+            }
+        }
+        SCLogDebug!("Rust POP3 parser registered.");
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for POP3.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(state: &mut Pop3State, direction: Direction, buf: &[u8]) -> AppLayerResult {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse(std::ptr::null(), &ss, direction)
+    }
+
+    #[test]
+    fn test_pop3_session() {
+        let mut state = Pop3State::new();
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"+OK POP3 server ready <1896.697170952@dbc.mtview.ca.us>\r\n",
+        );
+        parse(&mut state, Direction::ToServer, b"USER alice\r\n");
+        parse(&mut state, Direction::ToClient, b"+OK\r\n");
+        parse(&mut state, Direction::ToServer, b"PASS secret\r\n");
+        parse(&mut state, Direction::ToClient, b"+OK maildrop locked\r\n");
+        parse(&mut state, Direction::ToServer, b"STAT\r\nLIST\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"+OK 2 320\r\n+OK 2 messages\r\n1 120\r\n2 200\r\n.\r\n",
+        );
+        parse(&mut state, Direction::ToServer, b"RETR 1\r\n");
+        // the message data has a dot stuffed line, and is split
+        let r = parse(
+            &mut state,
+            Direction::ToClient,
+            b"+OK 120 octets\r\nSubject: hi\r\n\r\n..dot\r\n.",
+        );
+        assert_eq!(r, AppLayerResult::incomplete(38, 2));
+        parse(&mut state, Direction::ToClient, b".\r\n");
+        assert!(!state.multiline);
+
+        assert_eq!(state.transactions.len(), 6);
+        let tx = &state.transactions[0];
+        assert!(tx.greeting.is_some());
+        let tx = &state.transactions[1];
+        assert_eq!(tx.user.as_deref(), Some(&b"alice"[..]));
+        let tx = &state.transactions[3];
+        assert_eq!(tx.command.as_deref(), Some(&b"STAT"[..]));
+        assert_eq!(tx.messages, Some(2));
+        assert_eq!(tx.size, Some(320));
+        let tx = &state.transactions[4];
+        assert_eq!(tx.messages, Some(2));
+        assert_eq!(tx.size, Some(320));
+        assert!(tx.complete);
+        let tx = &state.transactions[5];
+        assert_eq!(tx.message, Some(1));
+        assert_eq!(tx.size, Some(22));
+        assert!(tx.complete);
+        assert_eq!(state.retr_count, 1);
+    }
+
+    #[test]
+    fn test_pop3_auth_stls() {
+        let mut state = Pop3State::new();
+        parse(&mut state, Direction::ToClient, b"+OK ready\r\n");
+        parse(&mut state, Direction::ToServer, b"CAPA\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"+OK\r\nSTLS\r\nSASL PLAIN\r\n.\r\n",
+        );
+        parse(&mut state, Direction::ToServer, b"AUTH PLAIN\r\n");
+        parse(&mut state, Direction::ToClient, b"+ \r\n");
+        parse(&mut state, Direction::ToServer, b"AGFsaWNlAHNlY3JldA==\r\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"-ERR authentication failed\r\n",
+        );
+        assert!(!state.sasl);
+        parse(&mut state, Direction::ToServer, b"STLS\r\n");
+        parse(&mut state, Direction::ToClient, b"+OK Begin TLS\r\n");
+        assert!(state.tls);
+
+        assert_eq!(state.transactions.len(), 4);
+        let tx = &state.transactions[1];
+        assert_eq!(tx.capabilities.len(), 2);
+        let tx = &state.transactions[2];
+        assert_eq!(tx.auth_mechanism.as_deref(), Some(&b"PLAIN"[..]));
+        assert_eq!(tx.status, Some(Pop3Status::Err));
+    }
+
+    #[test]
+    fn test_pop3_mass_retr() {
+        let mut state = Pop3State::new();
+        parse(&mut state, Direction::ToClient, b"+OK ready\r\n");
+        let threshold = POP3_MASS_RETR_THRESHOLD.load(Ordering::Relaxed);
+        for _ in 0..threshold + 1 {
+            parse(&mut state, Direction::ToServer, b"RETR 1\r\n");
+            parse(&mut state, Direction::ToClient, b"+OK\r\nx\r\n.\r\n");
+        }
+        assert_eq!(state.retr_count, threshold + 1);
+        assert!(state.transactions.iter().all(|tx| tx.complete));
+    }
+
+    #[test]
+    fn test_pop3_unexpected_response() {
+        let mut state = Pop3State::new();
+        parse(&mut state, Direction::ToClient, b"+OK ready\r\n");
+        parse(&mut state, Direction::ToClient, b"-ERR timeout\r\n");
+        // the event is on a transaction of its own
+        assert_eq!(state.transactions.len(), 2);
+        assert!(state.transactions[1].command.is_none());
+    }
+}
@@ -518,7 +518,7 @@ pub unsafe extern "C" fn rs_quic_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_QUIC = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust quic parser registered.");
         AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_QUIC);
@@ -0,0 +1,301 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{McsConnectRequest, McsConnectRequestChild};
+use super::rdp::{CertificateBlob, RdpTransaction, RdpTransactionItem, ALPROTO_RDP};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperGetMultiData, DetectHelperKeywordRegister,
+    DetectHelperMultiBufferMpmRegister, DetectSignatureSetAppProto, SCSigTableElmt,
+    SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+static mut G_RDP_CHANNEL_NAME_BUFFER_ID: c_int = 0;
+static mut G_RDP_CERT_SUBJECT_BUFFER_ID: c_int = 0;
+static mut G_RDP_CERT_ISSUER_BUFFER_ID: c_int = 0;
+
+/// Get the name of the static virtual channel at `idx` in the client
+/// network data of a MCS connect request.
+fn rdp_mcs_get_channel_name(mcs: &McsConnectRequest, idx: u32) -> Option<&str> {
+    mcs.children
+        .iter()
+        .filter_map(|child| match child {
+            McsConnectRequestChild::CsNet(net) => Some(net.channels.iter()),
+            _ => None,
+        })
+        .flatten()
+        .nth(idx as usize)
+        .map(|name| name.as_str())
+}
+
+unsafe extern "C" fn rdp_channel_name_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_RDP) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_RDP_CHANNEL_NAME_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn rdp_channel_name_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        rdp_channel_name_get_data,
+    );
+}
+
+unsafe extern "C" fn rdp_channel_name_get_data(
+    tx: *const c_void, _flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, RdpTransaction);
+    if let RdpTransactionItem::McsConnectRequest(ref mcs) = tx.item {
+        if let Some(name) = rdp_mcs_get_channel_name(mcs, local_id) {
+            *buffer = name.as_ptr();
+            *buffer_len = name.len() as u32;
+            return true;
+        }
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+/// Get the subject or issuer of the certificate at `idx` in the chain
+/// presented by the server. Certificates that could not be decoded are
+/// skipped.
+fn rdp_chain_get_cert_name(chain: &[CertificateBlob], idx: u32, issuer: bool) -> Option<&str> {
+    chain
+        .iter()
+        .filter_map(|blob| {
+            if issuer {
+                blob.issuer.as_deref()
+            } else {
+                blob.subject.as_deref()
+            }
+        })
+        .nth(idx as usize)
+}
+
+unsafe fn rdp_cert_name_get_data(
+    tx: *const c_void, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32, issuer: bool,
+) -> bool {
+    let tx = cast_pointer!(tx, RdpTransaction);
+    if let RdpTransactionItem::TlsCertificateChain(ref chain) = tx.item {
+        if let Some(name) = rdp_chain_get_cert_name(chain, local_id, issuer) {
+            *buffer = name.as_ptr();
+            *buffer_len = name.len() as u32;
+            return true;
+        }
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn rdp_cert_subject_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_RDP) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_RDP_CERT_SUBJECT_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn rdp_cert_subject_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        rdp_cert_subject_get_data,
+    );
+}
+
+unsafe extern "C" fn rdp_cert_subject_get_data(
+    tx: *const c_void, _flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    rdp_cert_name_get_data(tx, local_id, buffer, buffer_len, false)
+}
+
+unsafe extern "C" fn rdp_cert_issuer_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_RDP) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_RDP_CERT_ISSUER_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn rdp_cert_issuer_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        rdp_cert_issuer_get_data,
+    );
+}
+
+unsafe extern "C" fn rdp_cert_issuer_get_data(
+    tx: *const c_void, _flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    rdp_cert_name_get_data(tx, local_id, buffer, buffer_len, true)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectRdpRegister() {
+    let kw = SCSigTableElmt {
+        name: b"rdp.channel_name\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the RDP static virtual channel names\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/rdp-keywords.html#rdp-channel-name\0".as_ptr() as *const libc::c_char,
+        Setup: rdp_channel_name_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_rdp_channel_name_kw_id = DetectHelperKeywordRegister(&kw);
+    G_RDP_CHANNEL_NAME_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"rdp.channel_name\0".as_ptr() as *const libc::c_char,
+        b"RDP channel name\0".as_ptr() as *const libc::c_char,
+        ALPROTO_RDP,
+        false,
+        true,
+        rdp_channel_name_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"rdp.cert_subject\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the subjects of the RDP server certificates\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/rdp-keywords.html#rdp-cert-subject\0".as_ptr() as *const libc::c_char,
+        Setup: rdp_cert_subject_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_rdp_cert_subject_kw_id = DetectHelperKeywordRegister(&kw);
+    G_RDP_CERT_SUBJECT_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"rdp.cert_subject\0".as_ptr() as *const libc::c_char,
+        b"RDP server certificate subject\0".as_ptr() as *const libc::c_char,
+        ALPROTO_RDP,
+        true,
+        false,
+        rdp_cert_subject_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"rdp.cert_issuer\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the issuers of the RDP server certificates\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/rdp-keywords.html#rdp-cert-issuer\0".as_ptr() as *const libc::c_char,
+        Setup: rdp_cert_issuer_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_rdp_cert_issuer_kw_id = DetectHelperKeywordRegister(&kw);
+    G_RDP_CERT_ISSUER_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"rdp.cert_issuer\0".as_ptr() as *const libc::c_char,
+        b"RDP server certificate issuer\0".as_ptr() as *const libc::c_char,
+        ALPROTO_RDP,
+        true,
+        false,
+        rdp_cert_issuer_get,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdp::parser::{CsNet, CsUnknown};
+
+    #[test]
+    fn test_rdp_mcs_get_channel_name() {
+        let mcs = McsConnectRequest {
+            children: vec![
+                McsConnectRequestChild::CsUnknown(CsUnknown {
+                    typ: 0xc002,
+                    data: Vec::new(),
+                }),
+                McsConnectRequestChild::CsNet(CsNet {
+                    channels: vec![String::from("rdpdr"), String::from("MS_T120")],
+                }),
+            ],
+        };
+        assert_eq!(rdp_mcs_get_channel_name(&mcs, 0), Some("rdpdr"));
+        assert_eq!(rdp_mcs_get_channel_name(&mcs, 1), Some("MS_T120"));
+        assert_eq!(rdp_mcs_get_channel_name(&mcs, 2), None);
+    }
+
+    #[test]
+    fn test_rdp_chain_get_cert_name() {
+        let blob = |subject: Option<&str>, issuer: Option<&str>| CertificateBlob {
+            data: Vec::new(),
+            subject: subject.map(String::from),
+            issuer: issuer.map(String::from),
+        };
+        let chain = vec![
+            blob(Some("CN=rdp.example.com"), Some("CN=Example CA")),
+            // not decoded
+            blob(None, None),
+            blob(Some("CN=Example CA"), Some("CN=Example Root")),
+        ];
+        assert_eq!(
+            rdp_chain_get_cert_name(&chain, 0, false),
+            Some("CN=rdp.example.com")
+        );
+        assert_eq!(
+            rdp_chain_get_cert_name(&chain, 0, true),
+            Some("CN=Example CA")
+        );
+        assert_eq!(
+            rdp_chain_get_cert_name(&chain, 1, true),
+            Some("CN=Example Root")
+        );
+        assert_eq!(rdp_chain_get_cert_name(&chain, 2, true), None);
+    }
+}
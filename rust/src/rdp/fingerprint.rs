@@ -0,0 +1,143 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! RDP client fingerprint (RDFP), computed from the client data blocks
+//! of the MCS connect request.
+//!
+//! The fingerprint string is made of the client version, the cluster
+//! flags, the encryption methods and the names of the requested static
+//! virtual channels:
+//! `major;minor;cluster_flags;encryption_methods;ext_encryption_methods;channels`.
+//! The hash is the md5 of this string. The connect request is only seen
+//! in the clear when standard RDP security is used.
+
+use super::parser::{McsConnectRequest, McsConnectRequestChild};
+use super::rdp::RdpState;
+use crate::fingerprint::FlowFingerprints;
+use digest::Digest;
+use digest::Update;
+use md5::Md5;
+use nom7::number::complete::le_u32;
+use std::os::raw::c_void;
+
+/// rdp-spec, section 2.2.1.3.3 Client Security Data
+const CS_SECURITY: u16 = 0xc002;
+/// rdp-spec, section 2.2.1.3.5 Client Cluster Data
+const CS_CLUSTER: u16 = 0xc004;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RdpFingerprint {
+    pub string: String,
+    pub hash: String,
+}
+
+impl RdpFingerprint {
+    pub fn new(mcs: &McsConnectRequest) -> Self {
+        let mut version = 0;
+        let mut cluster_flags = 0;
+        let mut encryption_methods = 0;
+        let mut ext_encryption_methods = 0;
+        let mut channels: &[String] = &[];
+        for child in &mcs.children {
+            match child {
+                McsConnectRequestChild::CsClientCore(core) => {
+                    if let Some(v) = &core.version {
+                        version = v.clone() as u32;
+                    }
+                }
+                McsConnectRequestChild::CsNet(net) => {
+                    channels = &net.channels;
+                }
+                McsConnectRequestChild::CsUnknown(unknown) => match unknown.typ {
+                    CS_SECURITY => {
+                        if let Ok((rem, methods)) = le_u32::<_, ()>(unknown.data.as_slice()) {
+                            encryption_methods = methods;
+                            if let Ok((_, ext)) = le_u32::<_, ()>(rem) {
+                                ext_encryption_methods = ext;
+                            }
+                        }
+                    }
+                    CS_CLUSTER => {
+                        if let Ok((_, flags)) = le_u32::<_, ()>(unknown.data.as_slice()) {
+                            cluster_flags = flags;
+                        }
+                    }
+                    _ => (),
+                },
+            }
+        }
+        let string = format!(
+            "{};{};{};{};{};{}",
+            version >> 16,
+            version & 0xffff,
+            cluster_flags,
+            encryption_methods,
+            ext_encryption_methods,
+            channels.join(",")
+        );
+        let hash = format!("{:x}", Md5::new().chain(string.as_bytes()).finalize());
+        Self { string, hash }
+    }
+}
+
+/// Add the RDP fingerprint of the flow to `fingerprints`.
+#[no_mangle]
+pub unsafe extern "C" fn SCRdpStateGetFingerprints(
+    state: *const c_void, fingerprints: &mut FlowFingerprints,
+) {
+    let state = cast_pointer!(state, RdpState);
+    if let Some(fp) = &state.fingerprint {
+        fingerprints.add("rdfp", fp.hash.as_bytes(), fp.string.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdp::parser::{CsNet, CsUnknown};
+
+    #[test]
+    fn test_rdp_fingerprint() {
+        let mcs = McsConnectRequest {
+            children: vec![
+                McsConnectRequestChild::CsUnknown(CsUnknown {
+                    typ: CS_CLUSTER,
+                    data: vec![0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                }),
+                McsConnectRequestChild::CsUnknown(CsUnknown {
+                    typ: CS_SECURITY,
+                    data: vec![0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                }),
+                McsConnectRequestChild::CsNet(CsNet {
+                    channels: vec![String::from("rdpdr"), String::from("cliprdr")],
+                }),
+            ],
+        };
+        let fp = RdpFingerprint::new(&mcs);
+        assert_eq!(fp.string, "0;0;13;27;0;rdpdr,cliprdr");
+        assert_eq!(fp.hash.len(), 32);
+
+        // truncated blocks are ignored
+        let mcs = McsConnectRequest {
+            children: vec![McsConnectRequestChild::CsUnknown(CsUnknown {
+                typ: CS_SECURITY,
+                data: vec![0x1b, 0x00],
+            })],
+        };
+        assert_eq!(RdpFingerprint::new(&mcs).string, "0;0;0;0;0;");
+    }
+}
@@ -46,6 +46,12 @@ fn log(tx: &RdpTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
             js.set_string("event_type", "connect_response")?;
         }
 
+        RdpTransactionItem::FastPathInputRate(rate) => {
+            js.set_string("event_type", "fast_path_input_rate")?;
+            js.set_uint("pdus", rate.pdus as u64)?;
+            js.set_uint("keyboard_events", rate.keyboard_events as u64)?;
+        }
+
         RdpTransactionItem::TlsCertificateChain(chain) => {
             js.set_string("event_type", "tls_handshake")?;
             js.open_array("x509_serials")?;
@@ -358,6 +364,7 @@ fn mcs_req_to_json(mcs: &McsConnectRequest, js: &mut JsonBuilder) -> Result<(),
             }
 
             McsConnectRequestChild::CsNet(ref net) => {
+                js.set_uint("channel_count", net.channels.len() as u64)?;
                 if !net.channels.is_empty() {
                     js.open_array("channels")?;
                     for channel in &net.channels {
@@ -19,7 +19,9 @@
 //!
 //! written by Zach Kelly <zach.kelly@lmco.com>
 
+pub mod detect;
 pub mod error;
+pub mod fingerprint;
 pub mod log;
 pub mod parser;
 pub mod rdp;
@@ -434,6 +434,43 @@ pub struct CsUnknown {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct McsConnectResponse {}
 
+/// rdp-spec, section 2.2.8.1.2 Client Fast-Path Input Event PDU
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FastPathInput {
+    /// number of events, 0 if encrypted with the number of events in the
+    /// encrypted data
+    pub num_events: u8,
+    pub encrypted: bool,
+    /// scancode and unicode keyboard events. The encrypted events are
+    /// taken for keyboard events if their size is the size of
+    /// `num_events` keyboard events.
+    pub keyboard_events: u8,
+}
+
+/// rdp-spec, section 2.2.8.1.2.2 Fast-Path Input Event
+#[derive(Clone, Copy, Debug, FromPrimitive, PartialEq, Eq)]
+pub enum FastPathInputEventCode {
+    Scancode = 0x0,
+    Mouse = 0x1,
+    MouseX = 0x2,
+    Sync = 0x3,
+    Unicode = 0x4,
+    QoeTimestamp = 0x6,
+}
+
+impl FastPathInputEventCode {
+    /// size of the event data following the event header
+    fn data_len(self) -> usize {
+        match self {
+            FastPathInputEventCode::Scancode => 1,
+            FastPathInputEventCode::Mouse | FastPathInputEventCode::MouseX => 6,
+            FastPathInputEventCode::Sync => 0,
+            FastPathInputEventCode::Unicode => 2,
+            FastPathInputEventCode::QoeTimestamp => 4,
+        }
+    }
+}
+
 // ==================
 
 /// parser for t.123 and children
@@ -475,6 +512,16 @@ pub fn parse_t123_tpkt(input: &[u8]) -> IResult<&[u8], T123Tpkt, RdpError> {
     return Ok((i4, T123Tpkt { child }));
 }
 
+/// parser for a t.123 tpkt, skipping its data
+/// t.123-spec, section 8
+pub fn skip_t123_tpkt(input: &[u8]) -> IResult<&[u8], (), RdpError> {
+    let (i, _version) = verify(be_u8, |&x| x == TpktVersion::T123 as u8)(input)?;
+    let (i, _reserved) = be_u8(i)?;
+    let (i, sz) = map_opt(be_u16, |x: u16| x.checked_sub(4))(i)?;
+    let (i, _data) = take(sz)(i)?;
+    Ok((i, ()))
+}
+
 fn take_4_4_bits(input: &[u8]) -> IResult<&[u8], (u8, u8), RdpError> {
     map(be_u8, |b| (b >> 4, b & 0xf))(input)
 }
@@ -1030,6 +1077,123 @@ fn parse_mcs_connect_response(i: &[u8]) -> IResult<&[u8], McsConnectResponse, Rd
     Ok((i, McsConnectResponse {}))
 }
 
+/// rdp-spec, section 2.2.8.1.2
+const FASTPATH_INPUT_ACTION_FASTPATH: u8 = 0x0;
+const FASTPATH_INPUT_ENCRYPTED: u8 = 0x2;
+/// size of the MAC signature of the encrypted data
+const FASTPATH_DATA_SIGNATURE_LEN: usize = 8;
+
+/// count the keyboard events of unencrypted fast-path input events, up to
+/// the first malformed one
+fn fast_path_keyboard_events(mut events: &[u8], num_events: u8) -> u8 {
+    let mut count = 0;
+    for _ in 0..num_events {
+        let header = events.first().map(|&x| x >> 5);
+        let code: FastPathInputEventCode = match header.and_then(num::FromPrimitive::from_u8) {
+            Some(code) => code,
+            None => break,
+        };
+        let len = 1 + code.data_len();
+        if events.len() < len {
+            break;
+        }
+        if code == FastPathInputEventCode::Scancode || code == FastPathInputEventCode::Unicode {
+            count += 1;
+        }
+        events = &events[len..];
+    }
+    count
+}
+
+/// parser for a client fast-path input PDU, counting its keyboard events
+/// rdp-spec, section 2.2.8.1.2
+pub fn parse_fast_path_input(input: &[u8]) -> IResult<&[u8], FastPathInput, RdpError> {
+    let (i, header) = verify(be_u8, |&x| x & 0x3 == FASTPATH_INPUT_ACTION_FASTPATH)(input)?;
+    let (i, length1) = be_u8(i)?;
+    let (i, length) = if length1 & 0x80 != 0 {
+        let (i, length2) = be_u8(i)?;
+        (i, ((length1 as usize & 0x7f) << 8) | length2 as usize)
+    } else {
+        (i, length1 as usize)
+    };
+    // the length includes the header
+    let header_len = input.len() - i.len();
+    if length <= header_len {
+        return Err(Err::Error(make_error(i, ErrorKind::Verify)));
+    }
+    let (rem, data) = take(length - header_len)(i)?;
+    let mut pdu = FastPathInput {
+        num_events: (header >> 2) & 0xf,
+        encrypted: (header >> 6) & FASTPATH_INPUT_ENCRYPTED != 0,
+        keyboard_events: 0,
+    };
+    if pdu.encrypted {
+        let events_len = data.len().saturating_sub(FASTPATH_DATA_SIGNATURE_LEN);
+        let n = pdu.num_events as usize;
+        if n > 0 && (events_len == 2 * n || events_len == 3 * n) {
+            pdu.keyboard_events = pdu.num_events;
+        }
+    } else if pdu.num_events == 0 {
+        // the number of events is in the data
+        if let Some((&num_events, events)) = data.split_first() {
+            pdu.num_events = num_events;
+            pdu.keyboard_events = fast_path_keyboard_events(events, num_events);
+        }
+    } else {
+        pdu.keyboard_events = fast_path_keyboard_events(data, pdu.num_events);
+    }
+    Ok((rem, pdu))
+}
+
+#[cfg(test)]
+mod tests_fast_path_input {
+    use crate::rdp::parser::*;
+
+    #[test]
+    fn test_fast_path_input() {
+        // 2 scancode events, press and release of 'a', then a mouse event
+        let buf: &[u8] = &[
+            0x0c, 0x0d, 0x00, 0x1e, 0x01, 0x1e, 0x20, 0x00, 0x08, 0x10, 0x00, 0x20, 0x00, 0xff,
+        ];
+        let (rem, pdu) = parse_fast_path_input(buf).unwrap();
+        assert_eq!(rem, &[0xff]);
+        assert_eq!(
+            pdu,
+            FastPathInput {
+                num_events: 3,
+                encrypted: false,
+                keyboard_events: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fast_path_input_encrypted() {
+        // 2 events of 2 bytes after the signature: keyboard events
+        let mut buf = vec![0x88, 0x0e];
+        buf.extend_from_slice(&[0xaa; 12]);
+        let (_, pdu) = parse_fast_path_input(&buf).unwrap();
+        assert!(pdu.encrypted);
+        assert_eq!(pdu.keyboard_events, 2);
+        // a mouse event
+        let mut buf = vec![0x84, 0x11];
+        buf.extend_from_slice(&[0xaa; 15]);
+        let (_, pdu) = parse_fast_path_input(&buf).unwrap();
+        assert_eq!(pdu.num_events, 1);
+        assert_eq!(pdu.keyboard_events, 0);
+    }
+
+    #[test]
+    fn test_fast_path_input_long_length() {
+        let mut buf = vec![0x04, 0x80, 0x0a, 0x03];
+        buf.extend_from_slice(&[0x00; 6]);
+        let (rem, pdu) = parse_fast_path_input(&buf).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(pdu.num_events, 1);
+        assert!(parse_fast_path_input(&[0x03, 0x00, 0x00, 0x25]).is_err());
+    }
+}
+
 #[cfg(test)]
 mod tests_cookie_21182 {
     use crate::rdp::parser::*;
@@ -20,14 +20,32 @@
 //! RDP application layer
 
 use crate::applayer::{self, *};
+use crate::conf::{conf_get, conf_get_bool};
 use crate::core::{AppProto, Flow, ALPROTO_UNKNOWN, IPPROTO_TCP};
+use crate::rdp::fingerprint::RdpFingerprint;
 use crate::rdp::parser::*;
+use crate::x509::x509_get_subject_issuer;
 use nom7::Err;
 use std;
 use std::collections::VecDeque;
 use tls_parser::{parse_tls_plaintext, TlsMessage, TlsMessageHandshake, TlsRecordType};
 
-static mut ALPROTO_RDP: AppProto = ALPROTO_UNKNOWN;
+pub(super) static mut ALPROTO_RDP: AppProto = ALPROTO_UNKNOWN;
+
+/// keyboard events per second of the fast-path input above which a
+/// session is flagged, 0 to disable
+static mut RDP_MAX_KEYBOARD_RATE: u32 = 60;
+
+/// hand the flow over to the TLS parser after a TLS/NLA negotiation,
+/// instead of parsing the TLS handshake here for its certificates
+static mut RDP_TLS_UPGRADE: bool = false;
+
+#[derive(AppLayerEvent)]
+pub enum RdpEvent {
+    /// a second of fast-path input had more keyboard events than the
+    /// maximum keyboard rate
+    KeyboardInputRate,
+}
 
 //
 // transactions
@@ -36,6 +54,30 @@ static mut ALPROTO_RDP: AppProto = ALPROTO_UNKNOWN;
 #[derive(Debug, PartialEq, Eq)]
 pub struct CertificateBlob {
     pub data: Vec<u8>,
+    /// Subject and issuer, if the certificate could be decoded.
+    pub subject: Option<String>,
+    pub issuer: Option<String>,
+}
+
+impl CertificateBlob {
+    pub fn new(data: &[u8]) -> Self {
+        let (subject, issuer) = match x509_get_subject_issuer(data) {
+            Some((subject, issuer)) => (Some(subject), Some(issuer)),
+            None => (None, None),
+        };
+        Self {
+            data: data.to_vec(),
+            subject,
+            issuer,
+        }
+    }
+}
+
+/// fast-path input of the client during a second
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RdpInputRate {
+    pub pdus: u32,
+    pub keyboard_events: u32,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -45,6 +87,8 @@ pub enum RdpTransactionItem {
     McsConnectRequest(McsConnectRequest),
     McsConnectResponse(McsConnectResponse),
     TlsCertificateChain(Vec<CertificateBlob>),
+    /// first second of fast-path input above the maximum keyboard rate
+    FastPathInputRate(RdpInputRate),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -112,6 +156,25 @@ pub struct RdpState {
     transactions: VecDeque<RdpTransaction>,
     tls_parsing: bool,
     bypass_parsing: bool,
+    /// the flow is handed over to the TLS parser once the server selects
+    /// a TLS based security protocol
+    tls_upgrade_enabled: bool,
+    /// the server selected a TLS based security protocol, the flow is to
+    /// be handed over to the TLS parser
+    tls_upgrade: bool,
+    /// client fingerprint, kept on the state as transactions are freed
+    /// before the flow ends
+    pub(super) fingerprint: Option<RdpFingerprint>,
+    /// standard RDP security: the client data following the MCS connect
+    /// response is parsed for the rate of its fast-path input
+    input_parsing: bool,
+    /// time in seconds of the data being parsed
+    ts: u64,
+    /// second of the fast-path input being counted
+    input_second: u64,
+    input_rate: RdpInputRate,
+    /// the maximum keyboard rate was exceeded
+    input_flagged: bool,
 }
 
 impl State<RdpTransaction> for RdpState {
@@ -132,6 +195,14 @@ impl RdpState {
             transactions: VecDeque::new(),
             tls_parsing: false,
             bypass_parsing: false,
+            tls_upgrade_enabled: unsafe { RDP_TLS_UPGRADE },
+            tls_upgrade: false,
+            fingerprint: None,
+            input_parsing: false,
+            ts: 0,
+            input_second: 0,
+            input_rate: RdpInputRate::default(),
+            input_flagged: false,
         }
     }
 
@@ -162,8 +233,64 @@ impl RdpState {
         return tx;
     }
 
+    /// Check the fast-path input of the previous second against the
+    /// maximum keyboard rate once the current time is past it.
+    fn update_input_rate(&mut self) {
+        if self.input_second == self.ts {
+            return;
+        }
+        self.input_second = self.ts;
+        let rate = std::mem::take(&mut self.input_rate);
+        let max = unsafe { RDP_MAX_KEYBOARD_RATE };
+        if max > 0 && !self.input_flagged && rate.keyboard_events > max {
+            self.input_flagged = true;
+            let mut tx = self.new_tx(RdpTransactionItem::FastPathInputRate(rate));
+            tx.tx_data.set_event(RdpEvent::KeyboardInputRate as u8);
+            self.transactions.push_back(tx);
+        }
+    }
+
+    /// parse the client data following the MCS connect response of a
+    /// session using standard RDP security, counting the fast-path input
+    fn parse_ts_input(&mut self, input: &[u8]) -> AppLayerResult {
+        self.update_input_rate();
+        let mut available = input;
+        while !available.is_empty() {
+            // slow-path PDUs are in a tpkt, fast-path ones are not
+            let r = if available[0] == TpktVersion::T123 as u8 {
+                skip_t123_tpkt(available).map(|(remainder, _)| remainder)
+            } else {
+                parse_fast_path_input(available).map(|(remainder, pdu)| {
+                    self.input_rate.pdus += 1;
+                    self.input_rate.keyboard_events += pdu.keyboard_events as u32;
+                    remainder
+                })
+            };
+            match r {
+                Ok(remainder) => {
+                    available = remainder;
+                }
+                Err(Err::Incomplete(_)) => {
+                    return AppLayerResult::incomplete(
+                        (input.len() - available.len()) as u32,
+                        (available.len() + 1) as u32,
+                    );
+                }
+                Err(Err::Failure(_)) | Err(Err::Error(_)) => {
+                    // lost track of the PDUs, stop counting
+                    self.input_parsing = false;
+                    return AppLayerResult::ok();
+                }
+            }
+        }
+        return AppLayerResult::ok();
+    }
+
     /// parse buffer captures from client to server
     fn parse_ts(&mut self, input: &[u8]) -> AppLayerResult {
+        if self.input_parsing {
+            return self.parse_ts_input(input);
+        }
         // no need to process input buffer
         if self.bypass_parsing {
             return AppLayerResult::ok();
@@ -214,6 +341,7 @@ impl RdpState {
                                 #[allow(clippy::single_match)]
                                 match x223.child {
                                     X223DataChild::McsConnectRequest(mcs) => {
+                                        self.fingerprint = Some(RdpFingerprint::new(&mcs));
                                         let tx =
                                             self.new_tx(RdpTransactionItem::McsConnectRequest(mcs));
                                         self.transactions.push_back(tx);
@@ -281,9 +409,7 @@ impl RdpState {
                                 )) => {
                                     let mut chain = Vec::new();
                                     for cert in &contents.cert_chain {
-                                        chain.push(CertificateBlob {
-                                            data: cert.data.to_vec(),
-                                        });
+                                        chain.push(CertificateBlob::new(cert.data));
                                     }
                                     let tx =
                                         self.new_tx(RdpTransactionItem::TlsCertificateChain(chain));
@@ -318,9 +444,20 @@ impl RdpState {
                         match t123.child {
                             // X.224 connection confirm
                             T123TpktChild::X224ConnectionConfirm(x224) => {
+                                // SSL, HYBRID, RDSTLS and HYBRID_EX all start with a
+                                // TLS handshake once the connection is confirmed
+                                let tls = matches!(
+                                    x224.negotiation_from_server,
+                                    Some(NegotiationFromServer::Response(ref response))
+                                        if response.protocol != Protocol::ProtocolRdp
+                                );
                                 let tx =
                                     self.new_tx(RdpTransactionItem::X224ConnectionConfirm(x224));
                                 self.transactions.push_back(tx);
+                                if tls && self.tls_upgrade_enabled {
+                                    self.tls_upgrade = true;
+                                    return AppLayerResult::ok();
+                                }
                             }
 
                             // X.223 data packet, evaluate what it encapsulates
@@ -332,6 +469,7 @@ impl RdpState {
                                             .new_tx(RdpTransactionItem::McsConnectResponse(mcs));
                                         self.transactions.push_back(tx);
                                         self.bypass_parsing = true;
+                                        self.input_parsing = unsafe { RDP_MAX_KEYBOARD_RATE } > 0;
                                         return AppLayerResult::ok();
                                     }
 
@@ -431,11 +569,13 @@ fn probe_tls_handshake(input: &[u8]) -> bool {
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_rdp_parse_ts(
-    _flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
+    flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice,
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
     let state = cast_pointer!(state, RdpState);
+    let f = cast_pointer!(flow, Flow);
+    state.ts = f.get_last_time().as_secs();
     let buf = stream_slice.as_slice();
     // attempt to parse bytes as `rdp` protocol
     return state.parse_ts(buf);
@@ -443,14 +583,29 @@ pub unsafe extern "C" fn rs_rdp_parse_ts(
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_rdp_parse_tc(
-    _flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
+    flow: *const Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice,
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
     let state = cast_pointer!(state, RdpState);
     let buf = stream_slice.as_slice();
+    if state.input_parsing {
+        // the output of the server closes the seconds of input
+        let f = cast_pointer!(flow, Flow);
+        state.ts = f.get_last_time().as_secs();
+        state.update_input_rate();
+    }
     // attempt to parse bytes as `rdp` protocol
-    return state.parse_tc(buf);
+    let r = state.parse_tc(buf);
+    if state.tls_upgrade {
+        state.tls_upgrade = false;
+        // if the upgrade can't be done, the TLS handshake is parsed here
+        // for its certificate chain
+        if applayer_request_tls_upgrade(flow) {
+            state.bypass_parsing = true;
+        }
+    }
+    return r;
 }
 
 export_tx_data_get!(rs_rdp_get_tx_data, RdpTransaction);
@@ -462,6 +617,17 @@ export_state_data_get!(rs_rdp_get_state_data, RdpState);
 
 const PARSER_NAME: &[u8] = b"rdp\0";
 
+unsafe fn rdp_configure() {
+    if let Some(val) = conf_get("app-layer.protocols.rdp.max-keyboard-rate") {
+        if let Ok(v) = val.parse::<u32>() {
+            RDP_MAX_KEYBOARD_RATE = v;
+        } else {
+            SCLogError!("Invalid value for rdp.max-keyboard-rate");
+        }
+    }
+    RDP_TLS_UPGRADE = conf_get_bool("app-layer.protocols.rdp.tls-upgrade");
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_rdp_register_parser() {
     let default_port = std::ffi::CString::new("[3389]").unwrap();
@@ -483,8 +649,8 @@ pub unsafe extern "C" fn rs_rdp_register_parser() {
         tx_comp_st_ts: 1,
         tx_comp_st_tc: 1,
         tx_get_progress: rs_rdp_tx_get_progress,
-        get_eventinfo: None,
-        get_eventinfo_byid: None,
+        get_eventinfo: Some(RdpEvent::get_event_info),
+        get_eventinfo_byid: Some(RdpEvent::get_event_info_by_id),
         localstorage_new: None,
         localstorage_free: None,
         get_tx_files: None,
@@ -503,7 +669,8 @@ pub unsafe extern "C" fn rs_rdp_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_RDP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
+            rdp_configure();
         }
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_RDP);
     }
@@ -520,6 +687,37 @@ mod tests {
         assert!(probe_rdp(buf));
     }
 
+    #[test]
+    fn test_parse_ts_input_rate() {
+        let mut state = RdpState::new();
+        state.bypass_parsing = true;
+        state.input_parsing = true;
+        // a slow-path PDU, then 2 fast-path PDUs of 2 encrypted keyboard events
+        let mut buf = vec![0x03, 0x00, 0x00, 0x08, 0x02, 0xf0, 0x80, 0x00];
+        for _ in 0..2 {
+            buf.extend_from_slice(&[0x88, 0x0e]);
+            buf.extend_from_slice(&[0xaa; 12]);
+        }
+        state.ts = 10;
+        for _ in 0..16 {
+            assert_eq!(AppLayerResult::ok(), state.parse_ts(&buf));
+        }
+        assert_eq!(state.input_rate.keyboard_events, 64);
+        assert!(state.transactions.is_empty());
+        // checked once the second is over
+        state.ts = 11;
+        state.update_input_rate();
+        assert_eq!(
+            state.transactions[0].item,
+            RdpTransactionItem::FastPathInputRate(RdpInputRate {
+                pdus: 32,
+                keyboard_events: 64,
+            })
+        );
+        // a fast-path PDU cut by the end of the data
+        assert_eq!(AppLayerResult::incomplete(8, 3), state.parse_ts(&buf[..10]));
+    }
+
     #[test]
     fn test_probe_rdp_other() {
         let buf: &[u8] = &[0x04, 0x00];
@@ -590,6 +788,38 @@ mod tests {
         assert_eq!(item, state.transactions[0].item);
     }
 
+    #[test]
+    fn test_parse_tc_tls_upgrade() {
+        // connection confirm, negotiation response selecting PROTOCOL_HYBRID
+        let buf: &[u8] = &[
+            0x03, 0x00, 0x00, 0x13, 0x0e, 0xd0, 0x00, 0x00, 0x12, 0x34, 0x00, 0x02, 0x1f, 0x08,
+            0x00, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let mut state = RdpState::new();
+        state.tls_upgrade_enabled = true;
+        assert_eq!(AppLayerResult::ok(), state.parse_tc(buf));
+        assert_eq!(1, state.transactions.len());
+        assert!(state.tls_upgrade);
+
+        // hand over disabled, the TLS handshake is parsed here
+        let mut state = RdpState::new();
+        state.tls_upgrade_enabled = false;
+        assert_eq!(AppLayerResult::ok(), state.parse_tc(buf));
+        assert_eq!(1, state.transactions.len());
+        assert!(!state.tls_upgrade);
+
+        // connection confirm, negotiation response selecting PROTOCOL_RDP
+        let buf: &[u8] = &[
+            0x03, 0x00, 0x00, 0x13, 0x0e, 0xd0, 0x00, 0x00, 0x12, 0x34, 0x00, 0x02, 0x1f, 0x08,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let mut state = RdpState::new();
+        state.tls_upgrade_enabled = true;
+        assert_eq!(AppLayerResult::ok(), state.parse_tc(buf));
+        assert_eq!(1, state.transactions.len());
+        assert!(!state.tls_upgrade);
+    }
+
     #[test]
     fn test_parse_tc_other() {
         let buf: &[u8] = &[0x03, 0x00, 0x00, 0x01, 0x00];
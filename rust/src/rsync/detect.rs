@@ -0,0 +1,153 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::rsync::{RsyncTransaction, ALPROTO_RSYNC};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferMpmRegister, DetectHelperGetData,
+    DetectHelperGetMultiData, DetectHelperKeywordRegister, DetectHelperMultiBufferMpmRegister,
+    DetectSignatureSetAppProto, SCSigTableElmt, SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+static mut G_RSYNC_MODULE_BUFFER_ID: c_int = 0;
+static mut G_RSYNC_LISTED_MODULE_BUFFER_ID: c_int = 0;
+
+unsafe extern "C" fn rsync_module_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_RSYNC) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_RSYNC_MODULE_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn rsync_module_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        rsync_module_get_data,
+    );
+}
+
+unsafe extern "C" fn rsync_module_get_data(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, RsyncTransaction);
+    if let Some(ref module) = tx.module {
+        *buffer = module.as_ptr();
+        *buffer_len = module.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn rsync_listed_module_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_RSYNC) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_RSYNC_LISTED_MODULE_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn rsync_listed_module_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        rsync_listed_module_get_data,
+    );
+}
+
+unsafe extern "C" fn rsync_listed_module_get_data(
+    tx: *const c_void, _flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, RsyncTransaction);
+    if let Some(module) = tx.modules.get(local_id as usize) {
+        *buffer = module.as_ptr();
+        *buffer_len = module.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectRsyncRegister() {
+    let kw = SCSigTableElmt {
+        name: b"rsync.module\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the requested rsync module\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/rsync-keywords.html#rsync-module\0".as_ptr() as *const libc::c_char,
+        Setup: rsync_module_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_rsync_module_kw_id = DetectHelperKeywordRegister(&kw);
+    G_RSYNC_MODULE_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"rsync.module\0".as_ptr() as *const libc::c_char,
+        b"rsync requested module\0".as_ptr() as *const libc::c_char,
+        ALPROTO_RSYNC,
+        false,
+        true,
+        rsync_module_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"rsync.listed_module\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the modules listed by an rsync server\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/rsync-keywords.html#rsync-listed-module\0".as_ptr() as *const libc::c_char,
+        Setup: rsync_listed_module_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_rsync_listed_module_kw_id = DetectHelperKeywordRegister(&kw);
+    G_RSYNC_LISTED_MODULE_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"rsync.listed_module\0".as_ptr() as *const libc::c_char,
+        b"rsync module listing\0".as_ptr() as *const libc::c_char,
+        ALPROTO_RSYNC,
+        true,
+        false,
+        rsync_listed_module_get,
+    );
+}
@@ -0,0 +1,84 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::rsync::{RsyncResult, RsyncTransaction};
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_rsync(tx: &RsyncTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("rsync")?;
+    if let Some(ref version) = tx.client_version {
+        js.set_string_from_bytes("client_version", version)?;
+    }
+    if let Some(ref version) = tx.server_version {
+        js.set_string_from_bytes("server_version", version)?;
+    }
+    if !tx.server_digests.is_empty() {
+        js.open_array("server_digests")?;
+        for d in &tx.server_digests {
+            js.append_string_from_bytes(d)?;
+        }
+        js.close()?;
+    }
+    if let Some(ref module) = tx.module {
+        js.set_string_from_bytes("module", module)?;
+    }
+    js.set_bool("listing", tx.listing)?;
+    if !tx.modules.is_empty() {
+        js.open_array("modules")?;
+        for m in &tx.modules {
+            js.append_string_from_bytes(m)?;
+        }
+        js.close()?;
+    }
+    if !tx.motd.is_empty() {
+        js.open_array("motd")?;
+        for l in &tx.motd {
+            js.append_string_from_bytes(l)?;
+        }
+        js.close()?;
+    }
+    if let Some(ref challenge) = tx.auth_challenge {
+        js.set_string_from_bytes("auth_challenge", challenge)?;
+    }
+    if let Some(ref user) = tx.auth_user {
+        js.set_string_from_bytes("auth_user", user)?;
+    }
+    match tx.result {
+        Some(RsyncResult::Ok) => {
+            js.set_string("result", "ok")?;
+        }
+        Some(RsyncResult::Exit) => {
+            js.set_string("result", "exit")?;
+        }
+        Some(RsyncResult::Error(ref msg)) => {
+            js.set_string("result", "error")?;
+            js.set_string_from_bytes("error", msg)?;
+        }
+        None => {}
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRsyncLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, RsyncTransaction);
+    log_rsync(tx, js).is_ok()
+}
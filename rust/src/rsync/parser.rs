@@ -0,0 +1,160 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use nom7::bytes::complete::{tag, take_while};
+use nom7::combinator::rest;
+use nom7::IResult;
+
+pub const RSYNC_GREETING_PREFIX: &[u8] = b"@RSYNCD: ";
+
+/// Client side module request asking for the module listing.
+pub const RSYNC_LIST_REQUEST: &[u8] = b"#list";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RsyncGreeting<'a> {
+    /// Protocol version, like "31.0".
+    pub version: &'a [u8],
+    /// Checksum digests offered, protocol 31 and later.
+    pub digests: Vec<&'a [u8]>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RsyncLine<'a> {
+    Greeting(RsyncGreeting<'a>),
+    /// `@RSYNCD: AUTHREQD <challenge>`
+    AuthRequired(&'a [u8]),
+    /// `@RSYNCD: OK`
+    Ok,
+    /// `@RSYNCD: EXIT`
+    Exit,
+    /// `@ERROR: <message>`
+    Error(&'a [u8]),
+    /// Anything else: module names, MOTD, module listing or
+    /// authentication responses.
+    Text(&'a [u8]),
+}
+
+fn parse_greeting(i: &[u8]) -> IResult<&[u8], RsyncGreeting> {
+    let (i, version) = take_while(|c: u8| c.is_ascii_digit() || c == b'.')(i)?;
+    if version.is_empty() {
+        return Err(nom7::Err::Error(nom7::error::make_error(
+            i,
+            nom7::error::ErrorKind::Digit,
+        )));
+    }
+    let (i, rest) = rest(i)?;
+    let digests = rest
+        .split(|&c| c == b' ')
+        .filter(|d| !d.is_empty())
+        .collect();
+    Ok((i, RsyncGreeting { version, digests }))
+}
+
+fn parse_rsyncd_line(i: &[u8]) -> IResult<&[u8], RsyncLine> {
+    let (i, _) = tag(RSYNC_GREETING_PREFIX)(i)?;
+    if let Some(challenge) = i.strip_prefix(b"AUTHREQD ") {
+        return Ok((&i[i.len()..], RsyncLine::AuthRequired(challenge)));
+    }
+    match i {
+        b"OK" => Ok((&i[i.len()..], RsyncLine::Ok)),
+        b"EXIT" => Ok((&i[i.len()..], RsyncLine::Exit)),
+        _ => {
+            let (i, greeting) = parse_greeting(i)?;
+            Ok((i, RsyncLine::Greeting(greeting)))
+        }
+    }
+}
+
+/// Classify a line as returned by `parse_line`.
+pub fn classify_line(line: &[u8]) -> RsyncLine {
+    if line.starts_with(RSYNC_GREETING_PREFIX) {
+        if let Ok((_, l)) = parse_rsyncd_line(line) {
+            return l;
+        }
+    } else if let Some(msg) = line.strip_prefix(b"@ERROR") {
+        let msg = msg.strip_prefix(b":").unwrap_or(msg);
+        return RsyncLine::Error(msg.strip_prefix(b" ").unwrap_or(msg));
+    }
+    RsyncLine::Text(line)
+}
+
+/// Extract the module name of a listing line, which is formatted as
+/// the name padded to 15 chars, a tab, and the module comment.
+pub fn listing_module_name(line: &[u8]) -> &[u8] {
+    let name = line.split(|&c| c == b'\t').next().unwrap_or(line);
+    let end = name
+        .iter()
+        .rposition(|&c| c != b' ')
+        .map(|p| p + 1)
+        .unwrap_or(0);
+    &name[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::parse_line;
+
+    #[test]
+    fn test_parse_greeting() {
+        let buf: &[u8] = b"@RSYNCD: 31.0 sha512 sha256 sha1 md5 md4\n";
+        let (rem, line) = parse_line(buf).unwrap();
+        assert!(rem.is_empty());
+        match classify_line(line) {
+            RsyncLine::Greeting(g) => {
+                assert_eq!(g.version, b"31.0");
+                assert_eq!(g.digests.len(), 5);
+                assert_eq!(g.digests[0], b"sha512");
+            }
+            l => panic!("unexpected line {:?}", l),
+        }
+    }
+
+    #[test]
+    fn test_parse_incomplete() {
+        let buf: &[u8] = b"@RSYNCD: 31";
+        assert!(matches!(parse_line(buf), Err(nom7::Err::Incomplete(_))));
+    }
+
+    #[test]
+    fn test_classify_lines() {
+        assert_eq!(
+            classify_line(b"@RSYNCD: AUTHREQD eBTKy1u0yXkbGs7j3T1gkA"),
+            RsyncLine::AuthRequired(b"eBTKy1u0yXkbGs7j3T1gkA")
+        );
+        assert_eq!(classify_line(b"@RSYNCD: OK"), RsyncLine::Ok);
+        assert_eq!(classify_line(b"@RSYNCD: EXIT"), RsyncLine::Exit);
+        assert_eq!(
+            classify_line(b"@ERROR: auth failed on module backup"),
+            RsyncLine::Error(b"auth failed on module backup")
+        );
+        assert_eq!(classify_line(b"backup"), RsyncLine::Text(b"backup"));
+        assert_eq!(
+            classify_line(b"@RSYNCD: garbage"),
+            RsyncLine::Text(b"@RSYNCD: garbage")
+        );
+    }
+
+    #[test]
+    fn test_listing_module_name() {
+        assert_eq!(
+            listing_module_name(b"backup         \tNightly backups"),
+            b"backup"
+        );
+        assert_eq!(listing_module_name(b"pub"), b"pub");
+    }
+}
@@ -0,0 +1,520 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, RsyncLine};
+use crate::applayer::{self, *};
+use crate::common::parse_line;
+use crate::core::{AppProto, Direction, Flow, ALPROTO_UNKNOWN, IPPROTO_TCP};
+use crate::frames::Frame;
+use nom7 as nom;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(super) static mut ALPROTO_RSYNC: AppProto = ALPROTO_UNKNOWN;
+
+/// Lines longer than this are not expected in the text part of the
+/// protocol.
+const RSYNC_MAX_LINE_LEN: usize = 4096;
+
+/// Maximum number of listed modules and MOTD lines kept per transaction.
+const RSYNC_MAX_LINES: usize = 256;
+
+#[derive(AppLayerFrameType)]
+pub enum RsyncFrameType {
+    Line,
+}
+
+#[derive(AppLayerEvent)]
+pub enum RsyncEvent {
+    /// First line is not an `@RSYNCD:` greeting.
+    InvalidGreeting,
+    /// Line exceeding `RSYNC_MAX_LINE_LEN`.
+    LineTooLong,
+    /// The server returned its list of modules.
+    ModuleListing,
+    /// The server granted access to a module without asking for
+    /// authentication.
+    UnauthenticatedAccess,
+    /// The server rejected the access after an authentication
+    /// challenge.
+    AuthFailed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RsyncResult {
+    Ok,
+    Exit,
+    Error(Vec<u8>),
+}
+
+#[derive(Debug, Default)]
+pub struct RsyncTransaction {
+    tx_id: u64,
+    pub client_version: Option<Vec<u8>>,
+    pub server_version: Option<Vec<u8>>,
+    pub server_digests: Vec<Vec<u8>>,
+    /// Requested module, empty for a listing request.
+    pub module: Option<Vec<u8>>,
+    pub listing: bool,
+    /// Modules returned by the server for a listing request.
+    pub modules: Vec<Vec<u8>>,
+    pub motd: Vec<Vec<u8>>,
+    pub auth_challenge: Option<Vec<u8>>,
+    pub auth_user: Option<Vec<u8>>,
+    pub result: Option<RsyncResult>,
+    complete: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for RsyncTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum RsyncClientState {
+    #[default]
+    Greeting,
+    Module,
+    Auth,
+    /// Arguments and file transfer, not parsed.
+    Done,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum RsyncServerState {
+    #[default]
+    Greeting,
+    Reply,
+    /// File transfer, not parsed.
+    Done,
+}
+
+#[derive(Default)]
+pub struct RsyncState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<RsyncTransaction>,
+    client: RsyncClientState,
+    server: RsyncServerState,
+}
+
+impl State<RsyncTransaction> for RsyncState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&RsyncTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl RsyncState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&RsyncTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    /// Return the session transaction. The daemon protocol serves a
+    /// single module request per connection, so there is a single
+    /// transaction covering the whole text exchange.
+    fn get_current_tx(&mut self) -> &mut RsyncTransaction {
+        if self.transactions.is_empty() {
+            self.tx_id += 1;
+            let tx = RsyncTransaction {
+                tx_id: self.tx_id,
+                ..Default::default()
+            };
+            self.transactions.push_back(tx);
+        }
+        // checked above
+        self.transactions.back_mut().unwrap()
+    }
+
+    fn set_event(&mut self, event: RsyncEvent) {
+        self.get_current_tx().tx_data.set_event(event as u8);
+    }
+
+    /// End the session, closing the open transaction if there is one.
+    fn finish(&mut self) {
+        self.client = RsyncClientState::Done;
+        self.server = RsyncServerState::Done;
+        if let Some(tx) = self.transactions.back_mut() {
+            tx.complete = true;
+        }
+    }
+
+    fn handle_request_line(&mut self, line: &[u8]) {
+        match self.client {
+            RsyncClientState::Greeting => {
+                if let RsyncLine::Greeting(g) = parser::classify_line(line) {
+                    self.get_current_tx().client_version = Some(g.version.to_vec());
+                    self.client = RsyncClientState::Module;
+                } else {
+                    self.set_event(RsyncEvent::InvalidGreeting);
+                    self.finish();
+                }
+            }
+            RsyncClientState::Module => {
+                let tx = self.get_current_tx();
+                tx.listing = line.is_empty() || line == parser::RSYNC_LIST_REQUEST;
+                tx.module = Some(line.to_vec());
+                self.client = RsyncClientState::Auth;
+            }
+            RsyncClientState::Auth => {
+                let tx = self.get_current_tx();
+                if tx.auth_challenge.is_some() && tx.auth_user.is_none() {
+                    // "<user> <response>"
+                    let user = line.split(|&c| c == b' ').next().unwrap_or(line);
+                    tx.auth_user = Some(user.to_vec());
+                }
+                self.client = RsyncClientState::Done;
+            }
+            RsyncClientState::Done => {}
+        }
+    }
+
+    fn handle_response_line(&mut self, line: &[u8]) {
+        let l = parser::classify_line(line);
+        if self.server == RsyncServerState::Greeting {
+            if let RsyncLine::Greeting(g) = l {
+                let tx = self.get_current_tx();
+                tx.server_version = Some(g.version.to_vec());
+                tx.server_digests = g.digests.iter().map(|d| d.to_vec()).collect();
+                self.server = RsyncServerState::Reply;
+            } else {
+                self.set_event(RsyncEvent::InvalidGreeting);
+                self.finish();
+            }
+            return;
+        }
+        match l {
+            RsyncLine::AuthRequired(challenge) => {
+                self.get_current_tx().auth_challenge = Some(challenge.to_vec());
+            }
+            RsyncLine::Ok => {
+                let tx = self.get_current_tx();
+                tx.result = Some(RsyncResult::Ok);
+                if tx.auth_challenge.is_none() {
+                    tx.tx_data
+                        .set_event(RsyncEvent::UnauthenticatedAccess as u8);
+                }
+                self.finish();
+            }
+            RsyncLine::Exit => {
+                let tx = self.get_current_tx();
+                tx.result = Some(RsyncResult::Exit);
+                if tx.listing && !tx.modules.is_empty() {
+                    tx.tx_data.set_event(RsyncEvent::ModuleListing as u8);
+                }
+                self.finish();
+            }
+            RsyncLine::Error(msg) => {
+                let tx = self.get_current_tx();
+                tx.result = Some(RsyncResult::Error(msg.to_vec()));
+                if tx.auth_challenge.is_some() {
+                    tx.tx_data.set_event(RsyncEvent::AuthFailed as u8);
+                }
+                self.finish();
+            }
+            RsyncLine::Greeting(_) => {}
+            RsyncLine::Text(text) => {
+                let tx = self.get_current_tx();
+                if tx.listing {
+                    if tx.modules.len() < RSYNC_MAX_LINES {
+                        let name = parser::listing_module_name(text);
+                        if !name.is_empty() {
+                            tx.modules.push(name.to_vec());
+                        }
+                    }
+                } else if tx.motd.len() < RSYNC_MAX_LINES {
+                    tx.motd.push(text.to_vec());
+                }
+            }
+        }
+    }
+
+    fn parse(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        let mut start = input;
+        while !start.is_empty() {
+            let done = if direction == Direction::ToServer {
+                self.client == RsyncClientState::Done
+            } else {
+                self.server == RsyncServerState::Done
+            };
+            if done {
+                return AppLayerResult::ok();
+            }
+            match parse_line(start) {
+                Ok((rem, line)) => {
+                    let _pdu = Frame::new(
+                        flow,
+                        stream_slice,
+                        start,
+                        (start.len() - rem.len()) as i64,
+                        RsyncFrameType::Line as u8,
+                        None,
+                    );
+                    start = rem;
+                    if direction == Direction::ToServer {
+                        self.handle_request_line(line);
+                    } else {
+                        self.handle_response_line(line);
+                    }
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    if start.len() > RSYNC_MAX_LINE_LEN {
+                        self.set_event(RsyncEvent::LineTooLong);
+                        self.finish();
+                        return AppLayerResult::ok();
+                    }
+                    let consumed = input.len() - start.len();
+                    let needed = start.len() + 1;
+                    return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                }
+                Err(_) => {
+                    return AppLayerResult::err();
+                }
+            }
+        }
+        // Input was fully consumed.
+        return AppLayerResult::ok();
+    }
+}
+
+// C exports.
+
+extern "C" fn rs_rsync_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = RsyncState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_rsync_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut RsyncState));
+}
+
+unsafe extern "C" fn rs_rsync_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, RsyncState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_rsync_parse_request(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, RsyncState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_rsync_parse_response(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, RsyncState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+        state.finish();
+        return AppLayerResult::ok();
+    }
+    state.parse(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_rsync_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, RsyncState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_rsync_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, RsyncState);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn rs_rsync_tx_get_alstate_progress(tx: *mut c_void, _direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, RsyncTransaction);
+    if tx.complete {
+        return 1;
+    }
+    return 0;
+}
+
+export_tx_data_get!(rs_rsync_get_tx_data, RsyncTransaction);
+export_state_data_get!(rs_rsync_get_state_data, RsyncState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"rsync\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterRsyncParser() {
+    let default_port = CString::new("[873]").unwrap();
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_TCP,
+        probe_ts: None,
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 16,
+        state_new: rs_rsync_state_new,
+        state_free: rs_rsync_state_free,
+        tx_free: rs_rsync_state_tx_free,
+        parse_ts: rs_rsync_parse_request,
+        parse_tc: rs_rsync_parse_response,
+        get_tx_count: rs_rsync_state_get_tx_count,
+        get_tx: rs_rsync_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_rsync_tx_get_alstate_progress,
+        get_eventinfo: Some(RsyncEvent::get_event_info),
+        get_eventinfo_byid: Some(RsyncEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<RsyncState, RsyncTransaction>),
+        get_tx_data: rs_rsync_get_tx_data,
+        get_state_data: rs_rsync_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(RsyncFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(RsyncFrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("tcp").unwrap();
+
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_RSYNC = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        SCLogDebug!("Rust rsync parser registered.");
+        AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_RSYNC);
+        for direction in [Direction::ToServer, Direction::ToClient] {
+            if AppLayerProtoDetectPMRegisterPatternCS(
+                IPPROTO_TCP,
+                ALPROTO_RSYNC,
+                b"@RSYNCD: \0".as_ptr() as *const c_char,
+                parser::RSYNC_GREETING_PREFIX.len() as u16,
+                0,
+                direction.into(),
+            ) < 0
+            {
+                SCLogDebug!("Failed to register rsync protocol detection pattern");
+            }
+        }
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for RSYNC.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(state: &mut RsyncState, direction: Direction, buf: &[u8]) -> AppLayerResult {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse(std::ptr::null(), &ss, direction)
+    }
+
+    #[test]
+    fn test_rsync_module_listing() {
+        let mut state = RsyncState::new();
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"@RSYNCD: 31.0 sha512 md5\n",
+        );
+        parse(&mut state, Direction::ToServer, b"@RSYNCD: 31.0\n#list\n");
+        let r = parse(
+            &mut state,
+            Direction::ToClient,
+            b"backup         \tNightly\nwww            \tweb root\n@RSYNCD: EXIT\n",
+        );
+        assert_eq!(r, AppLayerResult::ok());
+
+        assert_eq!(state.transactions.len(), 1);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.client_version.as_deref(), Some(&b"31.0"[..]));
+        assert_eq!(tx.server_digests.len(), 2);
+        assert!(tx.listing);
+        assert_eq!(tx.modules, vec![b"backup".to_vec(), b"www".to_vec()]);
+        assert_eq!(tx.result, Some(RsyncResult::Exit));
+        assert!(tx.complete);
+    }
+
+    #[test]
+    fn test_rsync_module_auth() {
+        let mut state = RsyncState::new();
+        parse(&mut state, Direction::ToClient, b"@RSYNCD: 31.0\n");
+        parse(&mut state, Direction::ToServer, b"@RSYNCD: 31.0\nbackup\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"@RSYNCD: AUTHREQD abcdef\n",
+        );
+        parse(&mut state, Direction::ToServer, b"operator c2VjcmV0\n");
+        parse(
+            &mut state,
+            Direction::ToClient,
+            b"@RSYNCD: OK\n\x00\x01\x02",
+        );
+
+        let tx = &state.transactions[0];
+        assert!(!tx.listing);
+        assert_eq!(tx.module.as_deref(), Some(&b"backup"[..]));
+        assert_eq!(tx.auth_challenge.as_deref(), Some(&b"abcdef"[..]));
+        assert_eq!(tx.auth_user.as_deref(), Some(&b"operator"[..]));
+        assert_eq!(tx.result, Some(RsyncResult::Ok));
+        assert_eq!(state.client, RsyncClientState::Done);
+
+        // EOF once the transaction was freed does not create another one
+        state.free_tx(0);
+        state.finish();
+        assert!(state.transactions.is_empty());
+        assert_eq!(state.tx_id, 1);
+    }
+}
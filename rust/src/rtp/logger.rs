@@ -0,0 +1,51 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::rtp::RtpTransaction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_rtp(tx: &RtpTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("rtp")?;
+    if !tx.expectation.call_id.is_empty() {
+        js.set_string("call_id", &tx.expectation.call_id)?;
+    }
+    if !tx.expectation.media.is_empty() {
+        js.set_string("media", &tx.expectation.media)?;
+    }
+    js.set_bool("rtcp", tx.expectation.rtcp)?;
+    if let Some(ref hdr) = tx.rtp_header {
+        js.set_uint("payload_type", hdr.payload_type as u64)?;
+        js.set_uint("ssrc", hdr.ssrc as u64)?;
+    }
+    if let Some(ref hdr) = tx.rtcp_header {
+        js.set_uint("packet_type", hdr.packet_type as u64)?;
+        js.set_uint("ssrc", hdr.ssrc as u64)?;
+    }
+    js.set_uint("packets", tx.packets)?;
+    js.set_uint("bytes", tx.bytes)?;
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRtpLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, RtpTransaction);
+    log_rtp(tx, js).is_ok()
+}
@@ -0,0 +1,135 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! RTP and RTCP fixed headers, RFC 3550.
+
+use crate::common::nom7::bits;
+use nom7::bits::streaming::take as take_bits;
+use nom7::combinator::verify;
+use nom7::number::streaming::{be_u16, be_u32, be_u8};
+use nom7::sequence::tuple;
+use nom7::IResult;
+
+pub const RTP_VERSION: u8 = 2;
+
+/// Packet types of the RTCP packets, the first being a sender report.
+pub const RTCP_TYPE_MIN: u8 = 200;
+pub const RTCP_TYPE_MAX: u8 = 204;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RtpHeader {
+    pub marker: bool,
+    pub payload_type: u8,
+    pub sequence: u16,
+    pub timestamp: u32,
+    pub ssrc: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RtcpHeader {
+    pub packet_type: u8,
+    /// Length in 32 bit words minus one.
+    pub length: u16,
+    /// SSRC of the sender of the report.
+    pub ssrc: u32,
+}
+
+/// Version, padding, extension and CSRC count or report count.
+fn parse_first_byte(i: &[u8]) -> IResult<&[u8], (u8, u8, u8, u8)> {
+    bits(tuple((
+        verify(take_bits(2u8), |&v| v == RTP_VERSION),
+        take_bits(1u8),
+        take_bits(1u8),
+        take_bits(4u8),
+    )))(i)
+}
+
+pub fn parse_rtp_header(i: &[u8]) -> IResult<&[u8], RtpHeader> {
+    let (i, _) = parse_first_byte(i)?;
+    let (i, marker_pt) = be_u8(i)?;
+    let (i, sequence) = be_u16(i)?;
+    let (i, timestamp) = be_u32(i)?;
+    let (i, ssrc) = be_u32(i)?;
+    Ok((
+        i,
+        RtpHeader {
+            marker: marker_pt & 0x80 != 0,
+            payload_type: marker_pt & 0x7f,
+            sequence,
+            timestamp,
+            ssrc,
+        },
+    ))
+}
+
+/// Parse the header of the first packet of a compound RTCP packet.
+pub fn parse_rtcp_header(i: &[u8]) -> IResult<&[u8], RtcpHeader> {
+    let (i, _) = parse_first_byte(i)?;
+    let (i, packet_type) = verify(be_u8, |t| (RTCP_TYPE_MIN..=RTCP_TYPE_MAX).contains(t))(i)?;
+    let (i, length) = be_u16(i)?;
+    let (i, ssrc) = be_u32(i)?;
+    Ok((
+        i,
+        RtcpHeader {
+            packet_type,
+            length,
+            ssrc,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rtp_header() {
+        let buf: &[u8] = &[
+            0x80, 0x88, 0x12, 0x34, 0x00, 0x00, 0x03, 0x20, 0xde, 0xad, 0xbe, 0xef, 0xd5, 0xd5,
+        ];
+        let (rem, hdr) = parse_rtp_header(buf).unwrap();
+        assert_eq!(rem, &[0xd5, 0xd5]);
+        assert_eq!(
+            hdr,
+            RtpHeader {
+                marker: true,
+                payload_type: 8,
+                sequence: 0x1234,
+                timestamp: 800,
+                ssrc: 0xdeadbeef,
+            }
+        );
+        assert!(parse_rtp_header(&buf[..11]).is_err());
+        // version 1
+        let buf: &[u8] = &[
+            0x40, 0x88, 0x12, 0x34, 0x00, 0x00, 0x03, 0x20, 0xde, 0xad, 0xbe, 0xef,
+        ];
+        assert!(parse_rtp_header(buf).is_err());
+    }
+
+    #[test]
+    fn test_parse_rtcp_header() {
+        let buf: &[u8] = &[0x81, 0xc9, 0x00, 0x07, 0xde, 0xad, 0xbe, 0xef];
+        let (_, hdr) = parse_rtcp_header(buf).unwrap();
+        assert_eq!(hdr.packet_type, 201);
+        assert_eq!(hdr.length, 7);
+        assert_eq!(hdr.ssrc, 0xdeadbeef);
+        // RTP packet with payload type 8
+        let buf: &[u8] = &[0x80, 0x08, 0x00, 0x07, 0xde, 0xad, 0xbe, 0xef];
+        assert!(parse_rtcp_header(buf).is_err());
+    }
+}
@@ -0,0 +1,387 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! RTP and RTCP flows, as announced by the SDP bodies of SIP messages.
+//!
+//! There is no protocol detection for RTP: the flows only get the
+//! protocol from the expectations registered by the SIP parser, along
+//! with the Call-ID of the call and the media they carry. The first
+//! packet of the flow is a transaction. If a log interval is configured,
+//! so is the first packet after each interval, with the packet and byte
+//! counts since the previous one. Flows can instead be bypassed once
+//! their first packet is seen.
+
+use super::parser::{self, RtcpHeader, RtpHeader};
+use crate::applayer::{self, *};
+use crate::conf::{conf_get, conf_get_bool};
+use crate::core::{AppProto, Direction, Flow, ALPROTO_UNKNOWN, IPPROTO_UDP};
+use crate::expectation::{expectation_get_data, expectation_register_proto};
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(crate) static mut ALPROTO_RTP: AppProto = ALPROTO_UNKNOWN;
+
+/// Bypass the flows once their first packet is seen.
+static mut RTP_BYPASS: bool = false;
+/// Seconds between the transactions of a flow, 0 for only the first.
+static mut RTP_LOG_INTERVAL: u64 = 0;
+
+/// Data of the expectations of RTP and RTCP flows.
+#[derive(Debug, Clone, Default)]
+pub struct RtpExpectation {
+    pub call_id: String,
+    /// The media type of the SDP media description, e.g. audio.
+    pub media: String,
+    pub rtcp: bool,
+}
+
+#[derive(AppLayerEvent)]
+pub enum RtpEvent {
+    /// Packet not starting with an RTP or RTCP header.
+    MalformedHeader,
+}
+
+#[derive(Debug, Default)]
+pub struct RtpTransaction {
+    tx_id: u64,
+    /// The expectation of the flow.
+    pub expectation: RtpExpectation,
+    /// Header of the first packet of an RTP flow.
+    pub rtp_header: Option<RtpHeader>,
+    /// Header of the first packet of an RTCP flow.
+    pub rtcp_header: Option<RtcpHeader>,
+    /// Packets and bytes since the previous transaction, for the
+    /// transactions of the log interval.
+    pub packets: u64,
+    pub bytes: u64,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for RtpTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+#[derive(Default)]
+pub struct RtpState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<RtpTransaction>,
+    expectation: RtpExpectation,
+    /// Time of the last transaction, in seconds.
+    last_tx_ts: u64,
+    packets: u64,
+    bytes: u64,
+}
+
+impl State<RtpTransaction> for RtpState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&RtpTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl RtpState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&RtpTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self, direction: Direction) -> RtpTransaction {
+        self.tx_id += 1;
+        RtpTransaction {
+            tx_id: self.tx_id,
+            expectation: self.expectation.clone(),
+            tx_data: AppLayerTxData::for_direction(direction),
+            ..Default::default()
+        }
+    }
+
+    /// Parse the first packet of the flow.
+    fn parse_first(&mut self, input: &[u8], direction: Direction, ts: u64) {
+        let mut tx = self.new_tx(direction);
+        let valid = if self.expectation.rtcp {
+            parser::parse_rtcp_header(input)
+                .map(|(_, hdr)| tx.rtcp_header = Some(hdr))
+                .is_ok()
+        } else {
+            parser::parse_rtp_header(input)
+                .map(|(_, hdr)| tx.rtp_header = Some(hdr))
+                .is_ok()
+        };
+        if !valid {
+            tx.tx_data.set_event(RtpEvent::MalformedHeader as u8);
+        }
+        tx.packets = 1;
+        tx.bytes = input.len() as u64;
+        self.transactions.push_back(tx);
+        self.last_tx_ts = ts;
+    }
+
+    /// Count a packet, adding a transaction with the counts once the
+    /// log interval has elapsed.
+    fn parse(&mut self, input: &[u8], direction: Direction, ts: u64, interval: u64) {
+        if self.tx_id == 0 {
+            self.parse_first(input, direction, ts);
+            return;
+        }
+        self.packets += 1;
+        self.bytes += input.len() as u64;
+        if interval > 0 && ts >= self.last_tx_ts + interval {
+            let mut tx = self.new_tx(direction);
+            tx.packets = std::mem::take(&mut self.packets);
+            tx.bytes = std::mem::take(&mut self.bytes);
+            self.transactions.push_back(tx);
+            self.last_tx_ts = ts;
+        }
+    }
+}
+
+/// Nothing is left to inspect or log once the first packet is seen.
+fn rtp_set_bypass_ready(pstate: *mut c_void) {
+    // no parser state when replaying packets in unit tests
+    if pstate.is_null() {
+        return;
+    }
+    unsafe {
+        AppLayerParserStateSetFlag(
+            pstate,
+            APP_LAYER_PARSER_NO_INSPECTION
+                | APP_LAYER_PARSER_NO_REASSEMBLY
+                | APP_LAYER_PARSER_BYPASS_READY,
+        );
+    }
+}
+
+unsafe fn rtp_flow_time(flow: *const Flow) -> u64 {
+    let flow = cast_pointer!(flow, Flow);
+    flow.get_last_time().as_secs()
+}
+
+unsafe fn rtp_parse(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    direction: Direction,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, RtpState);
+    let input = stream_slice.as_slice();
+    if input.is_empty() {
+        return AppLayerResult::ok();
+    }
+    if state.tx_id == 0 {
+        if let Some(expectation) = expectation_get_data::<RtpExpectation>(flow) {
+            state.expectation = expectation.clone();
+        }
+    }
+    state.parse(input, direction, rtp_flow_time(flow), RTP_LOG_INTERVAL);
+    if RTP_BYPASS {
+        rtp_set_bypass_ready(pstate);
+    }
+    AppLayerResult::ok()
+}
+
+// C exports.
+
+extern "C" fn rs_rtp_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = RtpState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_rtp_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut RtpState));
+}
+
+unsafe extern "C" fn rs_rtp_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, RtpState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_rtp_parse_ts(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    rtp_parse(flow, state, pstate, stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_rtp_parse_tc(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    rtp_parse(flow, state, pstate, stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_rtp_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, RtpState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_rtp_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, RtpState);
+    return state.tx_id;
+}
+
+extern "C" fn rs_rtp_tx_get_alstate_progress(_tx: *mut c_void, _direction: u8) -> c_int {
+    // Each transaction is complete when created.
+    return 1;
+}
+
+export_tx_data_get!(rs_rtp_get_tx_data, RtpTransaction);
+export_state_data_get!(rs_rtp_get_state_data, RtpState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"rtp\0";
+
+unsafe fn rtp_configure() {
+    RTP_BYPASS = conf_get_bool("app-layer.protocols.rtp.bypass");
+    if let Some(val) = conf_get("app-layer.protocols.rtp.log-interval") {
+        if let Ok(v) = val.parse::<u64>() {
+            RTP_LOG_INTERVAL = v;
+        } else {
+            SCLogError!("Invalid value for rtp.log-interval");
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterRtpParser() {
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        // only set by the expectations of the SIP parser
+        default_port: std::ptr::null(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: None,
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 0,
+        state_new: rs_rtp_state_new,
+        state_free: rs_rtp_state_free,
+        tx_free: rs_rtp_state_tx_free,
+        parse_ts: rs_rtp_parse_ts,
+        parse_tc: rs_rtp_parse_tc,
+        get_tx_count: rs_rtp_state_get_tx_count,
+        get_tx: rs_rtp_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_rtp_tx_get_alstate_progress,
+        get_eventinfo: Some(RtpEvent::get_event_info),
+        get_eventinfo_byid: Some(RtpEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<RtpState, RtpTransaction>),
+        get_tx_data: rs_rtp_get_tx_data,
+        get_state_data: rs_rtp_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: None,
+        get_frame_name_by_id: None,
+    };
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_RTP = alproto;
+        expectation_register_proto(IPPROTO_UDP, alproto);
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        rtp_configure();
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_RTP);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for RTP.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const RTP_PACKET: &[u8] = &[
+        0x80, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0xa0, 0x12, 0x34, 0x56, 0x78, 0xff, 0xff,
+    ];
+
+    #[test]
+    fn test_rtp_parse() {
+        let mut state = RtpState::new();
+        state.expectation = RtpExpectation {
+            call_id: "a84b4c76e66710".to_string(),
+            media: "audio".to_string(),
+            rtcp: false,
+        };
+        state.parse(RTP_PACKET, Direction::ToServer, 100, 0);
+        state.parse(RTP_PACKET, Direction::ToClient, 200, 0);
+        assert_eq!(state.transactions.len(), 1);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.expectation.call_id, "a84b4c76e66710");
+        assert_eq!(tx.rtp_header.as_ref().unwrap().ssrc, 0x12345678);
+        assert!(tx.rtcp_header.is_none());
+        assert_eq!(tx.packets, 1);
+        assert_eq!(tx.bytes, 14);
+
+        // an RTP packet on an RTCP flow
+        let mut state = RtpState::new();
+        state.expectation.rtcp = true;
+        state.parse(RTP_PACKET, Direction::ToServer, 100, 0);
+        let tx = &state.transactions[0];
+        assert!(tx.rtp_header.is_none());
+        assert!(tx.rtcp_header.is_none());
+    }
+
+    #[test]
+    fn test_rtp_parse_interval() {
+        let mut state = RtpState::new();
+        state.parse(RTP_PACKET, Direction::ToServer, 100, 10);
+        state.parse(RTP_PACKET, Direction::ToServer, 105, 10);
+        state.parse(RTP_PACKET, Direction::ToClient, 109, 10);
+        assert_eq!(state.transactions.len(), 1);
+        state.parse(RTP_PACKET, Direction::ToServer, 110, 10);
+        state.parse(RTP_PACKET, Direction::ToServer, 115, 10);
+        assert_eq!(state.transactions.len(), 2);
+        let tx = &state.transactions[1];
+        assert_eq!(tx.packets, 3);
+        assert_eq!(tx.bytes, 42);
+        assert!(tx.rtp_header.is_none());
+        assert_eq!(state.packets, 1);
+    }
+}
@@ -396,7 +396,7 @@ fn parse_attributes(i: &[u8]) -> IResult<&[u8], Vec<String>> {
     let (i, attrs) = many0(preceded(
         tag("a="),
         tuple((
-            map_res(take_while(is_alphabetic), std::str::from_utf8),
+            map_res(take_while(is_token_char), std::str::from_utf8),
             opt(preceded(
                 char_parser(':'),
                 map_res(take_till(is_line_ending), std::str::from_utf8),
@@ -676,6 +676,11 @@ mod tests {
         let (_, a) = parse_attributes(buf).expect("parsing failed");
         assert_eq!(a.first().unwrap(), "sendrecv");
         assert_eq!(a.get(1).unwrap(), "rtpmap:8 PCMA/8000/1");
+
+        let buf: &[u8] = "a=rtcp-mux\r\na=ice-ufrag:F7gI\r\n".as_bytes();
+        let (rem, a) = parse_attributes(buf).expect("parsing failed");
+        assert!(rem.is_empty());
+        assert_eq!(a, vec!["rtcp-mux", "ice-ufrag:F7gI"]);
     }
 
     #[test]
@@ -0,0 +1,316 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Tracking of the INVITE dialogs of a flow by Call-ID.
+//!
+//! A dialog starts with an INVITE, becomes early with a provisional
+//! response and confirmed with a 2xx response. The ACK of the caller
+//! establishes the call and a BYE of either side tears it down. A final
+//! response other than 2xx ends the dialog before it was confirmed.
+//!
+//! The time from the INVITE to the 2xx response and the time from the
+//! establishment to the BYE are kept, so that calls can be accounted for
+//! from the SIP records alone.
+
+use crate::sip::parser::{Request, Response};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Number of dialogs tracked per flow.
+const SIP_MAX_DIALOGS: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SIPDialogState {
+    /// INVITE sent, no response yet.
+    Calling,
+    /// Provisional response received.
+    Early,
+    /// 2xx response received, waiting for the ACK.
+    Confirmed,
+    /// 2xx response acknowledged.
+    Established,
+    /// Torn down by a BYE.
+    Terminated,
+    /// Ended by a final response other than 2xx.
+    Failed,
+}
+
+impl SIPDialogState {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            SIPDialogState::Calling => "calling",
+            SIPDialogState::Early => "early",
+            SIPDialogState::Confirmed => "confirmed",
+            SIPDialogState::Established => "established",
+            SIPDialogState::Terminated => "terminated",
+            SIPDialogState::Failed => "failed",
+        }
+    }
+}
+
+/// State of the dialog of a message, as of that message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SIPDialogInfo {
+    pub state: SIPDialogState,
+    /// Seconds from the INVITE to the 2xx response.
+    pub setup_time: Option<u64>,
+    /// Seconds from the establishment to the BYE.
+    pub duration: Option<u64>,
+    /// The message moved the dialog to `state`.
+    pub transition: bool,
+}
+
+#[derive(Debug)]
+struct SIPDialog {
+    state: SIPDialogState,
+    invite_ts: u64,
+    confirmed_ts: Option<u64>,
+    established_ts: Option<u64>,
+}
+
+impl SIPDialog {
+    fn info(&self, transition: bool, end_ts: Option<u64>) -> SIPDialogInfo {
+        let setup_time = self
+            .confirmed_ts
+            .map(|ts| ts.saturating_sub(self.invite_ts));
+        // Without the ACK, the call is considered up from the 2xx.
+        let start = self.established_ts.or(self.confirmed_ts);
+        let duration = match (start, end_ts) {
+            (Some(start), Some(end)) => Some(end.saturating_sub(start)),
+            _ => None,
+        };
+        SIPDialogInfo {
+            state: self.state,
+            setup_time,
+            duration,
+            transition,
+        }
+    }
+}
+
+pub(super) fn header_value<'a>(
+    headers: &'a HashMap<String, Vec<String>>, name: &str,
+) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .and_then(|(_, values)| values.first())
+        .map(|value| value.trim())
+}
+
+/// Get the method of the CSeq header, e.g. INVITE for "314159 INVITE".
+fn cseq_method(headers: &HashMap<String, Vec<String>>) -> Option<&str> {
+    header_value(headers, "CSeq")?.split_whitespace().nth(1)
+}
+
+/// INVITE dialogs of a flow by Call-ID.
+#[derive(Debug, Default)]
+pub struct SIPDialogTable {
+    dialogs: HashMap<String, SIPDialog>,
+}
+
+impl SIPDialogTable {
+    /// Update the dialog of a request seen at `ts`. Returns the state
+    /// of the dialog if the request belongs to one.
+    pub fn update_request(&mut self, request: &Request, ts: u64) -> Option<SIPDialogInfo> {
+        let call_id = header_value(&request.headers, "Call-ID")?;
+        if request.method == "INVITE" && !self.dialogs.contains_key(call_id) {
+            if self.dialogs.len() >= SIP_MAX_DIALOGS {
+                self.evict_oldest();
+            }
+            let dialog = SIPDialog {
+                state: SIPDialogState::Calling,
+                invite_ts: ts,
+                confirmed_ts: None,
+                established_ts: None,
+            };
+            let info = dialog.info(true, None);
+            self.dialogs.insert(call_id.to_string(), dialog);
+            return Some(info);
+        }
+        let mut entry = match self.dialogs.entry(call_id.to_string()) {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(_) => return None,
+        };
+        let dialog = entry.get_mut();
+        match (request.method.as_str(), dialog.state) {
+            ("ACK", SIPDialogState::Confirmed) => {
+                dialog.state = SIPDialogState::Established;
+                dialog.established_ts = Some(ts);
+                Some(dialog.info(true, None))
+            }
+            ("BYE", SIPDialogState::Confirmed | SIPDialogState::Established) => {
+                let mut dialog = entry.remove();
+                dialog.state = SIPDialogState::Terminated;
+                Some(dialog.info(true, Some(ts)))
+            }
+            _ => Some(dialog.info(false, None)),
+        }
+    }
+
+    /// Update the dialog of a response seen at `ts`. Returns the state
+    /// of the dialog if the response belongs to one.
+    pub fn update_response(&mut self, response: &Response, ts: u64) -> Option<SIPDialogInfo> {
+        let call_id = header_value(&response.headers, "Call-ID")?;
+        let mut entry = match self.dialogs.entry(call_id.to_string()) {
+            Entry::Occupied(entry) => entry,
+            Entry::Vacant(_) => return None,
+        };
+        let dialog = entry.get_mut();
+        let pending = matches!(
+            dialog.state,
+            SIPDialogState::Calling | SIPDialogState::Early
+        );
+        if !pending || cseq_method(&response.headers) != Some("INVITE") {
+            return Some(dialog.info(false, None));
+        }
+        match response.code.parse::<u16>().unwrap_or(0) {
+            101..=199 if dialog.state == SIPDialogState::Calling => {
+                dialog.state = SIPDialogState::Early;
+                Some(dialog.info(true, None))
+            }
+            200..=299 => {
+                dialog.state = SIPDialogState::Confirmed;
+                dialog.confirmed_ts = Some(ts);
+                Some(dialog.info(true, None))
+            }
+            300..=699 => {
+                let mut dialog = entry.remove();
+                dialog.state = SIPDialogState::Failed;
+                Some(dialog.info(true, None))
+            }
+            _ => Some(dialog.info(false, None)),
+        }
+    }
+
+    /// Drop the dialog that started first.
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .dialogs
+            .iter()
+            .min_by_key(|(_, dialog)| dialog.invite_ts)
+            .map(|(call_id, _)| call_id.clone());
+        if let Some(call_id) = oldest {
+            self.dialogs.remove(&call_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sip::parser::{sip_parse_request, sip_parse_response};
+
+    fn request(method: &str, call_id: &str) -> Request {
+        let buf = format!(
+            "{} sip:bob@biloxi.com SIP/2.0\r\n\
+             i: {}\r\n\
+             CSeq: 1 {}\r\n\
+             Content-Length: 0\r\n\
+             \r\n",
+            method, call_id, method
+        );
+        sip_parse_request(buf.as_bytes()).unwrap().1
+    }
+
+    fn response(code: &str, method: &str, call_id: &str) -> Response {
+        let buf = format!(
+            "SIP/2.0 {} Reason\r\n\
+             Call-ID: {}\r\n\
+             CSeq: 1 {}\r\n\
+             Content-Length: 0\r\n\
+             \r\n",
+            code, call_id, method
+        );
+        sip_parse_response(buf.as_bytes()).unwrap().1
+    }
+
+    #[test]
+    fn test_sip_dialog_call() {
+        let mut table = SIPDialogTable::default();
+        let info = table
+            .update_request(&request("INVITE", "a84b"), 100)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Calling);
+        assert!(info.transition);
+
+        // other calls are not affected
+        assert!(table
+            .update_response(&response("180", "INVITE", "x"), 101)
+            .is_none());
+
+        let info = table
+            .update_response(&response("100", "INVITE", "a84b"), 101)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Calling);
+        assert!(!info.transition);
+        let info = table
+            .update_response(&response("180", "INVITE", "a84b"), 102)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Early);
+        assert!(info.transition);
+        let info = table
+            .update_response(&response("200", "INVITE", "a84b"), 110)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Confirmed);
+        assert_eq!(info.setup_time, Some(10));
+        let info = table.update_request(&request("ACK", "a84b"), 111).unwrap();
+        assert_eq!(info.state, SIPDialogState::Established);
+        assert!(info.transition);
+        // retransmitted ACK
+        let info = table.update_request(&request("ACK", "a84b"), 112).unwrap();
+        assert!(!info.transition);
+
+        let info = table.update_request(&request("BYE", "a84b"), 171).unwrap();
+        assert_eq!(info.state, SIPDialogState::Terminated);
+        assert!(info.transition);
+        assert_eq!(info.setup_time, Some(10));
+        assert_eq!(info.duration, Some(60));
+        // the response to the BYE comes after the dialog ended
+        assert!(table
+            .update_response(&response("200", "BYE", "a84b"), 171)
+            .is_none());
+        assert!(table.dialogs.is_empty());
+    }
+
+    #[test]
+    fn test_sip_dialog_failed() {
+        let mut table = SIPDialogTable::default();
+        table.update_request(&request("INVITE", "a84b"), 100);
+        let info = table
+            .update_request(&request("CANCEL", "a84b"), 105)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Calling);
+        // the 200 of the CANCEL is not the answer of the INVITE
+        let info = table
+            .update_response(&response("200", "CANCEL", "a84b"), 105)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Calling);
+        let info = table
+            .update_response(&response("487", "INVITE", "a84b"), 105)
+            .unwrap();
+        assert_eq!(info.state, SIPDialogState::Failed);
+        assert_eq!(info.setup_time, None);
+        assert!(table.dialogs.is_empty());
+
+        for i in 0..SIP_MAX_DIALOGS + 1 {
+            table.update_request(&request("INVITE", &i.to_string()), i as u64);
+        }
+        assert_eq!(table.dialogs.len(), SIP_MAX_DIALOGS);
+        assert!(!table.dialogs.contains_key("0"));
+    }
+}
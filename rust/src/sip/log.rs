@@ -51,6 +51,18 @@ fn log(tx: &SIPTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
         js.set_string("response_line", resp_line)?;
     }
 
+    if let Some(dialog) = &tx.dialog {
+        js.open_object("dialog")?;
+        js.set_string("state", dialog.state.to_str())?;
+        if let Some(setup_time) = dialog.setup_time {
+            js.set_uint("setup_time", setup_time)?;
+        }
+        if let Some(duration) = dialog.duration {
+            js.set_uint("duration", duration)?;
+        }
+        js.close()?;
+    }
+
     js.close()?;
 
     Ok(())
@@ -0,0 +1,235 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Expectations of the RTP and RTCP flows of the SDP bodies.
+//!
+//! The address and ports of a media description are where the sender of
+//! the offer or answer receives the media. Each is registered as an
+//! expectation of the rtp protocol with the Call-ID, so that the media
+//! flows get the protocol whichever side sends first. Expectations are
+//! kept per IP pair of the SIP flow: media relayed through another host
+//! than the SIP endpoints is not expected. A media address and port is
+//! expected once per Call-ID, so that retransmissions, provisional
+//! responses and re-INVITEs repeating the same SDP do not add
+//! expectations.
+
+use crate::core::{Flow, ALPROTO_UNKNOWN, STREAM_TOCLIENT, STREAM_TOSERVER};
+use crate::expectation::expectation_create;
+use crate::rtp::rtp::{RtpExpectation, ALPROTO_RTP};
+use crate::sdp::parser::SdpMessage;
+use crate::sip::dialog::header_value;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+
+/// Most media flows tracked per SIP flow.
+const SIP_MAX_MEDIA_EXPECTATIONS: usize = 1024;
+
+/// A media flow of an SDP body.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SIPMediaFlow {
+    pub addr: IpAddr,
+    pub port: u16,
+    pub media: String,
+    pub rtcp: bool,
+}
+
+/// Get the port of an `a=rtcp` attribute, RFC 3605.
+fn rtcp_attribute_port(attributes: &[String]) -> Option<u16> {
+    attributes
+        .iter()
+        .find_map(|attr| attr.strip_prefix("rtcp:"))
+        .and_then(|value| value.split_whitespace().next())
+        .and_then(|port| port.parse().ok())
+}
+
+/// Get the RTP and RTCP flows of the media descriptions of an SDP body.
+///
+/// Media descriptions that are not RTP, disabled with port 0, on hold or
+/// without connection address are skipped. RTCP is on the next port
+/// unless given by an `a=rtcp` attribute, or multiplexed with RTP.
+pub fn sip_media_flows(sdp: &SdpMessage) -> Vec<SIPMediaFlow> {
+    let mut flows = Vec::new();
+    let session_addr = sdp.connection_data.as_ref().map(|c| c.connection_address);
+    for media in sdp.media_description.iter().flatten() {
+        if media.port == 0 || !media.proto.starts_with("RTP/") {
+            continue;
+        }
+        let addr = match media
+            .connection_data
+            .as_ref()
+            .map(|c| c.connection_address)
+            .or(session_addr)
+        {
+            Some(addr) if !addr.is_unspecified() => addr,
+            _ => continue,
+        };
+        flows.push(SIPMediaFlow {
+            addr,
+            port: media.port,
+            media: media.media.clone(),
+            rtcp: false,
+        });
+        let attributes = media.attributes.as_deref().unwrap_or_default();
+        if attributes.iter().any(|attr| attr == "rtcp-mux") {
+            continue;
+        }
+        let rtcp_port = rtcp_attribute_port(attributes).or_else(|| media.port.checked_add(1));
+        if let Some(port) = rtcp_port {
+            flows.push(SIPMediaFlow {
+                addr,
+                port,
+                media: media.media.clone(),
+                rtcp: true,
+            });
+        }
+    }
+    flows
+}
+
+/// Media flows already expected on a flow, by Call-ID, address and port.
+#[derive(Debug, Default)]
+pub struct SIPMediaTable {
+    expected: HashSet<(String, IpAddr, u16)>,
+}
+
+impl SIPMediaTable {
+    /// Track the media flow `addr` and `port` of the call `call_id`.
+    /// Returns false if it is already expected.
+    fn insert(&mut self, call_id: &str, addr: IpAddr, port: u16) -> bool {
+        let key = (call_id.to_string(), addr, port);
+        if self.expected.contains(&key) {
+            return false;
+        }
+        if self.expected.len() >= SIP_MAX_MEDIA_EXPECTATIONS {
+            self.expected.clear();
+        }
+        self.expected.insert(key)
+    }
+
+    /// Register the expectations of the media flows of the SDP body of a
+    /// message that are not expected yet.
+    pub fn expect(
+        &mut self, flow: *const Flow, headers: &HashMap<String, Vec<String>>,
+        sdp: Option<&SdpMessage>,
+    ) {
+        let alproto = unsafe { ALPROTO_RTP };
+        if alproto == ALPROTO_UNKNOWN || flow.is_null() {
+            return;
+        }
+        let sdp = match sdp {
+            Some(sdp) => sdp,
+            None => return,
+        };
+        let call_id = header_value(headers, "Call-ID").unwrap_or_default();
+        for media in sip_media_flows(sdp) {
+            if !unsafe { &*flow }.has_address(&media.addr) {
+                SCLogDebug!("media address {} not on the SIP flow", media.addr);
+                continue;
+            }
+            if !self.insert(call_id, media.addr, media.port) {
+                SCLogDebug!("media {}:{} already expected", media.addr, media.port);
+                continue;
+            }
+            let data = RtpExpectation {
+                call_id: call_id.to_string(),
+                media: media.media,
+                rtcp: media.rtcp,
+            };
+            if !expectation_create(
+                flow,
+                STREAM_TOSERVER | STREAM_TOCLIENT,
+                0,
+                media.port,
+                alproto,
+                data,
+            ) {
+                SCLogDebug!("no expectation created for port {}", media.port);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sdp::parser::sdp_parse_message;
+
+    #[test]
+    fn test_sip_media_flows() {
+        let buf: &[u8] = "v=0\r\n\
+                          o=alice 2890844526 2890844526 IN IP4 192.0.2.10\r\n\
+                          s=call\r\n\
+                          c=IN IP4 192.0.2.10\r\n\
+                          t=0 0\r\n\
+                          m=audio 49170 RTP/AVP 0 8\r\n\
+                          a=rtcp:53020\r\n\
+                          m=video 51372 RTP/AVP 31\r\n\
+                          c=IN IP4 192.0.2.20\r\n\
+                          a=rtcp-mux\r\n\
+                          m=audio 0 RTP/AVP 0\r\n"
+            .as_bytes();
+        let (_, sdp) = sdp_parse_message(buf).unwrap();
+        let flows = sip_media_flows(&sdp);
+        let addr: IpAddr = "192.0.2.10".parse().unwrap();
+        assert_eq!(
+            flows,
+            vec![
+                SIPMediaFlow {
+                    addr,
+                    port: 49170,
+                    media: "audio".to_string(),
+                    rtcp: false,
+                },
+                SIPMediaFlow {
+                    addr,
+                    port: 53020,
+                    media: "audio".to_string(),
+                    rtcp: true,
+                },
+                SIPMediaFlow {
+                    addr: "192.0.2.20".parse().unwrap(),
+                    port: 51372,
+                    media: "video".to_string(),
+                    rtcp: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sip_media_flows_hold() {
+        let buf: &[u8] = "v=0\r\n\
+                          o=bob 2808844564 2808844564 IN IP4 192.0.2.30\r\n\
+                          s=call\r\n\
+                          c=IN IP4 0.0.0.0\r\n\
+                          t=0 0\r\n\
+                          m=audio 49172 RTP/AVP 0\r\n"
+            .as_bytes();
+        let (_, sdp) = sdp_parse_message(buf).unwrap();
+        assert!(sip_media_flows(&sdp).is_empty());
+    }
+
+    #[test]
+    fn test_sip_media_table() {
+        let mut table = SIPMediaTable::default();
+        let addr: IpAddr = "192.0.2.10".parse().unwrap();
+        assert!(table.insert("a84b4c76e66710", addr, 49170));
+        assert!(!table.insert("a84b4c76e66710", addr, 49170));
+        assert!(table.insert("a84b4c76e66710", addr, 49171));
+        assert!(table.insert("f81d4fae7dec11d0", addr, 49170));
+    }
+}
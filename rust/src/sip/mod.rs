@@ -20,6 +20,9 @@
 // written by Giuseppe Longo <giuseppe@glongo.it>
 
 pub mod detect;
+pub mod dialog;
 pub mod log;
+pub mod media;
 pub mod parser;
+pub mod registration;
 pub mod sip;
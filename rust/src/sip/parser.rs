@@ -97,20 +97,41 @@ fn is_header_value(b: u8) -> bool {
     is_alphanumeric(b) || is_token_char(b) || b"\"#$&(),/;:<=>?@[]{}()^|~\\\t\n\r ".contains(&b)
 }
 
+/// Compact forms of header names, RFC 3261 section 7.3.3 and the
+/// IANA registry.
+const SIP_COMPACT_HEADERS: &[(u8, &str)] = &[
+    (b'a', "Accept-Contact"),
+    (b'b', "Referred-By"),
+    (b'c', "Content-Type"),
+    (b'd', "Request-Disposition"),
+    (b'e', "Content-Encoding"),
+    (b'f', "From"),
+    (b'i', "Call-ID"),
+    (b'j', "Reject-Contact"),
+    (b'k', "Supported"),
+    (b'l', "Content-Length"),
+    (b'm', "Contact"),
+    (b'o', "Event"),
+    (b'r', "Refer-To"),
+    (b's', "Subject"),
+    (b't', "To"),
+    (b'u', "Allow-Events"),
+    (b'v', "Via"),
+    (b'x', "Session-Expires"),
+];
+
+/// Normalize a header name that has a compact form to its regular
+/// form. Header names are case insensitive, so this also applies to
+/// the regular form in another case.
 fn expand_header_name(h: &str) -> &str {
-    match h {
-        "i" => "Call-ID",
-        "m" => "Contact",
-        "e" => "Content-Encoding",
-        "l" => "Content-Length",
-        "c" => "Content-Type",
-        "f" => "From",
-        "s" => "Subject",
-        "k" => "Supported",
-        "t" => "To",
-        "v" => "Via",
-        _ => h,
+    for (compact, name) in SIP_COMPACT_HEADERS {
+        if (h.len() == 1 && h.as_bytes()[0].to_ascii_lowercase() == *compact)
+            || h.eq_ignore_ascii_case(name)
+        {
+            return *name;
+        }
     }
+    h
 }
 
 pub fn sip_parse_request(oi: &[u8]) -> IResult<&[u8], Request> {
@@ -173,6 +194,38 @@ pub fn sip_parse_response(oi: &[u8]) -> IResult<&[u8], Response> {
     ))
 }
 
+/// Returns true if `i` starts with a request or status line, i.e. is
+/// not the continuation of a message.
+pub fn sip_is_message_start(i: &[u8]) -> bool {
+    fn request_line(i: &[u8]) -> IResult<&[u8], ()> {
+        let (i, _) = parse_method(i)?;
+        let (i, _) = char(' ')(i)?;
+        let (i, _) = parse_request_uri(i)?;
+        let (i, _) = char(' ')(i)?;
+        let (i, _) = parse_version(i)?;
+        Ok((i, ()))
+    }
+    fn status_line(i: &[u8]) -> IResult<&[u8], ()> {
+        let (i, _) = parse_version(i)?;
+        let (i, _) = char(' ')(i)?;
+        Ok((i, ()))
+    }
+    request_line(i).is_ok() || status_line(i).is_ok()
+}
+
+/// Length of the start line and headers, and length of the whole message
+/// starting at `i` as given by its Content-Length header. None if the start
+/// line and headers are not complete or there is no valid Content-Length.
+pub fn sip_message_len(i: &[u8]) -> Option<(usize, usize)> {
+    let start_line_len = i.windows(2).position(|w| w == b"\r\n")? + 2;
+    let (rem, headers) = parse_headers(&i[start_line_len..]).ok()?;
+    let (body, _) = crlf::<_, nom7::error::Error<_>>(rem).ok()?;
+    let content_length = headers.get("Content-Length")?.first()?;
+    let content_length: usize = content_length.trim().parse().ok()?;
+    let headers_len = i.len() - body.len();
+    Some((headers_len, headers_len + content_length))
+}
+
 #[inline]
 fn parse_method(i: &[u8]) -> IResult<&[u8], &str> {
     map_res(take_while(is_method_char), std::str::from_utf8)(i)
@@ -383,4 +436,47 @@ mod tests {
             "<sip:carol@chicago.com>"
         );
     }
+
+    #[test]
+    fn test_compact_headers() {
+        let buf: &[u8] = "INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+                          v: SIP/2.0/UDP pc33.atlanta.com\r\n\
+                          F: <sip:alice@atlanta.com>;tag=1928301774\r\n\
+                          t: <sip:bob@biloxi.com>\r\n\
+                          CALL-ID: a84b4c76e66710\r\n\
+                          l: 0\r\n\
+                          \r\n"
+            .as_bytes();
+
+        let (_, req) = sip_parse_request(buf).unwrap();
+        assert_eq!(
+            req.headers["Via"].first().unwrap(),
+            "SIP/2.0/UDP pc33.atlanta.com"
+        );
+        assert_eq!(
+            req.headers["From"].first().unwrap(),
+            "<sip:alice@atlanta.com>;tag=1928301774"
+        );
+        assert_eq!(req.headers["To"].first().unwrap(), "<sip:bob@biloxi.com>");
+        assert_eq!(req.headers["Call-ID"].first().unwrap(), "a84b4c76e66710");
+        assert_eq!(req.headers["Content-Length"].first().unwrap(), "0");
+        assert_eq!(expand_header_name("User-Agent"), "User-Agent");
+    }
+
+    #[test]
+    fn test_message_len() {
+        let buf: &[u8] = "INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+                          l: 10\r\n\
+                          \r\n\
+                          v=0\r\n"
+            .as_bytes();
+        assert_eq!(sip_message_len(buf), Some((buf.len() - 5, buf.len() + 5)));
+        assert!(sip_is_message_start(buf));
+        assert!(!sip_is_message_start(b"o=- 0 0 IN IP4 10.0.0.1\r\n"));
+        assert!(sip_is_message_start(b"SIP/2.0 200 OK\r\n"));
+
+        // No Content-Length, or headers not complete.
+        assert_eq!(sip_message_len(b"SIP/2.0 200 OK\r\n\r\n"), None);
+        assert_eq!(sip_message_len(b"SIP/2.0 200 OK\r\nl: 10\r\n"), None);
+    }
 }
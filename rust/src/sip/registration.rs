@@ -0,0 +1,233 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Tracking of the REGISTER requests of a flow per address-of-record.
+//!
+//! Credential stuffing against a registrar shows up as many REGISTER
+//! requests for the same address-of-record (AOR) in a short time. The
+//! timestamps of the attempts are kept per AOR and an event is raised
+//! when more than `max-attempts` are seen within `window` seconds.
+
+use crate::conf::conf_get;
+use crate::sip::parser::Request;
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug)]
+struct SIPRegisterFloodConfig {
+    enabled: bool,
+    /// Number of attempts allowed per AOR within the window.
+    max_attempts: usize,
+    /// Window in seconds.
+    window: u64,
+    /// Number of AORs tracked per flow.
+    max_aors: usize,
+}
+
+static mut SIP_REGISTER_FLOOD_CONFIG: SIPRegisterFloodConfig = SIPRegisterFloodConfig {
+    enabled: true,
+    max_attempts: 10,
+    window: 60,
+    max_aors: 256,
+};
+
+fn sip_register_flood_conf_u64(name: &str, value: &mut u64) {
+    let key = format!("app-layer.protocols.sip.register-flood.{}", name);
+    if let Some(val) = conf_get(&key) {
+        if let Ok(v) = val.parse::<u64>() {
+            *value = v;
+        } else {
+            SCLogError!("Invalid value for sip.register-flood.{}", name);
+        }
+    }
+}
+
+pub(super) unsafe fn register_flood_detection() {
+    if let Some(val) = conf_get("app-layer.protocols.sip.register-flood.enabled") {
+        SIP_REGISTER_FLOOD_CONFIG.enabled = matches!(val, "1" | "yes" | "true" | "on");
+    }
+    let mut max_attempts = SIP_REGISTER_FLOOD_CONFIG.max_attempts as u64;
+    sip_register_flood_conf_u64("max-attempts", &mut max_attempts);
+    SIP_REGISTER_FLOOD_CONFIG.max_attempts = max_attempts as usize;
+    sip_register_flood_conf_u64("window", &mut SIP_REGISTER_FLOOD_CONFIG.window);
+    let mut max_aors = SIP_REGISTER_FLOOD_CONFIG.max_aors as u64;
+    sip_register_flood_conf_u64("max-aors", &mut max_aors);
+    if max_aors == 0 {
+        SCLogError!("Invalid value for sip.register-flood.max-aors, using 1");
+        max_aors = 1;
+    }
+    SIP_REGISTER_FLOOD_CONFIG.max_aors = max_aors as usize;
+}
+
+/// Get the address-of-record of a REGISTER request from its To header:
+/// the URI without the display name, the angle brackets and the
+/// parameters.
+pub fn sip_register_aor(request: &Request) -> Option<&str> {
+    if request.method != "REGISTER" {
+        return None;
+    }
+    let to = request.headers.get("To")?.first()?;
+    let uri = match (to.find('<'), to.find('>')) {
+        (Some(start), Some(end)) if start < end => &to[start + 1..end],
+        _ => to.as_str(),
+    };
+    let aor = uri.split(';').next().unwrap_or_default().trim();
+    if aor.is_empty() {
+        return None;
+    }
+    Some(aor)
+}
+
+#[derive(Debug, Default)]
+struct SIPRegistrationEntry {
+    /// Timestamps of the attempts within the window, oldest first.
+    attempts: VecDeque<u64>,
+    /// Set once the event was raised, until the rate goes down again.
+    flood: bool,
+}
+
+impl SIPRegistrationEntry {
+    fn last_seen(&self) -> u64 {
+        self.attempts.back().copied().unwrap_or(0)
+    }
+}
+
+/// REGISTER attempts of a flow per address-of-record.
+#[derive(Debug, Default)]
+pub struct SIPRegistrationTable {
+    entries: HashMap<String, SIPRegistrationEntry>,
+}
+
+impl SIPRegistrationTable {
+    /// Record an attempt for `aor` at `ts`. Returns true if the AOR is
+    /// flooded, only once per flood.
+    pub fn update(&mut self, aor: &str, ts: u64) -> bool {
+        self.update_with_config(aor, ts, unsafe { &SIP_REGISTER_FLOOD_CONFIG })
+    }
+
+    fn update_with_config(&mut self, aor: &str, ts: u64, config: &SIPRegisterFloodConfig) -> bool {
+        if !config.enabled {
+            return false;
+        }
+        if !self.entries.contains_key(aor) && self.entries.len() >= config.max_aors {
+            self.evict_oldest();
+        }
+        let entry = self.entries.entry(aor.to_string()).or_default();
+        while let Some(&first) = entry.attempts.front() {
+            if ts.saturating_sub(first) < config.window {
+                break;
+            }
+            entry.attempts.pop_front();
+        }
+        // only keep what is needed to detect the flood
+        if entry.attempts.len() > config.max_attempts {
+            entry.attempts.pop_front();
+        }
+        entry.attempts.push_back(ts);
+
+        if entry.attempts.len() <= config.max_attempts {
+            entry.flood = false;
+            return false;
+        }
+        if entry.flood {
+            return false;
+        }
+        entry.flood = true;
+        true
+    }
+
+    /// Drop the AOR that was not seen for the longest time.
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_seen())
+            .map(|(aor, _)| aor.clone());
+        if let Some(aor) = oldest {
+            self.entries.remove(&aor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sip::parser::sip_parse_request;
+
+    #[test]
+    fn test_sip_register_aor() {
+        let buf: &[u8] = b"REGISTER sip:registrar.biloxi.com SIP/2.0\r\n\
+                           To: Bob <sip:bob@biloxi.com;transport=udp>;tag=1\r\n\
+                           Content-Length: 0\r\n\
+                           \r\n";
+        let (_, request) = sip_parse_request(buf).unwrap();
+        assert_eq!(sip_register_aor(&request), Some("sip:bob@biloxi.com"));
+
+        let buf: &[u8] = b"REGISTER sip:registrar.biloxi.com SIP/2.0\r\n\
+                           t: sip:alice@atlanta.com;tag=2\r\n\
+                           Content-Length: 0\r\n\
+                           \r\n";
+        let (_, request) = sip_parse_request(buf).unwrap();
+        assert_eq!(sip_register_aor(&request), Some("sip:alice@atlanta.com"));
+
+        let buf: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+                           To: <sip:bob@biloxi.com>\r\n\
+                           Content-Length: 0\r\n\
+                           \r\n";
+        let (_, request) = sip_parse_request(buf).unwrap();
+        assert_eq!(sip_register_aor(&request), None);
+    }
+
+    #[test]
+    fn test_sip_registration_flood() {
+        let config = SIPRegisterFloodConfig {
+            enabled: true,
+            max_attempts: 3,
+            window: 10,
+            max_aors: 2,
+        };
+        let mut table = SIPRegistrationTable::default();
+        for ts in 0..3 {
+            assert!(!table.update_with_config("sip:bob@biloxi.com", ts, &config));
+        }
+        // other AORs are counted separately
+        assert!(!table.update_with_config("sip:alice@atlanta.com", 3, &config));
+        assert!(table.update_with_config("sip:bob@biloxi.com", 4, &config));
+        // the event is only raised once per flood
+        assert!(!table.update_with_config("sip:bob@biloxi.com", 5, &config));
+
+        // the old attempts leave the window
+        assert!(!table.update_with_config("sip:bob@biloxi.com", 20, &config));
+        for ts in 21..23 {
+            assert!(!table.update_with_config("sip:bob@biloxi.com", ts, &config));
+        }
+        assert!(table.update_with_config("sip:bob@biloxi.com", 23, &config));
+
+        // the least recently seen AOR is evicted
+        assert!(!table.update_with_config("sip:carol@chicago.com", 24, &config));
+        assert_eq!(table.entries.len(), 2);
+        assert!(!table.entries.contains_key("sip:alice@atlanta.com"));
+
+        let disabled = SIPRegisterFloodConfig {
+            enabled: false,
+            ..config
+        };
+        let mut table = SIPRegistrationTable::default();
+        for ts in 0..5 {
+            assert!(!table.update_with_config("sip:bob@biloxi.com", ts, &disabled));
+        }
+    }
+}
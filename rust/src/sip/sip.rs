@@ -19,9 +19,12 @@
 
 use crate::applayer::{self, *};
 use crate::core;
-use crate::core::{AppProto, ALPROTO_UNKNOWN, IPPROTO_TCP, IPPROTO_UDP};
+use crate::core::{AppProto, Direction, ALPROTO_UNKNOWN, IPPROTO_TCP, IPPROTO_UDP};
 use crate::frames::*;
+use crate::sip::dialog::*;
+use crate::sip::media::SIPMediaTable;
 use crate::sip::parser::*;
+use crate::sip::registration::*;
 use nom7::Err;
 use std;
 use std::collections::VecDeque;
@@ -44,6 +47,27 @@ pub enum SIPFrameType {
 pub enum SIPEvent {
     IncompleteData,
     InvalidData,
+    /// A message split over UDP datagrams was followed by another message
+    /// before its body was complete.
+    IncompleteBody,
+    /// Too many REGISTER requests for the same address-of-record.
+    RegisterFlood,
+    /// The ACK of the caller established the call of an INVITE dialog.
+    DialogEstablished,
+    /// A BYE tore down the call of an INVITE dialog.
+    DialogTeardown,
+}
+
+/// Maximum size of a message reassembled from several UDP datagrams.
+const SIP_UDP_MAX_MESSAGE_SIZE: usize = 65535;
+
+/// A SIP over UDP message whose body, as given by its Content-Length,
+/// continues in the next datagrams. The transaction of the message is
+/// created from its headers, and gets the body once it is complete.
+struct SIPFragmentedMessage {
+    data: Vec<u8>,
+    len: usize,
+    tx_id: u64,
 }
 
 #[derive(Default)]
@@ -53,6 +77,13 @@ pub struct SIPState {
     tx_id: u64,
     request_frame: Option<Frame>,
     response_frame: Option<Frame>,
+    request_fragments: Option<SIPFragmentedMessage>,
+    response_fragments: Option<SIPFragmentedMessage>,
+    /// Time of the last message, in seconds.
+    ts: u64,
+    registrations: SIPRegistrationTable,
+    dialogs: SIPDialogTable,
+    media: SIPMediaTable,
 }
 
 impl State<SIPTransaction> for SIPState {
@@ -71,6 +102,10 @@ pub struct SIPTransaction {
     pub response: Option<Response>,
     pub request_line: Option<String>,
     pub response_line: Option<String>,
+    /// State of the INVITE dialog of the message.
+    pub dialog: Option<SIPDialogInfo>,
+    /// The body of the message is still to come in the next datagrams.
+    body_pending: bool,
     tx_data: applayer::AppLayerTxData,
 }
 
@@ -106,14 +141,66 @@ impl SIPState {
         }
     }
 
+    fn update_ts(&mut self, ts: u64) {
+        self.ts = ts;
+    }
+
+    /// Track the REGISTER requests per address-of-record, setting an
+    /// event on the transaction of the attempt that floods it.
+    fn track_registration(&mut self, tx: &mut SIPTransaction) {
+        if let Some(aor) = tx.request.as_ref().and_then(sip_register_aor) {
+            if self.registrations.update(aor, self.ts) {
+                SCLogDebug!("REGISTER flood for {}", aor);
+                tx.tx_data.set_event(SIPEvent::RegisterFlood as u8);
+            }
+        }
+    }
+
+    /// Track the INVITE dialog of the message of the transaction,
+    /// setting an event when the call is established or torn down.
+    fn track_dialog(&mut self, tx: &mut SIPTransaction) {
+        let info = if let Some(request) = &tx.request {
+            self.dialogs.update_request(request, self.ts)
+        } else if let Some(response) = &tx.response {
+            self.dialogs.update_response(response, self.ts)
+        } else {
+            None
+        };
+        if let Some(info) = info {
+            if info.transition {
+                match info.state {
+                    SIPDialogState::Established => {
+                        tx.tx_data.set_event(SIPEvent::DialogEstablished as u8);
+                    }
+                    SIPDialogState::Terminated => {
+                        tx.tx_data.set_event(SIPEvent::DialogTeardown as u8);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        tx.dialog = info;
+    }
+
     fn set_event(&mut self, event: SIPEvent) {
         if let Some(tx) = self.transactions.back_mut() {
             tx.tx_data.set_event(event as u8);
         }
     }
 
-    // app-layer-frame-documentation tag start: parse_request
-    fn parse_request(&mut self, flow: *const core::Flow, stream_slice: StreamSlice) -> bool {
+    fn fragments(&mut self, direction: Direction) -> &mut Option<SIPFragmentedMessage> {
+        match direction {
+            Direction::ToServer => &mut self.request_fragments,
+            Direction::ToClient => &mut self.response_fragments,
+        }
+    }
+
+    /// Parse a SIP over UDP datagram. The transaction of a message whose
+    /// body continues in the next datagrams is created from its headers,
+    /// and the message is buffered until it is complete.
+    fn parse_udp(
+        &mut self, flow: *const core::Flow, stream_slice: StreamSlice, direction: Direction,
+    ) -> bool {
         let input = stream_slice.as_slice();
         let _pdu = Frame::new(
             flow,
@@ -123,16 +210,126 @@ impl SIPState {
             SIPFrameType::Pdu as u8,
             None,
         );
-        SCLogDebug!("ts: pdu {:?}", _pdu);
+        SCLogDebug!("pdu {:?}", _pdu);
+
+        if let Some(mut msg) = self.fragments(direction).take() {
+            if !sip_is_message_start(input) {
+                msg.data.extend_from_slice(input);
+                if msg.data.len() < msg.len {
+                    *self.fragments(direction) = Some(msg);
+                    return true;
+                }
+                return self.complete_body(flow, &msg, direction);
+            }
+            // The next message started, the transaction keeps the headers.
+            self.end_body(flow, &msg, direction);
+        }
+
+        if let Some((headers_len, len)) = sip_message_len(input) {
+            if len > input.len() && len <= SIP_UDP_MAX_MESSAGE_SIZE {
+                SCLogDebug!("message of {} bytes split over datagrams", len);
+                let headers = &input[..headers_len];
+                if !self.parse_message(flow, Some(&stream_slice), headers, direction) {
+                    return false;
+                }
+                if let Some(tx) = self.transactions.back_mut() {
+                    tx.body_pending = true;
+                    let tx_id = tx.id;
+                    *self.fragments(direction) = Some(SIPFragmentedMessage {
+                        data: input.to_vec(),
+                        len,
+                        tx_id,
+                    });
+                }
+                return true;
+            }
+        }
+        self.parse_message(flow, Some(&stream_slice), input, direction)
+    }
+
+    /// Set the message `msg` on the transaction created from its headers.
+    /// Returns the transaction, and if the message could be parsed.
+    fn set_message(
+        &mut self, flow: *const core::Flow, msg: &SIPFragmentedMessage, direction: Direction,
+    ) -> Option<(&mut SIPTransaction, bool)> {
+        let tx = self.transactions.iter_mut().find(|tx| tx.id == msg.tx_id)?;
+        tx.body_pending = false;
+        let parsed = match direction {
+            Direction::ToServer => sip_parse_request(&msg.data).map(|(_, request)| {
+                self.media
+                    .expect(flow, &request.headers, request.body.as_ref());
+                tx.request = Some(request);
+            }),
+            Direction::ToClient => sip_parse_response(&msg.data).map(|(_, response)| {
+                self.media
+                    .expect(flow, &response.headers, response.body.as_ref());
+                tx.response = Some(response);
+            }),
+        };
+        Some((tx, parsed.is_ok()))
+    }
+
+    /// Set the complete message `msg` on its transaction.
+    fn complete_body(
+        &mut self, flow: *const core::Flow, msg: &SIPFragmentedMessage, direction: Direction,
+    ) -> bool {
+        match self.set_message(flow, msg, direction) {
+            Some((tx, false)) => {
+                tx.tx_data.set_event(SIPEvent::InvalidData as u8);
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// End the message `msg` without the rest of its body. Its transaction
+    /// gets what we have of the body if it parses, else keeps the headers.
+    fn end_body(
+        &mut self, flow: *const core::Flow, msg: &SIPFragmentedMessage, direction: Direction,
+    ) {
+        if let Some((tx, _)) = self.set_message(flow, msg, direction) {
+            tx.tx_data.set_event(SIPEvent::IncompleteBody as u8);
+        }
+    }
+
+    /// Flush the message still waiting for its body at the end of the flow.
+    fn flush_fragments(&mut self, flow: *const core::Flow, direction: Direction) {
+        if let Some(msg) = self.fragments(direction).take() {
+            SCLogDebug!("flushing incomplete message at EOF");
+            self.end_body(flow, &msg, direction);
+        }
+    }
+
+    /// Parse a complete message. The frames of the message are only created
+    /// if it was not reassembled, i.e. `stream_slice` is set.
+    fn parse_message(
+        &mut self, flow: *const core::Flow, stream_slice: Option<&StreamSlice>, input: &[u8],
+        direction: Direction,
+    ) -> bool {
+        match direction {
+            Direction::ToServer => self.parse_request(flow, stream_slice, input),
+            Direction::ToClient => self.parse_response(flow, stream_slice, input),
+        }
+    }
 
+    // app-layer-frame-documentation tag start: parse_request
+    fn parse_request(
+        &mut self, flow: *const core::Flow, stream_slice: Option<&StreamSlice>, input: &[u8],
+    ) -> bool {
         match sip_parse_request(input) {
             Ok((_, request)) => {
                 let mut tx = self.new_tx(crate::core::Direction::ToServer);
-                sip_frames_ts(flow, &stream_slice, &request, tx.id);
+                if let Some(stream_slice) = stream_slice {
+                    sip_frames_ts(flow, stream_slice, &request, tx.id);
+                }
+                self.media
+                    .expect(flow, &request.headers, request.body.as_ref());
                 tx.request = Some(request);
                 if let Ok((_, req_line)) = sip_take_line(input) {
                     tx.request_line = req_line;
                 }
+                self.track_registration(&mut tx);
+                self.track_dialog(&mut tx);
                 self.transactions.push_back(tx);
                 return true;
             }
@@ -174,10 +371,14 @@ impl SIPState {
                     let mut tx = self.new_tx(crate::core::Direction::ToServer);
                     let tx_id = tx.id;
                     sip_frames_ts(flow, &stream_slice, &request, tx_id);
+                    self.media
+                        .expect(flow, &request.headers, request.body.as_ref());
                     tx.request = Some(request);
                     if let Ok((_, req_line)) = sip_take_line(start) {
                         tx.request_line = req_line;
                     }
+                    self.track_registration(&mut tx);
+                    self.track_dialog(&mut tx);
                     self.transactions.push_back(tx);
                     let consumed = start.len() - rem.len();
                     start = rem;
@@ -209,26 +410,22 @@ impl SIPState {
         return AppLayerResult::ok();
     }
 
-    fn parse_response(&mut self, flow: *const core::Flow, stream_slice: StreamSlice) -> bool {
-        let input = stream_slice.as_slice();
-        let _pdu = Frame::new(
-            flow,
-            &stream_slice,
-            input,
-            input.len() as i64,
-            SIPFrameType::Pdu as u8,
-            None,
-        );
-        SCLogDebug!("tc: pdu {:?}", _pdu);
-
+    fn parse_response(
+        &mut self, flow: *const core::Flow, stream_slice: Option<&StreamSlice>, input: &[u8],
+    ) -> bool {
         match sip_parse_response(input) {
             Ok((_, response)) => {
                 let mut tx = self.new_tx(crate::core::Direction::ToClient);
-                sip_frames_tc(flow, &stream_slice, &response, tx.id);
+                if let Some(stream_slice) = stream_slice {
+                    sip_frames_tc(flow, stream_slice, &response, tx.id);
+                }
+                self.media
+                    .expect(flow, &response.headers, response.body.as_ref());
                 tx.response = Some(response);
                 if let Ok((_, resp_line)) = sip_take_line(input) {
                     tx.response_line = resp_line;
                 }
+                self.track_dialog(&mut tx);
                 self.transactions.push_back(tx);
                 return true;
             }
@@ -269,10 +466,13 @@ impl SIPState {
                     let mut tx = self.new_tx(crate::core::Direction::ToClient);
                     let tx_id = tx.id;
                     sip_frames_tc(flow, &stream_slice, &response, tx_id);
+                    self.media
+                        .expect(flow, &response.headers, response.body.as_ref());
                     tx.response = Some(response);
                     if let Ok((_, resp_line)) = sip_take_line(start) {
                         tx.response_line = resp_line;
                     }
+                    self.track_dialog(&mut tx);
                     self.transactions.push_back(tx);
                     let consumed = start.len() - rem.len();
                     start = rem;
@@ -313,6 +513,8 @@ impl SIPTransaction {
             response: None,
             request_line: None,
             response_line: None,
+            dialog: None,
+            body_pending: false,
             tx_data: applayer::AppLayerTxData::for_direction(direction),
         }
     }
@@ -429,21 +631,39 @@ pub unsafe extern "C" fn rs_sip_state_tx_free(state: *mut std::os::raw::c_void,
 }
 
 #[no_mangle]
-pub extern "C" fn rs_sip_tx_get_alstate_progress(
-    _tx: *mut std::os::raw::c_void, _direction: u8,
+pub unsafe extern "C" fn rs_sip_tx_get_alstate_progress(
+    tx: *mut std::os::raw::c_void, _direction: u8,
 ) -> std::os::raw::c_int {
+    let tx = cast_pointer!(tx, SIPTransaction);
+    if tx.body_pending {
+        return 0;
+    }
     1
 }
 
 pub static mut ALPROTO_SIP: AppProto = ALPROTO_UNKNOWN;
 
+unsafe fn sip_flow_time(flow: *const core::Flow) -> u64 {
+    let flow = cast_pointer!(flow, core::Flow);
+    flow.get_last_time().as_secs()
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_sip_parse_request(
-    flow: *const core::Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
+    flow: *const core::Flow, state: *mut std::os::raw::c_void, pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice, _data: *const std::os::raw::c_void,
 ) -> AppLayerResult {
     let state = cast_pointer!(state, SIPState);
-    state.parse_request(flow, stream_slice).into()
+    if stream_slice.is_empty() {
+        if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+            state.flush_fragments(flow, Direction::ToServer);
+        }
+        return AppLayerResult::ok();
+    }
+    state.update_ts(sip_flow_time(flow));
+    state
+        .parse_udp(flow, stream_slice, Direction::ToServer)
+        .into()
 }
 
 #[no_mangle]
@@ -460,16 +680,26 @@ pub unsafe extern "C" fn rs_sip_parse_request_tcp(
     }
 
     let state = cast_pointer!(state, SIPState);
+    state.update_ts(sip_flow_time(flow));
     state.parse_request_tcp(flow, stream_slice)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_sip_parse_response(
-    flow: *const core::Flow, state: *mut std::os::raw::c_void, _pstate: *mut std::os::raw::c_void,
+    flow: *const core::Flow, state: *mut std::os::raw::c_void, pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice, _data: *const std::os::raw::c_void,
 ) -> AppLayerResult {
     let state = cast_pointer!(state, SIPState);
-    state.parse_response(flow, stream_slice).into()
+    if stream_slice.is_empty() {
+        if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0 {
+            state.flush_fragments(flow, Direction::ToClient);
+        }
+        return AppLayerResult::ok();
+    }
+    state.update_ts(sip_flow_time(flow));
+    state
+        .parse_udp(flow, stream_slice, Direction::ToClient)
+        .into()
 }
 
 #[no_mangle]
@@ -486,6 +716,7 @@ pub unsafe extern "C" fn rs_sip_parse_response_tcp(
     }
 
     let state = cast_pointer!(state, SIPState);
+    state.update_ts(sip_flow_time(flow));
     state.parse_response_tcp(flow, stream_slice)
 }
 
@@ -574,12 +805,14 @@ pub unsafe extern "C" fn rs_sip_register_parser() {
         get_frame_name_by_id: Some(SIPFrameType::ffi_name_from_id),
     };
 
+    register_flood_detection();
+
     let ip_proto_str = CString::new("udp").unwrap();
     if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_SIP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if register_pattern_probe(core::IPPROTO_UDP) < 0 {
             return;
@@ -601,7 +834,7 @@ pub unsafe extern "C" fn rs_sip_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_SIP = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         if register_pattern_probe(core::IPPROTO_TCP) < 0 {
             return;
@@ -611,3 +844,113 @@ pub unsafe extern "C" fn rs_sip_register_parser() {
         SCLogDebug!("Protocol detection and parsing disabled for TCP SIP.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::STREAM_TOSERVER;
+
+    #[test]
+    fn test_sip_udp_fragmented_body() {
+        let headers: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+                               f: <sip:alice@atlanta.com>\r\n\
+                               c: application/sdp\r\n\
+                               l: 54\r\n\
+                               \r\n";
+        let body: &[u8] = b"v=0\r\n\
+                            o=- 0 0 IN IP4 10.0.0.1\r\n\
+                            s=-\r\n\
+                            t=0 0\r\n\
+                            ";
+        assert_eq!(body.len(), 42);
+        let flow = std::ptr::null();
+
+        let mut state = SIPState::new();
+        let first = [headers, &body[..10]].concat();
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(&first, STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        // The transaction is created from the headers.
+        assert_eq!(state.transactions.len(), 1);
+        assert!(state.transactions[0].body_pending);
+        // Twelve bytes short of the Content-Length.
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(&body[10..], STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert_eq!(state.transactions.len(), 1);
+        assert!(state.transactions[0].body_pending);
+
+        // The next request ends the incomplete one.
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(headers, STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert_eq!(state.transactions.len(), 2);
+        assert!(!state.transactions[0].body_pending);
+        let request = state.transactions[0].request.as_ref().unwrap();
+        assert_eq!(request.headers["From"].len(), 1);
+        assert_eq!(request.body_len, 42);
+        assert!(state.transactions[1].body_pending);
+        assert!(state.request_fragments.is_some());
+
+        let mut state = SIPState::new();
+        let body = [body, &b"a=sendrecv\r\n"[..]].concat();
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(headers, STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(&body[..20], STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(&body[20..], STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert_eq!(state.transactions.len(), 1);
+        assert!(state.request_fragments.is_none());
+        assert!(!state.transactions[0].body_pending);
+        let request = state.transactions[0].request.as_ref().unwrap();
+        assert_eq!(request.body_len, 54);
+    }
+
+    #[test]
+    fn test_sip_udp_inflated_content_length() {
+        let buf: &[u8] = b"INVITE sip:bob@biloxi.com SIP/2.0\r\n\
+                           f: <sip:alice@atlanta.com>\r\n\
+                           i: a84b4c76e66710\r\n\
+                           l: 1000\r\n\
+                           \r\n\
+                           v=0\r\n";
+        let flow = std::ptr::null();
+
+        // A lone INVITE claiming more body than it has is not held back.
+        let mut state = SIPState::new();
+        assert!(state.parse_udp(
+            flow,
+            StreamSlice::from_slice(buf, STREAM_TOSERVER, 0),
+            Direction::ToServer
+        ));
+        assert_eq!(state.transactions.len(), 1);
+        let tx = &state.transactions[0];
+        assert!(tx.body_pending);
+        let request = tx.request.as_ref().unwrap();
+        assert_eq!(request.method, "INVITE");
+        assert_eq!(request.headers["Call-ID"].len(), 1);
+
+        // It is flushed at the end of the flow.
+        state.flush_fragments(flow, Direction::ToServer);
+        assert!(state.request_fragments.is_none());
+        assert_eq!(state.transactions.len(), 1);
+        assert!(!state.transactions[0].body_pending);
+        assert!(state.transactions[0].request.is_some());
+    }
+}
@@ -23,6 +23,7 @@ use crate::smb::smb2::*;
 use crate::smb::dcerpc_records::*;
 use crate::smb::events::*;
 use crate::dcerpc::dcerpc::*;
+use crate::dcerpc::ndr::NDR_DREP_LITTLE_ENDIAN;
 use crate::smb::smb_status::*;
 
 impl SMBCommonHdr {
@@ -97,6 +98,7 @@ pub struct SMBTransactionDCERPC {
     pub frag_cnt_tc: u16,
     pub stub_data_ts: Vec<u8>,
     pub stub_data_tc: Vec<u8>,
+    pub stub_request: Option<DCERPCStubRequest>,
 }
 
 impl SMBTransactionDCERPC {
@@ -185,6 +187,7 @@ pub fn smb_write_dcerpc_record(state: &mut SMBState,
 {
     let mut bind_ifaces : Option<Vec<DCERPCIface>> = None;
     let mut is_bind = false;
+    let mut decode_stub_call_id : Option<(u32, bool)> = None;
 
     SCLogDebug!("called for {} bytes of data", data.len());
     match parse_dcerpc_record(data) {
@@ -221,6 +224,9 @@ pub fn smb_write_dcerpc_record(state: &mut SMBState,
                                 false
                             },
                         };
+                        if found && dcer.last_frag {
+                            smb_dcerpc_decode_request_stub(state, dcer.call_id, dcer.little_endian);
+                        }
                         return found;
                     },
                     _ => {
@@ -247,6 +253,7 @@ pub fn smb_write_dcerpc_record(state: &mut SMBState,
                             }
                             if dcer.last_frag {
                                 tx.request_done = true;
+                                decode_stub_call_id = Some((dcer.call_id, dcer.little_endian));
                             } else {
                                 SCLogDebug!("NOT last frag, so request side of DCERPC remains open");
                             }
@@ -281,7 +288,8 @@ pub fn smb_write_dcerpc_record(state: &mut SMBState,
                                     };
                                     let uuid_str = uuid::Uuid::from_slice(&x.clone());
                                     let _uuid_str = uuid_str.map(|uuid_str| uuid_str.to_hyphenated().to_string()).unwrap();
-                                    let d = DCERPCIface::new(x,i.ver,i.ver_min);
+                                    let mut d = DCERPCIface::new(x,i.ver,i.ver_min);
+                                    d.context_id = i.ctx_id;
                                     SCLogDebug!("UUID {} version {}/{} bytes {:?}",
                                             _uuid_str,
                                             i.ver, i.ver_min,i.iface);
@@ -318,9 +326,34 @@ pub fn smb_write_dcerpc_record(state: &mut SMBState,
         // that is part of the state
         state.dcerpc_ifaces = bind_ifaces; // TODO store per ssn
     }
+    if let Some((call_id, little_endian)) = decode_stub_call_id {
+        smb_dcerpc_decode_request_stub(state, call_id, little_endian);
+    }
     return true;
 }
 
+/// Decode the stub of a completed DCERPC request, based on the interface
+/// bound to its context id.
+fn smb_dcerpc_decode_request_stub(state: &mut SMBState, call_id: u32, little_endian: bool)
+{
+    let ifaces = match state.dcerpc_ifaces {
+        Some(ref ifaces) => ifaces,
+        None => { return; },
+    };
+    let drep_0 = if little_endian { NDR_DREP_LITTLE_ENDIAN } else { 0 };
+    for tx in state.transactions.iter_mut().rev() {
+        if let Some(SMBTransactionTypeData::DCERPC(ref mut tdn)) = tx.type_data {
+            if tdn.call_id == call_id && tdn.req_cmd == DCERPC_TYPE_REQUEST && tx.request_done {
+                if let Some(iface) = ifaces.iter().find(|i| i.context_id == tdn.context_id) {
+                    tdn.stub_request = dcerpc_decode_request_stub(&iface.uuid,
+                            tdn.opnum, &tdn.stub_data_ts, drep_0);
+                }
+                break;
+            }
+        }
+    }
+}
+
 /// Update TX for bind ack. Needs to update both tx and state.
 ///
 fn smb_dcerpc_response_bindack(
@@ -437,6 +470,53 @@ fn dcerpc_response_handle(tx: &mut SMBTransaction,
     }
 }
 
+impl SMBState {
+    /// Take the buffered data of a partial DCERPC reply.
+    pub fn dcerpc_rec_frag_take(&mut self, key: &SMBHashKeyHdrGuid) -> Option<Vec<u8>> {
+        let data = self.dcerpc_rec_frag_cache.pop(key)?;
+        self.dcerpc_rec_frag_bytes = self.dcerpc_rec_frag_bytes.saturating_sub(data.len());
+        Some(data)
+    }
+
+    /// Buffer the data of a partial DCERPC reply until the rest of it
+    /// comes in. The least recently used data is dropped to stay within
+    /// `max-dcerpc-frag-buffer-size`.
+    pub fn dcerpc_rec_frag_store(&mut self, key: SMBHashKeyHdrGuid, data: Vec<u8>) {
+        let limit = unsafe { SMB_CFG_MAX_FRAG_BUFFER_SIZE };
+        self.dcerpc_rec_frag_store_limit(key, data, limit);
+    }
+
+    fn dcerpc_rec_frag_store_limit(&mut self, key: SMBHashKeyHdrGuid, data: Vec<u8>, limit: usize) {
+        self.dcerpc_rec_frag_take(&key);
+        if data.len() > limit {
+            SCLogDebug!("DCERPC reply data of {} bytes dropped", data.len());
+            self.set_event(SMBEvent::BufferOverflowFragmented);
+            return;
+        }
+        let mut evicted = false;
+        while self.dcerpc_rec_frag_bytes + data.len() > limit {
+            match self.dcerpc_rec_frag_cache.pop_lru() {
+                Some((_, old)) => {
+                    self.dcerpc_rec_frag_bytes =
+                        self.dcerpc_rec_frag_bytes.saturating_sub(old.len());
+                    evicted = true;
+                }
+                None => {
+                    self.dcerpc_rec_frag_bytes = 0;
+                }
+            }
+        }
+        if evicted {
+            self.set_event(SMBEvent::BufferOverflowFragmented);
+        }
+        self.dcerpc_rec_frag_bytes += data.len();
+        // may evict the oldest entry on max-dcerpc-frag-cache-size
+        if let Some((_, old)) = self.dcerpc_rec_frag_cache.push(key, data) {
+            self.dcerpc_rec_frag_bytes = self.dcerpc_rec_frag_bytes.saturating_sub(old.len());
+        }
+    }
+}
+
 /// Handle DCERPC reply record. Called for READ, TRANS, IOCTL
 ///
 pub fn smb_read_dcerpc_record(state: &mut SMBState,
@@ -455,7 +535,7 @@ pub fn smb_read_dcerpc_record(state: &mut SMBState,
     // msg_id 0 as this data crosses cmd/reply pairs
     let ehdr = SMBHashKeyHdrGuid::new(SMBCommonHdr::new(SMBHDR_TYPE_TRANS_FRAG,
             hdr.ssn_id, hdr.tree_id, 0_u64), guid.to_vec());
-    let mut prevdata = state.dcerpc_rec_frag_cache.pop(&ehdr).unwrap_or_default();
+    let mut prevdata = state.dcerpc_rec_frag_take(&ehdr).unwrap_or_default();
     SCLogDebug!("indata {} prevdata {}", indata.len(), prevdata.len());
     prevdata.extend_from_slice(indata);
     let data = prevdata;
@@ -476,7 +556,7 @@ pub fn smb_read_dcerpc_record(state: &mut SMBState,
                 if ntstatus == SMB_NTSTATUS_BUFFER_OVERFLOW && data.len() < dcer.frag_len as usize {
                     SCLogDebug!("short record {} < {}: storing partial data in state",
                             data.len(), dcer.frag_len);
-                    state.dcerpc_rec_frag_cache.put(ehdr, data.to_vec());
+                    state.dcerpc_rec_frag_store(ehdr, data.to_vec());
                     return true; // TODO review
                 }
 
@@ -514,13 +594,17 @@ pub fn smb_read_dcerpc_record(state: &mut SMBState,
     return true;
 }
 
-/// Try to find out if the input data looks like DCERPC
+/// Try to find out if the input data looks like DCERPC: a version 5.0
+/// or 5.1 header of a connection oriented packet type.
 pub fn smb_dcerpc_probe(data: &[u8]) -> bool
 {
     if let Ok((_, recr)) = parse_dcerpc_record(data) {
         SCLogDebug!("SMB: could be DCERPC {:?}", recr);
-        if recr.version_major == 5 && recr.version_minor < 3 &&
-            recr.frag_len > 0 && recr.packet_type <= 20
+        let co_type = matches!(recr.packet_type,
+                DCERPC_TYPE_REQUEST | DCERPC_TYPE_RESPONSE | DCERPC_TYPE_FAULT |
+                DCERPC_TYPE_BIND..=DCERPC_TYPE_ORPHANED);
+        if recr.version_major == 5 && recr.version_minor <= 1 &&
+            recr.frag_len >= 16 && co_type
             {
                 SCLogDebug!("SMB: looks like we have dcerpc");
                 return true;
@@ -528,3 +612,68 @@ pub fn smb_dcerpc_probe(data: &[u8]) -> bool
     }
     return false;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(fid: u8) -> SMBHashKeyHdrGuid {
+        let hdr = SMBCommonHdr::new(SMBHDR_TYPE_TRANS_FRAG, 1, 1, 0);
+        SMBHashKeyHdrGuid::new(hdr, vec![fid, 0])
+    }
+
+    #[test]
+    fn test_smb_dcerpc_rec_frag_buffer_limit() {
+        let mut state = SMBState::new();
+        state.dcerpc_rec_frag_store_limit(key(1), vec![0; 40], 100);
+        state.dcerpc_rec_frag_store_limit(key(2), vec![0; 40], 100);
+        assert_eq!(state.dcerpc_rec_frag_bytes, 80);
+
+        // replacing the data of a key
+        state.dcerpc_rec_frag_store_limit(key(1), vec![0; 50], 100);
+        assert_eq!(state.dcerpc_rec_frag_bytes, 90);
+
+        // the least recently used data is dropped to make room
+        state.dcerpc_rec_frag_store_limit(key(3), vec![0; 30], 100);
+        assert_eq!(state.dcerpc_rec_frag_bytes, 80);
+        assert!(!state.dcerpc_rec_frag_cache.contains(&key(2)));
+        let data = state.dcerpc_rec_frag_take(&key(1)).unwrap();
+        assert_eq!(data.len(), 50);
+        assert_eq!(state.dcerpc_rec_frag_bytes, 30);
+
+        // data bigger than the limit is not buffered
+        state.dcerpc_rec_frag_store_limit(key(4), vec![0; 101], 100);
+        assert!(!state.dcerpc_rec_frag_cache.contains(&key(4)));
+        assert_eq!(state.dcerpc_rec_frag_bytes, 30);
+    }
+
+    #[test]
+    fn test_smb_dcerpc_probe() {
+        // BIND, little endian, frag_len 72
+        let mut bind = vec![
+            0x05, 0x00, 0x0b, 0x03, 0x10, 0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00,
+        ];
+        assert!(smb_dcerpc_probe(&bind));
+        // REQUEST of version 5.1
+        bind[1] = 1;
+        bind[2] = DCERPC_TYPE_REQUEST;
+        assert!(smb_dcerpc_probe(&bind));
+        // connectionless PING
+        bind[2] = DCERPC_TYPE_PING;
+        assert!(!smb_dcerpc_probe(&bind));
+        bind[2] = DCERPC_TYPE_RTS;
+        assert!(!smb_dcerpc_probe(&bind));
+        bind[2] = DCERPC_TYPE_BIND;
+        // version 4
+        bind[0] = 4;
+        assert!(!smb_dcerpc_probe(&bind));
+        bind[0] = 5;
+        // frag_len shorter than the header
+        bind[8] = 8;
+        assert!(!smb_dcerpc_probe(&bind));
+        assert!(!smb_dcerpc_probe(
+            b"MZ\x90\x00\x03\x00\x00\x00\x04\x00\x00\x00\xff\xff\x00\x00"
+        ));
+    }
+}
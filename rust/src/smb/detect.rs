@@ -17,13 +17,24 @@
 
 use crate::core::*;
 use crate::dcerpc::dcerpc::DCERPC_TYPE_REQUEST;
-use crate::dcerpc::detect::{DCEIfaceData, DCEOpnumData, DETECT_DCE_OPNUM_RANGE_UNINITIALIZED};
-use crate::detect::uint::detect_match_uint;
+use crate::dcerpc::detect::{DCEIfaceData, DCEOpnumData};
+use crate::detect::uint::{detect_match_uint, detect_parse_uint_enum, DetectUintData};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferMpmRegister, DetectHelperGetData,
+    DetectHelperGetMultiData, DetectHelperKeywordRegister, DetectHelperMultiBufferMpmRegister,
+    DetectSignatureSetAppProto, EnumString, SCSigTableElmt, SIGMATCH_INFO_STICKY_BUFFER,
+    SIGMATCH_NOOPT,
+};
 use crate::smb::smb::*;
+use crate::smb::smb_status::*;
 use std::ffi::CStr;
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 use std::ptr;
 
+static mut G_SMB_CREATE_CONTEXT_BUFFER_ID: c_int = 0;
+static mut G_SMB_SERVER_NATIVE_OS_BUFFER_ID: c_int = 0;
+static mut G_SMB_SERVER_NATIVE_LM_BUFFER_ID: c_int = 0;
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_smb_tx_get_share(
     tx: &mut SMBTransaction, buffer: *mut *const u8, buffer_len: *mut u32,
@@ -60,6 +71,25 @@ pub unsafe extern "C" fn rs_smb_tx_get_named_pipe(
     return 0;
 }
 
+/// Get the server component of the tree connect UNC path, for both
+/// shares and named pipes.
+#[no_mangle]
+pub unsafe extern "C" fn rs_smb_tx_get_share_host(
+    tx: &mut SMBTransaction, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> u8 {
+    if let Some(SMBTransactionTypeData::TREECONNECT(ref x)) = tx.type_data {
+        if !x.share_host.is_empty() {
+            *buffer = x.share_host.as_ptr();
+            *buffer_len = x.share_host.len() as u32;
+            return 1;
+        }
+    }
+
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return 0;
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_smb_tx_get_stub_data(
     tx: &mut SMBTransaction, direction: u8, buffer: *mut *const u8, buffer_len: *mut u32,
@@ -82,22 +112,64 @@ pub unsafe extern "C" fn rs_smb_tx_get_stub_data(
     return 0;
 }
 
+/// Get the file names touched by a transaction: the created, opened,
+/// deleted or queried file, or both the old and the new name of a
+/// rename. Names that are not known are skipped.
+fn smb_tx_get_filename(tx: &SMBTransaction, idx: u32) -> Option<&[u8]> {
+    let names: [&[u8]; 2] = match tx.type_data {
+        Some(SMBTransactionTypeData::CREATE(ref x)) => [x.filename.as_slice(), &[]],
+        Some(SMBTransactionTypeData::SETFILEPATHINFO(ref x)) => [x.filename.as_slice(), &[]],
+        Some(SMBTransactionTypeData::QUERYINFO(ref x)) => [x.filename.as_slice(), &[]],
+        Some(SMBTransactionTypeData::RENAME(ref x)) => [x.oldname.as_slice(), x.newname.as_slice()],
+        _ => return None,
+    };
+    names
+        .into_iter()
+        .filter(|n| !n.is_empty() && *n != b"<unknown>")
+        .nth(idx as usize)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rs_smb_tx_get_filename(
+    tx: &mut SMBTransaction, idx: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> u8 {
+    if let Some(name) = smb_tx_get_filename(tx, idx) {
+        *buffer = name.as_ptr();
+        *buffer_len = name.len() as u32;
+        return 1;
+    }
+
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return 0;
+}
+
+/// Get the dialect selected by the server in a SMB1 or SMB2 negotiate.
+#[no_mangle]
+pub unsafe extern "C" fn rs_smb_tx_get_dialect(
+    tx: &mut SMBTransaction, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> u8 {
+    if let Some(SMBTransactionTypeData::NEGOTIATE(ref x)) = tx.type_data {
+        if !x.dialect.is_empty() {
+            *buffer = x.dialect.as_ptr();
+            *buffer_len = x.dialect.len() as u32;
+            return 1;
+        }
+    }
+
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return 0;
+}
+
 #[no_mangle]
 pub extern "C" fn rs_smb_tx_match_dce_opnum(
     tx: &mut SMBTransaction, dce_data: &mut DCEOpnumData,
 ) -> u8 {
     SCLogDebug!("rs_smb_tx_get_dce_opnum: start");
     if let Some(SMBTransactionTypeData::DCERPC(ref x)) = tx.type_data {
-        if x.req_cmd == DCERPC_TYPE_REQUEST {
-            for range in dce_data.data.iter() {
-                if range.range2 == DETECT_DCE_OPNUM_RANGE_UNINITIALIZED {
-                    if range.range1 == x.opnum as u32 {
-                        return 1;
-                    }
-                } else if range.range1 <= x.opnum as u32 && range.range2 >= x.opnum as u32 {
-                    return 1;
-                }
-            }
+        if x.req_cmd == DCERPC_TYPE_REQUEST && dce_data.is_match(x.opnum) {
+            return 1;
         }
     }
 
@@ -160,6 +232,14 @@ pub unsafe extern "C" fn rs_smb_tx_get_ntlmssp_user(
             *buffer_len = ntlmssp.user.len() as u32;
             return 1;
         }
+        // session setup without extended security
+        if let Some(ref host) = x.request_host {
+            if let Some(ref account) = host.account_name {
+                *buffer = account.as_ptr();
+                *buffer_len = account.len() as u32;
+                return 1;
+            }
+        }
     }
 
     *buffer = ptr::null();
@@ -177,6 +257,14 @@ pub unsafe extern "C" fn rs_smb_tx_get_ntlmssp_domain(
             *buffer_len = ntlmssp.domain.len() as u32;
             return 1;
         }
+        // session setup without extended security
+        if let Some(ref host) = x.request_host {
+            if host.account_name.is_some() {
+                *buffer = host.primary_domain.as_ptr();
+                *buffer_len = host.primary_domain.len() as u32;
+                return 1;
+            }
+        }
     }
 
     *buffer = ptr::null();
@@ -230,9 +318,268 @@ pub unsafe extern "C" fn rs_smb_version_free(ptr: *mut c_void) {
     std::mem::drop(Box::from_raw(ptr as *mut u8));
 }
 
+/// Match if the session of the tx shows the scan-like read pattern.
+#[no_mangle]
+pub extern "C" fn rs_smb_tx_match_read_scan(state: &SMBState, tx: &SMBTransaction) -> u8 {
+    if let Some(scan) = state.get_read_scan(tx.hdr.ssn_id) {
+        if scan.flagged {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+/// NTSTATUS known by name, so that smb.ntstatus takes names like
+/// STATUS_ACCESS_DENIED as well as numeric values.
+struct SMBNtStatus(u32);
+
+impl EnumString<u32> for SMBNtStatus {
+    fn from_u(v: u32) -> Option<Self> {
+        smb_ntstatus_string(v).map(|_| SMBNtStatus(v))
+    }
+    fn into_u(self) -> u32 {
+        self.0
+    }
+    fn to_str(&self) -> &'static str {
+        smb_ntstatus_string(self.0).unwrap_or("")
+    }
+    fn from_str(s: &str) -> Option<Self> {
+        smb_ntstatus_from_string(s).map(SMBNtStatus)
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rs_smb_ntstatus_parse(carg: *const c_char) -> *mut DetectUintData<u32> {
+    if carg.is_null() {
+        return std::ptr::null_mut();
+    }
+    if let Ok(arg) = CStr::from_ptr(carg).to_str() {
+        if let Some(ctx) = detect_parse_uint_enum::<u32, SMBNtStatus>(arg.trim()) {
+            return Box::into_raw(Box::new(ctx));
+        }
+    }
+    return std::ptr::null_mut();
+}
+
+/// Match the status of the tx as NTSTATUS. SMB1 DOS errors are matched
+/// on their NTSTATUS equivalent.
+#[no_mangle]
+pub extern "C" fn rs_smb_tx_match_ntstatus(tx: &SMBTransaction, ctx: &DetectUintData<u32>) -> u8 {
+    if let Some(status) = tx.vercmd.get_status().and_then(|s| s.ntstatus()) {
+        if detect_match_uint(ctx, status) {
+            return 1;
+        }
+    }
+    return 0;
+}
+
+unsafe extern "C" fn smb_create_context_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SMB) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SMB_CREATE_CONTEXT_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn smb_create_context_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int, local_id: u32,
+) -> *mut c_void {
+    return DetectHelperGetMultiData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        local_id,
+        smb_create_context_get_data,
+    );
+}
+
+/// Get the name of the create context at `local_id` of a CREATE request.
+unsafe extern "C" fn smb_create_context_get_data(
+    tx: *const c_void, _flow_flags: u8, local_id: u32, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, SMBTransaction);
+    if let Some(SMBTransactionTypeData::CREATE(ref x)) = tx.type_data {
+        if let Some(name) = x.create_contexts.get(local_id as usize) {
+            *buffer = name.as_ptr();
+            *buffer_len = name.len() as u32;
+            return true;
+        }
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+/// Get the native OS or LAN Manager string of the server from the
+/// response of an SMB1 SESSION_SETUP_ANDX.
+fn smb_tx_get_server_host(tx: &SMBTransaction, lm: bool) -> Option<&[u8]> {
+    if let Some(SMBTransactionTypeData::SESSIONSETUP(ref x)) = tx.type_data {
+        let host = x.response_host.as_ref()?;
+        let s = if lm { &host.native_lm } else { &host.native_os };
+        if !s.is_empty() {
+            return Some(s);
+        }
+    }
+    return None;
+}
+
+unsafe extern "C" fn smb_server_native_os_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SMB) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SMB_SERVER_NATIVE_OS_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn smb_server_native_os_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        smb_server_native_os_get_data,
+    );
+}
+
+unsafe extern "C" fn smb_server_native_os_get_data(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, SMBTransaction);
+    if let Some(os) = smb_tx_get_server_host(tx, false) {
+        *buffer = os.as_ptr();
+        *buffer_len = os.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn smb_server_native_lm_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SMB) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SMB_SERVER_NATIVE_LM_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn smb_server_native_lm_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        smb_server_native_lm_get_data,
+    );
+}
+
+unsafe extern "C" fn smb_server_native_lm_get_data(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, SMBTransaction);
+    if let Some(lm) = smb_tx_get_server_host(tx, true) {
+        *buffer = lm.as_ptr();
+        *buffer_len = lm.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectSmbRegister() {
+    let kw = SCSigTableElmt {
+        name: b"smb.create_context\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the SMB2 create context names of a CREATE request\0"
+            .as_ptr() as *const libc::c_char,
+        url: b"/rules/smb-keywords.html#smb-create-context\0".as_ptr() as *const libc::c_char,
+        Setup: smb_create_context_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_smb_create_context_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SMB_CREATE_CONTEXT_BUFFER_ID = DetectHelperMultiBufferMpmRegister(
+        b"smb.create_context\0".as_ptr() as *const libc::c_char,
+        b"SMB2 create context name\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SMB,
+        false,
+        true,
+        smb_create_context_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"smb.server_native_os\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the native OS of the SMB1 server\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/smb-keywords.html#smb-server-native-os\0".as_ptr() as *const libc::c_char,
+        Setup: smb_server_native_os_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_smb_server_native_os_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SMB_SERVER_NATIVE_OS_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"smb.server_native_os\0".as_ptr() as *const libc::c_char,
+        b"SMB1 server native OS\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SMB,
+        true,
+        false,
+        smb_server_native_os_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"smb.server_native_lm\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the native LAN Manager of the SMB1 server\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/smb-keywords.html#smb-server-native-lm\0".as_ptr() as *const libc::c_char,
+        Setup: smb_server_native_lm_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_smb_server_native_lm_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SMB_SERVER_NATIVE_LM_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"smb.server_native_lm\0".as_ptr() as *const libc::c_char,
+        b"SMB1 server native LAN Manager\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SMB,
+        true,
+        false,
+        smb_server_native_lm_get,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::detect::uint::detect_parse_uint;
+    use crate::smb::session::SMBTransactionSessionSetup;
+    use crate::smb::smb1_session::SessionSetupResponse;
 
     #[test]
     fn test_parse_cmd_data() {
@@ -247,4 +594,112 @@ mod tests {
         assert_eq!(1u8, parse_version_data(" 1").unwrap());
         assert_eq!(2u8, parse_version_data(" 2 ").unwrap());
     }
+
+    #[test]
+    fn test_smb_tx_get_filename() {
+        let mut tx = SMBTransaction::new();
+        assert_eq!(smb_tx_get_filename(&tx, 0), None);
+
+        tx.type_data = Some(SMBTransactionTypeData::RENAME(SMBTransactionRename::new(
+            Vec::new(),
+            b"doc.txt".to_vec(),
+            b"doc.txt.locked".to_vec(),
+        )));
+        assert_eq!(smb_tx_get_filename(&tx, 0), Some(&b"doc.txt"[..]));
+        assert_eq!(smb_tx_get_filename(&tx, 1), Some(&b"doc.txt.locked"[..]));
+        assert_eq!(smb_tx_get_filename(&tx, 2), None);
+
+        // unknown old name, the new name is the only buffer
+        tx.type_data = Some(SMBTransactionTypeData::RENAME(SMBTransactionRename::new(
+            Vec::new(),
+            b"<unknown>".to_vec(),
+            b"doc.txt.locked".to_vec(),
+        )));
+        assert_eq!(smb_tx_get_filename(&tx, 0), Some(&b"doc.txt.locked"[..]));
+        assert_eq!(smb_tx_get_filename(&tx, 1), None);
+    }
+
+    #[test]
+    fn test_smb_tx_get_dialect() {
+        let mut tx = SMBTransaction::new();
+        let mut buf: *const u8 = ptr::null();
+        let mut len = 0;
+        tx.type_data = Some(SMBTransactionTypeData::NEGOTIATE(SMBTransactionNegotiate::new(1)));
+        assert_eq!(unsafe { rs_smb_tx_get_dialect(&mut tx, &mut buf, &mut len) }, 0);
+
+        if let Some(SMBTransactionTypeData::NEGOTIATE(ref mut x)) = tx.type_data {
+            x.dialect = b"NT LM 0.12".to_vec();
+        }
+        assert_eq!(unsafe { rs_smb_tx_get_dialect(&mut tx, &mut buf, &mut len) }, 1);
+        let dialect = unsafe { std::slice::from_raw_parts(buf, len as usize) };
+        assert_eq!(dialect, b"NT LM 0.12");
+    }
+
+    #[test]
+    fn test_smb_tx_get_server_host() {
+        let mut tx = SMBTransaction::new();
+        let td = SMBTransactionSessionSetup::new();
+        tx.type_data = Some(SMBTransactionTypeData::SESSIONSETUP(td));
+        assert_eq!(smb_tx_get_server_host(&tx, false), None);
+
+        let mut td = SMBTransactionSessionSetup::new();
+        td.response_host = Some(SessionSetupResponse {
+            native_os: b"Windows 5.1".to_vec(),
+            native_lm: Vec::new(),
+        });
+        tx.type_data = Some(SMBTransactionTypeData::SESSIONSETUP(td));
+        let os = smb_tx_get_server_host(&tx, false);
+        assert_eq!(os, Some(&b"Windows 5.1"[..]));
+        assert_eq!(smb_tx_get_server_host(&tx, true), None);
+    }
+
+    #[test]
+    fn test_smb_tx_get_share_host() {
+        let mut tx = SMBTransaction::new();
+        let mut buf: *const u8 = ptr::null();
+        let mut len = 0;
+        tx.type_data = Some(SMBTransactionTypeData::TREECONNECT(
+            SMBTransactionTreeConnect::new(b"IPC$".to_vec()),
+        ));
+        assert_eq!(unsafe { rs_smb_tx_get_share_host(&mut tx, &mut buf, &mut len) }, 0);
+
+        tx.type_data = Some(SMBTransactionTypeData::TREECONNECT(
+            SMBTransactionTreeConnect::new(b"\\\\10.0.0.5\\IPC$".to_vec()),
+        ));
+        assert_eq!(unsafe { rs_smb_tx_get_share_host(&mut tx, &mut buf, &mut len) }, 1);
+        let host = unsafe { std::slice::from_raw_parts(buf, len as usize) };
+        assert_eq!(host, b"10.0.0.5");
+    }
+
+    #[test]
+    fn test_smb_ntstatus_parse() {
+        let ctx = detect_parse_uint_enum::<u32, SMBNtStatus>("STATUS_LOGON_FAILURE").unwrap();
+        assert_eq!(ctx.arg1, SMB_NTSTATUS_LOGON_FAILURE);
+        let ctx = detect_parse_uint_enum::<u32, SMBNtStatus>("status_access_denied").unwrap();
+        assert_eq!(ctx.arg1, SMB_NTSTATUS_ACCESS_DENIED);
+        let ctx = detect_parse_uint_enum::<u32, SMBNtStatus>("0xc000006d").unwrap();
+        assert_eq!(ctx.arg1, SMB_NTSTATUS_LOGON_FAILURE);
+        assert!(detect_parse_uint_enum::<u32, SMBNtStatus>("STATUS_NOT_A_STATUS").is_none());
+    }
+
+    #[test]
+    fn test_smb_tx_match_ntstatus() {
+        let (_, ctx) = detect_parse_uint::<u32>("0xc0000022").unwrap();
+        let mut tx = SMBTransaction::new();
+        assert_eq!(rs_smb_tx_match_ntstatus(&tx, &ctx), 0);
+        tx.set_status(0xc0000022, false);
+        assert_eq!(rs_smb_tx_match_ntstatus(&tx, &ctx), 1);
+
+        // ERRDOS/ERRnoaccess, class in the low byte and code in the high word
+        let mut tx = SMBTransaction::new();
+        tx.set_status(0x0005_0001, true);
+        assert_eq!(rs_smb_tx_match_ntstatus(&tx, &ctx), 1);
+        assert_eq!(tx.vercmd.get_status().unwrap().name(), "DOS_ACCESS_DENIED");
+
+        // ERRSRV/ERRusempx has no NTSTATUS equivalent
+        let mut tx = SMBTransaction::new();
+        tx.set_status(0x00fa_0002, true);
+        let (_, ctx) = detect_parse_uint::<u32>(">0").unwrap();
+        assert_eq!(rs_smb_tx_match_ntstatus(&tx, &ctx), 0);
+    }
 }
\ No newline at end of file
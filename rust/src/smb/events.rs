@@ -18,6 +18,7 @@
 use crate::smb::smb::*;
 
 #[derive(AppLayerEvent)]
+#[ffi_prefix("rs_smb_state")]
 pub enum SMBEvent {
     InternalError,
     MalformedData,
@@ -50,6 +51,32 @@ pub enum SMBEvent {
     UnusualNtlmsspOrder,
     /// Too many live transactions in one flow
     TooManyTransactions,
+    /// CANCEL request for a command the server answered with
+    /// STATUS_PENDING
+    AsyncCommandCancelled,
+    /// Oplock/lease break notifications in a window reached the
+    /// `break-storm` threshold
+    OplockBreakStorm,
+    /// SMB1 negotiate without NT LM 0.12: the client did not offer it
+    /// or the server selected an older dialect
+    NegotiateDialectDowngrade,
+    /// TRANSFORM_HEADER seen: the session is encrypted and its records
+    /// can't be inspected anymore. Set once per flow.
+    EncryptedSession,
+    /// No record found after a gap, the parsing of the flow stops.
+    UnrecoverableGap,
+    /// Small reads of a session at scattered offsets across many files
+    /// reached the `read-scan` thresholds
+    ReadScanPattern,
+    /// Partial DCERPC replies sent with STATUS_BUFFER_OVERFLOW exceeded
+    /// `max-dcerpc-frag-buffer-size`, buffered data was dropped
+    BufferOverflowFragmented,
+    /// CREATE request with a timewarp token, opening a file from a
+    /// previous version (shadow copy)
+    CreateTimewarpToken,
+    /// The TREE_CONNECT was not seen and a READ or WRITE looked like
+    /// DCERPC, so the tree is handled as a pipe
+    DcerpcPipeInferred,
 }
 
 impl SMBTransaction {
@@ -0,0 +1,176 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! File name normalization shared by the SMB1 and SMB2 create, open,
+//! rename, delete and query paths, so that the names used for file
+//! tracking, detection and logging look the same regardless of the
+//! dialect or the string encoding used on the wire.
+
+use crate::smb::smb::SMB_CFG_FILENAME_PRESERVE_CASE;
+
+const SMB_PATH_SEPARATOR: u8 = b'\\';
+
+/// Decode a UTF-16LE name to UTF-8. Decoding stops at the first NUL
+/// character, invalid surrogates are replaced and a trailing odd byte
+/// is ignored.
+fn smb_filename_decode_utf16(raw: &[u8]) -> String {
+    let units = raw
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&u| u != 0);
+    char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Use backslashes as the only path separator and collapse runs of
+/// separators. A leading double backslash of a UNC path is kept.
+fn smb_filename_normalize_separators(name: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(name.len());
+    for (idx, &c) in name.iter().enumerate() {
+        let c = if c == b'/' { SMB_PATH_SEPARATOR } else { c };
+        if c == SMB_PATH_SEPARATOR && out.last() == Some(&SMB_PATH_SEPARATOR) && idx != 1 {
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Normalize a file name as found on the wire.
+///
+/// `unicode` selects UTF-16LE decoding, otherwise the name is taken
+/// as is up to the first NUL byte. Unless `preserve_case` is set the
+/// name is lowercased.
+pub fn smb_filename_normalize(raw: &[u8], unicode: bool, preserve_case: bool) -> Vec<u8> {
+    let name = if unicode {
+        let name = smb_filename_decode_utf16(raw);
+        if preserve_case {
+            name.into_bytes()
+        } else {
+            name.to_lowercase().into_bytes()
+        }
+    } else {
+        let len = raw.iter().position(|&c| c == 0).unwrap_or(raw.len());
+        let mut name = raw[..len].to_vec();
+        if !preserve_case {
+            name.make_ascii_lowercase();
+        }
+        name
+    };
+    smb_filename_normalize_separators(&name)
+}
+
+/// Normalize a file name using the configured case handling.
+pub fn smb_filename(raw: &[u8], unicode: bool) -> Vec<u8> {
+    smb_filename_normalize(raw, unicode, unsafe { SMB_CFG_FILENAME_PRESERVE_CASE })
+}
+
+/// Split the UNC path of a tree connect, `\\server\share`, into its
+/// server and share components. Forward slashes are accepted as
+/// separators. Returns `None` if there is no server component.
+pub fn smb_parse_unc_path(path: &[u8]) -> Option<(&[u8], &[u8])> {
+    let is_sep = |c: &u8| *c == SMB_PATH_SEPARATOR || *c == b'/';
+    if path.len() < 2 || !path[..2].iter().all(is_sep) {
+        return None;
+    }
+    let path = &path[2..];
+    let host_len = path.iter().position(is_sep).unwrap_or(path.len());
+    if host_len == 0 {
+        return None;
+    }
+    let share = &path[host_len..];
+    let skip = share.iter().take_while(|c| is_sep(c)).count();
+    Some((&path[..host_len], &share[skip..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_smb_filename_unicode() {
+        let raw = utf16("Dir\\File.TXT");
+        assert_eq!(smb_filename_normalize(&raw, true, true), b"Dir\\File.TXT");
+        assert_eq!(smb_filename_normalize(&raw, true, false), b"dir\\file.txt");
+
+        // Non-ASCII characters are kept instead of losing their high byte.
+        let raw = utf16("\\r\u{e9}sum\u{e9}.doc");
+        assert_eq!(
+            smb_filename_normalize(&raw, true, true),
+            "\\r\u{e9}sum\u{e9}.doc".as_bytes()
+        );
+
+        // NUL terminator, trailing padding and odd trailing byte.
+        let mut raw = utf16("a.txt");
+        raw.extend_from_slice(&[0, 0, 0x41, 0, 0]);
+        assert_eq!(smb_filename_normalize(&raw, true, true), b"a.txt");
+        assert!(smb_filename_normalize(&[], true, true).is_empty());
+    }
+
+    #[test]
+    fn test_smb_filename_ascii() {
+        assert_eq!(
+            smb_filename_normalize(b"\\SHARE\\A.TXT\x00", false, true),
+            b"\\SHARE\\A.TXT"
+        );
+        assert_eq!(
+            smb_filename_normalize(b"\\SHARE\\A.TXT\x00", false, false),
+            b"\\share\\a.txt"
+        );
+    }
+
+    #[test]
+    fn test_smb_filename_separators() {
+        assert_eq!(
+            smb_filename_normalize(b"dir//sub\\\\a.txt", false, true),
+            b"dir\\sub\\a.txt"
+        );
+        assert_eq!(
+            smb_filename_normalize(b"\\\\server\\\\share", false, true),
+            b"\\\\server\\share"
+        );
+        assert_eq!(
+            smb_filename_normalize(b"//server/share/a", false, true),
+            b"\\\\server\\share\\a"
+        );
+        assert_eq!(smb_filename_normalize(b"\\", false, true), b"\\");
+    }
+
+    #[test]
+    fn test_smb_parse_unc_path() {
+        assert_eq!(
+            smb_parse_unc_path(b"\\\\192.168.1.1\\IPC$"),
+            Some((&b"192.168.1.1"[..], &b"IPC$"[..]))
+        );
+        assert_eq!(
+            smb_parse_unc_path(b"//fileserver/share"),
+            Some((&b"fileserver"[..], &b"share"[..]))
+        );
+        assert_eq!(
+            smb_parse_unc_path(b"\\\\host"),
+            Some((&b"host"[..], &b""[..]))
+        );
+        assert_eq!(smb_parse_unc_path(b"\\host\\share"), None);
+        assert_eq!(smb_parse_unc_path(b"\\\\\\share"), None);
+        assert_eq!(smb_parse_unc_path(b"IPC$"), None);
+    }
+}
@@ -0,0 +1,248 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! SMB command latency.
+//!
+//! The latency of a transaction is the time between the first packet of
+//! its request and the packet completing its response. Latencies are
+//! counted in histograms of power of 2 buckets of microseconds: per
+//! command for each flow, logged with the flow, and for all the flows,
+//! for the stats. Percentiles are the upper bound of their bucket.
+
+use crate::core::Direction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use crate::smb::smb::*;
+use crate::smb::smb1::smb1_command_string;
+use crate::smb::smb2::smb2_command_string;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Number of buckets. Bucket `n` counts the latencies below `2^n`
+/// microseconds, the last one all the others.
+const LATENCY_BUCKETS: usize = 32;
+
+fn latency_bucket(latency: u64) -> usize {
+    std::cmp::min(
+        (u64::BITS - latency.leading_zeros()) as usize,
+        LATENCY_BUCKETS - 1,
+    )
+}
+
+/// Get the `p`th percentile of the latencies counted in `buckets`.
+fn buckets_percentile(buckets: &[u64; LATENCY_BUCKETS], max: u64, p: u64) -> u64 {
+    let count: u64 = buckets.iter().sum();
+    let rank = (count * p + 99) / 100;
+    let mut seen = 0;
+    for (n, c) in buckets.iter().enumerate() {
+        seen += c;
+        if seen >= rank && seen > 0 {
+            if n == LATENCY_BUCKETS - 1 {
+                break;
+            }
+            return std::cmp::min((1 << n) - 1, max);
+        }
+    }
+    max
+}
+
+#[derive(Debug, Default)]
+pub struct LatencyHistogram {
+    buckets: [u64; LATENCY_BUCKETS],
+    count: u64,
+    max: u64,
+}
+
+impl LatencyHistogram {
+    pub fn add(&mut self, latency: u64) {
+        self.buckets[latency_bucket(latency)] += 1;
+        self.count += 1;
+        self.max = std::cmp::max(self.max, latency);
+    }
+
+    pub fn percentile(&self, p: u64) -> u64 {
+        buckets_percentile(&self.buckets, self.max, p)
+    }
+
+    fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        js.set_uint("count", self.count)?;
+        js.set_uint("p50", self.percentile(50))?;
+        js.set_uint("p90", self.percentile(90))?;
+        js.set_uint("p99", self.percentile(99))?;
+        js.set_uint("max", self.max)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const BUCKET_INIT: AtomicU64 = AtomicU64::new(0);
+static SMB_LATENCY_BUCKETS: [AtomicU64; LATENCY_BUCKETS] = [BUCKET_INIT; LATENCY_BUCKETS];
+static SMB_LATENCY_MAX: AtomicU64 = AtomicU64::new(0);
+
+fn smb_latency_global_add(latency: u64) {
+    SMB_LATENCY_BUCKETS[latency_bucket(latency)].fetch_add(1, Ordering::Relaxed);
+    SMB_LATENCY_MAX.fetch_max(latency, Ordering::Relaxed);
+}
+
+fn smb_latency_global_percentile(p: u64) -> u64 {
+    let buckets = std::array::from_fn(|n| SMB_LATENCY_BUCKETS[n].load(Ordering::Relaxed));
+    buckets_percentile(&buckets, SMB_LATENCY_MAX.load(Ordering::Relaxed), p)
+}
+
+/// Latencies of a flow, per SMB version and command.
+#[derive(Debug, Default)]
+pub struct SMBLatencies {
+    commands: BTreeMap<(u8, u16), LatencyHistogram>,
+}
+
+impl SMBLatencies {
+    fn add(&mut self, tx: &SMBTransaction, latency: u64) {
+        let (ok, cmd) = match tx.vercmd.get_version() {
+            1 => {
+                let (ok, cmd) = tx.vercmd.get_smb1_cmd();
+                (ok, cmd as u16)
+            }
+            2 => tx.vercmd.get_smb2_cmd(),
+            _ => (false, 0),
+        };
+        if !ok {
+            return;
+        }
+        self.commands
+            .entry((tx.vercmd.get_version(), cmd))
+            .or_default()
+            .add(latency);
+        smb_latency_global_add(latency);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        js.open_object("latencies")?;
+        for ((version, cmd), histogram) in &self.commands {
+            let name = if *version == 1 {
+                smb1_command_string(*cmd as u8)
+            } else {
+                smb2_command_string(*cmd)
+            };
+            js.open_object(&name)?;
+            histogram.log(js)?;
+            js.close()?;
+        }
+        js.close()?;
+        Ok(())
+    }
+}
+
+impl SMBState {
+    /// Record the latency of the transactions completed by the data just
+    /// parsed in `direction`. Only the transactions up to `tx_id`, the
+    /// last one before the data, completed by a response have one: the
+    /// others were created by the response or completed by a request.
+    pub fn update_latencies(&mut self, direction: Direction, tx_id: u64) {
+        for tx in self.transactions.iter_mut() {
+            if tx.latency_done || !tx.response_done {
+                continue;
+            }
+            tx.latency_done = true;
+            if direction == Direction::ToClient && tx.id <= tx_id {
+                let latency = self.ts_usec.saturating_sub(tx.request_ts);
+                tx.latency = Some(latency);
+                self.latencies.add(tx, latency);
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn SCSmbLatencyP50() -> u64 {
+    smb_latency_global_percentile(50)
+}
+
+#[no_mangle]
+pub extern "C" fn SCSmbLatencyP90() -> u64 {
+    smb_latency_global_percentile(90)
+}
+
+#[no_mangle]
+pub extern "C" fn SCSmbLatencyP99() -> u64 {
+    smb_latency_global_percentile(99)
+}
+
+#[no_mangle]
+pub extern "C" fn SCSmbLatencyMax() -> u64 {
+    SMB_LATENCY_MAX.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::smb::smb2::SMB2_COMMAND_READ;
+
+    #[test]
+    fn test_latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.percentile(50), 0);
+        for latency in 1..=90 {
+            histogram.add(latency * 10);
+        }
+        for _ in 0..9 {
+            histogram.add(5_000);
+        }
+        histogram.add(100_000);
+        assert_eq!(histogram.count, 100);
+        // the 50th latency, 500us, is in the [256, 512) bucket
+        assert_eq!(histogram.percentile(50), 511);
+        assert_eq!(histogram.percentile(90), 1023);
+        assert_eq!(histogram.percentile(99), 8191);
+        assert_eq!(histogram.percentile(100), 100_000);
+    }
+
+    #[test]
+    fn test_latency_bucket() {
+        assert_eq!(latency_bucket(0), 0);
+        assert_eq!(latency_bucket(1), 1);
+        assert_eq!(latency_bucket(3), 2);
+        assert_eq!(latency_bucket(4), 3);
+        assert_eq!(latency_bucket(u64::MAX), LATENCY_BUCKETS - 1);
+    }
+
+    #[test]
+    fn test_update_latencies() {
+        let mut state = SMBState::new();
+        state.ts_usec = 1_000;
+        let tx_id = state
+            .new_generic_tx(2, SMB2_COMMAND_READ, SMBCommonHdr::default())
+            .id;
+        state.update_latencies(Direction::ToServer, tx_id);
+
+        state.ts_usec = 1_250;
+        state.transactions.back_mut().unwrap().response_done = true;
+        state.update_latencies(Direction::ToClient, tx_id);
+        let tx = state.transactions.back().unwrap();
+        assert_eq!(tx.latency, Some(250));
+        assert_eq!(state.latencies.commands[&(2, SMB2_COMMAND_READ)].count, 1);
+
+        // created and completed by a response
+        let tx = state.new_generic_tx(2, SMB2_COMMAND_READ, SMBCommonHdr::default());
+        tx.response_done = true;
+        state.update_latencies(Direction::ToClient, tx_id);
+        assert_eq!(state.transactions.back().unwrap().latency, None);
+        assert_eq!(state.latencies.commands[&(2, SMB2_COMMAND_READ)].count, 1);
+    }
+}
@@ -21,10 +21,13 @@ use uuid;
 use crate::jsonbuilder::{JsonBuilder, JsonError};
 use crate::smb::smb::*;
 use crate::smb::smb1::*;
+use crate::smb::smb1_records::*;
 use crate::smb::smb2::*;
+use crate::smb::smb2_records::{SMB2_FILE_DISPOSITION_INFO_EX, SMB2_FILE_RENAME_INFO_EX};
+use crate::smb::smb2_oplock::*;
 use crate::dcerpc::dcerpc::*;
+use crate::dcerpc::log::log_dcerpc_stub_request;
 use crate::smb::funcs::*;
-use crate::smb::smb_status::*;
 
 #[cfg(not(feature = "debug"))]
 fn debug_add_progress(_js: &mut JsonBuilder, _tx: &SMBTransaction) -> Result<(), JsonError> { Ok(()) }
@@ -52,6 +55,16 @@ fn fuid_to_string(fuid: &[u8]) -> String {
     }
 }
 
+/// Name of a create context: the tag of most contexts, like "DHnQ", or the
+/// hex of the GUID naming the others, like the AppInstanceId context.
+fn create_context_name_string(name: &[u8]) -> String {
+    if name.iter().all(|c| c.is_ascii_graphic()) {
+        String::from_utf8_lossy(name).to_string()
+    } else {
+        name.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
+
 fn guid_to_string(guid: &[u8]) -> String {
     if guid.len() == 16 {
         let output = format!("{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
@@ -65,6 +78,46 @@ fn guid_to_string(guid: &[u8]) -> String {
     }
 }
 
+const FILE_ATTRIBUTES: &[(u32, &str)] = &[
+    (0x0001, "READONLY"),
+    (0x0002, "HIDDEN"),
+    (0x0004, "SYSTEM"),
+    (0x0010, "DIRECTORY"),
+    (0x0020, "ARCHIVE"),
+    (0x0080, "NORMAL"),
+    (0x0100, "TEMPORARY"),
+    (0x0200, "SPARSE_FILE"),
+    (0x0400, "REPARSE_POINT"),
+    (0x0800, "COMPRESSED"),
+    (0x1000, "OFFLINE"),
+    (0x2000, "NOT_CONTENT_INDEXED"),
+    (0x4000, "ENCRYPTED"),
+];
+
+fn smb_log_file_attributes(jsb: &mut JsonBuilder, attributes: u32) -> Result<(), JsonError> {
+    jsb.open_array("attributes")?;
+    for (flag, name) in FILE_ATTRIBUTES {
+        if attributes & flag != 0 {
+            jsb.append_string(name)?;
+        }
+    }
+    jsb.close()?;
+    Ok(())
+}
+
+fn smb_query_info_level_string(loi: u16) -> String {
+    match loi {
+        SMB_INFO_STANDARD => "Info Standard",
+        SMB_QUERY_FILE_BASIC_INFO => "Query File Basic Info",
+        SMB_QUERY_FILE_STANDARD_INFO => "Query File Standard Info",
+        SMB_QUERY_FILE_ALL_INFO => "Query File All Info",
+        SMB_PASSTHROUGH_FILE_BASIC_INFO => "File Basic Information",
+        SMB_PASSTHROUGH_FILE_STANDARD_INFO => "File Standard Information",
+        SMB_PASSTHROUGH_FILE_ALL_INFO => "File All Information",
+        _ => { return loi.to_string(); },
+    }.to_string()
+}
+
 fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransaction) -> Result<(), JsonError>
 {
     jsb.set_uint("id", tx.id)?;
@@ -80,6 +133,27 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
         jsb.set_string("dialect", dialect)?;
     }
 
+    // gaps of both directions of the flow so far
+    if !state.ts_gap_stats.is_empty() || !state.tc_gap_stats.is_empty() {
+        jsb.open_object("gaps")?;
+        let recovered = state.ts_gap_stats.recovered as u64 + state.tc_gap_stats.recovered as u64;
+        jsb.set_uint("recovered", recovered)?;
+        let unrecoverable =
+            state.ts_gap_stats.unrecoverable as u64 + state.tc_gap_stats.unrecoverable as u64;
+        jsb.set_uint("unrecoverable", unrecoverable)?;
+        jsb.close()?;
+    }
+
+    // scan-like read pattern of the session of the tx
+    if let Some(scan) = state.get_read_scan(tx.hdr.ssn_id) {
+        if scan.flagged {
+            jsb.open_object("read_scan")?;
+            jsb.set_uint("sparse_reads", scan.sparse_reads as u64)?;
+            jsb.set_uint("files", scan.files() as u64)?;
+            jsb.close()?;
+        }
+    }
+
     match tx.vercmd.get_version() {
         1 => {
             let (ok, cmd) = tx.vercmd.get_smb1_cmd();
@@ -96,49 +170,28 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
         _ => { },
     }
 
-    match tx.vercmd.get_ntstatus() {
-        (true, ntstatus) => {
-            let status = smb_ntstatus_string(ntstatus);
-            match status {
-                Some(x) => jsb.set_string("status", x)?,
-                None => {
-                    let status_str = format!("{}", ntstatus);
-                    jsb.set_string("status", &status_str)?
-                },
-            };
-            let status_hex = format!("0x{:x}", ntstatus);
-            jsb.set_string("status_code", &status_hex)?;
-        },
-        (false, _) => {
-            #[allow(clippy::single_match)]
-            match tx.vercmd.get_dos_error() {
-                (true, errclass, errcode) => {
-                    match errclass {
-                        1 => { // DOSERR
-                            let status = smb_dos_error_string(errcode);
-                            jsb.set_string("status", &status)?;
-                        },
-                        2 => { // SRVERR
-                            let status = smb_srv_error_string(errcode);
-                            jsb.set_string("status", &status)?;
-                        }
-                        _ => {
-                            let s = format!("UNKNOWN_{:02x}_{:04x}", errclass, errcode);
-                            jsb.set_string("status", &s)?;
-                        },
-                    }
-                    let status_hex = format!("0x{:04x}", errcode);
-                    jsb.set_string("status_code", &status_hex)?;
-                },
-                (_, _, _) => {
-                },
-            }
-        },
+    if let Some(status) = tx.vercmd.get_status() {
+        jsb.set_string("status", &status.name())?;
+        jsb.set_string("status_code", &status.code())?;
     }
 
-
     jsb.set_uint("session_id", tx.hdr.ssn_id)?;
     jsb.set_uint("tree_id", tx.hdr.tree_id as u64)?;
+    if tx.async_id != 0 {
+        jsb.set_uint("async_id", tx.async_id)?;
+    }
+    if let Some(latency) = tx.async_latency {
+        jsb.set_uint("async_latency", latency)?;
+    }
+    if let Some(latency) = tx.latency {
+        jsb.set_uint("latency", latency)?;
+    }
+    if tx.cancelled {
+        jsb.set_bool("cancelled", true)?;
+    }
+    if state.is_session_encrypted(tx.hdr.ssn_id) {
+        jsb.set_bool("encrypted", true)?;
+    }
 
     debug_add_progress(jsb, tx)?;
 
@@ -179,6 +232,12 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
                 jsb.set_string("native_os", &os)?;
                 let lm = String::from_utf8_lossy(&r.native_lm);
                 jsb.set_string("native_lm", &lm)?;
+                if let Some(ref account) = r.account_name {
+                    let account = String::from_utf8_lossy(account);
+                    jsb.set_string("account_name", &account)?;
+                    let domain = String::from_utf8_lossy(&r.primary_domain);
+                    jsb.set_string("primary_domain", &domain)?;
+                }
                 jsb.close()?;
             }
             if let Some(ref r) = x.response_host {
@@ -191,10 +250,8 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
             }
         },
         Some(SMBTransactionTypeData::CREATE(ref x)) => {
-            let mut name_raw = x.filename.to_vec();
-            name_raw.retain(|&i|i != 0x00);
-            if !name_raw.is_empty() {
-                let name = String::from_utf8_lossy(&name_raw);
+            if !x.filename.is_empty() {
+                let name = String::from_utf8_lossy(&x.filename);
                 if x.directory {
                     jsb.set_string("directory", &name)?;
                 } else {
@@ -225,9 +282,32 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
             jsb.set_uint("modified", x.last_write_ts as u64)?;
             jsb.set_uint("changed", x.last_change_ts as u64)?;
             jsb.set_uint("size", x.size)?;
+            if x.attributes != 0 {
+                smb_log_file_attributes(jsb, x.attributes)?;
+            }
 
             let gs = fuid_to_string(&x.guid);
             jsb.set_string("fuid", &gs)?;
+
+            if x.oplock_level != SMB2_OPLOCK_LEVEL_NONE {
+                jsb.set_string("oplock_level", &smb2_oplock_level_string(x.oplock_level))?;
+            }
+            if !x.lease_key.is_empty() {
+                jsb.open_object("lease")?;
+                jsb.set_string("key", &guid_to_string(&x.lease_key))?;
+                jsb.set_string("state", &smb2_lease_state_string(x.lease_state))?;
+                jsb.close()?;
+            }
+            if !x.create_contexts.is_empty() {
+                jsb.open_array("create_contexts")?;
+                for name in &x.create_contexts {
+                    jsb.append_string(&create_context_name_string(name))?;
+                }
+                jsb.close()?;
+            }
+            if x.timewarp_ts != 0 {
+                jsb.set_uint("timewarp", x.timewarp_ts as u64)?;
+            }
         },
         Some(SMBTransactionTypeData::NEGOTIATE(ref x)) => {
             if x.smb_ver == 1 {
@@ -266,6 +346,10 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
             } else {
                 jsb.set_string("share", &share_name)?;
             }
+            if !x.share_host.is_empty() {
+                let share_host = String::from_utf8_lossy(&x.share_host);
+                jsb.set_string("share_host", &share_host)?;
+            }
 
             // handle services
             if tx.vercmd.get_version() == 1 {
@@ -301,17 +385,28 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
         },
         Some(SMBTransactionTypeData::RENAME(ref x)) => {
             if tx.vercmd.get_version() == 2 {
+                let info_level = if x.info_level == SMB2_FILE_RENAME_INFO_EX as u16 {
+                    "SMB2_FILE_RENAME_INFO_EX"
+                } else {
+                    "SMB2_FILE_RENAME_INFO"
+                };
                 jsb.open_object("set_info")?;
                 jsb.set_string("class", "FILE_INFO")?;
-                jsb.set_string("info_level", "SMB2_FILE_RENAME_INFO")?;
+                jsb.set_string("info_level", info_level)?;
                 jsb.close()?;
             }
 
+            // the file after the rename, like for create and close
+            let file_name = String::from_utf8_lossy(&x.newname);
+            jsb.set_string("filename", &file_name)?;
             jsb.open_object("rename")?;
             let file_name = String::from_utf8_lossy(&x.oldname);
             jsb.set_string("from", &file_name)?;
             let file_name = String::from_utf8_lossy(&x.newname);
             jsb.set_string("to", &file_name)?;
+            if x.replace {
+                jsb.set_bool("replace", true)?;
+            }
             jsb.close()?;
             let gs = fuid_to_string(&x.fuid);
             jsb.set_string("fuid", &gs)?;
@@ -335,6 +430,9 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
                         jsb.open_object("req")?;
                         jsb.set_uint("frag_cnt", x.frag_cnt_ts as u64)?;
                         jsb.set_uint("stub_data_size", x.stub_data_ts.len() as u64)?;
+                        if let Some(ref req) = x.stub_request {
+                            log_dcerpc_stub_request(jsb, req)?;
+                        }
                         jsb.close()?;
                         if let Some(ref ifaces) = state.dcerpc_ifaces {
                             // First filter the interfaces to those
@@ -402,11 +500,35 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
         Some(SMBTransactionTypeData::IOCTL(ref x)) => {
             jsb.set_string("function", &fsctl_func_to_string(x.func))?;
         },
+        Some(SMBTransactionTypeData::OPLOCKBREAK(ref x)) => {
+            jsb.open_object("oplock_break")?;
+            jsb.set_bool("notification", x.notification)?;
+            if let Some(oplock_level) = x.oplock_level {
+                jsb.set_string("oplock_level", &smb2_oplock_level_string(oplock_level))?;
+                jsb.set_string("fuid", &fuid_to_string(&x.key))?;
+            } else {
+                jsb.open_object("lease")?;
+                jsb.set_string("key", &guid_to_string(&x.key))?;
+                jsb.set_string("state", &smb2_lease_state_string(x.lease_state))?;
+                jsb.close()?;
+            }
+            jsb.close()?;
+        },
         Some(SMBTransactionTypeData::SETFILEPATHINFO(ref x)) => {
-            let mut name_raw = x.filename.to_vec();
-            name_raw.retain(|&i|i != 0x00);
-            if !name_raw.is_empty() {
-                let name = String::from_utf8_lossy(&name_raw);
+            if tx.vercmd.get_version() == 2 {
+                let info_level = if x.loi == SMB2_FILE_DISPOSITION_INFO_EX as u16 {
+                    "SMB2_FILE_DISPOSITION_INFO_EX"
+                } else {
+                    "SMB2_FILE_DISPOSITION_INFO"
+                };
+                jsb.open_object("set_info")?;
+                jsb.set_string("class", "FILE_INFO")?;
+                jsb.set_string("info_level", info_level)?;
+                jsb.close()?;
+            }
+
+            if !x.filename.is_empty() {
+                let name = String::from_utf8_lossy(&x.filename);
                 jsb.set_string("filename", &name)?;
             } else {
                 // name suggestion from Bro
@@ -439,6 +561,37 @@ fn smb_common_header(jsb: &mut JsonBuilder, state: &SMBState, tx: &SMBTransactio
             let gs = fuid_to_string(&x.fid);
             jsb.set_string("fuid", &gs)?;
         },
+        Some(SMBTransactionTypeData::QUERYINFO(ref x)) => {
+            if !x.filename.is_empty() {
+                let name = String::from_utf8_lossy(&x.filename);
+                jsb.set_string("filename", &name)?;
+            } else {
+                jsb.set_string("filename", "<share_root>")?;
+            }
+            if x.subcmd == SMB1_TRANS2_QUERY_PATH_INFO {
+                jsb.set_string("subcmd", "QUERY_PATH_INFO")?;
+                jsb.set_string("level_of_interest", &smb_query_info_level_string(x.loi))?;
+            }
+
+            if let Some(ref ts) = x.info.create_ts {
+                jsb.set_uint("created", ts.as_unix() as u64)?;
+            }
+            if let Some(ref ts) = x.info.last_access_ts {
+                jsb.set_uint("accessed", ts.as_unix() as u64)?;
+            }
+            if let Some(ref ts) = x.info.last_write_ts {
+                jsb.set_uint("modified", ts.as_unix() as u64)?;
+            }
+            if let Some(ref ts) = x.info.last_change_ts {
+                jsb.set_uint("changed", ts.as_unix() as u64)?;
+            }
+            if let Some(size) = x.info.size {
+                jsb.set_uint("size", size)?;
+            }
+            if let Some(attributes) = x.info.attributes.filter(|&a| a != 0) {
+                smb_log_file_attributes(jsb, attributes)?;
+            }
+        },
         _ => {  },
     }
     return Ok(());
@@ -456,3 +609,32 @@ pub extern "C" fn rs_smb_log_json_response(jsb: &mut JsonBuilder, state: &mut SM
     smb_common_header(jsb, state, tx).is_ok()
 }
 
+
+fn smb_log_flow(jsb: &mut JsonBuilder, state: &SMBState) -> Result<(), JsonError>
+{
+    jsb.open_object("smb")?;
+    if let Some(ref host) = state.server_host {
+        jsb.open_object("server")?;
+        let os = String::from_utf8_lossy(&host.native_os);
+        jsb.set_string("native_os", &os)?;
+        let lm = String::from_utf8_lossy(&host.native_lm);
+        jsb.set_string("native_lm", &lm)?;
+        jsb.close()?;
+    }
+    if !state.latencies.is_empty() {
+        state.latencies.log(jsb)?;
+    }
+    jsb.close()?;
+    return Ok(());
+}
+
+/// Log the SMB info of the flow record: the native OS and LAN Manager of
+/// the server and the latencies of the commands.
+#[no_mangle]
+pub extern "C" fn SCSmbStateLogFlow(jsb: &mut JsonBuilder, state: &SMBState) -> bool
+{
+    if state.server_host.is_none() && state.latencies.is_empty() {
+        return true;
+    }
+    smb_log_flow(jsb, state).is_ok()
+}
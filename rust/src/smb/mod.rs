@@ -20,6 +20,7 @@
 pub mod error;
 pub mod smb_records;
 pub mod smb_status;
+pub mod smb_dos_error;
 pub mod smb1_records;
 pub mod smb2_records;
 pub mod nbss_records;
@@ -32,6 +33,9 @@ pub mod smb1_session;
 pub mod smb2;
 pub mod smb2_session;
 pub mod smb2_ioctl;
+pub mod smb2_oplock;
+pub mod smb2_read_scan;
+pub mod latency;
 pub mod smb3;
 pub mod dcerpc;
 pub mod session;
@@ -41,4 +45,5 @@ pub mod debug;
 pub mod events;
 pub mod auth;
 pub mod files;
+pub mod filename;
 pub mod funcs;
@@ -41,7 +41,7 @@ use crate::applayer;
 use crate::applayer::*;
 use crate::frames::*;
 use crate::conf::*;
-use crate::applayer::{AppLayerResult, AppLayerTxData, AppLayerEvent};
+use crate::applayer::{AppLayerResult, AppLayerTxData};
 
 use crate::smb::nbss_records::*;
 use crate::smb::smb1_records::*;
@@ -54,7 +54,14 @@ use crate::smb::dcerpc::*;
 use crate::smb::session::*;
 use crate::smb::events::*;
 use crate::smb::files::*;
+use crate::smb::filename::smb_parse_unc_path;
 use crate::smb::smb2_ioctl::*;
+use crate::smb::smb2_oplock::*;
+use crate::smb::smb2_read_scan::*;
+use crate::smb::latency::SMBLatencies;
+use crate::smb::smb1_session::SessionSetupResponse;
+use crate::smb::smb_dos_error::*;
+use crate::smb::smb_status::*;
 
 #[derive(AppLayerFrameType)]
 pub enum SMBFrameType {
@@ -73,7 +80,12 @@ pub enum SMBFrameType {
 }
 
 pub const MIN_REC_SIZE: u16 = 32 + 4; // SMB hdr + nbss hdr
-pub const SMB_CONFIG_DEFAULT_STREAM_DEPTH: u32 = 0;
+/// Detection port if none is configured.
+const SMB_DEFAULT_PORT: &str = "445";
+/// Data searched for a record after a gap before giving up. Twice the
+/// default max read and write size, as the gap can hit the start of
+/// such a record.
+const SMB_GAP_RESYNC_MAX_LEN: u64 = 33554432;
 
 pub static mut SMB_CFG_MAX_READ_SIZE: u32 = 16777216;
 pub static mut SMB_CFG_MAX_READ_QUEUE_SIZE: u32 = 67108864;
@@ -87,12 +99,31 @@ pub static mut SMB_CFG_MAX_GUID_CACHE_SIZE: usize = 1024;
 pub static mut SMB_CFG_MAX_READ_OFFSET_CACHE_SIZE: usize = 128;
 /// For SMBState::ssn2tree_cache
 pub static mut SMB_CFG_MAX_TREE_CACHE_SIZE: usize = 512;
-/// For SMBState::dcerpc_rec_frag_cache
+/// For SMBState::dcerpc_rec_frag_cache, SMBState::pipe_read_pending
+/// and SMBState::trans_req_frag_cache
 pub static mut SMB_CFG_MAX_FRAG_CACHE_SIZE: usize = 128;
 /// For SMBState::ssn2vec_cache
 pub static mut SMB_CFG_MAX_SSN2VEC_CACHE_SIZE: usize = 512;
-
-static mut ALPROTO_SMB: AppProto = ALPROTO_UNKNOWN;
+/// Bytes of partial DCERPC replies buffered in
+/// SMBState::dcerpc_rec_frag_cache
+pub static mut SMB_CFG_MAX_FRAG_BUFFER_SIZE: usize = 1048576;
+/// Number of oplock/lease break notifications in a window that is
+/// considered a break storm. 0 disables the check.
+pub static mut SMB_CFG_BREAK_STORM_THRESHOLD: u32 = 100;
+/// Oplock/lease break storm window in seconds.
+pub static mut SMB_CFG_BREAK_STORM_WINDOW: u64 = 10;
+/// Number of small non-sequential reads of a session that is considered
+/// a scan-like read pattern. 0 disables the check.
+pub static mut SMB_CFG_READ_SCAN_READS: u32 = 200;
+/// Number of files the reads of a scan-like read pattern are spread over.
+pub static mut SMB_CFG_READ_SCAN_FILES: u32 = 50;
+/// Reads up to this size count for the scan-like read pattern.
+pub static mut SMB_CFG_READ_SCAN_MAX_READ_SIZE: u32 = 4096;
+/// Keep the case of file names. If disabled, names are lowercased
+/// by the file name normalization.
+pub static mut SMB_CFG_FILENAME_PRESERVE_CASE: bool = true;
+
+pub static mut ALPROTO_SMB: AppProto = ALPROTO_UNKNOWN;
 
 static mut SMB_MAX_TX: usize = 1024;
 
@@ -106,42 +137,6 @@ pub extern "C" fn rs_smb_init(context: &'static mut SuricataFileContext)
     }
 }
 
-pub const SMB_SRV_ERROR:                u16 = 1;
-pub const SMB_SRV_BADPW:                u16 = 2;
-pub const SMB_SRV_BADTYPE:              u16 = 3;
-pub const SMB_SRV_ACCESS:               u16 = 4;
-pub const SMB_SRV_BADUID:               u16 = 91;
-
-pub fn smb_srv_error_string(c: u16) -> String {
-    match c {
-        SMB_SRV_ERROR           => "SRV_ERROR",
-        SMB_SRV_BADPW           => "SRV_BADPW",
-        SMB_SRV_BADTYPE         => "SRV_BADTYPE",
-        SMB_SRV_ACCESS          => "SRV_ACCESS",
-        SMB_SRV_BADUID          => "SRV_BADUID",
-        _ => { return (c).to_string(); },
-    }.to_string()
-}
-
-pub const SMB_DOS_SUCCESS:                u16 = 0;
-pub const SMB_DOS_BAD_FUNC:               u16 = 1;
-pub const SMB_DOS_BAD_FILE:               u16 = 2;
-pub const SMB_DOS_BAD_PATH:               u16 = 3;
-pub const SMB_DOS_TOO_MANY_OPEN_FILES:    u16 = 4;
-pub const SMB_DOS_ACCESS_DENIED:          u16 = 5;
-
-pub fn smb_dos_error_string(c: u16) -> String {
-    match c {
-        SMB_DOS_SUCCESS           => "DOS_SUCCESS",
-        SMB_DOS_BAD_FUNC          => "DOS_BAD_FUNC",
-        SMB_DOS_BAD_FILE          => "DOS_BAD_FILE",
-        SMB_DOS_BAD_PATH          => "DOS_BAD_PATH",
-        SMB_DOS_TOO_MANY_OPEN_FILES => "DOS_TOO_MANY_OPEN_FILES",
-        SMB_DOS_ACCESS_DENIED     => "DOS_ACCESS_DENIED",
-        _ => { return (c).to_string(); },
-    }.to_string()
-}
-
 pub const NTLMSSP_NEGOTIATE:               u32 = 1;
 #[cfg(feature = "debug")]
 pub const NTLMSSP_CHALLENGE:               u32 = 2;
@@ -157,6 +152,46 @@ pub fn ntlmssp_type_string(c: u32) -> String {
     }.to_string()
 }
 
+/// Status of an SMB response: an NTSTATUS, or a DOS error class and code
+/// for SMB1 servers that did not negotiate NT status codes.
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum SMBStatus {
+    NtStatus(u32),
+    DosError(u8, u16),
+}
+
+impl SMBStatus {
+    /// Name of the status, e.g. STATUS_ACCESS_DENIED or DOS_ACCESS_DENIED.
+    pub fn name(&self) -> String {
+        match *self {
+            SMBStatus::NtStatus(status) => match smb_ntstatus_string(status) {
+                Some(name) => name.to_string(),
+                None => format!("{}", status),
+            },
+            SMBStatus::DosError(class, code) => match smb_dos_error_string(class, code) {
+                Some(name) => name.to_string(),
+                None => format!("UNKNOWN_{:02x}_{:04x}", class, code),
+            },
+        }
+    }
+
+    /// Code of the status as logged: the NTSTATUS or the DOS error code.
+    pub fn code(&self) -> String {
+        match *self {
+            SMBStatus::NtStatus(status) => format!("0x{:x}", status),
+            SMBStatus::DosError(_, code) => format!("0x{:04x}", code),
+        }
+    }
+
+    /// The status as NTSTATUS, mapping DOS errors to their NT equivalent.
+    pub fn ntstatus(&self) -> Option<u32> {
+        match *self {
+            SMBStatus::NtStatus(status) => Some(status),
+            SMBStatus::DosError(class, code) => smb_dos_error_to_ntstatus(class, code),
+        }
+    }
+}
+
 #[derive(Default, Eq, PartialEq, Debug, Clone)]
 pub struct SMBVerCmdStat {
     smb_ver: u8,
@@ -251,6 +286,18 @@ impl SMBVerCmdStat {
         (self.status_set && self.status_is_dos_error, self.status_error_class, self.status as u16)
     }
 
+    /// Get the status of a response, whether it is an NTSTATUS or an
+    /// SMB1 DOS error.
+    pub fn get_status(&self) -> Option<SMBStatus> {
+        if !self.status_set {
+            return None;
+        }
+        if self.status_is_dos_error {
+            return Some(SMBStatus::DosError(self.status_error_class, self.status as u16));
+        }
+        Some(SMBStatus::NtStatus(self.status))
+    }
+
     fn set_status(&mut self, status: u32, is_dos_error: bool)
     {
         if is_dos_error {
@@ -289,6 +336,11 @@ impl SMBFiletime {
         }
     }
 
+    /// FILETIME of a time in seconds since the unix epoch
+    pub fn from_unix(secs: u32) -> Self {
+        Self::new((secs as u64 + 11644473600) * 10000000)
+    }
+
     /// inspired by Bro, convert FILETIME to secs since unix epoch
     pub fn as_unix(&self) -> u32 {
         if self.ts > 116_444_736_000_000_000_u64 {
@@ -311,6 +363,53 @@ pub enum SMBTransactionTypeData {
     IOCTL(SMBTransactionIoctl),
     RENAME(SMBTransactionRename),
     SETFILEPATHINFO(SMBTransactionSetFilePathInfo),
+    OPLOCKBREAK(SMBTransactionOplockBreak),
+    QUERYINFO(SMBTransactionQueryInfo),
+}
+
+// Used for QUERY_INFORMATION and Trans2 QUERY_PATH_INFO
+#[derive(Debug)]
+pub struct SMBTransactionQueryInfo {
+    /// Trans2 subcommand, 0 for QUERY_INFORMATION.
+    pub subcmd: u16,
+    pub loi: u16,
+    pub filename: Vec<u8>,
+    /// File metadata from the response.
+    pub info: SmbFileInfoRecord,
+}
+
+impl SMBState {
+    pub fn new_queryinfo_tx(&mut self, filename: Vec<u8>, subcmd: u16, loi: u16)
+        -> &mut SMBTransaction
+    {
+        let mut tx = self.new_tx();
+
+        tx.type_data = Some(SMBTransactionTypeData::QUERYINFO(
+                    SMBTransactionQueryInfo {
+                        subcmd,
+                        loi,
+                        filename,
+                        info: SmbFileInfoRecord::default(),
+                    }));
+        tx.request_done = true;
+        tx.response_done = self.tc_trunc; // no response expected if tc is truncated
+
+        SCLogDebug!("SMB: TX QUERYINFO created: ID {}", tx.id);
+        self.transactions.push_back(tx);
+        let tx_ref = self.transactions.back_mut();
+        return tx_ref.unwrap();
+    }
+
+    /// Find the last create transaction of `filename`, to attach the
+    /// metadata of a query information response to.
+    pub fn get_create_tx_by_name(&mut self, filename: &[u8])
+        -> Option<&mut SMBTransaction>
+    {
+        self.transactions.iter_mut().rev().find(|tx| {
+            matches!(tx.type_data,
+                    Some(SMBTransactionTypeData::CREATE(ref c)) if c.filename == filename)
+        })
+    }
 }
 
 // Used for Trans2 SET_PATH_INFO and SET_FILE_INFO
@@ -380,12 +479,18 @@ pub struct SMBTransactionRename {
     pub oldname: Vec<u8>,
     pub newname: Vec<u8>,
     pub fuid: Vec<u8>,
+    /// SMB2 SET_INFO file info level
+    pub info_level: u16,
+    /// existing file with the new name is replaced
+    pub replace: bool,
 }
 
 impl SMBTransactionRename {
     pub fn new(fuid: Vec<u8>, oldname: Vec<u8>, newname: Vec<u8>) -> Self {
         return Self {
             fuid, oldname, newname,
+            info_level: 0,
+            replace: false,
         };
     }
 }
@@ -416,12 +521,26 @@ pub struct SMBTransactionCreate {
     pub filename: Vec<u8>,
     pub guid: Vec<u8>,
 
+    /// Oplock level requested, updated to the level granted by the
+    /// response.
+    pub oplock_level: u8,
+    /// Lease key if a lease was requested, empty otherwise.
+    pub lease_key: Vec<u8>,
+    /// Lease state requested, updated to the state granted.
+    pub lease_state: u32,
+    /// Names of the create contexts of the request.
+    pub create_contexts: Vec<Vec<u8>>,
+    /// Snapshot time of a timewarp token, 0 if none.
+    pub timewarp_ts: u32,
+
     pub create_ts: u32,
     pub last_access_ts: u32,
     pub last_write_ts: u32,
     pub last_change_ts: u32,
 
     pub size: u64,
+    /// File attributes, 0 if unknown.
+    pub attributes: u32,
 }
 
 impl SMBTransactionCreate {
@@ -434,6 +553,26 @@ impl SMBTransactionCreate {
             ..Default::default()
         }
     }
+
+    /// Fill in the metadata the create response did not have, e.g. for
+    /// the legacy SMB1 OPEN commands.
+    pub fn update_info(&mut self, info: &SmbFileInfoRecord) {
+        fn update_ts(ts: &mut u32, info_ts: &Option<SMBFiletime>) {
+            if let (0, Some(info_ts)) = (*ts, info_ts) {
+                *ts = info_ts.as_unix();
+            }
+        }
+        update_ts(&mut self.create_ts, &info.create_ts);
+        update_ts(&mut self.last_access_ts, &info.last_access_ts);
+        update_ts(&mut self.last_write_ts, &info.last_write_ts);
+        update_ts(&mut self.last_change_ts, &info.last_change_ts);
+        if let (0, Some(size)) = (self.size, info.size) {
+            self.size = size;
+        }
+        if let (0, Some(attributes)) = (self.attributes, info.attributes) {
+            self.attributes = attributes;
+        }
+    }
 }
 
 #[derive(Default, Debug)]
@@ -441,6 +580,9 @@ pub struct SMBTransactionNegotiate {
     pub smb_ver: u8,
     pub dialects: Vec<Vec<u8>>,
     pub dialects2: Vec<Vec<u8>>,
+    /// Dialect selected by the server, as a string for both SMB1
+    /// and SMB2.
+    pub dialect: Vec<u8>,
 
     // SMB1 doesn't have the client GUID
     pub client_guid: Option<Vec<u8>>,
@@ -463,6 +605,8 @@ pub struct SMBTransactionTreeConnect {
     pub share_type: u8,
     pub tree_id: u32,
     pub share_name: Vec<u8>,
+    /// server component of the UNC path
+    pub share_host: Vec<u8>,
 
     /// SMB1 service strings
     pub req_service: Option<Vec<u8>>,
@@ -471,8 +615,12 @@ pub struct SMBTransactionTreeConnect {
 
 impl SMBTransactionTreeConnect {
     pub fn new(share_name: Vec<u8>) -> Self {
+        let share_host = smb_parse_unc_path(&share_name)
+            .map(|(host, _)| host.to_vec())
+            .unwrap_or_default();
         return Self {
             share_name,
+            share_host,
             ..Default::default()
         };
     }
@@ -495,6 +643,24 @@ pub struct SMBTransaction {
     /// Command specific data
     pub type_data: Option<SMBTransactionTypeData>,
 
+    /// SMB2 async id from the interim (STATUS_PENDING) response, 0 if
+    /// the command did not go async.
+    pub async_id: u64,
+    /// Timestamp in microseconds of the interim response.
+    pub async_ts: u64,
+    /// Microseconds between the interim and the final response.
+    pub async_latency: Option<u64>,
+    /// A CANCEL request was seen for this command.
+    pub cancelled: bool,
+
+    /// Timestamp in microseconds of the first packet of the request.
+    pub request_ts: u64,
+    /// Microseconds between the request and the response, None if the
+    /// response was not seen.
+    pub latency: Option<u64>,
+    /// The transaction was accounted for in the latencies.
+    pub latency_done: bool,
+
     pub tx_data: AppLayerTxData,
 }
 
@@ -519,6 +685,13 @@ impl SMBTransaction {
               request_done: false,
               response_done: false,
               type_data: None,
+              async_id: 0,
+              async_ts: 0,
+              async_latency: None,
+              cancelled: false,
+              request_ts: 0,
+              latency: None,
+              latency_done: false,
               tx_data: AppLayerTxData::new(),
         }
     }
@@ -554,6 +727,8 @@ impl Drop for SMBTransaction {
 pub struct SMBFileGUIDOffset {
     pub guid: Vec<u8>,
     pub offset: u64,
+    /// The read is for a pipe, decided when the request was seen.
+    pub is_pipe: bool,
 }
 
 impl SMBFileGUIDOffset {
@@ -561,6 +736,7 @@ impl SMBFileGUIDOffset {
         Self {
             guid,
             offset,
+            is_pipe: false,
         }
     }
 }
@@ -577,6 +753,7 @@ pub const SMBHDR_TYPE_HEADER:      u32 = 6;
 pub const SMBHDR_TYPE_TRANS_FRAG:  u32 = 8;
 pub const SMBHDR_TYPE_TREE:        u32 = 9;
 pub const SMBHDR_TYPE_DCERPCTX:    u32 = 10;
+pub const SMBHDR_TYPE_TRANS_REQ_FRAG: u32 = 11;
 
 #[derive(Default, Hash, Eq, PartialEq, Debug)]
 pub struct SMBCommonHdr {
@@ -718,6 +895,34 @@ pub struct SMBState<> {
     /// store partial data records that are transferred in multiple
     /// requests for DCERPC.
     pub dcerpc_rec_frag_cache: LruCache<SMBHashKeyHdrGuid, Vec<u8>>,
+    /// Bytes buffered in `dcerpc_rec_frag_cache`.
+    pub dcerpc_rec_frag_bytes: usize,
+
+    /// SMB1 FIDs (with the ssn id mixed in) with DCERPC data left to be
+    /// read from the pipe through READ_ANDX.
+    ///
+    /// Lifecycle of the members:
+    /// - Added by TRANS and READ_ANDX responses with BUFFER_OVERFLOW
+    /// - Removed by the READ_ANDX response completing the data
+    /// - Removed by CLOSE requests, as the FID may be reused
+    pub pipe_read_pending: LruCache<Vec<u8>, ()>,
+
+    /// SMB1 TRANS requests to a DCERPC pipe whose data continues in
+    /// TRANS_SECONDARY requests, by ssn/tree/msgid.
+    pub trans_req_frag_cache: LruCache<SMBCommonHdr, SMBTransRequestFrag>,
+
+    /// Sessions for which a TRANSFORM_HEADER was seen, so encrypted.
+    pub encrypted_sessions: LruCache<u64, ()>,
+
+    /// Read pattern of the sessions, by session id.
+    pub read_scans: LruCache<u64, SMBReadScan>,
+
+    /// Latencies of the commands of the flow.
+    pub latencies: SMBLatencies,
+
+    /// Native OS and LAN Manager of the server, from the last SMB1
+    /// SESSION_SETUP_ANDX response that had them.
+    pub server_host: Option<SessionSetupResponse>,
 
     skip_ts: u32,
     skip_tc: u32,
@@ -733,6 +938,9 @@ pub struct SMBState<> {
     pub ts_gap: bool, // last TS update was gap
     pub tc_gap: bool, // last TC update was gap
 
+    pub ts_gap_stats: GapStats,
+    pub tc_gap_stats: GapStats,
+
     pub ts_trunc: bool, // no more data for TOSERVER
     pub tc_trunc: bool, // no more data for TOCLIENT
 
@@ -760,9 +968,20 @@ pub struct SMBState<> {
     pub max_read_size: u32,
     pub max_write_size: u32,
 
+    /// SMB1 UID of the last successful SESSION_SETUP, used to remap
+    /// the FIDs of the previous session on reauthentication.
+    pub smb1_ssn_id: u32,
+
+    /// Start in seconds of the current oplock/lease break rate window.
+    pub break_window_ts: u64,
+    /// Number of break notifications in the current window.
+    pub break_window_cnt: u32,
+
     /// Timestamp in seconds of last update. This is packet time,
     /// potentially coming from pcaps.
     ts: u64,
+    /// Timestamp of last update in microseconds.
+    pub ts_usec: u64,
 }
 
 impl State<SMBTransaction> for SMBState {
@@ -791,6 +1010,13 @@ impl SMBState {
             read_offset_cache:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_READ_OFFSET_CACHE_SIZE }).unwrap()),
             ssn2tree_cache:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_TREE_CACHE_SIZE }).unwrap()),
             dcerpc_rec_frag_cache:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_FRAG_CACHE_SIZE }).unwrap()),
+            dcerpc_rec_frag_bytes: 0,
+            pipe_read_pending:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_FRAG_CACHE_SIZE }).unwrap()),
+            trans_req_frag_cache:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_FRAG_CACHE_SIZE }).unwrap()),
+            encrypted_sessions:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_SSN2VEC_CACHE_SIZE }).unwrap()),
+            read_scans:LruCache::new(NonZeroUsize::new(unsafe { SMB_CFG_MAX_SSN2VEC_CACHE_SIZE }).unwrap()),
+            latencies: SMBLatencies::default(),
+            server_host: None,
             skip_ts:0,
             skip_tc:0,
             file_ts_left:0,
@@ -801,6 +1027,8 @@ impl SMBState {
             tc_ssn_gap: false,
             ts_gap: false,
             tc_gap: false,
+            ts_gap_stats: GapStats::default(),
+            tc_gap_stats: GapStats::default(),
             ts_trunc: false,
             tc_trunc: false,
             check_post_gap_file_txs: false,
@@ -813,7 +1041,11 @@ impl SMBState {
             dcerpc_ifaces: None,
             max_read_size: 0,
             max_write_size: 0,
+            smb1_ssn_id: 0,
+            break_window_ts: 0,
+            break_window_cnt: 0,
             ts: 0,
+            ts_usec: 0,
         }
     }
 
@@ -826,6 +1058,7 @@ impl SMBState {
         let mut tx = SMBTransaction::new();
         self.tx_id += 1;
         tx.id = self.tx_id;
+        tx.request_ts = self.ts_usec;
         SCLogDebug!("TX {} created", tx.id);
         if self.transactions.len() > unsafe { SMB_MAX_TX } {
             let mut index = self.tx_index_completed;
@@ -899,9 +1132,10 @@ impl SMBState {
         return None;
     }
 
-    fn update_ts(&mut self, ts: u64) {
-        if ts != self.ts {
-            self.ts = ts;
+    fn update_ts(&mut self, ts: std::time::Duration) {
+        self.ts_usec = ts.as_micros() as u64;
+        if ts.as_secs() != self.ts {
+            self.ts = ts.as_secs();
             self.post_gap_files_checked = false;
         }
     }
@@ -1079,8 +1313,10 @@ impl SMBState {
                 if s.len() > 1 && s[0] == 0x5c_u8 {
                     s = &s[1..];
                 }
-                match str::from_utf8(s) {
-                    Ok("PSEXESVC") => ("PSEXESVC", false),
+                // names may have been lowercased by the file name
+                // normalization, so compare case insensitive
+                match str::from_utf8(s).map(|s| s.to_ascii_lowercase()).as_deref() {
+                    Ok("psexesvc") => ("PSEXESVC", false),
                     Ok("svcctl") => ("svcctl", true),
                     Ok("srvsvc") => ("srvsvc", true),
                     Ok("atsvc") => ("atsvc", true),
@@ -1090,7 +1326,7 @@ impl SMBState {
                     Ok("winreg") => ("winreg", true),
                     Ok("suricata::dcerpc") => ("unknown", true),
                     Err(_) => ("MALFORMED", false),
-                    Ok(&_) => {
+                    Ok(_) => {
                         SCLogDebug!("don't know {}", String::from_utf8_lossy(n));
                         ("UNKNOWN", false)
                     },
@@ -1190,6 +1426,30 @@ impl SMBState {
         }
     }
 
+    /// Mark the session of a TRANSFORM_HEADER as encrypted. The records
+    /// of the session can't be inspected anymore, the first encrypted
+    /// session of the flow gets a tx with the `encrypted_session` event.
+    pub fn set_session_encrypted(&mut self, session_id: u64)
+    {
+        if self.encrypted_sessions.contains(&session_id) {
+            return;
+        }
+        let first = self.encrypted_sessions.is_empty();
+        self.encrypted_sessions.put(session_id, ());
+        SCLogDebug!("session {} is encrypted", session_id);
+        if first {
+            let hdr = SMBCommonHdr::new(SMBHDR_TYPE_GENERICTX, session_id, 0, 0);
+            let tx = self.new_generic_tx(3, 0, hdr);
+            tx.response_done = true;
+            tx.set_event(SMBEvent::EncryptedSession);
+        }
+    }
+
+    pub fn is_session_encrypted(&self, session_id: u64) -> bool
+    {
+        self.encrypted_sessions.contains(&session_id)
+    }
+
     // return how much data we consumed
     fn handle_skip(&mut self, direction: Direction, input_size: u32) -> u32 {
         let mut skip_left = if direction == Direction::ToServer {
@@ -1349,8 +1609,22 @@ impl SMBState {
                                 return consumed;
                             }
                         }
+                    } else if smb.version == 0xfd_u8 { // SMB3 transform
+                        if let Ok((_, ref hdr)) = parse_smb3_transform_header(nbss_part_hdr.data) {
+                            SCLogDebug!("SMBv3 transform: partial record for session {}", hdr.session_id);
+                            self.add_nbss_ts_frames(flow, stream_slice, input, nbss_part_hdr.length as i64);
+                            self.add_smb3_ts_pdu_frame(flow, stream_slice, nbss_part_hdr.data, nbss_part_hdr.length as i64);
+                            self.add_smb3_ts_hdr_data_frames(flow, stream_slice, nbss_part_hdr.data, nbss_part_hdr.length as i64);
+                            self.set_session_encrypted(hdr.session_id);
+
+                            // nothing to parse in the encrypted data, so skip
+                            // the rest of the NBSS record instead of buffering it.
+                            let nbss_remaining = nbss_part_hdr.length - nbss_part_hdr.data.len() as u32;
+                            self.set_skip(Direction::ToServer, nbss_remaining);
+                            let consumed = input.len() - output.len();
+                            return consumed;
+                        }
                     }
-                    // no SMB3 here yet, will buffer full records
                 }
             }
         }
@@ -1366,7 +1640,7 @@ impl SMBState {
         if consumed > 0 {
             if consumed > cur_i.len() as u32 {
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
             cur_i = &cur_i[consumed as usize..];
         }
@@ -1376,7 +1650,7 @@ impl SMBState {
         if consumed > 0 {
             if consumed > cur_i.len() as u32 {
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
             cur_i = &cur_i[consumed as usize..];
         }
@@ -1399,6 +1673,7 @@ impl SMBState {
                         cur_i = &cur_i[nbss_offset..];
 
                         self.ts_gap = false;
+                        self.ts_gap_stats.resync();
                         break;
                     },
                     _ => {
@@ -1409,6 +1684,12 @@ impl SMBState {
                             consumed -= 3;
                         }
                         SCLogDebug!("smb record NOT found");
+                        if self.ts_gap_stats.skip(consumed) > SMB_GAP_RESYNC_MAX_LEN {
+                            SCLogDebug!("TS no record found after GAP, giving up");
+                            self.ts_gap_stats.give_up();
+                            self.set_event(SMBEvent::UnrecoverableGap);
+                            return AppLayerResult::error(AppLayerError::Gap);
+                        }
                         return AppLayerResult::incomplete(consumed, 8);
                     },
                 }
@@ -1490,10 +1771,11 @@ impl SMBState {
                                     while !nbss_data.is_empty() {
                                         SCLogDebug!("SMBv3 transform record");
                                         match parse_smb3_transform_record(nbss_data) {
-                                            Ok((nbss_data_rem, ref _smb3_record)) => {
+                                            Ok((nbss_data_rem, ref smb3_record)) => {
                                                 let record_len = (nbss_data.len() - nbss_data_rem.len()) as i64;
                                                 self.add_smb3_ts_pdu_frame(flow, stream_slice, nbss_data, record_len);
                                                 self.add_smb3_ts_hdr_data_frames(flow, stream_slice, nbss_data, record_len);
+                                                self.set_session_encrypted(smb3_record.session_id);
                                                 nbss_data = nbss_data_rem;
                                             },
                                             _ => {
@@ -1540,7 +1822,7 @@ impl SMBState {
                         return AppLayerResult::ok();
                     } else {
                         self.set_event(SMBEvent::InternalError);
-                        return AppLayerResult::err();
+                        return AppLayerResult::error(AppLayerError::Internal);
                     }
                 },
                 Err(_) => {
@@ -1641,13 +1923,9 @@ impl SMBState {
                             SCLogDebug!("SMB1: partial record {}",
                                     r.command);
                             if r.command == SMB1_COMMAND_READ_ANDX {
-                                let tree_key = SMBCommonHdr::new(SMBHDR_TYPE_SHARE,
-                                        r.ssn_id as u64, r.tree_id as u32, 0);
-                                let is_pipe = match self.ssn2tree_cache.get(&tree_key) {
-                                    Some(n) => n.is_pipe,
-                                        None => false,
-                                };
-                                if is_pipe {
+                                // see if it's a read from a pipe. We only handle those
+                                // if complete.
+                                if smb1_read_response_is_pipe(self, r) {
                                     return 0;
                                 }
 
@@ -1687,8 +1965,22 @@ impl SMBState {
                                 return consumed;
                             }
                         }
+                    } else if smb.version == 0xfd_u8 { // SMB3 transform
+                        if let Ok((_, ref hdr)) = parse_smb3_transform_header(nbss_part_hdr.data) {
+                            SCLogDebug!("SMBv3 transform: partial record for session {}", hdr.session_id);
+                            self.add_nbss_tc_frames(flow, stream_slice, input, nbss_part_hdr.length as i64);
+                            self.add_smb3_tc_pdu_frame(flow, stream_slice, nbss_part_hdr.data, nbss_part_hdr.length as i64);
+                            self.add_smb3_tc_hdr_data_frames(flow, stream_slice, nbss_part_hdr.data, nbss_part_hdr.length as i64);
+                            self.set_session_encrypted(hdr.session_id);
+
+                            // nothing to parse in the encrypted data, so skip
+                            // the rest of the NBSS record instead of buffering it.
+                            let nbss_remaining = nbss_part_hdr.length - nbss_part_hdr.data.len() as u32;
+                            self.set_skip(Direction::ToClient, nbss_remaining);
+                            let consumed = input.len() - output.len();
+                            return consumed;
+                        }
                     }
-                    // no SMB3 here yet, will buffer full records
                 }
             }
         }
@@ -1703,7 +1995,7 @@ impl SMBState {
         if consumed > 0 {
             if consumed > cur_i.len() as u32 {
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
             cur_i = &cur_i[consumed as usize..];
         }
@@ -1713,7 +2005,7 @@ impl SMBState {
         if consumed > 0 {
             if consumed > cur_i.len() as u32 {
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
             cur_i = &cur_i[consumed as usize..];
         }
@@ -1736,6 +2028,7 @@ impl SMBState {
                         cur_i = &cur_i[nbss_offset..];
 
                         self.tc_gap = false;
+                        self.tc_gap_stats.resync();
                         break;
                     },
                     _ => {
@@ -1746,6 +2039,12 @@ impl SMBState {
                             consumed -= 3;
                         }
                         SCLogDebug!("smb record NOT found");
+                        if self.tc_gap_stats.skip(consumed) > SMB_GAP_RESYNC_MAX_LEN {
+                            SCLogDebug!("TC no record found after GAP, giving up");
+                            self.tc_gap_stats.give_up();
+                            self.set_event(SMBEvent::UnrecoverableGap);
+                            return AppLayerResult::error(AppLayerError::Gap);
+                        }
                         return AppLayerResult::incomplete(consumed, 8);
                     },
                 }
@@ -1814,10 +2113,11 @@ impl SMBState {
                                     while !nbss_data.is_empty() {
                                         SCLogDebug!("SMBv3 transform record");
                                         match parse_smb3_transform_record(nbss_data) {
-                                            Ok((nbss_data_rem, ref _smb3_record)) => {
+                                            Ok((nbss_data_rem, ref smb3_record)) => {
                                                 let record_len = (nbss_data.len() - nbss_data_rem.len()) as i64;
                                                 self.add_smb3_tc_pdu_frame(flow, stream_slice, nbss_data, record_len);
                                                 self.add_smb3_tc_hdr_data_frames(flow, stream_slice, nbss_data, record_len);
+                                                self.set_session_encrypted(smb3_record.session_id);
                                                 nbss_data = nbss_data_rem;
                                             },
                                             _ => {
@@ -1866,7 +2166,7 @@ impl SMBState {
                         return AppLayerResult::ok();
                     } else {
                         self.set_event(SMBEvent::InternalError);
-                        return AppLayerResult::err();
+                        return AppLayerResult::error(AppLayerError::Internal);
                     }
                 },
                 Err(_) => {
@@ -1888,9 +2188,11 @@ impl SMBState {
     /// returns: 0 ok, 1 unrecoverable error
     pub fn parse_tcp_data_ts_gap(&mut self, gap_size: u32) -> AppLayerResult {
         SCLogDebug!("GAP of size {} in toserver direction", gap_size);
+        self.ts_gap_stats.gap();
         let consumed = self.handle_skip(Direction::ToServer, gap_size);
         if consumed == gap_size {
             /* no need to tag ssn as gap'd as we got it in our skip logic. */
+            self.ts_gap_stats.resync();
             return AppLayerResult::ok();
         }
 
@@ -1901,13 +2203,14 @@ impl SMBState {
             let consumed2 = self.filetracker_update(Direction::ToServer, &gap, new_gap_size);
             if consumed2 == new_gap_size {
                 /* no need to tag ssn as gap'd as we got it in our file logic. */
+                self.ts_gap_stats.resync();
                 return AppLayerResult::ok();
             }
 
             if consumed2 > new_gap_size {
                 SCLogDebug!("consumed more than GAP size: {} > {}", consumed2, new_gap_size);
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
         }
 
@@ -1920,9 +2223,11 @@ impl SMBState {
     /// returns: 0 ok, 1 unrecoverable error
     pub fn parse_tcp_data_tc_gap(&mut self, gap_size: u32) -> AppLayerResult {
         SCLogDebug!("GAP of size {} in toclient direction", gap_size);
+        self.tc_gap_stats.gap();
         let consumed = self.handle_skip(Direction::ToClient, gap_size);
         if consumed == gap_size {
             /* no need to tag ssn as gap'd as we got it in our skip logic. */
+            self.tc_gap_stats.resync();
             return AppLayerResult::ok();
         }
 
@@ -1933,13 +2238,14 @@ impl SMBState {
             let consumed2 = self.filetracker_update(Direction::ToClient, &gap, new_gap_size);
             if consumed2 == new_gap_size {
                 /* no need to tag ssn as gap'd as we got it in our file logic. */
+                self.tc_gap_stats.resync();
                 return AppLayerResult::ok();
             }
 
             if consumed2 > new_gap_size {
                 SCLogDebug!("consumed more than GAP size: {} > {}", consumed2, new_gap_size);
                 self.set_event(SMBEvent::InternalError);
-                return AppLayerResult::err();
+                return AppLayerResult::error(AppLayerError::Internal);
             }
         }
 
@@ -2014,8 +2320,11 @@ pub unsafe extern "C" fn rs_smb_parse_request_tcp(flow: *const Flow,
         state.ts_gap = true;
     }
 
-    state.update_ts(flow.get_last_time().as_secs());
-    state.parse_tcp_data_ts(flow, &stream_slice)
+    state.update_ts(flow.get_last_time());
+    let tx_id = state.tx_id;
+    let res = state.parse_tcp_data_ts(flow, &stream_slice);
+    state.update_latencies(Direction::ToServer, tx_id);
+    res
 }
 
 #[no_mangle]
@@ -2049,8 +2358,11 @@ pub unsafe extern "C" fn rs_smb_parse_response_tcp(flow: *const Flow,
         state.tc_gap = true;
     }
 
-    state.update_ts(flow.get_last_time().as_secs());
-    state.parse_tcp_data_tc(flow, &stream_slice)
+    state.update_ts(flow.get_last_time());
+    let tx_id = state.tx_id;
+    let res = state.parse_tcp_data_tc(flow, &stream_slice);
+    state.update_latencies(Direction::ToClient, tx_id);
+    res
 }
 
 #[no_mangle]
@@ -2250,24 +2562,6 @@ pub unsafe extern "C" fn rs_smb_get_tx_data(
     return &mut tx.tx_data;
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn rs_smb_state_get_event_info_by_id(
-    event_id: u8,
-    event_name: *mut *const std::os::raw::c_char,
-    event_type: *mut AppLayerEventType,
-) -> std::os::raw::c_int {
-    SMBEvent::get_event_info_by_id(event_id, event_name, event_type)
-}
-
-#[no_mangle]
-pub unsafe extern "C" fn rs_smb_state_get_event_info(
-    event_name: *const std::os::raw::c_char,
-    event_id: *mut u8,
-    event_type: *mut AppLayerEventType,
-) -> std::os::raw::c_int {
-    SMBEvent::get_event_info(event_name, event_id, event_type)
-}
-
 pub unsafe extern "C" fn smb3_probe_tcp(f: *const Flow, dir: u8, input: *const u8, len: u32, rdir: *mut u8) -> u16 {
     let retval = rs_smb_probe_tcp(f, dir, input, len, rdir);
     let f = cast_pointer!(f, Flow);
@@ -2329,8 +2623,6 @@ const PARSER_NAME: &[u8] = b"smb\0";
 
 #[no_mangle]
 pub unsafe extern "C" fn rs_smb_register_parser() {
-    let default_port = CString::new("445").unwrap();
-    let mut stream_depth = SMB_CONFIG_DEFAULT_STREAM_DEPTH;
     let parser = RustParser {
         name: PARSER_NAME.as_ptr() as *const std::os::raw::c_char,
         default_port: std::ptr::null(),
@@ -2358,7 +2650,7 @@ pub unsafe extern "C" fn rs_smb_register_parser() {
         get_tx_data: rs_smb_get_tx_data,
         get_state_data: rs_smb_get_state_data,
         apply_tx_config: None,
-        flags: APP_LAYER_PARSER_OPT_ACCEPT_GAPS,
+        flags: APP_LAYER_PARSER_OPT_ACCEPT_GAPS | APP_LAYER_PARSER_OPT_UNLIMITED_STREAM_DEPTH,
         get_frame_id_by_name: Some(SMBFrameType::ffi_id_from_name),
         get_frame_name_by_id: Some(SMBFrameType::ffi_name_from_id),
     };
@@ -2376,31 +2668,17 @@ pub unsafe extern "C" fn rs_smb_register_parser() {
             return;
         }
 
-        let have_cfg = AppLayerProtoDetectPPParseConfPorts(ip_proto_str.as_ptr(),
-                    IPPROTO_TCP, parser.name, ALPROTO_SMB, 0,
-                    MIN_REC_SIZE, rs_smb_probe_tcp, rs_smb_probe_tcp);
-
-        if have_cfg == 0 {
-            AppLayerProtoDetectPPRegister(IPPROTO_TCP, default_port.as_ptr(), ALPROTO_SMB,
-                                          0, MIN_REC_SIZE, Direction::ToServer as u8, rs_smb_probe_tcp, rs_smb_probe_tcp);
-        }
+        applayer_register_probing_ports(IPPROTO_TCP, parser.name, ALPROTO_SMB, 0,
+                    MIN_REC_SIZE, rs_smb_probe_tcp, rs_smb_probe_tcp, Some(SMB_DEFAULT_PORT));
 
         if AppLayerParserConfParserEnabled(
             ip_proto_str.as_ptr(),
             parser.name,
         ) != 0
         {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust SMB parser registered.");
-        let retval = conf_get("app-layer.protocols.smb.stream-depth");
-        if let Some(val) = retval {
-            match get_memval(val) {
-                Ok(retval) => { stream_depth = retval as u32; }
-                Err(_) => { SCLogError!("Invalid depth value"); }
-            }
-        }
-        AppLayerParserSetStreamDepth(IPPROTO_TCP, ALPROTO_SMB, stream_depth);
         let retval = conf_get("app-layer.protocols.smb.max-read-size");
         if let Some(val) = retval {
             match get_memval(val) {
@@ -2498,6 +2776,16 @@ pub unsafe extern "C" fn rs_smb_register_parser() {
                 SCLogError!("Invalid max-dcerpc-frag-cache-size value");
             }
         }
+        if let Some(val) = conf_get("app-layer.protocols.smb.max-dcerpc-frag-buffer-size") {
+            match get_memval(val) {
+                Ok(v) if v > 0 => {
+                    SMB_CFG_MAX_FRAG_BUFFER_SIZE = v as usize;
+                }
+                _ => {
+                    SCLogError!("Invalid max-dcerpc-frag-buffer-size value");
+                }
+            }
+        }
         let retval = conf_get("app-layer.protocols.smb.max-session-cache-size");
         if let Some(val) = retval {
             if let Ok(v) = val.parse::<usize>() {
@@ -2510,6 +2798,49 @@ pub unsafe extern "C" fn rs_smb_register_parser() {
                 SCLogError!("Invalid max-session-cache-size value");
             }
         }
+        if let Some(val) = conf_get("app-layer.protocols.smb.break-storm.threshold") {
+            if let Ok(v) = val.parse::<u32>() {
+                SMB_CFG_BREAK_STORM_THRESHOLD = v;
+            } else {
+                SCLogError!("Invalid break-storm.threshold value");
+            }
+        }
+        if let Some(val) = conf_get("app-layer.protocols.smb.break-storm.window") {
+            if let Ok(v) = val.parse::<u64>() {
+                if v > 0 {
+                    SMB_CFG_BREAK_STORM_WINDOW = v;
+                } else {
+                    SCLogError!("Invalid break-storm.window value");
+                }
+            } else {
+                SCLogError!("Invalid break-storm.window value");
+            }
+        }
+        if let Some(val) = conf_get("app-layer.protocols.smb.read-scan.reads") {
+            if let Ok(v) = val.parse::<u32>() {
+                SMB_CFG_READ_SCAN_READS = v;
+            } else {
+                SCLogError!("Invalid read-scan.reads value");
+            }
+        }
+        if let Some(val) = conf_get("app-layer.protocols.smb.read-scan.files") {
+            if let Ok(v) = val.parse::<u32>() {
+                SMB_CFG_READ_SCAN_FILES = v;
+            } else {
+                SCLogError!("Invalid read-scan.files value");
+            }
+        }
+        if let Some(val) = conf_get("app-layer.protocols.smb.read-scan.max-read-size") {
+            if let Ok(v) = get_memval(val) {
+                SMB_CFG_READ_SCAN_MAX_READ_SIZE = v as u32;
+            } else {
+                SCLogError!("Invalid read-scan.max-read-size value");
+            }
+        }
+        if conf_get("app-layer.protocols.smb.filename-preserve-case").is_some() {
+            SMB_CFG_FILENAME_PRESERVE_CASE =
+                conf_get_bool("app-layer.protocols.smb.filename-preserve-case");
+        }
         SCLogConfig!("read: max record size: {}, max queued chunks {}, max queued size {}",
                 SMB_CFG_MAX_READ_SIZE, SMB_CFG_MAX_READ_QUEUE_CNT, SMB_CFG_MAX_READ_QUEUE_SIZE);
         SCLogConfig!("write: max record size: {}, max queued chunks {}, max queued size {}",
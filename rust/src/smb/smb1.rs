@@ -25,6 +25,7 @@ use crate::smb::smb::*;
 use crate::smb::dcerpc::*;
 use crate::smb::events::*;
 use crate::smb::files::*;
+use crate::smb::filename::smb_filename;
 
 use crate::smb::smb1_records::*;
 use crate::smb::smb1_session::*;
@@ -57,6 +58,7 @@ pub const SMB1_COMMAND_LOCK_AND_READ:           u8 = 0x13;
 pub const SMB1_COMMAND_WRITE_AND_UNLOCK:        u8 = 0x14;
 pub const SMB1_COMMAND_LOCKING_ANDX:            u8 = 0x24;
 pub const SMB1_COMMAND_TRANS:                   u8 = 0x25;
+pub const SMB1_COMMAND_TRANS_SECONDARY:         u8 = 0x26;
 pub const SMB1_COMMAND_ECHO:                    u8 = 0x2b;
 pub const SMB1_COMMAND_WRITE_AND_CLOSE:         u8 = 0x2c;
 pub const SMB1_COMMAND_OPEN_ANDX:               u8 = 0x2d;
@@ -107,6 +109,7 @@ pub fn smb1_command_string(c: u8) -> String {
         SMB1_COMMAND_READ_ANDX          => "SMB1_COMMAND_READ_ANDX",
         SMB1_COMMAND_WRITE_ANDX         => "SMB1_COMMAND_WRITE_ANDX",
         SMB1_COMMAND_TRANS              => "SMB1_COMMAND_TRANS",
+        SMB1_COMMAND_TRANS_SECONDARY    => "SMB1_COMMAND_TRANS_SECONDARY",
         SMB1_COMMAND_TRANS2             => "SMB1_COMMAND_TRANS2",
         SMB1_COMMAND_TRANS2_SECONDARY   => "SMB1_COMMAND_TRANS2_SECONDARY",
         SMB1_COMMAND_FIND_CLOSE2        => "SMB1_COMMAND_FIND_CLOSE2",
@@ -148,6 +151,19 @@ pub fn smb1_check_tx(cmd: u8) -> bool {
     }
 }
 
+/// NT LM 0.12, or its Samba alias, is the dialect that supports
+/// NTLM authentication, the other SMB1 dialects predate it.
+fn smb1_dialect_is_ntlm(d: &[u8]) -> bool {
+    d == b"NT LM 0.12" || d == b"NT LANMAN 1.0"
+}
+
+/// A dialect selected by the server is a downgrade if it is an SMB1
+/// dialect older than NT LM 0.12. "SMB 2.002" and "SMB 2.???" are
+/// offered to move on to SMB2.
+fn smb1_dialect_is_downgrade(d: &[u8]) -> bool {
+    !smb1_dialect_is_ntlm(d) && !d.starts_with(b"SMB 2.")
+}
+
 fn smb1_close_file(state: &mut SMBState, fid: &[u8], direction: Direction)
 {
     if let Some(tx) = state.get_file_tx_by_fuid(fid, direction) {
@@ -164,6 +180,52 @@ fn smb1_close_file(state: &mut SMBState, fid: &[u8], direction: Direction)
     }
 }
 
+/// Check if a READ_ANDX response is for a pipe: the tree is a pipe
+/// share or the FID had pipe data pending when the read was requested.
+pub fn smb1_read_response_is_pipe(state: &mut SMBState, r: &SmbRecord) -> bool
+{
+    let fid_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_OFFSET);
+    if let Some(o) = state.read_offset_cache.peek(&fid_key) {
+        if o.is_pipe {
+            return true;
+        }
+    }
+    let tree_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_SHARE);
+    match state.ssn2tree_cache.get(&tree_key) {
+        Some(n) => n.is_pipe,
+        None => false,
+    }
+}
+
+/// Forget the pipe state of a FID that is closed, so that pipe data
+/// of the old FID doesn't get mixed with the data of a reused FID.
+fn smb1_pipe_fid_closed(state: &mut SMBState, r: &SmbRecord, fid: &[u8])
+{
+    let mut frankenfid = fid.to_vec();
+    frankenfid.extend_from_slice(&u32_as_bytes(r.ssn_id));
+    if state.pipe_read_pending.pop(&frankenfid).is_some() {
+        SCLogDebug!("FID {:?} closed with pipe data pending", fid);
+    }
+    let key = SMBHashKeyHdrGuid::new(SMBCommonHdr::from1(r, SMBHDR_TYPE_TRANS_FRAG), fid.to_vec());
+    state.dcerpc_rec_frag_take(&key);
+}
+
+/// Map the FID from a create/open response to the file name stored
+/// when processing the request, so that later READ/WRITE/CLOSE commands
+/// on the FID can be tied to the file.
+fn smb1_map_fid_to_name(state: &mut SMBState, r: &SmbRecord, fid: &[u8])
+{
+    let guid_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_FILENAME);
+    if let Some(p) = state.ssn2vec_cache.pop(&guid_key) {
+        let mut fid = fid.to_vec();
+        fid.extend_from_slice(&u32_as_bytes(r.ssn_id));
+        SCLogDebug!("fid {:?} name {:?}", fid, p);
+        _ = state.guid2name_cache.put(fid, p);
+    } else {
+        SCLogDebug!("SMBv1 response: GUID NOT FOUND");
+    }
+}
+
 fn smb1_command_is_andx(c: u8) -> bool {
     match c {
         SMB1_COMMAND_LOCKING_ANDX |
@@ -193,12 +255,7 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                     SCLogDebug!("RENAME {:?}", rd);
 
                     let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
-                    let mut newname = rd.newname;
-                    newname.retain(|&i|i != 0x00);
-                    let mut oldname = rd.oldname;
-                    oldname.retain(|&i|i != 0x00);
-
-                    let tx = state.new_rename_tx(Vec::new(), oldname, newname);
+                    let tx = state.new_rename_tx(Vec::new(), rd.oldname, rd.newname);
                     tx.hdr = tx_hdr;
                     tx.request_done = true;
                     tx.vercmd.set_smb1_cmd(SMB1_COMMAND_RENAME);
@@ -252,8 +309,7 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                                         Ok((_, ren)) => {
                                             SCLogDebug!("TRANS2 SET_PATH_INFO DATA RENAME DONE {:?}", ren);
                                             let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
-                                            let mut newname = ren.newname.to_vec();
-                                            newname.retain(|&i|i != 0x00);
+                                            let newname = smb_filename(ren.newname, r.has_unicode_support());
 
                                             let fid : Vec<u8> = Vec::new();
 
@@ -335,8 +391,7 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                                         Ok((_, ren)) => {
                                             SCLogDebug!("TRANS2 SET_FILE_INFO DATA RENAME DONE {:?}", ren);
                                             let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
-                                            let mut newname = ren.newname.to_vec();
-                                            newname.retain(|&i|i != 0x00);
+                                            let newname = smb_filename(ren.newname, r.has_unicode_support());
 
                                             let mut frankenfid = pd.fid.to_vec();
                                             frankenfid.extend_from_slice(&u32_as_bytes(r.ssn_id));
@@ -379,6 +434,30 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                                 false
                             },
                         }
+                    } else if rd.subcmd == SMB1_TRANS2_QUERY_PATH_INFO {
+                        SCLogDebug!("QUERY_PATH_INFO");
+                        match parse_trans2_request_params_query_path_info(rd.setup_blob) {
+                            Ok((_, pd)) => {
+                                SCLogDebug!("TRANS2 QUERY_PATH_INFO PARAMS DONE {:?}", pd);
+                                let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
+
+                                let tx = state.new_queryinfo_tx(pd.oldname, rd.subcmd, pd.loi);
+                                tx.hdr = tx_hdr;
+                                tx.vercmd.set_smb1_cmd(SMB1_COMMAND_TRANS2);
+                                true
+                            },
+                            Err(Err::Incomplete(_n)) => {
+                                SCLogDebug!("TRANS2 QUERY_PATH_INFO PARAMS INCOMPLETE {:?}", _n);
+                                events.push(SMBEvent::MalformedData);
+                                false
+                            },
+                            Err(Err::Error(_e)) |
+                            Err(Err::Failure(_e)) => {
+                                SCLogDebug!("TRANS2 QUERY_PATH_INFO PARAMS ERROR {:?}", _e);
+                                events.push(SMBEvent::MalformedData);
+                                false
+                            },
+                        }
                     } else {
                         false
                     }
@@ -396,6 +475,23 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                 },
             }
         },
+        SMB1_COMMAND_QUERY_INFORMATION => {
+            match parse_smb_query_information_request_record(r.data, r) {
+                Ok((_, qr)) => {
+                    SCLogDebug!("QUERY_INFORMATION {:?}", qr);
+                    let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
+
+                    let tx = state.new_queryinfo_tx(qr.file_name, 0, 0);
+                    tx.hdr = tx_hdr;
+                    tx.vercmd.set_smb1_cmd(SMB1_COMMAND_QUERY_INFORMATION);
+                    true
+                },
+                _ => {
+                    events.push(SMBEvent::MalformedData);
+                    false
+                },
+            }
+        },
         SMB1_COMMAND_READ_ANDX => {
             match parse_smb_read_andx_request_record(&r.data[*andx_offset-SMB1_HEADER_SIZE..]) {
                 Ok((_, rr)) => {
@@ -405,7 +501,11 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                     let fid_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_OFFSET);
                     let mut fid = rr.fid.to_vec();
                     fid.extend_from_slice(&u32_as_bytes(r.ssn_id));
-                    let fidoff = SMBFileGUIDOffset::new(fid, rr.offset);
+                    // a read continuing the data of a TRANS response is
+                    // for the pipe, whatever we know about the tree.
+                    let is_pipe = state.pipe_read_pending.contains(&fid);
+                    let mut fidoff = SMBFileGUIDOffset::new(fid, rr.offset);
+                    fidoff.is_pipe = is_pipe;
                     state.read_offset_cache.put(fid_key, fidoff);
                 },
                 _ => {
@@ -424,6 +524,11 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
             smb1_trans_request_record(state, r);
             true
         },
+        SMB1_COMMAND_TRANS_SECONDARY => {
+            smb1_trans_secondary_request_record(state, r, &mut events);
+            no_response_expected = true;
+            false
+        },
         SMB1_COMMAND_NEGOTIATE_PROTOCOL => {
             match parse_smb1_negotiate_protocol_record(r.data) {
                 Ok((_, pr)) => {
@@ -443,6 +548,8 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                         dialects.push(dvec);
                     }
 
+                    let have_ntlm = dialects.iter().any(|d| smb1_dialect_is_ntlm(d));
+
                     let found = match state.get_negotiate_tx(1) {
                         Some(tx) => {
                             SCLogDebug!("WEIRD, should not have NEGOTIATE tx!");
@@ -460,6 +567,9 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                         if bad_dialects {
                             tx.set_event(SMBEvent::NegotiateMalformedDialects);
                         }
+                        if !have_ntlm {
+                            tx.set_event(SMBEvent::NegotiateDialectDowngrade);
+                        }
                     }
                     true
                 },
@@ -494,6 +604,32 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
                 },
             }
         },
+        SMB1_COMMAND_OPEN | SMB1_COMMAND_OPEN_ANDX => {
+            let data = &r.data[*andx_offset-SMB1_HEADER_SIZE..];
+            let res = if command == SMB1_COMMAND_OPEN {
+                parse_smb_open_request_record(data, r)
+            } else {
+                parse_smb_open_andx_request_record(data, r)
+            };
+            match res {
+                Ok((_, or)) => {
+                    SCLogDebug!("Open {:?}", or);
+                    let name_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_FILENAME);
+                    state.ssn2vec_cache.put(name_key, or.file_name.to_vec());
+
+                    let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
+                    let tx = state.new_create_tx(&or.file_name,
+                            or.disposition, false, false, tx_hdr);
+                    tx.vercmd.set_smb1_cmd(command);
+                    SCLogDebug!("TS OPEN TX {} created", tx.id);
+                    true
+                },
+                _ => {
+                    events.push(SMBEvent::MalformedData);
+                    false
+                },
+            }
+        },
         SMB1_COMMAND_SESSION_SETUP_ANDX => {
             SCLogDebug!("SMB1_COMMAND_SESSION_SETUP_ANDX user_id {}", r.user_id);
             smb1_session_setup_request(state, r, *andx_offset);
@@ -504,10 +640,8 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
             match parse_smb_connect_tree_andx_record(&r.data[*andx_offset-SMB1_HEADER_SIZE..], r) {
                 Ok((_, tr)) => {
                     let name_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_TREE);
-                    let mut name_val = tr.path;
-                    if name_val.len() > 1 {
-                        name_val = name_val[1..].to_vec();
-                    }
+                    // keep the UNC path as is, like SMB2 does
+                    let name_val = tr.path;
 
                     // store hdr as SMBHDR_TYPE_TREE, so with tree id 0
                     // when the response finds this we update it
@@ -541,6 +675,7 @@ fn smb1_request_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, and
 
                     SCLogDebug!("closing FID {:?}/{:?}", cd.fid, fid);
                     smb1_close_file(state, &fid, Direction::ToServer);
+                    smb1_pipe_fid_closed(state, r, cd.fid);
                 },
                 _ => {
                     events.push(SMBEvent::MalformedData);
@@ -640,13 +775,20 @@ fn smb1_response_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, an
                                         None
                                     } else {
                                         let d = x.dialects[dialect_idx].to_vec();
+                                        x.dialect = d.clone();
                                         Some(d)
                                     }
                                 },
                                 _ => { None },
                             };
-                            if d.is_none() {
-                                tx.set_event(SMBEvent::NegotiateMalformedDialects);
+                            match d {
+                                None => {
+                                    tx.set_event(SMBEvent::NegotiateMalformedDialects);
+                                }
+                                Some(ref d) if smb1_dialect_is_downgrade(d) => {
+                                    tx.set_event(SMBEvent::NegotiateDialectDowngrade);
+                                }
+                                _ => {}
                             }
                             (true, d)
                         },
@@ -726,18 +868,7 @@ fn smb1_response_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, an
                     Ok((_, cr)) => {
                         SCLogDebug!("Create AndX {:?}", cr);
 
-                        let guid_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_FILENAME);
-                        if let Some(mut p) = state.ssn2vec_cache.pop(&guid_key) {
-                            p.retain(|&i|i != 0x00);
-
-                            let mut fid = cr.fid.to_vec();
-                            fid.extend_from_slice(&u32_as_bytes(r.ssn_id));
-                            SCLogDebug!("SMB1_COMMAND_NT_CREATE_ANDX fid {:?}", fid);
-                            SCLogDebug!("fid {:?} name {:?}", fid, p);
-                            _ = state.guid2name_cache.put(fid, p);
-                        } else {
-                            SCLogDebug!("SMBv1 response: GUID NOT FOUND");
-                        }
+                        smb1_map_fid_to_name(state, r, cr.fid);
 
                         let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
                         if let Some(tx) = state.get_generic_tx(1, command as u16, &tx_hdr) {
@@ -752,6 +883,7 @@ fn smb1_response_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, an
                                 tdn.last_write_ts = cr.last_write_ts.as_unix();
                                 tdn.last_change_ts = cr.last_change_ts.as_unix();
                                 tdn.size = cr.file_size;
+                                tdn.attributes = cr.attributes;
                                 tdn.guid = cr.fid.to_vec();
                             }
                         }
@@ -766,6 +898,44 @@ fn smb1_response_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, an
                 false
             }
         },
+        SMB1_COMMAND_OPEN | SMB1_COMMAND_OPEN_ANDX => {
+            SCLogDebug!("{} response {:08x}", &smb1_command_string(command), r.nt_status);
+            if r.nt_status == SMB_NTSTATUS_SUCCESS {
+                let data = &r.data[*andx_offset-SMB1_HEADER_SIZE..];
+                let res = if command == SMB1_COMMAND_OPEN {
+                    parse_smb_open_response_record(data)
+                } else {
+                    parse_smb_open_andx_response_record(data)
+                };
+                match res {
+                    Ok((_, or)) => {
+                        SCLogDebug!("Open {:?}", or);
+                        smb1_map_fid_to_name(state, r, or.fid);
+
+                        let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
+                        if let Some(tx) = state.get_generic_tx(1, command as u16, &tx_hdr) {
+                            SCLogDebug!("tx {} with {}/{} marked as done",
+                                    tx.id, command, &smb1_command_string(command));
+                            tx.set_status(r.nt_status, false);
+                            tx.response_done = true;
+
+                            if let Some(SMBTransactionTypeData::CREATE(ref mut tdn)) = tx.type_data {
+                                tdn.last_write_ts = or.last_write_ts;
+                                tdn.size = or.file_size as u64;
+                                tdn.guid = or.fid.to_vec();
+                            }
+                        }
+                        true
+                    },
+                    _ => {
+                        events.push(SMBEvent::MalformedData);
+                        false
+                    },
+                }
+            } else {
+                false
+            }
+        },
         SMB1_COMMAND_CLOSE => {
             let fid = state.ssn2vec_cache.pop(&SMBCommonHdr::from1(r, SMBHDR_TYPE_GUID));
             if let Some(fid) = fid {
@@ -778,6 +948,10 @@ fn smb1_response_record_one(state: &mut SMBState, r: &SmbRecord, command: u8, an
             smb1_trans_response_record(state, r);
             true
         },
+        SMB1_COMMAND_QUERY_INFORMATION |
+        SMB1_COMMAND_TRANS2 => {
+            smb1_query_info_response_record(state, r, command)
+        },
         SMB1_COMMAND_SESSION_SETUP_ANDX => {
             smb1_session_setup_response(state, r, *andx_offset);
             true
@@ -845,6 +1019,73 @@ pub fn smb1_response_record(state: &mut SMBState, r: &SmbRecord) -> u32 {
     0
 }
 
+/// Parse the file metadata of a QUERY_INFORMATION or TRANS2
+/// QUERY_PATH_INFO response into the query tx, and fill in what the
+/// create tx of the same file lacks. Returns false if the response is not
+/// for a query tx, e.g. another TRANS2 subcommand.
+fn smb1_query_info_response_record(state: &mut SMBState, r: &SmbRecord, command: u8) -> bool
+{
+    let tx_hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_GENERICTX);
+    let tx = match state.get_generic_tx(1, command as u16, &tx_hdr) {
+        Some(tx) => tx,
+        None => { return false; },
+    };
+    let (loi, filename) = match tx.type_data {
+        Some(SMBTransactionTypeData::QUERYINFO(ref x)) => (x.loi, x.filename.to_vec()),
+        _ => { return false; },
+    };
+    SCLogDebug!("tx {} with {}/{} marked as done",
+            tx.id, command, &smb1_command_string(command));
+    tx.set_status(r.nt_status, r.is_dos_error);
+    tx.response_done = true;
+    if r.nt_status != SMB_NTSTATUS_SUCCESS {
+        return true;
+    }
+
+    let info = if command == SMB1_COMMAND_QUERY_INFORMATION {
+        parse_smb_query_information_response_record(r.data).ok().map(|(_, qr)| {
+            SmbFileInfoRecord {
+                attributes: Some(qr.attributes as u32),
+                last_write_ts: Some(SMBFiletime::from_unix(qr.last_write_ts)),
+                size: Some(qr.file_size as u64),
+                ..Default::default()
+            }
+        })
+    } else {
+        match parse_smb_trans_response_record(r.data) {
+            Ok((_, rd)) => {
+                match parse_trans2_response_data_query_info(rd.data, loi) {
+                    Some(Ok((_, info))) => Some(info),
+                    Some(Err(_)) => None,
+                    None => {
+                        SCLogDebug!("QUERY_PATH_INFO level {} not parsed", loi);
+                        return true;
+                    },
+                }
+            },
+            _ => None,
+        }
+    };
+    let info = match info {
+        Some(info) => info,
+        None => {
+            tx.set_event(SMBEvent::MalformedData);
+            return true;
+        },
+    };
+    SCLogDebug!("file info {:?}", info);
+    if let Some(SMBTransactionTypeData::QUERYINFO(ref mut x)) = tx.type_data {
+        x.info = info.clone();
+    }
+
+    if let Some(ctx) = state.get_create_tx_by_name(&filename) {
+        if let Some(SMBTransactionTypeData::CREATE(ref mut tdn)) = ctx.type_data {
+            tdn.update_info(&info);
+        }
+    }
+    true
+}
+
 pub fn smb1_trans_request_record(state: &mut SMBState, r: &SmbRecord)
 {
     let mut events : Vec<SMBEvent> = Vec::new();
@@ -870,7 +1111,15 @@ pub fn smb1_trans_request_record(state: &mut SMBState, r: &SmbRecord)
                 pipe_dcerpc = is_dcerpc;
             }
 
-            if pipe_dcerpc {
+            if pipe_dcerpc && (rd.params.total_data_cnt as usize) > rd.data.data.len() {
+                SCLogDebug!("SMBv1 TRANS TO PIPE: queueing {} of {} bytes",
+                        rd.data.data.len(), rd.params.total_data_cnt);
+                let key = SMBCommonHdr::from1(r, SMBHDR_TYPE_TRANS_REQ_FRAG);
+                state.trans_req_frag_cache.put(key, SMBTransRequestFrag {
+                    total_data_cnt: rd.params.total_data_cnt,
+                    data: rd.data.data.to_vec(),
+                });
+            } else if pipe_dcerpc {
                 SCLogDebug!("SMBv1 TRANS TO PIPE");
                 let hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER);
                 let vercmd = SMBVerCmdStat::new1(r.command);
@@ -884,6 +1133,54 @@ pub fn smb1_trans_request_record(state: &mut SMBState, r: &SmbRecord)
     smb1_request_record_generic(state, r, events);
 }
 
+/// Data of a TRANS request to a DCERPC pipe, waiting for the rest of
+/// it in TRANS_SECONDARY requests.
+#[derive(Debug)]
+pub struct SMBTransRequestFrag {
+    pub total_data_cnt: u16,
+    pub data: Vec<u8>,
+}
+
+/// Append the data of a TRANS_SECONDARY request to its TRANS request.
+/// Once all the data is there, it is passed to DCERPC.
+fn smb1_trans_secondary_request_record(state: &mut SMBState, r: &SmbRecord,
+        events: &mut Vec<SMBEvent>)
+{
+    let rd = match parse_smb_trans_secondary_request_record(r.data) {
+        Ok((_, rd)) => rd,
+        _ => {
+            events.push(SMBEvent::MalformedData);
+            return;
+        },
+    };
+    SCLogDebug!("TRANS_SECONDARY request {:?}", rd);
+
+    let key = SMBCommonHdr::from1(r, SMBHDR_TYPE_TRANS_REQ_FRAG);
+    let mut frag = match state.trans_req_frag_cache.pop(&key) {
+        Some(frag) => frag,
+        None => {
+            // not for a DCERPC pipe, or the TRANS was missed
+            return;
+        },
+    };
+    let len = frag.data.len() + rd.data.len();
+    if rd.data_displacement as usize != frag.data.len() || len > frag.total_data_cnt as usize {
+        SCLogDebug!("TRANS_SECONDARY displacement {} len {}, have {} of {}",
+                rd.data_displacement, rd.data.len(), frag.data.len(), frag.total_data_cnt);
+        events.push(SMBEvent::MalformedData);
+        return;
+    }
+    frag.data.extend_from_slice(rd.data);
+    if len < frag.total_data_cnt as usize {
+        state.trans_req_frag_cache.put(key, frag);
+        return;
+    }
+    SCLogDebug!("SMBv1 TRANS TO PIPE: {} bytes reassembled", len);
+    let hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER);
+    let vercmd = SMBVerCmdStat::new1(SMB1_COMMAND_TRANS);
+    smb_write_dcerpc_record(state, vercmd, hdr, &frag.data);
+}
+
 pub fn smb1_trans_response_record(state: &mut SMBState, r: &SmbRecord)
 {
     let mut events : Vec<SMBEvent> = Vec::new();
@@ -908,9 +1205,13 @@ pub fn smb1_trans_response_record(state: &mut SMBState, r: &SmbRecord)
             // if we get status 'BUFFER_OVERFLOW' this is only a part of
             // the data. Store it in the ssn/tree for later use.
             if r.nt_status == SMB_NTSTATUS_BUFFER_OVERFLOW {
+                // the rest of the data is read from the pipe using READ_ANDX
+                if !fid.is_empty() {
+                    state.pipe_read_pending.put(frankenfid, ());
+                }
                 let key = SMBHashKeyHdrGuid::new(SMBCommonHdr::from1(r, SMBHDR_TYPE_TRANS_FRAG), fid);
                 SCLogDebug!("SMBv1/TRANS: queueing data for len {} key {:?}", rd.data.len(), key);
-                state.dcerpc_rec_frag_cache.put(key, rd.data.to_vec());
+                state.dcerpc_rec_frag_store(key, rd.data.to_vec());
             } else if is_dcerpc {
                 SCLogDebug!("SMBv1 TRANS TO PIPE");
                 let hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER);
@@ -977,15 +1278,27 @@ pub fn smb1_write_request_record(state: &mut SMBState, r: &SmbRecord, andx_offse
             };
             if !found {
                 let tree_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_SHARE);
-                let (share_name, is_pipe) = match state.ssn2tree_cache.get(&tree_key) {
+                let (share_name, mut is_pipe) = match state.ssn2tree_cache.get(&tree_key) {
                     Some(n) => (n.name.to_vec(), n.is_pipe),
                     None => (Vec::new(), false),
                 };
+                // if we missed the TREE connect, writes would be handled as file data
+                let mut pipe_inferred = false;
+                if share_name.is_empty() && !is_pipe && smb_dcerpc_probe(rd.data) {
+                    SCLogDebug!("SMBv1 WRITE: no tree connect seen, looks like dcerpc");
+                    let tree = SMBTree::new(b"suricata::dcerpc".to_vec(), true);
+                    state.ssn2tree_cache.put(tree_key, tree);
+                    is_pipe = true;
+                    pipe_inferred = true;
+                }
                 if is_pipe {
                     SCLogDebug!("SMBv1 WRITE TO PIPE");
                     let hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER);
                     let vercmd = SMBVerCmdStat::new1_with_ntstatus(command, r.nt_status);
                     smb_write_dcerpc_record(state, vercmd, hdr, rd.data);
+                    if pipe_inferred {
+                        state.set_event(SMBEvent::DcerpcPipeInferred);
+                    }
                 } else {
                     let tx = state.new_file_tx(&file_fid, &file_name, Direction::ToServer);
                     if let Some(SMBTransactionTypeData::FILE(ref mut tdf)) = tx.type_data {
@@ -1026,7 +1339,9 @@ pub fn smb1_read_response_record(state: &mut SMBState, r: &SmbRecord, andx_offse
 {
     let mut events : Vec<SMBEvent> = Vec::new();
 
-    if r.nt_status == SMB_NTSTATUS_SUCCESS {
+    // a pipe read returns BUFFER_OVERFLOW if the message doesn't fit
+    // in the requested size, the client then reads the rest of it.
+    if r.nt_status == SMB_NTSTATUS_SUCCESS || r.nt_status == SMB_NTSTATUS_BUFFER_OVERFLOW {
         match parse_smb_read_andx_response_record(&r.data[andx_offset-SMB1_HEADER_SIZE..]) {
             Ok((_, rd)) => {
                 SCLogDebug!("SMBv1: read response => {:?}", rd);
@@ -1037,6 +1352,7 @@ pub fn smb1_read_response_record(state: &mut SMBState, r: &SmbRecord, andx_offse
                     state.set_skip(Direction::ToClient, nbss_remaining);
                     return;
                 }
+                let is_pipe = smb1_read_response_is_pipe(state, r);
                 let fid_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_OFFSET);
                 let (offset, file_fid) = match state.read_offset_cache.pop(&fid_key) {
                     Some(o) => (o.offset, o.guid),
@@ -1050,9 +1366,9 @@ pub fn smb1_read_response_record(state: &mut SMBState, r: &SmbRecord, andx_offse
                 SCLogDebug!("SMBv1 READ: FID {:?} offset {}", file_fid, offset);
 
                 let tree_key = SMBCommonHdr::from1(r, SMBHDR_TYPE_SHARE);
-                let (is_pipe, share_name) = match state.ssn2tree_cache.get(&tree_key) {
-                    Some(n) => (n.is_pipe, n.name.to_vec()),
-                    _ => { (false, Vec::new()) },
+                let share_name = match state.ssn2tree_cache.get(&tree_key) {
+                    Some(n) => n.name.to_vec(),
+                    _ => Vec::new(),
                 };
                 if !is_pipe {
                     let file_name = match state.guid2name_cache.get(&file_fid) {
@@ -1097,12 +1413,17 @@ pub fn smb1_read_response_record(state: &mut SMBState, r: &SmbRecord, andx_offse
                 } else {
                     SCLogDebug!("SMBv1 READ response from PIPE");
                     let hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER);
-                    let vercmd = SMBVerCmdStat::new1(SMB1_COMMAND_READ_ANDX);
+                    let vercmd = SMBVerCmdStat::new1_with_ntstatus(SMB1_COMMAND_READ_ANDX, r.nt_status);
 
                     // hack: we store fid with ssn id mixed in, but here we want the
                     // real thing instead.
                     let pure_fid = if file_fid.len() > 2 { &file_fid[0..2] } else { &[] };
                     smb_read_dcerpc_record(state, vercmd, hdr, pure_fid, rd.data);
+                    if r.nt_status == SMB_NTSTATUS_BUFFER_OVERFLOW {
+                        state.pipe_read_pending.put(file_fid.to_vec(), ());
+                    } else {
+                        state.pipe_read_pending.pop(&file_fid);
+                    }
                 }
 
                 state.set_file_left(Direction::ToClient, rd.len, rd.data.len() as u32, file_fid.to_vec());
@@ -1150,3 +1471,132 @@ fn smb1_response_record_generic(state: &mut SMBState, r: &SmbRecord, events: Vec
         tx.set_events(events);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smb1_dialect_is_downgrade() {
+        assert!(!smb1_dialect_is_downgrade(b"NT LM 0.12"));
+        assert!(!smb1_dialect_is_downgrade(b"NT LANMAN 1.0"));
+        assert!(!smb1_dialect_is_downgrade(b"SMB 2.002"));
+        assert!(!smb1_dialect_is_downgrade(b"SMB 2.???"));
+        assert!(smb1_dialect_is_downgrade(b"LANMAN2.1"));
+        assert!(smb1_dialect_is_downgrade(b"PC NETWORK PROGRAM 1.0"));
+        assert!(smb1_dialect_is_downgrade(b"NT LM 0.12x"));
+    }
+
+    #[test]
+    fn test_smb1_pipe_read_pending() {
+        let mut state = SMBState::new();
+        let r = SmbRecord {
+            command: SMB1_COMMAND_READ_ANDX,
+            is_dos_error: false,
+            nt_status: SMB_NTSTATUS_SUCCESS,
+            flags: 0x80,
+            flags2: 0,
+            tree_id: 1,
+            user_id: 0,
+            multiplex_id: 7,
+            process_id: 0,
+            ssn_id: 0x0800,
+            data: &[],
+        };
+        let fid = [0x00, 0x40];
+        let mut frankenfid = fid.to_vec();
+        frankenfid.extend_from_slice(&u32_as_bytes(r.ssn_id));
+
+        // unknown tree and no pipe data pending: a file read
+        state.read_offset_cache.put(SMBCommonHdr::from1(&r, SMBHDR_TYPE_OFFSET),
+                SMBFileGUIDOffset::new(frankenfid.clone(), 0));
+        assert!(!smb1_read_response_is_pipe(&mut state, &r));
+
+        // read requested while the data of a TRANS response was pending
+        let mut fidoff = SMBFileGUIDOffset::new(frankenfid.clone(), 0);
+        fidoff.is_pipe = true;
+        state.read_offset_cache.put(SMBCommonHdr::from1(&r, SMBHDR_TYPE_OFFSET), fidoff);
+        assert!(smb1_read_response_is_pipe(&mut state, &r));
+
+        // closing the FID drops the pending state and the partial data
+        state.pipe_read_pending.put(frankenfid.clone(), ());
+        let key = SMBHashKeyHdrGuid::new(SMBCommonHdr::from1(&r, SMBHDR_TYPE_TRANS_FRAG), fid.to_vec());
+        state.dcerpc_rec_frag_store(key, vec![0x05, 0x00]);
+        assert_eq!(state.dcerpc_rec_frag_bytes, 2);
+        smb1_pipe_fid_closed(&mut state, &r, &fid);
+        assert!(!state.pipe_read_pending.contains(&frankenfid));
+        assert!(state.dcerpc_rec_frag_cache.is_empty());
+        assert_eq!(state.dcerpc_rec_frag_bytes, 0);
+    }
+
+    fn trans_secondary_record(total: u16, displacement: u16, data: &[u8]) -> Vec<u8> {
+        let mut rec = vec![8]; // wct
+        let len = data.len() as u16;
+        // data offset: SMB header, wct, 8 words and bcc
+        for v in [0, total, 0, 0, 0, len, 51, displacement, len] {
+            rec.extend_from_slice(&v.to_le_bytes());
+        }
+        rec.extend_from_slice(data);
+        rec
+    }
+
+    #[test]
+    fn test_smb1_trans_secondary() {
+        let mut state = SMBState::new();
+        let r = SmbRecord {
+            command: SMB1_COMMAND_TRANS_SECONDARY,
+            is_dos_error: false,
+            nt_status: SMB_NTSTATUS_SUCCESS,
+            flags: 0,
+            flags2: 0,
+            tree_id: 1,
+            user_id: 0,
+            multiplex_id: 7,
+            process_id: 0,
+            ssn_id: 0x0800,
+            data: &[],
+        };
+        // DCERPC request for opnum 15 with 8 bytes of stub data
+        let mut dcerpc = vec![
+            0x05, 0x00, 0x00, 0x03, 0x10, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        ];
+        dcerpc.extend_from_slice(&[0x41; 8]);
+        let key = SMBCommonHdr::from1(&r, SMBHDR_TYPE_TRANS_REQ_FRAG);
+        state.trans_req_frag_cache.put(key, SMBTransRequestFrag {
+            total_data_cnt: 32,
+            data: dcerpc[..20].to_vec(),
+        });
+
+        let mut events = Vec::new();
+        let rec = trans_secondary_record(32, 20, &dcerpc[20..24]);
+        smb1_trans_secondary_request_record(&mut state, &SmbRecord { data: &rec, ..r }, &mut events);
+        assert_eq!(state.trans_req_frag_cache.len(), 1);
+        assert!(state.transactions.is_empty());
+
+        let rec = trans_secondary_record(32, 24, &dcerpc[24..]);
+        smb1_trans_secondary_request_record(&mut state, &SmbRecord { data: &rec, ..r }, &mut events);
+        assert!(events.is_empty());
+        assert!(state.trans_req_frag_cache.is_empty());
+        assert_eq!(state.transactions.len(), 1);
+        match state.transactions[0].type_data {
+            Some(SMBTransactionTypeData::DCERPC(ref tdn)) => {
+                assert_eq!(tdn.opnum, 15);
+                assert_eq!(tdn.stub_data_ts, vec![0x41; 8]);
+            }
+            _ => panic!("no DCERPC tx"),
+        }
+
+        // data out of order
+        let key = SMBCommonHdr::from1(&r, SMBHDR_TYPE_TRANS_REQ_FRAG);
+        state.trans_req_frag_cache.put(key, SMBTransRequestFrag {
+            total_data_cnt: 32,
+            data: dcerpc[..20].to_vec(),
+        });
+        let rec = trans_secondary_record(32, 24, &dcerpc[24..]);
+        smb1_trans_secondary_request_record(&mut state, &SmbRecord { data: &rec, ..r }, &mut events);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], SMBEvent::MalformedData));
+        assert!(state.trans_req_frag_cache.is_empty());
+    }
+}
@@ -17,6 +17,7 @@
 
 use crate::common::nom7::take_until_and_consume;
 use crate::smb::error::SmbError;
+use crate::smb::filename::smb_filename;
 use crate::smb::smb::*;
 use crate::smb::smb_records::*;
 use nom7::bytes::streaming::{tag, take};
@@ -47,6 +48,17 @@ pub fn smb1_get_string<'a>(i: &'a[u8], r: &SmbRecord, offset: usize) -> IResult<
     }
 }
 
+/// take a file name, unicode or ascii based on record, and normalize it
+pub fn smb1_get_filename<'a>(i: &'a[u8], r: &SmbRecord, offset: usize) -> IResult<&'a[u8], Vec<u8>, SmbError> {
+    if r.has_unicode_support() {
+        let (i, _) = cond(offset % 2 == 1, take(1_usize))(i)?;
+        let (i, name) = smb_get_utf16_string(i)?;
+        Ok((i, smb_filename(name, true)))
+    } else {
+        let (i, name) = smb_get_ascii_string(i)?;
+        Ok((i, smb_filename(&name, false)))
+    }
+}
 
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbParamBlockAndXHeader {
@@ -280,6 +292,7 @@ pub fn parse_smb_trans_request_record_pipe(i: &[u8])
 
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbRecordTransRequestParams<> {
+    pub total_data_cnt: u16,
     pub max_data_cnt: u16,
     param_cnt: u16,
     param_offset: u16,
@@ -293,7 +306,7 @@ pub fn parse_smb_trans_request_record_params(i: &[u8])
 {
    let (i, wct) = le_u8(i)?;
    let (i, _total_param_cnt) = le_u16(i)?;
-   let (i, _total_data_count) = le_u16(i)?;
+   let (i, total_data_cnt) = le_u16(i)?;
    let (i, _max_param_cnt) = le_u16(i)?;
    let (i, max_data_cnt) = le_u16(i)?;
    let (i, _max_setup_cnt) = le_u8(i)?;
@@ -310,6 +323,7 @@ pub fn parse_smb_trans_request_record_params(i: &[u8])
    let (i, pipe) = cond(wct == 16 && setup_cnt == 2 && data_cnt > 0, parse_smb_trans_request_record_pipe)(i)?;
    let (i, bcc) = le_u16(i)?;
    let params = SmbRecordTransRequestParams {
+            total_data_cnt,
             max_data_cnt,
             param_cnt,
             param_offset,
@@ -387,6 +401,46 @@ pub fn parse_smb_trans_request_record<'a>(i: &'a[u8], r: &SmbRecord)
     Ok((rem, res))
 }
 
+#[derive(Debug,PartialEq, Eq)]
+pub struct SmbRecordTransSecondaryRequest<'a> {
+    pub total_data_cnt: u16,
+    pub data_displacement: u16,
+    pub data: &'a[u8],
+}
+
+/// TRANS_SECONDARY request, carrying the part of the parameters and
+/// data of a TRANS request that did not fit in it.
+pub fn parse_smb_trans_secondary_request_record(i: &[u8]) -> IResult<&[u8], SmbRecordTransSecondaryRequest> {
+    let origin_i = i;
+    let (i, _wct) = le_u8(i)?;
+    let (i, _total_param_cnt) = le_u16(i)?;
+    let (i, total_data_cnt) = le_u16(i)?;
+    let (i, _param_cnt) = le_u16(i)?;
+    let (i, _param_offset) = le_u16(i)?;
+    let (i, _param_displacement) = le_u16(i)?;
+    let (i, data_cnt) = le_u16(i)?;
+    let (i, data_offset) = le_u16(i)?;
+    let (i, data_displacement) = le_u16(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let data = if data_cnt > 0 {
+        // data offset is from the start of the SMB header
+        let hdr_len = SMB1_HEADER_SIZE + (origin_i.len() - i.len());
+        if (data_offset as usize) < hdr_len {
+            return Err(Err::Error(make_error(i, ErrorKind::LengthValue)));
+        }
+        let (i, _padding) = take(data_offset as usize - SMB1_HEADER_SIZE)(origin_i)?;
+        let (_, data) = take(data_cnt)(i)?;
+        data
+    } else {
+        &[]
+    };
+    let record = SmbRecordTransSecondaryRequest {
+        total_data_cnt,
+        data_displacement,
+        data,
+    };
+    Ok((i, record))
+}
 
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbRecordTransResponse<'a> {
@@ -445,18 +499,57 @@ pub fn parse_smb_trans_response_record(i: &[u8]) -> IResult<&[u8], SmbRecordTran
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbRecordSetupAndX<'a> {
     pub sec_blob: &'a[u8],
+    /// Request without extended security: the client authenticates
+    /// with plain LM/NTLM passwords instead of a security blob, and the
+    /// account name and primary domain come before the native OS and
+    /// LAN Manager strings.
+    pub legacy: bool,
 }
 
-pub fn parse_smb_setup_andx_record(i: &[u8]) -> IResult<&[u8], SmbRecordSetupAndX> {
+/// Request with extended security, wct 12.
+fn setup_andx_record(i: &[u8]) -> IResult<&[u8], SmbRecordSetupAndX> {
     let (i, _skip1) = take(15_usize)(i)?;
     let (i, sec_blob_len) = le_u16(i)?;
     let (i, _skip2) = take(8_usize)(i)?;
     let (i, _bcc) = le_u16(i)?;
     let (i, sec_blob) = take(sec_blob_len)(i)?;
-    let record = SmbRecordSetupAndX { sec_blob };
+    let record = SmbRecordSetupAndX { sec_blob, legacy: false };
+    Ok((i, record))
+}
+
+/// NT LM 0.12 request without extended security, wct 13.
+fn setup_andx_legacy_record(i: &[u8]) -> IResult<&[u8], SmbRecordSetupAndX> {
+    let (i, _skip1) = take(15_usize)(i)?;
+    let (i, oem_password_len) = le_u16(i)?;
+    let (i, unicode_password_len) = le_u16(i)?;
+    let (i, _skip2) = take(8_usize)(i)?; // reserved, capabilities
+    let (i, _bcc) = le_u16(i)?;
+    let (i, _oem_password) = take(oem_password_len)(i)?;
+    let (i, _unicode_password) = take(unicode_password_len)(i)?;
+    let record = SmbRecordSetupAndX { sec_blob: &[], legacy: true };
     Ok((i, record))
 }
 
+/// LAN Manager request, wct 10.
+fn setup_andx_lanman_record(i: &[u8]) -> IResult<&[u8], SmbRecordSetupAndX> {
+    let (i, _skip1) = take(15_usize)(i)?;
+    let (i, password_len) = le_u16(i)?;
+    let (i, _reserved) = take(4_usize)(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let (i, _password) = take(password_len)(i)?;
+    let record = SmbRecordSetupAndX { sec_blob: &[], legacy: true };
+    Ok((i, record))
+}
+
+pub fn parse_smb_setup_andx_record(i: &[u8]) -> IResult<&[u8], SmbRecordSetupAndX> {
+    let (i, wct) = peek(le_u8)(i)?;
+    match wct {
+        10 => setup_andx_lanman_record(i),
+        13 => setup_andx_legacy_record(i),
+        _ => setup_andx_record(i),
+    }
+}
+
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbResponseRecordSetupAndX<'a> {
     pub sec_blob: &'a[u8],
@@ -570,12 +663,15 @@ pub fn parse_smb_rename_request_record(i: &[u8]) -> IResult<&[u8], SmbRequestRen
     let (i, _search_attr) = le_u16(i)?;
     let (i, _bcc) = le_u16(i)?;
     let (i, _oldtype) = le_u8(i)?;
-    let (i, oldname) = smb_get_unicode_string(i)?;
+    let (i, oldname) = smb_get_utf16_string(i)?;
     let (i, _newtype) = le_u8(i)?;
-    let (i, newname) = smb_get_unicode_string_with_offset(i, 1)?; // HACK if we assume oldname is a series of utf16 chars offset would be 1
+    // oldname is a series of utf16 chars after the 1 byte type, so the
+    // newname needs 1 byte of padding to be aligned again
+    let (i, _pad) = take(1_usize)(i)?;
+    let (i, newname) = smb_get_utf16_string(i)?;
     let record = SmbRequestRenameRecord {
-        oldname,
-        newname
+        oldname: smb_filename(oldname, true),
+        newname: smb_filename(newname, true),
     };
     Ok((i, record))
 }
@@ -599,7 +695,7 @@ pub fn parse_smb_create_andx_request_record<'a>(i: &'a[u8], r: &SmbRecord)
     let (i, bcc) = le_u16(i)?;
     let (i, file_name) = cond(
         bcc >= file_name_len,
-        |b| smb1_get_string(b, r, (bcc - file_name_len) as usize)
+        |b| smb1_get_filename(b, r, (bcc - file_name_len) as usize)
     )(i)?;
     let (i, _skip3) = rest(i)?;
     let record = SmbRequestCreateAndXRecord {
@@ -665,8 +761,8 @@ pub struct Trans2RecordParamSetPathInfo<> {
 pub fn parse_trans2_request_params_set_path_info(i: &[u8]) -> IResult<&[u8], Trans2RecordParamSetPathInfo, SmbError> {
     let (i, loi) = le_u16(i)?;
     let (i, _reserved) = take(4_usize)(i)?;
-    let (i, oldname) = smb_get_unicode_string(i)?;
-    let record = Trans2RecordParamSetPathInfo { loi, oldname };
+    let (i, oldname) = smb_get_utf16_string(i)?;
+    let record = Trans2RecordParamSetPathInfo { loi, oldname: smb_filename(oldname, true) };
     Ok((i, record))
 }
 
@@ -732,6 +828,151 @@ pub fn parse_smb_trans2_request_record(i: &[u8]) -> IResult<&[u8], SmbRequestTra
     Ok((i, record))
 }
 
+pub const SMB1_TRANS2_QUERY_PATH_INFO: u16 = 5;
+
+/// Information levels of a TRANS2 QUERY_PATH_INFO the file metadata is
+/// extracted from. The pass-through levels are the FileInformationClass
+/// plus 1000.
+pub const SMB_INFO_STANDARD: u16 = 0x0001;
+pub const SMB_QUERY_FILE_BASIC_INFO: u16 = 0x0101;
+pub const SMB_QUERY_FILE_STANDARD_INFO: u16 = 0x0102;
+pub const SMB_QUERY_FILE_ALL_INFO: u16 = 0x0107;
+pub const SMB_PASSTHROUGH_FILE_BASIC_INFO: u16 = 1004;
+pub const SMB_PASSTHROUGH_FILE_STANDARD_INFO: u16 = 1005;
+pub const SMB_PASSTHROUGH_FILE_ALL_INFO: u16 = 1018;
+
+/// TRANS2 QUERY_PATH_INFO parameters have the same layout as the
+/// SET_PATH_INFO ones.
+pub fn parse_trans2_request_params_query_path_info(i: &[u8]) -> IResult<&[u8], Trans2RecordParamSetPathInfo, SmbError> {
+    parse_trans2_request_params_set_path_info(i)
+}
+
+/// File metadata of a query information response. Fields not part of the
+/// information level are None.
+#[derive(Debug,Default,Clone,PartialEq, Eq)]
+pub struct SmbFileInfoRecord {
+    pub attributes: Option<u32>,
+    pub create_ts: Option<SMBFiletime>,
+    pub last_access_ts: Option<SMBFiletime>,
+    pub last_write_ts: Option<SMBFiletime>,
+    pub last_change_ts: Option<SMBFiletime>,
+    pub size: Option<u64>,
+}
+
+fn parse_file_basic_info(i: &[u8]) -> IResult<&[u8], SmbFileInfoRecord> {
+    let (i, create_ts) = le_u64(i)?;
+    let (i, last_access_ts) = le_u64(i)?;
+    let (i, last_write_ts) = le_u64(i)?;
+    let (i, last_change_ts) = le_u64(i)?;
+    let (i, attributes) = le_u32(i)?;
+    let record = SmbFileInfoRecord {
+        attributes: Some(attributes),
+        create_ts: Some(SMBFiletime::new(create_ts)),
+        last_access_ts: Some(SMBFiletime::new(last_access_ts)),
+        last_write_ts: Some(SMBFiletime::new(last_write_ts)),
+        last_change_ts: Some(SMBFiletime::new(last_change_ts)),
+        size: None,
+    };
+    Ok((i, record))
+}
+
+fn parse_file_standard_info(i: &[u8]) -> IResult<&[u8], SmbFileInfoRecord> {
+    let (i, _alloc_size) = le_u64(i)?;
+    let (i, eof) = le_u64(i)?;
+    let record = SmbFileInfoRecord {
+        size: Some(eof),
+        ..Default::default()
+    };
+    Ok((i, record))
+}
+
+fn parse_file_all_info(i: &[u8]) -> IResult<&[u8], SmbFileInfoRecord> {
+    let (i, basic) = parse_file_basic_info(i)?;
+    let (i, _reserved) = take(4_usize)(i)?;
+    let (i, standard) = parse_file_standard_info(i)?;
+    let record = SmbFileInfoRecord {
+        size: standard.size,
+        ..basic
+    };
+    Ok((i, record))
+}
+
+fn parse_info_standard(i: &[u8]) -> IResult<&[u8], SmbFileInfoRecord> {
+    // creation, last access and last write date and time in the DOS
+    // format, not converted
+    let (i, _dos_times) = take(12_usize)(i)?;
+    let (i, file_size) = le_u32(i)?;
+    let (i, _alloc_size) = le_u32(i)?;
+    let (i, attributes) = le_u16(i)?;
+    let record = SmbFileInfoRecord {
+        attributes: Some(attributes as u32),
+        size: Some(file_size as u64),
+        ..Default::default()
+    };
+    Ok((i, record))
+}
+
+/// Parse the data of a TRANS2 QUERY_PATH_INFO response for information
+/// level `loi`. Returns None for levels without file metadata.
+pub fn parse_trans2_response_data_query_info(i: &[u8], loi: u16)
+    -> Option<IResult<&[u8], SmbFileInfoRecord>>
+{
+    let res = match loi {
+        SMB_INFO_STANDARD => parse_info_standard(i),
+        SMB_QUERY_FILE_BASIC_INFO |
+        SMB_PASSTHROUGH_FILE_BASIC_INFO => parse_file_basic_info(i),
+        SMB_QUERY_FILE_STANDARD_INFO |
+        SMB_PASSTHROUGH_FILE_STANDARD_INFO => parse_file_standard_info(i),
+        SMB_QUERY_FILE_ALL_INFO |
+        SMB_PASSTHROUGH_FILE_ALL_INFO => parse_file_all_info(i),
+        _ => { return None; },
+    };
+    Some(res)
+}
+
+#[derive(Debug,PartialEq, Eq)]
+pub struct SmbRequestQueryInformationRecord<> {
+    pub file_name: Vec<u8>,
+}
+
+/// SMB_COM_QUERY_INFORMATION request.
+pub fn parse_smb_query_information_request_record<'a>(i: &'a[u8], r: &SmbRecord)
+    -> IResult<&'a[u8], SmbRequestQueryInformationRecord<>, SmbError>
+{
+    let (i, _wct) = le_u8(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let (i, _buffer_format) = le_u8(i)?;
+    let (i, file_name) = smb1_get_filename(i, r, 4)?;
+    let record = SmbRequestQueryInformationRecord {
+        file_name,
+    };
+    Ok((i, record))
+}
+
+#[derive(Debug,PartialEq, Eq)]
+pub struct SmbResponseQueryInformationRecord {
+    pub attributes: u16,
+    /// last write time in seconds since the unix epoch
+    pub last_write_ts: u32,
+    pub file_size: u32,
+}
+
+/// SMB_COM_QUERY_INFORMATION response.
+pub fn parse_smb_query_information_response_record(i: &[u8]) -> IResult<&[u8], SmbResponseQueryInformationRecord> {
+    let (i, _wct) = verify(le_u8, |&v| v == 10)(i)?;
+    let (i, attributes) = le_u16(i)?;
+    let (i, last_write_ts) = le_u32(i)?;
+    let (i, file_size) = le_u32(i)?;
+    let (i, _) = take(10_usize)(i)?; // reserved
+    let (i, _bcc) = le_u16(i)?;
+    let record = SmbResponseQueryInformationRecord {
+        attributes,
+        last_write_ts,
+        file_size,
+    };
+    Ok((i, record))
+}
+
 #[derive(Debug,PartialEq, Eq)]
 pub struct SmbResponseCreateAndXRecord<'a> {
     pub fid: &'a[u8],
@@ -739,6 +980,7 @@ pub struct SmbResponseCreateAndXRecord<'a> {
     pub last_access_ts: SMBFiletime,
     pub last_write_ts: SMBFiletime,
     pub last_change_ts: SMBFiletime,
+    pub attributes: u32,
     pub file_size: u64,
 }
 
@@ -754,7 +996,7 @@ pub fn parse_smb_create_andx_response_record(i: &[u8]) -> IResult<&[u8], SmbResp
     let (i, last_access_ts) = le_u64(i)?;
     let (i, last_write_ts) = le_u64(i)?;
     let (i, last_change_ts) = le_u64(i)?;
-    let (i, _) = take(4_usize)(i)?;
+    let (i, attributes) = le_u32(i)?;
     let (i, file_size) = le_u64(i)?;
     let (i, _eof) = le_u64(i)?;
     let (i, _file_type) = le_u16(i)?;
@@ -768,6 +1010,116 @@ pub fn parse_smb_create_andx_response_record(i: &[u8]) -> IResult<&[u8], SmbResp
         last_access_ts: SMBFiletime::new(last_access_ts),
         last_write_ts: SMBFiletime::new(last_write_ts),
         last_change_ts: SMBFiletime::new(last_change_ts),
+        attributes,
+        file_size,
+    };
+    Ok((i, record))
+}
+
+/// Map the OpenMode/OpenFunction of the legacy OPEN commands to the
+/// NT_CREATE_ANDX CreateDisposition values.
+fn smb1_open_mode_to_disposition(open_mode: u16) -> u32 {
+    let create = open_mode & 0x0010 != 0;
+    match (create, open_mode & 0x0003) {
+        (false, 2) => 4,    // FILE_OVERWRITE
+        (true, 0) => 2,     // FILE_CREATE
+        (true, 1) => 3,     // FILE_OPEN_IF
+        (true, 2) => 5,     // FILE_OVERWRITE_IF
+        _ => 1,             // FILE_OPEN
+    }
+}
+
+#[derive(Debug,PartialEq, Eq)]
+pub struct SmbRequestOpenRecord<> {
+    pub disposition: u32,
+    pub file_name: Vec<u8>,
+}
+
+/// SMB_COM_OPEN request. Always opens an existing file.
+pub fn parse_smb_open_request_record<'a>(i: &'a[u8], r: &SmbRecord)
+    -> IResult<&'a[u8], SmbRequestOpenRecord<>, SmbError>
+{
+    let (i, _wct) = le_u8(i)?;
+    let (i, _access_mode) = le_u16(i)?;
+    let (i, _search_attrs) = le_u16(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let (i, _buffer_format) = le_u8(i)?;
+    let (i, file_name) = smb1_get_filename(i, r, 8)?;
+    let record = SmbRequestOpenRecord {
+        disposition: 1, // FILE_OPEN
+        file_name,
+    };
+    Ok((i, record))
+}
+
+/// SMB_COM_OPEN_ANDX request.
+pub fn parse_smb_open_andx_request_record<'a>(i: &'a[u8], r: &SmbRecord)
+    -> IResult<&'a[u8], SmbRequestOpenRecord<>, SmbError>
+{
+    let (i, _andx_hdr) = take(5_usize)(i)?;
+    let (i, _flags) = le_u16(i)?;
+    let (i, _access_mode) = le_u16(i)?;
+    let (i, _search_attrs) = le_u16(i)?;
+    let (i, _file_attrs) = le_u16(i)?;
+    let (i, _creation_time) = le_u32(i)?;
+    let (i, open_mode) = le_u16(i)?;
+    let (i, _alloc_size) = le_u32(i)?;
+    let (i, _timeout) = le_u32(i)?;
+    let (i, _reserved) = take(4_usize)(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let (i, file_name) = smb1_get_filename(i, r, 33)?;
+    let record = SmbRequestOpenRecord {
+        disposition: smb1_open_mode_to_disposition(open_mode),
+        file_name,
+    };
+    Ok((i, record))
+}
+
+#[derive(Debug,PartialEq, Eq)]
+pub struct SmbResponseOpenRecord<'a> {
+    pub fid: &'a[u8],
+    /// last write time in seconds since the unix epoch
+    pub last_write_ts: u32,
+    pub file_size: u32,
+}
+
+/// SMB_COM_OPEN response.
+pub fn parse_smb_open_response_record(i: &[u8]) -> IResult<&[u8], SmbResponseOpenRecord> {
+    let (i, _wct) = le_u8(i)?;
+    let (i, fid) = take(2_usize)(i)?;
+    let (i, _file_attrs) = le_u16(i)?;
+    let (i, last_write_ts) = le_u32(i)?;
+    let (i, file_size) = le_u32(i)?;
+    let (i, _access_mode) = le_u16(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let record = SmbResponseOpenRecord {
+        fid,
+        last_write_ts,
+        file_size,
+    };
+    Ok((i, record))
+}
+
+/// SMB_COM_OPEN_ANDX response.
+pub fn parse_smb_open_andx_response_record(i: &[u8]) -> IResult<&[u8], SmbResponseOpenRecord> {
+    let (i, wct) = le_u8(i)?;
+    let (i, _andx_command) = le_u8(i)?;
+    let (i, _) = take(1_usize)(i)?; // reserved
+    let (i, _andx_offset) = le_u16(i)?;
+    let (i, fid) = take(2_usize)(i)?;
+    let (i, _file_attrs) = le_u16(i)?;
+    let (i, last_write_ts) = le_u32(i)?;
+    let (i, file_size) = le_u32(i)?;
+    let (i, _access_rights) = le_u16(i)?;
+    let (i, _resource_type) = le_u16(i)?;
+    let (i, _pipe_status) = le_u16(i)?;
+    let (i, _open_results) = le_u16(i)?;
+    let (i, _) = take(6_usize)(i)?; // reserved
+    let (i, _) = cond(wct == 19, take(8_usize))(i)?;
+    let (i, _bcc) = le_u16(i)?;
+    let record = SmbResponseOpenRecord {
+        fid,
+        last_write_ts,
         file_size,
     };
     Ok((i, record))
@@ -878,3 +1230,90 @@ fn test_parse_smb1_write_andx_request_record_origin() {
     assert_eq!(record.data.len(), 20);
     assert_eq!(record.data, b"ABCDEFGHIJKLMNOPQR\n\n");
 }
+
+#[test]
+fn test_parse_smb1_open_andx_request_record() {
+    // SMB header with FLAGS2_UNICODE set, followed by the request
+    let data = hex::decode(concat!(
+        "ff534d422d000000001801800000000000000000000000000000fffe00000000",
+        "0fff0000000000020000000000000000001200000000000000000000000000",
+        "1300", "00", "5c0066006f006f002e007400780074000000"
+    )).unwrap();
+    let (_, r) = parse_smb_record(&data).unwrap();
+    let (_, record) = parse_smb_open_andx_request_record(r.data, &r).unwrap();
+    assert_eq!(record.disposition, 5);
+    assert_eq!(record.file_name, b"\\foo.txt");
+}
+
+#[test]
+fn test_parse_smb1_open_andx_response_record() {
+    let data = hex::decode(concat!(
+        "0fff000000", "0040", "2000", "00000000", "0c000000", "0200", "0000",
+        "0000", "0200", "000000000000", "0000"
+    )).unwrap();
+    let (_, record) = parse_smb_open_andx_response_record(&data).unwrap();
+    assert_eq!(record.fid, &[0x00, 0x40]);
+    assert_eq!(record.file_size, 12);
+}
+
+#[test]
+fn test_parse_smb1_query_information_records() {
+    // SMB header with FLAGS2_UNICODE set, followed by the request
+    let data = hex::decode(concat!(
+        "ff534d4208000000001801800000000000000000000000000000fffe00000000",
+        "00", "1300", "04", "5c0066006f006f002e007400780074000000"
+    )).unwrap();
+    let (_, r) = parse_smb_record(&data).unwrap();
+    let (_, record) = parse_smb_query_information_request_record(r.data, &r).unwrap();
+    assert_eq!(record.file_name, b"\\foo.txt");
+
+    let data = hex::decode(concat!(
+        "0a", "2000", "80b3e565", "00100000", "00000000000000000000", "0000"
+    )).unwrap();
+    let (_, record) = parse_smb_query_information_response_record(&data).unwrap();
+    assert_eq!(record.attributes, 0x20);
+    assert_eq!(record.last_write_ts, 1709552512);
+    assert_eq!(record.file_size, 4096);
+}
+
+#[test]
+fn test_parse_smb1_setup_andx_legacy_record() {
+    let data = hex::decode(concat!(
+        "0dff000000", "0411", "3200", "0000", "00000000", "0300", "0200",
+        "00000000", "d4000000", "0a00", "414243", "4445", "75736572004100"
+    )).unwrap();
+    let (rem, record) = parse_smb_setup_andx_record(&data).unwrap();
+    assert!(record.legacy);
+    assert!(record.sec_blob.is_empty());
+    assert_eq!(rem, b"user\0A\0");
+
+    let data = hex::decode(concat!(
+        "0aff000000", "0411", "3200", "0000", "00000000", "0100", "00000000",
+        "0700", "00", "7573657200", "00"
+    )).unwrap();
+    let (rem, record) = parse_smb_setup_andx_record(&data).unwrap();
+    assert!(record.legacy);
+    assert_eq!(rem, b"user\0\0");
+}
+
+#[test]
+fn test_parse_smb1_trans2_query_info_data() {
+    // FileAllInformation: basic info, then standard info
+    let data = hex::decode(concat!(
+        "004045f3286eda01", "004045f3286eda01", "004045f3286eda01", "004045f3286eda01",
+        "20000000", "00000000",
+        "0010000000000000", "0c00000000000000", "01000000", "00", "00", "0000"
+    )).unwrap();
+    let (_, record) = parse_trans2_response_data_query_info(&data, SMB_PASSTHROUGH_FILE_ALL_INFO)
+        .unwrap().unwrap();
+    assert_eq!(record.attributes, Some(0x20));
+    assert_eq!(record.size, Some(12));
+    assert_eq!(record.create_ts.unwrap().as_unix(), 1709552512);
+
+    let (_, record) = parse_trans2_response_data_query_info(&data[40..], SMB_QUERY_FILE_STANDARD_INFO)
+        .unwrap().unwrap();
+    assert_eq!(record.attributes, None);
+    assert_eq!(record.size, Some(12));
+
+    assert!(parse_trans2_response_data_query_info(&data, 0x0104).is_none());
+}
@@ -18,6 +18,7 @@
 use crate::smb::smb_records::*;
 use crate::smb::smb1_records::*;
 use crate::smb::smb::*;
+use crate::smb::smb_status::*;
 use crate::smb::events::*;
 use crate::smb::auth::*;
 
@@ -26,9 +27,11 @@ pub struct SessionSetupRequest {
     pub native_os: Vec<u8>,
     pub native_lm: Vec<u8>,
     pub primary_domain: Vec<u8>,
+    /// Account name of a request without extended security.
+    pub account_name: Option<Vec<u8>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SessionSetupResponse {
     pub native_os: Vec<u8>,
     pub native_lm: Vec<u8>,
@@ -59,6 +62,7 @@ pub fn smb1_session_setup_request_host_info(r: &SmbRecord, blob: &[u8]) -> Sessi
             native_os,
             native_lm,
             primary_domain,
+            account_name: None,
         }
     } else {
         let (native_os, native_lm, primary_domain) = match smb_get_ascii_string(blob) {
@@ -81,8 +85,57 @@ pub fn smb1_session_setup_request_host_info(r: &SmbRecord, blob: &[u8]) -> Sessi
             native_os,
             native_lm,
             primary_domain,
+            account_name: None,
+        }
+    }
+}
+
+/// Get up to `count` null terminated strings, unicode or ascii depending
+/// on the record. Missing strings are left empty.
+fn smb1_session_setup_strings(r: &SmbRecord, blob: &[u8], count: usize) -> Vec<Vec<u8>>
+{
+    let mut strings = Vec::with_capacity(count);
+    let unicode = blob.len() > 1 && r.has_unicode_support();
+    let mut rem = if unicode && (r.data.len() - blob.len()) % 2 == 1 {
+        &blob[1..]
+    } else {
+        blob
+    };
+    while strings.len() < count {
+        let res = if unicode {
+            smb_get_unicode_string(rem)
+        } else {
+            smb_get_ascii_string(rem)
+        };
+        match res {
+            Ok((i, s)) => {
+                strings.push(s);
+                rem = i;
+            },
+            _ => { break; },
         }
     }
+    strings.resize(count, Vec::new());
+    strings
+}
+
+/// Get the account name, primary domain, native OS and native LAN
+/// Manager strings of a request without extended security.
+pub fn smb1_session_setup_request_legacy_info(r: &SmbRecord, blob: &[u8]) -> SessionSetupRequest
+{
+    let mut strings = smb1_session_setup_strings(r, blob, 4).into_iter();
+    let account_name = strings.next().unwrap_or_default();
+    let primary_domain = strings.next().unwrap_or_default();
+    let native_os = strings.next().unwrap_or_default();
+    let native_lm = strings.next().unwrap_or_default();
+    SCLogDebug!("account {:?} domain {:?} os {:?} lm {:?}",
+            account_name, primary_domain, native_os, native_lm);
+    SessionSetupRequest {
+        native_os,
+        native_lm,
+        primary_domain,
+        account_name: Some(account_name),
+    }
 }
 
 pub fn smb1_session_setup_response_host_info(r: &SmbRecord, blob: &[u8]) -> SessionSetupResponse
@@ -135,6 +188,10 @@ pub fn smb1_session_setup_request(state: &mut SMBState, r: &SmbRecord, andx_offs
             tx.vercmd.set_smb1_cmd(r.command);
 
             if let Some(SMBTransactionTypeData::SESSIONSETUP(ref mut td)) = tx.type_data {
+                if setup.legacy {
+                    td.request_host = Some(smb1_session_setup_request_legacy_info(r, rem));
+                    return;
+                }
                 td.request_host = Some(smb1_session_setup_request_host_info(r, rem));
                 if let Some(s) = parse_secblob(setup.sec_blob) {
                     td.ntlmssp = s.ntlmssp;
@@ -153,12 +210,20 @@ pub fn smb1_session_setup_request(state: &mut SMBState, r: &SmbRecord, andx_offs
     }
 }
 
+/// Update the tx with the response. Returns the native OS and LAN Manager
+/// strings of the server if the response has them.
 fn smb1_session_setup_update_tx(tx: &mut SMBTransaction, r: &SmbRecord, andx_offset: usize)
+    -> Option<SessionSetupResponse>
 {
+    let mut server_host = None;
     match parse_smb_response_setup_andx_record(&r.data[andx_offset-SMB1_HEADER_SIZE..]) {
         Ok((rem, _setup)) => {
             if let Some(SMBTransactionTypeData::SESSIONSETUP(ref mut td)) = tx.type_data {
-                td.response_host = Some(smb1_session_setup_response_host_info(r, rem));
+                let host = smb1_session_setup_response_host_info(r, rem);
+                if !host.native_os.is_empty() || !host.native_lm.is_empty() {
+                    server_host = Some(host.clone());
+                }
+                td.response_host = Some(host);
             }
         },
         _ => {
@@ -169,17 +234,44 @@ fn smb1_session_setup_update_tx(tx: &mut SMBTransaction, r: &SmbRecord, andx_off
     tx.hdr = SMBCommonHdr::from1(r, SMBHDR_TYPE_HEADER); // to overwrite ssn_id 0
     tx.set_status(r.nt_status, r.is_dos_error);
     tx.response_done = true;
+    server_host
+}
+
+/// Make the file names of the FIDs opened under the previous session
+/// available under the UID of a new session.
+///
+/// SMB1 FIDs are stored in the guid2name_cache with the UID appended, so
+/// when a session reauthenticates and gets a new UID the names of the
+/// files that remain open would no longer be found.
+fn smb1_session_remap_fids(state: &mut SMBState, ssn_id: u32)
+{
+    let old_ssn_id = state.smb1_ssn_id;
+    state.smb1_ssn_id = ssn_id;
+    if old_ssn_id == 0 || old_ssn_id == ssn_id {
+        return;
+    }
+    let old_suffix = u32_as_bytes(old_ssn_id);
+    let new_suffix = u32_as_bytes(ssn_id);
+    let remapped: Vec<(Vec<u8>, Vec<u8>)> = state.guid2name_cache.iter()
+        .filter(|(fid, _)| fid.len() == 6 && fid.ends_with(&old_suffix))
+        .map(|(fid, name)| ([&fid[..2], &new_suffix[..]].concat(), name.to_vec()))
+        .collect();
+    SCLogDebug!("remapping {} fids from ssn {} to ssn {}", remapped.len(), old_ssn_id, ssn_id);
+    for (fid, name) in remapped {
+        _ = state.guid2name_cache.put(fid, name);
+    }
 }
 
 pub fn smb1_session_setup_response(state: &mut SMBState, r: &SmbRecord, andx_offset: usize)
 {
+    let mut server_host = None;
     // try exact match with session id already set (e.g. NTLMSSP AUTH phase)
     let found = r.ssn_id != 0 && match state.get_sessionsetup_tx(
                 SMBCommonHdr::new(SMBHDR_TYPE_HEADER,
                     r.ssn_id as u64, 0, r.multiplex_id as u64))
     {
         Some(tx) => {
-            smb1_session_setup_update_tx(tx, r, andx_offset);
+            server_host = smb1_session_setup_update_tx(tx, r, andx_offset);
             SCLogDebug!("smb1_session_setup_response: tx {:?}", tx);
             true
         },
@@ -190,10 +282,116 @@ pub fn smb1_session_setup_response(state: &mut SMBState, r: &SmbRecord, andx_off
         if let Some(tx) = state.get_sessionsetup_tx(
                 SMBCommonHdr::new(SMBHDR_TYPE_HEADER, 0, 0, r.multiplex_id as u64))
         {
-            smb1_session_setup_update_tx(tx, r, andx_offset);
+            server_host = smb1_session_setup_update_tx(tx, r, andx_offset);
             SCLogDebug!("smb1_session_setup_response: tx {:?}", tx);
         } else {
             SCLogDebug!("smb1_session_setup_response: tx not found for {:?}", r);
         }
     }
+    if server_host.is_some() {
+        state.server_host = server_host;
+    }
+    if r.nt_status == SMB_NTSTATUS_SUCCESS && r.ssn_id != 0 {
+        smb1_session_remap_fids(state, r.ssn_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u8> {
+        s.encode_utf16().chain([0]).flat_map(|c| c.to_le_bytes()).collect()
+    }
+
+    fn legacy_request() -> Vec<u8> {
+        // SMB header with FLAGS2_UNICODE set
+        let mut data = hex::decode(concat!(
+            "ff534d4273000000001801800000000000000000000000000000fffe00000100",
+            "0dff000000", "0411", "3200", "0000", "00000000", "1800", "1800",
+            "00000000", "d4000000"
+        )).unwrap();
+        let mut bytes = vec![0; 48]; // passwords
+        bytes.push(0); // pad to 2 bytes
+        for s in ["jdoe", "WORKGROUP", "Windows NT 1381", "Windows NT 4.0"] {
+            bytes.extend(utf16(s));
+        }
+        data.extend_from_slice(&(bytes.len() as u16).to_le_bytes());
+        data.extend(bytes);
+        data
+    }
+
+    #[test]
+    fn test_smb1_session_setup_request_legacy() {
+        let data = legacy_request();
+        let (_, r) = parse_smb_record(&data).unwrap();
+
+        let mut state = SMBState::new();
+        smb1_session_setup_request(&mut state, &r, SMB1_HEADER_SIZE);
+        let tx = state.get_sessionsetup_tx(
+            SMBCommonHdr::new(SMBHDR_TYPE_HEADER, 0, 0, 1)).unwrap();
+        if let Some(SMBTransactionTypeData::SESSIONSETUP(ref td)) = tx.type_data {
+            let host = td.request_host.as_ref().unwrap();
+            assert_eq!(host.account_name.as_deref(), Some(&b"jdoe"[..]));
+            assert_eq!(host.primary_domain, b"WORKGROUP");
+            assert_eq!(host.native_os, b"Windows NT 1381");
+            assert_eq!(host.native_lm, b"Windows NT 4.0");
+            assert!(td.ntlmssp.is_none());
+        } else {
+            panic!("not a session setup tx");
+        }
+    }
+
+    #[test]
+    fn test_smb1_session_setup_response_server_host() {
+        let data = legacy_request();
+        let (_, r) = parse_smb_record(&data).unwrap();
+        let mut state = SMBState::new();
+        smb1_session_setup_request(&mut state, &r, SMB1_HEADER_SIZE);
+
+        // response with UID 2048 and a 4 bytes security blob
+        let mut data = hex::decode(concat!(
+            "ff534d4273000000009801800000000000000000000000000000fffe00080100",
+            "04ff000000", "0000", "0400"
+        )).unwrap();
+        let mut bytes = vec![0xa1, 0x07, 0x30, 0x05];
+        bytes.push(0); // pad to 2 bytes
+        for s in ["Windows 5.1", "Windows 2000 LAN Manager"] {
+            bytes.extend(utf16(s));
+        }
+        data.extend_from_slice(&(bytes.len() as u16).to_le_bytes());
+        data.extend(bytes);
+        let (_, r) = parse_smb_record(&data).unwrap();
+        smb1_session_setup_response(&mut state, &r, SMB1_HEADER_SIZE);
+
+        let host = state.server_host.as_ref().unwrap();
+        assert_eq!(host.native_os, b"Windows 5.1");
+        assert_eq!(host.native_lm, b"Windows 2000 LAN Manager");
+        let tx = state.transactions.back().unwrap();
+        assert!(tx.response_done);
+        if let Some(SMBTransactionTypeData::SESSIONSETUP(ref td)) = tx.type_data {
+            assert_eq!(td.response_host.as_ref().unwrap().native_os, b"Windows 5.1");
+        } else {
+            panic!("not a session setup tx");
+        }
+    }
+
+    #[test]
+    fn test_smb1_session_remap_fids() {
+        let mut state = SMBState::new();
+        let mut fid = vec![0x01, 0x40];
+        fid.extend_from_slice(&u32_as_bytes(2048));
+        _ = state.guid2name_cache.put(fid, b"file.txt".to_vec());
+
+        smb1_session_remap_fids(&mut state, 2048);
+        assert_eq!(state.guid2name_cache.len(), 1);
+
+        // Reauthentication with a new UID.
+        smb1_session_remap_fids(&mut state, 4096);
+        assert_eq!(state.smb1_ssn_id, 4096);
+        let mut new_fid = vec![0x01, 0x40];
+        new_fid.extend_from_slice(&u32_as_bytes(4096));
+        assert_eq!(state.guid2name_cache.get(&new_fid), Some(&b"file.txt".to_vec()));
+        assert_eq!(state.guid2name_cache.len(), 2);
+    }
 }
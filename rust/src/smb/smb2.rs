@@ -23,9 +23,11 @@ use crate::smb::smb::*;
 use crate::smb::smb2_records::*;
 use crate::smb::smb2_session::*;
 use crate::smb::smb2_ioctl::*;
+use crate::smb::smb2_oplock::*;
 use crate::smb::dcerpc::*;
 use crate::smb::events::*;
 use crate::smb::files::*;
+use crate::smb::filename::smb_filename;
 use crate::smb::smb_status::*;
 
 pub const SMB2_COMMAND_NEGOTIATE_PROTOCOL:      u16 = 0;
@@ -163,6 +165,7 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
             SCLogDebug!("SMBv2 READ: GUID {:?} offset {}", file_guid, offset);
 
             let mut set_event_fileoverlap = false;
+            let mut set_event_read_scan = false;
             // look up existing tracker and if we have it update it
             let found = if let Some(tx) = state.get_file_tx_by_fuid_with_open_file(&file_guid, Direction::ToClient) {
                 if let Some(SMBTransactionTypeData::FILE(ref mut tdf)) = tx.type_data {
@@ -182,6 +185,7 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                             rd.len, false, &file_id);
                     }
                 }
+                set_event_read_scan = state.read_scan_update(r.session_id, &file_guid, offset, rd.len);
                 true
             } else {
                 false
@@ -200,6 +204,7 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                 };
                 SCLogDebug!("SMBv2/READ: share_name {:?} is_pipe {} is_dcerpc {}",
                         share_name, is_pipe, is_dcerpc);
+                let mut pipe_inferred = false;
 
                 if share_name.is_empty() && !is_pipe {
                     SCLogDebug!("SMBv2/READ: no tree connect seen, we don't know if we are a pipe");
@@ -214,6 +219,7 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                         }
                         is_pipe = true;
                         is_dcerpc = true;
+                        pipe_inferred = true;
                     } else {
                         SCLogDebug!("SMBv2/READ: not DCERPC");
                     }
@@ -224,6 +230,9 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                     let hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_HEADER);
                     let vercmd = SMBVerCmdStat::new2_with_ntstatus(SMB2_COMMAND_READ, r.nt_status);
                     smb_read_dcerpc_record(state, vercmd, hdr, &file_guid, rd.data);
+                    if pipe_inferred {
+                        state.set_event(SMBEvent::DcerpcPipeInferred);
+                    }
                 } else if is_pipe {
                     SCLogDebug!("non-DCERPC pipe");
                     state.set_skip(Direction::ToClient, nbss_remaining);
@@ -232,6 +241,7 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                         Some(n) => { n.to_vec() }
                         None => { b"<unknown>".to_vec() }
                     };
+                    set_event_read_scan = state.read_scan_update(r.session_id, &file_guid, offset, rd.len);
 
                     let tx = state.new_file_tx(&file_guid, &file_name, Direction::ToClient);
                     tx.vercmd.set_smb2_cmd(SMB2_COMMAND_READ);
@@ -261,6 +271,9 @@ pub fn smb2_read_response_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
             if set_event_fileoverlap {
                 state.set_event(SMBEvent::FileOverlap);
             }
+            if set_event_read_scan {
+                state.set_event(SMBEvent::ReadScanPattern);
+            }
             state.set_file_left(Direction::ToClient, rd.len, rd.data.len() as u32, file_guid.to_vec());
         }
         _ => {
@@ -343,6 +356,7 @@ pub fn smb2_write_request_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                 };
                 SCLogDebug!("SMBv2/WRITE: share_name {:?} is_pipe {} is_dcerpc {}",
                         share_name, is_pipe, is_dcerpc);
+                let mut pipe_inferred = false;
 
                 // if we missed the TREE connect we can't be sure if 'is_dcerpc' is correct
                 if share_name.is_empty() && !is_pipe {
@@ -359,6 +373,7 @@ pub fn smb2_write_request_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                         }
                         is_pipe = true;
                         is_dcerpc = true;
+                        pipe_inferred = true;
                     } else {
                         SCLogDebug!("SMBv2/WRITE: not DCERPC");
                     }
@@ -368,6 +383,9 @@ pub fn smb2_write_request_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
                     let hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_HEADER);
                     let vercmd = SMBVerCmdStat::new2(SMB2_COMMAND_WRITE);
                     smb_write_dcerpc_record(state, vercmd, hdr, wr.data);
+                    if pipe_inferred {
+                        state.set_event(SMBEvent::DcerpcPipeInferred);
+                    }
                 } else if is_pipe {
                     SCLogDebug!("non-DCERPC pipe: skip rest of the record");
                     state.set_skip(Direction::ToServer, nbss_remaining);
@@ -408,6 +426,68 @@ pub fn smb2_write_request_record(state: &mut SMBState, r: &Smb2Record, nbss_rema
     }
 }
 
+/// Flag the command a CANCEL request refers to. A command that went async
+/// is referenced by its async id, otherwise by its message id.
+fn smb2_cancel_request_record(state: &mut SMBState, r: &Smb2Record)
+{
+    let tx = state.transactions.iter_mut().find(|tx| {
+        let (_, cmd) = tx.vercmd.get_smb2_cmd();
+        if tx.vercmd.get_version() != 2 || cmd == SMB2_COMMAND_CANCEL ||
+            tx.hdr.ssn_id != r.session_id || tx.response_done
+        {
+            false
+        } else if r.async_id != 0 {
+            tx.async_id == r.async_id
+        } else {
+            tx.hdr.msg_id == r.message_id
+        }
+    });
+    if let Some(tx) = tx {
+        SCLogDebug!("SMBv2 CANCEL for tx {} async_id {}", tx.id, tx.async_id);
+        tx.cancelled = true;
+        if tx.async_id != 0 {
+            tx.set_event(SMBEvent::AsyncCommandCancelled);
+        }
+    } else {
+        SCLogDebug!("SMBv2 CANCEL for unknown command {:?}", r);
+    }
+}
+
+/// Track the interim STATUS_PENDING response of an async command and
+/// its final response, to record the async latency.
+fn smb2_async_response_record(state: &mut SMBState, r: &Smb2Record)
+{
+    if r.async_id == 0 {
+        return;
+    }
+    let ts = state.ts_usec;
+    if r.nt_status == SMB_NTSTATUS_PENDING {
+        let tx = state.transactions.iter_mut().find(|tx| {
+            let (_, cmd) = tx.vercmd.get_smb2_cmd();
+            tx.vercmd.get_version() == 2 && cmd == r.command && tx.async_id == 0 &&
+                !tx.response_done && tx.hdr.ssn_id == r.session_id &&
+                tx.hdr.msg_id == r.message_id
+        });
+        if let Some(tx) = tx {
+            tx.async_id = r.async_id;
+            tx.async_ts = ts;
+        }
+        return;
+    }
+    let tx = state.transactions.iter_mut().find(|tx| {
+        tx.async_id == r.async_id && tx.hdr.ssn_id == r.session_id &&
+            tx.async_latency.is_none()
+    });
+    if let Some(tx) = tx {
+        tx.async_latency = Some(ts.saturating_sub(tx.async_ts));
+        if r.nt_status == SMB_NTSTATUS_CANCELLED {
+            // nothing more will follow, whatever the command
+            tx.set_status(r.nt_status, false);
+            tx.response_done = true;
+        }
+    }
+}
+
 pub fn smb2_request_record(state: &mut SMBState, r: &Smb2Record)
 {
     SCLogDebug!("SMBv2 request record, command {} tree {} session {}",
@@ -425,13 +505,16 @@ pub fn smb2_request_record(state: &mut SMBState, r: &Smb2Record)
                     match rd.data {
                         Smb2SetInfoRequestData::RENAME(ref ren) => {
                             let tx_hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_GENERICTX);
-                            let mut newname = ren.name.to_vec();
-                            newname.retain(|&i|i != 0x00);
+                            let newname = smb_filename(ren.name, true);
                             let oldname = match state.guid2name_cache.get(rd.guid) {
                                 Some(n) => { n.to_vec() },
                                 None => { b"<unknown>".to_vec() },
                             };
                             let tx = state.new_rename_tx(rd.guid.to_vec(), oldname, newname);
+                            if let Some(SMBTransactionTypeData::RENAME(ref mut td)) = tx.type_data {
+                                td.info_level = rd.infolvl as u16;
+                                td.replace = ren.replace;
+                            }
                             tx.hdr = tx_hdr;
                             tx.request_done = true;
                             tx.vercmd.set_smb2_cmd(SMB2_COMMAND_SET_INFO);
@@ -517,11 +600,9 @@ pub fn smb2_request_record(state: &mut SMBState, r: &Smb2Record)
         SMB2_COMMAND_TREE_CONNECT => {
             if let Ok((_, tr)) = parse_smb2_request_tree_connect(r.data) {
                 let name_key = SMBCommonHdr::from2(r, SMBHDR_TYPE_TREE);
-                let mut name_val = tr.share_name.to_vec();
+                // skip the path length, the path is right after it
+                let mut name_val = tr.share_name.get(2..).unwrap_or_default().to_vec();
                 name_val.retain(|&i|i != 0x00);
-                if name_val.len() > 1 {
-                    name_val = name_val[1..].to_vec();
-                }
 
                 let tx = state.new_treeconnect_tx(name_key, name_val);
                 tx.request_done = true;
@@ -557,12 +638,27 @@ pub fn smb2_request_record(state: &mut SMBState, r: &Smb2Record)
                 let dir = cr.create_options & 0x0000_0001 != 0;
                 SCLogDebug!("create_options {:08x}", cr.create_options);
 
+                let file_name = smb_filename(cr.data, true);
                 let name_key = SMBCommonHdr::from2_notree(r, SMBHDR_TYPE_FILENAME);
-                state.ssn2vec_cache.put(name_key, cr.data.to_vec());
+                state.ssn2vec_cache.put(name_key, file_name.clone());
 
                 let tx_hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_GENERICTX);
-                let tx = state.new_create_tx(cr.data, cr.disposition, del, dir, tx_hdr);
+                let tx = state.new_create_tx(&file_name, cr.disposition, del, dir, tx_hdr);
                 tx.vercmd.set_smb2_cmd(r.command);
+                if let Some(SMBTransactionTypeData::CREATE(ref mut tdn)) = tx.type_data {
+                    tdn.oplock_level = cr.oplock_level;
+                    if let Some(lease) = cr.lease {
+                        tdn.lease_key = lease.key.to_vec();
+                        tdn.lease_state = lease.state;
+                    }
+                    tdn.create_contexts = cr.contexts.iter().map(|ctx| ctx.name.to_vec()).collect();
+                    if let Some(ref ts) = cr.timewarp {
+                        tdn.timewarp_ts = ts.as_unix();
+                    }
+                }
+                if cr.timewarp.is_some() {
+                    tx.set_event(SMBEvent::CreateTimewarpToken);
+                }
                 SCLogDebug!("TS CREATE TX {} created", tx.id);
                 true
             } else {
@@ -612,6 +708,18 @@ pub fn smb2_request_record(state: &mut SMBState, r: &Smb2Record)
             }
             false
         },
+        SMB2_COMMAND_OPLOCK_BREAK => {
+            smb2_oplock_break_request_record(state, r);
+            true
+        },
+        SMB2_COMMAND_CANCEL => {
+            smb2_cancel_request_record(state, r);
+            // CANCEL has no response of its own
+            let tx_key = SMBCommonHdr::from2(r, SMBHDR_TYPE_GENERICTX);
+            let tx = state.new_generic_tx(2, r.command, tx_key);
+            tx.response_done = true;
+            true
+        },
         _ => {
             false
         },
@@ -632,6 +740,8 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
             &smb2_command_string(r.command), r.nt_status,
             r.tree_id, r.session_id, r.message_id);
 
+    smb2_async_response_record(state, r);
+
     let mut events : Vec<SMBEvent> = Vec::new();
 
     let have_tx = match r.command {
@@ -643,6 +753,10 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
             smb2_session_setup_response(state, r);
             true
         },
+        SMB2_COMMAND_OPLOCK_BREAK => {
+            smb2_oplock_break_response_record(state, r);
+            true
+        },
         SMB2_COMMAND_WRITE => {
             if r.nt_status == SMB_NTSTATUS_SUCCESS {
                 if let Ok((_, _wr)) = parse_smb2_response_write(r.data) {
@@ -693,8 +807,7 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
                     SCLogDebug!("SMBv2: Create response => {:?}", cr);
 
                     let guid_key = SMBCommonHdr::from2_notree(r, SMBHDR_TYPE_FILENAME);
-                    if let Some(mut p) = state.ssn2vec_cache.pop(&guid_key) {
-                        p.retain(|&i|i != 0x00);
+                    if let Some(p) = state.ssn2vec_cache.pop(&guid_key) {
                         _ = state.guid2name_cache.put(cr.guid.to_vec(), p);
                     } else {
                         SCLogDebug!("SMBv2 response: GUID NOT FOUND");
@@ -713,7 +826,12 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
                             tdn.last_write_ts = cr.last_write_ts.as_unix();
                             tdn.last_change_ts = cr.last_change_ts.as_unix();
                             tdn.size = cr.size;
+                            tdn.attributes = cr.attributes;
                             tdn.guid = cr.guid.to_vec();
+                            tdn.oplock_level = cr.oplock_level;
+                            if let Some(lease) = cr.lease {
+                                tdn.lease_state = lease.state;
+                            }
                         }
                     }
                 } else {
@@ -800,6 +918,7 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
                     Some(tx) => {
                         if let Some(SMBTransactionTypeData::NEGOTIATE(ref mut tdn)) = tx.type_data {
                             tdn.server_guid = rd.server_guid.to_vec();
+                            tdn.dialect = smb2_dialect_string(rd.dialect).into_bytes();
                         }
                         tx.set_status(r.nt_status, false);
                         tx.response_done = true;
@@ -812,6 +931,7 @@ pub fn smb2_response_record(state: &mut SMBState, r: &Smb2Record)
                     Some(tx) => {
                         if let Some(SMBTransactionTypeData::NEGOTIATE(ref mut tdn)) = tx.type_data {
                             tdn.server_guid = rd.server_guid.to_vec();
+                            tdn.dialect = smb2_dialect_string(rd.dialect).into_bytes();
                         }
                         tx.set_status(r.nt_status, false);
                         tx.response_done = true;
@@ -0,0 +1,240 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! SMB2 oplock and lease break handling.
+//!
+//! The server sends a break notification for each open whose oplock or
+//! lease conflicts with an open by another client. A high rate of breaks
+//! is typical for a client rewriting many files on a share, e.g. while
+//! they are being encrypted.
+
+use crate::smb::events::*;
+use crate::smb::smb::*;
+use crate::smb::smb2::*;
+use crate::smb::smb2_records::*;
+use crate::smb::smb_status::*;
+
+pub const SMB2_OPLOCK_LEVEL_NONE: u8 = 0x00;
+pub const SMB2_OPLOCK_LEVEL_II: u8 = 0x01;
+pub const SMB2_OPLOCK_LEVEL_EXCLUSIVE: u8 = 0x08;
+pub const SMB2_OPLOCK_LEVEL_BATCH: u8 = 0x09;
+pub const SMB2_OPLOCK_LEVEL_LEASE: u8 = 0xff;
+
+pub const SMB2_LEASE_READ_CACHING: u32 = 0x01;
+pub const SMB2_LEASE_HANDLE_CACHING: u32 = 0x02;
+pub const SMB2_LEASE_WRITE_CACHING: u32 = 0x04;
+
+/// Message id of the break notifications, which are not a response to a
+/// request.
+const SMB2_MESSAGE_ID_UNSOLICITED: u64 = 0xffff_ffff_ffff_ffff;
+
+pub fn smb2_oplock_level_string(level: u8) -> String {
+    match level {
+        SMB2_OPLOCK_LEVEL_NONE => "NONE",
+        SMB2_OPLOCK_LEVEL_II => "II",
+        SMB2_OPLOCK_LEVEL_EXCLUSIVE => "EXCLUSIVE",
+        SMB2_OPLOCK_LEVEL_BATCH => "BATCH",
+        SMB2_OPLOCK_LEVEL_LEASE => "LEASE",
+        _ => {
+            return level.to_string();
+        }
+    }
+    .to_string()
+}
+
+/// Lease state as its caching flags, e.g. "RWH".
+pub fn smb2_lease_state_string(state: u32) -> String {
+    let mut s = String::new();
+    if state & SMB2_LEASE_READ_CACHING != 0 {
+        s.push('R');
+    }
+    if state & SMB2_LEASE_WRITE_CACHING != 0 {
+        s.push('W');
+    }
+    if state & SMB2_LEASE_HANDLE_CACHING != 0 {
+        s.push('H');
+    }
+    if s.is_empty() {
+        s.push_str("NONE");
+    }
+    s
+}
+
+#[derive(Debug, Default)]
+pub struct SMBTransactionOplockBreak {
+    /// Break notification by the server, acknowledgment by the client
+    /// otherwise.
+    pub notification: bool,
+    /// Oplock level to break to or acknowledged, None for a lease break.
+    pub oplock_level: Option<u8>,
+    /// File id of an oplock break, lease key of a lease break.
+    pub key: Vec<u8>,
+    /// Lease state to break to or acknowledged.
+    pub lease_state: u32,
+}
+
+impl SMBTransactionOplockBreak {
+    pub fn new(notification: bool, rd: &Smb2OplockBreakRecord) -> Self {
+        let mut brk = Self {
+            notification,
+            ..Default::default()
+        };
+        brk.update(rd);
+        brk
+    }
+
+    fn update(&mut self, rd: &Smb2OplockBreakRecord) {
+        match rd {
+            Smb2OplockBreakRecord::Oplock { oplock_level, guid } => {
+                self.oplock_level = Some(*oplock_level);
+                self.key = guid.to_vec();
+            }
+            Smb2OplockBreakRecord::Lease { key, state } => {
+                self.oplock_level = None;
+                self.key = key.to_vec();
+                self.lease_state = *state;
+            }
+        }
+    }
+}
+
+impl SMBState {
+    pub fn new_oplock_break_tx(
+        &mut self, hdr: SMBCommonHdr, brk: SMBTransactionOplockBreak,
+    ) -> &mut SMBTransaction {
+        let mut tx = self.new_tx();
+        tx.hdr = hdr;
+        tx.vercmd.set_smb2_cmd(SMB2_COMMAND_OPLOCK_BREAK);
+        tx.type_data = Some(SMBTransactionTypeData::OPLOCKBREAK(brk));
+        tx.request_done = true;
+        tx.response_done = self.tc_trunc; // no response expected if tc is truncated
+
+        SCLogDebug!("SMB: TX OPLOCK_BREAK created: ID {}", tx.id);
+        self.transactions.push_back(tx);
+        let tx_ref = self.transactions.back_mut();
+        return tx_ref.unwrap();
+    }
+
+    /// Count a break notification. Returns true, once per window, when
+    /// the number of notifications in the window reaches the break storm
+    /// threshold.
+    pub fn break_storm_update(&mut self) -> bool {
+        let threshold = unsafe { SMB_CFG_BREAK_STORM_THRESHOLD };
+        if threshold == 0 {
+            return false;
+        }
+        let window = unsafe { SMB_CFG_BREAK_STORM_WINDOW };
+        let ts = self.ts_usec / 1_000_000;
+        if self.break_window_cnt == 0 || ts.saturating_sub(self.break_window_ts) >= window {
+            self.break_window_ts = ts;
+            self.break_window_cnt = 0;
+        }
+        self.break_window_cnt = self.break_window_cnt.saturating_add(1);
+        self.break_window_cnt == threshold
+    }
+}
+
+/// Oplock or lease break acknowledgment by the client.
+pub fn smb2_oplock_break_request_record(state: &mut SMBState, r: &Smb2Record) {
+    let hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_GENERICTX);
+    match parse_smb2_oplock_break(r.data) {
+        Ok((_, rd)) => {
+            SCLogDebug!("OPLOCK_BREAK acknowledgment {:?}", rd);
+            state.new_oplock_break_tx(hdr, SMBTransactionOplockBreak::new(false, &rd));
+        }
+        _ => {
+            let tx = state.new_generic_tx(2, r.command, hdr);
+            tx.set_event(SMBEvent::MalformedData);
+        }
+    }
+}
+
+/// Oplock or lease break notification, or response to an
+/// acknowledgment, by the server.
+pub fn smb2_oplock_break_response_record(state: &mut SMBState, r: &Smb2Record) {
+    let hdr = SMBCommonHdr::from2(r, SMBHDR_TYPE_GENERICTX);
+    let rd = parse_smb2_oplock_break(r.data).ok().map(|(_, rd)| rd);
+
+    if r.message_id == SMB2_MESSAGE_ID_UNSOLICITED {
+        SCLogDebug!("OPLOCK_BREAK notification {:?}", rd);
+        let storm = state.break_storm_update();
+        let tx = if let Some(rd) = rd {
+            state.new_oplock_break_tx(hdr, SMBTransactionOplockBreak::new(true, &rd))
+        } else {
+            let tx = state.new_generic_tx(2, r.command, hdr);
+            tx.set_event(SMBEvent::MalformedData);
+            tx
+        };
+        tx.set_status(r.nt_status, false);
+        tx.response_done = true;
+        if storm {
+            tx.set_event(SMBEvent::OplockBreakStorm);
+        }
+        return;
+    }
+
+    if let Some(tx) = state.get_generic_tx(2, SMB2_COMMAND_OPLOCK_BREAK, &hdr) {
+        tx.set_status(r.nt_status, false);
+        tx.response_done = true;
+        match rd {
+            Some(rd) => {
+                if let Some(SMBTransactionTypeData::OPLOCKBREAK(ref mut tdb)) = tx.type_data {
+                    tdb.update(&rd);
+                }
+            }
+            None if r.nt_status == SMB_NTSTATUS_SUCCESS => {
+                tx.set_event(SMBEvent::MalformedData);
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smb2_lease_state_string() {
+        assert_eq!(smb2_lease_state_string(0), "NONE");
+        assert_eq!(smb2_lease_state_string(0x07), "RWH");
+        assert_eq!(smb2_lease_state_string(0x03), "RH");
+        assert_eq!(smb2_oplock_level_string(SMB2_OPLOCK_LEVEL_BATCH), "BATCH");
+        assert_eq!(smb2_oplock_level_string(0x20), "32");
+    }
+
+    #[test]
+    fn test_smb2_break_storm() {
+        let mut state = SMBState::new();
+        let threshold = unsafe { SMB_CFG_BREAK_STORM_THRESHOLD };
+        let window = unsafe { SMB_CFG_BREAK_STORM_WINDOW };
+        state.ts_usec = 1_000_000;
+        for _ in 1..threshold {
+            assert!(!state.break_storm_update());
+        }
+        assert!(state.break_storm_update());
+        // Raised once per window.
+        assert!(!state.break_storm_update());
+
+        // A new window starts the count over.
+        state.ts_usec += window * 1_000_000;
+        for _ in 1..threshold {
+            assert!(!state.break_storm_update());
+        }
+        assert!(state.break_storm_update());
+    }
+}
@@ -0,0 +1,150 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! SMB2 scan-like read pattern.
+//!
+//! Document harvesting tools read a small part of many files, e.g. to
+//! check their type or to grab their metadata, instead of reading files
+//! from start to end. Per session, the small reads that do not continue
+//! the previous read of the session are counted together with the files
+//! they are spread over. Once both reach their threshold, the session is
+//! flagged.
+
+use crate::smb::smb::*;
+
+#[derive(Debug, Default)]
+pub struct SMBReadScan {
+    /// Small reads not continuing the previous read of the session.
+    pub sparse_reads: u32,
+    /// Files of the sparse reads, only kept up to the files threshold.
+    files: Vec<Vec<u8>>,
+    /// File and offset the next read continues from.
+    last_guid: Vec<u8>,
+    next_offset: u64,
+    /// The thresholds were reached.
+    pub flagged: bool,
+}
+
+impl SMBReadScan {
+    /// Number of distinct files of the sparse reads, capped at the files
+    /// threshold.
+    pub fn files(&self) -> u32 {
+        self.files.len() as u32
+    }
+
+    /// Account for a read. Returns true for the read reaching both
+    /// thresholds.
+    fn update(&mut self, guid: &[u8], offset: u64, len: u32, cfg: &SMBReadScanConfig) -> bool {
+        let sequential = guid == self.last_guid && offset == self.next_offset;
+        if self.last_guid != guid {
+            self.last_guid = guid.to_vec();
+        }
+        self.next_offset = offset.saturating_add(len as u64);
+        if sequential || len > cfg.max_read_size {
+            return false;
+        }
+        self.sparse_reads = self.sparse_reads.saturating_add(1);
+        if (self.files.len() as u32) < cfg.files && !self.files.iter().any(|f| f == guid) {
+            self.files.push(guid.to_vec());
+        }
+        if !self.flagged && self.sparse_reads >= cfg.reads && self.files() >= cfg.files {
+            self.flagged = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Thresholds of the scan-like read pattern.
+#[derive(Debug)]
+pub struct SMBReadScanConfig {
+    /// Sparse reads of a session, 0 disables the check.
+    pub reads: u32,
+    /// Distinct files of the sparse reads.
+    pub files: u32,
+    /// Reads up to this size are small.
+    pub max_read_size: u32,
+}
+
+impl SMBState {
+    /// Account for a read of `len` bytes at `offset` of the file `guid`
+    /// by the session. Returns true, once per session, when the session
+    /// starts to look like it is scanning files.
+    pub fn read_scan_update(
+        &mut self, session_id: u64, guid: &[u8], offset: u64, len: u32,
+    ) -> bool {
+        let cfg = unsafe {
+            SMBReadScanConfig {
+                reads: SMB_CFG_READ_SCAN_READS,
+                files: SMB_CFG_READ_SCAN_FILES,
+                max_read_size: SMB_CFG_READ_SCAN_MAX_READ_SIZE,
+            }
+        };
+        if cfg.reads == 0 {
+            return false;
+        }
+        if let Some(scan) = self.read_scans.get_mut(&session_id) {
+            return scan.update(guid, offset, len, &cfg);
+        }
+        let mut scan = SMBReadScan::default();
+        let flagged = scan.update(guid, offset, len, &cfg);
+        self.read_scans.put(session_id, scan);
+        flagged
+    }
+
+    /// Get the read pattern of a session, if it did any read.
+    pub fn get_read_scan(&self, session_id: u64) -> Option<&SMBReadScan> {
+        self.read_scans.peek(&session_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smb2_read_scan() {
+        let cfg = SMBReadScanConfig {
+            reads: 4,
+            files: 2,
+            max_read_size: 4096,
+        };
+        let mut scan = SMBReadScan::default();
+        // reading a file from start to end is not sparse
+        assert!(!scan.update(b"file1", 0, 4096, &cfg));
+        assert!(!scan.update(b"file1", 4096, 4096, &cfg));
+        assert!(!scan.update(b"file1", 8192, 1000, &cfg));
+        assert_eq!(scan.sparse_reads, 1);
+
+        // scattered small reads, but on a single file
+        assert!(!scan.update(b"file1", 65536, 512, &cfg));
+        assert!(!scan.update(b"file1", 1024, 512, &cfg));
+        assert!(!scan.update(b"file1", 32768, 512, &cfg));
+        assert_eq!(scan.sparse_reads, 4);
+        assert_eq!(scan.files(), 1);
+
+        // large reads don't count
+        assert!(!scan.update(b"file2", 0, 65536, &cfg));
+        assert_eq!(scan.sparse_reads, 4);
+
+        assert!(scan.update(b"file3", 512, 512, &cfg));
+        assert!(scan.flagged);
+        // flagged once
+        assert!(!scan.update(b"file4", 512, 512, &cfg));
+        assert_eq!(scan.files(), 2);
+    }
+}
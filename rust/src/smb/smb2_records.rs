@@ -22,6 +22,7 @@ use nom7::combinator::{cond, map_parser, rest};
 use nom7::error::{make_error, ErrorKind};
 use nom7::multi::count;
 use nom7::number::streaming::{le_u16, le_u32, le_u64, le_u8};
+use nom7::sequence::preceded;
 use nom7::{Err, IResult, Needed};
 
 const SMB2_FLAGS_SERVER_TO_REDIR: u32 = 0x0000_0001;
@@ -100,8 +101,9 @@ pub fn parse_smb2_request_record(i: &[u8]) -> IResult<&[u8], Smb2Record> {
     let (i, flags) = parse_smb2_flags(i)?;
     let (i, chain_offset) = le_u32(i)?;
     let (i, message_id) = le_u64(i)?;
-    let (i, _process_id) = le_u32(i)?;
-    let (i, tree_id) = le_u32(i)?;
+    let (i, _process_id) = cond(flags.async_command == 0, le_u32)(i)?;
+    let (i, tree_id) = cond(flags.async_command == 0, le_u32)(i)?;
+    let (i, async_id) = cond(flags.async_command == 1, le_u64)(i)?;
     let (i, session_id) = le_u64(i)?;
     let (i, _signature) = take(16_usize)(i)?;
     let (i, data) = if chain_offset > hlen as u32 {
@@ -115,8 +117,8 @@ pub fn parse_smb2_request_record(i: &[u8]) -> IResult<&[u8], Smb2Record> {
         nt_status: 0,
         command,
         message_id,
-        tree_id,
-        async_id: 0,
+        tree_id: tree_id.unwrap_or(0),
+        async_id: async_id.unwrap_or(0),
         session_id,
         data,
     };
@@ -242,30 +244,196 @@ pub fn parse_smb2_response_tree_connect(i: &[u8]) -> IResult<&[u8], Smb2TreeConn
     Ok((i, record))
 }
 
+/// Size of the SMB2 header, which offsets in the commands are relative to.
+const SMB2_HEADER_SIZE: usize = 64;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Smb2CreateRequestRecord<'a> {
+    pub oplock_level: u8,
     pub disposition: u32,
     pub create_options: u32,
     pub data: &'a [u8],
+    pub contexts: Vec<Smb2CreateContext<'a>>,
+    pub lease: Option<Smb2Lease<'a>>,
+    /// Timestamp of the snapshot a SMB2_CREATE_TIMEWARP_TOKEN context
+    /// opens the file from.
+    pub timewarp: Option<SMBFiletime>,
+}
+
+/// Lease of a SMB2_CREATE_REQUEST_LEASE or SMB2_CREATE_REQUEST_LEASE_V2
+/// create context. Both versions start with the key and state.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Smb2Lease<'a> {
+    pub key: &'a [u8],
+    pub state: u32,
+}
+
+pub fn parse_smb2_lease(i: &[u8]) -> IResult<&[u8], Smb2Lease> {
+    let (i, key) = take(16_usize)(i)?;
+    let (i, state) = le_u32(i)?;
+    Ok((i, Smb2Lease { key, state }))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Smb2CreateContext<'a> {
+    pub next: u32,
+    pub name: &'a [u8],
+    pub data: &'a [u8],
+}
+
+pub fn parse_smb2_create_context(i: &[u8]) -> IResult<&[u8], Smb2CreateContext> {
+    let ctx = i;
+    let (i, next) = le_u32(i)?;
+    let (i, name_offset) = le_u16(i)?;
+    let (i, name_length) = le_u16(i)?;
+    let (i, _reserved) = le_u16(i)?;
+    let (i, data_offset) = le_u16(i)?;
+    let (i, data_length) = le_u32(i)?;
+    let (_, name) = preceded(take(name_offset), take(name_length))(ctx)?;
+    let (_, data) = preceded(take(data_offset), take(data_length))(ctx)?;
+    Ok((i, Smb2CreateContext { next, name, data }))
+}
+
+/// Create context names of the requests.
+pub const SMB2_CREATE_DURABLE_HANDLE_REQUEST: &[u8] = b"DHnQ";
+pub const SMB2_CREATE_DURABLE_HANDLE_RECONNECT: &[u8] = b"DHnC";
+pub const SMB2_CREATE_DURABLE_HANDLE_REQUEST_V2: &[u8] = b"DH2Q";
+pub const SMB2_CREATE_DURABLE_HANDLE_RECONNECT_V2: &[u8] = b"DH2C";
+pub const SMB2_CREATE_TIMEWARP_TOKEN: &[u8] = b"TWrp";
+pub const SMB2_CREATE_REQUEST_LEASE: &[u8] = b"RqLs";
+
+/// Max number of create contexts parsed per CREATE command.
+const SMB2_CREATE_CONTEXTS_MAX: usize = 32;
+
+/// Parse the create contexts at `offset` of a CREATE request or response
+/// `body`. Parsing stops at the first context that does not fit in the
+/// contexts buffer.
+fn smb2_create_contexts(body: &[u8], offset: u32, length: u32) -> Vec<Smb2CreateContext> {
+    let mut contexts = Vec::new();
+    if length == 0 {
+        return contexts;
+    }
+    let start = match (offset as usize).checked_sub(SMB2_HEADER_SIZE) {
+        Some(start) => start,
+        None => return contexts,
+    };
+    let mut buf = match body.get(start..start + length as usize) {
+        Some(buf) => buf,
+        None => return contexts,
+    };
+    while contexts.len() < SMB2_CREATE_CONTEXTS_MAX {
+        let ctx = match parse_smb2_create_context(buf) {
+            Ok((_, ctx)) => ctx,
+            Err(_) => break,
+        };
+        let next = ctx.next as usize;
+        contexts.push(ctx);
+        if next == 0 {
+            break;
+        }
+        buf = match buf.get(next..) {
+            Some(buf) => buf,
+            None => break,
+        };
+    }
+    contexts
+}
+
+fn smb2_create_find_context<'a>(
+    contexts: &[Smb2CreateContext<'a>], name: &[u8],
+) -> Option<&'a [u8]> {
+    contexts
+        .iter()
+        .find(|ctx| ctx.name == name)
+        .map(|ctx| ctx.data)
+}
+
+fn smb2_create_lease<'a>(contexts: &[Smb2CreateContext<'a>]) -> Option<Smb2Lease<'a>> {
+    let data = smb2_create_find_context(contexts, SMB2_CREATE_REQUEST_LEASE)?;
+    parse_smb2_lease(data).ok().map(|(_, lease)| lease)
+}
+
+fn smb2_create_timewarp(contexts: &[Smb2CreateContext]) -> Option<SMBFiletime> {
+    let data = smb2_create_find_context(contexts, SMB2_CREATE_TIMEWARP_TOKEN)?;
+    le_u64::<_, ()>(data)
+        .ok()
+        .map(|(_, ts)| SMBFiletime::new(ts))
 }
 
 pub fn parse_smb2_request_create(i: &[u8]) -> IResult<&[u8], Smb2CreateRequestRecord> {
-    let (i, _skip1) = take(36_usize)(i)?;
+    let body = i;
+    let (i, _skip1) = take(3_usize)(i)?;
+    let (i, oplock_level) = le_u8(i)?;
+    let (i, _skip2) = take(32_usize)(i)?;
     let (i, disposition) = le_u32(i)?;
     let (i, create_options) = le_u32(i)?;
     let (i, _file_name_offset) = le_u16(i)?;
     let (i, file_name_length) = le_u16(i)?;
-    let (i, _skip2) = take(8_usize)(i)?;
+    let (i, contexts_offset) = le_u32(i)?;
+    let (i, contexts_length) = le_u32(i)?;
     let (i, data) = take(file_name_length)(i)?;
     let (i, _skip3) = rest(i)?;
+    let contexts = smb2_create_contexts(body, contexts_offset, contexts_length);
     let record = Smb2CreateRequestRecord {
+        oplock_level,
         disposition,
         create_options,
         data,
+        lease: smb2_create_lease(&contexts),
+        timewarp: smb2_create_timewarp(&contexts),
+        contexts,
     };
     Ok((i, record))
 }
 
+/// OPLOCK_BREAK notification, acknowledgment or response, for either an
+/// oplock or a lease.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Smb2OplockBreakRecord<'a> {
+    Oplock {
+        oplock_level: u8,
+        guid: &'a [u8],
+    },
+    Lease {
+        key: &'a [u8],
+        /// Lease state the client has to break to, or acknowledges.
+        state: u32,
+    },
+}
+
+pub fn parse_smb2_oplock_break(i: &[u8]) -> IResult<&[u8], Smb2OplockBreakRecord> {
+    let (i, structure_size) = le_u16(i)?;
+    match structure_size {
+        // oplock break notification, acknowledgment and response
+        24 => {
+            let (i, oplock_level) = le_u8(i)?;
+            let (i, _reserved) = take(5_usize)(i)?;
+            let (i, guid) = take(16_usize)(i)?;
+            Ok((i, Smb2OplockBreakRecord::Oplock { oplock_level, guid }))
+        }
+        // lease break notification
+        44 => {
+            let (i, _new_epoch) = le_u16(i)?;
+            let (i, _flags) = le_u32(i)?;
+            let (i, key) = take(16_usize)(i)?;
+            let (i, _current_state) = le_u32(i)?;
+            let (i, state) = le_u32(i)?;
+            let (i, _skip) = take(12_usize)(i)?;
+            Ok((i, Smb2OplockBreakRecord::Lease { key, state }))
+        }
+        // lease break acknowledgment and response
+        36 => {
+            let (i, _reserved) = le_u16(i)?;
+            let (i, _flags) = le_u32(i)?;
+            let (i, key) = take(16_usize)(i)?;
+            let (i, state) = le_u32(i)?;
+            let (i, _duration) = le_u64(i)?;
+            Ok((i, Smb2OplockBreakRecord::Lease { key, state }))
+        }
+        _ => Err(Err::Error(make_error(i, ErrorKind::Verify))),
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct Smb2IOCtlRequestRecord<'a> {
     pub is_pipe: bool,
@@ -342,21 +510,44 @@ pub fn parse_smb2_request_close(i: &[u8]) -> IResult<&[u8], Smb2CloseRequestReco
     Ok((i, record))
 }
 
+// file info levels from [MS-FSCC] section 2.4
+pub const SMB2_FILE_RENAME_INFO: u8 = 10;
+pub const SMB2_FILE_DISPOSITION_INFO: u8 = 13;
+pub const SMB2_FILE_DISPOSITION_INFO_EX: u8 = 64;
+pub const SMB2_FILE_RENAME_INFO_EX: u8 = 65;
+
+// flags of FileRenameInformationEx and FileDispositionInformationEx
+const SMB2_FILE_RENAME_REPLACE_IF_EXISTS: u32 = 0x1;
+const SMB2_FILE_DISPOSITION_DELETE: u32 = 0x1;
+
 #[derive(Debug, PartialEq)]
 pub struct Smb2SetInfoRequestRenameRecord<'a> {
+    pub replace: bool,
     pub name: &'a [u8],
 }
 
-pub fn parse_smb2_request_setinfo_rename(i: &[u8]) -> IResult<&[u8], Smb2SetInfoRequestData> {
-    let (i, _replace) = le_u8(i)?;
-    let (i, _reserved) = take(7_usize)(i)?;
+fn parse_smb2_request_setinfo_rename_name(
+    i: &[u8], replace: bool,
+) -> IResult<&[u8], Smb2SetInfoRequestData> {
     let (i, _root_handle) = take(8_usize)(i)?;
     let (i, name_len) = le_u32(i)?;
     let (i, name) = take(name_len)(i)?;
-    let record = Smb2SetInfoRequestData::RENAME(Smb2SetInfoRequestRenameRecord { name });
+    let record = Smb2SetInfoRequestData::RENAME(Smb2SetInfoRequestRenameRecord { replace, name });
     Ok((i, record))
 }
 
+pub fn parse_smb2_request_setinfo_rename(i: &[u8]) -> IResult<&[u8], Smb2SetInfoRequestData> {
+    let (i, replace) = le_u8(i)?;
+    let (i, _reserved) = take(7_usize)(i)?;
+    parse_smb2_request_setinfo_rename_name(i, replace != 0)
+}
+
+pub fn parse_smb2_request_setinfo_rename_ex(i: &[u8]) -> IResult<&[u8], Smb2SetInfoRequestData> {
+    let (i, flags) = le_u32(i)?;
+    let (i, _reserved) = take(4_usize)(i)?;
+    parse_smb2_request_setinfo_rename_name(i, flags & SMB2_FILE_RENAME_REPLACE_IF_EXISTS != 0)
+}
+
 #[derive(Debug, PartialEq)]
 pub struct Smb2SetInfoRequestDispoRecord {
     pub delete: bool,
@@ -370,6 +561,16 @@ pub fn parse_smb2_request_setinfo_disposition(i: &[u8]) -> IResult<&[u8], Smb2Se
     Ok((i, record))
 }
 
+pub fn parse_smb2_request_setinfo_disposition_ex(
+    i: &[u8],
+) -> IResult<&[u8], Smb2SetInfoRequestData> {
+    let (i, flags) = le_u32(i)?;
+    let record = Smb2SetInfoRequestData::DISPOSITION(Smb2SetInfoRequestDispoRecord {
+        delete: flags & SMB2_FILE_DISPOSITION_DELETE != 0,
+    });
+    Ok((i, record))
+}
+
 #[derive(Debug, PartialEq)]
 pub enum Smb2SetInfoRequestData<'a> {
     DISPOSITION(Smb2SetInfoRequestDispoRecord),
@@ -389,14 +590,19 @@ fn parse_smb2_request_setinfo_data(
     i: &[u8], class: u8, infolvl: u8,
 ) -> IResult<&[u8], Smb2SetInfoRequestData> {
     if class == 1 {
-        // constants from [MS-FSCC] section 2.4
         match infolvl {
-            10 => {
+            SMB2_FILE_RENAME_INFO => {
                 return parse_smb2_request_setinfo_rename(i);
             }
-            0xd => {
+            SMB2_FILE_DISPOSITION_INFO => {
                 return parse_smb2_request_setinfo_disposition(i);
             }
+            SMB2_FILE_DISPOSITION_INFO_EX => {
+                return parse_smb2_request_setinfo_disposition_ex(i);
+            }
+            SMB2_FILE_RENAME_INFO_EX => {
+                return parse_smb2_request_setinfo_rename_ex(i);
+            }
             _ => {}
         }
     }
@@ -507,17 +713,21 @@ pub fn parse_smb2_response_read(i: &[u8]) -> IResult<&[u8], Smb2ReadResponseReco
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Smb2CreateResponseRecord<'a> {
+    pub oplock_level: u8,
     pub guid: &'a [u8],
     pub create_ts: SMBFiletime,
     pub last_access_ts: SMBFiletime,
     pub last_write_ts: SMBFiletime,
     pub last_change_ts: SMBFiletime,
     pub size: u64,
+    pub attributes: u32,
+    pub lease: Option<Smb2Lease<'a>>,
 }
 
 pub fn parse_smb2_response_create(i: &[u8]) -> IResult<&[u8], Smb2CreateResponseRecord> {
+    let body = i;
     let (i, _ssize) = le_u16(i)?;
-    let (i, _oplock) = le_u8(i)?;
+    let (i, oplock_level) = le_u8(i)?;
     let (i, _resp_flags) = le_u8(i)?;
     let (i, _create_action) = le_u32(i)?;
     let (i, create_ts) = le_u64(i)?;
@@ -526,17 +736,22 @@ pub fn parse_smb2_response_create(i: &[u8]) -> IResult<&[u8], Smb2CreateResponse
     let (i, last_change_ts) = le_u64(i)?;
     let (i, _alloc_size) = le_u64(i)?;
     let (i, eof) = le_u64(i)?;
-    let (i, _attrs) = le_u32(i)?;
+    let (i, attributes) = le_u32(i)?;
     let (i, _padding) = take(4_usize)(i)?;
     let (i, guid) = take(16_usize)(i)?;
-    let (i, _skip2) = take(8_usize)(i)?;
+    let (i, contexts_offset) = le_u32(i)?;
+    let (i, contexts_length) = le_u32(i)?;
+    let contexts = smb2_create_contexts(body, contexts_offset, contexts_length);
     let record = Smb2CreateResponseRecord {
+        oplock_level,
         guid,
         create_ts: SMBFiletime::new(create_ts),
         last_access_ts: SMBFiletime::new(last_access_ts),
         last_write_ts: SMBFiletime::new(last_write_ts),
         last_change_ts: SMBFiletime::new(last_change_ts),
         size: eof,
+        attributes,
+        lease: smb2_create_lease(&contexts),
     };
     Ok((i, record))
 }
@@ -700,6 +915,119 @@ mod tests {
         let dir = record.create_options & 0x0000_0001 != 0;
         assert!(!del);
         assert!(dir);
+        assert_eq!(record.oplock_level, 0);
+        let names: Vec<&[u8]> = record.contexts.iter().map(|ctx| ctx.name).collect();
+        assert_eq!(names, vec![b"DHnQ", b"MxAc", b"QFid"]);
+        assert_eq!(record.lease, None);
+        assert_eq!(record.timewarp, None);
+    }
+    #[test]
+    fn test_parse_smb2_request_create_lease() {
+        let mut data = vec![0x39, 0x00, 0x00, 0xff];
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&1_u32.to_le_bytes()); // FILE_OPEN
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.extend_from_slice(&120_u16.to_le_bytes());
+        data.extend_from_slice(&2_u16.to_le_bytes());
+        data.extend_from_slice(&128_u32.to_le_bytes());
+        data.extend_from_slice(&56_u32.to_le_bytes());
+        data.extend_from_slice(b"a\0");
+        data.extend_from_slice(&[0; 6]);
+        // SMB2_CREATE_REQUEST_LEASE context
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.extend_from_slice(&[16, 0, 4, 0, 0, 0, 24, 0]);
+        data.extend_from_slice(&32_u32.to_le_bytes());
+        data.extend_from_slice(b"RqLs\0\0\0\0");
+        let key: Vec<u8> = (1..=16).collect();
+        data.extend_from_slice(&key);
+        data.extend_from_slice(&7_u32.to_le_bytes());
+        data.extend_from_slice(&[0; 12]);
+
+        let (_, record) = parse_smb2_request_create(&data).unwrap();
+        assert_eq!(record.oplock_level, 0xff);
+        assert_eq!(record.disposition, 1);
+        assert_eq!(record.data, b"a\0");
+        assert_eq!(
+            record.lease,
+            Some(Smb2Lease {
+                key: &key,
+                state: 7
+            })
+        );
+
+        // Context beyond the end of the record.
+        let (_, record) = parse_smb2_request_create(&data[..100]).unwrap();
+        assert_eq!(record.lease, None);
+        assert!(record.contexts.is_empty());
+    }
+    #[test]
+    fn test_parse_smb2_request_create_timewarp() {
+        let mut data = vec![0x39, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(&1_u32.to_le_bytes()); // FILE_OPEN
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.extend_from_slice(&120_u16.to_le_bytes());
+        data.extend_from_slice(&2_u16.to_le_bytes());
+        data.extend_from_slice(&128_u32.to_le_bytes());
+        data.extend_from_slice(&64_u32.to_le_bytes());
+        data.extend_from_slice(b"a\0");
+        data.extend_from_slice(&[0; 6]);
+        // SMB2_CREATE_DURABLE_HANDLE_REQUEST context
+        data.extend_from_slice(&32_u32.to_le_bytes());
+        data.extend_from_slice(&[16, 0, 4, 0, 0, 0, 24, 0]);
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.extend_from_slice(b"DHnQ\0\0\0\0");
+        data.extend_from_slice(&[0; 8]);
+        // SMB2_CREATE_TIMEWARP_TOKEN context
+        let ts = SMBFiletime::from_unix(1_700_000_000);
+        data.extend_from_slice(&0_u32.to_le_bytes());
+        data.extend_from_slice(&[16, 0, 4, 0, 0, 0, 24, 0]);
+        data.extend_from_slice(&8_u32.to_le_bytes());
+        data.extend_from_slice(b"TWrp\0\0\0\0");
+        data.extend_from_slice(&((1_700_000_000_u64 + 11644473600) * 10000000).to_le_bytes());
+
+        let (_, record) = parse_smb2_request_create(&data).unwrap();
+        let names: Vec<&[u8]> = record.contexts.iter().map(|ctx| ctx.name).collect();
+        assert_eq!(names, vec![b"DHnQ", b"TWrp"]);
+        assert_eq!(record.timewarp, Some(ts));
+        assert_eq!(record.lease, None);
+
+        // Timewarp token too short for the timestamp.
+        data[108] = 7;
+        let (_, record) = parse_smb2_request_create(&data).unwrap();
+        assert_eq!(record.contexts.len(), 2);
+        assert_eq!(record.timewarp, None);
+    }
+    #[test]
+    fn test_parse_smb2_oplock_break() {
+        let mut data = vec![0x18, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0xaa; 16]);
+        let (_, record) = parse_smb2_oplock_break(&data).unwrap();
+        assert_eq!(
+            record,
+            Smb2OplockBreakRecord::Oplock {
+                oplock_level: 1,
+                guid: &[0xaa; 16]
+            }
+        );
+
+        // lease break notification, RWH to R
+        let mut data = vec![0x2c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[0xbb; 16]);
+        data.extend_from_slice(&7_u32.to_le_bytes());
+        data.extend_from_slice(&1_u32.to_le_bytes());
+        data.extend_from_slice(&[0; 12]);
+        let (_, record) = parse_smb2_oplock_break(&data).unwrap();
+        assert_eq!(
+            record,
+            Smb2OplockBreakRecord::Lease {
+                key: &[0xbb; 16],
+                state: 1
+            }
+        );
+
+        data[0] = 0x10;
+        assert!(parse_smb2_oplock_break(&data).is_err());
     }
     #[test]
     fn test_parse_smb2_request_close() {
@@ -733,6 +1061,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_smb2_request_setinfo_rename() {
+        let data = hex::decode(
+            "2100010a1a00000060000000000000004d0000000000000009000000ffffffff010000000000000000000000000000000600000061002e006200",
+        )
+        .unwrap();
+        let (_, record) = parse_smb2_request_setinfo(&data).unwrap();
+        let expected = Smb2SetInfoRequestRenameRecord {
+            replace: true,
+            name: b"a\x00.\x00b\x00",
+        };
+        assert_eq!(record.infolvl, SMB2_FILE_RENAME_INFO);
+        assert_eq!(record.data, Smb2SetInfoRequestData::RENAME(expected));
+
+        // FileRenameInformationEx, with the flags replacing the replace byte
+        let data = hex::decode(
+            "210001411a00000060000000000000004d0000000000000009000000ffffffff010000000000000000000000000000000600000061002e006200",
+        )
+        .unwrap();
+        let (_, record) = parse_smb2_request_setinfo(&data).unwrap();
+        let expected = Smb2SetInfoRequestRenameRecord {
+            replace: true,
+            name: b"a\x00.\x00b\x00",
+        };
+        assert_eq!(record.infolvl, SMB2_FILE_RENAME_INFO_EX);
+        assert_eq!(record.data, Smb2SetInfoRequestData::RENAME(expected));
+    }
+
+    #[test]
+    fn test_parse_smb2_request_setinfo_disposition_ex() {
+        // FILE_DISPOSITION_DELETE | FILE_DISPOSITION_POSIX_SEMANTICS
+        let data =
+            hex::decode("210001400400000060000000000000004d0000000000000009000000ffffffff03000000")
+                .unwrap();
+        let (_, record) = parse_smb2_request_setinfo(&data).unwrap();
+        let expected = Smb2SetInfoRequestDispoRecord { delete: true };
+        assert_eq!(record.infolvl, SMB2_FILE_DISPOSITION_INFO_EX);
+        assert_eq!(record.data, Smb2SetInfoRequestData::DISPOSITION(expected));
+    }
+
     #[test]
     fn test_parse_smb2_request_read() {
         // https://raw.githubusercontent.com/bro/bro/master/testing/btest/Traces/smb/smb2.pcap
@@ -786,6 +1154,20 @@ mod tests {
         assert!(record.request);
     }
 
+    #[test]
+    fn test_parse_smb2_request_record_async_cancel() {
+        let data = hex::decode("fe534d4240000000000000000c00000002000000000000000700000000000000080000000000000011000000000000000000000000000000000000000000000004000000").unwrap();
+        let result = parse_smb2_request_record(&data);
+        assert!(result.is_ok());
+        let record = result.unwrap().1;
+        assert!(record.is_request());
+        assert_eq!(record.command, crate::smb::smb2::SMB2_COMMAND_CANCEL);
+        assert_eq!(record.message_id, 7);
+        assert_eq!(record.tree_id, 0);
+        assert_eq!(record.async_id, 8);
+        assert_eq!(record.session_id, 0x11);
+    }
+
     #[test]
     fn test_parse_smb2_request_tree_connect() {
         let data = hex::decode("0900000048002c005c005c003100390032002e003100360038002e003100390039002e003100330033005c004900500043002400").unwrap();
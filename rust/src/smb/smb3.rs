@@ -19,6 +19,9 @@ use nom7::bytes::streaming::{tag, take};
 use nom7::number::streaming::{le_u16, le_u32, le_u64};
 use nom7::IResult;
 
+/// Size of the TRANSFORM_HEADER
+pub const SMB3_TRANSFORM_HEADER_SIZE: usize = 52;
+
 #[derive(Debug,PartialEq, Eq)]
 pub struct Smb3TransformRecord<'a> {
     pub session_id: u64,
@@ -26,7 +29,16 @@ pub struct Smb3TransformRecord<'a> {
     pub enc_data: &'a[u8],
 }
 
-pub fn parse_smb3_transform_record(i: &[u8]) -> IResult<&[u8], Smb3TransformRecord> {
+/// TRANSFORM_HEADER without the encrypted message, for records that
+/// are not complete yet.
+#[derive(Debug,PartialEq, Eq)]
+pub struct Smb3TransformHeader {
+    pub session_id: u64,
+    pub enc_algo: u16,
+    pub msg_size: u32,
+}
+
+pub fn parse_smb3_transform_header(i: &[u8]) -> IResult<&[u8], Smb3TransformHeader> {
     let (i, _) = tag(b"\xfdSMB")(i)?;
     let (i, _signature) = take(16_usize)(i)?;
     let (i, _nonce) = take(16_usize)(i)?;
@@ -34,10 +46,20 @@ pub fn parse_smb3_transform_record(i: &[u8]) -> IResult<&[u8], Smb3TransformReco
     let (i, _reserved) = le_u16(i)?;
     let (i, enc_algo) = le_u16(i)?;
     let (i, session_id) = le_u64(i)?;
-    let (i, enc_data) = take(msg_size)(i)?;
-    let record = Smb3TransformRecord {
+    let hdr = Smb3TransformHeader {
         session_id,
         enc_algo,
+        msg_size,
+    };
+    Ok((i, hdr))
+}
+
+pub fn parse_smb3_transform_record(i: &[u8]) -> IResult<&[u8], Smb3TransformRecord> {
+    let (i, hdr) = parse_smb3_transform_header(i)?;
+    let (i, enc_data) = take(hdr.msg_size)(i)?;
+    let record = Smb3TransformRecord {
+        session_id: hdr.session_id,
+        enc_algo: hdr.enc_algo,
         enc_data,
     };
     Ok((i, record))
@@ -55,5 +77,12 @@ mod tests {
         assert_eq!(record.session_id, 79167320227901);
         assert_eq!(record.enc_algo, 1);
         assert_eq!(record.enc_data.len(), 105);
+
+        // header of a record that is not complete yet
+        let (rem, hdr) = parse_smb3_transform_header(&data[..SMB3_TRANSFORM_HEADER_SIZE + 10]).unwrap();
+        assert_eq!(rem.len(), 10);
+        assert_eq!(hdr.session_id, 79167320227901);
+        assert_eq!(hdr.msg_size, 105);
+        assert!(parse_smb3_transform_record(&data[..SMB3_TRANSFORM_HEADER_SIZE + 10]).is_err());
     }
 }
@@ -0,0 +1,335 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! SMB1 DOS error classes and codes.
+//!
+//! SMB1 servers not negotiating NT status codes report errors as a class
+//! and a code. The codes and their NTSTATUS equivalents are taken from
+//! [MS-CIFS] 2.2.2.4.
+
+use crate::smb::smb_status::*;
+
+pub const SMB_DOS_CLASS_SUCCESS:            u8 = 0x00;
+pub const SMB_DOS_CLASS_DOS:                u8 = 0x01;
+pub const SMB_DOS_CLASS_SRV:                u8 = 0x02;
+pub const SMB_DOS_CLASS_HRD:                u8 = 0x03;
+pub const SMB_DOS_CLASS_CMD:                u8 = 0xff;
+
+/* ERRDOS */
+pub const SMB_DOS_SUCCESS:                  u16 = 0x0000;
+pub const SMB_DOS_BAD_FUNC:                 u16 = 0x0001;
+pub const SMB_DOS_BAD_FILE:                 u16 = 0x0002;
+pub const SMB_DOS_BAD_PATH:                 u16 = 0x0003;
+pub const SMB_DOS_TOO_MANY_OPEN_FILES:      u16 = 0x0004;
+pub const SMB_DOS_ACCESS_DENIED:            u16 = 0x0005;
+pub const SMB_DOS_BAD_FID:                  u16 = 0x0006;
+pub const SMB_DOS_BAD_MCB:                  u16 = 0x0007;
+pub const SMB_DOS_NO_MEM:                   u16 = 0x0008;
+pub const SMB_DOS_BAD_MEM:                  u16 = 0x0009;
+pub const SMB_DOS_BAD_ENV:                  u16 = 0x000a;
+pub const SMB_DOS_BAD_FORMAT:               u16 = 0x000b;
+pub const SMB_DOS_BAD_ACCESS:               u16 = 0x000c;
+pub const SMB_DOS_BAD_DATA:                 u16 = 0x000d;
+pub const SMB_DOS_BAD_DRIVE:                u16 = 0x000f;
+pub const SMB_DOS_REM_CD:                   u16 = 0x0010;
+pub const SMB_DOS_DIFF_DEVICE:              u16 = 0x0011;
+pub const SMB_DOS_NO_FILES:                 u16 = 0x0012;
+pub const SMB_DOS_GENERAL:                  u16 = 0x001f;
+pub const SMB_DOS_BAD_SHARE:                u16 = 0x0020;
+pub const SMB_DOS_LOCK:                     u16 = 0x0021;
+pub const SMB_DOS_EOF:                      u16 = 0x0026;
+pub const SMB_DOS_UNSUP:                    u16 = 0x0032;
+pub const SMB_DOS_FILE_EXISTS:              u16 = 0x0050;
+pub const SMB_DOS_INVALID_PARAM:            u16 = 0x0057;
+pub const SMB_DOS_UNKNOWN_LEVEL:            u16 = 0x007c;
+pub const SMB_DOS_INVALID_SEEK:             u16 = 0x0083;
+pub const SMB_DOS_BAD_PIPE:                 u16 = 0x00e6;
+pub const SMB_DOS_PIPE_BUSY:                u16 = 0x00e7;
+pub const SMB_DOS_PIPE_CLOSING:             u16 = 0x00e8;
+pub const SMB_DOS_NOT_CONNECTED:            u16 = 0x00e9;
+pub const SMB_DOS_MORE_DATA:                u16 = 0x00ea;
+pub const SMB_DOS_EAS_NOT_SUPPORTED:        u16 = 0x011a;
+pub const SMB_DOS_NOTIFY_ENUM_DIR:          u16 = 0x03fe;
+
+/* ERRSRV */
+pub const SMB_SRV_ERROR:                    u16 = 0x0001;
+pub const SMB_SRV_BADPW:                    u16 = 0x0002;
+pub const SMB_SRV_BADTYPE:                  u16 = 0x0003;
+pub const SMB_SRV_ACCESS:                   u16 = 0x0004;
+pub const SMB_SRV_INVTID:                   u16 = 0x0005;
+pub const SMB_SRV_INVNETNAME:               u16 = 0x0006;
+pub const SMB_SRV_INVDEVICE:                u16 = 0x0007;
+pub const SMB_SRV_INVSESS:                  u16 = 0x0010;
+pub const SMB_SRV_WORKING:                  u16 = 0x0011;
+pub const SMB_SRV_NOTME:                    u16 = 0x0012;
+pub const SMB_SRV_BADCMD:                   u16 = 0x0016;
+pub const SMB_SRV_QFULL:                    u16 = 0x0031;
+pub const SMB_SRV_QTOOBIG:                  u16 = 0x0032;
+pub const SMB_SRV_QEOF:                     u16 = 0x0033;
+pub const SMB_SRV_INVPFID:                  u16 = 0x0034;
+pub const SMB_SRV_SMBCMD:                   u16 = 0x0040;
+pub const SMB_SRV_SRVERROR:                 u16 = 0x0041;
+pub const SMB_SRV_FILESPECS:                u16 = 0x0043;
+pub const SMB_SRV_BADPERMITS:               u16 = 0x0045;
+pub const SMB_SRV_SETATTRMODE:              u16 = 0x0047;
+pub const SMB_SRV_TIMEOUT:                  u16 = 0x0058;
+pub const SMB_SRV_NORESOURCE:               u16 = 0x0059;
+pub const SMB_SRV_TOOMANYUIDS:              u16 = 0x005a;
+pub const SMB_SRV_BADUID:                   u16 = 0x005b;
+pub const SMB_SRV_USEMPX:                   u16 = 0x00fa;
+pub const SMB_SRV_USESTD:                   u16 = 0x00fb;
+pub const SMB_SRV_CONTMPX:                  u16 = 0x00fc;
+pub const SMB_SRV_ACCOUNTEXPIRED:           u16 = 0x08bf;
+pub const SMB_SRV_BADCLIENT:                u16 = 0x08c0;
+pub const SMB_SRV_BADLOGONTIME:             u16 = 0x08c1;
+pub const SMB_SRV_PASSWORDEXPIRED:          u16 = 0x08c2;
+pub const SMB_SRV_NOSUPPORT:                u16 = 0xffff;
+
+/* ERRHRD */
+pub const SMB_HRD_NO_WRITE:                 u16 = 0x0013;
+pub const SMB_HRD_BAD_UNIT:                 u16 = 0x0014;
+pub const SMB_HRD_NOT_READY:                u16 = 0x0015;
+pub const SMB_HRD_BAD_CMD:                  u16 = 0x0016;
+pub const SMB_HRD_DATA:                     u16 = 0x0017;
+pub const SMB_HRD_BAD_REQ:                  u16 = 0x0018;
+pub const SMB_HRD_SEEK:                     u16 = 0x0019;
+pub const SMB_HRD_BAD_MEDIA:                u16 = 0x001a;
+pub const SMB_HRD_BAD_SECTOR:               u16 = 0x001b;
+pub const SMB_HRD_NO_PAPER:                 u16 = 0x001c;
+pub const SMB_HRD_WRITE:                    u16 = 0x001d;
+pub const SMB_HRD_READ:                     u16 = 0x001e;
+pub const SMB_HRD_GENERAL:                  u16 = 0x001f;
+pub const SMB_HRD_BAD_SHARE:                u16 = 0x0020;
+pub const SMB_HRD_LOCK:                     u16 = 0x0021;
+pub const SMB_HRD_WRONG_DISK:               u16 = 0x0022;
+pub const SMB_HRD_FCB_UNAVAIL:              u16 = 0x0023;
+pub const SMB_HRD_SHARE_BUF_EXC:            u16 = 0x0024;
+pub const SMB_HRD_DISK_FULL:                u16 = 0x0027;
+
+fn smb_dos_class_dos_string(c: u16) -> Option<&'static str> {
+    match c {
+        SMB_DOS_SUCCESS             => Some("DOS_SUCCESS"),
+        SMB_DOS_BAD_FUNC            => Some("DOS_BAD_FUNC"),
+        SMB_DOS_BAD_FILE            => Some("DOS_BAD_FILE"),
+        SMB_DOS_BAD_PATH            => Some("DOS_BAD_PATH"),
+        SMB_DOS_TOO_MANY_OPEN_FILES => Some("DOS_TOO_MANY_OPEN_FILES"),
+        SMB_DOS_ACCESS_DENIED       => Some("DOS_ACCESS_DENIED"),
+        SMB_DOS_BAD_FID             => Some("DOS_BAD_FID"),
+        SMB_DOS_BAD_MCB             => Some("DOS_BAD_MCB"),
+        SMB_DOS_NO_MEM              => Some("DOS_NO_MEM"),
+        SMB_DOS_BAD_MEM             => Some("DOS_BAD_MEM"),
+        SMB_DOS_BAD_ENV             => Some("DOS_BAD_ENV"),
+        SMB_DOS_BAD_FORMAT          => Some("DOS_BAD_FORMAT"),
+        SMB_DOS_BAD_ACCESS          => Some("DOS_BAD_ACCESS"),
+        SMB_DOS_BAD_DATA            => Some("DOS_BAD_DATA"),
+        SMB_DOS_BAD_DRIVE           => Some("DOS_BAD_DRIVE"),
+        SMB_DOS_REM_CD              => Some("DOS_REM_CD"),
+        SMB_DOS_DIFF_DEVICE         => Some("DOS_DIFF_DEVICE"),
+        SMB_DOS_NO_FILES            => Some("DOS_NO_FILES"),
+        SMB_DOS_GENERAL             => Some("DOS_GENERAL"),
+        SMB_DOS_BAD_SHARE           => Some("DOS_BAD_SHARE"),
+        SMB_DOS_LOCK                => Some("DOS_LOCK"),
+        SMB_DOS_EOF                 => Some("DOS_EOF"),
+        SMB_DOS_UNSUP               => Some("DOS_UNSUP"),
+        SMB_DOS_FILE_EXISTS         => Some("DOS_FILE_EXISTS"),
+        SMB_DOS_INVALID_PARAM       => Some("DOS_INVALID_PARAM"),
+        SMB_DOS_UNKNOWN_LEVEL       => Some("DOS_UNKNOWN_LEVEL"),
+        SMB_DOS_INVALID_SEEK        => Some("DOS_INVALID_SEEK"),
+        SMB_DOS_BAD_PIPE            => Some("DOS_BAD_PIPE"),
+        SMB_DOS_PIPE_BUSY           => Some("DOS_PIPE_BUSY"),
+        SMB_DOS_PIPE_CLOSING        => Some("DOS_PIPE_CLOSING"),
+        SMB_DOS_NOT_CONNECTED       => Some("DOS_NOT_CONNECTED"),
+        SMB_DOS_MORE_DATA           => Some("DOS_MORE_DATA"),
+        SMB_DOS_EAS_NOT_SUPPORTED   => Some("DOS_EAS_NOT_SUPPORTED"),
+        SMB_DOS_NOTIFY_ENUM_DIR     => Some("DOS_NOTIFY_ENUM_DIR"),
+        _ => None,
+    }
+}
+
+fn smb_dos_class_srv_string(c: u16) -> Option<&'static str> {
+    match c {
+        SMB_SRV_ERROR           => Some("SRV_ERROR"),
+        SMB_SRV_BADPW           => Some("SRV_BADPW"),
+        SMB_SRV_BADTYPE         => Some("SRV_BADTYPE"),
+        SMB_SRV_ACCESS          => Some("SRV_ACCESS"),
+        SMB_SRV_INVTID          => Some("SRV_INVTID"),
+        SMB_SRV_INVNETNAME      => Some("SRV_INVNETNAME"),
+        SMB_SRV_INVDEVICE       => Some("SRV_INVDEVICE"),
+        SMB_SRV_INVSESS         => Some("SRV_INVSESS"),
+        SMB_SRV_WORKING         => Some("SRV_WORKING"),
+        SMB_SRV_NOTME           => Some("SRV_NOTME"),
+        SMB_SRV_BADCMD          => Some("SRV_BADCMD"),
+        SMB_SRV_QFULL           => Some("SRV_QFULL"),
+        SMB_SRV_QTOOBIG         => Some("SRV_QTOOBIG"),
+        SMB_SRV_QEOF            => Some("SRV_QEOF"),
+        SMB_SRV_INVPFID         => Some("SRV_INVPFID"),
+        SMB_SRV_SMBCMD          => Some("SRV_SMBCMD"),
+        SMB_SRV_SRVERROR        => Some("SRV_SRVERROR"),
+        SMB_SRV_FILESPECS       => Some("SRV_FILESPECS"),
+        SMB_SRV_BADPERMITS      => Some("SRV_BADPERMITS"),
+        SMB_SRV_SETATTRMODE     => Some("SRV_SETATTRMODE"),
+        SMB_SRV_TIMEOUT         => Some("SRV_TIMEOUT"),
+        SMB_SRV_NORESOURCE      => Some("SRV_NORESOURCE"),
+        SMB_SRV_TOOMANYUIDS     => Some("SRV_TOOMANYUIDS"),
+        SMB_SRV_BADUID          => Some("SRV_BADUID"),
+        SMB_SRV_USEMPX          => Some("SRV_USEMPX"),
+        SMB_SRV_USESTD          => Some("SRV_USESTD"),
+        SMB_SRV_CONTMPX         => Some("SRV_CONTMPX"),
+        SMB_SRV_ACCOUNTEXPIRED  => Some("SRV_ACCOUNTEXPIRED"),
+        SMB_SRV_BADCLIENT       => Some("SRV_BADCLIENT"),
+        SMB_SRV_BADLOGONTIME    => Some("SRV_BADLOGONTIME"),
+        SMB_SRV_PASSWORDEXPIRED => Some("SRV_PASSWORDEXPIRED"),
+        SMB_SRV_NOSUPPORT       => Some("SRV_NOSUPPORT"),
+        _ => None,
+    }
+}
+
+fn smb_dos_class_hrd_string(c: u16) -> Option<&'static str> {
+    match c {
+        SMB_HRD_NO_WRITE        => Some("HRD_NO_WRITE"),
+        SMB_HRD_BAD_UNIT        => Some("HRD_BAD_UNIT"),
+        SMB_HRD_NOT_READY       => Some("HRD_NOT_READY"),
+        SMB_HRD_BAD_CMD         => Some("HRD_BAD_CMD"),
+        SMB_HRD_DATA            => Some("HRD_DATA"),
+        SMB_HRD_BAD_REQ         => Some("HRD_BAD_REQ"),
+        SMB_HRD_SEEK            => Some("HRD_SEEK"),
+        SMB_HRD_BAD_MEDIA       => Some("HRD_BAD_MEDIA"),
+        SMB_HRD_BAD_SECTOR      => Some("HRD_BAD_SECTOR"),
+        SMB_HRD_NO_PAPER        => Some("HRD_NO_PAPER"),
+        SMB_HRD_WRITE           => Some("HRD_WRITE"),
+        SMB_HRD_READ            => Some("HRD_READ"),
+        SMB_HRD_GENERAL         => Some("HRD_GENERAL"),
+        SMB_HRD_BAD_SHARE       => Some("HRD_BAD_SHARE"),
+        SMB_HRD_LOCK            => Some("HRD_LOCK"),
+        SMB_HRD_WRONG_DISK      => Some("HRD_WRONG_DISK"),
+        SMB_HRD_FCB_UNAVAIL     => Some("HRD_FCB_UNAVAIL"),
+        SMB_HRD_SHARE_BUF_EXC   => Some("HRD_SHARE_BUF_EXC"),
+        SMB_HRD_DISK_FULL       => Some("HRD_DISK_FULL"),
+        _ => None,
+    }
+}
+
+/// Name of a DOS error, e.g. DOS_ACCESS_DENIED for class 1 code 5.
+pub fn smb_dos_error_string(class: u8, code: u16) -> Option<&'static str> {
+    match class {
+        SMB_DOS_CLASS_SUCCESS if code == 0 => Some("SUCCESS"),
+        SMB_DOS_CLASS_DOS => smb_dos_class_dos_string(code),
+        SMB_DOS_CLASS_SRV => smb_dos_class_srv_string(code),
+        SMB_DOS_CLASS_HRD => smb_dos_class_hrd_string(code),
+        SMB_DOS_CLASS_CMD => Some("CMD_NOT_SMB"),
+        _ => None,
+    }
+}
+
+/// NTSTATUS a server negotiating NT status codes would have returned
+/// for a DOS error, if there is one.
+pub fn smb_dos_error_to_ntstatus(class: u8, code: u16) -> Option<u32> {
+    let status = match (class, code) {
+        (SMB_DOS_CLASS_SUCCESS, 0)                      => SMB_NTSTATUS_SUCCESS,
+
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_FUNC)           => SMB_NTSTATUS_NOT_IMPLEMENTED,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_FILE)           => SMB_NTSTATUS_NO_SUCH_FILE,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_PATH)           => SMB_NTSTATUS_OBJECT_PATH_NOT_FOUND,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_TOO_MANY_OPEN_FILES) => SMB_NTSTATUS_TOO_MANY_OPENED_FILES,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_ACCESS_DENIED)      => SMB_NTSTATUS_ACCESS_DENIED,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_FID)            => SMB_NTSTATUS_INVALID_HANDLE,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_NO_MEM)             => SMB_NTSTATUS_INSUFF_SERVER_RESOURCES,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_DATA)           => SMB_NTSTATUS_DATA_ERROR,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_REM_CD)             => SMB_NTSTATUS_DIRECTORY_NOT_EMPTY,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_DIFF_DEVICE)        => SMB_NTSTATUS_NOT_SAME_DEVICE,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_NO_FILES)           => SMB_NTSTATUS_NO_MORE_FILES,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_SHARE)          => SMB_NTSTATUS_SHARING_VIOLATION,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_LOCK)               => SMB_NTSTATUS_FILE_LOCK_CONFLICT,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_EOF)                => SMB_NTSTATUS_END_OF_FILE,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_UNSUP)              => SMB_NTSTATUS_NOT_SUPPORTED,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_FILE_EXISTS)        => SMB_NTSTATUS_OBJECT_NAME_COLLISION,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_INVALID_PARAM)      => SMB_NTSTATUS_INVALID_PARAMETER,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_BAD_PIPE)           => SMB_NTSTATUS_INVALID_PIPE_STATE,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_PIPE_BUSY)          => SMB_NTSTATUS_PIPE_BUSY,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_PIPE_CLOSING)       => SMB_NTSTATUS_PIPE_CLOSING,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_NOT_CONNECTED)      => SMB_NTSTATUS_PIPE_DISCONNECTED,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_MORE_DATA)          => SMB_NTSTATUS_BUFFER_OVERFLOW,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_EAS_NOT_SUPPORTED)  => SMB_NTSTATUS_EAS_NOT_SUPPORTED,
+        (SMB_DOS_CLASS_DOS, SMB_DOS_NOTIFY_ENUM_DIR)    => SMB_NTSTATUS_NOTIFY_ENUM_DIR,
+
+        (SMB_DOS_CLASS_SRV, SMB_SRV_BADPW)              => SMB_NTSTATUS_WRONG_PASSWORD,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_BADTYPE)            => SMB_NTSTATUS_PATH_NOT_COVERED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_ACCESS)             => SMB_NTSTATUS_NETWORK_ACCESS_DENIED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_INVNETNAME)         => SMB_NTSTATUS_BAD_NETWORK_NAME,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_INVDEVICE)          => SMB_NTSTATUS_BAD_DEVICE_TYPE,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_QFULL)              => SMB_NTSTATUS_PRINT_QUEUE_FULL,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_QTOOBIG)            => SMB_NTSTATUS_NO_SPOOL_SPACE,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_INVPFID)            => SMB_NTSTATUS_PRINT_CANCELLED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_SRVERROR)           => SMB_NTSTATUS_UNEXPECTED_NETWORK_ERROR,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_BADPERMITS)         => SMB_NTSTATUS_NETWORK_ACCESS_DENIED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_NORESOURCE)         => SMB_NTSTATUS_REQUEST_NOT_ACCEPTED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_TOOMANYUIDS)        => SMB_NTSTATUS_TOO_MANY_SESSIONS,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_ACCOUNTEXPIRED)     => SMB_NTSTATUS_ACCOUNT_DISABLED,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_BADCLIENT)          => SMB_NTSTATUS_INVALID_WORKSTATION,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_BADLOGONTIME)       => SMB_NTSTATUS_INVALID_LOGON_HOURS,
+        (SMB_DOS_CLASS_SRV, SMB_SRV_PASSWORDEXPIRED)    => SMB_NTSTATUS_PASSWORD_EXPIRED,
+
+        (SMB_DOS_CLASS_HRD, SMB_HRD_NO_WRITE)           => SMB_NTSTATUS_MEDIA_WRITE_PROTECTED,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_NOT_READY)          => SMB_NTSTATUS_NO_MEDIA_IN_DEVICE,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_DATA)               => SMB_NTSTATUS_DATA_ERROR,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_BAD_MEDIA)          => SMB_NTSTATUS_DISK_CORRUPT_ERROR,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_BAD_SECTOR)         => SMB_NTSTATUS_NONEXISTENT_SECTOR,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_NO_PAPER)           => SMB_NTSTATUS_DEVICE_PAPER_EMPTY,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_BAD_SHARE)          => SMB_NTSTATUS_SHARING_VIOLATION,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_LOCK)               => SMB_NTSTATUS_FILE_LOCK_CONFLICT,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_WRONG_DISK)         => SMB_NTSTATUS_WRONG_VOLUME,
+        (SMB_DOS_CLASS_HRD, SMB_HRD_DISK_FULL)          => SMB_NTSTATUS_DISK_FULL,
+        _ => {
+            return None;
+        }
+    };
+    Some(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smb_dos_error() {
+        assert_eq!(
+            smb_dos_error_string(SMB_DOS_CLASS_DOS, SMB_DOS_ACCESS_DENIED),
+            Some("DOS_ACCESS_DENIED")
+        );
+        assert_eq!(
+            smb_dos_error_string(SMB_DOS_CLASS_SRV, SMB_SRV_BADUID),
+            Some("SRV_BADUID")
+        );
+        assert_eq!(
+            smb_dos_error_string(SMB_DOS_CLASS_HRD, SMB_HRD_DISK_FULL),
+            Some("HRD_DISK_FULL")
+        );
+        // same code, other class
+        assert_eq!(smb_dos_error_string(SMB_DOS_CLASS_HRD, SMB_DOS_ACCESS_DENIED), None);
+        assert_eq!(smb_dos_error_string(0x04, 1), None);
+
+        assert_eq!(
+            smb_dos_error_to_ntstatus(SMB_DOS_CLASS_DOS, SMB_DOS_ACCESS_DENIED),
+            Some(SMB_NTSTATUS_ACCESS_DENIED)
+        );
+        assert_eq!(
+            smb_dos_error_to_ntstatus(SMB_DOS_CLASS_SRV, SMB_SRV_BADPW),
+            Some(SMB_NTSTATUS_WRONG_PASSWORD)
+        );
+        assert_eq!(smb_dos_error_to_ntstatus(SMB_DOS_CLASS_SRV, SMB_SRV_USEMPX), None);
+    }
+}
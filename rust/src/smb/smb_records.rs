@@ -45,6 +45,27 @@ pub fn smb_get_unicode_string(blob: &[u8]) -> IResult<&[u8], Vec<u8>, SmbError>
     Err(Err::Error(SmbError::BadEncoding))
 }
 
+/// Take a UTF16 string that is null terminated, like
+/// smb_get_unicode_string, but return the raw UTF16 data without the
+/// terminator so that it can be decoded properly.
+pub fn smb_get_utf16_string(blob: &[u8]) -> IResult<&[u8], &[u8], SmbError>
+{
+    let mut c = blob;
+    while !c.is_empty() {
+        if c.len() == 1 && c[0] == 0 {
+            let name = &blob[..blob.len() - c.len()];
+            return Ok((&c[1..], name))
+        } else if c.len() == 1 {
+            break;
+        } else if c[0] == 0 && c[1] == 0 {
+            let name = &blob[..blob.len() - c.len()];
+            return Ok((&c[2..], name))
+        }
+        c = &c[2..];
+    }
+    Err(Err::Error(SmbError::BadEncoding))
+}
+
 // parse an ASCII string that is null terminated
 pub fn smb_get_ascii_string(i: &[u8]) -> IResult<&[u8], Vec<u8>, SmbError> {
     let (i, s) = take_until_and_consume(b"\x00")(i)?;
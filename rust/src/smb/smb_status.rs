@@ -1808,1802 +1808,1819 @@ pub const SMB_NTSTATUS_VHD_CHILD_PARENT_SIZE_MISMATCH:
 pub const SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED:                          u32 = 0xc03a0018;
 pub const SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT:                         u32 = 0xc03a0019;
 
-pub fn smb_ntstatus_string(c: u32) -> Option<&'static str> {
-    match c {
-        SMB_NTSTATUS_SUCCESS                                                        => Some("STATUS_SUCCESS"),
-        SMB_NTSTATUS_WAIT_1                                                         => Some("STATUS_WAIT_1"),
-        SMB_NTSTATUS_WAIT_2                                                         => Some("STATUS_WAIT_2"),
-        SMB_NTSTATUS_WAIT_3                                                         => Some("STATUS_WAIT_3"),
-        SMB_NTSTATUS_WAIT_63                                                        => Some("STATUS_WAIT_63"),
-        SMB_NTSTATUS_ABANDONED                                                      => Some("STATUS_ABANDONED"),
-        SMB_NTSTATUS_ABANDONED_WAIT_63                                              => Some("STATUS_ABANDONED_WAIT_63"),
-        SMB_NTSTATUS_USER_APC                                                       => Some("STATUS_USER_APC"),
-        SMB_NTSTATUS_ALERTED                                                        => Some("STATUS_ALERTED"),
-        SMB_NTSTATUS_TIMEOUT                                                        => Some("STATUS_TIMEOUT"),
-        SMB_NTSTATUS_PENDING                                                        => Some("STATUS_PENDING"),
-        SMB_NTSTATUS_REPARSE                                                        => Some("STATUS_REPARSE"),
-        SMB_NTSTATUS_MORE_ENTRIES                                                   => Some("STATUS_MORE_ENTRIES"),
-        SMB_NTSTATUS_NOT_ALL_ASSIGNED                                               => Some("STATUS_NOT_ALL_ASSIGNED"),
-        SMB_NTSTATUS_SOME_NOT_MAPPED                                                => Some("STATUS_SOME_NOT_MAPPED"),
-        SMB_NTSTATUS_OPLOCK_BREAK_IN_PROGRESS                                       => Some("STATUS_OPLOCK_BREAK_IN_PROGRESS"),
-        SMB_NTSTATUS_VOLUME_MOUNTED                                                 => Some("STATUS_VOLUME_MOUNTED"),
-        SMB_NTSTATUS_RXACT_COMMITTED                                                => Some("STATUS_RXACT_COMMITTED"),
-        SMB_NTSTATUS_NOTIFY_CLEANUP                                                 => Some("STATUS_NOTIFY_CLEANUP"),
-        SMB_NTSTATUS_NOTIFY_ENUM_DIR                                                => Some("STATUS_NOTIFY_ENUM_DIR"),
-        SMB_NTSTATUS_NO_QUOTAS_FOR_ACCOUNT                                          => Some("STATUS_NO_QUOTAS_FOR_ACCOUNT"),
-        SMB_NTSTATUS_PRIMARY_TRANSPORT_CONNECT_FAILED                               => Some("STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED"),
-        SMB_NTSTATUS_PAGE_FAULT_TRANSITION                                          => Some("STATUS_PAGE_FAULT_TRANSITION"),
-        SMB_NTSTATUS_PAGE_FAULT_DEMAND_ZERO                                         => Some("STATUS_PAGE_FAULT_DEMAND_ZERO"),
-        SMB_NTSTATUS_PAGE_FAULT_COPY_ON_WRITE                                       => Some("STATUS_PAGE_FAULT_COPY_ON_WRITE"),
-        SMB_NTSTATUS_PAGE_FAULT_GUARD_PAGE                                          => Some("STATUS_PAGE_FAULT_GUARD_PAGE"),
-        SMB_NTSTATUS_PAGE_FAULT_PAGING_FILE                                         => Some("STATUS_PAGE_FAULT_PAGING_FILE"),
-        SMB_NTSTATUS_CACHE_PAGE_LOCKED                                              => Some("STATUS_CACHE_PAGE_LOCKED"),
-        SMB_NTSTATUS_CRASH_DUMP                                                     => Some("STATUS_CRASH_DUMP"),
-        SMB_NTSTATUS_BUFFER_ALL_ZEROS                                               => Some("STATUS_BUFFER_ALL_ZEROS"),
-        SMB_NTSTATUS_REPARSE_OBJECT                                                 => Some("STATUS_REPARSE_OBJECT"),
-        SMB_NTSTATUS_RESOURCE_REQUIREMENTS_CHANGED                                  => Some("STATUS_RESOURCE_REQUIREMENTS_CHANGED"),
-        SMB_NTSTATUS_TRANSLATION_COMPLETE                                           => Some("STATUS_TRANSLATION_COMPLETE"),
-        SMB_NTSTATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY                                => Some("STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY"),
-        SMB_NTSTATUS_NOTHING_TO_TERMINATE                                           => Some("STATUS_NOTHING_TO_TERMINATE"),
-        SMB_NTSTATUS_PROCESS_NOT_IN_JOB                                             => Some("STATUS_PROCESS_NOT_IN_JOB"),
-        SMB_NTSTATUS_PROCESS_IN_JOB                                                 => Some("STATUS_PROCESS_IN_JOB"),
-        SMB_NTSTATUS_VOLSNAP_HIBERNATE_READY                                        => Some("STATUS_VOLSNAP_HIBERNATE_READY"),
-        SMB_NTSTATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY                             => Some("STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY"),
-        SMB_NTSTATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED                             => Some("STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED"),
-        SMB_NTSTATUS_INTERRUPT_STILL_CONNECTED                                      => Some("STATUS_INTERRUPT_STILL_CONNECTED"),
-        SMB_NTSTATUS_PROCESS_CLONED                                                 => Some("STATUS_PROCESS_CLONED"),
-        SMB_NTSTATUS_FILE_LOCKED_WITH_ONLY_READERS                                  => Some("STATUS_FILE_LOCKED_WITH_ONLY_READERS"),
-        SMB_NTSTATUS_FILE_LOCKED_WITH_WRITERS                                       => Some("STATUS_FILE_LOCKED_WITH_WRITERS"),
-        SMB_NTSTATUS_RESOURCEMANAGER_READ_ONLY                                      => Some("STATUS_RESOURCEMANAGER_READ_ONLY"),
-        SMB_NTSTATUS_WAIT_FOR_OPLOCK                                                => Some("STATUS_WAIT_FOR_OPLOCK"),
-        SMB_NTDBG_EXCEPTION_HANDLED                                                 => Some("DBG_EXCEPTION_HANDLED"),
-        SMB_NTDBG_CONTINUE                                                          => Some("DBG_CONTINUE"),
-        SMB_NTSTATUS_FLT_IO_COMPLETE                                                => Some("STATUS_FLT_IO_COMPLETE"),
-        SMB_NTSTATUS_FILE_NOT_AVAILABLE                                             => Some("STATUS_FILE_NOT_AVAILABLE"),
-        SMB_NTSTATUS_SHARE_UNAVAILABLE                                              => Some("STATUS_SHARE_UNAVAILABLE"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_THREAD_AFFINITY                              => Some("STATUS_CALLBACK_RETURNED_THREAD_AFFINITY"),
-        SMB_NTSTATUS_OBJECT_NAME_EXISTS                                             => Some("STATUS_OBJECT_NAME_EXISTS"),
-        SMB_NTSTATUS_THREAD_WAS_SUSPENDED                                           => Some("STATUS_THREAD_WAS_SUSPENDED"),
-        SMB_NTSTATUS_WORKING_SET_LIMIT_RANGE                                        => Some("STATUS_WORKING_SET_LIMIT_RANGE"),
-        SMB_NTSTATUS_IMAGE_NOT_AT_BASE                                              => Some("STATUS_IMAGE_NOT_AT_BASE"),
-        SMB_NTSTATUS_RXACT_STATE_CREATED                                            => Some("STATUS_RXACT_STATE_CREATED"),
-        SMB_NTSTATUS_SEGMENT_NOTIFICATION                                           => Some("STATUS_SEGMENT_NOTIFICATION"),
-        SMB_NTSTATUS_LOCAL_USER_SESSION_KEY                                         => Some("STATUS_LOCAL_USER_SESSION_KEY"),
-        SMB_NTSTATUS_BAD_CURRENT_DIRECTORY                                          => Some("STATUS_BAD_CURRENT_DIRECTORY"),
-        SMB_NTSTATUS_SERIAL_MORE_WRITES                                             => Some("STATUS_SERIAL_MORE_WRITES"),
-        SMB_NTSTATUS_REGISTRY_RECOVERED                                             => Some("STATUS_REGISTRY_RECOVERED"),
-        SMB_NTSTATUS_FT_READ_RECOVERY_FROM_BACKUP                                   => Some("STATUS_FT_READ_RECOVERY_FROM_BACKUP"),
-        SMB_NTSTATUS_FT_WRITE_RECOVERY                                              => Some("STATUS_FT_WRITE_RECOVERY"),
-        SMB_NTSTATUS_SERIAL_COUNTER_TIMEOUT                                         => Some("STATUS_SERIAL_COUNTER_TIMEOUT"),
-        SMB_NTSTATUS_NULL_LM_PASSWORD                                               => Some("STATUS_NULL_LM_PASSWORD"),
-        SMB_NTSTATUS_IMAGE_MACHINE_TYPE_MISMATCH                                    => Some("STATUS_IMAGE_MACHINE_TYPE_MISMATCH"),
-        SMB_NTSTATUS_RECEIVE_PARTIAL                                                => Some("STATUS_RECEIVE_PARTIAL"),
-        SMB_NTSTATUS_RECEIVE_EXPEDITED                                              => Some("STATUS_RECEIVE_EXPEDITED"),
-        SMB_NTSTATUS_RECEIVE_PARTIAL_EXPEDITED                                      => Some("STATUS_RECEIVE_PARTIAL_EXPEDITED"),
-        SMB_NTSTATUS_EVENT_DONE                                                     => Some("STATUS_EVENT_DONE"),
-        SMB_NTSTATUS_EVENT_PENDING                                                  => Some("STATUS_EVENT_PENDING"),
-        SMB_NTSTATUS_CHECKING_FILE_SYSTEM                                           => Some("STATUS_CHECKING_FILE_SYSTEM"),
-        SMB_NTSTATUS_FATAL_APP_EXIT                                                 => Some("STATUS_FATAL_APP_EXIT"),
-        SMB_NTSTATUS_PREDEFINED_HANDLE                                              => Some("STATUS_PREDEFINED_HANDLE"),
-        SMB_NTSTATUS_WAS_UNLOCKED                                                   => Some("STATUS_WAS_UNLOCKED"),
-        SMB_NTSTATUS_SERVICE_NOTIFICATION                                           => Some("STATUS_SERVICE_NOTIFICATION"),
-        SMB_NTSTATUS_WAS_LOCKED                                                     => Some("STATUS_WAS_LOCKED"),
-        SMB_NTSTATUS_LOG_HARD_ERROR                                                 => Some("STATUS_LOG_HARD_ERROR"),
-        SMB_NTSTATUS_ALREADY_WIN32                                                  => Some("STATUS_ALREADY_WIN32"),
-        SMB_NTSTATUS_WX86_UNSIMULATE                                                => Some("STATUS_WX86_UNSIMULATE"),
-        SMB_NTSTATUS_WX86_CONTINUE                                                  => Some("STATUS_WX86_CONTINUE"),
-        SMB_NTSTATUS_WX86_SINGLE_STEP                                               => Some("STATUS_WX86_SINGLE_STEP"),
-        SMB_NTSTATUS_WX86_BREAKPOINT                                                => Some("STATUS_WX86_BREAKPOINT"),
-        SMB_NTSTATUS_WX86_EXCEPTION_CONTINUE                                        => Some("STATUS_WX86_EXCEPTION_CONTINUE"),
-        SMB_NTSTATUS_WX86_EXCEPTION_LASTCHANCE                                      => Some("STATUS_WX86_EXCEPTION_LASTCHANCE"),
-        SMB_NTSTATUS_WX86_EXCEPTION_CHAIN                                           => Some("STATUS_WX86_EXCEPTION_CHAIN"),
-        SMB_NTSTATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE                                => Some("STATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE"),
-        SMB_NTSTATUS_NO_YIELD_PERFORMED                                             => Some("STATUS_NO_YIELD_PERFORMED"),
-        SMB_NTSTATUS_TIMER_RESUME_IGNORED                                           => Some("STATUS_TIMER_RESUME_IGNORED"),
-        SMB_NTSTATUS_ARBITRATION_UNHANDLED                                          => Some("STATUS_ARBITRATION_UNHANDLED"),
-        SMB_NTSTATUS_CARDBUS_NOT_SUPPORTED                                          => Some("STATUS_CARDBUS_NOT_SUPPORTED"),
-        SMB_NTSTATUS_WX86_CREATEWX86TIB                                             => Some("STATUS_WX86_CREATEWX86TIB"),
-        SMB_NTSTATUS_MP_PROCESSOR_MISMATCH                                          => Some("STATUS_MP_PROCESSOR_MISMATCH"),
-        SMB_NTSTATUS_HIBERNATED                                                     => Some("STATUS_HIBERNATED"),
-        SMB_NTSTATUS_RESUME_HIBERNATION                                             => Some("STATUS_RESUME_HIBERNATION"),
-        SMB_NTSTATUS_FIRMWARE_UPDATED                                               => Some("STATUS_FIRMWARE_UPDATED"),
-        SMB_NTSTATUS_DRIVERS_LEAKING_LOCKED_PAGES                                   => Some("STATUS_DRIVERS_LEAKING_LOCKED_PAGES"),
-        SMB_NTSTATUS_MESSAGE_RETRIEVED                                              => Some("STATUS_MESSAGE_RETRIEVED"),
-        SMB_NTSTATUS_SYSTEM_POWERSTATE_TRANSITION                                   => Some("STATUS_SYSTEM_POWERSTATE_TRANSITION"),
-        SMB_NTSTATUS_ALPC_CHECK_COMPLETION_LIST                                     => Some("STATUS_ALPC_CHECK_COMPLETION_LIST"),
-        SMB_NTSTATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION                           => Some("STATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION"),
-        SMB_NTSTATUS_ACCESS_AUDIT_BY_POLICY                                         => Some("STATUS_ACCESS_AUDIT_BY_POLICY"),
-        SMB_NTSTATUS_ABANDON_HIBERFILE                                              => Some("STATUS_ABANDON_HIBERFILE"),
-        SMB_NTSTATUS_BIZRULES_NOT_ENABLED                                           => Some("STATUS_BIZRULES_NOT_ENABLED"),
-        SMB_NTSTATUS_WAKE_SYSTEM                                                    => Some("STATUS_WAKE_SYSTEM"),
-        SMB_NTSTATUS_DS_SHUTTING_DOWN                                               => Some("STATUS_DS_SHUTTING_DOWN"),
-        SMB_NTDBG_REPLY_LATER                                                       => Some("DBG_REPLY_LATER"),
-        SMB_NTDBG_UNABLE_TO_PROVIDE_HANDLE                                          => Some("DBG_UNABLE_TO_PROVIDE_HANDLE"),
-        SMB_NTDBG_TERMINATE_THREAD                                                  => Some("DBG_TERMINATE_THREAD"),
-        SMB_NTDBG_TERMINATE_PROCESS                                                 => Some("DBG_TERMINATE_PROCESS"),
-        SMB_NTDBG_CONTROL_C                                                         => Some("DBG_CONTROL_C"),
-        SMB_NTDBG_PRINTEXCEPTION_C                                                  => Some("DBG_PRINTEXCEPTION_C"),
-        SMB_NTDBG_RIPEXCEPTION                                                      => Some("DBG_RIPEXCEPTION"),
-        SMB_NTDBG_CONTROL_BREAK                                                     => Some("DBG_CONTROL_BREAK"),
-        SMB_NTDBG_COMMAND_EXCEPTION                                                 => Some("DBG_COMMAND_EXCEPTION"),
-        SMB_NTRPC_NT_UUID_LOCAL_ONLY                                                => Some("RPC_NT_UUID_LOCAL_ONLY"),
-        SMB_NTRPC_NT_SEND_INCOMPLETE                                                => Some("RPC_NT_SEND_INCOMPLETE"),
-        SMB_NTSTATUS_CTX_CDM_CONNECT                                                => Some("STATUS_CTX_CDM_CONNECT"),
-        SMB_NTSTATUS_CTX_CDM_DISCONNECT                                             => Some("STATUS_CTX_CDM_DISCONNECT"),
-        SMB_NTSTATUS_SXS_RELEASE_ACTIVATION_CONTEXT                                 => Some("STATUS_SXS_RELEASE_ACTIVATION_CONTEXT"),
-        SMB_NTSTATUS_RECOVERY_NOT_NEEDED                                            => Some("STATUS_RECOVERY_NOT_NEEDED"),
-        SMB_NTSTATUS_RM_ALREADY_STARTED                                             => Some("STATUS_RM_ALREADY_STARTED"),
-        SMB_NTSTATUS_LOG_NO_RESTART                                                 => Some("STATUS_LOG_NO_RESTART"),
-        SMB_NTSTATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST                              => Some("STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST"),
-        SMB_NTSTATUS_GRAPHICS_PARTIAL_DATA_POPULATED                                => Some("STATUS_GRAPHICS_PARTIAL_DATA_POPULATED"),
-        SMB_NTSTATUS_GRAPHICS_DRIVER_MISMATCH                                       => Some("STATUS_GRAPHICS_DRIVER_MISMATCH"),
-        SMB_NTSTATUS_GRAPHICS_MODE_NOT_PINNED                                       => Some("STATUS_GRAPHICS_MODE_NOT_PINNED"),
-        SMB_NTSTATUS_GRAPHICS_NO_PREFERRED_MODE                                     => Some("STATUS_GRAPHICS_NO_PREFERRED_MODE"),
-        SMB_NTSTATUS_GRAPHICS_DATASET_IS_EMPTY                                      => Some("STATUS_GRAPHICS_DATASET_IS_EMPTY"),
-        SMB_NTSTATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET                           => Some("STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET"),
-        SMB_NTSTATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED       => Some("STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED"),
-        SMB_NTSTATUS_GRAPHICS_UNKNOWN_CHILD_STATUS                                  => Some("STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS"),
-        SMB_NTSTATUS_GRAPHICS_LEADLINK_START_DEFERRED                               => Some("STATUS_GRAPHICS_LEADLINK_START_DEFERRED"),
-        SMB_NTSTATUS_GRAPHICS_POLLING_TOO_FREQUENTLY                                => Some("STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY"),
-        SMB_NTSTATUS_GRAPHICS_START_DEFERRED                                        => Some("STATUS_GRAPHICS_START_DEFERRED"),
-        SMB_NTSTATUS_NDIS_INDICATION_REQUIRED                                       => Some("STATUS_NDIS_INDICATION_REQUIRED"),
-        SMB_NTSTATUS_GUARD_PAGE_VIOLATION                                           => Some("STATUS_GUARD_PAGE_VIOLATION"),
-        SMB_NTSTATUS_DATATYPE_MISALIGNMENT                                          => Some("STATUS_DATATYPE_MISALIGNMENT"),
-        SMB_NTSTATUS_BREAKPOINT                                                     => Some("STATUS_BREAKPOINT"),
-        SMB_NTSTATUS_SINGLE_STEP                                                    => Some("STATUS_SINGLE_STEP"),
-        SMB_NTSTATUS_BUFFER_OVERFLOW                                                => Some("STATUS_BUFFER_OVERFLOW"),
-        SMB_NTSTATUS_NO_MORE_FILES                                                  => Some("STATUS_NO_MORE_FILES"),
-        SMB_NTSTATUS_WAKE_SYSTEM_DEBUGGER                                           => Some("STATUS_WAKE_SYSTEM_DEBUGGER"),
-        SMB_NTSTATUS_HANDLES_CLOSED                                                 => Some("STATUS_HANDLES_CLOSED"),
-        SMB_NTSTATUS_NO_INHERITANCE                                                 => Some("STATUS_NO_INHERITANCE"),
-        SMB_NTSTATUS_GUID_SUBSTITUTION_MADE                                         => Some("STATUS_GUID_SUBSTITUTION_MADE"),
-        SMB_NTSTATUS_PARTIAL_COPY                                                   => Some("STATUS_PARTIAL_COPY"),
-        SMB_NTSTATUS_DEVICE_PAPER_EMPTY                                             => Some("STATUS_DEVICE_PAPER_EMPTY"),
-        SMB_NTSTATUS_DEVICE_POWERED_OFF                                             => Some("STATUS_DEVICE_POWERED_OFF"),
-        SMB_NTSTATUS_DEVICE_OFF_LINE                                                => Some("STATUS_DEVICE_OFF_LINE"),
-        SMB_NTSTATUS_DEVICE_BUSY                                                    => Some("STATUS_DEVICE_BUSY"),
-        SMB_NTSTATUS_NO_MORE_EAS                                                    => Some("STATUS_NO_MORE_EAS"),
-        SMB_NTSTATUS_INVALID_EA_NAME                                                => Some("STATUS_INVALID_EA_NAME"),
-        SMB_NTSTATUS_EA_LIST_INCONSISTENT                                           => Some("STATUS_EA_LIST_INCONSISTENT"),
-        SMB_NTSTATUS_INVALID_EA_FLAG                                                => Some("STATUS_INVALID_EA_FLAG"),
-        SMB_NTSTATUS_VERIFY_REQUIRED                                                => Some("STATUS_VERIFY_REQUIRED"),
-        SMB_NTSTATUS_EXTRANEOUS_INFORMATION                                         => Some("STATUS_EXTRANEOUS_INFORMATION"),
-        SMB_NTSTATUS_RXACT_COMMIT_NECESSARY                                         => Some("STATUS_RXACT_COMMIT_NECESSARY"),
-        SMB_NTSTATUS_NO_MORE_ENTRIES                                                => Some("STATUS_NO_MORE_ENTRIES"),
-        SMB_NTSTATUS_FILEMARK_DETECTED                                              => Some("STATUS_FILEMARK_DETECTED"),
-        SMB_NTSTATUS_MEDIA_CHANGED                                                  => Some("STATUS_MEDIA_CHANGED"),
-        SMB_NTSTATUS_BUS_RESET                                                      => Some("STATUS_BUS_RESET"),
-        SMB_NTSTATUS_END_OF_MEDIA                                                   => Some("STATUS_END_OF_MEDIA"),
-        SMB_NTSTATUS_BEGINNING_OF_MEDIA                                             => Some("STATUS_BEGINNING_OF_MEDIA"),
-        SMB_NTSTATUS_MEDIA_CHECK                                                    => Some("STATUS_MEDIA_CHECK"),
-        SMB_NTSTATUS_SETMARK_DETECTED                                               => Some("STATUS_SETMARK_DETECTED"),
-        SMB_NTSTATUS_NO_DATA_DETECTED                                               => Some("STATUS_NO_DATA_DETECTED"),
-        SMB_NTSTATUS_REDIRECTOR_HAS_OPEN_HANDLES                                    => Some("STATUS_REDIRECTOR_HAS_OPEN_HANDLES"),
-        SMB_NTSTATUS_SERVER_HAS_OPEN_HANDLES                                        => Some("STATUS_SERVER_HAS_OPEN_HANDLES"),
-        SMB_NTSTATUS_ALREADY_DISCONNECTED                                           => Some("STATUS_ALREADY_DISCONNECTED"),
-        SMB_NTSTATUS_LONGJUMP                                                       => Some("STATUS_LONGJUMP"),
-        SMB_NTSTATUS_CLEANER_CARTRIDGE_INSTALLED                                    => Some("STATUS_CLEANER_CARTRIDGE_INSTALLED"),
-        SMB_NTSTATUS_PLUGPLAY_QUERY_VETOED                                          => Some("STATUS_PLUGPLAY_QUERY_VETOED"),
-        SMB_NTSTATUS_UNWIND_CONSOLIDATE                                             => Some("STATUS_UNWIND_CONSOLIDATE"),
-        SMB_NTSTATUS_REGISTRY_HIVE_RECOVERED                                        => Some("STATUS_REGISTRY_HIVE_RECOVERED"),
-        SMB_NTSTATUS_DLL_MIGHT_BE_INSECURE                                          => Some("STATUS_DLL_MIGHT_BE_INSECURE"),
-        SMB_NTSTATUS_DLL_MIGHT_BE_INCOMPATIBLE                                      => Some("STATUS_DLL_MIGHT_BE_INCOMPATIBLE"),
-        SMB_NTSTATUS_STOPPED_ON_SYMLINK                                             => Some("STATUS_STOPPED_ON_SYMLINK"),
-        SMB_NTSTATUS_DEVICE_REQUIRES_CLEANING                                       => Some("STATUS_DEVICE_REQUIRES_CLEANING"),
-        SMB_NTSTATUS_DEVICE_DOOR_OPEN                                               => Some("STATUS_DEVICE_DOOR_OPEN"),
-        SMB_NTSTATUS_DATA_LOST_REPAIR                                               => Some("STATUS_DATA_LOST_REPAIR"),
-        SMB_NTDBG_EXCEPTION_NOT_HANDLED                                             => Some("DBG_EXCEPTION_NOT_HANDLED"),
-        SMB_NTSTATUS_CLUSTER_NODE_ALREADY_UP                                        => Some("STATUS_CLUSTER_NODE_ALREADY_UP"),
-        SMB_NTSTATUS_CLUSTER_NODE_ALREADY_DOWN                                      => Some("STATUS_CLUSTER_NODE_ALREADY_DOWN"),
-        SMB_NTSTATUS_CLUSTER_NETWORK_ALREADY_ONLINE                                 => Some("STATUS_CLUSTER_NETWORK_ALREADY_ONLINE"),
-        SMB_NTSTATUS_CLUSTER_NETWORK_ALREADY_OFFLINE                                => Some("STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE"),
-        SMB_NTSTATUS_CLUSTER_NODE_ALREADY_MEMBER                                    => Some("STATUS_CLUSTER_NODE_ALREADY_MEMBER"),
-        SMB_NTSTATUS_COULD_NOT_RESIZE_LOG                                           => Some("STATUS_COULD_NOT_RESIZE_LOG"),
-        SMB_NTSTATUS_NO_TXF_METADATA                                                => Some("STATUS_NO_TXF_METADATA"),
-        SMB_NTSTATUS_CANT_RECOVER_WITH_HANDLE_OPEN                                  => Some("STATUS_CANT_RECOVER_WITH_HANDLE_OPEN"),
-        SMB_NTSTATUS_TXF_METADATA_ALREADY_PRESENT                                   => Some("STATUS_TXF_METADATA_ALREADY_PRESENT"),
-        SMB_NTSTATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET                            => Some("STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET"),
-        SMB_NTSTATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED                     => Some("STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED"),
-        SMB_NTSTATUS_FLT_BUFFER_TOO_SMALL                                           => Some("STATUS_FLT_BUFFER_TOO_SMALL"),
-        SMB_NTSTATUS_FVE_PARTIAL_METADATA                                           => Some("STATUS_FVE_PARTIAL_METADATA"),
-        SMB_NTSTATUS_FVE_TRANSIENT_STATE                                            => Some("STATUS_FVE_TRANSIENT_STATE"),
-        SMB_NTSTATUS_UNSUCCESSFUL                                                   => Some("STATUS_UNSUCCESSFUL"),
-        SMB_NTSTATUS_NOT_IMPLEMENTED                                                => Some("STATUS_NOT_IMPLEMENTED"),
-        SMB_NTSTATUS_INVALID_INFO_CLASS                                             => Some("STATUS_INVALID_INFO_CLASS"),
-        SMB_NTSTATUS_INFO_LENGTH_MISMATCH                                           => Some("STATUS_INFO_LENGTH_MISMATCH"),
-        SMB_NTSTATUS_ACCESS_VIOLATION                                               => Some("STATUS_ACCESS_VIOLATION"),
-        SMB_NTSTATUS_IN_PAGE_ERROR                                                  => Some("STATUS_IN_PAGE_ERROR"),
-        SMB_NTSTATUS_PAGEFILE_QUOTA                                                 => Some("STATUS_PAGEFILE_QUOTA"),
-        SMB_NTSTATUS_INVALID_HANDLE                                                 => Some("STATUS_INVALID_HANDLE"),
-        SMB_NTSTATUS_BAD_INITIAL_STACK                                              => Some("STATUS_BAD_INITIAL_STACK"),
-        SMB_NTSTATUS_BAD_INITIAL_PC                                                 => Some("STATUS_BAD_INITIAL_PC"),
-        SMB_NTSTATUS_INVALID_CID                                                    => Some("STATUS_INVALID_CID"),
-        SMB_NTSTATUS_TIMER_NOT_CANCELED                                             => Some("STATUS_TIMER_NOT_CANCELED"),
-        SMB_NTSTATUS_INVALID_PARAMETER                                              => Some("STATUS_INVALID_PARAMETER"),
-        SMB_NTSTATUS_NO_SUCH_DEVICE                                                 => Some("STATUS_NO_SUCH_DEVICE"),
-        SMB_NTSTATUS_NO_SUCH_FILE                                                   => Some("STATUS_NO_SUCH_FILE"),
-        SMB_NTSTATUS_INVALID_DEVICE_REQUEST                                         => Some("STATUS_INVALID_DEVICE_REQUEST"),
-        SMB_NTSTATUS_END_OF_FILE                                                    => Some("STATUS_END_OF_FILE"),
-        SMB_NTSTATUS_WRONG_VOLUME                                                   => Some("STATUS_WRONG_VOLUME"),
-        SMB_NTSTATUS_NO_MEDIA_IN_DEVICE                                             => Some("STATUS_NO_MEDIA_IN_DEVICE"),
-        SMB_NTSTATUS_UNRECOGNIZED_MEDIA                                             => Some("STATUS_UNRECOGNIZED_MEDIA"),
-        SMB_NTSTATUS_NONEXISTENT_SECTOR                                             => Some("STATUS_NONEXISTENT_SECTOR"),
-        SMB_NTSTATUS_MORE_PROCESSING_REQUIRED                                       => Some("STATUS_MORE_PROCESSING_REQUIRED"),
-        SMB_NTSTATUS_NO_MEMORY                                                      => Some("STATUS_NO_MEMORY"),
-        SMB_NTSTATUS_CONFLICTING_ADDRESSES                                          => Some("STATUS_CONFLICTING_ADDRESSES"),
-        SMB_NTSTATUS_NOT_MAPPED_VIEW                                                => Some("STATUS_NOT_MAPPED_VIEW"),
-        SMB_NTSTATUS_UNABLE_TO_FREE_VM                                              => Some("STATUS_UNABLE_TO_FREE_VM"),
-        SMB_NTSTATUS_UNABLE_TO_DELETE_SECTION                                       => Some("STATUS_UNABLE_TO_DELETE_SECTION"),
-        SMB_NTSTATUS_INVALID_SYSTEM_SERVICE                                         => Some("STATUS_INVALID_SYSTEM_SERVICE"),
-        SMB_NTSTATUS_ILLEGAL_INSTRUCTION                                            => Some("STATUS_ILLEGAL_INSTRUCTION"),
-        SMB_NTSTATUS_INVALID_LOCK_SEQUENCE                                          => Some("STATUS_INVALID_LOCK_SEQUENCE"),
-        SMB_NTSTATUS_INVALID_VIEW_SIZE                                              => Some("STATUS_INVALID_VIEW_SIZE"),
-        SMB_NTSTATUS_INVALID_FILE_FOR_SECTION                                       => Some("STATUS_INVALID_FILE_FOR_SECTION"),
-        SMB_NTSTATUS_ALREADY_COMMITTED                                              => Some("STATUS_ALREADY_COMMITTED"),
-        SMB_NTSTATUS_ACCESS_DENIED                                                  => Some("STATUS_ACCESS_DENIED"),
-        SMB_NTSTATUS_BUFFER_TOO_SMALL                                               => Some("STATUS_BUFFER_TOO_SMALL"),
-        SMB_NTSTATUS_OBJECT_TYPE_MISMATCH                                           => Some("STATUS_OBJECT_TYPE_MISMATCH"),
-        SMB_NTSTATUS_NONCONTINUABLE_EXCEPTION                                       => Some("STATUS_NONCONTINUABLE_EXCEPTION"),
-        SMB_NTSTATUS_INVALID_DISPOSITION                                            => Some("STATUS_INVALID_DISPOSITION"),
-        SMB_NTSTATUS_UNWIND                                                         => Some("STATUS_UNWIND"),
-        SMB_NTSTATUS_BAD_STACK                                                      => Some("STATUS_BAD_STACK"),
-        SMB_NTSTATUS_INVALID_UNWIND_TARGET                                          => Some("STATUS_INVALID_UNWIND_TARGET"),
-        SMB_NTSTATUS_NOT_LOCKED                                                     => Some("STATUS_NOT_LOCKED"),
-        SMB_NTSTATUS_PARITY_ERROR                                                   => Some("STATUS_PARITY_ERROR"),
-        SMB_NTSTATUS_UNABLE_TO_DECOMMIT_VM                                          => Some("STATUS_UNABLE_TO_DECOMMIT_VM"),
-        SMB_NTSTATUS_NOT_COMMITTED                                                  => Some("STATUS_NOT_COMMITTED"),
-        SMB_NTSTATUS_INVALID_PORT_ATTRIBUTES                                        => Some("STATUS_INVALID_PORT_ATTRIBUTES"),
-        SMB_NTSTATUS_PORT_MESSAGE_TOO_LONG                                          => Some("STATUS_PORT_MESSAGE_TOO_LONG"),
-        SMB_NTSTATUS_INVALID_PARAMETER_MIX                                          => Some("STATUS_INVALID_PARAMETER_MIX"),
-        SMB_NTSTATUS_INVALID_QUOTA_LOWER                                            => Some("STATUS_INVALID_QUOTA_LOWER"),
-        SMB_NTSTATUS_DISK_CORRUPT_ERROR                                             => Some("STATUS_DISK_CORRUPT_ERROR"),
-        SMB_NTSTATUS_OBJECT_NAME_INVALID                                            => Some("STATUS_OBJECT_NAME_INVALID"),
-        SMB_NTSTATUS_OBJECT_NAME_NOT_FOUND                                          => Some("STATUS_OBJECT_NAME_NOT_FOUND"),
-        SMB_NTSTATUS_OBJECT_NAME_COLLISION                                          => Some("STATUS_OBJECT_NAME_COLLISION"),
-        SMB_NTSTATUS_PORT_DISCONNECTED                                              => Some("STATUS_PORT_DISCONNECTED"),
-        SMB_NTSTATUS_DEVICE_ALREADY_ATTACHED                                        => Some("STATUS_DEVICE_ALREADY_ATTACHED"),
-        SMB_NTSTATUS_OBJECT_PATH_INVALID                                            => Some("STATUS_OBJECT_PATH_INVALID"),
-        SMB_NTSTATUS_OBJECT_PATH_NOT_FOUND                                          => Some("STATUS_OBJECT_PATH_NOT_FOUND"),
-        SMB_NTSTATUS_OBJECT_PATH_SYNTAX_BAD                                         => Some("STATUS_OBJECT_PATH_SYNTAX_BAD"),
-        SMB_NTSTATUS_DATA_OVERRUN                                                   => Some("STATUS_DATA_OVERRUN"),
-        SMB_NTSTATUS_DATA_LATE_ERROR                                                => Some("STATUS_DATA_LATE_ERROR"),
-        SMB_NTSTATUS_DATA_ERROR                                                     => Some("STATUS_DATA_ERROR"),
-        SMB_NTSTATUS_CRC_ERROR                                                      => Some("STATUS_CRC_ERROR"),
-        SMB_NTSTATUS_SECTION_TOO_BIG                                                => Some("STATUS_SECTION_TOO_BIG"),
-        SMB_NTSTATUS_PORT_CONNECTION_REFUSED                                        => Some("STATUS_PORT_CONNECTION_REFUSED"),
-        SMB_NTSTATUS_INVALID_PORT_HANDLE                                            => Some("STATUS_INVALID_PORT_HANDLE"),
-        SMB_NTSTATUS_SHARING_VIOLATION                                              => Some("STATUS_SHARING_VIOLATION"),
-        SMB_NTSTATUS_QUOTA_EXCEEDED                                                 => Some("STATUS_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_INVALID_PAGE_PROTECTION                                        => Some("STATUS_INVALID_PAGE_PROTECTION"),
-        SMB_NTSTATUS_MUTANT_NOT_OWNED                                               => Some("STATUS_MUTANT_NOT_OWNED"),
-        SMB_NTSTATUS_SEMAPHORE_LIMIT_EXCEEDED                                       => Some("STATUS_SEMAPHORE_LIMIT_EXCEEDED"),
-        SMB_NTSTATUS_PORT_ALREADY_SET                                               => Some("STATUS_PORT_ALREADY_SET"),
-        SMB_NTSTATUS_SECTION_NOT_IMAGE                                              => Some("STATUS_SECTION_NOT_IMAGE"),
-        SMB_NTSTATUS_SUSPEND_COUNT_EXCEEDED                                         => Some("STATUS_SUSPEND_COUNT_EXCEEDED"),
-        SMB_NTSTATUS_THREAD_IS_TERMINATING                                          => Some("STATUS_THREAD_IS_TERMINATING"),
-        SMB_NTSTATUS_BAD_WORKING_SET_LIMIT                                          => Some("STATUS_BAD_WORKING_SET_LIMIT"),
-        SMB_NTSTATUS_INCOMPATIBLE_FILE_MAP                                          => Some("STATUS_INCOMPATIBLE_FILE_MAP"),
-        SMB_NTSTATUS_SECTION_PROTECTION                                             => Some("STATUS_SECTION_PROTECTION"),
-        SMB_NTSTATUS_EAS_NOT_SUPPORTED                                              => Some("STATUS_EAS_NOT_SUPPORTED"),
-        SMB_NTSTATUS_EA_TOO_LARGE                                                   => Some("STATUS_EA_TOO_LARGE"),
-        SMB_NTSTATUS_NONEXISTENT_EA_ENTRY                                           => Some("STATUS_NONEXISTENT_EA_ENTRY"),
-        SMB_NTSTATUS_NO_EAS_ON_FILE                                                 => Some("STATUS_NO_EAS_ON_FILE"),
-        SMB_NTSTATUS_EA_CORRUPT_ERROR                                               => Some("STATUS_EA_CORRUPT_ERROR"),
-        SMB_NTSTATUS_FILE_LOCK_CONFLICT                                             => Some("STATUS_FILE_LOCK_CONFLICT"),
-        SMB_NTSTATUS_LOCK_NOT_GRANTED                                               => Some("STATUS_LOCK_NOT_GRANTED"),
-        SMB_NTSTATUS_DELETE_PENDING                                                 => Some("STATUS_DELETE_PENDING"),
-        SMB_NTSTATUS_CTL_FILE_NOT_SUPPORTED                                         => Some("STATUS_CTL_FILE_NOT_SUPPORTED"),
-        SMB_NTSTATUS_UNKNOWN_REVISION                                               => Some("STATUS_UNKNOWN_REVISION"),
-        SMB_NTSTATUS_REVISION_MISMATCH                                              => Some("STATUS_REVISION_MISMATCH"),
-        SMB_NTSTATUS_INVALID_OWNER                                                  => Some("STATUS_INVALID_OWNER"),
-        SMB_NTSTATUS_INVALID_PRIMARY_GROUP                                          => Some("STATUS_INVALID_PRIMARY_GROUP"),
-        SMB_NTSTATUS_NO_IMPERSONATION_TOKEN                                         => Some("STATUS_NO_IMPERSONATION_TOKEN"),
-        SMB_NTSTATUS_CANT_DISABLE_MANDATORY                                         => Some("STATUS_CANT_DISABLE_MANDATORY"),
-        SMB_NTSTATUS_NO_LOGON_SERVERS                                               => Some("STATUS_NO_LOGON_SERVERS"),
-        SMB_NTSTATUS_NO_SUCH_LOGON_SESSION                                          => Some("STATUS_NO_SUCH_LOGON_SESSION"),
-        SMB_NTSTATUS_NO_SUCH_PRIVILEGE                                              => Some("STATUS_NO_SUCH_PRIVILEGE"),
-        SMB_NTSTATUS_PRIVILEGE_NOT_HELD                                             => Some("STATUS_PRIVILEGE_NOT_HELD"),
-        SMB_NTSTATUS_INVALID_ACCOUNT_NAME                                           => Some("STATUS_INVALID_ACCOUNT_NAME"),
-        SMB_NTSTATUS_USER_EXISTS                                                    => Some("STATUS_USER_EXISTS"),
-        SMB_NTSTATUS_NO_SUCH_USER                                                   => Some("STATUS_NO_SUCH_USER"),
-        SMB_NTSTATUS_GROUP_EXISTS                                                   => Some("STATUS_GROUP_EXISTS"),
-        SMB_NTSTATUS_NO_SUCH_GROUP                                                  => Some("STATUS_NO_SUCH_GROUP"),
-        SMB_NTSTATUS_MEMBER_IN_GROUP                                                => Some("STATUS_MEMBER_IN_GROUP"),
-        SMB_NTSTATUS_MEMBER_NOT_IN_GROUP                                            => Some("STATUS_MEMBER_NOT_IN_GROUP"),
-        SMB_NTSTATUS_LAST_ADMIN                                                     => Some("STATUS_LAST_ADMIN"),
-        SMB_NTSTATUS_WRONG_PASSWORD                                                 => Some("STATUS_WRONG_PASSWORD"),
-        SMB_NTSTATUS_ILL_FORMED_PASSWORD                                            => Some("STATUS_ILL_FORMED_PASSWORD"),
-        SMB_NTSTATUS_PASSWORD_RESTRICTION                                           => Some("STATUS_PASSWORD_RESTRICTION"),
-        SMB_NTSTATUS_LOGON_FAILURE                                                  => Some("STATUS_LOGON_FAILURE"),
-        SMB_NTSTATUS_ACCOUNT_RESTRICTION                                            => Some("STATUS_ACCOUNT_RESTRICTION"),
-        SMB_NTSTATUS_INVALID_LOGON_HOURS                                            => Some("STATUS_INVALID_LOGON_HOURS"),
-        SMB_NTSTATUS_INVALID_WORKSTATION                                            => Some("STATUS_INVALID_WORKSTATION"),
-        SMB_NTSTATUS_PASSWORD_EXPIRED                                               => Some("STATUS_PASSWORD_EXPIRED"),
-        SMB_NTSTATUS_ACCOUNT_DISABLED                                               => Some("STATUS_ACCOUNT_DISABLED"),
-        SMB_NTSTATUS_NONE_MAPPED                                                    => Some("STATUS_NONE_MAPPED"),
-        SMB_NTSTATUS_TOO_MANY_LUIDS_REQUESTED                                       => Some("STATUS_TOO_MANY_LUIDS_REQUESTED"),
-        SMB_NTSTATUS_LUIDS_EXHAUSTED                                                => Some("STATUS_LUIDS_EXHAUSTED"),
-        SMB_NTSTATUS_INVALID_SUB_AUTHORITY                                          => Some("STATUS_INVALID_SUB_AUTHORITY"),
-        SMB_NTSTATUS_INVALID_ACL                                                    => Some("STATUS_INVALID_ACL"),
-        SMB_NTSTATUS_INVALID_SID                                                    => Some("STATUS_INVALID_SID"),
-        SMB_NTSTATUS_INVALID_SECURITY_DESCR                                         => Some("STATUS_INVALID_SECURITY_DESCR"),
-        SMB_NTSTATUS_PROCEDURE_NOT_FOUND                                            => Some("STATUS_PROCEDURE_NOT_FOUND"),
-        SMB_NTSTATUS_INVALID_IMAGE_FORMAT                                           => Some("STATUS_INVALID_IMAGE_FORMAT"),
-        SMB_NTSTATUS_NO_TOKEN                                                       => Some("STATUS_NO_TOKEN"),
-        SMB_NTSTATUS_BAD_INHERITANCE_ACL                                            => Some("STATUS_BAD_INHERITANCE_ACL"),
-        SMB_NTSTATUS_RANGE_NOT_LOCKED                                               => Some("STATUS_RANGE_NOT_LOCKED"),
-        SMB_NTSTATUS_DISK_FULL                                                      => Some("STATUS_DISK_FULL"),
-        SMB_NTSTATUS_SERVER_DISABLED                                                => Some("STATUS_SERVER_DISABLED"),
-        SMB_NTSTATUS_SERVER_NOT_DISABLED                                            => Some("STATUS_SERVER_NOT_DISABLED"),
-        SMB_NTSTATUS_TOO_MANY_GUIDS_REQUESTED                                       => Some("STATUS_TOO_MANY_GUIDS_REQUESTED"),
-        SMB_NTSTATUS_GUIDS_EXHAUSTED                                                => Some("STATUS_GUIDS_EXHAUSTED"),
-        SMB_NTSTATUS_INVALID_ID_AUTHORITY                                           => Some("STATUS_INVALID_ID_AUTHORITY"),
-        SMB_NTSTATUS_AGENTS_EXHAUSTED                                               => Some("STATUS_AGENTS_EXHAUSTED"),
-        SMB_NTSTATUS_INVALID_VOLUME_LABEL                                           => Some("STATUS_INVALID_VOLUME_LABEL"),
-        SMB_NTSTATUS_SECTION_NOT_EXTENDED                                           => Some("STATUS_SECTION_NOT_EXTENDED"),
-        SMB_NTSTATUS_NOT_MAPPED_DATA                                                => Some("STATUS_NOT_MAPPED_DATA"),
-        SMB_NTSTATUS_RESOURCE_DATA_NOT_FOUND                                        => Some("STATUS_RESOURCE_DATA_NOT_FOUND"),
-        SMB_NTSTATUS_RESOURCE_TYPE_NOT_FOUND                                        => Some("STATUS_RESOURCE_TYPE_NOT_FOUND"),
-        SMB_NTSTATUS_RESOURCE_NAME_NOT_FOUND                                        => Some("STATUS_RESOURCE_NAME_NOT_FOUND"),
-        SMB_NTSTATUS_ARRAY_BOUNDS_EXCEEDED                                          => Some("STATUS_ARRAY_BOUNDS_EXCEEDED"),
-        SMB_NTSTATUS_FLOAT_DENORMAL_OPERAND                                         => Some("STATUS_FLOAT_DENORMAL_OPERAND"),
-        SMB_NTSTATUS_FLOAT_DIVIDE_BY_ZERO                                           => Some("STATUS_FLOAT_DIVIDE_BY_ZERO"),
-        SMB_NTSTATUS_FLOAT_INEXACT_RESULT                                           => Some("STATUS_FLOAT_INEXACT_RESULT"),
-        SMB_NTSTATUS_FLOAT_INVALID_OPERATION                                        => Some("STATUS_FLOAT_INVALID_OPERATION"),
-        SMB_NTSTATUS_FLOAT_OVERFLOW                                                 => Some("STATUS_FLOAT_OVERFLOW"),
-        SMB_NTSTATUS_FLOAT_STACK_CHECK                                              => Some("STATUS_FLOAT_STACK_CHECK"),
-        SMB_NTSTATUS_FLOAT_UNDERFLOW                                                => Some("STATUS_FLOAT_UNDERFLOW"),
-        SMB_NTSTATUS_INTEGER_DIVIDE_BY_ZERO                                         => Some("STATUS_INTEGER_DIVIDE_BY_ZERO"),
-        SMB_NTSTATUS_INTEGER_OVERFLOW                                               => Some("STATUS_INTEGER_OVERFLOW"),
-        SMB_NTSTATUS_PRIVILEGED_INSTRUCTION                                         => Some("STATUS_PRIVILEGED_INSTRUCTION"),
-        SMB_NTSTATUS_TOO_MANY_PAGING_FILES                                          => Some("STATUS_TOO_MANY_PAGING_FILES"),
-        SMB_NTSTATUS_FILE_INVALID                                                   => Some("STATUS_FILE_INVALID"),
-        SMB_NTSTATUS_ALLOTTED_SPACE_EXCEEDED                                        => Some("STATUS_ALLOTTED_SPACE_EXCEEDED"),
-        SMB_NTSTATUS_INSUFFICIENT_RESOURCES                                         => Some("STATUS_INSUFFICIENT_RESOURCES"),
-        SMB_NTSTATUS_DFS_EXIT_PATH_FOUND                                            => Some("STATUS_DFS_EXIT_PATH_FOUND"),
-        SMB_NTSTATUS_DEVICE_DATA_ERROR                                              => Some("STATUS_DEVICE_DATA_ERROR"),
-        SMB_NTSTATUS_DEVICE_NOT_CONNECTED                                           => Some("STATUS_DEVICE_NOT_CONNECTED"),
-        SMB_NTSTATUS_FREE_VM_NOT_AT_BASE                                            => Some("STATUS_FREE_VM_NOT_AT_BASE"),
-        SMB_NTSTATUS_MEMORY_NOT_ALLOCATED                                           => Some("STATUS_MEMORY_NOT_ALLOCATED"),
-        SMB_NTSTATUS_WORKING_SET_QUOTA                                              => Some("STATUS_WORKING_SET_QUOTA"),
-        SMB_NTSTATUS_MEDIA_WRITE_PROTECTED                                          => Some("STATUS_MEDIA_WRITE_PROTECTED"),
-        SMB_NTSTATUS_DEVICE_NOT_READY                                               => Some("STATUS_DEVICE_NOT_READY"),
-        SMB_NTSTATUS_INVALID_GROUP_ATTRIBUTES                                       => Some("STATUS_INVALID_GROUP_ATTRIBUTES"),
-        SMB_NTSTATUS_BAD_IMPERSONATION_LEVEL                                        => Some("STATUS_BAD_IMPERSONATION_LEVEL"),
-        SMB_NTSTATUS_CANT_OPEN_ANONYMOUS                                            => Some("STATUS_CANT_OPEN_ANONYMOUS"),
-        SMB_NTSTATUS_BAD_VALIDATION_CLASS                                           => Some("STATUS_BAD_VALIDATION_CLASS"),
-        SMB_NTSTATUS_BAD_TOKEN_TYPE                                                 => Some("STATUS_BAD_TOKEN_TYPE"),
-        SMB_NTSTATUS_BAD_MASTER_BOOT_RECORD                                         => Some("STATUS_BAD_MASTER_BOOT_RECORD"),
-        SMB_NTSTATUS_INSTRUCTION_MISALIGNMENT                                       => Some("STATUS_INSTRUCTION_MISALIGNMENT"),
-        SMB_NTSTATUS_INSTANCE_NOT_AVAILABLE                                         => Some("STATUS_INSTANCE_NOT_AVAILABLE"),
-        SMB_NTSTATUS_PIPE_NOT_AVAILABLE                                             => Some("STATUS_PIPE_NOT_AVAILABLE"),
-        SMB_NTSTATUS_INVALID_PIPE_STATE                                             => Some("STATUS_INVALID_PIPE_STATE"),
-        SMB_NTSTATUS_PIPE_BUSY                                                      => Some("STATUS_PIPE_BUSY"),
-        SMB_NTSTATUS_ILLEGAL_FUNCTION                                               => Some("STATUS_ILLEGAL_FUNCTION"),
-        SMB_NTSTATUS_PIPE_DISCONNECTED                                              => Some("STATUS_PIPE_DISCONNECTED"),
-        SMB_NTSTATUS_PIPE_CLOSING                                                   => Some("STATUS_PIPE_CLOSING"),
-        SMB_NTSTATUS_PIPE_CONNECTED                                                 => Some("STATUS_PIPE_CONNECTED"),
-        SMB_NTSTATUS_PIPE_LISTENING                                                 => Some("STATUS_PIPE_LISTENING"),
-        SMB_NTSTATUS_INVALID_READ_MODE                                              => Some("STATUS_INVALID_READ_MODE"),
-        SMB_NTSTATUS_IO_TIMEOUT                                                     => Some("STATUS_IO_TIMEOUT"),
-        SMB_NTSTATUS_FILE_FORCED_CLOSED                                             => Some("STATUS_FILE_FORCED_CLOSED"),
-        SMB_NTSTATUS_PROFILING_NOT_STARTED                                          => Some("STATUS_PROFILING_NOT_STARTED"),
-        SMB_NTSTATUS_PROFILING_NOT_STOPPED                                          => Some("STATUS_PROFILING_NOT_STOPPED"),
-        SMB_NTSTATUS_COULD_NOT_INTERPRET                                            => Some("STATUS_COULD_NOT_INTERPRET"),
-        SMB_NTSTATUS_FILE_IS_A_DIRECTORY                                            => Some("STATUS_FILE_IS_A_DIRECTORY"),
-        SMB_NTSTATUS_NOT_SUPPORTED                                                  => Some("STATUS_NOT_SUPPORTED"),
-        SMB_NTSTATUS_REMOTE_NOT_LISTENING                                           => Some("STATUS_REMOTE_NOT_LISTENING"),
-        SMB_NTSTATUS_DUPLICATE_NAME                                                 => Some("STATUS_DUPLICATE_NAME"),
-        SMB_NTSTATUS_BAD_NETWORK_PATH                                               => Some("STATUS_BAD_NETWORK_PATH"),
-        SMB_NTSTATUS_NETWORK_BUSY                                                   => Some("STATUS_NETWORK_BUSY"),
-        SMB_NTSTATUS_DEVICE_DOES_NOT_EXIST                                          => Some("STATUS_DEVICE_DOES_NOT_EXIST"),
-        SMB_NTSTATUS_TOO_MANY_COMMANDS                                              => Some("STATUS_TOO_MANY_COMMANDS"),
-        SMB_NTSTATUS_ADAPTER_HARDWARE_ERROR                                         => Some("STATUS_ADAPTER_HARDWARE_ERROR"),
-        SMB_NTSTATUS_INVALID_NETWORK_RESPONSE                                       => Some("STATUS_INVALID_NETWORK_RESPONSE"),
-        SMB_NTSTATUS_UNEXPECTED_NETWORK_ERROR                                       => Some("STATUS_UNEXPECTED_NETWORK_ERROR"),
-        SMB_NTSTATUS_BAD_REMOTE_ADAPTER                                             => Some("STATUS_BAD_REMOTE_ADAPTER"),
-        SMB_NTSTATUS_PRINT_QUEUE_FULL                                               => Some("STATUS_PRINT_QUEUE_FULL"),
-        SMB_NTSTATUS_NO_SPOOL_SPACE                                                 => Some("STATUS_NO_SPOOL_SPACE"),
-        SMB_NTSTATUS_PRINT_CANCELLED                                                => Some("STATUS_PRINT_CANCELLED"),
-        SMB_NTSTATUS_NETWORK_NAME_DELETED                                           => Some("STATUS_NETWORK_NAME_DELETED"),
-        SMB_NTSTATUS_NETWORK_ACCESS_DENIED                                          => Some("STATUS_NETWORK_ACCESS_DENIED"),
-        SMB_NTSTATUS_BAD_DEVICE_TYPE                                                => Some("STATUS_BAD_DEVICE_TYPE"),
-        SMB_NTSTATUS_BAD_NETWORK_NAME                                               => Some("STATUS_BAD_NETWORK_NAME"),
-        SMB_NTSTATUS_TOO_MANY_NAMES                                                 => Some("STATUS_TOO_MANY_NAMES"),
-        SMB_NTSTATUS_TOO_MANY_SESSIONS                                              => Some("STATUS_TOO_MANY_SESSIONS"),
-        SMB_NTSTATUS_SHARING_PAUSED                                                 => Some("STATUS_SHARING_PAUSED"),
-        SMB_NTSTATUS_REQUEST_NOT_ACCEPTED                                           => Some("STATUS_REQUEST_NOT_ACCEPTED"),
-        SMB_NTSTATUS_REDIRECTOR_PAUSED                                              => Some("STATUS_REDIRECTOR_PAUSED"),
-        SMB_NTSTATUS_NET_WRITE_FAULT                                                => Some("STATUS_NET_WRITE_FAULT"),
-        SMB_NTSTATUS_PROFILING_AT_LIMIT                                             => Some("STATUS_PROFILING_AT_LIMIT"),
-        SMB_NTSTATUS_NOT_SAME_DEVICE                                                => Some("STATUS_NOT_SAME_DEVICE"),
-        SMB_NTSTATUS_FILE_RENAMED                                                   => Some("STATUS_FILE_RENAMED"),
-        SMB_NTSTATUS_VIRTUAL_CIRCUIT_CLOSED                                         => Some("STATUS_VIRTUAL_CIRCUIT_CLOSED"),
-        SMB_NTSTATUS_NO_SECURITY_ON_OBJECT                                          => Some("STATUS_NO_SECURITY_ON_OBJECT"),
-        SMB_NTSTATUS_CANT_WAIT                                                      => Some("STATUS_CANT_WAIT"),
-        SMB_NTSTATUS_PIPE_EMPTY                                                     => Some("STATUS_PIPE_EMPTY"),
-        SMB_NTSTATUS_CANT_ACCESS_DOMAIN_INFO                                        => Some("STATUS_CANT_ACCESS_DOMAIN_INFO"),
-        SMB_NTSTATUS_CANT_TERMINATE_SELF                                            => Some("STATUS_CANT_TERMINATE_SELF"),
-        SMB_NTSTATUS_INVALID_SERVER_STATE                                           => Some("STATUS_INVALID_SERVER_STATE"),
-        SMB_NTSTATUS_INVALID_DOMAIN_STATE                                           => Some("STATUS_INVALID_DOMAIN_STATE"),
-        SMB_NTSTATUS_INVALID_DOMAIN_ROLE                                            => Some("STATUS_INVALID_DOMAIN_ROLE"),
-        SMB_NTSTATUS_NO_SUCH_DOMAIN                                                 => Some("STATUS_NO_SUCH_DOMAIN"),
-        SMB_NTSTATUS_DOMAIN_EXISTS                                                  => Some("STATUS_DOMAIN_EXISTS"),
-        SMB_NTSTATUS_DOMAIN_LIMIT_EXCEEDED                                          => Some("STATUS_DOMAIN_LIMIT_EXCEEDED"),
-        SMB_NTSTATUS_OPLOCK_NOT_GRANTED                                             => Some("STATUS_OPLOCK_NOT_GRANTED"),
-        SMB_NTSTATUS_INVALID_OPLOCK_PROTOCOL                                        => Some("STATUS_INVALID_OPLOCK_PROTOCOL"),
-        SMB_NTSTATUS_INTERNAL_DB_CORRUPTION                                         => Some("STATUS_INTERNAL_DB_CORRUPTION"),
-        SMB_NTSTATUS_INTERNAL_ERROR                                                 => Some("STATUS_INTERNAL_ERROR"),
-        SMB_NTSTATUS_GENERIC_NOT_MAPPED                                             => Some("STATUS_GENERIC_NOT_MAPPED"),
-        SMB_NTSTATUS_BAD_DESCRIPTOR_FORMAT                                          => Some("STATUS_BAD_DESCRIPTOR_FORMAT"),
-        SMB_NTSTATUS_INVALID_USER_BUFFER                                            => Some("STATUS_INVALID_USER_BUFFER"),
-        SMB_NTSTATUS_UNEXPECTED_IO_ERROR                                            => Some("STATUS_UNEXPECTED_IO_ERROR"),
-        SMB_NTSTATUS_UNEXPECTED_MM_CREATE_ERR                                       => Some("STATUS_UNEXPECTED_MM_CREATE_ERR"),
-        SMB_NTSTATUS_UNEXPECTED_MM_MAP_ERROR                                        => Some("STATUS_UNEXPECTED_MM_MAP_ERROR"),
-        SMB_NTSTATUS_UNEXPECTED_MM_EXTEND_ERR                                       => Some("STATUS_UNEXPECTED_MM_EXTEND_ERR"),
-        SMB_NTSTATUS_NOT_LOGON_PROCESS                                              => Some("STATUS_NOT_LOGON_PROCESS"),
-        SMB_NTSTATUS_LOGON_SESSION_EXISTS                                           => Some("STATUS_LOGON_SESSION_EXISTS"),
-        SMB_NTSTATUS_INVALID_PARAMETER_1                                            => Some("STATUS_INVALID_PARAMETER_1"),
-        SMB_NTSTATUS_INVALID_PARAMETER_2                                            => Some("STATUS_INVALID_PARAMETER_2"),
-        SMB_NTSTATUS_INVALID_PARAMETER_3                                            => Some("STATUS_INVALID_PARAMETER_3"),
-        SMB_NTSTATUS_INVALID_PARAMETER_4                                            => Some("STATUS_INVALID_PARAMETER_4"),
-        SMB_NTSTATUS_INVALID_PARAMETER_5                                            => Some("STATUS_INVALID_PARAMETER_5"),
-        SMB_NTSTATUS_INVALID_PARAMETER_6                                            => Some("STATUS_INVALID_PARAMETER_6"),
-        SMB_NTSTATUS_INVALID_PARAMETER_7                                            => Some("STATUS_INVALID_PARAMETER_7"),
-        SMB_NTSTATUS_INVALID_PARAMETER_8                                            => Some("STATUS_INVALID_PARAMETER_8"),
-        SMB_NTSTATUS_INVALID_PARAMETER_9                                            => Some("STATUS_INVALID_PARAMETER_9"),
-        SMB_NTSTATUS_INVALID_PARAMETER_10                                           => Some("STATUS_INVALID_PARAMETER_10"),
-        SMB_NTSTATUS_INVALID_PARAMETER_11                                           => Some("STATUS_INVALID_PARAMETER_11"),
-        SMB_NTSTATUS_INVALID_PARAMETER_12                                           => Some("STATUS_INVALID_PARAMETER_12"),
-        SMB_NTSTATUS_REDIRECTOR_NOT_STARTED                                         => Some("STATUS_REDIRECTOR_NOT_STARTED"),
-        SMB_NTSTATUS_REDIRECTOR_STARTED                                             => Some("STATUS_REDIRECTOR_STARTED"),
-        SMB_NTSTATUS_STACK_OVERFLOW                                                 => Some("STATUS_STACK_OVERFLOW"),
-        SMB_NTSTATUS_NO_SUCH_PACKAGE                                                => Some("STATUS_NO_SUCH_PACKAGE"),
-        SMB_NTSTATUS_BAD_FUNCTION_TABLE                                             => Some("STATUS_BAD_FUNCTION_TABLE"),
-        SMB_NTSTATUS_VARIABLE_NOT_FOUND                                             => Some("STATUS_VARIABLE_NOT_FOUND"),
-        SMB_NTSTATUS_DIRECTORY_NOT_EMPTY                                            => Some("STATUS_DIRECTORY_NOT_EMPTY"),
-        SMB_NTSTATUS_FILE_CORRUPT_ERROR                                             => Some("STATUS_FILE_CORRUPT_ERROR"),
-        SMB_NTSTATUS_NOT_A_DIRECTORY                                                => Some("STATUS_NOT_A_DIRECTORY"),
-        SMB_NTSTATUS_BAD_LOGON_SESSION_STATE                                        => Some("STATUS_BAD_LOGON_SESSION_STATE"),
-        SMB_NTSTATUS_LOGON_SESSION_COLLISION                                        => Some("STATUS_LOGON_SESSION_COLLISION"),
-        SMB_NTSTATUS_NAME_TOO_LONG                                                  => Some("STATUS_NAME_TOO_LONG"),
-        SMB_NTSTATUS_FILES_OPEN                                                     => Some("STATUS_FILES_OPEN"),
-        SMB_NTSTATUS_CONNECTION_IN_USE                                              => Some("STATUS_CONNECTION_IN_USE"),
-        SMB_NTSTATUS_MESSAGE_NOT_FOUND                                              => Some("STATUS_MESSAGE_NOT_FOUND"),
-        SMB_NTSTATUS_PROCESS_IS_TERMINATING                                         => Some("STATUS_PROCESS_IS_TERMINATING"),
-        SMB_NTSTATUS_INVALID_LOGON_TYPE                                             => Some("STATUS_INVALID_LOGON_TYPE"),
-        SMB_NTSTATUS_NO_GUID_TRANSLATION                                            => Some("STATUS_NO_GUID_TRANSLATION"),
-        SMB_NTSTATUS_CANNOT_IMPERSONATE                                             => Some("STATUS_CANNOT_IMPERSONATE"),
-        SMB_NTSTATUS_IMAGE_ALREADY_LOADED                                           => Some("STATUS_IMAGE_ALREADY_LOADED"),
-        SMB_NTSTATUS_NO_LDT                                                         => Some("STATUS_NO_LDT"),
-        SMB_NTSTATUS_INVALID_LDT_SIZE                                               => Some("STATUS_INVALID_LDT_SIZE"),
-        SMB_NTSTATUS_INVALID_LDT_OFFSET                                             => Some("STATUS_INVALID_LDT_OFFSET"),
-        SMB_NTSTATUS_INVALID_LDT_DESCRIPTOR                                         => Some("STATUS_INVALID_LDT_DESCRIPTOR"),
-        SMB_NTSTATUS_INVALID_IMAGE_NE_FORMAT                                        => Some("STATUS_INVALID_IMAGE_NE_FORMAT"),
-        SMB_NTSTATUS_RXACT_INVALID_STATE                                            => Some("STATUS_RXACT_INVALID_STATE"),
-        SMB_NTSTATUS_RXACT_COMMIT_FAILURE                                           => Some("STATUS_RXACT_COMMIT_FAILURE"),
-        SMB_NTSTATUS_MAPPED_FILE_SIZE_ZERO                                          => Some("STATUS_MAPPED_FILE_SIZE_ZERO"),
-        SMB_NTSTATUS_TOO_MANY_OPENED_FILES                                          => Some("STATUS_TOO_MANY_OPENED_FILES"),
-        SMB_NTSTATUS_CANCELLED                                                      => Some("STATUS_CANCELLED"),
-        SMB_NTSTATUS_CANNOT_DELETE                                                  => Some("STATUS_CANNOT_DELETE"),
-        SMB_NTSTATUS_INVALID_COMPUTER_NAME                                          => Some("STATUS_INVALID_COMPUTER_NAME"),
-        SMB_NTSTATUS_FILE_DELETED                                                   => Some("STATUS_FILE_DELETED"),
-        SMB_NTSTATUS_SPECIAL_ACCOUNT                                                => Some("STATUS_SPECIAL_ACCOUNT"),
-        SMB_NTSTATUS_SPECIAL_GROUP                                                  => Some("STATUS_SPECIAL_GROUP"),
-        SMB_NTSTATUS_SPECIAL_USER                                                   => Some("STATUS_SPECIAL_USER"),
-        SMB_NTSTATUS_MEMBERS_PRIMARY_GROUP                                          => Some("STATUS_MEMBERS_PRIMARY_GROUP"),
-        SMB_NTSTATUS_FILE_CLOSED                                                    => Some("STATUS_FILE_CLOSED"),
-        SMB_NTSTATUS_TOO_MANY_THREADS                                               => Some("STATUS_TOO_MANY_THREADS"),
-        SMB_NTSTATUS_THREAD_NOT_IN_PROCESS                                          => Some("STATUS_THREAD_NOT_IN_PROCESS"),
-        SMB_NTSTATUS_TOKEN_ALREADY_IN_USE                                           => Some("STATUS_TOKEN_ALREADY_IN_USE"),
-        SMB_NTSTATUS_PAGEFILE_QUOTA_EXCEEDED                                        => Some("STATUS_PAGEFILE_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_COMMITMENT_LIMIT                                               => Some("STATUS_COMMITMENT_LIMIT"),
-        SMB_NTSTATUS_INVALID_IMAGE_LE_FORMAT                                        => Some("STATUS_INVALID_IMAGE_LE_FORMAT"),
-        SMB_NTSTATUS_INVALID_IMAGE_NOT_MZ                                           => Some("STATUS_INVALID_IMAGE_NOT_MZ"),
-        SMB_NTSTATUS_INVALID_IMAGE_PROTECT                                          => Some("STATUS_INVALID_IMAGE_PROTECT"),
-        SMB_NTSTATUS_INVALID_IMAGE_WIN_16                                           => Some("STATUS_INVALID_IMAGE_WIN_16"),
-        SMB_NTSTATUS_LOGON_SERVER_CONFLICT                                          => Some("STATUS_LOGON_SERVER_CONFLICT"),
-        SMB_NTSTATUS_TIME_DIFFERENCE_AT_DC                                          => Some("STATUS_TIME_DIFFERENCE_AT_DC"),
-        SMB_NTSTATUS_SYNCHRONIZATION_REQUIRED                                       => Some("STATUS_SYNCHRONIZATION_REQUIRED"),
-        SMB_NTSTATUS_DLL_NOT_FOUND                                                  => Some("STATUS_DLL_NOT_FOUND"),
-        SMB_NTSTATUS_OPEN_FAILED                                                    => Some("STATUS_OPEN_FAILED"),
-        SMB_NTSTATUS_IO_PRIVILEGE_FAILED                                            => Some("STATUS_IO_PRIVILEGE_FAILED"),
-        SMB_NTSTATUS_ORDINAL_NOT_FOUND                                              => Some("STATUS_ORDINAL_NOT_FOUND"),
-        SMB_NTSTATUS_ENTRYPOINT_NOT_FOUND                                           => Some("STATUS_ENTRYPOINT_NOT_FOUND"),
-        SMB_NTSTATUS_CONTROL_C_EXIT                                                 => Some("STATUS_CONTROL_C_EXIT"),
-        SMB_NTSTATUS_LOCAL_DISCONNECT                                               => Some("STATUS_LOCAL_DISCONNECT"),
-        SMB_NTSTATUS_REMOTE_DISCONNECT                                              => Some("STATUS_REMOTE_DISCONNECT"),
-        SMB_NTSTATUS_REMOTE_RESOURCES                                               => Some("STATUS_REMOTE_RESOURCES"),
-        SMB_NTSTATUS_LINK_FAILED                                                    => Some("STATUS_LINK_FAILED"),
-        SMB_NTSTATUS_LINK_TIMEOUT                                                   => Some("STATUS_LINK_TIMEOUT"),
-        SMB_NTSTATUS_INVALID_CONNECTION                                             => Some("STATUS_INVALID_CONNECTION"),
-        SMB_NTSTATUS_INVALID_ADDRESS                                                => Some("STATUS_INVALID_ADDRESS"),
-        SMB_NTSTATUS_DLL_INIT_FAILED                                                => Some("STATUS_DLL_INIT_FAILED"),
-        SMB_NTSTATUS_MISSING_SYSTEMFILE                                             => Some("STATUS_MISSING_SYSTEMFILE"),
-        SMB_NTSTATUS_UNHANDLED_EXCEPTION                                            => Some("STATUS_UNHANDLED_EXCEPTION"),
-        SMB_NTSTATUS_APP_INIT_FAILURE                                               => Some("STATUS_APP_INIT_FAILURE"),
-        SMB_NTSTATUS_PAGEFILE_CREATE_FAILED                                         => Some("STATUS_PAGEFILE_CREATE_FAILED"),
-        SMB_NTSTATUS_NO_PAGEFILE                                                    => Some("STATUS_NO_PAGEFILE"),
-        SMB_NTSTATUS_INVALID_LEVEL                                                  => Some("STATUS_INVALID_LEVEL"),
-        SMB_NTSTATUS_WRONG_PASSWORD_CORE                                            => Some("STATUS_WRONG_PASSWORD_CORE"),
-        SMB_NTSTATUS_ILLEGAL_FLOAT_CONTEXT                                          => Some("STATUS_ILLEGAL_FLOAT_CONTEXT"),
-        SMB_NTSTATUS_PIPE_BROKEN                                                    => Some("STATUS_PIPE_BROKEN"),
-        SMB_NTSTATUS_REGISTRY_CORRUPT                                               => Some("STATUS_REGISTRY_CORRUPT"),
-        SMB_NTSTATUS_REGISTRY_IO_FAILED                                             => Some("STATUS_REGISTRY_IO_FAILED"),
-        SMB_NTSTATUS_NO_EVENT_PAIR                                                  => Some("STATUS_NO_EVENT_PAIR"),
-        SMB_NTSTATUS_UNRECOGNIZED_VOLUME                                            => Some("STATUS_UNRECOGNIZED_VOLUME"),
-        SMB_NTSTATUS_SERIAL_NO_DEVICE_INITED                                        => Some("STATUS_SERIAL_NO_DEVICE_INITED"),
-        SMB_NTSTATUS_NO_SUCH_ALIAS                                                  => Some("STATUS_NO_SUCH_ALIAS"),
-        SMB_NTSTATUS_MEMBER_NOT_IN_ALIAS                                            => Some("STATUS_MEMBER_NOT_IN_ALIAS"),
-        SMB_NTSTATUS_MEMBER_IN_ALIAS                                                => Some("STATUS_MEMBER_IN_ALIAS"),
-        SMB_NTSTATUS_ALIAS_EXISTS                                                   => Some("STATUS_ALIAS_EXISTS"),
-        SMB_NTSTATUS_LOGON_NOT_GRANTED                                              => Some("STATUS_LOGON_NOT_GRANTED"),
-        SMB_NTSTATUS_TOO_MANY_SECRETS                                               => Some("STATUS_TOO_MANY_SECRETS"),
-        SMB_NTSTATUS_SECRET_TOO_LONG                                                => Some("STATUS_SECRET_TOO_LONG"),
-        SMB_NTSTATUS_INTERNAL_DB_ERROR                                              => Some("STATUS_INTERNAL_DB_ERROR"),
-        SMB_NTSTATUS_FULLSCREEN_MODE                                                => Some("STATUS_FULLSCREEN_MODE"),
-        SMB_NTSTATUS_TOO_MANY_CONTEXT_IDS                                           => Some("STATUS_TOO_MANY_CONTEXT_IDS"),
-        SMB_NTSTATUS_LOGON_TYPE_NOT_GRANTED                                         => Some("STATUS_LOGON_TYPE_NOT_GRANTED"),
-        SMB_NTSTATUS_NOT_REGISTRY_FILE                                              => Some("STATUS_NOT_REGISTRY_FILE"),
-        SMB_NTSTATUS_NT_CROSS_ENCRYPTION_REQUIRED                                   => Some("STATUS_NT_CROSS_ENCRYPTION_REQUIRED"),
-        SMB_NTSTATUS_DOMAIN_CTRLR_CONFIG_ERROR                                      => Some("STATUS_DOMAIN_CTRLR_CONFIG_ERROR"),
-        SMB_NTSTATUS_FT_MISSING_MEMBER                                              => Some("STATUS_FT_MISSING_MEMBER"),
-        SMB_NTSTATUS_ILL_FORMED_SERVICE_ENTRY                                       => Some("STATUS_ILL_FORMED_SERVICE_ENTRY"),
-        SMB_NTSTATUS_ILLEGAL_CHARACTER                                              => Some("STATUS_ILLEGAL_CHARACTER"),
-        SMB_NTSTATUS_UNMAPPABLE_CHARACTER                                           => Some("STATUS_UNMAPPABLE_CHARACTER"),
-        SMB_NTSTATUS_UNDEFINED_CHARACTER                                            => Some("STATUS_UNDEFINED_CHARACTER"),
-        SMB_NTSTATUS_FLOPPY_VOLUME                                                  => Some("STATUS_FLOPPY_VOLUME"),
-        SMB_NTSTATUS_FLOPPY_ID_MARK_NOT_FOUND                                       => Some("STATUS_FLOPPY_ID_MARK_NOT_FOUND"),
-        SMB_NTSTATUS_FLOPPY_WRONG_CYLINDER                                          => Some("STATUS_FLOPPY_WRONG_CYLINDER"),
-        SMB_NTSTATUS_FLOPPY_UNKNOWN_ERROR                                           => Some("STATUS_FLOPPY_UNKNOWN_ERROR"),
-        SMB_NTSTATUS_FLOPPY_BAD_REGISTERS                                           => Some("STATUS_FLOPPY_BAD_REGISTERS"),
-        SMB_NTSTATUS_DISK_RECALIBRATE_FAILED                                        => Some("STATUS_DISK_RECALIBRATE_FAILED"),
-        SMB_NTSTATUS_DISK_OPERATION_FAILED                                          => Some("STATUS_DISK_OPERATION_FAILED"),
-        SMB_NTSTATUS_DISK_RESET_FAILED                                              => Some("STATUS_DISK_RESET_FAILED"),
-        SMB_NTSTATUS_SHARED_IRQ_BUSY                                                => Some("STATUS_SHARED_IRQ_BUSY"),
-        SMB_NTSTATUS_FT_ORPHANING                                                   => Some("STATUS_FT_ORPHANING"),
-        SMB_NTSTATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT                               => Some("STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT"),
-        SMB_NTSTATUS_PARTITION_FAILURE                                              => Some("STATUS_PARTITION_FAILURE"),
-        SMB_NTSTATUS_INVALID_BLOCK_LENGTH                                           => Some("STATUS_INVALID_BLOCK_LENGTH"),
-        SMB_NTSTATUS_DEVICE_NOT_PARTITIONED                                         => Some("STATUS_DEVICE_NOT_PARTITIONED"),
-        SMB_NTSTATUS_UNABLE_TO_LOCK_MEDIA                                           => Some("STATUS_UNABLE_TO_LOCK_MEDIA"),
-        SMB_NTSTATUS_UNABLE_TO_UNLOAD_MEDIA                                         => Some("STATUS_UNABLE_TO_UNLOAD_MEDIA"),
-        SMB_NTSTATUS_EOM_OVERFLOW                                                   => Some("STATUS_EOM_OVERFLOW"),
-        SMB_NTSTATUS_NO_MEDIA                                                       => Some("STATUS_NO_MEDIA"),
-        SMB_NTSTATUS_NO_SUCH_MEMBER                                                 => Some("STATUS_NO_SUCH_MEMBER"),
-        SMB_NTSTATUS_INVALID_MEMBER                                                 => Some("STATUS_INVALID_MEMBER"),
-        SMB_NTSTATUS_KEY_DELETED                                                    => Some("STATUS_KEY_DELETED"),
-        SMB_NTSTATUS_NO_LOG_SPACE                                                   => Some("STATUS_NO_LOG_SPACE"),
-        SMB_NTSTATUS_TOO_MANY_SIDS                                                  => Some("STATUS_TOO_MANY_SIDS"),
-        SMB_NTSTATUS_LM_CROSS_ENCRYPTION_REQUIRED                                   => Some("STATUS_LM_CROSS_ENCRYPTION_REQUIRED"),
-        SMB_NTSTATUS_KEY_HAS_CHILDREN                                               => Some("STATUS_KEY_HAS_CHILDREN"),
-        SMB_NTSTATUS_CHILD_MUST_BE_VOLATILE                                         => Some("STATUS_CHILD_MUST_BE_VOLATILE"),
-        SMB_NTSTATUS_DEVICE_CONFIGURATION_ERROR                                     => Some("STATUS_DEVICE_CONFIGURATION_ERROR"),
-        SMB_NTSTATUS_DRIVER_INTERNAL_ERROR                                          => Some("STATUS_DRIVER_INTERNAL_ERROR"),
-        SMB_NTSTATUS_INVALID_DEVICE_STATE                                           => Some("STATUS_INVALID_DEVICE_STATE"),
-        SMB_NTSTATUS_IO_DEVICE_ERROR                                                => Some("STATUS_IO_DEVICE_ERROR"),
-        SMB_NTSTATUS_DEVICE_PROTOCOL_ERROR                                          => Some("STATUS_DEVICE_PROTOCOL_ERROR"),
-        SMB_NTSTATUS_BACKUP_CONTROLLER                                              => Some("STATUS_BACKUP_CONTROLLER"),
-        SMB_NTSTATUS_LOG_FILE_FULL                                                  => Some("STATUS_LOG_FILE_FULL"),
-        SMB_NTSTATUS_TOO_LATE                                                       => Some("STATUS_TOO_LATE"),
-        SMB_NTSTATUS_NO_TRUST_LSA_SECRET                                            => Some("STATUS_NO_TRUST_LSA_SECRET"),
-        SMB_NTSTATUS_NO_TRUST_SAM_ACCOUNT                                           => Some("STATUS_NO_TRUST_SAM_ACCOUNT"),
-        SMB_NTSTATUS_TRUSTED_DOMAIN_FAILURE                                         => Some("STATUS_TRUSTED_DOMAIN_FAILURE"),
-        SMB_NTSTATUS_TRUSTED_RELATIONSHIP_FAILURE                                   => Some("STATUS_TRUSTED_RELATIONSHIP_FAILURE"),
-        SMB_NTSTATUS_EVENTLOG_FILE_CORRUPT                                          => Some("STATUS_EVENTLOG_FILE_CORRUPT"),
-        SMB_NTSTATUS_EVENTLOG_CANT_START                                            => Some("STATUS_EVENTLOG_CANT_START"),
-        SMB_NTSTATUS_TRUST_FAILURE                                                  => Some("STATUS_TRUST_FAILURE"),
-        SMB_NTSTATUS_MUTANT_LIMIT_EXCEEDED                                          => Some("STATUS_MUTANT_LIMIT_EXCEEDED"),
-        SMB_NTSTATUS_NETLOGON_NOT_STARTED                                           => Some("STATUS_NETLOGON_NOT_STARTED"),
-        SMB_NTSTATUS_ACCOUNT_EXPIRED                                                => Some("STATUS_ACCOUNT_EXPIRED"),
-        SMB_NTSTATUS_POSSIBLE_DEADLOCK                                              => Some("STATUS_POSSIBLE_DEADLOCK"),
-        SMB_NTSTATUS_NETWORK_CREDENTIAL_CONFLICT                                    => Some("STATUS_NETWORK_CREDENTIAL_CONFLICT"),
-        SMB_NTSTATUS_REMOTE_SESSION_LIMIT                                           => Some("STATUS_REMOTE_SESSION_LIMIT"),
-        SMB_NTSTATUS_EVENTLOG_FILE_CHANGED                                          => Some("STATUS_EVENTLOG_FILE_CHANGED"),
-        SMB_NTSTATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT                              => Some("STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT"),
-        SMB_NTSTATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT                              => Some("STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT"),
-        SMB_NTSTATUS_NOLOGON_SERVER_TRUST_ACCOUNT                                   => Some("STATUS_NOLOGON_SERVER_TRUST_ACCOUNT"),
-        SMB_NTSTATUS_DOMAIN_TRUST_INCONSISTENT                                      => Some("STATUS_DOMAIN_TRUST_INCONSISTENT"),
-        SMB_NTSTATUS_FS_DRIVER_REQUIRED                                             => Some("STATUS_FS_DRIVER_REQUIRED"),
-        SMB_NTSTATUS_IMAGE_ALREADY_LOADED_AS_DLL                                    => Some("STATUS_IMAGE_ALREADY_LOADED_AS_DLL"),
-        SMB_NTSTATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING           => Some("STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING"),
-        SMB_NTSTATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME                              => Some("STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME"),
-        SMB_NTSTATUS_SECURITY_STREAM_IS_INCONSISTENT                                => Some("STATUS_SECURITY_STREAM_IS_INCONSISTENT"),
-        SMB_NTSTATUS_INVALID_LOCK_RANGE                                             => Some("STATUS_INVALID_LOCK_RANGE"),
-        SMB_NTSTATUS_INVALID_ACE_CONDITION                                          => Some("STATUS_INVALID_ACE_CONDITION"),
-        SMB_NTSTATUS_IMAGE_SUBSYSTEM_NOT_PRESENT                                    => Some("STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT"),
-        SMB_NTSTATUS_NOTIFICATION_GUID_ALREADY_DEFINED                              => Some("STATUS_NOTIFICATION_GUID_ALREADY_DEFINED"),
-        SMB_NTSTATUS_NETWORK_OPEN_RESTRICTION                                       => Some("STATUS_NETWORK_OPEN_RESTRICTION"),
-        SMB_NTSTATUS_NO_USER_SESSION_KEY                                            => Some("STATUS_NO_USER_SESSION_KEY"),
-        SMB_NTSTATUS_USER_SESSION_DELETED                                           => Some("STATUS_USER_SESSION_DELETED"),
-        SMB_NTSTATUS_RESOURCE_LANG_NOT_FOUND                                        => Some("STATUS_RESOURCE_LANG_NOT_FOUND"),
-        SMB_NTSTATUS_INSUFF_SERVER_RESOURCES                                        => Some("STATUS_INSUFF_SERVER_RESOURCES"),
-        SMB_NTSTATUS_INVALID_BUFFER_SIZE                                            => Some("STATUS_INVALID_BUFFER_SIZE"),
-        SMB_NTSTATUS_INVALID_ADDRESS_COMPONENT                                      => Some("STATUS_INVALID_ADDRESS_COMPONENT"),
-        SMB_NTSTATUS_INVALID_ADDRESS_WILDCARD                                       => Some("STATUS_INVALID_ADDRESS_WILDCARD"),
-        SMB_NTSTATUS_TOO_MANY_ADDRESSES                                             => Some("STATUS_TOO_MANY_ADDRESSES"),
-        SMB_NTSTATUS_ADDRESS_ALREADY_EXISTS                                         => Some("STATUS_ADDRESS_ALREADY_EXISTS"),
-        SMB_NTSTATUS_ADDRESS_CLOSED                                                 => Some("STATUS_ADDRESS_CLOSED"),
-        SMB_NTSTATUS_CONNECTION_DISCONNECTED                                        => Some("STATUS_CONNECTION_DISCONNECTED"),
-        SMB_NTSTATUS_CONNECTION_RESET                                               => Some("STATUS_CONNECTION_RESET"),
-        SMB_NTSTATUS_TOO_MANY_NODES                                                 => Some("STATUS_TOO_MANY_NODES"),
-        SMB_NTSTATUS_TRANSACTION_ABORTED                                            => Some("STATUS_TRANSACTION_ABORTED"),
-        SMB_NTSTATUS_TRANSACTION_TIMED_OUT                                          => Some("STATUS_TRANSACTION_TIMED_OUT"),
-        SMB_NTSTATUS_TRANSACTION_NO_RELEASE                                         => Some("STATUS_TRANSACTION_NO_RELEASE"),
-        SMB_NTSTATUS_TRANSACTION_NO_MATCH                                           => Some("STATUS_TRANSACTION_NO_MATCH"),
-        SMB_NTSTATUS_TRANSACTION_RESPONDED                                          => Some("STATUS_TRANSACTION_RESPONDED"),
-        SMB_NTSTATUS_TRANSACTION_INVALID_ID                                         => Some("STATUS_TRANSACTION_INVALID_ID"),
-        SMB_NTSTATUS_TRANSACTION_INVALID_TYPE                                       => Some("STATUS_TRANSACTION_INVALID_TYPE"),
-        SMB_NTSTATUS_NOT_SERVER_SESSION                                             => Some("STATUS_NOT_SERVER_SESSION"),
-        SMB_NTSTATUS_NOT_CLIENT_SESSION                                             => Some("STATUS_NOT_CLIENT_SESSION"),
-        SMB_NTSTATUS_CANNOT_LOAD_REGISTRY_FILE                                      => Some("STATUS_CANNOT_LOAD_REGISTRY_FILE"),
-        SMB_NTSTATUS_DEBUG_ATTACH_FAILED                                            => Some("STATUS_DEBUG_ATTACH_FAILED"),
-        SMB_NTSTATUS_SYSTEM_PROCESS_TERMINATED                                      => Some("STATUS_SYSTEM_PROCESS_TERMINATED"),
-        SMB_NTSTATUS_DATA_NOT_ACCEPTED                                              => Some("STATUS_DATA_NOT_ACCEPTED"),
-        SMB_NTSTATUS_NO_BROWSER_SERVERS_FOUND                                       => Some("STATUS_NO_BROWSER_SERVERS_FOUND"),
-        SMB_NTSTATUS_VDM_HARD_ERROR                                                 => Some("STATUS_VDM_HARD_ERROR"),
-        SMB_NTSTATUS_DRIVER_CANCEL_TIMEOUT                                          => Some("STATUS_DRIVER_CANCEL_TIMEOUT"),
-        SMB_NTSTATUS_REPLY_MESSAGE_MISMATCH                                         => Some("STATUS_REPLY_MESSAGE_MISMATCH"),
-        SMB_NTSTATUS_MAPPED_ALIGNMENT                                               => Some("STATUS_MAPPED_ALIGNMENT"),
-        SMB_NTSTATUS_IMAGE_CHECKSUM_MISMATCH                                        => Some("STATUS_IMAGE_CHECKSUM_MISMATCH"),
-        SMB_NTSTATUS_LOST_WRITEBEHIND_DATA                                          => Some("STATUS_LOST_WRITEBEHIND_DATA"),
-        SMB_NTSTATUS_CLIENT_SERVER_PARAMETERS_INVALID                               => Some("STATUS_CLIENT_SERVER_PARAMETERS_INVALID"),
-        SMB_NTSTATUS_PASSWORD_MUST_CHANGE                                           => Some("STATUS_PASSWORD_MUST_CHANGE"),
-        SMB_NTSTATUS_NOT_FOUND                                                      => Some("STATUS_NOT_FOUND"),
-        SMB_NTSTATUS_NOT_TINY_STREAM                                                => Some("STATUS_NOT_TINY_STREAM"),
-        SMB_NTSTATUS_RECOVERY_FAILURE                                               => Some("STATUS_RECOVERY_FAILURE"),
-        SMB_NTSTATUS_STACK_OVERFLOW_READ                                            => Some("STATUS_STACK_OVERFLOW_READ"),
-        SMB_NTSTATUS_FAIL_CHECK                                                     => Some("STATUS_FAIL_CHECK"),
-        SMB_NTSTATUS_DUPLICATE_OBJECTID                                             => Some("STATUS_DUPLICATE_OBJECTID"),
-        SMB_NTSTATUS_OBJECTID_EXISTS                                                => Some("STATUS_OBJECTID_EXISTS"),
-        SMB_NTSTATUS_CONVERT_TO_LARGE                                               => Some("STATUS_CONVERT_TO_LARGE"),
-        SMB_NTSTATUS_RETRY                                                          => Some("STATUS_RETRY"),
-        SMB_NTSTATUS_FOUND_OUT_OF_SCOPE                                             => Some("STATUS_FOUND_OUT_OF_SCOPE"),
-        SMB_NTSTATUS_ALLOCATE_BUCKET                                                => Some("STATUS_ALLOCATE_BUCKET"),
-        SMB_NTSTATUS_PROPSET_NOT_FOUND                                              => Some("STATUS_PROPSET_NOT_FOUND"),
-        SMB_NTSTATUS_MARSHALL_OVERFLOW                                              => Some("STATUS_MARSHALL_OVERFLOW"),
-        SMB_NTSTATUS_INVALID_VARIANT                                                => Some("STATUS_INVALID_VARIANT"),
-        SMB_NTSTATUS_DOMAIN_CONTROLLER_NOT_FOUND                                    => Some("STATUS_DOMAIN_CONTROLLER_NOT_FOUND"),
-        SMB_NTSTATUS_ACCOUNT_LOCKED_OUT                                             => Some("STATUS_ACCOUNT_LOCKED_OUT"),
-        SMB_NTSTATUS_HANDLE_NOT_CLOSABLE                                            => Some("STATUS_HANDLE_NOT_CLOSABLE"),
-        SMB_NTSTATUS_CONNECTION_REFUSED                                             => Some("STATUS_CONNECTION_REFUSED"),
-        SMB_NTSTATUS_GRACEFUL_DISCONNECT                                            => Some("STATUS_GRACEFUL_DISCONNECT"),
-        SMB_NTSTATUS_ADDRESS_ALREADY_ASSOCIATED                                     => Some("STATUS_ADDRESS_ALREADY_ASSOCIATED"),
-        SMB_NTSTATUS_ADDRESS_NOT_ASSOCIATED                                         => Some("STATUS_ADDRESS_NOT_ASSOCIATED"),
-        SMB_NTSTATUS_CONNECTION_INVALID                                             => Some("STATUS_CONNECTION_INVALID"),
-        SMB_NTSTATUS_CONNECTION_ACTIVE                                              => Some("STATUS_CONNECTION_ACTIVE"),
-        SMB_NTSTATUS_NETWORK_UNREACHABLE                                            => Some("STATUS_NETWORK_UNREACHABLE"),
-        SMB_NTSTATUS_HOST_UNREACHABLE                                               => Some("STATUS_HOST_UNREACHABLE"),
-        SMB_NTSTATUS_PROTOCOL_UNREACHABLE                                           => Some("STATUS_PROTOCOL_UNREACHABLE"),
-        SMB_NTSTATUS_PORT_UNREACHABLE                                               => Some("STATUS_PORT_UNREACHABLE"),
-        SMB_NTSTATUS_REQUEST_ABORTED                                                => Some("STATUS_REQUEST_ABORTED"),
-        SMB_NTSTATUS_CONNECTION_ABORTED                                             => Some("STATUS_CONNECTION_ABORTED"),
-        SMB_NTSTATUS_BAD_COMPRESSION_BUFFER                                         => Some("STATUS_BAD_COMPRESSION_BUFFER"),
-        SMB_NTSTATUS_USER_MAPPED_FILE                                               => Some("STATUS_USER_MAPPED_FILE"),
-        SMB_NTSTATUS_AUDIT_FAILED                                                   => Some("STATUS_AUDIT_FAILED"),
-        SMB_NTSTATUS_TIMER_RESOLUTION_NOT_SET                                       => Some("STATUS_TIMER_RESOLUTION_NOT_SET"),
-        SMB_NTSTATUS_CONNECTION_COUNT_LIMIT                                         => Some("STATUS_CONNECTION_COUNT_LIMIT"),
-        SMB_NTSTATUS_LOGIN_TIME_RESTRICTION                                         => Some("STATUS_LOGIN_TIME_RESTRICTION"),
-        SMB_NTSTATUS_LOGIN_WKSTA_RESTRICTION                                        => Some("STATUS_LOGIN_WKSTA_RESTRICTION"),
-        SMB_NTSTATUS_IMAGE_MP_UP_MISMATCH                                           => Some("STATUS_IMAGE_MP_UP_MISMATCH"),
-        SMB_NTSTATUS_INSUFFICIENT_LOGON_INFO                                        => Some("STATUS_INSUFFICIENT_LOGON_INFO"),
-        SMB_NTSTATUS_BAD_DLL_ENTRYPOINT                                             => Some("STATUS_BAD_DLL_ENTRYPOINT"),
-        SMB_NTSTATUS_BAD_SERVICE_ENTRYPOINT                                         => Some("STATUS_BAD_SERVICE_ENTRYPOINT"),
-        SMB_NTSTATUS_LPC_REPLY_LOST                                                 => Some("STATUS_LPC_REPLY_LOST"),
-        SMB_NTSTATUS_IP_ADDRESS_CONFLICT1                                           => Some("STATUS_IP_ADDRESS_CONFLICT1"),
-        SMB_NTSTATUS_IP_ADDRESS_CONFLICT2                                           => Some("STATUS_IP_ADDRESS_CONFLICT2"),
-        SMB_NTSTATUS_REGISTRY_QUOTA_LIMIT                                           => Some("STATUS_REGISTRY_QUOTA_LIMIT"),
-        SMB_NTSTATUS_PATH_NOT_COVERED                                               => Some("STATUS_PATH_NOT_COVERED"),
-        SMB_NTSTATUS_NO_CALLBACK_ACTIVE                                             => Some("STATUS_NO_CALLBACK_ACTIVE"),
-        SMB_NTSTATUS_LICENSE_QUOTA_EXCEEDED                                         => Some("STATUS_LICENSE_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_PWD_TOO_SHORT                                                  => Some("STATUS_PWD_TOO_SHORT"),
-        SMB_NTSTATUS_PWD_TOO_RECENT                                                 => Some("STATUS_PWD_TOO_RECENT"),
-        SMB_NTSTATUS_PWD_HISTORY_CONFLICT                                           => Some("STATUS_PWD_HISTORY_CONFLICT"),
-        SMB_NTSTATUS_PLUGPLAY_NO_DEVICE                                             => Some("STATUS_PLUGPLAY_NO_DEVICE"),
-        SMB_NTSTATUS_UNSUPPORTED_COMPRESSION                                        => Some("STATUS_UNSUPPORTED_COMPRESSION"),
-        SMB_NTSTATUS_INVALID_HW_PROFILE                                             => Some("STATUS_INVALID_HW_PROFILE"),
-        SMB_NTSTATUS_INVALID_PLUGPLAY_DEVICE_PATH                                   => Some("STATUS_INVALID_PLUGPLAY_DEVICE_PATH"),
-        SMB_NTSTATUS_DRIVER_ORDINAL_NOT_FOUND                                       => Some("STATUS_DRIVER_ORDINAL_NOT_FOUND"),
-        SMB_NTSTATUS_DRIVER_ENTRYPOINT_NOT_FOUND                                    => Some("STATUS_DRIVER_ENTRYPOINT_NOT_FOUND"),
-        SMB_NTSTATUS_RESOURCE_NOT_OWNED                                             => Some("STATUS_RESOURCE_NOT_OWNED"),
-        SMB_NTSTATUS_TOO_MANY_LINKS                                                 => Some("STATUS_TOO_MANY_LINKS"),
-        SMB_NTSTATUS_QUOTA_LIST_INCONSISTENT                                        => Some("STATUS_QUOTA_LIST_INCONSISTENT"),
-        SMB_NTSTATUS_FILE_IS_OFFLINE                                                => Some("STATUS_FILE_IS_OFFLINE"),
-        SMB_NTSTATUS_EVALUATION_EXPIRATION                                          => Some("STATUS_EVALUATION_EXPIRATION"),
-        SMB_NTSTATUS_ILLEGAL_DLL_RELOCATION                                         => Some("STATUS_ILLEGAL_DLL_RELOCATION"),
-        SMB_NTSTATUS_LICENSE_VIOLATION                                              => Some("STATUS_LICENSE_VIOLATION"),
-        SMB_NTSTATUS_DLL_INIT_FAILED_LOGOFF                                         => Some("STATUS_DLL_INIT_FAILED_LOGOFF"),
-        SMB_NTSTATUS_DRIVER_UNABLE_TO_LOAD                                          => Some("STATUS_DRIVER_UNABLE_TO_LOAD"),
-        SMB_NTSTATUS_DFS_UNAVAILABLE                                                => Some("STATUS_DFS_UNAVAILABLE"),
-        SMB_NTSTATUS_VOLUME_DISMOUNTED                                              => Some("STATUS_VOLUME_DISMOUNTED"),
-        SMB_NTSTATUS_WX86_INTERNAL_ERROR                                            => Some("STATUS_WX86_INTERNAL_ERROR"),
-        SMB_NTSTATUS_WX86_FLOAT_STACK_CHECK                                         => Some("STATUS_WX86_FLOAT_STACK_CHECK"),
-        SMB_NTSTATUS_VALIDATE_CONTINUE                                              => Some("STATUS_VALIDATE_CONTINUE"),
-        SMB_NTSTATUS_NO_MATCH                                                       => Some("STATUS_NO_MATCH"),
-        SMB_NTSTATUS_NO_MORE_MATCHES                                                => Some("STATUS_NO_MORE_MATCHES"),
-        SMB_NTSTATUS_NOT_A_REPARSE_POINT                                            => Some("STATUS_NOT_A_REPARSE_POINT"),
-        SMB_NTSTATUS_IO_REPARSE_TAG_INVALID                                         => Some("STATUS_IO_REPARSE_TAG_INVALID"),
-        SMB_NTSTATUS_IO_REPARSE_TAG_MISMATCH                                        => Some("STATUS_IO_REPARSE_TAG_MISMATCH"),
-        SMB_NTSTATUS_IO_REPARSE_DATA_INVALID                                        => Some("STATUS_IO_REPARSE_DATA_INVALID"),
-        SMB_NTSTATUS_IO_REPARSE_TAG_NOT_HANDLED                                     => Some("STATUS_IO_REPARSE_TAG_NOT_HANDLED"),
-        SMB_NTSTATUS_REPARSE_POINT_NOT_RESOLVED                                     => Some("STATUS_REPARSE_POINT_NOT_RESOLVED"),
-        SMB_NTSTATUS_DIRECTORY_IS_A_REPARSE_POINT                                   => Some("STATUS_DIRECTORY_IS_A_REPARSE_POINT"),
-        SMB_NTSTATUS_RANGE_LIST_CONFLICT                                            => Some("STATUS_RANGE_LIST_CONFLICT"),
-        SMB_NTSTATUS_SOURCE_ELEMENT_EMPTY                                           => Some("STATUS_SOURCE_ELEMENT_EMPTY"),
-        SMB_NTSTATUS_DESTINATION_ELEMENT_FULL                                       => Some("STATUS_DESTINATION_ELEMENT_FULL"),
-        SMB_NTSTATUS_ILLEGAL_ELEMENT_ADDRESS                                        => Some("STATUS_ILLEGAL_ELEMENT_ADDRESS"),
-        SMB_NTSTATUS_MAGAZINE_NOT_PRESENT                                           => Some("STATUS_MAGAZINE_NOT_PRESENT"),
-        SMB_NTSTATUS_REINITIALIZATION_NEEDED                                        => Some("STATUS_REINITIALIZATION_NEEDED"),
-        SMB_NTSTATUS_ENCRYPTION_FAILED                                              => Some("STATUS_ENCRYPTION_FAILED"),
-        SMB_NTSTATUS_DECRYPTION_FAILED                                              => Some("STATUS_DECRYPTION_FAILED"),
-        SMB_NTSTATUS_RANGE_NOT_FOUND                                                => Some("STATUS_RANGE_NOT_FOUND"),
-        SMB_NTSTATUS_NO_RECOVERY_POLICY                                             => Some("STATUS_NO_RECOVERY_POLICY"),
-        SMB_NTSTATUS_NO_EFS                                                         => Some("STATUS_NO_EFS"),
-        SMB_NTSTATUS_WRONG_EFS                                                      => Some("STATUS_WRONG_EFS"),
-        SMB_NTSTATUS_NO_USER_KEYS                                                   => Some("STATUS_NO_USER_KEYS"),
-        SMB_NTSTATUS_FILE_NOT_ENCRYPTED                                             => Some("STATUS_FILE_NOT_ENCRYPTED"),
-        SMB_NTSTATUS_NOT_EXPORT_FORMAT                                              => Some("STATUS_NOT_EXPORT_FORMAT"),
-        SMB_NTSTATUS_FILE_ENCRYPTED                                                 => Some("STATUS_FILE_ENCRYPTED"),
-        SMB_NTSTATUS_WMI_GUID_NOT_FOUND                                             => Some("STATUS_WMI_GUID_NOT_FOUND"),
-        SMB_NTSTATUS_WMI_INSTANCE_NOT_FOUND                                         => Some("STATUS_WMI_INSTANCE_NOT_FOUND"),
-        SMB_NTSTATUS_WMI_ITEMID_NOT_FOUND                                           => Some("STATUS_WMI_ITEMID_NOT_FOUND"),
-        SMB_NTSTATUS_WMI_TRY_AGAIN                                                  => Some("STATUS_WMI_TRY_AGAIN"),
-        SMB_NTSTATUS_SHARED_POLICY                                                  => Some("STATUS_SHARED_POLICY"),
-        SMB_NTSTATUS_POLICY_OBJECT_NOT_FOUND                                        => Some("STATUS_POLICY_OBJECT_NOT_FOUND"),
-        SMB_NTSTATUS_POLICY_ONLY_IN_DS                                              => Some("STATUS_POLICY_ONLY_IN_DS"),
-        SMB_NTSTATUS_VOLUME_NOT_UPGRADED                                            => Some("STATUS_VOLUME_NOT_UPGRADED"),
-        SMB_NTSTATUS_REMOTE_STORAGE_NOT_ACTIVE                                      => Some("STATUS_REMOTE_STORAGE_NOT_ACTIVE"),
-        SMB_NTSTATUS_REMOTE_STORAGE_MEDIA_ERROR                                     => Some("STATUS_REMOTE_STORAGE_MEDIA_ERROR"),
-        SMB_NTSTATUS_NO_TRACKING_SERVICE                                            => Some("STATUS_NO_TRACKING_SERVICE"),
-        SMB_NTSTATUS_SERVER_SID_MISMATCH                                            => Some("STATUS_SERVER_SID_MISMATCH"),
-        SMB_NTSTATUS_DS_NO_ATTRIBUTE_OR_VALUE                                       => Some("STATUS_DS_NO_ATTRIBUTE_OR_VALUE"),
-        SMB_NTSTATUS_DS_INVALID_ATTRIBUTE_SYNTAX                                    => Some("STATUS_DS_INVALID_ATTRIBUTE_SYNTAX"),
-        SMB_NTSTATUS_DS_ATTRIBUTE_TYPE_UNDEFINED                                    => Some("STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED"),
-        SMB_NTSTATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS                                   => Some("STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS"),
-        SMB_NTSTATUS_DS_BUSY                                                        => Some("STATUS_DS_BUSY"),
-        SMB_NTSTATUS_DS_UNAVAILABLE                                                 => Some("STATUS_DS_UNAVAILABLE"),
-        SMB_NTSTATUS_DS_NO_RIDS_ALLOCATED                                           => Some("STATUS_DS_NO_RIDS_ALLOCATED"),
-        SMB_NTSTATUS_DS_NO_MORE_RIDS                                                => Some("STATUS_DS_NO_MORE_RIDS"),
-        SMB_NTSTATUS_DS_INCORRECT_ROLE_OWNER                                        => Some("STATUS_DS_INCORRECT_ROLE_OWNER"),
-        SMB_NTSTATUS_DS_RIDMGR_INIT_ERROR                                           => Some("STATUS_DS_RIDMGR_INIT_ERROR"),
-        SMB_NTSTATUS_DS_OBJ_CLASS_VIOLATION                                         => Some("STATUS_DS_OBJ_CLASS_VIOLATION"),
-        SMB_NTSTATUS_DS_CANT_ON_NON_LEAF                                            => Some("STATUS_DS_CANT_ON_NON_LEAF"),
-        SMB_NTSTATUS_DS_CANT_ON_RDN                                                 => Some("STATUS_DS_CANT_ON_RDN"),
-        SMB_NTSTATUS_DS_CANT_MOD_OBJ_CLASS                                          => Some("STATUS_DS_CANT_MOD_OBJ_CLASS"),
-        SMB_NTSTATUS_DS_CROSS_DOM_MOVE_FAILED                                       => Some("STATUS_DS_CROSS_DOM_MOVE_FAILED"),
-        SMB_NTSTATUS_DS_GC_NOT_AVAILABLE                                            => Some("STATUS_DS_GC_NOT_AVAILABLE"),
-        SMB_NTSTATUS_DIRECTORY_SERVICE_REQUIRED                                     => Some("STATUS_DIRECTORY_SERVICE_REQUIRED"),
-        SMB_NTSTATUS_REPARSE_ATTRIBUTE_CONFLICT                                     => Some("STATUS_REPARSE_ATTRIBUTE_CONFLICT"),
-        SMB_NTSTATUS_CANT_ENABLE_DENY_ONLY                                          => Some("STATUS_CANT_ENABLE_DENY_ONLY"),
-        SMB_NTSTATUS_FLOAT_MULTIPLE_FAULTS                                          => Some("STATUS_FLOAT_MULTIPLE_FAULTS"),
-        SMB_NTSTATUS_FLOAT_MULTIPLE_TRAPS                                           => Some("STATUS_FLOAT_MULTIPLE_TRAPS"),
-        SMB_NTSTATUS_DEVICE_REMOVED                                                 => Some("STATUS_DEVICE_REMOVED"),
-        SMB_NTSTATUS_JOURNAL_DELETE_IN_PROGRESS                                     => Some("STATUS_JOURNAL_DELETE_IN_PROGRESS"),
-        SMB_NTSTATUS_JOURNAL_NOT_ACTIVE                                             => Some("STATUS_JOURNAL_NOT_ACTIVE"),
-        SMB_NTSTATUS_NOINTERFACE                                                    => Some("STATUS_NOINTERFACE"),
-        SMB_NTSTATUS_DS_ADMIN_LIMIT_EXCEEDED                                        => Some("STATUS_DS_ADMIN_LIMIT_EXCEEDED"),
-        SMB_NTSTATUS_DRIVER_FAILED_SLEEP                                            => Some("STATUS_DRIVER_FAILED_SLEEP"),
-        SMB_NTSTATUS_MUTUAL_AUTHENTICATION_FAILED                                   => Some("STATUS_MUTUAL_AUTHENTICATION_FAILED"),
-        SMB_NTSTATUS_CORRUPT_SYSTEM_FILE                                            => Some("STATUS_CORRUPT_SYSTEM_FILE"),
-        SMB_NTSTATUS_DATATYPE_MISALIGNMENT_ERROR                                    => Some("STATUS_DATATYPE_MISALIGNMENT_ERROR"),
-        SMB_NTSTATUS_WMI_READ_ONLY                                                  => Some("STATUS_WMI_READ_ONLY"),
-        SMB_NTSTATUS_WMI_SET_FAILURE                                                => Some("STATUS_WMI_SET_FAILURE"),
-        SMB_NTSTATUS_COMMITMENT_MINIMUM                                             => Some("STATUS_COMMITMENT_MINIMUM"),
-        SMB_NTSTATUS_REG_NAT_CONSUMPTION                                            => Some("STATUS_REG_NAT_CONSUMPTION"),
-        SMB_NTSTATUS_TRANSPORT_FULL                                                 => Some("STATUS_TRANSPORT_FULL"),
-        SMB_NTSTATUS_DS_SAM_INIT_FAILURE                                            => Some("STATUS_DS_SAM_INIT_FAILURE"),
-        SMB_NTSTATUS_ONLY_IF_CONNECTED                                              => Some("STATUS_ONLY_IF_CONNECTED"),
-        SMB_NTSTATUS_DS_SENSITIVE_GROUP_VIOLATION                                   => Some("STATUS_DS_SENSITIVE_GROUP_VIOLATION"),
-        SMB_NTSTATUS_PNP_RESTART_ENUMERATION                                        => Some("STATUS_PNP_RESTART_ENUMERATION"),
-        SMB_NTSTATUS_JOURNAL_ENTRY_DELETED                                          => Some("STATUS_JOURNAL_ENTRY_DELETED"),
-        SMB_NTSTATUS_DS_CANT_MOD_PRIMARYGROUPID                                     => Some("STATUS_DS_CANT_MOD_PRIMARYGROUPID"),
-        SMB_NTSTATUS_SYSTEM_IMAGE_BAD_SIGNATURE                                     => Some("STATUS_SYSTEM_IMAGE_BAD_SIGNATURE"),
-        SMB_NTSTATUS_PNP_REBOOT_REQUIRED                                            => Some("STATUS_PNP_REBOOT_REQUIRED"),
-        SMB_NTSTATUS_POWER_STATE_INVALID                                            => Some("STATUS_POWER_STATE_INVALID"),
-        SMB_NTSTATUS_DS_INVALID_GROUP_TYPE                                          => Some("STATUS_DS_INVALID_GROUP_TYPE"),
-        SMB_NTSTATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN                          => Some("STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN"),
-        SMB_NTSTATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN                           => Some("STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN"),
-        SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER                               => Some("STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER"),
-        SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER                           => Some("STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER"),
-        SMB_NTSTATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER                            => Some("STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER"),
-        SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER                         => Some("STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER"),
-        SMB_NTSTATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER                    => Some("STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER"),
-        SMB_NTSTATUS_DS_HAVE_PRIMARY_MEMBERS                                        => Some("STATUS_DS_HAVE_PRIMARY_MEMBERS"),
-        SMB_NTSTATUS_WMI_NOT_SUPPORTED                                              => Some("STATUS_WMI_NOT_SUPPORTED"),
-        SMB_NTSTATUS_INSUFFICIENT_POWER                                             => Some("STATUS_INSUFFICIENT_POWER"),
-        SMB_NTSTATUS_SAM_NEED_BOOTKEY_PASSWORD                                      => Some("STATUS_SAM_NEED_BOOTKEY_PASSWORD"),
-        SMB_NTSTATUS_SAM_NEED_BOOTKEY_FLOPPY                                        => Some("STATUS_SAM_NEED_BOOTKEY_FLOPPY"),
-        SMB_NTSTATUS_DS_CANT_START                                                  => Some("STATUS_DS_CANT_START"),
-        SMB_NTSTATUS_DS_INIT_FAILURE                                                => Some("STATUS_DS_INIT_FAILURE"),
-        SMB_NTSTATUS_SAM_INIT_FAILURE                                               => Some("STATUS_SAM_INIT_FAILURE"),
-        SMB_NTSTATUS_DS_GC_REQUIRED                                                 => Some("STATUS_DS_GC_REQUIRED"),
-        SMB_NTSTATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY                                  => Some("STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY"),
-        SMB_NTSTATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS                                  => Some("STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS"),
-        SMB_NTSTATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED                              => Some("STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_CURRENT_DOMAIN_NOT_ALLOWED                                     => Some("STATUS_CURRENT_DOMAIN_NOT_ALLOWED"),
-        SMB_NTSTATUS_CANNOT_MAKE                                                    => Some("STATUS_CANNOT_MAKE"),
-        SMB_NTSTATUS_SYSTEM_SHUTDOWN                                                => Some("STATUS_SYSTEM_SHUTDOWN"),
-        SMB_NTSTATUS_DS_INIT_FAILURE_CONSOLE                                        => Some("STATUS_DS_INIT_FAILURE_CONSOLE"),
-        SMB_NTSTATUS_DS_SAM_INIT_FAILURE_CONSOLE                                    => Some("STATUS_DS_SAM_INIT_FAILURE_CONSOLE"),
-        SMB_NTSTATUS_UNFINISHED_CONTEXT_DELETED                                     => Some("STATUS_UNFINISHED_CONTEXT_DELETED"),
-        SMB_NTSTATUS_NO_TGT_REPLY                                                   => Some("STATUS_NO_TGT_REPLY"),
-        SMB_NTSTATUS_OBJECTID_NOT_FOUND                                             => Some("STATUS_OBJECTID_NOT_FOUND"),
-        SMB_NTSTATUS_NO_IP_ADDRESSES                                                => Some("STATUS_NO_IP_ADDRESSES"),
-        SMB_NTSTATUS_WRONG_CREDENTIAL_HANDLE                                        => Some("STATUS_WRONG_CREDENTIAL_HANDLE"),
-        SMB_NTSTATUS_CRYPTO_SYSTEM_INVALID                                          => Some("STATUS_CRYPTO_SYSTEM_INVALID"),
-        SMB_NTSTATUS_MAX_REFERRALS_EXCEEDED                                         => Some("STATUS_MAX_REFERRALS_EXCEEDED"),
-        SMB_NTSTATUS_MUST_BE_KDC                                                    => Some("STATUS_MUST_BE_KDC"),
-        SMB_NTSTATUS_STRONG_CRYPTO_NOT_SUPPORTED                                    => Some("STATUS_STRONG_CRYPTO_NOT_SUPPORTED"),
-        SMB_NTSTATUS_TOO_MANY_PRINCIPALS                                            => Some("STATUS_TOO_MANY_PRINCIPALS"),
-        SMB_NTSTATUS_NO_PA_DATA                                                     => Some("STATUS_NO_PA_DATA"),
-        SMB_NTSTATUS_PKINIT_NAME_MISMATCH                                           => Some("STATUS_PKINIT_NAME_MISMATCH"),
-        SMB_NTSTATUS_SMARTCARD_LOGON_REQUIRED                                       => Some("STATUS_SMARTCARD_LOGON_REQUIRED"),
-        SMB_NTSTATUS_KDC_INVALID_REQUEST                                            => Some("STATUS_KDC_INVALID_REQUEST"),
-        SMB_NTSTATUS_KDC_UNABLE_TO_REFER                                            => Some("STATUS_KDC_UNABLE_TO_REFER"),
-        SMB_NTSTATUS_KDC_UNKNOWN_ETYPE                                              => Some("STATUS_KDC_UNKNOWN_ETYPE"),
-        SMB_NTSTATUS_SHUTDOWN_IN_PROGRESS                                           => Some("STATUS_SHUTDOWN_IN_PROGRESS"),
-        SMB_NTSTATUS_SERVER_SHUTDOWN_IN_PROGRESS                                    => Some("STATUS_SERVER_SHUTDOWN_IN_PROGRESS"),
-        SMB_NTSTATUS_NOT_SUPPORTED_ON_SBS                                           => Some("STATUS_NOT_SUPPORTED_ON_SBS"),
-        SMB_NTSTATUS_WMI_GUID_DISCONNECTED                                          => Some("STATUS_WMI_GUID_DISCONNECTED"),
-        SMB_NTSTATUS_WMI_ALREADY_DISABLED                                           => Some("STATUS_WMI_ALREADY_DISABLED"),
-        SMB_NTSTATUS_WMI_ALREADY_ENABLED                                            => Some("STATUS_WMI_ALREADY_ENABLED"),
-        SMB_NTSTATUS_MFT_TOO_FRAGMENTED                                             => Some("STATUS_MFT_TOO_FRAGMENTED"),
-        SMB_NTSTATUS_COPY_PROTECTION_FAILURE                                        => Some("STATUS_COPY_PROTECTION_FAILURE"),
-        SMB_NTSTATUS_CSS_AUTHENTICATION_FAILURE                                     => Some("STATUS_CSS_AUTHENTICATION_FAILURE"),
-        SMB_NTSTATUS_CSS_KEY_NOT_PRESENT                                            => Some("STATUS_CSS_KEY_NOT_PRESENT"),
-        SMB_NTSTATUS_CSS_KEY_NOT_ESTABLISHED                                        => Some("STATUS_CSS_KEY_NOT_ESTABLISHED"),
-        SMB_NTSTATUS_CSS_SCRAMBLED_SECTOR                                           => Some("STATUS_CSS_SCRAMBLED_SECTOR"),
-        SMB_NTSTATUS_CSS_REGION_MISMATCH                                            => Some("STATUS_CSS_REGION_MISMATCH"),
-        SMB_NTSTATUS_CSS_RESETS_EXHAUSTED                                           => Some("STATUS_CSS_RESETS_EXHAUSTED"),
-        SMB_NTSTATUS_PKINIT_FAILURE                                                 => Some("STATUS_PKINIT_FAILURE"),
-        SMB_NTSTATUS_SMARTCARD_SUBSYSTEM_FAILURE                                    => Some("STATUS_SMARTCARD_SUBSYSTEM_FAILURE"),
-        SMB_NTSTATUS_NO_KERB_KEY                                                    => Some("STATUS_NO_KERB_KEY"),
-        SMB_NTSTATUS_HOST_DOWN                                                      => Some("STATUS_HOST_DOWN"),
-        SMB_NTSTATUS_UNSUPPORTED_PREAUTH                                            => Some("STATUS_UNSUPPORTED_PREAUTH"),
-        SMB_NTSTATUS_EFS_ALG_BLOB_TOO_BIG                                           => Some("STATUS_EFS_ALG_BLOB_TOO_BIG"),
-        SMB_NTSTATUS_PORT_NOT_SET                                                   => Some("STATUS_PORT_NOT_SET"),
-        SMB_NTSTATUS_DEBUGGER_INACTIVE                                              => Some("STATUS_DEBUGGER_INACTIVE"),
-        SMB_NTSTATUS_DS_VERSION_CHECK_FAILURE                                       => Some("STATUS_DS_VERSION_CHECK_FAILURE"),
-        SMB_NTSTATUS_AUDITING_DISABLED                                              => Some("STATUS_AUDITING_DISABLED"),
-        SMB_NTSTATUS_PRENT4_MACHINE_ACCOUNT                                         => Some("STATUS_PRENT4_MACHINE_ACCOUNT"),
-        SMB_NTSTATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER                               => Some("STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER"),
-        SMB_NTSTATUS_INVALID_IMAGE_WIN_32                                           => Some("STATUS_INVALID_IMAGE_WIN_32"),
-        SMB_NTSTATUS_INVALID_IMAGE_WIN_64                                           => Some("STATUS_INVALID_IMAGE_WIN_64"),
-        SMB_NTSTATUS_BAD_BINDINGS                                                   => Some("STATUS_BAD_BINDINGS"),
-        SMB_NTSTATUS_NETWORK_SESSION_EXPIRED                                        => Some("STATUS_NETWORK_SESSION_EXPIRED"),
-        SMB_NTSTATUS_APPHELP_BLOCK                                                  => Some("STATUS_APPHELP_BLOCK"),
-        SMB_NTSTATUS_ALL_SIDS_FILTERED                                              => Some("STATUS_ALL_SIDS_FILTERED"),
-        SMB_NTSTATUS_NOT_SAFE_MODE_DRIVER                                           => Some("STATUS_NOT_SAFE_MODE_DRIVER"),
-        SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT                              => Some("STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT"),
-        SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_PATH                                 => Some("STATUS_ACCESS_DISABLED_BY_POLICY_PATH"),
-        SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER                            => Some("STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER"),
-        SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_OTHER                                => Some("STATUS_ACCESS_DISABLED_BY_POLICY_OTHER"),
-        SMB_NTSTATUS_FAILED_DRIVER_ENTRY                                            => Some("STATUS_FAILED_DRIVER_ENTRY"),
-        SMB_NTSTATUS_DEVICE_ENUMERATION_ERROR                                       => Some("STATUS_DEVICE_ENUMERATION_ERROR"),
-        SMB_NTSTATUS_MOUNT_POINT_NOT_RESOLVED                                       => Some("STATUS_MOUNT_POINT_NOT_RESOLVED"),
-        SMB_NTSTATUS_INVALID_DEVICE_OBJECT_PARAMETER                                => Some("STATUS_INVALID_DEVICE_OBJECT_PARAMETER"),
-        SMB_NTSTATUS_MCA_OCCURED                                                    => Some("STATUS_MCA_OCCURED"),
-        SMB_NTSTATUS_DRIVER_BLOCKED_CRITICAL                                        => Some("STATUS_DRIVER_BLOCKED_CRITICAL"),
-        SMB_NTSTATUS_DRIVER_BLOCKED                                                 => Some("STATUS_DRIVER_BLOCKED"),
-        SMB_NTSTATUS_DRIVER_DATABASE_ERROR                                          => Some("STATUS_DRIVER_DATABASE_ERROR"),
-        SMB_NTSTATUS_SYSTEM_HIVE_TOO_LARGE                                          => Some("STATUS_SYSTEM_HIVE_TOO_LARGE"),
-        SMB_NTSTATUS_INVALID_IMPORT_OF_NON_DLL                                      => Some("STATUS_INVALID_IMPORT_OF_NON_DLL"),
-        SMB_NTSTATUS_NO_SECRETS                                                     => Some("STATUS_NO_SECRETS"),
-        SMB_NTSTATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY                          => Some("STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY"),
-        SMB_NTSTATUS_FAILED_STACK_SWITCH                                            => Some("STATUS_FAILED_STACK_SWITCH"),
-        SMB_NTSTATUS_HEAP_CORRUPTION                                                => Some("STATUS_HEAP_CORRUPTION"),
-        SMB_NTSTATUS_SMARTCARD_WRONG_PIN                                            => Some("STATUS_SMARTCARD_WRONG_PIN"),
-        SMB_NTSTATUS_SMARTCARD_CARD_BLOCKED                                         => Some("STATUS_SMARTCARD_CARD_BLOCKED"),
-        SMB_NTSTATUS_SMARTCARD_CARD_NOT_AUTHENTICATED                               => Some("STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED"),
-        SMB_NTSTATUS_SMARTCARD_NO_CARD                                              => Some("STATUS_SMARTCARD_NO_CARD"),
-        SMB_NTSTATUS_SMARTCARD_NO_KEY_CONTAINER                                     => Some("STATUS_SMARTCARD_NO_KEY_CONTAINER"),
-        SMB_NTSTATUS_SMARTCARD_NO_CERTIFICATE                                       => Some("STATUS_SMARTCARD_NO_CERTIFICATE"),
-        SMB_NTSTATUS_SMARTCARD_NO_KEYSET                                            => Some("STATUS_SMARTCARD_NO_KEYSET"),
-        SMB_NTSTATUS_SMARTCARD_IO_ERROR                                             => Some("STATUS_SMARTCARD_IO_ERROR"),
-        SMB_NTSTATUS_DOWNGRADE_DETECTED                                             => Some("STATUS_DOWNGRADE_DETECTED"),
-        SMB_NTSTATUS_SMARTCARD_CERT_REVOKED                                         => Some("STATUS_SMARTCARD_CERT_REVOKED"),
-        SMB_NTSTATUS_ISSUING_CA_UNTRUSTED                                           => Some("STATUS_ISSUING_CA_UNTRUSTED"),
-        SMB_NTSTATUS_REVOCATION_OFFLINE_C                                           => Some("STATUS_REVOCATION_OFFLINE_C"),
-        SMB_NTSTATUS_PKINIT_CLIENT_FAILURE                                          => Some("STATUS_PKINIT_CLIENT_FAILURE"),
-        SMB_NTSTATUS_SMARTCARD_CERT_EXPIRED                                         => Some("STATUS_SMARTCARD_CERT_EXPIRED"),
-        SMB_NTSTATUS_DRIVER_FAILED_PRIOR_UNLOAD                                     => Some("STATUS_DRIVER_FAILED_PRIOR_UNLOAD"),
-        SMB_NTSTATUS_SMARTCARD_SILENT_CONTEXT                                       => Some("STATUS_SMARTCARD_SILENT_CONTEXT"),
-        SMB_NTSTATUS_PER_USER_TRUST_QUOTA_EXCEEDED                                  => Some("STATUS_PER_USER_TRUST_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_ALL_USER_TRUST_QUOTA_EXCEEDED                                  => Some("STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED                               => Some("STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_DS_NAME_NOT_UNIQUE                                             => Some("STATUS_DS_NAME_NOT_UNIQUE"),
-        SMB_NTSTATUS_DS_DUPLICATE_ID_FOUND                                          => Some("STATUS_DS_DUPLICATE_ID_FOUND"),
-        SMB_NTSTATUS_DS_GROUP_CONVERSION_ERROR                                      => Some("STATUS_DS_GROUP_CONVERSION_ERROR"),
-        SMB_NTSTATUS_VOLSNAP_PREPARE_HIBERNATE                                      => Some("STATUS_VOLSNAP_PREPARE_HIBERNATE"),
-        SMB_NTSTATUS_USER2USER_REQUIRED                                             => Some("STATUS_USER2USER_REQUIRED"),
-        SMB_NTSTATUS_STACK_BUFFER_OVERRUN                                           => Some("STATUS_STACK_BUFFER_OVERRUN"),
-        SMB_NTSTATUS_NO_S4U_PROT_SUPPORT                                            => Some("STATUS_NO_S4U_PROT_SUPPORT"),
-        SMB_NTSTATUS_CROSSREALM_DELEGATION_FAILURE                                  => Some("STATUS_CROSSREALM_DELEGATION_FAILURE"),
-        SMB_NTSTATUS_REVOCATION_OFFLINE_KDC                                         => Some("STATUS_REVOCATION_OFFLINE_KDC"),
-        SMB_NTSTATUS_ISSUING_CA_UNTRUSTED_KDC                                       => Some("STATUS_ISSUING_CA_UNTRUSTED_KDC"),
-        SMB_NTSTATUS_KDC_CERT_EXPIRED                                               => Some("STATUS_KDC_CERT_EXPIRED"),
-        SMB_NTSTATUS_KDC_CERT_REVOKED                                               => Some("STATUS_KDC_CERT_REVOKED"),
-        SMB_NTSTATUS_PARAMETER_QUOTA_EXCEEDED                                       => Some("STATUS_PARAMETER_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_HIBERNATION_FAILURE                                            => Some("STATUS_HIBERNATION_FAILURE"),
-        SMB_NTSTATUS_DELAY_LOAD_FAILED                                              => Some("STATUS_DELAY_LOAD_FAILED"),
-        SMB_NTSTATUS_AUTHENTICATION_FIREWALL_FAILED                                 => Some("STATUS_AUTHENTICATION_FIREWALL_FAILED"),
-        SMB_NTSTATUS_VDM_DISALLOWED                                                 => Some("STATUS_VDM_DISALLOWED"),
-        SMB_NTSTATUS_HUNG_DISPLAY_DRIVER_THREAD                                     => Some("STATUS_HUNG_DISPLAY_DRIVER_THREAD"),
-        SMB_NTSTATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE        => Some("STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE"),
-        SMB_NTSTATUS_INVALID_CRUNTIME_PARAMETER                                     => Some("STATUS_INVALID_CRUNTIME_PARAMETER"),
-        SMB_NTSTATUS_NTLM_BLOCKED                                                   => Some("STATUS_NTLM_BLOCKED"),
-        SMB_NTSTATUS_DS_SRC_SID_EXISTS_IN_FOREST                                    => Some("STATUS_DS_SRC_SID_EXISTS_IN_FOREST"),
-        SMB_NTSTATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST                                => Some("STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST"),
-        SMB_NTSTATUS_DS_FLAT_NAME_EXISTS_IN_FOREST                                  => Some("STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST"),
-        SMB_NTSTATUS_INVALID_USER_PRINCIPAL_NAME                                    => Some("STATUS_INVALID_USER_PRINCIPAL_NAME"),
-        SMB_NTSTATUS_ASSERTION_FAILURE                                              => Some("STATUS_ASSERTION_FAILURE"),
-        SMB_NTSTATUS_VERIFIER_STOP                                                  => Some("STATUS_VERIFIER_STOP"),
-        SMB_NTSTATUS_CALLBACK_POP_STACK                                             => Some("STATUS_CALLBACK_POP_STACK"),
-        SMB_NTSTATUS_INCOMPATIBLE_DRIVER_BLOCKED                                    => Some("STATUS_INCOMPATIBLE_DRIVER_BLOCKED"),
-        SMB_NTSTATUS_HIVE_UNLOADED                                                  => Some("STATUS_HIVE_UNLOADED"),
-        SMB_NTSTATUS_COMPRESSION_DISABLED                                           => Some("STATUS_COMPRESSION_DISABLED"),
-        SMB_NTSTATUS_FILE_SYSTEM_LIMITATION                                         => Some("STATUS_FILE_SYSTEM_LIMITATION"),
-        SMB_NTSTATUS_INVALID_IMAGE_HASH                                             => Some("STATUS_INVALID_IMAGE_HASH"),
-        SMB_NTSTATUS_NOT_CAPABLE                                                    => Some("STATUS_NOT_CAPABLE"),
-        SMB_NTSTATUS_REQUEST_OUT_OF_SEQUENCE                                        => Some("STATUS_REQUEST_OUT_OF_SEQUENCE"),
-        SMB_NTSTATUS_IMPLEMENTATION_LIMIT                                           => Some("STATUS_IMPLEMENTATION_LIMIT"),
-        SMB_NTSTATUS_ELEVATION_REQUIRED                                             => Some("STATUS_ELEVATION_REQUIRED"),
-        SMB_NTSTATUS_NO_SECURITY_CONTEXT                                            => Some("STATUS_NO_SECURITY_CONTEXT"),
-        SMB_NTSTATUS_PKU2U_CERT_FAILURE                                             => Some("STATUS_PKU2U_CERT_FAILURE"),
-        SMB_NTSTATUS_BEYOND_VDL                                                     => Some("STATUS_BEYOND_VDL"),
-        SMB_NTSTATUS_ENCOUNTERED_WRITE_IN_PROGRESS                                  => Some("STATUS_ENCOUNTERED_WRITE_IN_PROGRESS"),
-        SMB_NTSTATUS_PTE_CHANGED                                                    => Some("STATUS_PTE_CHANGED"),
-        SMB_NTSTATUS_PURGE_FAILED                                                   => Some("STATUS_PURGE_FAILED"),
-        SMB_NTSTATUS_CRED_REQUIRES_CONFIRMATION                                     => Some("STATUS_CRED_REQUIRES_CONFIRMATION"),
-        SMB_NTSTATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE                          => Some("STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE"),
-        SMB_NTSTATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER                               => Some("STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER"),
-        SMB_NTSTATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE                          => Some("STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE"),
-        SMB_NTSTATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE                               => Some("STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE"),
-        SMB_NTSTATUS_CS_ENCRYPTION_FILE_NOT_CSE                                     => Some("STATUS_CS_ENCRYPTION_FILE_NOT_CSE"),
-        SMB_NTSTATUS_INVALID_LABEL                                                  => Some("STATUS_INVALID_LABEL"),
-        SMB_NTSTATUS_DRIVER_PROCESS_TERMINATED                                      => Some("STATUS_DRIVER_PROCESS_TERMINATED"),
-        SMB_NTSTATUS_AMBIGUOUS_SYSTEM_DEVICE                                        => Some("STATUS_AMBIGUOUS_SYSTEM_DEVICE"),
-        SMB_NTSTATUS_SYSTEM_DEVICE_NOT_FOUND                                        => Some("STATUS_SYSTEM_DEVICE_NOT_FOUND"),
-        SMB_NTSTATUS_RESTART_BOOT_APPLICATION                                       => Some("STATUS_RESTART_BOOT_APPLICATION"),
-        SMB_NTSTATUS_INSUFFICIENT_NVRAM_RESOURCES                                   => Some("STATUS_INSUFFICIENT_NVRAM_RESOURCES"),
-        SMB_NTSTATUS_NO_RANGES_PROCESSED                                            => Some("STATUS_NO_RANGES_PROCESSED"),
-        SMB_NTSTATUS_DEVICE_FEATURE_NOT_SUPPORTED                                   => Some("STATUS_DEVICE_FEATURE_NOT_SUPPORTED"),
-        SMB_NTSTATUS_DEVICE_UNREACHABLE                                             => Some("STATUS_DEVICE_UNREACHABLE"),
-        SMB_NTSTATUS_INVALID_TOKEN                                                  => Some("STATUS_INVALID_TOKEN"),
-        SMB_NTSTATUS_SERVER_UNAVAILABLE                                             => Some("STATUS_SERVER_UNAVAILABLE"),
-        SMB_NTSTATUS_INVALID_TASK_NAME                                              => Some("STATUS_INVALID_TASK_NAME"),
-        SMB_NTSTATUS_INVALID_TASK_INDEX                                             => Some("STATUS_INVALID_TASK_INDEX"),
-        SMB_NTSTATUS_THREAD_ALREADY_IN_TASK                                         => Some("STATUS_THREAD_ALREADY_IN_TASK"),
-        SMB_NTSTATUS_CALLBACK_BYPASS                                                => Some("STATUS_CALLBACK_BYPASS"),
-        SMB_NTSTATUS_FAIL_FAST_EXCEPTION                                            => Some("STATUS_FAIL_FAST_EXCEPTION"),
-        SMB_NTSTATUS_IMAGE_CERT_REVOKED                                             => Some("STATUS_IMAGE_CERT_REVOKED"),
-        SMB_NTSTATUS_PORT_CLOSED                                                    => Some("STATUS_PORT_CLOSED"),
-        SMB_NTSTATUS_MESSAGE_LOST                                                   => Some("STATUS_MESSAGE_LOST"),
-        SMB_NTSTATUS_INVALID_MESSAGE                                                => Some("STATUS_INVALID_MESSAGE"),
-        SMB_NTSTATUS_REQUEST_CANCELED                                               => Some("STATUS_REQUEST_CANCELED"),
-        SMB_NTSTATUS_RECURSIVE_DISPATCH                                             => Some("STATUS_RECURSIVE_DISPATCH"),
-        SMB_NTSTATUS_LPC_RECEIVE_BUFFER_EXPECTED                                    => Some("STATUS_LPC_RECEIVE_BUFFER_EXPECTED"),
-        SMB_NTSTATUS_LPC_INVALID_CONNECTION_USAGE                                   => Some("STATUS_LPC_INVALID_CONNECTION_USAGE"),
-        SMB_NTSTATUS_LPC_REQUESTS_NOT_ALLOWED                                       => Some("STATUS_LPC_REQUESTS_NOT_ALLOWED"),
-        SMB_NTSTATUS_RESOURCE_IN_USE                                                => Some("STATUS_RESOURCE_IN_USE"),
-        SMB_NTSTATUS_HARDWARE_MEMORY_ERROR                                          => Some("STATUS_HARDWARE_MEMORY_ERROR"),
-        SMB_NTSTATUS_THREADPOOL_HANDLE_EXCEPTION                                    => Some("STATUS_THREADPOOL_HANDLE_EXCEPTION"),
-        SMB_NTSTATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED                      => Some("STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED"),
-        SMB_NTSTATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED              => Some("STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED"),
-        SMB_NTSTATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED                  => Some("STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED"),
-        SMB_NTSTATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED                   => Some("STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED"),
-        SMB_NTSTATUS_THREADPOOL_RELEASED_DURING_OPERATION                           => Some("STATUS_THREADPOOL_RELEASED_DURING_OPERATION"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING                          => Some("STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING"),
-        SMB_NTSTATUS_APC_RETURNED_WHILE_IMPERSONATING                               => Some("STATUS_APC_RETURNED_WHILE_IMPERSONATING"),
-        SMB_NTSTATUS_PROCESS_IS_PROTECTED                                           => Some("STATUS_PROCESS_IS_PROTECTED"),
-        SMB_NTSTATUS_MCA_EXCEPTION                                                  => Some("STATUS_MCA_EXCEPTION"),
-        SMB_NTSTATUS_CERTIFICATE_MAPPING_NOT_UNIQUE                                 => Some("STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE"),
-        SMB_NTSTATUS_SYMLINK_CLASS_DISABLED                                         => Some("STATUS_SYMLINK_CLASS_DISABLED"),
-        SMB_NTSTATUS_INVALID_IDN_NORMALIZATION                                      => Some("STATUS_INVALID_IDN_NORMALIZATION"),
-        SMB_NTSTATUS_NO_UNICODE_TRANSLATION                                         => Some("STATUS_NO_UNICODE_TRANSLATION"),
-        SMB_NTSTATUS_ALREADY_REGISTERED                                             => Some("STATUS_ALREADY_REGISTERED"),
-        SMB_NTSTATUS_CONTEXT_MISMATCH                                               => Some("STATUS_CONTEXT_MISMATCH"),
-        SMB_NTSTATUS_PORT_ALREADY_HAS_COMPLETION_LIST                               => Some("STATUS_PORT_ALREADY_HAS_COMPLETION_LIST"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_THREAD_PRIORITY                              => Some("STATUS_CALLBACK_RETURNED_THREAD_PRIORITY"),
-        SMB_NTSTATUS_INVALID_THREAD                                                 => Some("STATUS_INVALID_THREAD"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_TRANSACTION                                  => Some("STATUS_CALLBACK_RETURNED_TRANSACTION"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_LDR_LOCK                                     => Some("STATUS_CALLBACK_RETURNED_LDR_LOCK"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_LANG                                         => Some("STATUS_CALLBACK_RETURNED_LANG"),
-        SMB_NTSTATUS_CALLBACK_RETURNED_PRI_BACK                                     => Some("STATUS_CALLBACK_RETURNED_PRI_BACK"),
-        SMB_NTSTATUS_DISK_REPAIR_DISABLED                                           => Some("STATUS_DISK_REPAIR_DISABLED"),
-        SMB_NTSTATUS_DS_DOMAIN_RENAME_IN_PROGRESS                                   => Some("STATUS_DS_DOMAIN_RENAME_IN_PROGRESS"),
-        SMB_NTSTATUS_DISK_QUOTA_EXCEEDED                                            => Some("STATUS_DISK_QUOTA_EXCEEDED"),
-        SMB_NTSTATUS_CONTENT_BLOCKED                                                => Some("STATUS_CONTENT_BLOCKED"),
-        SMB_NTSTATUS_BAD_CLUSTERS                                                   => Some("STATUS_BAD_CLUSTERS"),
-        SMB_NTSTATUS_VOLUME_DIRTY                                                   => Some("STATUS_VOLUME_DIRTY"),
-        SMB_NTSTATUS_FILE_CHECKED_OUT                                               => Some("STATUS_FILE_CHECKED_OUT"),
-        SMB_NTSTATUS_CHECKOUT_REQUIRED                                              => Some("STATUS_CHECKOUT_REQUIRED"),
-        SMB_NTSTATUS_BAD_FILE_TYPE                                                  => Some("STATUS_BAD_FILE_TYPE"),
-        SMB_NTSTATUS_FILE_TOO_LARGE                                                 => Some("STATUS_FILE_TOO_LARGE"),
-        SMB_NTSTATUS_FORMS_AUTH_REQUIRED                                            => Some("STATUS_FORMS_AUTH_REQUIRED"),
-        SMB_NTSTATUS_VIRUS_INFECTED                                                 => Some("STATUS_VIRUS_INFECTED"),
-        SMB_NTSTATUS_VIRUS_DELETED                                                  => Some("STATUS_VIRUS_DELETED"),
-        SMB_NTSTATUS_BAD_MCFG_TABLE                                                 => Some("STATUS_BAD_MCFG_TABLE"),
-        SMB_NTSTATUS_CANNOT_BREAK_OPLOCK                                            => Some("STATUS_CANNOT_BREAK_OPLOCK"),
-        SMB_NTSTATUS_WOW_ASSERTION                                                  => Some("STATUS_WOW_ASSERTION"),
-        SMB_NTSTATUS_INVALID_SIGNATURE                                              => Some("STATUS_INVALID_SIGNATURE"),
-        SMB_NTSTATUS_HMAC_NOT_SUPPORTED                                             => Some("STATUS_HMAC_NOT_SUPPORTED"),
-        SMB_NTSTATUS_IPSEC_QUEUE_OVERFLOW                                           => Some("STATUS_IPSEC_QUEUE_OVERFLOW"),
-        SMB_NTSTATUS_ND_QUEUE_OVERFLOW                                              => Some("STATUS_ND_QUEUE_OVERFLOW"),
-        SMB_NTSTATUS_HOPLIMIT_EXCEEDED                                              => Some("STATUS_HOPLIMIT_EXCEEDED"),
-        SMB_NTSTATUS_PROTOCOL_NOT_SUPPORTED                                         => Some("STATUS_PROTOCOL_NOT_SUPPORTED"),
-        SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED                     => Some("STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED"),
-        SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR                     => Some("STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR"),
-        SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR                         => Some("STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR"),
-        SMB_NTSTATUS_XML_PARSE_ERROR                                                => Some("STATUS_XML_PARSE_ERROR"),
-        SMB_NTSTATUS_XMLDSIG_ERROR                                                  => Some("STATUS_XMLDSIG_ERROR"),
-        SMB_NTSTATUS_WRONG_COMPARTMENT                                              => Some("STATUS_WRONG_COMPARTMENT"),
-        SMB_NTSTATUS_AUTHIP_FAILURE                                                 => Some("STATUS_AUTHIP_FAILURE"),
-        SMB_NTSTATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS                          => Some("STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS"),
-        SMB_NTSTATUS_DS_OID_NOT_FOUND                                               => Some("STATUS_DS_OID_NOT_FOUND"),
-        SMB_NTSTATUS_HASH_NOT_SUPPORTED                                             => Some("STATUS_HASH_NOT_SUPPORTED"),
-        SMB_NTSTATUS_HASH_NOT_PRESENT                                               => Some("STATUS_HASH_NOT_PRESENT"),
-        SMB_NTSTATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED                                 => Some("STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED"),
-        SMB_NTSTATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED                                => Some("STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED"),
-        SMB_NTSTATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED                                => Some("STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED"),
-        SMB_NTSTATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED                               => Some("STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED"),
-        SMB_NTDBG_NO_STATE_CHANGE                                                   => Some("DBG_NO_STATE_CHANGE"),
-        SMB_NTDBG_APP_NOT_IDLE                                                      => Some("DBG_APP_NOT_IDLE"),
-        SMB_NTRPC_NT_INVALID_STRING_BINDING                                         => Some("RPC_NT_INVALID_STRING_BINDING"),
-        SMB_NTRPC_NT_WRONG_KIND_OF_BINDING                                          => Some("RPC_NT_WRONG_KIND_OF_BINDING"),
-        SMB_NTRPC_NT_INVALID_BINDING                                                => Some("RPC_NT_INVALID_BINDING"),
-        SMB_NTRPC_NT_PROTSEQ_NOT_SUPPORTED                                          => Some("RPC_NT_PROTSEQ_NOT_SUPPORTED"),
-        SMB_NTRPC_NT_INVALID_RPC_PROTSEQ                                            => Some("RPC_NT_INVALID_RPC_PROTSEQ"),
-        SMB_NTRPC_NT_INVALID_STRING_UUID                                            => Some("RPC_NT_INVALID_STRING_UUID"),
-        SMB_NTRPC_NT_INVALID_ENDPOINT_FORMAT                                        => Some("RPC_NT_INVALID_ENDPOINT_FORMAT"),
-        SMB_NTRPC_NT_INVALID_NET_ADDR                                               => Some("RPC_NT_INVALID_NET_ADDR"),
-        SMB_NTRPC_NT_NO_ENDPOINT_FOUND                                              => Some("RPC_NT_NO_ENDPOINT_FOUND"),
-        SMB_NTRPC_NT_INVALID_TIMEOUT                                                => Some("RPC_NT_INVALID_TIMEOUT"),
-        SMB_NTRPC_NT_OBJECT_NOT_FOUND                                               => Some("RPC_NT_OBJECT_NOT_FOUND"),
-        SMB_NTRPC_NT_ALREADY_REGISTERED                                             => Some("RPC_NT_ALREADY_REGISTERED"),
-        SMB_NTRPC_NT_TYPE_ALREADY_REGISTERED                                        => Some("RPC_NT_TYPE_ALREADY_REGISTERED"),
-        SMB_NTRPC_NT_ALREADY_LISTENING                                              => Some("RPC_NT_ALREADY_LISTENING"),
-        SMB_NTRPC_NT_NO_PROTSEQS_REGISTERED                                         => Some("RPC_NT_NO_PROTSEQS_REGISTERED"),
-        SMB_NTRPC_NT_NOT_LISTENING                                                  => Some("RPC_NT_NOT_LISTENING"),
-        SMB_NTRPC_NT_UNKNOWN_MGR_TYPE                                               => Some("RPC_NT_UNKNOWN_MGR_TYPE"),
-        SMB_NTRPC_NT_UNKNOWN_IF                                                     => Some("RPC_NT_UNKNOWN_IF"),
-        SMB_NTRPC_NT_NO_BINDINGS                                                    => Some("RPC_NT_NO_BINDINGS"),
-        SMB_NTRPC_NT_NO_PROTSEQS                                                    => Some("RPC_NT_NO_PROTSEQS"),
-        SMB_NTRPC_NT_CANT_CREATE_ENDPOINT                                           => Some("RPC_NT_CANT_CREATE_ENDPOINT"),
-        SMB_NTRPC_NT_OUT_OF_RESOURCES                                               => Some("RPC_NT_OUT_OF_RESOURCES"),
-        SMB_NTRPC_NT_SERVER_UNAVAILABLE                                             => Some("RPC_NT_SERVER_UNAVAILABLE"),
-        SMB_NTRPC_NT_SERVER_TOO_BUSY                                                => Some("RPC_NT_SERVER_TOO_BUSY"),
-        SMB_NTRPC_NT_INVALID_NETWORK_OPTIONS                                        => Some("RPC_NT_INVALID_NETWORK_OPTIONS"),
-        SMB_NTRPC_NT_NO_CALL_ACTIVE                                                 => Some("RPC_NT_NO_CALL_ACTIVE"),
-        SMB_NTRPC_NT_CALL_FAILED                                                    => Some("RPC_NT_CALL_FAILED"),
-        SMB_NTRPC_NT_CALL_FAILED_DNE                                                => Some("RPC_NT_CALL_FAILED_DNE"),
-        SMB_NTRPC_NT_PROTOCOL_ERROR                                                 => Some("RPC_NT_PROTOCOL_ERROR"),
-        SMB_NTRPC_NT_UNSUPPORTED_TRANS_SYN                                          => Some("RPC_NT_UNSUPPORTED_TRANS_SYN"),
-        SMB_NTRPC_NT_UNSUPPORTED_TYPE                                               => Some("RPC_NT_UNSUPPORTED_TYPE"),
-        SMB_NTRPC_NT_INVALID_TAG                                                    => Some("RPC_NT_INVALID_TAG"),
-        SMB_NTRPC_NT_INVALID_BOUND                                                  => Some("RPC_NT_INVALID_BOUND"),
-        SMB_NTRPC_NT_NO_ENTRY_NAME                                                  => Some("RPC_NT_NO_ENTRY_NAME"),
-        SMB_NTRPC_NT_INVALID_NAME_SYNTAX                                            => Some("RPC_NT_INVALID_NAME_SYNTAX"),
-        SMB_NTRPC_NT_UNSUPPORTED_NAME_SYNTAX                                        => Some("RPC_NT_UNSUPPORTED_NAME_SYNTAX"),
-        SMB_NTRPC_NT_UUID_NO_ADDRESS                                                => Some("RPC_NT_UUID_NO_ADDRESS"),
-        SMB_NTRPC_NT_DUPLICATE_ENDPOINT                                             => Some("RPC_NT_DUPLICATE_ENDPOINT"),
-        SMB_NTRPC_NT_UNKNOWN_AUTHN_TYPE                                             => Some("RPC_NT_UNKNOWN_AUTHN_TYPE"),
-        SMB_NTRPC_NT_MAX_CALLS_TOO_SMALL                                            => Some("RPC_NT_MAX_CALLS_TOO_SMALL"),
-        SMB_NTRPC_NT_STRING_TOO_LONG                                                => Some("RPC_NT_STRING_TOO_LONG"),
-        SMB_NTRPC_NT_PROTSEQ_NOT_FOUND                                              => Some("RPC_NT_PROTSEQ_NOT_FOUND"),
-        SMB_NTRPC_NT_PROCNUM_OUT_OF_RANGE                                           => Some("RPC_NT_PROCNUM_OUT_OF_RANGE"),
-        SMB_NTRPC_NT_BINDING_HAS_NO_AUTH                                            => Some("RPC_NT_BINDING_HAS_NO_AUTH"),
-        SMB_NTRPC_NT_UNKNOWN_AUTHN_SERVICE                                          => Some("RPC_NT_UNKNOWN_AUTHN_SERVICE"),
-        SMB_NTRPC_NT_UNKNOWN_AUTHN_LEVEL                                            => Some("RPC_NT_UNKNOWN_AUTHN_LEVEL"),
-        SMB_NTRPC_NT_INVALID_AUTH_IDENTITY                                          => Some("RPC_NT_INVALID_AUTH_IDENTITY"),
-        SMB_NTRPC_NT_UNKNOWN_AUTHZ_SERVICE                                          => Some("RPC_NT_UNKNOWN_AUTHZ_SERVICE"),
-        SMB_NTEPT_NT_INVALID_ENTRY                                                  => Some("EPT_NT_INVALID_ENTRY"),
-        SMB_NTEPT_NT_CANT_PERFORM_OP                                                => Some("EPT_NT_CANT_PERFORM_OP"),
-        SMB_NTEPT_NT_NOT_REGISTERED                                                 => Some("EPT_NT_NOT_REGISTERED"),
-        SMB_NTRPC_NT_NOTHING_TO_EXPORT                                              => Some("RPC_NT_NOTHING_TO_EXPORT"),
-        SMB_NTRPC_NT_INCOMPLETE_NAME                                                => Some("RPC_NT_INCOMPLETE_NAME"),
-        SMB_NTRPC_NT_INVALID_VERS_OPTION                                            => Some("RPC_NT_INVALID_VERS_OPTION"),
-        SMB_NTRPC_NT_NO_MORE_MEMBERS                                                => Some("RPC_NT_NO_MORE_MEMBERS"),
-        SMB_NTRPC_NT_NOT_ALL_OBJS_UNEXPORTED                                        => Some("RPC_NT_NOT_ALL_OBJS_UNEXPORTED"),
-        SMB_NTRPC_NT_INTERFACE_NOT_FOUND                                            => Some("RPC_NT_INTERFACE_NOT_FOUND"),
-        SMB_NTRPC_NT_ENTRY_ALREADY_EXISTS                                           => Some("RPC_NT_ENTRY_ALREADY_EXISTS"),
-        SMB_NTRPC_NT_ENTRY_NOT_FOUND                                                => Some("RPC_NT_ENTRY_NOT_FOUND"),
-        SMB_NTRPC_NT_NAME_SERVICE_UNAVAILABLE                                       => Some("RPC_NT_NAME_SERVICE_UNAVAILABLE"),
-        SMB_NTRPC_NT_INVALID_NAF_ID                                                 => Some("RPC_NT_INVALID_NAF_ID"),
-        SMB_NTRPC_NT_CANNOT_SUPPORT                                                 => Some("RPC_NT_CANNOT_SUPPORT"),
-        SMB_NTRPC_NT_NO_CONTEXT_AVAILABLE                                           => Some("RPC_NT_NO_CONTEXT_AVAILABLE"),
-        SMB_NTRPC_NT_INTERNAL_ERROR                                                 => Some("RPC_NT_INTERNAL_ERROR"),
-        SMB_NTRPC_NT_ZERO_DIVIDE                                                    => Some("RPC_NT_ZERO_DIVIDE"),
-        SMB_NTRPC_NT_ADDRESS_ERROR                                                  => Some("RPC_NT_ADDRESS_ERROR"),
-        SMB_NTRPC_NT_FP_DIV_ZERO                                                    => Some("RPC_NT_FP_DIV_ZERO"),
-        SMB_NTRPC_NT_FP_UNDERFLOW                                                   => Some("RPC_NT_FP_UNDERFLOW"),
-        SMB_NTRPC_NT_FP_OVERFLOW                                                    => Some("RPC_NT_FP_OVERFLOW"),
-        SMB_NTRPC_NT_CALL_IN_PROGRESS                                               => Some("RPC_NT_CALL_IN_PROGRESS"),
-        SMB_NTRPC_NT_NO_MORE_BINDINGS                                               => Some("RPC_NT_NO_MORE_BINDINGS"),
-        SMB_NTRPC_NT_GROUP_MEMBER_NOT_FOUND                                         => Some("RPC_NT_GROUP_MEMBER_NOT_FOUND"),
-        SMB_NTEPT_NT_CANT_CREATE                                                    => Some("EPT_NT_CANT_CREATE"),
-        SMB_NTRPC_NT_INVALID_OBJECT                                                 => Some("RPC_NT_INVALID_OBJECT"),
-        SMB_NTRPC_NT_NO_INTERFACES                                                  => Some("RPC_NT_NO_INTERFACES"),
-        SMB_NTRPC_NT_CALL_CANCELLED                                                 => Some("RPC_NT_CALL_CANCELLED"),
-        SMB_NTRPC_NT_BINDING_INCOMPLETE                                             => Some("RPC_NT_BINDING_INCOMPLETE"),
-        SMB_NTRPC_NT_COMM_FAILURE                                                   => Some("RPC_NT_COMM_FAILURE"),
-        SMB_NTRPC_NT_UNSUPPORTED_AUTHN_LEVEL                                        => Some("RPC_NT_UNSUPPORTED_AUTHN_LEVEL"),
-        SMB_NTRPC_NT_NO_PRINC_NAME                                                  => Some("RPC_NT_NO_PRINC_NAME"),
-        SMB_NTRPC_NT_NOT_RPC_ERROR                                                  => Some("RPC_NT_NOT_RPC_ERROR"),
-        SMB_NTRPC_NT_SEC_PKG_ERROR                                                  => Some("RPC_NT_SEC_PKG_ERROR"),
-        SMB_NTRPC_NT_NOT_CANCELLED                                                  => Some("RPC_NT_NOT_CANCELLED"),
-        SMB_NTRPC_NT_INVALID_ASYNC_HANDLE                                           => Some("RPC_NT_INVALID_ASYNC_HANDLE"),
-        SMB_NTRPC_NT_INVALID_ASYNC_CALL                                             => Some("RPC_NT_INVALID_ASYNC_CALL"),
-        SMB_NTRPC_NT_PROXY_ACCESS_DENIED                                            => Some("RPC_NT_PROXY_ACCESS_DENIED"),
-        SMB_NTRPC_NT_NO_MORE_ENTRIES                                                => Some("RPC_NT_NO_MORE_ENTRIES"),
-        SMB_NTRPC_NT_SS_CHAR_TRANS_OPEN_FAIL                                        => Some("RPC_NT_SS_CHAR_TRANS_OPEN_FAIL"),
-        SMB_NTRPC_NT_SS_CHAR_TRANS_SHORT_FILE                                       => Some("RPC_NT_SS_CHAR_TRANS_SHORT_FILE"),
-        SMB_NTRPC_NT_SS_IN_NULL_CONTEXT                                             => Some("RPC_NT_SS_IN_NULL_CONTEXT"),
-        SMB_NTRPC_NT_SS_CONTEXT_MISMATCH                                            => Some("RPC_NT_SS_CONTEXT_MISMATCH"),
-        SMB_NTRPC_NT_SS_CONTEXT_DAMAGED                                             => Some("RPC_NT_SS_CONTEXT_DAMAGED"),
-        SMB_NTRPC_NT_SS_HANDLES_MISMATCH                                            => Some("RPC_NT_SS_HANDLES_MISMATCH"),
-        SMB_NTRPC_NT_SS_CANNOT_GET_CALL_HANDLE                                      => Some("RPC_NT_SS_CANNOT_GET_CALL_HANDLE"),
-        SMB_NTRPC_NT_NULL_REF_POINTER                                               => Some("RPC_NT_NULL_REF_POINTER"),
-        SMB_NTRPC_NT_ENUM_VALUE_OUT_OF_RANGE                                        => Some("RPC_NT_ENUM_VALUE_OUT_OF_RANGE"),
-        SMB_NTRPC_NT_BYTE_COUNT_TOO_SMALL                                           => Some("RPC_NT_BYTE_COUNT_TOO_SMALL"),
-        SMB_NTRPC_NT_BAD_STUB_DATA                                                  => Some("RPC_NT_BAD_STUB_DATA"),
-        SMB_NTRPC_NT_INVALID_ES_ACTION                                              => Some("RPC_NT_INVALID_ES_ACTION"),
-        SMB_NTRPC_NT_WRONG_ES_VERSION                                               => Some("RPC_NT_WRONG_ES_VERSION"),
-        SMB_NTRPC_NT_WRONG_STUB_VERSION                                             => Some("RPC_NT_WRONG_STUB_VERSION"),
-        SMB_NTRPC_NT_INVALID_PIPE_OBJECT                                            => Some("RPC_NT_INVALID_PIPE_OBJECT"),
-        SMB_NTRPC_NT_INVALID_PIPE_OPERATION                                         => Some("RPC_NT_INVALID_PIPE_OPERATION"),
-        SMB_NTRPC_NT_WRONG_PIPE_VERSION                                             => Some("RPC_NT_WRONG_PIPE_VERSION"),
-        SMB_NTRPC_NT_PIPE_CLOSED                                                    => Some("RPC_NT_PIPE_CLOSED"),
-        SMB_NTRPC_NT_PIPE_DISCIPLINE_ERROR                                          => Some("RPC_NT_PIPE_DISCIPLINE_ERROR"),
-        SMB_NTRPC_NT_PIPE_EMPTY                                                     => Some("RPC_NT_PIPE_EMPTY"),
-        SMB_NTSTATUS_PNP_BAD_MPS_TABLE                                              => Some("STATUS_PNP_BAD_MPS_TABLE"),
-        SMB_NTSTATUS_PNP_TRANSLATION_FAILED                                         => Some("STATUS_PNP_TRANSLATION_FAILED"),
-        SMB_NTSTATUS_PNP_IRQ_TRANSLATION_FAILED                                     => Some("STATUS_PNP_IRQ_TRANSLATION_FAILED"),
-        SMB_NTSTATUS_PNP_INVALID_ID                                                 => Some("STATUS_PNP_INVALID_ID"),
-        SMB_NTSTATUS_IO_REISSUE_AS_CACHED                                           => Some("STATUS_IO_REISSUE_AS_CACHED"),
-        SMB_NTSTATUS_CTX_WINSTATION_NAME_INVALID                                    => Some("STATUS_CTX_WINSTATION_NAME_INVALID"),
-        SMB_NTSTATUS_CTX_INVALID_PD                                                 => Some("STATUS_CTX_INVALID_PD"),
-        SMB_NTSTATUS_CTX_PD_NOT_FOUND                                               => Some("STATUS_CTX_PD_NOT_FOUND"),
-        SMB_NTSTATUS_CTX_CLOSE_PENDING                                              => Some("STATUS_CTX_CLOSE_PENDING"),
-        SMB_NTSTATUS_CTX_NO_OUTBUF                                                  => Some("STATUS_CTX_NO_OUTBUF"),
-        SMB_NTSTATUS_CTX_MODEM_INF_NOT_FOUND                                        => Some("STATUS_CTX_MODEM_INF_NOT_FOUND"),
-        SMB_NTSTATUS_CTX_INVALID_MODEMNAME                                          => Some("STATUS_CTX_INVALID_MODEMNAME"),
-        SMB_NTSTATUS_CTX_RESPONSE_ERROR                                             => Some("STATUS_CTX_RESPONSE_ERROR"),
-        SMB_NTSTATUS_CTX_MODEM_RESPONSE_TIMEOUT                                     => Some("STATUS_CTX_MODEM_RESPONSE_TIMEOUT"),
-        SMB_NTSTATUS_CTX_MODEM_RESPONSE_NO_CARRIER                                  => Some("STATUS_CTX_MODEM_RESPONSE_NO_CARRIER"),
-        SMB_NTSTATUS_CTX_MODEM_RESPONSE_NO_DIALTONE                                 => Some("STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE"),
-        SMB_NTSTATUS_CTX_MODEM_RESPONSE_BUSY                                        => Some("STATUS_CTX_MODEM_RESPONSE_BUSY"),
-        SMB_NTSTATUS_CTX_MODEM_RESPONSE_VOICE                                       => Some("STATUS_CTX_MODEM_RESPONSE_VOICE"),
-        SMB_NTSTATUS_CTX_TD_ERROR                                                   => Some("STATUS_CTX_TD_ERROR"),
-        SMB_NTSTATUS_CTX_LICENSE_CLIENT_INVALID                                     => Some("STATUS_CTX_LICENSE_CLIENT_INVALID"),
-        SMB_NTSTATUS_CTX_LICENSE_NOT_AVAILABLE                                      => Some("STATUS_CTX_LICENSE_NOT_AVAILABLE"),
-        SMB_NTSTATUS_CTX_LICENSE_EXPIRED                                            => Some("STATUS_CTX_LICENSE_EXPIRED"),
-        SMB_NTSTATUS_CTX_WINSTATION_NOT_FOUND                                       => Some("STATUS_CTX_WINSTATION_NOT_FOUND"),
-        SMB_NTSTATUS_CTX_WINSTATION_NAME_COLLISION                                  => Some("STATUS_CTX_WINSTATION_NAME_COLLISION"),
-        SMB_NTSTATUS_CTX_WINSTATION_BUSY                                            => Some("STATUS_CTX_WINSTATION_BUSY"),
-        SMB_NTSTATUS_CTX_BAD_VIDEO_MODE                                             => Some("STATUS_CTX_BAD_VIDEO_MODE"),
-        SMB_NTSTATUS_CTX_GRAPHICS_INVALID                                           => Some("STATUS_CTX_GRAPHICS_INVALID"),
-        SMB_NTSTATUS_CTX_NOT_CONSOLE                                                => Some("STATUS_CTX_NOT_CONSOLE"),
-        SMB_NTSTATUS_CTX_CLIENT_QUERY_TIMEOUT                                       => Some("STATUS_CTX_CLIENT_QUERY_TIMEOUT"),
-        SMB_NTSTATUS_CTX_CONSOLE_DISCONNECT                                         => Some("STATUS_CTX_CONSOLE_DISCONNECT"),
-        SMB_NTSTATUS_CTX_CONSOLE_CONNECT                                            => Some("STATUS_CTX_CONSOLE_CONNECT"),
-        SMB_NTSTATUS_CTX_SHADOW_DENIED                                              => Some("STATUS_CTX_SHADOW_DENIED"),
-        SMB_NTSTATUS_CTX_WINSTATION_ACCESS_DENIED                                   => Some("STATUS_CTX_WINSTATION_ACCESS_DENIED"),
-        SMB_NTSTATUS_CTX_INVALID_WD                                                 => Some("STATUS_CTX_INVALID_WD"),
-        SMB_NTSTATUS_CTX_WD_NOT_FOUND                                               => Some("STATUS_CTX_WD_NOT_FOUND"),
-        SMB_NTSTATUS_CTX_SHADOW_INVALID                                             => Some("STATUS_CTX_SHADOW_INVALID"),
-        SMB_NTSTATUS_CTX_SHADOW_DISABLED                                            => Some("STATUS_CTX_SHADOW_DISABLED"),
-        SMB_NTSTATUS_RDP_PROTOCOL_ERROR                                             => Some("STATUS_RDP_PROTOCOL_ERROR"),
-        SMB_NTSTATUS_CTX_CLIENT_LICENSE_NOT_SET                                     => Some("STATUS_CTX_CLIENT_LICENSE_NOT_SET"),
-        SMB_NTSTATUS_CTX_CLIENT_LICENSE_IN_USE                                      => Some("STATUS_CTX_CLIENT_LICENSE_IN_USE"),
-        SMB_NTSTATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE                                => Some("STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE"),
-        SMB_NTSTATUS_CTX_SHADOW_NOT_RUNNING                                         => Some("STATUS_CTX_SHADOW_NOT_RUNNING"),
-        SMB_NTSTATUS_CTX_LOGON_DISABLED                                             => Some("STATUS_CTX_LOGON_DISABLED"),
-        SMB_NTSTATUS_CTX_SECURITY_LAYER_ERROR                                       => Some("STATUS_CTX_SECURITY_LAYER_ERROR"),
-        SMB_NTSTATUS_TS_INCOMPATIBLE_SESSIONS                                       => Some("STATUS_TS_INCOMPATIBLE_SESSIONS"),
-        SMB_NTSTATUS_MUI_FILE_NOT_FOUND                                             => Some("STATUS_MUI_FILE_NOT_FOUND"),
-        SMB_NTSTATUS_MUI_INVALID_FILE                                               => Some("STATUS_MUI_INVALID_FILE"),
-        SMB_NTSTATUS_MUI_INVALID_RC_CONFIG                                          => Some("STATUS_MUI_INVALID_RC_CONFIG"),
-        SMB_NTSTATUS_MUI_INVALID_LOCALE_NAME                                        => Some("STATUS_MUI_INVALID_LOCALE_NAME"),
-        SMB_NTSTATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME                              => Some("STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME"),
-        SMB_NTSTATUS_MUI_FILE_NOT_LOADED                                            => Some("STATUS_MUI_FILE_NOT_LOADED"),
-        SMB_NTSTATUS_RESOURCE_ENUM_USER_STOP                                        => Some("STATUS_RESOURCE_ENUM_USER_STOP"),
-        SMB_NTSTATUS_CLUSTER_INVALID_NODE                                           => Some("STATUS_CLUSTER_INVALID_NODE"),
-        SMB_NTSTATUS_CLUSTER_NODE_EXISTS                                            => Some("STATUS_CLUSTER_NODE_EXISTS"),
-        SMB_NTSTATUS_CLUSTER_JOIN_IN_PROGRESS                                       => Some("STATUS_CLUSTER_JOIN_IN_PROGRESS"),
-        SMB_NTSTATUS_CLUSTER_NODE_NOT_FOUND                                         => Some("STATUS_CLUSTER_NODE_NOT_FOUND"),
-        SMB_NTSTATUS_CLUSTER_LOCAL_NODE_NOT_FOUND                                   => Some("STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND"),
-        SMB_NTSTATUS_CLUSTER_NETWORK_EXISTS                                         => Some("STATUS_CLUSTER_NETWORK_EXISTS"),
-        SMB_NTSTATUS_CLUSTER_NETWORK_NOT_FOUND                                      => Some("STATUS_CLUSTER_NETWORK_NOT_FOUND"),
-        SMB_NTSTATUS_CLUSTER_NETINTERFACE_EXISTS                                    => Some("STATUS_CLUSTER_NETINTERFACE_EXISTS"),
-        SMB_NTSTATUS_CLUSTER_NETINTERFACE_NOT_FOUND                                 => Some("STATUS_CLUSTER_NETINTERFACE_NOT_FOUND"),
-        SMB_NTSTATUS_CLUSTER_INVALID_REQUEST                                        => Some("STATUS_CLUSTER_INVALID_REQUEST"),
-        SMB_NTSTATUS_CLUSTER_INVALID_NETWORK_PROVIDER                               => Some("STATUS_CLUSTER_INVALID_NETWORK_PROVIDER"),
-        SMB_NTSTATUS_CLUSTER_NODE_DOWN                                              => Some("STATUS_CLUSTER_NODE_DOWN"),
-        SMB_NTSTATUS_CLUSTER_NODE_UNREACHABLE                                       => Some("STATUS_CLUSTER_NODE_UNREACHABLE"),
-        SMB_NTSTATUS_CLUSTER_NODE_NOT_MEMBER                                        => Some("STATUS_CLUSTER_NODE_NOT_MEMBER"),
-        SMB_NTSTATUS_CLUSTER_JOIN_NOT_IN_PROGRESS                                   => Some("STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS"),
-        SMB_NTSTATUS_CLUSTER_INVALID_NETWORK                                        => Some("STATUS_CLUSTER_INVALID_NETWORK"),
-        SMB_NTSTATUS_CLUSTER_NO_NET_ADAPTERS                                        => Some("STATUS_CLUSTER_NO_NET_ADAPTERS"),
-        SMB_NTSTATUS_CLUSTER_NODE_UP                                                => Some("STATUS_CLUSTER_NODE_UP"),
-        SMB_NTSTATUS_CLUSTER_NODE_PAUSED                                            => Some("STATUS_CLUSTER_NODE_PAUSED"),
-        SMB_NTSTATUS_CLUSTER_NODE_NOT_PAUSED                                        => Some("STATUS_CLUSTER_NODE_NOT_PAUSED"),
-        SMB_NTSTATUS_CLUSTER_NO_SECURITY_CONTEXT                                    => Some("STATUS_CLUSTER_NO_SECURITY_CONTEXT"),
-        SMB_NTSTATUS_CLUSTER_NETWORK_NOT_INTERNAL                                   => Some("STATUS_CLUSTER_NETWORK_NOT_INTERNAL"),
-        SMB_NTSTATUS_CLUSTER_POISONED                                               => Some("STATUS_CLUSTER_POISONED"),
-        SMB_NTSTATUS_ACPI_INVALID_OPCODE                                            => Some("STATUS_ACPI_INVALID_OPCODE"),
-        SMB_NTSTATUS_ACPI_STACK_OVERFLOW                                            => Some("STATUS_ACPI_STACK_OVERFLOW"),
-        SMB_NTSTATUS_ACPI_ASSERT_FAILED                                             => Some("STATUS_ACPI_ASSERT_FAILED"),
-        SMB_NTSTATUS_ACPI_INVALID_INDEX                                             => Some("STATUS_ACPI_INVALID_INDEX"),
-        SMB_NTSTATUS_ACPI_INVALID_ARGUMENT                                          => Some("STATUS_ACPI_INVALID_ARGUMENT"),
-        SMB_NTSTATUS_ACPI_FATAL                                                     => Some("STATUS_ACPI_FATAL"),
-        SMB_NTSTATUS_ACPI_INVALID_SUPERNAME                                         => Some("STATUS_ACPI_INVALID_SUPERNAME"),
-        SMB_NTSTATUS_ACPI_INVALID_ARGTYPE                                           => Some("STATUS_ACPI_INVALID_ARGTYPE"),
-        SMB_NTSTATUS_ACPI_INVALID_OBJTYPE                                           => Some("STATUS_ACPI_INVALID_OBJTYPE"),
-        SMB_NTSTATUS_ACPI_INVALID_TARGETTYPE                                        => Some("STATUS_ACPI_INVALID_TARGETTYPE"),
-        SMB_NTSTATUS_ACPI_INCORRECT_ARGUMENT_COUNT                                  => Some("STATUS_ACPI_INCORRECT_ARGUMENT_COUNT"),
-        SMB_NTSTATUS_ACPI_ADDRESS_NOT_MAPPED                                        => Some("STATUS_ACPI_ADDRESS_NOT_MAPPED"),
-        SMB_NTSTATUS_ACPI_INVALID_EVENTTYPE                                         => Some("STATUS_ACPI_INVALID_EVENTTYPE"),
-        SMB_NTSTATUS_ACPI_HANDLER_COLLISION                                         => Some("STATUS_ACPI_HANDLER_COLLISION"),
-        SMB_NTSTATUS_ACPI_INVALID_DATA                                              => Some("STATUS_ACPI_INVALID_DATA"),
-        SMB_NTSTATUS_ACPI_INVALID_REGION                                            => Some("STATUS_ACPI_INVALID_REGION"),
-        SMB_NTSTATUS_ACPI_INVALID_ACCESS_SIZE                                       => Some("STATUS_ACPI_INVALID_ACCESS_SIZE"),
-        SMB_NTSTATUS_ACPI_ACQUIRE_GLOBAL_LOCK                                       => Some("STATUS_ACPI_ACQUIRE_GLOBAL_LOCK"),
-        SMB_NTSTATUS_ACPI_ALREADY_INITIALIZED                                       => Some("STATUS_ACPI_ALREADY_INITIALIZED"),
-        SMB_NTSTATUS_ACPI_NOT_INITIALIZED                                           => Some("STATUS_ACPI_NOT_INITIALIZED"),
-        SMB_NTSTATUS_ACPI_INVALID_MUTEX_LEVEL                                       => Some("STATUS_ACPI_INVALID_MUTEX_LEVEL"),
-        SMB_NTSTATUS_ACPI_MUTEX_NOT_OWNED                                           => Some("STATUS_ACPI_MUTEX_NOT_OWNED"),
-        SMB_NTSTATUS_ACPI_MUTEX_NOT_OWNER                                           => Some("STATUS_ACPI_MUTEX_NOT_OWNER"),
-        SMB_NTSTATUS_ACPI_RS_ACCESS                                                 => Some("STATUS_ACPI_RS_ACCESS"),
-        SMB_NTSTATUS_ACPI_INVALID_TABLE                                             => Some("STATUS_ACPI_INVALID_TABLE"),
-        SMB_NTSTATUS_ACPI_REG_HANDLER_FAILED                                        => Some("STATUS_ACPI_REG_HANDLER_FAILED"),
-        SMB_NTSTATUS_ACPI_POWER_REQUEST_FAILED                                      => Some("STATUS_ACPI_POWER_REQUEST_FAILED"),
-        SMB_NTSTATUS_SXS_SECTION_NOT_FOUND                                          => Some("STATUS_SXS_SECTION_NOT_FOUND"),
-        SMB_NTSTATUS_SXS_CANT_GEN_ACTCTX                                            => Some("STATUS_SXS_CANT_GEN_ACTCTX"),
-        SMB_NTSTATUS_SXS_INVALID_ACTCTXDATA_FORMAT                                  => Some("STATUS_SXS_INVALID_ACTCTXDATA_FORMAT"),
-        SMB_NTSTATUS_SXS_ASSEMBLY_NOT_FOUND                                         => Some("STATUS_SXS_ASSEMBLY_NOT_FOUND"),
-        SMB_NTSTATUS_SXS_MANIFEST_FORMAT_ERROR                                      => Some("STATUS_SXS_MANIFEST_FORMAT_ERROR"),
-        SMB_NTSTATUS_SXS_MANIFEST_PARSE_ERROR                                       => Some("STATUS_SXS_MANIFEST_PARSE_ERROR"),
-        SMB_NTSTATUS_SXS_ACTIVATION_CONTEXT_DISABLED                                => Some("STATUS_SXS_ACTIVATION_CONTEXT_DISABLED"),
-        SMB_NTSTATUS_SXS_KEY_NOT_FOUND                                              => Some("STATUS_SXS_KEY_NOT_FOUND"),
-        SMB_NTSTATUS_SXS_VERSION_CONFLICT                                           => Some("STATUS_SXS_VERSION_CONFLICT"),
-        SMB_NTSTATUS_SXS_WRONG_SECTION_TYPE                                         => Some("STATUS_SXS_WRONG_SECTION_TYPE"),
-        SMB_NTSTATUS_SXS_THREAD_QUERIES_DISABLED                                    => Some("STATUS_SXS_THREAD_QUERIES_DISABLED"),
-        SMB_NTSTATUS_SXS_ASSEMBLY_MISSING                                           => Some("STATUS_SXS_ASSEMBLY_MISSING"),
-        SMB_NTSTATUS_SXS_PROCESS_DEFAULT_ALREADY_SET                                => Some("STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET"),
-        SMB_NTSTATUS_SXS_EARLY_DEACTIVATION                                         => Some("STATUS_SXS_EARLY_DEACTIVATION"),
-        SMB_NTSTATUS_SXS_INVALID_DEACTIVATION                                       => Some("STATUS_SXS_INVALID_DEACTIVATION"),
-        SMB_NTSTATUS_SXS_MULTIPLE_DEACTIVATION                                      => Some("STATUS_SXS_MULTIPLE_DEACTIVATION"),
-        SMB_NTSTATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY                    => Some("STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY"),
-        SMB_NTSTATUS_SXS_PROCESS_TERMINATION_REQUESTED                              => Some("STATUS_SXS_PROCESS_TERMINATION_REQUESTED"),
-        SMB_NTSTATUS_SXS_CORRUPT_ACTIVATION_STACK                                   => Some("STATUS_SXS_CORRUPT_ACTIVATION_STACK"),
-        SMB_NTSTATUS_SXS_CORRUPTION                                                 => Some("STATUS_SXS_CORRUPTION"),
-        SMB_NTSTATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE                           => Some("STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE"),
-        SMB_NTSTATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME                            => Some("STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME"),
-        SMB_NTSTATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE                               => Some("STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE"),
-        SMB_NTSTATUS_SXS_IDENTITY_PARSE_ERROR                                       => Some("STATUS_SXS_IDENTITY_PARSE_ERROR"),
-        SMB_NTSTATUS_SXS_COMPONENT_STORE_CORRUPT                                    => Some("STATUS_SXS_COMPONENT_STORE_CORRUPT"),
-        SMB_NTSTATUS_SXS_FILE_HASH_MISMATCH                                         => Some("STATUS_SXS_FILE_HASH_MISMATCH"),
-        SMB_NTSTATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT              => Some("STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT"),
-        SMB_NTSTATUS_SXS_IDENTITIES_DIFFERENT                                       => Some("STATUS_SXS_IDENTITIES_DIFFERENT"),
-        SMB_NTSTATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT                               => Some("STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT"),
-        SMB_NTSTATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY                                  => Some("STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY"),
-        SMB_NTSTATUS_ADVANCED_INSTALLER_FAILED                                      => Some("STATUS_ADVANCED_INSTALLER_FAILED"),
-        SMB_NTSTATUS_XML_ENCODING_MISMATCH                                          => Some("STATUS_XML_ENCODING_MISMATCH"),
-        SMB_NTSTATUS_SXS_MANIFEST_TOO_BIG                                           => Some("STATUS_SXS_MANIFEST_TOO_BIG"),
-        SMB_NTSTATUS_SXS_SETTING_NOT_REGISTERED                                     => Some("STATUS_SXS_SETTING_NOT_REGISTERED"),
-        SMB_NTSTATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE                             => Some("STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE"),
-        SMB_NTSTATUS_SMI_PRIMITIVE_INSTALLER_FAILED                                 => Some("STATUS_SMI_PRIMITIVE_INSTALLER_FAILED"),
-        SMB_NTSTATUS_GENERIC_COMMAND_FAILED                                         => Some("STATUS_GENERIC_COMMAND_FAILED"),
-        SMB_NTSTATUS_SXS_FILE_HASH_MISSING                                          => Some("STATUS_SXS_FILE_HASH_MISSING"),
-        SMB_NTSTATUS_TRANSACTIONAL_CONFLICT                                         => Some("STATUS_TRANSACTIONAL_CONFLICT"),
-        SMB_NTSTATUS_INVALID_TRANSACTION                                            => Some("STATUS_INVALID_TRANSACTION"),
-        SMB_NTSTATUS_TRANSACTION_NOT_ACTIVE                                         => Some("STATUS_TRANSACTION_NOT_ACTIVE"),
-        SMB_NTSTATUS_TM_INITIALIZATION_FAILED                                       => Some("STATUS_TM_INITIALIZATION_FAILED"),
-        SMB_NTSTATUS_RM_NOT_ACTIVE                                                  => Some("STATUS_RM_NOT_ACTIVE"),
-        SMB_NTSTATUS_RM_METADATA_CORRUPT                                            => Some("STATUS_RM_METADATA_CORRUPT"),
-        SMB_NTSTATUS_TRANSACTION_NOT_JOINED                                         => Some("STATUS_TRANSACTION_NOT_JOINED"),
-        SMB_NTSTATUS_DIRECTORY_NOT_RM                                               => Some("STATUS_DIRECTORY_NOT_RM"),
-        SMB_NTSTATUS_TRANSACTIONS_UNSUPPORTED_REMOTE                                => Some("STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE"),
-        SMB_NTSTATUS_LOG_RESIZE_INVALID_SIZE                                        => Some("STATUS_LOG_RESIZE_INVALID_SIZE"),
-        SMB_NTSTATUS_REMOTE_FILE_VERSION_MISMATCH                                   => Some("STATUS_REMOTE_FILE_VERSION_MISMATCH"),
-        SMB_NTSTATUS_CRM_PROTOCOL_ALREADY_EXISTS                                    => Some("STATUS_CRM_PROTOCOL_ALREADY_EXISTS"),
-        SMB_NTSTATUS_TRANSACTION_PROPAGATION_FAILED                                 => Some("STATUS_TRANSACTION_PROPAGATION_FAILED"),
-        SMB_NTSTATUS_CRM_PROTOCOL_NOT_FOUND                                         => Some("STATUS_CRM_PROTOCOL_NOT_FOUND"),
-        SMB_NTSTATUS_TRANSACTION_SUPERIOR_EXISTS                                    => Some("STATUS_TRANSACTION_SUPERIOR_EXISTS"),
-        SMB_NTSTATUS_TRANSACTION_REQUEST_NOT_VALID                                  => Some("STATUS_TRANSACTION_REQUEST_NOT_VALID"),
-        SMB_NTSTATUS_TRANSACTION_NOT_REQUESTED                                      => Some("STATUS_TRANSACTION_NOT_REQUESTED"),
-        SMB_NTSTATUS_TRANSACTION_ALREADY_ABORTED                                    => Some("STATUS_TRANSACTION_ALREADY_ABORTED"),
-        SMB_NTSTATUS_TRANSACTION_ALREADY_COMMITTED                                  => Some("STATUS_TRANSACTION_ALREADY_COMMITTED"),
-        SMB_NTSTATUS_TRANSACTION_INVALID_MARSHALL_BUFFER                            => Some("STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER"),
-        SMB_NTSTATUS_CURRENT_TRANSACTION_NOT_VALID                                  => Some("STATUS_CURRENT_TRANSACTION_NOT_VALID"),
-        SMB_NTSTATUS_LOG_GROWTH_FAILED                                              => Some("STATUS_LOG_GROWTH_FAILED"),
-        SMB_NTSTATUS_OBJECT_NO_LONGER_EXISTS                                        => Some("STATUS_OBJECT_NO_LONGER_EXISTS"),
-        SMB_NTSTATUS_STREAM_MINIVERSION_NOT_FOUND                                   => Some("STATUS_STREAM_MINIVERSION_NOT_FOUND"),
-        SMB_NTSTATUS_STREAM_MINIVERSION_NOT_VALID                                   => Some("STATUS_STREAM_MINIVERSION_NOT_VALID"),
-        SMB_NTSTATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION            => Some("STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION"),
-        SMB_NTSTATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT                       => Some("STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT"),
-        SMB_NTSTATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS                           => Some("STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS"),
-        SMB_NTSTATUS_HANDLE_NO_LONGER_VALID                                         => Some("STATUS_HANDLE_NO_LONGER_VALID"),
-        SMB_NTSTATUS_LOG_CORRUPTION_DETECTED                                        => Some("STATUS_LOG_CORRUPTION_DETECTED"),
-        SMB_NTSTATUS_RM_DISCONNECTED                                                => Some("STATUS_RM_DISCONNECTED"),
-        SMB_NTSTATUS_ENLISTMENT_NOT_SUPERIOR                                        => Some("STATUS_ENLISTMENT_NOT_SUPERIOR"),
-        SMB_NTSTATUS_FILE_IDENTITY_NOT_PERSISTENT                                   => Some("STATUS_FILE_IDENTITY_NOT_PERSISTENT"),
-        SMB_NTSTATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY                            => Some("STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY"),
-        SMB_NTSTATUS_CANT_CROSS_RM_BOUNDARY                                         => Some("STATUS_CANT_CROSS_RM_BOUNDARY"),
-        SMB_NTSTATUS_TXF_DIR_NOT_EMPTY                                              => Some("STATUS_TXF_DIR_NOT_EMPTY"),
-        SMB_NTSTATUS_INDOUBT_TRANSACTIONS_EXIST                                     => Some("STATUS_INDOUBT_TRANSACTIONS_EXIST"),
-        SMB_NTSTATUS_TM_VOLATILE                                                    => Some("STATUS_TM_VOLATILE"),
-        SMB_NTSTATUS_ROLLBACK_TIMER_EXPIRED                                         => Some("STATUS_ROLLBACK_TIMER_EXPIRED"),
-        SMB_NTSTATUS_TXF_ATTRIBUTE_CORRUPT                                          => Some("STATUS_TXF_ATTRIBUTE_CORRUPT"),
-        SMB_NTSTATUS_EFS_NOT_ALLOWED_IN_TRANSACTION                                 => Some("STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION"),
-        SMB_NTSTATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED                                 => Some("STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED"),
-        SMB_NTSTATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE                          => Some("STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE"),
-        SMB_NTSTATUS_TRANSACTION_REQUIRED_PROMOTION                                 => Some("STATUS_TRANSACTION_REQUIRED_PROMOTION"),
-        SMB_NTSTATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION                             => Some("STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION"),
-        SMB_NTSTATUS_TRANSACTIONS_NOT_FROZEN                                        => Some("STATUS_TRANSACTIONS_NOT_FROZEN"),
-        SMB_NTSTATUS_TRANSACTION_FREEZE_IN_PROGRESS                                 => Some("STATUS_TRANSACTION_FREEZE_IN_PROGRESS"),
-        SMB_NTSTATUS_NOT_SNAPSHOT_VOLUME                                            => Some("STATUS_NOT_SNAPSHOT_VOLUME"),
-        SMB_NTSTATUS_NO_SAVEPOINT_WITH_OPEN_FILES                                   => Some("STATUS_NO_SAVEPOINT_WITH_OPEN_FILES"),
-        SMB_NTSTATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION                              => Some("STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION"),
-        SMB_NTSTATUS_TM_IDENTITY_MISMATCH                                           => Some("STATUS_TM_IDENTITY_MISMATCH"),
-        SMB_NTSTATUS_FLOATED_SECTION                                                => Some("STATUS_FLOATED_SECTION"),
-        SMB_NTSTATUS_CANNOT_ACCEPT_TRANSACTED_WORK                                  => Some("STATUS_CANNOT_ACCEPT_TRANSACTED_WORK"),
-        SMB_NTSTATUS_CANNOT_ABORT_TRANSACTIONS                                      => Some("STATUS_CANNOT_ABORT_TRANSACTIONS"),
-        SMB_NTSTATUS_TRANSACTION_NOT_FOUND                                          => Some("STATUS_TRANSACTION_NOT_FOUND"),
-        SMB_NTSTATUS_RESOURCEMANAGER_NOT_FOUND                                      => Some("STATUS_RESOURCEMANAGER_NOT_FOUND"),
-        SMB_NTSTATUS_ENLISTMENT_NOT_FOUND                                           => Some("STATUS_ENLISTMENT_NOT_FOUND"),
-        SMB_NTSTATUS_TRANSACTIONMANAGER_NOT_FOUND                                   => Some("STATUS_TRANSACTIONMANAGER_NOT_FOUND"),
-        SMB_NTSTATUS_TRANSACTIONMANAGER_NOT_ONLINE                                  => Some("STATUS_TRANSACTIONMANAGER_NOT_ONLINE"),
-        SMB_NTSTATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION                     => Some("STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION"),
-        SMB_NTSTATUS_TRANSACTION_NOT_ROOT                                           => Some("STATUS_TRANSACTION_NOT_ROOT"),
-        SMB_NTSTATUS_TRANSACTION_OBJECT_EXPIRED                                     => Some("STATUS_TRANSACTION_OBJECT_EXPIRED"),
-        SMB_NTSTATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION                         => Some("STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION"),
-        SMB_NTSTATUS_TRANSACTION_RESPONSE_NOT_ENLISTED                              => Some("STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED"),
-        SMB_NTSTATUS_TRANSACTION_RECORD_TOO_LONG                                    => Some("STATUS_TRANSACTION_RECORD_TOO_LONG"),
-        SMB_NTSTATUS_NO_LINK_TRACKING_IN_TRANSACTION                                => Some("STATUS_NO_LINK_TRACKING_IN_TRANSACTION"),
-        SMB_NTSTATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION                         => Some("STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION"),
-        SMB_NTSTATUS_TRANSACTION_INTEGRITY_VIOLATED                                 => Some("STATUS_TRANSACTION_INTEGRITY_VIOLATED"),
-        SMB_NTSTATUS_EXPIRED_HANDLE                                                 => Some("STATUS_EXPIRED_HANDLE"),
-        SMB_NTSTATUS_TRANSACTION_NOT_ENLISTED                                       => Some("STATUS_TRANSACTION_NOT_ENLISTED"),
-        SMB_NTSTATUS_LOG_SECTOR_INVALID                                             => Some("STATUS_LOG_SECTOR_INVALID"),
-        SMB_NTSTATUS_LOG_SECTOR_PARITY_INVALID                                      => Some("STATUS_LOG_SECTOR_PARITY_INVALID"),
-        SMB_NTSTATUS_LOG_SECTOR_REMAPPED                                            => Some("STATUS_LOG_SECTOR_REMAPPED"),
-        SMB_NTSTATUS_LOG_BLOCK_INCOMPLETE                                           => Some("STATUS_LOG_BLOCK_INCOMPLETE"),
-        SMB_NTSTATUS_LOG_INVALID_RANGE                                              => Some("STATUS_LOG_INVALID_RANGE"),
-        SMB_NTSTATUS_LOG_BLOCKS_EXHAUSTED                                           => Some("STATUS_LOG_BLOCKS_EXHAUSTED"),
-        SMB_NTSTATUS_LOG_READ_CONTEXT_INVALID                                       => Some("STATUS_LOG_READ_CONTEXT_INVALID"),
-        SMB_NTSTATUS_LOG_RESTART_INVALID                                            => Some("STATUS_LOG_RESTART_INVALID"),
-        SMB_NTSTATUS_LOG_BLOCK_VERSION                                              => Some("STATUS_LOG_BLOCK_VERSION"),
-        SMB_NTSTATUS_LOG_BLOCK_INVALID                                              => Some("STATUS_LOG_BLOCK_INVALID"),
-        SMB_NTSTATUS_LOG_READ_MODE_INVALID                                          => Some("STATUS_LOG_READ_MODE_INVALID"),
-        SMB_NTSTATUS_LOG_METADATA_CORRUPT                                           => Some("STATUS_LOG_METADATA_CORRUPT"),
-        SMB_NTSTATUS_LOG_METADATA_INVALID                                           => Some("STATUS_LOG_METADATA_INVALID"),
-        SMB_NTSTATUS_LOG_METADATA_INCONSISTENT                                      => Some("STATUS_LOG_METADATA_INCONSISTENT"),
-        SMB_NTSTATUS_LOG_RESERVATION_INVALID                                        => Some("STATUS_LOG_RESERVATION_INVALID"),
-        SMB_NTSTATUS_LOG_CANT_DELETE                                                => Some("STATUS_LOG_CANT_DELETE"),
-        SMB_NTSTATUS_LOG_CONTAINER_LIMIT_EXCEEDED                                   => Some("STATUS_LOG_CONTAINER_LIMIT_EXCEEDED"),
-        SMB_NTSTATUS_LOG_START_OF_LOG                                               => Some("STATUS_LOG_START_OF_LOG"),
-        SMB_NTSTATUS_LOG_POLICY_ALREADY_INSTALLED                                   => Some("STATUS_LOG_POLICY_ALREADY_INSTALLED"),
-        SMB_NTSTATUS_LOG_POLICY_NOT_INSTALLED                                       => Some("STATUS_LOG_POLICY_NOT_INSTALLED"),
-        SMB_NTSTATUS_LOG_POLICY_INVALID                                             => Some("STATUS_LOG_POLICY_INVALID"),
-        SMB_NTSTATUS_LOG_POLICY_CONFLICT                                            => Some("STATUS_LOG_POLICY_CONFLICT"),
-        SMB_NTSTATUS_LOG_PINNED_ARCHIVE_TAIL                                        => Some("STATUS_LOG_PINNED_ARCHIVE_TAIL"),
-        SMB_NTSTATUS_LOG_RECORD_NONEXISTENT                                         => Some("STATUS_LOG_RECORD_NONEXISTENT"),
-        SMB_NTSTATUS_LOG_RECORDS_RESERVED_INVALID                                   => Some("STATUS_LOG_RECORDS_RESERVED_INVALID"),
-        SMB_NTSTATUS_LOG_SPACE_RESERVED_INVALID                                     => Some("STATUS_LOG_SPACE_RESERVED_INVALID"),
-        SMB_NTSTATUS_LOG_TAIL_INVALID                                               => Some("STATUS_LOG_TAIL_INVALID"),
-        SMB_NTSTATUS_LOG_FULL                                                       => Some("STATUS_LOG_FULL"),
-        SMB_NTSTATUS_LOG_MULTIPLEXED                                                => Some("STATUS_LOG_MULTIPLEXED"),
-        SMB_NTSTATUS_LOG_DEDICATED                                                  => Some("STATUS_LOG_DEDICATED"),
-        SMB_NTSTATUS_LOG_ARCHIVE_NOT_IN_PROGRESS                                    => Some("STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS"),
-        SMB_NTSTATUS_LOG_ARCHIVE_IN_PROGRESS                                        => Some("STATUS_LOG_ARCHIVE_IN_PROGRESS"),
-        SMB_NTSTATUS_LOG_EPHEMERAL                                                  => Some("STATUS_LOG_EPHEMERAL"),
-        SMB_NTSTATUS_LOG_NOT_ENOUGH_CONTAINERS                                      => Some("STATUS_LOG_NOT_ENOUGH_CONTAINERS"),
-        SMB_NTSTATUS_LOG_CLIENT_ALREADY_REGISTERED                                  => Some("STATUS_LOG_CLIENT_ALREADY_REGISTERED"),
-        SMB_NTSTATUS_LOG_CLIENT_NOT_REGISTERED                                      => Some("STATUS_LOG_CLIENT_NOT_REGISTERED"),
-        SMB_NTSTATUS_LOG_FULL_HANDLER_IN_PROGRESS                                   => Some("STATUS_LOG_FULL_HANDLER_IN_PROGRESS"),
-        SMB_NTSTATUS_LOG_CONTAINER_READ_FAILED                                      => Some("STATUS_LOG_CONTAINER_READ_FAILED"),
-        SMB_NTSTATUS_LOG_CONTAINER_WRITE_FAILED                                     => Some("STATUS_LOG_CONTAINER_WRITE_FAILED"),
-        SMB_NTSTATUS_LOG_CONTAINER_OPEN_FAILED                                      => Some("STATUS_LOG_CONTAINER_OPEN_FAILED"),
-        SMB_NTSTATUS_LOG_CONTAINER_STATE_INVALID                                    => Some("STATUS_LOG_CONTAINER_STATE_INVALID"),
-        SMB_NTSTATUS_LOG_STATE_INVALID                                              => Some("STATUS_LOG_STATE_INVALID"),
-        SMB_NTSTATUS_LOG_PINNED                                                     => Some("STATUS_LOG_PINNED"),
-        SMB_NTSTATUS_LOG_METADATA_FLUSH_FAILED                                      => Some("STATUS_LOG_METADATA_FLUSH_FAILED"),
-        SMB_NTSTATUS_LOG_INCONSISTENT_SECURITY                                      => Some("STATUS_LOG_INCONSISTENT_SECURITY"),
-        SMB_NTSTATUS_LOG_APPENDED_FLUSH_FAILED                                      => Some("STATUS_LOG_APPENDED_FLUSH_FAILED"),
-        SMB_NTSTATUS_LOG_PINNED_RESERVATION                                         => Some("STATUS_LOG_PINNED_RESERVATION"),
-        SMB_NTSTATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD                               => Some("STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD"),
-        SMB_NTSTATUS_FLT_NO_HANDLER_DEFINED                                         => Some("STATUS_FLT_NO_HANDLER_DEFINED"),
-        SMB_NTSTATUS_FLT_CONTEXT_ALREADY_DEFINED                                    => Some("STATUS_FLT_CONTEXT_ALREADY_DEFINED"),
-        SMB_NTSTATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST                               => Some("STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST"),
-        SMB_NTSTATUS_FLT_DISALLOW_FAST_IO                                           => Some("STATUS_FLT_DISALLOW_FAST_IO"),
-        SMB_NTSTATUS_FLT_INVALID_NAME_REQUEST                                       => Some("STATUS_FLT_INVALID_NAME_REQUEST"),
-        SMB_NTSTATUS_FLT_NOT_SAFE_TO_POST_OPERATION                                 => Some("STATUS_FLT_NOT_SAFE_TO_POST_OPERATION"),
-        SMB_NTSTATUS_FLT_NOT_INITIALIZED                                            => Some("STATUS_FLT_NOT_INITIALIZED"),
-        SMB_NTSTATUS_FLT_FILTER_NOT_READY                                           => Some("STATUS_FLT_FILTER_NOT_READY"),
-        SMB_NTSTATUS_FLT_POST_OPERATION_CLEANUP                                     => Some("STATUS_FLT_POST_OPERATION_CLEANUP"),
-        SMB_NTSTATUS_FLT_INTERNAL_ERROR                                             => Some("STATUS_FLT_INTERNAL_ERROR"),
-        SMB_NTSTATUS_FLT_DELETING_OBJECT                                            => Some("STATUS_FLT_DELETING_OBJECT"),
-        SMB_NTSTATUS_FLT_MUST_BE_NONPAGED_POOL                                      => Some("STATUS_FLT_MUST_BE_NONPAGED_POOL"),
-        SMB_NTSTATUS_FLT_DUPLICATE_ENTRY                                            => Some("STATUS_FLT_DUPLICATE_ENTRY"),
-        SMB_NTSTATUS_FLT_CBDQ_DISABLED                                              => Some("STATUS_FLT_CBDQ_DISABLED"),
-        SMB_NTSTATUS_FLT_DO_NOT_ATTACH                                              => Some("STATUS_FLT_DO_NOT_ATTACH"),
-        SMB_NTSTATUS_FLT_DO_NOT_DETACH                                              => Some("STATUS_FLT_DO_NOT_DETACH"),
-        SMB_NTSTATUS_FLT_INSTANCE_ALTITUDE_COLLISION                                => Some("STATUS_FLT_INSTANCE_ALTITUDE_COLLISION"),
-        SMB_NTSTATUS_FLT_INSTANCE_NAME_COLLISION                                    => Some("STATUS_FLT_INSTANCE_NAME_COLLISION"),
-        SMB_NTSTATUS_FLT_FILTER_NOT_FOUND                                           => Some("STATUS_FLT_FILTER_NOT_FOUND"),
-        SMB_NTSTATUS_FLT_VOLUME_NOT_FOUND                                           => Some("STATUS_FLT_VOLUME_NOT_FOUND"),
-        SMB_NTSTATUS_FLT_INSTANCE_NOT_FOUND                                         => Some("STATUS_FLT_INSTANCE_NOT_FOUND"),
-        SMB_NTSTATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND                               => Some("STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND"),
-        SMB_NTSTATUS_FLT_INVALID_CONTEXT_REGISTRATION                               => Some("STATUS_FLT_INVALID_CONTEXT_REGISTRATION"),
-        SMB_NTSTATUS_FLT_NAME_CACHE_MISS                                            => Some("STATUS_FLT_NAME_CACHE_MISS"),
-        SMB_NTSTATUS_FLT_NO_DEVICE_OBJECT                                           => Some("STATUS_FLT_NO_DEVICE_OBJECT"),
-        SMB_NTSTATUS_FLT_VOLUME_ALREADY_MOUNTED                                     => Some("STATUS_FLT_VOLUME_ALREADY_MOUNTED"),
-        SMB_NTSTATUS_FLT_ALREADY_ENLISTED                                           => Some("STATUS_FLT_ALREADY_ENLISTED"),
-        SMB_NTSTATUS_FLT_CONTEXT_ALREADY_LINKED                                     => Some("STATUS_FLT_CONTEXT_ALREADY_LINKED"),
-        SMB_NTSTATUS_FLT_NO_WAITER_FOR_REPLY                                        => Some("STATUS_FLT_NO_WAITER_FOR_REPLY"),
-        SMB_NTSTATUS_MONITOR_NO_DESCRIPTOR                                          => Some("STATUS_MONITOR_NO_DESCRIPTOR"),
-        SMB_NTSTATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT                              => Some("STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT"),
-        SMB_NTSTATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM                            => Some("STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM"),
-        SMB_NTSTATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK                          => Some("STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK"),
-        SMB_NTSTATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED                      => Some("STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED"),
-        SMB_NTSTATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK                     => Some("STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK"),
-        SMB_NTSTATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK                     => Some("STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK"),
-        SMB_NTSTATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA                                => Some("STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA"),
-        SMB_NTSTATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK                          => Some("STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK"),
-        SMB_NTSTATUS_MONITOR_INVALID_MANUFACTURE_DATE                               => Some("STATUS_MONITOR_INVALID_MANUFACTURE_DATE"),
-        SMB_NTSTATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER                              => Some("STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER"),
-        SMB_NTSTATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER                               => Some("STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER                               => Some("STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER"),
-        SMB_NTSTATUS_GRAPHICS_ADAPTER_WAS_RESET                                     => Some("STATUS_GRAPHICS_ADAPTER_WAS_RESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_DRIVER_MODEL                                  => Some("STATUS_GRAPHICS_INVALID_DRIVER_MODEL"),
-        SMB_NTSTATUS_GRAPHICS_PRESENT_MODE_CHANGED                                  => Some("STATUS_GRAPHICS_PRESENT_MODE_CHANGED"),
-        SMB_NTSTATUS_GRAPHICS_PRESENT_OCCLUDED                                      => Some("STATUS_GRAPHICS_PRESENT_OCCLUDED"),
-        SMB_NTSTATUS_GRAPHICS_PRESENT_DENIED                                        => Some("STATUS_GRAPHICS_PRESENT_DENIED"),
-        SMB_NTSTATUS_GRAPHICS_CANNOTCOLORCONVERT                                    => Some("STATUS_GRAPHICS_CANNOTCOLORCONVERT"),
-        SMB_NTSTATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED                          => Some("STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED"),
-        SMB_NTSTATUS_GRAPHICS_PRESENT_UNOCCLUDED                                    => Some("STATUS_GRAPHICS_PRESENT_UNOCCLUDED"),
-        SMB_NTSTATUS_GRAPHICS_NO_VIDEO_MEMORY                                       => Some("STATUS_GRAPHICS_NO_VIDEO_MEMORY"),
-        SMB_NTSTATUS_GRAPHICS_CANT_LOCK_MEMORY                                      => Some("STATUS_GRAPHICS_CANT_LOCK_MEMORY"),
-        SMB_NTSTATUS_GRAPHICS_ALLOCATION_BUSY                                       => Some("STATUS_GRAPHICS_ALLOCATION_BUSY"),
-        SMB_NTSTATUS_GRAPHICS_TOO_MANY_REFERENCES                                   => Some("STATUS_GRAPHICS_TOO_MANY_REFERENCES"),
-        SMB_NTSTATUS_GRAPHICS_TRY_AGAIN_LATER                                       => Some("STATUS_GRAPHICS_TRY_AGAIN_LATER"),
-        SMB_NTSTATUS_GRAPHICS_TRY_AGAIN_NOW                                         => Some("STATUS_GRAPHICS_TRY_AGAIN_NOW"),
-        SMB_NTSTATUS_GRAPHICS_ALLOCATION_INVALID                                    => Some("STATUS_GRAPHICS_ALLOCATION_INVALID"),
-        SMB_NTSTATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE                      => Some("STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE"),
-        SMB_NTSTATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED                      => Some("STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION                          => Some("STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_USAGE                              => Some("STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE"),
-        SMB_NTSTATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION                         => Some("STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION"),
-        SMB_NTSTATUS_GRAPHICS_ALLOCATION_CLOSED                                     => Some("STATUS_GRAPHICS_ALLOCATION_CLOSED"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE                           => Some("STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE                             => Some("STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE"),
-        SMB_NTSTATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE                               => Some("STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE"),
-        SMB_NTSTATUS_GRAPHICS_ALLOCATION_CONTENT_LOST                               => Some("STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST"),
-        SMB_NTSTATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE                               => Some("STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY                                => Some("STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED                          => Some("STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED                => Some("STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN                                         => Some("STATUS_GRAPHICS_INVALID_VIDPN"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE                          => Some("STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET                          => Some("STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET"),
-        SMB_NTSTATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED                          => Some("STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET                           => Some("STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET                           => Some("STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_FREQUENCY                                     => Some("STATUS_GRAPHICS_INVALID_FREQUENCY"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_ACTIVE_REGION                                 => Some("STATUS_GRAPHICS_INVALID_ACTIVE_REGION"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_TOTAL_REGION                                  => Some("STATUS_GRAPHICS_INVALID_TOTAL_REGION"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE                     => Some("STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE                     => Some("STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE"),
-        SMB_NTSTATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET                        => Some("STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY                              => Some("STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_MODE_ALREADY_IN_MODESET                               => Some("STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET                         => Some("STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET                         => Some("STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET"),
-        SMB_NTSTATUS_GRAPHICS_SOURCE_ALREADY_IN_SET                                 => Some("STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_TARGET_ALREADY_IN_SET                                 => Some("STATUS_GRAPHICS_TARGET_ALREADY_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH                            => Some("STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH"),
-        SMB_NTSTATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY                         => Some("STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET                     => Some("STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE                        => Some("STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE"),
-        SMB_NTSTATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET                             => Some("STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET                         => Some("STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_STALE_MODESET                                         => Some("STATUS_GRAPHICS_STALE_MODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET                         => Some("STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE                           => Some("STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE"),
-        SMB_NTSTATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN                       => Some("STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN"),
-        SMB_NTSTATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE                                => Some("STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE"),
-        SMB_NTSTATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION       => Some("STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION"),
-        SMB_NTSTATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES               => Some("STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES"),
-        SMB_NTSTATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY                                  => Some("STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE                 => Some("STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE"),
-        SMB_NTSTATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET                 => Some("STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET                          => Some("STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR                             => Some("STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR"),
-        SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET                          => Some("STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET                      => Some("STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET"),
-        SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE                   => Some("STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE                      => Some("STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE"),
-        SMB_NTSTATUS_GRAPHICS_RESOURCES_NOT_RELATED                                 => Some("STATUS_GRAPHICS_RESOURCES_NOT_RELATED"),
-        SMB_NTSTATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE                              => Some("STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE"),
-        SMB_NTSTATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE                              => Some("STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE"),
-        SMB_NTSTATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET                             => Some("STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET"),
-        SMB_NTSTATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER          => Some("STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER"),
-        SMB_NTSTATUS_GRAPHICS_NO_VIDPNMGR                                           => Some("STATUS_GRAPHICS_NO_VIDPNMGR"),
-        SMB_NTSTATUS_GRAPHICS_NO_ACTIVE_VIDPN                                       => Some("STATUS_GRAPHICS_NO_ACTIVE_VIDPN"),
-        SMB_NTSTATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY                                  => Some("STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_MONITOR_NOT_CONNECTED                                 => Some("STATUS_GRAPHICS_MONITOR_NOT_CONNECTED"),
-        SMB_NTSTATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY                                => Some("STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE                           => Some("STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE                            => Some("STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_STRIDE                                        => Some("STATUS_GRAPHICS_INVALID_STRIDE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PIXELFORMAT                                   => Some("STATUS_GRAPHICS_INVALID_PIXELFORMAT"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_COLORBASIS                                    => Some("STATUS_GRAPHICS_INVALID_COLORBASIS"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE                          => Some("STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE"),
-        SMB_NTSTATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY                                => Some("STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY"),
-        SMB_NTSTATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT                    => Some("STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT"),
-        SMB_NTSTATUS_GRAPHICS_VIDPN_SOURCE_IN_USE                                   => Some("STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE"),
-        SMB_NTSTATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN                              => Some("STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL                       => Some("STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION          => Some("STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION"),
-        SMB_NTSTATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED    => Some("STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_GAMMA_RAMP                                    => Some("STATUS_GRAPHICS_INVALID_GAMMA_RAMP"),
-        SMB_NTSTATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED                              => Some("STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED                           => Some("STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_MODE_NOT_IN_MODESET                                   => Some("STATUS_GRAPHICS_MODE_NOT_IN_MODESET"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON          => Some("STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE                             => Some("STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE                           => Some("STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE"),
-        SMB_NTSTATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS                     => Some("STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_SCANLINE_ORDERING                             => Some("STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING"),
-        SMB_NTSTATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED                          => Some("STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED"),
-        SMB_NTSTATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS                      => Some("STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS"),
-        SMB_NTSTATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT                           => Some("STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM                        => Some("STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN                     => Some("STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT             => Some("STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT"),
-        SMB_NTSTATUS_GRAPHICS_MAX_NUM_PATHS_REACHED                                 => Some("STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED"),
-        SMB_NTSTATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION                    => Some("STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_CLIENT_TYPE                                   => Some("STATUS_GRAPHICS_INVALID_CLIENT_TYPE"),
-        SMB_NTSTATUS_GRAPHICS_CLIENTVIDPN_NOT_SET                                   => Some("STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET"),
-        SMB_NTSTATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED                     => Some("STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED"),
-        SMB_NTSTATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED                        => Some("STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_NOT_A_LINKED_ADAPTER                                  => Some("STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER"),
-        SMB_NTSTATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED                               => Some("STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED"),
-        SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED                             => Some("STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED"),
-        SMB_NTSTATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY                               => Some("STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY"),
-        SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_STARTED                                => Some("STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED"),
-        SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON                             => Some("STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON"),
-        SMB_NTSTATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE                        => Some("STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE"),
-        SMB_NTSTATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER                                => Some("STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER"),
-        SMB_NTSTATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED                           => Some("STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_NOT_SUPPORTED                                     => Some("STATUS_GRAPHICS_OPM_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_COPP_NOT_SUPPORTED                                    => Some("STATUS_GRAPHICS_COPP_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_UAB_NOT_SUPPORTED                                     => Some("STATUS_GRAPHICS_UAB_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS                      => Some("STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS"),
-        SMB_NTSTATUS_GRAPHICS_OPM_PARAMETER_ARRAY_TOO_SMALL                         => Some("STATUS_GRAPHICS_OPM_PARAMETER_ARRAY_TOO_SMALL"),
-        SMB_NTSTATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST                        => Some("STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST"),
-        SMB_NTSTATUS_GRAPHICS_PVP_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME             => Some("STATUS_GRAPHICS_PVP_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME"),
-        SMB_NTSTATUS_GRAPHICS_PVP_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP            => Some("STATUS_GRAPHICS_PVP_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP"),
-        SMB_NTSTATUS_GRAPHICS_PVP_MIRRORING_DEVICES_NOT_SUPPORTED                   => Some("STATUS_GRAPHICS_PVP_MIRRORING_DEVICES_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_POINTER                                   => Some("STATUS_GRAPHICS_OPM_INVALID_POINTER"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INTERNAL_ERROR                                    => Some("STATUS_GRAPHICS_OPM_INTERNAL_ERROR"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_HANDLE                                    => Some("STATUS_GRAPHICS_OPM_INVALID_HANDLE"),
-        SMB_NTSTATUS_GRAPHICS_PVP_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE          => Some("STATUS_GRAPHICS_PVP_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE"),
-        SMB_NTSTATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH                        => Some("STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH"),
-        SMB_NTSTATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED                             => Some("STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED                              => Some("STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED"),
-        SMB_NTSTATUS_GRAPHICS_PVP_HFS_FAILED                                        => Some("STATUS_GRAPHICS_PVP_HFS_FAILED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_SRM                                       => Some("STATUS_GRAPHICS_OPM_INVALID_SRM"),
-        SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP                      => Some("STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP"),
-        SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP                       => Some("STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP"),
-        SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA                     => Some("STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA"),
-        SMB_NTSTATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET                                => Some("STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET"),
-        SMB_NTSTATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH                               => Some("STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH"),
-        SMB_NTSTATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE                  => Some("STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE"),
-        SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS                 => Some("STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS"),
-        SMB_NTSTATUS_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS                   => Some("STATUS_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS"),
-        SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS     => Some("STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST                       => Some("STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST"),
-        SMB_NTSTATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR                             => Some("STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR"),
-        SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS      => Some("STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS"),
-        SMB_NTSTATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED                           => Some("STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST                     => Some("STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST"),
-        SMB_NTSTATUS_GRAPHICS_I2C_NOT_SUPPORTED                                     => Some("STATUS_GRAPHICS_I2C_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST                             => Some("STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST"),
-        SMB_NTSTATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA                           => Some("STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA"),
-        SMB_NTSTATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA                              => Some("STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED                               => Some("STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_DATA                                    => Some("STATUS_GRAPHICS_DDCCI_INVALID_DATA"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE     => Some("STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING                     => Some("STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING"),
-        SMB_NTSTATUS_GRAPHICS_MCA_INTERNAL_ERROR                                    => Some("STATUS_GRAPHICS_MCA_INTERNAL_ERROR"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND                         => Some("STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH                          => Some("STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH"),
-        SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM                        => Some("STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE                       => Some("STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE"),
-        SMB_NTSTATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS                              => Some("STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS"),
-        SMB_NTSTATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED                        => Some("STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME                 => Some("STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME"),
-        SMB_NTSTATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP                => Some("STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP"),
-        SMB_NTSTATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED                       => Some("STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED"),
-        SMB_NTSTATUS_GRAPHICS_INVALID_POINTER                                       => Some("STATUS_GRAPHICS_INVALID_POINTER"),
-        SMB_NTSTATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE              => Some("STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE"),
-        SMB_NTSTATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL                             => Some("STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL"),
-        SMB_NTSTATUS_GRAPHICS_INTERNAL_ERROR                                        => Some("STATUS_GRAPHICS_INTERNAL_ERROR"),
-        SMB_NTSTATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS                       => Some("STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS"),
-        SMB_NTSTATUS_FVE_LOCKED_VOLUME                                              => Some("STATUS_FVE_LOCKED_VOLUME"),
-        SMB_NTSTATUS_FVE_NOT_ENCRYPTED                                              => Some("STATUS_FVE_NOT_ENCRYPTED"),
-        SMB_NTSTATUS_FVE_BAD_INFORMATION                                            => Some("STATUS_FVE_BAD_INFORMATION"),
-        SMB_NTSTATUS_FVE_TOO_SMALL                                                  => Some("STATUS_FVE_TOO_SMALL"),
-        SMB_NTSTATUS_FVE_FAILED_WRONG_FS                                            => Some("STATUS_FVE_FAILED_WRONG_FS"),
-        SMB_NTSTATUS_FVE_FAILED_BAD_FS                                              => Some("STATUS_FVE_FAILED_BAD_FS"),
-        SMB_NTSTATUS_FVE_FS_NOT_EXTENDED                                            => Some("STATUS_FVE_FS_NOT_EXTENDED"),
-        SMB_NTSTATUS_FVE_FS_MOUNTED                                                 => Some("STATUS_FVE_FS_MOUNTED"),
-        SMB_NTSTATUS_FVE_NO_LICENSE                                                 => Some("STATUS_FVE_NO_LICENSE"),
-        SMB_NTSTATUS_FVE_ACTION_NOT_ALLOWED                                         => Some("STATUS_FVE_ACTION_NOT_ALLOWED"),
-        SMB_NTSTATUS_FVE_BAD_DATA                                                   => Some("STATUS_FVE_BAD_DATA"),
-        SMB_NTSTATUS_FVE_VOLUME_NOT_BOUND                                           => Some("STATUS_FVE_VOLUME_NOT_BOUND"),
-        SMB_NTSTATUS_FVE_NOT_DATA_VOLUME                                            => Some("STATUS_FVE_NOT_DATA_VOLUME"),
-        SMB_NTSTATUS_FVE_CONV_READ_ERROR                                            => Some("STATUS_FVE_CONV_READ_ERROR"),
-        SMB_NTSTATUS_FVE_CONV_WRITE_ERROR                                           => Some("STATUS_FVE_CONV_WRITE_ERROR"),
-        SMB_NTSTATUS_FVE_OVERLAPPED_UPDATE                                          => Some("STATUS_FVE_OVERLAPPED_UPDATE"),
-        SMB_NTSTATUS_FVE_FAILED_SECTOR_SIZE                                         => Some("STATUS_FVE_FAILED_SECTOR_SIZE"),
-        SMB_NTSTATUS_FVE_FAILED_AUTHENTICATION                                      => Some("STATUS_FVE_FAILED_AUTHENTICATION"),
-        SMB_NTSTATUS_FVE_NOT_OS_VOLUME                                              => Some("STATUS_FVE_NOT_OS_VOLUME"),
-        SMB_NTSTATUS_FVE_KEYFILE_NOT_FOUND                                          => Some("STATUS_FVE_KEYFILE_NOT_FOUND"),
-        SMB_NTSTATUS_FVE_KEYFILE_INVALID                                            => Some("STATUS_FVE_KEYFILE_INVALID"),
-        SMB_NTSTATUS_FVE_KEYFILE_NO_VMK                                             => Some("STATUS_FVE_KEYFILE_NO_VMK"),
-        SMB_NTSTATUS_FVE_TPM_DISABLED                                               => Some("STATUS_FVE_TPM_DISABLED"),
-        SMB_NTSTATUS_FVE_TPM_SRK_AUTH_NOT_ZERO                                      => Some("STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO"),
-        SMB_NTSTATUS_FVE_TPM_INVALID_PCR                                            => Some("STATUS_FVE_TPM_INVALID_PCR"),
-        SMB_NTSTATUS_FVE_TPM_NO_VMK                                                 => Some("STATUS_FVE_TPM_NO_VMK"),
-        SMB_NTSTATUS_FVE_PIN_INVALID                                                => Some("STATUS_FVE_PIN_INVALID"),
-        SMB_NTSTATUS_FVE_AUTH_INVALID_APPLICATION                                   => Some("STATUS_FVE_AUTH_INVALID_APPLICATION"),
-        SMB_NTSTATUS_FVE_AUTH_INVALID_CONFIG                                        => Some("STATUS_FVE_AUTH_INVALID_CONFIG"),
-        SMB_NTSTATUS_FVE_DEBUGGER_ENABLED                                           => Some("STATUS_FVE_DEBUGGER_ENABLED"),
-        SMB_NTSTATUS_FVE_DRY_RUN_FAILED                                             => Some("STATUS_FVE_DRY_RUN_FAILED"),
-        SMB_NTSTATUS_FVE_BAD_METADATA_POINTER                                       => Some("STATUS_FVE_BAD_METADATA_POINTER"),
-        SMB_NTSTATUS_FVE_OLD_METADATA_COPY                                          => Some("STATUS_FVE_OLD_METADATA_COPY"),
-        SMB_NTSTATUS_FVE_REBOOT_REQUIRED                                            => Some("STATUS_FVE_REBOOT_REQUIRED"),
-        SMB_NTSTATUS_FVE_RAW_ACCESS                                                 => Some("STATUS_FVE_RAW_ACCESS"),
-        SMB_NTSTATUS_FVE_RAW_BLOCKED                                                => Some("STATUS_FVE_RAW_BLOCKED"),
-        SMB_NTSTATUS_FVE_NO_FEATURE_LICENSE                                         => Some("STATUS_FVE_NO_FEATURE_LICENSE"),
-        SMB_NTSTATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED                        => Some("STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED"),
-        SMB_NTSTATUS_FVE_CONV_RECOVERY_FAILED                                       => Some("STATUS_FVE_CONV_RECOVERY_FAILED"),
-        SMB_NTSTATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG                                  => Some("STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG"),
-        SMB_NTSTATUS_FVE_VOLUME_TOO_SMALL                                           => Some("STATUS_FVE_VOLUME_TOO_SMALL"),
-        SMB_NTSTATUS_FWP_CALLOUT_NOT_FOUND                                          => Some("STATUS_FWP_CALLOUT_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_CONDITION_NOT_FOUND                                        => Some("STATUS_FWP_CONDITION_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_FILTER_NOT_FOUND                                           => Some("STATUS_FWP_FILTER_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_LAYER_NOT_FOUND                                            => Some("STATUS_FWP_LAYER_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_PROVIDER_NOT_FOUND                                         => Some("STATUS_FWP_PROVIDER_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND                                 => Some("STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_SUBLAYER_NOT_FOUND                                         => Some("STATUS_FWP_SUBLAYER_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_NOT_FOUND                                                  => Some("STATUS_FWP_NOT_FOUND"),
-        SMB_NTSTATUS_FWP_ALREADY_EXISTS                                             => Some("STATUS_FWP_ALREADY_EXISTS"),
-        SMB_NTSTATUS_FWP_IN_USE                                                     => Some("STATUS_FWP_IN_USE"),
-        SMB_NTSTATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS                                => Some("STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS"),
-        SMB_NTSTATUS_FWP_WRONG_SESSION                                              => Some("STATUS_FWP_WRONG_SESSION"),
-        SMB_NTSTATUS_FWP_NO_TXN_IN_PROGRESS                                         => Some("STATUS_FWP_NO_TXN_IN_PROGRESS"),
-        SMB_NTSTATUS_FWP_TXN_IN_PROGRESS                                            => Some("STATUS_FWP_TXN_IN_PROGRESS"),
-        SMB_NTSTATUS_FWP_TXN_ABORTED                                                => Some("STATUS_FWP_TXN_ABORTED"),
-        SMB_NTSTATUS_FWP_SESSION_ABORTED                                            => Some("STATUS_FWP_SESSION_ABORTED"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_TXN                                           => Some("STATUS_FWP_INCOMPATIBLE_TXN"),
-        SMB_NTSTATUS_FWP_TIMEOUT                                                    => Some("STATUS_FWP_TIMEOUT"),
-        SMB_NTSTATUS_FWP_NET_EVENTS_DISABLED                                        => Some("STATUS_FWP_NET_EVENTS_DISABLED"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_LAYER                                         => Some("STATUS_FWP_INCOMPATIBLE_LAYER"),
-        SMB_NTSTATUS_FWP_KM_CLIENTS_ONLY                                            => Some("STATUS_FWP_KM_CLIENTS_ONLY"),
-        SMB_NTSTATUS_FWP_LIFETIME_MISMATCH                                          => Some("STATUS_FWP_LIFETIME_MISMATCH"),
-        SMB_NTSTATUS_FWP_BUILTIN_OBJECT                                             => Some("STATUS_FWP_BUILTIN_OBJECT"),
-        SMB_NTSTATUS_FWP_TOO_MANY_BOOTTIME_FILTERS                                  => Some("STATUS_FWP_TOO_MANY_BOOTTIME_FILTERS"),
-        SMB_NTSTATUS_FWP_NOTIFICATION_DROPPED                                       => Some("STATUS_FWP_NOTIFICATION_DROPPED"),
-        SMB_NTSTATUS_FWP_TRAFFIC_MISMATCH                                           => Some("STATUS_FWP_TRAFFIC_MISMATCH"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_SA_STATE                                      => Some("STATUS_FWP_INCOMPATIBLE_SA_STATE"),
-        SMB_NTSTATUS_FWP_NULL_POINTER                                               => Some("STATUS_FWP_NULL_POINTER"),
-        SMB_NTSTATUS_FWP_INVALID_ENUMERATOR                                         => Some("STATUS_FWP_INVALID_ENUMERATOR"),
-        SMB_NTSTATUS_FWP_INVALID_FLAGS                                              => Some("STATUS_FWP_INVALID_FLAGS"),
-        SMB_NTSTATUS_FWP_INVALID_NET_MASK                                           => Some("STATUS_FWP_INVALID_NET_MASK"),
-        SMB_NTSTATUS_FWP_INVALID_RANGE                                              => Some("STATUS_FWP_INVALID_RANGE"),
-        SMB_NTSTATUS_FWP_INVALID_INTERVAL                                           => Some("STATUS_FWP_INVALID_INTERVAL"),
-        SMB_NTSTATUS_FWP_ZERO_LENGTH_ARRAY                                          => Some("STATUS_FWP_ZERO_LENGTH_ARRAY"),
-        SMB_NTSTATUS_FWP_NULL_DISPLAY_NAME                                          => Some("STATUS_FWP_NULL_DISPLAY_NAME"),
-        SMB_NTSTATUS_FWP_INVALID_ACTION_TYPE                                        => Some("STATUS_FWP_INVALID_ACTION_TYPE"),
-        SMB_NTSTATUS_FWP_INVALID_WEIGHT                                             => Some("STATUS_FWP_INVALID_WEIGHT"),
-        SMB_NTSTATUS_FWP_MATCH_TYPE_MISMATCH                                        => Some("STATUS_FWP_MATCH_TYPE_MISMATCH"),
-        SMB_NTSTATUS_FWP_TYPE_MISMATCH                                              => Some("STATUS_FWP_TYPE_MISMATCH"),
-        SMB_NTSTATUS_FWP_OUT_OF_BOUNDS                                              => Some("STATUS_FWP_OUT_OF_BOUNDS"),
-        SMB_NTSTATUS_FWP_RESERVED                                                   => Some("STATUS_FWP_RESERVED"),
-        SMB_NTSTATUS_FWP_DUPLICATE_CONDITION                                        => Some("STATUS_FWP_DUPLICATE_CONDITION"),
-        SMB_NTSTATUS_FWP_DUPLICATE_KEYMOD                                           => Some("STATUS_FWP_DUPLICATE_KEYMOD"),
-        SMB_NTSTATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER                             => Some("STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER"),
-        SMB_NTSTATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER                          => Some("STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER"),
-        SMB_NTSTATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER                            => Some("STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER"),
-        SMB_NTSTATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT                          => Some("STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_AUTH_METHOD                                   => Some("STATUS_FWP_INCOMPATIBLE_AUTH_METHOD"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_DH_GROUP                                      => Some("STATUS_FWP_INCOMPATIBLE_DH_GROUP"),
-        SMB_NTSTATUS_FWP_EM_NOT_SUPPORTED                                           => Some("STATUS_FWP_EM_NOT_SUPPORTED"),
-        SMB_NTSTATUS_FWP_NEVER_MATCH                                                => Some("STATUS_FWP_NEVER_MATCH"),
-        SMB_NTSTATUS_FWP_PROVIDER_CONTEXT_MISMATCH                                  => Some("STATUS_FWP_PROVIDER_CONTEXT_MISMATCH"),
-        SMB_NTSTATUS_FWP_INVALID_PARAMETER                                          => Some("STATUS_FWP_INVALID_PARAMETER"),
-        SMB_NTSTATUS_FWP_TOO_MANY_SUBLAYERS                                         => Some("STATUS_FWP_TOO_MANY_SUBLAYERS"),
-        SMB_NTSTATUS_FWP_CALLOUT_NOTIFICATION_FAILED                                => Some("STATUS_FWP_CALLOUT_NOTIFICATION_FAILED"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_AUTH_CONFIG                                   => Some("STATUS_FWP_INCOMPATIBLE_AUTH_CONFIG"),
-        SMB_NTSTATUS_FWP_INCOMPATIBLE_CIPHER_CONFIG                                 => Some("STATUS_FWP_INCOMPATIBLE_CIPHER_CONFIG"),
-        SMB_NTSTATUS_FWP_DUPLICATE_AUTH_METHOD                                      => Some("STATUS_FWP_DUPLICATE_AUTH_METHOD"),
-        SMB_NTSTATUS_FWP_TCPIP_NOT_READY                                            => Some("STATUS_FWP_TCPIP_NOT_READY"),
-        SMB_NTSTATUS_FWP_INJECT_HANDLE_CLOSING                                      => Some("STATUS_FWP_INJECT_HANDLE_CLOSING"),
-        SMB_NTSTATUS_FWP_INJECT_HANDLE_STALE                                        => Some("STATUS_FWP_INJECT_HANDLE_STALE"),
-        SMB_NTSTATUS_FWP_CANNOT_PEND                                                => Some("STATUS_FWP_CANNOT_PEND"),
-        SMB_NTSTATUS_NDIS_CLOSING                                                   => Some("STATUS_NDIS_CLOSING"),
-        SMB_NTSTATUS_NDIS_BAD_VERSION                                               => Some("STATUS_NDIS_BAD_VERSION"),
-        SMB_NTSTATUS_NDIS_BAD_CHARACTERISTICS                                       => Some("STATUS_NDIS_BAD_CHARACTERISTICS"),
-        SMB_NTSTATUS_NDIS_ADAPTER_NOT_FOUND                                         => Some("STATUS_NDIS_ADAPTER_NOT_FOUND"),
-        SMB_NTSTATUS_NDIS_OPEN_FAILED                                               => Some("STATUS_NDIS_OPEN_FAILED"),
-        SMB_NTSTATUS_NDIS_DEVICE_FAILED                                             => Some("STATUS_NDIS_DEVICE_FAILED"),
-        SMB_NTSTATUS_NDIS_MULTICAST_FULL                                            => Some("STATUS_NDIS_MULTICAST_FULL"),
-        SMB_NTSTATUS_NDIS_MULTICAST_EXISTS                                          => Some("STATUS_NDIS_MULTICAST_EXISTS"),
-        SMB_NTSTATUS_NDIS_MULTICAST_NOT_FOUND                                       => Some("STATUS_NDIS_MULTICAST_NOT_FOUND"),
-        SMB_NTSTATUS_NDIS_REQUEST_ABORTED                                           => Some("STATUS_NDIS_REQUEST_ABORTED"),
-        SMB_NTSTATUS_NDIS_RESET_IN_PROGRESS                                         => Some("STATUS_NDIS_RESET_IN_PROGRESS"),
-        SMB_NTSTATUS_NDIS_INVALID_PACKET                                            => Some("STATUS_NDIS_INVALID_PACKET"),
-        SMB_NTSTATUS_NDIS_INVALID_DEVICE_REQUEST                                    => Some("STATUS_NDIS_INVALID_DEVICE_REQUEST"),
-        SMB_NTSTATUS_NDIS_ADAPTER_NOT_READY                                         => Some("STATUS_NDIS_ADAPTER_NOT_READY"),
-        SMB_NTSTATUS_NDIS_INVALID_LENGTH                                            => Some("STATUS_NDIS_INVALID_LENGTH"),
-        SMB_NTSTATUS_NDIS_INVALID_DATA                                              => Some("STATUS_NDIS_INVALID_DATA"),
-        SMB_NTSTATUS_NDIS_BUFFER_TOO_SHORT                                          => Some("STATUS_NDIS_BUFFER_TOO_SHORT"),
-        SMB_NTSTATUS_NDIS_INVALID_OID                                               => Some("STATUS_NDIS_INVALID_OID"),
-        SMB_NTSTATUS_NDIS_ADAPTER_REMOVED                                           => Some("STATUS_NDIS_ADAPTER_REMOVED"),
-        SMB_NTSTATUS_NDIS_UNSUPPORTED_MEDIA                                         => Some("STATUS_NDIS_UNSUPPORTED_MEDIA"),
-        SMB_NTSTATUS_NDIS_GROUP_ADDRESS_IN_USE                                      => Some("STATUS_NDIS_GROUP_ADDRESS_IN_USE"),
-        SMB_NTSTATUS_NDIS_FILE_NOT_FOUND                                            => Some("STATUS_NDIS_FILE_NOT_FOUND"),
-        SMB_NTSTATUS_NDIS_ERROR_READING_FILE                                        => Some("STATUS_NDIS_ERROR_READING_FILE"),
-        SMB_NTSTATUS_NDIS_ALREADY_MAPPED                                            => Some("STATUS_NDIS_ALREADY_MAPPED"),
-        SMB_NTSTATUS_NDIS_RESOURCE_CONFLICT                                         => Some("STATUS_NDIS_RESOURCE_CONFLICT"),
-        SMB_NTSTATUS_NDIS_MEDIA_DISCONNECTED                                        => Some("STATUS_NDIS_MEDIA_DISCONNECTED"),
-        SMB_NTSTATUS_NDIS_INVALID_ADDRESS                                           => Some("STATUS_NDIS_INVALID_ADDRESS"),
-        SMB_NTSTATUS_NDIS_PAUSED                                                    => Some("STATUS_NDIS_PAUSED"),
-        SMB_NTSTATUS_NDIS_INTERFACE_NOT_FOUND                                       => Some("STATUS_NDIS_INTERFACE_NOT_FOUND"),
-        SMB_NTSTATUS_NDIS_UNSUPPORTED_REVISION                                      => Some("STATUS_NDIS_UNSUPPORTED_REVISION"),
-        SMB_NTSTATUS_NDIS_INVALID_PORT                                              => Some("STATUS_NDIS_INVALID_PORT"),
-        SMB_NTSTATUS_NDIS_INVALID_PORT_STATE                                        => Some("STATUS_NDIS_INVALID_PORT_STATE"),
-        SMB_NTSTATUS_NDIS_LOW_POWER_STATE                                           => Some("STATUS_NDIS_LOW_POWER_STATE"),
-        SMB_NTSTATUS_NDIS_NOT_SUPPORTED                                             => Some("STATUS_NDIS_NOT_SUPPORTED"),
-        SMB_NTSTATUS_NDIS_OFFLOAD_POLICY                                            => Some("STATUS_NDIS_OFFLOAD_POLICY"),
-        SMB_NTSTATUS_NDIS_OFFLOAD_CONNECTION_REJECTED                               => Some("STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED"),
-        SMB_NTSTATUS_NDIS_OFFLOAD_PATH_REJECTED                                     => Some("STATUS_NDIS_OFFLOAD_PATH_REJECTED"),
-        SMB_NTSTATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED                                 => Some("STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED"),
-        SMB_NTSTATUS_NDIS_DOT11_MEDIA_IN_USE                                        => Some("STATUS_NDIS_DOT11_MEDIA_IN_USE"),
-        SMB_NTSTATUS_NDIS_DOT11_POWER_STATE_INVALID                                 => Some("STATUS_NDIS_DOT11_POWER_STATE_INVALID"),
-        SMB_NTSTATUS_NDIS_PM_WOL_PATTERN_LIST_FULL                                  => Some("STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL"),
-        SMB_NTSTATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL                             => Some("STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL"),
-        SMB_NTSTATUS_IPSEC_BAD_SPI                                                  => Some("STATUS_IPSEC_BAD_SPI"),
-        SMB_NTSTATUS_IPSEC_SA_LIFETIME_EXPIRED                                      => Some("STATUS_IPSEC_SA_LIFETIME_EXPIRED"),
-        SMB_NTSTATUS_IPSEC_WRONG_SA                                                 => Some("STATUS_IPSEC_WRONG_SA"),
-        SMB_NTSTATUS_IPSEC_REPLAY_CHECK_FAILED                                      => Some("STATUS_IPSEC_REPLAY_CHECK_FAILED"),
-        SMB_NTSTATUS_IPSEC_INVALID_PACKET                                           => Some("STATUS_IPSEC_INVALID_PACKET"),
-        SMB_NTSTATUS_IPSEC_INTEGRITY_CHECK_FAILED                                   => Some("STATUS_IPSEC_INTEGRITY_CHECK_FAILED"),
-        SMB_NTSTATUS_IPSEC_CLEAR_TEXT_DROP                                          => Some("STATUS_IPSEC_CLEAR_TEXT_DROP"),
-        SMB_NTSTATUS_IPSEC_AUTH_FIREWALL_DROP                                       => Some("STATUS_IPSEC_AUTH_FIREWALL_DROP"),
-        SMB_NTSTATUS_IPSEC_THROTTLE_DROP                                            => Some("STATUS_IPSEC_THROTTLE_DROP"),
-        SMB_NTSTATUS_IPSEC_DOSP_BLOCK                                               => Some("STATUS_IPSEC_DOSP_BLOCK"),
-        SMB_NTSTATUS_IPSEC_DOSP_RECEIVED_MULTICAST                                  => Some("STATUS_IPSEC_DOSP_RECEIVED_MULTICAST"),
-        SMB_NTSTATUS_IPSEC_DOSP_INVALID_PACKET                                      => Some("STATUS_IPSEC_DOSP_INVALID_PACKET"),
-        SMB_NTSTATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED                                 => Some("STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED"),
-        SMB_NTSTATUS_IPSEC_DOSP_MAX_ENTRIES                                         => Some("STATUS_IPSEC_DOSP_MAX_ENTRIES"),
-        SMB_NTSTATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED                                  => Some("STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED"),
-        SMB_NTSTATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES                         => Some("STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES"),
-        SMB_NTSTATUS_VOLMGR_MIRROR_NOT_SUPPORTED                                    => Some("STATUS_VOLMGR_MIRROR_NOT_SUPPORTED"),
-        SMB_NTSTATUS_VOLMGR_RAID5_NOT_SUPPORTED                                     => Some("STATUS_VOLMGR_RAID5_NOT_SUPPORTED"),
-        SMB_NTSTATUS_VIRTDISK_PROVIDER_NOT_FOUND                                    => Some("STATUS_VIRTDISK_PROVIDER_NOT_FOUND"),
-        SMB_NTSTATUS_VIRTDISK_NOT_VIRTUAL_DISK                                      => Some("STATUS_VIRTDISK_NOT_VIRTUAL_DISK"),
-        SMB_NTSTATUS_VHD_PARENT_VHD_ACCESS_DENIED                                   => Some("STATUS_VHD_PARENT_VHD_ACCESS_DENIED"),
-        SMB_NTSTATUS_VHD_CHILD_PARENT_SIZE_MISMATCH                                 => Some("STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH"),
-        SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED                          => Some("STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED"),
-        SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT                         => Some("STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT"),
- 
-        _ => None,
-    }
+/// Generate `smb_ntstatus_string` and `smb_ntstatus_from_string` from a
+/// single list of status codes and their names.
+macro_rules! smb_ntstatus_names {
+    ($($code:ident => $name:literal,)*) => {
+        pub fn smb_ntstatus_string(c: u32) -> Option<&'static str> {
+            match c {
+                $($code => Some($name),)*
+                _ => None,
+            }
+        }
+
+        /// Get the NTSTATUS of a name like STATUS_ACCESS_DENIED, ignoring case.
+        pub fn smb_ntstatus_from_string(s: &str) -> Option<u32> {
+            const NAMES: &[(&str, u32)] = &[$(($name, $code),)*];
+            NAMES
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(s))
+                .map(|&(_, code)| code)
+        }
+    };
 }
 
+smb_ntstatus_names! {
+    SMB_NTSTATUS_SUCCESS                                                        => "STATUS_SUCCESS",
+    SMB_NTSTATUS_WAIT_1                                                         => "STATUS_WAIT_1",
+    SMB_NTSTATUS_WAIT_2                                                         => "STATUS_WAIT_2",
+    SMB_NTSTATUS_WAIT_3                                                         => "STATUS_WAIT_3",
+    SMB_NTSTATUS_WAIT_63                                                        => "STATUS_WAIT_63",
+    SMB_NTSTATUS_ABANDONED                                                      => "STATUS_ABANDONED",
+    SMB_NTSTATUS_ABANDONED_WAIT_63                                              => "STATUS_ABANDONED_WAIT_63",
+    SMB_NTSTATUS_USER_APC                                                       => "STATUS_USER_APC",
+    SMB_NTSTATUS_ALERTED                                                        => "STATUS_ALERTED",
+    SMB_NTSTATUS_TIMEOUT                                                        => "STATUS_TIMEOUT",
+    SMB_NTSTATUS_PENDING                                                        => "STATUS_PENDING",
+    SMB_NTSTATUS_REPARSE                                                        => "STATUS_REPARSE",
+    SMB_NTSTATUS_MORE_ENTRIES                                                   => "STATUS_MORE_ENTRIES",
+    SMB_NTSTATUS_NOT_ALL_ASSIGNED                                               => "STATUS_NOT_ALL_ASSIGNED",
+    SMB_NTSTATUS_SOME_NOT_MAPPED                                                => "STATUS_SOME_NOT_MAPPED",
+    SMB_NTSTATUS_OPLOCK_BREAK_IN_PROGRESS                                       => "STATUS_OPLOCK_BREAK_IN_PROGRESS",
+    SMB_NTSTATUS_VOLUME_MOUNTED                                                 => "STATUS_VOLUME_MOUNTED",
+    SMB_NTSTATUS_RXACT_COMMITTED                                                => "STATUS_RXACT_COMMITTED",
+    SMB_NTSTATUS_NOTIFY_CLEANUP                                                 => "STATUS_NOTIFY_CLEANUP",
+    SMB_NTSTATUS_NOTIFY_ENUM_DIR                                                => "STATUS_NOTIFY_ENUM_DIR",
+    SMB_NTSTATUS_NO_QUOTAS_FOR_ACCOUNT                                          => "STATUS_NO_QUOTAS_FOR_ACCOUNT",
+    SMB_NTSTATUS_PRIMARY_TRANSPORT_CONNECT_FAILED                               => "STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED",
+    SMB_NTSTATUS_PAGE_FAULT_TRANSITION                                          => "STATUS_PAGE_FAULT_TRANSITION",
+    SMB_NTSTATUS_PAGE_FAULT_DEMAND_ZERO                                         => "STATUS_PAGE_FAULT_DEMAND_ZERO",
+    SMB_NTSTATUS_PAGE_FAULT_COPY_ON_WRITE                                       => "STATUS_PAGE_FAULT_COPY_ON_WRITE",
+    SMB_NTSTATUS_PAGE_FAULT_GUARD_PAGE                                          => "STATUS_PAGE_FAULT_GUARD_PAGE",
+    SMB_NTSTATUS_PAGE_FAULT_PAGING_FILE                                         => "STATUS_PAGE_FAULT_PAGING_FILE",
+    SMB_NTSTATUS_CACHE_PAGE_LOCKED                                              => "STATUS_CACHE_PAGE_LOCKED",
+    SMB_NTSTATUS_CRASH_DUMP                                                     => "STATUS_CRASH_DUMP",
+    SMB_NTSTATUS_BUFFER_ALL_ZEROS                                               => "STATUS_BUFFER_ALL_ZEROS",
+    SMB_NTSTATUS_REPARSE_OBJECT                                                 => "STATUS_REPARSE_OBJECT",
+    SMB_NTSTATUS_RESOURCE_REQUIREMENTS_CHANGED                                  => "STATUS_RESOURCE_REQUIREMENTS_CHANGED",
+    SMB_NTSTATUS_TRANSLATION_COMPLETE                                           => "STATUS_TRANSLATION_COMPLETE",
+    SMB_NTSTATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY                                => "STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY",
+    SMB_NTSTATUS_NOTHING_TO_TERMINATE                                           => "STATUS_NOTHING_TO_TERMINATE",
+    SMB_NTSTATUS_PROCESS_NOT_IN_JOB                                             => "STATUS_PROCESS_NOT_IN_JOB",
+    SMB_NTSTATUS_PROCESS_IN_JOB                                                 => "STATUS_PROCESS_IN_JOB",
+    SMB_NTSTATUS_VOLSNAP_HIBERNATE_READY                                        => "STATUS_VOLSNAP_HIBERNATE_READY",
+    SMB_NTSTATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY                             => "STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY",
+    SMB_NTSTATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED                             => "STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED",
+    SMB_NTSTATUS_INTERRUPT_STILL_CONNECTED                                      => "STATUS_INTERRUPT_STILL_CONNECTED",
+    SMB_NTSTATUS_PROCESS_CLONED                                                 => "STATUS_PROCESS_CLONED",
+    SMB_NTSTATUS_FILE_LOCKED_WITH_ONLY_READERS                                  => "STATUS_FILE_LOCKED_WITH_ONLY_READERS",
+    SMB_NTSTATUS_FILE_LOCKED_WITH_WRITERS                                       => "STATUS_FILE_LOCKED_WITH_WRITERS",
+    SMB_NTSTATUS_RESOURCEMANAGER_READ_ONLY                                      => "STATUS_RESOURCEMANAGER_READ_ONLY",
+    SMB_NTSTATUS_WAIT_FOR_OPLOCK                                                => "STATUS_WAIT_FOR_OPLOCK",
+    SMB_NTDBG_EXCEPTION_HANDLED                                                 => "DBG_EXCEPTION_HANDLED",
+    SMB_NTDBG_CONTINUE                                                          => "DBG_CONTINUE",
+    SMB_NTSTATUS_FLT_IO_COMPLETE                                                => "STATUS_FLT_IO_COMPLETE",
+    SMB_NTSTATUS_FILE_NOT_AVAILABLE                                             => "STATUS_FILE_NOT_AVAILABLE",
+    SMB_NTSTATUS_SHARE_UNAVAILABLE                                              => "STATUS_SHARE_UNAVAILABLE",
+    SMB_NTSTATUS_CALLBACK_RETURNED_THREAD_AFFINITY                              => "STATUS_CALLBACK_RETURNED_THREAD_AFFINITY",
+    SMB_NTSTATUS_OBJECT_NAME_EXISTS                                             => "STATUS_OBJECT_NAME_EXISTS",
+    SMB_NTSTATUS_THREAD_WAS_SUSPENDED                                           => "STATUS_THREAD_WAS_SUSPENDED",
+    SMB_NTSTATUS_WORKING_SET_LIMIT_RANGE                                        => "STATUS_WORKING_SET_LIMIT_RANGE",
+    SMB_NTSTATUS_IMAGE_NOT_AT_BASE                                              => "STATUS_IMAGE_NOT_AT_BASE",
+    SMB_NTSTATUS_RXACT_STATE_CREATED                                            => "STATUS_RXACT_STATE_CREATED",
+    SMB_NTSTATUS_SEGMENT_NOTIFICATION                                           => "STATUS_SEGMENT_NOTIFICATION",
+    SMB_NTSTATUS_LOCAL_USER_SESSION_KEY                                         => "STATUS_LOCAL_USER_SESSION_KEY",
+    SMB_NTSTATUS_BAD_CURRENT_DIRECTORY                                          => "STATUS_BAD_CURRENT_DIRECTORY",
+    SMB_NTSTATUS_SERIAL_MORE_WRITES                                             => "STATUS_SERIAL_MORE_WRITES",
+    SMB_NTSTATUS_REGISTRY_RECOVERED                                             => "STATUS_REGISTRY_RECOVERED",
+    SMB_NTSTATUS_FT_READ_RECOVERY_FROM_BACKUP                                   => "STATUS_FT_READ_RECOVERY_FROM_BACKUP",
+    SMB_NTSTATUS_FT_WRITE_RECOVERY                                              => "STATUS_FT_WRITE_RECOVERY",
+    SMB_NTSTATUS_SERIAL_COUNTER_TIMEOUT                                         => "STATUS_SERIAL_COUNTER_TIMEOUT",
+    SMB_NTSTATUS_NULL_LM_PASSWORD                                               => "STATUS_NULL_LM_PASSWORD",
+    SMB_NTSTATUS_IMAGE_MACHINE_TYPE_MISMATCH                                    => "STATUS_IMAGE_MACHINE_TYPE_MISMATCH",
+    SMB_NTSTATUS_RECEIVE_PARTIAL                                                => "STATUS_RECEIVE_PARTIAL",
+    SMB_NTSTATUS_RECEIVE_EXPEDITED                                              => "STATUS_RECEIVE_EXPEDITED",
+    SMB_NTSTATUS_RECEIVE_PARTIAL_EXPEDITED                                      => "STATUS_RECEIVE_PARTIAL_EXPEDITED",
+    SMB_NTSTATUS_EVENT_DONE                                                     => "STATUS_EVENT_DONE",
+    SMB_NTSTATUS_EVENT_PENDING                                                  => "STATUS_EVENT_PENDING",
+    SMB_NTSTATUS_CHECKING_FILE_SYSTEM                                           => "STATUS_CHECKING_FILE_SYSTEM",
+    SMB_NTSTATUS_FATAL_APP_EXIT                                                 => "STATUS_FATAL_APP_EXIT",
+    SMB_NTSTATUS_PREDEFINED_HANDLE                                              => "STATUS_PREDEFINED_HANDLE",
+    SMB_NTSTATUS_WAS_UNLOCKED                                                   => "STATUS_WAS_UNLOCKED",
+    SMB_NTSTATUS_SERVICE_NOTIFICATION                                           => "STATUS_SERVICE_NOTIFICATION",
+    SMB_NTSTATUS_WAS_LOCKED                                                     => "STATUS_WAS_LOCKED",
+    SMB_NTSTATUS_LOG_HARD_ERROR                                                 => "STATUS_LOG_HARD_ERROR",
+    SMB_NTSTATUS_ALREADY_WIN32                                                  => "STATUS_ALREADY_WIN32",
+    SMB_NTSTATUS_WX86_UNSIMULATE                                                => "STATUS_WX86_UNSIMULATE",
+    SMB_NTSTATUS_WX86_CONTINUE                                                  => "STATUS_WX86_CONTINUE",
+    SMB_NTSTATUS_WX86_SINGLE_STEP                                               => "STATUS_WX86_SINGLE_STEP",
+    SMB_NTSTATUS_WX86_BREAKPOINT                                                => "STATUS_WX86_BREAKPOINT",
+    SMB_NTSTATUS_WX86_EXCEPTION_CONTINUE                                        => "STATUS_WX86_EXCEPTION_CONTINUE",
+    SMB_NTSTATUS_WX86_EXCEPTION_LASTCHANCE                                      => "STATUS_WX86_EXCEPTION_LASTCHANCE",
+    SMB_NTSTATUS_WX86_EXCEPTION_CHAIN                                           => "STATUS_WX86_EXCEPTION_CHAIN",
+    SMB_NTSTATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE                                => "STATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE",
+    SMB_NTSTATUS_NO_YIELD_PERFORMED                                             => "STATUS_NO_YIELD_PERFORMED",
+    SMB_NTSTATUS_TIMER_RESUME_IGNORED                                           => "STATUS_TIMER_RESUME_IGNORED",
+    SMB_NTSTATUS_ARBITRATION_UNHANDLED                                          => "STATUS_ARBITRATION_UNHANDLED",
+    SMB_NTSTATUS_CARDBUS_NOT_SUPPORTED                                          => "STATUS_CARDBUS_NOT_SUPPORTED",
+    SMB_NTSTATUS_WX86_CREATEWX86TIB                                             => "STATUS_WX86_CREATEWX86TIB",
+    SMB_NTSTATUS_MP_PROCESSOR_MISMATCH                                          => "STATUS_MP_PROCESSOR_MISMATCH",
+    SMB_NTSTATUS_HIBERNATED                                                     => "STATUS_HIBERNATED",
+    SMB_NTSTATUS_RESUME_HIBERNATION                                             => "STATUS_RESUME_HIBERNATION",
+    SMB_NTSTATUS_FIRMWARE_UPDATED                                               => "STATUS_FIRMWARE_UPDATED",
+    SMB_NTSTATUS_DRIVERS_LEAKING_LOCKED_PAGES                                   => "STATUS_DRIVERS_LEAKING_LOCKED_PAGES",
+    SMB_NTSTATUS_MESSAGE_RETRIEVED                                              => "STATUS_MESSAGE_RETRIEVED",
+    SMB_NTSTATUS_SYSTEM_POWERSTATE_TRANSITION                                   => "STATUS_SYSTEM_POWERSTATE_TRANSITION",
+    SMB_NTSTATUS_ALPC_CHECK_COMPLETION_LIST                                     => "STATUS_ALPC_CHECK_COMPLETION_LIST",
+    SMB_NTSTATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION                           => "STATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION",
+    SMB_NTSTATUS_ACCESS_AUDIT_BY_POLICY                                         => "STATUS_ACCESS_AUDIT_BY_POLICY",
+    SMB_NTSTATUS_ABANDON_HIBERFILE                                              => "STATUS_ABANDON_HIBERFILE",
+    SMB_NTSTATUS_BIZRULES_NOT_ENABLED                                           => "STATUS_BIZRULES_NOT_ENABLED",
+    SMB_NTSTATUS_WAKE_SYSTEM                                                    => "STATUS_WAKE_SYSTEM",
+    SMB_NTSTATUS_DS_SHUTTING_DOWN                                               => "STATUS_DS_SHUTTING_DOWN",
+    SMB_NTDBG_REPLY_LATER                                                       => "DBG_REPLY_LATER",
+    SMB_NTDBG_UNABLE_TO_PROVIDE_HANDLE                                          => "DBG_UNABLE_TO_PROVIDE_HANDLE",
+    SMB_NTDBG_TERMINATE_THREAD                                                  => "DBG_TERMINATE_THREAD",
+    SMB_NTDBG_TERMINATE_PROCESS                                                 => "DBG_TERMINATE_PROCESS",
+    SMB_NTDBG_CONTROL_C                                                         => "DBG_CONTROL_C",
+    SMB_NTDBG_PRINTEXCEPTION_C                                                  => "DBG_PRINTEXCEPTION_C",
+    SMB_NTDBG_RIPEXCEPTION                                                      => "DBG_RIPEXCEPTION",
+    SMB_NTDBG_CONTROL_BREAK                                                     => "DBG_CONTROL_BREAK",
+    SMB_NTDBG_COMMAND_EXCEPTION                                                 => "DBG_COMMAND_EXCEPTION",
+    SMB_NTRPC_NT_UUID_LOCAL_ONLY                                                => "RPC_NT_UUID_LOCAL_ONLY",
+    SMB_NTRPC_NT_SEND_INCOMPLETE                                                => "RPC_NT_SEND_INCOMPLETE",
+    SMB_NTSTATUS_CTX_CDM_CONNECT                                                => "STATUS_CTX_CDM_CONNECT",
+    SMB_NTSTATUS_CTX_CDM_DISCONNECT                                             => "STATUS_CTX_CDM_DISCONNECT",
+    SMB_NTSTATUS_SXS_RELEASE_ACTIVATION_CONTEXT                                 => "STATUS_SXS_RELEASE_ACTIVATION_CONTEXT",
+    SMB_NTSTATUS_RECOVERY_NOT_NEEDED                                            => "STATUS_RECOVERY_NOT_NEEDED",
+    SMB_NTSTATUS_RM_ALREADY_STARTED                                             => "STATUS_RM_ALREADY_STARTED",
+    SMB_NTSTATUS_LOG_NO_RESTART                                                 => "STATUS_LOG_NO_RESTART",
+    SMB_NTSTATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST                              => "STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST",
+    SMB_NTSTATUS_GRAPHICS_PARTIAL_DATA_POPULATED                                => "STATUS_GRAPHICS_PARTIAL_DATA_POPULATED",
+    SMB_NTSTATUS_GRAPHICS_DRIVER_MISMATCH                                       => "STATUS_GRAPHICS_DRIVER_MISMATCH",
+    SMB_NTSTATUS_GRAPHICS_MODE_NOT_PINNED                                       => "STATUS_GRAPHICS_MODE_NOT_PINNED",
+    SMB_NTSTATUS_GRAPHICS_NO_PREFERRED_MODE                                     => "STATUS_GRAPHICS_NO_PREFERRED_MODE",
+    SMB_NTSTATUS_GRAPHICS_DATASET_IS_EMPTY                                      => "STATUS_GRAPHICS_DATASET_IS_EMPTY",
+    SMB_NTSTATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET                           => "STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET",
+    SMB_NTSTATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED       => "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED",
+    SMB_NTSTATUS_GRAPHICS_UNKNOWN_CHILD_STATUS                                  => "STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS",
+    SMB_NTSTATUS_GRAPHICS_LEADLINK_START_DEFERRED                               => "STATUS_GRAPHICS_LEADLINK_START_DEFERRED",
+    SMB_NTSTATUS_GRAPHICS_POLLING_TOO_FREQUENTLY                                => "STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY",
+    SMB_NTSTATUS_GRAPHICS_START_DEFERRED                                        => "STATUS_GRAPHICS_START_DEFERRED",
+    SMB_NTSTATUS_NDIS_INDICATION_REQUIRED                                       => "STATUS_NDIS_INDICATION_REQUIRED",
+    SMB_NTSTATUS_GUARD_PAGE_VIOLATION                                           => "STATUS_GUARD_PAGE_VIOLATION",
+    SMB_NTSTATUS_DATATYPE_MISALIGNMENT                                          => "STATUS_DATATYPE_MISALIGNMENT",
+    SMB_NTSTATUS_BREAKPOINT                                                     => "STATUS_BREAKPOINT",
+    SMB_NTSTATUS_SINGLE_STEP                                                    => "STATUS_SINGLE_STEP",
+    SMB_NTSTATUS_BUFFER_OVERFLOW                                                => "STATUS_BUFFER_OVERFLOW",
+    SMB_NTSTATUS_NO_MORE_FILES                                                  => "STATUS_NO_MORE_FILES",
+    SMB_NTSTATUS_WAKE_SYSTEM_DEBUGGER                                           => "STATUS_WAKE_SYSTEM_DEBUGGER",
+    SMB_NTSTATUS_HANDLES_CLOSED                                                 => "STATUS_HANDLES_CLOSED",
+    SMB_NTSTATUS_NO_INHERITANCE                                                 => "STATUS_NO_INHERITANCE",
+    SMB_NTSTATUS_GUID_SUBSTITUTION_MADE                                         => "STATUS_GUID_SUBSTITUTION_MADE",
+    SMB_NTSTATUS_PARTIAL_COPY                                                   => "STATUS_PARTIAL_COPY",
+    SMB_NTSTATUS_DEVICE_PAPER_EMPTY                                             => "STATUS_DEVICE_PAPER_EMPTY",
+    SMB_NTSTATUS_DEVICE_POWERED_OFF                                             => "STATUS_DEVICE_POWERED_OFF",
+    SMB_NTSTATUS_DEVICE_OFF_LINE                                                => "STATUS_DEVICE_OFF_LINE",
+    SMB_NTSTATUS_DEVICE_BUSY                                                    => "STATUS_DEVICE_BUSY",
+    SMB_NTSTATUS_NO_MORE_EAS                                                    => "STATUS_NO_MORE_EAS",
+    SMB_NTSTATUS_INVALID_EA_NAME                                                => "STATUS_INVALID_EA_NAME",
+    SMB_NTSTATUS_EA_LIST_INCONSISTENT                                           => "STATUS_EA_LIST_INCONSISTENT",
+    SMB_NTSTATUS_INVALID_EA_FLAG                                                => "STATUS_INVALID_EA_FLAG",
+    SMB_NTSTATUS_VERIFY_REQUIRED                                                => "STATUS_VERIFY_REQUIRED",
+    SMB_NTSTATUS_EXTRANEOUS_INFORMATION                                         => "STATUS_EXTRANEOUS_INFORMATION",
+    SMB_NTSTATUS_RXACT_COMMIT_NECESSARY                                         => "STATUS_RXACT_COMMIT_NECESSARY",
+    SMB_NTSTATUS_NO_MORE_ENTRIES                                                => "STATUS_NO_MORE_ENTRIES",
+    SMB_NTSTATUS_FILEMARK_DETECTED                                              => "STATUS_FILEMARK_DETECTED",
+    SMB_NTSTATUS_MEDIA_CHANGED                                                  => "STATUS_MEDIA_CHANGED",
+    SMB_NTSTATUS_BUS_RESET                                                      => "STATUS_BUS_RESET",
+    SMB_NTSTATUS_END_OF_MEDIA                                                   => "STATUS_END_OF_MEDIA",
+    SMB_NTSTATUS_BEGINNING_OF_MEDIA                                             => "STATUS_BEGINNING_OF_MEDIA",
+    SMB_NTSTATUS_MEDIA_CHECK                                                    => "STATUS_MEDIA_CHECK",
+    SMB_NTSTATUS_SETMARK_DETECTED                                               => "STATUS_SETMARK_DETECTED",
+    SMB_NTSTATUS_NO_DATA_DETECTED                                               => "STATUS_NO_DATA_DETECTED",
+    SMB_NTSTATUS_REDIRECTOR_HAS_OPEN_HANDLES                                    => "STATUS_REDIRECTOR_HAS_OPEN_HANDLES",
+    SMB_NTSTATUS_SERVER_HAS_OPEN_HANDLES                                        => "STATUS_SERVER_HAS_OPEN_HANDLES",
+    SMB_NTSTATUS_ALREADY_DISCONNECTED                                           => "STATUS_ALREADY_DISCONNECTED",
+    SMB_NTSTATUS_LONGJUMP                                                       => "STATUS_LONGJUMP",
+    SMB_NTSTATUS_CLEANER_CARTRIDGE_INSTALLED                                    => "STATUS_CLEANER_CARTRIDGE_INSTALLED",
+    SMB_NTSTATUS_PLUGPLAY_QUERY_VETOED                                          => "STATUS_PLUGPLAY_QUERY_VETOED",
+    SMB_NTSTATUS_UNWIND_CONSOLIDATE                                             => "STATUS_UNWIND_CONSOLIDATE",
+    SMB_NTSTATUS_REGISTRY_HIVE_RECOVERED                                        => "STATUS_REGISTRY_HIVE_RECOVERED",
+    SMB_NTSTATUS_DLL_MIGHT_BE_INSECURE                                          => "STATUS_DLL_MIGHT_BE_INSECURE",
+    SMB_NTSTATUS_DLL_MIGHT_BE_INCOMPATIBLE                                      => "STATUS_DLL_MIGHT_BE_INCOMPATIBLE",
+    SMB_NTSTATUS_STOPPED_ON_SYMLINK                                             => "STATUS_STOPPED_ON_SYMLINK",
+    SMB_NTSTATUS_DEVICE_REQUIRES_CLEANING                                       => "STATUS_DEVICE_REQUIRES_CLEANING",
+    SMB_NTSTATUS_DEVICE_DOOR_OPEN                                               => "STATUS_DEVICE_DOOR_OPEN",
+    SMB_NTSTATUS_DATA_LOST_REPAIR                                               => "STATUS_DATA_LOST_REPAIR",
+    SMB_NTDBG_EXCEPTION_NOT_HANDLED                                             => "DBG_EXCEPTION_NOT_HANDLED",
+    SMB_NTSTATUS_CLUSTER_NODE_ALREADY_UP                                        => "STATUS_CLUSTER_NODE_ALREADY_UP",
+    SMB_NTSTATUS_CLUSTER_NODE_ALREADY_DOWN                                      => "STATUS_CLUSTER_NODE_ALREADY_DOWN",
+    SMB_NTSTATUS_CLUSTER_NETWORK_ALREADY_ONLINE                                 => "STATUS_CLUSTER_NETWORK_ALREADY_ONLINE",
+    SMB_NTSTATUS_CLUSTER_NETWORK_ALREADY_OFFLINE                                => "STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE",
+    SMB_NTSTATUS_CLUSTER_NODE_ALREADY_MEMBER                                    => "STATUS_CLUSTER_NODE_ALREADY_MEMBER",
+    SMB_NTSTATUS_COULD_NOT_RESIZE_LOG                                           => "STATUS_COULD_NOT_RESIZE_LOG",
+    SMB_NTSTATUS_NO_TXF_METADATA                                                => "STATUS_NO_TXF_METADATA",
+    SMB_NTSTATUS_CANT_RECOVER_WITH_HANDLE_OPEN                                  => "STATUS_CANT_RECOVER_WITH_HANDLE_OPEN",
+    SMB_NTSTATUS_TXF_METADATA_ALREADY_PRESENT                                   => "STATUS_TXF_METADATA_ALREADY_PRESENT",
+    SMB_NTSTATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET                            => "STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET",
+    SMB_NTSTATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED                     => "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED",
+    SMB_NTSTATUS_FLT_BUFFER_TOO_SMALL                                           => "STATUS_FLT_BUFFER_TOO_SMALL",
+    SMB_NTSTATUS_FVE_PARTIAL_METADATA                                           => "STATUS_FVE_PARTIAL_METADATA",
+    SMB_NTSTATUS_FVE_TRANSIENT_STATE                                            => "STATUS_FVE_TRANSIENT_STATE",
+    SMB_NTSTATUS_UNSUCCESSFUL                                                   => "STATUS_UNSUCCESSFUL",
+    SMB_NTSTATUS_NOT_IMPLEMENTED                                                => "STATUS_NOT_IMPLEMENTED",
+    SMB_NTSTATUS_INVALID_INFO_CLASS                                             => "STATUS_INVALID_INFO_CLASS",
+    SMB_NTSTATUS_INFO_LENGTH_MISMATCH                                           => "STATUS_INFO_LENGTH_MISMATCH",
+    SMB_NTSTATUS_ACCESS_VIOLATION                                               => "STATUS_ACCESS_VIOLATION",
+    SMB_NTSTATUS_IN_PAGE_ERROR                                                  => "STATUS_IN_PAGE_ERROR",
+    SMB_NTSTATUS_PAGEFILE_QUOTA                                                 => "STATUS_PAGEFILE_QUOTA",
+    SMB_NTSTATUS_INVALID_HANDLE                                                 => "STATUS_INVALID_HANDLE",
+    SMB_NTSTATUS_BAD_INITIAL_STACK                                              => "STATUS_BAD_INITIAL_STACK",
+    SMB_NTSTATUS_BAD_INITIAL_PC                                                 => "STATUS_BAD_INITIAL_PC",
+    SMB_NTSTATUS_INVALID_CID                                                    => "STATUS_INVALID_CID",
+    SMB_NTSTATUS_TIMER_NOT_CANCELED                                             => "STATUS_TIMER_NOT_CANCELED",
+    SMB_NTSTATUS_INVALID_PARAMETER                                              => "STATUS_INVALID_PARAMETER",
+    SMB_NTSTATUS_NO_SUCH_DEVICE                                                 => "STATUS_NO_SUCH_DEVICE",
+    SMB_NTSTATUS_NO_SUCH_FILE                                                   => "STATUS_NO_SUCH_FILE",
+    SMB_NTSTATUS_INVALID_DEVICE_REQUEST                                         => "STATUS_INVALID_DEVICE_REQUEST",
+    SMB_NTSTATUS_END_OF_FILE                                                    => "STATUS_END_OF_FILE",
+    SMB_NTSTATUS_WRONG_VOLUME                                                   => "STATUS_WRONG_VOLUME",
+    SMB_NTSTATUS_NO_MEDIA_IN_DEVICE                                             => "STATUS_NO_MEDIA_IN_DEVICE",
+    SMB_NTSTATUS_UNRECOGNIZED_MEDIA                                             => "STATUS_UNRECOGNIZED_MEDIA",
+    SMB_NTSTATUS_NONEXISTENT_SECTOR                                             => "STATUS_NONEXISTENT_SECTOR",
+    SMB_NTSTATUS_MORE_PROCESSING_REQUIRED                                       => "STATUS_MORE_PROCESSING_REQUIRED",
+    SMB_NTSTATUS_NO_MEMORY                                                      => "STATUS_NO_MEMORY",
+    SMB_NTSTATUS_CONFLICTING_ADDRESSES                                          => "STATUS_CONFLICTING_ADDRESSES",
+    SMB_NTSTATUS_NOT_MAPPED_VIEW                                                => "STATUS_NOT_MAPPED_VIEW",
+    SMB_NTSTATUS_UNABLE_TO_FREE_VM                                              => "STATUS_UNABLE_TO_FREE_VM",
+    SMB_NTSTATUS_UNABLE_TO_DELETE_SECTION                                       => "STATUS_UNABLE_TO_DELETE_SECTION",
+    SMB_NTSTATUS_INVALID_SYSTEM_SERVICE                                         => "STATUS_INVALID_SYSTEM_SERVICE",
+    SMB_NTSTATUS_ILLEGAL_INSTRUCTION                                            => "STATUS_ILLEGAL_INSTRUCTION",
+    SMB_NTSTATUS_INVALID_LOCK_SEQUENCE                                          => "STATUS_INVALID_LOCK_SEQUENCE",
+    SMB_NTSTATUS_INVALID_VIEW_SIZE                                              => "STATUS_INVALID_VIEW_SIZE",
+    SMB_NTSTATUS_INVALID_FILE_FOR_SECTION                                       => "STATUS_INVALID_FILE_FOR_SECTION",
+    SMB_NTSTATUS_ALREADY_COMMITTED                                              => "STATUS_ALREADY_COMMITTED",
+    SMB_NTSTATUS_ACCESS_DENIED                                                  => "STATUS_ACCESS_DENIED",
+    SMB_NTSTATUS_BUFFER_TOO_SMALL                                               => "STATUS_BUFFER_TOO_SMALL",
+    SMB_NTSTATUS_OBJECT_TYPE_MISMATCH                                           => "STATUS_OBJECT_TYPE_MISMATCH",
+    SMB_NTSTATUS_NONCONTINUABLE_EXCEPTION                                       => "STATUS_NONCONTINUABLE_EXCEPTION",
+    SMB_NTSTATUS_INVALID_DISPOSITION                                            => "STATUS_INVALID_DISPOSITION",
+    SMB_NTSTATUS_UNWIND                                                         => "STATUS_UNWIND",
+    SMB_NTSTATUS_BAD_STACK                                                      => "STATUS_BAD_STACK",
+    SMB_NTSTATUS_INVALID_UNWIND_TARGET                                          => "STATUS_INVALID_UNWIND_TARGET",
+    SMB_NTSTATUS_NOT_LOCKED                                                     => "STATUS_NOT_LOCKED",
+    SMB_NTSTATUS_PARITY_ERROR                                                   => "STATUS_PARITY_ERROR",
+    SMB_NTSTATUS_UNABLE_TO_DECOMMIT_VM                                          => "STATUS_UNABLE_TO_DECOMMIT_VM",
+    SMB_NTSTATUS_NOT_COMMITTED                                                  => "STATUS_NOT_COMMITTED",
+    SMB_NTSTATUS_INVALID_PORT_ATTRIBUTES                                        => "STATUS_INVALID_PORT_ATTRIBUTES",
+    SMB_NTSTATUS_PORT_MESSAGE_TOO_LONG                                          => "STATUS_PORT_MESSAGE_TOO_LONG",
+    SMB_NTSTATUS_INVALID_PARAMETER_MIX                                          => "STATUS_INVALID_PARAMETER_MIX",
+    SMB_NTSTATUS_INVALID_QUOTA_LOWER                                            => "STATUS_INVALID_QUOTA_LOWER",
+    SMB_NTSTATUS_DISK_CORRUPT_ERROR                                             => "STATUS_DISK_CORRUPT_ERROR",
+    SMB_NTSTATUS_OBJECT_NAME_INVALID                                            => "STATUS_OBJECT_NAME_INVALID",
+    SMB_NTSTATUS_OBJECT_NAME_NOT_FOUND                                          => "STATUS_OBJECT_NAME_NOT_FOUND",
+    SMB_NTSTATUS_OBJECT_NAME_COLLISION                                          => "STATUS_OBJECT_NAME_COLLISION",
+    SMB_NTSTATUS_PORT_DISCONNECTED                                              => "STATUS_PORT_DISCONNECTED",
+    SMB_NTSTATUS_DEVICE_ALREADY_ATTACHED                                        => "STATUS_DEVICE_ALREADY_ATTACHED",
+    SMB_NTSTATUS_OBJECT_PATH_INVALID                                            => "STATUS_OBJECT_PATH_INVALID",
+    SMB_NTSTATUS_OBJECT_PATH_NOT_FOUND                                          => "STATUS_OBJECT_PATH_NOT_FOUND",
+    SMB_NTSTATUS_OBJECT_PATH_SYNTAX_BAD                                         => "STATUS_OBJECT_PATH_SYNTAX_BAD",
+    SMB_NTSTATUS_DATA_OVERRUN                                                   => "STATUS_DATA_OVERRUN",
+    SMB_NTSTATUS_DATA_LATE_ERROR                                                => "STATUS_DATA_LATE_ERROR",
+    SMB_NTSTATUS_DATA_ERROR                                                     => "STATUS_DATA_ERROR",
+    SMB_NTSTATUS_CRC_ERROR                                                      => "STATUS_CRC_ERROR",
+    SMB_NTSTATUS_SECTION_TOO_BIG                                                => "STATUS_SECTION_TOO_BIG",
+    SMB_NTSTATUS_PORT_CONNECTION_REFUSED                                        => "STATUS_PORT_CONNECTION_REFUSED",
+    SMB_NTSTATUS_INVALID_PORT_HANDLE                                            => "STATUS_INVALID_PORT_HANDLE",
+    SMB_NTSTATUS_SHARING_VIOLATION                                              => "STATUS_SHARING_VIOLATION",
+    SMB_NTSTATUS_QUOTA_EXCEEDED                                                 => "STATUS_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_INVALID_PAGE_PROTECTION                                        => "STATUS_INVALID_PAGE_PROTECTION",
+    SMB_NTSTATUS_MUTANT_NOT_OWNED                                               => "STATUS_MUTANT_NOT_OWNED",
+    SMB_NTSTATUS_SEMAPHORE_LIMIT_EXCEEDED                                       => "STATUS_SEMAPHORE_LIMIT_EXCEEDED",
+    SMB_NTSTATUS_PORT_ALREADY_SET                                               => "STATUS_PORT_ALREADY_SET",
+    SMB_NTSTATUS_SECTION_NOT_IMAGE                                              => "STATUS_SECTION_NOT_IMAGE",
+    SMB_NTSTATUS_SUSPEND_COUNT_EXCEEDED                                         => "STATUS_SUSPEND_COUNT_EXCEEDED",
+    SMB_NTSTATUS_THREAD_IS_TERMINATING                                          => "STATUS_THREAD_IS_TERMINATING",
+    SMB_NTSTATUS_BAD_WORKING_SET_LIMIT                                          => "STATUS_BAD_WORKING_SET_LIMIT",
+    SMB_NTSTATUS_INCOMPATIBLE_FILE_MAP                                          => "STATUS_INCOMPATIBLE_FILE_MAP",
+    SMB_NTSTATUS_SECTION_PROTECTION                                             => "STATUS_SECTION_PROTECTION",
+    SMB_NTSTATUS_EAS_NOT_SUPPORTED                                              => "STATUS_EAS_NOT_SUPPORTED",
+    SMB_NTSTATUS_EA_TOO_LARGE                                                   => "STATUS_EA_TOO_LARGE",
+    SMB_NTSTATUS_NONEXISTENT_EA_ENTRY                                           => "STATUS_NONEXISTENT_EA_ENTRY",
+    SMB_NTSTATUS_NO_EAS_ON_FILE                                                 => "STATUS_NO_EAS_ON_FILE",
+    SMB_NTSTATUS_EA_CORRUPT_ERROR                                               => "STATUS_EA_CORRUPT_ERROR",
+    SMB_NTSTATUS_FILE_LOCK_CONFLICT                                             => "STATUS_FILE_LOCK_CONFLICT",
+    SMB_NTSTATUS_LOCK_NOT_GRANTED                                               => "STATUS_LOCK_NOT_GRANTED",
+    SMB_NTSTATUS_DELETE_PENDING                                                 => "STATUS_DELETE_PENDING",
+    SMB_NTSTATUS_CTL_FILE_NOT_SUPPORTED                                         => "STATUS_CTL_FILE_NOT_SUPPORTED",
+    SMB_NTSTATUS_UNKNOWN_REVISION                                               => "STATUS_UNKNOWN_REVISION",
+    SMB_NTSTATUS_REVISION_MISMATCH                                              => "STATUS_REVISION_MISMATCH",
+    SMB_NTSTATUS_INVALID_OWNER                                                  => "STATUS_INVALID_OWNER",
+    SMB_NTSTATUS_INVALID_PRIMARY_GROUP                                          => "STATUS_INVALID_PRIMARY_GROUP",
+    SMB_NTSTATUS_NO_IMPERSONATION_TOKEN                                         => "STATUS_NO_IMPERSONATION_TOKEN",
+    SMB_NTSTATUS_CANT_DISABLE_MANDATORY                                         => "STATUS_CANT_DISABLE_MANDATORY",
+    SMB_NTSTATUS_NO_LOGON_SERVERS                                               => "STATUS_NO_LOGON_SERVERS",
+    SMB_NTSTATUS_NO_SUCH_LOGON_SESSION                                          => "STATUS_NO_SUCH_LOGON_SESSION",
+    SMB_NTSTATUS_NO_SUCH_PRIVILEGE                                              => "STATUS_NO_SUCH_PRIVILEGE",
+    SMB_NTSTATUS_PRIVILEGE_NOT_HELD                                             => "STATUS_PRIVILEGE_NOT_HELD",
+    SMB_NTSTATUS_INVALID_ACCOUNT_NAME                                           => "STATUS_INVALID_ACCOUNT_NAME",
+    SMB_NTSTATUS_USER_EXISTS                                                    => "STATUS_USER_EXISTS",
+    SMB_NTSTATUS_NO_SUCH_USER                                                   => "STATUS_NO_SUCH_USER",
+    SMB_NTSTATUS_GROUP_EXISTS                                                   => "STATUS_GROUP_EXISTS",
+    SMB_NTSTATUS_NO_SUCH_GROUP                                                  => "STATUS_NO_SUCH_GROUP",
+    SMB_NTSTATUS_MEMBER_IN_GROUP                                                => "STATUS_MEMBER_IN_GROUP",
+    SMB_NTSTATUS_MEMBER_NOT_IN_GROUP                                            => "STATUS_MEMBER_NOT_IN_GROUP",
+    SMB_NTSTATUS_LAST_ADMIN                                                     => "STATUS_LAST_ADMIN",
+    SMB_NTSTATUS_WRONG_PASSWORD                                                 => "STATUS_WRONG_PASSWORD",
+    SMB_NTSTATUS_ILL_FORMED_PASSWORD                                            => "STATUS_ILL_FORMED_PASSWORD",
+    SMB_NTSTATUS_PASSWORD_RESTRICTION                                           => "STATUS_PASSWORD_RESTRICTION",
+    SMB_NTSTATUS_LOGON_FAILURE                                                  => "STATUS_LOGON_FAILURE",
+    SMB_NTSTATUS_ACCOUNT_RESTRICTION                                            => "STATUS_ACCOUNT_RESTRICTION",
+    SMB_NTSTATUS_INVALID_LOGON_HOURS                                            => "STATUS_INVALID_LOGON_HOURS",
+    SMB_NTSTATUS_INVALID_WORKSTATION                                            => "STATUS_INVALID_WORKSTATION",
+    SMB_NTSTATUS_PASSWORD_EXPIRED                                               => "STATUS_PASSWORD_EXPIRED",
+    SMB_NTSTATUS_ACCOUNT_DISABLED                                               => "STATUS_ACCOUNT_DISABLED",
+    SMB_NTSTATUS_NONE_MAPPED                                                    => "STATUS_NONE_MAPPED",
+    SMB_NTSTATUS_TOO_MANY_LUIDS_REQUESTED                                       => "STATUS_TOO_MANY_LUIDS_REQUESTED",
+    SMB_NTSTATUS_LUIDS_EXHAUSTED                                                => "STATUS_LUIDS_EXHAUSTED",
+    SMB_NTSTATUS_INVALID_SUB_AUTHORITY                                          => "STATUS_INVALID_SUB_AUTHORITY",
+    SMB_NTSTATUS_INVALID_ACL                                                    => "STATUS_INVALID_ACL",
+    SMB_NTSTATUS_INVALID_SID                                                    => "STATUS_INVALID_SID",
+    SMB_NTSTATUS_INVALID_SECURITY_DESCR                                         => "STATUS_INVALID_SECURITY_DESCR",
+    SMB_NTSTATUS_PROCEDURE_NOT_FOUND                                            => "STATUS_PROCEDURE_NOT_FOUND",
+    SMB_NTSTATUS_INVALID_IMAGE_FORMAT                                           => "STATUS_INVALID_IMAGE_FORMAT",
+    SMB_NTSTATUS_NO_TOKEN                                                       => "STATUS_NO_TOKEN",
+    SMB_NTSTATUS_BAD_INHERITANCE_ACL                                            => "STATUS_BAD_INHERITANCE_ACL",
+    SMB_NTSTATUS_RANGE_NOT_LOCKED                                               => "STATUS_RANGE_NOT_LOCKED",
+    SMB_NTSTATUS_DISK_FULL                                                      => "STATUS_DISK_FULL",
+    SMB_NTSTATUS_SERVER_DISABLED                                                => "STATUS_SERVER_DISABLED",
+    SMB_NTSTATUS_SERVER_NOT_DISABLED                                            => "STATUS_SERVER_NOT_DISABLED",
+    SMB_NTSTATUS_TOO_MANY_GUIDS_REQUESTED                                       => "STATUS_TOO_MANY_GUIDS_REQUESTED",
+    SMB_NTSTATUS_GUIDS_EXHAUSTED                                                => "STATUS_GUIDS_EXHAUSTED",
+    SMB_NTSTATUS_INVALID_ID_AUTHORITY                                           => "STATUS_INVALID_ID_AUTHORITY",
+    SMB_NTSTATUS_AGENTS_EXHAUSTED                                               => "STATUS_AGENTS_EXHAUSTED",
+    SMB_NTSTATUS_INVALID_VOLUME_LABEL                                           => "STATUS_INVALID_VOLUME_LABEL",
+    SMB_NTSTATUS_SECTION_NOT_EXTENDED                                           => "STATUS_SECTION_NOT_EXTENDED",
+    SMB_NTSTATUS_NOT_MAPPED_DATA                                                => "STATUS_NOT_MAPPED_DATA",
+    SMB_NTSTATUS_RESOURCE_DATA_NOT_FOUND                                        => "STATUS_RESOURCE_DATA_NOT_FOUND",
+    SMB_NTSTATUS_RESOURCE_TYPE_NOT_FOUND                                        => "STATUS_RESOURCE_TYPE_NOT_FOUND",
+    SMB_NTSTATUS_RESOURCE_NAME_NOT_FOUND                                        => "STATUS_RESOURCE_NAME_NOT_FOUND",
+    SMB_NTSTATUS_ARRAY_BOUNDS_EXCEEDED                                          => "STATUS_ARRAY_BOUNDS_EXCEEDED",
+    SMB_NTSTATUS_FLOAT_DENORMAL_OPERAND                                         => "STATUS_FLOAT_DENORMAL_OPERAND",
+    SMB_NTSTATUS_FLOAT_DIVIDE_BY_ZERO                                           => "STATUS_FLOAT_DIVIDE_BY_ZERO",
+    SMB_NTSTATUS_FLOAT_INEXACT_RESULT                                           => "STATUS_FLOAT_INEXACT_RESULT",
+    SMB_NTSTATUS_FLOAT_INVALID_OPERATION                                        => "STATUS_FLOAT_INVALID_OPERATION",
+    SMB_NTSTATUS_FLOAT_OVERFLOW                                                 => "STATUS_FLOAT_OVERFLOW",
+    SMB_NTSTATUS_FLOAT_STACK_CHECK                                              => "STATUS_FLOAT_STACK_CHECK",
+    SMB_NTSTATUS_FLOAT_UNDERFLOW                                                => "STATUS_FLOAT_UNDERFLOW",
+    SMB_NTSTATUS_INTEGER_DIVIDE_BY_ZERO                                         => "STATUS_INTEGER_DIVIDE_BY_ZERO",
+    SMB_NTSTATUS_INTEGER_OVERFLOW                                               => "STATUS_INTEGER_OVERFLOW",
+    SMB_NTSTATUS_PRIVILEGED_INSTRUCTION                                         => "STATUS_PRIVILEGED_INSTRUCTION",
+    SMB_NTSTATUS_TOO_MANY_PAGING_FILES                                          => "STATUS_TOO_MANY_PAGING_FILES",
+    SMB_NTSTATUS_FILE_INVALID                                                   => "STATUS_FILE_INVALID",
+    SMB_NTSTATUS_ALLOTTED_SPACE_EXCEEDED                                        => "STATUS_ALLOTTED_SPACE_EXCEEDED",
+    SMB_NTSTATUS_INSUFFICIENT_RESOURCES                                         => "STATUS_INSUFFICIENT_RESOURCES",
+    SMB_NTSTATUS_DFS_EXIT_PATH_FOUND                                            => "STATUS_DFS_EXIT_PATH_FOUND",
+    SMB_NTSTATUS_DEVICE_DATA_ERROR                                              => "STATUS_DEVICE_DATA_ERROR",
+    SMB_NTSTATUS_DEVICE_NOT_CONNECTED                                           => "STATUS_DEVICE_NOT_CONNECTED",
+    SMB_NTSTATUS_FREE_VM_NOT_AT_BASE                                            => "STATUS_FREE_VM_NOT_AT_BASE",
+    SMB_NTSTATUS_MEMORY_NOT_ALLOCATED                                           => "STATUS_MEMORY_NOT_ALLOCATED",
+    SMB_NTSTATUS_WORKING_SET_QUOTA                                              => "STATUS_WORKING_SET_QUOTA",
+    SMB_NTSTATUS_MEDIA_WRITE_PROTECTED                                          => "STATUS_MEDIA_WRITE_PROTECTED",
+    SMB_NTSTATUS_DEVICE_NOT_READY                                               => "STATUS_DEVICE_NOT_READY",
+    SMB_NTSTATUS_INVALID_GROUP_ATTRIBUTES                                       => "STATUS_INVALID_GROUP_ATTRIBUTES",
+    SMB_NTSTATUS_BAD_IMPERSONATION_LEVEL                                        => "STATUS_BAD_IMPERSONATION_LEVEL",
+    SMB_NTSTATUS_CANT_OPEN_ANONYMOUS                                            => "STATUS_CANT_OPEN_ANONYMOUS",
+    SMB_NTSTATUS_BAD_VALIDATION_CLASS                                           => "STATUS_BAD_VALIDATION_CLASS",
+    SMB_NTSTATUS_BAD_TOKEN_TYPE                                                 => "STATUS_BAD_TOKEN_TYPE",
+    SMB_NTSTATUS_BAD_MASTER_BOOT_RECORD                                         => "STATUS_BAD_MASTER_BOOT_RECORD",
+    SMB_NTSTATUS_INSTRUCTION_MISALIGNMENT                                       => "STATUS_INSTRUCTION_MISALIGNMENT",
+    SMB_NTSTATUS_INSTANCE_NOT_AVAILABLE                                         => "STATUS_INSTANCE_NOT_AVAILABLE",
+    SMB_NTSTATUS_PIPE_NOT_AVAILABLE                                             => "STATUS_PIPE_NOT_AVAILABLE",
+    SMB_NTSTATUS_INVALID_PIPE_STATE                                             => "STATUS_INVALID_PIPE_STATE",
+    SMB_NTSTATUS_PIPE_BUSY                                                      => "STATUS_PIPE_BUSY",
+    SMB_NTSTATUS_ILLEGAL_FUNCTION                                               => "STATUS_ILLEGAL_FUNCTION",
+    SMB_NTSTATUS_PIPE_DISCONNECTED                                              => "STATUS_PIPE_DISCONNECTED",
+    SMB_NTSTATUS_PIPE_CLOSING                                                   => "STATUS_PIPE_CLOSING",
+    SMB_NTSTATUS_PIPE_CONNECTED                                                 => "STATUS_PIPE_CONNECTED",
+    SMB_NTSTATUS_PIPE_LISTENING                                                 => "STATUS_PIPE_LISTENING",
+    SMB_NTSTATUS_INVALID_READ_MODE                                              => "STATUS_INVALID_READ_MODE",
+    SMB_NTSTATUS_IO_TIMEOUT                                                     => "STATUS_IO_TIMEOUT",
+    SMB_NTSTATUS_FILE_FORCED_CLOSED                                             => "STATUS_FILE_FORCED_CLOSED",
+    SMB_NTSTATUS_PROFILING_NOT_STARTED                                          => "STATUS_PROFILING_NOT_STARTED",
+    SMB_NTSTATUS_PROFILING_NOT_STOPPED                                          => "STATUS_PROFILING_NOT_STOPPED",
+    SMB_NTSTATUS_COULD_NOT_INTERPRET                                            => "STATUS_COULD_NOT_INTERPRET",
+    SMB_NTSTATUS_FILE_IS_A_DIRECTORY                                            => "STATUS_FILE_IS_A_DIRECTORY",
+    SMB_NTSTATUS_NOT_SUPPORTED                                                  => "STATUS_NOT_SUPPORTED",
+    SMB_NTSTATUS_REMOTE_NOT_LISTENING                                           => "STATUS_REMOTE_NOT_LISTENING",
+    SMB_NTSTATUS_DUPLICATE_NAME                                                 => "STATUS_DUPLICATE_NAME",
+    SMB_NTSTATUS_BAD_NETWORK_PATH                                               => "STATUS_BAD_NETWORK_PATH",
+    SMB_NTSTATUS_NETWORK_BUSY                                                   => "STATUS_NETWORK_BUSY",
+    SMB_NTSTATUS_DEVICE_DOES_NOT_EXIST                                          => "STATUS_DEVICE_DOES_NOT_EXIST",
+    SMB_NTSTATUS_TOO_MANY_COMMANDS                                              => "STATUS_TOO_MANY_COMMANDS",
+    SMB_NTSTATUS_ADAPTER_HARDWARE_ERROR                                         => "STATUS_ADAPTER_HARDWARE_ERROR",
+    SMB_NTSTATUS_INVALID_NETWORK_RESPONSE                                       => "STATUS_INVALID_NETWORK_RESPONSE",
+    SMB_NTSTATUS_UNEXPECTED_NETWORK_ERROR                                       => "STATUS_UNEXPECTED_NETWORK_ERROR",
+    SMB_NTSTATUS_BAD_REMOTE_ADAPTER                                             => "STATUS_BAD_REMOTE_ADAPTER",
+    SMB_NTSTATUS_PRINT_QUEUE_FULL                                               => "STATUS_PRINT_QUEUE_FULL",
+    SMB_NTSTATUS_NO_SPOOL_SPACE                                                 => "STATUS_NO_SPOOL_SPACE",
+    SMB_NTSTATUS_PRINT_CANCELLED                                                => "STATUS_PRINT_CANCELLED",
+    SMB_NTSTATUS_NETWORK_NAME_DELETED                                           => "STATUS_NETWORK_NAME_DELETED",
+    SMB_NTSTATUS_NETWORK_ACCESS_DENIED                                          => "STATUS_NETWORK_ACCESS_DENIED",
+    SMB_NTSTATUS_BAD_DEVICE_TYPE                                                => "STATUS_BAD_DEVICE_TYPE",
+    SMB_NTSTATUS_BAD_NETWORK_NAME                                               => "STATUS_BAD_NETWORK_NAME",
+    SMB_NTSTATUS_TOO_MANY_NAMES                                                 => "STATUS_TOO_MANY_NAMES",
+    SMB_NTSTATUS_TOO_MANY_SESSIONS                                              => "STATUS_TOO_MANY_SESSIONS",
+    SMB_NTSTATUS_SHARING_PAUSED                                                 => "STATUS_SHARING_PAUSED",
+    SMB_NTSTATUS_REQUEST_NOT_ACCEPTED                                           => "STATUS_REQUEST_NOT_ACCEPTED",
+    SMB_NTSTATUS_REDIRECTOR_PAUSED                                              => "STATUS_REDIRECTOR_PAUSED",
+    SMB_NTSTATUS_NET_WRITE_FAULT                                                => "STATUS_NET_WRITE_FAULT",
+    SMB_NTSTATUS_PROFILING_AT_LIMIT                                             => "STATUS_PROFILING_AT_LIMIT",
+    SMB_NTSTATUS_NOT_SAME_DEVICE                                                => "STATUS_NOT_SAME_DEVICE",
+    SMB_NTSTATUS_FILE_RENAMED                                                   => "STATUS_FILE_RENAMED",
+    SMB_NTSTATUS_VIRTUAL_CIRCUIT_CLOSED                                         => "STATUS_VIRTUAL_CIRCUIT_CLOSED",
+    SMB_NTSTATUS_NO_SECURITY_ON_OBJECT                                          => "STATUS_NO_SECURITY_ON_OBJECT",
+    SMB_NTSTATUS_CANT_WAIT                                                      => "STATUS_CANT_WAIT",
+    SMB_NTSTATUS_PIPE_EMPTY                                                     => "STATUS_PIPE_EMPTY",
+    SMB_NTSTATUS_CANT_ACCESS_DOMAIN_INFO                                        => "STATUS_CANT_ACCESS_DOMAIN_INFO",
+    SMB_NTSTATUS_CANT_TERMINATE_SELF                                            => "STATUS_CANT_TERMINATE_SELF",
+    SMB_NTSTATUS_INVALID_SERVER_STATE                                           => "STATUS_INVALID_SERVER_STATE",
+    SMB_NTSTATUS_INVALID_DOMAIN_STATE                                           => "STATUS_INVALID_DOMAIN_STATE",
+    SMB_NTSTATUS_INVALID_DOMAIN_ROLE                                            => "STATUS_INVALID_DOMAIN_ROLE",
+    SMB_NTSTATUS_NO_SUCH_DOMAIN                                                 => "STATUS_NO_SUCH_DOMAIN",
+    SMB_NTSTATUS_DOMAIN_EXISTS                                                  => "STATUS_DOMAIN_EXISTS",
+    SMB_NTSTATUS_DOMAIN_LIMIT_EXCEEDED                                          => "STATUS_DOMAIN_LIMIT_EXCEEDED",
+    SMB_NTSTATUS_OPLOCK_NOT_GRANTED                                             => "STATUS_OPLOCK_NOT_GRANTED",
+    SMB_NTSTATUS_INVALID_OPLOCK_PROTOCOL                                        => "STATUS_INVALID_OPLOCK_PROTOCOL",
+    SMB_NTSTATUS_INTERNAL_DB_CORRUPTION                                         => "STATUS_INTERNAL_DB_CORRUPTION",
+    SMB_NTSTATUS_INTERNAL_ERROR                                                 => "STATUS_INTERNAL_ERROR",
+    SMB_NTSTATUS_GENERIC_NOT_MAPPED                                             => "STATUS_GENERIC_NOT_MAPPED",
+    SMB_NTSTATUS_BAD_DESCRIPTOR_FORMAT                                          => "STATUS_BAD_DESCRIPTOR_FORMAT",
+    SMB_NTSTATUS_INVALID_USER_BUFFER                                            => "STATUS_INVALID_USER_BUFFER",
+    SMB_NTSTATUS_UNEXPECTED_IO_ERROR                                            => "STATUS_UNEXPECTED_IO_ERROR",
+    SMB_NTSTATUS_UNEXPECTED_MM_CREATE_ERR                                       => "STATUS_UNEXPECTED_MM_CREATE_ERR",
+    SMB_NTSTATUS_UNEXPECTED_MM_MAP_ERROR                                        => "STATUS_UNEXPECTED_MM_MAP_ERROR",
+    SMB_NTSTATUS_UNEXPECTED_MM_EXTEND_ERR                                       => "STATUS_UNEXPECTED_MM_EXTEND_ERR",
+    SMB_NTSTATUS_NOT_LOGON_PROCESS                                              => "STATUS_NOT_LOGON_PROCESS",
+    SMB_NTSTATUS_LOGON_SESSION_EXISTS                                           => "STATUS_LOGON_SESSION_EXISTS",
+    SMB_NTSTATUS_INVALID_PARAMETER_1                                            => "STATUS_INVALID_PARAMETER_1",
+    SMB_NTSTATUS_INVALID_PARAMETER_2                                            => "STATUS_INVALID_PARAMETER_2",
+    SMB_NTSTATUS_INVALID_PARAMETER_3                                            => "STATUS_INVALID_PARAMETER_3",
+    SMB_NTSTATUS_INVALID_PARAMETER_4                                            => "STATUS_INVALID_PARAMETER_4",
+    SMB_NTSTATUS_INVALID_PARAMETER_5                                            => "STATUS_INVALID_PARAMETER_5",
+    SMB_NTSTATUS_INVALID_PARAMETER_6                                            => "STATUS_INVALID_PARAMETER_6",
+    SMB_NTSTATUS_INVALID_PARAMETER_7                                            => "STATUS_INVALID_PARAMETER_7",
+    SMB_NTSTATUS_INVALID_PARAMETER_8                                            => "STATUS_INVALID_PARAMETER_8",
+    SMB_NTSTATUS_INVALID_PARAMETER_9                                            => "STATUS_INVALID_PARAMETER_9",
+    SMB_NTSTATUS_INVALID_PARAMETER_10                                           => "STATUS_INVALID_PARAMETER_10",
+    SMB_NTSTATUS_INVALID_PARAMETER_11                                           => "STATUS_INVALID_PARAMETER_11",
+    SMB_NTSTATUS_INVALID_PARAMETER_12                                           => "STATUS_INVALID_PARAMETER_12",
+    SMB_NTSTATUS_REDIRECTOR_NOT_STARTED                                         => "STATUS_REDIRECTOR_NOT_STARTED",
+    SMB_NTSTATUS_REDIRECTOR_STARTED                                             => "STATUS_REDIRECTOR_STARTED",
+    SMB_NTSTATUS_STACK_OVERFLOW                                                 => "STATUS_STACK_OVERFLOW",
+    SMB_NTSTATUS_NO_SUCH_PACKAGE                                                => "STATUS_NO_SUCH_PACKAGE",
+    SMB_NTSTATUS_BAD_FUNCTION_TABLE                                             => "STATUS_BAD_FUNCTION_TABLE",
+    SMB_NTSTATUS_VARIABLE_NOT_FOUND                                             => "STATUS_VARIABLE_NOT_FOUND",
+    SMB_NTSTATUS_DIRECTORY_NOT_EMPTY                                            => "STATUS_DIRECTORY_NOT_EMPTY",
+    SMB_NTSTATUS_FILE_CORRUPT_ERROR                                             => "STATUS_FILE_CORRUPT_ERROR",
+    SMB_NTSTATUS_NOT_A_DIRECTORY                                                => "STATUS_NOT_A_DIRECTORY",
+    SMB_NTSTATUS_BAD_LOGON_SESSION_STATE                                        => "STATUS_BAD_LOGON_SESSION_STATE",
+    SMB_NTSTATUS_LOGON_SESSION_COLLISION                                        => "STATUS_LOGON_SESSION_COLLISION",
+    SMB_NTSTATUS_NAME_TOO_LONG                                                  => "STATUS_NAME_TOO_LONG",
+    SMB_NTSTATUS_FILES_OPEN                                                     => "STATUS_FILES_OPEN",
+    SMB_NTSTATUS_CONNECTION_IN_USE                                              => "STATUS_CONNECTION_IN_USE",
+    SMB_NTSTATUS_MESSAGE_NOT_FOUND                                              => "STATUS_MESSAGE_NOT_FOUND",
+    SMB_NTSTATUS_PROCESS_IS_TERMINATING                                         => "STATUS_PROCESS_IS_TERMINATING",
+    SMB_NTSTATUS_INVALID_LOGON_TYPE                                             => "STATUS_INVALID_LOGON_TYPE",
+    SMB_NTSTATUS_NO_GUID_TRANSLATION                                            => "STATUS_NO_GUID_TRANSLATION",
+    SMB_NTSTATUS_CANNOT_IMPERSONATE                                             => "STATUS_CANNOT_IMPERSONATE",
+    SMB_NTSTATUS_IMAGE_ALREADY_LOADED                                           => "STATUS_IMAGE_ALREADY_LOADED",
+    SMB_NTSTATUS_NO_LDT                                                         => "STATUS_NO_LDT",
+    SMB_NTSTATUS_INVALID_LDT_SIZE                                               => "STATUS_INVALID_LDT_SIZE",
+    SMB_NTSTATUS_INVALID_LDT_OFFSET                                             => "STATUS_INVALID_LDT_OFFSET",
+    SMB_NTSTATUS_INVALID_LDT_DESCRIPTOR                                         => "STATUS_INVALID_LDT_DESCRIPTOR",
+    SMB_NTSTATUS_INVALID_IMAGE_NE_FORMAT                                        => "STATUS_INVALID_IMAGE_NE_FORMAT",
+    SMB_NTSTATUS_RXACT_INVALID_STATE                                            => "STATUS_RXACT_INVALID_STATE",
+    SMB_NTSTATUS_RXACT_COMMIT_FAILURE                                           => "STATUS_RXACT_COMMIT_FAILURE",
+    SMB_NTSTATUS_MAPPED_FILE_SIZE_ZERO                                          => "STATUS_MAPPED_FILE_SIZE_ZERO",
+    SMB_NTSTATUS_TOO_MANY_OPENED_FILES                                          => "STATUS_TOO_MANY_OPENED_FILES",
+    SMB_NTSTATUS_CANCELLED                                                      => "STATUS_CANCELLED",
+    SMB_NTSTATUS_CANNOT_DELETE                                                  => "STATUS_CANNOT_DELETE",
+    SMB_NTSTATUS_INVALID_COMPUTER_NAME                                          => "STATUS_INVALID_COMPUTER_NAME",
+    SMB_NTSTATUS_FILE_DELETED                                                   => "STATUS_FILE_DELETED",
+    SMB_NTSTATUS_SPECIAL_ACCOUNT                                                => "STATUS_SPECIAL_ACCOUNT",
+    SMB_NTSTATUS_SPECIAL_GROUP                                                  => "STATUS_SPECIAL_GROUP",
+    SMB_NTSTATUS_SPECIAL_USER                                                   => "STATUS_SPECIAL_USER",
+    SMB_NTSTATUS_MEMBERS_PRIMARY_GROUP                                          => "STATUS_MEMBERS_PRIMARY_GROUP",
+    SMB_NTSTATUS_FILE_CLOSED                                                    => "STATUS_FILE_CLOSED",
+    SMB_NTSTATUS_TOO_MANY_THREADS                                               => "STATUS_TOO_MANY_THREADS",
+    SMB_NTSTATUS_THREAD_NOT_IN_PROCESS                                          => "STATUS_THREAD_NOT_IN_PROCESS",
+    SMB_NTSTATUS_TOKEN_ALREADY_IN_USE                                           => "STATUS_TOKEN_ALREADY_IN_USE",
+    SMB_NTSTATUS_PAGEFILE_QUOTA_EXCEEDED                                        => "STATUS_PAGEFILE_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_COMMITMENT_LIMIT                                               => "STATUS_COMMITMENT_LIMIT",
+    SMB_NTSTATUS_INVALID_IMAGE_LE_FORMAT                                        => "STATUS_INVALID_IMAGE_LE_FORMAT",
+    SMB_NTSTATUS_INVALID_IMAGE_NOT_MZ                                           => "STATUS_INVALID_IMAGE_NOT_MZ",
+    SMB_NTSTATUS_INVALID_IMAGE_PROTECT                                          => "STATUS_INVALID_IMAGE_PROTECT",
+    SMB_NTSTATUS_INVALID_IMAGE_WIN_16                                           => "STATUS_INVALID_IMAGE_WIN_16",
+    SMB_NTSTATUS_LOGON_SERVER_CONFLICT                                          => "STATUS_LOGON_SERVER_CONFLICT",
+    SMB_NTSTATUS_TIME_DIFFERENCE_AT_DC                                          => "STATUS_TIME_DIFFERENCE_AT_DC",
+    SMB_NTSTATUS_SYNCHRONIZATION_REQUIRED                                       => "STATUS_SYNCHRONIZATION_REQUIRED",
+    SMB_NTSTATUS_DLL_NOT_FOUND                                                  => "STATUS_DLL_NOT_FOUND",
+    SMB_NTSTATUS_OPEN_FAILED                                                    => "STATUS_OPEN_FAILED",
+    SMB_NTSTATUS_IO_PRIVILEGE_FAILED                                            => "STATUS_IO_PRIVILEGE_FAILED",
+    SMB_NTSTATUS_ORDINAL_NOT_FOUND                                              => "STATUS_ORDINAL_NOT_FOUND",
+    SMB_NTSTATUS_ENTRYPOINT_NOT_FOUND                                           => "STATUS_ENTRYPOINT_NOT_FOUND",
+    SMB_NTSTATUS_CONTROL_C_EXIT                                                 => "STATUS_CONTROL_C_EXIT",
+    SMB_NTSTATUS_LOCAL_DISCONNECT                                               => "STATUS_LOCAL_DISCONNECT",
+    SMB_NTSTATUS_REMOTE_DISCONNECT                                              => "STATUS_REMOTE_DISCONNECT",
+    SMB_NTSTATUS_REMOTE_RESOURCES                                               => "STATUS_REMOTE_RESOURCES",
+    SMB_NTSTATUS_LINK_FAILED                                                    => "STATUS_LINK_FAILED",
+    SMB_NTSTATUS_LINK_TIMEOUT                                                   => "STATUS_LINK_TIMEOUT",
+    SMB_NTSTATUS_INVALID_CONNECTION                                             => "STATUS_INVALID_CONNECTION",
+    SMB_NTSTATUS_INVALID_ADDRESS                                                => "STATUS_INVALID_ADDRESS",
+    SMB_NTSTATUS_DLL_INIT_FAILED                                                => "STATUS_DLL_INIT_FAILED",
+    SMB_NTSTATUS_MISSING_SYSTEMFILE                                             => "STATUS_MISSING_SYSTEMFILE",
+    SMB_NTSTATUS_UNHANDLED_EXCEPTION                                            => "STATUS_UNHANDLED_EXCEPTION",
+    SMB_NTSTATUS_APP_INIT_FAILURE                                               => "STATUS_APP_INIT_FAILURE",
+    SMB_NTSTATUS_PAGEFILE_CREATE_FAILED                                         => "STATUS_PAGEFILE_CREATE_FAILED",
+    SMB_NTSTATUS_NO_PAGEFILE                                                    => "STATUS_NO_PAGEFILE",
+    SMB_NTSTATUS_INVALID_LEVEL                                                  => "STATUS_INVALID_LEVEL",
+    SMB_NTSTATUS_WRONG_PASSWORD_CORE                                            => "STATUS_WRONG_PASSWORD_CORE",
+    SMB_NTSTATUS_ILLEGAL_FLOAT_CONTEXT                                          => "STATUS_ILLEGAL_FLOAT_CONTEXT",
+    SMB_NTSTATUS_PIPE_BROKEN                                                    => "STATUS_PIPE_BROKEN",
+    SMB_NTSTATUS_REGISTRY_CORRUPT                                               => "STATUS_REGISTRY_CORRUPT",
+    SMB_NTSTATUS_REGISTRY_IO_FAILED                                             => "STATUS_REGISTRY_IO_FAILED",
+    SMB_NTSTATUS_NO_EVENT_PAIR                                                  => "STATUS_NO_EVENT_PAIR",
+    SMB_NTSTATUS_UNRECOGNIZED_VOLUME                                            => "STATUS_UNRECOGNIZED_VOLUME",
+    SMB_NTSTATUS_SERIAL_NO_DEVICE_INITED                                        => "STATUS_SERIAL_NO_DEVICE_INITED",
+    SMB_NTSTATUS_NO_SUCH_ALIAS                                                  => "STATUS_NO_SUCH_ALIAS",
+    SMB_NTSTATUS_MEMBER_NOT_IN_ALIAS                                            => "STATUS_MEMBER_NOT_IN_ALIAS",
+    SMB_NTSTATUS_MEMBER_IN_ALIAS                                                => "STATUS_MEMBER_IN_ALIAS",
+    SMB_NTSTATUS_ALIAS_EXISTS                                                   => "STATUS_ALIAS_EXISTS",
+    SMB_NTSTATUS_LOGON_NOT_GRANTED                                              => "STATUS_LOGON_NOT_GRANTED",
+    SMB_NTSTATUS_TOO_MANY_SECRETS                                               => "STATUS_TOO_MANY_SECRETS",
+    SMB_NTSTATUS_SECRET_TOO_LONG                                                => "STATUS_SECRET_TOO_LONG",
+    SMB_NTSTATUS_INTERNAL_DB_ERROR                                              => "STATUS_INTERNAL_DB_ERROR",
+    SMB_NTSTATUS_FULLSCREEN_MODE                                                => "STATUS_FULLSCREEN_MODE",
+    SMB_NTSTATUS_TOO_MANY_CONTEXT_IDS                                           => "STATUS_TOO_MANY_CONTEXT_IDS",
+    SMB_NTSTATUS_LOGON_TYPE_NOT_GRANTED                                         => "STATUS_LOGON_TYPE_NOT_GRANTED",
+    SMB_NTSTATUS_NOT_REGISTRY_FILE                                              => "STATUS_NOT_REGISTRY_FILE",
+    SMB_NTSTATUS_NT_CROSS_ENCRYPTION_REQUIRED                                   => "STATUS_NT_CROSS_ENCRYPTION_REQUIRED",
+    SMB_NTSTATUS_DOMAIN_CTRLR_CONFIG_ERROR                                      => "STATUS_DOMAIN_CTRLR_CONFIG_ERROR",
+    SMB_NTSTATUS_FT_MISSING_MEMBER                                              => "STATUS_FT_MISSING_MEMBER",
+    SMB_NTSTATUS_ILL_FORMED_SERVICE_ENTRY                                       => "STATUS_ILL_FORMED_SERVICE_ENTRY",
+    SMB_NTSTATUS_ILLEGAL_CHARACTER                                              => "STATUS_ILLEGAL_CHARACTER",
+    SMB_NTSTATUS_UNMAPPABLE_CHARACTER                                           => "STATUS_UNMAPPABLE_CHARACTER",
+    SMB_NTSTATUS_UNDEFINED_CHARACTER                                            => "STATUS_UNDEFINED_CHARACTER",
+    SMB_NTSTATUS_FLOPPY_VOLUME                                                  => "STATUS_FLOPPY_VOLUME",
+    SMB_NTSTATUS_FLOPPY_ID_MARK_NOT_FOUND                                       => "STATUS_FLOPPY_ID_MARK_NOT_FOUND",
+    SMB_NTSTATUS_FLOPPY_WRONG_CYLINDER                                          => "STATUS_FLOPPY_WRONG_CYLINDER",
+    SMB_NTSTATUS_FLOPPY_UNKNOWN_ERROR                                           => "STATUS_FLOPPY_UNKNOWN_ERROR",
+    SMB_NTSTATUS_FLOPPY_BAD_REGISTERS                                           => "STATUS_FLOPPY_BAD_REGISTERS",
+    SMB_NTSTATUS_DISK_RECALIBRATE_FAILED                                        => "STATUS_DISK_RECALIBRATE_FAILED",
+    SMB_NTSTATUS_DISK_OPERATION_FAILED                                          => "STATUS_DISK_OPERATION_FAILED",
+    SMB_NTSTATUS_DISK_RESET_FAILED                                              => "STATUS_DISK_RESET_FAILED",
+    SMB_NTSTATUS_SHARED_IRQ_BUSY                                                => "STATUS_SHARED_IRQ_BUSY",
+    SMB_NTSTATUS_FT_ORPHANING                                                   => "STATUS_FT_ORPHANING",
+    SMB_NTSTATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT                               => "STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT",
+    SMB_NTSTATUS_PARTITION_FAILURE                                              => "STATUS_PARTITION_FAILURE",
+    SMB_NTSTATUS_INVALID_BLOCK_LENGTH                                           => "STATUS_INVALID_BLOCK_LENGTH",
+    SMB_NTSTATUS_DEVICE_NOT_PARTITIONED                                         => "STATUS_DEVICE_NOT_PARTITIONED",
+    SMB_NTSTATUS_UNABLE_TO_LOCK_MEDIA                                           => "STATUS_UNABLE_TO_LOCK_MEDIA",
+    SMB_NTSTATUS_UNABLE_TO_UNLOAD_MEDIA                                         => "STATUS_UNABLE_TO_UNLOAD_MEDIA",
+    SMB_NTSTATUS_EOM_OVERFLOW                                                   => "STATUS_EOM_OVERFLOW",
+    SMB_NTSTATUS_NO_MEDIA                                                       => "STATUS_NO_MEDIA",
+    SMB_NTSTATUS_NO_SUCH_MEMBER                                                 => "STATUS_NO_SUCH_MEMBER",
+    SMB_NTSTATUS_INVALID_MEMBER                                                 => "STATUS_INVALID_MEMBER",
+    SMB_NTSTATUS_KEY_DELETED                                                    => "STATUS_KEY_DELETED",
+    SMB_NTSTATUS_NO_LOG_SPACE                                                   => "STATUS_NO_LOG_SPACE",
+    SMB_NTSTATUS_TOO_MANY_SIDS                                                  => "STATUS_TOO_MANY_SIDS",
+    SMB_NTSTATUS_LM_CROSS_ENCRYPTION_REQUIRED                                   => "STATUS_LM_CROSS_ENCRYPTION_REQUIRED",
+    SMB_NTSTATUS_KEY_HAS_CHILDREN                                               => "STATUS_KEY_HAS_CHILDREN",
+    SMB_NTSTATUS_CHILD_MUST_BE_VOLATILE                                         => "STATUS_CHILD_MUST_BE_VOLATILE",
+    SMB_NTSTATUS_DEVICE_CONFIGURATION_ERROR                                     => "STATUS_DEVICE_CONFIGURATION_ERROR",
+    SMB_NTSTATUS_DRIVER_INTERNAL_ERROR                                          => "STATUS_DRIVER_INTERNAL_ERROR",
+    SMB_NTSTATUS_INVALID_DEVICE_STATE                                           => "STATUS_INVALID_DEVICE_STATE",
+    SMB_NTSTATUS_IO_DEVICE_ERROR                                                => "STATUS_IO_DEVICE_ERROR",
+    SMB_NTSTATUS_DEVICE_PROTOCOL_ERROR                                          => "STATUS_DEVICE_PROTOCOL_ERROR",
+    SMB_NTSTATUS_BACKUP_CONTROLLER                                              => "STATUS_BACKUP_CONTROLLER",
+    SMB_NTSTATUS_LOG_FILE_FULL                                                  => "STATUS_LOG_FILE_FULL",
+    SMB_NTSTATUS_TOO_LATE                                                       => "STATUS_TOO_LATE",
+    SMB_NTSTATUS_NO_TRUST_LSA_SECRET                                            => "STATUS_NO_TRUST_LSA_SECRET",
+    SMB_NTSTATUS_NO_TRUST_SAM_ACCOUNT                                           => "STATUS_NO_TRUST_SAM_ACCOUNT",
+    SMB_NTSTATUS_TRUSTED_DOMAIN_FAILURE                                         => "STATUS_TRUSTED_DOMAIN_FAILURE",
+    SMB_NTSTATUS_TRUSTED_RELATIONSHIP_FAILURE                                   => "STATUS_TRUSTED_RELATIONSHIP_FAILURE",
+    SMB_NTSTATUS_EVENTLOG_FILE_CORRUPT                                          => "STATUS_EVENTLOG_FILE_CORRUPT",
+    SMB_NTSTATUS_EVENTLOG_CANT_START                                            => "STATUS_EVENTLOG_CANT_START",
+    SMB_NTSTATUS_TRUST_FAILURE                                                  => "STATUS_TRUST_FAILURE",
+    SMB_NTSTATUS_MUTANT_LIMIT_EXCEEDED                                          => "STATUS_MUTANT_LIMIT_EXCEEDED",
+    SMB_NTSTATUS_NETLOGON_NOT_STARTED                                           => "STATUS_NETLOGON_NOT_STARTED",
+    SMB_NTSTATUS_ACCOUNT_EXPIRED                                                => "STATUS_ACCOUNT_EXPIRED",
+    SMB_NTSTATUS_POSSIBLE_DEADLOCK                                              => "STATUS_POSSIBLE_DEADLOCK",
+    SMB_NTSTATUS_NETWORK_CREDENTIAL_CONFLICT                                    => "STATUS_NETWORK_CREDENTIAL_CONFLICT",
+    SMB_NTSTATUS_REMOTE_SESSION_LIMIT                                           => "STATUS_REMOTE_SESSION_LIMIT",
+    SMB_NTSTATUS_EVENTLOG_FILE_CHANGED                                          => "STATUS_EVENTLOG_FILE_CHANGED",
+    SMB_NTSTATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT                              => "STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT",
+    SMB_NTSTATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT                              => "STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT",
+    SMB_NTSTATUS_NOLOGON_SERVER_TRUST_ACCOUNT                                   => "STATUS_NOLOGON_SERVER_TRUST_ACCOUNT",
+    SMB_NTSTATUS_DOMAIN_TRUST_INCONSISTENT                                      => "STATUS_DOMAIN_TRUST_INCONSISTENT",
+    SMB_NTSTATUS_FS_DRIVER_REQUIRED                                             => "STATUS_FS_DRIVER_REQUIRED",
+    SMB_NTSTATUS_IMAGE_ALREADY_LOADED_AS_DLL                                    => "STATUS_IMAGE_ALREADY_LOADED_AS_DLL",
+    SMB_NTSTATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING           => "STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING",
+    SMB_NTSTATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME                              => "STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME",
+    SMB_NTSTATUS_SECURITY_STREAM_IS_INCONSISTENT                                => "STATUS_SECURITY_STREAM_IS_INCONSISTENT",
+    SMB_NTSTATUS_INVALID_LOCK_RANGE                                             => "STATUS_INVALID_LOCK_RANGE",
+    SMB_NTSTATUS_INVALID_ACE_CONDITION                                          => "STATUS_INVALID_ACE_CONDITION",
+    SMB_NTSTATUS_IMAGE_SUBSYSTEM_NOT_PRESENT                                    => "STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT",
+    SMB_NTSTATUS_NOTIFICATION_GUID_ALREADY_DEFINED                              => "STATUS_NOTIFICATION_GUID_ALREADY_DEFINED",
+    SMB_NTSTATUS_NETWORK_OPEN_RESTRICTION                                       => "STATUS_NETWORK_OPEN_RESTRICTION",
+    SMB_NTSTATUS_NO_USER_SESSION_KEY                                            => "STATUS_NO_USER_SESSION_KEY",
+    SMB_NTSTATUS_USER_SESSION_DELETED                                           => "STATUS_USER_SESSION_DELETED",
+    SMB_NTSTATUS_RESOURCE_LANG_NOT_FOUND                                        => "STATUS_RESOURCE_LANG_NOT_FOUND",
+    SMB_NTSTATUS_INSUFF_SERVER_RESOURCES                                        => "STATUS_INSUFF_SERVER_RESOURCES",
+    SMB_NTSTATUS_INVALID_BUFFER_SIZE                                            => "STATUS_INVALID_BUFFER_SIZE",
+    SMB_NTSTATUS_INVALID_ADDRESS_COMPONENT                                      => "STATUS_INVALID_ADDRESS_COMPONENT",
+    SMB_NTSTATUS_INVALID_ADDRESS_WILDCARD                                       => "STATUS_INVALID_ADDRESS_WILDCARD",
+    SMB_NTSTATUS_TOO_MANY_ADDRESSES                                             => "STATUS_TOO_MANY_ADDRESSES",
+    SMB_NTSTATUS_ADDRESS_ALREADY_EXISTS                                         => "STATUS_ADDRESS_ALREADY_EXISTS",
+    SMB_NTSTATUS_ADDRESS_CLOSED                                                 => "STATUS_ADDRESS_CLOSED",
+    SMB_NTSTATUS_CONNECTION_DISCONNECTED                                        => "STATUS_CONNECTION_DISCONNECTED",
+    SMB_NTSTATUS_CONNECTION_RESET                                               => "STATUS_CONNECTION_RESET",
+    SMB_NTSTATUS_TOO_MANY_NODES                                                 => "STATUS_TOO_MANY_NODES",
+    SMB_NTSTATUS_TRANSACTION_ABORTED                                            => "STATUS_TRANSACTION_ABORTED",
+    SMB_NTSTATUS_TRANSACTION_TIMED_OUT                                          => "STATUS_TRANSACTION_TIMED_OUT",
+    SMB_NTSTATUS_TRANSACTION_NO_RELEASE                                         => "STATUS_TRANSACTION_NO_RELEASE",
+    SMB_NTSTATUS_TRANSACTION_NO_MATCH                                           => "STATUS_TRANSACTION_NO_MATCH",
+    SMB_NTSTATUS_TRANSACTION_RESPONDED                                          => "STATUS_TRANSACTION_RESPONDED",
+    SMB_NTSTATUS_TRANSACTION_INVALID_ID                                         => "STATUS_TRANSACTION_INVALID_ID",
+    SMB_NTSTATUS_TRANSACTION_INVALID_TYPE                                       => "STATUS_TRANSACTION_INVALID_TYPE",
+    SMB_NTSTATUS_NOT_SERVER_SESSION                                             => "STATUS_NOT_SERVER_SESSION",
+    SMB_NTSTATUS_NOT_CLIENT_SESSION                                             => "STATUS_NOT_CLIENT_SESSION",
+    SMB_NTSTATUS_CANNOT_LOAD_REGISTRY_FILE                                      => "STATUS_CANNOT_LOAD_REGISTRY_FILE",
+    SMB_NTSTATUS_DEBUG_ATTACH_FAILED                                            => "STATUS_DEBUG_ATTACH_FAILED",
+    SMB_NTSTATUS_SYSTEM_PROCESS_TERMINATED                                      => "STATUS_SYSTEM_PROCESS_TERMINATED",
+    SMB_NTSTATUS_DATA_NOT_ACCEPTED                                              => "STATUS_DATA_NOT_ACCEPTED",
+    SMB_NTSTATUS_NO_BROWSER_SERVERS_FOUND                                       => "STATUS_NO_BROWSER_SERVERS_FOUND",
+    SMB_NTSTATUS_VDM_HARD_ERROR                                                 => "STATUS_VDM_HARD_ERROR",
+    SMB_NTSTATUS_DRIVER_CANCEL_TIMEOUT                                          => "STATUS_DRIVER_CANCEL_TIMEOUT",
+    SMB_NTSTATUS_REPLY_MESSAGE_MISMATCH                                         => "STATUS_REPLY_MESSAGE_MISMATCH",
+    SMB_NTSTATUS_MAPPED_ALIGNMENT                                               => "STATUS_MAPPED_ALIGNMENT",
+    SMB_NTSTATUS_IMAGE_CHECKSUM_MISMATCH                                        => "STATUS_IMAGE_CHECKSUM_MISMATCH",
+    SMB_NTSTATUS_LOST_WRITEBEHIND_DATA                                          => "STATUS_LOST_WRITEBEHIND_DATA",
+    SMB_NTSTATUS_CLIENT_SERVER_PARAMETERS_INVALID                               => "STATUS_CLIENT_SERVER_PARAMETERS_INVALID",
+    SMB_NTSTATUS_PASSWORD_MUST_CHANGE                                           => "STATUS_PASSWORD_MUST_CHANGE",
+    SMB_NTSTATUS_NOT_FOUND                                                      => "STATUS_NOT_FOUND",
+    SMB_NTSTATUS_NOT_TINY_STREAM                                                => "STATUS_NOT_TINY_STREAM",
+    SMB_NTSTATUS_RECOVERY_FAILURE                                               => "STATUS_RECOVERY_FAILURE",
+    SMB_NTSTATUS_STACK_OVERFLOW_READ                                            => "STATUS_STACK_OVERFLOW_READ",
+    SMB_NTSTATUS_FAIL_CHECK                                                     => "STATUS_FAIL_CHECK",
+    SMB_NTSTATUS_DUPLICATE_OBJECTID                                             => "STATUS_DUPLICATE_OBJECTID",
+    SMB_NTSTATUS_OBJECTID_EXISTS                                                => "STATUS_OBJECTID_EXISTS",
+    SMB_NTSTATUS_CONVERT_TO_LARGE                                               => "STATUS_CONVERT_TO_LARGE",
+    SMB_NTSTATUS_RETRY                                                          => "STATUS_RETRY",
+    SMB_NTSTATUS_FOUND_OUT_OF_SCOPE                                             => "STATUS_FOUND_OUT_OF_SCOPE",
+    SMB_NTSTATUS_ALLOCATE_BUCKET                                                => "STATUS_ALLOCATE_BUCKET",
+    SMB_NTSTATUS_PROPSET_NOT_FOUND                                              => "STATUS_PROPSET_NOT_FOUND",
+    SMB_NTSTATUS_MARSHALL_OVERFLOW                                              => "STATUS_MARSHALL_OVERFLOW",
+    SMB_NTSTATUS_INVALID_VARIANT                                                => "STATUS_INVALID_VARIANT",
+    SMB_NTSTATUS_DOMAIN_CONTROLLER_NOT_FOUND                                    => "STATUS_DOMAIN_CONTROLLER_NOT_FOUND",
+    SMB_NTSTATUS_ACCOUNT_LOCKED_OUT                                             => "STATUS_ACCOUNT_LOCKED_OUT",
+    SMB_NTSTATUS_HANDLE_NOT_CLOSABLE                                            => "STATUS_HANDLE_NOT_CLOSABLE",
+    SMB_NTSTATUS_CONNECTION_REFUSED                                             => "STATUS_CONNECTION_REFUSED",
+    SMB_NTSTATUS_GRACEFUL_DISCONNECT                                            => "STATUS_GRACEFUL_DISCONNECT",
+    SMB_NTSTATUS_ADDRESS_ALREADY_ASSOCIATED                                     => "STATUS_ADDRESS_ALREADY_ASSOCIATED",
+    SMB_NTSTATUS_ADDRESS_NOT_ASSOCIATED                                         => "STATUS_ADDRESS_NOT_ASSOCIATED",
+    SMB_NTSTATUS_CONNECTION_INVALID                                             => "STATUS_CONNECTION_INVALID",
+    SMB_NTSTATUS_CONNECTION_ACTIVE                                              => "STATUS_CONNECTION_ACTIVE",
+    SMB_NTSTATUS_NETWORK_UNREACHABLE                                            => "STATUS_NETWORK_UNREACHABLE",
+    SMB_NTSTATUS_HOST_UNREACHABLE                                               => "STATUS_HOST_UNREACHABLE",
+    SMB_NTSTATUS_PROTOCOL_UNREACHABLE                                           => "STATUS_PROTOCOL_UNREACHABLE",
+    SMB_NTSTATUS_PORT_UNREACHABLE                                               => "STATUS_PORT_UNREACHABLE",
+    SMB_NTSTATUS_REQUEST_ABORTED                                                => "STATUS_REQUEST_ABORTED",
+    SMB_NTSTATUS_CONNECTION_ABORTED                                             => "STATUS_CONNECTION_ABORTED",
+    SMB_NTSTATUS_BAD_COMPRESSION_BUFFER                                         => "STATUS_BAD_COMPRESSION_BUFFER",
+    SMB_NTSTATUS_USER_MAPPED_FILE                                               => "STATUS_USER_MAPPED_FILE",
+    SMB_NTSTATUS_AUDIT_FAILED                                                   => "STATUS_AUDIT_FAILED",
+    SMB_NTSTATUS_TIMER_RESOLUTION_NOT_SET                                       => "STATUS_TIMER_RESOLUTION_NOT_SET",
+    SMB_NTSTATUS_CONNECTION_COUNT_LIMIT                                         => "STATUS_CONNECTION_COUNT_LIMIT",
+    SMB_NTSTATUS_LOGIN_TIME_RESTRICTION                                         => "STATUS_LOGIN_TIME_RESTRICTION",
+    SMB_NTSTATUS_LOGIN_WKSTA_RESTRICTION                                        => "STATUS_LOGIN_WKSTA_RESTRICTION",
+    SMB_NTSTATUS_IMAGE_MP_UP_MISMATCH                                           => "STATUS_IMAGE_MP_UP_MISMATCH",
+    SMB_NTSTATUS_INSUFFICIENT_LOGON_INFO                                        => "STATUS_INSUFFICIENT_LOGON_INFO",
+    SMB_NTSTATUS_BAD_DLL_ENTRYPOINT                                             => "STATUS_BAD_DLL_ENTRYPOINT",
+    SMB_NTSTATUS_BAD_SERVICE_ENTRYPOINT                                         => "STATUS_BAD_SERVICE_ENTRYPOINT",
+    SMB_NTSTATUS_LPC_REPLY_LOST                                                 => "STATUS_LPC_REPLY_LOST",
+    SMB_NTSTATUS_IP_ADDRESS_CONFLICT1                                           => "STATUS_IP_ADDRESS_CONFLICT1",
+    SMB_NTSTATUS_IP_ADDRESS_CONFLICT2                                           => "STATUS_IP_ADDRESS_CONFLICT2",
+    SMB_NTSTATUS_REGISTRY_QUOTA_LIMIT                                           => "STATUS_REGISTRY_QUOTA_LIMIT",
+    SMB_NTSTATUS_PATH_NOT_COVERED                                               => "STATUS_PATH_NOT_COVERED",
+    SMB_NTSTATUS_NO_CALLBACK_ACTIVE                                             => "STATUS_NO_CALLBACK_ACTIVE",
+    SMB_NTSTATUS_LICENSE_QUOTA_EXCEEDED                                         => "STATUS_LICENSE_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_PWD_TOO_SHORT                                                  => "STATUS_PWD_TOO_SHORT",
+    SMB_NTSTATUS_PWD_TOO_RECENT                                                 => "STATUS_PWD_TOO_RECENT",
+    SMB_NTSTATUS_PWD_HISTORY_CONFLICT                                           => "STATUS_PWD_HISTORY_CONFLICT",
+    SMB_NTSTATUS_PLUGPLAY_NO_DEVICE                                             => "STATUS_PLUGPLAY_NO_DEVICE",
+    SMB_NTSTATUS_UNSUPPORTED_COMPRESSION                                        => "STATUS_UNSUPPORTED_COMPRESSION",
+    SMB_NTSTATUS_INVALID_HW_PROFILE                                             => "STATUS_INVALID_HW_PROFILE",
+    SMB_NTSTATUS_INVALID_PLUGPLAY_DEVICE_PATH                                   => "STATUS_INVALID_PLUGPLAY_DEVICE_PATH",
+    SMB_NTSTATUS_DRIVER_ORDINAL_NOT_FOUND                                       => "STATUS_DRIVER_ORDINAL_NOT_FOUND",
+    SMB_NTSTATUS_DRIVER_ENTRYPOINT_NOT_FOUND                                    => "STATUS_DRIVER_ENTRYPOINT_NOT_FOUND",
+    SMB_NTSTATUS_RESOURCE_NOT_OWNED                                             => "STATUS_RESOURCE_NOT_OWNED",
+    SMB_NTSTATUS_TOO_MANY_LINKS                                                 => "STATUS_TOO_MANY_LINKS",
+    SMB_NTSTATUS_QUOTA_LIST_INCONSISTENT                                        => "STATUS_QUOTA_LIST_INCONSISTENT",
+    SMB_NTSTATUS_FILE_IS_OFFLINE                                                => "STATUS_FILE_IS_OFFLINE",
+    SMB_NTSTATUS_EVALUATION_EXPIRATION                                          => "STATUS_EVALUATION_EXPIRATION",
+    SMB_NTSTATUS_ILLEGAL_DLL_RELOCATION                                         => "STATUS_ILLEGAL_DLL_RELOCATION",
+    SMB_NTSTATUS_LICENSE_VIOLATION                                              => "STATUS_LICENSE_VIOLATION",
+    SMB_NTSTATUS_DLL_INIT_FAILED_LOGOFF                                         => "STATUS_DLL_INIT_FAILED_LOGOFF",
+    SMB_NTSTATUS_DRIVER_UNABLE_TO_LOAD                                          => "STATUS_DRIVER_UNABLE_TO_LOAD",
+    SMB_NTSTATUS_DFS_UNAVAILABLE                                                => "STATUS_DFS_UNAVAILABLE",
+    SMB_NTSTATUS_VOLUME_DISMOUNTED                                              => "STATUS_VOLUME_DISMOUNTED",
+    SMB_NTSTATUS_WX86_INTERNAL_ERROR                                            => "STATUS_WX86_INTERNAL_ERROR",
+    SMB_NTSTATUS_WX86_FLOAT_STACK_CHECK                                         => "STATUS_WX86_FLOAT_STACK_CHECK",
+    SMB_NTSTATUS_VALIDATE_CONTINUE                                              => "STATUS_VALIDATE_CONTINUE",
+    SMB_NTSTATUS_NO_MATCH                                                       => "STATUS_NO_MATCH",
+    SMB_NTSTATUS_NO_MORE_MATCHES                                                => "STATUS_NO_MORE_MATCHES",
+    SMB_NTSTATUS_NOT_A_REPARSE_POINT                                            => "STATUS_NOT_A_REPARSE_POINT",
+    SMB_NTSTATUS_IO_REPARSE_TAG_INVALID                                         => "STATUS_IO_REPARSE_TAG_INVALID",
+    SMB_NTSTATUS_IO_REPARSE_TAG_MISMATCH                                        => "STATUS_IO_REPARSE_TAG_MISMATCH",
+    SMB_NTSTATUS_IO_REPARSE_DATA_INVALID                                        => "STATUS_IO_REPARSE_DATA_INVALID",
+    SMB_NTSTATUS_IO_REPARSE_TAG_NOT_HANDLED                                     => "STATUS_IO_REPARSE_TAG_NOT_HANDLED",
+    SMB_NTSTATUS_REPARSE_POINT_NOT_RESOLVED                                     => "STATUS_REPARSE_POINT_NOT_RESOLVED",
+    SMB_NTSTATUS_DIRECTORY_IS_A_REPARSE_POINT                                   => "STATUS_DIRECTORY_IS_A_REPARSE_POINT",
+    SMB_NTSTATUS_RANGE_LIST_CONFLICT                                            => "STATUS_RANGE_LIST_CONFLICT",
+    SMB_NTSTATUS_SOURCE_ELEMENT_EMPTY                                           => "STATUS_SOURCE_ELEMENT_EMPTY",
+    SMB_NTSTATUS_DESTINATION_ELEMENT_FULL                                       => "STATUS_DESTINATION_ELEMENT_FULL",
+    SMB_NTSTATUS_ILLEGAL_ELEMENT_ADDRESS                                        => "STATUS_ILLEGAL_ELEMENT_ADDRESS",
+    SMB_NTSTATUS_MAGAZINE_NOT_PRESENT                                           => "STATUS_MAGAZINE_NOT_PRESENT",
+    SMB_NTSTATUS_REINITIALIZATION_NEEDED                                        => "STATUS_REINITIALIZATION_NEEDED",
+    SMB_NTSTATUS_ENCRYPTION_FAILED                                              => "STATUS_ENCRYPTION_FAILED",
+    SMB_NTSTATUS_DECRYPTION_FAILED                                              => "STATUS_DECRYPTION_FAILED",
+    SMB_NTSTATUS_RANGE_NOT_FOUND                                                => "STATUS_RANGE_NOT_FOUND",
+    SMB_NTSTATUS_NO_RECOVERY_POLICY                                             => "STATUS_NO_RECOVERY_POLICY",
+    SMB_NTSTATUS_NO_EFS                                                         => "STATUS_NO_EFS",
+    SMB_NTSTATUS_WRONG_EFS                                                      => "STATUS_WRONG_EFS",
+    SMB_NTSTATUS_NO_USER_KEYS                                                   => "STATUS_NO_USER_KEYS",
+    SMB_NTSTATUS_FILE_NOT_ENCRYPTED                                             => "STATUS_FILE_NOT_ENCRYPTED",
+    SMB_NTSTATUS_NOT_EXPORT_FORMAT                                              => "STATUS_NOT_EXPORT_FORMAT",
+    SMB_NTSTATUS_FILE_ENCRYPTED                                                 => "STATUS_FILE_ENCRYPTED",
+    SMB_NTSTATUS_WMI_GUID_NOT_FOUND                                             => "STATUS_WMI_GUID_NOT_FOUND",
+    SMB_NTSTATUS_WMI_INSTANCE_NOT_FOUND                                         => "STATUS_WMI_INSTANCE_NOT_FOUND",
+    SMB_NTSTATUS_WMI_ITEMID_NOT_FOUND                                           => "STATUS_WMI_ITEMID_NOT_FOUND",
+    SMB_NTSTATUS_WMI_TRY_AGAIN                                                  => "STATUS_WMI_TRY_AGAIN",
+    SMB_NTSTATUS_SHARED_POLICY                                                  => "STATUS_SHARED_POLICY",
+    SMB_NTSTATUS_POLICY_OBJECT_NOT_FOUND                                        => "STATUS_POLICY_OBJECT_NOT_FOUND",
+    SMB_NTSTATUS_POLICY_ONLY_IN_DS                                              => "STATUS_POLICY_ONLY_IN_DS",
+    SMB_NTSTATUS_VOLUME_NOT_UPGRADED                                            => "STATUS_VOLUME_NOT_UPGRADED",
+    SMB_NTSTATUS_REMOTE_STORAGE_NOT_ACTIVE                                      => "STATUS_REMOTE_STORAGE_NOT_ACTIVE",
+    SMB_NTSTATUS_REMOTE_STORAGE_MEDIA_ERROR                                     => "STATUS_REMOTE_STORAGE_MEDIA_ERROR",
+    SMB_NTSTATUS_NO_TRACKING_SERVICE                                            => "STATUS_NO_TRACKING_SERVICE",
+    SMB_NTSTATUS_SERVER_SID_MISMATCH                                            => "STATUS_SERVER_SID_MISMATCH",
+    SMB_NTSTATUS_DS_NO_ATTRIBUTE_OR_VALUE                                       => "STATUS_DS_NO_ATTRIBUTE_OR_VALUE",
+    SMB_NTSTATUS_DS_INVALID_ATTRIBUTE_SYNTAX                                    => "STATUS_DS_INVALID_ATTRIBUTE_SYNTAX",
+    SMB_NTSTATUS_DS_ATTRIBUTE_TYPE_UNDEFINED                                    => "STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED",
+    SMB_NTSTATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS                                   => "STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS",
+    SMB_NTSTATUS_DS_BUSY                                                        => "STATUS_DS_BUSY",
+    SMB_NTSTATUS_DS_UNAVAILABLE                                                 => "STATUS_DS_UNAVAILABLE",
+    SMB_NTSTATUS_DS_NO_RIDS_ALLOCATED                                           => "STATUS_DS_NO_RIDS_ALLOCATED",
+    SMB_NTSTATUS_DS_NO_MORE_RIDS                                                => "STATUS_DS_NO_MORE_RIDS",
+    SMB_NTSTATUS_DS_INCORRECT_ROLE_OWNER                                        => "STATUS_DS_INCORRECT_ROLE_OWNER",
+    SMB_NTSTATUS_DS_RIDMGR_INIT_ERROR                                           => "STATUS_DS_RIDMGR_INIT_ERROR",
+    SMB_NTSTATUS_DS_OBJ_CLASS_VIOLATION                                         => "STATUS_DS_OBJ_CLASS_VIOLATION",
+    SMB_NTSTATUS_DS_CANT_ON_NON_LEAF                                            => "STATUS_DS_CANT_ON_NON_LEAF",
+    SMB_NTSTATUS_DS_CANT_ON_RDN                                                 => "STATUS_DS_CANT_ON_RDN",
+    SMB_NTSTATUS_DS_CANT_MOD_OBJ_CLASS                                          => "STATUS_DS_CANT_MOD_OBJ_CLASS",
+    SMB_NTSTATUS_DS_CROSS_DOM_MOVE_FAILED                                       => "STATUS_DS_CROSS_DOM_MOVE_FAILED",
+    SMB_NTSTATUS_DS_GC_NOT_AVAILABLE                                            => "STATUS_DS_GC_NOT_AVAILABLE",
+    SMB_NTSTATUS_DIRECTORY_SERVICE_REQUIRED                                     => "STATUS_DIRECTORY_SERVICE_REQUIRED",
+    SMB_NTSTATUS_REPARSE_ATTRIBUTE_CONFLICT                                     => "STATUS_REPARSE_ATTRIBUTE_CONFLICT",
+    SMB_NTSTATUS_CANT_ENABLE_DENY_ONLY                                          => "STATUS_CANT_ENABLE_DENY_ONLY",
+    SMB_NTSTATUS_FLOAT_MULTIPLE_FAULTS                                          => "STATUS_FLOAT_MULTIPLE_FAULTS",
+    SMB_NTSTATUS_FLOAT_MULTIPLE_TRAPS                                           => "STATUS_FLOAT_MULTIPLE_TRAPS",
+    SMB_NTSTATUS_DEVICE_REMOVED                                                 => "STATUS_DEVICE_REMOVED",
+    SMB_NTSTATUS_JOURNAL_DELETE_IN_PROGRESS                                     => "STATUS_JOURNAL_DELETE_IN_PROGRESS",
+    SMB_NTSTATUS_JOURNAL_NOT_ACTIVE                                             => "STATUS_JOURNAL_NOT_ACTIVE",
+    SMB_NTSTATUS_NOINTERFACE                                                    => "STATUS_NOINTERFACE",
+    SMB_NTSTATUS_DS_ADMIN_LIMIT_EXCEEDED                                        => "STATUS_DS_ADMIN_LIMIT_EXCEEDED",
+    SMB_NTSTATUS_DRIVER_FAILED_SLEEP                                            => "STATUS_DRIVER_FAILED_SLEEP",
+    SMB_NTSTATUS_MUTUAL_AUTHENTICATION_FAILED                                   => "STATUS_MUTUAL_AUTHENTICATION_FAILED",
+    SMB_NTSTATUS_CORRUPT_SYSTEM_FILE                                            => "STATUS_CORRUPT_SYSTEM_FILE",
+    SMB_NTSTATUS_DATATYPE_MISALIGNMENT_ERROR                                    => "STATUS_DATATYPE_MISALIGNMENT_ERROR",
+    SMB_NTSTATUS_WMI_READ_ONLY                                                  => "STATUS_WMI_READ_ONLY",
+    SMB_NTSTATUS_WMI_SET_FAILURE                                                => "STATUS_WMI_SET_FAILURE",
+    SMB_NTSTATUS_COMMITMENT_MINIMUM                                             => "STATUS_COMMITMENT_MINIMUM",
+    SMB_NTSTATUS_REG_NAT_CONSUMPTION                                            => "STATUS_REG_NAT_CONSUMPTION",
+    SMB_NTSTATUS_TRANSPORT_FULL                                                 => "STATUS_TRANSPORT_FULL",
+    SMB_NTSTATUS_DS_SAM_INIT_FAILURE                                            => "STATUS_DS_SAM_INIT_FAILURE",
+    SMB_NTSTATUS_ONLY_IF_CONNECTED                                              => "STATUS_ONLY_IF_CONNECTED",
+    SMB_NTSTATUS_DS_SENSITIVE_GROUP_VIOLATION                                   => "STATUS_DS_SENSITIVE_GROUP_VIOLATION",
+    SMB_NTSTATUS_PNP_RESTART_ENUMERATION                                        => "STATUS_PNP_RESTART_ENUMERATION",
+    SMB_NTSTATUS_JOURNAL_ENTRY_DELETED                                          => "STATUS_JOURNAL_ENTRY_DELETED",
+    SMB_NTSTATUS_DS_CANT_MOD_PRIMARYGROUPID                                     => "STATUS_DS_CANT_MOD_PRIMARYGROUPID",
+    SMB_NTSTATUS_SYSTEM_IMAGE_BAD_SIGNATURE                                     => "STATUS_SYSTEM_IMAGE_BAD_SIGNATURE",
+    SMB_NTSTATUS_PNP_REBOOT_REQUIRED                                            => "STATUS_PNP_REBOOT_REQUIRED",
+    SMB_NTSTATUS_POWER_STATE_INVALID                                            => "STATUS_POWER_STATE_INVALID",
+    SMB_NTSTATUS_DS_INVALID_GROUP_TYPE                                          => "STATUS_DS_INVALID_GROUP_TYPE",
+    SMB_NTSTATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN                          => "STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN",
+    SMB_NTSTATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN                           => "STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN",
+    SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER                               => "STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER",
+    SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER                           => "STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER",
+    SMB_NTSTATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER                            => "STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER",
+    SMB_NTSTATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER                         => "STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER",
+    SMB_NTSTATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER                    => "STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER",
+    SMB_NTSTATUS_DS_HAVE_PRIMARY_MEMBERS                                        => "STATUS_DS_HAVE_PRIMARY_MEMBERS",
+    SMB_NTSTATUS_WMI_NOT_SUPPORTED                                              => "STATUS_WMI_NOT_SUPPORTED",
+    SMB_NTSTATUS_INSUFFICIENT_POWER                                             => "STATUS_INSUFFICIENT_POWER",
+    SMB_NTSTATUS_SAM_NEED_BOOTKEY_PASSWORD                                      => "STATUS_SAM_NEED_BOOTKEY_PASSWORD",
+    SMB_NTSTATUS_SAM_NEED_BOOTKEY_FLOPPY                                        => "STATUS_SAM_NEED_BOOTKEY_FLOPPY",
+    SMB_NTSTATUS_DS_CANT_START                                                  => "STATUS_DS_CANT_START",
+    SMB_NTSTATUS_DS_INIT_FAILURE                                                => "STATUS_DS_INIT_FAILURE",
+    SMB_NTSTATUS_SAM_INIT_FAILURE                                               => "STATUS_SAM_INIT_FAILURE",
+    SMB_NTSTATUS_DS_GC_REQUIRED                                                 => "STATUS_DS_GC_REQUIRED",
+    SMB_NTSTATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY                                  => "STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY",
+    SMB_NTSTATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS                                  => "STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS",
+    SMB_NTSTATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED                              => "STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_CURRENT_DOMAIN_NOT_ALLOWED                                     => "STATUS_CURRENT_DOMAIN_NOT_ALLOWED",
+    SMB_NTSTATUS_CANNOT_MAKE                                                    => "STATUS_CANNOT_MAKE",
+    SMB_NTSTATUS_SYSTEM_SHUTDOWN                                                => "STATUS_SYSTEM_SHUTDOWN",
+    SMB_NTSTATUS_DS_INIT_FAILURE_CONSOLE                                        => "STATUS_DS_INIT_FAILURE_CONSOLE",
+    SMB_NTSTATUS_DS_SAM_INIT_FAILURE_CONSOLE                                    => "STATUS_DS_SAM_INIT_FAILURE_CONSOLE",
+    SMB_NTSTATUS_UNFINISHED_CONTEXT_DELETED                                     => "STATUS_UNFINISHED_CONTEXT_DELETED",
+    SMB_NTSTATUS_NO_TGT_REPLY                                                   => "STATUS_NO_TGT_REPLY",
+    SMB_NTSTATUS_OBJECTID_NOT_FOUND                                             => "STATUS_OBJECTID_NOT_FOUND",
+    SMB_NTSTATUS_NO_IP_ADDRESSES                                                => "STATUS_NO_IP_ADDRESSES",
+    SMB_NTSTATUS_WRONG_CREDENTIAL_HANDLE                                        => "STATUS_WRONG_CREDENTIAL_HANDLE",
+    SMB_NTSTATUS_CRYPTO_SYSTEM_INVALID                                          => "STATUS_CRYPTO_SYSTEM_INVALID",
+    SMB_NTSTATUS_MAX_REFERRALS_EXCEEDED                                         => "STATUS_MAX_REFERRALS_EXCEEDED",
+    SMB_NTSTATUS_MUST_BE_KDC                                                    => "STATUS_MUST_BE_KDC",
+    SMB_NTSTATUS_STRONG_CRYPTO_NOT_SUPPORTED                                    => "STATUS_STRONG_CRYPTO_NOT_SUPPORTED",
+    SMB_NTSTATUS_TOO_MANY_PRINCIPALS                                            => "STATUS_TOO_MANY_PRINCIPALS",
+    SMB_NTSTATUS_NO_PA_DATA                                                     => "STATUS_NO_PA_DATA",
+    SMB_NTSTATUS_PKINIT_NAME_MISMATCH                                           => "STATUS_PKINIT_NAME_MISMATCH",
+    SMB_NTSTATUS_SMARTCARD_LOGON_REQUIRED                                       => "STATUS_SMARTCARD_LOGON_REQUIRED",
+    SMB_NTSTATUS_KDC_INVALID_REQUEST                                            => "STATUS_KDC_INVALID_REQUEST",
+    SMB_NTSTATUS_KDC_UNABLE_TO_REFER                                            => "STATUS_KDC_UNABLE_TO_REFER",
+    SMB_NTSTATUS_KDC_UNKNOWN_ETYPE                                              => "STATUS_KDC_UNKNOWN_ETYPE",
+    SMB_NTSTATUS_SHUTDOWN_IN_PROGRESS                                           => "STATUS_SHUTDOWN_IN_PROGRESS",
+    SMB_NTSTATUS_SERVER_SHUTDOWN_IN_PROGRESS                                    => "STATUS_SERVER_SHUTDOWN_IN_PROGRESS",
+    SMB_NTSTATUS_NOT_SUPPORTED_ON_SBS                                           => "STATUS_NOT_SUPPORTED_ON_SBS",
+    SMB_NTSTATUS_WMI_GUID_DISCONNECTED                                          => "STATUS_WMI_GUID_DISCONNECTED",
+    SMB_NTSTATUS_WMI_ALREADY_DISABLED                                           => "STATUS_WMI_ALREADY_DISABLED",
+    SMB_NTSTATUS_WMI_ALREADY_ENABLED                                            => "STATUS_WMI_ALREADY_ENABLED",
+    SMB_NTSTATUS_MFT_TOO_FRAGMENTED                                             => "STATUS_MFT_TOO_FRAGMENTED",
+    SMB_NTSTATUS_COPY_PROTECTION_FAILURE                                        => "STATUS_COPY_PROTECTION_FAILURE",
+    SMB_NTSTATUS_CSS_AUTHENTICATION_FAILURE                                     => "STATUS_CSS_AUTHENTICATION_FAILURE",
+    SMB_NTSTATUS_CSS_KEY_NOT_PRESENT                                            => "STATUS_CSS_KEY_NOT_PRESENT",
+    SMB_NTSTATUS_CSS_KEY_NOT_ESTABLISHED                                        => "STATUS_CSS_KEY_NOT_ESTABLISHED",
+    SMB_NTSTATUS_CSS_SCRAMBLED_SECTOR                                           => "STATUS_CSS_SCRAMBLED_SECTOR",
+    SMB_NTSTATUS_CSS_REGION_MISMATCH                                            => "STATUS_CSS_REGION_MISMATCH",
+    SMB_NTSTATUS_CSS_RESETS_EXHAUSTED                                           => "STATUS_CSS_RESETS_EXHAUSTED",
+    SMB_NTSTATUS_PKINIT_FAILURE                                                 => "STATUS_PKINIT_FAILURE",
+    SMB_NTSTATUS_SMARTCARD_SUBSYSTEM_FAILURE                                    => "STATUS_SMARTCARD_SUBSYSTEM_FAILURE",
+    SMB_NTSTATUS_NO_KERB_KEY                                                    => "STATUS_NO_KERB_KEY",
+    SMB_NTSTATUS_HOST_DOWN                                                      => "STATUS_HOST_DOWN",
+    SMB_NTSTATUS_UNSUPPORTED_PREAUTH                                            => "STATUS_UNSUPPORTED_PREAUTH",
+    SMB_NTSTATUS_EFS_ALG_BLOB_TOO_BIG                                           => "STATUS_EFS_ALG_BLOB_TOO_BIG",
+    SMB_NTSTATUS_PORT_NOT_SET                                                   => "STATUS_PORT_NOT_SET",
+    SMB_NTSTATUS_DEBUGGER_INACTIVE                                              => "STATUS_DEBUGGER_INACTIVE",
+    SMB_NTSTATUS_DS_VERSION_CHECK_FAILURE                                       => "STATUS_DS_VERSION_CHECK_FAILURE",
+    SMB_NTSTATUS_AUDITING_DISABLED                                              => "STATUS_AUDITING_DISABLED",
+    SMB_NTSTATUS_PRENT4_MACHINE_ACCOUNT                                         => "STATUS_PRENT4_MACHINE_ACCOUNT",
+    SMB_NTSTATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER                               => "STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER",
+    SMB_NTSTATUS_INVALID_IMAGE_WIN_32                                           => "STATUS_INVALID_IMAGE_WIN_32",
+    SMB_NTSTATUS_INVALID_IMAGE_WIN_64                                           => "STATUS_INVALID_IMAGE_WIN_64",
+    SMB_NTSTATUS_BAD_BINDINGS                                                   => "STATUS_BAD_BINDINGS",
+    SMB_NTSTATUS_NETWORK_SESSION_EXPIRED                                        => "STATUS_NETWORK_SESSION_EXPIRED",
+    SMB_NTSTATUS_APPHELP_BLOCK                                                  => "STATUS_APPHELP_BLOCK",
+    SMB_NTSTATUS_ALL_SIDS_FILTERED                                              => "STATUS_ALL_SIDS_FILTERED",
+    SMB_NTSTATUS_NOT_SAFE_MODE_DRIVER                                           => "STATUS_NOT_SAFE_MODE_DRIVER",
+    SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT                              => "STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT",
+    SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_PATH                                 => "STATUS_ACCESS_DISABLED_BY_POLICY_PATH",
+    SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER                            => "STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER",
+    SMB_NTSTATUS_ACCESS_DISABLED_BY_POLICY_OTHER                                => "STATUS_ACCESS_DISABLED_BY_POLICY_OTHER",
+    SMB_NTSTATUS_FAILED_DRIVER_ENTRY                                            => "STATUS_FAILED_DRIVER_ENTRY",
+    SMB_NTSTATUS_DEVICE_ENUMERATION_ERROR                                       => "STATUS_DEVICE_ENUMERATION_ERROR",
+    SMB_NTSTATUS_MOUNT_POINT_NOT_RESOLVED                                       => "STATUS_MOUNT_POINT_NOT_RESOLVED",
+    SMB_NTSTATUS_INVALID_DEVICE_OBJECT_PARAMETER                                => "STATUS_INVALID_DEVICE_OBJECT_PARAMETER",
+    SMB_NTSTATUS_MCA_OCCURED                                                    => "STATUS_MCA_OCCURED",
+    SMB_NTSTATUS_DRIVER_BLOCKED_CRITICAL                                        => "STATUS_DRIVER_BLOCKED_CRITICAL",
+    SMB_NTSTATUS_DRIVER_BLOCKED                                                 => "STATUS_DRIVER_BLOCKED",
+    SMB_NTSTATUS_DRIVER_DATABASE_ERROR                                          => "STATUS_DRIVER_DATABASE_ERROR",
+    SMB_NTSTATUS_SYSTEM_HIVE_TOO_LARGE                                          => "STATUS_SYSTEM_HIVE_TOO_LARGE",
+    SMB_NTSTATUS_INVALID_IMPORT_OF_NON_DLL                                      => "STATUS_INVALID_IMPORT_OF_NON_DLL",
+    SMB_NTSTATUS_NO_SECRETS                                                     => "STATUS_NO_SECRETS",
+    SMB_NTSTATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY                          => "STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY",
+    SMB_NTSTATUS_FAILED_STACK_SWITCH                                            => "STATUS_FAILED_STACK_SWITCH",
+    SMB_NTSTATUS_HEAP_CORRUPTION                                                => "STATUS_HEAP_CORRUPTION",
+    SMB_NTSTATUS_SMARTCARD_WRONG_PIN                                            => "STATUS_SMARTCARD_WRONG_PIN",
+    SMB_NTSTATUS_SMARTCARD_CARD_BLOCKED                                         => "STATUS_SMARTCARD_CARD_BLOCKED",
+    SMB_NTSTATUS_SMARTCARD_CARD_NOT_AUTHENTICATED                               => "STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED",
+    SMB_NTSTATUS_SMARTCARD_NO_CARD                                              => "STATUS_SMARTCARD_NO_CARD",
+    SMB_NTSTATUS_SMARTCARD_NO_KEY_CONTAINER                                     => "STATUS_SMARTCARD_NO_KEY_CONTAINER",
+    SMB_NTSTATUS_SMARTCARD_NO_CERTIFICATE                                       => "STATUS_SMARTCARD_NO_CERTIFICATE",
+    SMB_NTSTATUS_SMARTCARD_NO_KEYSET                                            => "STATUS_SMARTCARD_NO_KEYSET",
+    SMB_NTSTATUS_SMARTCARD_IO_ERROR                                             => "STATUS_SMARTCARD_IO_ERROR",
+    SMB_NTSTATUS_DOWNGRADE_DETECTED                                             => "STATUS_DOWNGRADE_DETECTED",
+    SMB_NTSTATUS_SMARTCARD_CERT_REVOKED                                         => "STATUS_SMARTCARD_CERT_REVOKED",
+    SMB_NTSTATUS_ISSUING_CA_UNTRUSTED                                           => "STATUS_ISSUING_CA_UNTRUSTED",
+    SMB_NTSTATUS_REVOCATION_OFFLINE_C                                           => "STATUS_REVOCATION_OFFLINE_C",
+    SMB_NTSTATUS_PKINIT_CLIENT_FAILURE                                          => "STATUS_PKINIT_CLIENT_FAILURE",
+    SMB_NTSTATUS_SMARTCARD_CERT_EXPIRED                                         => "STATUS_SMARTCARD_CERT_EXPIRED",
+    SMB_NTSTATUS_DRIVER_FAILED_PRIOR_UNLOAD                                     => "STATUS_DRIVER_FAILED_PRIOR_UNLOAD",
+    SMB_NTSTATUS_SMARTCARD_SILENT_CONTEXT                                       => "STATUS_SMARTCARD_SILENT_CONTEXT",
+    SMB_NTSTATUS_PER_USER_TRUST_QUOTA_EXCEEDED                                  => "STATUS_PER_USER_TRUST_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_ALL_USER_TRUST_QUOTA_EXCEEDED                                  => "STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED                               => "STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_DS_NAME_NOT_UNIQUE                                             => "STATUS_DS_NAME_NOT_UNIQUE",
+    SMB_NTSTATUS_DS_DUPLICATE_ID_FOUND                                          => "STATUS_DS_DUPLICATE_ID_FOUND",
+    SMB_NTSTATUS_DS_GROUP_CONVERSION_ERROR                                      => "STATUS_DS_GROUP_CONVERSION_ERROR",
+    SMB_NTSTATUS_VOLSNAP_PREPARE_HIBERNATE                                      => "STATUS_VOLSNAP_PREPARE_HIBERNATE",
+    SMB_NTSTATUS_USER2USER_REQUIRED                                             => "STATUS_USER2USER_REQUIRED",
+    SMB_NTSTATUS_STACK_BUFFER_OVERRUN                                           => "STATUS_STACK_BUFFER_OVERRUN",
+    SMB_NTSTATUS_NO_S4U_PROT_SUPPORT                                            => "STATUS_NO_S4U_PROT_SUPPORT",
+    SMB_NTSTATUS_CROSSREALM_DELEGATION_FAILURE                                  => "STATUS_CROSSREALM_DELEGATION_FAILURE",
+    SMB_NTSTATUS_REVOCATION_OFFLINE_KDC                                         => "STATUS_REVOCATION_OFFLINE_KDC",
+    SMB_NTSTATUS_ISSUING_CA_UNTRUSTED_KDC                                       => "STATUS_ISSUING_CA_UNTRUSTED_KDC",
+    SMB_NTSTATUS_KDC_CERT_EXPIRED                                               => "STATUS_KDC_CERT_EXPIRED",
+    SMB_NTSTATUS_KDC_CERT_REVOKED                                               => "STATUS_KDC_CERT_REVOKED",
+    SMB_NTSTATUS_PARAMETER_QUOTA_EXCEEDED                                       => "STATUS_PARAMETER_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_HIBERNATION_FAILURE                                            => "STATUS_HIBERNATION_FAILURE",
+    SMB_NTSTATUS_DELAY_LOAD_FAILED                                              => "STATUS_DELAY_LOAD_FAILED",
+    SMB_NTSTATUS_AUTHENTICATION_FIREWALL_FAILED                                 => "STATUS_AUTHENTICATION_FIREWALL_FAILED",
+    SMB_NTSTATUS_VDM_DISALLOWED                                                 => "STATUS_VDM_DISALLOWED",
+    SMB_NTSTATUS_HUNG_DISPLAY_DRIVER_THREAD                                     => "STATUS_HUNG_DISPLAY_DRIVER_THREAD",
+    SMB_NTSTATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE        => "STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE",
+    SMB_NTSTATUS_INVALID_CRUNTIME_PARAMETER                                     => "STATUS_INVALID_CRUNTIME_PARAMETER",
+    SMB_NTSTATUS_NTLM_BLOCKED                                                   => "STATUS_NTLM_BLOCKED",
+    SMB_NTSTATUS_DS_SRC_SID_EXISTS_IN_FOREST                                    => "STATUS_DS_SRC_SID_EXISTS_IN_FOREST",
+    SMB_NTSTATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST                                => "STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST",
+    SMB_NTSTATUS_DS_FLAT_NAME_EXISTS_IN_FOREST                                  => "STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST",
+    SMB_NTSTATUS_INVALID_USER_PRINCIPAL_NAME                                    => "STATUS_INVALID_USER_PRINCIPAL_NAME",
+    SMB_NTSTATUS_ASSERTION_FAILURE                                              => "STATUS_ASSERTION_FAILURE",
+    SMB_NTSTATUS_VERIFIER_STOP                                                  => "STATUS_VERIFIER_STOP",
+    SMB_NTSTATUS_CALLBACK_POP_STACK                                             => "STATUS_CALLBACK_POP_STACK",
+    SMB_NTSTATUS_INCOMPATIBLE_DRIVER_BLOCKED                                    => "STATUS_INCOMPATIBLE_DRIVER_BLOCKED",
+    SMB_NTSTATUS_HIVE_UNLOADED                                                  => "STATUS_HIVE_UNLOADED",
+    SMB_NTSTATUS_COMPRESSION_DISABLED                                           => "STATUS_COMPRESSION_DISABLED",
+    SMB_NTSTATUS_FILE_SYSTEM_LIMITATION                                         => "STATUS_FILE_SYSTEM_LIMITATION",
+    SMB_NTSTATUS_INVALID_IMAGE_HASH                                             => "STATUS_INVALID_IMAGE_HASH",
+    SMB_NTSTATUS_NOT_CAPABLE                                                    => "STATUS_NOT_CAPABLE",
+    SMB_NTSTATUS_REQUEST_OUT_OF_SEQUENCE                                        => "STATUS_REQUEST_OUT_OF_SEQUENCE",
+    SMB_NTSTATUS_IMPLEMENTATION_LIMIT                                           => "STATUS_IMPLEMENTATION_LIMIT",
+    SMB_NTSTATUS_ELEVATION_REQUIRED                                             => "STATUS_ELEVATION_REQUIRED",
+    SMB_NTSTATUS_NO_SECURITY_CONTEXT                                            => "STATUS_NO_SECURITY_CONTEXT",
+    SMB_NTSTATUS_PKU2U_CERT_FAILURE                                             => "STATUS_PKU2U_CERT_FAILURE",
+    SMB_NTSTATUS_BEYOND_VDL                                                     => "STATUS_BEYOND_VDL",
+    SMB_NTSTATUS_ENCOUNTERED_WRITE_IN_PROGRESS                                  => "STATUS_ENCOUNTERED_WRITE_IN_PROGRESS",
+    SMB_NTSTATUS_PTE_CHANGED                                                    => "STATUS_PTE_CHANGED",
+    SMB_NTSTATUS_PURGE_FAILED                                                   => "STATUS_PURGE_FAILED",
+    SMB_NTSTATUS_CRED_REQUIRES_CONFIRMATION                                     => "STATUS_CRED_REQUIRES_CONFIRMATION",
+    SMB_NTSTATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE                          => "STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE",
+    SMB_NTSTATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER                               => "STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER",
+    SMB_NTSTATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE                          => "STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE",
+    SMB_NTSTATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE                               => "STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE",
+    SMB_NTSTATUS_CS_ENCRYPTION_FILE_NOT_CSE                                     => "STATUS_CS_ENCRYPTION_FILE_NOT_CSE",
+    SMB_NTSTATUS_INVALID_LABEL                                                  => "STATUS_INVALID_LABEL",
+    SMB_NTSTATUS_DRIVER_PROCESS_TERMINATED                                      => "STATUS_DRIVER_PROCESS_TERMINATED",
+    SMB_NTSTATUS_AMBIGUOUS_SYSTEM_DEVICE                                        => "STATUS_AMBIGUOUS_SYSTEM_DEVICE",
+    SMB_NTSTATUS_SYSTEM_DEVICE_NOT_FOUND                                        => "STATUS_SYSTEM_DEVICE_NOT_FOUND",
+    SMB_NTSTATUS_RESTART_BOOT_APPLICATION                                       => "STATUS_RESTART_BOOT_APPLICATION",
+    SMB_NTSTATUS_INSUFFICIENT_NVRAM_RESOURCES                                   => "STATUS_INSUFFICIENT_NVRAM_RESOURCES",
+    SMB_NTSTATUS_NO_RANGES_PROCESSED                                            => "STATUS_NO_RANGES_PROCESSED",
+    SMB_NTSTATUS_DEVICE_FEATURE_NOT_SUPPORTED                                   => "STATUS_DEVICE_FEATURE_NOT_SUPPORTED",
+    SMB_NTSTATUS_DEVICE_UNREACHABLE                                             => "STATUS_DEVICE_UNREACHABLE",
+    SMB_NTSTATUS_INVALID_TOKEN                                                  => "STATUS_INVALID_TOKEN",
+    SMB_NTSTATUS_SERVER_UNAVAILABLE                                             => "STATUS_SERVER_UNAVAILABLE",
+    SMB_NTSTATUS_INVALID_TASK_NAME                                              => "STATUS_INVALID_TASK_NAME",
+    SMB_NTSTATUS_INVALID_TASK_INDEX                                             => "STATUS_INVALID_TASK_INDEX",
+    SMB_NTSTATUS_THREAD_ALREADY_IN_TASK                                         => "STATUS_THREAD_ALREADY_IN_TASK",
+    SMB_NTSTATUS_CALLBACK_BYPASS                                                => "STATUS_CALLBACK_BYPASS",
+    SMB_NTSTATUS_FAIL_FAST_EXCEPTION                                            => "STATUS_FAIL_FAST_EXCEPTION",
+    SMB_NTSTATUS_IMAGE_CERT_REVOKED                                             => "STATUS_IMAGE_CERT_REVOKED",
+    SMB_NTSTATUS_PORT_CLOSED                                                    => "STATUS_PORT_CLOSED",
+    SMB_NTSTATUS_MESSAGE_LOST                                                   => "STATUS_MESSAGE_LOST",
+    SMB_NTSTATUS_INVALID_MESSAGE                                                => "STATUS_INVALID_MESSAGE",
+    SMB_NTSTATUS_REQUEST_CANCELED                                               => "STATUS_REQUEST_CANCELED",
+    SMB_NTSTATUS_RECURSIVE_DISPATCH                                             => "STATUS_RECURSIVE_DISPATCH",
+    SMB_NTSTATUS_LPC_RECEIVE_BUFFER_EXPECTED                                    => "STATUS_LPC_RECEIVE_BUFFER_EXPECTED",
+    SMB_NTSTATUS_LPC_INVALID_CONNECTION_USAGE                                   => "STATUS_LPC_INVALID_CONNECTION_USAGE",
+    SMB_NTSTATUS_LPC_REQUESTS_NOT_ALLOWED                                       => "STATUS_LPC_REQUESTS_NOT_ALLOWED",
+    SMB_NTSTATUS_RESOURCE_IN_USE                                                => "STATUS_RESOURCE_IN_USE",
+    SMB_NTSTATUS_HARDWARE_MEMORY_ERROR                                          => "STATUS_HARDWARE_MEMORY_ERROR",
+    SMB_NTSTATUS_THREADPOOL_HANDLE_EXCEPTION                                    => "STATUS_THREADPOOL_HANDLE_EXCEPTION",
+    SMB_NTSTATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED                      => "STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED",
+    SMB_NTSTATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED              => "STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED",
+    SMB_NTSTATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED                  => "STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED",
+    SMB_NTSTATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED                   => "STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED",
+    SMB_NTSTATUS_THREADPOOL_RELEASED_DURING_OPERATION                           => "STATUS_THREADPOOL_RELEASED_DURING_OPERATION",
+    SMB_NTSTATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING                          => "STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING",
+    SMB_NTSTATUS_APC_RETURNED_WHILE_IMPERSONATING                               => "STATUS_APC_RETURNED_WHILE_IMPERSONATING",
+    SMB_NTSTATUS_PROCESS_IS_PROTECTED                                           => "STATUS_PROCESS_IS_PROTECTED",
+    SMB_NTSTATUS_MCA_EXCEPTION                                                  => "STATUS_MCA_EXCEPTION",
+    SMB_NTSTATUS_CERTIFICATE_MAPPING_NOT_UNIQUE                                 => "STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE",
+    SMB_NTSTATUS_SYMLINK_CLASS_DISABLED                                         => "STATUS_SYMLINK_CLASS_DISABLED",
+    SMB_NTSTATUS_INVALID_IDN_NORMALIZATION                                      => "STATUS_INVALID_IDN_NORMALIZATION",
+    SMB_NTSTATUS_NO_UNICODE_TRANSLATION                                         => "STATUS_NO_UNICODE_TRANSLATION",
+    SMB_NTSTATUS_ALREADY_REGISTERED                                             => "STATUS_ALREADY_REGISTERED",
+    SMB_NTSTATUS_CONTEXT_MISMATCH                                               => "STATUS_CONTEXT_MISMATCH",
+    SMB_NTSTATUS_PORT_ALREADY_HAS_COMPLETION_LIST                               => "STATUS_PORT_ALREADY_HAS_COMPLETION_LIST",
+    SMB_NTSTATUS_CALLBACK_RETURNED_THREAD_PRIORITY                              => "STATUS_CALLBACK_RETURNED_THREAD_PRIORITY",
+    SMB_NTSTATUS_INVALID_THREAD                                                 => "STATUS_INVALID_THREAD",
+    SMB_NTSTATUS_CALLBACK_RETURNED_TRANSACTION                                  => "STATUS_CALLBACK_RETURNED_TRANSACTION",
+    SMB_NTSTATUS_CALLBACK_RETURNED_LDR_LOCK                                     => "STATUS_CALLBACK_RETURNED_LDR_LOCK",
+    SMB_NTSTATUS_CALLBACK_RETURNED_LANG                                         => "STATUS_CALLBACK_RETURNED_LANG",
+    SMB_NTSTATUS_CALLBACK_RETURNED_PRI_BACK                                     => "STATUS_CALLBACK_RETURNED_PRI_BACK",
+    SMB_NTSTATUS_DISK_REPAIR_DISABLED                                           => "STATUS_DISK_REPAIR_DISABLED",
+    SMB_NTSTATUS_DS_DOMAIN_RENAME_IN_PROGRESS                                   => "STATUS_DS_DOMAIN_RENAME_IN_PROGRESS",
+    SMB_NTSTATUS_DISK_QUOTA_EXCEEDED                                            => "STATUS_DISK_QUOTA_EXCEEDED",
+    SMB_NTSTATUS_CONTENT_BLOCKED                                                => "STATUS_CONTENT_BLOCKED",
+    SMB_NTSTATUS_BAD_CLUSTERS                                                   => "STATUS_BAD_CLUSTERS",
+    SMB_NTSTATUS_VOLUME_DIRTY                                                   => "STATUS_VOLUME_DIRTY",
+    SMB_NTSTATUS_FILE_CHECKED_OUT                                               => "STATUS_FILE_CHECKED_OUT",
+    SMB_NTSTATUS_CHECKOUT_REQUIRED                                              => "STATUS_CHECKOUT_REQUIRED",
+    SMB_NTSTATUS_BAD_FILE_TYPE                                                  => "STATUS_BAD_FILE_TYPE",
+    SMB_NTSTATUS_FILE_TOO_LARGE                                                 => "STATUS_FILE_TOO_LARGE",
+    SMB_NTSTATUS_FORMS_AUTH_REQUIRED                                            => "STATUS_FORMS_AUTH_REQUIRED",
+    SMB_NTSTATUS_VIRUS_INFECTED                                                 => "STATUS_VIRUS_INFECTED",
+    SMB_NTSTATUS_VIRUS_DELETED                                                  => "STATUS_VIRUS_DELETED",
+    SMB_NTSTATUS_BAD_MCFG_TABLE                                                 => "STATUS_BAD_MCFG_TABLE",
+    SMB_NTSTATUS_CANNOT_BREAK_OPLOCK                                            => "STATUS_CANNOT_BREAK_OPLOCK",
+    SMB_NTSTATUS_WOW_ASSERTION                                                  => "STATUS_WOW_ASSERTION",
+    SMB_NTSTATUS_INVALID_SIGNATURE                                              => "STATUS_INVALID_SIGNATURE",
+    SMB_NTSTATUS_HMAC_NOT_SUPPORTED                                             => "STATUS_HMAC_NOT_SUPPORTED",
+    SMB_NTSTATUS_IPSEC_QUEUE_OVERFLOW                                           => "STATUS_IPSEC_QUEUE_OVERFLOW",
+    SMB_NTSTATUS_ND_QUEUE_OVERFLOW                                              => "STATUS_ND_QUEUE_OVERFLOW",
+    SMB_NTSTATUS_HOPLIMIT_EXCEEDED                                              => "STATUS_HOPLIMIT_EXCEEDED",
+    SMB_NTSTATUS_PROTOCOL_NOT_SUPPORTED                                         => "STATUS_PROTOCOL_NOT_SUPPORTED",
+    SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED                     => "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED",
+    SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR                     => "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR",
+    SMB_NTSTATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR                         => "STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR",
+    SMB_NTSTATUS_XML_PARSE_ERROR                                                => "STATUS_XML_PARSE_ERROR",
+    SMB_NTSTATUS_XMLDSIG_ERROR                                                  => "STATUS_XMLDSIG_ERROR",
+    SMB_NTSTATUS_WRONG_COMPARTMENT                                              => "STATUS_WRONG_COMPARTMENT",
+    SMB_NTSTATUS_AUTHIP_FAILURE                                                 => "STATUS_AUTHIP_FAILURE",
+    SMB_NTSTATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS                          => "STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS",
+    SMB_NTSTATUS_DS_OID_NOT_FOUND                                               => "STATUS_DS_OID_NOT_FOUND",
+    SMB_NTSTATUS_HASH_NOT_SUPPORTED                                             => "STATUS_HASH_NOT_SUPPORTED",
+    SMB_NTSTATUS_HASH_NOT_PRESENT                                               => "STATUS_HASH_NOT_PRESENT",
+    SMB_NTSTATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED                                 => "STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED",
+    SMB_NTSTATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED                                => "STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED",
+    SMB_NTSTATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED                                => "STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED",
+    SMB_NTSTATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED                               => "STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED",
+    SMB_NTDBG_NO_STATE_CHANGE                                                   => "DBG_NO_STATE_CHANGE",
+    SMB_NTDBG_APP_NOT_IDLE                                                      => "DBG_APP_NOT_IDLE",
+    SMB_NTRPC_NT_INVALID_STRING_BINDING                                         => "RPC_NT_INVALID_STRING_BINDING",
+    SMB_NTRPC_NT_WRONG_KIND_OF_BINDING                                          => "RPC_NT_WRONG_KIND_OF_BINDING",
+    SMB_NTRPC_NT_INVALID_BINDING                                                => "RPC_NT_INVALID_BINDING",
+    SMB_NTRPC_NT_PROTSEQ_NOT_SUPPORTED                                          => "RPC_NT_PROTSEQ_NOT_SUPPORTED",
+    SMB_NTRPC_NT_INVALID_RPC_PROTSEQ                                            => "RPC_NT_INVALID_RPC_PROTSEQ",
+    SMB_NTRPC_NT_INVALID_STRING_UUID                                            => "RPC_NT_INVALID_STRING_UUID",
+    SMB_NTRPC_NT_INVALID_ENDPOINT_FORMAT                                        => "RPC_NT_INVALID_ENDPOINT_FORMAT",
+    SMB_NTRPC_NT_INVALID_NET_ADDR                                               => "RPC_NT_INVALID_NET_ADDR",
+    SMB_NTRPC_NT_NO_ENDPOINT_FOUND                                              => "RPC_NT_NO_ENDPOINT_FOUND",
+    SMB_NTRPC_NT_INVALID_TIMEOUT                                                => "RPC_NT_INVALID_TIMEOUT",
+    SMB_NTRPC_NT_OBJECT_NOT_FOUND                                               => "RPC_NT_OBJECT_NOT_FOUND",
+    SMB_NTRPC_NT_ALREADY_REGISTERED                                             => "RPC_NT_ALREADY_REGISTERED",
+    SMB_NTRPC_NT_TYPE_ALREADY_REGISTERED                                        => "RPC_NT_TYPE_ALREADY_REGISTERED",
+    SMB_NTRPC_NT_ALREADY_LISTENING                                              => "RPC_NT_ALREADY_LISTENING",
+    SMB_NTRPC_NT_NO_PROTSEQS_REGISTERED                                         => "RPC_NT_NO_PROTSEQS_REGISTERED",
+    SMB_NTRPC_NT_NOT_LISTENING                                                  => "RPC_NT_NOT_LISTENING",
+    SMB_NTRPC_NT_UNKNOWN_MGR_TYPE                                               => "RPC_NT_UNKNOWN_MGR_TYPE",
+    SMB_NTRPC_NT_UNKNOWN_IF                                                     => "RPC_NT_UNKNOWN_IF",
+    SMB_NTRPC_NT_NO_BINDINGS                                                    => "RPC_NT_NO_BINDINGS",
+    SMB_NTRPC_NT_NO_PROTSEQS                                                    => "RPC_NT_NO_PROTSEQS",
+    SMB_NTRPC_NT_CANT_CREATE_ENDPOINT                                           => "RPC_NT_CANT_CREATE_ENDPOINT",
+    SMB_NTRPC_NT_OUT_OF_RESOURCES                                               => "RPC_NT_OUT_OF_RESOURCES",
+    SMB_NTRPC_NT_SERVER_UNAVAILABLE                                             => "RPC_NT_SERVER_UNAVAILABLE",
+    SMB_NTRPC_NT_SERVER_TOO_BUSY                                                => "RPC_NT_SERVER_TOO_BUSY",
+    SMB_NTRPC_NT_INVALID_NETWORK_OPTIONS                                        => "RPC_NT_INVALID_NETWORK_OPTIONS",
+    SMB_NTRPC_NT_NO_CALL_ACTIVE                                                 => "RPC_NT_NO_CALL_ACTIVE",
+    SMB_NTRPC_NT_CALL_FAILED                                                    => "RPC_NT_CALL_FAILED",
+    SMB_NTRPC_NT_CALL_FAILED_DNE                                                => "RPC_NT_CALL_FAILED_DNE",
+    SMB_NTRPC_NT_PROTOCOL_ERROR                                                 => "RPC_NT_PROTOCOL_ERROR",
+    SMB_NTRPC_NT_UNSUPPORTED_TRANS_SYN                                          => "RPC_NT_UNSUPPORTED_TRANS_SYN",
+    SMB_NTRPC_NT_UNSUPPORTED_TYPE                                               => "RPC_NT_UNSUPPORTED_TYPE",
+    SMB_NTRPC_NT_INVALID_TAG                                                    => "RPC_NT_INVALID_TAG",
+    SMB_NTRPC_NT_INVALID_BOUND                                                  => "RPC_NT_INVALID_BOUND",
+    SMB_NTRPC_NT_NO_ENTRY_NAME                                                  => "RPC_NT_NO_ENTRY_NAME",
+    SMB_NTRPC_NT_INVALID_NAME_SYNTAX                                            => "RPC_NT_INVALID_NAME_SYNTAX",
+    SMB_NTRPC_NT_UNSUPPORTED_NAME_SYNTAX                                        => "RPC_NT_UNSUPPORTED_NAME_SYNTAX",
+    SMB_NTRPC_NT_UUID_NO_ADDRESS                                                => "RPC_NT_UUID_NO_ADDRESS",
+    SMB_NTRPC_NT_DUPLICATE_ENDPOINT                                             => "RPC_NT_DUPLICATE_ENDPOINT",
+    SMB_NTRPC_NT_UNKNOWN_AUTHN_TYPE                                             => "RPC_NT_UNKNOWN_AUTHN_TYPE",
+    SMB_NTRPC_NT_MAX_CALLS_TOO_SMALL                                            => "RPC_NT_MAX_CALLS_TOO_SMALL",
+    SMB_NTRPC_NT_STRING_TOO_LONG                                                => "RPC_NT_STRING_TOO_LONG",
+    SMB_NTRPC_NT_PROTSEQ_NOT_FOUND                                              => "RPC_NT_PROTSEQ_NOT_FOUND",
+    SMB_NTRPC_NT_PROCNUM_OUT_OF_RANGE                                           => "RPC_NT_PROCNUM_OUT_OF_RANGE",
+    SMB_NTRPC_NT_BINDING_HAS_NO_AUTH                                            => "RPC_NT_BINDING_HAS_NO_AUTH",
+    SMB_NTRPC_NT_UNKNOWN_AUTHN_SERVICE                                          => "RPC_NT_UNKNOWN_AUTHN_SERVICE",
+    SMB_NTRPC_NT_UNKNOWN_AUTHN_LEVEL                                            => "RPC_NT_UNKNOWN_AUTHN_LEVEL",
+    SMB_NTRPC_NT_INVALID_AUTH_IDENTITY                                          => "RPC_NT_INVALID_AUTH_IDENTITY",
+    SMB_NTRPC_NT_UNKNOWN_AUTHZ_SERVICE                                          => "RPC_NT_UNKNOWN_AUTHZ_SERVICE",
+    SMB_NTEPT_NT_INVALID_ENTRY                                                  => "EPT_NT_INVALID_ENTRY",
+    SMB_NTEPT_NT_CANT_PERFORM_OP                                                => "EPT_NT_CANT_PERFORM_OP",
+    SMB_NTEPT_NT_NOT_REGISTERED                                                 => "EPT_NT_NOT_REGISTERED",
+    SMB_NTRPC_NT_NOTHING_TO_EXPORT                                              => "RPC_NT_NOTHING_TO_EXPORT",
+    SMB_NTRPC_NT_INCOMPLETE_NAME                                                => "RPC_NT_INCOMPLETE_NAME",
+    SMB_NTRPC_NT_INVALID_VERS_OPTION                                            => "RPC_NT_INVALID_VERS_OPTION",
+    SMB_NTRPC_NT_NO_MORE_MEMBERS                                                => "RPC_NT_NO_MORE_MEMBERS",
+    SMB_NTRPC_NT_NOT_ALL_OBJS_UNEXPORTED                                        => "RPC_NT_NOT_ALL_OBJS_UNEXPORTED",
+    SMB_NTRPC_NT_INTERFACE_NOT_FOUND                                            => "RPC_NT_INTERFACE_NOT_FOUND",
+    SMB_NTRPC_NT_ENTRY_ALREADY_EXISTS                                           => "RPC_NT_ENTRY_ALREADY_EXISTS",
+    SMB_NTRPC_NT_ENTRY_NOT_FOUND                                                => "RPC_NT_ENTRY_NOT_FOUND",
+    SMB_NTRPC_NT_NAME_SERVICE_UNAVAILABLE                                       => "RPC_NT_NAME_SERVICE_UNAVAILABLE",
+    SMB_NTRPC_NT_INVALID_NAF_ID                                                 => "RPC_NT_INVALID_NAF_ID",
+    SMB_NTRPC_NT_CANNOT_SUPPORT                                                 => "RPC_NT_CANNOT_SUPPORT",
+    SMB_NTRPC_NT_NO_CONTEXT_AVAILABLE                                           => "RPC_NT_NO_CONTEXT_AVAILABLE",
+    SMB_NTRPC_NT_INTERNAL_ERROR                                                 => "RPC_NT_INTERNAL_ERROR",
+    SMB_NTRPC_NT_ZERO_DIVIDE                                                    => "RPC_NT_ZERO_DIVIDE",
+    SMB_NTRPC_NT_ADDRESS_ERROR                                                  => "RPC_NT_ADDRESS_ERROR",
+    SMB_NTRPC_NT_FP_DIV_ZERO                                                    => "RPC_NT_FP_DIV_ZERO",
+    SMB_NTRPC_NT_FP_UNDERFLOW                                                   => "RPC_NT_FP_UNDERFLOW",
+    SMB_NTRPC_NT_FP_OVERFLOW                                                    => "RPC_NT_FP_OVERFLOW",
+    SMB_NTRPC_NT_CALL_IN_PROGRESS                                               => "RPC_NT_CALL_IN_PROGRESS",
+    SMB_NTRPC_NT_NO_MORE_BINDINGS                                               => "RPC_NT_NO_MORE_BINDINGS",
+    SMB_NTRPC_NT_GROUP_MEMBER_NOT_FOUND                                         => "RPC_NT_GROUP_MEMBER_NOT_FOUND",
+    SMB_NTEPT_NT_CANT_CREATE                                                    => "EPT_NT_CANT_CREATE",
+    SMB_NTRPC_NT_INVALID_OBJECT                                                 => "RPC_NT_INVALID_OBJECT",
+    SMB_NTRPC_NT_NO_INTERFACES                                                  => "RPC_NT_NO_INTERFACES",
+    SMB_NTRPC_NT_CALL_CANCELLED                                                 => "RPC_NT_CALL_CANCELLED",
+    SMB_NTRPC_NT_BINDING_INCOMPLETE                                             => "RPC_NT_BINDING_INCOMPLETE",
+    SMB_NTRPC_NT_COMM_FAILURE                                                   => "RPC_NT_COMM_FAILURE",
+    SMB_NTRPC_NT_UNSUPPORTED_AUTHN_LEVEL                                        => "RPC_NT_UNSUPPORTED_AUTHN_LEVEL",
+    SMB_NTRPC_NT_NO_PRINC_NAME                                                  => "RPC_NT_NO_PRINC_NAME",
+    SMB_NTRPC_NT_NOT_RPC_ERROR                                                  => "RPC_NT_NOT_RPC_ERROR",
+    SMB_NTRPC_NT_SEC_PKG_ERROR                                                  => "RPC_NT_SEC_PKG_ERROR",
+    SMB_NTRPC_NT_NOT_CANCELLED                                                  => "RPC_NT_NOT_CANCELLED",
+    SMB_NTRPC_NT_INVALID_ASYNC_HANDLE                                           => "RPC_NT_INVALID_ASYNC_HANDLE",
+    SMB_NTRPC_NT_INVALID_ASYNC_CALL                                             => "RPC_NT_INVALID_ASYNC_CALL",
+    SMB_NTRPC_NT_PROXY_ACCESS_DENIED                                            => "RPC_NT_PROXY_ACCESS_DENIED",
+    SMB_NTRPC_NT_NO_MORE_ENTRIES                                                => "RPC_NT_NO_MORE_ENTRIES",
+    SMB_NTRPC_NT_SS_CHAR_TRANS_OPEN_FAIL                                        => "RPC_NT_SS_CHAR_TRANS_OPEN_FAIL",
+    SMB_NTRPC_NT_SS_CHAR_TRANS_SHORT_FILE                                       => "RPC_NT_SS_CHAR_TRANS_SHORT_FILE",
+    SMB_NTRPC_NT_SS_IN_NULL_CONTEXT                                             => "RPC_NT_SS_IN_NULL_CONTEXT",
+    SMB_NTRPC_NT_SS_CONTEXT_MISMATCH                                            => "RPC_NT_SS_CONTEXT_MISMATCH",
+    SMB_NTRPC_NT_SS_CONTEXT_DAMAGED                                             => "RPC_NT_SS_CONTEXT_DAMAGED",
+    SMB_NTRPC_NT_SS_HANDLES_MISMATCH                                            => "RPC_NT_SS_HANDLES_MISMATCH",
+    SMB_NTRPC_NT_SS_CANNOT_GET_CALL_HANDLE                                      => "RPC_NT_SS_CANNOT_GET_CALL_HANDLE",
+    SMB_NTRPC_NT_NULL_REF_POINTER                                               => "RPC_NT_NULL_REF_POINTER",
+    SMB_NTRPC_NT_ENUM_VALUE_OUT_OF_RANGE                                        => "RPC_NT_ENUM_VALUE_OUT_OF_RANGE",
+    SMB_NTRPC_NT_BYTE_COUNT_TOO_SMALL                                           => "RPC_NT_BYTE_COUNT_TOO_SMALL",
+    SMB_NTRPC_NT_BAD_STUB_DATA                                                  => "RPC_NT_BAD_STUB_DATA",
+    SMB_NTRPC_NT_INVALID_ES_ACTION                                              => "RPC_NT_INVALID_ES_ACTION",
+    SMB_NTRPC_NT_WRONG_ES_VERSION                                               => "RPC_NT_WRONG_ES_VERSION",
+    SMB_NTRPC_NT_WRONG_STUB_VERSION                                             => "RPC_NT_WRONG_STUB_VERSION",
+    SMB_NTRPC_NT_INVALID_PIPE_OBJECT                                            => "RPC_NT_INVALID_PIPE_OBJECT",
+    SMB_NTRPC_NT_INVALID_PIPE_OPERATION                                         => "RPC_NT_INVALID_PIPE_OPERATION",
+    SMB_NTRPC_NT_WRONG_PIPE_VERSION                                             => "RPC_NT_WRONG_PIPE_VERSION",
+    SMB_NTRPC_NT_PIPE_CLOSED                                                    => "RPC_NT_PIPE_CLOSED",
+    SMB_NTRPC_NT_PIPE_DISCIPLINE_ERROR                                          => "RPC_NT_PIPE_DISCIPLINE_ERROR",
+    SMB_NTRPC_NT_PIPE_EMPTY                                                     => "RPC_NT_PIPE_EMPTY",
+    SMB_NTSTATUS_PNP_BAD_MPS_TABLE                                              => "STATUS_PNP_BAD_MPS_TABLE",
+    SMB_NTSTATUS_PNP_TRANSLATION_FAILED                                         => "STATUS_PNP_TRANSLATION_FAILED",
+    SMB_NTSTATUS_PNP_IRQ_TRANSLATION_FAILED                                     => "STATUS_PNP_IRQ_TRANSLATION_FAILED",
+    SMB_NTSTATUS_PNP_INVALID_ID                                                 => "STATUS_PNP_INVALID_ID",
+    SMB_NTSTATUS_IO_REISSUE_AS_CACHED                                           => "STATUS_IO_REISSUE_AS_CACHED",
+    SMB_NTSTATUS_CTX_WINSTATION_NAME_INVALID                                    => "STATUS_CTX_WINSTATION_NAME_INVALID",
+    SMB_NTSTATUS_CTX_INVALID_PD                                                 => "STATUS_CTX_INVALID_PD",
+    SMB_NTSTATUS_CTX_PD_NOT_FOUND                                               => "STATUS_CTX_PD_NOT_FOUND",
+    SMB_NTSTATUS_CTX_CLOSE_PENDING                                              => "STATUS_CTX_CLOSE_PENDING",
+    SMB_NTSTATUS_CTX_NO_OUTBUF                                                  => "STATUS_CTX_NO_OUTBUF",
+    SMB_NTSTATUS_CTX_MODEM_INF_NOT_FOUND                                        => "STATUS_CTX_MODEM_INF_NOT_FOUND",
+    SMB_NTSTATUS_CTX_INVALID_MODEMNAME                                          => "STATUS_CTX_INVALID_MODEMNAME",
+    SMB_NTSTATUS_CTX_RESPONSE_ERROR                                             => "STATUS_CTX_RESPONSE_ERROR",
+    SMB_NTSTATUS_CTX_MODEM_RESPONSE_TIMEOUT                                     => "STATUS_CTX_MODEM_RESPONSE_TIMEOUT",
+    SMB_NTSTATUS_CTX_MODEM_RESPONSE_NO_CARRIER                                  => "STATUS_CTX_MODEM_RESPONSE_NO_CARRIER",
+    SMB_NTSTATUS_CTX_MODEM_RESPONSE_NO_DIALTONE                                 => "STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE",
+    SMB_NTSTATUS_CTX_MODEM_RESPONSE_BUSY                                        => "STATUS_CTX_MODEM_RESPONSE_BUSY",
+    SMB_NTSTATUS_CTX_MODEM_RESPONSE_VOICE                                       => "STATUS_CTX_MODEM_RESPONSE_VOICE",
+    SMB_NTSTATUS_CTX_TD_ERROR                                                   => "STATUS_CTX_TD_ERROR",
+    SMB_NTSTATUS_CTX_LICENSE_CLIENT_INVALID                                     => "STATUS_CTX_LICENSE_CLIENT_INVALID",
+    SMB_NTSTATUS_CTX_LICENSE_NOT_AVAILABLE                                      => "STATUS_CTX_LICENSE_NOT_AVAILABLE",
+    SMB_NTSTATUS_CTX_LICENSE_EXPIRED                                            => "STATUS_CTX_LICENSE_EXPIRED",
+    SMB_NTSTATUS_CTX_WINSTATION_NOT_FOUND                                       => "STATUS_CTX_WINSTATION_NOT_FOUND",
+    SMB_NTSTATUS_CTX_WINSTATION_NAME_COLLISION                                  => "STATUS_CTX_WINSTATION_NAME_COLLISION",
+    SMB_NTSTATUS_CTX_WINSTATION_BUSY                                            => "STATUS_CTX_WINSTATION_BUSY",
+    SMB_NTSTATUS_CTX_BAD_VIDEO_MODE                                             => "STATUS_CTX_BAD_VIDEO_MODE",
+    SMB_NTSTATUS_CTX_GRAPHICS_INVALID                                           => "STATUS_CTX_GRAPHICS_INVALID",
+    SMB_NTSTATUS_CTX_NOT_CONSOLE                                                => "STATUS_CTX_NOT_CONSOLE",
+    SMB_NTSTATUS_CTX_CLIENT_QUERY_TIMEOUT                                       => "STATUS_CTX_CLIENT_QUERY_TIMEOUT",
+    SMB_NTSTATUS_CTX_CONSOLE_DISCONNECT                                         => "STATUS_CTX_CONSOLE_DISCONNECT",
+    SMB_NTSTATUS_CTX_CONSOLE_CONNECT                                            => "STATUS_CTX_CONSOLE_CONNECT",
+    SMB_NTSTATUS_CTX_SHADOW_DENIED                                              => "STATUS_CTX_SHADOW_DENIED",
+    SMB_NTSTATUS_CTX_WINSTATION_ACCESS_DENIED                                   => "STATUS_CTX_WINSTATION_ACCESS_DENIED",
+    SMB_NTSTATUS_CTX_INVALID_WD                                                 => "STATUS_CTX_INVALID_WD",
+    SMB_NTSTATUS_CTX_WD_NOT_FOUND                                               => "STATUS_CTX_WD_NOT_FOUND",
+    SMB_NTSTATUS_CTX_SHADOW_INVALID                                             => "STATUS_CTX_SHADOW_INVALID",
+    SMB_NTSTATUS_CTX_SHADOW_DISABLED                                            => "STATUS_CTX_SHADOW_DISABLED",
+    SMB_NTSTATUS_RDP_PROTOCOL_ERROR                                             => "STATUS_RDP_PROTOCOL_ERROR",
+    SMB_NTSTATUS_CTX_CLIENT_LICENSE_NOT_SET                                     => "STATUS_CTX_CLIENT_LICENSE_NOT_SET",
+    SMB_NTSTATUS_CTX_CLIENT_LICENSE_IN_USE                                      => "STATUS_CTX_CLIENT_LICENSE_IN_USE",
+    SMB_NTSTATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE                                => "STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE",
+    SMB_NTSTATUS_CTX_SHADOW_NOT_RUNNING                                         => "STATUS_CTX_SHADOW_NOT_RUNNING",
+    SMB_NTSTATUS_CTX_LOGON_DISABLED                                             => "STATUS_CTX_LOGON_DISABLED",
+    SMB_NTSTATUS_CTX_SECURITY_LAYER_ERROR                                       => "STATUS_CTX_SECURITY_LAYER_ERROR",
+    SMB_NTSTATUS_TS_INCOMPATIBLE_SESSIONS                                       => "STATUS_TS_INCOMPATIBLE_SESSIONS",
+    SMB_NTSTATUS_MUI_FILE_NOT_FOUND                                             => "STATUS_MUI_FILE_NOT_FOUND",
+    SMB_NTSTATUS_MUI_INVALID_FILE                                               => "STATUS_MUI_INVALID_FILE",
+    SMB_NTSTATUS_MUI_INVALID_RC_CONFIG                                          => "STATUS_MUI_INVALID_RC_CONFIG",
+    SMB_NTSTATUS_MUI_INVALID_LOCALE_NAME                                        => "STATUS_MUI_INVALID_LOCALE_NAME",
+    SMB_NTSTATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME                              => "STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME",
+    SMB_NTSTATUS_MUI_FILE_NOT_LOADED                                            => "STATUS_MUI_FILE_NOT_LOADED",
+    SMB_NTSTATUS_RESOURCE_ENUM_USER_STOP                                        => "STATUS_RESOURCE_ENUM_USER_STOP",
+    SMB_NTSTATUS_CLUSTER_INVALID_NODE                                           => "STATUS_CLUSTER_INVALID_NODE",
+    SMB_NTSTATUS_CLUSTER_NODE_EXISTS                                            => "STATUS_CLUSTER_NODE_EXISTS",
+    SMB_NTSTATUS_CLUSTER_JOIN_IN_PROGRESS                                       => "STATUS_CLUSTER_JOIN_IN_PROGRESS",
+    SMB_NTSTATUS_CLUSTER_NODE_NOT_FOUND                                         => "STATUS_CLUSTER_NODE_NOT_FOUND",
+    SMB_NTSTATUS_CLUSTER_LOCAL_NODE_NOT_FOUND                                   => "STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND",
+    SMB_NTSTATUS_CLUSTER_NETWORK_EXISTS                                         => "STATUS_CLUSTER_NETWORK_EXISTS",
+    SMB_NTSTATUS_CLUSTER_NETWORK_NOT_FOUND                                      => "STATUS_CLUSTER_NETWORK_NOT_FOUND",
+    SMB_NTSTATUS_CLUSTER_NETINTERFACE_EXISTS                                    => "STATUS_CLUSTER_NETINTERFACE_EXISTS",
+    SMB_NTSTATUS_CLUSTER_NETINTERFACE_NOT_FOUND                                 => "STATUS_CLUSTER_NETINTERFACE_NOT_FOUND",
+    SMB_NTSTATUS_CLUSTER_INVALID_REQUEST                                        => "STATUS_CLUSTER_INVALID_REQUEST",
+    SMB_NTSTATUS_CLUSTER_INVALID_NETWORK_PROVIDER                               => "STATUS_CLUSTER_INVALID_NETWORK_PROVIDER",
+    SMB_NTSTATUS_CLUSTER_NODE_DOWN                                              => "STATUS_CLUSTER_NODE_DOWN",
+    SMB_NTSTATUS_CLUSTER_NODE_UNREACHABLE                                       => "STATUS_CLUSTER_NODE_UNREACHABLE",
+    SMB_NTSTATUS_CLUSTER_NODE_NOT_MEMBER                                        => "STATUS_CLUSTER_NODE_NOT_MEMBER",
+    SMB_NTSTATUS_CLUSTER_JOIN_NOT_IN_PROGRESS                                   => "STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS",
+    SMB_NTSTATUS_CLUSTER_INVALID_NETWORK                                        => "STATUS_CLUSTER_INVALID_NETWORK",
+    SMB_NTSTATUS_CLUSTER_NO_NET_ADAPTERS                                        => "STATUS_CLUSTER_NO_NET_ADAPTERS",
+    SMB_NTSTATUS_CLUSTER_NODE_UP                                                => "STATUS_CLUSTER_NODE_UP",
+    SMB_NTSTATUS_CLUSTER_NODE_PAUSED                                            => "STATUS_CLUSTER_NODE_PAUSED",
+    SMB_NTSTATUS_CLUSTER_NODE_NOT_PAUSED                                        => "STATUS_CLUSTER_NODE_NOT_PAUSED",
+    SMB_NTSTATUS_CLUSTER_NO_SECURITY_CONTEXT                                    => "STATUS_CLUSTER_NO_SECURITY_CONTEXT",
+    SMB_NTSTATUS_CLUSTER_NETWORK_NOT_INTERNAL                                   => "STATUS_CLUSTER_NETWORK_NOT_INTERNAL",
+    SMB_NTSTATUS_CLUSTER_POISONED                                               => "STATUS_CLUSTER_POISONED",
+    SMB_NTSTATUS_ACPI_INVALID_OPCODE                                            => "STATUS_ACPI_INVALID_OPCODE",
+    SMB_NTSTATUS_ACPI_STACK_OVERFLOW                                            => "STATUS_ACPI_STACK_OVERFLOW",
+    SMB_NTSTATUS_ACPI_ASSERT_FAILED                                             => "STATUS_ACPI_ASSERT_FAILED",
+    SMB_NTSTATUS_ACPI_INVALID_INDEX                                             => "STATUS_ACPI_INVALID_INDEX",
+    SMB_NTSTATUS_ACPI_INVALID_ARGUMENT                                          => "STATUS_ACPI_INVALID_ARGUMENT",
+    SMB_NTSTATUS_ACPI_FATAL                                                     => "STATUS_ACPI_FATAL",
+    SMB_NTSTATUS_ACPI_INVALID_SUPERNAME                                         => "STATUS_ACPI_INVALID_SUPERNAME",
+    SMB_NTSTATUS_ACPI_INVALID_ARGTYPE                                           => "STATUS_ACPI_INVALID_ARGTYPE",
+    SMB_NTSTATUS_ACPI_INVALID_OBJTYPE                                           => "STATUS_ACPI_INVALID_OBJTYPE",
+    SMB_NTSTATUS_ACPI_INVALID_TARGETTYPE                                        => "STATUS_ACPI_INVALID_TARGETTYPE",
+    SMB_NTSTATUS_ACPI_INCORRECT_ARGUMENT_COUNT                                  => "STATUS_ACPI_INCORRECT_ARGUMENT_COUNT",
+    SMB_NTSTATUS_ACPI_ADDRESS_NOT_MAPPED                                        => "STATUS_ACPI_ADDRESS_NOT_MAPPED",
+    SMB_NTSTATUS_ACPI_INVALID_EVENTTYPE                                         => "STATUS_ACPI_INVALID_EVENTTYPE",
+    SMB_NTSTATUS_ACPI_HANDLER_COLLISION                                         => "STATUS_ACPI_HANDLER_COLLISION",
+    SMB_NTSTATUS_ACPI_INVALID_DATA                                              => "STATUS_ACPI_INVALID_DATA",
+    SMB_NTSTATUS_ACPI_INVALID_REGION                                            => "STATUS_ACPI_INVALID_REGION",
+    SMB_NTSTATUS_ACPI_INVALID_ACCESS_SIZE                                       => "STATUS_ACPI_INVALID_ACCESS_SIZE",
+    SMB_NTSTATUS_ACPI_ACQUIRE_GLOBAL_LOCK                                       => "STATUS_ACPI_ACQUIRE_GLOBAL_LOCK",
+    SMB_NTSTATUS_ACPI_ALREADY_INITIALIZED                                       => "STATUS_ACPI_ALREADY_INITIALIZED",
+    SMB_NTSTATUS_ACPI_NOT_INITIALIZED                                           => "STATUS_ACPI_NOT_INITIALIZED",
+    SMB_NTSTATUS_ACPI_INVALID_MUTEX_LEVEL                                       => "STATUS_ACPI_INVALID_MUTEX_LEVEL",
+    SMB_NTSTATUS_ACPI_MUTEX_NOT_OWNED                                           => "STATUS_ACPI_MUTEX_NOT_OWNED",
+    SMB_NTSTATUS_ACPI_MUTEX_NOT_OWNER                                           => "STATUS_ACPI_MUTEX_NOT_OWNER",
+    SMB_NTSTATUS_ACPI_RS_ACCESS                                                 => "STATUS_ACPI_RS_ACCESS",
+    SMB_NTSTATUS_ACPI_INVALID_TABLE                                             => "STATUS_ACPI_INVALID_TABLE",
+    SMB_NTSTATUS_ACPI_REG_HANDLER_FAILED                                        => "STATUS_ACPI_REG_HANDLER_FAILED",
+    SMB_NTSTATUS_ACPI_POWER_REQUEST_FAILED                                      => "STATUS_ACPI_POWER_REQUEST_FAILED",
+    SMB_NTSTATUS_SXS_SECTION_NOT_FOUND                                          => "STATUS_SXS_SECTION_NOT_FOUND",
+    SMB_NTSTATUS_SXS_CANT_GEN_ACTCTX                                            => "STATUS_SXS_CANT_GEN_ACTCTX",
+    SMB_NTSTATUS_SXS_INVALID_ACTCTXDATA_FORMAT                                  => "STATUS_SXS_INVALID_ACTCTXDATA_FORMAT",
+    SMB_NTSTATUS_SXS_ASSEMBLY_NOT_FOUND                                         => "STATUS_SXS_ASSEMBLY_NOT_FOUND",
+    SMB_NTSTATUS_SXS_MANIFEST_FORMAT_ERROR                                      => "STATUS_SXS_MANIFEST_FORMAT_ERROR",
+    SMB_NTSTATUS_SXS_MANIFEST_PARSE_ERROR                                       => "STATUS_SXS_MANIFEST_PARSE_ERROR",
+    SMB_NTSTATUS_SXS_ACTIVATION_CONTEXT_DISABLED                                => "STATUS_SXS_ACTIVATION_CONTEXT_DISABLED",
+    SMB_NTSTATUS_SXS_KEY_NOT_FOUND                                              => "STATUS_SXS_KEY_NOT_FOUND",
+    SMB_NTSTATUS_SXS_VERSION_CONFLICT                                           => "STATUS_SXS_VERSION_CONFLICT",
+    SMB_NTSTATUS_SXS_WRONG_SECTION_TYPE                                         => "STATUS_SXS_WRONG_SECTION_TYPE",
+    SMB_NTSTATUS_SXS_THREAD_QUERIES_DISABLED                                    => "STATUS_SXS_THREAD_QUERIES_DISABLED",
+    SMB_NTSTATUS_SXS_ASSEMBLY_MISSING                                           => "STATUS_SXS_ASSEMBLY_MISSING",
+    SMB_NTSTATUS_SXS_PROCESS_DEFAULT_ALREADY_SET                                => "STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET",
+    SMB_NTSTATUS_SXS_EARLY_DEACTIVATION                                         => "STATUS_SXS_EARLY_DEACTIVATION",
+    SMB_NTSTATUS_SXS_INVALID_DEACTIVATION                                       => "STATUS_SXS_INVALID_DEACTIVATION",
+    SMB_NTSTATUS_SXS_MULTIPLE_DEACTIVATION                                      => "STATUS_SXS_MULTIPLE_DEACTIVATION",
+    SMB_NTSTATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY                    => "STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY",
+    SMB_NTSTATUS_SXS_PROCESS_TERMINATION_REQUESTED                              => "STATUS_SXS_PROCESS_TERMINATION_REQUESTED",
+    SMB_NTSTATUS_SXS_CORRUPT_ACTIVATION_STACK                                   => "STATUS_SXS_CORRUPT_ACTIVATION_STACK",
+    SMB_NTSTATUS_SXS_CORRUPTION                                                 => "STATUS_SXS_CORRUPTION",
+    SMB_NTSTATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE                           => "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE",
+    SMB_NTSTATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME                            => "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME",
+    SMB_NTSTATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE                               => "STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE",
+    SMB_NTSTATUS_SXS_IDENTITY_PARSE_ERROR                                       => "STATUS_SXS_IDENTITY_PARSE_ERROR",
+    SMB_NTSTATUS_SXS_COMPONENT_STORE_CORRUPT                                    => "STATUS_SXS_COMPONENT_STORE_CORRUPT",
+    SMB_NTSTATUS_SXS_FILE_HASH_MISMATCH                                         => "STATUS_SXS_FILE_HASH_MISMATCH",
+    SMB_NTSTATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT              => "STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT",
+    SMB_NTSTATUS_SXS_IDENTITIES_DIFFERENT                                       => "STATUS_SXS_IDENTITIES_DIFFERENT",
+    SMB_NTSTATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT                               => "STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT",
+    SMB_NTSTATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY                                  => "STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY",
+    SMB_NTSTATUS_ADVANCED_INSTALLER_FAILED                                      => "STATUS_ADVANCED_INSTALLER_FAILED",
+    SMB_NTSTATUS_XML_ENCODING_MISMATCH                                          => "STATUS_XML_ENCODING_MISMATCH",
+    SMB_NTSTATUS_SXS_MANIFEST_TOO_BIG                                           => "STATUS_SXS_MANIFEST_TOO_BIG",
+    SMB_NTSTATUS_SXS_SETTING_NOT_REGISTERED                                     => "STATUS_SXS_SETTING_NOT_REGISTERED",
+    SMB_NTSTATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE                             => "STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE",
+    SMB_NTSTATUS_SMI_PRIMITIVE_INSTALLER_FAILED                                 => "STATUS_SMI_PRIMITIVE_INSTALLER_FAILED",
+    SMB_NTSTATUS_GENERIC_COMMAND_FAILED                                         => "STATUS_GENERIC_COMMAND_FAILED",
+    SMB_NTSTATUS_SXS_FILE_HASH_MISSING                                          => "STATUS_SXS_FILE_HASH_MISSING",
+    SMB_NTSTATUS_TRANSACTIONAL_CONFLICT                                         => "STATUS_TRANSACTIONAL_CONFLICT",
+    SMB_NTSTATUS_INVALID_TRANSACTION                                            => "STATUS_INVALID_TRANSACTION",
+    SMB_NTSTATUS_TRANSACTION_NOT_ACTIVE                                         => "STATUS_TRANSACTION_NOT_ACTIVE",
+    SMB_NTSTATUS_TM_INITIALIZATION_FAILED                                       => "STATUS_TM_INITIALIZATION_FAILED",
+    SMB_NTSTATUS_RM_NOT_ACTIVE                                                  => "STATUS_RM_NOT_ACTIVE",
+    SMB_NTSTATUS_RM_METADATA_CORRUPT                                            => "STATUS_RM_METADATA_CORRUPT",
+    SMB_NTSTATUS_TRANSACTION_NOT_JOINED                                         => "STATUS_TRANSACTION_NOT_JOINED",
+    SMB_NTSTATUS_DIRECTORY_NOT_RM                                               => "STATUS_DIRECTORY_NOT_RM",
+    SMB_NTSTATUS_TRANSACTIONS_UNSUPPORTED_REMOTE                                => "STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE",
+    SMB_NTSTATUS_LOG_RESIZE_INVALID_SIZE                                        => "STATUS_LOG_RESIZE_INVALID_SIZE",
+    SMB_NTSTATUS_REMOTE_FILE_VERSION_MISMATCH                                   => "STATUS_REMOTE_FILE_VERSION_MISMATCH",
+    SMB_NTSTATUS_CRM_PROTOCOL_ALREADY_EXISTS                                    => "STATUS_CRM_PROTOCOL_ALREADY_EXISTS",
+    SMB_NTSTATUS_TRANSACTION_PROPAGATION_FAILED                                 => "STATUS_TRANSACTION_PROPAGATION_FAILED",
+    SMB_NTSTATUS_CRM_PROTOCOL_NOT_FOUND                                         => "STATUS_CRM_PROTOCOL_NOT_FOUND",
+    SMB_NTSTATUS_TRANSACTION_SUPERIOR_EXISTS                                    => "STATUS_TRANSACTION_SUPERIOR_EXISTS",
+    SMB_NTSTATUS_TRANSACTION_REQUEST_NOT_VALID                                  => "STATUS_TRANSACTION_REQUEST_NOT_VALID",
+    SMB_NTSTATUS_TRANSACTION_NOT_REQUESTED                                      => "STATUS_TRANSACTION_NOT_REQUESTED",
+    SMB_NTSTATUS_TRANSACTION_ALREADY_ABORTED                                    => "STATUS_TRANSACTION_ALREADY_ABORTED",
+    SMB_NTSTATUS_TRANSACTION_ALREADY_COMMITTED                                  => "STATUS_TRANSACTION_ALREADY_COMMITTED",
+    SMB_NTSTATUS_TRANSACTION_INVALID_MARSHALL_BUFFER                            => "STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER",
+    SMB_NTSTATUS_CURRENT_TRANSACTION_NOT_VALID                                  => "STATUS_CURRENT_TRANSACTION_NOT_VALID",
+    SMB_NTSTATUS_LOG_GROWTH_FAILED                                              => "STATUS_LOG_GROWTH_FAILED",
+    SMB_NTSTATUS_OBJECT_NO_LONGER_EXISTS                                        => "STATUS_OBJECT_NO_LONGER_EXISTS",
+    SMB_NTSTATUS_STREAM_MINIVERSION_NOT_FOUND                                   => "STATUS_STREAM_MINIVERSION_NOT_FOUND",
+    SMB_NTSTATUS_STREAM_MINIVERSION_NOT_VALID                                   => "STATUS_STREAM_MINIVERSION_NOT_VALID",
+    SMB_NTSTATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION            => "STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION",
+    SMB_NTSTATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT                       => "STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT",
+    SMB_NTSTATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS                           => "STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS",
+    SMB_NTSTATUS_HANDLE_NO_LONGER_VALID                                         => "STATUS_HANDLE_NO_LONGER_VALID",
+    SMB_NTSTATUS_LOG_CORRUPTION_DETECTED                                        => "STATUS_LOG_CORRUPTION_DETECTED",
+    SMB_NTSTATUS_RM_DISCONNECTED                                                => "STATUS_RM_DISCONNECTED",
+    SMB_NTSTATUS_ENLISTMENT_NOT_SUPERIOR                                        => "STATUS_ENLISTMENT_NOT_SUPERIOR",
+    SMB_NTSTATUS_FILE_IDENTITY_NOT_PERSISTENT                                   => "STATUS_FILE_IDENTITY_NOT_PERSISTENT",
+    SMB_NTSTATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY                            => "STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY",
+    SMB_NTSTATUS_CANT_CROSS_RM_BOUNDARY                                         => "STATUS_CANT_CROSS_RM_BOUNDARY",
+    SMB_NTSTATUS_TXF_DIR_NOT_EMPTY                                              => "STATUS_TXF_DIR_NOT_EMPTY",
+    SMB_NTSTATUS_INDOUBT_TRANSACTIONS_EXIST                                     => "STATUS_INDOUBT_TRANSACTIONS_EXIST",
+    SMB_NTSTATUS_TM_VOLATILE                                                    => "STATUS_TM_VOLATILE",
+    SMB_NTSTATUS_ROLLBACK_TIMER_EXPIRED                                         => "STATUS_ROLLBACK_TIMER_EXPIRED",
+    SMB_NTSTATUS_TXF_ATTRIBUTE_CORRUPT                                          => "STATUS_TXF_ATTRIBUTE_CORRUPT",
+    SMB_NTSTATUS_EFS_NOT_ALLOWED_IN_TRANSACTION                                 => "STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION",
+    SMB_NTSTATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED                                 => "STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED",
+    SMB_NTSTATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE                          => "STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE",
+    SMB_NTSTATUS_TRANSACTION_REQUIRED_PROMOTION                                 => "STATUS_TRANSACTION_REQUIRED_PROMOTION",
+    SMB_NTSTATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION                             => "STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION",
+    SMB_NTSTATUS_TRANSACTIONS_NOT_FROZEN                                        => "STATUS_TRANSACTIONS_NOT_FROZEN",
+    SMB_NTSTATUS_TRANSACTION_FREEZE_IN_PROGRESS                                 => "STATUS_TRANSACTION_FREEZE_IN_PROGRESS",
+    SMB_NTSTATUS_NOT_SNAPSHOT_VOLUME                                            => "STATUS_NOT_SNAPSHOT_VOLUME",
+    SMB_NTSTATUS_NO_SAVEPOINT_WITH_OPEN_FILES                                   => "STATUS_NO_SAVEPOINT_WITH_OPEN_FILES",
+    SMB_NTSTATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION                              => "STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION",
+    SMB_NTSTATUS_TM_IDENTITY_MISMATCH                                           => "STATUS_TM_IDENTITY_MISMATCH",
+    SMB_NTSTATUS_FLOATED_SECTION                                                => "STATUS_FLOATED_SECTION",
+    SMB_NTSTATUS_CANNOT_ACCEPT_TRANSACTED_WORK                                  => "STATUS_CANNOT_ACCEPT_TRANSACTED_WORK",
+    SMB_NTSTATUS_CANNOT_ABORT_TRANSACTIONS                                      => "STATUS_CANNOT_ABORT_TRANSACTIONS",
+    SMB_NTSTATUS_TRANSACTION_NOT_FOUND                                          => "STATUS_TRANSACTION_NOT_FOUND",
+    SMB_NTSTATUS_RESOURCEMANAGER_NOT_FOUND                                      => "STATUS_RESOURCEMANAGER_NOT_FOUND",
+    SMB_NTSTATUS_ENLISTMENT_NOT_FOUND                                           => "STATUS_ENLISTMENT_NOT_FOUND",
+    SMB_NTSTATUS_TRANSACTIONMANAGER_NOT_FOUND                                   => "STATUS_TRANSACTIONMANAGER_NOT_FOUND",
+    SMB_NTSTATUS_TRANSACTIONMANAGER_NOT_ONLINE                                  => "STATUS_TRANSACTIONMANAGER_NOT_ONLINE",
+    SMB_NTSTATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION                     => "STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION",
+    SMB_NTSTATUS_TRANSACTION_NOT_ROOT                                           => "STATUS_TRANSACTION_NOT_ROOT",
+    SMB_NTSTATUS_TRANSACTION_OBJECT_EXPIRED                                     => "STATUS_TRANSACTION_OBJECT_EXPIRED",
+    SMB_NTSTATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION                         => "STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION",
+    SMB_NTSTATUS_TRANSACTION_RESPONSE_NOT_ENLISTED                              => "STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED",
+    SMB_NTSTATUS_TRANSACTION_RECORD_TOO_LONG                                    => "STATUS_TRANSACTION_RECORD_TOO_LONG",
+    SMB_NTSTATUS_NO_LINK_TRACKING_IN_TRANSACTION                                => "STATUS_NO_LINK_TRACKING_IN_TRANSACTION",
+    SMB_NTSTATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION                         => "STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION",
+    SMB_NTSTATUS_TRANSACTION_INTEGRITY_VIOLATED                                 => "STATUS_TRANSACTION_INTEGRITY_VIOLATED",
+    SMB_NTSTATUS_EXPIRED_HANDLE                                                 => "STATUS_EXPIRED_HANDLE",
+    SMB_NTSTATUS_TRANSACTION_NOT_ENLISTED                                       => "STATUS_TRANSACTION_NOT_ENLISTED",
+    SMB_NTSTATUS_LOG_SECTOR_INVALID                                             => "STATUS_LOG_SECTOR_INVALID",
+    SMB_NTSTATUS_LOG_SECTOR_PARITY_INVALID                                      => "STATUS_LOG_SECTOR_PARITY_INVALID",
+    SMB_NTSTATUS_LOG_SECTOR_REMAPPED                                            => "STATUS_LOG_SECTOR_REMAPPED",
+    SMB_NTSTATUS_LOG_BLOCK_INCOMPLETE                                           => "STATUS_LOG_BLOCK_INCOMPLETE",
+    SMB_NTSTATUS_LOG_INVALID_RANGE                                              => "STATUS_LOG_INVALID_RANGE",
+    SMB_NTSTATUS_LOG_BLOCKS_EXHAUSTED                                           => "STATUS_LOG_BLOCKS_EXHAUSTED",
+    SMB_NTSTATUS_LOG_READ_CONTEXT_INVALID                                       => "STATUS_LOG_READ_CONTEXT_INVALID",
+    SMB_NTSTATUS_LOG_RESTART_INVALID                                            => "STATUS_LOG_RESTART_INVALID",
+    SMB_NTSTATUS_LOG_BLOCK_VERSION                                              => "STATUS_LOG_BLOCK_VERSION",
+    SMB_NTSTATUS_LOG_BLOCK_INVALID                                              => "STATUS_LOG_BLOCK_INVALID",
+    SMB_NTSTATUS_LOG_READ_MODE_INVALID                                          => "STATUS_LOG_READ_MODE_INVALID",
+    SMB_NTSTATUS_LOG_METADATA_CORRUPT                                           => "STATUS_LOG_METADATA_CORRUPT",
+    SMB_NTSTATUS_LOG_METADATA_INVALID                                           => "STATUS_LOG_METADATA_INVALID",
+    SMB_NTSTATUS_LOG_METADATA_INCONSISTENT                                      => "STATUS_LOG_METADATA_INCONSISTENT",
+    SMB_NTSTATUS_LOG_RESERVATION_INVALID                                        => "STATUS_LOG_RESERVATION_INVALID",
+    SMB_NTSTATUS_LOG_CANT_DELETE                                                => "STATUS_LOG_CANT_DELETE",
+    SMB_NTSTATUS_LOG_CONTAINER_LIMIT_EXCEEDED                                   => "STATUS_LOG_CONTAINER_LIMIT_EXCEEDED",
+    SMB_NTSTATUS_LOG_START_OF_LOG                                               => "STATUS_LOG_START_OF_LOG",
+    SMB_NTSTATUS_LOG_POLICY_ALREADY_INSTALLED                                   => "STATUS_LOG_POLICY_ALREADY_INSTALLED",
+    SMB_NTSTATUS_LOG_POLICY_NOT_INSTALLED                                       => "STATUS_LOG_POLICY_NOT_INSTALLED",
+    SMB_NTSTATUS_LOG_POLICY_INVALID                                             => "STATUS_LOG_POLICY_INVALID",
+    SMB_NTSTATUS_LOG_POLICY_CONFLICT                                            => "STATUS_LOG_POLICY_CONFLICT",
+    SMB_NTSTATUS_LOG_PINNED_ARCHIVE_TAIL                                        => "STATUS_LOG_PINNED_ARCHIVE_TAIL",
+    SMB_NTSTATUS_LOG_RECORD_NONEXISTENT                                         => "STATUS_LOG_RECORD_NONEXISTENT",
+    SMB_NTSTATUS_LOG_RECORDS_RESERVED_INVALID                                   => "STATUS_LOG_RECORDS_RESERVED_INVALID",
+    SMB_NTSTATUS_LOG_SPACE_RESERVED_INVALID                                     => "STATUS_LOG_SPACE_RESERVED_INVALID",
+    SMB_NTSTATUS_LOG_TAIL_INVALID                                               => "STATUS_LOG_TAIL_INVALID",
+    SMB_NTSTATUS_LOG_FULL                                                       => "STATUS_LOG_FULL",
+    SMB_NTSTATUS_LOG_MULTIPLEXED                                                => "STATUS_LOG_MULTIPLEXED",
+    SMB_NTSTATUS_LOG_DEDICATED                                                  => "STATUS_LOG_DEDICATED",
+    SMB_NTSTATUS_LOG_ARCHIVE_NOT_IN_PROGRESS                                    => "STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS",
+    SMB_NTSTATUS_LOG_ARCHIVE_IN_PROGRESS                                        => "STATUS_LOG_ARCHIVE_IN_PROGRESS",
+    SMB_NTSTATUS_LOG_EPHEMERAL                                                  => "STATUS_LOG_EPHEMERAL",
+    SMB_NTSTATUS_LOG_NOT_ENOUGH_CONTAINERS                                      => "STATUS_LOG_NOT_ENOUGH_CONTAINERS",
+    SMB_NTSTATUS_LOG_CLIENT_ALREADY_REGISTERED                                  => "STATUS_LOG_CLIENT_ALREADY_REGISTERED",
+    SMB_NTSTATUS_LOG_CLIENT_NOT_REGISTERED                                      => "STATUS_LOG_CLIENT_NOT_REGISTERED",
+    SMB_NTSTATUS_LOG_FULL_HANDLER_IN_PROGRESS                                   => "STATUS_LOG_FULL_HANDLER_IN_PROGRESS",
+    SMB_NTSTATUS_LOG_CONTAINER_READ_FAILED                                      => "STATUS_LOG_CONTAINER_READ_FAILED",
+    SMB_NTSTATUS_LOG_CONTAINER_WRITE_FAILED                                     => "STATUS_LOG_CONTAINER_WRITE_FAILED",
+    SMB_NTSTATUS_LOG_CONTAINER_OPEN_FAILED                                      => "STATUS_LOG_CONTAINER_OPEN_FAILED",
+    SMB_NTSTATUS_LOG_CONTAINER_STATE_INVALID                                    => "STATUS_LOG_CONTAINER_STATE_INVALID",
+    SMB_NTSTATUS_LOG_STATE_INVALID                                              => "STATUS_LOG_STATE_INVALID",
+    SMB_NTSTATUS_LOG_PINNED                                                     => "STATUS_LOG_PINNED",
+    SMB_NTSTATUS_LOG_METADATA_FLUSH_FAILED                                      => "STATUS_LOG_METADATA_FLUSH_FAILED",
+    SMB_NTSTATUS_LOG_INCONSISTENT_SECURITY                                      => "STATUS_LOG_INCONSISTENT_SECURITY",
+    SMB_NTSTATUS_LOG_APPENDED_FLUSH_FAILED                                      => "STATUS_LOG_APPENDED_FLUSH_FAILED",
+    SMB_NTSTATUS_LOG_PINNED_RESERVATION                                         => "STATUS_LOG_PINNED_RESERVATION",
+    SMB_NTSTATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD                               => "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD",
+    SMB_NTSTATUS_FLT_NO_HANDLER_DEFINED                                         => "STATUS_FLT_NO_HANDLER_DEFINED",
+    SMB_NTSTATUS_FLT_CONTEXT_ALREADY_DEFINED                                    => "STATUS_FLT_CONTEXT_ALREADY_DEFINED",
+    SMB_NTSTATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST                               => "STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST",
+    SMB_NTSTATUS_FLT_DISALLOW_FAST_IO                                           => "STATUS_FLT_DISALLOW_FAST_IO",
+    SMB_NTSTATUS_FLT_INVALID_NAME_REQUEST                                       => "STATUS_FLT_INVALID_NAME_REQUEST",
+    SMB_NTSTATUS_FLT_NOT_SAFE_TO_POST_OPERATION                                 => "STATUS_FLT_NOT_SAFE_TO_POST_OPERATION",
+    SMB_NTSTATUS_FLT_NOT_INITIALIZED                                            => "STATUS_FLT_NOT_INITIALIZED",
+    SMB_NTSTATUS_FLT_FILTER_NOT_READY                                           => "STATUS_FLT_FILTER_NOT_READY",
+    SMB_NTSTATUS_FLT_POST_OPERATION_CLEANUP                                     => "STATUS_FLT_POST_OPERATION_CLEANUP",
+    SMB_NTSTATUS_FLT_INTERNAL_ERROR                                             => "STATUS_FLT_INTERNAL_ERROR",
+    SMB_NTSTATUS_FLT_DELETING_OBJECT                                            => "STATUS_FLT_DELETING_OBJECT",
+    SMB_NTSTATUS_FLT_MUST_BE_NONPAGED_POOL                                      => "STATUS_FLT_MUST_BE_NONPAGED_POOL",
+    SMB_NTSTATUS_FLT_DUPLICATE_ENTRY                                            => "STATUS_FLT_DUPLICATE_ENTRY",
+    SMB_NTSTATUS_FLT_CBDQ_DISABLED                                              => "STATUS_FLT_CBDQ_DISABLED",
+    SMB_NTSTATUS_FLT_DO_NOT_ATTACH                                              => "STATUS_FLT_DO_NOT_ATTACH",
+    SMB_NTSTATUS_FLT_DO_NOT_DETACH                                              => "STATUS_FLT_DO_NOT_DETACH",
+    SMB_NTSTATUS_FLT_INSTANCE_ALTITUDE_COLLISION                                => "STATUS_FLT_INSTANCE_ALTITUDE_COLLISION",
+    SMB_NTSTATUS_FLT_INSTANCE_NAME_COLLISION                                    => "STATUS_FLT_INSTANCE_NAME_COLLISION",
+    SMB_NTSTATUS_FLT_FILTER_NOT_FOUND                                           => "STATUS_FLT_FILTER_NOT_FOUND",
+    SMB_NTSTATUS_FLT_VOLUME_NOT_FOUND                                           => "STATUS_FLT_VOLUME_NOT_FOUND",
+    SMB_NTSTATUS_FLT_INSTANCE_NOT_FOUND                                         => "STATUS_FLT_INSTANCE_NOT_FOUND",
+    SMB_NTSTATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND                               => "STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND",
+    SMB_NTSTATUS_FLT_INVALID_CONTEXT_REGISTRATION                               => "STATUS_FLT_INVALID_CONTEXT_REGISTRATION",
+    SMB_NTSTATUS_FLT_NAME_CACHE_MISS                                            => "STATUS_FLT_NAME_CACHE_MISS",
+    SMB_NTSTATUS_FLT_NO_DEVICE_OBJECT                                           => "STATUS_FLT_NO_DEVICE_OBJECT",
+    SMB_NTSTATUS_FLT_VOLUME_ALREADY_MOUNTED                                     => "STATUS_FLT_VOLUME_ALREADY_MOUNTED",
+    SMB_NTSTATUS_FLT_ALREADY_ENLISTED                                           => "STATUS_FLT_ALREADY_ENLISTED",
+    SMB_NTSTATUS_FLT_CONTEXT_ALREADY_LINKED                                     => "STATUS_FLT_CONTEXT_ALREADY_LINKED",
+    SMB_NTSTATUS_FLT_NO_WAITER_FOR_REPLY                                        => "STATUS_FLT_NO_WAITER_FOR_REPLY",
+    SMB_NTSTATUS_MONITOR_NO_DESCRIPTOR                                          => "STATUS_MONITOR_NO_DESCRIPTOR",
+    SMB_NTSTATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT                              => "STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT",
+    SMB_NTSTATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM                            => "STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM",
+    SMB_NTSTATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK                          => "STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK",
+    SMB_NTSTATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED                      => "STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED",
+    SMB_NTSTATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK                     => "STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK",
+    SMB_NTSTATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK                     => "STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK",
+    SMB_NTSTATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA                                => "STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA",
+    SMB_NTSTATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK                          => "STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK",
+    SMB_NTSTATUS_MONITOR_INVALID_MANUFACTURE_DATE                               => "STATUS_MONITOR_INVALID_MANUFACTURE_DATE",
+    SMB_NTSTATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER                              => "STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER",
+    SMB_NTSTATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER                               => "STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER",
+    SMB_NTSTATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER                               => "STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER",
+    SMB_NTSTATUS_GRAPHICS_ADAPTER_WAS_RESET                                     => "STATUS_GRAPHICS_ADAPTER_WAS_RESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_DRIVER_MODEL                                  => "STATUS_GRAPHICS_INVALID_DRIVER_MODEL",
+    SMB_NTSTATUS_GRAPHICS_PRESENT_MODE_CHANGED                                  => "STATUS_GRAPHICS_PRESENT_MODE_CHANGED",
+    SMB_NTSTATUS_GRAPHICS_PRESENT_OCCLUDED                                      => "STATUS_GRAPHICS_PRESENT_OCCLUDED",
+    SMB_NTSTATUS_GRAPHICS_PRESENT_DENIED                                        => "STATUS_GRAPHICS_PRESENT_DENIED",
+    SMB_NTSTATUS_GRAPHICS_CANNOTCOLORCONVERT                                    => "STATUS_GRAPHICS_CANNOTCOLORCONVERT",
+    SMB_NTSTATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED                          => "STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED",
+    SMB_NTSTATUS_GRAPHICS_PRESENT_UNOCCLUDED                                    => "STATUS_GRAPHICS_PRESENT_UNOCCLUDED",
+    SMB_NTSTATUS_GRAPHICS_NO_VIDEO_MEMORY                                       => "STATUS_GRAPHICS_NO_VIDEO_MEMORY",
+    SMB_NTSTATUS_GRAPHICS_CANT_LOCK_MEMORY                                      => "STATUS_GRAPHICS_CANT_LOCK_MEMORY",
+    SMB_NTSTATUS_GRAPHICS_ALLOCATION_BUSY                                       => "STATUS_GRAPHICS_ALLOCATION_BUSY",
+    SMB_NTSTATUS_GRAPHICS_TOO_MANY_REFERENCES                                   => "STATUS_GRAPHICS_TOO_MANY_REFERENCES",
+    SMB_NTSTATUS_GRAPHICS_TRY_AGAIN_LATER                                       => "STATUS_GRAPHICS_TRY_AGAIN_LATER",
+    SMB_NTSTATUS_GRAPHICS_TRY_AGAIN_NOW                                         => "STATUS_GRAPHICS_TRY_AGAIN_NOW",
+    SMB_NTSTATUS_GRAPHICS_ALLOCATION_INVALID                                    => "STATUS_GRAPHICS_ALLOCATION_INVALID",
+    SMB_NTSTATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE                      => "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE",
+    SMB_NTSTATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED                      => "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION                          => "STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION",
+    SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_USAGE                              => "STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE",
+    SMB_NTSTATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION                         => "STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION",
+    SMB_NTSTATUS_GRAPHICS_ALLOCATION_CLOSED                                     => "STATUS_GRAPHICS_ALLOCATION_CLOSED",
+    SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE                           => "STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE                             => "STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE",
+    SMB_NTSTATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE                               => "STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE",
+    SMB_NTSTATUS_GRAPHICS_ALLOCATION_CONTENT_LOST                               => "STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST",
+    SMB_NTSTATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE                               => "STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY                                => "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED                          => "STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED                => "STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN                                         => "STATUS_GRAPHICS_INVALID_VIDPN",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE                          => "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET                          => "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET",
+    SMB_NTSTATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED                          => "STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET                           => "STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET                           => "STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_FREQUENCY                                     => "STATUS_GRAPHICS_INVALID_FREQUENCY",
+    SMB_NTSTATUS_GRAPHICS_INVALID_ACTIVE_REGION                                 => "STATUS_GRAPHICS_INVALID_ACTIVE_REGION",
+    SMB_NTSTATUS_GRAPHICS_INVALID_TOTAL_REGION                                  => "STATUS_GRAPHICS_INVALID_TOTAL_REGION",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE                     => "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE                     => "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE",
+    SMB_NTSTATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET                        => "STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY                              => "STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_MODE_ALREADY_IN_MODESET                               => "STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET                         => "STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET                         => "STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET",
+    SMB_NTSTATUS_GRAPHICS_SOURCE_ALREADY_IN_SET                                 => "STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_TARGET_ALREADY_IN_SET                                 => "STATUS_GRAPHICS_TARGET_ALREADY_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH                            => "STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH",
+    SMB_NTSTATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY                         => "STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET                     => "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE                        => "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE",
+    SMB_NTSTATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET                             => "STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET                         => "STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_STALE_MODESET                                         => "STATUS_GRAPHICS_STALE_MODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET                         => "STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE                           => "STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE",
+    SMB_NTSTATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN                       => "STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN",
+    SMB_NTSTATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE                                => "STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE",
+    SMB_NTSTATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION       => "STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION",
+    SMB_NTSTATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES               => "STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES",
+    SMB_NTSTATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY                                  => "STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE                 => "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE",
+    SMB_NTSTATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET                 => "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET                          => "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR                             => "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR",
+    SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET                          => "STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET                      => "STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET",
+    SMB_NTSTATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE                   => "STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE                      => "STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE",
+    SMB_NTSTATUS_GRAPHICS_RESOURCES_NOT_RELATED                                 => "STATUS_GRAPHICS_RESOURCES_NOT_RELATED",
+    SMB_NTSTATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE                              => "STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE",
+    SMB_NTSTATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE                              => "STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE",
+    SMB_NTSTATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET                             => "STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET",
+    SMB_NTSTATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER          => "STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER",
+    SMB_NTSTATUS_GRAPHICS_NO_VIDPNMGR                                           => "STATUS_GRAPHICS_NO_VIDPNMGR",
+    SMB_NTSTATUS_GRAPHICS_NO_ACTIVE_VIDPN                                       => "STATUS_GRAPHICS_NO_ACTIVE_VIDPN",
+    SMB_NTSTATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY                                  => "STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_MONITOR_NOT_CONNECTED                                 => "STATUS_GRAPHICS_MONITOR_NOT_CONNECTED",
+    SMB_NTSTATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY                                => "STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE                           => "STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE                            => "STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_STRIDE                                        => "STATUS_GRAPHICS_INVALID_STRIDE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PIXELFORMAT                                   => "STATUS_GRAPHICS_INVALID_PIXELFORMAT",
+    SMB_NTSTATUS_GRAPHICS_INVALID_COLORBASIS                                    => "STATUS_GRAPHICS_INVALID_COLORBASIS",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE                          => "STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE",
+    SMB_NTSTATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY                                => "STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY",
+    SMB_NTSTATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT                    => "STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT",
+    SMB_NTSTATUS_GRAPHICS_VIDPN_SOURCE_IN_USE                                   => "STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE",
+    SMB_NTSTATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN                              => "STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL                       => "STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION          => "STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION",
+    SMB_NTSTATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED    => "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_INVALID_GAMMA_RAMP                                    => "STATUS_GRAPHICS_INVALID_GAMMA_RAMP",
+    SMB_NTSTATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED                              => "STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED                           => "STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_MODE_NOT_IN_MODESET                                   => "STATUS_GRAPHICS_MODE_NOT_IN_MODESET",
+    SMB_NTSTATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON          => "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE                             => "STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE",
+    SMB_NTSTATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE                           => "STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE",
+    SMB_NTSTATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS                     => "STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS",
+    SMB_NTSTATUS_GRAPHICS_INVALID_SCANLINE_ORDERING                             => "STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING",
+    SMB_NTSTATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED                          => "STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED",
+    SMB_NTSTATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS                      => "STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS",
+    SMB_NTSTATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT                           => "STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM                        => "STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN                     => "STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN",
+    SMB_NTSTATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT             => "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT",
+    SMB_NTSTATUS_GRAPHICS_MAX_NUM_PATHS_REACHED                                 => "STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED",
+    SMB_NTSTATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION                    => "STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION",
+    SMB_NTSTATUS_GRAPHICS_INVALID_CLIENT_TYPE                                   => "STATUS_GRAPHICS_INVALID_CLIENT_TYPE",
+    SMB_NTSTATUS_GRAPHICS_CLIENTVIDPN_NOT_SET                                   => "STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET",
+    SMB_NTSTATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED                     => "STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED",
+    SMB_NTSTATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED                        => "STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_NOT_A_LINKED_ADAPTER                                  => "STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER",
+    SMB_NTSTATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED                               => "STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED",
+    SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED                             => "STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED",
+    SMB_NTSTATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY                               => "STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY",
+    SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_STARTED                                => "STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED",
+    SMB_NTSTATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON                             => "STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON",
+    SMB_NTSTATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE                        => "STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE",
+    SMB_NTSTATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER                                => "STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER",
+    SMB_NTSTATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED                           => "STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED",
+    SMB_NTSTATUS_GRAPHICS_OPM_NOT_SUPPORTED                                     => "STATUS_GRAPHICS_OPM_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_COPP_NOT_SUPPORTED                                    => "STATUS_GRAPHICS_COPP_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_UAB_NOT_SUPPORTED                                     => "STATUS_GRAPHICS_UAB_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS                      => "STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS",
+    SMB_NTSTATUS_GRAPHICS_OPM_PARAMETER_ARRAY_TOO_SMALL                         => "STATUS_GRAPHICS_OPM_PARAMETER_ARRAY_TOO_SMALL",
+    SMB_NTSTATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST                        => "STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST",
+    SMB_NTSTATUS_GRAPHICS_PVP_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME             => "STATUS_GRAPHICS_PVP_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME",
+    SMB_NTSTATUS_GRAPHICS_PVP_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP            => "STATUS_GRAPHICS_PVP_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP",
+    SMB_NTSTATUS_GRAPHICS_PVP_MIRRORING_DEVICES_NOT_SUPPORTED                   => "STATUS_GRAPHICS_PVP_MIRRORING_DEVICES_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_POINTER                                   => "STATUS_GRAPHICS_OPM_INVALID_POINTER",
+    SMB_NTSTATUS_GRAPHICS_OPM_INTERNAL_ERROR                                    => "STATUS_GRAPHICS_OPM_INTERNAL_ERROR",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_HANDLE                                    => "STATUS_GRAPHICS_OPM_INVALID_HANDLE",
+    SMB_NTSTATUS_GRAPHICS_PVP_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE          => "STATUS_GRAPHICS_PVP_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE",
+    SMB_NTSTATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH                        => "STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH",
+    SMB_NTSTATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED                             => "STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED",
+    SMB_NTSTATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED                              => "STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED",
+    SMB_NTSTATUS_GRAPHICS_PVP_HFS_FAILED                                        => "STATUS_GRAPHICS_PVP_HFS_FAILED",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_SRM                                       => "STATUS_GRAPHICS_OPM_INVALID_SRM",
+    SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP                      => "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP",
+    SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP                       => "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP",
+    SMB_NTSTATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA                     => "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA",
+    SMB_NTSTATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET                                => "STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET",
+    SMB_NTSTATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH                               => "STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH",
+    SMB_NTSTATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE                  => "STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE",
+    SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS                 => "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS",
+    SMB_NTSTATUS_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS                   => "STATUS_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS",
+    SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS     => "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST                       => "STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST",
+    SMB_NTSTATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR                             => "STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR",
+    SMB_NTSTATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS      => "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS",
+    SMB_NTSTATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED                           => "STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST                     => "STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST",
+    SMB_NTSTATUS_GRAPHICS_I2C_NOT_SUPPORTED                                     => "STATUS_GRAPHICS_I2C_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST                             => "STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST",
+    SMB_NTSTATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA                           => "STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA",
+    SMB_NTSTATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA                              => "STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED                               => "STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_DATA                                    => "STATUS_GRAPHICS_DDCCI_INVALID_DATA",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE     => "STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING                     => "STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING",
+    SMB_NTSTATUS_GRAPHICS_MCA_INTERNAL_ERROR                                    => "STATUS_GRAPHICS_MCA_INTERNAL_ERROR",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND                         => "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH                          => "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH",
+    SMB_NTSTATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM                        => "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM",
+    SMB_NTSTATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE                       => "STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE",
+    SMB_NTSTATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS                              => "STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS",
+    SMB_NTSTATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED                        => "STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME                 => "STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME",
+    SMB_NTSTATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP                => "STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP",
+    SMB_NTSTATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED                       => "STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED",
+    SMB_NTSTATUS_GRAPHICS_INVALID_POINTER                                       => "STATUS_GRAPHICS_INVALID_POINTER",
+    SMB_NTSTATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE              => "STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE",
+    SMB_NTSTATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL                             => "STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL",
+    SMB_NTSTATUS_GRAPHICS_INTERNAL_ERROR                                        => "STATUS_GRAPHICS_INTERNAL_ERROR",
+    SMB_NTSTATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS                       => "STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS",
+    SMB_NTSTATUS_FVE_LOCKED_VOLUME                                              => "STATUS_FVE_LOCKED_VOLUME",
+    SMB_NTSTATUS_FVE_NOT_ENCRYPTED                                              => "STATUS_FVE_NOT_ENCRYPTED",
+    SMB_NTSTATUS_FVE_BAD_INFORMATION                                            => "STATUS_FVE_BAD_INFORMATION",
+    SMB_NTSTATUS_FVE_TOO_SMALL                                                  => "STATUS_FVE_TOO_SMALL",
+    SMB_NTSTATUS_FVE_FAILED_WRONG_FS                                            => "STATUS_FVE_FAILED_WRONG_FS",
+    SMB_NTSTATUS_FVE_FAILED_BAD_FS                                              => "STATUS_FVE_FAILED_BAD_FS",
+    SMB_NTSTATUS_FVE_FS_NOT_EXTENDED                                            => "STATUS_FVE_FS_NOT_EXTENDED",
+    SMB_NTSTATUS_FVE_FS_MOUNTED                                                 => "STATUS_FVE_FS_MOUNTED",
+    SMB_NTSTATUS_FVE_NO_LICENSE                                                 => "STATUS_FVE_NO_LICENSE",
+    SMB_NTSTATUS_FVE_ACTION_NOT_ALLOWED                                         => "STATUS_FVE_ACTION_NOT_ALLOWED",
+    SMB_NTSTATUS_FVE_BAD_DATA                                                   => "STATUS_FVE_BAD_DATA",
+    SMB_NTSTATUS_FVE_VOLUME_NOT_BOUND                                           => "STATUS_FVE_VOLUME_NOT_BOUND",
+    SMB_NTSTATUS_FVE_NOT_DATA_VOLUME                                            => "STATUS_FVE_NOT_DATA_VOLUME",
+    SMB_NTSTATUS_FVE_CONV_READ_ERROR                                            => "STATUS_FVE_CONV_READ_ERROR",
+    SMB_NTSTATUS_FVE_CONV_WRITE_ERROR                                           => "STATUS_FVE_CONV_WRITE_ERROR",
+    SMB_NTSTATUS_FVE_OVERLAPPED_UPDATE                                          => "STATUS_FVE_OVERLAPPED_UPDATE",
+    SMB_NTSTATUS_FVE_FAILED_SECTOR_SIZE                                         => "STATUS_FVE_FAILED_SECTOR_SIZE",
+    SMB_NTSTATUS_FVE_FAILED_AUTHENTICATION                                      => "STATUS_FVE_FAILED_AUTHENTICATION",
+    SMB_NTSTATUS_FVE_NOT_OS_VOLUME                                              => "STATUS_FVE_NOT_OS_VOLUME",
+    SMB_NTSTATUS_FVE_KEYFILE_NOT_FOUND                                          => "STATUS_FVE_KEYFILE_NOT_FOUND",
+    SMB_NTSTATUS_FVE_KEYFILE_INVALID                                            => "STATUS_FVE_KEYFILE_INVALID",
+    SMB_NTSTATUS_FVE_KEYFILE_NO_VMK                                             => "STATUS_FVE_KEYFILE_NO_VMK",
+    SMB_NTSTATUS_FVE_TPM_DISABLED                                               => "STATUS_FVE_TPM_DISABLED",
+    SMB_NTSTATUS_FVE_TPM_SRK_AUTH_NOT_ZERO                                      => "STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO",
+    SMB_NTSTATUS_FVE_TPM_INVALID_PCR                                            => "STATUS_FVE_TPM_INVALID_PCR",
+    SMB_NTSTATUS_FVE_TPM_NO_VMK                                                 => "STATUS_FVE_TPM_NO_VMK",
+    SMB_NTSTATUS_FVE_PIN_INVALID                                                => "STATUS_FVE_PIN_INVALID",
+    SMB_NTSTATUS_FVE_AUTH_INVALID_APPLICATION                                   => "STATUS_FVE_AUTH_INVALID_APPLICATION",
+    SMB_NTSTATUS_FVE_AUTH_INVALID_CONFIG                                        => "STATUS_FVE_AUTH_INVALID_CONFIG",
+    SMB_NTSTATUS_FVE_DEBUGGER_ENABLED                                           => "STATUS_FVE_DEBUGGER_ENABLED",
+    SMB_NTSTATUS_FVE_DRY_RUN_FAILED                                             => "STATUS_FVE_DRY_RUN_FAILED",
+    SMB_NTSTATUS_FVE_BAD_METADATA_POINTER                                       => "STATUS_FVE_BAD_METADATA_POINTER",
+    SMB_NTSTATUS_FVE_OLD_METADATA_COPY                                          => "STATUS_FVE_OLD_METADATA_COPY",
+    SMB_NTSTATUS_FVE_REBOOT_REQUIRED                                            => "STATUS_FVE_REBOOT_REQUIRED",
+    SMB_NTSTATUS_FVE_RAW_ACCESS                                                 => "STATUS_FVE_RAW_ACCESS",
+    SMB_NTSTATUS_FVE_RAW_BLOCKED                                                => "STATUS_FVE_RAW_BLOCKED",
+    SMB_NTSTATUS_FVE_NO_FEATURE_LICENSE                                         => "STATUS_FVE_NO_FEATURE_LICENSE",
+    SMB_NTSTATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED                        => "STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED",
+    SMB_NTSTATUS_FVE_CONV_RECOVERY_FAILED                                       => "STATUS_FVE_CONV_RECOVERY_FAILED",
+    SMB_NTSTATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG                                  => "STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG",
+    SMB_NTSTATUS_FVE_VOLUME_TOO_SMALL                                           => "STATUS_FVE_VOLUME_TOO_SMALL",
+    SMB_NTSTATUS_FWP_CALLOUT_NOT_FOUND                                          => "STATUS_FWP_CALLOUT_NOT_FOUND",
+    SMB_NTSTATUS_FWP_CONDITION_NOT_FOUND                                        => "STATUS_FWP_CONDITION_NOT_FOUND",
+    SMB_NTSTATUS_FWP_FILTER_NOT_FOUND                                           => "STATUS_FWP_FILTER_NOT_FOUND",
+    SMB_NTSTATUS_FWP_LAYER_NOT_FOUND                                            => "STATUS_FWP_LAYER_NOT_FOUND",
+    SMB_NTSTATUS_FWP_PROVIDER_NOT_FOUND                                         => "STATUS_FWP_PROVIDER_NOT_FOUND",
+    SMB_NTSTATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND                                 => "STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND",
+    SMB_NTSTATUS_FWP_SUBLAYER_NOT_FOUND                                         => "STATUS_FWP_SUBLAYER_NOT_FOUND",
+    SMB_NTSTATUS_FWP_NOT_FOUND                                                  => "STATUS_FWP_NOT_FOUND",
+    SMB_NTSTATUS_FWP_ALREADY_EXISTS                                             => "STATUS_FWP_ALREADY_EXISTS",
+    SMB_NTSTATUS_FWP_IN_USE                                                     => "STATUS_FWP_IN_USE",
+    SMB_NTSTATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS                                => "STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS",
+    SMB_NTSTATUS_FWP_WRONG_SESSION                                              => "STATUS_FWP_WRONG_SESSION",
+    SMB_NTSTATUS_FWP_NO_TXN_IN_PROGRESS                                         => "STATUS_FWP_NO_TXN_IN_PROGRESS",
+    SMB_NTSTATUS_FWP_TXN_IN_PROGRESS                                            => "STATUS_FWP_TXN_IN_PROGRESS",
+    SMB_NTSTATUS_FWP_TXN_ABORTED                                                => "STATUS_FWP_TXN_ABORTED",
+    SMB_NTSTATUS_FWP_SESSION_ABORTED                                            => "STATUS_FWP_SESSION_ABORTED",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_TXN                                           => "STATUS_FWP_INCOMPATIBLE_TXN",
+    SMB_NTSTATUS_FWP_TIMEOUT                                                    => "STATUS_FWP_TIMEOUT",
+    SMB_NTSTATUS_FWP_NET_EVENTS_DISABLED                                        => "STATUS_FWP_NET_EVENTS_DISABLED",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_LAYER                                         => "STATUS_FWP_INCOMPATIBLE_LAYER",
+    SMB_NTSTATUS_FWP_KM_CLIENTS_ONLY                                            => "STATUS_FWP_KM_CLIENTS_ONLY",
+    SMB_NTSTATUS_FWP_LIFETIME_MISMATCH                                          => "STATUS_FWP_LIFETIME_MISMATCH",
+    SMB_NTSTATUS_FWP_BUILTIN_OBJECT                                             => "STATUS_FWP_BUILTIN_OBJECT",
+    SMB_NTSTATUS_FWP_TOO_MANY_BOOTTIME_FILTERS                                  => "STATUS_FWP_TOO_MANY_BOOTTIME_FILTERS",
+    SMB_NTSTATUS_FWP_NOTIFICATION_DROPPED                                       => "STATUS_FWP_NOTIFICATION_DROPPED",
+    SMB_NTSTATUS_FWP_TRAFFIC_MISMATCH                                           => "STATUS_FWP_TRAFFIC_MISMATCH",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_SA_STATE                                      => "STATUS_FWP_INCOMPATIBLE_SA_STATE",
+    SMB_NTSTATUS_FWP_NULL_POINTER                                               => "STATUS_FWP_NULL_POINTER",
+    SMB_NTSTATUS_FWP_INVALID_ENUMERATOR                                         => "STATUS_FWP_INVALID_ENUMERATOR",
+    SMB_NTSTATUS_FWP_INVALID_FLAGS                                              => "STATUS_FWP_INVALID_FLAGS",
+    SMB_NTSTATUS_FWP_INVALID_NET_MASK                                           => "STATUS_FWP_INVALID_NET_MASK",
+    SMB_NTSTATUS_FWP_INVALID_RANGE                                              => "STATUS_FWP_INVALID_RANGE",
+    SMB_NTSTATUS_FWP_INVALID_INTERVAL                                           => "STATUS_FWP_INVALID_INTERVAL",
+    SMB_NTSTATUS_FWP_ZERO_LENGTH_ARRAY                                          => "STATUS_FWP_ZERO_LENGTH_ARRAY",
+    SMB_NTSTATUS_FWP_NULL_DISPLAY_NAME                                          => "STATUS_FWP_NULL_DISPLAY_NAME",
+    SMB_NTSTATUS_FWP_INVALID_ACTION_TYPE                                        => "STATUS_FWP_INVALID_ACTION_TYPE",
+    SMB_NTSTATUS_FWP_INVALID_WEIGHT                                             => "STATUS_FWP_INVALID_WEIGHT",
+    SMB_NTSTATUS_FWP_MATCH_TYPE_MISMATCH                                        => "STATUS_FWP_MATCH_TYPE_MISMATCH",
+    SMB_NTSTATUS_FWP_TYPE_MISMATCH                                              => "STATUS_FWP_TYPE_MISMATCH",
+    SMB_NTSTATUS_FWP_OUT_OF_BOUNDS                                              => "STATUS_FWP_OUT_OF_BOUNDS",
+    SMB_NTSTATUS_FWP_RESERVED                                                   => "STATUS_FWP_RESERVED",
+    SMB_NTSTATUS_FWP_DUPLICATE_CONDITION                                        => "STATUS_FWP_DUPLICATE_CONDITION",
+    SMB_NTSTATUS_FWP_DUPLICATE_KEYMOD                                           => "STATUS_FWP_DUPLICATE_KEYMOD",
+    SMB_NTSTATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER                             => "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER",
+    SMB_NTSTATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER                          => "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER",
+    SMB_NTSTATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER                            => "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER",
+    SMB_NTSTATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT                          => "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_AUTH_METHOD                                   => "STATUS_FWP_INCOMPATIBLE_AUTH_METHOD",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_DH_GROUP                                      => "STATUS_FWP_INCOMPATIBLE_DH_GROUP",
+    SMB_NTSTATUS_FWP_EM_NOT_SUPPORTED                                           => "STATUS_FWP_EM_NOT_SUPPORTED",
+    SMB_NTSTATUS_FWP_NEVER_MATCH                                                => "STATUS_FWP_NEVER_MATCH",
+    SMB_NTSTATUS_FWP_PROVIDER_CONTEXT_MISMATCH                                  => "STATUS_FWP_PROVIDER_CONTEXT_MISMATCH",
+    SMB_NTSTATUS_FWP_INVALID_PARAMETER                                          => "STATUS_FWP_INVALID_PARAMETER",
+    SMB_NTSTATUS_FWP_TOO_MANY_SUBLAYERS                                         => "STATUS_FWP_TOO_MANY_SUBLAYERS",
+    SMB_NTSTATUS_FWP_CALLOUT_NOTIFICATION_FAILED                                => "STATUS_FWP_CALLOUT_NOTIFICATION_FAILED",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_AUTH_CONFIG                                   => "STATUS_FWP_INCOMPATIBLE_AUTH_CONFIG",
+    SMB_NTSTATUS_FWP_INCOMPATIBLE_CIPHER_CONFIG                                 => "STATUS_FWP_INCOMPATIBLE_CIPHER_CONFIG",
+    SMB_NTSTATUS_FWP_DUPLICATE_AUTH_METHOD                                      => "STATUS_FWP_DUPLICATE_AUTH_METHOD",
+    SMB_NTSTATUS_FWP_TCPIP_NOT_READY                                            => "STATUS_FWP_TCPIP_NOT_READY",
+    SMB_NTSTATUS_FWP_INJECT_HANDLE_CLOSING                                      => "STATUS_FWP_INJECT_HANDLE_CLOSING",
+    SMB_NTSTATUS_FWP_INJECT_HANDLE_STALE                                        => "STATUS_FWP_INJECT_HANDLE_STALE",
+    SMB_NTSTATUS_FWP_CANNOT_PEND                                                => "STATUS_FWP_CANNOT_PEND",
+    SMB_NTSTATUS_NDIS_CLOSING                                                   => "STATUS_NDIS_CLOSING",
+    SMB_NTSTATUS_NDIS_BAD_VERSION                                               => "STATUS_NDIS_BAD_VERSION",
+    SMB_NTSTATUS_NDIS_BAD_CHARACTERISTICS                                       => "STATUS_NDIS_BAD_CHARACTERISTICS",
+    SMB_NTSTATUS_NDIS_ADAPTER_NOT_FOUND                                         => "STATUS_NDIS_ADAPTER_NOT_FOUND",
+    SMB_NTSTATUS_NDIS_OPEN_FAILED                                               => "STATUS_NDIS_OPEN_FAILED",
+    SMB_NTSTATUS_NDIS_DEVICE_FAILED                                             => "STATUS_NDIS_DEVICE_FAILED",
+    SMB_NTSTATUS_NDIS_MULTICAST_FULL                                            => "STATUS_NDIS_MULTICAST_FULL",
+    SMB_NTSTATUS_NDIS_MULTICAST_EXISTS                                          => "STATUS_NDIS_MULTICAST_EXISTS",
+    SMB_NTSTATUS_NDIS_MULTICAST_NOT_FOUND                                       => "STATUS_NDIS_MULTICAST_NOT_FOUND",
+    SMB_NTSTATUS_NDIS_REQUEST_ABORTED                                           => "STATUS_NDIS_REQUEST_ABORTED",
+    SMB_NTSTATUS_NDIS_RESET_IN_PROGRESS                                         => "STATUS_NDIS_RESET_IN_PROGRESS",
+    SMB_NTSTATUS_NDIS_INVALID_PACKET                                            => "STATUS_NDIS_INVALID_PACKET",
+    SMB_NTSTATUS_NDIS_INVALID_DEVICE_REQUEST                                    => "STATUS_NDIS_INVALID_DEVICE_REQUEST",
+    SMB_NTSTATUS_NDIS_ADAPTER_NOT_READY                                         => "STATUS_NDIS_ADAPTER_NOT_READY",
+    SMB_NTSTATUS_NDIS_INVALID_LENGTH                                            => "STATUS_NDIS_INVALID_LENGTH",
+    SMB_NTSTATUS_NDIS_INVALID_DATA                                              => "STATUS_NDIS_INVALID_DATA",
+    SMB_NTSTATUS_NDIS_BUFFER_TOO_SHORT                                          => "STATUS_NDIS_BUFFER_TOO_SHORT",
+    SMB_NTSTATUS_NDIS_INVALID_OID                                               => "STATUS_NDIS_INVALID_OID",
+    SMB_NTSTATUS_NDIS_ADAPTER_REMOVED                                           => "STATUS_NDIS_ADAPTER_REMOVED",
+    SMB_NTSTATUS_NDIS_UNSUPPORTED_MEDIA                                         => "STATUS_NDIS_UNSUPPORTED_MEDIA",
+    SMB_NTSTATUS_NDIS_GROUP_ADDRESS_IN_USE                                      => "STATUS_NDIS_GROUP_ADDRESS_IN_USE",
+    SMB_NTSTATUS_NDIS_FILE_NOT_FOUND                                            => "STATUS_NDIS_FILE_NOT_FOUND",
+    SMB_NTSTATUS_NDIS_ERROR_READING_FILE                                        => "STATUS_NDIS_ERROR_READING_FILE",
+    SMB_NTSTATUS_NDIS_ALREADY_MAPPED                                            => "STATUS_NDIS_ALREADY_MAPPED",
+    SMB_NTSTATUS_NDIS_RESOURCE_CONFLICT                                         => "STATUS_NDIS_RESOURCE_CONFLICT",
+    SMB_NTSTATUS_NDIS_MEDIA_DISCONNECTED                                        => "STATUS_NDIS_MEDIA_DISCONNECTED",
+    SMB_NTSTATUS_NDIS_INVALID_ADDRESS                                           => "STATUS_NDIS_INVALID_ADDRESS",
+    SMB_NTSTATUS_NDIS_PAUSED                                                    => "STATUS_NDIS_PAUSED",
+    SMB_NTSTATUS_NDIS_INTERFACE_NOT_FOUND                                       => "STATUS_NDIS_INTERFACE_NOT_FOUND",
+    SMB_NTSTATUS_NDIS_UNSUPPORTED_REVISION                                      => "STATUS_NDIS_UNSUPPORTED_REVISION",
+    SMB_NTSTATUS_NDIS_INVALID_PORT                                              => "STATUS_NDIS_INVALID_PORT",
+    SMB_NTSTATUS_NDIS_INVALID_PORT_STATE                                        => "STATUS_NDIS_INVALID_PORT_STATE",
+    SMB_NTSTATUS_NDIS_LOW_POWER_STATE                                           => "STATUS_NDIS_LOW_POWER_STATE",
+    SMB_NTSTATUS_NDIS_NOT_SUPPORTED                                             => "STATUS_NDIS_NOT_SUPPORTED",
+    SMB_NTSTATUS_NDIS_OFFLOAD_POLICY                                            => "STATUS_NDIS_OFFLOAD_POLICY",
+    SMB_NTSTATUS_NDIS_OFFLOAD_CONNECTION_REJECTED                               => "STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED",
+    SMB_NTSTATUS_NDIS_OFFLOAD_PATH_REJECTED                                     => "STATUS_NDIS_OFFLOAD_PATH_REJECTED",
+    SMB_NTSTATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED                                 => "STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED",
+    SMB_NTSTATUS_NDIS_DOT11_MEDIA_IN_USE                                        => "STATUS_NDIS_DOT11_MEDIA_IN_USE",
+    SMB_NTSTATUS_NDIS_DOT11_POWER_STATE_INVALID                                 => "STATUS_NDIS_DOT11_POWER_STATE_INVALID",
+    SMB_NTSTATUS_NDIS_PM_WOL_PATTERN_LIST_FULL                                  => "STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL",
+    SMB_NTSTATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL                             => "STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL",
+    SMB_NTSTATUS_IPSEC_BAD_SPI                                                  => "STATUS_IPSEC_BAD_SPI",
+    SMB_NTSTATUS_IPSEC_SA_LIFETIME_EXPIRED                                      => "STATUS_IPSEC_SA_LIFETIME_EXPIRED",
+    SMB_NTSTATUS_IPSEC_WRONG_SA                                                 => "STATUS_IPSEC_WRONG_SA",
+    SMB_NTSTATUS_IPSEC_REPLAY_CHECK_FAILED                                      => "STATUS_IPSEC_REPLAY_CHECK_FAILED",
+    SMB_NTSTATUS_IPSEC_INVALID_PACKET                                           => "STATUS_IPSEC_INVALID_PACKET",
+    SMB_NTSTATUS_IPSEC_INTEGRITY_CHECK_FAILED                                   => "STATUS_IPSEC_INTEGRITY_CHECK_FAILED",
+    SMB_NTSTATUS_IPSEC_CLEAR_TEXT_DROP                                          => "STATUS_IPSEC_CLEAR_TEXT_DROP",
+    SMB_NTSTATUS_IPSEC_AUTH_FIREWALL_DROP                                       => "STATUS_IPSEC_AUTH_FIREWALL_DROP",
+    SMB_NTSTATUS_IPSEC_THROTTLE_DROP                                            => "STATUS_IPSEC_THROTTLE_DROP",
+    SMB_NTSTATUS_IPSEC_DOSP_BLOCK                                               => "STATUS_IPSEC_DOSP_BLOCK",
+    SMB_NTSTATUS_IPSEC_DOSP_RECEIVED_MULTICAST                                  => "STATUS_IPSEC_DOSP_RECEIVED_MULTICAST",
+    SMB_NTSTATUS_IPSEC_DOSP_INVALID_PACKET                                      => "STATUS_IPSEC_DOSP_INVALID_PACKET",
+    SMB_NTSTATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED                                 => "STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED",
+    SMB_NTSTATUS_IPSEC_DOSP_MAX_ENTRIES                                         => "STATUS_IPSEC_DOSP_MAX_ENTRIES",
+    SMB_NTSTATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED                                  => "STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED",
+    SMB_NTSTATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES                         => "STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES",
+    SMB_NTSTATUS_VOLMGR_MIRROR_NOT_SUPPORTED                                    => "STATUS_VOLMGR_MIRROR_NOT_SUPPORTED",
+    SMB_NTSTATUS_VOLMGR_RAID5_NOT_SUPPORTED                                     => "STATUS_VOLMGR_RAID5_NOT_SUPPORTED",
+    SMB_NTSTATUS_VIRTDISK_PROVIDER_NOT_FOUND                                    => "STATUS_VIRTDISK_PROVIDER_NOT_FOUND",
+    SMB_NTSTATUS_VIRTDISK_NOT_VIRTUAL_DISK                                      => "STATUS_VIRTDISK_NOT_VIRTUAL_DISK",
+    SMB_NTSTATUS_VHD_PARENT_VHD_ACCESS_DENIED                                   => "STATUS_VHD_PARENT_VHD_ACCESS_DENIED",
+    SMB_NTSTATUS_VHD_CHILD_PARENT_SIZE_MISMATCH                                 => "STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH",
+    SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED                          => "STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED",
+    SMB_NTSTATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT                         => "STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT",
+}
@@ -0,0 +1,328 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Algorithm negotiation and the configurable list of banned algorithms.
+//!
+//! Once the KEXINIT of both sides is seen, the algorithms that will be
+//! used are known: for each category it is the first algorithm of the
+//! client list that the server also supports (RFC 4253, section 7.1).
+//! The negotiated algorithms are kept on the transaction for detection
+//! and logging.
+//!
+//! An event is raised when a negotiated algorithm contains one of the
+//! configured banned substrings. Algorithms that are only offered do
+//! not raise anything.
+
+use super::parser::SshPacketKeyExchange;
+use super::ssh::SSHEvent;
+use crate::applayer::EventList;
+use crate::conf::conf_get;
+
+const SSH_BANNED_KEX_DEFAULT: &str = "sha1";
+const SSH_BANNED_HOST_KEY_DEFAULT: &str = "ssh-dss";
+const SSH_BANNED_CIPHER_DEFAULT: &str = "cbc";
+const SSH_BANNED_MAC_DEFAULT: &str = "";
+
+/// Banned substrings per algorithm category.
+#[derive(Debug, Default)]
+pub struct SshBannedAlgorithms {
+    kex: Vec<String>,
+    host_key: Vec<String>,
+    cipher: Vec<String>,
+    mac: Vec<String>,
+}
+
+static mut SSH_BANNED_ALGORITHMS: SshBannedAlgorithms = SshBannedAlgorithms {
+    kex: Vec::new(),
+    host_key: Vec::new(),
+    cipher: Vec::new(),
+    mac: Vec::new(),
+};
+
+fn ssh_banned_parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn ssh_banned_conf_list(name: &str, default: &str) -> Vec<String> {
+    let key = format!("app-layer.protocols.ssh.banned-algorithms.{}", name);
+    ssh_banned_parse_list(conf_get(&key).unwrap_or(default))
+}
+
+/// Load the banned algorithms from the configuration. A category that
+/// is not configured uses its default list, an empty value disables it.
+pub fn ssh_banned_algorithms_configure() {
+    unsafe {
+        SSH_BANNED_ALGORITHMS = SshBannedAlgorithms {
+            kex: ssh_banned_conf_list("kex", SSH_BANNED_KEX_DEFAULT),
+            host_key: ssh_banned_conf_list("host-key", SSH_BANNED_HOST_KEY_DEFAULT),
+            cipher: ssh_banned_conf_list("cipher", SSH_BANNED_CIPHER_DEFAULT),
+            mac: ssh_banned_conf_list("mac", SSH_BANNED_MAC_DEFAULT),
+        };
+    }
+}
+
+pub fn ssh_banned_algorithms_enabled() -> bool {
+    unsafe { !SSH_BANNED_ALGORITHMS.is_empty() }
+}
+
+impl SshBannedAlgorithms {
+    fn is_empty(&self) -> bool {
+        self.kex.is_empty()
+            && self.host_key.is_empty()
+            && self.cipher.is_empty()
+            && self.mac.is_empty()
+    }
+
+    /// Check the negotiated algorithms, setting an event per category
+    /// using a banned algorithm. The banned algorithms are appended to
+    /// `found`.
+    pub fn check(
+        &self, negotiated: &SshNegotiatedAlgorithms, events: &mut EventList,
+        found: &mut Vec<Vec<u8>>,
+    ) {
+        let checks = [
+            (&negotiated.kex, &self.kex, SSHEvent::BannedKexAlgorithm),
+            (
+                &negotiated.host_key,
+                &self.host_key,
+                SSHEvent::BannedHostKeyAlgorithm,
+            ),
+            (&negotiated.cipher_cts, &self.cipher, SSHEvent::BannedCipher),
+            (&negotiated.cipher_stc, &self.cipher, SSHEvent::BannedCipher),
+            (&negotiated.mac_cts, &self.mac, SSHEvent::BannedMac),
+            (&negotiated.mac_stc, &self.mac, SSHEvent::BannedMac),
+        ];
+        for (alg, banned, event) in checks {
+            if let Some(alg) = alg {
+                if ssh_algorithm_is_banned(alg, banned) && !found.contains(alg) {
+                    events.set_event(event as u8);
+                    found.push(alg.clone());
+                }
+            }
+        }
+    }
+}
+
+fn ssh_algorithm_is_banned(alg: &[u8], banned: &[String]) -> bool {
+    banned.iter().any(|b| {
+        let b = b.as_bytes();
+        alg.windows(b.len()).any(|w| w == b)
+    })
+}
+
+/// Algorithm name-lists of a KEXINIT, kept until the KEXINIT of the
+/// other side is seen.
+#[derive(Debug, Default)]
+pub struct SshKexAlgorithms {
+    kex: Vec<u8>,
+    host_key: Vec<u8>,
+    cipher_cts: Vec<u8>,
+    cipher_stc: Vec<u8>,
+    mac_cts: Vec<u8>,
+    mac_stc: Vec<u8>,
+}
+
+impl SshKexAlgorithms {
+    pub fn new(kexinit: &SshPacketKeyExchange) -> Self {
+        Self {
+            kex: kexinit.kex_algs.to_vec(),
+            host_key: kexinit.server_host_key_algs.to_vec(),
+            cipher_cts: kexinit.encr_algs_client_to_server.to_vec(),
+            cipher_stc: kexinit.encr_algs_server_to_client.to_vec(),
+            mac_cts: kexinit.mac_algs_client_to_server.to_vec(),
+            mac_stc: kexinit.mac_algs_server_to_client.to_vec(),
+        }
+    }
+}
+
+/// Algorithms negotiated by both sides, `None` if there was no match.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SshNegotiatedAlgorithms {
+    pub kex: Option<Vec<u8>>,
+    pub host_key: Option<Vec<u8>>,
+    pub cipher_cts: Option<Vec<u8>>,
+    pub cipher_stc: Option<Vec<u8>>,
+    pub mac_cts: Option<Vec<u8>>,
+    pub mac_stc: Option<Vec<u8>>,
+}
+
+/// Pick the first algorithm of the client list that is also in the
+/// server list.
+fn ssh_negotiate_algorithm(client: &[u8], server: &[u8]) -> Option<Vec<u8>> {
+    client
+        .split(|&c| c == b',')
+        .filter(|alg| !alg.is_empty())
+        .find(|alg| server.split(|&c| c == b',').any(|s| s == *alg))
+        .map(|alg| alg.to_vec())
+}
+
+/// With an AEAD cipher the negotiated MAC is not used.
+fn ssh_cipher_is_aead(cipher: &Option<Vec<u8>>) -> bool {
+    match cipher {
+        Some(c) => c.starts_with(b"chacha20-poly1305") || c.ends_with(b"-gcm@openssh.com"),
+        None => false,
+    }
+}
+
+impl SshNegotiatedAlgorithms {
+    pub fn new(client: &SshKexAlgorithms, server: &SshKexAlgorithms) -> Self {
+        let mut negotiated = Self {
+            kex: ssh_negotiate_algorithm(&client.kex, &server.kex),
+            host_key: ssh_negotiate_algorithm(&client.host_key, &server.host_key),
+            cipher_cts: ssh_negotiate_algorithm(&client.cipher_cts, &server.cipher_cts),
+            cipher_stc: ssh_negotiate_algorithm(&client.cipher_stc, &server.cipher_stc),
+            mac_cts: ssh_negotiate_algorithm(&client.mac_cts, &server.mac_cts),
+            mac_stc: ssh_negotiate_algorithm(&client.mac_stc, &server.mac_stc),
+        };
+        if ssh_cipher_is_aead(&negotiated.cipher_cts) {
+            negotiated.mac_cts = None;
+        }
+        if ssh_cipher_is_aead(&negotiated.cipher_stc) {
+            negotiated.mac_stc = None;
+        }
+        negotiated
+    }
+
+    /// Negotiated cipher of a direction.
+    pub fn cipher(&self, to_server: bool) -> &Option<Vec<u8>> {
+        if to_server {
+            &self.cipher_cts
+        } else {
+            &self.cipher_stc
+        }
+    }
+
+    /// Negotiated MAC of a direction, `None` with an AEAD cipher.
+    pub fn mac(&self, to_server: bool) -> &Option<Vec<u8>> {
+        if to_server {
+            &self.mac_cts
+        } else {
+            &self.mac_stc
+        }
+    }
+}
+
+/// Check the algorithms negotiated from the KEXINIT of both sides
+/// against the configured banned algorithms.
+pub fn ssh_check_banned_algorithms(
+    negotiated: &SshNegotiatedAlgorithms, events: &mut EventList, found: &mut Vec<Vec<u8>>,
+) {
+    if !ssh_banned_algorithms_enabled() {
+        return;
+    }
+    unsafe {
+        SSH_BANNED_ALGORITHMS.check(negotiated, events, found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kex_algorithms(kex: &[u8], host_key: &[u8], cipher: &[u8], mac: &[u8]) -> SshKexAlgorithms {
+        SshKexAlgorithms {
+            kex: kex.to_vec(),
+            host_key: host_key.to_vec(),
+            cipher_cts: cipher.to_vec(),
+            cipher_stc: cipher.to_vec(),
+            mac_cts: mac.to_vec(),
+            mac_stc: mac.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_ssh_negotiate_algorithm() {
+        assert_eq!(
+            ssh_negotiate_algorithm(
+                b"curve25519-sha256,ext-info-c",
+                b"ecdh-sha2-nistp256,curve25519-sha256"
+            ),
+            Some(b"curve25519-sha256".to_vec())
+        );
+        // the client preference wins
+        assert_eq!(
+            ssh_negotiate_algorithm(b"aes128-cbc,aes128-ctr", b"aes128-ctr,aes128-cbc"),
+            Some(b"aes128-cbc".to_vec())
+        );
+        assert_eq!(ssh_negotiate_algorithm(b"aes128-cbc", b"aes128-ctr"), None);
+        assert_eq!(ssh_negotiate_algorithm(b"", b""), None);
+    }
+
+    #[test]
+    fn test_ssh_banned_algorithms() {
+        let banned = SshBannedAlgorithms {
+            kex: ssh_banned_parse_list(SSH_BANNED_KEX_DEFAULT),
+            host_key: ssh_banned_parse_list(SSH_BANNED_HOST_KEY_DEFAULT),
+            cipher: ssh_banned_parse_list(SSH_BANNED_CIPHER_DEFAULT),
+            mac: ssh_banned_parse_list("hmac-md5, umac-64"),
+        };
+        assert!(!banned.is_empty());
+
+        // banned algorithms only offered by the client
+        let client = kex_algorithms(
+            b"curve25519-sha256,diffie-hellman-group14-sha1",
+            b"ssh-ed25519,ssh-dss",
+            b"aes128-ctr,aes128-cbc",
+            b"hmac-sha2-256,hmac-md5",
+        );
+        let server = kex_algorithms(
+            b"diffie-hellman-group14-sha1,curve25519-sha256",
+            b"ssh-dss,ssh-ed25519",
+            b"aes128-cbc,aes128-ctr",
+            b"hmac-md5,hmac-sha2-256",
+        );
+        let mut events = EventList::new();
+        let mut found = Vec::new();
+        banned.check(
+            &SshNegotiatedAlgorithms::new(&client, &server),
+            &mut events,
+            &mut found,
+        );
+        assert!(found.is_empty());
+
+        // negotiated banned algorithms, each reported once
+        let negotiated = SshNegotiatedAlgorithms::new(&server, &client);
+        banned.check(&negotiated, &mut events, &mut found);
+        assert_eq!(
+            found,
+            vec![
+                b"diffie-hellman-group14-sha1".to_vec(),
+                b"ssh-dss".to_vec(),
+                b"aes128-cbc".to_vec(),
+                b"hmac-md5".to_vec(),
+            ]
+        );
+
+        // the MAC is not used with an AEAD cipher
+        let client = kex_algorithms(
+            b"",
+            b"",
+            b"chacha20-poly1305@openssh.com",
+            b"umac-64@openssh.com",
+        );
+        let negotiated = SshNegotiatedAlgorithms::new(&client, &client);
+        assert_eq!(negotiated.mac_cts, None);
+        let mut found = Vec::new();
+        banned.check(&negotiated, &mut events, &mut found);
+        assert!(found.is_empty());
+    }
+}
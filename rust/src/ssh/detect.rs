@@ -15,10 +15,26 @@
  * 02110-1301, USA.
  */
 
-use super::ssh::SSHTransaction;
+use super::algorithms::SshNegotiatedAlgorithms;
+use super::ssh::{SSHTransaction, ALPROTO_SSH};
 use crate::core::Direction;
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferMpmRegister, DetectHelperBufferRegister,
+    DetectHelperGetData, DetectHelperKeywordRegister, DetectSignatureSetAppProto, SCSigTableElmt,
+    SigMatchAppendSMToList, SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::os::raw::{c_int, c_void};
 use std::ptr;
 
+static mut G_SSH_HASSH_SKIPPED_KW_ID: c_int = 0;
+static mut G_SSH_HASSH_SKIPPED_BUFFER_ID: c_int = 0;
+static mut G_SSH_COMMENT_BUFFER_ID: c_int = 0;
+static mut G_SSH_KEX_COOKIE_BUFFER_ID: c_int = 0;
+static mut G_SSH_KEX_ALGORITHM_BUFFER_ID: c_int = 0;
+static mut G_SSH_HOST_KEY_ALGORITHM_BUFFER_ID: c_int = 0;
+static mut G_SSH_CIPHER_BUFFER_ID: c_int = 0;
+static mut G_SSH_MAC_BUFFER_ID: c_int = 0;
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_ssh_tx_get_protocol(
     tx: *mut std::os::raw::c_void, buffer: *mut *const u8, buffer_len: *mut u32, direction: u8,
@@ -140,3 +156,433 @@ pub unsafe extern "C" fn rs_ssh_tx_get_hassh_string(
 
     return 0;
 }
+
+/// Get the 16 byte cookie of the KEXINIT of the direction.
+#[no_mangle]
+pub unsafe extern "C" fn SCSshTxGetKexCookie(
+    tx: *mut std::os::raw::c_void, buffer: *mut *const u8, buffer_len: *mut u32, direction: u8,
+) -> u8 {
+    let tx = cast_pointer!(tx, SSHTransaction);
+    let m = match direction.into() {
+        Direction::ToServer => &tx.cli_hdr.kex_cookie,
+        Direction::ToClient => &tx.srv_hdr.kex_cookie,
+    };
+    if !m.is_empty() {
+        *buffer = m.as_ptr();
+        *buffer_len = m.len() as u32;
+        return 1;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+
+    return 0;
+}
+
+unsafe extern "C" fn hassh_skipped_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if SigMatchAppendSMToList(
+        de,
+        s,
+        G_SSH_HASSH_SKIPPED_KW_ID,
+        ptr::null(),
+        G_SSH_HASSH_SKIPPED_BUFFER_ID,
+    )
+    .is_null()
+    {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn hassh_skipped_match(
+    _de: *mut c_void, _f: *mut c_void, flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, _ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, SSHTransaction);
+    let hdr = match flags.into() {
+        Direction::ToServer => &tx.cli_hdr,
+        Direction::ToClient => &tx.srv_hdr,
+    };
+    return hdr.hassh_skipped as c_int;
+}
+
+unsafe extern "C" fn ssh_comment_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_COMMENT_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_comment_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_comment_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_comment_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, SSHTransaction);
+    let hdr = match flow_flags.into() {
+        Direction::ToServer => &tx.cli_hdr,
+        Direction::ToClient => &tx.srv_hdr,
+    };
+    if !hdr.comment.is_empty() {
+        *buffer = hdr.comment.as_ptr();
+        *buffer_len = hdr.comment.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn ssh_kex_cookie_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_KEX_COOKIE_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_kex_cookie_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_kex_cookie_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_kex_cookie_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    SCSshTxGetKexCookie(tx as *mut c_void, buffer, buffer_len, flow_flags) != 0
+}
+
+/// Set the buffer to a negotiated algorithm, the direction of the
+/// inspection picks the cipher and MAC.
+unsafe fn ssh_negotiated_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+    get: fn(&SshNegotiatedAlgorithms, bool) -> &Option<Vec<u8>>,
+) -> bool {
+    let tx = cast_pointer!(tx, SSHTransaction);
+    let to_server = Direction::from(flow_flags) == Direction::ToServer;
+    if let Some(negotiated) = &tx.negotiated {
+        if let Some(alg) = get(negotiated, to_server) {
+            *buffer = alg.as_ptr();
+            *buffer_len = alg.len() as u32;
+            return true;
+        }
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn ssh_kex_algorithm_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_KEX_ALGORITHM_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_kex_algorithm_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_kex_algorithm_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_kex_algorithm_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    ssh_negotiated_get_data(tx, flow_flags, buffer, buffer_len, |n, _| &n.kex)
+}
+
+unsafe extern "C" fn ssh_host_key_algorithm_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_HOST_KEY_ALGORITHM_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_host_key_algorithm_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_host_key_algorithm_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_host_key_algorithm_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    ssh_negotiated_get_data(tx, flow_flags, buffer, buffer_len, |n, _| &n.host_key)
+}
+
+unsafe extern "C" fn ssh_cipher_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_CIPHER_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_cipher_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_cipher_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_cipher_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    ssh_negotiated_get_data(
+        tx,
+        flow_flags,
+        buffer,
+        buffer_len,
+        SshNegotiatedAlgorithms::cipher,
+    )
+}
+
+unsafe extern "C" fn ssh_mac_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_SSH) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_SSH_MAC_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn ssh_mac_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        ssh_mac_get_data,
+    );
+}
+
+unsafe extern "C" fn ssh_mac_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    ssh_negotiated_get_data(
+        tx,
+        flow_flags,
+        buffer,
+        buffer_len,
+        SshNegotiatedAlgorithms::mac,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectSshRegister() {
+    let kw = SCSigTableElmt {
+        name: b"ssh.hassh_skipped\0".as_ptr() as *const libc::c_char,
+        desc: b"match if the hassh could not be generated from the KEXINIT\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-hassh-skipped\0".as_ptr() as *const libc::c_char,
+        Setup: hassh_skipped_setup,
+        flags: SIGMATCH_NOOPT,
+        AppLayerTxMatch: Some(hassh_skipped_match),
+        Free: None,
+    };
+    G_SSH_HASSH_SKIPPED_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_SSH_HASSH_SKIPPED_BUFFER_ID = DetectHelperBufferRegister(
+        b"ssh.hassh_skipped\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.comment\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the comment of the SSH banner\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-comment\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_comment_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_comment_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_COMMENT_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.comment\0".as_ptr() as *const libc::c_char,
+        b"ssh banner comment\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_comment_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.kex_cookie\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the cookie of the SSH KEXINIT\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-kex-cookie\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_kex_cookie_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_kex_cookie_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_KEX_COOKIE_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.kex_cookie\0".as_ptr() as *const libc::c_char,
+        b"ssh kexinit cookie\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_kex_cookie_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.kex_algorithm\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the negotiated SSH key exchange algorithm\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-kex-algorithm\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_kex_algorithm_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_kex_algorithm_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_KEX_ALGORITHM_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.kex_algorithm\0".as_ptr() as *const libc::c_char,
+        b"ssh negotiated kex algorithm\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_kex_algorithm_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.host_key_algorithm\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the negotiated SSH host key algorithm\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-host-key-algorithm\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_host_key_algorithm_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_host_key_algorithm_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_HOST_KEY_ALGORITHM_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.host_key_algorithm\0".as_ptr() as *const libc::c_char,
+        b"ssh negotiated host key algorithm\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_host_key_algorithm_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.cipher\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the negotiated SSH cipher of the direction\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-cipher\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_cipher_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_cipher_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_CIPHER_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.cipher\0".as_ptr() as *const libc::c_char,
+        b"ssh negotiated cipher\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_cipher_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"ssh.mac\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the negotiated SSH MAC of the direction\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/ssh-keywords.html#ssh-mac\0".as_ptr() as *const libc::c_char,
+        Setup: ssh_mac_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_ssh_mac_kw_id = DetectHelperKeywordRegister(&kw);
+    G_SSH_MAC_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"ssh.mac\0".as_ptr() as *const libc::c_char,
+        b"ssh negotiated mac\0".as_ptr() as *const libc::c_char,
+        ALPROTO_SSH,
+        true,
+        true,
+        ssh_mac_get,
+    );
+}
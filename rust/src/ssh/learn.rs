@@ -0,0 +1,110 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Auto-learn mode of the hassh datasets.
+//!
+//! The hassh values are added to the configured datasets as they are
+//! seen, building a baseline. In auto-learn mode, a value that was not in
+//! the dataset yet also raises an event when the destination port of the
+//! flow is one of the configured ports, or for any port if none are
+//! configured. Datasets saved with `state` keep the baseline across
+//! restarts.
+
+use crate::conf::{conf_get, conf_get_bool};
+
+#[derive(Debug, Default)]
+struct SshHasshLearnConfig {
+    enabled: bool,
+    /// Destination ports to raise the events on, all if empty.
+    ports: Vec<u16>,
+}
+
+impl SshHasshLearnConfig {
+    fn alerts(&self, dp: Option<u16>) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self.ports.is_empty() {
+            return true;
+        }
+        dp.map_or(false, |dp| self.ports.contains(&dp))
+    }
+}
+
+static mut SSH_HASSH_LEARN: SshHasshLearnConfig = SshHasshLearnConfig {
+    enabled: false,
+    ports: Vec::new(),
+};
+
+/// Parse a comma separated list of ports.
+fn ssh_hassh_learn_parse_ports(value: &str) -> Option<Vec<u16>> {
+    value
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<u16>().ok())
+        .collect()
+}
+
+pub fn ssh_hassh_learn_configure() {
+    let enabled = conf_get_bool("app-layer.protocols.ssh.datasets.auto-learn.enabled");
+    let ports = match conf_get("app-layer.protocols.ssh.datasets.auto-learn.ports") {
+        Some(value) => ssh_hassh_learn_parse_ports(value).unwrap_or_else(|| {
+            SCLogError!("Invalid value for ssh.datasets.auto-learn.ports: {}", value);
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+    unsafe {
+        SSH_HASSH_LEARN = SshHasshLearnConfig { enabled, ports };
+    }
+}
+
+/// Returns true if a hassh added to a dataset for the first time on a
+/// flow to the destination port `dp` raises an event.
+pub fn ssh_hassh_learn_alerts(dp: Option<u16>) -> bool {
+    unsafe { SSH_HASSH_LEARN.alerts(dp) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_hassh_learn_ports() {
+        assert_eq!(
+            ssh_hassh_learn_parse_ports("22, 2222,"),
+            Some(vec![22, 2222])
+        );
+        assert_eq!(ssh_hassh_learn_parse_ports(""), Some(vec![]));
+        assert_eq!(ssh_hassh_learn_parse_ports("22,ssh"), None);
+        assert_eq!(ssh_hassh_learn_parse_ports("65536"), None);
+    }
+
+    #[test]
+    fn test_ssh_hassh_learn_alerts() {
+        let mut config = SshHasshLearnConfig::default();
+        assert!(!config.alerts(Some(22)));
+        config.enabled = true;
+        assert!(config.alerts(Some(22)));
+        assert!(config.alerts(None));
+        config.ports = vec![22, 2222];
+        assert!(config.alerts(Some(2222)));
+        assert!(!config.alerts(Some(80)));
+        assert!(!config.alerts(None));
+    }
+}
@@ -15,9 +15,56 @@
  * 02110-1301, USA.
  */
 
-use super::ssh::{SSHTransaction, SSH_MAX_BANNER_LEN};
+use super::algorithms::SshNegotiatedAlgorithms;
+use super::ssh::{
+    hassh_string_log_is_enabled, kex_cookie_log_is_enabled, SSHTransaction, SshHeader,
+    SSH_MAX_BANNER_LEN,
+};
 use crate::jsonbuilder::{JsonBuilder, JsonError};
 
+fn log_negotiated_direction(
+    negotiated: &SshNegotiatedAlgorithms, to_server: bool, name: &str, js: &mut JsonBuilder,
+) -> Result<(), JsonError> {
+    js.open_object(name)?;
+    if let Some(cipher) = negotiated.cipher(to_server) {
+        js.set_string_from_bytes("cipher", cipher)?;
+    }
+    if let Some(mac) = negotiated.mac(to_server) {
+        js.set_string_from_bytes("mac", mac)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+fn log_negotiated(
+    negotiated: &SshNegotiatedAlgorithms, js: &mut JsonBuilder,
+) -> Result<(), JsonError> {
+    js.open_object("negotiated")?;
+    if let Some(kex) = &negotiated.kex {
+        js.set_string_from_bytes("kex", kex)?;
+    }
+    if let Some(host_key) = &negotiated.host_key {
+        js.set_string_from_bytes("host_key", host_key)?;
+    }
+    log_negotiated_direction(negotiated, true, "client_to_server", js)?;
+    log_negotiated_direction(negotiated, false, "server_to_client", js)?;
+    js.close()?;
+    Ok(())
+}
+
+fn log_kex(hdr: &SshHeader, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    if hdr.kex_records > 0 {
+        js.open_object("kex")?;
+        js.set_uint("records", hdr.kex_records as u64)?;
+        js.set_uint("bytes", hdr.kex_bytes)?;
+        if !hdr.kex_cookie.is_empty() && kex_cookie_log_is_enabled() {
+            js.set_hex("cookie", &hdr.kex_cookie)?;
+        }
+        js.close()?;
+    }
+    Ok(())
+}
+
 fn log_ssh(tx: &SSHTransaction, js: &mut JsonBuilder) -> Result<bool, JsonError> {
     js.open_object("ssh")?;
     if tx.cli_hdr.protover.is_empty() && tx.srv_hdr.protover.is_empty() {
@@ -29,16 +76,26 @@ fn log_ssh(tx: &SSHTransaction, js: &mut JsonBuilder) -> Result<bool, JsonError>
         if !tx.cli_hdr.swver.is_empty() {
             js.set_string_from_bytes_limited("software_version", &tx.cli_hdr.swver, SSH_MAX_BANNER_LEN)?;
         }
+        if !tx.cli_hdr.comment.is_empty() {
+            js.set_string_from_bytes_limited("comment", &tx.cli_hdr.comment, SSH_MAX_BANNER_LEN)?;
+        }
+        if !tx.cli_hdr.service.is_empty() {
+            js.set_string_from_bytes("service", &tx.cli_hdr.service)?;
+        }
         if !tx.cli_hdr.hassh.is_empty() || !tx.cli_hdr.hassh_string.is_empty() {
             js.open_object("hassh")?;
             if !tx.cli_hdr.hassh.is_empty() {
                 js.set_string_from_bytes("hash", &tx.cli_hdr.hassh)?;
             }
-            if !tx.cli_hdr.hassh_string.is_empty() {
+            if !tx.cli_hdr.hassh_string.is_empty() && hassh_string_log_is_enabled() {
                 js.set_string_from_bytes("string", &tx.cli_hdr.hassh_string)?;
             }
+            if tx.cli_hdr.hassh_first_seen {
+                js.set_bool("first_seen", true)?;
+            }
             js.close()?;
         }
+        log_kex(&tx.cli_hdr, js)?;
         js.close()?;
     }
     if !tx.srv_hdr.protover.is_empty() {
@@ -47,18 +104,46 @@ fn log_ssh(tx: &SSHTransaction, js: &mut JsonBuilder) -> Result<bool, JsonError>
         if !tx.srv_hdr.swver.is_empty() {
             js.set_string_from_bytes_limited("software_version", &tx.srv_hdr.swver, SSH_MAX_BANNER_LEN)?;
         }
+        if !tx.srv_hdr.comment.is_empty() {
+            js.set_string_from_bytes_limited("comment", &tx.srv_hdr.comment, SSH_MAX_BANNER_LEN)?;
+        }
+        if !tx.srv_hdr.service.is_empty() {
+            js.set_string_from_bytes("service", &tx.srv_hdr.service)?;
+        }
         if !tx.srv_hdr.hassh.is_empty() || !tx.srv_hdr.hassh_string.is_empty() {
             js.open_object("hassh")?;
             if !tx.srv_hdr.hassh.is_empty() {
                 js.set_string_from_bytes("hash", &tx.srv_hdr.hassh)?;
             }
-            if !tx.srv_hdr.hassh_string.is_empty() {
+            if !tx.srv_hdr.hassh_string.is_empty() && hassh_string_log_is_enabled() {
                 js.set_string_from_bytes("string", &tx.srv_hdr.hassh_string)?;
             }
+            if tx.srv_hdr.hassh_first_seen {
+                js.set_bool("first_seen", true)?;
+            }
             js.close()?;
         }
+        log_kex(&tx.srv_hdr, js)?;
+        js.close()?;
+    }
+    if let Some(negotiated) = &tx.negotiated {
+        log_negotiated(negotiated, js)?;
+    }
+    if !tx.banned_algorithms.is_empty() {
+        js.open_array("banned_algorithms")?;
+        for alg in &tx.banned_algorithms {
+            js.append_string_from_bytes(alg)?;
+        }
+        js.close()?;
+    }
+    let gaps = (&tx.cli_hdr.gaps, &tx.srv_hdr.gaps);
+    if !gaps.0.is_empty() || !gaps.1.is_empty() {
+        js.open_object("gaps")?;
+        js.set_uint("recovered", gaps.0.recovered as u64 + gaps.1.recovered as u64)?;
+        js.set_uint("unrecoverable", gaps.0.unrecoverable as u64 + gaps.1.unrecoverable as u64)?;
         js.close()?;
     }
+    tx.timing.log(js)?;
     js.close()?;
     return Ok(true);
 }
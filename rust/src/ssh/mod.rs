@@ -17,7 +17,12 @@
 
 //! SSH application layer, logger, detection and parser module.
 
+pub mod algorithms;
 pub mod detect;
+pub mod learn;
 pub mod logger;
 mod parser;
+#[cfg(test)]
+mod replay;
 pub mod ssh;
+pub mod timing;
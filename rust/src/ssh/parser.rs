@@ -21,10 +21,10 @@ use md5::Md5;
 use nom7::branch::alt;
 use nom7::bytes::streaming::{is_not, tag, take, take_while};
 use nom7::character::streaming::char;
-use nom7::combinator::{complete, eof, not, rest, verify};
+use nom7::combinator::{complete, eof, not, opt, rest, verify};
 use nom7::multi::length_data;
 use nom7::number::streaming::{be_u32, be_u8};
-use nom7::sequence::terminated;
+use nom7::sequence::{preceded, terminated};
 use nom7::IResult;
 use std::fmt;
 
@@ -88,6 +88,7 @@ pub fn ssh_parse_line(i: &[u8]) -> IResult<&[u8], &[u8]> {
 pub struct SshBanner<'a> {
     pub protover: &'a [u8],
     pub swver: &'a [u8],
+    pub comment: &'a [u8],
 }
 
 // Could be simplified adding dummy \n at the end
@@ -98,7 +99,18 @@ pub fn ssh_parse_banner(i: &[u8]) -> IResult<&[u8], SshBanner> {
     let (i, _) = char('-')(i)?;
     let (i, swver) = alt((complete(is_not(" \r\n")), rest))(i)?;
     //remaining after space is comments
-    Ok((i, SshBanner { protover, swver }))
+    let (i, comment) = opt(complete(preceded(
+        char(' '),
+        alt((complete(is_not("\r\n")), rest)),
+    )))(i)?;
+    Ok((
+        i,
+        SshBanner {
+            protover,
+            swver,
+            comment: comment.unwrap_or_default(),
+        },
+    ))
 }
 
 #[derive(PartialEq, Eq)]
@@ -118,6 +130,47 @@ impl fmt::Display for SshRecordHeader {
     }
 }
 
+/// Maximum packet length implementations must support, RFC 4253 6.1.
+const SSH_MAX_PACKET_LEN: u32 = 35000;
+/// Block size of the cleartext records sent before NEWKEYS.
+const SSH_CLEARTEXT_BLOCK_SIZE: u32 = 8;
+
+impl SshRecordHeader {
+    /// Check if this can be the header of a record sent before the keys
+    /// are in use: the record is padded to the cleartext block size with
+    /// at least 4 bytes of padding and carries a transport or key
+    /// exchange message. Used to resynchronize on the record stream when
+    /// the banners were not seen.
+    pub fn is_cleartext(&self) -> bool {
+        if self.pkt_len > SSH_MAX_PACKET_LEN
+            || self.padding_len < 4
+            || self.padding_len as u32 >= self.pkt_len
+            || (self.pkt_len + 4) % SSH_CLEARTEXT_BLOCK_SIZE != 0
+        {
+            return false;
+        }
+        match self.msg_code {
+            MessageCode::Undefined(code) => (30..=49).contains(&code),
+            _ => true,
+        }
+    }
+
+    /// Direction the message of the record is sent in: Some(true) for
+    /// client to server, Some(false) for server to client and None if
+    /// both sides send it.
+    pub fn to_server(&self) -> Option<bool> {
+        match self.msg_code {
+            MessageCode::ServiceRequest | MessageCode::KexdhInit => Some(true),
+            MessageCode::ServiceAccept | MessageCode::KexdhReply => Some(false),
+            // diffie-hellman group exchange request and init
+            MessageCode::Undefined(32) | MessageCode::Undefined(34) => Some(true),
+            // diffie-hellman group exchange reply
+            MessageCode::Undefined(33) => Some(false),
+            _ => None,
+        }
+    }
+}
+
 pub fn ssh_parse_record_header(i: &[u8]) -> IResult<&[u8], SshRecordHeader> {
     let (i, pkt_len) = verify(be_u32, |&val| val > 1)(i)?;
     let (i, padding_len) = be_u8(i)?;
@@ -241,6 +294,20 @@ pub fn ssh_parse_key_exchange(i: &[u8]) -> IResult<&[u8], SshPacketKeyExchange>
     ))
 }
 
+/// Maximum length of a service name.
+const SSH_MAX_SERVICE_NAME_LEN: usize = 64;
+
+/// Parse the service name of a SERVICE_REQUEST or SERVICE_ACCEPT message,
+/// RFC 4253 10. The name must be printable, which rules out most of the
+/// encrypted records taken for one of these messages.
+pub fn ssh_parse_service(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    verify(parse_string, |name: &[u8]| {
+        !name.is_empty()
+            && name.len() <= SSH_MAX_SERVICE_NAME_LEN
+            && name.iter().all(|c| c.is_ascii_graphic())
+    })(i)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -269,6 +336,7 @@ mod tests {
                 // Check the first message.
                 assert_eq!(message.protover, b"2.0");
                 assert_eq!(message.swver, b"Soft");
+                assert_eq!(message.comment, b"");
             }
             Err(err) => {
                 panic!("Result should not be an error: {:?}.", err);
@@ -276,6 +344,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ssh_parse_banner_comment() {
+        let buf = b"SSH-2.0-OpenSSH_8.9p1 Ubuntu-3ubuntu0.6";
+        let (_, banner) = ssh_parse_banner(buf).unwrap();
+        assert_eq!(banner.protover, b"2.0");
+        assert_eq!(banner.swver, b"OpenSSH_8.9p1");
+        assert_eq!(banner.comment, b"Ubuntu-3ubuntu0.6");
+
+        // Comments may contain spaces.
+        let buf = b"SSH-2.0-ROSSSH FW 7.12 build 3\r\n";
+        let (rem, banner) = ssh_parse_banner(buf).unwrap();
+        assert_eq!(banner.swver, b"ROSSSH");
+        assert_eq!(banner.comment, b"FW 7.12 build 3");
+        assert_eq!(rem, b"\r\n");
+    }
+
+    #[test]
+    fn test_ssh_record_header_is_cleartext() {
+        // KEXDH_INIT with 6 bytes of padding
+        let buf = [0x00, 0x00, 0x00, 0x2c, 0x06, 0x1e];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(hdr.is_cleartext());
+        assert_eq!(hdr.to_server(), Some(true));
+
+        // KEXDH_REPLY
+        let buf = [0x00, 0x00, 0x00, 0x2c, 0x06, 0x1f];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(hdr.is_cleartext());
+        assert_eq!(hdr.to_server(), Some(false));
+
+        // KEXINIT is sent by both sides
+        let buf = [0x00, 0x00, 0x01, 0x14, 0x04, 0x14];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(hdr.is_cleartext());
+        assert_eq!(hdr.to_server(), None);
+
+        // not padded to the block size
+        let buf = [0x00, 0x00, 0x00, 0x2d, 0x06, 0x1e];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(!hdr.is_cleartext());
+
+        // too little padding
+        let buf = [0x00, 0x00, 0x00, 0x2c, 0x02, 0x1e];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(!hdr.is_cleartext());
+
+        // too long, or a message that is never sent in cleartext
+        let buf = [0x00, 0x01, 0x00, 0x04, 0x06, 0x14];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(!hdr.is_cleartext());
+        let buf = [0x00, 0x00, 0x00, 0x2c, 0x06, 0x32];
+        let (_, hdr) = ssh_parse_record_header(&buf).unwrap();
+        assert!(!hdr.is_cleartext());
+    }
+
     #[test]
     fn test_parse_line() {
         let buf = b"SSH-Single\n";
@@ -724,4 +847,14 @@ mod tests {
             panic!("ssh_parse_key_exchange() parsed malicious key_exchange");
         }
 }
+
+    #[test]
+    fn test_ssh_parse_service() {
+        let buf = b"\x00\x00\x00\x0cssh-userauth\x00\x00\x00\x00";
+        let (rem, service) = ssh_parse_service(buf).unwrap();
+        assert_eq!(service, b"ssh-userauth");
+        assert_eq!(rem, b"\x00\x00\x00\x00");
+        assert!(ssh_parse_service(b"\x00\x00\x00\x03\x8f\x01\xfa").is_err());
+        assert!(ssh_parse_service(b"\x00\x00\x00\x00").is_err());
+    }
 }
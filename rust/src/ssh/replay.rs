@@ -0,0 +1,322 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Replay of captured SSH streams through the parser, for regression
+//! tests of inputs found by fuzzing or seen in the field.
+//!
+//! The streams are in the `corpus` directory. A corpus file is a
+//! sequence of chunks, each made of a flags byte and a big endian 32 bit
+//! length, followed by that many bytes of data unless the chunk is a
+//! gap. The flags are:
+//!
+//! - 0x01: the data goes to the client, otherwise to the server
+//! - 0x02: a gap of `length` bytes, without data
+//! - 0x04: the session was picked up midstream
+//!
+//! The driver buffers the data of each direction the way the app-layer
+//! does on an incomplete result, and stops feeding the parser once both
+//! directions are encrypted, as the flow is bypassed then. Every corpus
+//! is replayed as recorded and in smaller chunks: the outcome must not
+//! depend on how the stream was segmented.
+
+use super::ssh::*;
+use crate::applayer::{AppLayerResult, GapStats, StreamSlice};
+use crate::core::{STREAM_MIDSTREAM, STREAM_TOCLIENT, STREAM_TOSERVER};
+use nom7::bytes::streaming::take;
+use nom7::combinator::cond;
+use nom7::number::streaming::{be_u32, be_u8};
+use nom7::IResult;
+use std::os::raw::c_void;
+
+const REPLAY_TO_CLIENT: u8 = 0x01;
+const REPLAY_GAP: u8 = 0x02;
+const REPLAY_MIDSTREAM: u8 = 0x04;
+
+struct ReplayChunk<'a> {
+    flags: u8,
+    len: u32,
+    data: &'a [u8],
+}
+
+fn parse_replay_chunk(i: &[u8]) -> IResult<&[u8], ReplayChunk> {
+    let (i, flags) = be_u8(i)?;
+    let (i, len) = be_u32(i)?;
+    let (i, data) = cond(flags & REPLAY_GAP == 0, take(len))(i)?;
+    Ok((
+        i,
+        ReplayChunk {
+            flags,
+            len,
+            data: data.unwrap_or_default(),
+        },
+    ))
+}
+
+/// Data of a direction not consumed by the parser yet.
+#[derive(Default)]
+struct ReplayStream {
+    buf: Vec<u8>,
+    /// Bytes the parser needs before being called again.
+    needed: usize,
+    /// Stream offset of the start of `buf`.
+    offset: u64,
+    failed: bool,
+}
+
+/// Outcome of a direction of a replay.
+#[derive(Debug, PartialEq, Eq)]
+struct ReplayDirection {
+    progress: SshStateProgress,
+    failed: bool,
+    protover: Vec<u8>,
+    swver: Vec<u8>,
+    kex_records: u32,
+    kex_bytes: u64,
+    gaps: GapStats,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct ReplaySummary {
+    client: ReplayDirection,
+    server: ReplayDirection,
+    kex: Option<Vec<u8>>,
+    host_key: Option<Vec<u8>>,
+}
+
+fn stream_direction(to_client: bool) -> u8 {
+    if to_client {
+        STREAM_TOCLIENT
+    } else {
+        STREAM_TOSERVER
+    }
+}
+
+struct Replay {
+    state: *mut c_void,
+    /// To server and to client streams.
+    streams: [ReplayStream; 2],
+}
+
+impl Replay {
+    fn new() -> Self {
+        Self {
+            state: rs_ssh_state_new(std::ptr::null_mut(), 0),
+            streams: Default::default(),
+        }
+    }
+
+    fn tx(&self) -> &SSHTransaction {
+        unsafe { &*(rs_ssh_state_get_tx(self.state, 0) as *const SSHTransaction) }
+    }
+
+    fn progress(&self, to_client: bool) -> SshStateProgress {
+        let tx = unsafe { rs_ssh_state_get_tx(self.state, 0) };
+        unsafe { rs_ssh_tx_get_flags(tx, stream_direction(to_client)) }
+    }
+
+    fn is_bypassed(&self) -> bool {
+        self.progress(false) == SshStateProgress::SshStateEncrypted
+            && self.progress(true) == SshStateProgress::SshStateEncrypted
+    }
+
+    fn parse(&self, to_client: bool, slice: StreamSlice) -> AppLayerResult {
+        let (flow, pstate, data) = (std::ptr::null(), std::ptr::null_mut(), std::ptr::null());
+        unsafe {
+            if to_client {
+                rs_ssh_parse_response(flow, self.state, pstate, slice, data)
+            } else {
+                rs_ssh_parse_request(flow, self.state, pstate, slice, data)
+            }
+        }
+    }
+
+    fn data(&mut self, to_client: bool, flags: u8, data: &[u8]) {
+        let idx = to_client as usize;
+        if self.streams[idx].failed || self.is_bypassed() {
+            return;
+        }
+        self.streams[idx].buf.extend_from_slice(data);
+        let stream = &self.streams[idx];
+        if stream.buf.len() < stream.needed {
+            return;
+        }
+        let len = stream.buf.len();
+        let flags = flags | stream_direction(to_client);
+        let r = self.parse(
+            to_client,
+            StreamSlice::from_slice(&stream.buf, flags, stream.offset),
+        );
+        let stream = &mut self.streams[idx];
+        if r.is_incomplete() {
+            let consumed = r.consumed as usize;
+            // the app-layer puts the protocol in error on these
+            assert!(consumed <= len, "consumed {} of {}", consumed, len);
+            assert!(
+                r.needed > 0 && consumed + r.needed as usize >= len,
+                "consumed {} and needed {} of {}",
+                consumed,
+                r.needed,
+                len
+            );
+            stream.buf.drain(..consumed);
+            stream.offset += consumed as u64;
+            stream.needed = r.needed as usize;
+        } else if r.status == 0 {
+            stream.buf.clear();
+            stream.offset += len as u64;
+            stream.needed = 0;
+        } else {
+            stream.failed = true;
+        }
+    }
+
+    fn gap(&mut self, to_client: bool, flags: u8, size: u32) {
+        let idx = to_client as usize;
+        if self.streams[idx].failed || self.is_bypassed() {
+            return;
+        }
+        // the start of an incomplete record is lost along with the gap
+        let stream = &mut self.streams[idx];
+        stream.offset += stream.buf.len() as u64;
+        stream.buf.clear();
+        stream.needed = 0;
+        let flags = flags | stream_direction(to_client);
+        let offset = stream.offset;
+        let r = self.parse(to_client, StreamSlice::from_gap(size, flags, offset));
+        let stream = &mut self.streams[idx];
+        stream.offset += size as u64;
+        if r.status < 0 {
+            stream.failed = true;
+        }
+    }
+
+    fn direction(&self, to_client: bool) -> ReplayDirection {
+        let tx = self.tx();
+        let hdr = if to_client { &tx.srv_hdr } else { &tx.cli_hdr };
+        ReplayDirection {
+            progress: self.progress(to_client),
+            failed: self.streams[to_client as usize].failed,
+            protover: hdr.protover.clone(),
+            swver: hdr.swver.clone(),
+            kex_records: hdr.kex_records,
+            kex_bytes: hdr.kex_bytes,
+            gaps: hdr.gaps,
+        }
+    }
+
+    fn summary(&self) -> ReplaySummary {
+        let negotiated = self.tx().negotiated.as_ref();
+        ReplaySummary {
+            client: self.direction(false),
+            server: self.direction(true),
+            kex: negotiated.and_then(|n| n.kex.clone()),
+            host_key: negotiated.and_then(|n| n.host_key.clone()),
+        }
+    }
+}
+
+impl Drop for Replay {
+    fn drop(&mut self) {
+        unsafe { rs_ssh_state_free(self.state) };
+    }
+}
+
+/// Replay a corpus, feeding the data in chunks of up to `max_chunk`
+/// bytes.
+fn replay(corpus: &[u8], max_chunk: usize) -> ReplaySummary {
+    let mut replay = Replay::new();
+    let mut input = corpus;
+    while !input.is_empty() {
+        let (rem, chunk) = parse_replay_chunk(input).expect("invalid corpus");
+        input = rem;
+        let to_client = chunk.flags & REPLAY_TO_CLIENT != 0;
+        let flags = if chunk.flags & REPLAY_MIDSTREAM != 0 {
+            STREAM_MIDSTREAM
+        } else {
+            0
+        };
+        if chunk.flags & REPLAY_GAP != 0 {
+            replay.gap(to_client, flags, chunk.len);
+            continue;
+        }
+        for data in chunk.data.chunks(max_chunk) {
+            replay.data(to_client, flags, data);
+        }
+    }
+    replay.summary()
+}
+
+/// Replay a corpus as recorded and re-chunked, checking the outcome is
+/// the same.
+fn replay_all(corpus: &[u8]) -> ReplaySummary {
+    let summary = replay(corpus, usize::MAX);
+    for max_chunk in [1, 7, 64] {
+        assert_eq!(
+            replay(corpus, max_chunk),
+            summary,
+            "chunks of {} bytes",
+            max_chunk
+        );
+    }
+    summary
+}
+
+#[test]
+fn test_ssh_replay_handshake() {
+    let summary = replay_all(include_bytes!("corpus/handshake.bin"));
+    assert_eq!(summary.client.progress, SshStateProgress::SshStateEncrypted);
+    assert_eq!(summary.server.progress, SshStateProgress::SshStateEncrypted);
+    assert!(!summary.client.failed && !summary.server.failed);
+    assert_eq!(summary.client.protover, b"2.0");
+    assert_eq!(summary.client.swver, b"OpenSSH_9.6");
+    assert_eq!(summary.server.swver, b"OpenSSH_8.9p1");
+    // KEXINIT, ECDH init or reply, NEWKEYS
+    assert_eq!(summary.client.kex_records, 3);
+    assert_eq!(summary.client.kex_bytes, 832);
+    assert_eq!(summary.server.kex_records, 3);
+    assert_eq!(summary.server.kex_bytes, 800);
+    assert_eq!(summary.kex, Some(b"curve25519-sha256".to_vec()));
+    assert_eq!(summary.host_key, Some(b"ssh-ed25519".to_vec()));
+}
+
+#[test]
+fn test_ssh_replay_midstream() {
+    let summary = replay_all(include_bytes!("corpus/midstream.bin"));
+    assert_eq!(summary.client.progress, SshStateProgress::SshStateEncrypted);
+    assert_eq!(summary.server.progress, SshStateProgress::SshStateEncrypted);
+    assert!(summary.client.protover.is_empty());
+    assert!(summary.server.protover.is_empty());
+    assert_eq!(summary.client.kex_records, 2);
+    assert_eq!(summary.server.kex_records, 2);
+    // the KEXINIT were missed
+    assert_eq!(summary.kex, None);
+}
+
+#[test]
+fn test_ssh_replay_gap() {
+    let summary = replay_all(include_bytes!("corpus/gap.bin"));
+    assert_eq!(summary.client.progress, SshStateProgress::SshStateEncrypted);
+    assert_eq!(summary.server.progress, SshStateProgress::SshStateEncrypted);
+    assert!(!summary.server.failed);
+    assert_eq!(summary.server.gaps.recovered, 1);
+    assert_eq!(summary.server.gaps.unrecoverable, 0);
+    assert!(summary.client.gaps.is_empty());
+    // the ECDH reply is counted from its header
+    assert_eq!(summary.server.kex_records, 3);
+    assert_eq!(summary.server.kex_bytes, 800);
+    assert_eq!(summary.kex, Some(b"curve25519-sha256".to_vec()));
+}
@@ -15,21 +15,53 @@
  * 02110-1301, USA.
  */
 
+use super::algorithms::{
+    ssh_banned_algorithms_configure, ssh_check_banned_algorithms, SshKexAlgorithms,
+    SshNegotiatedAlgorithms,
+};
+use super::learn::{ssh_hassh_learn_alerts, ssh_hassh_learn_configure};
 use super::parser;
+use super::timing::{ssh_timing_enable, ssh_timing_is_enabled, SshTiming};
 use crate::applayer::*;
+use crate::conf::{conf_get, conf_get_bool};
 use crate::core::*;
+use crate::datasets::{DatasetConfig, DatasetType};
+use crate::fingerprint::FlowFingerprints;
 use nom7::Err;
 use std::ffi::CString;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use crate::frames::Frame;
 
-static mut ALPROTO_SSH: AppProto = ALPROTO_UNKNOWN;
+pub(super) static mut ALPROTO_SSH: AppProto = ALPROTO_UNKNOWN;
 static HASSH_ENABLED: AtomicBool = AtomicBool::new(false);
+/// Datasets to add the client and server hassh to.
+static HASSH_DATASET: DatasetConfig = DatasetConfig::new();
+static HASSH_SERVER_DATASET: DatasetConfig = DatasetConfig::new();
 
 fn hassh_is_enabled() -> bool {
     HASSH_ENABLED.load(Ordering::Relaxed)
 }
 
+/// Log the hassh string next to the hash, on by default.
+static HASSH_STRING_LOG: AtomicBool = AtomicBool::new(true);
+
+/// Log the cookie of the KEXINIT, off by default.
+static KEX_COOKIE_LOG: AtomicBool = AtomicBool::new(false);
+
+/// Lines tolerated before the banner, and their max total length.
+static BANNER_SKIP_LINES: AtomicU32 = AtomicU32::new(0);
+static BANNER_SKIP_BYTES: AtomicU32 = AtomicU32::new(1024);
+/// Sessions picked up midstream are probed.
+static MIDSTREAM_PROBE: AtomicBool = AtomicBool::new(false);
+
+pub(super) fn hassh_string_log_is_enabled() -> bool {
+    HASSH_STRING_LOG.load(Ordering::Relaxed)
+}
+
+pub(super) fn kex_cookie_log_is_enabled() -> bool {
+    KEX_COOKIE_LOG.load(Ordering::Relaxed)
+}
+
 #[derive(AppLayerFrameType)]
 pub enum SshFrameType {
     RecordHdr,
@@ -43,31 +75,93 @@ pub enum SSHEvent {
     LongBanner,
     InvalidRecord,
     LongKexRecord,
+    KexinitTruncated,
+    HasshSkipped,
+    MissingBanner,
+    BannedKexAlgorithm,
+    BannedHostKeyAlgorithm,
+    BannedCipher,
+    BannedMac,
+    /// No cleartext record found after a gap in the key exchange.
+    UnrecoverableGap,
+    /// Client hassh added to its dataset for the first time.
+    NewHassh,
+    /// Server hassh added to its dataset for the first time.
+    NewHasshServer,
+    /// Lines skipped before the banner.
+    LinesBeforeBanner,
 }
 
+/// Progress of a direction of the connection. The values are also the
+/// transaction progress the detection engine inspects the buffers at.
 #[repr(u8)]
-#[derive(Copy, Clone, PartialOrd, PartialEq, Eq)]
-pub enum SSHConnectionState {
-    SshStateInProgress = 0,
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq, Eq)]
+pub enum SshStateProgress {
+    /// Waiting for the banner.
+    SshStateBanner = 0,
+    /// Long banner parsed, skipping to its end of line.
     SshStateBannerWaitEol = 1,
+    /// Banner parsed, the key exchange follows.
     SshStateBannerDone = 2,
-    SshStateFinished = 3,
+    /// KEXINIT seen, the hassh is available.
+    SshStateKexInit = 3,
+    /// Key exchange method specific messages seen.
+    SshStateKexDh = 4,
+    /// NEWKEYS seen, further records of the direction are encrypted.
+    SshStateNewKeys = 5,
+    /// Both directions switched to the new keys, nothing left to inspect.
+    SshStateEncrypted = 6,
 }
 
+/// First and last message codes of the key exchange method specific
+/// messages, e.g. KEXDH_INIT and KEXDH_REPLY.
+const SSH_MSG_KEX_METHOD_FIRST: u8 = 30;
+const SSH_MSG_KEX_METHOD_LAST: u8 = 49;
+
 pub const SSH_MAX_BANNER_LEN: usize = 256;
 const SSH_RECORD_HEADER_LEN: usize = 6;
 const SSH_MAX_REASSEMBLED_RECORD_LEN: usize = 65535;
+/// Data skipped looking for a cleartext record on a midstream pickup,
+/// or after a gap, before assuming the key exchange was missed.
+const SSH_MAX_RESYNC_LEN: u32 = 65535;
+/// Depth of the midstream probe, enough to get past a KEXINIT.
+const SSH_MIDSTREAM_PROBE_DEPTH: u16 = 2048;
 
 pub struct SshHeader {
     record_left: u32,
     record_left_msg: parser::MessageCode,
+    /// Bytes skipped looking for a record when the banner was missed,
+    /// or after a gap.
+    resync_skipped: u32,
+    /// Lines skipped before the banner, and their length.
+    banner_skipped_lines: u32,
+    banner_skipped_bytes: u32,
+    pub gaps: GapStats,
+    /// Cleartext records of the direction, up to and including the
+    /// NEWKEYS.
+    pub kex_records: u32,
+    /// Bytes of the cleartext records, headers included.
+    pub kex_bytes: u64,
 
-    flags: SSHConnectionState,
+    progress: SshStateProgress,
     pub protover: Vec<u8>,
     pub swver: Vec<u8>,
+    /// Comment following the software version in the banner.
+    pub comment: Vec<u8>,
+    /// Service name of the SERVICE_REQUEST of the client or the
+    /// SERVICE_ACCEPT of the server, if visible.
+    pub service: Vec<u8>,
 
     pub hassh: Vec<u8>,
     pub hassh_string: Vec<u8>,
+    /// Hassh generation was enabled but the KEXINIT could not be used.
+    pub hassh_skipped: bool,
+    /// The hassh was not in its dataset yet.
+    pub hassh_first_seen: bool,
+    /// Random cookie of the last KEXINIT.
+    pub kex_cookie: Vec<u8>,
+    /// Algorithms of the KEXINIT, for the negotiation with the other side.
+    kexinit: Option<SshKexAlgorithms>,
 }
 
 impl Default for SshHeader {
@@ -81,21 +175,275 @@ impl SshHeader {
         Self {
             record_left: 0,
             record_left_msg: parser::MessageCode::Undefined(0),
+            resync_skipped: 0,
+            banner_skipped_lines: 0,
+            banner_skipped_bytes: 0,
+            gaps: GapStats::default(),
+            kex_records: 0,
+            kex_bytes: 0,
 
-            flags: SSHConnectionState::SshStateInProgress,
+            progress: SshStateProgress::SshStateBanner,
             protover: Vec::new(),
             swver: Vec::new(),
+            comment: Vec::new(),
+            service: Vec::new(),
 
             hassh: Vec::new(),
             hassh_string: Vec::new(),
+            hassh_skipped: false,
+            hassh_first_seen: false,
+            kex_cookie: Vec::new(),
+            kexinit: None,
+        }
+    }
+
+    /// Parse the payload of a KEXINIT record, generating the hassh if
+    /// `hassh` is set and keeping the algorithms for the negotiation.
+    /// Returns true if the hassh was added to its dataset for the first
+    /// time.
+    fn parse_kexinit(
+        &mut self, input: &[u8], resp: bool, hassh: bool, events: &mut EventList,
+    ) -> bool {
+        match parser::ssh_parse_key_exchange(input) {
+            Ok((_, key_exchange)) => {
+                self.kexinit = Some(SshKexAlgorithms::new(&key_exchange));
+                self.kex_cookie = key_exchange.cookie.to_vec();
+                if !hassh {
+                    return false;
+                }
+                key_exchange.generate_hassh(&mut self.hassh_string, &mut self.hassh, &resp);
+                let dataset = if resp {
+                    &HASSH_SERVER_DATASET
+                } else {
+                    &HASSH_DATASET
+                };
+                if let Some(set) = dataset.find(&[DatasetType::Md5, DatasetType::String]) {
+                    if set.add_hex(&self.hassh) {
+                        self.hassh_first_seen = true;
+                        return true;
+                    }
+                }
+            }
+            Err(Err::Incomplete(_)) => {
+                SCLogDebug!("SSH KEXINIT truncated, skipping hassh");
+                events.set_event(SSHEvent::KexinitTruncated as u8);
+                if hassh {
+                    self.skip_hassh(events);
+                }
+            }
+            Err(_) => {
+                SCLogDebug!("SSH invalid KEXINIT, skipping hassh");
+                if hassh {
+                    self.skip_hassh(events);
+                }
+            }
+        }
+        false
+    }
+
+    /// Account for a record of `pkt_len` bytes, unless the direction
+    /// already switched to the new keys.
+    fn count_kex_record(&mut self, pkt_len: u32) {
+        if self.progress < SshStateProgress::SshStateNewKeys {
+            self.kex_records = self.kex_records.saturating_add(1);
+            self.kex_bytes = self.kex_bytes.saturating_add(pkt_len as u64 + 4);
+        }
+    }
+
+    fn skip_hassh(&mut self, events: &mut EventList) {
+        self.hassh_skipped = true;
+        events.set_event(SSHEvent::HasshSkipped as u8);
+    }
+
+    /// Advance the progress on a record of the direction. The progress
+    /// never goes back, e.g. on a key re-exchange.
+    fn update_progress(&mut self, msg_code: parser::MessageCode) {
+        let progress = match msg_code {
+            parser::MessageCode::Kexinit => SshStateProgress::SshStateKexInit,
+            parser::MessageCode::KexdhInit | parser::MessageCode::KexdhReply => {
+                SshStateProgress::SshStateKexDh
+            }
+            parser::MessageCode::Undefined(code)
+                if (SSH_MSG_KEX_METHOD_FIRST..=SSH_MSG_KEX_METHOD_LAST).contains(&code) =>
+            {
+                SshStateProgress::SshStateKexDh
+            }
+            parser::MessageCode::NewKeys => SshStateProgress::SshStateNewKeys,
+            _ => {
+                return;
+            }
+        };
+        if progress > self.progress {
+            self.progress = progress;
         }
     }
+
+    /// Progress of the direction as transaction progress: the banner is
+    /// only done once its end of line is seen.
+    fn tx_progress(&self) -> SshStateProgress {
+        if self.progress == SshStateProgress::SshStateBannerWaitEol {
+            return SshStateProgress::SshStateBanner;
+        }
+        self.progress
+    }
+}
+
+/// Update the progress of both directions after a record of the direction
+/// of `hdr`. Returns true when both directions have become encrypted.
+///
+/// With the authentication timing, both directions stay at the NEWKEYS
+/// progress until the first encrypted bursts are seen, see
+/// `SSHState::parse_encrypted`.
+fn ssh_update_progress(
+    hdr: &mut SshHeader, ohdr: &mut SshHeader, msg_code: parser::MessageCode,
+) -> bool {
+    hdr.update_progress(msg_code);
+    if hdr.progress == SshStateProgress::SshStateNewKeys
+        && ohdr.progress == SshStateProgress::SshStateNewKeys
+        && !ssh_timing_is_enabled()
+    {
+        hdr.progress = SshStateProgress::SshStateEncrypted;
+        ohdr.progress = SshStateProgress::SshStateEncrypted;
+        return true;
+    }
+    false
+}
+
+/// Parse a KEXINIT record of the direction of `hdr` and, once the
+/// KEXINIT of both sides is known, negotiate the algorithms and check
+/// them. On a key re-exchange the negotiation is redone. If `learn` is
+/// set, a hassh new to its dataset raises an event.
+#[allow(clippy::too_many_arguments)]
+fn ssh_parse_kexinit(
+    hdr: &mut SshHeader, ohdr: &SshHeader, input: &[u8], resp: bool, events: &mut EventList,
+    negotiated: &mut Option<SshNegotiatedAlgorithms>, banned: &mut Vec<Vec<u8>>, learn: bool,
+) {
+    if hdr.parse_kexinit(input, resp, hassh_is_enabled(), events) && learn {
+        let event = if resp {
+            SSHEvent::NewHasshServer
+        } else {
+            SSHEvent::NewHassh
+        };
+        events.set_event(event as u8);
+    }
+    if let (Some(kex), Some(okex)) = (&hdr.kexinit, &ohdr.kexinit) {
+        let (client, server) = if resp { (okex, kex) } else { (kex, okex) };
+        let algs = SshNegotiatedAlgorithms::new(client, server);
+        ssh_check_banned_algorithms(&algs, events, banned);
+        *negotiated = Some(algs);
+    }
+}
+
+/// Keep the service name of a SERVICE_REQUEST of the client or a
+/// SERVICE_ACCEPT of the server. These follow the NEWKEYS, so are only
+/// visible with the "none" cipher, or from implementations sending them
+/// before.
+fn ssh_record_service(
+    hdr: &mut SshHeader, input: &[u8], resp: bool, negotiated: &Option<SshNegotiatedAlgorithms>,
+) {
+    if hdr.progress >= SshStateProgress::SshStateNewKeys {
+        let cipher = negotiated.as_ref().and_then(|algs| {
+            if resp {
+                algs.cipher_stc.as_ref()
+            } else {
+                algs.cipher_cts.as_ref()
+            }
+        });
+        if cipher.map(|c| c.as_slice()) != Some(b"none") {
+            return;
+        }
+    }
+    if let Ok((_, service)) = parser::ssh_parse_service(input) {
+        hdr.service = service.to_vec();
+    }
+}
+
+/// Nothing is left to inspect once both directions are encrypted.
+fn ssh_set_bypass_ready(pstate: *mut std::os::raw::c_void) {
+    // no parser state when replaying streams in unit tests
+    if pstate.is_null() {
+        return;
+    }
+    unsafe {
+        AppLayerParserStateSetFlag(
+            pstate,
+            APP_LAYER_PARSER_NO_INSPECTION
+            | APP_LAYER_PARSER_NO_REASSEMBLY
+            | APP_LAYER_PARSER_BYPASS_READY,
+        );
+    }
+}
+
+/// Check if the input can be the start of a banner.
+fn ssh_is_banner_start(input: &[u8]) -> bool {
+    let len = std::cmp::min(input.len(), 4);
+    input[..len] == b"SSH-"[..len]
+}
+
+/// Check if the input has a banner after at most `max_lines` lines of
+/// `max_bytes` in total. Returns None if more data is needed.
+fn ssh_probe_banner(input: &[u8], max_lines: u32, max_bytes: u32) -> Option<bool> {
+    let mut rem = input;
+    let mut lines = 0;
+    loop {
+        if ssh_is_banner_start(rem) {
+            return if rem.len() >= 4 { Some(true) } else { None };
+        }
+        if lines == max_lines {
+            return Some(false);
+        }
+        match parser::ssh_parse_line(rem) {
+            Ok((next, _)) => {
+                rem = next;
+                lines += 1;
+            }
+            Err(Err::Incomplete(_)) => {
+                return if input.len() > max_bytes as usize {
+                    Some(false)
+                } else {
+                    None
+                };
+            }
+            Err(_) => {
+                return Some(false);
+            }
+        }
+        if input.len() - rem.len() > max_bytes as usize {
+            return Some(false);
+        }
+    }
+}
+
+/// Find the offset of the first header of a cleartext record.
+fn ssh_find_record(input: &[u8]) -> Option<usize> {
+    let last = input.len().saturating_sub(SSH_RECORD_HEADER_LEN - 1);
+    (0..last).find(|&offset| {
+        matches!(parser::ssh_parse_record_header(&input[offset..]),
+            Ok((_, head)) if head.is_cleartext())
+    })
+}
+
+/// Find the first cleartext record of a session picked up midstream,
+/// anywhere in the input if `search` is set, else only at its start.
+fn ssh_midstream_record(input: &[u8], search: bool) -> Option<usize> {
+    if search {
+        return ssh_find_record(input);
+    }
+    match parser::ssh_parse_record_header(input) {
+        Ok((_, head)) if head.is_cleartext() => Some(0),
+        _ => None,
+    }
 }
 
 #[derive(Default)]
 pub struct SSHTransaction {
     pub srv_hdr: SshHeader,
     pub cli_hdr: SshHeader,
+    /// Algorithms negotiated from the KEXINIT of both sides.
+    pub negotiated: Option<SshNegotiatedAlgorithms>,
+    /// Negotiated algorithms matching the banned algorithms.
+    pub banned_algorithms: Vec<Vec<u8>>,
+    pub timing: SshTiming,
 
     tx_data: AppLayerTxData,
 }
@@ -104,6 +452,11 @@ pub struct SSHTransaction {
 pub struct SSHState {
     state_data: AppLayerStateData,
     transaction: SSHTransaction,
+    /// Time of the data being parsed in microseconds, only set with
+    /// the authentication timing.
+    ts: u64,
+    /// Destination port of the flow.
+    dp: Option<u16>,
 }
 
 impl SSHState {
@@ -112,17 +465,62 @@ impl SSHState {
     }
 
     fn set_event(&mut self, event: SSHEvent) {
-        self.transaction.tx_data.set_event(event as u8);
+        self.transaction.tx_data.events.set_event(event as u8);
+    }
+
+    /// Keep the time at which a direction got its banner, KEXINIT and
+    /// NEWKEYS. Without a banner, e.g. when picked up midstream, the
+    /// progress does not tell when the KEXINIT was seen.
+    fn update_timing(&mut self, resp: bool) {
+        let tx = &mut self.transaction;
+        let (hdr, timing) = if !resp {
+            (&tx.cli_hdr, &mut tx.timing.client)
+        } else {
+            (&tx.srv_hdr, &mut tx.timing.server)
+        };
+        if timing.banner.is_none() && !hdr.protover.is_empty() {
+            timing.banner = Some(self.ts);
+        }
+        if timing.kexinit.is_none()
+            && timing.banner.is_some()
+            && hdr.progress >= SshStateProgress::SshStateKexInit
+        {
+            timing.kexinit = Some(self.ts);
+        }
+        if timing.newkeys.is_none() && hdr.progress >= SshStateProgress::SshStateNewKeys {
+            timing.newkeys = Some(self.ts);
+        }
+    }
+
+    /// Account for `len` bytes of encrypted data of a direction that
+    /// switched to the new keys. Once the timing is complete, both
+    /// directions become encrypted and the flow can be bypassed.
+    fn parse_encrypted(
+        &mut self, resp: bool, len: usize, pstate: *mut std::os::raw::c_void,
+    ) -> AppLayerResult {
+        let tx = &mut self.transaction;
+        tx.timing.encrypted_data(resp, self.ts, len);
+        if tx.timing.is_complete()
+            && tx.cli_hdr.progress == SshStateProgress::SshStateNewKeys
+            && tx.srv_hdr.progress == SshStateProgress::SshStateNewKeys
+        {
+            SCLogDebug!("SSH authentication timing complete");
+            tx.cli_hdr.progress = SshStateProgress::SshStateEncrypted;
+            tx.srv_hdr.progress = SshStateProgress::SshStateEncrypted;
+            ssh_set_bypass_ready(pstate);
+        }
+        return AppLayerResult::ok();
     }
 
     fn parse_record(
         &mut self, mut input: &[u8], resp: bool, pstate: *mut std::os::raw::c_void,
         flow: *const Flow, stream_slice: &StreamSlice,
     ) -> AppLayerResult {
+        let learn = ssh_hassh_learn_alerts(self.dp);
         let (hdr, ohdr) = if !resp {
-            (&mut self.transaction.cli_hdr, &self.transaction.srv_hdr)
+            (&mut self.transaction.cli_hdr, &mut self.transaction.srv_hdr)
         } else {
-            (&mut self.transaction.srv_hdr, &self.transaction.cli_hdr)
+            (&mut self.transaction.srv_hdr, &mut self.transaction.cli_hdr)
         };
         let il = input.len();
         //first skip record left bytes
@@ -134,27 +532,27 @@ impl SSHState {
                 return AppLayerResult::ok();
             } else {
                 let start = hdr.record_left as usize;
-                match hdr.record_left_msg {
-                    // parse reassembled tcp segments
-                    parser::MessageCode::Kexinit if hassh_is_enabled() => {
-                        if let Ok((_rem, key_exchange)) =
-                            parser::ssh_parse_key_exchange(&input[..start])
-                        {
-                            key_exchange.generate_hassh(
-                                &mut hdr.hassh_string,
-                                &mut hdr.hassh,
-                                &resp,
-                            );
-                        }
-                        hdr.record_left_msg = parser::MessageCode::Undefined(0);
-                    }
-                    _ => {}
+                // parse reassembled tcp segments
+                if hdr.record_left_msg == parser::MessageCode::Kexinit {
+                    ssh_parse_kexinit(
+                        hdr,
+                        ohdr,
+                        &input[..start],
+                        resp,
+                        &mut self.transaction.tx_data.events,
+                        &mut self.transaction.negotiated,
+                        &mut self.transaction.banned_algorithms,
+                        learn,
+                    );
+                    hdr.record_left_msg = parser::MessageCode::Undefined(0);
+                    hdr.update_progress(parser::MessageCode::Kexinit);
                 }
                 input = &input[start..];
                 hdr.record_left = 0;
             }
         }
         //parse records out of input
+        let mut encrypted_len = 0;
         while !input.is_empty() {
             match parser::ssh_parse_record(input) {
                 Ok((rem, head)) => {
@@ -183,28 +581,44 @@ impl SSHState {
                         Some(0),
                     );
                     SCLogDebug!("SSH valid record {}", head);
-                    match head.msg_code {
-                        parser::MessageCode::Kexinit if hassh_is_enabled() => {
-                            //let endkex = SSH_RECORD_HEADER_LEN + head.pkt_len - 2;
-                            let endkex = input.len() - rem.len();
-                            if let Ok((_, key_exchange)) = parser::ssh_parse_key_exchange(&input[SSH_RECORD_HEADER_LEN..endkex]) {
-                                key_exchange.generate_hassh(&mut hdr.hassh_string, &mut hdr.hassh, &resp);
-                            }
-                        }
-                        parser::MessageCode::NewKeys => {
-                            hdr.flags = SSHConnectionState::SshStateFinished;
-                            if ohdr.flags >= SSHConnectionState::SshStateFinished {
-                                unsafe {
-                                    AppLayerParserStateSetFlag(
-                                        pstate,
-                                        APP_LAYER_PARSER_NO_INSPECTION
-                                        | APP_LAYER_PARSER_NO_REASSEMBLY
-                                        | APP_LAYER_PARSER_BYPASS_READY,
-                                    );
-                                }
-                            }
-                        }
-                        _ => {}
+                    hdr.count_kex_record(head.pkt_len);
+                    if head.msg_code == parser::MessageCode::Kexinit {
+                        //let endkex = SSH_RECORD_HEADER_LEN + head.pkt_len - 2;
+                        let endkex = input.len() - rem.len();
+                        ssh_parse_kexinit(
+                            hdr,
+                            ohdr,
+                            &input[SSH_RECORD_HEADER_LEN..endkex],
+                            resp,
+                            &mut self.transaction.tx_data.events,
+                            &mut self.transaction.negotiated,
+                            &mut self.transaction.banned_algorithms,
+                            learn,
+                        );
+                    }
+                    let service_msg = if resp {
+                        parser::MessageCode::ServiceAccept
+                    } else {
+                        parser::MessageCode::ServiceRequest
+                    };
+                    if head.msg_code == service_msg {
+                        let end = input.len() - rem.len();
+                        ssh_record_service(
+                            hdr,
+                            &input[SSH_RECORD_HEADER_LEN..end],
+                            resp,
+                            &self.transaction.negotiated,
+                        );
+                    }
+                    if ssh_update_progress(hdr, ohdr, head.msg_code) {
+                        ssh_set_bypass_ready(pstate);
+                    }
+                    if ssh_timing_is_enabled()
+                        && hdr.progress >= SshStateProgress::SshStateNewKeys
+                    {
+                        // what follows the NEWKEYS is encrypted
+                        encrypted_len = rem.len();
+                        break;
                     }
                     
                     input = rem;
@@ -239,30 +653,32 @@ impl SSHState {
                                 Some(0),
                             );
                             SCLogDebug!("SSH valid record header {}", head);
+                            hdr.count_kex_record(head.pkt_len);
                             let remlen = rem.len() as u32;
                             hdr.record_left = head.pkt_len - 2 - remlen;
                             //header with rem as incomplete data
-                            match head.msg_code { 
-                                parser::MessageCode::NewKeys => {
-                                    hdr.flags = SSHConnectionState::SshStateFinished;
+                            if head.msg_code == parser::MessageCode::Kexinit {
+                                // check if buffer is bigger than maximum reassembled packet size
+                                hdr.record_left = head.pkt_len - 2;
+                                if hdr.record_left < SSH_MAX_REASSEMBLED_RECORD_LEN as u32 {
+                                    // saving type of incomplete kex message,
+                                    // progress is updated once the hassh is generated
+                                    hdr.record_left_msg = parser::MessageCode::Kexinit;
+                                    return AppLayerResult::incomplete(
+                                        (il - rem.len()) as u32,
+                                        head.pkt_len - 2
+                                    );
                                 }
-                                parser::MessageCode::Kexinit if hassh_is_enabled() => {
-                                    // check if buffer is bigger than maximum reassembled packet size
-                                    hdr.record_left = head.pkt_len - 2;
-                                    if hdr.record_left < SSH_MAX_REASSEMBLED_RECORD_LEN as u32 {
-                                        // saving type of incomplete kex message
-                                        hdr.record_left_msg = parser::MessageCode::Kexinit;
-                                        return AppLayerResult::incomplete(
-                                            (il - rem.len()) as u32,
-                                            head.pkt_len - 2
-                                        );
-                                    }
-                                    else {
-                                        SCLogDebug!("SSH buffer is bigger than maximum reassembled packet size");
-                                        self.set_event(SSHEvent::LongKexRecord);
+                                else {
+                                    SCLogDebug!("SSH buffer is bigger than maximum reassembled packet size");
+                                    if hassh_is_enabled() {
+                                        hdr.skip_hassh(&mut self.transaction.tx_data.events);
                                     }
+                                    self.transaction.tx_data.events.set_event(SSHEvent::LongKexRecord as u8);
                                 }
-                                _ => {}
+                            }
+                            if ssh_update_progress(hdr, ohdr, head.msg_code) {
+                                ssh_set_bypass_ready(pstate);
                             }
                             return AppLayerResult::ok();
                         }
@@ -289,19 +705,182 @@ impl SSHState {
                 }
             }
         }
+        if encrypted_len > 0 {
+            return self.parse_encrypted(resp, encrypted_len, pstate);
+        }
+        return AppLayerResult::ok();
+    }
+
+    /// Handle a gap of `gap_size` bytes in a direction. A gap in the
+    /// encrypted data or within a record being skipped leaves the
+    /// parsing in sync, otherwise the next data is searched for a
+    /// record.
+    fn parse_gap(&mut self, resp: bool, gap_size: u32) -> AppLayerResult {
+        let hdr = if !resp {
+            &mut self.transaction.cli_hdr
+        } else {
+            &mut self.transaction.srv_hdr
+        };
+        SCLogDebug!("SSH gap of {} bytes, progress {:?}", gap_size, hdr.progress);
+        hdr.gaps.gap();
+        if hdr.progress >= SshStateProgress::SshStateNewKeys {
+            hdr.gaps.resync();
+            return AppLayerResult::ok();
+        }
+        let reassembling = hdr.record_left_msg == parser::MessageCode::Kexinit;
+        if hdr.record_left > gap_size && !reassembling {
+            hdr.record_left -= gap_size;
+            hdr.gaps.resync();
+            return AppLayerResult::ok();
+        }
+        if reassembling && hassh_is_enabled() {
+            hdr.skip_hassh(&mut self.transaction.tx_data.events);
+        }
+        hdr.record_left = 0;
+        hdr.record_left_msg = parser::MessageCode::Undefined(0);
+        hdr.resync_skipped = 0;
         return AppLayerResult::ok();
     }
 
+    /// Parse data of a direction whose banner was not seen because the
+    /// session was picked up midstream, or whose record boundaries were
+    /// lost in a gap: skip to the first cleartext record and continue
+    /// from there.
+    fn parse_resync(
+        &mut self, input: &[u8], resp: bool, pstate: *mut std::os::raw::c_void,
+        flow: *const Flow, stream_slice: &StreamSlice,
+    ) -> AppLayerResult {
+        let (hdr, ohdr) = if !resp {
+            (&mut self.transaction.cli_hdr, &mut self.transaction.srv_hdr)
+        } else {
+            (&mut self.transaction.srv_hdr, &mut self.transaction.cli_hdr)
+        };
+        if let Some(offset) = ssh_find_record(input) {
+            SCLogDebug!("SSH resync, record found at offset {}", offset);
+            hdr.gaps.resync();
+            if hdr.progress < SshStateProgress::SshStateBannerDone {
+                hdr.progress = SshStateProgress::SshStateBannerDone;
+                self.set_event(SSHEvent::MissingBanner);
+            }
+            let mut r = self.parse_record(&input[offset..], resp, pstate, flow, stream_slice);
+            if r.is_incomplete() {
+                r.consumed += offset as u32;
+            }
+            return r;
+        }
+        // keep the bytes that may be the start of a header
+        let consumed = input.len().saturating_sub(SSH_RECORD_HEADER_LEN - 1);
+        hdr.resync_skipped = hdr.resync_skipped.saturating_add(consumed as u32);
+        if hdr.resync_skipped > SSH_MAX_RESYNC_LEN {
+            SCLogDebug!("SSH resync, no cleartext record, assuming encrypted");
+            if hdr.gaps.is_pending() {
+                hdr.gaps.give_up();
+                self.transaction.tx_data.events.set_event(SSHEvent::UnrecoverableGap as u8);
+            }
+            if hdr.progress < SshStateProgress::SshStateBannerDone {
+                hdr.progress = SshStateProgress::SshStateBannerDone;
+                self.transaction.tx_data.events.set_event(SSHEvent::MissingBanner as u8);
+            }
+            if ssh_update_progress(hdr, ohdr, parser::MessageCode::NewKeys) {
+                ssh_set_bypass_ready(pstate);
+            }
+            return AppLayerResult::ok();
+        }
+        return AppLayerResult::incomplete(consumed as u32, SSH_RECORD_HEADER_LEN as u32);
+    }
+
+    /// Skip the lines sent before the banner, RFC 4253 section 4.2, up to
+    /// `max_lines` lines of `max_bytes` in total. Returns the length of
+    /// the lines skipped, or the result of the parsing if the banner was
+    /// not reached.
+    fn skip_banner_lines(
+        &mut self, input: &[u8], resp: bool, max_lines: u32, max_bytes: u32,
+    ) -> Result<usize, AppLayerResult> {
+        let tx = &mut self.transaction;
+        let hdr = if !resp {
+            &mut tx.cli_hdr
+        } else {
+            &mut tx.srv_hdr
+        };
+        let mut rem = input;
+        while !ssh_is_banner_start(rem) {
+            match parser::ssh_parse_line(rem) {
+                Ok((next, _)) => {
+                    let len = (rem.len() - next.len()) as u32;
+                    hdr.banner_skipped_lines += 1;
+                    hdr.banner_skipped_bytes = hdr.banner_skipped_bytes.saturating_add(len);
+                    if hdr.banner_skipped_lines > max_lines || hdr.banner_skipped_bytes > max_bytes
+                    {
+                        SCLogDebug!("SSH too many lines before the banner");
+                        tx.tx_data.events.set_event(SSHEvent::InvalidBanner as u8);
+                        return Err(AppLayerResult::err());
+                    }
+                    if hdr.banner_skipped_lines == 1 {
+                        let event = SSHEvent::LinesBeforeBanner as u8;
+                        tx.tx_data.events.set_event(event);
+                    }
+                    rem = next;
+                }
+                Err(Err::Incomplete(_)) => {
+                    let len = hdr.banner_skipped_bytes as usize + rem.len();
+                    if len > max_bytes as usize {
+                        SCLogDebug!("SSH line before the banner too long");
+                        tx.tx_data.events.set_event(SSHEvent::InvalidBanner as u8);
+                        return Err(AppLayerResult::err());
+                    }
+                    let consumed = input.len() - rem.len();
+                    return Err(AppLayerResult::incomplete(
+                        consumed as u32,
+                        (rem.len() + 1) as u32,
+                    ));
+                }
+                Err(_e) => {
+                    SCLogDebug!("SSH invalid line before the banner {}", _e);
+                    tx.tx_data.events.set_event(SSHEvent::InvalidBanner as u8);
+                    return Err(AppLayerResult::err());
+                }
+            }
+        }
+        return Ok(input.len() - rem.len());
+    }
+
     fn parse_banner(
         &mut self, input: &[u8], resp: bool, pstate: *mut std::os::raw::c_void,
         flow: *const Flow, stream_slice: &StreamSlice,
     ) -> AppLayerResult {
+        let progress = if !resp {
+            self.transaction.cli_hdr.progress
+        } else {
+            self.transaction.srv_hdr.progress
+        };
+        if stream_slice.flags() & STREAM_MIDSTREAM != 0
+            && progress == SshStateProgress::SshStateBanner
+            && !ssh_is_banner_start(input)
+        {
+            return self.parse_resync(input, resp, pstate, flow, stream_slice);
+        }
+        let max_lines = BANNER_SKIP_LINES.load(Ordering::Relaxed);
+        if max_lines > 0
+            && progress == SshStateProgress::SshStateBanner
+            && !ssh_is_banner_start(input)
+        {
+            let max_bytes = BANNER_SKIP_BYTES.load(Ordering::Relaxed);
+            let skipped = match self.skip_banner_lines(input, resp, max_lines, max_bytes) {
+                Ok(skipped) => skipped,
+                Err(r) => return r,
+            };
+            let mut r = self.parse_banner(&input[skipped..], resp, pstate, flow, stream_slice);
+            if r.is_incomplete() {
+                r.consumed += skipped as u32;
+            }
+            return r;
+        }
         let hdr = if !resp {
             &mut self.transaction.cli_hdr
         } else {
             &mut self.transaction.srv_hdr
         };
-        if hdr.flags == SSHConnectionState::SshStateBannerWaitEol {
+        if hdr.progress == SshStateProgress::SshStateBannerWaitEol {
             match parser::ssh_parse_line(input) {
                 Ok((rem, _)) => {
                     let mut r = self.parse_record(rem, resp, pstate, flow, stream_slice);
@@ -330,7 +909,8 @@ impl SSHState {
                     if !banner.swver.is_empty() {
                         hdr.swver.extend(banner.swver);
                     }
-                    hdr.flags = SSHConnectionState::SshStateBannerDone;
+                    hdr.comment.extend(banner.comment);
+                    hdr.progress = SshStateProgress::SshStateBannerDone;
                 } else {
                     SCLogDebug!("SSH invalid banner");
                     self.set_event(SSHEvent::InvalidBanner);
@@ -366,7 +946,8 @@ impl SSHState {
                         if !banner.swver.is_empty() {
                             hdr.swver.extend(banner.swver);
                         }
-                        hdr.flags = SSHConnectionState::SshStateBannerWaitEol;
+                        hdr.comment.extend(banner.comment);
+                        hdr.progress = SshStateProgress::SshStateBannerWaitEol;
                         self.set_event(SSHEvent::LongBanner);
                         return AppLayerResult::ok();
                     } else {
@@ -406,20 +987,57 @@ pub extern "C" fn rs_ssh_state_tx_free(_state: *mut std::os::raw::c_void, _tx_id
     //do nothing
 }
 
+unsafe fn ssh_parse(
+    flow: *const Flow, state: &mut SSHState, pstate: *mut std::os::raw::c_void,
+    stream_slice: &StreamSlice, resp: bool,
+) -> AppLayerResult {
+    if stream_slice.is_gap() {
+        return state.parse_gap(resp, stream_slice.gap_size());
+    }
+    // no flow when replaying streams in unit tests
+    if state.dp.is_none() && !flow.is_null() {
+        let f = cast_pointer!(flow, Flow);
+        state.dp = Some(f.get_ports().1);
+    }
+    let buf = stream_slice.as_slice();
+    let hdr = if !resp {
+        &state.transaction.cli_hdr
+    } else {
+        &state.transaction.srv_hdr
+    };
+    if hdr.gaps.is_pending() {
+        return state.parse_resync(buf, resp, pstate, flow, stream_slice);
+    }
+    let progress = hdr.progress;
+    if !ssh_timing_is_enabled() {
+        if progress < SshStateProgress::SshStateBannerDone {
+            return state.parse_banner(buf, resp, pstate, flow, stream_slice);
+        } else {
+            return state.parse_record(buf, resp, pstate, flow, stream_slice);
+        }
+    }
+
+    let f = cast_pointer!(flow, Flow);
+    state.ts = f.get_last_time().as_micros() as u64;
+    let r = if progress < SshStateProgress::SshStateBannerDone {
+        state.parse_banner(buf, resp, pstate, flow, stream_slice)
+    } else if progress >= SshStateProgress::SshStateNewKeys {
+        state.parse_encrypted(resp, buf.len(), pstate)
+    } else {
+        state.parse_record(buf, resp, pstate, flow, stream_slice)
+    };
+    state.update_timing(resp);
+    return r;
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_ssh_parse_request(
     flow: *const Flow, state: *mut std::os::raw::c_void, pstate: *mut std::os::raw::c_void,
     stream_slice: StreamSlice,
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
-    let state = &mut cast_pointer!(state, SSHState);
-    let buf = stream_slice.as_slice();
-    let hdr = &mut state.transaction.cli_hdr;
-    if hdr.flags < SSHConnectionState::SshStateBannerDone {
-        return state.parse_banner(buf, false, pstate, flow, &stream_slice);
-    } else {
-        return state.parse_record(buf, false, pstate, flow, &stream_slice);
-    }
+    let state = cast_pointer!(state, SSHState);
+    return ssh_parse(flow, state, pstate, &stream_slice, false);
 }
 
 #[no_mangle]
@@ -428,14 +1046,8 @@ pub unsafe extern "C" fn rs_ssh_parse_response(
     stream_slice: StreamSlice,
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
-    let state = &mut cast_pointer!(state, SSHState);
-    let buf = stream_slice.as_slice();
-    let hdr = &mut state.transaction.srv_hdr;
-    if hdr.flags < SSHConnectionState::SshStateBannerDone {
-        return state.parse_banner(buf, true, pstate, flow, &stream_slice);
-    } else {
-        return state.parse_record(buf, true, pstate, flow, &stream_slice);
-    }
+    let state = cast_pointer!(state, SSHState);
+    return ssh_parse(flow, state, pstate, &stream_slice, true);
 }
 
 #[no_mangle]
@@ -454,12 +1066,12 @@ pub extern "C" fn rs_ssh_state_get_tx_count(_state: *mut std::os::raw::c_void) -
 #[no_mangle]
 pub unsafe extern "C" fn rs_ssh_tx_get_flags(
     tx: *mut std::os::raw::c_void, direction: u8,
-) -> SSHConnectionState {
+) -> SshStateProgress {
     let tx = cast_pointer!(tx, SSHTransaction);
     if direction == Direction::ToServer.into() {
-        return tx.cli_hdr.flags;
+        return tx.cli_hdr.progress;
     } else {
-        return tx.srv_hdr.flags;
+        return tx.srv_hdr.progress;
     }
 }
 
@@ -469,33 +1081,112 @@ pub unsafe extern "C" fn rs_ssh_tx_get_alstate_progress(
 ) -> std::os::raw::c_int {
     let tx = cast_pointer!(tx, SSHTransaction);
 
-    if tx.cli_hdr.flags >= SSHConnectionState::SshStateFinished
-        && tx.srv_hdr.flags >= SSHConnectionState::SshStateFinished
-    {
-        return SSHConnectionState::SshStateFinished as i32;
+    if direction == Direction::ToServer.into() {
+        return tx.cli_hdr.tx_progress() as i32;
+    } else {
+        return tx.srv_hdr.tx_progress() as i32;
     }
+}
 
-    if direction == Direction::ToServer.into() {
-        if tx.cli_hdr.flags >= SSHConnectionState::SshStateBannerDone {
-            return SSHConnectionState::SshStateBannerDone as i32;
+/// Probe the flows to the detection ports for banners preceded by other
+/// lines, and for sessions picked up midstream if enabled.
+unsafe extern "C" fn ssh_probe(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    ssh_probe_common(direction, input, input_len, rdir, true)
+}
+
+/// Probe the flows to any port, if enabled. A session picked up
+/// midstream has to start with a cleartext record: looking for one in
+/// the data would match too much of the traffic of other protocols.
+unsafe extern "C" fn ssh_probe_any_port(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    ssh_probe_common(direction, input, input_len, rdir, false)
+}
+
+unsafe fn ssh_probe_common(
+    direction: u8, input: *const u8, input_len: u32, rdir: *mut u8, search: bool,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    let banner = ssh_probe_banner(
+        slice,
+        BANNER_SKIP_LINES.load(Ordering::Relaxed),
+        BANNER_SKIP_BYTES.load(Ordering::Relaxed),
+    );
+    if banner == Some(true) {
+        return ALPROTO_SSH;
+    }
+    if MIDSTREAM_PROBE.load(Ordering::Relaxed) {
+        let alproto = ssh_probe_midstream(direction, slice, rdir, search);
+        if alproto != ALPROTO_FAILED || banner.is_some() {
+            return alproto;
         }
-    } else if tx.srv_hdr.flags >= SSHConnectionState::SshStateBannerDone {
-        return SSHConnectionState::SshStateBannerDone as i32;
+    } else if banner.is_some() {
+        return ALPROTO_FAILED;
     }
+    return ALPROTO_UNKNOWN;
+}
 
-    return SSHConnectionState::SshStateInProgress as i32;
+/// Probe for sessions picked up midstream. A banner is accepted in
+/// either direction. Without a banner the data has to have a cleartext
+/// record, see ssh_midstream_record, the direction is set from its
+/// message if it is only sent by one side.
+unsafe fn ssh_probe_midstream(
+    direction: u8, slice: &[u8], rdir: *mut u8, search: bool,
+) -> AppProto {
+    if slice.len() < SSH_RECORD_HEADER_LEN {
+        return ALPROTO_UNKNOWN;
+    }
+    if slice.starts_with(b"SSH-") {
+        return ALPROTO_SSH;
+    }
+    if let Some(offset) = ssh_midstream_record(slice, search) {
+        if let Ok((_, head)) = parser::ssh_parse_record_header(&slice[offset..]) {
+            if let Some(to_server) = head.to_server() {
+                let dir = if to_server {
+                    Direction::ToServer
+                } else {
+                    Direction::ToClient
+                };
+                if (direction & DIR_BOTH) != dir as u8 {
+                    *rdir = dir as u8;
+                }
+            }
+        }
+        return ALPROTO_SSH;
+    }
+    if search && slice.len() < SSH_MIDSTREAM_PROBE_DEPTH as usize {
+        return ALPROTO_UNKNOWN;
+    }
+    return ALPROTO_FAILED;
 }
 
 // Parser name as a C style string.
 const PARSER_NAME: &[u8] = b"ssh\0";
 
+fn ssh_banner_tolerance_conf(name: &str, value: &AtomicU32) {
+    let key = format!("app-layer.protocols.ssh.banner-tolerance.{}", name);
+    if let Some(val) = conf_get(&key) {
+        if let Ok(v) = val.parse::<u32>() {
+            value.store(v, Ordering::Relaxed);
+        } else {
+            SCLogError!("Invalid value for ssh.banner-tolerance.{}", name);
+        }
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn rs_ssh_register_parser() {
     let parser = RustParser {
         name: PARSER_NAME.as_ptr() as *const std::os::raw::c_char,
         default_port: std::ptr::null(),
         ipproto: IPPROTO_TCP,
-        //simple patterns, no probing
+        // patterns, the probing parsers are registered with a depth
+        // depending on the configuration
         probe_ts: None,
         probe_tc: None,
         min_depth: 0,
@@ -507,8 +1198,8 @@ pub unsafe extern "C" fn rs_ssh_register_parser() {
         parse_tc: rs_ssh_parse_response,
         get_tx_count: rs_ssh_state_get_tx_count,
         get_tx: rs_ssh_state_get_tx,
-        tx_comp_st_ts: SSHConnectionState::SshStateFinished as i32,
-        tx_comp_st_tc: SSHConnectionState::SshStateFinished as i32,
+        tx_comp_st_ts: SshStateProgress::SshStateEncrypted as i32,
+        tx_comp_st_tc: SshStateProgress::SshStateEncrypted as i32,
         tx_get_progress: rs_ssh_tx_get_alstate_progress,
         get_eventinfo: Some(SSHEvent::get_event_info),
         get_eventinfo_byid: Some(SSHEvent::get_event_info_by_id),
@@ -519,7 +1210,7 @@ pub unsafe extern "C" fn rs_ssh_register_parser() {
         get_tx_data: rs_ssh_get_tx_data,
         get_state_data: rs_ssh_get_state_data,
         apply_tx_config: None,
-        flags: 0,
+        flags: APP_LAYER_PARSER_OPT_ACCEPT_GAPS,
         get_frame_id_by_name: Some(SshFrameType::ffi_id_from_name),
         get_frame_name_by_id: Some(SshFrameType::ffi_name_from_id),
     };
@@ -529,10 +1220,65 @@ pub unsafe extern "C" fn rs_ssh_register_parser() {
     if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_SSH = alproto;
+        ssh_banner_tolerance_conf("lines", &BANNER_SKIP_LINES);
+        ssh_banner_tolerance_conf("bytes", &BANNER_SKIP_BYTES);
+        MIDSTREAM_PROBE.store(conf_get_bool("stream.midstream"), Ordering::Relaxed);
+        let mut probe_depth = 0;
+        if MIDSTREAM_PROBE.load(Ordering::Relaxed) {
+            probe_depth = SSH_MIDSTREAM_PROBE_DEPTH;
+        }
+        if BANNER_SKIP_LINES.load(Ordering::Relaxed) > 0 {
+            // enough to see the start of the banner after the lines
+            let depth = BANNER_SKIP_BYTES.load(Ordering::Relaxed).saturating_add(4);
+            probe_depth = std::cmp::max(probe_depth, std::cmp::min(depth, u16::MAX as u32) as u16);
+        }
+        if probe_depth > 0 {
+            applayer_register_probing_ports(
+                IPPROTO_TCP,
+                parser.name,
+                ALPROTO_SSH,
+                0,
+                probe_depth,
+                ssh_probe,
+                ssh_probe,
+                Some("22"),
+            );
+            if conf_get_bool("app-layer.protocols.ssh.detection-any-port") {
+                // port 0 runs the probing parser on any port
+                let port = CString::new("0").unwrap();
+                AppLayerProtoDetectPPRegister(
+                    IPPROTO_TCP,
+                    port.as_ptr(),
+                    ALPROTO_SSH,
+                    0,
+                    probe_depth,
+                    Direction::ToServer.into(),
+                    ssh_probe_any_port,
+                    ssh_probe_any_port,
+                );
+            }
+        }
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_SSH);
+        HASSH_DATASET.configure("app-layer.protocols.ssh.datasets.hassh");
+        HASSH_SERVER_DATASET.configure("app-layer.protocols.ssh.datasets.hassh-server");
+        ssh_hassh_learn_configure();
+        ssh_banned_algorithms_configure();
+        if conf_get_bool("app-layer.protocols.ssh.auth-timing") {
+            ssh_timing_enable();
+        }
+        if conf_get("app-layer.protocols.ssh.log-hassh-string").is_some() {
+            HASSH_STRING_LOG.store(
+                conf_get_bool("app-layer.protocols.ssh.log-hassh-string"),
+                Ordering::Relaxed,
+            );
+        }
+        KEX_COOKIE_LOG.store(
+            conf_get_bool("app-layer.protocols.ssh.log-kex-cookie"),
+            Ordering::Relaxed,
+        );
         SCLogDebug!("Rust ssh parser registered.");
     } else {
         SCLogNotice!("Protocol detector and parser disabled for SSH.");
@@ -553,15 +1299,355 @@ pub extern "C" fn rs_ssh_hassh_is_enabled() -> bool {
 pub unsafe extern "C" fn rs_ssh_tx_get_log_condition( tx: *mut std::os::raw::c_void) -> bool {
     let tx = cast_pointer!(tx, SSHTransaction);
     
-    if rs_ssh_hassh_is_enabled() {
-        if  tx.cli_hdr.flags == SSHConnectionState::SshStateFinished &&
-            tx.srv_hdr.flags == SSHConnectionState::SshStateFinished {
-            return true; 
+    let progress =
+        if rs_ssh_hassh_is_enabled() || ssh_timing_is_enabled() || kex_cookie_log_is_enabled() {
+            SshStateProgress::SshStateEncrypted
+        } else {
+            SshStateProgress::SshStateBannerDone
+        };
+    return tx.cli_hdr.progress >= progress && tx.srv_hdr.progress >= progress;
+}
+
+/// Add the client and server hassh of the flow to `fingerprints`.
+#[no_mangle]
+pub unsafe extern "C" fn SCSshStateGetFingerprints(
+    state: *const std::os::raw::c_void, fingerprints: &mut FlowFingerprints,
+) {
+    let state = cast_pointer!(state, SSHState);
+    let tx = &state.transaction;
+    fingerprints.add("hassh", &tx.cli_hdr.hassh, &tx.cli_hdr.hassh_string);
+    fingerprints.add("hassh_server", &tx.srv_hdr.hassh, &tx.srv_hdr.hassh_string);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_kexinit_hassh_skipped() {
+        let mut events = EventList::new();
+        // cookie, then a name-list claiming more data than present
+        let mut kexinit = vec![0; 16];
+        kexinit.extend_from_slice(&[0x00, 0x00, 0x01, 0x00, 0x63, 0x75]);
+
+        let mut hdr = SshHeader::new();
+        hdr.parse_kexinit(&kexinit, false, true, &mut events);
+        assert!(hdr.hassh_skipped);
+        assert!(hdr.hassh.is_empty());
+
+        // complete KEXINIT with empty name-lists
+        let mut kexinit = vec![0; 16];
+        kexinit.extend_from_slice(&[0; 45]);
+        let mut hdr = SshHeader::new();
+        hdr.parse_kexinit(&kexinit, false, true, &mut events);
+        assert!(!hdr.hassh_skipped);
+        assert!(!hdr.hassh.is_empty());
+    }
+
+    #[test]
+    fn test_ssh_kexinit_cookie() {
+        let mut events = EventList::new();
+        let mut kexinit = (1..=16).collect::<Vec<u8>>();
+        kexinit.extend_from_slice(&[0; 45]);
+
+        // kept without the hassh
+        let mut hdr = SshHeader::new();
+        hdr.parse_kexinit(&kexinit, false, false, &mut events);
+        assert_eq!(hdr.kex_cookie, (1..=16).collect::<Vec<u8>>());
+
+        // not from a truncated KEXINIT
+        let mut hdr = SshHeader::new();
+        hdr.parse_kexinit(&kexinit[..10], false, false, &mut events);
+        assert!(hdr.kex_cookie.is_empty());
+    }
+
+    /// Build a KEXINIT payload from its kex, host key, cipher and MAC
+    /// name-lists, the same for both directions.
+    fn build_kexinit(lists: [&[u8]; 4]) -> Vec<u8> {
+        let mut kexinit = vec![0; 16];
+        let [kex, host_key, cipher, mac] = lists;
+        for list in [
+            kex, host_key, cipher, cipher, mac, mac, b"none", b"none", b"", b"",
+        ] {
+            kexinit.extend_from_slice(&(list.len() as u32).to_be_bytes());
+            kexinit.extend_from_slice(list);
         }
+        kexinit.extend_from_slice(&[0; 5]);
+        kexinit
     }
-    else if  tx.cli_hdr.flags == SSHConnectionState::SshStateBannerDone && 
-        tx.srv_hdr.flags == SSHConnectionState::SshStateBannerDone {
-        return true;
+
+    #[test]
+    fn test_ssh_kexinit_hassh_first_seen() {
+        let mut events = EventList::new();
+        HASSH_SERVER_DATASET.set_name("test-ssh-hassh-server");
+        let kexinit = build_kexinit([b"test-first-seen-kex", b"ssh-ed25519", b"aes128-ctr", b""]);
+
+        let mut hdr = SshHeader::new();
+        assert!(hdr.parse_kexinit(&kexinit, true, true, &mut events));
+        assert!(hdr.hassh_first_seen);
+        // already in the dataset, on a re-exchange or another flow
+        assert!(!hdr.parse_kexinit(&kexinit, true, true, &mut events));
+        assert!(hdr.hassh_first_seen);
+        let mut hdr = SshHeader::new();
+        assert!(!hdr.parse_kexinit(&kexinit, true, true, &mut events));
+        assert!(!hdr.hassh_first_seen);
+    }
+
+    #[test]
+    fn test_ssh_kexinit_negotiated() {
+        let mut events = EventList::new();
+        let mut negotiated = None;
+        let mut banned = Vec::new();
+        let mut cli = SshHeader::new();
+        let mut srv = SshHeader::new();
+
+        let client = build_kexinit([
+            b"curve25519-sha256,diffie-hellman-group14-sha256",
+            b"ssh-ed25519,rsa-sha2-512",
+            b"aes256-gcm@openssh.com,aes128-ctr",
+            b"hmac-sha2-256",
+        ]);
+        let server = build_kexinit([
+            b"diffie-hellman-group14-sha256,curve25519-sha256",
+            b"rsa-sha2-512",
+            b"aes128-ctr,aes256-gcm@openssh.com",
+            b"hmac-sha2-512,hmac-sha2-256",
+        ]);
+        ssh_parse_kexinit(
+            &mut cli,
+            &srv,
+            &client,
+            false,
+            &mut events,
+            &mut negotiated,
+            &mut banned,
+        );
+        assert!(negotiated.is_none());
+        ssh_parse_kexinit(
+            &mut srv,
+            &cli,
+            &server,
+            true,
+            &mut events,
+            &mut negotiated,
+            &mut banned,
+        );
+        let negotiated = negotiated.unwrap();
+        assert_eq!(negotiated.kex, Some(b"curve25519-sha256".to_vec()));
+        assert_eq!(negotiated.host_key, Some(b"rsa-sha2-512".to_vec()));
+        assert_eq!(
+            negotiated.cipher(true),
+            &Some(b"aes256-gcm@openssh.com".to_vec())
+        );
+        assert_eq!(
+            negotiated.cipher(false),
+            &Some(b"aes256-gcm@openssh.com".to_vec())
+        );
+        // no MAC with an AEAD cipher
+        assert_eq!(negotiated.mac(true), &None);
+    }
+
+    #[test]
+    fn test_ssh_midstream_resync() {
+        let mut state = SSHState::new();
+        // tail of a previous record, then a KEXDH_INIT record
+        let mut buf = vec![0xde, 0xad, 0xbe, 0xef, 0x01];
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x2c, 0x06, 0x1e]);
+        buf.extend_from_slice(&[0x00; 42]);
+        assert!(!ssh_is_banner_start(&buf));
+        assert_eq!(ssh_find_record(&buf), Some(5));
+        assert_eq!(ssh_midstream_record(&buf, true), Some(5));
+        // not looked for on any port
+        assert_eq!(ssh_midstream_record(&buf, false), None);
+        assert_eq!(ssh_midstream_record(&buf[5..], false), Some(0));
+
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOSERVER | STREAM_MIDSTREAM, 0);
+        let r = state.parse_banner(&buf, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.progress, SshStateProgress::SshStateKexDh);
+        assert!(state.transaction.cli_hdr.protover.is_empty());
+
+        // no record yet, keep the bytes that may start a header
+        let mut state = SSHState::new();
+        let buf = [0xff; 20];
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOCLIENT | STREAM_MIDSTREAM, 0);
+        let r = state.parse_banner(&buf, true, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::incomplete(15, SSH_RECORD_HEADER_LEN as u32));
+        assert_eq!(state.transaction.srv_hdr.progress, SshStateProgress::SshStateBanner);
+
+        // a banner is still parsed as such
+        assert!(ssh_is_banner_start(b"SSH-2.0-OpenSSH\r\n"));
+        assert!(ssh_is_banner_start(b"SS"));
+    }
+
+    #[test]
+    fn test_ssh_banner_lines() {
+        let mut state = SSHState::new();
+        let buf = b"Welcome\r\n\r\nSSH-2.0-OpenSSH_9.6\r\n";
+        assert_eq!(state.skip_banner_lines(buf, true, 2, 1024), Ok(11));
+        assert_eq!(state.transaction.srv_hdr.banner_skipped_lines, 2);
+        let rem = &buf[11..];
+        let ss = StreamSlice::from_slice(rem, STREAM_TOCLIENT, 0);
+        let r = state.parse_banner(rem, true, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transaction.srv_hdr.swver, b"OpenSSH_9.6");
+
+        // line not complete yet
+        let mut state = SSHState::new();
+        assert_eq!(
+            state.skip_banner_lines(b"Welcome\r\nto the", true, 2, 1024),
+            Err(AppLayerResult::incomplete(9, 7))
+        );
+        // too many lines, or too long
+        let mut state = SSHState::new();
+        assert_eq!(
+            state.skip_banner_lines(b"a\nb\nc\nSSH-2.0-x\r\n", true, 2, 1024),
+            Err(AppLayerResult::err())
+        );
+        let mut state = SSHState::new();
+        assert_eq!(
+            state.skip_banner_lines(&[b'a'; 64], true, 2, 32),
+            Err(AppLayerResult::err())
+        );
+    }
+
+    #[test]
+    fn test_ssh_probe_banner() {
+        assert_eq!(ssh_probe_banner(b"SSH-2.0-x\r\n", 0, 0), Some(true));
+        assert_eq!(ssh_probe_banner(b"Welcome\r\n", 0, 1024), Some(false));
+        let buf = b"Welcome\r\nSSH-2.0-x\r\n";
+        assert_eq!(ssh_probe_banner(buf, 1, 1024), Some(true));
+        assert_eq!(ssh_probe_banner(buf, 1, 4), Some(false));
+        assert_eq!(ssh_probe_banner(b"Welcome\r\nSS", 1, 1024), None);
+        assert_eq!(ssh_probe_banner(b"Welcome", 1, 1024), None);
+        assert_eq!(ssh_probe_banner(b"Welcome", 1, 4), Some(false));
+        let buf = b"a\nb\nSSH-2.0-x\r\n";
+        assert_eq!(ssh_probe_banner(buf, 1, 1024), Some(false));
+        assert_eq!(ssh_probe_banner(buf, 2, 1024), Some(true));
+    }
+
+    #[test]
+    fn test_ssh_gap() {
+        let mut state = SSHState::new();
+        state.transaction.cli_hdr.progress = SshStateProgress::SshStateKexInit;
+
+        // within a record being skipped
+        state.transaction.cli_hdr.record_left = 100;
+        assert_eq!(state.parse_gap(false, 60), AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.record_left, 40);
+        assert!(!state.transaction.cli_hdr.gaps.is_pending());
+        assert_eq!(state.transaction.cli_hdr.gaps.recovered, 1);
+
+        // past the end of the record, look for the next one
+        assert_eq!(state.parse_gap(false, 60), AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.record_left, 0);
+        assert!(state.transaction.cli_hdr.gaps.is_pending());
+        let mut buf = vec![0xde, 0xad, 0xbe];
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x2c, 0x06, 0x1e]);
+        buf.extend_from_slice(&[0x00; 42]);
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOSERVER, 0);
+        let r = state.parse_resync(&buf, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.progress, SshStateProgress::SshStateKexDh);
+        assert_eq!(state.transaction.cli_hdr.gaps.recovered, 2);
+
+        // no cleartext record after the gap
+        assert_eq!(state.parse_gap(true, 10), AppLayerResult::ok());
+        let buf = [0xff; 1024];
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOCLIENT, 0);
+        for _ in 0..70 {
+            let _ = state.parse_resync(&buf, true, std::ptr::null_mut(), std::ptr::null(), &ss);
+        }
+        assert_eq!(state.transaction.srv_hdr.gaps.unrecoverable, 1);
+        assert!(!state.transaction.srv_hdr.gaps.is_pending());
+        assert_eq!(state.transaction.srv_hdr.progress, SshStateProgress::SshStateNewKeys);
+
+        // encrypted data is not affected
+        assert_eq!(state.parse_gap(true, 10), AppLayerResult::ok());
+        assert!(!state.transaction.srv_hdr.gaps.is_pending());
+    }
+
+    #[test]
+    fn test_ssh_kex_records() {
+        let mut state = SSHState::new();
+        state.transaction.cli_hdr.progress = SshStateProgress::SshStateBannerDone;
+        state.transaction.srv_hdr.progress = SshStateProgress::SshStateBannerDone;
+        let mut buf = Vec::new();
+        // IGNORE, NEWKEYS, then a record of the encrypted data
+        for msg_code in [2, 21, 30] {
+            buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x0c, 0x04, msg_code]);
+            buf.extend_from_slice(&[0x00; 10]);
+        }
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOSERVER, 0);
+        let r = state.parse_record(&buf, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.kex_records, 2);
+        assert_eq!(state.transaction.cli_hdr.kex_bytes, 32);
+        assert_eq!(state.transaction.srv_hdr.kex_records, 0);
+    }
+
+    #[test]
+    fn test_ssh_service() {
+        let mut record = vec![0x00, 0x00, 0x00, 0x18, 0x06, 0x05];
+        record.extend_from_slice(b"\x00\x00\x00\x0cssh-userauth");
+        record.extend_from_slice(&[0x00; 6]);
+        // NEWKEYS, then the SERVICE_REQUEST
+        let mut buf = vec![0x00, 0x00, 0x00, 0x0c, 0x0a, 0x15];
+        buf.extend_from_slice(&[0x00; 10]);
+        buf.extend_from_slice(&record);
+        let ss = StreamSlice::from_slice(&buf, STREAM_TOSERVER, 0);
+
+        // sent before the NEWKEYS
+        let mut state = SSHState::new();
+        state.transaction.cli_hdr.progress = SshStateProgress::SshStateBannerDone;
+        let r = state.parse_record(&record, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transaction.cli_hdr.service, b"ssh-userauth");
+
+        // encrypted
+        let mut state = SSHState::new();
+        state.transaction.cli_hdr.progress = SshStateProgress::SshStateKexDh;
+        state.parse_record(&buf, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert!(state.transaction.cli_hdr.service.is_empty());
+
+        // "none" cipher
+        let mut state = SSHState::new();
+        state.transaction.cli_hdr.progress = SshStateProgress::SshStateKexDh;
+        state.transaction.negotiated = Some(SshNegotiatedAlgorithms {
+            cipher_cts: Some(b"none".to_vec()),
+            ..Default::default()
+        });
+        state.parse_record(&buf, false, std::ptr::null_mut(), std::ptr::null(), &ss);
+        assert_eq!(state.transaction.cli_hdr.service, b"ssh-userauth");
+    }
+
+    #[test]
+    fn test_ssh_progress() {
+        let mut cli = SshHeader::new();
+        let mut srv = SshHeader::new();
+        cli.progress = SshStateProgress::SshStateBannerWaitEol;
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateBanner);
+        cli.progress = SshStateProgress::SshStateBannerDone;
+        srv.progress = SshStateProgress::SshStateBannerDone;
+
+        assert!(!ssh_update_progress(&mut cli, &mut srv, parser::MessageCode::Ignore));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateBannerDone);
+        assert!(!ssh_update_progress(&mut cli, &mut srv, parser::MessageCode::Kexinit));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateKexInit);
+        // ECDH init, a key exchange method specific message
+        assert!(!ssh_update_progress(&mut cli, &mut srv, parser::MessageCode::Undefined(30)));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateKexDh);
+        assert!(!ssh_update_progress(&mut cli, &mut srv, parser::MessageCode::NewKeys));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateNewKeys);
+        assert_eq!(srv.tx_progress(), SshStateProgress::SshStateBannerDone);
+
+        // The client side is encrypted, its records are meaningless.
+        assert!(!ssh_update_progress(&mut cli, &mut srv, parser::MessageCode::Kexinit));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateNewKeys);
+
+        assert!(!ssh_update_progress(&mut srv, &mut cli, parser::MessageCode::Kexinit));
+        assert!(ssh_update_progress(&mut srv, &mut cli, parser::MessageCode::NewKeys));
+        assert_eq!(cli.tx_progress(), SshStateProgress::SshStateEncrypted);
+        assert_eq!(srv.tx_progress(), SshStateProgress::SshStateEncrypted);
     }
-    return false;
 }
@@ -0,0 +1,191 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Timing of the connection setup and of the authentication phase.
+//!
+//! The time of the banner, KEXINIT and NEWKEYS of each direction is
+//! kept, as well as the start of the first two bursts of encrypted data
+//! that follow: the service request and the first authentication
+//! request for the client, their answers for the server. A burst is
+//! the encrypted data of a direction until data is seen in the other
+//! direction. Interactive logins are slow to send the authentication
+//! request, brute forcers and scripts are not.
+//!
+//! As the bursts are encrypted, the flow is only bypassed once they
+//! are seen, or after `SSH_TIMING_MAX_ENCRYPTED_LEN` bytes.
+
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static TIMING_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Encrypted data after which the bursts are not waited for anymore.
+const SSH_TIMING_MAX_ENCRYPTED_LEN: u64 = 65535;
+
+pub fn ssh_timing_enable() {
+    TIMING_ENABLED.store(true, Ordering::Relaxed)
+}
+
+pub fn ssh_timing_is_enabled() -> bool {
+    TIMING_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Timestamps of a direction, in microseconds.
+#[derive(Debug, Default)]
+pub struct SshDirectionTiming {
+    pub banner: Option<u64>,
+    pub kexinit: Option<u64>,
+    pub newkeys: Option<u64>,
+    pub first_burst: Option<u64>,
+    pub second_burst: Option<u64>,
+}
+
+fn ssh_timing_delta(start: Option<u64>, end: Option<u64>) -> Option<u64> {
+    match (start, end) {
+        (Some(start), Some(end)) => Some(end.saturating_sub(start)),
+        _ => None,
+    }
+}
+
+impl SshDirectionTiming {
+    fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        let deltas = [
+            ("banner_to_kexinit", self.banner, self.kexinit),
+            ("kexinit_to_newkeys", self.kexinit, self.newkeys),
+            ("newkeys_to_first_burst", self.newkeys, self.first_burst),
+            ("first_to_second_burst", self.first_burst, self.second_burst),
+        ];
+        for (name, start, end) in deltas {
+            if let Some(delta) = ssh_timing_delta(start, end) {
+                js.set_uint(name, delta)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.kexinit.is_none() && self.newkeys.is_none() && self.first_burst.is_none()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SshTiming {
+    pub client: SshDirectionTiming,
+    pub server: SshDirectionTiming,
+    /// Direction of the last encrypted data, true for the server.
+    last_burst_resp: Option<bool>,
+    encrypted_len: u64,
+}
+
+impl SshTiming {
+    fn direction(&mut self, resp: bool) -> &mut SshDirectionTiming {
+        if resp {
+            &mut self.server
+        } else {
+            &mut self.client
+        }
+    }
+
+    /// Account for encrypted data of a direction seen at `ts`.
+    pub fn encrypted_data(&mut self, resp: bool, ts: u64, len: usize) {
+        self.encrypted_len = self.encrypted_len.saturating_add(len as u64);
+        if self.last_burst_resp == Some(resp) {
+            return;
+        }
+        self.last_burst_resp = Some(resp);
+        let dir = self.direction(resp);
+        if dir.first_burst.is_none() {
+            dir.first_burst = Some(ts);
+        } else if dir.second_burst.is_none() {
+            dir.second_burst = Some(ts);
+        }
+    }
+
+    /// True once there is nothing left to time.
+    pub fn is_complete(&self) -> bool {
+        (self.client.second_burst.is_some() && self.server.second_burst.is_some())
+            || self.encrypted_len > SSH_TIMING_MAX_ENCRYPTED_LEN
+    }
+
+    pub fn log(&self, js: &mut JsonBuilder) -> Result<(), JsonError> {
+        if self.client.is_empty() && self.server.is_empty() {
+            return Ok(());
+        }
+        js.open_object("timing")?;
+        js.open_object("client")?;
+        self.client.log(js)?;
+        js.close()?;
+        js.open_object("server")?;
+        self.server.log(js)?;
+        js.close()?;
+        js.close()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonbuilder::{jb_len, jb_ptr};
+
+    fn log_str(timing: &SshTiming) -> String {
+        let mut js = JsonBuilder::try_new_object().unwrap();
+        timing.log(&mut js).unwrap();
+        js.close().unwrap();
+        let buf = unsafe { std::slice::from_raw_parts(jb_ptr(&mut js), jb_len(&js)) };
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_ssh_timing_bursts() {
+        let mut timing = SshTiming::default();
+        timing.encrypted_data(false, 100, 64);
+        // same burst
+        timing.encrypted_data(false, 110, 64);
+        timing.encrypted_data(true, 200, 64);
+        assert!(!timing.is_complete());
+        timing.encrypted_data(false, 5000, 128);
+        timing.encrypted_data(true, 5100, 64);
+        assert!(timing.is_complete());
+        assert_eq!(timing.client.first_burst, Some(100));
+        assert_eq!(timing.client.second_burst, Some(5000));
+        assert_eq!(timing.server.first_burst, Some(200));
+        assert_eq!(timing.server.second_burst, Some(5100));
+
+        // a single direction stops being waited for after a while
+        let mut timing = SshTiming::default();
+        timing.encrypted_data(false, 100, SSH_TIMING_MAX_ENCRYPTED_LEN as usize);
+        assert!(!timing.is_complete());
+        timing.encrypted_data(false, 200, 1);
+        assert!(timing.is_complete());
+    }
+
+    #[test]
+    fn test_ssh_timing_log() {
+        let mut timing = SshTiming::default();
+        assert_eq!(log_str(&timing), r#"{}"#);
+
+        timing.client.banner = Some(10);
+        timing.client.kexinit = Some(30);
+        timing.client.newkeys = Some(1000);
+        timing.encrypted_data(false, 1500, 64);
+        assert_eq!(
+            log_str(&timing),
+            r#"{"timing":{"client":{"banner_to_kexinit":20,"kexinit_to_newkeys":970,"newkeys_to_first_burst":500},"server":{}}}"#
+        );
+    }
+}
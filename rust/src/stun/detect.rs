@@ -0,0 +1,294 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::StunMethod;
+use super::stun::{StunTransaction, ALPROTO_STUN};
+use crate::core::STREAM_TOSERVER;
+use crate::detect::uint::{
+    detect_parse_uint_enum, rs_detect_u16_free, rs_detect_u16_match, rs_detect_u16_parse,
+    DetectUintData,
+};
+use crate::detect::{
+    DetectBufferSetActiveList, DetectHelperBufferMpmRegister, DetectHelperBufferRegister,
+    DetectHelperGetData, DetectHelperKeywordRegister, DetectSignatureSetAppProto, SCSigTableElmt,
+    SigMatchAppendSMToList, SIGMATCH_INFO_STICKY_BUFFER, SIGMATCH_NOOPT,
+};
+use std::ffi::CStr;
+use std::os::raw::{c_int, c_void};
+use std::ptr;
+
+static mut G_STUN_METHOD_KW_ID: c_int = 0;
+static mut G_STUN_METHOD_BUFFER_ID: c_int = 0;
+static mut G_STUN_ERROR_CODE_KW_ID: c_int = 0;
+static mut G_STUN_ERROR_CODE_BUFFER_ID: c_int = 0;
+static mut G_STUN_USERNAME_BUFFER_ID: c_int = 0;
+static mut G_STUN_REALM_BUFFER_ID: c_int = 0;
+
+unsafe extern "C" fn stun_parse_method(
+    ustr: *const std::os::raw::c_char,
+) -> *mut DetectUintData<u16> {
+    let ft_name: &CStr = CStr::from_ptr(ustr); //unsafe
+    if let Ok(s) = ft_name.to_str() {
+        if let Some(ctx) = detect_parse_uint_enum::<u16, StunMethod>(s) {
+            let boxed = Box::new(ctx);
+            return Box::into_raw(boxed) as *mut _;
+        }
+    }
+    return std::ptr::null_mut();
+}
+
+unsafe extern "C" fn stun_detect_method_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_STUN) != 0 {
+        return -1;
+    }
+    let ctx = stun_parse_method(raw) as *mut c_void;
+    if ctx.is_null() {
+        return -1;
+    }
+    if SigMatchAppendSMToList(de, s, G_STUN_METHOD_KW_ID, ctx, G_STUN_METHOD_BUFFER_ID).is_null() {
+        stun_detect_method_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn stun_detect_method_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, StunTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    if tx.request.is_none() && tx.response.is_none() {
+        // malformed message
+        return 0;
+    }
+    return rs_detect_u16_match(tx.method, ctx);
+}
+
+unsafe extern "C" fn stun_detect_method_free(_de: *mut c_void, ctx: *mut c_void) {
+    // Just unbox...
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    rs_detect_u16_free(ctx);
+}
+
+unsafe extern "C" fn stun_detect_error_code_setup(
+    de: *mut c_void, s: *mut c_void, raw: *const libc::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_STUN) != 0 {
+        return -1;
+    }
+    let ctx = rs_detect_u16_parse(raw) as *mut c_void;
+    if ctx.is_null() {
+        return -1;
+    }
+    if SigMatchAppendSMToList(
+        de,
+        s,
+        G_STUN_ERROR_CODE_KW_ID,
+        ctx,
+        G_STUN_ERROR_CODE_BUFFER_ID,
+    )
+    .is_null()
+    {
+        stun_detect_error_code_free(std::ptr::null_mut(), ctx);
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn stun_detect_error_code_match(
+    _de: *mut c_void, _f: *mut c_void, _flags: u8, _state: *mut c_void, tx: *mut c_void,
+    _sig: *const c_void, ctx: *const c_void,
+) -> c_int {
+    let tx = cast_pointer!(tx, StunTransaction);
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    if let Some(code) = tx.response.as_ref().and_then(|r| r.error_code) {
+        return rs_detect_u16_match(code, ctx);
+    }
+    return 0;
+}
+
+unsafe extern "C" fn stun_detect_error_code_free(_de: *mut c_void, ctx: *mut c_void) {
+    // Just unbox...
+    let ctx = cast_pointer!(ctx, DetectUintData<u16>);
+    rs_detect_u16_free(ctx);
+}
+
+unsafe extern "C" fn stun_username_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_STUN) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_STUN_USERNAME_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn stun_username_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        stun_username_get_data,
+    );
+}
+
+unsafe extern "C" fn stun_username_get_data(
+    tx: *const c_void, _flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, StunTransaction);
+    if let Some(username) = tx.request.as_ref().and_then(|r| r.username.as_ref()) {
+        *buffer = username.as_ptr();
+        *buffer_len = username.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+unsafe extern "C" fn stun_realm_setup(
+    de: *mut c_void, s: *mut c_void, _raw: *const std::os::raw::c_char,
+) -> c_int {
+    if DetectSignatureSetAppProto(s, ALPROTO_STUN) != 0 {
+        return -1;
+    }
+    if DetectBufferSetActiveList(de, s, G_STUN_REALM_BUFFER_ID) < 0 {
+        return -1;
+    }
+    return 0;
+}
+
+unsafe extern "C" fn stun_realm_get(
+    de: *mut c_void, transforms: *const c_void, flow: *const c_void, flow_flags: u8,
+    tx: *const c_void, list_id: c_int,
+) -> *mut c_void {
+    return DetectHelperGetData(
+        de,
+        transforms,
+        flow,
+        flow_flags,
+        tx,
+        list_id,
+        stun_realm_get_data,
+    );
+}
+
+unsafe extern "C" fn stun_realm_get_data(
+    tx: *const c_void, flow_flags: u8, buffer: *mut *const u8, buffer_len: *mut u32,
+) -> bool {
+    let tx = cast_pointer!(tx, StunTransaction);
+    // The server announces the realm in an error response, the client
+    // repeats it in the authenticated request.
+    let msg = if flow_flags & STREAM_TOSERVER != 0 {
+        tx.request.as_ref()
+    } else {
+        tx.response.as_ref()
+    };
+    if let Some(realm) = msg.and_then(|m| m.realm.as_ref()) {
+        *buffer = realm.as_ptr();
+        *buffer_len = realm.len() as u32;
+        return true;
+    }
+    *buffer = ptr::null();
+    *buffer_len = 0;
+    return false;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCDetectStunRegister() {
+    let kw = SCSigTableElmt {
+        name: b"stun.method\0".as_ptr() as *const libc::c_char,
+        desc: b"match STUN or TURN method\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/stun-keywords.html#stun-method\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(stun_detect_method_match),
+        Setup: stun_detect_method_setup,
+        Free: Some(stun_detect_method_free),
+        flags: 0,
+    };
+    G_STUN_METHOD_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_STUN_METHOD_BUFFER_ID = DetectHelperBufferRegister(
+        b"stun.method\0".as_ptr() as *const libc::c_char,
+        ALPROTO_STUN,
+        true,
+        true,
+    );
+    let kw = SCSigTableElmt {
+        name: b"stun.error_code\0".as_ptr() as *const libc::c_char,
+        desc: b"match STUN error response code\0".as_ptr() as *const libc::c_char,
+        url: b"/rules/stun-keywords.html#stun-error-code\0".as_ptr() as *const libc::c_char,
+        AppLayerTxMatch: Some(stun_detect_error_code_match),
+        Setup: stun_detect_error_code_setup,
+        Free: Some(stun_detect_error_code_free),
+        flags: 0,
+    };
+    G_STUN_ERROR_CODE_KW_ID = DetectHelperKeywordRegister(&kw);
+    G_STUN_ERROR_CODE_BUFFER_ID = DetectHelperBufferRegister(
+        b"stun.error_code\0".as_ptr() as *const libc::c_char,
+        ALPROTO_STUN,
+        true,
+        false,
+    );
+    let kw = SCSigTableElmt {
+        name: b"stun.username\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the STUN USERNAME attribute\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/stun-keywords.html#stun-username\0".as_ptr() as *const libc::c_char,
+        Setup: stun_username_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_stun_username_kw_id = DetectHelperKeywordRegister(&kw);
+    G_STUN_USERNAME_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"stun.username\0".as_ptr() as *const libc::c_char,
+        b"STUN USERNAME\0".as_ptr() as *const libc::c_char,
+        ALPROTO_STUN,
+        false,
+        true,
+        stun_username_get,
+    );
+    let kw = SCSigTableElmt {
+        name: b"stun.realm\0".as_ptr() as *const libc::c_char,
+        desc: b"sticky buffer to match on the STUN REALM attribute\0".as_ptr()
+            as *const libc::c_char,
+        url: b"/rules/stun-keywords.html#stun-realm\0".as_ptr() as *const libc::c_char,
+        Setup: stun_realm_setup,
+        flags: SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER,
+        AppLayerTxMatch: None,
+        Free: None,
+    };
+    let _g_stun_realm_kw_id = DetectHelperKeywordRegister(&kw);
+    G_STUN_REALM_BUFFER_ID = DetectHelperBufferMpmRegister(
+        b"stun.realm\0".as_ptr() as *const libc::c_char,
+        b"STUN REALM\0".as_ptr() as *const libc::c_char,
+        ALPROTO_STUN,
+        true,
+        true,
+        stun_realm_get,
+    );
+}
@@ -0,0 +1,97 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::StunMethod;
+use super::stun::{StunMessageData, StunTransaction};
+use crate::detect::EnumString;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+use std::net::SocketAddr;
+
+fn log_address(name: &str, addr: &SocketAddr, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object(name)?;
+    js.set_string("ip", &addr.ip().to_string())?;
+    js.set_uint("port", addr.port() as u64)?;
+    js.close()?;
+    Ok(())
+}
+
+fn log_message(name: &str, msg: &StunMessageData, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object(name)?;
+    js.set_string("class", msg.class.as_str())?;
+    if let Some(ref username) = msg.username {
+        js.set_string_from_bytes("username", username)?;
+    }
+    if let Some(ref realm) = msg.realm {
+        js.set_string_from_bytes("realm", realm)?;
+    }
+    if let Some(ref software) = msg.software {
+        js.set_string_from_bytes("software", software)?;
+    }
+    if let Some(code) = msg.error_code {
+        js.set_uint("error_code", code as u64)?;
+    }
+    if let Some(ref reason) = msg.error_reason {
+        js.set_string_from_bytes("error_reason", reason)?;
+    }
+    if let Some(ref addr) = msg.mapped_address {
+        log_address("mapped_address", addr, js)?;
+    }
+    if let Some(ref addr) = msg.xor_mapped_address {
+        log_address("xor_mapped_address", addr, js)?;
+    }
+    if let Some(ref addr) = msg.xor_relayed_address {
+        log_address("xor_relayed_address", addr, js)?;
+    }
+    if let Some(lifetime) = msg.lifetime {
+        js.set_uint("lifetime", lifetime as u64)?;
+    }
+    if let Some(protocol) = msg.requested_transport {
+        js.set_uint("requested_transport", protocol as u64)?;
+    }
+    js.set_bool("message_integrity", msg.message_integrity)?;
+    js.close()?;
+    Ok(())
+}
+
+fn log_stun(tx: &StunTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("stun")?;
+    if let Some(method) = StunMethod::from_u(tx.method) {
+        js.set_string("method", method.to_str())?;
+    } else {
+        js.set_uint("method", tx.method as u64)?;
+    }
+    if !tx.transaction_id.is_empty() {
+        js.set_hex("transaction_id", &tx.transaction_id)?;
+    }
+    if let Some(ref request) = tx.request {
+        log_message("request", request, js)?;
+    }
+    if let Some(ref response) = tx.response {
+        log_message("response", response, js)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCStunLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, StunTransaction);
+    log_stun(tx, js).is_ok()
+}
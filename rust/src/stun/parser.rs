@@ -0,0 +1,346 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! STUN (RFC 8489) and TURN (RFC 8656) message parsing.
+
+use nom7::bytes::complete::{tag, take};
+use nom7::combinator::{all_consuming, verify};
+use nom7::multi::many0;
+use nom7::number::complete::{be_u16, be_u32, be_u8};
+use nom7::sequence::tuple;
+use nom7::IResult;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use suricata_derive::EnumStringU16;
+
+pub const STUN_MAGIC_COOKIE: u32 = 0x2112_a442;
+pub const STUN_HEADER_LEN: usize = 20;
+pub const STUN_TRANSACTION_ID_LEN: usize = 12;
+
+pub const STUN_ATTR_MAPPED_ADDRESS: u16 = 0x0001;
+pub const STUN_ATTR_USERNAME: u16 = 0x0006;
+pub const STUN_ATTR_MESSAGE_INTEGRITY: u16 = 0x0008;
+pub const STUN_ATTR_ERROR_CODE: u16 = 0x0009;
+pub const STUN_ATTR_LIFETIME: u16 = 0x000d;
+pub const STUN_ATTR_REALM: u16 = 0x0014;
+pub const STUN_ATTR_XOR_RELAYED_ADDRESS: u16 = 0x0016;
+pub const STUN_ATTR_REQUESTED_TRANSPORT: u16 = 0x0019;
+pub const STUN_ATTR_XOR_MAPPED_ADDRESS: u16 = 0x0020;
+pub const STUN_ATTR_SOFTWARE: u16 = 0x8022;
+
+const STUN_FAMILY_IPV4: u8 = 0x01;
+const STUN_FAMILY_IPV6: u8 = 0x02;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StunClass {
+    Request,
+    Indication,
+    SuccessResponse,
+    ErrorResponse,
+}
+
+impl StunClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StunClass::Request => "request",
+            StunClass::Indication => "indication",
+            StunClass::SuccessResponse => "success_response",
+            StunClass::ErrorResponse => "error_response",
+        }
+    }
+
+    pub fn is_response(&self) -> bool {
+        matches!(self, StunClass::SuccessResponse | StunClass::ErrorResponse)
+    }
+}
+
+/// STUN and TURN methods.
+#[derive(Clone, Debug, PartialEq, Eq, EnumStringU16)]
+#[repr(u16)]
+pub enum StunMethod {
+    Binding = 1,
+    Allocate = 3,
+    Refresh = 4,
+    Send = 6,
+    Data = 7,
+    CreatePermission = 8,
+    ChannelBind = 9,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum StunAttribute<'a> {
+    MappedAddress(SocketAddr),
+    XorMappedAddress(SocketAddr),
+    XorRelayedAddress(SocketAddr),
+    Username(&'a [u8]),
+    Realm(&'a [u8]),
+    Software(&'a [u8]),
+    ErrorCode(u16, &'a [u8]),
+    Lifetime(u32),
+    RequestedTransport(u8),
+    MessageIntegrity,
+    /// Attribute not parsed, by type.
+    Other(u16),
+    /// Known attribute with a value that could not be parsed, by type.
+    Invalid(u16),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StunMessage<'a> {
+    pub class: StunClass,
+    pub method: u16,
+    pub transaction_id: &'a [u8],
+    pub attributes: Vec<StunAttribute<'a>>,
+}
+
+/// Split a message type into its class and method. The class bits are
+/// interleaved with the method bits.
+fn message_type_split(t: u16) -> (StunClass, u16) {
+    let method = (t & 0x000f) | ((t & 0x00e0) >> 1) | ((t & 0x3e00) >> 2);
+    let class = match ((t & 0x0100) >> 7) | ((t & 0x0010) >> 4) {
+        0 => StunClass::Request,
+        1 => StunClass::Indication,
+        2 => StunClass::SuccessResponse,
+        _ => StunClass::ErrorResponse,
+    };
+    (class, method)
+}
+
+/// Parse a (XOR-)MAPPED-ADDRESS value. With `xor` the port and address
+/// are obfuscated with the magic cookie and the transaction id.
+fn parse_address<'a>(
+    i: &'a [u8], xor: bool, transaction_id: &[u8],
+) -> IResult<&'a [u8], SocketAddr> {
+    let (i, _reserved) = be_u8(i)?;
+    let (i, family) = verify(be_u8, |&f| f == STUN_FAMILY_IPV4 || f == STUN_FAMILY_IPV6)(i)?;
+    let (i, mut port) = be_u16(i)?;
+    if xor {
+        port ^= (STUN_MAGIC_COOKIE >> 16) as u16;
+    }
+    if family == STUN_FAMILY_IPV4 {
+        let (i, mut addr) = be_u32(i)?;
+        if xor {
+            addr ^= STUN_MAGIC_COOKIE;
+        }
+        let ip = IpAddr::V4(Ipv4Addr::from(addr));
+        return Ok((i, SocketAddr::new(ip, port)));
+    }
+    let (i, addr) = take(16_usize)(i)?;
+    let mut octets = [0u8; 16];
+    octets.copy_from_slice(addr);
+    if xor {
+        let mut key = STUN_MAGIC_COOKIE.to_be_bytes().to_vec();
+        key.extend_from_slice(transaction_id);
+        for (o, k) in octets.iter_mut().zip(key) {
+            *o ^= k;
+        }
+    }
+    let ip = IpAddr::V6(Ipv6Addr::from(octets));
+    Ok((i, SocketAddr::new(ip, port)))
+}
+
+fn parse_error_code(i: &[u8]) -> IResult<&[u8], (u16, &[u8])> {
+    let (i, _reserved) = be_u16(i)?;
+    let (i, class) = verify(be_u8, |&c| (3..=6).contains(&(c & 0x07)))(i)?;
+    let (i, number) = verify(be_u8, |&n| n < 100)(i)?;
+    let code = (class & 0x07) as u16 * 100 + number as u16;
+    Ok((&[], (code, i)))
+}
+
+fn parse_attribute_value<'a>(
+    attr_type: u16, value: &'a [u8], transaction_id: &[u8],
+) -> IResult<&'a [u8], StunAttribute<'a>> {
+    let attr = match attr_type {
+        STUN_ATTR_MAPPED_ADDRESS => {
+            let (_, addr) = all_consuming(|i| parse_address(i, false, transaction_id))(value)?;
+            StunAttribute::MappedAddress(addr)
+        }
+        STUN_ATTR_XOR_MAPPED_ADDRESS => {
+            let (_, addr) = all_consuming(|i| parse_address(i, true, transaction_id))(value)?;
+            StunAttribute::XorMappedAddress(addr)
+        }
+        STUN_ATTR_XOR_RELAYED_ADDRESS => {
+            let (_, addr) = all_consuming(|i| parse_address(i, true, transaction_id))(value)?;
+            StunAttribute::XorRelayedAddress(addr)
+        }
+        STUN_ATTR_USERNAME => StunAttribute::Username(value),
+        STUN_ATTR_REALM => StunAttribute::Realm(value),
+        STUN_ATTR_SOFTWARE => StunAttribute::Software(value),
+        STUN_ATTR_ERROR_CODE => {
+            let (_, (code, reason)) = parse_error_code(value)?;
+            StunAttribute::ErrorCode(code, reason)
+        }
+        STUN_ATTR_LIFETIME => {
+            let (_, lifetime) = all_consuming(be_u32)(value)?;
+            StunAttribute::Lifetime(lifetime)
+        }
+        STUN_ATTR_REQUESTED_TRANSPORT => {
+            // protocol then 3 bytes RFFU
+            let (_, (protocol, _)) = all_consuming(tuple((be_u8, take(3_usize))))(value)?;
+            StunAttribute::RequestedTransport(protocol)
+        }
+        STUN_ATTR_MESSAGE_INTEGRITY => StunAttribute::MessageIntegrity,
+        _ => StunAttribute::Other(attr_type),
+    };
+    Ok((&[], attr))
+}
+
+fn parse_attribute<'a>(i: &'a [u8], transaction_id: &[u8]) -> IResult<&'a [u8], StunAttribute<'a>> {
+    let (i, attr_type) = be_u16(i)?;
+    let (i, len) = be_u16(i)?;
+    let (i, value) = take(len as usize)(i)?;
+    // Values are padded to a multiple of 4 bytes.
+    let (i, _padding) = take((4 - (len as usize % 4)) % 4)(i)?;
+    let attr = match parse_attribute_value(attr_type, value, transaction_id) {
+        Ok((_, attr)) => attr,
+        Err(_) => StunAttribute::Invalid(attr_type),
+    };
+    Ok((i, attr))
+}
+
+/// Parse the header, returning the message type, length and transaction
+/// id.
+pub fn parse_header(i: &[u8]) -> IResult<&[u8], (u16, u16, &[u8])> {
+    // The 2 most significant bits are zero, this tells STUN apart from
+    // TURN ChannelData and other protocols multiplexed on the same port.
+    let (i, message_type) = verify(be_u16, |&t| t & 0xc000 == 0)(i)?;
+    let (i, len) = verify(be_u16, |&l| l % 4 == 0)(i)?;
+    let (i, _cookie) = tag(STUN_MAGIC_COOKIE.to_be_bytes())(i)?;
+    let (i, transaction_id) = take(STUN_TRANSACTION_ID_LEN)(i)?;
+    Ok((i, (message_type, len, transaction_id)))
+}
+
+/// Parse a complete message, as carried by a single UDP datagram.
+pub fn parse_message(i: &[u8]) -> IResult<&[u8], StunMessage> {
+    let (i, (message_type, len, transaction_id)) = parse_header(i)?;
+    let (i, attrs) = take(len as usize)(i)?;
+    let (_, attributes) = all_consuming(many0(|i| parse_attribute(i, transaction_id)))(attrs)?;
+    let (class, method) = message_type_split(message_type);
+    Ok((
+        i,
+        StunMessage {
+            class,
+            method,
+            transaction_id,
+            attributes,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Binding success response from RFC 5769 section 2.2.
+    const BINDING_RESPONSE_IPV4: &[u8] = &[
+        0x01, 0x01, 0x00, 0x3c, 0x21, 0x12, 0xa4, 0x42, 0xb7, 0xe7, 0xa7, 0x01, 0xbc, 0x34, 0xd6,
+        0x86, 0xfa, 0x87, 0xdf, 0xae, 0x80, 0x22, 0x00, 0x0b, 0x74, 0x65, 0x73, 0x74, 0x20, 0x76,
+        0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x00, 0x20, 0x00, 0x08, 0x00, 0x01, 0xa1, 0x47, 0xe1,
+        0x12, 0xa6, 0x43, 0x00, 0x08, 0x00, 0x14, 0x2b, 0x91, 0xf5, 0x99, 0xfd, 0x9e, 0x90, 0xc3,
+        0x8c, 0x74, 0x89, 0xf9, 0x2a, 0xf9, 0xba, 0x53, 0xf0, 0x6b, 0xe7, 0xd7, 0x80, 0x28, 0x00,
+        0x04, 0xc0, 0x7d, 0x4c, 0x96,
+    ];
+
+    // Binding success response from RFC 5769 section 2.3.
+    const BINDING_RESPONSE_IPV6: &[u8] = &[
+        0x01, 0x01, 0x00, 0x48, 0x21, 0x12, 0xa4, 0x42, 0xb7, 0xe7, 0xa7, 0x01, 0xbc, 0x34, 0xd6,
+        0x86, 0xfa, 0x87, 0xdf, 0xae, 0x80, 0x22, 0x00, 0x0b, 0x74, 0x65, 0x73, 0x74, 0x20, 0x76,
+        0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x00, 0x20, 0x00, 0x14, 0x00, 0x02, 0xa1, 0x47, 0x01,
+        0x13, 0xa9, 0xfa, 0xa5, 0xd3, 0xf1, 0x79, 0xbc, 0x25, 0xf4, 0xb5, 0xbe, 0xd2, 0xb9, 0xd9,
+        0x00, 0x08, 0x00, 0x14, 0xa3, 0x82, 0x95, 0x4e, 0x4b, 0xe6, 0x7b, 0xf1, 0x17, 0x84, 0xc9,
+        0x7c, 0x82, 0x92, 0xc2, 0x75, 0xbf, 0xe3, 0xed, 0x41, 0x80, 0x28, 0x00, 0x04, 0xc8, 0xfb,
+        0x0b, 0x4c,
+    ];
+
+    #[test]
+    fn test_message_type_split() {
+        assert_eq!(message_type_split(0x0001), (StunClass::Request, 1));
+        assert_eq!(message_type_split(0x0101), (StunClass::SuccessResponse, 1));
+        assert_eq!(message_type_split(0x0113), (StunClass::ErrorResponse, 3));
+        assert_eq!(message_type_split(0x0016), (StunClass::Indication, 6));
+    }
+
+    #[test]
+    fn test_parse_binding_response() {
+        let (rem, msg) = parse_message(BINDING_RESPONSE_IPV4).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(msg.class, StunClass::SuccessResponse);
+        assert_eq!(msg.method, StunMethod::Binding as u16);
+        assert_eq!(msg.transaction_id, &BINDING_RESPONSE_IPV4[8..20]);
+        assert_eq!(msg.attributes.len(), 4);
+        assert_eq!(msg.attributes[0], StunAttribute::Software(b"test vector"));
+        assert_eq!(
+            msg.attributes[1],
+            StunAttribute::XorMappedAddress("192.0.2.1:32853".parse().unwrap())
+        );
+        assert_eq!(msg.attributes[2], StunAttribute::MessageIntegrity);
+
+        let (_, msg) = parse_message(BINDING_RESPONSE_IPV6).unwrap();
+        assert_eq!(
+            msg.attributes[1],
+            StunAttribute::XorMappedAddress(
+                "[2001:db8:1234:5678:11:2233:4455:6677]:32853"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_allocate_error() {
+        let mut buf = vec![0x01, 0x13, 0x00, 0x1c];
+        buf.extend_from_slice(&STUN_MAGIC_COOKIE.to_be_bytes());
+        buf.extend_from_slice(&[0x11; 12]);
+        // ERROR-CODE 401 Unauthorized
+        buf.extend_from_slice(&[0x00, 0x09, 0x00, 0x10, 0x00, 0x00, 0x04, 0x01]);
+        buf.extend_from_slice(b"Unauthorized");
+        // REALM
+        buf.extend_from_slice(&[0x00, 0x14, 0x00, 0x03]);
+        buf.extend_from_slice(b"abc\x00");
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(msg.class, StunClass::ErrorResponse);
+        assert_eq!(msg.method, StunMethod::Allocate as u16);
+        assert_eq!(
+            msg.attributes,
+            vec![
+                StunAttribute::ErrorCode(401, b"Unauthorized"),
+                StunAttribute::Realm(b"abc"),
+            ]
+        );
+
+        // Invalid error class.
+        buf[26] = 0x07;
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(
+            msg.attributes[0],
+            StunAttribute::Invalid(STUN_ATTR_ERROR_CODE)
+        );
+
+        // Attribute overflowing the message.
+        buf[3] = 0x18;
+        assert!(parse_message(&buf).is_err());
+    }
+
+    #[test]
+    fn test_parse_header_invalid() {
+        // ChannelData
+        assert!(parse_header(&[0x40, 0x00, 0x00, 0x04, 0x21, 0x12, 0xa4, 0x42]).is_err());
+        // Classic STUN, no magic cookie.
+        let mut buf = BINDING_RESPONSE_IPV4.to_vec();
+        buf[4] = 0;
+        assert!(parse_message(&buf).is_err());
+    }
+}
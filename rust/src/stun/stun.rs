@@ -0,0 +1,514 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, StunAttribute, StunClass, StunMessage, StunMethod};
+use crate::applayer::{self, *};
+use crate::core::{
+    AppProto, Direction, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, DIR_BOTH, IPPROTO_UDP,
+};
+use crate::detect::EnumString;
+use crate::frames::Frame;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::net::SocketAddr;
+use std::os::raw::{c_char, c_int, c_void};
+
+static STUN_LIMITS: AppLayerLimits = AppLayerLimits::new(256);
+
+pub(super) static mut ALPROTO_STUN: AppProto = ALPROTO_UNKNOWN;
+
+#[derive(AppLayerFrameType)]
+pub enum StunFrameType {
+    /// A complete message.
+    Pdu,
+}
+
+#[derive(AppLayerEvent)]
+pub enum StunEvent {
+    /// Not a valid STUN message.
+    MalformedMessage,
+    /// Attribute with a value that could not be parsed.
+    InvalidAttribute,
+    /// Requests not answered while the transaction limit was reached.
+    TooManyTransactions,
+}
+
+/// The attributes of a message that are logged and inspected.
+#[derive(Debug)]
+pub struct StunMessageData {
+    pub class: StunClass,
+    pub username: Option<Vec<u8>>,
+    pub realm: Option<Vec<u8>>,
+    pub software: Option<Vec<u8>>,
+    pub error_code: Option<u16>,
+    pub error_reason: Option<Vec<u8>>,
+    pub mapped_address: Option<SocketAddr>,
+    pub xor_mapped_address: Option<SocketAddr>,
+    pub xor_relayed_address: Option<SocketAddr>,
+    pub lifetime: Option<u32>,
+    pub requested_transport: Option<u8>,
+    pub message_integrity: bool,
+}
+
+impl StunMessageData {
+    /// Returns the data and whether an attribute could not be parsed.
+    fn new(msg: &StunMessage) -> (Self, bool) {
+        let mut data = Self {
+            class: msg.class,
+            username: None,
+            realm: None,
+            software: None,
+            error_code: None,
+            error_reason: None,
+            mapped_address: None,
+            xor_mapped_address: None,
+            xor_relayed_address: None,
+            lifetime: None,
+            requested_transport: None,
+            message_integrity: false,
+        };
+        let mut invalid = false;
+        for attr in &msg.attributes {
+            match attr {
+                StunAttribute::MappedAddress(addr) => data.mapped_address = Some(*addr),
+                StunAttribute::XorMappedAddress(addr) => data.xor_mapped_address = Some(*addr),
+                StunAttribute::XorRelayedAddress(addr) => data.xor_relayed_address = Some(*addr),
+                StunAttribute::Username(v) => data.username = Some(v.to_vec()),
+                StunAttribute::Realm(v) => data.realm = Some(v.to_vec()),
+                StunAttribute::Software(v) => data.software = Some(v.to_vec()),
+                StunAttribute::ErrorCode(code, reason) => {
+                    data.error_code = Some(*code);
+                    data.error_reason = Some(reason.to_vec());
+                }
+                StunAttribute::Lifetime(v) => data.lifetime = Some(*v),
+                StunAttribute::RequestedTransport(v) => data.requested_transport = Some(*v),
+                StunAttribute::MessageIntegrity => data.message_integrity = true,
+                StunAttribute::Other(_) => {}
+                StunAttribute::Invalid(_) => invalid = true,
+            }
+        }
+        (data, invalid)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StunTransaction {
+    tx_id: u64,
+    pub method: u16,
+    pub transaction_id: Vec<u8>,
+    /// Request or indication.
+    pub request: Option<StunMessageData>,
+    pub response: Option<StunMessageData>,
+    complete: bool,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for StunTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+impl TxLimit for StunTransaction {
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn force_complete(&mut self) {
+        self.complete = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
+#[derive(Default)]
+pub struct StunState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<StunTransaction>,
+    tx_index_completed: usize,
+}
+
+impl State<StunTransaction> for StunState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&StunTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl StunState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.tx_index_completed = 0;
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&StunTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self, tx_data: AppLayerTxData) -> &mut StunTransaction {
+        self.tx_id += 1;
+        let mut tx = StunTransaction {
+            tx_id: self.tx_id,
+            tx_data,
+            ..Default::default()
+        };
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            STUN_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            StunEvent::TooManyTransactions as u8,
+        );
+        self.transactions.push_back(tx);
+        // just pushed
+        self.transactions.back_mut().unwrap()
+    }
+
+    /// Find the index of the unanswered request a response is for.
+    fn find_request(&self, msg: &StunMessage) -> Option<usize> {
+        self.transactions.iter().position(|tx| {
+            !tx.complete
+                && tx.request.is_some()
+                && tx.method == msg.method
+                && tx.transaction_id == msg.transaction_id
+        })
+    }
+
+    fn handle_message(&mut self, msg: &StunMessage, direction: Direction) -> &mut StunTransaction {
+        let (data, invalid) = StunMessageData::new(msg);
+        let tx = if msg.class.is_response() {
+            // The response without a request is logged on its own.
+            let tx = match self.find_request(msg) {
+                Some(index) => &mut self.transactions[index],
+                None => self.new_tx(AppLayerTxData::for_direction(direction)),
+            };
+            tx.response = Some(data);
+            tx.complete = true;
+            tx
+        } else {
+            let tx_data = if msg.class == StunClass::Indication {
+                AppLayerTxData::for_direction(direction)
+            } else {
+                AppLayerTxData::new()
+            };
+            let tx = self.new_tx(tx_data);
+            tx.request = Some(data);
+            // Indications are not answered.
+            tx.complete = msg.class == StunClass::Indication;
+            tx
+        };
+        tx.method = msg.method;
+        tx.transaction_id = msg.transaction_id.to_vec();
+        if invalid {
+            tx.tx_data.set_event(StunEvent::InvalidAttribute as u8);
+        }
+        tx
+    }
+
+    fn parse(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        if input.is_empty() {
+            return AppLayerResult::ok();
+        }
+        // TURN ChannelData messages share the port with STUN, they carry
+        // relayed application data and are not inspected.
+        if input[0] & 0xc0 == 0x40 {
+            return AppLayerResult::ok();
+        }
+        let frame = Frame::new(
+            flow,
+            stream_slice,
+            input,
+            input.len() as i64,
+            StunFrameType::Pdu as u8,
+            None,
+        );
+        match parser::parse_message(input) {
+            Ok((rem, msg)) if rem.is_empty() => {
+                let tx = self.handle_message(&msg, direction);
+                if let Some(frame) = frame {
+                    frame.set_tx(flow, tx.tx_id - 1);
+                }
+            }
+            _ => {
+                let tx = self.new_tx(AppLayerTxData::for_direction(direction));
+                tx.complete = true;
+                tx.tx_data.set_event(StunEvent::MalformedMessage as u8);
+            }
+        }
+        AppLayerResult::ok()
+    }
+}
+
+/// Probe for a message with the magic cookie and a length matching the
+/// datagram. Responses go to the client.
+fn probe(input: &[u8]) -> Option<Direction> {
+    let (rem, (_, len, _)) = parser::parse_header(input).ok()?;
+    if rem.len() != len as usize {
+        return None;
+    }
+    let (_, msg) = parser::parse_message(input).ok()?;
+    // The class bits alone are too weak, require a known method.
+    StunMethod::from_u(msg.method)?;
+    match msg.class {
+        StunClass::Request => Some(Direction::ToServer),
+        StunClass::SuccessResponse | StunClass::ErrorResponse => Some(Direction::ToClient),
+        StunClass::Indication => None,
+    }
+}
+
+// C exports.
+
+unsafe extern "C" fn rs_stun_probing_parser(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    match probe(slice) {
+        Some(dir) => {
+            if (direction & DIR_BOTH) != dir.into() {
+                *rdir = dir as u8;
+            }
+            ALPROTO_STUN
+        }
+        None => ALPROTO_FAILED,
+    }
+}
+
+extern "C" fn rs_stun_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = StunState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_stun_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut StunState));
+}
+
+unsafe extern "C" fn rs_stun_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, StunState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_stun_parse_request(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, StunState);
+    state.parse(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_stun_parse_response(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, StunState);
+    state.parse(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_stun_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, StunState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_stun_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, StunState);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn rs_stun_tx_get_alstate_progress(tx: *mut c_void, _direction: u8) -> c_int {
+    let tx = cast_pointer!(tx, StunTransaction);
+    if tx.complete {
+        return 1;
+    }
+    return 0;
+}
+
+export_tx_data_get!(rs_stun_get_tx_data, StunTransaction);
+export_state_data_get!(rs_stun_get_state_data, StunState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"stun\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterStunParser() {
+    let default_port = CString::new("[3478]").unwrap();
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: Some(rs_stun_probing_parser),
+        probe_tc: Some(rs_stun_probing_parser),
+        min_depth: 0,
+        max_depth: parser::STUN_HEADER_LEN as u16,
+        state_new: rs_stun_state_new,
+        state_free: rs_stun_state_free,
+        tx_free: rs_stun_state_tx_free,
+        parse_ts: rs_stun_parse_request,
+        parse_tc: rs_stun_parse_response,
+        get_tx_count: rs_stun_state_get_tx_count,
+        get_tx: rs_stun_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_stun_tx_get_alstate_progress,
+        get_eventinfo: Some(StunEvent::get_event_info),
+        get_eventinfo_byid: Some(StunEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<StunState, StunTransaction>),
+        get_tx_data: rs_stun_get_tx_data,
+        get_state_data: rs_stun_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(StunFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(StunFrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_STUN = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        STUN_LIMITS.configure("stun");
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_STUN);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for STUN.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn message(message_type: u16, tid: u8, attrs: &[u8]) -> Vec<u8> {
+        let mut buf = message_type.to_be_bytes().to_vec();
+        buf.extend_from_slice(&(attrs.len() as u16).to_be_bytes());
+        buf.extend_from_slice(&parser::STUN_MAGIC_COOKIE.to_be_bytes());
+        buf.extend_from_slice(&[tid; 12]);
+        buf.extend_from_slice(attrs);
+        buf
+    }
+
+    fn parse(state: &mut StunState, direction: Direction, buf: &[u8]) {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse(std::ptr::null(), &ss, direction);
+    }
+
+    #[test]
+    fn test_stun_probe() {
+        assert_eq!(probe(&message(0x0001, 1, &[])), Some(Direction::ToServer));
+        assert_eq!(probe(&message(0x0003, 1, &[])), Some(Direction::ToServer));
+        assert_eq!(probe(&message(0x0101, 1, &[])), Some(Direction::ToClient));
+        // Unknown method.
+        assert_eq!(probe(&message(0x0002, 1, &[])), None);
+        // Length not matching the datagram.
+        let mut buf = message(0x0001, 1, &[]);
+        buf.push(0);
+        assert_eq!(probe(&buf), None);
+    }
+
+    #[test]
+    fn test_stun_allocate() {
+        let mut state = StunState::new();
+        // Allocate request with REQUESTED-TRANSPORT UDP.
+        parse(
+            &mut state,
+            Direction::ToServer,
+            &message(0x0003, 1, &[0x00, 0x19, 0x00, 0x04, 0x11, 0x00, 0x00, 0x00]),
+        );
+        // Binding request in between.
+        parse(&mut state, Direction::ToServer, &message(0x0001, 2, &[]));
+        // 401 with a realm for the allocate request.
+        let mut attrs = vec![0x00, 0x09, 0x00, 0x04, 0x00, 0x00, 0x04, 0x01];
+        attrs.extend_from_slice(&[0x00, 0x14, 0x00, 0x04]);
+        attrs.extend_from_slice(b"test");
+        parse(&mut state, Direction::ToClient, &message(0x0113, 1, &attrs));
+
+        assert_eq!(state.transactions.len(), 2);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.method, parser::StunMethod::Allocate as u16);
+        assert_eq!(tx.request.as_ref().unwrap().requested_transport, Some(17));
+        let response = tx.response.as_ref().unwrap();
+        assert_eq!(response.class, StunClass::ErrorResponse);
+        assert_eq!(response.error_code, Some(401));
+        assert_eq!(response.realm.as_deref(), Some(&b"test"[..]));
+        assert!(tx.complete);
+        assert!(!state.transactions[1].complete);
+
+        // Unsolicited response.
+        parse(&mut state, Direction::ToClient, &message(0x0101, 3, &[]));
+        assert_eq!(state.transactions.len(), 3);
+        assert!(state.transactions[2].request.is_none());
+
+        // ChannelData is skipped, garbage is a malformed message.
+        parse(&mut state, Direction::ToServer, &[0x40, 0x00, 0x00, 0x00]);
+        assert_eq!(state.transactions.len(), 3);
+        parse(&mut state, Direction::ToServer, &[0x00, 0x01, 0x00]);
+        assert_eq!(state.transactions.len(), 4);
+    }
+
+    #[test]
+    fn test_stun_tx_limit() {
+        let mut state = StunState::new();
+        for i in 0..259 {
+            parse(
+                &mut state,
+                Direction::ToServer,
+                &message(0x0001, i as u8, &[]),
+            );
+        }
+        assert_eq!(state.transactions.len(), 259);
+        // the oldest unanswered requests are completed to be freed
+        assert!(state.transactions[0].complete);
+        assert!(state.transactions[1].complete);
+        assert!(!state.transactions[2].complete);
+        assert_eq!(state.state_data.tx_limit_reached, 2);
+    }
+}
@@ -0,0 +1,60 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::syslog::SyslogTransaction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_syslog(tx: &SyslogTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("syslog")?;
+    if let Some(format) = tx.format {
+        js.set_string("format", format.as_str())?;
+    }
+    if let (Some(facility), Some(severity)) = (tx.facility(), tx.severity()) {
+        js.set_uint("facility", facility as u64)?;
+        js.set_uint("severity", severity as u64)?;
+    }
+    if let Some(version) = tx.version {
+        js.set_uint("version", version as u64)?;
+    }
+    if let Some(ref timestamp) = tx.timestamp {
+        js.set_string_from_bytes("timestamp", timestamp)?;
+    }
+    if let Some(ref hostname) = tx.hostname {
+        js.set_string_from_bytes("hostname", hostname)?;
+    }
+    if let Some(ref appname) = tx.appname {
+        js.set_string_from_bytes("appname", appname)?;
+    }
+    if let Some(ref procid) = tx.procid {
+        js.set_string_from_bytes("procid", procid)?;
+    }
+    if let Some(ref msgid) = tx.msgid {
+        js.set_string_from_bytes("msgid", msgid)?;
+    }
+    js.set_uint("message_length", tx.msg_len as u64)?;
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCSyslogLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, SyslogTransaction);
+    log_syslog(tx, js).is_ok()
+}
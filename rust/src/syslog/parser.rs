@@ -0,0 +1,499 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! Syslog message parsing.
+//!
+//! Both the BSD format (RFC 3164) and the current format (RFC 5424) are
+//! parsed. The parsing is lenient: a message is always returned, along
+//! with the list of the violations found in its header. The structured
+//! data and the message itself are not parsed.
+
+use nom7::bytes::complete::{tag, take_while_m_n};
+use nom7::bytes::streaming::{take, take_until};
+use nom7::character::streaming::{char, digit1};
+use nom7::combinator::verify;
+use nom7::error::{make_error, ErrorKind};
+use nom7::IResult;
+
+/// Highest PRI value: facility 23, severity 7.
+const SYSLOG_MAX_PRI: u16 = 191;
+
+/// Header field sizes (RFC 5424, section 6).
+const SYSLOG_MAX_HOSTNAME_LEN: usize = 255;
+const SYSLOG_MAX_APPNAME_LEN: usize = 48;
+const SYSLOG_MAX_PROCID_LEN: usize = 128;
+const SYSLOG_MAX_MSGID_LEN: usize = 32;
+/// Size of the TAG of the BSD format (RFC 3164, section 4.1.3).
+const SYSLOG_MAX_TAG_LEN: usize = 32;
+
+const SYSLOG_MONTHS: [&[u8]; 12] = [
+    b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyslogFormat {
+    Rfc3164,
+    Rfc5424,
+}
+
+impl SyslogFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyslogFormat::Rfc3164 => "rfc3164",
+            SyslogFormat::Rfc5424 => "rfc5424",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyslogViolation {
+    /// Missing PRI or out of range value.
+    InvalidPri,
+    InvalidTimestamp,
+    MissingHostname,
+    /// Hostname too long or with characters not allowed in a name or an
+    /// address.
+    InvalidHostname,
+    /// Other header field too long, or truncated header.
+    InvalidHeader,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SyslogMessage<'a> {
+    pub pri: Option<u8>,
+    pub format: SyslogFormat,
+    /// Only set for RFC 5424.
+    pub version: Option<u8>,
+    pub timestamp: Option<&'a [u8]>,
+    pub hostname: Option<&'a [u8]>,
+    pub appname: Option<&'a [u8]>,
+    pub procid: Option<&'a [u8]>,
+    pub msgid: Option<&'a [u8]>,
+    /// The message after the header.
+    pub msg: &'a [u8],
+    pub violations: Vec<SyslogViolation>,
+}
+
+impl<'a> SyslogMessage<'a> {
+    fn new(pri: Option<u8>, format: SyslogFormat) -> Self {
+        Self {
+            pri,
+            format,
+            version: None,
+            timestamp: None,
+            hostname: None,
+            appname: None,
+            procid: None,
+            msgid: None,
+            msg: &[],
+            violations: Vec::new(),
+        }
+    }
+}
+
+/// Parse the PRI part, "<" 1*3DIGIT ">", without leading zeros.
+pub fn parse_pri(i: &[u8]) -> IResult<&[u8], u8> {
+    let (i, _) = tag("<")(i)?;
+    let (i, digits) = verify(
+        take_while_m_n(1, 3, |c: u8| c.is_ascii_digit()),
+        |d: &[u8]| d.len() == 1 || d[0] != b'0',
+    )(i)?;
+    let (i, _) = tag(">")(i)?;
+    let pri = digits
+        .iter()
+        .fold(0u16, |acc, &d| acc * 10 + (d - b'0') as u16);
+    if pri > SYSLOG_MAX_PRI {
+        return Err(nom7::Err::Error(make_error(i, ErrorKind::Verify)));
+    }
+    Ok((i, pri as u8))
+}
+
+/// Split the next space separated field.
+fn next_field(i: &[u8]) -> Option<(&[u8], &[u8])> {
+    if i.is_empty() {
+        return None;
+    }
+    match i.iter().position(|&c| c == b' ') {
+        Some(pos) => Some((&i[pos + 1..], &i[..pos])),
+        None => Some((&[], i)),
+    }
+}
+
+fn is_digits(i: &[u8]) -> bool {
+    !i.is_empty() && i.iter().all(|c| c.is_ascii_digit())
+}
+
+/// Check a RFC 3339 timestamp as restricted by RFC 5424, section 6.2.3:
+/// `YYYY-MM-DDThh:mm:ss[.frac](Z|+hh:mm|-hh:mm)`.
+fn is_valid_rfc5424_timestamp(ts: &[u8]) -> bool {
+    if ts.len() < 20 {
+        return false;
+    }
+    let (date, time) = (&ts[..10], &ts[11..]);
+    if ts[10] != b'T'
+        || !is_digits(&date[..4])
+        || date[4] != b'-'
+        || !is_digits(&date[5..7])
+        || date[7] != b'-'
+        || !is_digits(&date[8..10])
+    {
+        return false;
+    }
+    if !is_digits(&time[..2])
+        || time[2] != b':'
+        || !is_digits(&time[3..5])
+        || time[5] != b':'
+        || !is_digits(&time[6..8])
+    {
+        return false;
+    }
+    let mut rem = &time[8..];
+    if let Some(frac) = rem.strip_prefix(b".") {
+        let len = frac.iter().take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 || len > 6 {
+            return false;
+        }
+        rem = &frac[len..];
+    }
+    match rem {
+        b"Z" => true,
+        [b'+' | b'-', h1, h2, b':', m1, m2] => [h1, h2, m1, m2].iter().all(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Check a RFC 3164 timestamp: `Mmm dd hh:mm:ss`, the day being padded
+/// with a space.
+fn is_valid_rfc3164_timestamp(ts: &[u8]) -> bool {
+    ts.len() == 15
+        && SYSLOG_MONTHS.contains(&&ts[..3])
+        && ts[3] == b' '
+        && (ts[4] == b' ' || ts[4].is_ascii_digit())
+        && ts[5].is_ascii_digit()
+        && ts[6] == b' '
+        && is_digits(&ts[7..9])
+        && ts[9] == b':'
+        && is_digits(&ts[10..12])
+        && ts[12] == b':'
+        && is_digits(&ts[13..15])
+}
+
+/// Hostnames are names, IPv4 or IPv6 addresses.
+fn is_valid_hostname(name: &[u8]) -> bool {
+    !name.is_empty()
+        && name.len() <= SYSLOG_MAX_HOSTNAME_LEN
+        && name
+            .iter()
+            .all(|&c| c.is_ascii_alphanumeric() || matches!(c, b'.' | b'-' | b'_' | b':'))
+}
+
+/// Get a RFC 5424 header field, `None` for the NILVALUE.
+fn nil_field(field: &[u8]) -> Option<&[u8]> {
+    if field == b"-" {
+        None
+    } else {
+        Some(field)
+    }
+}
+
+/// Skip the structured data, either the NILVALUE or a list of
+/// `[id param="value"...]` elements, in which `]` and `"` can be
+/// escaped.
+fn skip_structured_data(i: &[u8]) -> Option<&[u8]> {
+    if let Some(rem) = i.strip_prefix(b"-") {
+        return Some(rem);
+    }
+    let mut rem = i;
+    while let Some(element) = rem.strip_prefix(b"[") {
+        let mut in_value = false;
+        let mut escaped = false;
+        let mut end = None;
+        for (pos, &c) in element.iter().enumerate() {
+            if escaped {
+                escaped = false;
+            } else if c == b'\\' && in_value {
+                escaped = true;
+            } else if c == b'"' {
+                in_value = !in_value;
+            } else if c == b']' && !in_value {
+                end = Some(pos);
+                break;
+            }
+        }
+        rem = &element[end? + 1..];
+    }
+    if rem.len() == i.len() {
+        return None;
+    }
+    Some(rem)
+}
+
+fn parse_rfc5424<'a>(i: &'a [u8], msg: &mut SyslogMessage<'a>) {
+    let fields = [
+        (&mut msg.timestamp, 0),
+        (&mut msg.hostname, SYSLOG_MAX_HOSTNAME_LEN),
+        (&mut msg.appname, SYSLOG_MAX_APPNAME_LEN),
+        (&mut msg.procid, SYSLOG_MAX_PROCID_LEN),
+        (&mut msg.msgid, SYSLOG_MAX_MSGID_LEN),
+    ];
+    let mut rem = i;
+    for (value, max_len) in fields {
+        let (r, field) = match next_field(rem) {
+            Some(f) => f,
+            None => {
+                msg.violations.push(SyslogViolation::InvalidHeader);
+                return;
+            }
+        };
+        rem = r;
+        *value = nil_field(field);
+        if max_len > 0 && field.len() > max_len {
+            msg.violations.push(SyslogViolation::InvalidHeader);
+        }
+    }
+    if let Some(ts) = msg.timestamp {
+        if !is_valid_rfc5424_timestamp(ts) {
+            msg.violations.push(SyslogViolation::InvalidTimestamp);
+        }
+    }
+    match msg.hostname {
+        Some(hostname) => {
+            if !is_valid_hostname(hostname) {
+                msg.violations.push(SyslogViolation::InvalidHostname);
+            }
+        }
+        None => msg.violations.push(SyslogViolation::MissingHostname),
+    }
+    match skip_structured_data(rem) {
+        Some(r) => {
+            msg.msg = r.strip_prefix(b" ").unwrap_or(r);
+        }
+        None => {
+            msg.violations.push(SyslogViolation::InvalidHeader);
+            msg.msg = rem;
+        }
+    }
+}
+
+fn parse_rfc3164<'a>(i: &'a [u8], msg: &mut SyslogMessage<'a>) {
+    let ts = i.get(..15).filter(|ts| is_valid_rfc3164_timestamp(ts));
+    let rem = match ts {
+        Some(ts) if i.get(15) == Some(&b' ') => {
+            msg.timestamp = Some(ts);
+            &i[16..]
+        }
+        _ => {
+            // relays add their own header in this case (RFC 3164,
+            // section 4.3.3), the whole content is the message
+            msg.violations.push(SyslogViolation::InvalidTimestamp);
+            msg.msg = i;
+            return;
+        }
+    };
+    let (after_host, hostname) = match next_field(rem) {
+        Some(f) => f,
+        None => {
+            msg.violations.push(SyslogViolation::MissingHostname);
+            return;
+        }
+    };
+    // "<13>Oct 11 22:14:15 su[123]: ..." has no hostname, the first
+    // field being the tag
+    let rem = if hostname.ends_with(b":") || hostname.contains(&b'[') {
+        msg.violations.push(SyslogViolation::MissingHostname);
+        rem
+    } else {
+        if !is_valid_hostname(hostname) {
+            msg.violations.push(SyslogViolation::InvalidHostname);
+        }
+        msg.hostname = Some(hostname);
+        after_host
+    };
+    // TAG, optionally followed by "[pid]", then ":"
+    let tag_len = rem
+        .iter()
+        .take(SYSLOG_MAX_TAG_LEN + 1)
+        .take_while(|&&c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'_' | b'.' | b'/'))
+        .count();
+    let after_tag = &rem[tag_len..];
+    if tag_len == 0 || tag_len > SYSLOG_MAX_TAG_LEN {
+        msg.msg = rem;
+        return;
+    }
+    if let Some(pid) = after_tag.strip_prefix(b"[") {
+        if let Some(end) = pid.iter().position(|&c| c == b']') {
+            if let Some(r) = pid[end + 1..].strip_prefix(b":") {
+                msg.appname = Some(&rem[..tag_len]);
+                msg.procid = Some(&pid[..end]);
+                msg.msg = r.strip_prefix(b" ").unwrap_or(r);
+                return;
+            }
+        }
+    } else if let Some(r) = after_tag.strip_prefix(b":") {
+        msg.appname = Some(&rem[..tag_len]);
+        msg.msg = r.strip_prefix(b" ").unwrap_or(r);
+        return;
+    }
+    msg.msg = rem;
+}
+
+/// Parse a syslog message, without its TCP framing.
+pub fn parse_message(input: &[u8]) -> SyslogMessage {
+    let (i, pri) = match parse_pri(input) {
+        Ok((i, pri)) => (i, Some(pri)),
+        Err(_) => {
+            let mut msg = SyslogMessage::new(None, SyslogFormat::Rfc3164);
+            msg.violations.push(SyslogViolation::InvalidPri);
+            parse_rfc3164(input, &mut msg);
+            return msg;
+        }
+    };
+    // VERSION is NONZERO-DIGIT 0*2DIGIT, followed by a space
+    let version_len = i.iter().take(4).take_while(|c| c.is_ascii_digit()).count();
+    if (1..=3).contains(&version_len) && i[0] != b'0' && i.get(version_len) == Some(&b' ') {
+        let mut msg = SyslogMessage::new(pri, SyslogFormat::Rfc5424);
+        let version = i[..version_len]
+            .iter()
+            .fold(0u16, |acc, &d| acc * 10 + (d - b'0') as u16);
+        msg.version = Some(version.min(u8::MAX as u16) as u8);
+        parse_rfc5424(&i[version_len + 1..], &mut msg);
+        return msg;
+    }
+    let mut msg = SyslogMessage::new(pri, SyslogFormat::Rfc3164);
+    parse_rfc3164(i, &mut msg);
+    msg
+}
+
+/// Parse a message framed with octet counting (RFC 6587, section
+/// 3.4.1): the length of the message, a space and the message.
+pub fn parse_octet_counted_frame(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (i, len) = verify(digit1, |d: &[u8]| d.len() <= 9 && d[0] != b'0')(i)?;
+    let (i, _) = char(' ')(i)?;
+    let len = len
+        .iter()
+        .fold(0usize, |acc, &d| acc * 10 + (d - b'0') as usize);
+    take(len)(i)
+}
+
+/// Parse a message framed with a trailing LF (RFC 6587, section 3.4.2).
+pub fn parse_lf_frame(i: &[u8]) -> IResult<&[u8], &[u8]> {
+    let (i, frame) = take_until("\n")(i)?;
+    let (i, _) = char('\n')(i)?;
+    Ok((i, frame.strip_suffix(b"\r").unwrap_or(frame)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_syslog_parse_pri() {
+        assert_eq!(parse_pri(b"<34>x"), Ok((&b"x"[..], 34)));
+        assert_eq!(parse_pri(b"<0>x"), Ok((&b"x"[..], 0)));
+        assert_eq!(parse_pri(b"<191>x"), Ok((&b"x"[..], 191)));
+        assert!(parse_pri(b"<192>x").is_err());
+        assert!(parse_pri(b"<034>x").is_err());
+        assert!(parse_pri(b"<>x").is_err());
+        assert!(parse_pri(b"<1234>x").is_err());
+        assert!(parse_pri(b"34>x").is_err());
+    }
+
+    #[test]
+    fn test_syslog_parse_rfc5424() {
+        let msg = parse_message(
+            b"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 \
+              [exampleSDID@32473 iut=\"3\" eventSource=\"Appl\\]ication\"] An application event",
+        );
+        assert_eq!(msg.pri, Some(165));
+        assert_eq!(msg.format, SyslogFormat::Rfc5424);
+        assert_eq!(msg.version, Some(1));
+        assert_eq!(msg.timestamp, Some(&b"2003-10-11T22:14:15.003Z"[..]));
+        assert_eq!(msg.hostname, Some(&b"mymachine.example.com"[..]));
+        assert_eq!(msg.appname, Some(&b"evntslog"[..]));
+        assert_eq!(msg.procid, None);
+        assert_eq!(msg.msgid, Some(&b"ID47"[..]));
+        assert_eq!(msg.msg, b"An application event");
+        assert!(msg.violations.is_empty());
+
+        let msg = parse_message(b"<34>1 2003-10-11 - su - - -");
+        assert_eq!(
+            msg.violations,
+            vec![
+                SyslogViolation::InvalidTimestamp,
+                SyslogViolation::MissingHostname
+            ]
+        );
+
+        // truncated header
+        let msg = parse_message(b"<34>1 2003-10-11T22:14:15+02:00 host");
+        assert_eq!(msg.violations, vec![SyslogViolation::InvalidHeader]);
+        assert_eq!(msg.hostname, Some(&b"host"[..]));
+    }
+
+    #[test]
+    fn test_syslog_parse_rfc3164() {
+        let msg = parse_message(b"<34>Oct 11 22:14:15 mymachine su[230]: 'su root' failed");
+        assert_eq!(msg.pri, Some(34));
+        assert_eq!(msg.format, SyslogFormat::Rfc3164);
+        assert_eq!(msg.timestamp, Some(&b"Oct 11 22:14:15"[..]));
+        assert_eq!(msg.hostname, Some(&b"mymachine"[..]));
+        assert_eq!(msg.appname, Some(&b"su"[..]));
+        assert_eq!(msg.procid, Some(&b"230"[..]));
+        assert_eq!(msg.msg, b"'su root' failed");
+        assert!(msg.violations.is_empty());
+
+        let msg = parse_message(b"<13>Feb  5 17:32:18 sshd: session opened");
+        assert_eq!(msg.hostname, None);
+        assert_eq!(msg.appname, Some(&b"sshd"[..]));
+        assert_eq!(msg.violations, vec![SyslogViolation::MissingHostname]);
+
+        let msg = parse_message(b"<13>use the BFG!");
+        assert_eq!(msg.timestamp, None);
+        assert_eq!(msg.msg, b"use the BFG!");
+        assert_eq!(msg.violations, vec![SyslogViolation::InvalidTimestamp]);
+
+        let msg = parse_message(b"Oct 11 22:14:15 host$ app: x");
+        assert_eq!(msg.pri, None);
+        assert_eq!(
+            msg.violations,
+            vec![
+                SyslogViolation::InvalidPri,
+                SyslogViolation::InvalidHostname
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syslog_parse_frames() {
+        let buf = b"11 <13>1 - - -<13>msg\n";
+        let (rem, frame) = parse_octet_counted_frame(buf).unwrap();
+        assert_eq!(frame, b"<13>1 - - -");
+        let (rem, frame) = parse_lf_frame(rem).unwrap();
+        assert_eq!(frame, b"<13>msg");
+        assert!(rem.is_empty());
+
+        assert!(matches!(
+            parse_octet_counted_frame(b"20 <13>"),
+            Err(nom7::Err::Incomplete(_))
+        ));
+        assert!(matches!(
+            parse_lf_frame(b"<13>msg\r"),
+            Err(nom7::Err::Incomplete(_))
+        ));
+        assert_eq!(parse_lf_frame(b"<13>msg\r\n").unwrap().1, b"<13>msg");
+    }
+}
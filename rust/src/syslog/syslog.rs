@@ -0,0 +1,542 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, SyslogFormat, SyslogViolation};
+use crate::applayer::{self, *};
+use crate::core::{AppProto, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, IPPROTO_TCP, IPPROTO_UDP};
+use crate::frames::Frame;
+use nom7 as nom;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+static SYSLOG_LIMITS: AppLayerLimits = AppLayerLimits::new(256);
+
+pub(super) static mut ALPROTO_SYSLOG: AppProto = ALPROTO_UNKNOWN;
+
+/// Messages longer than this raise an event. Over TCP, the parsing of
+/// the flow stops as the framing can't be trusted anymore.
+const SYSLOG_MAX_MSG_LEN: usize = 8192;
+
+#[derive(AppLayerFrameType)]
+pub enum SyslogFrameType {
+    /// A message, not including the TCP framing.
+    Pdu,
+}
+
+#[derive(AppLayerEvent)]
+pub enum SyslogEvent {
+    /// Missing PRI or out of range value.
+    InvalidPri,
+    InvalidTimestamp,
+    MissingHostname,
+    InvalidHostname,
+    /// Header field too long, or truncated header.
+    InvalidHeader,
+    /// Message exceeding `SYSLOG_MAX_MSG_LEN`.
+    MessageTooLong,
+    /// TCP data neither framed with octet counting nor with a LF.
+    InvalidFraming,
+    TooManyTransactions,
+}
+
+impl From<SyslogViolation> for SyslogEvent {
+    fn from(violation: SyslogViolation) -> Self {
+        match violation {
+            SyslogViolation::InvalidPri => SyslogEvent::InvalidPri,
+            SyslogViolation::InvalidTimestamp => SyslogEvent::InvalidTimestamp,
+            SyslogViolation::MissingHostname => SyslogEvent::MissingHostname,
+            SyslogViolation::InvalidHostname => SyslogEvent::InvalidHostname,
+            SyslogViolation::InvalidHeader => SyslogEvent::InvalidHeader,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SyslogTransaction {
+    tx_id: u64,
+    pub pri: Option<u8>,
+    pub format: Option<SyslogFormat>,
+    pub version: Option<u8>,
+    pub timestamp: Option<Vec<u8>>,
+    pub hostname: Option<Vec<u8>>,
+    pub appname: Option<Vec<u8>>,
+    pub procid: Option<Vec<u8>>,
+    pub msgid: Option<Vec<u8>>,
+    /// Length of the message after the header.
+    pub msg_len: usize,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for SyslogTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+/// A transaction is complete once parsed, so reaching the limit only
+/// raises the event on the new transaction.
+impl TxLimit for SyslogTransaction {
+    fn is_complete(&self) -> bool {
+        true
+    }
+
+    fn force_complete(&mut self) {}
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
+impl SyslogTransaction {
+    pub fn facility(&self) -> Option<u8> {
+        self.pri.map(|pri| pri >> 3)
+    }
+
+    pub fn severity(&self) -> Option<u8> {
+        self.pri.map(|pri| pri & 0x7)
+    }
+}
+
+#[derive(Default)]
+pub struct SyslogState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<SyslogTransaction>,
+    tx_index_completed: usize,
+    /// Set once the TCP framing is lost.
+    done: bool,
+}
+
+impl State<SyslogTransaction> for SyslogState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&SyslogTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl SyslogState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.tx_index_completed = 0;
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&SyslogTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self) -> SyslogTransaction {
+        self.tx_id += 1;
+        let mut tx = SyslogTransaction {
+            tx_id: self.tx_id,
+            ..Default::default()
+        };
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            SYSLOG_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            SyslogEvent::TooManyTransactions as u8,
+        );
+        tx
+    }
+
+    /// Raise an event on a transaction of its own, for the data that
+    /// could not be parsed into a message.
+    fn set_event(&mut self, event: SyslogEvent) {
+        let mut tx = self.new_tx();
+        tx.tx_data.set_event(event as u8);
+        self.transactions.push_back(tx);
+    }
+
+    fn handle_message(&mut self, input: &[u8]) {
+        let msg = parser::parse_message(input);
+        let mut tx = self.new_tx();
+        tx.pri = msg.pri;
+        tx.format = Some(msg.format);
+        tx.version = msg.version;
+        tx.timestamp = msg.timestamp.map(|v| v.to_vec());
+        tx.hostname = msg.hostname.map(|v| v.to_vec());
+        tx.appname = msg.appname.map(|v| v.to_vec());
+        tx.procid = msg.procid.map(|v| v.to_vec());
+        tx.msgid = msg.msgid.map(|v| v.to_vec());
+        tx.msg_len = msg.msg.len();
+        for violation in msg.violations {
+            tx.tx_data.set_event(SyslogEvent::from(violation) as u8);
+        }
+        if input.len() > SYSLOG_MAX_MSG_LEN {
+            tx.tx_data.set_event(SyslogEvent::MessageTooLong as u8);
+        }
+        self.transactions.push_back(tx);
+    }
+
+    fn parse_udp(&mut self, flow: *const Flow, stream_slice: &StreamSlice) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        if input.is_empty() {
+            return AppLayerResult::ok();
+        }
+        let _pdu = Frame::new(
+            flow,
+            stream_slice,
+            input,
+            input.len() as i64,
+            SyslogFrameType::Pdu as u8,
+            None,
+        );
+        // senders commonly terminate the datagram as they would a line
+        let len = input.len()
+            - input
+                .iter()
+                .rev()
+                .take_while(|&&c| matches!(c, b'\n' | b'\r' | 0))
+                .count();
+        self.handle_message(&input[..len]);
+        AppLayerResult::ok()
+    }
+
+    fn parse_tcp(&mut self, flow: *const Flow, stream_slice: &StreamSlice) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        let mut start = input;
+        while !start.is_empty() {
+            if self.done {
+                return AppLayerResult::ok();
+            }
+            let frame = if start[0].is_ascii_digit() {
+                parser::parse_octet_counted_frame(start)
+            } else {
+                parser::parse_lf_frame(start)
+            };
+            match frame {
+                Ok((rem, msg)) => {
+                    let _pdu = Frame::new(
+                        flow,
+                        stream_slice,
+                        msg,
+                        msg.len() as i64,
+                        SyslogFrameType::Pdu as u8,
+                        None,
+                    );
+                    start = rem;
+                    self.handle_message(msg);
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    if start.len() > SYSLOG_MAX_MSG_LEN {
+                        self.set_event(SyslogEvent::MessageTooLong);
+                        self.done = true;
+                        return AppLayerResult::ok();
+                    }
+                    let consumed = input.len() - start.len();
+                    let needed = start.len() + 1;
+                    return AppLayerResult::incomplete(consumed as u32, needed as u32);
+                }
+                Err(_) => {
+                    self.set_event(SyslogEvent::InvalidFraming);
+                    self.done = true;
+                    return AppLayerResult::ok();
+                }
+            }
+        }
+        // Input was fully consumed.
+        return AppLayerResult::ok();
+    }
+}
+
+/// Probe for a PRI, optionally preceded by the octet count over TCP.
+fn probe(input: &[u8], tcp: bool) -> AppProto {
+    let mut i = input;
+    if tcp {
+        let count = i.iter().take_while(|c| c.is_ascii_digit()).count();
+        if count > 0 {
+            match i.get(count) {
+                Some(b' ') => i = &i[count + 1..],
+                Some(_) => return ALPROTO_FAILED,
+                None => return ALPROTO_UNKNOWN,
+            }
+        }
+    }
+    if parser::parse_pri(i).is_ok() {
+        return unsafe { ALPROTO_SYSLOG };
+    }
+    // "<191>" and a character
+    if tcp && i.len() < 6 {
+        return ALPROTO_UNKNOWN;
+    }
+    ALPROTO_FAILED
+}
+
+// C exports.
+
+unsafe extern "C" fn rs_syslog_probing_parser_udp(
+    _flow: *const Flow, _direction: u8, input: *const u8, input_len: u32, _rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    probe(slice, false)
+}
+
+unsafe extern "C" fn rs_syslog_probing_parser_tcp(
+    _flow: *const Flow, _direction: u8, input: *const u8, input_len: u32, _rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    probe(slice, true)
+}
+
+extern "C" fn rs_syslog_state_new(_orig_state: *mut c_void, _orig_proto: AppProto) -> *mut c_void {
+    let state = SyslogState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_syslog_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut SyslogState));
+}
+
+unsafe extern "C" fn rs_syslog_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, SyslogState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_syslog_parse_request_udp(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, SyslogState);
+    state.parse_udp(flow, &stream_slice)
+}
+
+unsafe extern "C" fn rs_syslog_parse_request_tcp(
+    flow: *const Flow, state: *mut c_void, pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, SyslogState);
+    if AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0 {
+        return AppLayerResult::ok();
+    }
+    state.parse_tcp(flow, &stream_slice)
+}
+
+/// Syslog is one way, the data sent by the collector is ignored.
+unsafe extern "C" fn rs_syslog_parse_response(
+    _flow: *const Flow, _state: *mut c_void, _pstate: *mut c_void, _stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    AppLayerResult::ok()
+}
+
+unsafe extern "C" fn rs_syslog_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, SyslogState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_syslog_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, SyslogState);
+    return state.tx_id;
+}
+
+/// A transaction is a single message, complete once parsed.
+unsafe extern "C" fn rs_syslog_tx_get_alstate_progress(_tx: *mut c_void, _direction: u8) -> c_int {
+    return 1;
+}
+
+export_tx_data_get!(rs_syslog_get_tx_data, SyslogTransaction);
+export_state_data_get!(rs_syslog_get_state_data, SyslogState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"syslog\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterSyslogParser() {
+    let default_port = CString::new("[514]").unwrap();
+    let mut parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: Some(rs_syslog_probing_parser_udp),
+        probe_tc: None,
+        min_depth: 0,
+        max_depth: 16,
+        state_new: rs_syslog_state_new,
+        state_free: rs_syslog_state_free,
+        tx_free: rs_syslog_state_tx_free,
+        parse_ts: rs_syslog_parse_request_udp,
+        parse_tc: rs_syslog_parse_response,
+        get_tx_count: rs_syslog_state_get_tx_count,
+        get_tx: rs_syslog_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_syslog_tx_get_alstate_progress,
+        get_eventinfo: Some(SyslogEvent::get_event_info),
+        get_eventinfo_byid: Some(SyslogEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(applayer::state_get_tx_iterator::<SyslogState, SyslogTransaction>),
+        get_tx_data: rs_syslog_get_tx_data,
+        get_state_data: rs_syslog_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(SyslogFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(SyslogFrameType::ffi_name_from_id),
+    };
+
+    SYSLOG_LIMITS.configure("syslog");
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_SYSLOG = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_SYSLOG);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for Syslog/UDP.");
+    }
+
+    // RFC 3195 and RFC 5425 assign the ports 601 and 6514 to syslog over
+    // TCP. The port 514 of syslog over UDP is rsh over TCP.
+    let default_port = CString::new("[601,6514]").unwrap();
+    parser.default_port = default_port.as_ptr();
+    parser.ipproto = IPPROTO_TCP;
+    parser.probe_ts = Some(rs_syslog_probing_parser_tcp);
+    parser.parse_ts = rs_syslog_parse_request_tcp;
+    let ip_proto_str = CString::new("tcp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_SYSLOG = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_SYSLOG);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for Syslog/TCP.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::Direction;
+
+    fn parse_tcp(state: &mut SyslogState, buf: &[u8]) -> AppLayerResult {
+        let ss = StreamSlice::from_slice(buf, Direction::ToServer.into(), 0);
+        state.parse_tcp(std::ptr::null(), &ss)
+    }
+
+    #[test]
+    fn test_syslog_probe() {
+        unsafe {
+            ALPROTO_SYSLOG = 1;
+        }
+        assert_eq!(probe(b"<34>Oct 11 22:14:15 host su: x", false), 1);
+        assert_eq!(probe(b"42 <34>1 - - - - - -", true), 1);
+        assert_eq!(probe(b"<34", true), ALPROTO_UNKNOWN);
+        assert_eq!(probe(b"42", true), ALPROTO_UNKNOWN);
+        assert_eq!(probe(b"GET / HTTP/1.1\r\n", true), ALPROTO_FAILED);
+        assert_eq!(probe(b"42 <34", false), ALPROTO_FAILED);
+    }
+
+    #[test]
+    fn test_syslog_udp() {
+        let mut state = SyslogState::new();
+        let ss = StreamSlice::from_slice(
+            b"<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 - hello\n",
+            Direction::ToServer.into(),
+            0,
+        );
+        assert_eq!(state.parse_udp(std::ptr::null(), &ss), AppLayerResult::ok());
+        let tx = &state.transactions[0];
+        assert_eq!(tx.facility(), Some(20));
+        assert_eq!(tx.severity(), Some(5));
+        assert_eq!(tx.format, Some(SyslogFormat::Rfc5424));
+        assert_eq!(tx.hostname.as_deref(), Some(&b"mymachine.example.com"[..]));
+        assert_eq!(tx.msgid.as_deref(), Some(&b"ID47"[..]));
+        assert_eq!(tx.msg_len, 5);
+    }
+
+    #[test]
+    fn test_syslog_tcp() {
+        let mut state = SyslogState::new();
+        let r = parse_tcp(
+            &mut state,
+            b"<13>Feb  5 17:32:18 host sshd[42]: a\n<13>Feb  5 17:3",
+        );
+        assert_eq!(r, AppLayerResult::incomplete(37, 16));
+        let r = parse_tcp(&mut state, b"<13>Feb  5 17:32:19 host sshd[42]: b\n");
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), 2);
+        assert_eq!(state.transactions[1].procid.as_deref(), Some(&b"42"[..]));
+
+        // octet counting
+        let r = parse_tcp(&mut state, b"18 <13>1 - host - - -");
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), 3);
+
+        // bad framing stops the parsing
+        let r = parse_tcp(&mut state, b"0 <13>\n<13>host\n");
+        assert_eq!(r, AppLayerResult::ok());
+        assert!(state.done);
+        assert_eq!(state.transactions.len(), 4);
+        let r = parse_tcp(&mut state, b"<13>host\n");
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), 4);
+
+        let mut state = SyslogState::new();
+        let buf = vec![b'a'; SYSLOG_MAX_MSG_LEN + 1];
+        let r = parse_tcp(&mut state, &buf);
+        assert_eq!(r, AppLayerResult::ok());
+        assert!(state.done);
+    }
+
+    #[test]
+    fn test_syslog_tx_limit() {
+        let mut state = SyslogState::new();
+        let buf = b"<13>Feb  5 17:32:18 host sshd[42]: a\n".repeat(SYSLOG_LIMITS.max_tx() + 2);
+        let r = parse_tcp(&mut state, &buf);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), SYSLOG_LIMITS.max_tx() + 2);
+        assert_eq!(state.state_data.tx_limit_reached, 1);
+    }
+}
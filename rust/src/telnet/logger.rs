@@ -0,0 +1,91 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser;
+use super::telnet::{TelnetLoginResult, TelnetTransaction, TelnetTxType};
+use crate::core::Direction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn command_name(command: u8) -> &'static str {
+    match command {
+        parser::TELNET_WILL => "will",
+        parser::TELNET_WONT => "wont",
+        parser::TELNET_DO => "do",
+        parser::TELNET_DONT => "dont",
+        _ => "unknown",
+    }
+}
+
+fn log_telnet(tx: &TelnetTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("telnet")?;
+    match tx.tx_type {
+        TelnetTxType::Login => {
+            js.set_string("type", "login")?;
+        }
+        TelnetTxType::Command => {
+            js.set_string("type", "command")?;
+        }
+    }
+    if let Some(ref username) = tx.username {
+        js.set_string_from_bytes("username", username)?;
+    }
+    if tx.tx_type == TelnetTxType::Login {
+        js.set_bool("password_sent", tx.password_sent)?;
+    }
+    if let Some(result) = tx.login_result {
+        match result {
+            TelnetLoginResult::Success => js.set_string("login", "success")?,
+            TelnetLoginResult::Failure => js.set_string("login", "failure")?,
+        };
+    }
+    if let Some(ref command) = tx.command {
+        js.set_string_from_bytes("command", command)?;
+    }
+    if let Some(ref ttype) = tx.terminal_type {
+        js.set_string_from_bytes("terminal_type", ttype)?;
+    }
+    if !tx.options.is_empty() {
+        js.open_array("options")?;
+        for o in &tx.options {
+            js.start_object()?;
+            if o.direction == Direction::ToServer {
+                js.set_string("direction", "to_server")?;
+            } else {
+                js.set_string("direction", "to_client")?;
+            }
+            js.set_string("command", command_name(o.command))?;
+            if let Some(name) = parser::option_name(o.option) {
+                js.set_string("option", name)?;
+            } else {
+                js.set_string("option", &format!("unknown-{}", o.option))?;
+            }
+            js.close()?;
+        }
+        js.close()?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rs_telnet_logger_log(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, TelnetTransaction);
+    log_telnet(tx, js).is_ok()
+}
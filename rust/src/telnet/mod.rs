@@ -17,5 +17,6 @@
 
 //! Telnet application layer and parser module.
 
-pub mod telnet;
+pub mod logger;
 mod parser;
+pub mod telnet;
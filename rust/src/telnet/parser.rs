@@ -75,3 +75,204 @@ pub fn parse_welcome_message(i: &[u8]) -> IResult<&[u8], TelnetMessageType> {
         Ok((i, TelnetMessageType::Data(t)))
     }
 }
+
+pub const TELNET_SE: u8 = 240;
+pub const TELNET_SB: u8 = 250;
+pub const TELNET_WILL: u8 = 251;
+pub const TELNET_WONT: u8 = 252;
+pub const TELNET_DO: u8 = 253;
+pub const TELNET_DONT: u8 = 254;
+
+pub const TELNET_OPT_ECHO: u8 = 1;
+pub const TELNET_OPT_SGA: u8 = 3;
+pub const TELNET_OPT_STATUS: u8 = 5;
+pub const TELNET_OPT_TTYPE: u8 = 24;
+pub const TELNET_OPT_NAWS: u8 = 31;
+pub const TELNET_OPT_TSPEED: u8 = 32;
+pub const TELNET_OPT_LFLOW: u8 = 33;
+pub const TELNET_OPT_LINEMODE: u8 = 34;
+pub const TELNET_OPT_XDISPLOC: u8 = 35;
+pub const TELNET_OPT_OLD_ENVIRON: u8 = 36;
+pub const TELNET_OPT_AUTHENTICATION: u8 = 37;
+pub const TELNET_OPT_ENCRYPT: u8 = 38;
+pub const TELNET_OPT_NEW_ENVIRON: u8 = 39;
+
+/// Decoded IAC control message.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TelnetControl<'a> {
+    Will(u8),
+    Wont(u8),
+    Do(u8),
+    Dont(u8),
+    /// Option code and the raw suboption data (without IAC SE).
+    Suboption(u8, &'a [u8]),
+    /// Any other 2 byte IAC command, such as NOP, GA or SE.
+    Command(u8),
+}
+
+/// Decode a control message as returned by `parse_ctl_message`.
+pub fn decode_ctl_message(c: &[u8]) -> Option<TelnetControl> {
+    if c.len() < 2 || c[0] != 0xff {
+        return None;
+    }
+    match c[1] {
+        TELNET_WILL if c.len() > 2 => Some(TelnetControl::Will(c[2])),
+        TELNET_WONT if c.len() > 2 => Some(TelnetControl::Wont(c[2])),
+        TELNET_DO if c.len() > 2 => Some(TelnetControl::Do(c[2])),
+        TELNET_DONT if c.len() > 2 => Some(TelnetControl::Dont(c[2])),
+        TELNET_SB if c.len() > 2 => Some(TelnetControl::Suboption(c[2], &c[3..])),
+        TELNET_SE..=249 => Some(TelnetControl::Command(c[1])),
+        _ => None,
+    }
+}
+
+pub fn option_name(option: u8) -> Option<&'static str> {
+    match option {
+        0 => Some("binary"),
+        TELNET_OPT_ECHO => Some("echo"),
+        TELNET_OPT_SGA => Some("suppress_go_ahead"),
+        TELNET_OPT_STATUS => Some("status"),
+        6 => Some("timing_mark"),
+        TELNET_OPT_TTYPE => Some("terminal_type"),
+        TELNET_OPT_NAWS => Some("naws"),
+        TELNET_OPT_TSPEED => Some("terminal_speed"),
+        TELNET_OPT_LFLOW => Some("remote_flow_control"),
+        TELNET_OPT_LINEMODE => Some("linemode"),
+        TELNET_OPT_XDISPLOC => Some("x_display_location"),
+        TELNET_OPT_OLD_ENVIRON => Some("old_environ"),
+        TELNET_OPT_AUTHENTICATION => Some("authentication"),
+        TELNET_OPT_ENCRYPT => Some("encrypt"),
+        TELNET_OPT_NEW_ENVIRON => Some("new_environ"),
+        _ => None,
+    }
+}
+
+// ENVIRON suboption codes, RFC 1572.
+const ENVIRON_IS: u8 = 0;
+const ENVIRON_INFO: u8 = 2;
+const ENVIRON_VAR: u8 = 0;
+const ENVIRON_VALUE: u8 = 1;
+const ENVIRON_ESC: u8 = 2;
+const ENVIRON_USERVAR: u8 = 3;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct TelnetEnvironVar {
+    pub name: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+/// Parse the variables of an ENVIRON or NEW-ENVIRON IS/INFO suboption.
+///
+/// The OLD-ENVIRON option (RFC 1408) has the VAR and VALUE codes swapped
+/// in many implementations, so for it both orders are accepted: the first
+/// code seen after IS/INFO is taken as VAR.
+pub fn parse_environ(data: &[u8], old: bool) -> Vec<TelnetEnvironVar> {
+    let mut vars = Vec::new();
+    if data.is_empty() || (data[0] != ENVIRON_IS && data[0] != ENVIRON_INFO) {
+        return vars;
+    }
+    let (mut var_code, mut value_code) = (ENVIRON_VAR, ENVIRON_VALUE);
+    if old && data.len() > 1 && data[1] == ENVIRON_VALUE {
+        std::mem::swap(&mut var_code, &mut value_code);
+    }
+    let mut cur: Option<TelnetEnvironVar> = None;
+    let mut in_value = false;
+    let mut i = 1;
+    while i < data.len() {
+        let b = data[i];
+        if b == var_code || b == ENVIRON_USERVAR {
+            if let Some(v) = cur.take() {
+                vars.push(v);
+            }
+            cur = Some(TelnetEnvironVar::default());
+            in_value = false;
+        } else if b == value_code {
+            in_value = true;
+        } else if let Some(v) = cur.as_mut() {
+            let b = if (b == ENVIRON_ESC || b == 0xff) && i + 1 < data.len() {
+                // escaped code or doubled IAC
+                i += 1;
+                data[i]
+            } else {
+                b
+            };
+            if in_value {
+                v.value.push(b);
+            } else {
+                v.name.push(b);
+            }
+        }
+        i += 1;
+    }
+    if let Some(v) = cur.take() {
+        vars.push(v);
+    }
+    vars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ctl_negotiation() {
+        let buf: &[u8] = b"\xff\xfd\x18\xff\xfb\x1f";
+        let (rem, m) = parse_message(buf).unwrap();
+        assert_eq!(rem, b"\xff\xfb\x1f");
+        match m {
+            TelnetMessageType::Control(c) => {
+                assert_eq!(decode_ctl_message(c), Some(TelnetControl::Do(TELNET_OPT_TTYPE)));
+            }
+            _ => panic!("expected control message"),
+        }
+        let (rem, m) = parse_message(rem).unwrap();
+        assert!(rem.is_empty());
+        match m {
+            TelnetMessageType::Control(c) => {
+                assert_eq!(decode_ctl_message(c), Some(TelnetControl::Will(TELNET_OPT_NAWS)));
+            }
+            _ => panic!("expected control message"),
+        }
+    }
+
+    #[test]
+    fn test_parse_ctl_suboption() {
+        let buf: &[u8] = b"\xff\xfa\x27\x00\x00USER\x01-froot\xff\xf0";
+        let (rem, m) = parse_message(buf).unwrap();
+        assert_eq!(rem, b"\xff\xf0");
+        let c = match m {
+            TelnetMessageType::Control(c) => c,
+            _ => panic!("expected control message"),
+        };
+        match decode_ctl_message(c) {
+            Some(TelnetControl::Suboption(opt, data)) => {
+                assert_eq!(opt, TELNET_OPT_NEW_ENVIRON);
+                let vars = parse_environ(data, false);
+                assert_eq!(vars.len(), 1);
+                assert_eq!(vars[0].name, b"USER");
+                assert_eq!(vars[0].value, b"-froot");
+            }
+            _ => panic!("expected suboption"),
+        }
+    }
+
+    #[test]
+    fn test_parse_environ_escaped() {
+        let data: &[u8] = b"\x00\x00A\x02\x01B\x01x\x03DISPLAY\x01h:0";
+        let vars = parse_environ(data, false);
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0].name, b"A\x01B");
+        assert_eq!(vars[0].value, b"x");
+        assert_eq!(vars[1].name, b"DISPLAY");
+        assert_eq!(vars[1].value, b"h:0");
+    }
+
+    #[test]
+    fn test_parse_old_environ_swapped() {
+        let data: &[u8] = b"\x00\x01LD_PRELOAD\x00/tmp/x.so";
+        let vars = parse_environ(data, true);
+        assert_eq!(vars.len(), 1);
+        assert_eq!(vars[0].name, b"LD_PRELOAD");
+        assert_eq!(vars[0].value, b"/tmp/x.so");
+    }
+}
@@ -16,17 +16,39 @@
  */
 
 use std;
-use crate::core::{ALPROTO_UNKNOWN, AppProto, Flow, IPPROTO_TCP};
+use crate::core::{ALPROTO_UNKNOWN, AppProto, Direction, Flow, IPPROTO_TCP};
 use crate::applayer::{self, *};
 use crate::frames::*;
+use std::collections::VecDeque;
 use std::ffi::CString;
 use nom7::IResult;
-use super::parser;
+use super::parser::{self, TelnetControl};
 
 static mut ALPROTO_TELNET: AppProto = ALPROTO_UNKNOWN;
 
+static TELNET_LIMITS: AppLayerLimits = AppLayerLimits::new(256);
+
+/// Suboptions larger than this are considered suspicious, legitimate
+/// ones (terminal type, window size, environment) are a few dozen bytes.
+const TELNET_MAX_SUBOPTION_LEN: usize = 512;
+
+/// Maximum number of negotiated options stored per transaction.
+const TELNET_MAX_OPTIONS: usize = 64;
+
 #[derive(AppLayerEvent)]
-enum TelnetEvent {}
+enum TelnetEvent {
+    TooManyTransactions,
+    /// Unknown IAC command.
+    InvalidCommand,
+    /// Suboption exceeding `TELNET_MAX_SUBOPTION_LEN`.
+    SuboptionTooLong,
+    /// ENVIRON USER variable starting with a '-', as in the `-f root`
+    /// login bypass.
+    EnvironUserInjection,
+    /// ENVIRON variable affecting the dynamic linker or the shell of the
+    /// login process, like LD_PRELOAD.
+    EnvironDangerousVariable,
+}
 
 #[derive(AppLayerFrameType)]
 pub enum TelnetFrameType {
@@ -35,18 +57,77 @@ pub enum TelnetFrameType {
     Data,
 }
 
-#[derive(Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelnetTxType {
+    /// Option negotiation and login exchange.
+    Login,
+    /// Command line sent after a successful login.
+    Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelnetLoginResult {
+    Success,
+    Failure,
+}
+
+#[derive(Debug)]
+pub struct TelnetOption {
+    pub direction: Direction,
+    pub command: u8,
+    pub option: u8,
+}
+
 pub struct TelnetTransaction {
     tx_id: u64,
+    pub tx_type: TelnetTxType,
+    pub username: Option<Vec<u8>>,
+    pub password_sent: bool,
+    pub login_result: Option<TelnetLoginResult>,
+    pub command: Option<Vec<u8>>,
+    pub options: Vec<TelnetOption>,
+    pub terminal_type: Option<Vec<u8>>,
+    complete: bool,
     tx_data: AppLayerTxData,
 }
 
+impl TelnetTransaction {
+    pub fn new(tx_type: TelnetTxType) -> Self {
+        Self {
+            tx_id: 0,
+            tx_type,
+            username: None,
+            password_sent: false,
+            login_result: None,
+            command: None,
+            options: Vec::new(),
+            terminal_type: None,
+            complete: false,
+            tx_data: AppLayerTxData::new(),
+        }
+    }
+}
+
 impl Transaction for TelnetTransaction {
     fn id(&self) -> u64 {
         self.tx_id
     }
 }
 
+impl TxLimit for TelnetTransaction {
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn force_complete(&mut self) {
+        self.complete = true;
+    }
+
+    fn tx_data_mut(&mut self) -> &mut AppLayerTxData {
+        &mut self.tx_data
+    }
+}
+
 pub enum TelnetProtocolState {
     Idle,
     LoginSent,
@@ -60,7 +141,8 @@ pub enum TelnetProtocolState {
 pub struct TelnetState {
     state_data: AppLayerStateData,
     tx_id: u64,
-    transactions: Vec<TelnetTransaction>,
+    transactions: VecDeque<TelnetTransaction>,
+    tx_index_completed: usize,
     request_gap: bool,
     response_gap: bool,
 
@@ -95,7 +177,8 @@ impl TelnetState {
         Self {
             state_data: AppLayerStateData::new(),
             tx_id: 0,
-            transactions: Vec::new(),
+            transactions: VecDeque::new(),
+            tx_index_completed: 0,
             request_gap: false,
             response_gap: false,
             request_frame: None,
@@ -120,6 +203,7 @@ impl TelnetState {
             }
         }
         if found {
+            self.tx_index_completed = 0;
             self.transactions.remove(index);
         }
     }
@@ -128,9 +212,102 @@ impl TelnetState {
         self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
     }
 
-    fn _find_request(&mut self) -> Option<&mut TelnetTransaction> {
-        // TODO
-        None
+    fn new_tx(&mut self, tx_type: TelnetTxType) -> &mut TelnetTransaction {
+        // A new transaction closes the previous one.
+        if let Some(tx) = self.transactions.back_mut() {
+            tx.complete = true;
+        }
+        let mut tx = TelnetTransaction::new(tx_type);
+        self.tx_id += 1;
+        tx.tx_id = self.tx_id;
+        applayer_enforce_tx_limit(
+            self.transactions.iter_mut(),
+            &mut self.state_data,
+            TELNET_LIMITS.max_tx(),
+            &mut self.tx_index_completed,
+            &mut tx,
+            TelnetEvent::TooManyTransactions as u8,
+        );
+        self.transactions.push_back(tx);
+        // just pushed
+        self.transactions.back_mut().unwrap()
+    }
+
+    /// Return the open login transaction, creating one if needed.
+    fn login_tx(&mut self) -> &mut TelnetTransaction {
+        let open = matches!(self.transactions.back(),
+            Some(tx) if tx.tx_type == TelnetTxType::Login && !tx.complete);
+        if !open {
+            return self.new_tx(TelnetTxType::Login);
+        }
+        self.transactions.back_mut().unwrap()
+    }
+
+    /// Return the transaction control messages are attached to: the open
+    /// login transaction before login, the last transaction after it, even
+    /// if complete, so that negotiations do not create transactions.
+    fn control_tx(&mut self) -> &mut TelnetTransaction {
+        if let TelnetProtocolState::AuthOk = self.state {
+            if self.transactions.is_empty() {
+                return self.new_tx(TelnetTxType::Command);
+            }
+            return self.transactions.back_mut().unwrap();
+        }
+        self.login_tx()
+    }
+
+    fn handle_control(&mut self, c: &[u8], direction: Direction) {
+        let tx = self.control_tx();
+        match parser::decode_ctl_message(c) {
+            Some(TelnetControl::Will(option))
+            | Some(TelnetControl::Wont(option))
+            | Some(TelnetControl::Do(option))
+            | Some(TelnetControl::Dont(option)) => {
+                if tx.options.len() < TELNET_MAX_OPTIONS {
+                    tx.options.push(TelnetOption {
+                        direction,
+                        command: c[1],
+                        option,
+                    });
+                }
+            }
+            Some(TelnetControl::Suboption(option, data)) => {
+                if data.len() > TELNET_MAX_SUBOPTION_LEN {
+                    tx.tx_data.set_event(TelnetEvent::SuboptionTooLong as u8);
+                }
+                match option {
+                    parser::TELNET_OPT_TTYPE if direction == Direction::ToServer => {
+                        // IS <terminal type>
+                        if data.len() > 1 && data[0] == 0 {
+                            tx.terminal_type = Some(data[1..].to_vec());
+                        }
+                    }
+                    parser::TELNET_OPT_OLD_ENVIRON | parser::TELNET_OPT_NEW_ENVIRON
+                        if direction == Direction::ToServer =>
+                    {
+                        let old = option == parser::TELNET_OPT_OLD_ENVIRON;
+                        for var in parser::parse_environ(data, old) {
+                            if var.name == b"USER" {
+                                if var.value.first() == Some(&b'-') {
+                                    tx.tx_data.set_event(TelnetEvent::EnvironUserInjection as u8);
+                                }
+                                if tx.username.is_none() && !var.value.is_empty() {
+                                    tx.username = Some(var.value);
+                                }
+                            } else if environ_var_is_dangerous(&var.name) {
+                                tx.tx_data
+                                    .set_event(TelnetEvent::EnvironDangerousVariable as u8);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Some(TelnetControl::Command(_)) => {}
+            None => {
+                tx.tx_data.set_event(TelnetEvent::InvalidCommand as u8);
+            }
+        }
     }
 
     // app-layer-frame-documentation tag start: parse_request
@@ -212,23 +389,30 @@ impl TelnetState {
                     }
 
                     if let parser::TelnetMessageType::Data(d) = request {
+                        let line = trim_line(d);
                         match self.state {
                             TelnetProtocolState::LoginSent => {
                                 self.state = TelnetProtocolState::LoginRecv;
+                                let tx = self.login_tx();
+                                tx.username = Some(line.to_vec());
                             }
                             TelnetProtocolState::PasswdSent => {
                                 self.state = TelnetProtocolState::PasswdRecv;
+                                self.login_tx().password_sent = true;
                             }
                             TelnetProtocolState::AuthOk => {
-                                let _message = std::str::from_utf8(d);
-                                if let Ok(_message) = _message {
-                                    SCLogDebug!("=> {}", _message);
+                                if !line.is_empty() {
+                                    SCLogDebug!("=> {:?}", line);
+                                    let tx = self.new_tx(TelnetTxType::Command);
+                                    tx.command = Some(line.to_vec());
+                                    tx.complete = true;
                                 }
                             }
                             _ => {}
                         }
-                    } else if let parser::TelnetMessageType::Control(_c) = request {
-                        SCLogDebug!("request {:?}", _c);
+                    } else if let parser::TelnetMessageType::Control(c) = request {
+                        SCLogDebug!("request {:?}", c);
+                        self.handle_control(c, Direction::ToServer);
                     }
                 }
                 Err(nom7::Err::Incomplete(_)) => {
@@ -306,16 +490,20 @@ impl TelnetState {
                             TelnetProtocolState::Idle |
                             TelnetProtocolState::AuthFail => {
                                 self.state = TelnetProtocolState::LoginSent;
+                                let _ = self.login_tx();
                             },
                             TelnetProtocolState::LoginRecv => {
                                 self.state = TelnetProtocolState::PasswdSent;
                             },
                             TelnetProtocolState::PasswdRecv => {
-                                if let Ok(message) = std::str::from_utf8(d) {
+                                if let Ok(message) = std::str::from_utf8(trim_line(d)) {
                                     match message {
                                         "Login incorrect" => {
                                             SCLogDebug!("LOGIN FAILED");
                                             self.state = TelnetProtocolState::AuthFail;
+                                            let tx = self.login_tx();
+                                            tx.login_result = Some(TelnetLoginResult::Failure);
+                                            tx.complete = true;
                                         },
                                         "" => {
 
@@ -323,6 +511,9 @@ impl TelnetState {
                                         &_ => {
                                             SCLogDebug!("LOGIN OK");
                                             self.state = TelnetProtocolState::AuthOk;
+                                            let tx = self.login_tx();
+                                            tx.login_result = Some(TelnetLoginResult::Success);
+                                            tx.complete = true;
                                         },
                                     }
                                 }
@@ -335,8 +526,9 @@ impl TelnetState {
                             },
                             _ => {},
                         }
-                    } else if let parser::TelnetMessageType::Control(_c) = response {
-                        SCLogDebug!("response {:?}", _c);
+                    } else if let parser::TelnetMessageType::Control(c) = response {
+                        SCLogDebug!("response {:?}", c);
+                        self.handle_control(c, Direction::ToClient);
                     }
                 }
                 Err(nom7::Err::Incomplete(_)) => {
@@ -362,6 +554,34 @@ impl TelnetState {
     fn on_response_gap(&mut self, _size: u32) {
         self.response_gap = true;
     }
+
+    fn on_eof(&mut self) {
+        for tx in self.transactions.iter_mut() {
+            tx.complete = true;
+        }
+    }
+}
+
+/// Strip the line terminators and padding NULs telnet clients send.
+fn trim_line(d: &[u8]) -> &[u8] {
+    let mut end = d.len();
+    while end > 0 && matches!(d[end - 1], b'\r' | b'\n' | 0) {
+        end -= 1;
+    }
+    let mut start = 0;
+    while start < end && d[start] == 0 {
+        start += 1;
+    }
+    &d[start..end]
+}
+
+fn environ_var_is_dangerous(name: &[u8]) -> bool {
+    name.starts_with(b"LD_")
+        || name.starts_with(b"_RLD")
+        || name == b"LIBPATH"
+        || name == b"SHLIB_PATH"
+        || name == b"IFS"
+        || name == b"ELF_LD_PRELOAD"
 }
 
 /// Probe for a valid header.
@@ -425,14 +645,13 @@ pub unsafe extern "C" fn rs_telnet_parse_request(
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
     let eof = AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) > 0;
+    let state = cast_pointer!(state, TelnetState);
 
     if eof {
-        // If needed, handle EOF, or pass it into the parser.
+        state.on_eof();
         return AppLayerResult::ok();
     }
 
-    let state = cast_pointer!(state, TelnetState);
-
     if stream_slice.is_gap() {
         // Here we have a gap signaled by the input being null, but a greater
         // than 0 input_len which provides the size of the gap.
@@ -452,9 +671,13 @@ pub unsafe extern "C" fn rs_telnet_parse_response(
     stream_slice: StreamSlice,
     _data: *const std::os::raw::c_void
 ) -> AppLayerResult {
-    let _eof = AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0;
+    let eof = AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) > 0;
     let state = cast_pointer!(state, TelnetState);
 
+    if eof {
+        state.on_eof();
+    }
+
     if stream_slice.is_gap() {
         // Here we have a gap signaled by the input being null, but a greater
         // than 0 input_len which provides the size of the gap.
@@ -495,8 +718,10 @@ pub unsafe extern "C" fn rs_telnet_tx_get_alstate_progress(
     tx: *mut std::os::raw::c_void,
     _direction: u8,
 ) -> std::os::raw::c_int {
-    let _tx = cast_pointer!(tx, TelnetTransaction);
-    // TODO
+    let tx = cast_pointer!(tx, TelnetTransaction);
+    if tx.complete {
+        return 1;
+    }
     return 0;
 }
 
@@ -556,10 +781,85 @@ pub unsafe extern "C" fn rs_telnet_register_parser() {
             parser.name,
         ) != 0
         {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
+        TELNET_LIMITS.configure("telnet");
+        AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_TELNET);
         SCLogDebug!("Rust telnet parser registered.");
     } else {
         SCLogDebug!("Protocol detector and parser disabled for TELNET.");
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_telnet_login_and_commands() {
+        let mut state = TelnetState::new();
+        let ss = StreamSlice::from_slice(&[], 0, 0);
+        let r = state.parse_response(std::ptr::null(), &ss, b"\xff\xfd\x18login: ");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_request(std::ptr::null(), &ss, b"\xff\xfb\x18root\r\n");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_response(std::ptr::null(), &ss, b"Password: ");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_request(std::ptr::null(), &ss, b"secret\r\n");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_response(std::ptr::null(), &ss, b"\r\nLast login: today\r\n");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_request(std::ptr::null(), &ss, b"uname -a\r\n");
+        assert_eq!(r, AppLayerResult::ok());
+
+        assert_eq!(state.transactions.len(), 2);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.tx_type, TelnetTxType::Login);
+        assert_eq!(tx.username.as_deref(), Some(&b"root"[..]));
+        assert!(tx.password_sent);
+        assert_eq!(tx.login_result, Some(TelnetLoginResult::Success));
+        assert_eq!(tx.options.len(), 2);
+        assert!(tx.complete);
+        let tx = &state.transactions[1];
+        assert_eq!(tx.tx_type, TelnetTxType::Command);
+        assert_eq!(tx.command.as_deref(), Some(&b"uname -a"[..]));
+
+        // negotiations after login go to the last command
+        let r = state.parse_request(std::ptr::null(), &ss, b"\xff\xfb\x1f");
+        assert_eq!(r, AppLayerResult::ok());
+        let r = state.parse_response(std::ptr::null(), &ss, b"\xff\xfd\x1f\xff\xfb\x01");
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), 2);
+        assert_eq!(state.transactions[1].options.len(), 3);
+    }
+
+    #[test]
+    fn test_telnet_tx_limit() {
+        let mut state = TelnetState::new();
+        state.state = TelnetProtocolState::AuthOk;
+        let ss = StreamSlice::from_slice(&[], 0, 0);
+        let buf = b"ls\r\n".repeat(TELNET_LIMITS.max_tx() + 2);
+        let r = state.parse_request(std::ptr::null(), &ss, &buf);
+        assert_eq!(r, AppLayerResult::ok());
+        assert_eq!(state.transactions.len(), TELNET_LIMITS.max_tx() + 2);
+        assert_eq!(state.state_data.tx_limit_reached, 1);
+    }
+
+    #[test]
+    fn test_telnet_login_failure() {
+        let mut state = TelnetState::new();
+        let ss = StreamSlice::from_slice(&[], 0, 0);
+        state.parse_response(std::ptr::null(), &ss, b"login: ");
+        state.parse_request(std::ptr::null(), &ss, b"admin\r\n");
+        state.parse_response(std::ptr::null(), &ss, b"Password: ");
+        state.parse_request(std::ptr::null(), &ss, b"admin\r\n");
+        state.parse_response(std::ptr::null(), &ss, b"Login incorrect\r\nlogin: ");
+
+        assert_eq!(state.transactions.len(), 2);
+        assert_eq!(
+            state.transactions[0].login_result,
+            Some(TelnetLoginResult::Failure)
+        );
+        assert!(!state.transactions[1].complete);
+    }
+}
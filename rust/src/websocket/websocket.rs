@@ -375,7 +375,7 @@ pub unsafe extern "C" fn rs_websocket_register_parser() {
         let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
         ALPROTO_WEBSOCKET = alproto;
         if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
-            let _ = AppLayerRegisterParser(&parser, alproto);
+            let _ = applayer_register_parser(&parser, alproto);
         }
         SCLogDebug!("Rust websocket parser registered.");
         if let Some(val) = conf_get("app-layer.protocols.websocket.max-payload-size") {
@@ -0,0 +1,49 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser;
+use super::wireguard::WireGuardTransaction;
+use crate::jsonbuilder::{JsonBuilder, JsonError};
+use std;
+
+fn log_wireguard(tx: &WireGuardTransaction, js: &mut JsonBuilder) -> Result<(), JsonError> {
+    js.open_object("wireguard")?;
+    if let Some(message_type) = parser::message_type_string(tx.message_type) {
+        js.set_string("message_type", message_type)?;
+    } else {
+        js.set_uint("message_type", tx.message_type as u64)?;
+    }
+    if let Some(index) = tx.sender_index {
+        js.set_uint("sender_index", index as u64)?;
+    }
+    if let Some(index) = tx.receiver_index {
+        js.set_uint("receiver_index", index as u64)?;
+    }
+    if let Some(mac2) = tx.mac2 {
+        js.set_bool("mac2", mac2)?;
+    }
+    js.close()?;
+    Ok(())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn SCWireGuardLoggerLog(
+    tx: *mut std::os::raw::c_void, js: &mut JsonBuilder,
+) -> bool {
+    let tx = cast_pointer!(tx, WireGuardTransaction);
+    log_wireguard(tx, js).is_ok()
+}
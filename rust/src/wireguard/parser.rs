@@ -0,0 +1,255 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+//! WireGuard message parsing.
+
+use nom7::bytes::complete::{tag, take};
+use nom7::combinator::{eof, verify};
+use nom7::number::complete::{le_u32, le_u64, le_u8};
+use nom7::IResult;
+
+pub const WG_HANDSHAKE_INITIATION: u8 = 1;
+pub const WG_HANDSHAKE_RESPONSE: u8 = 2;
+pub const WG_COOKIE_REPLY: u8 = 3;
+pub const WG_TRANSPORT_DATA: u8 = 4;
+
+pub const WG_HANDSHAKE_INITIATION_LEN: usize = 148;
+pub const WG_HANDSHAKE_RESPONSE_LEN: usize = 92;
+pub const WG_COOKIE_REPLY_LEN: usize = 64;
+/// Header and the authentication tag of an empty keepalive.
+pub const WG_TRANSPORT_DATA_MIN_LEN: usize = 32;
+
+const WG_KEY_LEN: usize = 32;
+const WG_MAC_LEN: usize = 16;
+/// Poly1305 authentication tag of the AEAD encrypted fields.
+const WG_TAG_LEN: usize = 16;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WireGuardMessage {
+    HandshakeInitiation {
+        sender_index: u32,
+        /// mac2 is set when the responder asked for a cookie.
+        mac2: bool,
+    },
+    HandshakeResponse {
+        sender_index: u32,
+        receiver_index: u32,
+        mac2: bool,
+    },
+    CookieReply {
+        receiver_index: u32,
+    },
+    TransportData {
+        receiver_index: u32,
+        counter: u64,
+    },
+}
+
+impl WireGuardMessage {
+    pub fn message_type(&self) -> u8 {
+        match self {
+            WireGuardMessage::HandshakeInitiation { .. } => WG_HANDSHAKE_INITIATION,
+            WireGuardMessage::HandshakeResponse { .. } => WG_HANDSHAKE_RESPONSE,
+            WireGuardMessage::CookieReply { .. } => WG_COOKIE_REPLY,
+            WireGuardMessage::TransportData { .. } => WG_TRANSPORT_DATA,
+        }
+    }
+}
+
+pub fn message_type_string(message_type: u8) -> Option<&'static str> {
+    match message_type {
+        WG_HANDSHAKE_INITIATION => Some("handshake_initiation"),
+        WG_HANDSHAKE_RESPONSE => Some("handshake_response"),
+        WG_COOKIE_REPLY => Some("cookie_reply"),
+        WG_TRANSPORT_DATA => Some("transport_data"),
+        _ => None,
+    }
+}
+
+/// Message type followed by 3 reserved zero bytes.
+fn parse_type(i: &[u8]) -> IResult<&[u8], u8> {
+    let (i, message_type) = le_u8(i)?;
+    let (i, _) = tag([0u8, 0, 0])(i)?;
+    Ok((i, message_type))
+}
+
+/// Parse the MACs, returning whether mac2 is set.
+fn parse_macs(i: &[u8]) -> IResult<&[u8], bool> {
+    let (i, _mac1) = verify(take(WG_MAC_LEN), |mac: &[u8]| mac.iter().any(|&b| b != 0))(i)?;
+    let (i, mac2) = take(WG_MAC_LEN)(i)?;
+    let (i, _) = eof(i)?;
+    Ok((i, mac2.iter().any(|&b| b != 0)))
+}
+
+fn parse_handshake_initiation(i: &[u8]) -> IResult<&[u8], WireGuardMessage> {
+    let (i, sender_index) = le_u32(i)?;
+    let (i, _ephemeral) = take(WG_KEY_LEN)(i)?;
+    let (i, _static) = take(WG_KEY_LEN + WG_TAG_LEN)(i)?;
+    let (i, _timestamp) = take(12 + WG_TAG_LEN)(i)?;
+    let (i, mac2) = parse_macs(i)?;
+    Ok((
+        i,
+        WireGuardMessage::HandshakeInitiation { sender_index, mac2 },
+    ))
+}
+
+fn parse_handshake_response(i: &[u8]) -> IResult<&[u8], WireGuardMessage> {
+    let (i, sender_index) = le_u32(i)?;
+    let (i, receiver_index) = le_u32(i)?;
+    let (i, _ephemeral) = take(WG_KEY_LEN)(i)?;
+    let (i, _empty) = take(WG_TAG_LEN)(i)?;
+    let (i, mac2) = parse_macs(i)?;
+    Ok((
+        i,
+        WireGuardMessage::HandshakeResponse {
+            sender_index,
+            receiver_index,
+            mac2,
+        },
+    ))
+}
+
+fn parse_cookie_reply(i: &[u8]) -> IResult<&[u8], WireGuardMessage> {
+    let (i, receiver_index) = le_u32(i)?;
+    let (i, _nonce) = take(24_usize)(i)?;
+    let (i, _cookie) = take(WG_MAC_LEN + WG_TAG_LEN)(i)?;
+    let (i, _) = eof(i)?;
+    Ok((i, WireGuardMessage::CookieReply { receiver_index }))
+}
+
+fn parse_transport_data(i: &[u8]) -> IResult<&[u8], WireGuardMessage> {
+    let (i, receiver_index) = le_u32(i)?;
+    let (i, counter) = le_u64(i)?;
+    // The payload is padded to 16 bytes and followed by the tag.
+    let (i, _) = verify(take(i.len()), |data: &[u8]| {
+        data.len() >= WG_TAG_LEN && data.len() % 16 == 0
+    })(i)?;
+    Ok((
+        i,
+        WireGuardMessage::TransportData {
+            receiver_index,
+            counter,
+        },
+    ))
+}
+
+/// Parse a complete message, as carried by a single UDP datagram.
+pub fn parse_message(i: &[u8]) -> IResult<&[u8], WireGuardMessage> {
+    let (i, message_type) = parse_type(i)?;
+    match message_type {
+        WG_HANDSHAKE_INITIATION => parse_handshake_initiation(i),
+        WG_HANDSHAKE_RESPONSE => parse_handshake_response(i),
+        WG_COOKIE_REPLY => parse_cookie_reply(i),
+        WG_TRANSPORT_DATA => parse_transport_data(i),
+        _ => Err(nom7::Err::Error(nom7::error::make_error(
+            i,
+            nom7::error::ErrorKind::Switch,
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn handshake_initiation(sender_index: u32, mac2: bool) -> Vec<u8> {
+        let mut buf = vec![WG_HANDSHAKE_INITIATION, 0, 0, 0];
+        buf.extend_from_slice(&sender_index.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 108]);
+        buf.extend_from_slice(&[0xa5; WG_MAC_LEN]);
+        buf.extend_from_slice(&[if mac2 { 0x33 } else { 0 }; WG_MAC_LEN]);
+        buf
+    }
+
+    #[test]
+    fn test_parse_handshake_initiation() {
+        let buf = handshake_initiation(0x0badcafe, false);
+        assert_eq!(buf.len(), WG_HANDSHAKE_INITIATION_LEN);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(
+            msg,
+            WireGuardMessage::HandshakeInitiation {
+                sender_index: 0x0badcafe,
+                mac2: false
+            }
+        );
+
+        let buf = handshake_initiation(1, true);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(
+            msg,
+            WireGuardMessage::HandshakeInitiation {
+                sender_index: 1,
+                mac2: true
+            }
+        );
+
+        // Truncated, trailing data or reserved bytes set.
+        assert!(parse_message(&buf[..147]).is_err());
+        let mut long = buf.clone();
+        long.push(0);
+        assert!(parse_message(&long).is_err());
+        let mut reserved = buf;
+        reserved[1] = 1;
+        assert!(parse_message(&reserved).is_err());
+    }
+
+    #[test]
+    fn test_parse_other_messages() {
+        let mut buf = vec![WG_HANDSHAKE_RESPONSE, 0, 0, 0];
+        buf.extend_from_slice(&2_u32.to_le_bytes());
+        buf.extend_from_slice(&1_u32.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 48]);
+        buf.extend_from_slice(&[0xa5; 16]);
+        buf.extend_from_slice(&[0; 16]);
+        assert_eq!(buf.len(), WG_HANDSHAKE_RESPONSE_LEN);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(
+            msg,
+            WireGuardMessage::HandshakeResponse {
+                sender_index: 2,
+                receiver_index: 1,
+                mac2: false
+            }
+        );
+
+        let mut buf = vec![WG_COOKIE_REPLY, 0, 0, 0];
+        buf.extend_from_slice(&1_u32.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 56]);
+        assert_eq!(buf.len(), WG_COOKIE_REPLY_LEN);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(msg, WireGuardMessage::CookieReply { receiver_index: 1 });
+
+        let mut buf = vec![WG_TRANSPORT_DATA, 0, 0, 0];
+        buf.extend_from_slice(&2_u32.to_le_bytes());
+        buf.extend_from_slice(&7_u64.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 16]);
+        assert_eq!(buf.len(), WG_TRANSPORT_DATA_MIN_LEN);
+        let (_, msg) = parse_message(&buf).unwrap();
+        assert_eq!(
+            msg,
+            WireGuardMessage::TransportData {
+                receiver_index: 2,
+                counter: 7
+            }
+        );
+        buf.push(0);
+        assert!(parse_message(&buf).is_err());
+
+        assert!(parse_message(&[5, 0, 0, 0]).is_err());
+    }
+}
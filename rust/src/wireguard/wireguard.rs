@@ -0,0 +1,418 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+use super::parser::{self, WireGuardMessage};
+use crate::applayer::{self, *};
+use crate::core::{
+    AppProto, Direction, Flow, ALPROTO_FAILED, ALPROTO_UNKNOWN, DIR_BOTH, IPPROTO_UDP,
+};
+use crate::frames::Frame;
+use std;
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+pub(super) static mut ALPROTO_WIREGUARD: AppProto = ALPROTO_UNKNOWN;
+
+#[derive(AppLayerFrameType)]
+pub enum WireGuardFrameType {
+    /// A complete message.
+    Pdu,
+}
+
+#[derive(AppLayerEvent)]
+pub enum WireGuardEvent {
+    /// Message with an unknown type, reserved bytes set or of the wrong
+    /// length for its type.
+    MalformedMessage,
+    /// Handshake response not answering the last initiation.
+    HandshakeIndexMismatch,
+}
+
+/// A handshake or cookie message. Transport data messages do not create
+/// transactions.
+#[derive(Debug, Default)]
+pub struct WireGuardTransaction {
+    tx_id: u64,
+    pub message_type: u8,
+    pub sender_index: Option<u32>,
+    pub receiver_index: Option<u32>,
+    /// mac2 presence, for the handshake messages.
+    pub mac2: Option<bool>,
+    tx_data: AppLayerTxData,
+}
+
+impl Transaction for WireGuardTransaction {
+    fn id(&self) -> u64 {
+        self.tx_id
+    }
+}
+
+impl WireGuardTransaction {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            tx_data: AppLayerTxData::for_direction(direction),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct WireGuardState {
+    state_data: AppLayerStateData,
+    tx_id: u64,
+    transactions: VecDeque<WireGuardTransaction>,
+    /// Sender index of the last handshake initiation.
+    initiation_index: Option<u32>,
+}
+
+impl State<WireGuardTransaction> for WireGuardState {
+    fn get_transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    fn get_transaction_by_index(&self, index: usize) -> Option<&WireGuardTransaction> {
+        self.transactions.get(index)
+    }
+}
+
+impl WireGuardState {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Free a transaction by ID.
+    fn free_tx(&mut self, tx_id: u64) {
+        if let Some(index) = self
+            .transactions
+            .iter()
+            .position(|tx| tx.tx_id == tx_id + 1)
+        {
+            self.transactions.remove(index);
+        }
+    }
+
+    pub fn get_tx(&mut self, tx_id: u64) -> Option<&WireGuardTransaction> {
+        self.transactions.iter().find(|tx| tx.tx_id == tx_id + 1)
+    }
+
+    fn new_tx(&mut self, direction: Direction) -> &mut WireGuardTransaction {
+        let mut tx = WireGuardTransaction::new(direction);
+        self.tx_id += 1;
+        tx.tx_id = self.tx_id;
+        self.transactions.push_back(tx);
+        // just pushed
+        self.transactions.back_mut().unwrap()
+    }
+
+    fn parse(
+        &mut self, flow: *const Flow, stream_slice: &StreamSlice, direction: Direction,
+    ) -> AppLayerResult {
+        let input = stream_slice.as_slice();
+        if input.is_empty() {
+            return AppLayerResult::ok();
+        }
+        let frame = Frame::new(
+            flow,
+            stream_slice,
+            input,
+            input.len() as i64,
+            WireGuardFrameType::Pdu as u8,
+            None,
+        );
+
+        let msg = match parser::parse_message(input) {
+            Ok((_, msg)) => msg,
+            Err(_) => {
+                let tx = self.new_tx(direction);
+                tx.message_type = input[0];
+                tx.tx_data.set_event(WireGuardEvent::MalformedMessage as u8);
+                return AppLayerResult::ok();
+            }
+        };
+        let message_type = msg.message_type();
+        let mut mismatch = false;
+        let (sender_index, receiver_index, mac2) = match msg {
+            WireGuardMessage::HandshakeInitiation { sender_index, mac2 } => {
+                self.initiation_index = Some(sender_index);
+                (Some(sender_index), None, Some(mac2))
+            }
+            WireGuardMessage::HandshakeResponse {
+                sender_index,
+                receiver_index,
+                mac2,
+            } => {
+                if let Some(index) = self.initiation_index {
+                    mismatch = index != receiver_index;
+                }
+                (Some(sender_index), Some(receiver_index), Some(mac2))
+            }
+            WireGuardMessage::CookieReply { receiver_index } => (None, Some(receiver_index), None),
+            WireGuardMessage::TransportData { .. } => {
+                return AppLayerResult::ok();
+            }
+        };
+
+        let tx = self.new_tx(direction);
+        tx.message_type = message_type;
+        tx.sender_index = sender_index;
+        tx.receiver_index = receiver_index;
+        tx.mac2 = mac2;
+        if mismatch {
+            tx.tx_data
+                .set_event(WireGuardEvent::HandshakeIndexMismatch as u8);
+        }
+        if let Some(frame) = frame {
+            frame.set_tx(flow, tx.tx_id - 1);
+        }
+        AppLayerResult::ok()
+    }
+}
+
+/// Probe for a handshake or cookie message. Returns the direction the
+/// message was sent in: initiations go to the server.
+fn probe(input: &[u8]) -> Option<Direction> {
+    // Transport data is not used for detection, it is too weak a pattern.
+    let expected_len = match input.first()? {
+        &parser::WG_HANDSHAKE_INITIATION => parser::WG_HANDSHAKE_INITIATION_LEN,
+        &parser::WG_HANDSHAKE_RESPONSE => parser::WG_HANDSHAKE_RESPONSE_LEN,
+        &parser::WG_COOKIE_REPLY => parser::WG_COOKIE_REPLY_LEN,
+        _ => {
+            return None;
+        }
+    };
+    if input.len() != expected_len {
+        return None;
+    }
+    match parser::parse_message(input).ok()? {
+        (_, WireGuardMessage::HandshakeInitiation { .. }) => Some(Direction::ToServer),
+        _ => Some(Direction::ToClient),
+    }
+}
+
+// C exports.
+
+unsafe extern "C" fn rs_wireguard_probing_parser(
+    _flow: *const Flow, direction: u8, input: *const u8, input_len: u32, rdir: *mut u8,
+) -> AppProto {
+    if input.is_null() {
+        return ALPROTO_UNKNOWN;
+    }
+    let slice = build_slice!(input, input_len as usize);
+    match probe(slice) {
+        Some(dir) => {
+            if (direction & DIR_BOTH) != dir.into() {
+                *rdir = dir as u8;
+            }
+            ALPROTO_WIREGUARD
+        }
+        None => ALPROTO_FAILED,
+    }
+}
+
+extern "C" fn rs_wireguard_state_new(
+    _orig_state: *mut c_void, _orig_proto: AppProto,
+) -> *mut c_void {
+    let state = WireGuardState::new();
+    let boxed = Box::new(state);
+    return Box::into_raw(boxed) as *mut c_void;
+}
+
+unsafe extern "C" fn rs_wireguard_state_free(state: *mut c_void) {
+    std::mem::drop(Box::from_raw(state as *mut WireGuardState));
+}
+
+unsafe extern "C" fn rs_wireguard_state_tx_free(state: *mut c_void, tx_id: u64) {
+    let state = cast_pointer!(state, WireGuardState);
+    state.free_tx(tx_id);
+}
+
+unsafe extern "C" fn rs_wireguard_parse_request(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, WireGuardState);
+    state.parse(flow, &stream_slice, Direction::ToServer)
+}
+
+unsafe extern "C" fn rs_wireguard_parse_response(
+    flow: *const Flow, state: *mut c_void, _pstate: *mut c_void, stream_slice: StreamSlice,
+    _data: *const c_void,
+) -> AppLayerResult {
+    let state = cast_pointer!(state, WireGuardState);
+    state.parse(flow, &stream_slice, Direction::ToClient)
+}
+
+unsafe extern "C" fn rs_wireguard_state_get_tx(state: *mut c_void, tx_id: u64) -> *mut c_void {
+    let state = cast_pointer!(state, WireGuardState);
+    match state.get_tx(tx_id) {
+        Some(tx) => {
+            return tx as *const _ as *mut _;
+        }
+        None => {
+            return std::ptr::null_mut();
+        }
+    }
+}
+
+unsafe extern "C" fn rs_wireguard_state_get_tx_count(state: *mut c_void) -> u64 {
+    let state = cast_pointer!(state, WireGuardState);
+    return state.tx_id;
+}
+
+unsafe extern "C" fn rs_wireguard_tx_get_alstate_progress(
+    _tx: *mut c_void, _direction: u8,
+) -> c_int {
+    // A message is complete as soon as it is parsed.
+    return 1;
+}
+
+export_tx_data_get!(rs_wireguard_get_tx_data, WireGuardTransaction);
+export_state_data_get!(rs_wireguard_get_state_data, WireGuardState);
+
+// Parser name as a C style string.
+const PARSER_NAME: &[u8] = b"wireguard\0";
+
+#[no_mangle]
+pub unsafe extern "C" fn SCRegisterWireGuardParser() {
+    let default_port = CString::new("[51820]").unwrap();
+    let parser = RustParser {
+        name: PARSER_NAME.as_ptr() as *const c_char,
+        default_port: default_port.as_ptr(),
+        ipproto: IPPROTO_UDP,
+        probe_ts: Some(rs_wireguard_probing_parser),
+        probe_tc: Some(rs_wireguard_probing_parser),
+        min_depth: 0,
+        max_depth: parser::WG_HANDSHAKE_INITIATION_LEN as u16,
+        state_new: rs_wireguard_state_new,
+        state_free: rs_wireguard_state_free,
+        tx_free: rs_wireguard_state_tx_free,
+        parse_ts: rs_wireguard_parse_request,
+        parse_tc: rs_wireguard_parse_response,
+        get_tx_count: rs_wireguard_state_get_tx_count,
+        get_tx: rs_wireguard_state_get_tx,
+        tx_comp_st_ts: 1,
+        tx_comp_st_tc: 1,
+        tx_get_progress: rs_wireguard_tx_get_alstate_progress,
+        get_eventinfo: Some(WireGuardEvent::get_event_info),
+        get_eventinfo_byid: Some(WireGuardEvent::get_event_info_by_id),
+        localstorage_new: None,
+        localstorage_free: None,
+        get_tx_files: None,
+        get_tx_iterator: Some(
+            applayer::state_get_tx_iterator::<WireGuardState, WireGuardTransaction>,
+        ),
+        get_tx_data: rs_wireguard_get_tx_data,
+        get_state_data: rs_wireguard_get_state_data,
+        apply_tx_config: None,
+        flags: 0,
+        get_frame_id_by_name: Some(WireGuardFrameType::ffi_id_from_name),
+        get_frame_name_by_id: Some(WireGuardFrameType::ffi_name_from_id),
+    };
+
+    let ip_proto_str = CString::new("udp").unwrap();
+    if AppLayerProtoDetectConfProtoDetectionEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+        let alproto = AppLayerRegisterProtocolDetection(&parser, 1);
+        ALPROTO_WIREGUARD = alproto;
+        if AppLayerParserConfParserEnabled(ip_proto_str.as_ptr(), parser.name) != 0 {
+            let _ = applayer_register_parser(&parser, alproto);
+        }
+        AppLayerParserRegisterLogger(IPPROTO_UDP, ALPROTO_WIREGUARD);
+    } else {
+        SCLogDebug!("Protocol detector and parser disabled for WireGuard.");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn handshake_initiation(sender_index: u32) -> Vec<u8> {
+        let mut buf = vec![parser::WG_HANDSHAKE_INITIATION, 0, 0, 0];
+        buf.extend_from_slice(&sender_index.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 108]);
+        buf.extend_from_slice(&[0xa5; 16]);
+        buf.extend_from_slice(&[0; 16]);
+        buf
+    }
+
+    fn handshake_response(sender_index: u32, receiver_index: u32) -> Vec<u8> {
+        let mut buf = vec![parser::WG_HANDSHAKE_RESPONSE, 0, 0, 0];
+        buf.extend_from_slice(&sender_index.to_le_bytes());
+        buf.extend_from_slice(&receiver_index.to_le_bytes());
+        buf.extend_from_slice(&[0x5a; 48]);
+        buf.extend_from_slice(&[0xa5; 16]);
+        buf.extend_from_slice(&[0x33; 16]);
+        buf
+    }
+
+    fn parse(state: &mut WireGuardState, direction: Direction, buf: &[u8]) {
+        let ss = StreamSlice::from_slice(buf, direction.into(), 0);
+        state.parse(std::ptr::null(), &ss, direction);
+    }
+
+    #[test]
+    fn test_wireguard_probe() {
+        let init = handshake_initiation(1);
+        assert_eq!(probe(&init), Some(Direction::ToServer));
+        assert_eq!(probe(&handshake_response(2, 1)), Some(Direction::ToClient));
+        assert_eq!(probe(&init[..100]), None);
+        let mut reserved = init.clone();
+        reserved[3] = 1;
+        assert_eq!(probe(&reserved), None);
+        // Transport data is not probed.
+        let mut data = vec![parser::WG_TRANSPORT_DATA, 0, 0, 0];
+        data.extend_from_slice(&[0; 28]);
+        assert_eq!(probe(&data), None);
+    }
+
+    #[test]
+    fn test_wireguard_handshake() {
+        let mut state = WireGuardState::new();
+        parse(
+            &mut state,
+            Direction::ToServer,
+            &handshake_initiation(0x1234),
+        );
+        parse(
+            &mut state,
+            Direction::ToClient,
+            &handshake_response(0x5678, 0x1234),
+        );
+        let mut data = vec![parser::WG_TRANSPORT_DATA, 0, 0, 0];
+        data.extend_from_slice(&0x5678_u32.to_le_bytes());
+        data.extend_from_slice(&[0; 24]);
+        parse(&mut state, Direction::ToServer, &data);
+
+        assert_eq!(state.transactions.len(), 2);
+        let tx = &state.transactions[0];
+        assert_eq!(tx.message_type, parser::WG_HANDSHAKE_INITIATION);
+        assert_eq!(tx.sender_index, Some(0x1234));
+        assert_eq!(tx.receiver_index, None);
+        assert_eq!(tx.mac2, Some(false));
+        let tx = &state.transactions[1];
+        assert_eq!(tx.message_type, parser::WG_HANDSHAKE_RESPONSE);
+        assert_eq!(tx.sender_index, Some(0x5678));
+        assert_eq!(tx.receiver_index, Some(0x1234));
+        assert_eq!(tx.mac2, Some(true));
+
+        // Malformed messages still create a transaction for the event.
+        parse(&mut state, Direction::ToServer, &[0x09, 0, 0, 0]);
+        assert_eq!(state.transactions.len(), 3);
+        assert_eq!(state.transactions[2].message_type, 9);
+    }
+}
@@ -61,6 +61,16 @@ impl<'a> fmt::Display for SCGeneralName<'a> {
     }
 }
 
+/// Subject and issuer of a DER encoded certificate, for the protocols
+/// keeping the raw certificates.
+pub fn x509_get_subject_issuer(input: &[u8]) -> Option<(String, String)> {
+    let (_rem, cert) = X509Certificate::from_der(input).ok()?;
+    Some((
+        cert.tbs_certificate.subject.to_string(),
+        cert.tbs_certificate.issuer.to_string(),
+    ))
+}
+
 /// Attempt to parse a X.509 from input, and return a pointer to the parsed object if successful.
 ///
 /// # Safety
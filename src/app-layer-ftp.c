@@ -29,7 +29,6 @@
 #include "app-layer-ftp.h"
 #include "app-layer.h"
 #include "app-layer-parser.h"
-#include "app-layer-expectation.h"
 #include "app-layer-detect-proto.h"
 
 #include "rust.h"
@@ -429,26 +428,13 @@ static int FTPParseRequestCommand(
     SCReturnInt(0);
 }
 
-struct FtpTransferCmd {
-    /** Need to look like a ExpectationData so DFree must
-     *  be first field . */
-    void (*DFree)(void *);
-    uint64_t flow_id;
-    uint8_t *file_name;
-    uint16_t file_len;
-    uint8_t direction; /**< direction in which the data will flow */
-    FtpRequestCommand cmd;
-};
-
-static void FtpTransferCmdFree(void *data)
+static void FTPFreeSizeFileName(FtpState *state)
 {
-    struct FtpTransferCmd *cmd = (struct FtpTransferCmd *) data;
-    if (cmd == NULL)
-        return;
-    if (cmd->file_name) {
-        FTPFree(cmd->file_name, cmd->file_len + 1);
+    if (state->size_file_name != NULL) {
+        FTPFree(state->size_file_name, state->size_file_name_len);
+        state->size_file_name = NULL;
+        state->size_file_name_len = 0;
     }
-    FTPFree(cmd, sizeof(struct FtpTransferCmd));
 }
 
 static uint32_t CopyCommandLine(uint8_t **dest, FtpLineState *line)
@@ -578,33 +564,20 @@ static AppLayerResult FTPParseRequest(Flow *f, void *ftp_state, AppLayerParserSt
                 if (state->dyn_port == 0 || line.len < 6) {
                     SCReturnStruct(APP_LAYER_ERROR);
                 }
-                struct FtpTransferCmd *data = FTPCalloc(1, sizeof(struct FtpTransferCmd));
-                if (data == NULL)
-                    SCReturnStruct(APP_LAYER_ERROR);
-                data->DFree = FtpTransferCmdFree;
                 /*
                  * Min size has been checked in FTPParseRequestCommand
                  * SC_FILENAME_MAX includes the null
                  */
                 uint32_t file_name_len = MIN(SC_FILENAME_MAX - 1, line.len - 5);
-#if SC_FILENAME_MAX > UINT16_MAX
-#error SC_FILENAME_MAX is greater than UINT16_MAX
-#endif
-                    data->file_name = FTPCalloc(file_name_len + 1, sizeof(char));
-                    if (data->file_name == NULL) {
-                        FtpTransferCmdFree(data);
-                        SCReturnStruct(APP_LAYER_ERROR);
-                    }
-                    data->file_name[file_name_len] = 0;
-                    data->file_len = (uint16_t)file_name_len;
-                    memcpy(data->file_name, line.buf + 5, file_name_len);
-                    data->cmd = state->command;
-                    data->flow_id = FlowGetId(f);
-                    data->direction = direction;
-                    int ret = AppLayerExpectationCreate(f, direction,
-                                            0, state->dyn_port, ALPROTO_FTPDATA, data);
-                    if (ret == -1) {
-                        FtpTransferCmdFree(data);
+                /* size of the file if the client asked for it before */
+                const bool has_file_size = state->command == FTP_COMMAND_RETR &&
+                                           state->size_file_name_len == file_name_len &&
+                                           state->size_file_name != NULL &&
+                                           memcmp(state->size_file_name, line.buf + 5,
+                                                   file_name_len) == 0;
+                    if (!SCFtpDataExpectationCreate(f, direction, state->dyn_port,
+                                (uint8_t)state->command, line.buf + 5, file_name_len,
+                                has_file_size, state->file_size)) {
                         SCLogDebug("No expectation created.");
                         SCReturnStruct(APP_LAYER_ERROR);
                     } else {
@@ -613,6 +586,7 @@ static AppLayerResult FTPParseRequest(Flow *f, void *ftp_state, AppLayerParserSt
                             state->dyn_port);
                     }
 
+                    FTPFreeSizeFileName(state);
                     /* reset the dyn port to avoid duplicate */
                     state->dyn_port = 0;
                     /* reset active/passive indicator */
@@ -659,6 +633,31 @@ static int FTPParsePassiveResponseV6(FtpState *state, const uint8_t *input, uint
     return 0;
 }
 
+/**
+ * \brief Keep the file size replied to a SIZE command, to announce it with
+ *        the data connection of a RETR of the file.
+ */
+static void FTPParseSizeResponse(
+        FtpState *state, const FTPTransaction *tx, const uint8_t *input, uint32_t input_len)
+{
+    uint64_t file_size;
+
+    /* the request is "SIZE <file name>", with its null terminator */
+    if (tx->command_descriptor->command != FTP_COMMAND_SIZE || tx->request_length <= 6 ||
+            !SCFtpParseSizeResponse(input, input_len, &file_size)) {
+        return;
+    }
+    FTPFreeSizeFileName(state);
+    const uint32_t len = tx->request_length - 6;
+    state->size_file_name = FTPCalloc(len, sizeof(uint8_t));
+    if (state->size_file_name == NULL) {
+        return;
+    }
+    memcpy(state->size_file_name, tx->request + 5, len);
+    state->size_file_name_len = len;
+    state->file_size = file_size;
+}
+
 /**
  * \brief  Handle preliminary replies -- keep tx open
  * \retval bool True for a positive preliminary reply; false otherwise
@@ -763,6 +762,10 @@ static AppLayerResult FTPParseResponse(Flow *f, void *ftp_state, AppLayerParserS
                     FTPParsePassiveResponseV6(ftp_state, line.buf, line.len);
                 }
                 break;
+
+            case FTP_COMMAND_SIZE:
+                FTPParseSizeResponse(state, tx, line.buf, line.len);
+                break;
             default:
                 break;
         }
@@ -829,6 +832,7 @@ static void FTPStateFree(void *s)
     FtpState *fstate = (FtpState *) s;
     if (fstate->port_line != NULL)
         FTPFree(fstate->port_line, fstate->port_line_size);
+    FTPFreeSizeFileName(fstate);
 
     FTPTransaction *tx = NULL;
     while ((tx = TAILQ_FIRST(&fstate->tx_list))) {
@@ -1026,239 +1030,7 @@ static int FTPRegisterPatternsForProtocolDetection(void)
 
 
 static StreamingBufferConfig sbcfg = STREAMING_BUFFER_CONFIG_INITIALIZER;
-
-/**
- * \brief This function is called to retrieve a ftp request
- * \param ftp_state the ftp state structure for the parser
- * \param output the resulting output
- *
- * \retval 1 when the command is parsed, 0 otherwise
- */
-static AppLayerResult FTPDataParse(Flow *f, FtpDataState *ftpdata_state,
-        AppLayerParserState *pstate, StreamSlice stream_slice, void *local_data, uint8_t direction)
-{
-    const uint8_t *input = StreamSliceGetData(&stream_slice);
-    uint32_t input_len = StreamSliceGetDataLen(&stream_slice);
-    const bool eof = (direction & STREAM_TOSERVER)
-                             ? AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TS) != 0
-                             : AppLayerParserStateIssetFlag(pstate, APP_LAYER_PARSER_EOF_TC) != 0;
-
-    SCTxDataUpdateFileFlags(&ftpdata_state->tx_data, ftpdata_state->state_data.file_flags);
-    if (ftpdata_state->tx_data.file_tx == 0)
-        ftpdata_state->tx_data.file_tx = direction & (STREAM_TOSERVER | STREAM_TOCLIENT);
-
-    /* we depend on detection engine for file pruning */
-    const uint16_t flags = FileFlowFlagsToFlags(ftpdata_state->tx_data.file_flags, direction);
-    int ret = 0;
-
-    SCLogDebug("FTP-DATA input_len %u flags %04x dir %d/%s EOF %s", input_len, flags, direction,
-            (direction & STREAM_TOSERVER) ? "toserver" : "toclient", eof ? "true" : "false");
-
-    SCLogDebug("FTP-DATA flags %04x dir %d", flags, direction);
-    if (input_len && ftpdata_state->files == NULL) {
-        struct FtpTransferCmd *data =
-                (struct FtpTransferCmd *)FlowGetStorageById(f, AppLayerExpectationGetFlowId());
-        if (data == NULL) {
-            SCReturnStruct(APP_LAYER_ERROR);
-        }
-
-        /* we shouldn't get data in the wrong dir. Don't set things up for this dir */
-        if ((direction & data->direction) == 0) {
-            // TODO set event for data in wrong direction
-            SCLogDebug("input %u not for our direction (%s): %s/%s", input_len,
-                    (direction & STREAM_TOSERVER) ? "toserver" : "toclient",
-                    data->cmd == FTP_COMMAND_STOR ? "STOR" : "RETR",
-                    (data->direction & STREAM_TOSERVER) ? "toserver" : "toclient");
-            SCReturnStruct(APP_LAYER_OK);
-        }
-
-        ftpdata_state->files = FileContainerAlloc();
-        if (ftpdata_state->files == NULL) {
-            FlowFreeStorageById(f, AppLayerExpectationGetFlowId());
-            SCReturnStruct(APP_LAYER_ERROR);
-        }
-
-        ftpdata_state->file_name = data->file_name;
-        ftpdata_state->file_len = data->file_len;
-        data->file_name = NULL;
-        data->file_len = 0;
-        f->parent_id = data->flow_id;
-        ftpdata_state->command = data->cmd;
-        switch (data->cmd) {
-            case FTP_COMMAND_STOR:
-                ftpdata_state->direction = data->direction;
-                SCLogDebug("STOR data to %s",
-                        (ftpdata_state->direction & STREAM_TOSERVER) ? "toserver" : "toclient");
-                break;
-            case FTP_COMMAND_RETR:
-                ftpdata_state->direction = data->direction;
-                SCLogDebug("RETR data to %s",
-                        (ftpdata_state->direction & STREAM_TOSERVER) ? "toserver" : "toclient");
-                break;
-            default:
-                break;
-        }
-
-        /* open with fixed track_id 0 as we can have just one
-         * file per ftp-data flow. */
-        if (FileOpenFileWithId(ftpdata_state->files, &sbcfg,
-                         0ULL, (uint8_t *) ftpdata_state->file_name,
-                         ftpdata_state->file_len,
-                         input, input_len, flags) != 0) {
-            SCLogDebug("Can't open file");
-            ret = -1;
-        }
-        FlowFreeStorageById(f, AppLayerExpectationGetFlowId());
-        ftpdata_state->tx_data.files_opened = 1;
-    } else {
-        if (ftpdata_state->state == FTPDATA_STATE_FINISHED) {
-            SCLogDebug("state is already finished");
-            DEBUG_VALIDATE_BUG_ON(input_len); // data after state finished is a bug.
-            SCReturnStruct(APP_LAYER_OK);
-        }
-        if ((direction & ftpdata_state->direction) == 0) {
-            if (input_len) {
-                // TODO set event for data in wrong direction
-            }
-            SCLogDebug("input %u not for us (%s): %s/%s", input_len,
-                    (direction & STREAM_TOSERVER) ? "toserver" : "toclient",
-                    ftpdata_state->command == FTP_COMMAND_STOR ? "STOR" : "RETR",
-                    (ftpdata_state->direction & STREAM_TOSERVER) ? "toserver" : "toclient");
-            SCReturnStruct(APP_LAYER_OK);
-        }
-        if (input_len != 0) {
-            ret = FileAppendData(ftpdata_state->files, &sbcfg, input, input_len);
-            if (ret == -2) {
-                ret = 0;
-                SCLogDebug("FileAppendData() - file no longer being extracted");
-                goto out;
-            } else if (ret < 0) {
-                SCLogDebug("FileAppendData() failed: %d", ret);
-                ret = -2;
-                goto out;
-            }
-        }
-    }
-
-    BUG_ON((direction & ftpdata_state->direction) == 0); // should be unreachable
-    if (eof) {
-        ret = FileCloseFile(ftpdata_state->files, &sbcfg, NULL, 0, flags);
-        ftpdata_state->state = FTPDATA_STATE_FINISHED;
-        SCLogDebug("closed because of eof: state now FTPDATA_STATE_FINISHED");
-    }
-out:
-    if (ret < 0) {
-        SCReturnStruct(APP_LAYER_ERROR);
-    }
-    SCReturnStruct(APP_LAYER_OK);
-}
-
-static AppLayerResult FTPDataParseRequest(Flow *f, void *ftp_state, AppLayerParserState *pstate,
-        StreamSlice stream_slice, void *local_data)
-{
-    return FTPDataParse(f, ftp_state, pstate, stream_slice, local_data, STREAM_TOSERVER);
-}
-
-static AppLayerResult FTPDataParseResponse(Flow *f, void *ftp_state, AppLayerParserState *pstate,
-        StreamSlice stream_slice, void *local_data)
-{
-    return FTPDataParse(f, ftp_state, pstate, stream_slice, local_data, STREAM_TOCLIENT);
-}
-
-#ifdef DEBUG
-static SCMutex ftpdata_state_mem_lock = SCMUTEX_INITIALIZER;
-static uint64_t ftpdata_state_memuse = 0;
-static uint64_t ftpdata_state_memcnt = 0;
-#endif
-
-static void *FTPDataStateAlloc(void *orig_state, AppProto proto_orig)
-{
-    void *s = FTPCalloc(1, sizeof(FtpDataState));
-    if (unlikely(s == NULL))
-        return NULL;
-
-    FtpDataState *state = (FtpDataState *) s;
-    state->state = FTPDATA_STATE_IN_PROGRESS;
-
-#ifdef DEBUG
-    SCMutexLock(&ftpdata_state_mem_lock);
-    ftpdata_state_memcnt++;
-    ftpdata_state_memuse+=sizeof(FtpDataState);
-    SCMutexUnlock(&ftpdata_state_mem_lock);
-#endif
-    return s;
-}
-
-static void FTPDataStateFree(void *s)
-{
-    FtpDataState *fstate = (FtpDataState *) s;
-
-    if (fstate->tx_data.de_state != NULL) {
-        DetectEngineStateFree(fstate->tx_data.de_state);
-    }
-    if (fstate->file_name != NULL) {
-        FTPFree(fstate->file_name, fstate->file_len + 1);
-    }
-
-    FileContainerFree(fstate->files, &sbcfg);
-
-    FTPFree(s, sizeof(FtpDataState));
-#ifdef DEBUG
-    SCMutexLock(&ftpdata_state_mem_lock);
-    ftpdata_state_memcnt--;
-    ftpdata_state_memuse-=sizeof(FtpDataState);
-    SCMutexUnlock(&ftpdata_state_mem_lock);
-#endif
-}
-
-static AppLayerTxData *FTPDataGetTxData(void *vtx)
-{
-    FtpDataState *ftp_state = (FtpDataState *)vtx;
-    return &ftp_state->tx_data;
-}
-
-static AppLayerStateData *FTPDataGetStateData(void *vstate)
-{
-    FtpDataState *ftp_state = (FtpDataState *)vstate;
-    return &ftp_state->state_data;
-}
-
-static void FTPDataStateTransactionFree(void *state, uint64_t tx_id)
-{
-    /* do nothing */
-}
-
-static void *FTPDataGetTx(void *state, uint64_t tx_id)
-{
-    FtpDataState *ftp_state = (FtpDataState *)state;
-    return ftp_state;
-}
-
-static uint64_t FTPDataGetTxCnt(void *state)
-{
-    /* ftp-data is single tx */
-    return 1;
-}
-
-static int FTPDataGetAlstateProgress(void *tx, uint8_t direction)
-{
-    FtpDataState *ftpdata_state = (FtpDataState *)tx;
-    if (direction == ftpdata_state->direction)
-        return ftpdata_state->state;
-    else
-        return FTPDATA_STATE_FINISHED;
-}
-
-static AppLayerGetFileState FTPDataStateGetTxFiles(void *tx, uint8_t direction)
-{
-    FtpDataState *ftpdata_state = (FtpDataState *)tx;
-    AppLayerGetFileState files = { .fc = NULL, .cfg = &sbcfg };
-
-    if (direction == ftpdata_state->direction)
-        files.fc = ftpdata_state->files;
-
-    return files;
-}
+static SuricataFileContext sfc = { &sbcfg };
 
 static void FTPSetMpmState(void)
 {
@@ -1335,14 +1107,12 @@ static AppLayerGetTxIterTuple FTPGetTxIterator(const uint8_t ipproto, const AppP
 void RegisterFTPParsers(void)
 {
     const char *proto_name = "ftp";
-    const char *proto_data_name = "ftp-data";
 
     /** FTP */
     if (AppLayerProtoDetectConfProtoDetectionEnabled("tcp", proto_name)) {
         AppLayerProtoDetectRegisterProtocol(ALPROTO_FTP, proto_name);
         if (FTPRegisterPatternsForProtocolDetection() < 0 )
             return;
-        AppLayerProtoDetectRegisterProtocol(ALPROTO_FTPDATA, proto_data_name);
     }
 
     if (AppLayerParserConfParserEnabled("tcp", proto_name)) {
@@ -1369,33 +1139,10 @@ void RegisterFTPParsers(void)
         AppLayerParserRegisterStateProgressCompletionStatus(
                 ALPROTO_FTP, FTP_STATE_FINISHED, FTP_STATE_FINISHED);
 
-        AppLayerRegisterExpectationProto(IPPROTO_TCP, ALPROTO_FTPDATA);
-        AppLayerParserRegisterParser(IPPROTO_TCP, ALPROTO_FTPDATA, STREAM_TOSERVER,
-                                     FTPDataParseRequest);
-        AppLayerParserRegisterParser(IPPROTO_TCP, ALPROTO_FTPDATA, STREAM_TOCLIENT,
-                                     FTPDataParseResponse);
-        AppLayerParserRegisterStateFuncs(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataStateAlloc, FTPDataStateFree);
-        AppLayerParserRegisterParserAcceptableDataDirection(IPPROTO_TCP, ALPROTO_FTPDATA, STREAM_TOSERVER | STREAM_TOCLIENT);
-        AppLayerParserRegisterTxFreeFunc(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataStateTransactionFree);
-
-        AppLayerParserRegisterGetTxFilesFunc(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataStateGetTxFiles);
-
-        AppLayerParserRegisterGetTx(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataGetTx);
-        AppLayerParserRegisterTxDataFunc(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataGetTxData);
-        AppLayerParserRegisterStateDataFunc(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataGetStateData);
-
-        AppLayerParserRegisterGetTxCnt(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataGetTxCnt);
-
-        AppLayerParserRegisterGetStateProgressFunc(IPPROTO_TCP, ALPROTO_FTPDATA, FTPDataGetAlstateProgress);
-
-        AppLayerParserRegisterStateProgressCompletionStatus(
-                ALPROTO_FTPDATA, FTPDATA_STATE_FINISHED, FTPDATA_STATE_FINISHED);
-
         AppLayerParserRegisterGetEventInfo(IPPROTO_TCP, ALPROTO_FTP, ftp_get_event_info);
         AppLayerParserRegisterGetEventInfoById(IPPROTO_TCP, ALPROTO_FTP, ftp_get_event_info_by_id);
 
         AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_FTP);
-        AppLayerParserRegisterLogger(IPPROTO_TCP, ALPROTO_FTPDATA);
 
         sbcfg.buf_size = 4096;
         sbcfg.Calloc = FTPCalloc;
@@ -1409,6 +1156,9 @@ void RegisterFTPParsers(void)
 
     FTPSetMpmState();
 
+    /* ftp-data, the flows of the expectations of the ftp parser */
+    SCRegisterFtpDataParser(&sfc);
+
 #ifdef UNITTESTS
     AppLayerParserRegisterProtocolUnittests(IPPROTO_TCP, ALPROTO_FTP, FTPParserRegisterTests);
 #endif
@@ -1443,13 +1193,18 @@ uint16_t JsonGetNextLineFromBuffer(const char *buffer, const uint16_t len)
 
 bool EveFTPDataAddMetadata(void *vtx, JsonBuilder *jb)
 {
-    const FtpDataState *ftp_state = (FtpDataState *)vtx;
+    const uint8_t *file_name;
+    uint32_t file_name_len;
+    uint64_t file_size;
     jb_open_object(jb, "ftp_data");
 
-    if (ftp_state->file_name) {
-        jb_set_string_from_bytes(jb, "filename", ftp_state->file_name, ftp_state->file_len);
+    if (SCFtpDataTxGetFileName(vtx, &file_name, &file_name_len)) {
+        jb_set_string_from_bytes(jb, "filename", file_name, file_name_len);
+    }
+    if (SCFtpDataTxGetFileSize(vtx, &file_size)) {
+        jb_set_uint(jb, "size", file_size);
     }
-    switch (ftp_state->command) {
+    switch (SCFtpDataTxGetCommand(vtx)) {
         case FTP_COMMAND_STOR:
             JB_SET_STRING(jb, "command", "STOR");
             break;
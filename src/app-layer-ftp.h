@@ -160,27 +160,13 @@ typedef struct FtpState_ {
 
     uint16_t dyn_port;
 
-    AppLayerStateData state_data;
-} FtpState;
-
-enum {
-    FTPDATA_STATE_IN_PROGRESS,
-    FTPDATA_STATE_FINISHED,
-};
+    /** file name of the last SIZE command and the size replied */
+    uint8_t *size_file_name;
+    uint32_t size_file_name_len;
+    uint64_t file_size;
 
-/** FTP Data State for app layer parser */
-typedef struct FtpDataState_ {
-    uint8_t *input;
-    uint8_t *file_name;
-    FileContainer *files;
-    int32_t input_len;
-    int16_t file_len;
-    FtpRequestCommand command;
-    uint8_t state;
-    uint8_t direction;
-    AppLayerTxData tx_data;
     AppLayerStateData state_data;
-} FtpDataState;
+} FtpState;
 
 void RegisterFTPParsers(void);
 void FTPParserRegisterTests(void);
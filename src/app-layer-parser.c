@@ -143,7 +143,15 @@ struct AppLayerParserState_ {
     /* Used to store decoder events. */
     AppLayerDecoderEvents *decoder_events;
 
+    /* Per event counts of the events of the freed transactions and of
+     * the app-layer events of all packets of the flow, for the anomaly
+     * summary logger. */
+    AnomalySummary *anomaly_summary;
+
     FramesContainer *frames;
+
+    /* Time the app-layer state was allocated. */
+    SCTime_t alstate_ts;
 };
 
 enum ExceptionPolicy g_applayerparser_error_policy = EXCEPTION_POLICY_NOT_SET;
@@ -239,6 +247,8 @@ void AppLayerParserStateFree(AppLayerParserState *pstate)
 
     if (pstate->decoder_events != NULL)
         AppLayerDecoderEventsFreeEvents(&pstate->decoder_events);
+    if (pstate->anomaly_summary != NULL)
+        SCAnomalySummaryFree(pstate->anomaly_summary);
     AppLayerParserFramesFreeContainer(pstate->frames);
     SCFree(pstate);
 
@@ -877,6 +887,71 @@ static void AppLayerParserFileTxHousekeeping(
 extern int g_detect_disabled;
 extern bool g_file_logger_enabled;
 extern bool g_filedata_logger_enabled;
+extern bool g_anomaly_summary_enabled;
+
+/** \brief add the parser events in \a events to \a summary */
+static void AppLayerParserAnomalySummaryAdd(
+        const Flow *f, AnomalySummary *summary, const AppLayerDecoderEvents *events)
+{
+    if (events == NULL || events->cnt == 0)
+        return;
+
+    const char *alproto_name = AppProtoToString(f->alproto);
+    for (uint8_t i = 0; i < events->cnt; i++) {
+        const uint8_t event_code = events->events[i];
+        const char *event_name = NULL;
+        AppLayerEventType event_type;
+        if (AppLayerParserGetEventInfoById(
+                    f->proto, f->alproto, event_code, &event_name, &event_type) != 0) {
+            event_name = NULL;
+        }
+        SCAnomalySummaryAdd(summary, f->alproto, alproto_name, event_code, event_name);
+    }
+}
+
+static AnomalySummary *AppLayerParserGetAnomalySummaryStorage(Flow *f)
+{
+    if (f->alparser == NULL) {
+        f->alparser = AppLayerParserStateAlloc();
+        if (f->alparser == NULL)
+            return NULL;
+    }
+    AppLayerParserState *pstate = f->alparser;
+    if (pstate->anomaly_summary == NULL) {
+        pstate->anomaly_summary = SCAnomalySummaryNew();
+    }
+    return pstate->anomaly_summary;
+}
+
+/**
+ * \brief add the app-layer events of packet \a p to the anomaly summary
+ *        of its flow
+ *
+ * The packet events are reset for each packet, so they are added for every
+ * packet of the flow. They are counted as "applayer" events, like the
+ * app-layer-event rule keyword does.
+ */
+void AppLayerParserAnomalySummaryAddPacket(const Packet *p)
+{
+    if (!g_anomaly_summary_enabled || p->flow == NULL || p->app_layer_events == NULL ||
+            p->app_layer_events->cnt == 0)
+        return;
+
+    AnomalySummary *summary = AppLayerParserGetAnomalySummaryStorage(p->flow);
+    if (summary == NULL)
+        return;
+
+    const AppLayerDecoderEvents *events = p->app_layer_events;
+    for (uint8_t i = 0; i < events->cnt; i++) {
+        const uint8_t event_code = events->events[i];
+        const char *event_name = NULL;
+        AppLayerEventType event_type;
+        if (AppLayerGetEventInfoById(event_code, &event_name, &event_type) != 0) {
+            event_name = NULL;
+        }
+        SCAnomalySummaryAdd(summary, ALPROTO_UNKNOWN, "applayer", event_code, event_name);
+    }
+}
 
 /**
  * \brief remove obsolete (inspected and logged) transactions
@@ -1007,6 +1082,12 @@ void AppLayerParserTransactionsCleanup(Flow *f, const uint8_t pkt_dir)
         }
 
         /* if we are here, the tx can be freed. */
+        if (g_anomaly_summary_enabled && txd && txd->events && txd->events->cnt) {
+            AnomalySummary *summary = AppLayerParserGetAnomalySummaryStorage(f);
+            if (summary != NULL) {
+                AppLayerParserAnomalySummaryAdd(f, summary, txd->events);
+            }
+        }
         p->StateTransactionFree(alstate, i);
         SCLogDebug("%p/%"PRIu64" freed", tx, i);
 
@@ -1045,6 +1126,66 @@ next:
     SCReturn;
 }
 
+/**
+ * \brief Get the time the app-layer state of the flow was allocated.
+ *
+ * \retval true if the flow has an app-layer state, false otherwise
+ */
+bool AppLayerParserGetStateCreationTs(const Flow *f, SCTime_t *ts)
+{
+    if (f->alstate == NULL || f->alparser == NULL)
+        return false;
+
+    const AppLayerParserState *pstate = f->alparser;
+    *ts = pstate->alstate_ts;
+    return true;
+}
+
+/**
+ * \brief get the anomaly summary of a flow
+ *
+ * Fills \a summary with the events counted for the flow so far and the
+ * events of the transactions that have not been freed yet. The flow is
+ * not modified, so this can be called more than once for a flow.
+ *
+ * \param summary empty summary to fill
+ *
+ * \retval true if events were raised on the flow
+ */
+bool AppLayerParserGetAnomalySummary(const Flow *f, AnomalySummary *summary)
+{
+    const AppLayerParserState *pstate = f->alparser;
+    if (pstate == NULL)
+        return false;
+
+    if (pstate->anomaly_summary != NULL) {
+        SCAnomalySummaryMerge(summary, pstate->anomaly_summary);
+    }
+    if (f->alstate == NULL)
+        return !SCAnomalySummaryIsEmpty(summary);
+
+    AppLayerGetTxIteratorFunc IterFunc = AppLayerGetTxIterator(f->proto, f->alproto);
+    AppLayerGetTxIterState state;
+    memset(&state, 0, sizeof(state));
+    const uint64_t total_txs = AppLayerParserGetTxCnt(f, f->alstate);
+    uint64_t i = pstate->min_id;
+    while (1) {
+        AppLayerGetTxIterTuple ires =
+                IterFunc(f->proto, f->alproto, f->alstate, i, total_txs, &state);
+        if (ires.tx_ptr == NULL)
+            break;
+        AppLayerTxData *txd = AppLayerParserGetTxData(f->proto, f->alproto, ires.tx_ptr);
+        if (txd != NULL) {
+            AppLayerParserAnomalySummaryAdd(f, summary, txd->events);
+        }
+        if (!ires.has_next)
+            break;
+        i = ires.tx_id + 1;
+    }
+
+    return !SCAnomalySummaryIsEmpty(summary);
+}
+
 static inline int StateGetProgressCompletionStatus(const AppProto alproto, const uint8_t flags)
 {
     if (flags & STREAM_TOSERVER) {
@@ -1307,6 +1448,14 @@ int AppLayerParserParse(ThreadVars *tv, AppLayerParserThreadCtx *alp_tctx, Flow
 
     SetEOFFlags(pstate, flags);
 
+    /* parser only wanted the first data in this direction */
+    if ((p->option_flags & APP_LAYER_PARSER_OPT_FIRST_DATA_ONLY) && input_len > 0 &&
+            (pstate->flags & (direction == 0 ? APP_LAYER_PARSER_FIRST_DATA_TS
+                                             : APP_LAYER_PARSER_FIRST_DATA_TC))) {
+        SCLogDebug("parser already got its first data in this direction");
+        goto end;
+    }
+
     alstate = f->alstate;
     if (alstate == NULL || FlowChangeProto(f)) {
         f->alstate = alstate = p->StateAlloc(alstate, f->alproto_orig);
@@ -1316,6 +1465,7 @@ int AppLayerParserParse(ThreadVars *tv, AppLayerParserThreadCtx *alp_tctx, Flow
         }
         SCLogDebug("alloced new app layer state %p (name %s)",
                    alstate, AppLayerGetProtoName(f->alproto));
+        pstate->alstate_ts = f->lastts;
 
         /* set flow flags to state */
         if (f->file_flags != 0) {
@@ -1357,11 +1507,33 @@ int AppLayerParserParse(ThreadVars *tv, AppLayerParserThreadCtx *alp_tctx, Flow
             goto error;
         }
 #endif
+        SCAppLayerBytesAdd(tv, f->proto, alproto, flags, input_len);
+
         /* invoke the parser */
         AppLayerResult res = p->Parser[direction](f, alstate, pstate, stream_slice,
                 alp_tctx->alproto_local_storage[f->protomap][alproto]);
+
+        /* count the times the parser reached its transaction limit */
+        AppLayerStateData *sd = AppLayerParserGetStateData(f->proto, alproto, alstate);
+        if (sd != NULL && sd->tx_limit_reached > 0) {
+            AppLayerIncTxLimitCounter(tv, f, sd->tx_limit_reached);
+            sd->tx_limit_reached = 0;
+        }
         if (res.status < 0) {
-            AppLayerIncParserErrorCounter(tv, f);
+            switch (res.status) {
+                case APP_LAYER_ERROR_REASON_GAP:
+                    AppLayerIncGapErrorCounter(tv, f);
+                    break;
+                case APP_LAYER_ERROR_REASON_MEMCAP:
+                    AppLayerIncMemcapErrorCounter(tv, f);
+                    break;
+                case APP_LAYER_ERROR_REASON_INTERNAL:
+                    AppLayerIncInternalErrorCounter(tv, f);
+                    break;
+                default:
+                    AppLayerIncParserErrorCounter(tv, f);
+                    break;
+            }
             goto error;
         } else if (res.status > 0) {
             DEBUG_VALIDATE_BUG_ON(res.consumed > input_len);
@@ -1397,6 +1569,20 @@ int AppLayerParserParse(ThreadVars *tv, AppLayerParserThreadCtx *alp_tctx, Flow
             }
             consumed = res.consumed;
         }
+
+        if ((p->option_flags & APP_LAYER_PARSER_OPT_FIRST_DATA_ONLY) && input_len > 0) {
+            AppLayerParserStateSetFlag(pstate,
+                    direction == 0 ? APP_LAYER_PARSER_FIRST_DATA_TS
+                                   : APP_LAYER_PARSER_FIRST_DATA_TC);
+            /* once both directions are done, there is nothing left to
+             * feed the parser with */
+            if ((pstate->flags & (APP_LAYER_PARSER_FIRST_DATA_TS |
+                                         APP_LAYER_PARSER_FIRST_DATA_TC)) ==
+                    (APP_LAYER_PARSER_FIRST_DATA_TS | APP_LAYER_PARSER_FIRST_DATA_TC)) {
+                AppLayerParserStateSetFlag(
+                        pstate, APP_LAYER_PARSER_NO_INSPECTION | APP_LAYER_PARSER_NO_REASSEMBLY);
+            }
+        }
     }
 
     /* set the packets to no inspection and reassembly if required */
@@ -1732,6 +1918,14 @@ void AppLayerParserRegisterProtocolParsers(void)
     RegisterHTTP2Parsers();
     rs_telnet_register_parser();
     RegisterIMAPParsers();
+    SCRegisterImapParser();
+    SCRegisterRsyncParser();
+    SCRegisterOpenVpnParser();
+    SCRegisterWireGuardParser();
+    SCRegisterStunParser();
+    SCRegisterSyslogParser();
+    SCRegisterDhcpv6Parser();
+    SCRegisterRtpParser();
 
     /** POP3 */
     AppLayerProtoDetectRegisterProtocol(ALPROTO_POP3, "pop3");
@@ -1743,6 +1937,7 @@ void AppLayerParserRegisterProtocolParsers(void)
     } else {
         SCLogInfo("Protocol detection and parser disabled for pop3 protocol.");
     }
+    SCRegisterPop3Parser();
 
     ValidateParsers();
 }
@@ -38,12 +38,17 @@
 #define APP_LAYER_PARSER_BYPASS_READY          BIT_U16(4)
 #define APP_LAYER_PARSER_EOF_TS                BIT_U16(5)
 #define APP_LAYER_PARSER_EOF_TC                BIT_U16(6)
-/* 2x vacancy */
+#define APP_LAYER_PARSER_FIRST_DATA_TS         BIT_U16(7)
+#define APP_LAYER_PARSER_FIRST_DATA_TC         BIT_U16(8)
 #define APP_LAYER_PARSER_SFRAME_TS             BIT_U16(9)
 #define APP_LAYER_PARSER_SFRAME_TC             BIT_U16(10)
 
 /* Flags for AppLayerParserProtoCtx. */
 #define APP_LAYER_PARSER_OPT_ACCEPT_GAPS BIT_U32(0)
+/** parser wants the full stream, stream depth is not applied */
+#define APP_LAYER_PARSER_OPT_UNLIMITED_STREAM_DEPTH BIT_U32(1)
+/** parser only needs the first data in each direction */
+#define APP_LAYER_PARSER_OPT_FIRST_DATA_ONLY BIT_U32(2)
 
 #define APP_LAYER_PARSER_INT_STREAM_DEPTH_SET   BIT_U32(0)
 
@@ -86,9 +91,26 @@
  *  all of it. */
 #define APP_LAYER_OK (AppLayerResult) { 0, 0, 0 }
 
+/** reason of a parser error, passed as the status of the result and
+ *  counted per protocol in the app_layer.error stats */
+enum AppLayerErrorReason {
+    /** invalid or unsupported data */
+    APP_LAYER_ERROR_REASON_PARSER = -1,
+    /** data lost in a gap the parser can't recover from */
+    APP_LAYER_ERROR_REASON_GAP = -2,
+    /** a memcap or limit of the parser was reached */
+    APP_LAYER_ERROR_REASON_MEMCAP = -3,
+    /** inconsistent state of the parser */
+    APP_LAYER_ERROR_REASON_INTERNAL = -4,
+};
+
 /** parser has hit an unrecoverable error. Returning this to the API
  *  leads to no further calls to the parser. */
-#define APP_LAYER_ERROR (AppLayerResult) { -1, 0, 0 }
+#define APP_LAYER_ERROR (AppLayerResult) { APP_LAYER_ERROR_REASON_PARSER, 0, 0 }
+/** unrecoverable error for a specific reason, see APP_LAYER_ERROR */
+#define APP_LAYER_ERROR_GAP      (AppLayerResult) { APP_LAYER_ERROR_REASON_GAP, 0, 0 }
+#define APP_LAYER_ERROR_MEMCAP   (AppLayerResult) { APP_LAYER_ERROR_REASON_MEMCAP, 0, 0 }
+#define APP_LAYER_ERROR_INTERNAL (AppLayerResult) { APP_LAYER_ERROR_REASON_INTERNAL, 0, 0 }
 
 /** parser needs more data. Through 'c' it will indicate how many
  *  of the input bytes it has consumed. Through 'n' it will indicate
@@ -308,6 +330,9 @@ AppLayerParserState *AppLayerParserStateAlloc(void);
 void AppLayerParserStateFree(AppLayerParserState *pstate);
 
 void AppLayerParserTransactionsCleanup(Flow *f, const uint8_t pkt_dir);
+bool AppLayerParserGetAnomalySummary(const Flow *f, AnomalySummary *summary);
+void AppLayerParserAnomalySummaryAddPacket(const Packet *p);
+bool AppLayerParserGetStateCreationTs(const Flow *f, SCTime_t *ts);
 
 /***** Unittests *****/
 
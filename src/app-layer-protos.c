@@ -68,6 +68,13 @@ const AppProtoStringTuple AppProtoStrings[ALPROTO_MAX] = {
     { ALPROTO_HTTP2, "http2" },
     { ALPROTO_BITTORRENT_DHT, "bittorrent-dht" },
     { ALPROTO_POP3, "pop3" },
+    { ALPROTO_RSYNC, "rsync" },
+    { ALPROTO_OPENVPN, "openvpn" },
+    { ALPROTO_WIREGUARD, "wireguard" },
+    { ALPROTO_STUN, "stun" },
+    { ALPROTO_SYSLOG, "syslog" },
+    { ALPROTO_DHCPV6, "dhcpv6" },
+    { ALPROTO_RTP, "rtp" },
     { ALPROTO_HTTP, "http" },
     { ALPROTO_FAILED, "failed" },
 };
@@ -64,6 +64,13 @@ enum AppProtoEnum {
     ALPROTO_HTTP2,
     ALPROTO_BITTORRENT_DHT,
     ALPROTO_POP3,
+    ALPROTO_RSYNC,
+    ALPROTO_OPENVPN,
+    ALPROTO_WIREGUARD,
+    ALPROTO_STUN,
+    ALPROTO_SYSLOG,
+    ALPROTO_DHCPV6,
+    ALPROTO_RTP,
 
     // signature-only (ie not seen in flow)
     // HTTP for any version (ALPROTO_HTTP1 (version 1) or ALPROTO_HTTP2)
@@ -180,7 +180,6 @@ int AppLayerRegisterParser(const struct AppLayerParser *p, AppProto alproto)
     if (p->flags) {
         AppLayerParserRegisterOptionFlags(p->ip_proto, alproto,
                 p->flags);
-
     }
 
     if (p->GetFrameIdByName && p->GetFrameNameById) {
@@ -81,6 +81,8 @@ typedef struct AppLayerCounterNames_ {
     char parser_error[MAX_COUNTER_SIZE];
     char internal_error[MAX_COUNTER_SIZE];
     char alloc_error[MAX_COUNTER_SIZE];
+    char memcap_error[MAX_COUNTER_SIZE];
+    char tx_limit[MAX_COUNTER_SIZE];
     char eps_name[EXCEPTION_POLICY_MAX][MAX_COUNTER_SIZE];
 } AppLayerCounterNames;
 
@@ -91,6 +93,8 @@ typedef struct AppLayerCounters_ {
     uint16_t parser_error_id;
     uint16_t internal_error_id;
     uint16_t alloc_error_id;
+    uint16_t memcap_error_id;
+    uint16_t tx_limit_id;
     ExceptionPolicyCounters eps_error;
 } AppLayerCounters;
 
@@ -190,6 +194,22 @@ void AppLayerIncInternalErrorCounter(ThreadVars *tv, Flow *f)
     }
 }
 
+void AppLayerIncMemcapErrorCounter(ThreadVars *tv, Flow *f)
+{
+    const uint16_t id = applayer_counters[f->protomap][f->alproto].memcap_error_id;
+    if (likely(tv && id > 0)) {
+        StatsIncr(tv, id);
+    }
+}
+
+void AppLayerIncTxLimitCounter(ThreadVars *tv, Flow *f, uint64_t step)
+{
+    const uint16_t id = applayer_counters[f->protomap][f->alproto].tx_limit_id;
+    if (likely(tv && id > 0)) {
+        StatsAddUI64(tv, id, step);
+    }
+}
+
 static void AppLayerIncrErrorExcPolicyCounter(ThreadVars *tv, Flow *f, enum ExceptionPolicy policy)
 {
 #ifdef UNITTESTS
@@ -1113,6 +1133,10 @@ void AppLayerRegisterGlobalCounters(void)
     StatsRegisterGlobalCounter("ftp.memuse", FTPMemuseGlobalCounter);
     StatsRegisterGlobalCounter("ftp.memcap", FTPMemcapGlobalCounter);
     StatsRegisterGlobalCounter("app_layer.expectations", ExpectationGetCounter);
+    StatsRegisterGlobalCounter("smb.latency.p50", SCSmbLatencyP50);
+    StatsRegisterGlobalCounter("smb.latency.p90", SCSmbLatencyP90);
+    StatsRegisterGlobalCounter("smb.latency.p99", SCSmbLatencyP99);
+    StatsRegisterGlobalCounter("smb.latency.max", SCSmbLatencyMax);
 }
 
 static bool IsAppLayerErrorExceptionPolicyStatsValid(enum ExceptionPolicy policy)
@@ -1197,6 +1221,12 @@ void AppLayerSetupCounters(void)
                     snprintf(applayer_counter_names[ipproto_map][alproto].internal_error,
                             sizeof(applayer_counter_names[ipproto_map][alproto].internal_error),
                             "%s%s%s.internal", estr, alproto_str, ipproto_suffix);
+                    snprintf(applayer_counter_names[ipproto_map][alproto].memcap_error,
+                            sizeof(applayer_counter_names[ipproto_map][alproto].memcap_error),
+                            "%s%s%s.memcap", estr, alproto_str, ipproto_suffix);
+                    snprintf(applayer_counter_names[ipproto_map][alproto].tx_limit,
+                            sizeof(applayer_counter_names[ipproto_map][alproto].tx_limit),
+                            "%s%s%s.tx_limit_reached", estr, alproto_str, ipproto_suffix);
 
                     AppLayerSetupExceptionPolicyPerProtoCounters(
                             ipproto_map, alproto, alproto_str, ipproto_suffix);
@@ -1222,6 +1252,12 @@ void AppLayerSetupCounters(void)
                     snprintf(applayer_counter_names[ipproto_map][alproto].internal_error,
                             sizeof(applayer_counter_names[ipproto_map][alproto].internal_error),
                             "%s%s.internal", estr, alproto_str);
+                    snprintf(applayer_counter_names[ipproto_map][alproto].memcap_error,
+                            sizeof(applayer_counter_names[ipproto_map][alproto].memcap_error),
+                            "%s%s.memcap", estr, alproto_str);
+                    snprintf(applayer_counter_names[ipproto_map][alproto].tx_limit,
+                            sizeof(applayer_counter_names[ipproto_map][alproto].tx_limit),
+                            "%s%s.tx_limit_reached", estr, alproto_str);
                     AppLayerSetupExceptionPolicyPerProtoCounters(
                             ipproto_map, alproto, alproto_str, "");
                 }
@@ -1237,6 +1273,9 @@ void AppLayerSetupCounters(void)
             }
         }
     }
+
+    /* bytes passed to the Rust parsers, if enabled */
+    SCAppLayerBytesSetupCounters();
 }
 
 void AppLayerRegisterThreadCounters(ThreadVars *tv)
@@ -1278,6 +1317,10 @@ void AppLayerRegisterThreadCounters(ThreadVars *tv)
                         applayer_counter_names[ipproto_map][alproto].parser_error, tv);
                 applayer_counters[ipproto_map][alproto].internal_error_id = StatsRegisterCounter(
                         applayer_counter_names[ipproto_map][alproto].internal_error, tv);
+                applayer_counters[ipproto_map][alproto].memcap_error_id = StatsRegisterCounter(
+                        applayer_counter_names[ipproto_map][alproto].memcap_error, tv);
+                applayer_counters[ipproto_map][alproto].tx_limit_id = StatsRegisterCounter(
+                        applayer_counter_names[ipproto_map][alproto].tx_limit, tv);
                 /* We don't log stats counters if exception policy is `ignore`/`not set` */
                 if (g_stats_eps_per_app_proto_errors &&
                         g_applayerparser_error_policy != EXCEPTION_POLICY_NOT_SET) {
@@ -1301,6 +1344,7 @@ void AppLayerRegisterThreadCounters(ThreadVars *tv)
             }
         }
     }
+    SCAppLayerBytesRegisterThreadCounters(tv);
 }
 
 void AppLayerDeSetupCounters(void)
@@ -143,6 +143,8 @@ void AppLayerIncTxCounter(ThreadVars *tv, Flow *f, uint64_t step);
 void AppLayerIncGapErrorCounter(ThreadVars *tv, Flow *f);
 void AppLayerIncAllocErrorCounter(ThreadVars *tv, Flow *f);
 void AppLayerIncParserErrorCounter(ThreadVars *tv, Flow *f);
+void AppLayerIncMemcapErrorCounter(ThreadVars *tv, Flow *f);
+void AppLayerIncTxLimitCounter(ThreadVars *tv, Flow *f, uint64_t step);
 void AppLayerIncInternalErrorCounter(ThreadVars *tv, Flow *f);
 
 static inline const uint8_t *StreamSliceGetData(const StreamSlice *stream_slice)
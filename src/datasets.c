@@ -43,6 +43,8 @@
 SCMutex sets_lock = SCMUTEX_INITIALIZER;
 static Dataset *sets = NULL;
 static uint32_t set_ids = 0;
+/* bumped when the hidden sets of a reload are cleaned up */
+static SC_ATOMIC_DECLARE(uint32_t, sets_generation);
 
 static int DatasetAddwRep(Dataset *set, const uint8_t *data, const uint32_t data_len,
         DataRepType *rep);
@@ -627,6 +629,59 @@ Dataset *DatasetFind(const char *name, enum DatasetTypes type)
     return set;
 }
 
+/** \brief look for set by name and take a reference to it
+ *
+ *  The set is not freed by a rule reload as long as the reference is
+ *  held, even if it is replaced. The reference is released with
+ *  DatasetRelease. */
+Dataset *DatasetFindRef(const char *name, enum DatasetTypes type)
+{
+    SCMutexLock(&sets_lock);
+    Dataset *set = DatasetSearchByName(name);
+    if (set) {
+        if (set->type != type) {
+            SCMutexUnlock(&sets_lock);
+            return NULL;
+        }
+        set->refcnt++;
+    }
+    SCMutexUnlock(&sets_lock);
+    return set;
+}
+
+/** \brief release a reference taken with DatasetFindRef, freeing the
+ *         set if it was replaced by a rule reload */
+void DatasetRelease(Dataset *set)
+{
+    SCMutexLock(&sets_lock);
+    BUG_ON(set->refcnt == 0);
+    set->refcnt--;
+    if (set->refcnt == 0 && set->hidden) {
+        Dataset *prev = NULL;
+        for (Dataset *cur = sets; cur != NULL; prev = cur, cur = cur->next) {
+            if (cur != set)
+                continue;
+            if (prev != NULL) {
+                prev->next = cur->next;
+            } else {
+                sets = cur->next;
+            }
+            SCLogDebug("freeing released hidden set %s", set->name);
+            THashShutdown(set->hash);
+            SCFree(set);
+            break;
+        }
+    }
+    SCMutexUnlock(&sets_lock);
+}
+
+/** \brief generation of the sets, changes when the sets replaced by a rule
+ *         reload are cleaned up, so the references are to be renewed */
+uint32_t DatasetsGeneration(void)
+{
+    return SC_ATOMIC_GET(sets_generation);
+}
+
 Dataset *DatasetGet(const char *name, enum DatasetTypes type, const char *save, const char *load,
         uint64_t memcap, uint32_t hashsize)
 {
@@ -819,6 +874,13 @@ void DatasetPostReloadCleanup(void)
             cur = next;
             continue;
         }
+        // Keep the set while references are held, DatasetRelease frees it
+        if (cur->refcnt > 0) {
+            SCLogDebug("Hidden set %s still referenced", cur->name);
+            prev = cur;
+            cur = next;
+            continue;
+        }
         // Delete the set in case it was hidden
         if (prev != NULL) {
             prev->next = next;
@@ -830,6 +892,7 @@ void DatasetPostReloadCleanup(void)
         cur = next;
     }
     SCMutexUnlock(&sets_lock);
+    (void)SC_ATOMIC_ADD(sets_generation, 1);
 }
 
 static void GetDefaultMemcap(uint64_t *memcap, uint32_t *hashsize)
@@ -1406,7 +1469,9 @@ static int DatasetAddIPv4(Dataset *set, const uint8_t *data, const uint32_t data
         return -1;
     }
 
-    if (data_len < 4) {
+    /* don't add the start of an IPv6 address, e.g. from a buffer holding
+     * both IPv4 and IPv6 addresses */
+    if (data_len != 4) {
         return -2;
     }
 
@@ -1426,12 +1491,13 @@ static int DatasetAddIPv6(Dataset *set, const uint8_t *data, const uint32_t data
         return -1;
     }
 
-    if (data_len != 16) {
+    /* IPv4 addresses are stored in the first 4 bytes, as in lookups */
+    if (data_len != 16 && data_len != 4) {
         return -2;
     }
 
     IPv6Type lookup = { .rep.value = 0 };
-    memcpy(lookup.ipv6, data, 16);
+    memcpy(lookup.ipv6, data, data_len);
     struct THashDataGetResult res = THashGetFromHash(set->hash, &lookup);
     if (res.data) {
         DatasetUnlockData(res.data);
@@ -43,6 +43,7 @@ typedef struct Dataset {
     uint32_t id;
     bool from_yaml;                     /* Mark whether the set was retrieved from YAML */
     bool hidden;                        /* Mark the old sets hidden in case of reload */
+    uint32_t refcnt;                    /* References taken with DatasetFindRef */
     THashTableContext *hash;
 
     char load[PATH_MAX];
@@ -53,6 +54,9 @@ typedef struct Dataset {
 
 enum DatasetTypes DatasetGetTypeFromString(const char *s);
 Dataset *DatasetFind(const char *name, enum DatasetTypes type);
+Dataset *DatasetFindRef(const char *name, enum DatasetTypes type);
+void DatasetRelease(Dataset *set);
+uint32_t DatasetsGeneration(void);
 Dataset *DatasetGet(const char *name, enum DatasetTypes type, const char *save, const char *load,
         uint64_t memcap, uint32_t hashsize);
 int DatasetAdd(Dataset *set, const uint8_t *data, const uint32_t data_len);
@@ -1,4 +1,4 @@
-/* Copyright (C) 2020-2021 Open Information Security Foundation
+/* Copyright (C) 2020-2024 Open Information Security Foundation
  *
  * You can copy, redistribute or modify this Program under the terms of
  * the GNU General Public License version 2 as published by the Free
@@ -27,7 +27,7 @@
  *
  * \author Victor Julien <victor@inliniac.net>
  *
- * Decodes ERSPAN Types I and II
+ * Decodes ERSPAN Types I, II and III
  */
 
 #include "suricata-common.h"
@@ -35,6 +35,7 @@
 #include "decode.h"
 #include "decode-events.h"
 #include "decode-erspan.h"
+#include "rust.h"
 
 #include "util-validate.h"
 #include "util-unittest.h"
@@ -42,7 +43,7 @@
 #include "conf.h"
 
 /**
- * \brief Functions to decode ERSPAN Type I and II packets
+ * \brief Functions to decode ERSPAN Type I, II and III packets
  */
 
 /*
@@ -71,7 +72,7 @@ int DecodeERSPANTypeI(ThreadVars *tv, DecodeThreadVars *dtv, Packet *p,
 }
 
 /**
- * \brief ERSPAN Type II
+ * \brief ERSPAN Type II and III
  */
 int DecodeERSPAN(ThreadVars *tv, DecodeThreadVars *dtv, Packet *p, const uint8_t *pkt, uint32_t len)
 {
@@ -79,36 +80,37 @@ int DecodeERSPAN(ThreadVars *tv, DecodeThreadVars *dtv, Packet *p, const uint8_t
 
     StatsIncr(tv, dtv->counter_erspan);
 
-    if (len < sizeof(ErspanHdr)) {
-        ENGINE_SET_EVENT(p,ERSPAN_HEADER_TOO_SMALL);
-        return TM_ECODE_FAILED;
+    ErspanHeader hdr;
+    memset(&hdr, 0, sizeof(hdr));
+    switch (SCErspanDecode(pkt, len, &hdr)) {
+        case ErspanEventNone:
+            break;
+        case ErspanEventHeaderTooSmall:
+            ENGINE_SET_EVENT(p, ERSPAN_HEADER_TOO_SMALL);
+            return TM_ECODE_FAILED;
+        case ErspanEventUnsupportedVersion:
+            ENGINE_SET_EVENT(p, ERSPAN_UNSUPPORTED_VERSION);
+            return TM_ECODE_FAILED;
+        case ErspanEventUnsupportedFrameType:
+            ENGINE_SET_EVENT(p, ERSPAN_UNSUPPORTED_FRAME_TYPE);
+            return TM_ECODE_FAILED;
     }
     if (!PacketIncreaseCheckLayers(p)) {
         return TM_ECODE_FAILED;
     }
 
-    const ErspanHdr *ehdr = (const ErspanHdr *)pkt;
-    uint16_t version = SCNtohs(ehdr->ver_vlan) >> 12;
-    uint16_t vlan_id = SCNtohs(ehdr->ver_vlan) & 0x0fff;
-
-    SCLogDebug("ERSPAN: version %u vlan %u", version, vlan_id);
-
-    /* only v1 is tested at this time */
-    if (version != 1) {
-        ENGINE_SET_EVENT(p,ERSPAN_UNSUPPORTED_VERSION);
-        return TM_ECODE_FAILED;
-    }
+    SCLogDebug("ERSPAN: version %u vlan %u session %u", hdr.version, hdr.vlan, hdr.session_id);
 
-    if (vlan_id > 0) {
+    if (hdr.vlan > 0) {
         if (p->vlan_idx > VLAN_MAX_LAYER_IDX) {
             ENGINE_SET_EVENT(p,ERSPAN_TOO_MANY_VLAN_LAYERS);
             return TM_ECODE_FAILED;
         }
-        p->vlan_id[p->vlan_idx] = vlan_id;
+        p->vlan_id[p->vlan_idx] = hdr.vlan;
         p->vlan_idx++;
     }
 
-    return DecodeEthernet(tv, dtv, p, pkt + sizeof(ErspanHdr), len - sizeof(ErspanHdr));
+    return DecodeEthernet(tv, dtv, p, pkt + hdr.header_len, len - hdr.header_len);
 }
 
 /**
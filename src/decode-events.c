@@ -546,6 +546,10 @@ const struct DecodeEvents_ DEvents[] = {
             "decoder.erspan.too_many_vlan_layers",
             ERSPAN_TOO_MANY_VLAN_LAYERS,
     },
+    {
+            "decoder.erspan.unsupported_frame_type",
+            ERSPAN_UNSUPPORTED_FRAME_TYPE,
+    },
 
     /* Cisco Fabric Path/DCE events. */
     {
@@ -202,6 +202,7 @@ enum {
     ERSPAN_HEADER_TOO_SMALL,
     ERSPAN_UNSUPPORTED_VERSION,
     ERSPAN_TOO_MANY_VLAN_LAYERS,
+    ERSPAN_UNSUPPORTED_FRAME_TYPE,
 
     /* Cisco Fabric Path/DCE events. */
     DCE_PKT_TOO_SMALL,
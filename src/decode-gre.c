@@ -1,4 +1,4 @@
-/* Copyright (C) 2007-2021 Open Information Security Foundation
+/* Copyright (C) 2007-2024 Open Information Security Foundation
  *
  * You can copy, redistribute or modify this Program under the terms of
  * the GNU General Public License version 2 as published by the Free
@@ -35,258 +35,138 @@
 #include "decode.h"
 #include "decode-events.h"
 #include "decode-gre.h"
+#include "rust.h"
 
 #include "util-validate.h"
 #include "util-unittest.h"
 #include "util-debug.h"
 
+static void GRESetEvent(Packet *p, GreEvent event)
+{
+    switch (event) {
+        case GreEventNone:
+            break;
+        case GreEventPktTooSmall:
+            ENGINE_SET_INVALID_EVENT(p, GRE_PKT_TOO_SMALL);
+            break;
+        case GreEventWrongVersion:
+            ENGINE_SET_INVALID_EVENT(p, GRE_WRONG_VERSION);
+            break;
+        case GreEventVersion0Recur:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_RECUR);
+            break;
+        case GreEventVersion0Flags:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_FLAGS);
+            break;
+        case GreEventVersion0HdrTooBig:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_HDR_TOO_BIG);
+            break;
+        case GreEventVersion0MalformedSreHdr:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_MALFORMED_SRE_HDR);
+            break;
+        case GreEventVersion1Chksum:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_CHKSUM);
+            break;
+        case GreEventVersion1Route:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_ROUTE);
+            break;
+        case GreEventVersion1Ssr:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_SSR);
+            break;
+        case GreEventVersion1Recur:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_RECUR);
+            break;
+        case GreEventVersion1Flags:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_FLAGS);
+            break;
+        case GreEventVersion1WrongProtocol:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_WRONG_PROTOCOL);
+            break;
+        case GreEventVersion1NoKey:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_NO_KEY);
+            break;
+        case GreEventVersion1HdrTooBig:
+            ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_HDR_TOO_BIG);
+            break;
+    }
+}
+
 /**
  * \brief Function to decode GRE packets
+ *
+ * The header is validated by the Rust decoder, which also tells which
+ * protocol the payload is to be decoded as.
  */
 
 int DecodeGRE(ThreadVars *tv, DecodeThreadVars *dtv, Packet *p, const uint8_t *pkt, uint32_t len)
 {
     DEBUG_VALIDATE_BUG_ON(pkt == NULL);
 
-    uint32_t header_len = GRE_HDR_LEN;
-    GRESreHdr *gsre = NULL;
-    GREPPtPHd *gre_pptp_h = NULL;
-
     StatsIncr(tv, dtv->counter_gre);
 
-    if(len < GRE_HDR_LEN)    {
-        ENGINE_SET_INVALID_EVENT(p, GRE_PKT_TOO_SMALL);
+    GreHeader hdr;
+    memset(&hdr, 0, sizeof(hdr));
+    GreEvent event = SCGreDecode(pkt, len, &hdr);
+    if (event == GreEventPktTooSmall) {
+        GRESetEvent(p, event);
         return TM_ECODE_FAILED;
     }
     if (!PacketIncreaseCheckLayers(p)) {
         return TM_ECODE_FAILED;
     }
 
-    GREHdr *greh = PacketSetGRE(p, pkt);
-
-    SCLogDebug("p %p pkt %p GRE protocol %04x Len: %d GRE version %x", p, pkt, GRE_GET_PROTO(greh),
-            len, GRE_GET_VERSION(greh));
-
-    switch (GRE_GET_VERSION(greh)) {
-        case GRE_VERSION_0:
-
-            /* GRE version 0 doesn't support the fields below RFC 1701 */
-
-            /**
-             * \todo We need to make sure this does not allow bypassing
-             *       inspection.  A server may just ignore these and
-             *       continue processing the packet, but we will not look
-             *       further into it.
-             */
-
-            if (GRE_FLAG_ISSET_RECUR(greh)) {
-                ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_RECUR);
-                return TM_ECODE_OK;
-            }
-
-            if (GREV1_FLAG_ISSET_FLAGS(greh)) {
-                ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_FLAGS);
-                return TM_ECODE_OK;
-            }
-
-            /* Adjust header length based on content */
-
-            if (GRE_FLAG_ISSET_KY(greh))
-                header_len += GRE_KEY_LEN;
+    PacketSetGRE(p, pkt);
 
-            if (GRE_FLAG_ISSET_SQ(greh))
-                header_len += GRE_SEQ_LEN;
-
-            if (GRE_FLAG_ISSET_CHKSUM(greh) || GRE_FLAG_ISSET_ROUTE(greh))
-                header_len += GRE_CHKSUM_LEN + GRE_OFFSET_LEN;
-
-            if (header_len > len)   {
-                ENGINE_SET_INVALID_EVENT(p, GRE_VERSION0_HDR_TOO_BIG);
-                return TM_ECODE_OK;
-            }
-
-            if (GRE_FLAG_ISSET_ROUTE(greh)) {
-                while (1)
-                {
-                    if ((header_len + GRE_SRE_HDR_LEN) > len) {
-                        ENGINE_SET_INVALID_EVENT(p,
-                                                 GRE_VERSION0_MALFORMED_SRE_HDR);
-                        return TM_ECODE_OK;
-                    }
-
-                    gsre = (GRESreHdr *)(pkt + header_len);
-
-                    header_len += GRE_SRE_HDR_LEN;
-
-                    if ((SCNtohs(gsre->af) == 0) && (gsre->sre_length == 0))
-                        break;
-
-                    header_len += gsre->sre_length;
-                    if (header_len > len) {
-                        ENGINE_SET_INVALID_EVENT(p,
-                                                 GRE_VERSION0_MALFORMED_SRE_HDR);
-                        return TM_ECODE_OK;
-                    }
-                }
-            }
-            break;
-
-        case GRE_VERSION_1:
-
-            /* GRE version 1 doesn't support the fields below RFC 1701 */
-
-            /**
-             * \todo We need to make sure this does not allow bypassing
-             *       inspection.  A server may just ignore these and
-             *       continue processing the packet, but we will not look
-             *       further into it.
-             */
-
-            if (GRE_FLAG_ISSET_CHKSUM(greh)) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_CHKSUM);
-                return TM_ECODE_OK;
-            }
-
-            if (GRE_FLAG_ISSET_ROUTE(greh)) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_ROUTE);
-                return TM_ECODE_OK;
-            }
-
-            if (GRE_FLAG_ISSET_SSR(greh)) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_SSR);
-                return TM_ECODE_OK;
-            }
-
-            if (GRE_FLAG_ISSET_RECUR(greh)) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_RECUR);
-                return TM_ECODE_OK;
-            }
-
-            if (GREV1_FLAG_ISSET_FLAGS(greh)) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_FLAGS);
-                return TM_ECODE_OK;
-            }
-
-            if (GRE_GET_PROTO(greh) != GRE_PROTO_PPP) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_WRONG_PROTOCOL);
-                return TM_ECODE_OK;
-            }
-
-            if (!(GRE_FLAG_ISSET_KY(greh))) {
-                ENGINE_SET_INVALID_EVENT(p,GRE_VERSION1_NO_KEY);
-                return TM_ECODE_OK;
-            }
-
-            header_len += GRE_KEY_LEN;
-            /* key is set and proto == PPP */
-            gre_pptp_h = (GREPPtPHd *)pkt;
-
-            /* Adjust header length based on content */
-
-            if (GRE_FLAG_ISSET_SQ(greh))
-                header_len += GRE_SEQ_LEN;
+    if (event != GreEventNone) {
+        GRESetEvent(p, event);
+        return TM_ECODE_OK;
+    }
 
-            if (GREV1_FLAG_ISSET_ACK(greh))
-                header_len += GREV1_ACK_LEN;
+    SCLogDebug("p %p pkt %p GRE protocol %04x Len: %d GRE version %x", p, pkt, hdr.proto, len,
+            hdr.version);
 
-            if (header_len > len)   {
-                ENGINE_SET_INVALID_EVENT(p, GRE_VERSION1_HDR_TOO_BIG);
-                return TM_ECODE_OK;
-            }
+    p->l4.vars.gre.key = hdr.key;
+    p->l4.vars.gre.key_set = hdr.key_set;
+    p->l4.vars.gre.erspan_session_id = hdr.erspan_session_id;
+    p->l4.vars.gre.erspan_session_id_set = hdr.erspan_session_id_set;
 
+    enum DecodeTunnelProto proto;
+    switch (hdr.tunnel) {
+        case GreTunnelIpv4:
+            proto = DECODE_TUNNEL_IPV4;
             break;
-        default:
-            ENGINE_SET_INVALID_EVENT(p, GRE_WRONG_VERSION);
-            return TM_ECODE_OK;
-    }
-
-    switch (GRE_GET_PROTO(greh)) {
-        case ETHERNET_TYPE_IP:
-        {
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len,
-                    len - header_len, DECODE_TUNNEL_IPV4);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelIpv6:
+            proto = DECODE_TUNNEL_IPV6;
             break;
-        }
-
-        case GRE_PROTO_PPP:
-        {
-            if (gre_pptp_h && !gre_pptp_h->payload_length)
-                return TM_ECODE_OK;
-
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len,
-                    len - header_len, DECODE_TUNNEL_PPP);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelPpp:
+            proto = DECODE_TUNNEL_PPP;
             break;
-        }
-
-        case ETHERNET_TYPE_IPV6:
-        {
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len,
-                    len - header_len, DECODE_TUNNEL_IPV6);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelVlan:
+            proto = DECODE_TUNNEL_VLAN;
             break;
-        }
-
-        case ETHERNET_TYPE_VLAN:
-        {
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len,
-                    len - header_len, DECODE_TUNNEL_VLAN);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelEthernet:
+            proto = DECODE_TUNNEL_ETHERNET;
             break;
-        }
-
-        case ETHERNET_TYPE_ERSPAN:
-        {
-            // Determine if it's Type I or Type II based on the flags in the GRE header.
-            // Type I:  0|0|0|0|0|00000|000000000|00000
-            // Type II: 0|0|0|1|0|00000|000000000|00000
-            //                Seq
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len, len - header_len,
-                    GRE_FLAG_ISSET_SQ(greh) == 0 ? DECODE_TUNNEL_ERSPANI : DECODE_TUNNEL_ERSPANII);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelArp:
+            proto = DECODE_TUNNEL_ARP;
             break;
-        }
-
-        case ETHERNET_TYPE_BRIDGE:
-        {
-            Packet *tp = PacketTunnelPktSetup(tv, dtv, p, pkt + header_len,
-                    len - header_len, DECODE_TUNNEL_ETHERNET);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq,tp);
-            }
+        case GreTunnelErspanI:
+            proto = DECODE_TUNNEL_ERSPANI;
             break;
-        }
-
-        case ETHERNET_TYPE_ARP: {
-            Packet *tp = PacketTunnelPktSetup(
-                    tv, dtv, p, pkt + header_len, len - header_len, DECODE_TUNNEL_ARP);
-            if (tp != NULL) {
-                PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
-                PacketEnqueueNoLock(&tv->decode_pq, tp);
-            }
+        case GreTunnelErspan:
+            /* Type II and III, told apart by the ERSPAN header */
+            proto = DECODE_TUNNEL_ERSPANII;
             break;
-        }
-
         default:
             return TM_ECODE_OK;
     }
+
+    Packet *tp = PacketTunnelPktSetup(
+            tv, dtv, p, pkt + hdr.header_len, len - hdr.header_len, proto);
+    if (tp != NULL) {
+        PKT_SET_SRC(tp, PKT_SRC_DECODER_GRE);
+        PacketEnqueueNoLock(&tv->decode_pq, tp);
+    }
     return TM_ECODE_OK;
 }
 
@@ -39,48 +39,13 @@ typedef struct GREHdr_
 
 } __attribute__((__packed__)) GREHdr;
 
-/* Enhanced GRE header - https://tools.ietf.org/html/rfc2637#section-4.1 */
-typedef struct GREPPtPHdr_ {
-    GREHdr greh;             /** base GRE packet header */
-    uint16_t payload_length; /** PPP payload length */
-    uint16_t call_id;        /** PPP peer id */
-} __attribute__((__packed__)) GREPPtPHd;
-
-/* Generic Routing Encapsulation Source Route Entries (SREs).
- * The header is followed by a variable amount of Routing Information.
- */
-typedef struct GRESreHdr_
-{
-    uint16_t af; /**< Address family */
-    uint8_t sre_offset;
-    uint8_t sre_length;
-} __attribute__((__packed__)) GRESreHdr;
-
-#define GRE_VERSION_0           0x0000
-#define GRE_VERSION_1           0x0001
-
-#define GRE_HDR_LEN             4
-#define GRE_CHKSUM_LEN          2
-#define GRE_OFFSET_LEN          2
-#define GRE_KEY_LEN             4
-#define GRE_SEQ_LEN             4
-#define GRE_SRE_HDR_LEN         4
-#define GRE_PROTO_PPP           0x880b
-
-#define GRE_FLAG_ISSET_CHKSUM(r)    (r->flags & 0x80)
-#define GRE_FLAG_ISSET_ROUTE(r)     (r->flags & 0x40)
-#define GRE_FLAG_ISSET_KY(r)        (r->flags & 0x20)
-#define GRE_FLAG_ISSET_SQ(r)        (r->flags & 0x10)
-#define GRE_FLAG_ISSET_SSR(r)       (r->flags & 0x08)
-#define GRE_FLAG_ISSET_RECUR(r)     (r->flags & 0x07)
-#define GRE_GET_VERSION(r)   (r->version & 0x07)
-#define GRE_GET_FLAGS(r)     (r->version & 0xF8)
-#define GRE_GET_PROTO(r)     SCNtohs(r->ether_type)
-
-#define GREV1_HDR_LEN           8
-#define GREV1_ACK_LEN           4
-#define GREV1_FLAG_ISSET_FLAGS(r)  (r->version & 0x78)
-#define GREV1_FLAG_ISSET_ACK(r)    (r->version & 0x80)
+/** Fields of the GRE header kept on the packet for logging */
+typedef struct GREVars_ {
+    uint32_t key;
+    uint16_t erspan_session_id;
+    bool key_set;
+    bool erspan_session_id_set;
+} GREVars;
 
 void DecodeGRERegisterTests(void);
 
@@ -450,6 +450,7 @@ struct PacketL4 {
         TCPVars tcp;
         ICMPV4Vars icmpv4;
         ICMPV6Vars icmpv6;
+        GREVars gre;
     } vars;
 };
 
@@ -1061,7 +1062,7 @@ static inline void PacketTunnelSetVerdicted(Packet *p)
 
 enum DecodeTunnelProto {
     DECODE_TUNNEL_ETHERNET,
-    DECODE_TUNNEL_ERSPANII,
+    DECODE_TUNNEL_ERSPANII, /**< Type II and III */
     DECODE_TUNNEL_ERSPANI,
     DECODE_TUNNEL_VLAN,
     DECODE_TUNNEL_IPV4,
@@ -0,0 +1,123 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Generic keywords on the app-layer state of a flow, independent of
+ * the protocol: app-layer-state.age and tx.count.
+ */
+
+#include "suricata-common.h"
+#include "rust.h"
+#include "app-layer-parser.h"
+#include "detect-app-layer-state.h"
+#include "detect-engine.h"
+#include "detect-engine-uint.h"
+#include "detect-parse.h"
+
+static int DetectAppLayerStateAgeMatch(
+        DetectEngineThreadCtx *det_ctx, Packet *p, const Signature *s, const SigMatchCtx *ctx)
+{
+    if (p->flow == NULL) {
+        return 0;
+    }
+    SCTime_t created;
+    if (!AppLayerParserGetStateCreationTs(p->flow, &created)) {
+        return 0;
+    }
+    uint32_t age = SCTIME_SECS(p->flow->lastts) - SCTIME_SECS(created);
+
+    const DetectU32Data *du32 = (const DetectU32Data *)ctx;
+    return DetectU32Match(age, du32);
+}
+
+static void DetectAppLayerStateAgeFree(DetectEngineCtx *de_ctx, void *ptr)
+{
+    rs_detect_u32_free(ptr);
+}
+
+static int DetectAppLayerStateAgeSetup(DetectEngineCtx *de_ctx, Signature *s, const char *rawstr)
+{
+    DetectU32Data *du32 = DetectU32Parse(rawstr);
+    if (du32 == NULL)
+        return -1;
+
+    if (SigMatchAppendSMToList(de_ctx, s, DETECT_APP_LAYER_STATE_AGE, (SigMatchCtx *)du32,
+                DETECT_SM_LIST_MATCH) == NULL) {
+        DetectAppLayerStateAgeFree(de_ctx, du32);
+        return -1;
+    }
+    s->flags |= SIG_FLAG_REQUIRE_PACKET;
+
+    return 0;
+}
+
+void DetectAppLayerStateAgeRegister(void)
+{
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].name = "app-layer-state.age";
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].desc =
+            "match on the time since the app-layer state of the flow was created";
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].url = "/rules/app-layer.html#app-layer-state-age";
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].Match = DetectAppLayerStateAgeMatch;
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].Setup = DetectAppLayerStateAgeSetup;
+    sigmatch_table[DETECT_APP_LAYER_STATE_AGE].Free = DetectAppLayerStateAgeFree;
+}
+
+static int DetectTxCountMatch(
+        DetectEngineThreadCtx *det_ctx, Packet *p, const Signature *s, const SigMatchCtx *ctx)
+{
+    if (p->flow == NULL || p->flow->alstate == NULL) {
+        return 0;
+    }
+    uint64_t tx_cnt = AppLayerParserGetTxCnt(p->flow, p->flow->alstate);
+
+    const DetectU64Data *du64 = (const DetectU64Data *)ctx;
+    return DetectU64Match(tx_cnt, du64);
+}
+
+static void DetectTxCountFree(DetectEngineCtx *de_ctx, void *ptr)
+{
+    rs_detect_u64_free(ptr);
+}
+
+static int DetectTxCountSetup(DetectEngineCtx *de_ctx, Signature *s, const char *rawstr)
+{
+    DetectU64Data *du64 = DetectU64Parse(rawstr);
+    if (du64 == NULL)
+        return -1;
+
+    if (SigMatchAppendSMToList(
+                de_ctx, s, DETECT_TX_COUNT, (SigMatchCtx *)du64, DETECT_SM_LIST_MATCH) == NULL) {
+        DetectTxCountFree(de_ctx, du64);
+        return -1;
+    }
+    s->flags |= SIG_FLAG_REQUIRE_PACKET;
+
+    return 0;
+}
+
+void DetectTxCountRegister(void)
+{
+    sigmatch_table[DETECT_TX_COUNT].name = "tx.count";
+    sigmatch_table[DETECT_TX_COUNT].desc =
+            "match on the number of app-layer transactions created in the flow";
+    sigmatch_table[DETECT_TX_COUNT].url = "/rules/app-layer.html#tx-count";
+    sigmatch_table[DETECT_TX_COUNT].Match = DetectTxCountMatch;
+    sigmatch_table[DETECT_TX_COUNT].Setup = DetectTxCountSetup;
+    sigmatch_table[DETECT_TX_COUNT].Free = DetectTxCountFree;
+}
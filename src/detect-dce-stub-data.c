@@ -1836,6 +1836,81 @@ static int DetectDceStubDataTestParse06(void)
     PASS;
 }
 
+/**
+ * \test Test dce_stub_data on DCERPC over UDP, with the stub data content
+ *       as fast pattern.
+ */
+static int DetectDceStubDataTestParse07(void)
+{
+    ThreadVars th_v;
+    Flow f;
+    DetectEngineThreadCtx *det_ctx = NULL;
+    AppLayerParserThreadCtx *alp_tctx = AppLayerParserThreadCtxAlloc();
+    FAIL_IF_NULL(alp_tctx);
+
+    /* request, little endian, not fragmented, 8 bytes of stub data */
+    uint8_t dcerpc_request[] = {
+        0x04, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xb8, 0x4a, 0x9f, 0x4d, 0x1c, 0x7d, 0xcf, 0x11,
+        0x86, 0x1e, 0x00, 0x20, 0xaf, 0x6e, 0x7c, 0x57,
+        0x86, 0xc2, 0x37, 0x67, 0xf7, 0x1e, 0xd1, 0x11,
+        0xbc, 0xd9, 0x00, 0x60, 0x97, 0x92, 0xd2, 0x6c,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x00, 0xff, 0xff,
+        0xff, 0xff, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x41, 0x41, 0x41, 0x41, 0x42, 0x42, 0x42, 0x42,
+    };
+    uint32_t dcerpc_request_len = sizeof(dcerpc_request);
+
+    memset(&th_v, 0, sizeof(th_v));
+    memset(&f, 0, sizeof(f));
+
+    Packet *p = UTHBuildPacket(NULL, 0, IPPROTO_UDP);
+    FAIL_IF_NULL(p);
+
+    FLOW_INITIALIZE(&f);
+    f.proto = IPPROTO_UDP;
+    f.protomap = FlowGetProtoMapping(f.proto);
+    f.alproto = ALPROTO_DCERPC;
+    p->flow = &f;
+    p->flowflags |= FLOW_PKT_TOSERVER;
+    p->flags |= PKT_HAS_FLOW;
+
+    DetectEngineCtx *de_ctx = DetectEngineCtxInit();
+    FAIL_IF_NULL(de_ctx);
+    de_ctx->flags |= DE_QUIET;
+
+    Signature *s = DetectEngineAppendSig(de_ctx, "alert udp any any -> any any "
+                                                 "(msg:\"DCERPC\"; "
+                                                 "dcerpc.stub_data; content:\"AAAABBBB\"; "
+                                                 "sid:1;)");
+    FAIL_IF_NULL(s);
+
+    SigGroupBuild(de_ctx);
+    DetectEngineThreadCtxInit(&th_v, (void *)de_ctx, (void *)&det_ctx);
+
+    /* the stub data content is the fast pattern */
+    FAIL_IF_NULL(s->app_inspect);
+    FAIL_IF_NOT(s->app_inspect->mpm);
+
+    int r = AppLayerParserParse(NULL, alp_tctx, &f, ALPROTO_DCERPC, STREAM_TOSERVER | STREAM_START,
+            dcerpc_request, dcerpc_request_len);
+    FAIL_IF(r != 0);
+    FAIL_IF_NULL(f.alstate);
+
+    SigMatchSignatures(&th_v, de_ctx, det_ctx, p);
+    FAIL_IF_NOT(PacketAlertCheck(p, 1));
+
+    AppLayerParserThreadCtxFree(alp_tctx);
+    DetectEngineThreadCtxDeinit(&th_v, (void *)det_ctx);
+    DetectEngineCtxFree(de_ctx);
+    FLOW_DESTROY(&f);
+    UTHFreePackets(&p, 1);
+    PASS;
+}
+
 static void DetectDceStubDataRegisterTests(void)
 {
     UtRegisterTest("DetectDceStubDataTestParse02",
@@ -1848,5 +1923,6 @@ static void DetectDceStubDataRegisterTests(void)
                    DetectDceStubDataTestParse05);
     UtRegisterTest("DetectDceStubDataTestParse06",
                    DetectDceStubDataTestParse06);
+    UtRegisterTest("DetectDceStubDataTestParse07", DetectDceStubDataTestParse07);
 }
 #endif
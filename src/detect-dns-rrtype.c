@@ -35,7 +35,7 @@ static int DetectDnsRrtypeSetup(DetectEngineCtx *de_ctx, Signature *s, const cha
         SCReturnInt(-1);
     }
 
-    void *detect = DetectU16Parse(str);
+    void *detect = SCDnsDetectRrtypeParse(str);
     if (detect == NULL) {
         SCLogError("failed to parse dns.rrtype: %s", str);
         SCReturnInt(-1);
@@ -54,7 +54,7 @@ static void DetectDnsRrtypeFree(DetectEngineCtx *de_ctx, void *ptr)
 {
     SCEnter();
     if (ptr != NULL) {
-        rs_detect_u16_free(ptr);
+        SCDnsDetectRrtypeFree(ptr);
     }
     SCReturn;
 }
@@ -68,7 +68,8 @@ static int DetectDnsRrtypeMatch(DetectEngineThreadCtx *det_ctx, Flow *f, uint8_t
 void DetectDnsRrtypeRegister(void)
 {
     sigmatch_table[DETECT_AL_DNS_RRTYPE].name = "dns.rrtype";
-    sigmatch_table[DETECT_AL_DNS_RRTYPE].desc = "Match the DNS rrtype in message body.";
+    sigmatch_table[DETECT_AL_DNS_RRTYPE].desc =
+            "Match a list of DNS rrtypes in the query, answer or authority section.";
     sigmatch_table[DETECT_AL_DNS_RRTYPE].url = "/rules/dns-keywords.html#dns-rrtype";
     sigmatch_table[DETECT_AL_DNS_RRTYPE].Setup = DetectDnsRrtypeSetup;
     sigmatch_table[DETECT_AL_DNS_RRTYPE].Free = DetectDnsRrtypeFree;
@@ -21,10 +21,10 @@
 #include "rust.h"
 
 typedef union {
-    uint8_t u8[16];
-    uint16_t u16[8];
-    uint32_t u32[4];
-    uint64_t u64[2];
+    uint8_t u8[24];
+    uint16_t u16[12];
+    uint32_t u32[6];
+    uint64_t u64[3];
 } PrefilterPacketHeaderValue;
 
 #define PREFILTER_EXTRA_MATCH_UNUSED  0
@@ -83,6 +83,10 @@
 
 #include "detect-config.h"
 
+#include "detect-smb-dialect.h"
+#include "detect-smb-filename.h"
+#include "detect-smb-ntstatus.h"
+#include "detect-smb-read-scan.h"
 #include "detect-smb-share.h"
 #include "detect-smb-version.h"
 
@@ -187,6 +191,7 @@
 #include "detect-iprep.h"
 #include "detect-geoip.h"
 #include "detect-app-layer-protocol.h"
+#include "detect-app-layer-state.h"
 #include "detect-template.h"
 #include "detect-template2.h"
 #include "detect-tcphdr.h"
@@ -561,6 +566,8 @@ void SigTableSetup(void)
     DetectFlowPktsToServerRegister();
     DetectFlowBytesToClientRegister();
     DetectFlowBytesToServerRegister();
+    DetectAppLayerStateAgeRegister();
+    DetectTxCountRegister();
     DetectRequiresRegister();
     DetectWindowRegister();
     DetectRpcRegister();
@@ -600,9 +607,14 @@ void SigTableSetup(void)
     DetectDceStubDataRegister();
     DetectSmbNamedPipeRegister();
     DetectSmbShareRegister();
+    DetectSmbShareHostRegister();
     DetectSmbNtlmsspUserRegister();
     DetectSmbNtlmsspDomainRegister();
     DetectSmbVersionRegister();
+    DetectSmbFilenameRegister();
+    DetectSmbDialectRegister();
+    DetectSmbReadScanRegister();
+    DetectSmbNtstatusRegister();
     DetectTlsRegister();
     DetectTlsValidityRegister();
     DetectTlsVersionRegister();
@@ -683,6 +695,14 @@ void SigTableSetup(void)
     ScDetectRfbRegister();
     ScDetectSipRegister();
     ScDetectTemplateRegister();
+    SCDetectRsyncRegister();
+    SCDetectStunRegister();
+    SCDetectSshRegister();
+    SCDetectDnsRegister();
+    SCDetectRdpRegister();
+    SCDetectLdapRegister();
+    SCDetectDcerpcRegister();
+    SCDetectSmbRegister();
 
     /* close keyword registration */
     DetectBufferTypeCloseRegistration();
@@ -129,6 +129,9 @@ enum DetectKeywordId {
     DETECT_FLOW_BYTES_TO_CLIENT,
     DETECT_FLOW_BYTES_TO_SERVER,
 
+    DETECT_APP_LAYER_STATE_AGE,
+    DETECT_TX_COUNT,
+
     DETECT_REQUIRES,
 
     DETECT_AL_TLS_VERSION,
@@ -214,9 +217,14 @@ enum DetectKeywordId {
     DETECT_DCE_STUB_DATA,
     DETECT_SMB_NAMED_PIPE,
     DETECT_SMB_SHARE,
+    DETECT_SMB_SHARE_HOST,
     DETECT_SMB_NTLMSSP_USER,
     DETECT_SMB_NTLMSSP_DOMAIN,
     DETECT_SMB_VERSION,
+    DETECT_SMB_FILENAME,
+    DETECT_SMB_DIALECT,
+    DETECT_SMB_READ_SCAN,
+    DETECT_SMB_NTSTATUS,
 
     DETECT_ENGINE_EVENT,
     DETECT_STREAM_EVENT,
@@ -148,3 +148,19 @@ DetectUintData_u64 *DetectU64Parse(const char *u64str)
 {
     return rs_detect_u64_parse(u64str);
 }
+
+void PrefilterPacketU64Set(PrefilterPacketHeaderValue *v, void *smctx)
+{
+    const DetectUintData_u64 *a = smctx;
+    v->u8[0] = a->mode;
+    v->u64[1] = a->arg1;
+    v->u64[2] = a->arg2;
+}
+
+bool PrefilterPacketU64Compare(PrefilterPacketHeaderValue v, void *smctx)
+{
+    const DetectUintData_u64 *a = smctx;
+    if (v.u8[0] == a->mode && v.u64[1] == a->arg1 && v.u64[2] == a->arg2)
+        return true;
+    return false;
+}
@@ -204,6 +204,34 @@ static int DetectFlowBytesToClientSetup(DetectEngineCtx *de_ctx, Signature *s, c
     return 0;
 }
 
+static void PrefilterPacketFlowBytesToClientMatch(
+        DetectEngineThreadCtx *det_ctx, Packet *p, const void *pectx)
+{
+    const PrefilterPacketHeaderCtx *ctx = pectx;
+    if (!PrefilterPacketHeaderExtraMatch(ctx, p))
+        return;
+
+    DetectU64Data du64;
+    du64.mode = ctx->v1.u8[0];
+    du64.arg1 = ctx->v1.u64[1];
+    du64.arg2 = ctx->v1.u64[2];
+    if (DetectFlowBytesToClientMatch(det_ctx, p, NULL, (const SigMatchCtx *)&du64)) {
+        PrefilterAddSids(&det_ctx->pmq, ctx->sigs_array, ctx->sigs_cnt);
+    }
+}
+
+static int PrefilterSetupFlowBytesToClient(DetectEngineCtx *de_ctx, SigGroupHead *sgh)
+{
+    return PrefilterSetupPacketHeader(de_ctx, sgh, DETECT_FLOW_BYTES_TO_CLIENT,
+            SIG_MASK_REQUIRE_FLOW, PrefilterPacketU64Set, PrefilterPacketU64Compare,
+            PrefilterPacketFlowBytesToClientMatch);
+}
+
+static bool PrefilterFlowBytesToClientIsPrefilterable(const Signature *s)
+{
+    return PrefilterIsPrefilterableById(s, DETECT_FLOW_BYTES_TO_CLIENT);
+}
+
 void DetectFlowBytesToClientRegister(void)
 {
     sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].name = "flow.bytes_toclient";
@@ -213,6 +241,9 @@ void DetectFlowBytesToClientRegister(void)
     sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].Match = DetectFlowBytesToClientMatch;
     sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].Setup = DetectFlowBytesToClientSetup;
     sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].Free = DetectFlowBytesToClientFree;
+    sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].SupportsPrefilter =
+            PrefilterFlowBytesToClientIsPrefilterable;
+    sigmatch_table[DETECT_FLOW_BYTES_TO_CLIENT].SetupPrefilter = PrefilterSetupFlowBytesToClient;
 }
 
 static int DetectFlowBytesToServerMatch(
@@ -248,6 +279,34 @@ static int DetectFlowBytesToServerSetup(DetectEngineCtx *de_ctx, Signature *s, c
     return 0;
 }
 
+static void PrefilterPacketFlowBytesToServerMatch(
+        DetectEngineThreadCtx *det_ctx, Packet *p, const void *pectx)
+{
+    const PrefilterPacketHeaderCtx *ctx = pectx;
+    if (!PrefilterPacketHeaderExtraMatch(ctx, p))
+        return;
+
+    DetectU64Data du64;
+    du64.mode = ctx->v1.u8[0];
+    du64.arg1 = ctx->v1.u64[1];
+    du64.arg2 = ctx->v1.u64[2];
+    if (DetectFlowBytesToServerMatch(det_ctx, p, NULL, (const SigMatchCtx *)&du64)) {
+        PrefilterAddSids(&det_ctx->pmq, ctx->sigs_array, ctx->sigs_cnt);
+    }
+}
+
+static int PrefilterSetupFlowBytesToServer(DetectEngineCtx *de_ctx, SigGroupHead *sgh)
+{
+    return PrefilterSetupPacketHeader(de_ctx, sgh, DETECT_FLOW_BYTES_TO_SERVER,
+            SIG_MASK_REQUIRE_FLOW, PrefilterPacketU64Set, PrefilterPacketU64Compare,
+            PrefilterPacketFlowBytesToServerMatch);
+}
+
+static bool PrefilterFlowBytesToServerIsPrefilterable(const Signature *s)
+{
+    return PrefilterIsPrefilterableById(s, DETECT_FLOW_BYTES_TO_SERVER);
+}
+
 void DetectFlowBytesToServerRegister(void)
 {
     sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].name = "flow.bytes_toserver";
@@ -257,4 +316,7 @@ void DetectFlowBytesToServerRegister(void)
     sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].Match = DetectFlowBytesToServerMatch;
     sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].Setup = DetectFlowBytesToServerSetup;
     sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].Free = DetectFlowBytesToServerFree;
+    sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].SupportsPrefilter =
+            PrefilterFlowBytesToServerIsPrefilterable;
+    sigmatch_table[DETECT_FLOW_BYTES_TO_SERVER].SetupPrefilter = PrefilterSetupFlowBytesToServer;
 }
@@ -100,12 +100,11 @@ static int DetectFtpdataMatch(DetectEngineThreadCtx *det_ctx,
         const Signature *s, const SigMatchCtx *m)
 {
     const DetectFtpdataData *ftpcommandd = (const DetectFtpdataData *) m;
-    const FtpDataState *ftp_state = (const FtpDataState *)state;
 
-    if (ftp_state == NULL)
+    if (txv == NULL)
         return 0;
 
-    if (ftpcommandd->command == ftp_state->command) {
+    if (ftpcommandd->command == SCFtpDataTxGetCommand(txv)) {
         /* Only match if the flow is in the good direction */
         if ((flags & STREAM_TOSERVER) && (ftpcommandd->command == FTP_COMMAND_RETR)) {
             return 0;
@@ -0,0 +1,91 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+/**
+ * \file
+ *
+ * Implements the smb.dialect sticky buffer: the dialect selected by the
+ * server in a SMB1 or SMB2 negotiate, e.g. "NT LM 0.12" or "3.11".
+ */
+
+#include "suricata-common.h"
+
+#include "detect.h"
+#include "detect-parse.h"
+
+#include "detect-engine.h"
+#include "detect-engine-mpm.h"
+#include "detect-engine-prefilter.h"
+#include "detect-engine-content-inspection.h"
+
+#include "detect-smb-dialect.h"
+#include "rust.h"
+
+#define KEYWORD_NAME "smb.dialect"
+#define KEYWORD_ID   DETECT_SMB_DIALECT
+
+static int g_smb_dialect_buffer_id = 0;
+
+static int DetectSmbDialectSetup(DetectEngineCtx *de_ctx, Signature *s, const char *arg)
+{
+    if (DetectBufferSetActiveList(de_ctx, s, g_smb_dialect_buffer_id) < 0)
+        return -1;
+
+    if (DetectSignatureSetAppProto(s, ALPROTO_SMB) < 0)
+        return -1;
+
+    return 0;
+}
+
+static InspectionBuffer *GetDialectData(DetectEngineThreadCtx *det_ctx,
+        const DetectEngineTransforms *transforms, Flow *_f, const uint8_t _flow_flags, void *txv,
+        const int list_id)
+{
+    InspectionBuffer *buffer = InspectionBufferGet(det_ctx, list_id);
+    if (buffer->inspect == NULL) {
+        uint32_t b_len = 0;
+        const uint8_t *b = NULL;
+
+        if (rs_smb_tx_get_dialect(txv, &b, &b_len) != 1)
+            return NULL;
+        if (b == NULL || b_len == 0)
+            return NULL;
+
+        InspectionBufferSetup(det_ctx, list_id, buffer, b, b_len);
+        InspectionBufferApplyTransforms(buffer, transforms);
+    }
+    return buffer;
+}
+
+void DetectSmbDialectRegister(void)
+{
+    sigmatch_table[KEYWORD_ID].name = KEYWORD_NAME;
+    sigmatch_table[KEYWORD_ID].Setup = DetectSmbDialectSetup;
+    sigmatch_table[KEYWORD_ID].flags |= SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER;
+    sigmatch_table[KEYWORD_ID].desc =
+            "sticky buffer to match on the SMB dialect selected by the server in negotiate";
+    sigmatch_table[KEYWORD_ID].url = "/rules/smb-keywords.html#smb-dialect";
+
+    DetectAppLayerMpmRegister(KEYWORD_NAME, SIG_FLAG_TOCLIENT, 2, PrefilterGenericMpmRegister,
+            GetDialectData, ALPROTO_SMB, 1);
+
+    DetectAppLayerInspectEngineRegister(KEYWORD_NAME, ALPROTO_SMB, SIG_FLAG_TOCLIENT, 1,
+            DetectEngineInspectBufferGeneric, GetDialectData);
+
+    DetectBufferTypeSetDescriptionByName(KEYWORD_NAME, "smb negotiated dialect");
+
+    g_smb_dialect_buffer_id = DetectBufferTypeGetByName(KEYWORD_NAME);
+}
@@ -0,0 +1,94 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Implements the smb.filename multi-buffer keyword: the file names of
+ * create, delete, query and rename transactions. A rename provides
+ * both the old and the new name.
+ */
+
+#include "suricata-common.h"
+
+#include "detect.h"
+#include "detect-parse.h"
+
+#include "detect-engine.h"
+#include "detect-engine-mpm.h"
+#include "detect-engine-prefilter.h"
+#include "detect-engine-content-inspection.h"
+
+#include "detect-smb-filename.h"
+#include "rust.h"
+
+#define KEYWORD_NAME "smb.filename"
+#define KEYWORD_ID   DETECT_SMB_FILENAME
+
+static int g_smb_filename_buffer_id = 0;
+
+static int DetectSmbFilenameSetup(DetectEngineCtx *de_ctx, Signature *s, const char *arg)
+{
+    if (DetectBufferSetActiveList(de_ctx, s, g_smb_filename_buffer_id) < 0)
+        return -1;
+
+    if (DetectSignatureSetAppProto(s, ALPROTO_SMB) < 0)
+        return -1;
+
+    return 0;
+}
+
+static InspectionBuffer *GetFilenameData(DetectEngineThreadCtx *det_ctx,
+        const DetectEngineTransforms *transforms, Flow *_f, const uint8_t _flow_flags, void *txv,
+        const int list_id, uint32_t local_id)
+{
+    InspectionBuffer *buffer = InspectionBufferMultipleForListGet(det_ctx, list_id, local_id);
+    if (buffer == NULL)
+        return NULL;
+    if (buffer->initialized)
+        return buffer;
+
+    const uint8_t *b = NULL;
+    uint32_t b_len = 0;
+    if (rs_smb_tx_get_filename(txv, local_id, &b, &b_len) != 1) {
+        InspectionBufferSetupMultiEmpty(buffer);
+        return NULL;
+    }
+
+    InspectionBufferSetupMulti(buffer, transforms, b, b_len);
+    buffer->flags = DETECT_CI_FLAGS_SINGLE;
+    return buffer;
+}
+
+void DetectSmbFilenameRegister(void)
+{
+    sigmatch_table[KEYWORD_ID].name = KEYWORD_NAME;
+    sigmatch_table[KEYWORD_ID].Setup = DetectSmbFilenameSetup;
+    sigmatch_table[KEYWORD_ID].flags |= SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER;
+    sigmatch_table[KEYWORD_ID].desc =
+            "sticky buffer to match on the SMB file names of create, delete, query and rename "
+            "requests";
+    sigmatch_table[KEYWORD_ID].url = "/rules/smb-keywords.html#smb-filename";
+
+    DetectAppLayerMultiRegister(
+            KEYWORD_NAME, ALPROTO_SMB, SIG_FLAG_TOSERVER, 0, GetFilenameData, 2, 1);
+
+    DetectBufferTypeSetDescriptionByName(KEYWORD_NAME, "smb file names");
+    DetectBufferTypeSupportsMultiInstance(KEYWORD_NAME);
+
+    g_smb_filename_buffer_id = DetectBufferTypeGetByName(KEYWORD_NAME);
+}
@@ -0,0 +1,104 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Implements the smb.ntstatus keyword
+ */
+
+#include "suricata-common.h"
+
+#include "detect.h"
+#include "detect-parse.h"
+
+#include "detect-engine.h"
+#include "detect-engine-uint.h"
+
+#include "detect-smb-ntstatus.h"
+#include "rust.h"
+
+#define BUFFER_NAME  "smb_ntstatus"
+#define KEYWORD_NAME "smb.ntstatus"
+
+static int g_smb_ntstatus_list_id = 0;
+
+static void DetectSmbNtstatusFree(DetectEngineCtx *de_ctx, void *ptr)
+{
+    rs_detect_u32_free(ptr);
+}
+
+/**
+ * \brief Setup the "smb.ntstatus" keyword, which takes an unsigned
+ *        32-bit integer value or a status name like STATUS_ACCESS_DENIED.
+ *
+ * \retval 0 on success, -1 on failure
+ */
+static int DetectSmbNtstatusSetup(DetectEngineCtx *de_ctx, Signature *s, const char *arg)
+{
+    if (DetectSignatureSetAppProto(s, ALPROTO_SMB) < 0)
+        return -1;
+
+    DetectU32Data *du32 = rs_smb_ntstatus_parse(arg);
+    if (du32 == NULL) {
+        SCLogError("Error parsing smb.ntstatus option in signature");
+        return -1;
+    }
+
+    if (SigMatchAppendSMToList(
+                de_ctx, s, DETECT_SMB_NTSTATUS, (SigMatchCtx *)du32, g_smb_ntstatus_list_id) ==
+            NULL) {
+        DetectSmbNtstatusFree(de_ctx, du32);
+        return -1;
+    }
+
+    return 0;
+}
+
+/**
+ * \brief App layer match function for the "smb.ntstatus" keyword.
+ *
+ * \retval 1 On Match.
+ * \retval 0 On no match.
+ */
+static int DetectSmbNtstatusMatchRust(DetectEngineThreadCtx *det_ctx, Flow *f, uint8_t flags,
+        void *state, void *txv, const Signature *s, const SigMatchCtx *m)
+{
+    return rs_smb_tx_match_ntstatus(txv, (const DetectU32Data *)m);
+}
+
+/**
+ * \brief Registers the keyword handlers for the "smb.ntstatus" keyword.
+ */
+void DetectSmbNtstatusRegister(void)
+{
+    sigmatch_table[DETECT_SMB_NTSTATUS].name = KEYWORD_NAME;
+    sigmatch_table[DETECT_SMB_NTSTATUS].Setup = DetectSmbNtstatusSetup;
+    sigmatch_table[DETECT_SMB_NTSTATUS].AppLayerTxMatch = DetectSmbNtstatusMatchRust;
+    sigmatch_table[DETECT_SMB_NTSTATUS].Free = DetectSmbNtstatusFree;
+    sigmatch_table[DETECT_SMB_NTSTATUS].desc =
+            "match on the NTSTATUS of an SMB response, including SMB1 DOS errors";
+    sigmatch_table[DETECT_SMB_NTSTATUS].url = "/rules/smb-keywords.html#smb-ntstatus";
+
+    DetectAppLayerInspectEngineRegister(
+            BUFFER_NAME, ALPROTO_SMB, SIG_FLAG_TOSERVER, 0, DetectEngineInspectGenericList, NULL);
+
+    DetectAppLayerInspectEngineRegister(
+            BUFFER_NAME, ALPROTO_SMB, SIG_FLAG_TOCLIENT, 0, DetectEngineInspectGenericList, NULL);
+
+    g_smb_ntstatus_list_id = DetectBufferTypeRegister(BUFFER_NAME);
+}
@@ -0,0 +1,89 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Implements the smb.read_scan keyword
+ */
+
+#include "suricata-common.h"
+
+#include "detect.h"
+#include "detect-parse.h"
+
+#include "detect-engine.h"
+
+#include "detect-smb-read-scan.h"
+#include "rust.h"
+
+#define BUFFER_NAME  "smb_read_scan"
+#define KEYWORD_NAME "smb.read_scan"
+
+static int g_smb_read_scan_list_id = 0;
+
+/**
+ * \brief Setup the "smb.read_scan" keyword, which takes no value.
+ *
+ * \retval 0 on success, -1 on failure
+ */
+static int DetectSmbReadScanSetup(DetectEngineCtx *de_ctx, Signature *s, const char *arg)
+{
+    if (DetectSignatureSetAppProto(s, ALPROTO_SMB) < 0)
+        return -1;
+
+    if (SigMatchAppendSMToList(de_ctx, s, DETECT_SMB_READ_SCAN, NULL, g_smb_read_scan_list_id) ==
+            NULL) {
+        return -1;
+    }
+
+    return 0;
+}
+
+/**
+ * \brief App layer match function for the "smb.read_scan" keyword.
+ *
+ * \retval 1 On Match.
+ * \retval 0 On no match.
+ */
+static int DetectSmbReadScanMatchRust(DetectEngineThreadCtx *det_ctx, Flow *f, uint8_t flags,
+        void *state, void *txv, const Signature *s, const SigMatchCtx *m)
+{
+    return rs_smb_tx_match_read_scan(state, txv);
+}
+
+/**
+ * \brief Registers the keyword handlers for the "smb.read_scan" keyword.
+ */
+void DetectSmbReadScanRegister(void)
+{
+    sigmatch_table[DETECT_SMB_READ_SCAN].name = KEYWORD_NAME;
+    sigmatch_table[DETECT_SMB_READ_SCAN].Setup = DetectSmbReadScanSetup;
+    sigmatch_table[DETECT_SMB_READ_SCAN].AppLayerTxMatch = DetectSmbReadScanMatchRust;
+    sigmatch_table[DETECT_SMB_READ_SCAN].desc =
+            "match if the SMB session shows a scan-like read pattern";
+    sigmatch_table[DETECT_SMB_READ_SCAN].url = "/rules/smb-keywords.html#smb-read-scan";
+    sigmatch_table[DETECT_SMB_READ_SCAN].flags = SIGMATCH_NOOPT;
+
+    DetectAppLayerInspectEngineRegister(
+            BUFFER_NAME, ALPROTO_SMB, SIG_FLAG_TOSERVER, 0, DetectEngineInspectGenericList, NULL);
+
+    DetectAppLayerInspectEngineRegister(
+            BUFFER_NAME, ALPROTO_SMB, SIG_FLAG_TOCLIENT, 0, DetectEngineInspectGenericList, NULL);
+
+    g_smb_read_scan_list_id = DetectBufferTypeRegister(BUFFER_NAME);
+}
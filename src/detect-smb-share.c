@@ -152,3 +152,64 @@ void DetectSmbShareRegister(void)
 
     g_smb_share_buffer_id = DetectBufferTypeGetByName(BUFFER_NAME);
 }
+
+#undef BUFFER_NAME
+#undef KEYWORD_NAME
+#undef KEYWORD_NAME_LEGACY
+#undef KEYWORD_ID
+
+#define KEYWORD_NAME "smb.share_host"
+#define KEYWORD_ID   DETECT_SMB_SHARE_HOST
+
+static int g_smb_share_host_buffer_id = 0;
+
+static int DetectSmbShareHostSetup(DetectEngineCtx *de_ctx, Signature *s, const char *arg)
+{
+    if (DetectBufferSetActiveList(de_ctx, s, g_smb_share_host_buffer_id) < 0)
+        return -1;
+
+    if (DetectSignatureSetAppProto(s, ALPROTO_SMB) < 0)
+        return -1;
+
+    return 0;
+}
+
+static InspectionBuffer *GetShareHostData(DetectEngineThreadCtx *det_ctx,
+        const DetectEngineTransforms *transforms, Flow *_f, const uint8_t _flow_flags, void *txv,
+        const int list_id)
+{
+    InspectionBuffer *buffer = InspectionBufferGet(det_ctx, list_id);
+    if (buffer->inspect == NULL) {
+        uint32_t b_len = 0;
+        const uint8_t *b = NULL;
+
+        if (rs_smb_tx_get_share_host(txv, &b, &b_len) != 1)
+            return NULL;
+        if (b == NULL || b_len == 0)
+            return NULL;
+
+        InspectionBufferSetup(det_ctx, list_id, buffer, b, b_len);
+        InspectionBufferApplyTransforms(buffer, transforms);
+    }
+    return buffer;
+}
+
+void DetectSmbShareHostRegister(void)
+{
+    sigmatch_table[KEYWORD_ID].name = KEYWORD_NAME;
+    sigmatch_table[KEYWORD_ID].Setup = DetectSmbShareHostSetup;
+    sigmatch_table[KEYWORD_ID].flags |= SIGMATCH_NOOPT | SIGMATCH_INFO_STICKY_BUFFER;
+    sigmatch_table[KEYWORD_ID].desc =
+            "sticky buffer to match on the server name of the SMB tree connect path";
+    sigmatch_table[KEYWORD_ID].url = "/rules/smb-keywords.html#smb-share-host";
+
+    DetectAppLayerMpmRegister(KEYWORD_NAME, SIG_FLAG_TOSERVER, 2, PrefilterGenericMpmRegister,
+            GetShareHostData, ALPROTO_SMB, 1);
+
+    DetectAppLayerInspectEngineRegister(KEYWORD_NAME, ALPROTO_SMB, SIG_FLAG_TOSERVER, 0,
+            DetectEngineInspectBufferGeneric, GetShareHostData);
+
+    DetectBufferTypeSetDescriptionByName(KEYWORD_NAME, "smb tree connect server name");
+
+    g_smb_share_host_buffer_id = DetectBufferTypeGetByName(KEYWORD_NAME);
+}
@@ -26,5 +26,6 @@
 
 void DetectSmbNamedPipeRegister(void);
 void DetectSmbShareRegister(void);
+void DetectSmbShareHostRegister(void);
 
 #endif /* SURICATA_DETECT_SMB_NAMED_PIPE_H */
@@ -388,6 +388,8 @@ static inline void FlowWorkerStreamTCPUpdate(ThreadVars *tv, FlowWorkerThreadDat
     while ((x = PacketDequeueNoLock(&fw->pq))) {
         SCLogDebug("packet %"PRIu64" extra packet %p", p->pcap_cnt, x);
 
+        AppLayerParserAnomalySummaryAddPacket(x);
+
         if (detect_thread != NULL) {
             FLOWWORKER_PROFILING_START(x, PROFILE_FLOWWORKER_DETECT);
             Detect(tv, x, detect_thread);
@@ -434,6 +436,7 @@ static void FlowWorkerFlowTimeout(ThreadVars *tv, Packet *p, FlowWorkerThreadDat
     FlowWorkerStreamTCPUpdate(tv, fw, p, detect_thread, true);
 
     PacketUpdateEngineEventCounters(tv, fw->dtv, p);
+    AppLayerParserAnomalySummaryAddPacket(p);
 
     /* handle Detect */
     SCLogDebug("packet %"PRIu64" calling Detect", p->pcap_cnt);
@@ -627,6 +630,7 @@ static TmEcode FlowWorker(ThreadVars *tv, Packet *p, void *data)
     }
 
     PacketUpdateEngineEventCounters(tv, fw->dtv, p);
+    AppLayerParserAnomalySummaryAddPacket(p);
 
     /* handle Detect */
     DEBUG_ASSERT_FLOW_LOCKED(p->flow);
@@ -1228,6 +1228,73 @@ uint32_t FlowGetFlags(Flow *flow)
 {
     return flow->flags;
 }
+
+/**
+ * \brief Get flow id.
+ *
+ * A function to get the flow id as logged in the EVE records useful when
+ * the caller only has an opaque pointer to the flow structure.
+ */
+int64_t FlowGetFlowId(Flow *flow)
+{
+    return FlowGetId(flow);
+}
+
+/**
+ * \brief Set the parent flow id.
+ *
+ * A function to link a flow to the flow it was expected by useful when the
+ * caller only has an opaque pointer to the flow structure.
+ */
+void FlowSetParentId(Flow *flow, int64_t id)
+{
+    flow->parent_id = id;
+}
+
+/**
+ * \brief Check if an address is the source or destination of the flow.
+ *
+ * A function to match an address against the flow useful when the caller
+ * only has an opaque pointer to the flow structure.
+ *
+ * \param addr IPv4 or IPv6 address in network byte order
+ * \param addr_len 4 for IPv4, 16 for IPv6
+ */
+bool FlowHasAddress(Flow *flow, const uint8_t *addr, uint8_t addr_len)
+{
+    if (FLOW_IS_IPV4(flow) && addr_len == 4) {
+        return memcmp(flow->src.addr_data8, addr, 4) == 0 ||
+               memcmp(flow->dst.addr_data8, addr, 4) == 0;
+    } else if (FLOW_IS_IPV6(flow) && addr_len == 16) {
+        return memcmp(flow->src.addr_data8, addr, 16) == 0 ||
+               memcmp(flow->dst.addr_data8, addr, 16) == 0;
+    }
+    return false;
+}
+
+/**
+ * \brief Get the source and destination addresses of the flow.
+ *
+ * A function to get the addresses of the flow useful when the caller only
+ * has an opaque pointer to the flow structure.
+ *
+ * \param src buffer of 16 bytes for the source address in network byte order
+ * \param dst buffer of 16 bytes for the destination address in network byte order
+ * \retval length of the addresses, 4 for IPv4, 16 for IPv6, 0 otherwise
+ */
+uint8_t FlowGetAddresses(Flow *flow, uint8_t *src, uint8_t *dst)
+{
+    if (FLOW_IS_IPV4(flow)) {
+        memcpy(src, flow->src.addr_data8, 4);
+        memcpy(dst, flow->dst.addr_data8, 4);
+        return 4;
+    } else if (FLOW_IS_IPV6(flow)) {
+        memcpy(src, flow->src.addr_data8, 16);
+        memcpy(dst, flow->dst.addr_data8, 16);
+        return 16;
+    }
+    return 0;
+}
 /************************************Unittests*******************************/
 
 #ifdef UNITTESTS
@@ -588,8 +588,12 @@ void RegisterFlowBypassInfo(void);
 
 void FlowGetLastTimeAsParts(Flow *flow, uint64_t *secs, uint64_t *usecs);
 uint32_t FlowGetFlags(Flow *flow);
+int64_t FlowGetFlowId(Flow *flow);
+void FlowSetParentId(Flow *flow, int64_t id);
 uint16_t FlowGetSourcePort(Flow *flow);
 uint16_t FlowGetDestinationPort(Flow *flow);
+bool FlowHasAddress(Flow *flow, const uint8_t *addr, uint8_t addr_len);
+uint8_t FlowGetAddresses(Flow *flow, uint8_t *src, uint8_t *dst);
 
 /** ----- Inline functions ----- */
 
@@ -281,6 +281,16 @@ static void AlertJsonTunnel(const Packet *p, JsonBuilder *js)
     jb_set_uint(js, "dest_port", addr.dp);
     jb_set_string(js, "proto", addr.proto);
 
+    if (PacketIsGRE(p->root)) {
+        const GREVars *gre = &p->root->l4.vars.gre;
+        if (gre->key_set) {
+            jb_set_uint(js, "gre_key", gre->key);
+        }
+        if (gre->erspan_session_id_set) {
+            jb_set_uint(js, "erspan_session_id", gre->erspan_session_id);
+        }
+    }
+
     jb_set_uint(js, "depth", p->recursion_level);
     if (pcap_cnt != 0) {
         jb_set_uint(js, "pcap_cnt", pcap_cnt);
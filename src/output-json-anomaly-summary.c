@@ -0,0 +1,93 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Logs a single record per flow at flow end with the number of times
+ * each app-layer event was raised on the flow.
+ */
+
+#include "suricata-common.h"
+#include "conf.h"
+
+#include "threadvars.h"
+
+#include "util-debug.h"
+
+#include "output.h"
+#include "output-json.h"
+#include "output-json-flow.h"
+#include "output-json-anomaly-summary.h"
+
+#include "app-layer-parser.h"
+
+/** set if the anomaly summary logger is enabled, so the app-layer parser
+ *  keeps track of the events of the transactions it frees. */
+bool g_anomaly_summary_enabled = false;
+
+static int JsonAnomalySummaryLogger(ThreadVars *tv, void *thread_data, Flow *f)
+{
+    SCEnter();
+    OutputJsonThreadCtx *thread = thread_data;
+
+    AnomalySummary *summary = SCAnomalySummaryNew();
+    if (unlikely(summary == NULL)) {
+        SCReturnInt(TM_ECODE_OK);
+    }
+    JsonBuilder *jb = NULL;
+    if (!AppLayerParserGetAnomalySummary(f, summary)) {
+        goto end;
+    }
+
+    jb = CreateEveHeaderFromFlow(f, "anomaly_summary");
+    if (unlikely(jb == NULL)) {
+        goto end;
+    }
+
+    EveAddAppProto(f, jb);
+    if (!SCAnomalySummaryLogJson(summary, jb)) {
+        goto end;
+    }
+    EveAddCommonOptions(&thread->ctx->cfg, NULL, f, jb, LOG_DIR_FLOW);
+
+    OutputJsonBuilderBuffer(jb, thread);
+
+end:
+    if (jb != NULL) {
+        jb_free(jb);
+    }
+    SCAnomalySummaryFree(summary);
+    SCReturnInt(TM_ECODE_OK);
+}
+
+static OutputInitResult JsonAnomalySummaryLogInitSub(ConfNode *conf, OutputCtx *parent_ctx)
+{
+    OutputInitResult result = OutputJsonLogInitSub(conf, parent_ctx);
+    if (result.ok) {
+        g_anomaly_summary_enabled = true;
+    }
+    return result;
+}
+
+void JsonAnomalySummaryLogRegister(void)
+{
+    /* register as child of eve-log */
+    OutputRegisterFlowSubModule(LOGGER_JSON_ANOMALY_SUMMARY, "eve-log", "JsonAnomalySummaryLog",
+            "eve-log.anomaly-summary", JsonAnomalySummaryLogInitSub, JsonAnomalySummaryLogger,
+            JsonLogThreadInit, JsonLogThreadDeinit);
+}
@@ -0,0 +1,126 @@
+/* Copyright (C) 2024 Open Information Security Foundation
+ *
+ * You can copy, redistribute or modify this Program under the terms of
+ * the GNU General Public License version 2 as published by the Free
+ * Software Foundation.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * version 2 along with this program; if not, write to the Free Software
+ * Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA
+ * 02110-1301, USA.
+ */
+
+/**
+ * \file
+ *
+ * Logs a single record per flow at flow end with the client and server
+ * fingerprints computed by the app-layer parsers: JA3, JA3S and JA4 for
+ * TLS, hassh and hassh-server for SSH and the RDP client fingerprint.
+ */
+
+#include "suricata-common.h"
+#include "conf.h"
+
+#include "threadvars.h"
+
+#include "util-debug.h"
+#include "util-ja3.h"
+#include "util-ja4.h"
+
+#include "output.h"
+#include "output-json.h"
+#include "output-json-flow.h"
+#include "output-json-fingerprint.h"
+
+#include "app-layer-ssl.h"
+
+static void JsonFingerprintAddJa3(
+        FlowFingerprints *fps, const char *kind, const SSLStateConnp *connp)
+{
+    const char *str = NULL;
+    if (connp->ja3_str != NULL) {
+        str = connp->ja3_str->data;
+    }
+    SCFingerprintsAdd(fps, kind, connp->ja3_hash, str);
+}
+
+static void JsonFingerprintAddTls(FlowFingerprints *fps, SSLState *ssl_state)
+{
+    JsonFingerprintAddJa3(fps, "ja3", &ssl_state->client_connp);
+    JsonFingerprintAddJa3(fps, "ja3s", &ssl_state->server_connp);
+
+    if (ssl_state->client_connp.ja4 != NULL) {
+        char buffer[JA4_HEX_LEN + 1];
+        SCJA4GetHash(ssl_state->client_connp.ja4, (uint8_t(*)[JA4_HEX_LEN])buffer);
+        buffer[JA4_HEX_LEN] = '\0';
+        SCFingerprintsAdd(fps, "ja4", buffer, NULL);
+    }
+}
+
+static int JsonFingerprintLogger(ThreadVars *tv, void *thread_data, Flow *f)
+{
+    SCEnter();
+    OutputJsonThreadCtx *thread = thread_data;
+
+    void *state = FlowGetAppState(f);
+    if (state == NULL) {
+        SCReturnInt(TM_ECODE_OK);
+    }
+
+    FlowFingerprints *fps = SCFingerprintsNew();
+    if (unlikely(fps == NULL)) {
+        SCReturnInt(TM_ECODE_OK);
+    }
+
+    switch (f->alproto) {
+        case ALPROTO_TLS:
+            JsonFingerprintAddTls(fps, state);
+            break;
+        case ALPROTO_SSH:
+            SCSshStateGetFingerprints(state, fps);
+            break;
+        case ALPROTO_RDP:
+            SCRdpStateGetFingerprints(state, fps);
+            break;
+        default:
+            break;
+    }
+
+    if (SCFingerprintsIsEmpty(fps)) {
+        SCFingerprintsFree(fps);
+        SCReturnInt(TM_ECODE_OK);
+    }
+
+    JsonBuilder *jb = CreateEveHeaderFromFlow(f, "fingerprint");
+    if (unlikely(jb == NULL)) {
+        SCFingerprintsFree(fps);
+        SCReturnInt(TM_ECODE_OK);
+    }
+
+    EveAddAppProto(f, jb);
+    if (!SCFingerprintsLogJson(fps, jb)) {
+        jb_free(jb);
+        SCFingerprintsFree(fps);
+        SCReturnInt(TM_ECODE_OK);
+    }
+    EveAddCommonOptions(&thread->ctx->cfg, NULL, f, jb, LOG_DIR_FLOW);
+
+    OutputJsonBuilderBuffer(jb, thread);
+    jb_free(jb);
+    SCFingerprintsFree(fps);
+
+    SCReturnInt(TM_ECODE_OK);
+}
+
+void JsonFingerprintLogRegister(void)
+{
+    /* register as child of eve-log */
+    OutputRegisterFlowSubModule(LOGGER_JSON_FINGERPRINT, "eve-log", "JsonFingerprintLog",
+            "eve-log.fingerprint", OutputJsonLogInitSub, JsonFingerprintLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+}
@@ -51,7 +51,10 @@
 #include "stream-tcp-private.h"
 #include "flow-storage.h"
 
-static JsonBuilder *CreateEveHeaderFromFlow(const Flow *f)
+/**
+ * \brief create an EVE record header for a flow record of type \a event_type
+ */
+JsonBuilder *CreateEveHeaderFromFlow(const Flow *f, const char *event_type)
 {
     char timebuf[64];
     char srcip[46] = {0}, dstip[46] = {0};
@@ -104,7 +107,7 @@ static JsonBuilder *CreateEveHeaderFromFlow(const Flow *f)
         jb_set_string(jb, "in_iface", f->livedev->dev);
     }
 
-    JB_SET_STRING(jb, "event_type", "flow");
+    jb_set_string(jb, "event_type", event_type);
 
     /* vlan */
     if (f->vlan_idx > 0) {
@@ -323,6 +326,10 @@ static void EveFlowLogJSON(OutputJsonThreadCtx *aft, JsonBuilder *jb, Flow *f)
         /* Close tcp. */
         jb_close(jb);
     }
+
+    if (f->alproto == ALPROTO_SMB && f->alstate != NULL) {
+        SCSmbStateLogFlow(jb, f->alstate);
+    }
 }
 
 static int JsonFlowLogger(ThreadVars *tv, void *thread_data, Flow *f)
@@ -333,7 +340,7 @@ static int JsonFlowLogger(ThreadVars *tv, void *thread_data, Flow *f)
     /* reset */
     MemBufferReset(thread->buffer);
 
-    JsonBuilder *jb = CreateEveHeaderFromFlow(f);
+    JsonBuilder *jb = CreateEveHeaderFromFlow(f, "flow");
     if (unlikely(jb == NULL)) {
         SCReturnInt(TM_ECODE_OK);
     }
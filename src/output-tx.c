@@ -58,6 +58,8 @@ typedef struct OutputTxLogger_ {
     int ts_log_progress;
     TmEcode (*ThreadInit)(ThreadVars *, const void *, void **);
     TmEcode (*ThreadDeinit)(ThreadVars *, void *);
+    /** NULL if all transactions are logged */
+    EveSampler *sampler;
 } OutputTxLogger;
 
 static OutputTxLogger **list = NULL;
@@ -65,11 +67,21 @@ static OutputTxLogger **list = NULL;
 int SCOutputRegisterTxLogger(LoggerId id, const char *name, AppProto alproto, TxLogger LogFunc,
         void *initdata, int tc_log_progress, int ts_log_progress, TxLoggerCondition LogCondition,
         ThreadInitFunc ThreadInit, ThreadDeinitFunc ThreadDeinit)
+{
+    return OutputRegisterTxLoggerSampled(id, name, alproto, LogFunc, initdata, tc_log_progress,
+            ts_log_progress, LogCondition, ThreadInit, ThreadDeinit, NULL);
+}
+
+int OutputRegisterTxLoggerSampled(LoggerId id, const char *name, AppProto alproto,
+        TxLogger LogFunc, void *initdata, int tc_log_progress, int ts_log_progress,
+        TxLoggerCondition LogCondition, ThreadInitFunc ThreadInit, ThreadDeinitFunc ThreadDeinit,
+        EveSampler *sampler)
 {
     if (list == NULL) {
         list = SCCalloc(ALPROTO_MAX, sizeof(OutputTxLogger *));
         if (unlikely(list == NULL)) {
             SCLogError("Failed to allocate OutputTx list");
+            SCEveSamplerFree(sampler);
             return -1;
         }
     }
@@ -77,11 +89,14 @@ int SCOutputRegisterTxLogger(LoggerId id, const char *name, AppProto alproto, Tx
     if (alproto != ALPROTO_UNKNOWN && !(AppLayerParserIsEnabled(alproto))) {
         SCLogDebug(
                 "%s logger not enabled: protocol %s is disabled", name, AppProtoToString(alproto));
+        SCEveSamplerFree(sampler);
         return -1;
     }
     OutputTxLogger *op = SCCalloc(1, sizeof(*op));
-    if (op == NULL)
+    if (op == NULL) {
+        SCEveSamplerFree(sampler);
         return -1;
+    }
 
     op->alproto = alproto;
     op->LogFunc = LogFunc;
@@ -91,6 +106,7 @@ int SCOutputRegisterTxLogger(LoggerId id, const char *name, AppProto alproto, Tx
     op->logger_id = id;
     op->ThreadInit = ThreadInit;
     op->ThreadDeinit = ThreadDeinit;
+    op->sampler = sampler;
 
     if (alproto == ALPROTO_UNKNOWN) {
         op->tc_log_progress = 0;
@@ -314,10 +330,15 @@ static void OutputTxLogCallLoggers(ThreadVars *tv, OutputTxLoggerThreadData *op_
                 }
             }
 
-            SCLogDebug("Logging tx_id %" PRIu64 " to logger %d", tx_id, logger->logger_id);
-            PACKET_PROFILING_LOGGER_START(p, logger->logger_id);
-            logger->LogFunc(tv, store->thread_data, p, f, alstate, tx, tx_id);
-            PACKET_PROFILING_LOGGER_END(p, logger->logger_id);
+            if (logger->sampler != NULL && !SCEveSamplerSample(logger->sampler, tx_id)) {
+                SCLogDebug("tx_id %" PRIu64 " not sampled by logger %d", tx_id, logger->logger_id);
+                StatsIncr(tv, store->suppressed_id);
+            } else {
+                SCLogDebug("Logging tx_id %" PRIu64 " to logger %d", tx_id, logger->logger_id);
+                PACKET_PROFILING_LOGGER_START(p, logger->logger_id);
+                logger->LogFunc(tv, store->thread_data, p, f, alstate, tx, tx_id);
+                PACKET_PROFILING_LOGGER_END(p, logger->logger_id);
+            }
 
             ctx->tx_logged |= BIT_U32(logger->logger_id);
         }
@@ -560,6 +581,10 @@ static TmEcode OutputTxLogThreadInit(ThreadVars *tv, const void *_initdata, void
 
                     /* store thread handle */
                     ts->thread_data = retptr;
+                    if (logger->sampler != NULL) {
+                        ts->suppressed_id =
+                                StatsRegisterCounter(SCEveSamplerCounterName(logger->sampler), tv);
+                    }
 
                     if (td->store[alproto] == NULL) {
                         td->store[alproto] = ts;
@@ -668,6 +693,7 @@ void OutputTxShutdown(void)
         OutputTxLogger *logger = list[alproto];
         while (logger) {
             OutputTxLogger *next_logger = logger->next;
+            SCEveSamplerFree(logger->sampler);
             SCFree(logger);
             logger = next_logger;
         }
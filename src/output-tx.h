@@ -29,6 +29,7 @@
 #include "tm-threads.h"
 #include "decode.h"
 #include "flow.h"
+#include "rust.h"
 
 /** \brief Transaction logger function pointer type. */
 typedef int (*TxLogger)(ThreadVars *, void *thread_data, const Packet *, Flow *f, void *state, void *tx, uint64_t tx_id);
@@ -78,6 +79,16 @@ int SCOutputRegisterTxLogger(LoggerId id, const char *name, AppProto alproto, Tx
         void *, int tc_log_progress, int ts_log_progress, TxLoggerCondition LogCondition,
         ThreadInitFunc, ThreadDeinitFunc);
 
+/** \brief Register a transaction logger that only logs the transactions
+ *  picked by sampler.
+ *
+ * Same as SCOutputRegisterTxLogger, the logger takes ownership of the
+ * sampler. Internal function: private API.
+ */
+int OutputRegisterTxLoggerSampled(LoggerId id, const char *name, AppProto alproto,
+        TxLogger LogFunc, void *, int tc_log_progress, int ts_log_progress,
+        TxLoggerCondition LogCondition, ThreadInitFunc, ThreadDeinitFunc, EveSampler *sampler);
+
 /** Internal function: private API. */
 void OutputTxLoggerRegister (void);
 
@@ -46,6 +46,8 @@
 #include "output-json.h"
 #include "output-json-alert.h"
 #include "output-json-anomaly.h"
+#include "output-json-anomaly-summary.h"
+#include "output-json-fingerprint.h"
 #include "output-json-flow.h"
 #include "output-json-netflow.h"
 #include "log-cf-common.h"
@@ -891,6 +893,16 @@ void OutputRegisterRootLoggers(void)
     RegisterSimpleJsonApplayerLogger(ALPROTO_RFB, rs_rfb_logger_log, NULL);
     RegisterSimpleJsonApplayerLogger(ALPROTO_MQTT, JsonMQTTAddMetadata, NULL);
     RegisterSimpleJsonApplayerLogger(ALPROTO_PGSQL, JsonPgsqlAddMetadata, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_TELNET, rs_telnet_logger_log, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_RSYNC, SCRsyncLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_IMAP, SCImapLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_POP3, SCPop3LoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_OPENVPN, SCOpenVpnLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_WIREGUARD, SCWireGuardLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_STUN, SCStunLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_SYSLOG, SCSyslogLoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_DHCPV6, SCDhcpv6LoggerLog, NULL);
+    RegisterSimpleJsonApplayerLogger(ALPROTO_RTP, SCRtpLoggerLog, NULL);
     RegisterSimpleJsonApplayerLogger(ALPROTO_WEBSOCKET, rs_websocket_logger_log, NULL);
     RegisterSimpleJsonApplayerLogger(ALPROTO_LDAP, rs_ldap_logger_log, NULL);
     RegisterSimpleJsonApplayerLogger(ALPROTO_DOH2, AlertJsonDoh2, NULL);
@@ -1007,6 +1019,10 @@ void OutputRegisterLoggers(void)
     /* flow/netflow */
     JsonFlowLogRegister();
     JsonNetFlowLogRegister();
+    /* per flow app-layer event counts */
+    JsonAnomalySummaryLogRegister();
+    /* per flow client and server fingerprints */
+    JsonFingerprintLogRegister();
     /* json stats */
     JsonStatsLogRegister();
 
@@ -1069,6 +1085,46 @@ void OutputRegisterLoggers(void)
     JsonMQTTLogRegister();
     /* Pgsql JSON logger. */
     JsonPgsqlLogRegister();
+    /* Telnet JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonTelnetLog", "eve-log.telnet",
+            OutputJsonLogInitSub, ALPROTO_TELNET, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* Rsync JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonRsyncLog", "eve-log.rsync",
+            OutputJsonLogInitSub, ALPROTO_RSYNC, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* OpenVPN JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonOpenVpnLog", "eve-log.openvpn",
+            OutputJsonLogInitSub, ALPROTO_OPENVPN, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* WireGuard JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonWireGuardLog", "eve-log.wireguard",
+            OutputJsonLogInitSub, ALPROTO_WIREGUARD, JsonGenericDirPacketLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* STUN JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonStunLog", "eve-log.stun",
+            OutputJsonLogInitSub, ALPROTO_STUN, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* Syslog JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonSyslogLog", "eve-log.syslog",
+            OutputJsonLogInitSub, ALPROTO_SYSLOG, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* DHCPv6 JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonDhcpv6Log", "eve-log.dhcpv6",
+            OutputJsonLogInitSub, ALPROTO_DHCPV6, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* RTP JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonRtpLog", "eve-log.rtp",
+            OutputJsonLogInitSub, ALPROTO_RTP, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* IMAP JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonImapLog", "eve-log.imap",
+            OutputJsonLogInitSub, ALPROTO_IMAP, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
+    /* POP3 JSON logger. */
+    OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonPop3Log", "eve-log.pop3",
+            OutputJsonLogInitSub, ALPROTO_POP3, JsonGenericDirFlowLogger, JsonLogThreadInit,
+            JsonLogThreadDeinit);
     /* WebSocket JSON logger. */
     OutputRegisterTxSubModule(LOGGER_JSON_TX, "eve-log", "JsonWebSocketLog", "eve-log.websocket",
             OutputJsonLogInitSub, ALPROTO_WEBSOCKET, JsonGenericDirPacketLogger, JsonLogThreadInit,
@@ -33,6 +33,9 @@
 typedef struct OutputLoggerThreadStore_ {
     void *thread_data;
     struct OutputLoggerThreadStore_ *next;
+    /** tx loggers: counter of the transactions not logged due to
+     *  sampling, 0 if the logger does not sample */
+    uint16_t suppressed_id;
 } OutputLoggerThreadStore;
 
 #include "output-packet.h"
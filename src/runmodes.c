@@ -587,9 +587,12 @@ static void AddOutputToFreeList(OutputModule *module, OutputCtx *output_ctx)
     TAILQ_INSERT_TAIL(&output_free_list, fl_output, entries);
 }
 
-/** \brief Turn output into thread module */
-static void SetupOutput(
-        const char *name, OutputModule *module, OutputCtx *output_ctx, LoggerId *logger_bits)
+/** \brief Turn output into thread module
+ *
+ *  \param sampler sampler of the transactions of a tx logger, or NULL
+ */
+static void SetupOutput(const char *name, OutputModule *module, OutputCtx *output_ctx,
+        LoggerId *logger_bits, EveSampler *sampler)
 {
     /* flow logger doesn't run in the packet path */
     if (module->FlowLogFunc) {
@@ -614,9 +617,9 @@ static void SetupOutput(
                 module->PacketConditionFunc, output_ctx, module->ThreadInit, module->ThreadDeinit);
     } else if (module->TxLogFunc) {
         SCLogDebug("%s is a tx logger", module->name);
-        SCOutputRegisterTxLogger(module->logger_id, module->name, module->alproto,
+        OutputRegisterTxLoggerSampled(module->logger_id, module->name, module->alproto,
                 module->TxLogFunc, output_ctx, module->tc_log_progress, module->ts_log_progress,
-                module->TxLogCondition, module->ThreadInit, module->ThreadDeinit);
+                module->TxLogCondition, module->ThreadInit, module->ThreadDeinit, sampler);
         /* Not used with wild card loggers */
         if (module->alproto != ALPROTO_UNKNOWN) {
             logger_bits[module->alproto] |= BIT_U32(module->logger_id);
@@ -663,12 +666,14 @@ static void RunModeInitializeEveOutput(ConfNode *conf, OutputCtx *parent_ctx, Lo
         SCLogConfig("enabling 'eve-log' module '%s'", type->val);
 
         ConfNode *sub_output_config = ConfNodeLookupChild(type, type->val);
+        ConfNode *sampling = NULL;
         if (sub_output_config != NULL) {
             const char *enabled = ConfNodeLookupChildValue(
                 sub_output_config, "enabled");
             if (enabled != NULL && !ConfValIsTrue(enabled)) {
                 continue;
             }
+            sampling = ConfNodeLookupChild(sub_output_config, "sampling");
         }
 
         /* Now setup all registers logger of this name. */
@@ -692,8 +697,20 @@ static void RunModeInitializeEveOutput(ConfNode *conf, OutputCtx *parent_ctx, Lo
                     FatalError("unable to initialize sub-module %s", subname);
                 }
 
+                EveSampler *sampler = NULL;
+                if (sampling != NULL) {
+                    if (sub_module->TxLogFunc == NULL) {
+                        FatalError("eve-log type %s does not support sampling", type->val);
+                    }
+                    sampler = SCEveSamplerNew(sampling, type->val);
+                    if (sampler == NULL) {
+                        FatalError("invalid sampling configuration for eve-log type %s",
+                                type->val);
+                    }
+                }
+
                 AddOutputToFreeList(sub_module, result.ctx);
-                SetupOutput(sub_module->name, sub_module, result.ctx, logger_bits);
+                SetupOutput(sub_module->name, sub_module, result.ctx, logger_bits, sampler);
             }
         }
 
@@ -734,7 +751,7 @@ static void RunModeInitializeLuaOutput(ConfNode *conf, OutputCtx *parent_ctx, Lo
         }
 
         AddOutputToFreeList(m, result.ctx);
-        SetupOutput(m->name, m, result.ctx, logger_bits);
+        SetupOutput(m->name, m, result.ctx, logger_bits, NULL);
     }
 }
 
@@ -838,7 +855,7 @@ void RunModeInitializeOutputs(void)
                 AddOutputToFreeList(module, output_ctx);
             } else {
                 AddOutputToFreeList(module, output_ctx);
-                SetupOutput(module->name, module, output_ctx, logger_bits);
+                SetupOutput(module->name, module, output_ctx, logger_bits, NULL);
             }
         }
         if (count == 0) {
@@ -877,7 +894,7 @@ void RunModeInitializeOutputs(void)
                 }
 
                 AddOutputToFreeList(module, output_ctx);
-                SetupOutput(module->name, module, output_ctx, logger_bits);
+                SetupOutput(module->name, module, output_ctx, logger_bits, NULL);
             }
         }
     }
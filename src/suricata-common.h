@@ -492,6 +492,8 @@ typedef enum {
     LOGGER_JSON_FRAME,
     LOGGER_JSON_STREAM,
     LOGGER_JSON_ARP,
+    LOGGER_JSON_ANOMALY_SUMMARY,
+    LOGGER_JSON_FINGERPRINT,
 
     /* An ID that can be used by loggers registered by plugins and/or
      * library users. */
@@ -719,6 +719,39 @@ static TmEcode UnixManagerCaptureModeCommand(json_t *cmd,
     SCReturnInt(TM_ECODE_OK);
 }
 
+/**
+ * \brief Command to get the status of the components of the engine,
+ *        for now the registration of the app-layer parsers.
+ */
+static TmEcode UnixManagerStatusCommand(json_t *cmd, json_t *server_msg, void *data)
+{
+    SCEnter();
+    JsonBuilder *js = jb_new_array();
+    if (js == NULL) {
+        json_object_set_new(
+                server_msg, "message", json_string("internal error at json array creation"));
+        SCReturnInt(TM_ECODE_FAILED);
+    }
+    if (!SCAppLayerParserRegistrationsToJson(js) || !jb_close(js)) {
+        jb_free(js);
+        json_object_set_new(server_msg, "message", json_string("internal error at json creation"));
+        SCReturnInt(TM_ECODE_FAILED);
+    }
+    json_t *parsers = json_loadb((const char *)jb_ptr(js), jb_len(js), 0, NULL);
+    jb_free(js);
+    json_t *status = json_object();
+    if (parsers == NULL || status == NULL) {
+        json_decref(parsers);
+        json_decref(status);
+        json_object_set_new(
+                server_msg, "message", json_string("internal error at json object creation"));
+        SCReturnInt(TM_ECODE_FAILED);
+    }
+    json_object_set_new(status, "app-layer-parsers", parsers);
+    json_object_set_new(server_msg, "message", status);
+    SCReturnInt(TM_ECODE_OK);
+}
+
 static TmEcode UnixManagerReloadRulesWrapper(json_t *cmd, json_t *server_msg, void *data, int do_wait)
 {
     SCEnter();
@@ -1080,6 +1113,7 @@ int UnixManagerInit(void)
     UnixManagerRegisterCommand("uptime", UnixManagerUptimeCommand, &command, 0);
     UnixManagerRegisterCommand("running-mode", UnixManagerRunningModeCommand, &command, 0);
     UnixManagerRegisterCommand("capture-mode", UnixManagerCaptureModeCommand, &command, 0);
+    UnixManagerRegisterCommand("status", UnixManagerStatusCommand, &command, 0);
     UnixManagerRegisterCommand("conf-get", UnixManagerConfGetCommand, &command, UNIX_CMD_TAKE_ARGS);
     UnixManagerRegisterCommand("dump-counters", StatsOutputCounterSocket, NULL, 0);
     UnixManagerRegisterCommand("reload-rules", UnixManagerReloadRules, NULL, 0);
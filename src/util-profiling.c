@@ -1277,6 +1277,8 @@ const char *PacketProfileLoggerIdToString(LoggerId id)
         CASE_CODE(LOGGER_JSON_FRAME);
         CASE_CODE(LOGGER_JSON_STREAM);
         CASE_CODE(LOGGER_JSON_ARP);
+        CASE_CODE(LOGGER_JSON_ANOMALY_SUMMARY);
+        CASE_CODE(LOGGER_JSON_FINGERPRINT);
         CASE_CODE(LOGGER_USER);
 
         case LOGGER_SIZE: